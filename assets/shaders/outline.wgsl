@@ -0,0 +1,43 @@
+#import bevy_pbr::mesh_types
+#import bevy_pbr::mesh_view_bindings
+
+#ifdef PER_OBJECT_BUFFER_BATCH_SIZE
+@group(1) @binding(0)
+var<uniform> mesh: array<Mesh, #{PER_OBJECT_BUFFER_BATCH_SIZE}u>;
+#else
+@group(1) @binding(0)
+var<storage> mesh: array<Mesh>;
+#endif
+
+// NOTE: Bindings must come before functions that use them!
+#import bevy_pbr::mesh_functions
+
+const OUTLINE_WIDTH: f32 = 0.03;
+const OUTLINE_COLOR: vec4<f32> = vec4<f32>(1.0, 0.8, 0.1, 1.0);
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    // Inflate the mesh along its normals, so that its back faces stick out around the mesh
+    let position = vertex.position + normalize(vertex.normal) * OUTLINE_WIDTH;
+    var out: VertexOutput;
+    out.clip_position = mesh_position_local_to_clip(
+        get_model_matrix(vertex.instance_index),
+        vec4<f32>(position, 1.0)
+    );
+    return out;
+}
+
+@fragment
+fn fragment() -> @location(0) vec4<f32> {
+    return OUTLINE_COLOR;
+}
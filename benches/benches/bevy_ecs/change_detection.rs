@@ -23,6 +23,13 @@ struct Table(f32);
 #[derive(Component, Default)]
 #[component(storage = "SparseSet")]
 struct Sparse(f32);
+// The same components tracking replacements, which stores a third tick for them
+#[derive(Component, Default)]
+#[component(storage = "Table", track_replacements)]
+struct TrackedTable(f32);
+#[derive(Component, Default)]
+#[component(storage = "SparseSet", track_replacements)]
+struct TrackedSparse(f32);
 
 trait BenchModify {
     fn bench_modify(&mut self) -> f32;
@@ -40,6 +47,18 @@ impl BenchModify for Sparse {
         black_box(self.0)
     }
 }
+impl BenchModify for TrackedTable {
+    fn bench_modify(&mut self) -> f32 {
+        self.0 += 1f32;
+        black_box(self.0)
+    }
+}
+impl BenchModify for TrackedSparse {
+    fn bench_modify(&mut self) -> f32 {
+        self.0 += 1f32;
+        black_box(self.0)
+    }
+}
 
 const RANGE_ENTITIES_TO_BENCH_COUNT: std::ops::Range<u32> = 5..7;
 
@@ -96,6 +115,8 @@ fn all_added_detection(criterion: &mut Criterion) {
             vec![
                 Box::new(all_added_detection_generic::<Table>),
                 Box::new(all_added_detection_generic::<Sparse>),
+                Box::new(all_added_detection_generic::<TrackedTable>),
+                Box::new(all_added_detection_generic::<TrackedSparse>),
             ],
             entity_count,
         );
@@ -144,6 +165,8 @@ fn all_changed_detection(criterion: &mut Criterion) {
             vec![
                 Box::new(all_changed_detection_generic::<Table>),
                 Box::new(all_changed_detection_generic::<Sparse>),
+                Box::new(all_changed_detection_generic::<TrackedTable>),
+                Box::new(all_changed_detection_generic::<TrackedSparse>),
             ],
             entity_count,
         );
@@ -194,6 +217,8 @@ fn few_changed_detection(criterion: &mut Criterion) {
             vec![
                 Box::new(few_changed_detection_generic::<Table>),
                 Box::new(few_changed_detection_generic::<Sparse>),
+                Box::new(few_changed_detection_generic::<TrackedTable>),
+                Box::new(few_changed_detection_generic::<TrackedSparse>),
             ],
             entity_count,
         );
@@ -238,6 +263,8 @@ fn none_changed_detection(criterion: &mut Criterion) {
             vec![
                 Box::new(none_changed_detection_generic::<Table>),
                 Box::new(none_changed_detection_generic::<Sparse>),
+                Box::new(none_changed_detection_generic::<TrackedTable>),
+                Box::new(none_changed_detection_generic::<TrackedSparse>),
             ],
             entity_count,
         );
@@ -1,5 +1,11 @@
-use bevy_ecs::{component::Component, schedule::Schedule, world::World};
-use criterion::{BenchmarkId, Criterion};
+use bevy_ecs::{
+    component::Component,
+    entity_disabling::Disabled,
+    query::{Allows, QueryState, ReadOnlyWorldQuery},
+    schedule::Schedule,
+    world::World,
+};
+use criterion::{measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion};
 
 #[derive(Component)]
 struct A<const N: u16>(f32);
@@ -110,3 +116,40 @@ pub fn added_archetypes(criterion: &mut Criterion) {
         );
     }
 }
+
+/// Matches a query against `archetype_count` new archetypes, none of them disabled.
+fn match_archetypes<F: ReadOnlyWorldQuery>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    archetype_count: u16,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(name, archetype_count),
+        &archetype_count,
+        |bencher, &archetype_count| {
+            bencher.iter_batched(
+                || {
+                    let mut world = World::new();
+                    let query = QueryState::<&A<0>, F>::new(&mut world);
+                    add_archetypes(&mut world, archetype_count);
+                    (world, query)
+                },
+                |(world, mut query)| {
+                    query.update_archetypes(&world);
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        },
+    );
+}
+
+/// Compares matching archetypes with the default query filters, in a world never using
+/// [`Disabled`], to matching them without: a query allowing `Disabled` isn't filtered by it.
+pub fn query_archetype_matching(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("query_archetype_matching");
+    for archetype_count in [100, 1000, 10000] {
+        match_archetypes::<()>(&mut group, "default_filters", archetype_count);
+        match_archetypes::<Allows<Disabled>>(&mut group, "no_default_filters", archetype_count);
+    }
+    group.finish();
+}
@@ -17,6 +17,7 @@ criterion_group!(
     insert_simple,
     no_archetypes,
     added_archetypes,
+    query_archetype_matching,
 );
 
 fn add_remove(c: &mut Criterion) {
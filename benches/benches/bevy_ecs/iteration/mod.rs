@@ -10,6 +10,7 @@ mod iter_frag_sparse;
 mod iter_frag_wide;
 mod iter_frag_wide_sparse;
 mod iter_simple;
+mod iter_simple_disabled;
 mod iter_simple_foreach;
 mod iter_simple_foreach_sparse_set;
 mod iter_simple_foreach_wide;
@@ -45,6 +46,10 @@ fn iter_simple(c: &mut Criterion) {
         let mut bench = iter_simple_system::Benchmark::new();
         b.iter(move || bench.run());
     });
+    group.bench_function("disabled", |b| {
+        let mut bench = iter_simple_disabled::Benchmark::new();
+        b.iter(move || bench.run());
+    });
     group.bench_function("sparse_set", |b| {
         let mut bench = iter_simple_sparse_set::Benchmark::new();
         b.iter(move || bench.run());
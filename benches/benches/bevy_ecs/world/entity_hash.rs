@@ -0,0 +1,68 @@
+use bevy_ecs::entity::{Entity, EntityHashMap};
+use bevy_utils::HashMap;
+use criterion::{black_box, BenchmarkId, Criterion, Throughput};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const SIZES: [usize; 4] = [100, 1_000, 10_000, 100_000];
+
+/// Generates entities with mostly sequential indices and a few reused ones,
+/// similar to the entities of a world that has been running for a while.
+fn make_entities(count: usize) -> Vec<Entity> {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    (0..count)
+        .map(|index| {
+            let generation = if rng.gen_bool(0.1) {
+                rng.gen_range(1..16)
+            } else {
+                0
+            };
+            Entity::from_bits((generation as u64) << 32 | index as u64)
+        })
+        .collect()
+}
+
+pub fn entity_hash(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("entity_hash");
+
+    for size in SIZES {
+        group.throughput(Throughput::Elements(size as u64));
+        let entities = make_entities(size);
+
+        group.bench_with_input(
+            BenchmarkId::new("entity_hash_map", size),
+            &entities,
+            |bencher, entities| {
+                let mut map = EntityHashMap::default();
+                bencher.iter(|| {
+                    map.clear();
+                    for (i, &entity) in entities.iter().enumerate() {
+                        map.insert(entity, i);
+                    }
+                    for entity in entities {
+                        black_box(map.get(entity));
+                    }
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("hash_map", size),
+            &entities,
+            |bencher, entities| {
+                let mut map = HashMap::default();
+                bencher.iter(|| {
+                    map.clear();
+                    for (i, &entity) in entities.iter().enumerate() {
+                        map.insert(entity, i);
+                    }
+                    for entity in entities {
+                        black_box(map.get(entity));
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
@@ -1,10 +1,12 @@
 use criterion::criterion_group;
 
 mod commands;
+mod entity_hash;
 mod spawn;
 mod world_get;
 
 use commands::*;
+use entity_hash::*;
 use spawn::*;
 use world_get::*;
 
@@ -30,4 +32,5 @@ criterion_group!(
     query_get_many::<2>,
     query_get_many::<5>,
     query_get_many::<10>,
+    entity_hash,
 );
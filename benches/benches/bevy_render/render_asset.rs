@@ -0,0 +1,69 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use bevy_app::App;
+use bevy_asset::AssetPlugin;
+use bevy_core::TaskPoolPlugin;
+use bevy_ecs::system::SystemState;
+use bevy_math::URect;
+use bevy_render::{
+    render_asset::RenderAsset,
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+    renderer::{RenderDevice, RenderQueue},
+    texture::{Image, ImagePlugin},
+    RenderApp, RenderPlugin,
+};
+
+criterion_group!(benches, paint_canvas);
+criterion_main!(benches);
+
+/// Compares uploading a whole 4k canvas texture with uploading the 256x256 region of it painted
+/// every frame. Requires a GPU.
+fn paint_canvas(c: &mut Criterion) {
+    let mut app = App::new();
+    app.add_plugin(TaskPoolPlugin::default())
+        .add_plugin(AssetPlugin::default())
+        .add_plugin(RenderPlugin::default())
+        .add_plugin(ImagePlugin::default());
+    let render_world = &mut app.sub_app_mut(RenderApp).world;
+    let mut state = SystemState::<<Image as RenderAsset>::Param>::new(render_world);
+    let mut param = state.get(render_world);
+    let render_device = render_world.resource::<RenderDevice>();
+    let render_queue = render_world.resource::<RenderQueue>();
+    let flush = || {
+        render_queue.submit([]);
+        render_device.poll(wgpu::Maintain::Wait);
+    };
+
+    let size = Extent3d {
+        width: 4096,
+        height: 4096,
+        depth_or_array_layers: 1,
+    };
+    let mut canvas = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[255, 255, 255, 255],
+        TextureFormat::Rgba8UnormSrgb,
+    );
+    let mut gpu_canvas =
+        Image::prepare_asset(canvas.clone(), &mut param).unwrap_or_else(|_| unreachable!());
+
+    let mut group = c.benchmark_group("paint_canvas_4k");
+    group.bench_function("full_upload", |b| {
+        b.iter(|| {
+            gpu_canvas =
+                Image::prepare_asset(canvas.clone(), &mut param).unwrap_or_else(|_| unreachable!());
+            flush();
+        });
+    });
+
+    canvas.mark_dirty(URect::new(1024, 1024, 1280, 1280));
+    group.bench_function("dirty_region_upload", |b| {
+        b.iter(|| {
+            Image::update_asset(canvas.clone(), &mut gpu_canvas, &mut param)
+                .unwrap_or_else(|_| unreachable!());
+            flush();
+        });
+    });
+    group.finish();
+}
@@ -0,0 +1,70 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use bevy_app::{App, IntoSystemAppConfig};
+use bevy_asset::{AddAsset, AssetPlugin};
+use bevy_core::{FrameCountPlugin, TaskPoolPlugin};
+use bevy_math::Vec3;
+use bevy_render::{
+    camera::{Camera, OrthographicProjection},
+    primitives::Frustum,
+    texture::ImagePlugin,
+    view::VisibleEntities,
+    ExtractSchedule, RenderApp, RenderPlugin,
+};
+use bevy_sprite::{extract_sprites, ExtractedSprites, SpriteBundle, TextureAtlas};
+use bevy_time::TimePlugin;
+use bevy_transform::components::{GlobalTransform, Transform};
+use bevy_window::WindowPlugin;
+
+criterion_group!(benches, extract_many_sprites);
+criterion_main!(benches);
+
+/// Measures the frames of an app extracting a grid of visible sprites, like the `many_sprites`
+/// stress test. Requires a GPU.
+///
+/// To compare the parallel extraction with the previous one, save a baseline of this benchmark
+/// with `--save-baseline` on the parent commit of the change and run it again with `--baseline`.
+fn extract_many_sprites(c: &mut Criterion) {
+    let mut group = c.benchmark_group("extract_sprites");
+    for sprite_count in [1_000, 10_000, 100_000] {
+        let mut app = App::new();
+        app.add_plugin(TaskPoolPlugin::default())
+            .add_plugin(FrameCountPlugin)
+            .add_plugin(TimePlugin)
+            .add_plugin(WindowPlugin {
+                primary_window: None,
+                ..Default::default()
+            })
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(RenderPlugin::default())
+            .add_plugin(ImagePlugin::default())
+            .add_asset::<TextureAtlas>();
+        app.sub_app_mut(RenderApp)
+            .init_resource::<ExtractedSprites>()
+            .add_system(extract_sprites.in_schedule(ExtractSchedule));
+
+        // the sprites have no `Aabb`, so they are visible in every view
+        app.world.spawn((
+            Camera::default(),
+            OrthographicProjection::default(),
+            Frustum::default(),
+            VisibleEntities::default(),
+            GlobalTransform::default(),
+        ));
+        let side = (sprite_count as f32).sqrt() as u32;
+        app.world.spawn_batch((0..sprite_count).map(move |i| {
+            let translation = Vec3::new((i % side) as f32, (i / side) as f32, i as f32 * 1e-4);
+            SpriteBundle {
+                transform: Transform::from_translation(translation),
+                global_transform: GlobalTransform::from_translation(translation),
+                ..Default::default()
+            }
+        }));
+        app.update();
+
+        group.bench_function(BenchmarkId::from_parameter(sprite_count), |b| {
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
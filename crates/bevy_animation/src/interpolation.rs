@@ -0,0 +1,179 @@
+use bevy_math::{Quat, Vec2, Vec3, Vec4};
+use bevy_reflect::{FromReflect, Reflect};
+use bevy_render::color::Color;
+use std::ops::{Add, Mul};
+
+/// How the values of a curve are interpolated between its keyframes.
+#[derive(Reflect, FromReflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// The values are linearly interpolated, with a spherical linear interpolation for the
+    /// rotations.
+    #[default]
+    Linear,
+    /// The value of a keyframe is kept until the next keyframe.
+    Step,
+    /// The values are interpolated with a cubic Hermite spline.
+    ///
+    /// Each keyframe has three values: an in-tangent, the value, and an out-tangent, in this
+    /// order. The tangents are per second.
+    CubicSpline,
+}
+
+/// A value that can be interpolated between the keyframes of a curve.
+pub(crate) trait Interpolate: Copy {
+    /// Linearly interpolates from `self` to `end`.
+    fn interpolate_linear(self, end: Self, lerp: f32) -> Self;
+
+    /// Evaluates the cubic Hermite spline from `self` to `end`, with the tangents per second
+    /// `out_tangent` and `in_tangent`, at `lerp` in a step of `duration` seconds.
+    fn interpolate_cubic(
+        self,
+        out_tangent: Self,
+        in_tangent: Self,
+        end: Self,
+        lerp: f32,
+        duration: f32,
+    ) -> Self;
+}
+
+macro_rules! impl_interpolate {
+    ($($ty:ty),*) => {
+        $(impl Interpolate for $ty {
+            fn interpolate_linear(self, end: Self, lerp: f32) -> Self {
+                self + (end - self) * lerp
+            }
+
+            fn interpolate_cubic(
+                self,
+                out_tangent: Self,
+                in_tangent: Self,
+                end: Self,
+                lerp: f32,
+                duration: f32,
+            ) -> Self {
+                cubic_spline_interpolation(self, out_tangent, in_tangent, end, lerp, duration)
+            }
+        })*
+    };
+}
+
+impl_interpolate!(f32, Vec2, Vec3, Vec4);
+
+impl Interpolate for Quat {
+    fn interpolate_linear(self, end: Self, lerp: f32) -> Self {
+        // Choose the smallest angle for the rotation
+        let end = if end.dot(self) < 0.0 { -end } else { end };
+        // Rotations are using a spherical linear interpolation
+        self.normalize().slerp(end.normalize(), lerp)
+    }
+
+    fn interpolate_cubic(
+        self,
+        out_tangent: Self,
+        in_tangent: Self,
+        end: Self,
+        lerp: f32,
+        duration: f32,
+    ) -> Self {
+        // The spline of the components isn't a unit quaternion between keyframes
+        cubic_spline_interpolation(self, out_tangent, in_tangent, end, lerp, duration).normalize()
+    }
+}
+
+/// Colors are interpolated in linear RGBA.
+impl Interpolate for Color {
+    fn interpolate_linear(self, end: Self, lerp: f32) -> Self {
+        let start = Vec4::from(self.as_linear_rgba_f32());
+        let end = Vec4::from(end.as_linear_rgba_f32());
+        let [r, g, b, a] = start.interpolate_linear(end, lerp).to_array();
+        Color::rgba_linear(r, g, b, a)
+    }
+
+    fn interpolate_cubic(
+        self,
+        out_tangent: Self,
+        in_tangent: Self,
+        end: Self,
+        lerp: f32,
+        duration: f32,
+    ) -> Self {
+        let [start, out_tangent, in_tangent, end] = [self, out_tangent, in_tangent, end]
+            .map(|color| Vec4::from(color.as_linear_rgba_f32()));
+        let [r, g, b, a] = start
+            .interpolate_cubic(out_tangent, in_tangent, end, lerp, duration)
+            .to_array();
+        Color::rgba_linear(r, g, b, a)
+    }
+}
+
+fn cubic_spline_interpolation<T>(
+    start: T,
+    out_tangent: T,
+    in_tangent: T,
+    end: T,
+    lerp: f32,
+    duration: f32,
+) -> T
+where
+    T: Add<Output = T> + Mul<f32, Output = T>,
+{
+    let lerp2 = lerp * lerp;
+    let lerp3 = lerp2 * lerp;
+    start * (2.0 * lerp3 - 3.0 * lerp2 + 1.0)
+        + out_tangent * (duration * (lerp3 - 2.0 * lerp2 + lerp))
+        + end * (-2.0 * lerp3 + 3.0 * lerp2)
+        + in_tangent * (duration * (lerp3 - lerp2))
+}
+
+/// Samples `keyframes` at `seek_time`, in seconds.
+///
+/// Before the first keyframe the value of the first keyframe is returned, and after the last
+/// keyframe the value of the last one. Returns `None` if there are no keyframes.
+pub(crate) fn sample_keyframes<T: Interpolate>(
+    timestamps: &[f32],
+    keyframes: &[T],
+    interpolation: Interpolation,
+    seek_time: f32,
+) -> Option<T> {
+    let last = timestamps.len().checked_sub(1)?;
+    // Returns the value of the keyframe at `index`, skipping the tangents of cubic splines.
+    let value_at = |index: usize| match interpolation {
+        Interpolation::Linear | Interpolation::Step => keyframes[index],
+        Interpolation::CubicSpline => keyframes[index * 3 + 1],
+    };
+
+    if last == 0 {
+        return Some(value_at(0));
+    }
+
+    // Find the current keyframe
+    // PERF: finding the current keyframe can be optimised
+    let step_start =
+        match timestamps.binary_search_by(|probe| probe.partial_cmp(&seek_time).unwrap()) {
+            Ok(i) => i,
+            Err(0) => return Some(value_at(0)), // the curve isn't started yet
+            Err(i) => i - 1,
+        };
+    if step_start >= last {
+        // the curve is finished
+        return Some(value_at(last));
+    }
+
+    let ts_start = timestamps[step_start];
+    let ts_end = timestamps[step_start + 1];
+    let duration = ts_end - ts_start;
+    let lerp = (seek_time - ts_start) / duration;
+
+    let start = value_at(step_start);
+    let end = value_at(step_start + 1);
+    Some(match interpolation {
+        Interpolation::Step => start,
+        Interpolation::Linear => start.interpolate_linear(end, lerp),
+        Interpolation::CubicSpline => {
+            // The out-tangent of the keyframe at `step_start`, and the in-tangent of the next one
+            let out_tangent = keyframes[step_start * 3 + 2];
+            let in_tangent = keyframes[(step_start + 1) * 3];
+            start.interpolate_cubic(out_tangent, in_tangent, end, lerp, duration)
+        }
+    })
+}
@@ -2,6 +2,9 @@
 
 #![warn(missing_docs)]
 
+mod interpolation;
+mod property;
+
 use std::ops::Deref;
 use std::time::Duration;
 
@@ -15,12 +18,18 @@ use bevy_reflect::{FromReflect, Reflect, TypeUuid};
 use bevy_time::Time;
 use bevy_transform::{prelude::Transform, TransformSystem};
 use bevy_utils::{tracing::warn, HashMap};
+use interpolation::sample_keyframes;
+
+pub use interpolation::Interpolation;
+pub use property::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        AnimationClip, AnimationPlayer, AnimationPlugin, EntityPath, Keyframes, VariableCurve,
+        AnimationClip, AnimationPlayer, AnimationPlugin, AnimationTargetPath, CurveValue,
+        EntityPath, Interpolation, Keyframes, PlayingAnimation, PropertyCurve, PropertyKeyframes,
+        VariableCurve,
     };
 }
 
@@ -35,15 +44,59 @@ pub enum Keyframes {
     Scale(Vec<Vec3>),
 }
 
+/// A value of an attribute of a [`Transform`], sampled from a [`VariableCurve`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveValue {
+    /// A rotation.
+    Rotation(Quat),
+    /// A translation.
+    Translation(Vec3),
+    /// A scale.
+    Scale(Vec3),
+}
+
 /// Describes how an attribute of a [`Transform`] should be animated.
 ///
-/// `keyframe_timestamps` and `keyframes` should have the same length.
+/// `keyframe_timestamps` and `keyframes` should have the same length, except with
+/// [`Interpolation::CubicSpline`] where there are three keyframes per timestamp.
 #[derive(Reflect, FromReflect, Clone, Debug)]
 pub struct VariableCurve {
     /// Timestamp for each of the keyframes.
     pub keyframe_timestamps: Vec<f32>,
     /// List of the keyframes.
     pub keyframes: Keyframes,
+    /// How the keyframes are interpolated.
+    pub interpolation: Interpolation,
+}
+
+impl VariableCurve {
+    /// Samples the curve at `seek_time`, in seconds.
+    ///
+    /// Before the first keyframe the value of the first keyframe is returned, and after the last
+    /// keyframe the value of the last one. Returns `None` if the curve has no keyframes.
+    pub fn sample(&self, seek_time: f32) -> Option<CurveValue> {
+        let timestamps = &self.keyframe_timestamps;
+        Some(match &self.keyframes {
+            Keyframes::Rotation(keyframes) => CurveValue::Rotation(sample_keyframes(
+                timestamps,
+                keyframes,
+                self.interpolation,
+                seek_time,
+            )?),
+            Keyframes::Translation(keyframes) => CurveValue::Translation(sample_keyframes(
+                timestamps,
+                keyframes,
+                self.interpolation,
+                seek_time,
+            )?),
+            Keyframes::Scale(keyframes) => CurveValue::Scale(sample_keyframes(
+                timestamps,
+                keyframes,
+                self.interpolation,
+                seek_time,
+            )?),
+        })
+    }
 }
 
 /// Path to an entity, with [`Name`]s. Each entity in a path must have a name.
@@ -58,6 +111,7 @@ pub struct EntityPath {
 #[uuid = "d81b7179-0448-4eb0-89fe-c067222725bf"]
 pub struct AnimationClip {
     curves: Vec<Vec<VariableCurve>>,
+    property_curves: Vec<Vec<PropertyCurve>>,
     paths: HashMap<EntityPath, usize>,
     duration: f32,
 }
@@ -91,26 +145,62 @@ impl AnimationClip {
         self.duration
     }
 
+    /// [`PropertyCurve`]s for each bone. Indexed by the bone ID.
+    pub fn property_curves(&self) -> &Vec<Vec<PropertyCurve>> {
+        &self.property_curves
+    }
+
+    /// Gets the property curves for a bone.
+    ///
+    /// Returns `None` if the bone is invalid.
+    #[inline]
+    pub fn get_property_curves(&self, bone_id: usize) -> Option<&'_ Vec<PropertyCurve>> {
+        self.property_curves.get(bone_id)
+    }
+
     /// Add a [`VariableCurve`] to an [`EntityPath`].
     pub fn add_curve_to_path(&mut self, path: EntityPath, curve: VariableCurve) {
-        // Update the duration of the animation by this curve duration if it's longer
+        self.extend_duration(&curve.keyframe_timestamps);
+        let bone_id = self.bone_id(path);
+        self.curves[bone_id].push(curve);
+    }
+
+    /// Add a [`PropertyCurve`] to an [`EntityPath`], to animate a field of a component of the
+    /// entity.
+    ///
+    /// The component has to be registered in the
+    /// [`AppTypeRegistry`](bevy_app::AppTypeRegistry), and reflect
+    /// [`Component`].
+    pub fn add_property_curve_to_path(&mut self, path: EntityPath, curve: PropertyCurve) {
+        self.extend_duration(&curve.keyframe_timestamps);
+        let bone_id = self.bone_id(path);
+        self.property_curves[bone_id].push(curve);
+    }
+
+    /// Update the duration of the animation by a curve duration if it's longer
+    fn extend_duration(&mut self, keyframe_timestamps: &[f32]) {
         self.duration = self
             .duration
-            .max(*curve.keyframe_timestamps.last().unwrap_or(&0.0));
-        if let Some(bone_id) = self.paths.get(&path) {
-            self.curves[*bone_id].push(curve);
-        } else {
-            let idx = self.curves.len();
-            self.curves.push(vec![curve]);
-            self.paths.insert(path, idx);
-        }
+            .max(*keyframe_timestamps.last().unwrap_or(&0.0));
+    }
+
+    /// Returns the ID of the bone at `path`, adding it if it is new.
+    fn bone_id(&mut self, path: EntityPath) -> usize {
+        *self.paths.entry(path).or_insert_with(|| {
+            self.curves.push(Vec::new());
+            self.property_curves.push(Vec::new());
+            self.curves.len() - 1
+        })
     }
 }
 
+/// An animation played by an [`AnimationPlayer`], either as its current animation or while
+/// fading out in a transition.
 #[derive(Reflect)]
-struct PlayingAnimation {
+pub struct PlayingAnimation {
     repeat: bool,
     speed: f32,
+    weight: f32,
     elapsed: f32,
     animation_clip: Handle<AnimationClip>,
     path_cache: Vec<Vec<Option<Entity>>>,
@@ -121,6 +211,7 @@ impl Default for PlayingAnimation {
         Self {
             repeat: false,
             speed: 1.0,
+            weight: 1.0,
             elapsed: 0.0,
             animation_clip: Default::default(),
             path_cache: Vec::new(),
@@ -128,6 +219,61 @@ impl Default for PlayingAnimation {
     }
 }
 
+impl PlayingAnimation {
+    /// The clip of the animation.
+    pub fn animation_clip(&self) -> &Handle<AnimationClip> {
+        &self.animation_clip
+    }
+
+    /// Weight of the animation when blended with the other animations of its player
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Set the weight of the animation when blended with the other animations of its player.
+    ///
+    /// The weights are normalized: an animation playing alone is fully applied, whatever its
+    /// weight, unless it is `0.0`.
+    pub fn set_weight(&mut self, weight: f32) -> &mut Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Speed of the animation playback
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Set the speed of the animation playback
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Time elapsed playing the animation
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Seek to a specific time in the animation
+    pub fn seek_to(&mut self, elapsed: f32) -> &mut Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    /// The time at which the curves of the clip are sampled, wrapped when repeating.
+    fn seek_time(&self, animation_clip: &AnimationClip) -> f32 {
+        let mut elapsed = self.elapsed;
+        if self.repeat {
+            elapsed %= animation_clip.duration;
+        }
+        if elapsed < 0.0 {
+            elapsed += animation_clip.duration;
+        }
+        elapsed
+    }
+}
+
 /// An animation that is being faded out as part of a transition
 struct AnimationTransition {
     /// The current weight. Starts at 1.0 and goes to 0.0 during the fade-out.
@@ -178,6 +324,10 @@ impl AnimationPlayer {
         handle: Handle<AnimationClip>,
         transition_duration: Duration,
     ) -> &mut Self {
+        if transition_duration.is_zero() {
+            return self.start(handle);
+        }
+
         let mut animation = PlayingAnimation {
             animation_clip: handle,
             ..Default::default()
@@ -267,6 +417,71 @@ impl AnimationPlayer {
         self.animation.elapsed = elapsed;
         self
     }
+
+    /// Returns the weight of the current animation, and of each animation fading out in a
+    /// transition.
+    fn transition_weights(&self) -> (f32, Vec<f32>) {
+        // Each transition blends the output of the animations started before it with the
+        // animation started after it, so the weight of an older animation is shared with the
+        // newer ones as its transition progresses.
+        let fade_weight =
+            |transition: &AnimationTransition| transition.current_weight.clamp(0.0, 1.0);
+        let main_fade = self.transitions.last().map_or(0.0, fade_weight);
+        let mut remaining_weight = main_fade;
+        let mut transition_weights = vec![0.0; self.transitions.len()];
+        for index in (0..self.transitions.len()).rev() {
+            let fade = index
+                .checked_sub(1)
+                .map_or(0.0, |previous| fade_weight(&self.transitions[previous]));
+            transition_weights[index] = remaining_weight * (1.0 - fade);
+            remaining_weight *= fade;
+        }
+        (1.0 - main_fade, transition_weights)
+    }
+
+    /// Returns the current animation and the ones fading out, with their transition weights.
+    fn weighted_animations(&self) -> impl Iterator<Item = (f32, &PlayingAnimation)> {
+        let (main_weight, transition_weights) = self.transition_weights();
+        std::iter::once((main_weight, &self.animation)).chain(
+            transition_weights.into_iter().zip(
+                self.transitions
+                    .iter()
+                    .map(|transition| &transition.animation),
+            ),
+        )
+    }
+
+    /// Gets the playing animation of a clip, either the current animation or one fading out in
+    /// a transition.
+    ///
+    /// If the clip is played more than once, the most recently started animation is returned.
+    pub fn animation(&self, handle: &Handle<AnimationClip>) -> Option<&PlayingAnimation> {
+        std::iter::once(&self.animation)
+            .chain(
+                self.transitions
+                    .iter()
+                    .rev()
+                    .map(|transition| &transition.animation),
+            )
+            .find(|animation| animation.animation_clip == *handle)
+    }
+
+    /// Gets the playing animation of a clip mutably, to change its weight, speed or elapsed time.
+    ///
+    /// See [`animation`](Self::animation).
+    pub fn animation_mut(
+        &mut self,
+        handle: &Handle<AnimationClip>,
+    ) -> Option<&mut PlayingAnimation> {
+        std::iter::once(&mut self.animation)
+            .chain(
+                self.transitions
+                    .iter_mut()
+                    .rev()
+                    .map(|transition| &mut transition.animation),
+            )
+            .find(|animation| animation.animation_clip == *handle)
+    }
 }
 
 fn find_bone(
@@ -365,7 +580,7 @@ pub fn animation_player(
 #[allow(clippy::too_many_arguments)]
 fn run_animation_player(
     root: Entity,
-    mut player: Mut<AnimationPlayer>,
+    player: Mut<AnimationPlayer>,
     time: &Time,
     animations: &Assets<AnimationClip>,
     names: &Query<&Name>,
@@ -381,9 +596,15 @@ fn run_animation_player(
         return;
     }
 
+    let player = player.into_inner();
+    let (main_weight, transition_weights) = player.transition_weights();
+
+    // The total weights the properties of each target were animated with this frame
+    let mut blend_weights = HashMap::default();
+
     // Apply the main animation
     apply_animation(
-        1.0,
+        main_weight,
         &mut player.animation,
         paused,
         root,
@@ -394,17 +615,15 @@ fn run_animation_player(
         maybe_parent,
         parents,
         children,
+        &mut blend_weights,
     );
 
     // Apply any potential fade-out transitions from previous animations
-    for AnimationTransition {
-        current_weight,
-        animation,
-        ..
-    } in &mut player.transitions
+    for (AnimationTransition { animation, .. }, weight) in
+        player.transitions.iter_mut().zip(transition_weights)
     {
         apply_animation(
-            *current_weight,
+            weight,
             animation,
             paused,
             root,
@@ -415,10 +634,36 @@ fn run_animation_player(
             maybe_parent,
             parents,
             children,
+            &mut blend_weights,
         );
     }
 }
 
+/// Curves with more than one keyframe are only applied while they are playing, the ones with a
+/// single keyframe are used to set a value.
+fn curve_is_playing(keyframe_timestamps: &[f32], seek_time: f32) -> bool {
+    match keyframe_timestamps {
+        [first, .., last] => (*first..*last).contains(&seek_time),
+        _ => true,
+    }
+}
+
+/// The sum of the weights each property of a [`Transform`] was animated with, to normalize
+/// the blending of animations.
+#[derive(Default)]
+struct BlendWeights {
+    translation: f32,
+    rotation: f32,
+    scale: f32,
+}
+
+/// Adds `weight` to the `total` weight of a property, and returns how much the property should be
+/// interpolated toward the value animated with `weight`.
+fn blend_factor(total: &mut f32, weight: f32) -> f32 {
+    *total += weight;
+    weight / *total
+}
+
 #[allow(clippy::too_many_arguments)]
 fn apply_animation(
     weight: f32,
@@ -432,18 +677,17 @@ fn apply_animation(
     maybe_parent: Option<&Parent>,
     parents: &Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
     children: &Query<&Children>,
+    blend_weights: &mut HashMap<Entity, BlendWeights>,
 ) {
     if let Some(animation_clip) = animations.get(&animation.animation_clip) {
         if !paused {
             animation.elapsed += time.delta_seconds() * animation.speed;
         }
-        let mut elapsed = animation.elapsed;
-        if animation.repeat {
-            elapsed %= animation_clip.duration;
-        }
-        if elapsed < 0.0 {
-            elapsed += animation_clip.duration;
+        let weight = weight * animation.weight;
+        if weight <= 0.0 {
+            return;
         }
+        let elapsed = animation.seek_time(animation_clip);
         if animation.path_cache.len() != animation_clip.paths.len() {
             animation.path_cache = vec![Vec::new(); animation_clip.paths.len()];
         }
@@ -469,64 +713,26 @@ fn apply_animation(
             // to run their animation. Any players in the children or descendants will log a warning
             // and do nothing.
             let Ok(mut transform) = (unsafe { transforms.get_unchecked(target) }) else { continue };
+            let blend_weights = blend_weights.entry(target).or_default();
             for curve in curves {
-                // Some curves have only one keyframe used to set a transform
-                if curve.keyframe_timestamps.len() == 1 {
-                    match &curve.keyframes {
-                        Keyframes::Rotation(keyframes) => {
-                            transform.rotation = transform.rotation.slerp(keyframes[0], weight);
-                        }
-                        Keyframes::Translation(keyframes) => {
-                            transform.translation =
-                                transform.translation.lerp(keyframes[0], weight);
-                        }
-                        Keyframes::Scale(keyframes) => {
-                            transform.scale = transform.scale.lerp(keyframes[0], weight);
-                        }
-                    }
+                if !curve_is_playing(&curve.keyframe_timestamps, elapsed) {
                     continue;
                 }
-
-                // Find the current keyframe
-                // PERF: finding the current keyframe can be optimised
-                let step_start = match curve
-                    .keyframe_timestamps
-                    .binary_search_by(|probe| probe.partial_cmp(&elapsed).unwrap())
-                {
-                    Ok(n) if n >= curve.keyframe_timestamps.len() - 1 => continue, // this curve is finished
-                    Ok(i) => i,
-                    Err(0) => continue, // this curve isn't started yet
-                    Err(n) if n > curve.keyframe_timestamps.len() - 1 => continue, // this curve is finished
-                    Err(i) => i - 1,
-                };
-                let ts_start = curve.keyframe_timestamps[step_start];
-                let ts_end = curve.keyframe_timestamps[step_start + 1];
-                let lerp = (elapsed - ts_start) / (ts_end - ts_start);
+                let Some(value) = curve.sample(elapsed) else { continue };
 
                 // Apply the keyframe
-                match &curve.keyframes {
-                    Keyframes::Rotation(keyframes) => {
-                        let rot_start = keyframes[step_start];
-                        let mut rot_end = keyframes[step_start + 1];
-                        // Choose the smallest angle for the rotation
-                        if rot_end.dot(rot_start) < 0.0 {
-                            rot_end = -rot_end;
-                        }
-                        // Rotations are using a spherical linear interpolation
-                        let rot = rot_start.normalize().slerp(rot_end.normalize(), lerp);
-                        transform.rotation = transform.rotation.slerp(rot, weight);
+                match value {
+                    CurveValue::Rotation(rotation) => {
+                        let factor = blend_factor(&mut blend_weights.rotation, weight);
+                        transform.rotation = transform.rotation.slerp(rotation, factor);
                     }
-                    Keyframes::Translation(keyframes) => {
-                        let translation_start = keyframes[step_start];
-                        let translation_end = keyframes[step_start + 1];
-                        let result = translation_start.lerp(translation_end, lerp);
-                        transform.translation = transform.translation.lerp(result, weight);
+                    CurveValue::Translation(translation) => {
+                        let factor = blend_factor(&mut blend_weights.translation, weight);
+                        transform.translation = transform.translation.lerp(translation, factor);
                     }
-                    Keyframes::Scale(keyframes) => {
-                        let scale_start = keyframes[step_start];
-                        let scale_end = keyframes[step_start + 1];
-                        let result = scale_start.lerp(scale_end, lerp);
-                        transform.scale = transform.scale.lerp(result, weight);
+                    CurveValue::Scale(scale) => {
+                        let factor = blend_factor(&mut blend_weights.scale, weight);
+                        transform.scale = transform.scale.lerp(scale, factor);
                     }
                 }
             }
@@ -554,6 +760,487 @@ impl Plugin for AnimationPlugin {
                 animation_player
                     .in_base_set(CoreSet::PostUpdate)
                     .before(TransformSystem::TransformPropagate),
+            )
+            .add_system(
+                animate_properties
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(animation_player)
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::AssetPlugin;
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::Vec2;
+    use bevy_pbr::PointLight;
+    use bevy_render::color::Color;
+    use std::f32::consts::FRAC_PI_2;
+
+    struct TestApp {
+        app: App,
+        player: Entity,
+        bone: Entity,
+    }
+
+    impl TestApp {
+        fn new() -> Self {
+            let mut app = App::new();
+            let mut time = Time::default();
+            time.update();
+            app.add_plugin(AssetPlugin::default())
+                .add_plugin(AnimationPlugin::default())
+                .insert_resource(time);
+
+            let bone = app
+                .world
+                .spawn((Name::new("bone"), Transform::default()))
+                .id();
+            let player = app
+                .world
+                .spawn((Name::new("root"), AnimationPlayer::default()))
+                .add_child(bone)
+                .id();
+            TestApp { app, player, bone }
+        }
+
+        /// Adds a clip holding the bone at `translation` and `rotation`.
+        fn add_clip(&mut self, translation: Vec3, rotation: Quat) -> Handle<AnimationClip> {
+            let path = EntityPath {
+                parts: vec![Name::new("root"), Name::new("bone")],
+            };
+            let mut clip = AnimationClip::default();
+            clip.add_curve_to_path(
+                path.clone(),
+                VariableCurve {
+                    keyframe_timestamps: vec![0.0],
+                    keyframes: Keyframes::Translation(vec![translation]),
+                    interpolation: Interpolation::Linear,
+                },
             );
+            clip.add_curve_to_path(
+                path,
+                VariableCurve {
+                    keyframe_timestamps: vec![0.0],
+                    keyframes: Keyframes::Rotation(vec![rotation]),
+                    interpolation: Interpolation::Linear,
+                },
+            );
+            self.app
+                .world
+                .resource_mut::<Assets<AnimationClip>>()
+                .add(clip)
+        }
+
+        fn player(&mut self) -> Mut<'_, AnimationPlayer> {
+            self.app
+                .world
+                .get_mut::<AnimationPlayer>(self.player)
+                .unwrap()
+        }
+
+        /// Runs a frame `millis` after the last one.
+        fn advance(&mut self, millis: u64) {
+            let mut time = self.app.world.resource_mut::<Time>();
+            let last_update = time.last_update().unwrap();
+            time.update_with_instant(last_update + Duration::from_millis(millis));
+            self.app.update();
+        }
+
+        fn bone_transform(&self) -> Transform {
+            *self.app.world.get::<Transform>(self.bone).unwrap()
+        }
+    }
+
+    #[test]
+    fn transition_blends_the_clips() {
+        let mut test = TestApp::new();
+        let idle = test.add_clip(Vec3::ZERO, Quat::IDENTITY);
+        let walk = test.add_clip(Vec3::new(10.0, 0.0, 0.0), Quat::from_rotation_y(FRAC_PI_2));
+        test.player().play(idle);
+        test.advance(100);
+        assert_eq!(test.bone_transform().translation, Vec3::ZERO);
+
+        test.player()
+            .play_with_transition(walk, Duration::from_secs(1));
+        test.advance(500);
+        let transform = test.bone_transform();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2 / 2.0), 1e-5));
+
+        // Once the transition is finished, only the new clip is applied.
+        test.advance(600);
+        let transform = test.bone_transform();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(10.0, 0.0, 0.0), 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2), 1e-5));
+    }
+
+    #[test]
+    fn transition_during_a_transition() {
+        let mut test = TestApp::new();
+        let idle = test.add_clip(Vec3::ZERO, Quat::IDENTITY);
+        let walk = test.add_clip(Vec3::new(10.0, 0.0, 0.0), Quat::IDENTITY);
+        let jump = test.add_clip(Vec3::new(0.0, 10.0, 0.0), Quat::IDENTITY);
+        test.player().play(idle);
+        test.advance(100);
+        test.player()
+            .play_with_transition(walk, Duration::from_secs(1));
+        test.advance(500);
+
+        // The blend of idle and walk fades out toward jump.
+        test.player()
+            .play_with_transition(jump, Duration::from_secs(1));
+        test.advance(250);
+        // idle fades out to 0.25, the blend of idle and walk to 0.75.
+        let jump_weight = 0.25;
+        let walk_weight = 0.75 * 0.75;
+        let expected = Vec3::new(10.0 * walk_weight, 10.0 * jump_weight, 0.0);
+        assert!(test
+            .bone_transform()
+            .translation
+            .abs_diff_eq(expected, 1e-5));
+
+        test.advance(750);
+        assert!(test
+            .bone_transform()
+            .translation
+            .abs_diff_eq(Vec3::new(0.0, 10.0, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn transition_to_the_playing_clip_is_ignored() {
+        let mut test = TestApp::new();
+        let idle = test.add_clip(Vec3::ZERO, Quat::IDENTITY);
+        test.player().play(idle.clone()).repeat();
+        test.advance(500);
+
+        test.player()
+            .play_with_transition(idle.clone(), Duration::from_secs(1));
+        let player = test.player();
+        assert!(player.transitions.is_empty());
+        assert_eq!(player.animation(&idle).unwrap().elapsed(), 0.5);
+    }
+
+    #[test]
+    fn weights_of_the_playing_animations() {
+        let mut test = TestApp::new();
+        let idle = test.add_clip(Vec3::ZERO, Quat::IDENTITY);
+        let walk = test.add_clip(Vec3::new(10.0, 0.0, 0.0), Quat::IDENTITY);
+        test.player().play(idle.clone());
+        test.advance(100);
+        test.player()
+            .play_with_transition(walk.clone(), Duration::from_secs(1));
+        test.player().animation_mut(&walk).unwrap().set_weight(3.0);
+        test.advance(500);
+        // walk has a weight of 3.0 * 0.5, idle of 0.5
+        assert!(test
+            .bone_transform()
+            .translation
+            .abs_diff_eq(Vec3::new(7.5, 0.0, 0.0), 1e-5));
+
+        // An animation with no weight isn't applied.
+        test.player().animation_mut(&idle).unwrap().set_weight(0.0);
+        test.advance(100);
+        assert!(test
+            .bone_transform()
+            .translation
+            .abs_diff_eq(Vec3::new(10.0, 0.0, 0.0), 1e-5));
+
+        let mut player = test.player();
+        let walk = player.animation_mut(&walk).unwrap();
+        walk.set_speed(2.0).seek_to(0.25);
+        assert_eq!((walk.speed(), walk.elapsed()), (2.0, 0.25));
+    }
+
+    fn translation_curve(
+        keyframe_timestamps: Vec<f32>,
+        keyframes: Vec<Vec3>,
+        interpolation: Interpolation,
+    ) -> VariableCurve {
+        VariableCurve {
+            keyframe_timestamps,
+            keyframes: Keyframes::Translation(keyframes),
+            interpolation,
+        }
+    }
+
+    fn sample_translation(curve: &VariableCurve, seek_time: f32) -> Vec3 {
+        match curve.sample(seek_time) {
+            Some(CurveValue::Translation(translation)) => translation,
+            value => panic!("expected a translation, got {value:?}"),
+        }
+    }
+
+    fn sample_rotation(curve: &VariableCurve, seek_time: f32) -> Quat {
+        match curve.sample(seek_time) {
+            Some(CurveValue::Rotation(rotation)) => rotation,
+            value => panic!("expected a rotation, got {value:?}"),
+        }
+    }
+
+    #[test]
+    fn sample_step() {
+        let curve = translation_curve(
+            vec![0.0, 1.0, 2.0],
+            vec![Vec3::X, Vec3::Y, Vec3::Z],
+            Interpolation::Step,
+        );
+        assert_eq!(sample_translation(&curve, -1.0), Vec3::X);
+        assert_eq!(sample_translation(&curve, 0.5), Vec3::X);
+        assert_eq!(sample_translation(&curve, 1.0), Vec3::Y);
+        assert_eq!(sample_translation(&curve, 1.5), Vec3::Y);
+        assert_eq!(sample_translation(&curve, 3.0), Vec3::Z);
+    }
+
+    #[test]
+    fn sample_linear() {
+        let curve = translation_curve(
+            vec![0.0, 2.0],
+            vec![Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0)],
+            Interpolation::Linear,
+        );
+        assert_eq!(sample_translation(&curve, 0.0), Vec3::ZERO);
+        assert_eq!(sample_translation(&curve, 0.5), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(sample_translation(&curve, 2.0), Vec3::new(4.0, 0.0, 0.0));
+
+        // The rotations take the shortest path.
+        let curve = VariableCurve {
+            keyframe_timestamps: vec![0.0, 1.0],
+            keyframes: Keyframes::Rotation(vec![Quat::IDENTITY, -Quat::from_rotation_y(FRAC_PI_2)]),
+            interpolation: Interpolation::Linear,
+        };
+        assert!(
+            sample_rotation(&curve, 0.5).abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2 / 2.0), 1e-6)
+        );
+    }
+
+    #[test]
+    fn sample_cubic_spline() {
+        // In-tangent, value and out-tangent of each keyframe
+        let curve = translation_curve(
+            vec![0.0, 2.0],
+            vec![
+                Vec3::ZERO,
+                Vec3::ZERO,
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(3.0, 0.0, 0.0),
+                Vec3::new(4.0, 0.0, 0.0),
+                Vec3::ZERO,
+            ],
+            Interpolation::CubicSpline,
+        );
+        assert_eq!(sample_translation(&curve, 0.0), Vec3::ZERO);
+        assert_eq!(sample_translation(&curve, 2.0), Vec3::new(4.0, 0.0, 0.0));
+        assert_eq!(sample_translation(&curve, 3.0), Vec3::new(4.0, 0.0, 0.0));
+
+        // With t = 0.5: p0 * (2t³ - 3t² + 1) + 2 * m0 * (t³ - 2t² + t) + p1 * (-2t³ + 3t²)
+        // + 2 * m1 * (t³ - t²)
+        let midpoint = 0.5 * 4.0 + 2.0 * 0.125 * 1.0 - 2.0 * 0.125 * 3.0;
+        assert!(sample_translation(&curve, 1.0).abs_diff_eq(Vec3::new(midpoint, 0.0, 0.0), 1e-6));
+        // With t = 0.25
+        let quarter = 0.15625 * 4.0 + 2.0 * 0.140625 * 1.0 - 2.0 * 0.046875 * 3.0;
+        assert!(sample_translation(&curve, 0.5).abs_diff_eq(Vec3::new(quarter, 0.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn sample_cubic_spline_rotation_is_normalized() {
+        let curve = VariableCurve {
+            keyframe_timestamps: vec![0.0, 1.0],
+            keyframes: Keyframes::Rotation(vec![
+                Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+                Quat::IDENTITY,
+                Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+                Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+                Quat::from_rotation_y(FRAC_PI_2),
+                Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+            ]),
+            interpolation: Interpolation::CubicSpline,
+        };
+        let rotation = sample_rotation(&curve, 0.5);
+        assert!(rotation.is_normalized());
+        // Without tangents, the spline is symmetric around the midpoint of the keyframes.
+        assert!(rotation.abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2 / 2.0), 1e-6));
+    }
+
+    #[test]
+    fn sample_empty_curve() {
+        let curve = translation_curve(Vec::new(), Vec::new(), Interpolation::Linear);
+        assert_eq!(curve.sample(0.0), None);
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Glow {
+        color: Color,
+        offset: Vec2,
+    }
+
+    impl TestApp {
+        /// Adds a clip animating the fields of the components of the bone.
+        fn add_property_clip(&mut self, curves: Vec<PropertyCurve>) -> Handle<AnimationClip> {
+            let mut clip = AnimationClip::default();
+            for curve in curves {
+                clip.add_property_curve_to_path(
+                    EntityPath {
+                        parts: vec![Name::new("root"), Name::new("bone")],
+                    },
+                    curve,
+                );
+            }
+            self.app
+                .world
+                .resource_mut::<Assets<AnimationClip>>()
+                .add(clip)
+        }
+    }
+
+    fn property_curve(target: AnimationTargetPath, keyframes: PropertyKeyframes) -> PropertyCurve {
+        PropertyCurve {
+            target,
+            keyframe_timestamps: vec![0.0, 1.0],
+            keyframes,
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    #[test]
+    fn animate_point_light_intensity() {
+        let mut test = TestApp::new();
+        test.app.register_type::<PointLight>();
+        test.app
+            .world
+            .entity_mut(test.bone)
+            .insert(PointLight::default());
+        let clip = test.add_property_clip(vec![property_curve(
+            AnimationTargetPath::new::<PointLight>("intensity"),
+            PropertyKeyframes::F32(vec![0.0, 1000.0]),
+        )]);
+        test.player().play(clip);
+
+        test.advance(250);
+        assert_eq!(
+            test.app
+                .world
+                .get::<PointLight>(test.bone)
+                .unwrap()
+                .intensity,
+            250.0
+        );
+        test.advance(500);
+        assert_eq!(
+            test.app
+                .world
+                .get::<PointLight>(test.bone)
+                .unwrap()
+                .intensity,
+            750.0
+        );
+    }
+
+    #[test]
+    fn animate_custom_component_fields() {
+        let mut test = TestApp::new();
+        test.app.register_type::<Glow>();
+        test.app.world.entity_mut(test.bone).insert(Glow::default());
+        let clip = test.add_property_clip(vec![
+            property_curve(
+                AnimationTargetPath {
+                    component: "Glow".to_string(),
+                    field: "color".to_string(),
+                },
+                PropertyKeyframes::Color(vec![
+                    Color::rgba_linear(0.0, 0.0, 1.0, 1.0),
+                    Color::rgba_linear(1.0, 0.0, 0.0, 1.0),
+                ]),
+            ),
+            property_curve(
+                AnimationTargetPath::new::<Glow>("offset.y"),
+                PropertyKeyframes::F32(vec![2.0, 4.0]),
+            ),
+        ]);
+        test.player().play(clip);
+
+        test.advance(500);
+        let glow = test.app.world.get::<Glow>(test.bone).unwrap();
+        assert_eq!(glow.color, Color::rgba_linear(0.5, 0.0, 0.5, 1.0));
+        assert_eq!(glow.offset, Vec2::new(0.0, 3.0));
+    }
+
+    #[test]
+    fn properties_are_blended_in_transitions() {
+        let mut test = TestApp::new();
+        test.app.register_type::<PointLight>();
+        test.app
+            .world
+            .entity_mut(test.bone)
+            .insert(PointLight::default());
+        let intensity = AnimationTargetPath::new::<PointLight>("intensity");
+        let dim = test.add_property_clip(vec![property_curve(
+            intensity.clone(),
+            PropertyKeyframes::F32(vec![0.0, 0.0]),
+        )]);
+        let bright = test.add_property_clip(vec![property_curve(
+            intensity,
+            PropertyKeyframes::F32(vec![100.0, 100.0]),
+        )]);
+        test.player().play(dim);
+        test.advance(100);
+        test.player()
+            .play_with_transition(bright, Duration::from_secs(1));
+        test.advance(250);
+        assert_eq!(
+            test.app
+                .world
+                .get::<PointLight>(test.bone)
+                .unwrap()
+                .intensity,
+            25.0
+        );
+    }
+
+    #[test]
+    fn invalid_property_bindings_are_skipped() {
+        let mut test = TestApp::new();
+        test.app.register_type::<Glow>();
+        test.app.world.entity_mut(test.bone).insert(Glow::default());
+        let clip = test.add_property_clip(vec![
+            // Unregistered component
+            property_curve(
+                AnimationTargetPath::new::<PointLight>("intensity"),
+                PropertyKeyframes::F32(vec![0.0, 1.0]),
+            ),
+            // Field of another type
+            property_curve(
+                AnimationTargetPath::new::<Glow>("color"),
+                PropertyKeyframes::F32(vec![0.0, 1.0]),
+            ),
+            // Missing field
+            property_curve(
+                AnimationTargetPath::new::<Glow>("size"),
+                PropertyKeyframes::F32(vec![0.0, 1.0]),
+            ),
+            property_curve(
+                AnimationTargetPath::new::<Glow>("offset"),
+                PropertyKeyframes::Vec2(vec![Vec2::ZERO, Vec2::ONE]),
+            ),
+        ]);
+        test.player().play(clip);
+
+        test.advance(500);
+        test.advance(250);
+        let glow = test.app.world.get::<Glow>(test.bone).unwrap();
+        assert_eq!(glow.color, Color::default());
+        assert_eq!(glow.offset, Vec2::splat(0.75));
     }
 }
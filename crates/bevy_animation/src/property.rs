@@ -0,0 +1,331 @@
+use crate::{interpolation::sample_keyframes, AnimationClip, AnimationPlayer, EntityPath};
+use crate::{interpolation::Interpolate, Interpolation};
+use bevy_app::AppTypeRegistry;
+use bevy_asset::Assets;
+use bevy_core::Name;
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_hierarchy::Children;
+use bevy_math::{Quat, Vec2, Vec3, Vec4};
+use bevy_reflect::{FromReflect, GetPath, Reflect, TypeRegistry};
+use bevy_render::color::Color;
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+use std::fmt;
+
+/// A field of a reflected component, animated by a [`PropertyCurve`].
+#[derive(Reflect, FromReflect, Clone, Debug, Hash, PartialEq, Eq, Default)]
+pub struct AnimationTargetPath {
+    /// The type name of the component, as registered in the [`AppTypeRegistry`], like
+    /// `bevy_pbr::light::PointLight`. A short type name like `PointLight` can be used when it
+    /// is not ambiguous.
+    pub component: String,
+    /// The path to the field in the component, as used by [`GetPath`], like `intensity` or
+    /// `color`.
+    pub field: String,
+}
+
+impl AnimationTargetPath {
+    /// Targets the field at `field` of the component `C`.
+    pub fn new<C: Component>(field: impl Into<String>) -> Self {
+        AnimationTargetPath {
+            component: std::any::type_name::<C>().to_string(),
+            field: field.into(),
+        }
+    }
+}
+
+impl fmt::Display for AnimationTargetPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.component, self.field)
+    }
+}
+
+/// List of keyframes for a field of a reflected component.
+#[derive(Reflect, FromReflect, Clone, Debug)]
+pub enum PropertyKeyframes {
+    /// Keyframes for a `f32` field.
+    F32(Vec<f32>),
+    /// Keyframes for a [`Vec2`] field.
+    Vec2(Vec<Vec2>),
+    /// Keyframes for a [`Vec3`] field.
+    Vec3(Vec<Vec3>),
+    /// Keyframes for a [`Vec4`] field.
+    Vec4(Vec<Vec4>),
+    /// Keyframes for a [`Color`] field, interpolated in linear RGBA.
+    Color(Vec<Color>),
+    /// Keyframes for a [`Quat`] field.
+    Quat(Vec<Quat>),
+}
+
+/// A value of a field of a reflected component, sampled from a [`PropertyCurve`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// A `f32`.
+    F32(f32),
+    /// A [`Vec2`].
+    Vec2(Vec2),
+    /// A [`Vec3`].
+    Vec3(Vec3),
+    /// A [`Vec4`].
+    Vec4(Vec4),
+    /// A [`Color`].
+    Color(Color),
+    /// A [`Quat`].
+    Quat(Quat),
+}
+
+impl PropertyValue {
+    /// Interpolates toward `other` by `factor`, if it is of the same type.
+    fn blend(self, other: Self, factor: f32) -> Self {
+        match (self, other) {
+            (PropertyValue::F32(a), PropertyValue::F32(b)) => {
+                PropertyValue::F32(a.interpolate_linear(b, factor))
+            }
+            (PropertyValue::Vec2(a), PropertyValue::Vec2(b)) => {
+                PropertyValue::Vec2(a.interpolate_linear(b, factor))
+            }
+            (PropertyValue::Vec3(a), PropertyValue::Vec3(b)) => {
+                PropertyValue::Vec3(a.interpolate_linear(b, factor))
+            }
+            (PropertyValue::Vec4(a), PropertyValue::Vec4(b)) => {
+                PropertyValue::Vec4(a.interpolate_linear(b, factor))
+            }
+            (PropertyValue::Color(a), PropertyValue::Color(b)) => {
+                PropertyValue::Color(a.interpolate_linear(b, factor))
+            }
+            (PropertyValue::Quat(a), PropertyValue::Quat(b)) => {
+                PropertyValue::Quat(a.interpolate_linear(b, factor))
+            }
+            _ => other,
+        }
+    }
+
+    /// Sets `field` to this value, returning `false` if it is of another type.
+    fn apply(self, field: &mut dyn Reflect) -> bool {
+        fn set<T: Reflect>(field: &mut dyn Reflect, value: T) -> bool {
+            field
+                .downcast_mut::<T>()
+                .map(|field| *field = value)
+                .is_some()
+        }
+        match self {
+            PropertyValue::F32(value) => set(field, value),
+            PropertyValue::Vec2(value) => set(field, value),
+            PropertyValue::Vec3(value) => set(field, value),
+            PropertyValue::Vec4(value) => set(field, value),
+            PropertyValue::Color(value) => set(field, value),
+            PropertyValue::Quat(value) => set(field, value),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            PropertyValue::F32(_) => "f32",
+            PropertyValue::Vec2(_) => "Vec2",
+            PropertyValue::Vec3(_) => "Vec3",
+            PropertyValue::Vec4(_) => "Vec4",
+            PropertyValue::Color(_) => "Color",
+            PropertyValue::Quat(_) => "Quat",
+        }
+    }
+}
+
+/// Describes how a field of a reflected component should be animated.
+///
+/// `keyframe_timestamps` and `keyframes` should have the same length, except with
+/// [`Interpolation::CubicSpline`] where there are three keyframes per timestamp.
+#[derive(Reflect, FromReflect, Clone, Debug)]
+pub struct PropertyCurve {
+    /// The field animated by the curve.
+    pub target: AnimationTargetPath,
+    /// Timestamp for each of the keyframes.
+    pub keyframe_timestamps: Vec<f32>,
+    /// List of the keyframes.
+    pub keyframes: PropertyKeyframes,
+    /// How the keyframes are interpolated.
+    pub interpolation: Interpolation,
+}
+
+impl PropertyCurve {
+    /// Samples the curve at `seek_time`, in seconds.
+    ///
+    /// Before the first keyframe the value of the first keyframe is returned, and after the last
+    /// keyframe the value of the last one. Returns `None` if the curve has no keyframes.
+    pub fn sample(&self, seek_time: f32) -> Option<PropertyValue> {
+        let timestamps = &self.keyframe_timestamps;
+        let interpolation = self.interpolation;
+        Some(match &self.keyframes {
+            PropertyKeyframes::F32(keyframes) => PropertyValue::F32(sample_keyframes(
+                timestamps,
+                keyframes,
+                interpolation,
+                seek_time,
+            )?),
+            PropertyKeyframes::Vec2(keyframes) => PropertyValue::Vec2(sample_keyframes(
+                timestamps,
+                keyframes,
+                interpolation,
+                seek_time,
+            )?),
+            PropertyKeyframes::Vec3(keyframes) => PropertyValue::Vec3(sample_keyframes(
+                timestamps,
+                keyframes,
+                interpolation,
+                seek_time,
+            )?),
+            PropertyKeyframes::Vec4(keyframes) => PropertyValue::Vec4(sample_keyframes(
+                timestamps,
+                keyframes,
+                interpolation,
+                seek_time,
+            )?),
+            PropertyKeyframes::Color(keyframes) => PropertyValue::Color(sample_keyframes(
+                timestamps,
+                keyframes,
+                interpolation,
+                seek_time,
+            )?),
+            PropertyKeyframes::Quat(keyframes) => PropertyValue::Quat(sample_keyframes(
+                timestamps,
+                keyframes,
+                interpolation,
+                seek_time,
+            )?),
+        })
+    }
+}
+
+/// Why the field of a [`PropertyCurve`] couldn't be animated.
+enum PropertyBindingError {
+    UnregisteredComponent,
+    NotReflectComponent,
+    MissingComponent,
+    InvalidPath(String),
+    InvalidType(&'static str),
+}
+
+impl fmt::Display for PropertyBindingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyBindingError::UnregisteredComponent => {
+                write!(f, "the component type is not registered")
+            }
+            PropertyBindingError::NotReflectComponent => {
+                write!(f, "the type doesn't reflect `Component`")
+            }
+            PropertyBindingError::MissingComponent => {
+                write!(f, "the entity doesn't have the component")
+            }
+            PropertyBindingError::InvalidPath(error) => write!(f, "invalid field path: {error}"),
+            PropertyBindingError::InvalidType(type_name) => {
+                write!(f, "the field is not a `{type_name}`")
+            }
+        }
+    }
+}
+
+fn apply_property(
+    world: &mut World,
+    type_registry: &TypeRegistry,
+    entity: Entity,
+    target: &AnimationTargetPath,
+    value: PropertyValue,
+) -> Result<(), PropertyBindingError> {
+    let registration = type_registry
+        .get_with_name(&target.component)
+        .or_else(|| type_registry.get_with_short_name(&target.component))
+        .ok_or(PropertyBindingError::UnregisteredComponent)?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or(PropertyBindingError::NotReflectComponent)?;
+    let Some(mut entity) = world.get_entity_mut(entity) else {
+        return Ok(());
+    };
+    let mut component = reflect_component
+        .reflect_mut(&mut entity)
+        .ok_or(PropertyBindingError::MissingComponent)?;
+    let field = component
+        .reflect_path_mut(&target.field)
+        .map_err(|error| PropertyBindingError::InvalidPath(error.to_string()))?;
+    if value.apply(field) {
+        Ok(())
+    } else {
+        Err(PropertyBindingError::InvalidType(value.type_name()))
+    }
+}
+
+/// Finds the entity at `path` from the `root` of an animation.
+fn find_entity(world: &World, root: Entity, path: &EntityPath) -> Option<Entity> {
+    // Ignore the first name, it is the root node which we already have
+    path.parts.iter().skip(1).try_fold(root, |entity, part| {
+        world
+            .get::<Children>(entity)?
+            .iter()
+            .copied()
+            .find(|child| world.get::<Name>(*child) == Some(part))
+    })
+}
+
+/// System that animates the fields of reflected components with the [`PropertyCurve`]s of the
+/// animations played by every [`AnimationPlayer`], after they are advanced by
+/// [`animation_player`](crate::animation_player).
+///
+/// A field that can't be animated logs a warning once for each entity.
+pub fn animate_properties(
+    world: &mut World,
+    mut failed_bindings: Local<HashSet<(Entity, AnimationTargetPath)>>,
+) {
+    let mut players = world.query::<(Entity, &AnimationPlayer)>();
+    let Some(animations) = world.get_resource::<Assets<AnimationClip>>() else {
+        return;
+    };
+
+    // The blended value of each field, with the total weight it was animated with
+    let mut values: HashMap<(Entity, AnimationTargetPath), (f32, PropertyValue)> =
+        HashMap::default();
+    for (root, player) in players.iter(world) {
+        for (weight, animation) in player.weighted_animations() {
+            let weight = weight * animation.weight;
+            if weight <= 0.0 {
+                continue;
+            }
+            let Some(animation_clip) = animations.get(&animation.animation_clip) else {
+                continue;
+            };
+            let elapsed = animation.seek_time(animation_clip);
+            for (path, bone_id) in &animation_clip.paths {
+                let curves = &animation_clip.property_curves[*bone_id];
+                if curves.is_empty() {
+                    continue;
+                }
+                let Some(target) = find_entity(world, root, path) else {
+                    continue;
+                };
+                for curve in curves {
+                    if !crate::curve_is_playing(&curve.keyframe_timestamps, elapsed) {
+                        continue;
+                    }
+                    let Some(value) = curve.sample(elapsed) else {
+                        continue;
+                    };
+                    values
+                        .entry((target, curve.target.clone()))
+                        .and_modify(|(total, blended)| {
+                            *total += weight;
+                            *blended = blended.blend(value, weight / *total);
+                        })
+                        .or_insert((weight, value));
+                }
+            }
+        }
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    for ((entity, target), (_, value)) in values {
+        if let Err(error) = apply_property(world, &type_registry, entity, &target, value) {
+            if failed_bindings.insert((entity, target.clone())) {
+                warn!("Cannot animate {target} on {entity:?}: {error}");
+            }
+        }
+    }
+}
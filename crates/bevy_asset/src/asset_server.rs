@@ -1,17 +1,20 @@
 use crate::{
     path::{AssetPath, AssetPathId, SourcePathId},
     Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
-    AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    AssetLoader, AssetResult, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext,
+    LoadState, RefChange, RefChangeChannel, SourceInfo, SourceMeta,
 };
 use anyhow::Result;
-use bevy_ecs::system::{Res, ResMut, Resource};
+use bevy_ecs::{
+    event::EventWriter,
+    system::{Res, ResMut, Resource},
+};
 use bevy_log::warn;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
+use bevy_utils::{Entry, HashMap, HashSet, Uuid};
 use crossbeam_channel::TryRecvError;
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use std::{future::Future, marker::PhantomData, path::Path, sync::Arc};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an `AssetServer`.
@@ -41,6 +44,10 @@ pub enum AssetServerError {
     /// Encountered an error while reading an asset from disk.
     #[error("encountered an error while reading an asset: {0}")]
     AssetIoError(#[from] AssetIoError),
+
+    /// The future of an asset added with [`AssetServer::add_async`] returned an error.
+    #[error("encountered an error while adding an asset asynchronously: {0}")]
+    AsyncAssetError(anyhow::Error),
 }
 
 fn format_missing_asset_ext(exts: &[String]) -> String {
@@ -55,6 +62,72 @@ fn format_missing_asset_ext(exts: &[String]) -> String {
     }
 }
 
+/// An event sent when an asset of type `T` fails to load.
+///
+/// It is only sent for the assets loaded as a `T` with [`AssetServer::load`],
+/// the [`UntypedAssetLoadFailedEvent`] is sent for every asset that fails to load.
+pub struct AssetLoadFailedEvent<T: Asset> {
+    /// The id of the asset that failed to load.
+    pub id: HandleId,
+    /// The path of the asset that failed to load.
+    pub path: AssetPath<'static>,
+    /// The reason the asset failed to load.
+    pub error: Arc<AssetServerError>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Asset> Clone for AssetLoadFailedEvent<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            path: self.path.clone(),
+            error: self.error.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Asset> std::fmt::Debug for AssetLoadFailedEvent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AssetLoadFailedEvent")
+            .field("id", &self.id)
+            .field("path", &self.path)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<T: Asset> AssetLoadFailedEvent<T> {
+    /// Returns a weak handle to the asset that failed to load.
+    pub fn handle(&self) -> Handle<T> {
+        Handle::weak(self.id)
+    }
+}
+
+impl<T: Asset> From<UntypedAssetLoadFailedEvent> for AssetLoadFailedEvent<T> {
+    fn from(event: UntypedAssetLoadFailedEvent) -> Self {
+        Self {
+            id: event.id,
+            path: event.path,
+            error: event.error,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// An event sent when an asset fails to load, whatever its type.
+///
+/// The failure is also stored in the [`LoadState::Failed`] of the asset, until it's loaded again.
+#[derive(Clone, Debug)]
+pub struct UntypedAssetLoadFailedEvent {
+    /// The id of the asset that failed to load.
+    pub id: HandleId,
+    /// The path of the asset that failed to load.
+    pub path: AssetPath<'static>,
+    /// The reason the asset failed to load.
+    pub error: Arc<AssetServerError>,
+}
+
 #[derive(Default)]
 pub(crate) struct AssetRefCounter {
     pub(crate) channel: Arc<RefChangeChannel>,
@@ -73,6 +146,18 @@ pub struct AssetServerInternal {
     loaders: RwLock<Vec<Arc<dyn AssetLoader>>>,
     extension_to_loader_index: RwLock<HashMap<String, usize>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    /// The types of asset each path was loaded as, see [`AssetLoadFailedEvent`].
+    requested_types: RwLock<HashMap<AssetPathId, HashSet<Uuid>>>,
+    /// The load failures waiting to be sent as [`AssetLoadFailedEvent`]s, by asset type.
+    typed_load_failures: Mutex<HashMap<Uuid, Vec<UntypedAssetLoadFailedEvent>>>,
+    /// The load failures waiting to be sent as [`UntypedAssetLoadFailedEvent`]s.
+    untyped_load_failures: Mutex<Vec<UntypedAssetLoadFailedEvent>>,
+    /// The load states of the assets added with [`AssetServer::add_async`], until they're freed.
+    async_load_states: RwLock<HashMap<HandleId, LoadState>>,
+    /// The tasks of the assets added with [`AssetServer::add_async`], dropped to cancel them once
+    /// the assets are freed.
+    #[cfg(not(target_arch = "wasm32"))]
+    async_load_tasks: Mutex<HashMap<HandleId, bevy_tasks::Task<()>>>,
 }
 
 /// Loads assets from the filesystem in the background.
@@ -131,6 +216,12 @@ impl AssetServer {
                 asset_sources: Default::default(),
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
+                requested_types: Default::default(),
+                typed_load_failures: Default::default(),
+                untyped_load_failures: Default::default(),
+                async_load_states: Default::default(),
+                #[cfg(not(target_arch = "wasm32"))]
+                async_load_tasks: Default::default(),
                 asset_lifecycles: Default::default(),
                 asset_io,
             }),
@@ -153,7 +244,11 @@ impl AssetServer {
             panic!("Error while registering new asset type: {:?} with UUID: {:?}. Another type with the same UUID is already registered. Can not register new asset type with the same UUID",
                 std::any::type_name::<T>(), T::TYPE_UUID);
         }
-        Assets::new(self.server.asset_ref_counter.channel.sender.clone())
+        Assets::new(
+            self.server.asset_ref_counter.channel.sender.clone(),
+            self.server.asset_ref_counter.ref_counts.clone(),
+            self.server.handle_to_path.clone(),
+        )
     }
 
     /// Adds the provided asset loader to the server.
@@ -246,30 +341,33 @@ impl AssetServer {
                 let asset_sources = self.server.asset_sources.read();
                 asset_sources
                     .get(&id.source_path_id())
-                    .map_or(LoadState::NotLoaded, |info| info.load_state)
+                    .map_or(LoadState::NotLoaded, |info| info.load_state.clone())
             }
-            HandleId::Id(_, _) => LoadState::NotLoaded,
+            id @ HandleId::Id(_, _) => self
+                .server
+                .async_load_states
+                .read()
+                .get(&id)
+                .cloned()
+                .unwrap_or(LoadState::NotLoaded),
         }
     }
 
     /// Gets the overall load state of a group of assets from the provided handles.
     ///
     /// This method will only return [`LoadState::Loaded`] if all assets in the
-    /// group were loaded successfully.
+    /// group were loaded successfully, and returns the first failure of the group otherwise.
     pub fn get_group_load_state(&self, handles: impl IntoIterator<Item = HandleId>) -> LoadState {
         let mut load_state = LoadState::Loaded;
         for handle_id in handles {
-            match handle_id {
-                HandleId::AssetPathId(id) => match self.get_load_state(id) {
-                    LoadState::Loaded => continue,
-                    LoadState::Loading => {
-                        load_state = LoadState::Loading;
-                    }
-                    LoadState::Failed => return LoadState::Failed,
-                    LoadState::NotLoaded => return LoadState::NotLoaded,
-                    LoadState::Unloaded => return LoadState::Unloaded,
-                },
-                HandleId::Id(_, _) => return LoadState::NotLoaded,
+            match self.get_load_state(handle_id) {
+                LoadState::Loaded => continue,
+                LoadState::Loading => {
+                    load_state = LoadState::Loading;
+                }
+                load_state @ LoadState::Failed(_) => return load_state,
+                LoadState::NotLoaded => return LoadState::NotLoaded,
+                LoadState::Unloaded => return LoadState::Unloaded,
             }
         }
 
@@ -301,13 +399,69 @@ impl AssetServer {
     /// [asset loader]: AssetLoader
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<T> {
-        self.load_untyped(path).typed()
+        let asset_path = path.into();
+        // remember the type before loading, for the asset to be reported if it fails to load
+        self.server
+            .requested_types
+            .write()
+            .entry(asset_path.get_id())
+            .or_default()
+            .insert(T::TYPE_UUID);
+        self.load_untyped(asset_path).typed()
     }
 
+    /// Loads the asset at `asset_path`, recording its failure in its [`LoadState`] and
+    /// in the [`UntypedAssetLoadFailedEvent`] and [`AssetLoadFailedEvent`]s to send if it fails.
     async fn load_async(
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
+    ) -> Result<AssetPathId, Arc<AssetServerError>> {
+        match self.load_source(asset_path.clone(), force).await {
+            Ok(asset_path_id) => Ok(asset_path_id),
+            Err(error) => Err(self.fail_load(asset_path, error)),
+        }
+    }
+
+    fn fail_load(
+        &self,
+        asset_path: AssetPath<'_>,
+        error: AssetServerError,
+    ) -> Arc<AssetServerError> {
+        warn!("{}", error);
+        let error = Arc::new(error);
+        let asset_path_id = asset_path.get_id();
+        if let Some(source_info) = self
+            .server
+            .asset_sources
+            .write()
+            .get_mut(&asset_path_id.source_path_id())
+        {
+            source_info.load_state = LoadState::Failed(error.clone());
+        }
+
+        let event = UntypedAssetLoadFailedEvent {
+            id: asset_path_id.into(),
+            path: asset_path.to_owned(),
+            error: error.clone(),
+        };
+        if let Some(type_uuids) = self.server.requested_types.read().get(&asset_path_id) {
+            let mut typed_load_failures = self.server.typed_load_failures.lock();
+            for type_uuid in type_uuids {
+                typed_load_failures
+                    .entry(*type_uuid)
+                    .or_default()
+                    .push(event.clone());
+            }
+        }
+        self.server.untyped_load_failures.lock().push(event);
+        error
+    }
+
+    async fn load_source(
+        &self,
+        asset_path: AssetPath<'_>,
+        force: bool,
     ) -> Result<AssetPathId, AssetServerError> {
         let asset_path_id: AssetPathId = asset_path.get_id();
 
@@ -344,31 +498,11 @@ impl AssetServer {
             source_info.version
         };
 
-        let set_asset_failed = || {
-            let mut asset_sources = self.server.asset_sources.write();
-            let source_info = asset_sources
-                .get_mut(&asset_path_id.source_path_id())
-                .expect("`AssetSource` should exist at this point.");
-            source_info.load_state = LoadState::Failed;
-        };
-
         // get the according asset loader
-        let asset_loader = match self.get_path_asset_loader(asset_path.path()) {
-            Ok(loader) => loader,
-            Err(err) => {
-                set_asset_failed();
-                return Err(err);
-            }
-        };
+        let asset_loader = self.get_path_asset_loader(asset_path.path())?;
 
         // load the asset bytes
-        let bytes = match self.asset_io().load_path(asset_path.path()).await {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                set_asset_failed();
-                return Err(AssetServerError::AssetIoError(err));
-            }
-        };
+        let bytes = self.asset_io().load_path(asset_path.path()).await?;
 
         // load the asset source using the corresponding AssetLoader
         let mut load_context = LoadContext::new(
@@ -378,14 +512,10 @@ impl AssetServer {
             version,
         );
 
-        if let Err(err) = asset_loader
+        asset_loader
             .load(&bytes, &mut load_context)
             .await
-            .map_err(AssetServerError::AssetLoaderError)
-        {
-            set_asset_failed();
-            return Err(err);
-        }
+            .map_err(AssetServerError::AssetLoaderError)?;
 
         // if version has changed since we loaded and grabbed a lock, return. there is a newer
         // version being loaded
@@ -428,6 +558,81 @@ impl AssetServer {
         Ok(asset_path_id)
     }
 
+    /// Adds the [`Asset`] returned by `future` once it completes, like a procedurally generated one,
+    /// and returns a handle to it right away.
+    ///
+    /// The future runs on the [`IoTaskPool`], and its asset is then inserted in the [`Assets`]
+    /// collection like the loaded ones. Until then, [`AssetServer::get_load_state`] returns
+    /// [`LoadState::Loading`] for the handle, and it returns [`LoadState::Failed`] if the future
+    /// returns an error. No [`AssetLoadFailedEvent`] is sent for these assets, since they have no path.
+    ///
+    /// The future is cancelled if all the handles to the asset are dropped before it completes,
+    /// except on wasm where it always runs to completion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the asset type `A` hasn't been added to the app.
+    #[must_use = "dropping the returned strong handle cancels the future"]
+    pub fn add_async<A, E>(
+        &self,
+        future: impl Future<Output = Result<A, E>> + Send + 'static,
+    ) -> Handle<A>
+    where
+        A: Asset,
+        E: Into<anyhow::Error>,
+    {
+        let sender = {
+            let asset_lifecycles = self.server.asset_lifecycles.read();
+            let Some(asset_lifecycle) = asset_lifecycles.get(&A::TYPE_UUID) else {
+                panic!(
+                    "Failed to find AssetLifecycle for the asset type {} (UUID {:?}). \
+                        Are you sure this asset type has been added to your app builder?",
+                    std::any::type_name::<A>(),
+                    A::TYPE_UUID,
+                );
+            };
+            asset_lifecycle
+                .downcast_ref::<AssetLifecycleChannel<A>>()
+                .unwrap()
+                .sender
+                .clone()
+        };
+
+        let handle_id = HandleId::random::<A>();
+        self.server
+            .async_load_states
+            .write()
+            .insert(handle_id, LoadState::Loading);
+        let server = self.clone();
+        let task = IoTaskPool::get().spawn(async move {
+            match future.await {
+                Ok(asset) => sender
+                    .send(AssetLifecycleEvent::Create(AssetResult {
+                        asset: Box::new(asset),
+                        id: handle_id,
+                        version: 0,
+                    }))
+                    .unwrap(),
+                Err(error) => {
+                    let error = AssetServerError::AsyncAssetError(error.into());
+                    warn!("{}", error);
+                    // the asset may have been freed in the meantime
+                    if let Some(load_state) =
+                        server.server.async_load_states.write().get_mut(&handle_id)
+                    {
+                        *load_state = LoadState::Failed(Arc::new(error));
+                    }
+                }
+            }
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        self.server.async_load_tasks.lock().insert(handle_id, task);
+        #[cfg(target_arch = "wasm32")]
+        task.detach();
+
+        self.get_handle(handle_id)
+    }
+
     /// Queues the [`Asset`] at the provided path for loading and returns an untyped handle.
     ///
     /// See [`load`](AssetServer::load).
@@ -440,9 +645,21 @@ impl AssetServer {
     /// Force an [`Asset`] to be reloaded.
     ///
     /// This is useful for custom hot-reloading or for supporting `watch_for_changes`
-    /// in custom [`AssetIo`] implementations.
+    /// in custom [`AssetIo`] implementations, or to retry loading an asset that failed to load.
+    /// The [`LoadState::Failed`] of such an asset is replaced by [`LoadState::Loading`] right away.
     pub fn reload_asset<'a, P: Into<AssetPath<'a>>>(&self, path: P) {
-        self.load_untracked(path.into(), true);
+        let asset_path = path.into();
+        if let Some(source_info) = self
+            .server
+            .asset_sources
+            .write()
+            .get_mut(&asset_path.get_id().source_path_id())
+        {
+            if matches!(source_info.load_state, LoadState::Failed(_)) {
+                source_info.load_state = LoadState::Loading;
+            }
+        }
+        self.load_untracked(asset_path, true);
     }
 
     pub(crate) fn load_untracked(&self, asset_path: AssetPath<'_>, force: bool) -> HandleId {
@@ -450,9 +667,8 @@ impl AssetServer {
         let owned_path = asset_path.to_owned();
         IoTaskPool::get()
             .spawn(async move {
-                if let Err(err) = server.load_async(owned_path, force).await {
-                    warn!("{}", err);
-                }
+                // failures are logged and reported by `load_async`
+                let _ = server.load_async(owned_path, force).await;
             })
             .detach();
 
@@ -525,6 +741,16 @@ impl AssetServer {
                             asset_lifecycle.free_asset(potential_free);
                         }
                     }
+
+                    if let HandleId::Id(_, _) = potential_free {
+                        // dropping the task of an asset added with `add_async` cancels it
+                        #[cfg(not(target_arch = "wasm32"))]
+                        self.server.async_load_tasks.lock().remove(&potential_free);
+                        self.server
+                            .async_load_states
+                            .write()
+                            .remove(&potential_free);
+                    }
                 }
             }
         }
@@ -594,18 +820,30 @@ impl AssetServer {
         loop {
             match channel.receiver.try_recv() {
                 Ok(AssetLifecycleEvent::Create(result)) => {
-                    // update SourceInfo if this asset was loaded from an AssetPath
-                    if let HandleId::AssetPathId(id) = result.id {
-                        let asset_sources = asset_sources_guard
-                            .get_or_insert_with(|| self.server.asset_sources.write());
-                        if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
-                            if source_info.version == result.version {
-                                source_info.committed_assets.insert(id.label_id());
-                                if source_info.is_loaded() {
-                                    source_info.load_state = LoadState::Loaded;
+                    match result.id {
+                        // update SourceInfo if this asset was loaded from an AssetPath
+                        HandleId::AssetPathId(id) => {
+                            let asset_sources = asset_sources_guard
+                                .get_or_insert_with(|| self.server.asset_sources.write());
+                            if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
+                                if source_info.version == result.version {
+                                    source_info.committed_assets.insert(id.label_id());
+                                    if source_info.is_loaded() {
+                                        source_info.load_state = LoadState::Loaded;
+                                    }
                                 }
                             }
                         }
+                        // update the load state if this asset was added with `add_async`
+                        HandleId::Id(_, _) => {
+                            if let Some(load_state) =
+                                self.server.async_load_states.write().get_mut(&result.id)
+                            {
+                                *load_state = LoadState::Loaded;
+                                #[cfg(not(target_arch = "wasm32"))]
+                                self.server.async_load_tasks.lock().remove(&result.id);
+                            }
+                        }
                     }
 
                     assets.set_untracked(result.id, *result.asset);
@@ -640,6 +878,30 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
     free_unused_assets_system_impl(&asset_server);
 }
 
+/// Sends an [`AssetLoadFailedEvent`] for each asset of type `T` that failed to load since the last run.
+pub fn asset_load_failed_event_system<T: Asset>(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetLoadFailedEvent<T>>,
+) {
+    let failures = asset_server
+        .server
+        .typed_load_failures
+        .lock()
+        .remove(&T::TYPE_UUID);
+    if let Some(failures) = failures {
+        events.send_batch(failures.into_iter().map(AssetLoadFailedEvent::from));
+    }
+}
+
+/// Sends an [`UntypedAssetLoadFailedEvent`] for each asset that failed to load since the last run.
+pub fn untyped_asset_load_failed_event_system(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<UntypedAssetLoadFailedEvent>,
+) {
+    let failures = std::mem::take(&mut *asset_server.server.untyped_load_failures.lock());
+    events.send_batch(failures);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -648,6 +910,7 @@ mod test {
     use bevy_ecs::prelude::*;
     use bevy_reflect::TypeUuid;
     use bevy_utils::BoxedFuture;
+    use std::sync::atomic::{AtomicBool, Ordering};
 
     #[derive(Debug, TypeUuid)]
     #[uuid = "a5189b72-0572-4290-a2e0-96f73a491c44"]
@@ -684,6 +947,31 @@ mod test {
         }
     }
 
+    #[derive(Default)]
+    struct FailOnceLoader {
+        failed: std::sync::atomic::AtomicBool,
+    }
+    impl AssetLoader for FailOnceLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a [u8],
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            let has_failed = self.failed.swap(true, std::sync::atomic::Ordering::SeqCst);
+            Box::pin(async move {
+                if !has_failed {
+                    anyhow::bail!("failed once");
+                }
+                ctx.set_default_asset(LoadedAsset::new(PngAsset));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["once"]
+        }
+    }
+
     struct FakeMultipleDotLoader;
     impl AssetLoader for FakeMultipleDotLoader {
         fn load<'a>(
@@ -799,14 +1087,14 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(match err {
+        assert!(match &*err {
             AssetServerError::MissingAssetLoader { extensions } => {
-                extensions == ["not-a-real-extension"]
+                *extensions == ["not-a-real-extension"]
             }
             _ => false,
         });
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed(err));
     }
 
     #[test]
@@ -819,9 +1107,9 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetIoError(_)));
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed(err));
     }
 
     #[test]
@@ -835,9 +1123,158 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetLoaderError(_)));
+        assert!(matches!(*err, AssetServerError::AssetLoaderError(_)));
+
+        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed(err));
+    }
+
+    // updates the app until the asset is done loading, in the background
+    fn wait_for_load(app: &mut App, handle: &Handle<PngAsset>) -> LoadState {
+        for _ in 0..1000 {
+            app.update();
+            let load_state = app.world.resource::<AssetServer>().get_load_state(handle);
+            if !matches!(load_state, LoadState::NotLoaded | LoadState::Loading) {
+                // the failure events are sent in the next update
+                app.update();
+                return load_state;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("the asset should be done loading");
+    }
+
+    #[test]
+    fn test_failed_load_events_and_retry() {
+        let dir = create_dir_and_file("fake.once");
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FailOnceLoader::default());
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server.clone());
+        app.add_event::<AssetLoadFailedEvent<PngAsset>>()
+            .add_event::<UntypedAssetLoadFailedEvent>();
+        app.add_systems((
+            update_asset_storage_system::<PngAsset>,
+            asset_load_failed_event_system::<PngAsset>,
+            untyped_asset_load_failed_event_system,
+        ));
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+        let handle: Handle<PngAsset> = asset_server.load("fake.once");
+        let LoadState::Failed(error) = wait_for_load(&mut app, &handle) else {
+            panic!("the first load should fail");
+        };
+        assert!(matches!(*error, AssetServerError::AssetLoaderError(_)));
+
+        let events = app
+            .world
+            .resource::<Events<AssetLoadFailedEvent<PngAsset>>>();
+        let typed_events: Vec<_> = events.get_reader().iter(events).cloned().collect();
+        assert_eq!(typed_events.len(), 1);
+        assert_eq!(typed_events[0].handle(), handle);
+        assert_eq!(typed_events[0].path, AssetPath::from("fake.once"));
+        assert!(Arc::ptr_eq(&typed_events[0].error, &error));
+        let events = app.world.resource::<Events<UntypedAssetLoadFailedEvent>>();
+        let untyped_events: Vec<_> = events.get_reader().iter(events).cloned().collect();
+        assert_eq!(untyped_events.len(), 1);
+        assert_eq!(untyped_events[0].id, handle.id());
+
+        // retrying clears the failure
+        asset_server.reload_asset("fake.once");
+        assert_eq!(asset_server.get_load_state(&handle), LoadState::Loading);
+        assert_eq!(wait_for_load(&mut app, &handle), LoadState::Loaded);
+        assert!(app.world.resource::<Assets<PngAsset>>().contains(&handle));
+    }
+
+    #[test]
+    fn test_add_async() {
+        let asset_server = setup("");
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server.clone());
+        app.add_system(update_asset_storage_system::<PngAsset>);
+
+        let ready = Arc::new(AtomicBool::new(false));
+        let handle = asset_server.add_async({
+            let ready = ready.clone();
+            async move {
+                while !ready.load(Ordering::SeqCst) {
+                    futures_lite::future::yield_now().await;
+                }
+                Ok::<_, anyhow::Error>(PngAsset)
+            }
+        });
+        app.update();
+        assert_eq!(asset_server.get_load_state(&handle), LoadState::Loading);
+        assert!(!app.world.resource::<Assets<PngAsset>>().contains(&handle));
+
+        ready.store(true, Ordering::SeqCst);
+        assert_eq!(wait_for_load(&mut app, &handle), LoadState::Loaded);
+        assert!(app.world.resource::<Assets<PngAsset>>().contains(&handle));
+
+        let handle =
+            asset_server.add_async(async { Err::<PngAsset, _>(anyhow::anyhow!("failed")) });
+        let LoadState::Failed(error) = wait_for_load(&mut app, &handle) else {
+            panic!("the asset should fail to be added");
+        };
+        assert!(matches!(*error, AssetServerError::AsyncAssetError(_)));
+        assert!(!app.world.resource::<Assets<PngAsset>>().contains(&handle));
+    }
+
+    #[test]
+    fn test_add_async_cancelled_with_its_handles() {
+        let asset_server = setup("");
+        let assets = asset_server.register_asset_type::<PngAsset>();
+
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server.clone());
+        app.add_systems((
+            free_unused_assets_system,
+            update_asset_storage_system::<PngAsset>,
+        ));
+
+        struct SetOnDrop(Arc<AtomicBool>);
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let handle = asset_server.add_async({
+            let set_on_drop = SetOnDrop(dropped.clone());
+            async move {
+                let _set_on_drop = set_on_drop;
+                futures_lite::future::pending::<()>().await;
+                Ok::<_, anyhow::Error>(PngAsset)
+            }
+        });
+        app.update();
+        let weak_handle = handle.clone_weak();
+        assert_eq!(
+            asset_server.get_load_state(&weak_handle),
+            LoadState::Loading
+        );
+
+        // the first update marks the asset as unused, the second frees it and cancels its future
+        drop(handle);
+        app.update();
+        app.update();
+        assert_eq!(
+            asset_server.get_load_state(&weak_handle),
+            LoadState::NotLoaded
+        );
+        for _ in 0..1000 {
+            if dropped.load(Ordering::SeqCst) {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("the future should be dropped");
     }
 
     #[test]
@@ -1,13 +1,15 @@
 use crate::{
-    update_asset_storage_system, Asset, AssetLoader, AssetServer, AssetSet, Handle, HandleId,
-    RefChange, ReflectAsset, ReflectHandle,
+    asset_load_failed_event_system, update_asset_storage_system, Asset, AssetLoadFailedEvent,
+    AssetLoader, AssetPath, AssetServer, AssetSet, Handle, HandleId, RefChange, ReflectAsset,
+    ReflectHandle,
 };
 use bevy_app::{App, AppTypeRegistry};
 use bevy_ecs::prelude::*;
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect};
 use bevy_utils::HashMap;
 use crossbeam_channel::Sender;
-use std::fmt::Debug;
+use parking_lot::RwLock;
+use std::{fmt::Debug, sync::Arc};
 
 /// Events that involve assets of type `T`.
 ///
@@ -68,14 +70,24 @@ pub struct Assets<T: Asset> {
     assets: HashMap<HandleId, T>,
     events: Events<AssetEvent<T>>,
     pub(crate) ref_change_sender: Sender<RefChange>,
+    /// The strong handle counts tracked by the [`AssetServer`]
+    ref_counts: Arc<RwLock<HashMap<HandleId, usize>>>,
+    /// The paths of the assets loaded by the [`AssetServer`]
+    handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
 }
 
 impl<T: Asset> Assets<T> {
-    pub(crate) fn new(ref_change_sender: Sender<RefChange>) -> Self {
+    pub(crate) fn new(
+        ref_change_sender: Sender<RefChange>,
+        ref_counts: Arc<RwLock<HashMap<HandleId, usize>>>,
+        handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    ) -> Self {
         Assets {
             assets: HashMap::default(),
             events: Events::default(),
             ref_change_sender,
+            ref_counts,
+            handle_to_path,
         }
     }
 
@@ -153,6 +165,15 @@ impl<T: Asset> Assets<T> {
         self.assets.get_mut(&id)
     }
 
+    /// Get mutable access to the asset for the given handle, without sending an
+    /// [`AssetEvent::Modified`].
+    ///
+    /// Systems reacting to the events of this collection, such as the extraction of render
+    /// assets, won't see the changes to the asset.
+    pub fn get_mut_untracked(&mut self, handle: &Handle<T>) -> Option<&mut T> {
+        self.assets.get_mut(&handle.into())
+    }
+
     /// Gets a _Strong_ handle pointing to the same asset as the given one.
     pub fn get_handle<H: Into<HandleId>>(&self, handle: H) -> Handle<T> {
         Handle::strong(handle.into(), self.ref_change_sender.clone())
@@ -203,6 +224,39 @@ impl<T: Asset> Assets<T> {
         self.assets.keys().cloned()
     }
 
+    /// Returns the number of strong handles pointing at the asset of `handle`, which is useful to
+    /// find the handles keeping an asset from being freed.
+    ///
+    /// Handles report their creation and drop through a channel, that is only read by
+    /// [`free_unused_assets_system`](crate::free_unused_assets_system) at the start of each frame.
+    /// The count is eventually consistent: it ignores the handles created or dropped since that system last ran.
+    pub fn strong_handle_count<H: Into<HandleId>>(&self, handle: H) -> usize {
+        self.ref_counts
+            .read()
+            .get(&handle.into())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Gets an iterator over the [`HandleId`]'s in the collection, with their
+    /// [strong handle count](Assets::strong_handle_count) and the path they were loaded from, if any.
+    pub fn iter_handles(
+        &self,
+    ) -> impl Iterator<Item = (HandleId, usize, Option<AssetPath<'static>>)> {
+        // collect to release the locks before iterating
+        let ref_counts = self.ref_counts.read();
+        let handle_to_path = self.handle_to_path.read();
+        let handles: Vec<_> = self
+            .assets
+            .keys()
+            .map(|id| {
+                let count = ref_counts.get(id).copied().unwrap_or(0);
+                (*id, count, handle_to_path.get(id).cloned())
+            })
+            .collect();
+        handles.into_iter()
+    }
+
     /// Removes an asset for the given handle.
     ///
     /// The asset is returned if it existed in the collection, otherwise `None`.
@@ -334,9 +388,11 @@ impl AddAsset for App {
             .add_systems((
                 Assets::<T>::asset_event_system.in_base_set(AssetSet::AssetEvents),
                 update_asset_storage_system::<T>.in_base_set(AssetSet::LoadAssets),
+                asset_load_failed_event_system::<T>.in_base_set(AssetSet::LoadAssets),
             ))
             .register_type::<Handle<T>>()
             .add_event::<AssetEvent<T>>()
+            .add_event::<AssetLoadFailedEvent<T>>()
     }
 
     fn register_asset_reflect<T>(&mut self) -> &mut Self
@@ -486,7 +542,7 @@ macro_rules! load_internal_binary_asset {
 mod tests {
     use bevy_app::App;
 
-    use crate::{AddAsset, Assets};
+    use crate::{AddAsset, AssetPath, AssetServer, Assets};
 
     #[test]
     fn asset_overwriting() {
@@ -504,4 +560,63 @@ mod tests {
         let assets_after = app.world.resource_mut::<Assets<MyAsset>>();
         assert!(assets_after.get(&handle).is_some());
     }
+
+    #[test]
+    fn strong_handle_counts_follow_the_handles() {
+        #[derive(bevy_reflect::TypeUuid)]
+        #[uuid = "e33cd8c5-e8b2-4b48-8b3a-4eb3588c5b1b"]
+        struct MyAsset;
+        let mut app = App::new();
+        app.add_plugin(bevy_core::TaskPoolPlugin::default())
+            .add_plugin(bevy_core::TypeRegistrationPlugin::default())
+            .add_plugin(crate::AssetPlugin::default());
+        app.add_asset::<MyAsset>();
+        let strong_handle_count = |app: &App, id| {
+            app.world
+                .resource::<Assets<MyAsset>>()
+                .strong_handle_count(id)
+        };
+
+        let handle = app.world.resource_mut::<Assets<MyAsset>>().add(MyAsset);
+        let id = handle.id();
+        // the new handle isn't counted until the handles are tracked
+        assert_eq!(strong_handle_count(&app, id), 0);
+        app.update();
+        assert_eq!(strong_handle_count(&app, id), 1);
+
+        let strong_clone = handle.clone();
+        let weak_clone = handle.clone_weak();
+        app.update();
+        assert_eq!(strong_handle_count(&app, id), 2);
+        drop(strong_clone);
+        drop(weak_clone);
+        app.update();
+        assert_eq!(strong_handle_count(&app, id), 1);
+
+        let path_handle = app
+            .world
+            .resource::<AssetServer>()
+            .load_untyped("file.my_asset");
+        app.world
+            .resource_mut::<Assets<MyAsset>>()
+            .set_untracked(&path_handle, MyAsset);
+        app.update();
+        let mut handles: Vec<_> = app
+            .world
+            .resource::<Assets<MyAsset>>()
+            .iter_handles()
+            .collect();
+        handles.sort_by_key(|(_, _, path)| path.is_some());
+        assert_eq!(
+            handles,
+            [
+                (id, 1, None),
+                (path_handle.id(), 1, Some(AssetPath::from("file.my_asset"))),
+            ]
+        );
+
+        drop(handle);
+        app.update();
+        assert_eq!(strong_handle_count(&app, id), 0);
+    }
 }
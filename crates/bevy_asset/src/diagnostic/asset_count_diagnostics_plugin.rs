@@ -1,6 +1,6 @@
 use crate::{Asset, Assets};
 use bevy_app::prelude::*;
-use bevy_diagnostic::{Diagnostic, DiagnosticId, Diagnostics, MAX_DIAGNOSTIC_NAME_WIDTH};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 use bevy_ecs::prelude::*;
 
 /// Adds an asset count diagnostic to an [`App`] for assets of type `T`.
@@ -24,35 +24,20 @@ impl<T: Asset> Plugin for AssetCountDiagnosticsPlugin<T> {
 }
 
 impl<T: Asset> AssetCountDiagnosticsPlugin<T> {
-    /// Gets unique id of this diagnostic.
+    /// Gets unique path of this diagnostic.
     ///
-    /// The diagnostic id is the type uuid of `T`.
-    pub fn diagnostic_id() -> DiagnosticId {
-        DiagnosticId(T::TYPE_UUID)
+    /// The diagnostic path is `asset_count/{type_name}`, where `type_name` is the type name of `T`.
+    pub fn diagnostic_path() -> DiagnosticPath {
+        DiagnosticPath::from_components(["asset_count", std::any::type_name::<T>()])
     }
 
     /// Registers the asset count diagnostic for the current application.
-    pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
-        let asset_type_name = std::any::type_name::<T>();
-        let max_length = MAX_DIAGNOSTIC_NAME_WIDTH - "asset_count ".len();
-        diagnostics.add(Diagnostic::new(
-            Self::diagnostic_id(),
-            format!(
-                "asset_count {}",
-                if asset_type_name.len() > max_length {
-                    asset_type_name
-                        .split_at(asset_type_name.len() - max_length + 1)
-                        .1
-                } else {
-                    asset_type_name
-                }
-            ),
-            20,
-        ));
+    pub fn setup_system(mut diagnostics: ResMut<DiagnosticsStore>) {
+        diagnostics.add(Diagnostic::new(Self::diagnostic_path(), 20));
     }
 
     /// Updates the asset count of `T` assets.
-    pub fn diagnostic_system(mut diagnostics: ResMut<Diagnostics>, assets: Res<Assets<T>>) {
-        diagnostics.add_measurement(Self::diagnostic_id(), || assets.len() as f64);
+    pub fn diagnostic_system(mut diagnostics: ResMut<DiagnosticsStore>, assets: Res<Assets<T>>) {
+        diagnostics.add_measurement(&Self::diagnostic_path(), || assets.len() as f64);
     }
 }
@@ -1,7 +1,7 @@
-use crate::{path::AssetPath, LabelId};
+use crate::{path::AssetPath, AssetServerError, LabelId};
 use bevy_utils::{HashMap, HashSet, Uuid};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 /// Metadata for an asset source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -53,7 +53,7 @@ impl SourceInfo {
 }
 
 /// The load state of an asset.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Debug)]
 pub enum LoadState {
     /// The asset has not been loaded.
     NotLoaded,
@@ -61,9 +61,23 @@ pub enum LoadState {
     Loading,
     /// The asset has been loaded and is living inside an [`Assets`](crate::Assets) collection.
     Loaded,
-    /// The asset failed to load.
-    Failed,
+    /// The asset failed to load, because of this error.
+    ///
+    /// The error is also sent in an [`UntypedAssetLoadFailedEvent`](crate::UntypedAssetLoadFailedEvent).
+    Failed(Arc<AssetServerError>),
     /// The asset was previously loaded, however all handles were dropped and the asset was removed
     /// from the [`Assets`](crate::Assets) collection.
     Unloaded,
 }
+
+/// Two [`LoadState::Failed`] are equal if they hold the same error, from the same failed load.
+impl PartialEq for LoadState {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Failed(error), Self::Failed(other_error)) => Arc::ptr_eq(error, other_error),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for LoadState {}
@@ -0,0 +1,771 @@
+use crate::{AssetIo, AssetIoError, FileType, Metadata};
+use anyhow::Result;
+use bevy_utils::BoxedFuture;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// I/O implementation loading the assets of a remote source over HTTP.
+///
+/// Paths starting with the source name followed by `://`, such as `remote://models/tree.glb`,
+/// are requested relative to the base URL of the source. All other paths are loaded by the
+/// fallback asset I/O, usually the platform default.
+///
+/// Implementation details:
+///
+/// - `load_path` downloads the asset with range requests of [`HttpAssetIo::chunk_size`] bytes,
+///   retrying each of them with an exponential backoff, so that an interrupted transfer resumes
+///   where it stopped. The responses are read straight into the asset's buffer. Servers that
+///   don't support range requests send the whole asset at once.
+/// - If the server has a `.meta` file next to the asset with a `hash: "<hash>"` entry, the
+///   [`HttpAssetIo::content_hash`] of the asset must match it.
+/// - `read_directory` returns [`AssetIoError::NotSupported`] and `get_metadata` reports every
+///   remote path as a file.
+/// - Watching remote assets for changes is not supported.
+/// - Native builds support `http` and `https` URLs, while web builds make [fetch()] requests and
+///   support any URL the browser does.
+///
+/// ```no_run
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::{AssetPlugin, AssetServer, HttpAssetIo};
+/// let fallback = AssetPlugin::default().create_platform_default_asset_io();
+/// App::new()
+///     // Inserted before the `AssetPlugin`, which doesn't replace it
+///     .insert_resource(AssetServer::new(HttpAssetIo::new(
+///         "https://cdn.example.com/assets",
+///         fallback,
+///     )))
+///     .add_plugin(AssetPlugin::default());
+/// ```
+///
+/// [fetch()]: https://developer.mozilla.org/en-US/docs/Web/API/fetch
+pub struct HttpAssetIo {
+    base_url: String,
+    source_name: String,
+    fallback: Box<dyn AssetIo>,
+    /// The number of bytes requested at once.
+    pub chunk_size: u64,
+    /// The number of times a failed request is retried before the load fails.
+    pub max_retries: u32,
+    /// The delay before the first retry of a request, doubled after each retry.
+    pub initial_backoff: Duration,
+}
+
+impl HttpAssetIo {
+    /// The default source name of the remote paths.
+    pub const DEFAULT_SOURCE_NAME: &'static str = "remote";
+
+    /// Creates a new `HttpAssetIo` requesting the remote paths relative to `base_url`, and
+    /// loading the others with `fallback`.
+    pub fn new(base_url: impl Into<String>, fallback: Box<dyn AssetIo>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        HttpAssetIo {
+            base_url,
+            source_name: Self::DEFAULT_SOURCE_NAME.to_string(),
+            fallback,
+            chunk_size: 4 * 1024 * 1024,
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+        }
+    }
+
+    /// Sets the source name of the remote paths, `remote` by default.
+    pub fn with_source_name(mut self, source_name: impl Into<String>) -> Self {
+        self.source_name = source_name.into();
+        self
+    }
+
+    /// Returns the URL the remote paths are relative to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the hash of `bytes` that the `.meta` files of the remote assets are validated
+    /// against, formatted as 16 lowercase hexadecimal digits.
+    ///
+    /// This is the 64 bits FNV-1a hash, which detects corrupted transfers but not tampering.
+    pub fn content_hash(bytes: &[u8]) -> String {
+        let hash = bytes.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+        });
+        format!("{hash:016x}")
+    }
+
+    /// Returns the path relative to the base URL if `path` is a remote path.
+    fn remote_path(&self, path: &Path) -> Option<String> {
+        let path = path.to_str()?.replace('\\', "/");
+        let remote_path = path
+            .strip_prefix(self.source_name.as_str())?
+            .strip_prefix("://")?;
+        Some(remote_path.to_string())
+    }
+
+    fn url(&self, remote_path: &str) -> String {
+        format!("{}/{}", self.base_url, percent_encode(remote_path))
+    }
+
+    fn backoff(&self) -> Backoff {
+        Backoff {
+            delay: self.initial_backoff,
+            retries_left: self.max_retries,
+        }
+    }
+
+    /// Sends a request, retrying it if it fails for a reason that may be temporary.
+    async fn get_with_retries(
+        &self,
+        url: &str,
+        range: Option<(u64, u64)>,
+        backoff: &mut Backoff,
+    ) -> Result<HttpResponse, AssetIoError> {
+        loop {
+            let response = get(url, range).await;
+            let retryable = match &response {
+                Ok(response) => matches!(response.status, 408 | 429 | 500..=599),
+                Err(error) => !matches!(
+                    error.kind(),
+                    io::ErrorKind::Unsupported | io::ErrorKind::InvalidInput
+                ),
+            };
+            if !retryable || !backoff.wait(url).await {
+                return match response {
+                    Ok(response) => Ok(response),
+                    Err(error) => Err(error.into()),
+                };
+            }
+        }
+    }
+
+    async fn load_remote_path(
+        &self,
+        path: &Path,
+        remote_path: &str,
+    ) -> Result<Vec<u8>, AssetIoError> {
+        let url = self.url(remote_path);
+        let mut bytes = Vec::new();
+        let mut backoff = self.backoff();
+        loop {
+            let start = bytes.len() as u64;
+            let end = start + self.chunk_size.max(1) - 1;
+            let mut response = self
+                .get_with_retries(&url, Some((start, end)), &mut backoff)
+                .await?;
+            match response.status {
+                // The server doesn't support range requests
+                200 => {
+                    bytes.clear();
+                    match response.read_body(&mut bytes).await {
+                        Ok(()) => break,
+                        // The whole asset is sent again
+                        Err(error) => {
+                            if !backoff.wait(&url).await {
+                                return Err(error.into());
+                            }
+                        }
+                    }
+                }
+                206 => {
+                    let (range_start, total_size) = response
+                        .content_range
+                        .as_deref()
+                        .and_then(parse_content_range)
+                        .ok_or_else(|| invalid_response(&url, "invalid Content-Range header"))?;
+                    if range_start != start {
+                        return Err(invalid_response(&url, "unexpected range"));
+                    }
+                    let read = response.read_body(&mut bytes).await.and_then(|()| {
+                        if bytes.len() as u64 == start && start < total_size {
+                            Err(io::ErrorKind::UnexpectedEof.into())
+                        } else {
+                            Ok(())
+                        }
+                    });
+                    match read {
+                        Ok(()) => {
+                            if bytes.len() as u64 >= total_size {
+                                break;
+                            }
+                            backoff = self.backoff();
+                        }
+                        // The next request resumes the transfer after the bytes already read
+                        Err(error) => {
+                            if !backoff.wait(&url).await {
+                                return Err(error.into());
+                            }
+                        }
+                    }
+                }
+                // Empty assets have no satisfiable range
+                416 if start == 0 => break,
+                404 | 410 => return Err(AssetIoError::NotFound(path.to_owned())),
+                status => {
+                    return Err(AssetIoError::HttpStatus {
+                        path: path.to_owned(),
+                        status,
+                    })
+                }
+            }
+        }
+
+        let meta_url = format!("{url}.meta");
+        let mut backoff = self.backoff();
+        let meta = loop {
+            let mut response = self.get_with_retries(&meta_url, None, &mut backoff).await?;
+            if response.status != 200 {
+                break None;
+            }
+            let mut meta = Vec::new();
+            match response.read_body(&mut meta).await {
+                Ok(()) => break Some(meta),
+                Err(error) => {
+                    if !backoff.wait(&meta_url).await {
+                        return Err(error.into());
+                    }
+                }
+            }
+        };
+        if let Some(meta) = meta {
+            if let Some(expected_hash) = parse_meta_hash(&String::from_utf8_lossy(&meta)) {
+                if !expected_hash.eq_ignore_ascii_case(&Self::content_hash(&bytes)) {
+                    return Err(AssetIoError::HashMismatch(path.to_owned()));
+                }
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// The delays between the retries of a failed request, doubled after each retry.
+struct Backoff {
+    delay: Duration,
+    retries_left: u32,
+}
+
+impl Backoff {
+    /// Waits before retrying the request to `url`, returning `false` if there are no retries left.
+    async fn wait(&mut self, url: &str) -> bool {
+        if self.retries_left == 0 {
+            return false;
+        }
+        bevy_log::debug!("retrying request to {url} in {:?}", self.delay);
+        sleep(self.delay).await;
+        self.delay *= 2;
+        self.retries_left -= 1;
+        true
+    }
+}
+
+impl AssetIo for HttpAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        match self.remote_path(path) {
+            Some(remote_path) => {
+                Box::pin(async move { self.load_remote_path(path, &remote_path).await })
+            }
+            None => self.fallback.load_path(path),
+        }
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        if self.remote_path(path).is_some() {
+            return Err(AssetIoError::NotSupported(path.to_owned()));
+        }
+        self.fallback.read_directory(path)
+    }
+
+    fn watch_path_for_changes(
+        &self,
+        to_watch: &Path,
+        to_reload: Option<PathBuf>,
+    ) -> Result<(), AssetIoError> {
+        if self.remote_path(to_watch).is_some() {
+            return Ok(());
+        }
+        self.fallback.watch_path_for_changes(to_watch, to_reload)
+    }
+
+    fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+        self.fallback.watch_for_changes()
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        if self.remote_path(path).is_some() {
+            return Ok(Metadata::new(FileType::File));
+        }
+        self.fallback.get_metadata(path)
+    }
+}
+
+/// The status and headers of a response, whose body is read afterwards with `read_body`.
+struct HttpResponse {
+    status: u16,
+    content_range: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    body: surf::Response,
+    #[cfg(target_arch = "wasm32")]
+    body: web_sys::Response,
+}
+
+fn invalid_response(url: &str, reason: &str) -> AssetIoError {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid response from {url}: {reason}"),
+    )
+    .into()
+}
+
+/// Escapes the characters of a path that can't appear in a URL as is.
+fn percent_encode(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char);
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Parses the start and total size of a `bytes <start>-<end>/<size>` header.
+fn parse_content_range(content_range: &str) -> Option<(u64, u64)> {
+    let (range, total_size) = content_range
+        .trim()
+        .strip_prefix("bytes ")?
+        .split_once('/')?;
+    let (start, _end) = range.split_once('-')?;
+    Some((start.trim().parse().ok()?, total_size.trim().parse().ok()?))
+}
+
+/// Returns the value of the `hash: "<hash>"` entry of a `.meta` file.
+fn parse_meta_hash(meta: &str) -> Option<&str> {
+    meta.lines().find_map(|line| {
+        let value = line
+            .trim()
+            .strip_prefix("hash")?
+            .trim_start()
+            .strip_prefix(':')?;
+        Some(value.trim().trim_end_matches(',').trim_matches('"'))
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    async_io::Timer::after(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: Duration) {
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis() as i32,
+            )
+            .unwrap();
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Sends a `GET` request over HTTP/1.1, with TLS for `https` URLs.
+#[cfg(not(target_arch = "wasm32"))]
+async fn get(url: &str, range: Option<(u64, u64)>) -> io::Result<HttpResponse> {
+    let url = surf::Url::parse(url).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid URL {url}: {error}"),
+        )
+    })?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("only http and https URLs are supported, got {url}"),
+        ));
+    }
+
+    let mut request = surf::get(url);
+    if let Some((start, end)) = range {
+        request = request.header("Range", format!("bytes={start}-{end}"));
+    }
+    let body = request
+        .send()
+        .await
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, error.to_string()))?;
+
+    Ok(HttpResponse {
+        status: body.status().into(),
+        content_range: body
+            .header("Content-Range")
+            .map(|values| values.last().as_str().to_string()),
+        body,
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl HttpResponse {
+    /// Appends the body of the response to `bytes` as it's received.
+    ///
+    /// On error, the bytes received before it are kept.
+    async fn read_body(&mut self, bytes: &mut Vec<u8>) -> io::Result<()> {
+        use futures_lite::AsyncReadExt;
+
+        let expected_len = self.body.len();
+        let read = self.body.read_to_end(bytes).await?;
+        // A connection closed early ends the body before its announced length
+        if expected_len.map_or(false, |expected_len| read < expected_len) {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(())
+    }
+}
+
+/// Sends a `GET` request with [fetch()].
+///
+/// [fetch()]: https://developer.mozilla.org/en-US/docs/Web/API/fetch
+#[cfg(target_arch = "wasm32")]
+async fn get(url: &str, range: Option<(u64, u64)>) -> io::Result<HttpResponse> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{Request, RequestInit, Response};
+
+    let request = Request::new_with_str_and_init(url, &RequestInit::new()).map_err(js_error)?;
+    if let Some((start, end)) = range {
+        request
+            .headers()
+            .set("Range", &format!("bytes={start}-{end}"))
+            .map_err(js_error)?;
+    }
+    let window = web_sys::window().unwrap();
+    let body: Response = JsFuture::from(window.fetch_with_request(&request))
+        .await
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(js_error)?;
+
+    Ok(HttpResponse {
+        status: body.status(),
+        content_range: body.headers().get("Content-Range").map_err(js_error)?,
+        body,
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+impl HttpResponse {
+    /// Appends the body of the response to `bytes`.
+    async fn read_body(&mut self, bytes: &mut Vec<u8>) -> io::Result<()> {
+        let promise = self.body.array_buffer().map_err(js_error)?;
+        let data = wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(js_error)?;
+        let data = js_sys::Uint8Array::new(&data);
+        let start = bytes.len();
+        bytes.resize(start + data.length() as usize, 0);
+        data.copy_to(&mut bytes[start..]);
+        Ok(())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn js_error(error: wasm_bindgen::JsValue) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, format!("fetch failed: {error:?}"))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use bevy_utils::HashMap;
+    use futures_lite::future::block_on;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{TcpListener, TcpStream},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        thread,
+    };
+
+    /// A local HTTP server with the given files.
+    #[derive(Default)]
+    struct TestServer {
+        files: HashMap<String, Vec<u8>>,
+        supports_ranges: bool,
+        /// The number of requests to answer with `503 Service Unavailable` before the next ones
+        /// succeed.
+        failures: AtomicUsize,
+        /// The number of responses to interrupt in the middle of their body.
+        interruptions: AtomicUsize,
+        requests: Mutex<Vec<String>>,
+    }
+
+    impl TestServer {
+        /// Starts serving on a new thread, returning the base URL of the server.
+        fn start(self) -> (Arc<Self>, String) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/assets", listener.local_addr().unwrap());
+            let server = Arc::new(self);
+            let thread_server = server.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    thread_server.respond(stream.unwrap());
+                }
+            });
+            (server, url)
+        }
+
+        fn respond(&self, mut stream: TcpStream) {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let path = request_line.split(' ').nth(1).unwrap().to_string();
+            let mut range = None;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some(value) = line.strip_prefix("Range: bytes=") {
+                    let (start, end) = value.trim().split_once('-').unwrap();
+                    range = Some((
+                        start.parse::<usize>().unwrap(),
+                        end.parse::<usize>().unwrap(),
+                    ));
+                }
+            }
+            self.requests.lock().unwrap().push(path.clone());
+
+            let (status, headers, body) = if self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |failures| {
+                    failures.checked_sub(1)
+                })
+                .is_ok()
+            {
+                ("503 Service Unavailable", String::new(), Vec::new())
+            } else {
+                match (self.files.get(&path), range) {
+                    (None, _) => ("404 Not Found", String::new(), Vec::new()),
+                    (Some(file), Some((start, end))) if self.supports_ranges => {
+                        let end = end.min(file.len() - 1);
+                        (
+                            "206 Partial Content",
+                            format!("Content-Range: bytes {start}-{end}/{}\r\n", file.len()),
+                            file[start..=end].to_vec(),
+                        )
+                    }
+                    (Some(file), _) => ("200 OK", String::new(), file.clone()),
+                }
+            };
+            let head = format!(
+                "HTTP/1.1 {status}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let interrupted = self
+                .interruptions
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |interruptions| {
+                    interruptions.checked_sub(1)
+                })
+                .is_ok();
+            let sent_len = if interrupted {
+                body.len() / 2
+            } else {
+                body.len()
+            };
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&body[..sent_len]).unwrap();
+        }
+    }
+
+    /// The asset I/O of the paths that aren't remote.
+    struct LocalAssetIo;
+
+    impl AssetIo for LocalAssetIo {
+        fn load_path<'a>(
+            &'a self,
+            path: &'a Path,
+        ) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+            Box::pin(async move { Ok(path.to_str().unwrap().as_bytes().to_vec()) })
+        }
+
+        fn read_directory(
+            &self,
+            _path: &Path,
+        ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+            Ok(Box::new(std::iter::empty()))
+        }
+
+        fn get_metadata(&self, _path: &Path) -> Result<Metadata, AssetIoError> {
+            Ok(Metadata::new(FileType::Directory))
+        }
+
+        fn watch_path_for_changes(
+            &self,
+            _to_watch: &Path,
+            _to_reload: Option<PathBuf>,
+        ) -> Result<(), AssetIoError> {
+            Ok(())
+        }
+
+        fn watch_for_changes(&self) -> Result<(), AssetIoError> {
+            Ok(())
+        }
+    }
+
+    fn asset_io(url: String) -> HttpAssetIo {
+        let mut asset_io = HttpAssetIo::new(url, Box::new(LocalAssetIo));
+        asset_io.chunk_size = 4;
+        asset_io.initial_backoff = Duration::from_millis(1);
+        asset_io
+    }
+
+    fn load(asset_io: &HttpAssetIo, path: &str) -> Result<Vec<u8>, AssetIoError> {
+        block_on(asset_io.load_path(Path::new(path)))
+    }
+
+    #[test]
+    fn loads_remote_assets_in_ranges() {
+        let tree = b"a tree with many leaves".to_vec();
+        let (server, url) = TestServer {
+            files: HashMap::from_iter([(
+                "/assets/models/big%20tree.glb".to_string(),
+                tree.clone(),
+            )]),
+            supports_ranges: true,
+            ..Default::default()
+        }
+        .start();
+        let asset_io = asset_io(url);
+
+        assert_eq!(
+            load(&asset_io, "remote://models/big tree.glb").unwrap(),
+            tree
+        );
+        let requests = server.requests.lock().unwrap().clone();
+        // 6 ranges of 4 bytes, and the missing meta file
+        assert_eq!(requests.len(), 7);
+        assert_eq!(requests[6], "/assets/models/big%20tree.glb.meta");
+
+        // other paths are loaded by the fallback
+        assert_eq!(
+            load(&asset_io, "models/tree.glb").unwrap(),
+            b"models/tree.glb"
+        );
+        assert_eq!(server.requests.lock().unwrap().len(), 7);
+    }
+
+    #[test]
+    fn loads_whole_assets_without_range_support() {
+        let (server, url) = TestServer {
+            files: HashMap::from_iter([("/assets/tree.glb".to_string(), b"a tree".to_vec())]),
+            ..Default::default()
+        }
+        .start();
+
+        let asset_io = asset_io(url).with_source_name("cdn");
+        assert_eq!(load(&asset_io, "cdn://tree.glb").unwrap(), b"a tree");
+        assert_eq!(server.requests.lock().unwrap().len(), 2);
+        assert_eq!(
+            load(&asset_io, "remote://tree.glb").unwrap(),
+            b"remote://tree.glb"
+        );
+    }
+
+    #[test]
+    fn retries_failed_requests() {
+        let (server, url) = TestServer {
+            files: HashMap::from_iter([("/assets/tree.glb".to_string(), b"a tree".to_vec())]),
+            supports_ranges: true,
+            failures: AtomicUsize::new(3),
+            ..Default::default()
+        }
+        .start();
+
+        let asset_io = asset_io(url);
+        assert_eq!(load(&asset_io, "remote://tree.glb").unwrap(), b"a tree");
+        // 3 failures, 2 ranges and the meta file
+        assert_eq!(server.requests.lock().unwrap().len(), 6);
+
+        server.failures.store(4, Ordering::SeqCst);
+        assert!(matches!(
+            load(&asset_io, "remote://tree.glb"),
+            Err(AssetIoError::HttpStatus { status: 503, .. })
+        ));
+    }
+
+    #[test]
+    fn resumes_interrupted_transfers() {
+        let (server, url) = TestServer {
+            files: HashMap::from_iter([("/assets/tree.glb".to_string(), b"a tree".to_vec())]),
+            supports_ranges: true,
+            interruptions: AtomicUsize::new(1),
+            ..Default::default()
+        }
+        .start();
+
+        let asset_io = asset_io(url);
+        assert_eq!(load(&asset_io, "remote://tree.glb").unwrap(), b"a tree");
+        // the first range is interrupted after 2 bytes, and the second one requests the 4 others
+        assert_eq!(server.requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn validates_content_hash_against_meta() {
+        let tree = b"a tree".to_vec();
+        let meta = format!("(\n    hash: \"{}\",\n)", HttpAssetIo::content_hash(&tree));
+        let (_server, url) = TestServer {
+            files: HashMap::from_iter([
+                ("/assets/tree.glb".to_string(), tree.clone()),
+                ("/assets/tree.glb.meta".to_string(), meta.into_bytes()),
+                ("/assets/rock.glb".to_string(), b"a corrupted rock".to_vec()),
+                (
+                    "/assets/rock.glb.meta".to_string(),
+                    format!("hash: \"{}\"", HttpAssetIo::content_hash(b"a rock")).into_bytes(),
+                ),
+            ]),
+            supports_ranges: true,
+            ..Default::default()
+        }
+        .start();
+
+        let asset_io = asset_io(url);
+        assert_eq!(load(&asset_io, "remote://tree.glb").unwrap(), tree);
+        assert!(matches!(
+            load(&asset_io, "remote://rock.glb"),
+            Err(AssetIoError::HashMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn missing_assets_and_directories() {
+        let (_server, url) = TestServer::default().start();
+        let asset_io = asset_io(url);
+
+        assert!(matches!(
+            load(&asset_io, "remote://tree.glb"),
+            Err(AssetIoError::NotFound(_))
+        ));
+        assert!(matches!(
+            asset_io.read_directory(Path::new("remote://models")),
+            Err(AssetIoError::NotSupported(_))
+        ));
+        assert!(asset_io.is_file(Path::new("remote://models")));
+        assert!(asset_io.is_dir(Path::new("models")));
+    }
+
+    #[test]
+    fn content_hash_is_stable() {
+        assert_eq!(HttpAssetIo::content_hash(b""), "cbf29ce484222325");
+        assert_eq!(HttpAssetIo::content_hash(b"a"), "af63dc4c8601ec8c");
+    }
+}
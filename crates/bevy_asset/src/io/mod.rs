@@ -2,6 +2,8 @@
 mod android_asset_io;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 mod file_asset_io;
+#[cfg(feature = "http_asset_io")]
+mod http_asset_io;
 #[cfg(target_arch = "wasm32")]
 mod wasm_asset_io;
 
@@ -11,6 +13,8 @@ mod metadata;
 pub use android_asset_io::*;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 pub use file_asset_io::*;
+#[cfg(feature = "http_asset_io")]
+pub use http_asset_io::*;
 #[cfg(target_arch = "wasm32")]
 pub use wasm_asset_io::*;
 
@@ -39,6 +43,23 @@ pub enum AssetIoError {
     /// Failed to watch path.
     #[error("failed to watch path: {0}")]
     PathWatchError(PathBuf),
+
+    /// The asset I/O doesn't support the operation at this path.
+    #[error("operation not supported at path: {0}")]
+    NotSupported(PathBuf),
+
+    /// A request for an asset failed with an unexpected HTTP status.
+    #[error("unexpected HTTP status {status} while loading asset: {path}")]
+    HttpStatus {
+        /// The path of the asset.
+        path: PathBuf,
+        /// The HTTP status of the response.
+        status: u16,
+    },
+
+    /// The content of an asset doesn't match the hash of its `.meta` file.
+    #[error("content hash doesn't match the meta file of asset: {0}")]
+    HashMismatch(PathBuf),
 }
 
 /// A storage provider for an [`AssetServer`].
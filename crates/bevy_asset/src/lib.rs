@@ -118,7 +118,11 @@ impl Plugin for AssetPlugin {
                 .after(CoreSet::PostUpdate)
                 .before(CoreSet::Last),
         )
-        .add_system(asset_server::free_unused_assets_system.in_base_set(CoreSet::PreUpdate));
+        .add_event::<UntypedAssetLoadFailedEvent>()
+        .add_systems((
+            asset_server::free_unused_assets_system.in_base_set(CoreSet::PreUpdate),
+            asset_server::untyped_asset_load_failed_event_system.in_base_set(AssetSet::LoadAssets),
+        ));
 
         #[cfg(all(
             feature = "filesystem_watcher",
@@ -1,10 +1,10 @@
 use crate::{AudioSink, AudioSource, Decodable, SpatialAudioSink};
 use bevy_asset::{Asset, Handle, HandleId};
-use bevy_ecs::system::Resource;
+use bevy_ecs::{component::Component, entity::Entity, system::Resource};
 use bevy_math::Vec3;
-use bevy_transform::prelude::Transform;
+use bevy_transform::prelude::{GlobalTransform, Transform};
 use parking_lot::RwLock;
-use std::{collections::VecDeque, fmt};
+use std::{collections::VecDeque, fmt, time::Duration};
 
 /// Use this [`Resource`] to play audio.
 ///
@@ -86,6 +86,7 @@ where
             sink_handle: id,
             source_handle: audio_source,
             spatial: None,
+            entity: None,
         };
         self.queue.write().push_back(config);
         Handle::<AudioSink>::weak(id)
@@ -119,6 +120,7 @@ where
             sink_handle: id,
             source_handle: audio_source,
             spatial: None,
+            entity: None,
         };
         self.queue.write().push_back(config);
         Handle::<AudioSink>::weak(id)
@@ -193,6 +195,7 @@ where
                 right_ear: (listener.translation + listener.right() * gap / 2.0).to_array(),
                 emitter: emitter.to_array(),
             }),
+            entity: None,
         };
         self.queue.write().push_back(config);
         Handle::<SpatialAudioSink>::weak(id)
@@ -245,10 +248,62 @@ where
                 right_ear: (listener.translation + listener.right() * gap / 2.0).to_array(),
                 emitter: emitter.to_array(),
             }),
+            entity: None,
         };
         self.queue.write().push_back(config);
         Handle::<SpatialAudioSink>::weak(id)
     }
+
+    /// Play audio from a [`Handle`] to the audio source on `entity`, with [`PlaybackSettings`].
+    ///
+    /// Once the sound starts playing, a strong handle to its sink is inserted on `entity`: a
+    /// `Handle<SpatialAudioSink>` if the settings are [`spatial`](PlaybackSettings::spatial),
+    /// or a `Handle<AudioSink>` otherwise. Removing the handle or despawning the entity detaches
+    /// the sink, and the sound continues playing.
+    ///
+    /// A spatial sound is emitted from the [`GlobalTransform`] of `entity`, and follows it as it
+    /// moves. It is heard by the entity with a [`SpatialListener`].
+    ///
+    /// ```
+    /// # use bevy_ecs::system::{Commands, Res};
+    /// # use bevy_asset::AssetServer;
+    /// # use bevy_audio::{Audio, PlaybackSettings};
+    /// # use bevy_transform::prelude::{GlobalTransform, Transform};
+    /// fn play_audio_system(
+    ///     mut commands: Commands,
+    ///     asset_server: Res<AssetServer>,
+    ///     audio: Res<Audio>,
+    /// ) {
+    ///     let emitter = commands
+    ///         .spawn((Transform::from_xyz(-2.0, 0.0, 1.0), GlobalTransform::default()))
+    ///         .id();
+    ///     audio.play_on(
+    ///         emitter,
+    ///         asset_server.load("my_sound.ogg"),
+    ///         PlaybackSettings::LOOP.with_spatial(true),
+    ///     );
+    /// }
+    /// ```
+    pub fn play_on(
+        &self,
+        entity: Entity,
+        audio_source: Handle<Source>,
+        settings: PlaybackSettings,
+    ) {
+        let sink_handle = if settings.spatial {
+            HandleId::random::<SpatialAudioSink>()
+        } else {
+            HandleId::random::<AudioSink>()
+        };
+        let config = AudioToPlay {
+            settings,
+            sink_handle,
+            source_handle: audio_source,
+            spatial: None,
+            entity: Some(entity),
+        };
+        self.queue.write().push_back(config);
+    }
 }
 
 /// Settings to control playback from the start.
@@ -260,6 +315,11 @@ pub struct PlaybackSettings {
     pub volume: f32,
     /// Speed to play at.
     pub speed: f32,
+    /// Play the sound from the position of the entity it is played on with [`Audio::play_on`],
+    /// as heard by the [`SpatialListener`].
+    pub spatial: bool,
+    /// Position in the sound to start playing from.
+    pub start_position: Duration,
 }
 
 impl Default for PlaybackSettings {
@@ -274,6 +334,8 @@ impl PlaybackSettings {
         repeat: false,
         volume: 1.0,
         speed: 1.0,
+        spatial: false,
+        start_position: Duration::ZERO,
     };
 
     /// Will play the associate audio source in a loop.
@@ -281,6 +343,8 @@ impl PlaybackSettings {
         repeat: true,
         volume: 1.0,
         speed: 1.0,
+        spatial: false,
+        start_position: Duration::ZERO,
     };
 
     /// Helper to set the volume from start of playback.
@@ -294,6 +358,103 @@ impl PlaybackSettings {
         self.speed = speed;
         self
     }
+
+    /// Helper to make the playback spatial.
+    pub const fn with_spatial(mut self, spatial: bool) -> Self {
+        self.spatial = spatial;
+        self
+    }
+
+    /// Helper to set the position to start playing from.
+    pub const fn with_start_position(mut self, start_position: Duration) -> Self {
+        self.start_position = start_position;
+        self
+    }
+}
+
+/// Marks the entity hearing the spatial sounds played with [`Audio::play_on`], usually the
+/// camera.
+///
+/// The ears are placed at an offset from the [`GlobalTransform`] of the entity, in its local
+/// space. Only one listener is used: if there are several, a warning is logged and the first
+/// one found is used.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SpatialListener {
+    /// The position of the left ear, relative to the listener.
+    pub left_ear_offset: Vec3,
+    /// The position of the right ear, relative to the listener.
+    pub right_ear_offset: Vec3,
+}
+
+impl Default for SpatialListener {
+    fn default() -> Self {
+        Self::new(4.0)
+    }
+}
+
+impl SpatialListener {
+    /// Creates a listener with an ear on each side, separated by `gap`.
+    pub fn new(gap: f32) -> Self {
+        SpatialListener {
+            left_ear_offset: Vec3::X * gap / -2.0,
+            right_ear_offset: Vec3::X * gap / 2.0,
+        }
+    }
+
+    /// Returns the positions of the left and right ears in audio units, for a listener at
+    /// `transform`.
+    pub(crate) fn ears(&self, transform: &GlobalTransform, scale: &SpatialScale) -> [Vec3; 2] {
+        [self.left_ear_offset, self.right_ear_offset]
+            .map(|offset| transform.transform_point(offset) * scale.0)
+    }
+}
+
+/// The scale from world units to audio units, used to place the spatial sounds played with
+/// [`Audio::play_on`] and their [`SpatialListener`].
+///
+/// The volume of a sound depends on its distance to each ear in audio units, so this can be
+/// used to scale down a 2D world measured in pixels, for example. Defaults to no scaling.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct SpatialScale(pub Vec3);
+
+impl Default for SpatialScale {
+    fn default() -> Self {
+        SpatialScale(Vec3::ONE)
+    }
+}
+
+impl SpatialScale {
+    /// Creates a scale applied to all the axes.
+    pub const fn new(scale: f32) -> Self {
+        SpatialScale(Vec3::splat(scale))
+    }
+
+    /// Creates a scale for a 2D world, which doesn't scale the depth of the sounds.
+    pub const fn new_2d(scale: f32) -> Self {
+        SpatialScale(Vec3::new(scale, scale, 1.0))
+    }
+}
+
+/// The volume all the sounds are played at, multiplied with the volume of each sink.
+///
+/// Changing it also changes the volume of the sounds already playing.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct GlobalVolume {
+    /// The value `1.0` is the "normal" volume, which doesn't change the volume of the sounds.
+    pub volume: f32,
+}
+
+impl Default for GlobalVolume {
+    fn default() -> Self {
+        GlobalVolume::new(1.0)
+    }
+}
+
+impl GlobalVolume {
+    /// Creates a global volume.
+    pub const fn new(volume: f32) -> Self {
+        GlobalVolume { volume }
+    }
 }
 
 #[derive(Clone)]
@@ -312,6 +473,8 @@ where
     pub(crate) source_handle: Handle<Source>,
     pub(crate) settings: PlaybackSettings,
     pub(crate) spatial: Option<SpatialSettings>,
+    /// The entity where the handle to the sink is inserted, and spatial sounds are emitted from.
+    pub(crate) entity: Option<Entity>,
 }
 
 impl<Source> fmt::Debug for AudioToPlay<Source>
@@ -323,6 +486,7 @@ where
             .field("sink_handle", &self.sink_handle)
             .field("source_handle", &self.source_handle)
             .field("settings", &self.settings)
+            .field("entity", &self.entity)
             .finish()
     }
 }
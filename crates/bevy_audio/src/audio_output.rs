@@ -1,9 +1,22 @@
-use crate::{Audio, AudioSource, Decodable, SpatialAudioSink, SpatialSettings};
-use bevy_asset::{Asset, Assets};
-use bevy_ecs::system::{Res, ResMut, Resource};
+use crate::{
+    seekable::{PlaybackPosition, Seekable},
+    sinks::SinkVolume,
+    Audio, AudioSource, Decodable, GlobalVolume, SpatialAudioSink, SpatialAudioSinkPositions,
+    SpatialListener, SpatialScale, SpatialSettings,
+};
+use bevy_asset::{Asset, Assets, Handle};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+};
+use bevy_math::Vec3;
+use bevy_time::Time;
+use bevy_transform::prelude::GlobalTransform;
 use bevy_utils::tracing::warn;
+use parking_lot::Mutex;
 use rodio::{OutputStream, OutputStreamHandle, Sink, Source, SpatialSink};
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 use crate::AudioSink;
 
@@ -55,17 +68,28 @@ where
     Source: Asset + Decodable,
     f32: rodio::cpal::FromSample<Source::DecoderItem>,
 {
-    fn play_source(&self, audio_source: &Source, repeat: bool) -> Option<Sink> {
+    fn play_source(
+        &self,
+        audio_source: &Source,
+        repeat: bool,
+        start_position: Duration,
+    ) -> Option<(Sink, Arc<PlaybackPosition>)> {
         self.stream_handle
             .as_ref()
             .and_then(|stream_handle| match Sink::try_new(stream_handle) {
                 Ok(sink) => {
-                    if repeat {
-                        sink.append(audio_source.decoder().repeat_infinite());
+                    let position = if repeat {
+                        let decoder = audio_source.decoder().repeat_infinite();
+                        let (source, position) = Seekable::new(decoder, start_position);
+                        sink.append(source);
+                        position
                     } else {
-                        sink.append(audio_source.decoder());
-                    }
-                    Some(sink)
+                        let (source, position) =
+                            Seekable::new(audio_source.decoder(), start_position);
+                        sink.append(source);
+                        position
+                    };
+                    Some((sink, position))
                 }
                 Err(err) => {
                     warn!("Error playing sound: {err:?}");
@@ -78,8 +102,9 @@ where
         &self,
         audio_source: &Source,
         repeat: bool,
+        start_position: Duration,
         spatial: SpatialSettings,
-    ) -> Option<SpatialSink> {
+    ) -> Option<(SpatialSink, Arc<PlaybackPosition>)> {
         self.stream_handle.as_ref().and_then(|stream_handle| {
             match SpatialSink::try_new(
                 stream_handle,
@@ -88,12 +113,18 @@ where
                 spatial.right_ear,
             ) {
                 Ok(sink) => {
-                    if repeat {
-                        sink.append(audio_source.decoder().repeat_infinite());
+                    let position = if repeat {
+                        let decoder = audio_source.decoder().repeat_infinite();
+                        let (source, position) = Seekable::new(decoder, start_position);
+                        sink.append(source);
+                        position
                     } else {
-                        sink.append(audio_source.decoder());
-                    }
-                    Some(sink)
+                        let (source, position) =
+                            Seekable::new(audio_source.decoder(), start_position);
+                        sink.append(source);
+                        position
+                    };
+                    Some((sink, position))
                 }
                 Err(err) => {
                     warn!("Error playing spatial sound: {err:?}");
@@ -103,12 +134,16 @@ where
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn try_play_queued(
         &self,
         audio_sources: &Assets<Source>,
         audio: &mut Audio<Source>,
         sinks: &mut Assets<AudioSink>,
         spatial_sinks: &mut Assets<SpatialAudioSink>,
+        commands: &mut Commands,
+        entity_spatial_settings: impl Fn(Entity) -> SpatialSettings,
+        global_volume: f32,
     ) {
         let mut queue = audio.queue.write();
         let len = queue.len();
@@ -116,23 +151,53 @@ where
         while i < len {
             let config = queue.pop_front().unwrap();
             if let Some(audio_source) = audio_sources.get(&config.source_handle) {
-                if let Some(spatial) = config.spatial {
-                    if let Some(sink) =
-                        self.play_spatial_source(audio_source, config.settings.repeat, spatial)
-                    {
-                        sink.set_speed(config.settings.speed);
-                        sink.set_volume(config.settings.volume);
-
-                        // don't keep the strong handle. there is no way to return it to the user here as it is async
-                        let _ = spatial_sinks
-                            .set(config.sink_handle, SpatialAudioSink { sink: Some(sink) });
+                let spatial = match config.entity {
+                    Some(entity) if config.settings.spatial => {
+                        Some(entity_spatial_settings(entity))
                     }
-                } else if let Some(sink) = self.play_source(audio_source, config.settings.repeat) {
-                    sink.set_speed(config.settings.speed);
-                    sink.set_volume(config.settings.volume);
+                    _ => config.spatial,
+                };
+                let settings = &config.settings;
+                let volume = SinkVolume::new(settings.volume, global_volume);
+                if let Some(spatial) = spatial {
+                    if let Some((sink, position)) = self.play_spatial_source(
+                        audio_source,
+                        settings.repeat,
+                        settings.start_position,
+                        spatial,
+                    ) {
+                        sink.set_speed(settings.speed);
+                        sink.set_volume(volume.output_volume());
 
-                    // don't keep the strong handle. there is no way to return it to the user here as it is async
-                    let _ = sinks.set(config.sink_handle, AudioSink { sink: Some(sink) });
+                        // don't keep the strong handle, unless it can be inserted on the entity.
+                        // there is no other way to return it to the user here as it is async
+                        let handle = spatial_sinks.set(
+                            config.sink_handle,
+                            SpatialAudioSink {
+                                sink: Some(sink),
+                                position,
+                                volume: Mutex::new(volume),
+                            },
+                        );
+                        insert_on_entity(commands, config.entity, handle);
+                    }
+                } else if let Some((sink, position)) =
+                    self.play_source(audio_source, settings.repeat, settings.start_position)
+                {
+                    sink.set_speed(settings.speed);
+                    sink.set_volume(volume.output_volume());
+
+                    // don't keep the strong handle, unless it can be inserted on the entity.
+                    // there is no other way to return it to the user here as it is async
+                    let handle = sinks.set(
+                        config.sink_handle,
+                        AudioSink {
+                            sink: Some(sink),
+                            position,
+                            volume: Mutex::new(volume),
+                        },
+                    );
+                    insert_on_entity(commands, config.entity, handle);
                 }
             } else {
                 // audio source hasn't loaded yet. add it back to the queue
@@ -143,17 +208,375 @@ where
     }
 }
 
+fn insert_on_entity(commands: &mut Commands, entity: Option<Entity>, component: impl Component) {
+    if let Some(mut entity_commands) = entity.and_then(|entity| commands.get_entity(entity)) {
+        entity_commands.insert(component);
+    }
+}
+
 /// Plays audio currently queued in the [`Audio`] resource through the [`AudioOutput`] resource
+#[allow(clippy::too_many_arguments)]
 pub fn play_queued_audio_system<Source: Asset + Decodable>(
+    mut commands: Commands,
     audio_output: Res<AudioOutput<Source>>,
     audio_sources: Option<Res<Assets<Source>>>,
     mut audio: ResMut<Audio<Source>>,
     mut sinks: ResMut<Assets<AudioSink>>,
     mut spatial_sinks: ResMut<Assets<SpatialAudioSink>>,
+    spatial_scale: Res<SpatialScale>,
+    global_volume: Res<GlobalVolume>,
+    listeners: Query<(&SpatialListener, &GlobalTransform)>,
+    emitters: Query<&GlobalTransform>,
 ) where
     f32: rodio::cpal::FromSample<Source::DecoderItem>,
 {
+    let [left_ear, right_ear] = match listeners.iter().next() {
+        Some((listener, transform)) => listener.ears(transform, &spatial_scale),
+        None => SpatialListener::default().ears(&GlobalTransform::IDENTITY, &spatial_scale),
+    };
+    let entity_spatial_settings = |entity| {
+        let emitter = emitters
+            .get(entity)
+            .map_or(Vec3::ZERO, GlobalTransform::translation);
+        SpatialSettings {
+            left_ear: left_ear.to_array(),
+            right_ear: right_ear.to_array(),
+            emitter: (emitter * spatial_scale.0).to_array(),
+        }
+    };
+
     if let Some(audio_sources) = audio_sources {
-        audio_output.try_play_queued(&*audio_sources, &mut *audio, &mut sinks, &mut spatial_sinks);
+        audio_output.try_play_queued(
+            &*audio_sources,
+            &mut *audio,
+            &mut sinks,
+            &mut spatial_sinks,
+            &mut commands,
+            entity_spatial_settings,
+            global_volume.volume,
+        );
     };
 }
+
+/// Moves the spatial audio sinks whose handle is on an entity, like the ones played with
+/// [`Audio::play_on`], to the [`GlobalTransform`] of the entity, and their ears to the
+/// [`SpatialListener`].
+///
+/// If there is no listener, only the emitters are moved.
+pub fn update_spatial_audio_positions<Sink: Asset + SpatialAudioSinkPositions>(
+    spatial_scale: Res<SpatialScale>,
+    listeners: Query<(&SpatialListener, &GlobalTransform)>,
+    emitters: Query<(&GlobalTransform, &Handle<Sink>)>,
+    sinks: Res<Assets<Sink>>,
+    mut warned_multiple_listeners: Local<bool>,
+) {
+    let mut listeners = listeners.iter();
+    let ears = listeners
+        .next()
+        .map(|(listener, transform)| listener.ears(transform, &spatial_scale));
+    if listeners.next().is_none() {
+        *warned_multiple_listeners = false;
+    } else if !*warned_multiple_listeners {
+        warn!("There is more than one `SpatialListener`, only the first one is used.");
+        *warned_multiple_listeners = true;
+    }
+
+    for (transform, handle) in &emitters {
+        let Some(sink) = sinks.get(handle) else {
+            continue;
+        };
+        sink.set_emitter_position(transform.translation() * spatial_scale.0);
+        if let Some([left_ear, right_ear]) = ears {
+            sink.set_ears_position(left_ear, right_ear);
+        }
+    }
+}
+
+/// Fades the volume of the sinks with [`fade_to`](crate::AudioSinkPlayback::fade_to), and
+/// applies changes of the [`GlobalVolume`] to the sinks already playing.
+pub fn update_audio_sink_volumes(
+    time: Res<Time>,
+    global_volume: Res<GlobalVolume>,
+    sinks: Res<Assets<AudioSink>>,
+    spatial_sinks: Res<Assets<SpatialAudioSink>>,
+) {
+    for (_, sink) in sinks.iter() {
+        sink.update_volume(time.delta(), global_volume.volume);
+    }
+    for (_, sink) in spatial_sinks.iter() {
+        sink.update_volume(time.delta(), global_volume.volume);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AudioSinkPlayback;
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin};
+    use bevy_math::Quat;
+    use bevy_reflect::TypeUuid;
+    use bevy_transform::prelude::Transform;
+    use rodio::buffer::SamplesBuffer;
+    use std::f32::consts::PI;
+
+    /// Records the positions it is moved to.
+    #[derive(TypeUuid, Default)]
+    #[uuid = "2dd1e4de-1c8b-4c9a-9764-8d3cb1bd5a5c"]
+    struct MockSink {
+        emitter: Mutex<Option<Vec3>>,
+        ears: Mutex<Option<[Vec3; 2]>>,
+    }
+
+    impl SpatialAudioSinkPositions for MockSink {
+        fn set_ears_position(&self, left_position: Vec3, right_position: Vec3) {
+            *self.ears.lock() = Some([left_position, right_position]);
+        }
+
+        fn set_emitter_position(&self, position: Vec3) {
+            *self.emitter.lock() = Some(position);
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<MockSink>()
+            .init_resource::<SpatialScale>()
+            .add_system(update_spatial_audio_positions::<MockSink>);
+        app
+    }
+
+    fn spawn_emitter(app: &mut App, transform: Transform) -> (Entity, Handle<MockSink>) {
+        let handle = app
+            .world
+            .resource_mut::<Assets<MockSink>>()
+            .add(MockSink::default());
+        let entity = app
+            .world
+            .spawn((GlobalTransform::from(transform), handle.clone()))
+            .id();
+        (entity, handle)
+    }
+
+    fn positions(app: &App, handle: &Handle<MockSink>) -> (Option<Vec3>, Option<[Vec3; 2]>) {
+        let sink = app
+            .world
+            .resource::<Assets<MockSink>>()
+            .get(handle)
+            .unwrap();
+        (*sink.emitter.lock(), *sink.ears.lock())
+    }
+
+    #[test]
+    fn emitters_follow_their_entity() {
+        let mut app = app();
+        app.world.spawn((
+            SpatialListener::new(2.0),
+            GlobalTransform::from_xyz(0.0, 1.0, 0.0),
+        ));
+        let (emitter, handle) = spawn_emitter(&mut app, Transform::from_xyz(1.0, 2.0, 3.0));
+
+        app.update();
+        assert_eq!(
+            positions(&app, &handle),
+            (
+                Some(Vec3::new(1.0, 2.0, 3.0)),
+                Some([Vec3::new(-1.0, 1.0, 0.0), Vec3::new(1.0, 1.0, 0.0)])
+            )
+        );
+
+        *app.world.get_mut::<GlobalTransform>(emitter).unwrap() =
+            GlobalTransform::from_xyz(-4.0, 0.0, 0.0);
+        app.update();
+        assert_eq!(positions(&app, &handle).0, Some(Vec3::new(-4.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn ears_follow_the_listener_orientation_and_scale() {
+        let mut app = app();
+        app.insert_resource(SpatialScale::new_2d(0.5));
+        let listener = app
+            .world
+            .spawn((
+                SpatialListener::new(4.0),
+                GlobalTransform::from_xyz(2.0, 0.0, 0.0),
+            ))
+            .id();
+        let (_, handle) = spawn_emitter(&mut app, Transform::from_xyz(0.0, 4.0, 2.0));
+
+        app.update();
+        assert_eq!(
+            positions(&app, &handle),
+            (
+                Some(Vec3::new(0.0, 2.0, 2.0)),
+                Some([Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0)])
+            )
+        );
+
+        // Turning around swaps the sides of the ears.
+        *app.world.get_mut::<GlobalTransform>(listener).unwrap() = GlobalTransform::from(
+            Transform::from_xyz(2.0, 0.0, 0.0).with_rotation(Quat::from_rotation_y(PI)),
+        );
+        app.update();
+        let [left_ear, right_ear] = positions(&app, &handle).1.unwrap();
+        assert!(left_ear.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-6));
+        assert!(right_ear.abs_diff_eq(Vec3::new(0.0, 0.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn first_listener_is_used() {
+        let mut app = app();
+        app.world.spawn((
+            SpatialListener::new(2.0),
+            GlobalTransform::from_xyz(0.0, 0.0, 5.0),
+        ));
+        app.world.spawn((
+            SpatialListener::new(2.0),
+            GlobalTransform::from_xyz(0.0, 0.0, -5.0),
+        ));
+        let (_, handle) = spawn_emitter(&mut app, Transform::IDENTITY);
+
+        app.update();
+        app.update();
+        assert_eq!(
+            positions(&app, &handle).1,
+            Some([Vec3::new(-1.0, 0.0, 5.0), Vec3::new(1.0, 0.0, 5.0)])
+        );
+    }
+
+    #[test]
+    fn ears_are_not_moved_without_listener() {
+        let mut app = app();
+        let (_, handle) = spawn_emitter(&mut app, Transform::from_xyz(1.0, 0.0, 0.0));
+
+        app.update();
+        assert_eq!(
+            positions(&app, &handle),
+            (Some(Vec3::new(1.0, 0.0, 0.0)), None)
+        );
+    }
+
+    fn volume_app() -> App {
+        let mut app = App::new();
+        let mut time = Time::default();
+        time.update();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<AudioSink>()
+            .add_asset::<SpatialAudioSink>()
+            .init_resource::<GlobalVolume>()
+            .insert_resource(time)
+            .add_system(update_audio_sink_volumes);
+        app
+    }
+
+    fn add_sink(app: &mut App, volume: f32) -> Handle<AudioSink> {
+        let (sink, _) = Sink::new_idle();
+        let (_, position) =
+            Seekable::new(SamplesBuffer::new(1, 1, Vec::<f32>::new()), Duration::ZERO);
+        sink.set_volume(volume);
+        app.world
+            .resource_mut::<Assets<AudioSink>>()
+            .add(AudioSink {
+                sink: Some(sink),
+                position,
+                volume: Mutex::new(SinkVolume::new(volume, 1.0)),
+            })
+    }
+
+    /// Runs a frame `millis` after the last one.
+    fn advance(app: &mut App, millis: u64) {
+        let mut time = app.world.resource_mut::<Time>();
+        let last_update = time.last_update().unwrap();
+        time.update_with_instant(last_update + Duration::from_millis(millis));
+        app.update();
+    }
+
+    /// Returns the volume of the sink, and the volume it plays at.
+    fn volumes(app: &App, handle: &Handle<AudioSink>) -> (f32, f32) {
+        let sink = app
+            .world
+            .resource::<Assets<AudioSink>>()
+            .get(handle)
+            .unwrap();
+        (sink.volume(), sink.sink.as_ref().unwrap().volume())
+    }
+
+    #[test]
+    fn fade_interpolates_the_volume() {
+        let mut app = volume_app();
+        let handle = add_sink(&mut app, 1.0);
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        sinks
+            .get(&handle)
+            .unwrap()
+            .fade_to(0.0, Duration::from_secs(1));
+
+        for expected in [0.75, 0.5, 0.25, 0.0, 0.0] {
+            advance(&mut app, 250);
+            assert_eq!(volumes(&app, &handle), (expected, expected));
+        }
+    }
+
+    #[test]
+    fn fade_does_not_progress_while_paused() {
+        let mut app = volume_app();
+        let handle = add_sink(&mut app, 0.0);
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        let sink = sinks.get(&handle).unwrap();
+        sink.fade_to(1.0, Duration::from_secs(2));
+        sink.pause();
+
+        advance(&mut app, 500);
+        assert_eq!(volumes(&app, &handle), (0.0, 0.0));
+
+        // Paused virtual time doesn't progress the fade either.
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        sinks.get(&handle).unwrap().play();
+        app.world.resource_mut::<Time>().pause();
+        advance(&mut app, 500);
+        assert_eq!(volumes(&app, &handle), (0.0, 0.0));
+
+        app.world.resource_mut::<Time>().unpause();
+        advance(&mut app, 500);
+        assert_eq!(volumes(&app, &handle), (0.25, 0.25));
+    }
+
+    #[test]
+    fn set_volume_stops_the_fade() {
+        let mut app = volume_app();
+        let handle = add_sink(&mut app, 1.0);
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        sinks
+            .get(&handle)
+            .unwrap()
+            .fade_to(0.0, Duration::from_secs(1));
+
+        advance(&mut app, 500);
+        assert_eq!(volumes(&app, &handle), (0.5, 0.5));
+
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        sinks.get(&handle).unwrap().set_volume(0.8);
+        advance(&mut app, 250);
+        assert_eq!(volumes(&app, &handle), (0.8, 0.8));
+    }
+
+    #[test]
+    fn global_volume_applies_to_playing_sinks() {
+        let mut app = volume_app();
+        let handle = add_sink(&mut app, 0.5);
+
+        app.insert_resource(GlobalVolume::new(0.5));
+        advance(&mut app, 10);
+        assert_eq!(volumes(&app, &handle), (0.5, 0.25));
+
+        // Fades are multiplied with the global volume.
+        let sinks = app.world.resource::<Assets<AudioSink>>();
+        sinks
+            .get(&handle)
+            .unwrap()
+            .fade_to(1.0, Duration::from_secs(1));
+        advance(&mut app, 500);
+        assert_eq!(volumes(&app, &handle), (0.75, 0.375));
+    }
+}
@@ -25,14 +25,15 @@
 mod audio;
 mod audio_output;
 mod audio_source;
+mod seekable;
 mod sinks;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        Audio, AudioOutput, AudioSink, AudioSinkPlayback, AudioSource, Decodable, PlaybackSettings,
-        SpatialAudioSink,
+        Audio, AudioOutput, AudioSink, AudioSinkPlayback, AudioSource, Decodable, GlobalVolume,
+        PlaybackSettings, SpatialAudioSink, SpatialListener, SpatialScale,
     };
 }
 
@@ -43,11 +44,13 @@ pub use audio_source::*;
 pub use rodio::cpal::Sample as CpalSample;
 pub use rodio::source::Source;
 pub use rodio::Sample;
+pub use seekable::SeekError;
 pub use sinks::*;
 
 use bevy_app::prelude::*;
 use bevy_asset::{AddAsset, Asset};
 use bevy_ecs::prelude::*;
+use bevy_transform::TransformSystem;
 
 /// Adds support for audio playback to a Bevy Application
 ///
@@ -62,7 +65,15 @@ impl Plugin for AudioPlugin {
             .add_asset::<AudioSink>()
             .add_asset::<SpatialAudioSink>()
             .init_resource::<Audio<AudioSource>>()
-            .add_system(play_queued_audio_system::<AudioSource>.in_base_set(CoreSet::PostUpdate));
+            .init_resource::<SpatialScale>()
+            .init_resource::<GlobalVolume>()
+            .add_system(play_queued_audio_system::<AudioSource>.in_base_set(CoreSet::PostUpdate))
+            .add_system(update_audio_sink_volumes.in_base_set(CoreSet::PostUpdate))
+            .add_system(
+                update_spatial_audio_positions::<SpatialAudioSink>
+                    .in_base_set(CoreSet::PostUpdate)
+                    .after(TransformSystem::TransformPropagate),
+            );
 
         #[cfg(any(feature = "mp3", feature = "flac", feature = "wav", feature = "vorbis"))]
         app.init_asset_loader::<AudioLoader>();
@@ -0,0 +1,244 @@
+use rodio::{Sample, Source};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use thiserror::Error;
+
+/// An error that occurs when seeking in a sound with
+/// [`AudioSinkPlayback::try_seek`](crate::AudioSinkPlayback::try_seek).
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekError {
+    /// The sounds are decoded forward only, so seeking back would restart them instead.
+    #[error("cannot seek backward from {position:?} to {target:?}")]
+    BackwardSeek {
+        /// The playback position when seeking.
+        position: Duration,
+        /// The position that was seeked to.
+        target: Duration,
+    },
+}
+
+/// The playback position of a [`Seekable`] source, shared with its sink.
+#[derive(Debug)]
+pub(crate) struct PlaybackPosition {
+    /// The number of samples played or skipped since the start of the sound.
+    samples: AtomicU64,
+    /// The number of samples left to skip before playing the next one.
+    pending_skip: AtomicU64,
+    sample_rate: u64,
+    channels: u64,
+}
+
+impl PlaybackPosition {
+    /// Returns the number of samples in `duration`, rounded to the nearest whole frame.
+    fn samples_in(&self, duration: Duration) -> u64 {
+        let frames = (duration.as_nanos() * self.sample_rate as u128 + 500_000_000) / 1_000_000_000;
+        frames as u64 * self.channels
+    }
+
+    /// Returns the position in the sound, from its start.
+    ///
+    /// The sample rate and channels of the source are assumed not to change during playback.
+    pub(crate) fn position(&self) -> Duration {
+        let samples_per_second = (self.sample_rate * self.channels).max(1) as u128;
+        let nanos = self.samples.load(Ordering::Relaxed) as u128 * 1_000_000_000;
+        Duration::from_nanos((nanos / samples_per_second) as u64)
+    }
+
+    /// Skips the samples up to `target`, which is applied when the next sample is played.
+    pub(crate) fn try_seek(&self, target: Duration) -> Result<(), SeekError> {
+        let target_samples = self.samples_in(target);
+        match self
+            .samples
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |samples| {
+                (target_samples >= samples).then_some(target_samples)
+            }) {
+            Ok(samples) => {
+                self.pending_skip
+                    .fetch_add(target_samples - samples, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(_) => Err(SeekError::BackwardSeek {
+                position: self.position(),
+                target,
+            }),
+        }
+    }
+}
+
+/// A source that keeps track of its playback position, and can skip forward to another one.
+pub(crate) struct Seekable<S> {
+    inner: S,
+    position: Arc<PlaybackPosition>,
+}
+
+impl<S: Source> Seekable<S>
+where
+    S::Item: Sample,
+{
+    /// Wraps `inner`, starting its playback at `start_position`.
+    pub(crate) fn new(inner: S, start_position: Duration) -> (Self, Arc<PlaybackPosition>) {
+        let position = Arc::new(PlaybackPosition {
+            samples: AtomicU64::new(0),
+            pending_skip: AtomicU64::new(0),
+            sample_rate: inner.sample_rate().into(),
+            channels: inner.channels().into(),
+        });
+        // Seeking forward from the start can't fail.
+        let _ = position.try_seek(start_position);
+        (
+            Seekable {
+                inner,
+                position: position.clone(),
+            },
+            position,
+        )
+    }
+}
+
+impl<S: Source> Iterator for Seekable<S>
+where
+    S::Item: Sample,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let skipped = self.position.pending_skip.swap(0, Ordering::Relaxed);
+        for _ in 0..skipped {
+            self.inner.next()?;
+        }
+
+        let sample = self.inner.next()?;
+        self.position.samples.fetch_add(1, Ordering::Relaxed);
+        Some(sample)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Seeking can end the source earlier than the inner hint.
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<S: Source> Source for Seekable<S>
+where
+    S::Item: Sample,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::{buffer::SamplesBuffer, source::SineWave};
+
+    #[test]
+    fn position_increases_with_played_samples() {
+        // 48000 samples per second, in a single channel.
+        let (mut source, position) = Seekable::new(SineWave::new(440.0), Duration::ZERO);
+        assert_eq!(position.position(), Duration::ZERO);
+
+        let mut last_position = Duration::ZERO;
+        for _ in 0..4800 {
+            source.next().unwrap();
+            let current = position.position();
+            assert!(current >= last_position);
+            last_position = current;
+        }
+        assert_eq!(position.position(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn start_position_skips_the_start_of_the_sound() {
+        let (mut source, position) =
+            Seekable::new(SineWave::new(440.0), Duration::from_millis(250));
+        assert_eq!(position.position(), Duration::from_millis(250));
+
+        let expected = SineWave::new(440.0).nth(12000);
+        assert_eq!(source.next(), expected);
+        assert!(position.position() > Duration::from_millis(250));
+    }
+
+    #[test]
+    fn seeking_forward_skips_samples() {
+        let (mut source, position) = Seekable::new(SineWave::new(440.0), Duration::ZERO);
+        for _ in 0..10 {
+            source.next().unwrap();
+        }
+
+        assert_eq!(position.try_seek(Duration::from_millis(500)), Ok(()));
+        assert_eq!(position.position(), Duration::from_millis(500));
+        assert_eq!(source.next(), SineWave::new(440.0).nth(24000));
+
+        // Seeking to the current position doesn't skip anything.
+        let current = position.position();
+        assert_eq!(position.try_seek(current), Ok(()));
+        assert_eq!(source.next(), SineWave::new(440.0).nth(24001));
+    }
+
+    #[test]
+    fn seeking_backward_is_an_error() {
+        let (mut source, position) =
+            Seekable::new(SineWave::new(440.0), Duration::from_millis(500));
+        source.next().unwrap();
+        let current = position.position();
+
+        assert_eq!(
+            position.try_seek(Duration::from_millis(100)),
+            Err(SeekError::BackwardSeek {
+                position: current,
+                target: Duration::from_millis(100),
+            })
+        );
+        assert_eq!(position.position(), current);
+        // The sound continues from where it was, instead of restarting.
+        assert_eq!(source.next(), SineWave::new(440.0).nth(24001));
+    }
+
+    #[test]
+    fn seeking_past_the_end_ends_the_sound() {
+        let (mut source, position) = Seekable::new(
+            SineWave::new(440.0).take_duration(Duration::from_millis(100)),
+            Duration::ZERO,
+        );
+        source.next().unwrap();
+
+        assert_eq!(position.try_seek(Duration::from_secs(1)), Ok(()));
+        assert_eq!(source.next(), None);
+    }
+
+    #[test]
+    fn seeking_skips_whole_frames() {
+        // 10 frames per second of 2 channels, with the channel index as sample.
+        let samples = (0..20).map(|index| (index % 2) as f32).collect::<Vec<_>>();
+        let (mut source, position) = Seekable::new(
+            SamplesBuffer::new(2, 10, samples),
+            Duration::from_millis(120),
+        );
+        assert_eq!(position.position(), Duration::from_millis(100));
+        assert_eq!(source.next(), Some(0.0));
+        assert_eq!(source.next(), Some(1.0));
+        assert_eq!(position.position(), Duration::from_millis(200));
+
+        assert_eq!(position.try_seek(Duration::from_millis(330)), Ok(()));
+        assert_eq!(position.position(), Duration::from_millis(300));
+        assert_eq!(source.by_ref().count(), 14);
+    }
+}
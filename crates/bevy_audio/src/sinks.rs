@@ -1,7 +1,10 @@
+use crate::seekable::{PlaybackPosition, SeekError};
 use bevy_math::Vec3;
 use bevy_reflect::TypeUuid;
 use bevy_transform::prelude::Transform;
+use parking_lot::Mutex;
 use rodio::{Sink, SpatialSink};
+use std::{sync::Arc, time::Duration};
 
 /// Common interactions with an audio sink.
 pub trait AudioSinkPlayback {
@@ -9,14 +12,22 @@ pub trait AudioSinkPlayback {
     ///
     /// The value `1.0` is the "normal" volume (unfiltered input). Any value other than `1.0`
     /// will multiply each sample by this value.
+    ///
+    /// The sound is played at this volume multiplied by the [`GlobalVolume`](crate::GlobalVolume).
     fn volume(&self) -> f32;
 
-    /// Changes the volume of the sound.
+    /// Changes the volume of the sound, stopping any ongoing [fade](Self::fade_to).
     ///
     /// The value `1.0` is the "normal" volume (unfiltered input). Any value other than `1.0`
     /// will multiply each sample by this value.
     fn set_volume(&self, volume: f32);
 
+    /// Fades the volume of the sound from its current volume to `volume` over `duration`.
+    ///
+    /// The fade follows the virtual time of [`Time`](bevy_time::Time), and doesn't progress
+    /// while the time or the sink is paused. A zero `duration` sets the volume immediately.
+    fn fade_to(&self, volume: f32, duration: Duration);
+
     /// Gets the speed of the sound.
     ///
     /// The value `1.0` is the "normal" speed (unfiltered input). Any value other than `1.0`
@@ -60,6 +71,30 @@ pub trait AudioSinkPlayback {
     ///
     /// It won't be possible to restart it afterwards.
     fn stop(&self);
+
+    /// Gets the position in the sound, from where it started to play.
+    ///
+    /// For a sound played in repeat, this keeps increasing with each repetition.
+    fn position(&self) -> Duration;
+
+    /// Moves the playback to `position`, from where the sound started to play.
+    ///
+    /// The sounds are decoded as they are played, so they can only be seeked forward: seeking
+    /// before the current [`position`](Self::position) returns an error and leaves the playback
+    /// unchanged, instead of restarting the sound.
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError>;
+}
+
+/// Moving the listener and emitter of a spatial audio sink.
+///
+/// Used to move the sinks inserted on entities by [`Audio::play_on`](crate::Audio::play_on)
+/// along with their emitter and [`SpatialListener`](crate::SpatialListener).
+pub trait SpatialAudioSinkPositions {
+    /// Set the two ears position.
+    fn set_ears_position(&self, left_position: Vec3, right_position: Vec3);
+
+    /// Set the emitter position.
+    fn set_emitter_position(&self, position: Vec3);
 }
 
 /// Asset controlling the playback of a sound
@@ -90,6 +125,8 @@ pub struct AudioSink {
     // This field is an Option in order to allow us to have a safe drop that will detach the sink.
     // It will never be None during its life
     pub(crate) sink: Option<Sink>,
+    pub(crate) position: Arc<PlaybackPosition>,
+    pub(crate) volume: Mutex<SinkVolume>,
 }
 
 impl Drop for AudioSink {
@@ -98,13 +135,42 @@ impl Drop for AudioSink {
     }
 }
 
+impl AudioSink {
+    /// Advances the fade of the volume by `delta` unless paused, and applies `global_volume`.
+    pub(crate) fn update_volume(&self, delta: Duration, global_volume: f32) {
+        let sink = self.sink.as_ref().unwrap();
+        let delta = if sink.is_paused() {
+            Duration::ZERO
+        } else {
+            delta
+        };
+        if let Some(volume) = self.volume.lock().update(delta, global_volume) {
+            sink.set_volume(volume);
+        }
+    }
+}
+
 impl AudioSinkPlayback for AudioSink {
     fn volume(&self) -> f32 {
-        self.sink.as_ref().unwrap().volume()
+        self.volume.lock().volume
     }
 
     fn set_volume(&self, volume: f32) {
-        self.sink.as_ref().unwrap().set_volume(volume);
+        let mut sink_volume = self.volume.lock();
+        sink_volume.set_volume(volume);
+        self.sink
+            .as_ref()
+            .unwrap()
+            .set_volume(sink_volume.output_volume());
+    }
+
+    fn fade_to(&self, volume: f32, duration: Duration) {
+        let mut sink_volume = self.volume.lock();
+        sink_volume.fade_to(volume, duration);
+        self.sink
+            .as_ref()
+            .unwrap()
+            .set_volume(sink_volume.output_volume());
     }
 
     fn speed(&self) -> f32 {
@@ -130,6 +196,14 @@ impl AudioSinkPlayback for AudioSink {
     fn stop(&self) {
         self.sink.as_ref().unwrap().stop();
     }
+
+    fn position(&self) -> Duration {
+        self.position.position()
+    }
+
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError> {
+        self.position.try_seek(position)
+    }
 }
 
 /// Asset controlling the playback of a sound, or the locations of its listener and emitter.
@@ -157,6 +231,8 @@ pub struct SpatialAudioSink {
     // This field is an Option in order to allow us to have a safe drop that will detach the sink.
     // It will never be None during its life
     pub(crate) sink: Option<SpatialSink>,
+    pub(crate) position: Arc<PlaybackPosition>,
+    pub(crate) volume: Mutex<SinkVolume>,
 }
 
 impl Drop for SpatialAudioSink {
@@ -165,13 +241,42 @@ impl Drop for SpatialAudioSink {
     }
 }
 
+impl SpatialAudioSink {
+    /// Advances the fade of the volume by `delta` unless paused, and applies `global_volume`.
+    pub(crate) fn update_volume(&self, delta: Duration, global_volume: f32) {
+        let sink = self.sink.as_ref().unwrap();
+        let delta = if sink.is_paused() {
+            Duration::ZERO
+        } else {
+            delta
+        };
+        if let Some(volume) = self.volume.lock().update(delta, global_volume) {
+            sink.set_volume(volume);
+        }
+    }
+}
+
 impl AudioSinkPlayback for SpatialAudioSink {
     fn volume(&self) -> f32 {
-        self.sink.as_ref().unwrap().volume()
+        self.volume.lock().volume
     }
 
     fn set_volume(&self, volume: f32) {
-        self.sink.as_ref().unwrap().set_volume(volume);
+        let mut sink_volume = self.volume.lock();
+        sink_volume.set_volume(volume);
+        self.sink
+            .as_ref()
+            .unwrap()
+            .set_volume(sink_volume.output_volume());
+    }
+
+    fn fade_to(&self, volume: f32, duration: Duration) {
+        let mut sink_volume = self.volume.lock();
+        sink_volume.fade_to(volume, duration);
+        self.sink
+            .as_ref()
+            .unwrap()
+            .set_volume(sink_volume.output_volume());
     }
 
     fn speed(&self) -> f32 {
@@ -197,6 +302,14 @@ impl AudioSinkPlayback for SpatialAudioSink {
     fn stop(&self) {
         self.sink.as_ref().unwrap().stop();
     }
+
+    fn position(&self) -> Duration {
+        self.position.position()
+    }
+
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError> {
+        self.position.try_seek(position)
+    }
 }
 
 impl SpatialAudioSink {
@@ -223,3 +336,83 @@ impl SpatialAudioSink {
             .set_emitter_position(position.to_array());
     }
 }
+
+impl SpatialAudioSinkPositions for SpatialAudioSink {
+    fn set_ears_position(&self, left_position: Vec3, right_position: Vec3) {
+        SpatialAudioSink::set_ears_position(self, left_position, right_position);
+    }
+
+    fn set_emitter_position(&self, position: Vec3) {
+        SpatialAudioSink::set_emitter_position(self, position);
+    }
+}
+
+/// The volume of a sink, separate from the [`GlobalVolume`](crate::GlobalVolume) it is played
+/// with.
+#[derive(Debug)]
+pub(crate) struct SinkVolume {
+    volume: f32,
+    global_volume: f32,
+    fade: Option<AudioFade>,
+}
+
+/// An ongoing fade of the volume of a sink.
+#[derive(Debug, Clone, Copy)]
+struct AudioFade {
+    from: f32,
+    to: f32,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl SinkVolume {
+    pub(crate) fn new(volume: f32, global_volume: f32) -> Self {
+        SinkVolume {
+            volume,
+            global_volume,
+            fade: None,
+        }
+    }
+
+    /// The volume the sink plays at.
+    pub(crate) fn output_volume(&self) -> f32 {
+        self.volume * self.global_volume
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+        self.fade = None;
+    }
+
+    fn fade_to(&mut self, volume: f32, duration: Duration) {
+        if duration.is_zero() {
+            self.set_volume(volume);
+        } else {
+            self.fade = Some(AudioFade {
+                from: self.volume,
+                to: volume,
+                duration,
+                elapsed: Duration::ZERO,
+            });
+        }
+    }
+
+    /// Advances the fade by `delta` and applies `global_volume`, returning the volume the sink
+    /// should play at if it changed.
+    fn update(&mut self, delta: Duration, global_volume: f32) -> Option<f32> {
+        let mut changed = self.global_volume != global_volume;
+        self.global_volume = global_volume;
+
+        if let Some(fade) = &mut self.fade {
+            fade.elapsed = (fade.elapsed + delta).min(fade.duration);
+            let progress = fade.elapsed.as_secs_f32() / fade.duration.as_secs_f32();
+            self.volume = fade.from + (fade.to - fade.from) * progress;
+            if fade.elapsed == fade.duration {
+                self.fade = None;
+            }
+            changed = true;
+        }
+
+        changed.then(|| self.output_volume())
+    }
+}
@@ -6,6 +6,7 @@ mod name;
 mod serde;
 mod task_pool_options;
 
+pub use bevy_ecs::system::NonSendMarker;
 use bevy_ecs::system::{ResMut, Resource};
 pub use bytemuck::{bytes_of, cast_slice, Pod, Zeroable};
 pub use name::*;
@@ -25,13 +26,11 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 use bevy_utils::{Duration, HashSet, Instant};
 use std::borrow::Cow;
 use std::ffi::OsString;
-use std::marker::PhantomData;
 use std::ops::Range;
 use std::path::PathBuf;
 
 #[cfg(not(target_arch = "wasm32"))]
-#[cfg(not(target_arch = "wasm32"))]
-use bevy_tasks::tick_global_task_pools_on_main_thread;
+use bevy_tasks::{tick_global_task_pools_on_main_thread, GlobalTaskPoolTickSettings};
 
 /// Registration of default types to the `TypeRegistry` resource.
 #[derive(Default)]
@@ -107,19 +106,20 @@ impl Plugin for TaskPoolPlugin {
         self.task_pool_options.create_default_pools();
 
         #[cfg(not(target_arch = "wasm32"))]
-        app.add_system(tick_global_task_pools.in_base_set(bevy_app::CoreSet::Last));
+        app.add_system(
+            tick_global_task_pools(self.task_pool_options.tick_settings.clone())
+                .in_base_set(bevy_app::CoreSet::Last),
+        );
     }
 }
-/// A dummy type that is [`!Send`](Send), to force systems to run on the main thread.
-pub struct NonSendMarker(PhantomData<*mut ()>);
 
-/// A system used to check and advanced our task pools.
+/// Creates a system used to check and advanced our task pools.
 ///
-/// Calls [`tick_global_task_pools_on_main_thread`],
+/// Calls [`tick_global_task_pools_on_main_thread`] with the given `settings`,
 /// and uses [`NonSendMarker`] to ensure that this system runs on the main thread
 #[cfg(not(target_arch = "wasm32"))]
-fn tick_global_task_pools(_main_thread_marker: Option<NonSend<NonSendMarker>>) {
-    tick_global_task_pools_on_main_thread();
+fn tick_global_task_pools(settings: GlobalTaskPoolTickSettings) -> impl FnMut(NonSendMarker) {
+    move |_main_thread_marker| tick_global_task_pools_on_main_thread(&settings)
 }
 
 /// Maintains a count of frames rendered since the start of the application.
@@ -131,10 +131,20 @@ fn tick_global_task_pools(_main_thread_marker: Option<NonSend<NonSendMarker>>) {
 ///
 /// [`FrameCount`] will wrap to 0 after exceeding [`u32::MAX`]. Within reasonable
 /// assumptions, one may exploit wrapping arithmetic to determine the number of frames
-/// that have elapsed between two observations – see [`u32::wrapping_sub()`].
-#[derive(Default, Resource, Clone, Copy)]
+/// that have elapsed between two observations – see [`FrameCount::since`].
+#[derive(Default, Resource, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FrameCount(pub u32);
 
+impl FrameCount {
+    /// Returns the number of frames elapsed from `earlier` to `self`, accounting for
+    /// [`FrameCount`] wrapping to 0.
+    ///
+    /// The result is only meaningful if fewer than [`u32::MAX`] frames have elapsed.
+    pub fn since(self, earlier: FrameCount) -> u32 {
+        self.0.wrapping_sub(earlier.0)
+    }
+}
+
 /// Adds frame counting functionality to Apps.
 #[derive(Default)]
 pub struct FrameCountPlugin;
@@ -155,6 +165,15 @@ mod tests {
     use super::*;
     use bevy_tasks::prelude::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool};
 
+    #[test]
+    fn frame_count_since() {
+        assert_eq!(FrameCount(10).since(FrameCount(3)), 7);
+        assert_eq!(FrameCount(3).since(FrameCount(3)), 0);
+        // wrapping from u32::MAX to 0 counts as a single frame
+        assert_eq!(FrameCount(0).since(FrameCount(u32::MAX)), 1);
+        assert_eq!(FrameCount(4).since(FrameCount(u32::MAX - 5)), 10);
+    }
+
     #[test]
     fn runs_spawn_local_tasks() {
         let mut app = App::new();
@@ -1,5 +1,7 @@
 use bevy_ecs::prelude::Resource;
-use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
+use bevy_tasks::{
+    AsyncComputeTaskPool, ComputeTaskPool, GlobalTaskPoolTickSettings, IoTaskPool, TaskPoolBuilder,
+};
 use bevy_utils::tracing::trace;
 
 /// Defines a simple way to determine how many threads to use given the number of remaining cores
@@ -48,6 +50,12 @@ pub struct TaskPoolOptions {
     pub async_compute: TaskPoolThreadAssignmentPolicy,
     /// Used to determine number of compute threads to allocate
     pub compute: TaskPoolThreadAssignmentPolicy,
+
+    /// If true, scopes of the compute pool borrow the threads of the async compute pool while
+    /// they are idle
+    pub compute_borrows_async_compute_threads: bool,
+    /// Limits the time spent running the local tasks of the pools on the main thread each frame
+    pub tick_settings: GlobalTaskPoolTickSettings,
 }
 
 impl Default for TaskPoolOptions {
@@ -77,6 +85,9 @@ impl Default for TaskPoolOptions {
                 max_threads: std::usize::MAX,
                 percent: 1.0, // This 1.0 here means "whatever is left over"
             },
+
+            compute_borrows_async_compute_threads: true,
+            tick_settings: GlobalTaskPoolTickSettings::default(),
         }
     }
 }
@@ -98,6 +109,8 @@ impl TaskPoolOptions {
         trace!("Assigning {} cores to default task pools", total_threads);
 
         let mut remaining_threads = total_threads;
+        let async_compute_pool;
+        let async_compute_threads;
 
         {
             // Determine the number of IO threads we will use
@@ -118,14 +131,14 @@ impl TaskPoolOptions {
 
         {
             // Determine the number of async compute threads we will use
-            let async_compute_threads = self
+            async_compute_threads = self
                 .async_compute
                 .get_number_of_threads(remaining_threads, total_threads);
 
             trace!("Async Compute Threads: {}", async_compute_threads);
             remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
 
-            AsyncComputeTaskPool::init(|| {
+            async_compute_pool = AsyncComputeTaskPool::init(|| {
                 TaskPoolBuilder::default()
                     .num_threads(async_compute_threads)
                     .thread_name("Async Compute Task Pool".to_string())
@@ -143,10 +156,14 @@ impl TaskPoolOptions {
             trace!("Compute Threads: {}", compute_threads);
 
             ComputeTaskPool::init(|| {
-                TaskPoolBuilder::default()
+                let mut builder = TaskPoolBuilder::default()
                     .num_threads(compute_threads)
-                    .thread_name("Compute Task Pool".to_string())
-                    .build()
+                    .thread_name("Compute Task Pool".to_string());
+                if self.compute_borrows_async_compute_threads {
+                    builder =
+                        builder.borrow_idle_threads(async_compute_pool, async_compute_threads);
+                }
+                builder.build()
             });
         }
     }
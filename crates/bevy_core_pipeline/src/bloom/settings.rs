@@ -1,7 +1,7 @@
 use super::downsampling_pipeline::BloomUniforms;
-use bevy_ecs::{prelude::Component, query::QueryItem};
+use bevy_ecs::{prelude::Component, query::QueryItem, reflect::ReflectComponent};
 use bevy_math::{UVec4, Vec4};
-use bevy_reflect::Reflect;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, ReflectFromReflect};
 use bevy_render::{extract_component::ExtractComponent, prelude::Camera};
 
 /// Applies a bloom effect to an HDR-enabled 2d or 3d camera.
@@ -25,7 +25,8 @@ use bevy_render::{extract_component::ExtractComponent, prelude::Camera};
 /// See <https://starlederer.github.io/bloom/> for a visualization of the parametric curve
 /// used in Bevy as well as a visualization of the curve's respective scattering profile.
 #[allow(clippy::doc_markdown)]
-#[derive(Component, Reflect, Clone)]
+#[derive(Component, Reflect, FromReflect, Clone)]
+#[reflect(Component, FromReflect, Default)]
 pub struct BloomSettings {
     /// Controls the baseline of how much the image is scattered (default: 0.15).
     ///
@@ -90,6 +91,8 @@ pub struct BloomSettings {
     /// * 1.0 - maximum scattering angle is 90 degrees
     pub high_pass_frequency: f32,
 
+    /// Thresholding applied to the rendered image before it is blurred.
+    /// See [`BloomPrefilterSettings`].
     pub prefilter_settings: BloomPrefilterSettings,
 
     /// Controls whether bloom textures
@@ -159,7 +162,8 @@ impl Default for BloomSettings {
 /// * Changing these settings creates a physically inaccurate image
 /// * Changing these settings makes it easy to make the final result look worse
 /// * Non-default prefilter settings should be used in conjuction with [`BloomCompositeMode::Additive`]
-#[derive(Default, Clone, Reflect)]
+#[derive(Default, Clone, Reflect, FromReflect)]
+#[reflect(FromReflect, Default)]
 pub struct BloomPrefilterSettings {
     /// Baseline of the quadratic threshold curve (default: 0.0).
     ///
@@ -175,9 +179,30 @@ pub struct BloomPrefilterSettings {
     pub threshold_softness: f32,
 }
 
-#[derive(Clone, Reflect, PartialEq, Eq, Hash, Copy)]
+impl BloomPrefilterSettings {
+    /// Precomputes the constants of the soft-knee threshold curve evaluated in the first
+    /// downsampling pass, see <https://catlikecoding.com/unity/tutorials/advanced-rendering/bloom/#3.4>.
+    ///
+    /// Returns `(threshold, threshold - knee, 2 * knee, 0.25 / knee)`.
+    pub(crate) fn threshold_precomputations(&self) -> Vec4 {
+        let knee = self.threshold * self.threshold_softness.clamp(0.0, 1.0);
+
+        Vec4::new(
+            self.threshold,
+            self.threshold - knee,
+            2.0 * knee,
+            0.25 / (knee + 0.00001),
+        )
+    }
+}
+
+/// How the blurred bloom textures are combined with each other and with the original image.
+#[derive(Clone, Reflect, FromReflect, PartialEq, Eq, Hash, Copy)]
+#[reflect(FromReflect, PartialEq, Hash)]
 pub enum BloomCompositeMode {
+    /// Blends the blurred image with the original one, preserving the total amount of light.
     EnergyConserving,
+    /// Adds the blurred image on top of the original one, brightening the scene.
     Additive,
 }
 
@@ -196,17 +221,10 @@ impl ExtractComponent for BloomSettings {
             camera.hdr,
         ) {
             (Some((origin, _)), Some(size), Some(target_size), true, true) => {
-                let threshold = settings.prefilter_settings.threshold;
-                let threshold_softness = settings.prefilter_settings.threshold_softness;
-                let knee = threshold * threshold_softness.clamp(0.0, 1.0);
-
                 let uniform = BloomUniforms {
-                    threshold_precomputations: Vec4::new(
-                        threshold,
-                        threshold - knee,
-                        2.0 * knee,
-                        0.25 / (knee + 0.00001),
-                    ),
+                    threshold_precomputations: settings
+                        .prefilter_settings
+                        .threshold_precomputations(),
                     viewport: UVec4::new(origin.x, origin.y, size.x, size.y).as_vec4()
                         / UVec4::new(target_size.x, target_size.y, target_size.x, target_size.y)
                             .as_vec4(),
@@ -219,3 +237,69 @@ impl ExtractComponent for BloomSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Evaluates the soft-knee curve the same way `soft_threshold` in `bloom.wgsl` does.
+    fn soft_threshold(precomputations: Vec4, brightness: f32) -> f32 {
+        let softness = (brightness - precomputations.y).clamp(0.0, precomputations.z);
+        let softness = softness * softness * precomputations.w;
+        (brightness - precomputations.x).max(softness) / brightness.max(0.00001)
+    }
+
+    #[test]
+    fn knee_precomputations() {
+        let prefilter = BloomPrefilterSettings {
+            threshold: 0.6,
+            threshold_softness: 0.5,
+        };
+        let p = prefilter.threshold_precomputations();
+        assert_eq!(p.x, 0.6);
+        assert!((p.y - 0.3).abs() < 1e-6);
+        assert!((p.z - 0.6).abs() < 1e-6);
+        assert!((p.w - 0.25 / 0.30001).abs() < 1e-4);
+
+        // Softness is clamped to [0, 1]
+        let clamped = BloomPrefilterSettings {
+            threshold: 0.6,
+            threshold_softness: 4.0,
+        }
+        .threshold_precomputations();
+        assert!(clamped.y.abs() < 1e-6);
+        assert!((clamped.z - 1.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn knee_curve_is_continuous() {
+        let p = BloomPrefilterSettings {
+            threshold: 1.0,
+            threshold_softness: 0.5,
+        }
+        .threshold_precomputations();
+
+        // Below the knee nothing contributes
+        assert_eq!(soft_threshold(p, 0.4), 0.0);
+        // Inside the knee the contribution ramps up quadratically
+        let inside = soft_threshold(p, 0.75);
+        assert!(inside > 0.0 && inside < soft_threshold(p, 1.0));
+        // The curve joins the hard threshold at the top of the knee
+        let top = 1.5;
+        assert!((soft_threshold(p, top) * top - (top - 1.0)).abs() < 1e-3);
+        // Well above the knee, brightness is only reduced by the threshold
+        assert!((soft_threshold(p, 10.0) * 10.0 - 9.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn hard_threshold_without_softness() {
+        let p = BloomPrefilterSettings {
+            threshold: 1.0,
+            threshold_softness: 0.0,
+        }
+        .threshold_precomputations();
+
+        assert_eq!(soft_threshold(p, 0.99), 0.0);
+        assert!((soft_threshold(p, 2.0) - 0.5).abs() < 1e-6);
+    }
+}
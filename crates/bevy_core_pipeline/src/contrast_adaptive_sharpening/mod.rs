@@ -0,0 +1,411 @@
+use crate::{
+    core_2d::graph::{Core2d, Node2d},
+    core_3d::graph::{Core3d, Node3d},
+    fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+};
+use bevy_app::prelude::*;
+use bevy_asset::{load_internal_asset, HandleUntyped};
+use bevy_ecs::{prelude::*, query::QueryItem};
+use bevy_reflect::{
+    std_traits::ReflectDefault, FromReflect, Reflect, ReflectFromReflect, TypeUuid,
+};
+use bevy_render::{
+    extract_component::{ExtractComponent, ExtractComponentPlugin, UniformComponentPlugin},
+    prelude::Camera,
+    render_graph::{RenderGraphApp, ViewNodeRunner},
+    render_resource::*,
+    renderer::RenderDevice,
+    texture::BevyDefault,
+    view::{ExtractedView, ViewTarget},
+    RenderApp, RenderSet,
+};
+
+mod node;
+
+pub use node::CasNode;
+
+/// Applies contrast adaptive sharpening (CAS) to a camera.
+///
+/// CAS sharpens the details softened by anti-aliasing or upscaling, such as
+/// [`Fxaa`](crate::fxaa::Fxaa) or [`TemporalAntiAliasSettings`](crate::taa::TemporalAntiAliasSettings),
+/// while limiting the sharpening of high contrast edges to avoid halos. This uses the robust
+/// variant (RCAS) of AMD FidelityFX CAS.
+///
+/// Disabling the sharpening with [`CasSettings::enabled`] skips the pass while keeping its pipeline
+/// ready, so toggling it never waits for a shader to compile.
+#[derive(Reflect, FromReflect, Component, Clone, Debug, PartialEq)]
+#[reflect(Component, FromReflect, Default)]
+pub struct CasSettings {
+    /// Enable the sharpening pass.
+    pub enabled: bool,
+
+    /// How much to sharpen, from 0.0 (no sharpening) to 1.0 (maximum sharpening).
+    ///
+    /// Values outside of this range are clamped.
+    pub sharpening_strength: f32,
+
+    /// Sharpen less in noisy areas, such as film grain or dithering, instead of amplifying the
+    /// noise.
+    pub denoise: bool,
+}
+
+impl Default for CasSettings {
+    fn default() -> Self {
+        CasSettings {
+            enabled: true,
+            sharpening_strength: 0.6,
+            denoise: false,
+        }
+    }
+}
+
+/// The [`CasSettings`] of a view as sent to the shader.
+#[derive(Component, ShaderType, Clone)]
+pub struct CasUniform {
+    sharpness: f32,
+}
+
+impl ExtractComponent for CasSettings {
+    type Query = &'static Self;
+    type Filter = With<Camera>;
+    type Out = (Self, CasUniform);
+
+    fn extract_component(settings: QueryItem<'_, Self::Query>) -> Option<Self::Out> {
+        let uniform = CasUniform {
+            sharpness: settings.sharpening_strength.clamp(0.0, 1.0),
+        };
+        Some((settings.clone(), uniform))
+    }
+}
+
+const CAS_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 6925381244141981602);
+
+/// Adds support for contrast adaptive sharpening (CAS) to 2d and 3d cameras.
+///
+/// See [`CasSettings`] for more details.
+pub struct ContrastAdaptiveSharpeningPlugin;
+
+impl Plugin for ContrastAdaptiveSharpeningPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            CAS_SHADER_HANDLE,
+            "robust_contrast_adaptive_sharpening.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.register_type::<CasSettings>()
+            .add_plugin(ExtractComponentPlugin::<CasSettings>::default())
+            .add_plugin(UniformComponentPlugin::<CasUniform>::default());
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .init_resource::<CasPipeline>()
+            .init_resource::<SpecializedRenderPipelines<CasPipeline>>()
+            .add_system(prepare_cas_pipelines.in_set(RenderSet::Prepare));
+
+        add_cas_nodes(render_app);
+    }
+}
+
+/// Adds the CAS node to the `Core3d` and `Core2d` graphs, after anti-aliasing.
+fn add_cas_nodes(render_app: &mut App) {
+    render_app
+        .add_render_graph_node::<ViewNodeRunner<CasNode>>(
+            Core3d,
+            Node3d::ContrastAdaptiveSharpening,
+        )
+        .add_render_graph_edges(
+            Core3d,
+            (
+                Node3d::Fxaa,
+                Node3d::ContrastAdaptiveSharpening,
+                Node3d::EndMainPassPostProcessing,
+            ),
+        )
+        .add_render_graph_node::<ViewNodeRunner<CasNode>>(
+            Core2d,
+            Node2d::ContrastAdaptiveSharpening,
+        )
+        .add_render_graph_edges(
+            Core2d,
+            (
+                Node2d::Fxaa,
+                Node2d::ContrastAdaptiveSharpening,
+                Node2d::EndMainPassPostProcessing,
+            ),
+        );
+}
+
+#[derive(Resource)]
+pub struct CasPipeline {
+    texture_bind_group: BindGroupLayout,
+    sampler: Sampler,
+}
+
+impl FromWorld for CasPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let render_device = render_world.resource::<RenderDevice>();
+        let texture_bind_group =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("cas_texture_bind_group_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Buffer {
+                            ty: BufferBindingType::Uniform,
+                            has_dynamic_offset: true,
+                            min_binding_size: Some(CasUniform::min_size()),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+
+        CasPipeline {
+            texture_bind_group,
+            sampler,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ViewCasPipeline(pub CachedRenderPipelineId);
+
+/// The parameters a [`CasPipeline`] is specialized on.
+///
+/// Neither [`CasSettings::enabled`] nor [`CasSettings::sharpening_strength`] is part of the key,
+/// so changing them never recompiles the pipeline.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct CasPipelineKey {
+    texture_format: TextureFormat,
+    denoise: bool,
+}
+
+impl CasPipelineKey {
+    fn new(view: &ExtractedView, settings: &CasSettings) -> Self {
+        CasPipelineKey {
+            texture_format: if view.hdr {
+                ViewTarget::TEXTURE_FORMAT_HDR
+            } else {
+                TextureFormat::bevy_default()
+            },
+            denoise: settings.denoise,
+        }
+    }
+
+    fn shader_defs(&self) -> Vec<ShaderDefVal> {
+        let mut shader_defs = Vec::new();
+        if self.denoise {
+            shader_defs.push("RCAS_DENOISE".into());
+        }
+        shader_defs
+    }
+}
+
+impl SpecializedRenderPipeline for CasPipeline {
+    type Key = CasPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        RenderPipelineDescriptor {
+            label: Some("contrast_adaptive_sharpening".into()),
+            layout: vec![self.texture_bind_group.clone()],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: CAS_SHADER_HANDLE.typed(),
+                shader_defs: key.shader_defs(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: key.texture_format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: Vec::new(),
+        }
+    }
+}
+
+/// Specializes the pipeline of every view with [`CasSettings`], including disabled ones so that
+/// enabling them doesn't wait for the pipeline to compile.
+pub fn prepare_cas_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<CasPipeline>>,
+    cas_pipeline: Res<CasPipeline>,
+    views: Query<(Entity, &ExtractedView, &CasSettings)>,
+) {
+    for (entity, view, settings) in &views {
+        let pipeline_id = pipelines.specialize(
+            &pipeline_cache,
+            &cas_pipeline,
+            CasPipelineKey::new(view, settings),
+        );
+        commands.entity(entity).insert(ViewCasPipeline(pipeline_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fxaa::add_fxaa_nodes;
+    use bevy_render::render_graph::{EmptyNode, NodeId, RenderGraph, SlotInfo, SlotType};
+    use bevy_render::render_graph::{RenderLabel, RenderSubGraph};
+
+    fn post_processing_graph(
+        tonemapping: impl RenderLabel + Copy,
+        end: impl RenderLabel + Copy,
+    ) -> RenderGraph {
+        let mut graph = RenderGraph::default();
+        graph.set_input(vec![SlotInfo::new("view_entity", SlotType::Entity)]);
+        graph.add_node(tonemapping, EmptyNode);
+        graph.add_node(end, EmptyNode);
+        graph.add_node_edge(tonemapping, end);
+        graph
+    }
+
+    fn node_outputs(
+        render_graph: &RenderGraph,
+        sub_graph: impl RenderSubGraph,
+        node: impl RenderLabel,
+    ) -> Vec<NodeId> {
+        let graph = render_graph.get_sub_graph(sub_graph.dyn_clone()).unwrap();
+        graph
+            .iter_node_outputs(node)
+            .unwrap()
+            .map(|(_, node)| node.id)
+            .collect()
+    }
+
+    fn node_id(
+        render_graph: &RenderGraph,
+        sub_graph: impl RenderSubGraph,
+        node: impl RenderLabel,
+    ) -> NodeId {
+        let graph = render_graph.get_sub_graph(sub_graph.dyn_clone()).unwrap();
+        graph.get_node_id(node).unwrap()
+    }
+
+    #[test]
+    fn sharpening_runs_after_anti_aliasing() {
+        let mut render_app = App::new();
+        let mut render_graph = RenderGraph::default();
+        render_graph.add_sub_graph(
+            Core3d,
+            post_processing_graph(Node3d::Tonemapping, Node3d::EndMainPassPostProcessing),
+        );
+        render_graph.add_sub_graph(
+            Core2d,
+            post_processing_graph(Node2d::Tonemapping, Node2d::EndMainPassPostProcessing),
+        );
+        render_app.insert_resource(render_graph);
+
+        add_fxaa_nodes(&mut render_app);
+        add_cas_nodes(&mut render_app);
+
+        let graph = render_app.world.resource::<RenderGraph>();
+        // TONEMAPPING -> FXAA -> CAS -> END_MAIN_PASS_POST_PROCESSING
+        let fxaa = node_id(graph, Core3d, Node3d::Fxaa);
+        let cas = node_id(graph, Core3d, Node3d::ContrastAdaptiveSharpening);
+        let end = node_id(graph, Core3d, Node3d::EndMainPassPostProcessing);
+        assert!(node_outputs(graph, Core3d, Node3d::Tonemapping).contains(&fxaa));
+        assert_eq!(node_outputs(graph, Core3d, Node3d::Fxaa), vec![end, cas]);
+        assert_eq!(
+            node_outputs(graph, Core3d, Node3d::ContrastAdaptiveSharpening),
+            vec![end]
+        );
+
+        let fxaa = node_id(graph, Core2d, Node2d::Fxaa);
+        let cas = node_id(graph, Core2d, Node2d::ContrastAdaptiveSharpening);
+        let end = node_id(graph, Core2d, Node2d::EndMainPassPostProcessing);
+        assert!(node_outputs(graph, Core2d, Node2d::Tonemapping).contains(&fxaa));
+        assert_eq!(node_outputs(graph, Core2d, Node2d::Fxaa), vec![end, cas]);
+        assert_eq!(
+            node_outputs(graph, Core2d, Node2d::ContrastAdaptiveSharpening),
+            vec![end]
+        );
+
+        // both nodes receive the view entity
+        for (sub_graph, node) in [
+            (Core3d.dyn_clone(), Node3d::Fxaa.dyn_clone()),
+            (
+                Core3d.dyn_clone(),
+                Node3d::ContrastAdaptiveSharpening.dyn_clone(),
+            ),
+            (Core2d.dyn_clone(), Node2d::Fxaa.dyn_clone()),
+            (
+                Core2d.dyn_clone(),
+                Node2d::ContrastAdaptiveSharpening.dyn_clone(),
+            ),
+        ] {
+            let graph = graph.get_sub_graph(sub_graph).unwrap();
+            let input_node_id = graph.input_node().id;
+            assert!(graph
+                .iter_node_inputs(node)
+                .unwrap()
+                .any(|(_, node)| node.id == input_node_id));
+        }
+    }
+
+    #[test]
+    fn only_denoise_and_format_specialize_the_pipeline() {
+        let view = |hdr| ExtractedView {
+            projection: Default::default(),
+            transform: Default::default(),
+            view_projection: None,
+            hdr,
+            viewport: Default::default(),
+            color_grading: Default::default(),
+            msaa: Default::default(),
+        };
+        let settings = CasSettings::default();
+        let key = CasPipelineKey::new(&view(false), &settings);
+
+        // disabling or tuning the sharpening reuses the same pipeline
+        let disabled = CasSettings {
+            enabled: false,
+            sharpening_strength: 0.2,
+            ..settings.clone()
+        };
+        assert_eq!(CasPipelineKey::new(&view(false), &disabled), key);
+
+        let denoised = CasPipelineKey::new(
+            &view(false),
+            &CasSettings {
+                denoise: true,
+                ..settings.clone()
+            },
+        );
+        assert_ne!(denoised, key);
+        assert!(key.shader_defs().is_empty());
+        assert_eq!(denoised.shader_defs(), vec!["RCAS_DENOISE".into()]);
+
+        let hdr = CasPipelineKey::new(&view(true), &settings);
+        assert_ne!(hdr, key);
+        assert_eq!(hdr.texture_format, ViewTarget::TEXTURE_FORMAT_HDR);
+    }
+}
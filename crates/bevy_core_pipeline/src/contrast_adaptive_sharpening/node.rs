@@ -0,0 +1,89 @@
+use crate::contrast_adaptive_sharpening::{CasPipeline, CasSettings, CasUniform, ViewCasPipeline};
+use bevy_ecs::{prelude::*, query::QueryItem};
+use bevy_render::{
+    extract_component::{ComponentUniforms, DynamicUniformIndex},
+    render_graph::{NodeRunError, RenderGraphContext, ViewNode},
+    render_resource::{
+        BindGroupDescriptor, BindGroupEntry, BindingResource, Operations, PipelineCache,
+        RenderPassColorAttachment, RenderPassDescriptor,
+    },
+    renderer::RenderContext,
+    view::ViewTarget,
+};
+
+/// Sharpens the view target of views with enabled [`CasSettings`].
+#[derive(Default)]
+pub struct CasNode;
+
+impl ViewNode for CasNode {
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static ViewCasPipeline,
+        &'static CasSettings,
+        &'static DynamicUniformIndex<CasUniform>,
+    );
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (target, pipeline, settings, uniform_index): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        if !settings.enabled || settings.sharpening_strength <= 0.0 {
+            return Ok(());
+        }
+
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let cas_pipeline = world.resource::<CasPipeline>();
+        let uniforms = world.resource::<ComponentUniforms<CasUniform>>();
+        let (Some(pipeline), Some(uniforms)) = (
+            pipeline_cache.get_render_pipeline(pipeline.0),
+            uniforms.binding(),
+        ) else {
+            return Ok(());
+        };
+
+        let post_process = target.post_process_write();
+        let bind_group = render_context
+            .render_device()
+            .create_bind_group(&BindGroupDescriptor {
+                label: Some("cas_bind_group"),
+                layout: &cas_pipeline.texture_bind_group,
+                entries: &[
+                    BindGroupEntry {
+                        binding: 0,
+                        resource: BindingResource::TextureView(post_process.source),
+                    },
+                    BindGroupEntry {
+                        binding: 1,
+                        resource: BindingResource::Sampler(&cas_pipeline.sampler),
+                    },
+                    BindGroupEntry {
+                        binding: 2,
+                        resource: uniforms,
+                    },
+                ],
+            });
+
+        let pass_descriptor = RenderPassDescriptor {
+            label: Some("contrast_adaptive_sharpening_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+        };
+
+        let mut render_pass = render_context
+            .command_encoder()
+            .begin_render_pass(&pass_descriptor);
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[uniform_index.index()]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Robust Contrast Adaptive Sharpening (RCAS)
+// Based on the RCAS pass of AMD FidelityFX Super Resolution 1.0
+// https://github.com/GPUOpen-Effects/FidelityFX-FSR/blob/master/ffx-fsr/ffx_fsr1.h
+//
+// Sharpens each pixel with a lobe made of its four direct neighbours, limited so that the result
+// never clips outside of the range of the neighbourhood.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader
+
+struct CasUniform {
+    // The weight of the sharpening lobe, from 0.0 (none) to 1.0 (maximum).
+    sharpness: f32,
+};
+
+@group(0) @binding(0)
+var screen_texture: texture_2d<f32>;
+@group(0) @binding(1)
+var samp: sampler;
+@group(0) @binding(2)
+var<uniform> uniforms: CasUniform;
+
+// The maximum negative weight of the lobe, keeping the resolve stable.
+const RCAS_LIMIT: f32 = 0.1875;
+const EPSILON: f32 = 0.0001;
+
+// Luma times 2.
+fn luma(color: vec3<f32>) -> f32 {
+    return color.b * 0.5 + (color.r * 0.5 + color.g);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Sampling pattern:
+    //    b
+    //  d e f
+    //    h
+    let b = textureSample(screen_texture, samp, in.uv, vec2<i32>(0, -1)).rgb;
+    let d = textureSample(screen_texture, samp, in.uv, vec2<i32>(-1, 0)).rgb;
+    let center = textureSample(screen_texture, samp, in.uv);
+    let e = center.rgb;
+    let f = textureSample(screen_texture, samp, in.uv, vec2<i32>(1, 0)).rgb;
+    let h = textureSample(screen_texture, samp, in.uv, vec2<i32>(0, 1)).rgb;
+
+    // Min and max of the ring.
+    let mn4 = min(min(b, d), min(f, h));
+    let mx4 = max(max(b, d), max(f, h));
+
+    // Limiters: the largest lobe weights that don't clip below 0.0 or above 1.0.
+    let peak_c = vec2<f32>(1.0, -4.0);
+    let hit_min = min(mn4, e) / max(4.0 * mx4, vec3<f32>(EPSILON));
+    let hit_max = (peak_c.x - max(mx4, e)) / min(4.0 * mn4 + peak_c.y, vec3<f32>(-EPSILON));
+    let lobe_rgb = max(-hit_min, hit_max);
+    var lobe = max(-RCAS_LIMIT, min(max(lobe_rgb.r, max(lobe_rgb.g, lobe_rgb.b)), 0.0)) * uniforms.sharpness;
+
+#ifdef RCAS_DENOISE
+    // Reduce the sharpening of noisy areas, where the center stands out of its neighbourhood.
+    let b_l = luma(b);
+    let d_l = luma(d);
+    let e_l = luma(e);
+    let f_l = luma(f);
+    let h_l = luma(h);
+    let range = max(max(b_l, d_l), max(e_l, max(f_l, h_l))) - min(min(b_l, d_l), min(e_l, min(f_l, h_l)));
+    var noise = 0.25 * (b_l + d_l + f_l + h_l) - e_l;
+    noise = clamp(abs(noise) / max(range, EPSILON), 0.0, 1.0);
+    lobe *= -0.5 * noise + 1.0;
+#endif
+
+    // Resolve by normalizing the total weight.
+    let color = (lobe * (b + d + f + h) + e) / (4.0 * lobe + 1.0);
+    return vec4<f32>(color, center.a);
+}
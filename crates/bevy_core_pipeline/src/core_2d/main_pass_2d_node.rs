@@ -1,6 +1,6 @@
 use crate::{
     clear_color::{ClearColor, ClearColorConfig},
-    core_2d::{camera_2d::Camera2d, Transparent2d},
+    core_2d::{camera_2d::Camera2d, Opaque2d, Transparent2d},
 };
 use bevy_ecs::prelude::*;
 use bevy_render::{
@@ -15,9 +15,11 @@ use bevy_render::{
 use bevy_utils::tracing::info_span;
 
 pub struct MainPass2dNode {
+    #[allow(clippy::type_complexity)]
     query: QueryState<
         (
             &'static ExtractedCamera,
+            &'static RenderPhase<Opaque2d>,
             &'static RenderPhase<Transparent2d>,
             &'static ViewTarget,
             &'static Camera2d,
@@ -52,7 +54,7 @@ impl Node for MainPass2dNode {
         world: &World,
     ) -> Result<(), NodeRunError> {
         let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
-        let (camera, transparent_phase, target, camera_2d) =
+        let (camera, opaque_phase, transparent_phase, target, camera_2d) =
             if let Ok(result) = self.query.get_manual(world, view_entity) {
                 result
             } else {
@@ -82,6 +84,9 @@ impl Node for MainPass2dNode {
                 render_pass.set_camera_viewport(viewport);
             }
 
+            // Without a depth buffer, drawing the opaque items first keeps them below the
+            // transparent items
+            opaque_phase.render(&mut render_pass, world, view_entity);
             transparent_phase.render(&mut render_pass, world, view_entity);
         }
 
@@ -2,18 +2,40 @@ mod camera_2d;
 mod main_pass_2d_node;
 
 pub mod graph {
-    pub const NAME: &str = "core_2d";
+    use bevy_render::render_graph::{RenderLabel, RenderSubGraph};
+
+    /// The label of the sub graph rendering 2d cameras.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderSubGraph)]
+    pub struct Core2d;
+
+    /// The labels of the nodes of the [`Core2d`] sub graph.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderLabel)]
+    pub enum Node2d {
+        MsaaWriteback,
+        MainPass,
+        Bloom,
+        Tonemapping,
+        Fxaa,
+        ContrastAdaptiveSharpening,
+        Upscaling,
+        EndMainPassPostProcessing,
+    }
+
+    pub const NAME: Core2d = Core2d;
     pub mod input {
         pub const VIEW_ENTITY: &str = "view_entity";
     }
     pub mod node {
-        pub const MSAA_WRITEBACK: &str = "msaa_writeback";
-        pub const MAIN_PASS: &str = "main_pass";
-        pub const BLOOM: &str = "bloom";
-        pub const TONEMAPPING: &str = "tonemapping";
-        pub const FXAA: &str = "fxaa";
-        pub const UPSCALING: &str = "upscaling";
-        pub const END_MAIN_PASS_POST_PROCESSING: &str = "end_main_pass_post_processing";
+        use super::Node2d;
+
+        pub const MSAA_WRITEBACK: Node2d = Node2d::MsaaWriteback;
+        pub const MAIN_PASS: Node2d = Node2d::MainPass;
+        pub const BLOOM: Node2d = Node2d::Bloom;
+        pub const TONEMAPPING: Node2d = Node2d::Tonemapping;
+        pub const FXAA: Node2d = Node2d::Fxaa;
+        pub const CONTRAST_ADAPTIVE_SHARPENING: Node2d = Node2d::ContrastAdaptiveSharpening;
+        pub const UPSCALING: Node2d = Node2d::Upscaling;
+        pub const END_MAIN_PASS_POST_PROCESSING: Node2d = Node2d::EndMainPassPostProcessing;
     }
 }
 
@@ -51,9 +73,14 @@ impl Plugin for Core2dPlugin {
         };
 
         render_app
+            .init_resource::<DrawFunctions<Opaque2d>>()
             .init_resource::<DrawFunctions<Transparent2d>>()
             .add_systems((
                 extract_core_2d_camera_phases.in_schedule(ExtractSchedule),
+                sort_phase_system::<Opaque2d>.in_set(RenderSet::PhaseSort),
+                batch_phase_system::<Opaque2d>
+                    .after(sort_phase_system::<Opaque2d>)
+                    .in_set(RenderSet::PhaseSort),
                 sort_phase_system::<Transparent2d>.in_set(RenderSet::PhaseSort),
                 batch_phase_system::<Transparent2d>
                     .after(sort_phase_system::<Transparent2d>)
@@ -105,8 +132,75 @@ impl Plugin for Core2dPlugin {
     }
 }
 
+/// A 2d item drawn without blending, like the meshes of 2d materials with an opaque or masked
+/// `AlphaMode2d` from `bevy_sprite`.
+///
+/// There is no depth buffer in 2d, so these items are still drawn back-to-front, and their phase is
+/// drawn before the [`Transparent2d`] phase: opaque items are always drawn below transparent items,
+/// whatever their z position. Items at the same z position are grouped by pipeline, so that they can
+/// be drawn without switching pipelines, and batched.
+pub struct Opaque2d {
+    pub sort_key: FloatOrd,
+    /// Orders this item instead of `sort_key` when set, which then only orders the items with
+    /// the same draw order. This is the `DrawOrder2d` of sprites and 2d meshes in `bevy_sprite`.
+    pub draw_order: Option<FloatOrd>,
+    pub entity: Entity,
+    pub pipeline: CachedRenderPipelineId,
+    pub draw_function: DrawFunctionId,
+    /// Range in the vertex buffer of this item
+    pub batch_range: Option<Range<u32>>,
+}
+
+impl PhaseItem for Opaque2d {
+    type SortKey = (FloatOrd, FloatOrd, usize);
+
+    #[inline]
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    #[inline]
+    fn sort_key(&self) -> Self::SortKey {
+        (
+            self.draw_order.unwrap_or(self.sort_key),
+            self.sort_key,
+            self.pipeline.id(),
+        )
+    }
+
+    #[inline]
+    fn draw_function(&self) -> DrawFunctionId {
+        self.draw_function
+    }
+
+    #[inline]
+    fn sort(items: &mut [Self]) {
+        items.sort_by_key(|item| item.sort_key());
+    }
+}
+
+impl CachedRenderPipelinePhaseItem for Opaque2d {
+    #[inline]
+    fn cached_pipeline(&self) -> CachedRenderPipelineId {
+        self.pipeline
+    }
+}
+
+impl BatchedPhaseItem for Opaque2d {
+    fn batch_range(&self) -> &Option<Range<u32>> {
+        &self.batch_range
+    }
+
+    fn batch_range_mut(&mut self) -> &mut Option<Range<u32>> {
+        &mut self.batch_range
+    }
+}
+
 pub struct Transparent2d {
     pub sort_key: FloatOrd,
+    /// Orders this item instead of `sort_key` when set, which then only orders the items with
+    /// the same draw order. This is the `DrawOrder2d` of sprites and 2d meshes in `bevy_sprite`.
+    pub draw_order: Option<FloatOrd>,
     pub entity: Entity,
     pub pipeline: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
@@ -115,7 +209,7 @@ pub struct Transparent2d {
 }
 
 impl PhaseItem for Transparent2d {
-    type SortKey = FloatOrd;
+    type SortKey = (FloatOrd, FloatOrd);
 
     #[inline]
     fn entity(&self) -> Entity {
@@ -124,7 +218,7 @@ impl PhaseItem for Transparent2d {
 
     #[inline]
     fn sort_key(&self) -> Self::SortKey {
-        self.sort_key
+        (self.draw_order.unwrap_or(self.sort_key), self.sort_key)
     }
 
     #[inline]
@@ -161,9 +255,159 @@ pub fn extract_core_2d_camera_phases(
 ) {
     for (entity, camera) in &cameras_2d {
         if camera.is_active {
-            commands
-                .get_or_spawn(entity)
-                .insert(RenderPhase::<Transparent2d>::default());
+            commands.get_or_spawn(entity).insert((
+                RenderPhase::<Opaque2d>::default(),
+                RenderPhase::<Transparent2d>::default(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_render::{
+        render_phase::{Draw, TrackedRenderPass},
+        MainWorld,
+    };
+
+    struct NoDraw;
+
+    impl<P: PhaseItem> Draw<P> for NoDraw {
+        fn draw<'w>(
+            &mut self,
+            _world: &'w World,
+            _pass: &mut TrackedRenderPass<'w>,
+            _view: Entity,
+            _item: &P,
+        ) {
         }
     }
+
+    fn opaque_item(entity: Entity, z: f32, batch_range: Range<u32>) -> Opaque2d {
+        Opaque2d {
+            sort_key: FloatOrd(z),
+            draw_order: None,
+            entity,
+            pipeline: CachedRenderPipelineId::INVALID,
+            draw_function: DrawFunctions::<Opaque2d>::default().write().add(NoDraw),
+            batch_range: Some(batch_range),
+        }
+    }
+
+    fn transparent_item(
+        entity: Entity,
+        z: f32,
+        draw_order: Option<f32>,
+        batch_range: Range<u32>,
+    ) -> Transparent2d {
+        Transparent2d {
+            sort_key: FloatOrd(z),
+            draw_order: draw_order.map(FloatOrd),
+            entity,
+            pipeline: CachedRenderPipelineId::INVALID,
+            draw_function: DrawFunctions::<Transparent2d>::default()
+                .write()
+                .add(NoDraw),
+            batch_range: Some(batch_range),
+        }
+    }
+
+    #[test]
+    fn active_2d_cameras_get_both_phases() {
+        let mut main_world = MainWorld::default();
+        let active = main_world
+            .spawn((Camera::default(), Camera2d::default()))
+            .id();
+        let inactive = main_world
+            .spawn((
+                Camera {
+                    is_active: false,
+                    ..Default::default()
+                },
+                Camera2d::default(),
+            ))
+            .id();
+
+        let mut render_world = World::new();
+        render_world.insert_resource(main_world);
+        let mut system = IntoSystem::into_system(extract_core_2d_camera_phases);
+        system.initialize(&mut render_world);
+        system.run((), &mut render_world);
+        system.apply_buffers(&mut render_world);
+
+        let active = render_world.entity(active);
+        assert!(active.contains::<RenderPhase<Opaque2d>>());
+        assert!(active.contains::<RenderPhase<Transparent2d>>());
+        assert!(render_world.get_entity(inactive).is_none());
+    }
+
+    #[test]
+    fn opaque_items_are_drawn_back_to_front() {
+        let mut phase = RenderPhase::<Opaque2d>::default();
+        for (index, z) in [2.0, -1.0, 0.5].into_iter().enumerate() {
+            phase.add(opaque_item(Entity::from_raw(index as u32), z, 0..1));
+        }
+        phase.sort();
+        let sort_keys: Vec<_> = phase.items.iter().map(|item| item.sort_key.0).collect();
+        assert_eq!(sort_keys, [-1.0, 0.5, 2.0]);
+    }
+
+    #[test]
+    fn opaque_items_batch_like_transparent_items() {
+        let batched = Entity::from_raw(0);
+        let other = Entity::from_raw(1);
+        let mut phase = RenderPhase::<Opaque2d>::default();
+        phase.add(opaque_item(batched, 0.0, 0..4));
+        phase.add(opaque_item(batched, 0.0, 4..8));
+        phase.add(opaque_item(other, 0.0, 8..12));
+        phase.batch();
+        let batches: Vec<_> = phase
+            .items
+            .iter()
+            .map(|item| (item.entity, item.batch_range.clone()))
+            .collect();
+        assert_eq!(batches, [(batched, Some(0..8)), (other, Some(8..12))]);
+    }
+
+    #[test]
+    fn transparent_items_are_sorted_by_draw_order_then_z() {
+        let mut phase = RenderPhase::<Transparent2d>::default();
+        // The draw order wins over conflicting z positions, and ties are broken by z
+        let items = [
+            (10.0, Some(-1.0)),
+            (-5.0, Some(2.0)),
+            (3.0, Some(2.0)),
+            (0.0, None),
+        ];
+        for (index, (z, draw_order)) in items.into_iter().enumerate() {
+            phase.add(transparent_item(
+                Entity::from_raw(index as u32),
+                z,
+                draw_order,
+                0..1,
+            ));
+        }
+        phase.sort();
+        let entities: Vec<_> = phase.items.iter().map(|item| item.entity.index()).collect();
+        assert_eq!(entities, [0, 3, 1, 2]);
+    }
+
+    #[test]
+    fn transparent_items_with_equal_draw_orders_batch() {
+        let batched = Entity::from_raw(0);
+        let other = Entity::from_raw(1);
+        let mut phase = RenderPhase::<Transparent2d>::default();
+        phase.add(transparent_item(batched, 0.0, Some(1.0), 0..4));
+        phase.add(transparent_item(batched, 0.0, Some(1.0), 4..8));
+        phase.add(transparent_item(other, 5.0, Some(1.0), 8..12));
+        phase.sort();
+        phase.batch();
+        let batches: Vec<_> = phase
+            .items
+            .iter()
+            .map(|item| (item.entity, item.batch_range.clone()))
+            .collect();
+        assert_eq!(batches, [(batched, Some(0..8)), (other, Some(8..12))]);
+    }
 }
@@ -2,33 +2,61 @@ mod camera_3d;
 mod main_pass_3d_node;
 
 pub mod graph {
-    pub const NAME: &str = "core_3d";
+    use bevy_render::render_graph::{RenderLabel, RenderSubGraph};
+
+    /// The label of the sub graph rendering 3d cameras.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderSubGraph)]
+    pub struct Core3d;
+
+    /// The labels of the nodes of the [`Core3d`] sub graph.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderLabel)]
+    pub enum Node3d {
+        MsaaWriteback,
+        Prepass,
+        MainPass,
+        OitResolve,
+        Taa,
+        Bloom,
+        Tonemapping,
+        Fxaa,
+        ContrastAdaptiveSharpening,
+        Upscaling,
+        EndMainPassPostProcessing,
+    }
+
+    pub const NAME: Core3d = Core3d;
     pub mod input {
         pub const VIEW_ENTITY: &str = "view_entity";
     }
     pub mod node {
-        pub const MSAA_WRITEBACK: &str = "msaa_writeback";
-        pub const PREPASS: &str = "prepass";
-        pub const MAIN_PASS: &str = "main_pass";
-        pub const BLOOM: &str = "bloom";
-        pub const TONEMAPPING: &str = "tonemapping";
-        pub const FXAA: &str = "fxaa";
-        pub const UPSCALING: &str = "upscaling";
-        pub const END_MAIN_PASS_POST_PROCESSING: &str = "end_main_pass_post_processing";
+        use super::Node3d;
+
+        pub const MSAA_WRITEBACK: Node3d = Node3d::MsaaWriteback;
+        pub const PREPASS: Node3d = Node3d::Prepass;
+        pub const MAIN_PASS: Node3d = Node3d::MainPass;
+        pub const OIT_RESOLVE: Node3d = Node3d::OitResolve;
+        pub const TAA: Node3d = Node3d::Taa;
+        pub const BLOOM: Node3d = Node3d::Bloom;
+        pub const TONEMAPPING: Node3d = Node3d::Tonemapping;
+        pub const FXAA: Node3d = Node3d::Fxaa;
+        pub const CONTRAST_ADAPTIVE_SHARPENING: Node3d = Node3d::ContrastAdaptiveSharpening;
+        pub const UPSCALING: Node3d = Node3d::Upscaling;
+        pub const END_MAIN_PASS_POST_PROCESSING: Node3d = Node3d::EndMainPassPostProcessing;
     }
 }
 
-use std::cmp::Reverse;
+use std::{cmp::Reverse, ops::Range};
 
 pub use camera_3d::*;
 pub use main_pass_3d_node::*;
 
 use bevy_app::{App, IntoSystemAppConfig, Plugin};
+use bevy_asset::HandleId;
 use bevy_ecs::prelude::*;
 use bevy_render::{
+    batching::InstancedPhaseItem,
     camera::{Camera, ExtractedCamera},
     extract_component::ExtractComponentPlugin,
-    prelude::Msaa,
     render_graph::{EmptyNode, RenderGraph, SlotInfo, SlotType},
     render_phase::{
         sort_phase_system, CachedRenderPipelinePhaseItem, DrawFunctionId, DrawFunctions, PhaseItem,
@@ -40,7 +68,7 @@ use bevy_render::{
     },
     renderer::RenderDevice,
     texture::TextureCache,
-    view::ViewDepthTexture,
+    view::{ExtractedView, ViewDepthTexture},
     Extract, ExtractSchedule, RenderApp, RenderSet,
 };
 use bevy_utils::{FloatOrd, HashMap};
@@ -137,12 +165,17 @@ pub struct Opaque3d {
     pub distance: f32,
     pub pipeline: CachedRenderPipelineId,
     pub entity: Entity,
+    pub mesh: HandleId,
     pub draw_function: DrawFunctionId,
+    pub instance_range: Range<u32>,
+    pub dynamic_offset: Option<u32>,
 }
 
 impl PhaseItem for Opaque3d {
-    // NOTE: Values increase towards the camera. Front-to-back ordering for opaque means we need a descending sort.
-    type SortKey = Reverse<FloatOrd>;
+    // NOTE: Items are grouped by pipeline and mesh so that automatic batching can draw them
+    // as instances, and are then ordered front-to-back within each group.
+    // Values increase towards the camera. Front-to-back ordering for opaque means we need a descending sort.
+    type SortKey = (usize, HandleId, Reverse<FloatOrd>);
 
     #[inline]
     fn entity(&self) -> Entity {
@@ -151,19 +184,17 @@ impl PhaseItem for Opaque3d {
 
     #[inline]
     fn sort_key(&self) -> Self::SortKey {
-        Reverse(FloatOrd(self.distance))
+        (
+            self.pipeline.id(),
+            self.mesh,
+            Reverse(FloatOrd(self.distance)),
+        )
     }
 
     #[inline]
     fn draw_function(&self) -> DrawFunctionId {
         self.draw_function
     }
-
-    #[inline]
-    fn sort(items: &mut [Self]) {
-        // Key negated to match reversed SortKey ordering
-        radsort::sort_by_key(items, |item| -item.distance);
-    }
 }
 
 impl CachedRenderPipelinePhaseItem for Opaque3d {
@@ -173,16 +204,43 @@ impl CachedRenderPipelinePhaseItem for Opaque3d {
     }
 }
 
+impl InstancedPhaseItem for Opaque3d {
+    #[inline]
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    #[inline]
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    #[inline]
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    #[inline]
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
+
 pub struct AlphaMask3d {
     pub distance: f32,
     pub pipeline: CachedRenderPipelineId,
     pub entity: Entity,
+    pub mesh: HandleId,
     pub draw_function: DrawFunctionId,
+    pub instance_range: Range<u32>,
+    pub dynamic_offset: Option<u32>,
 }
 
 impl PhaseItem for AlphaMask3d {
-    // NOTE: Values increase towards the camera. Front-to-back ordering for alpha mask means we need a descending sort.
-    type SortKey = Reverse<FloatOrd>;
+    // NOTE: Items are grouped by pipeline and mesh so that automatic batching can draw them
+    // as instances, and are then ordered front-to-back within each group.
+    // Values increase towards the camera. Front-to-back ordering for alpha mask means we need a descending sort.
+    type SortKey = (usize, HandleId, Reverse<FloatOrd>);
 
     #[inline]
     fn entity(&self) -> Entity {
@@ -191,19 +249,17 @@ impl PhaseItem for AlphaMask3d {
 
     #[inline]
     fn sort_key(&self) -> Self::SortKey {
-        Reverse(FloatOrd(self.distance))
+        (
+            self.pipeline.id(),
+            self.mesh,
+            Reverse(FloatOrd(self.distance)),
+        )
     }
 
     #[inline]
     fn draw_function(&self) -> DrawFunctionId {
         self.draw_function
     }
-
-    #[inline]
-    fn sort(items: &mut [Self]) {
-        // Key negated to match reversed SortKey ordering
-        radsort::sort_by_key(items, |item| -item.distance);
-    }
 }
 
 impl CachedRenderPipelinePhaseItem for AlphaMask3d {
@@ -213,11 +269,35 @@ impl CachedRenderPipelinePhaseItem for AlphaMask3d {
     }
 }
 
+impl InstancedPhaseItem for AlphaMask3d {
+    #[inline]
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    #[inline]
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    #[inline]
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    #[inline]
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
+
 pub struct Transparent3d {
     pub distance: f32,
     pub pipeline: CachedRenderPipelineId,
     pub entity: Entity,
     pub draw_function: DrawFunctionId,
+    pub instance_range: Range<u32>,
+    pub dynamic_offset: Option<u32>,
 }
 
 impl PhaseItem for Transparent3d {
@@ -252,6 +332,28 @@ impl CachedRenderPipelinePhaseItem for Transparent3d {
     }
 }
 
+impl InstancedPhaseItem for Transparent3d {
+    #[inline]
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    #[inline]
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    #[inline]
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    #[inline]
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
+
 pub fn extract_core_3d_camera_phases(
     mut commands: Commands,
     cameras_3d: Extract<Query<(Entity, &Camera), With<Camera3d>>>,
@@ -270,10 +372,14 @@ pub fn extract_core_3d_camera_phases(
 pub fn prepare_core_3d_depth_textures(
     mut commands: Commands,
     mut texture_cache: ResMut<TextureCache>,
-    msaa: Res<Msaa>,
     render_device: Res<RenderDevice>,
     views_3d: Query<
-        (Entity, &ExtractedCamera, Option<&DepthPrepass>),
+        (
+            Entity,
+            &ExtractedCamera,
+            &ExtractedView,
+            Option<&DepthPrepass>,
+        ),
         (
             With<RenderPhase<Opaque3d>>,
             With<RenderPhase<AlphaMask3d>>,
@@ -282,13 +388,13 @@ pub fn prepare_core_3d_depth_textures(
     >,
 ) {
     let mut textures = HashMap::default();
-    for (entity, camera, depth_prepass) in &views_3d {
+    for (entity, camera, view, depth_prepass) in &views_3d {
         let Some(physical_target_size) = camera.physical_target_size else {
             continue;
         };
 
         let cached_texture = textures
-            .entry(camera.target.clone())
+            .entry((camera.target.clone(), view.msaa))
             .or_insert_with(|| {
                 // Default usage required to write to the depth texture
                 let mut usage = TextureUsages::RENDER_ATTACHMENT;
@@ -308,7 +414,7 @@ pub fn prepare_core_3d_depth_textures(
                     label: Some("view_depth_texture"),
                     size,
                     mip_level_count: 1,
-                    sample_count: msaa.samples(),
+                    sample_count: view.msaa.samples(),
                     dimension: TextureDimension::D2,
                     // PERF: vulkan docs recommend using 24 bit depth for better performance
                     format: TextureFormat::Depth32Float,
@@ -326,3 +432,23 @@ pub fn prepare_core_3d_depth_textures(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{graph, Camera3dBundle};
+    use bevy_render::render_graph::{RenderGraph, RenderSubGraph};
+
+    #[test]
+    fn camera_render_graph_matches_core_3d_sub_graph() {
+        let mut render_graph = RenderGraph::default();
+        render_graph.add_sub_graph(graph::Core3d, RenderGraph::default());
+
+        // The label is boxed by `bevy_render`, but still names the sub graph added here.
+        let camera_render_graph = Camera3dBundle::default().camera_render_graph;
+        assert_eq!(&*camera_render_graph.dyn_clone(), &*graph::NAME.dyn_clone());
+        assert!(render_graph
+            .get_sub_graph(camera_render_graph.dyn_clone())
+            .is_some());
+        assert!(render_graph.get_sub_graph("core_3d").is_none());
+    }
+}
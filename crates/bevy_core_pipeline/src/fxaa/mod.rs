@@ -9,7 +9,7 @@ use bevy_reflect::{
 use bevy_render::{
     extract_component::{ExtractComponent, ExtractComponentPlugin},
     prelude::Camera,
-    render_graph::RenderGraph,
+    render_graph::RenderGraphApp,
     render_resource::*,
     renderer::RenderDevice,
     texture::BevyDefault,
@@ -48,6 +48,9 @@ impl Sensitivity {
 #[extract_component_filter(With<Camera>)]
 pub struct Fxaa {
     /// Enable render passes for FXAA.
+    ///
+    /// The pipeline of a disabled [`Fxaa`] is still prepared, so enabling it again doesn't wait
+    /// for the shader to compile.
     pub enabled: bool,
 
     /// Use lower sensitivity for a sharper, faster, result.
@@ -92,53 +95,31 @@ impl Plugin for FxaaPlugin {
             .init_resource::<SpecializedRenderPipelines<FxaaPipeline>>()
             .add_system(prepare_fxaa_pipelines.in_set(RenderSet::Prepare));
 
-        {
-            let fxaa_node = FxaaNode::new(&mut render_app.world);
-            let mut binding = render_app.world.resource_mut::<RenderGraph>();
-            let graph = binding.get_sub_graph_mut(core_3d::graph::NAME).unwrap();
-
-            graph.add_node(core_3d::graph::node::FXAA, fxaa_node);
-
-            graph.add_slot_edge(
-                graph.input_node().id,
-                core_3d::graph::input::VIEW_ENTITY,
-                core_3d::graph::node::FXAA,
-                FxaaNode::IN_VIEW,
-            );
+        add_fxaa_nodes(render_app);
+    }
+}
 
-            graph.add_node_edge(
+/// Adds the FXAA node to the `Core3d` and `Core2d` graphs, after tonemapping.
+pub(crate) fn add_fxaa_nodes(render_app: &mut App) {
+    render_app
+        .add_render_graph_node::<FxaaNode>(core_3d::graph::NAME, core_3d::graph::node::FXAA)
+        .add_render_graph_edges(
+            core_3d::graph::NAME,
+            (
                 core_3d::graph::node::TONEMAPPING,
                 core_3d::graph::node::FXAA,
-            );
-            graph.add_node_edge(
-                core_3d::graph::node::FXAA,
                 core_3d::graph::node::END_MAIN_PASS_POST_PROCESSING,
-            );
-        }
-        {
-            let fxaa_node = FxaaNode::new(&mut render_app.world);
-            let mut binding = render_app.world.resource_mut::<RenderGraph>();
-            let graph = binding.get_sub_graph_mut(core_2d::graph::NAME).unwrap();
-
-            graph.add_node(core_2d::graph::node::FXAA, fxaa_node);
-
-            graph.add_slot_edge(
-                graph.input_node().id,
-                core_2d::graph::input::VIEW_ENTITY,
-                core_2d::graph::node::FXAA,
-                FxaaNode::IN_VIEW,
-            );
-
-            graph.add_node_edge(
+            ),
+        )
+        .add_render_graph_node::<FxaaNode>(core_2d::graph::NAME, core_2d::graph::node::FXAA)
+        .add_render_graph_edges(
+            core_2d::graph::NAME,
+            (
                 core_2d::graph::node::TONEMAPPING,
                 core_2d::graph::node::FXAA,
-            );
-            graph.add_node_edge(
-                core_2d::graph::node::FXAA,
                 core_2d::graph::node::END_MAIN_PASS_POST_PROCESSING,
-            );
-        }
-    }
+            ),
+        );
 }
 
 #[derive(Resource, Deref)]
@@ -225,9 +206,6 @@ pub fn prepare_fxaa_pipelines(
     views: Query<(Entity, &ExtractedView, &Fxaa)>,
 ) {
     for (entity, view, fxaa) in &views {
-        if !fxaa.enabled {
-            continue;
-        }
         let pipeline_id = pipelines.specialize(
             &pipeline_cache,
             &fxaa_pipeline,
@@ -38,6 +38,12 @@ impl FxaaNode {
     }
 }
 
+impl FromWorld for FxaaNode {
+    fn from_world(world: &mut World) -> Self {
+        Self::new(world)
+    }
+}
+
 impl Node for FxaaNode {
     fn input(&self) -> Vec<SlotInfo> {
         vec![SlotInfo::new(FxaaNode::IN_VIEW, SlotType::Entity)]
@@ -1,12 +1,15 @@
 pub mod blit;
 pub mod bloom;
 pub mod clear_color;
+pub mod contrast_adaptive_sharpening;
 pub mod core_2d;
 pub mod core_3d;
 pub mod fullscreen_vertex_shader;
 pub mod fxaa;
 pub mod msaa_writeback;
+pub mod oit;
 pub mod prepass;
+pub mod taa;
 pub mod tonemapping;
 pub mod upscaling;
 
@@ -23,12 +26,14 @@ use crate::{
     blit::BlitPlugin,
     bloom::BloomPlugin,
     clear_color::{ClearColor, ClearColorConfig},
+    contrast_adaptive_sharpening::ContrastAdaptiveSharpeningPlugin,
     core_2d::Core2dPlugin,
     core_3d::Core3dPlugin,
     fullscreen_vertex_shader::FULLSCREEN_SHADER_HANDLE,
     fxaa::FxaaPlugin,
     msaa_writeback::MsaaWritebackPlugin,
-    prepass::{DepthPrepass, NormalPrepass},
+    oit::OrderIndependentTransparencyPlugin,
+    prepass::{DepthPrepass, MotionVectorPrepass, NormalPrepass},
     tonemapping::TonemappingPlugin,
     upscaling::UpscalingPlugin,
 };
@@ -52,6 +57,7 @@ impl Plugin for CorePipelinePlugin {
             .register_type::<ClearColorConfig>()
             .register_type::<DepthPrepass>()
             .register_type::<NormalPrepass>()
+            .register_type::<MotionVectorPrepass>()
             .init_resource::<ClearColor>()
             .add_plugin(ExtractResourcePlugin::<ClearColor>::default())
             .add_plugin(Core2dPlugin)
@@ -61,6 +67,8 @@ impl Plugin for CorePipelinePlugin {
             .add_plugin(TonemappingPlugin)
             .add_plugin(UpscalingPlugin)
             .add_plugin(BloomPlugin)
-            .add_plugin(FxaaPlugin);
+            .add_plugin(OrderIndependentTransparencyPlugin)
+            .add_plugin(FxaaPlugin)
+            .add_plugin(ContrastAdaptiveSharpeningPlugin);
     }
 }
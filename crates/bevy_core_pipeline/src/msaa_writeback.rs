@@ -5,7 +5,7 @@ use bevy_render::{
     camera::ExtractedCamera,
     render_graph::{Node, NodeRunError, RenderGraph, RenderGraphContext, SlotInfo, SlotType},
     renderer::RenderContext,
-    view::{Msaa, ViewTarget},
+    view::{ExtractedView, ViewTarget},
     RenderSet,
 };
 use bevy_render::{render_resource::*, RenderApp};
@@ -17,7 +17,7 @@ pub struct MsaaWritebackPlugin;
 impl Plugin for MsaaWritebackPlugin {
     fn build(&self, app: &mut App) {
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
-            return
+            return;
         };
 
         render_app.add_system(queue_msaa_writeback_pipelines.in_set(RenderSet::Queue));
@@ -152,17 +152,18 @@ fn queue_msaa_writeback_pipelines(
     pipeline_cache: Res<PipelineCache>,
     mut pipelines: ResMut<SpecializedRenderPipelines<BlitPipeline>>,
     blit_pipeline: Res<BlitPipeline>,
-    view_targets: Query<(Entity, &ViewTarget, &ExtractedCamera)>,
-    msaa: Res<Msaa>,
+    view_targets: Query<(Entity, &ViewTarget, &ExtractedCamera, &ExtractedView)>,
 ) {
-    for (entity, view_target, camera) in view_targets.iter() {
+    for (entity, view_target, camera, view) in view_targets.iter() {
         // only do writeback if writeback is enabled for the camera and this isn't the first camera in the target,
         // as there is nothing to write back for the first camera.
-        if msaa.samples() > 1 && camera.msaa_writeback && camera.sorted_camera_index_for_target > 0
+        if view.msaa.samples() > 1
+            && camera.msaa_writeback
+            && camera.sorted_camera_index_for_target > 0
         {
             let key = BlitPipelineKey {
                 texture_format: view_target.main_texture_format(),
-                samples: msaa.samples(),
+                samples: view.msaa.samples(),
                 blend_state: None,
             };
 
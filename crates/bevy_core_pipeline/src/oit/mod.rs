@@ -0,0 +1,520 @@
+//! Order independent transparency (OIT) for 3d cameras.
+//!
+//! Transparent meshes are sorted per mesh before being blended, which can't order the fragments of
+//! intersecting or overlapping meshes, such as smoke volumes or stacks of glass panes. With OIT,
+//! the alpha blended fragments of the transparent phase are stored in per pixel layers instead, and
+//! a resolve pass sorts and composites them over the rendered image before post-processing.
+
+use crate::{
+    core_3d::{
+        graph::{Core3d, Node3d},
+        Camera3d,
+    },
+    fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+};
+use bevy_app::{prelude::*, IntoSystemAppConfig};
+use bevy_asset::{load_internal_asset, HandleUntyped};
+use bevy_ecs::prelude::*;
+use bevy_math::UVec2;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeUuid};
+use bevy_render::{
+    camera::{Camera, ExtractedCamera},
+    render_graph::{RenderGraph, RenderGraphApp, ViewNodeRunner},
+    render_resource::*,
+    renderer::RenderDevice,
+    settings::WgpuLimits,
+    texture::BevyDefault,
+    view::{ExtractedView, ViewTarget, ViewUniform},
+    Extract, ExtractSchedule, RenderApp, RenderSet,
+};
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+
+mod node;
+
+pub use node::OitResolveNode;
+
+/// Enables order independent transparency (OIT) for a [`Camera3d`].
+///
+/// The fragments of meshes using `AlphaMode::Blend` are stored in up to
+/// [`layer_count`](Self::layer_count) layers per pixel instead of being blended in draw order, and
+/// the [`OitResolveNode`] sorts them by depth and blends them from back to front.
+/// The other transparent blend modes are still sorted per mesh, and are drawn below the OIT
+/// fragments.
+///
+/// # Memory
+///
+/// The layers are stored in storage buffers sized for the viewport of the camera, taking
+/// [`memory_usage`](Self::memory_usage) bytes: [`FRAGMENT_SIZE`](Self::FRAGMENT_SIZE) bytes per
+/// pixel and layer, and [`COUNTER_SIZE`](Self::COUNTER_SIZE) bytes per pixel. With the default 4
+/// layers, a 1920x1080 viewport uses about 108MB. Views whose layers don't fit in a single storage
+/// buffer binding of the device fall back to sorted blending with a warning.
+///
+/// When a pixel is covered by more fragments than there are layers, the extra fragments are
+/// dropped.
+///
+/// Custom material shaders drawing blended meshes must call `oit_draw` from `bevy_pbr::oit` when
+/// the `OIT_ENABLED` shader def is set, like the PBR shader does.
+///
+/// WebGL2 doesn't support storage buffers: the setting is ignored with a warning.
+#[derive(Reflect, FromReflect, Component, Clone, Debug, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct OrderIndependentTransparencySettings {
+    /// The number of fragments stored per pixel.
+    ///
+    /// Higher values handle more overlapping fragments, at the cost of memory and of a slower
+    /// resolve. A value of 0 disables OIT.
+    pub layer_count: u32,
+}
+
+impl Default for OrderIndependentTransparencySettings {
+    fn default() -> Self {
+        Self { layer_count: 4 }
+    }
+}
+
+impl OrderIndependentTransparencySettings {
+    /// The size in bytes of a stored fragment: its color as four half floats, and its depth.
+    pub const FRAGMENT_SIZE: u64 = 12;
+    /// The size in bytes of the stored fragment counter of a pixel.
+    pub const COUNTER_SIZE: u64 = 4;
+
+    /// Returns the size in bytes of the layers buffer of a viewport of `viewport_size` pixels.
+    pub fn layers_size(&self, viewport_size: UVec2) -> u64 {
+        pixel_count(viewport_size) * self.layer_count as u64 * Self::FRAGMENT_SIZE
+    }
+
+    /// Returns the size in bytes of all the OIT buffers of a viewport of `viewport_size` pixels.
+    pub fn memory_usage(&self, viewport_size: UVec2) -> u64 {
+        self.layers_size(viewport_size) + pixel_count(viewport_size) * Self::COUNTER_SIZE
+    }
+
+    /// Returns `true` if the layers of a viewport of `viewport_size` pixels fit in a single buffer
+    /// binding of a device with `limits`.
+    fn fits_in(&self, viewport_size: UVec2, limits: &WgpuLimits) -> bool {
+        let layers_size = self.layers_size(viewport_size);
+        layers_size <= limits.max_storage_buffer_binding_size as u64
+            && layers_size <= limits.max_buffer_size
+    }
+}
+
+fn pixel_count(viewport_size: UVec2) -> u64 {
+    viewport_size.x as u64 * viewport_size.y as u64
+}
+
+const OIT_RESOLVE_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 4297915238401159715);
+
+/// Adds support for order independent transparency to 3d cameras.
+///
+/// See [`OrderIndependentTransparencySettings`] for more details.
+pub struct OrderIndependentTransparencyPlugin;
+
+impl Plugin for OrderIndependentTransparencyPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            OIT_RESOLVE_SHADER_HANDLE,
+            "oit_resolve.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.register_type::<OrderIndependentTransparencySettings>();
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .init_resource::<OitBuffers>()
+            .init_resource::<OitResolvePipeline>()
+            .init_resource::<SpecializedRenderPipelines<OitResolvePipeline>>()
+            .add_systems((
+                extract_oit_settings.in_schedule(ExtractSchedule),
+                prepare_oit_buffers.in_set(RenderSet::Prepare),
+                prepare_oit_resolve_pipelines
+                    .in_set(RenderSet::Prepare)
+                    .after(bevy_render::view::prepare_windows),
+            ));
+
+        add_oit_resolve_node(render_app);
+    }
+}
+
+/// Adds the OIT resolve node to the `Core3d` graph, between the main pass and post-processing.
+fn add_oit_resolve_node(render_app: &mut App) {
+    render_app
+        .add_render_graph_node::<ViewNodeRunner<OitResolveNode>>(Core3d, Node3d::OitResolve)
+        .add_render_graph_edges(
+            Core3d,
+            (Node3d::MainPass, Node3d::OitResolve, Node3d::Tonemapping),
+        );
+
+    let mut render_graph = render_app.world.resource_mut::<RenderGraph>();
+    let graph = render_graph.get_sub_graph_mut(Core3d).unwrap();
+    // OIT_RESOLVE -> TAA -> BLOOM, when they were added before
+    for node in [Node3d::Taa, Node3d::Bloom] {
+        if graph.get_node_id(node).is_ok() {
+            graph.add_node_edge(Node3d::OitResolve, node);
+        }
+    }
+}
+
+/// Extracts the [`OrderIndependentTransparencySettings`] of active 3d cameras, unless OIT is
+/// unsupported.
+fn extract_oit_settings(
+    mut commands: Commands,
+    cameras: Extract<
+        Query<(Entity, &Camera, &OrderIndependentTransparencySettings), With<Camera3d>>,
+    >,
+    mut warned: Local<bool>,
+) {
+    if cfg!(feature = "webgl") {
+        if !*warned && !cameras.is_empty() {
+            warn!("OrderIndependentTransparencySettings is not supported on WebGL2, falling back to sorted blending.");
+            *warned = true;
+        }
+        return;
+    }
+
+    for (entity, camera, settings) in &cameras {
+        if camera.is_active && settings.layer_count > 0 {
+            commands.get_or_spawn(entity).insert(settings.clone());
+        }
+    }
+}
+
+/// The OIT storage buffers of a view.
+#[derive(Component, Clone)]
+pub struct ViewOitBuffers {
+    /// The stored fragments, as three `u32` per fragment: the red and green, then the blue and
+    /// alpha half floats of its color, then the bits of its depth. The fragments of layer `i` for
+    /// pixel `p` of the viewport are at index `i * pixel_count + p`.
+    pub layers: Buffer,
+    /// The number of fragments drawn to each pixel of the viewport since the last resolve, as an
+    /// atomic `u32` per pixel.
+    pub layer_ids: Buffer,
+    pub viewport_size: UVec2,
+    pub layer_count: u32,
+}
+
+/// The OIT buffers of each view, kept across frames while their size doesn't change.
+#[derive(Resource, Default)]
+pub struct OitBuffers(HashMap<Entity, ViewOitBuffers>);
+
+/// Creates the [`ViewOitBuffers`] of each view with [`OrderIndependentTransparencySettings`],
+/// skipping the views whose layers don't fit in the device limits.
+pub fn prepare_oit_buffers(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    mut buffers: ResMut<OitBuffers>,
+    views: Query<(
+        Entity,
+        &ExtractedCamera,
+        &OrderIndependentTransparencySettings,
+    )>,
+    mut oversized_views: Local<HashSet<Entity>>,
+) {
+    let limits = render_device.limits();
+    let mut live_views = HashSet::new();
+    for (entity, camera, settings) in &views {
+        let Some(viewport_size) = camera.physical_viewport_size else {
+            continue;
+        };
+        if !settings.fits_in(viewport_size, &limits) {
+            if oversized_views.insert(entity) {
+                warn!(
+                    "The {} OIT layers of a {}x{} viewport need {} bytes, more than the device supports. Falling back to sorted blending.",
+                    settings.layer_count,
+                    viewport_size.x,
+                    viewport_size.y,
+                    settings.layers_size(viewport_size),
+                );
+            }
+            continue;
+        }
+        oversized_views.remove(&entity);
+        live_views.insert(entity);
+
+        let view_buffers = buffers
+            .0
+            .entry(entity)
+            .and_modify(|view_buffers| {
+                if view_buffers.viewport_size != viewport_size
+                    || view_buffers.layer_count != settings.layer_count
+                {
+                    *view_buffers = create_view_buffers(&render_device, settings, viewport_size);
+                }
+            })
+            .or_insert_with(|| create_view_buffers(&render_device, settings, viewport_size));
+        commands.entity(entity).insert(view_buffers.clone());
+    }
+    buffers.0.retain(|entity, _| live_views.contains(entity));
+}
+
+fn create_view_buffers(
+    render_device: &RenderDevice,
+    settings: &OrderIndependentTransparencySettings,
+    viewport_size: UVec2,
+) -> ViewOitBuffers {
+    // Buffers are zeroed on creation, so no fragment is stored initially
+    let layers = render_device.create_buffer(&BufferDescriptor {
+        label: Some("oit_layers"),
+        size: settings.layers_size(viewport_size),
+        usage: BufferUsages::STORAGE,
+        mapped_at_creation: false,
+    });
+    let layer_ids = render_device.create_buffer(&BufferDescriptor {
+        label: Some("oit_layer_ids"),
+        size: pixel_count(viewport_size) * OrderIndependentTransparencySettings::COUNTER_SIZE,
+        usage: BufferUsages::STORAGE | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    ViewOitBuffers {
+        layers,
+        layer_ids,
+        viewport_size,
+        layer_count: settings.layer_count,
+    }
+}
+
+#[derive(Resource)]
+pub struct OitResolvePipeline {
+    layout: BindGroupLayout,
+}
+
+impl FromWorld for OitResolvePipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let render_device = render_world.resource::<RenderDevice>();
+        let layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("oit_resolve_bind_group_layout"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: Some(ViewUniform::min_size()),
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        OitResolvePipeline { layout }
+    }
+}
+
+#[derive(Component)]
+pub struct ViewOitResolvePipeline(pub CachedRenderPipelineId);
+
+/// The parameters an [`OitResolvePipeline`] is specialized on.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct OitResolvePipelineKey {
+    texture_format: TextureFormat,
+    samples: u32,
+    layer_count: u32,
+}
+
+impl OitResolvePipelineKey {
+    fn new(view: &ExtractedView, settings: &OrderIndependentTransparencySettings) -> Self {
+        OitResolvePipelineKey {
+            texture_format: if view.hdr {
+                ViewTarget::TEXTURE_FORMAT_HDR
+            } else {
+                TextureFormat::bevy_default()
+            },
+            samples: view.msaa.samples(),
+            layer_count: settings.layer_count,
+        }
+    }
+
+    fn shader_defs(&self) -> Vec<ShaderDefVal> {
+        vec![ShaderDefVal::UInt("LAYER_COUNT".into(), self.layer_count)]
+    }
+}
+
+impl SpecializedRenderPipeline for OitResolvePipeline {
+    type Key = OitResolvePipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        RenderPipelineDescriptor {
+            label: Some("oit_resolve".into()),
+            layout: vec![self.layout.clone()],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: OIT_RESOLVE_SHADER_HANDLE.typed(),
+                shader_defs: key.shader_defs(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: key.texture_format,
+                    // The resolved fragments are premultiplied, and blended over the main pass
+                    blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState {
+                count: key.samples,
+                ..Default::default()
+            },
+            push_constant_ranges: Vec::new(),
+        }
+    }
+}
+
+/// Specializes the resolve pipeline of every view with [`OrderIndependentTransparencySettings`].
+pub fn prepare_oit_resolve_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<OitResolvePipeline>>,
+    resolve_pipeline: Res<OitResolvePipeline>,
+    views: Query<(
+        Entity,
+        &ExtractedView,
+        &OrderIndependentTransparencySettings,
+    )>,
+) {
+    for (entity, view, settings) in &views {
+        let pipeline_id = pipelines.specialize(
+            &pipeline_cache,
+            &resolve_pipeline,
+            OitResolvePipelineKey::new(view, settings),
+        );
+        commands
+            .entity(entity)
+            .insert(ViewOitResolvePipeline(pipeline_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::taa::{add_taa_node, TAANode};
+    use bevy_render::{
+        render_graph::{EmptyNode, NodeId, SlotInfo, SlotType},
+        view::Msaa,
+    };
+
+    fn node_outputs(graph: &RenderGraph, node: Node3d) -> Vec<NodeId> {
+        graph
+            .iter_node_outputs(node)
+            .unwrap()
+            .map(|(_, node)| node.id)
+            .collect()
+    }
+
+    #[test]
+    fn resolve_runs_between_main_pass_and_post_processing() {
+        let mut graph = RenderGraph::default();
+        graph.set_input(vec![SlotInfo::new("view_entity", SlotType::Entity)]);
+        for node in [Node3d::MainPass, Node3d::Bloom, Node3d::Tonemapping] {
+            graph.add_node(node, EmptyNode);
+        }
+        graph.add_node_edge(Node3d::MainPass, Node3d::Bloom);
+        graph.add_node_edge(Node3d::Bloom, Node3d::Tonemapping);
+        let mut render_graph = RenderGraph::default();
+        render_graph.add_sub_graph(Core3d, graph);
+        let mut render_app = App::new();
+        render_app.insert_resource(render_graph);
+
+        add_oit_resolve_node(&mut render_app);
+        // TAA is added after the core pipeline
+        let taa_node = TAANode::new(&mut render_app.world);
+        let mut render_graph = render_app.world.resource_mut::<RenderGraph>();
+        let graph = render_graph.get_sub_graph_mut(Core3d).unwrap();
+        add_taa_node(graph, taa_node);
+
+        // MAIN_PASS -> OIT_RESOLVE -> TAA -> BLOOM -> TONEMAPPING
+        let resolve = graph.get_node_id(Node3d::OitResolve).unwrap();
+        assert!(node_outputs(graph, Node3d::MainPass).contains(&resolve));
+        let outputs = node_outputs(graph, Node3d::OitResolve);
+        assert_eq!(outputs.len(), 3);
+        for node in [Node3d::Tonemapping, Node3d::Bloom, Node3d::Taa] {
+            assert!(outputs.contains(&graph.get_node_id(node).unwrap()));
+        }
+
+        // the resolve receives the view entity
+        let input_node_id = graph.input_node().id;
+        assert!(graph
+            .iter_node_inputs(Node3d::OitResolve)
+            .unwrap()
+            .any(|(_, node)| node.id == input_node_id));
+    }
+
+    #[test]
+    fn resolve_pipeline_specialization() {
+        let view = |hdr, msaa| ExtractedView {
+            projection: Default::default(),
+            transform: Default::default(),
+            view_projection: None,
+            hdr,
+            viewport: Default::default(),
+            color_grading: Default::default(),
+            msaa,
+        };
+        let settings = OrderIndependentTransparencySettings::default();
+        let key = OitResolvePipelineKey::new(&view(false, Msaa::Sample4), &settings);
+        assert_eq!(key.texture_format, TextureFormat::bevy_default());
+        assert_eq!(key.samples, 4);
+        assert_eq!(
+            key.shader_defs(),
+            vec![ShaderDefVal::UInt("LAYER_COUNT".into(), 4)]
+        );
+
+        let hdr = OitResolvePipelineKey::new(&view(true, Msaa::Off), &settings);
+        assert_eq!(hdr.texture_format, ViewTarget::TEXTURE_FORMAT_HDR);
+        assert_eq!(hdr.samples, 1);
+
+        // Cameras with different sample counts get their own pipelines in the same frame
+        let unsampled = OitResolvePipelineKey::new(&view(false, Msaa::Off), &settings);
+        assert_eq!(unsampled.samples, 1);
+        assert_ne!(unsampled, key);
+
+        let layers = OitResolvePipelineKey::new(
+            &view(false, Msaa::Sample4),
+            &OrderIndependentTransparencySettings { layer_count: 8 },
+        );
+        assert_ne!(layers, key);
+        assert_eq!(
+            layers.shader_defs(),
+            vec![ShaderDefVal::UInt("LAYER_COUNT".into(), 8)]
+        );
+    }
+
+    #[test]
+    fn memory_budget() {
+        let full_hd = UVec2::new(1920, 1080);
+        let settings = OrderIndependentTransparencySettings::default();
+        assert_eq!(settings.layers_size(full_hd), 1920 * 1080 * 4 * 12);
+        assert_eq!(settings.memory_usage(full_hd), 1920 * 1080 * (4 * 12 + 4));
+        assert!(settings.fits_in(full_hd, &WgpuLimits::default()));
+
+        // 8 layers exceed the default 128MiB storage binding size at 1080p
+        let eight_layers = OrderIndependentTransparencySettings { layer_count: 8 };
+        assert!(!eight_layers.fits_in(full_hd, &WgpuLimits::default()));
+        assert!(eight_layers.fits_in(UVec2::new(1280, 720), &WgpuLimits::default()));
+
+        assert!(!settings.fits_in(full_hd, &WgpuLimits::downlevel_webgl2_defaults()));
+    }
+}
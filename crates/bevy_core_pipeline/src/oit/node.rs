@@ -0,0 +1,90 @@
+use crate::oit::{OitResolvePipeline, ViewOitBuffers, ViewOitResolvePipeline};
+use bevy_ecs::{prelude::*, query::QueryItem};
+use bevy_render::{
+    camera::ExtractedCamera,
+    render_graph::{NodeRunError, RenderGraphContext, ViewNode},
+    render_resource::{
+        BindGroupDescriptor, BindGroupEntry, LoadOp, Operations, PipelineCache,
+        RenderPassDescriptor,
+    },
+    renderer::RenderContext,
+    view::{ViewTarget, ViewUniformOffset, ViewUniforms},
+};
+
+/// Sorts the fragments stored in the [`ViewOitBuffers`] of a view, and blends them over its
+/// view target.
+#[derive(Default)]
+pub struct OitResolveNode;
+
+impl ViewNode for OitResolveNode {
+    type ViewQuery = (
+        &'static ExtractedCamera,
+        &'static ViewTarget,
+        &'static ViewUniformOffset,
+        &'static ViewOitBuffers,
+        &'static ViewOitResolvePipeline,
+    );
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (camera, target, view_uniform_offset, buffers, pipeline): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let resolve_pipeline = world.resource::<OitResolvePipeline>();
+        let view_uniforms = world.resource::<ViewUniforms>();
+        let (Some(pipeline), Some(view_uniforms)) = (
+            pipeline_cache.get_render_pipeline(pipeline.0),
+            view_uniforms.uniforms.binding(),
+        ) else {
+            // The resolve resets the fragment counters, which must also be done while it isn't
+            // ready so that the next frames don't drop all their fragments
+            render_context
+                .command_encoder()
+                .clear_buffer(&buffers.layer_ids, 0, None);
+            return Ok(());
+        };
+
+        let bind_group = render_context
+            .render_device()
+            .create_bind_group(&BindGroupDescriptor {
+                label: Some("oit_resolve_bind_group"),
+                layout: &resolve_pipeline.layout,
+                entries: &[
+                    BindGroupEntry {
+                        binding: 0,
+                        resource: view_uniforms,
+                    },
+                    BindGroupEntry {
+                        binding: 1,
+                        resource: buffers.layers.as_entire_binding(),
+                    },
+                    BindGroupEntry {
+                        binding: 2,
+                        resource: buffers.layer_ids.as_entire_binding(),
+                    },
+                ],
+            });
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("oit_resolve_pass"),
+            color_attachments: &[Some(target.get_color_attachment(Operations {
+                load: LoadOp::Load,
+                store: true,
+            }))],
+            depth_stencil_attachment: None,
+        });
+
+        if let Some(viewport) = camera.viewport.as_ref() {
+            render_pass.set_camera_viewport(viewport);
+        }
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[view_uniform_offset.offset]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+#import bevy_render::view
+#import bevy_core_pipeline::fullscreen_vertex_shader
+
+@group(0) @binding(0) var<uniform> view: View;
+// See `ViewOitBuffers` for the layout of the buffers
+@group(0) @binding(1) var<storage, read> layers: array<u32>;
+@group(0) @binding(2) var<storage, read_write> layer_ids: array<atomic<u32>>;
+
+struct OitFragment {
+    color: vec4<f32>,
+    depth: f32,
+};
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let size = vec2<u32>(view.viewport.zw);
+    let pixel = vec2<u32>(in.position.xy - view.viewport.xy);
+    let pixel_index = pixel.y * size.x + pixel.x;
+    let pixel_count = arrayLength(&layer_ids);
+    if pixel_index >= pixel_count {
+        discard;
+    }
+
+    // Fragments past the layer count were dropped when drawn
+    let fragment_count = min(atomicLoad(&layer_ids[pixel_index]), #{LAYER_COUNT}u);
+    atomicStore(&layer_ids[pixel_index], 0u);
+    if fragment_count == 0u {
+        discard;
+    }
+
+    // Insertion sort from the farthest to the nearest fragment: by increasing depth, since the
+    // projections use reverse-z
+    var fragments: array<OitFragment, #{LAYER_COUNT}>;
+    for (var i = 0u; i < fragment_count; i += 1u) {
+        let index = 3u * (i * pixel_count + pixel_index);
+        let fragment = OitFragment(
+            vec4(unpack2x16float(layers[index]), unpack2x16float(layers[index + 1u])),
+            bitcast<f32>(layers[index + 2u]),
+        );
+        var j = i;
+        while j > 0u && fragments[j - 1u].depth > fragment.depth {
+            fragments[j] = fragments[j - 1u];
+            j -= 1u;
+        }
+        fragments[j] = fragment;
+    }
+
+    // Blend the fragments from back to front, premultiplying the result so that it can be blended
+    // over the main pass
+    var color = vec4(0.0);
+    for (var i = 0u; i < fragment_count; i += 1u) {
+        let fragment = fragments[i].color;
+        color = vec4(
+            fragment.rgb * fragment.a + color.rgb * (1.0 - fragment.a),
+            fragment.a + color.a * (1.0 - fragment.a),
+        );
+    }
+    return color;
+}
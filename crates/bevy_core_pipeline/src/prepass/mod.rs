@@ -7,6 +7,7 @@
 //!
 //! [`DepthPrepass`]
 //! [`NormalPrepass`]
+//! [`MotionVectorPrepass`]
 //!
 //! The textures are automatically added to the default mesh view bindings. You can also get the raw textures
 //! by querying the [`ViewPrepassTextures`] component on any camera with a prepass component.
@@ -16,7 +17,8 @@
 //! it will always create a depth buffer that will be used by the main pass.
 //!
 //! When using the default mesh view bindings you should be able to use `prepass_depth()`
-//! and `prepass_normal()` to load the related textures. These functions are defined in `bevy_pbr::prepass_utils`.
+//! and `prepass_normal()` to load the related textures. These functions are defined in `bevy_pbr::prepass_utils`,
+//! and are available to materials returning `true` from `Material::reads_prepass_textures`.
 //! See the `shader_prepass` example that shows how to use it.
 //!
 //! The prepass runs for each `Material`. You can control if the prepass should run per-material by setting the `prepass_enabled`
@@ -26,11 +28,12 @@
 
 pub mod node;
 
-use std::cmp::Reverse;
+use std::{cmp::Reverse, ops::Range};
 
 use bevy_ecs::prelude::*;
 use bevy_reflect::Reflect;
 use bevy_render::{
+    batching::InstancedPhaseItem,
     render_phase::{CachedRenderPipelinePhaseItem, DrawFunctionId, PhaseItem},
     render_resource::{CachedRenderPipelineId, Extent3d, TextureFormat},
     texture::CachedTexture,
@@ -39,6 +42,7 @@ use bevy_utils::FloatOrd;
 
 pub const DEPTH_PREPASS_FORMAT: TextureFormat = TextureFormat::Depth32Float;
 pub const NORMAL_PREPASS_FORMAT: TextureFormat = TextureFormat::Rgb10a2Unorm;
+pub const MOTION_VECTOR_PREPASS_FORMAT: TextureFormat = TextureFormat::Rg16Float;
 
 /// If added to a [`crate::prelude::Camera3d`] then depth values will be copied to a separate texture available to the main pass.
 #[derive(Component, Default, Reflect)]
@@ -49,6 +53,11 @@ pub struct DepthPrepass;
 #[derive(Component, Default, Reflect)]
 pub struct NormalPrepass;
 
+/// If added to a [`crate::prelude::Camera3d`] then screen space motion vectors will be copied to a separate texture available to the main pass.
+/// Motion vectors are stored in the range -1,1 as the offset from the previous frame's position to the current one, in UV space.
+#[derive(Component, Default, Reflect)]
+pub struct MotionVectorPrepass;
+
 /// Textures that are written to by the prepass.
 ///
 /// This component will only be present if any of the relevant prepass components are also present.
@@ -60,6 +69,9 @@ pub struct ViewPrepassTextures {
     /// The normals texture generated by the prepass.
     /// Exists only if [`NormalPrepass`] is added to the `ViewTarget`
     pub normal: Option<CachedTexture>,
+    /// The motion vectors texture generated by the prepass.
+    /// Exists only if [`MotionVectorPrepass`] is added to the `ViewTarget`
+    pub motion_vectors: Option<CachedTexture>,
     /// The size of the textures.
     pub size: Extent3d,
 }
@@ -74,6 +86,8 @@ pub struct Opaque3dPrepass {
     pub entity: Entity,
     pub pipeline_id: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
+    pub instance_range: Range<u32>,
+    pub dynamic_offset: Option<u32>,
 }
 
 impl PhaseItem for Opaque3dPrepass {
@@ -109,6 +123,28 @@ impl CachedRenderPipelinePhaseItem for Opaque3dPrepass {
     }
 }
 
+impl InstancedPhaseItem for Opaque3dPrepass {
+    #[inline]
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    #[inline]
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    #[inline]
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    #[inline]
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
+
 /// Alpha mask phase of the 3D prepass.
 ///
 /// Sorted front-to-back by the z-distance in front of the camera.
@@ -119,6 +155,8 @@ pub struct AlphaMask3dPrepass {
     pub entity: Entity,
     pub pipeline_id: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
+    pub instance_range: Range<u32>,
+    pub dynamic_offset: Option<u32>,
 }
 
 impl PhaseItem for AlphaMask3dPrepass {
@@ -153,3 +191,25 @@ impl CachedRenderPipelinePhaseItem for AlphaMask3dPrepass {
         self.pipeline_id
     }
 }
+
+impl InstancedPhaseItem for AlphaMask3dPrepass {
+    #[inline]
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    #[inline]
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    #[inline]
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    #[inline]
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
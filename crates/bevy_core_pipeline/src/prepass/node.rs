@@ -69,16 +69,34 @@ impl Node for PrepassNode {
             return Ok(());
         };
 
-        let mut color_attachments = vec![];
-        if let Some(view_normals_texture) = &view_prepass_textures.normal {
-            color_attachments.push(Some(RenderPassColorAttachment {
-                view: &view_normals_texture.default_view,
-                resolve_target: None,
-                ops: Operations {
-                    load: LoadOp::Clear(Color::BLACK.into()),
-                    store: true,
-                },
-            }));
+        let mut color_attachments = vec![
+            view_prepass_textures
+                .normal
+                .as_ref()
+                .map(|view_normals_texture| RenderPassColorAttachment {
+                    view: &view_normals_texture.default_view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::BLACK.into()),
+                        store: true,
+                    },
+                }),
+            view_prepass_textures
+                .motion_vectors
+                .as_ref()
+                .map(|view_motion_vectors_texture| RenderPassColorAttachment {
+                    view: &view_motion_vectors_texture.default_view,
+                    resolve_target: None,
+                    ops: Operations {
+                        // Undefined motion vectors are set to zero
+                        load: LoadOp::Clear(Color::NONE.into()),
+                        store: true,
+                    },
+                }),
+        ];
+        if color_attachments.iter().all(Option::is_none) {
+            // All attachments are none: clear the attachment list so that no fragment shader is required
+            color_attachments.clear();
         }
 
         {
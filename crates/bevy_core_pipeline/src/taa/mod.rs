@@ -0,0 +1,683 @@
+use crate::{
+    core_3d::{self, Camera3d},
+    fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+    prepass::{DepthPrepass, MotionVectorPrepass, ViewPrepassTextures},
+};
+use bevy_app::{App, IntoSystemAppConfig, Plugin};
+use bevy_asset::{load_internal_asset, HandleUntyped};
+use bevy_core::FrameCount;
+use bevy_ecs::{
+    prelude::{Bundle, Component, Entity},
+    query::{QueryState, With},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfig,
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+    world::{FromWorld, World},
+};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeUuid};
+use bevy_render::{
+    camera::{Camera, ExtractedCamera, Projection, TemporalJitter},
+    prelude::Msaa,
+    render_graph::{Node, NodeRunError, RenderGraph, RenderGraphContext, SlotInfo, SlotType},
+    render_resource::{
+        BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
+        BindGroupLayoutEntry, BindingResource, BindingType, CachedRenderPipelineId,
+        ColorTargetState, ColorWrites, Extent3d, FilterMode, FragmentState, MultisampleState,
+        Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment, RenderPassDescriptor,
+        RenderPipelineDescriptor, Sampler, SamplerBindingType, SamplerDescriptor, Shader,
+        ShaderStages, SpecializedRenderPipeline, SpecializedRenderPipelines, TextureDescriptor,
+        TextureDimension, TextureFormat, TextureSampleType, TextureUsages, TextureViewDimension,
+    },
+    renderer::{RenderContext, RenderDevice},
+    texture::{BevyDefault, CachedTexture, TextureCache},
+    view::{ExtractedView, ViewSet, ViewTarget},
+    ExtractSchedule, MainWorld, RenderApp, RenderSet,
+};
+use bevy_utils::tracing::warn;
+
+const TAA_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 656865235226276);
+
+/// The number of frames after which the jitter sequence of [`TemporalAntiAliasSettings`] repeats.
+pub const TAA_JITTER_SEQUENCE_LENGTH: u32 = 8;
+
+/// Adds support for Temporal Anti-Aliasing (TAA) to 3d cameras.
+///
+/// See [`TemporalAntiAliasSettings`] for more details.
+pub struct TemporalAntiAliasPlugin;
+
+impl Plugin for TemporalAntiAliasPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, TAA_SHADER_HANDLE, "taa.wgsl", Shader::from_wgsl);
+
+        app.register_type::<TemporalAntiAliasSettings>();
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .init_resource::<TAAPipeline>()
+            .init_resource::<SpecializedRenderPipelines<TAAPipeline>>()
+            .add_system(extract_taa_settings.in_schedule(ExtractSchedule))
+            .add_systems((
+                prepare_taa_jitter
+                    .in_set(RenderSet::Prepare)
+                    .before(ViewSet::PrepareUniforms),
+                prepare_taa_history_textures.in_set(RenderSet::Prepare),
+                prepare_taa_pipelines.in_set(RenderSet::Prepare),
+            ));
+
+        let taa_node = TAANode::new(&mut render_app.world);
+        let mut graph = render_app.world.resource_mut::<RenderGraph>();
+        let draw_3d_graph = graph.get_sub_graph_mut(core_3d::graph::NAME).unwrap();
+        add_taa_node(draw_3d_graph, taa_node);
+    }
+}
+
+pub(crate) fn add_taa_node(draw_3d_graph: &mut RenderGraph, taa_node: TAANode) {
+    draw_3d_graph.add_node(core_3d::graph::node::TAA, taa_node);
+    draw_3d_graph.add_slot_edge(
+        draw_3d_graph.input_node().id,
+        core_3d::graph::input::VIEW_ENTITY,
+        core_3d::graph::node::TAA,
+        TAANode::IN_VIEW,
+    );
+    // MAIN_PASS -> TAA -> BLOOM -> TONEMAPPING
+    draw_3d_graph.add_node_edge(core_3d::graph::node::MAIN_PASS, core_3d::graph::node::TAA);
+    draw_3d_graph.add_node_edge(core_3d::graph::node::TAA, core_3d::graph::node::BLOOM);
+    draw_3d_graph.add_node_edge(core_3d::graph::node::TAA, core_3d::graph::node::TONEMAPPING);
+    // OIT_RESOLVE -> TAA, so that the resolved transparent fragments are anti-aliased too
+    if draw_3d_graph
+        .get_node_id(core_3d::graph::node::OIT_RESOLVE)
+        .is_ok()
+    {
+        draw_3d_graph.add_node_edge(core_3d::graph::node::OIT_RESOLVE, core_3d::graph::node::TAA);
+    }
+}
+
+/// Bundle to apply temporal anti-aliasing.
+#[derive(Bundle, Default)]
+pub struct TemporalAntiAliasBundle {
+    pub settings: TemporalAntiAliasSettings,
+    pub jitter: TemporalJitter,
+    pub depth_prepass: DepthPrepass,
+    pub motion_vector_prepass: MotionVectorPrepass,
+}
+
+/// Component to apply temporal anti-aliasing to a 3D perspective camera.
+///
+/// Temporal anti-aliasing (TAA) is a form of image smoothing/filtering, like
+/// multisample anti-aliasing (MSAA), or fast approximate anti-aliasing (FXAA).
+/// TAA works by blending (averaging) each frame with the past few frames.
+///
+/// # Tradeoffs
+///
+/// Pros:
+/// * Cost scales with screen/view resolution, unlike MSAA which scales with number of triangles
+/// * Filters more types of aliasing than MSAA, such as textures and singular bright pixels (specular aliasing)
+/// * Greatly increases the quality of stochastic rendering techniques such as SSAO, shadow mapping, etc
+///
+/// Cons:
+/// * Chance of "ghosting" - ghostly trails left behind moving objects
+/// * Thin geometry, lighting detail, or texture lines may flicker noisily or disappear
+///
+/// Because TAA blends past frames with the current frame, when the frames differ too much
+/// (such as with fast moving objects or camera cuts), ghosting artifacts may occur.
+/// The history is constrained to the neighborhood of each pixel in the current frame to keep
+/// this to a minimum.
+///
+/// Artifacts tend to be reduced at higher framerates and rendering resolution.
+///
+/// # Usage Notes
+///
+/// Requires that you add [`TemporalAntiAliasPlugin`] to your app,
+/// and add the [`DepthPrepass`], [`MotionVectorPrepass`], and [`TemporalJitter`]
+/// components to your camera.
+///
+/// Cannot be used with [`bevy_render::camera::OrthographicProjection`].
+///
+/// Currently does not support skinned meshes. There will probably be ghosting artifacts if used with them.
+/// Does not work well with alpha-blended meshes as it requires depth writing to determine motion.
+///
+/// It is very important that correct motion vectors are written for everything on screen.
+/// Failure to do so will lead to ghosting artifacts. For instance, if particle effects
+/// are added using a third party library, the library must either:
+/// 1. Write particle motion vectors to the motion vectors prepass texture
+/// 2. Render particles after TAA
+///
+/// Multisampling must be disabled on the camera with [`Msaa::Off`].
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component, Default)]
+pub struct TemporalAntiAliasSettings {
+    /// Set to true to delete the saved temporal history (past frames).
+    ///
+    /// Useful for preventing ghosting when the history is no longer
+    /// representative of the current frame, such as in sudden camera cuts.
+    ///
+    /// After setting this to true, it will automatically be toggled
+    /// back to false after one frame.
+    pub reset: bool,
+}
+
+impl Default for TemporalAntiAliasSettings {
+    fn default() -> Self {
+        Self { reset: true }
+    }
+}
+
+/// Render node that resolves the current frame against the temporal history of a view.
+pub struct TAANode {
+    view_query: QueryState<(
+        &'static ExtractedCamera,
+        &'static ViewTarget,
+        &'static TAAHistoryTextures,
+        &'static ViewPrepassTextures,
+        &'static TAAPipelineId,
+    )>,
+}
+
+impl TAANode {
+    pub const IN_VIEW: &'static str = "view";
+
+    pub fn new(world: &mut World) -> Self {
+        Self {
+            view_query: QueryState::new(world),
+        }
+    }
+}
+
+impl Node for TAANode {
+    fn input(&self) -> Vec<SlotInfo> {
+        vec![SlotInfo::new(Self::IN_VIEW, SlotType::Entity)]
+    }
+
+    fn update(&mut self, world: &mut World) {
+        self.view_query.update_archetypes(world);
+    }
+
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        #[cfg(feature = "trace")]
+        let _taa_span = bevy_utils::tracing::info_span!("taa").entered();
+
+        let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
+        let Ok((camera, view_target, taa_history_textures, prepass_textures, taa_pipeline_id)) =
+            self.view_query.get_manual(world, view_entity)
+        else {
+            return Ok(());
+        };
+        let (Some(prepass_motion_vectors_texture), Some(prepass_depth_texture)) =
+            (&prepass_textures.motion_vectors, &prepass_textures.depth)
+        else {
+            return Ok(());
+        };
+        let pipelines = world.resource::<TAAPipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let Some(taa_pipeline) = pipeline_cache.get_render_pipeline(taa_pipeline_id.0) else {
+            return Ok(());
+        };
+
+        let view_target = view_target.post_process_write();
+
+        let taa_bind_group =
+            render_context
+                .render_device()
+                .create_bind_group(&BindGroupDescriptor {
+                    label: Some("taa_bind_group"),
+                    layout: &pipelines.taa_bind_group_layout,
+                    entries: &[
+                        BindGroupEntry {
+                            binding: 0,
+                            resource: BindingResource::TextureView(view_target.source),
+                        },
+                        BindGroupEntry {
+                            binding: 1,
+                            resource: BindingResource::TextureView(
+                                &taa_history_textures.read.default_view,
+                            ),
+                        },
+                        BindGroupEntry {
+                            binding: 2,
+                            resource: BindingResource::TextureView(
+                                &prepass_motion_vectors_texture.default_view,
+                            ),
+                        },
+                        BindGroupEntry {
+                            binding: 3,
+                            resource: BindingResource::TextureView(
+                                &prepass_depth_texture.default_view,
+                            ),
+                        },
+                        BindGroupEntry {
+                            binding: 4,
+                            resource: BindingResource::Sampler(&pipelines.nearest_sampler),
+                        },
+                        BindGroupEntry {
+                            binding: 5,
+                            resource: BindingResource::Sampler(&pipelines.linear_sampler),
+                        },
+                    ],
+                });
+
+        {
+            let mut taa_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some("taa_pass"),
+                color_attachments: &[
+                    Some(RenderPassColorAttachment {
+                        view: view_target.destination,
+                        resolve_target: None,
+                        ops: Operations::default(),
+                    }),
+                    Some(RenderPassColorAttachment {
+                        view: &taa_history_textures.write.default_view,
+                        resolve_target: None,
+                        ops: Operations::default(),
+                    }),
+                ],
+                depth_stencil_attachment: None,
+            });
+            taa_pass.set_render_pipeline(taa_pipeline);
+            taa_pass.set_bind_group(0, &taa_bind_group, &[]);
+            if let Some(viewport) = camera.viewport.as_ref() {
+                taa_pass.set_camera_viewport(viewport);
+            }
+            taa_pass.draw(0..3, 0..1);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct TAAPipeline {
+    taa_bind_group_layout: BindGroupLayout,
+    nearest_sampler: Sampler,
+    linear_sampler: Sampler,
+}
+
+impl FromWorld for TAAPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let nearest_sampler = render_device.create_sampler(&SamplerDescriptor {
+            label: Some("taa_nearest_sampler"),
+            mag_filter: FilterMode::Nearest,
+            min_filter: FilterMode::Nearest,
+            ..SamplerDescriptor::default()
+        });
+        let linear_sampler = render_device.create_sampler(&SamplerDescriptor {
+            label: Some("taa_linear_sampler"),
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..SamplerDescriptor::default()
+        });
+
+        let taa_bind_group_layout =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("taa_bind_group_layout"),
+                entries: &[
+                    // View target (read)
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // TAA history (read)
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Motion vectors
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Depth
+                    BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Depth,
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Nearest sampler
+                    BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::NonFiltering),
+                        count: None,
+                    },
+                    // Linear sampler
+                    BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: ShaderStages::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        TAAPipeline {
+            taa_bind_group_layout,
+            nearest_sampler,
+            linear_sampler,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct TAAPipelineKey {
+    hdr: bool,
+    reset: bool,
+}
+
+impl SpecializedRenderPipeline for TAAPipeline {
+    type Key = TAAPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let mut shader_defs = vec![];
+
+        let format = if key.hdr {
+            shader_defs.push("TONEMAP".into());
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        if key.reset {
+            shader_defs.push("RESET".into());
+        }
+
+        RenderPipelineDescriptor {
+            label: Some("taa_pipeline".into()),
+            layout: vec![self.taa_bind_group_layout.clone()],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: TAA_SHADER_HANDLE.typed::<Shader>(),
+                shader_defs,
+                entry_point: "taa".into(),
+                targets: vec![
+                    Some(ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: ColorWrites::ALL,
+                    }),
+                    Some(ColorTargetState {
+                        format: TAA_HISTORY_TEXTURE_FORMAT,
+                        blend: None,
+                        write_mask: ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: Vec::new(),
+        }
+    }
+}
+
+fn extract_taa_settings(
+    mut commands: Commands,
+    mut main_world: ResMut<MainWorld>,
+    mut msaa_warning_emitted: Local<bool>,
+) {
+    let default_msaa = *main_world.resource::<Msaa>();
+    let mut cameras_3d = main_world.query_filtered::<(
+        Entity,
+        &Camera,
+        &Projection,
+        &mut TemporalAntiAliasSettings,
+        Option<&Msaa>,
+    ), (
+        With<Camera3d>,
+        With<TemporalJitter>,
+        With<DepthPrepass>,
+        With<MotionVectorPrepass>,
+    )>();
+
+    for (entity, camera, camera_projection, mut taa_settings, msaa) in
+        cameras_3d.iter_mut(&mut main_world)
+    {
+        if *msaa.unwrap_or(&default_msaa) != Msaa::Off {
+            if !*msaa_warning_emitted {
+                warn!("Temporal anti-aliasing requires Msaa::Off, so it will not be applied");
+                *msaa_warning_emitted = true;
+            }
+            continue;
+        }
+
+        let has_perspective_projection = matches!(camera_projection, Projection::Perspective(_));
+        if camera.is_active && has_perspective_projection {
+            commands.get_or_spawn(entity).insert(taa_settings.clone());
+            taa_settings.reset = false;
+        }
+    }
+}
+
+/// Returns the subpixel jitter offset used by TAA on the given frame.
+///
+/// Offsets come from the Halton(2, 3) sequence, recentered to [-0.5, 0.5], and repeat every
+/// [`TAA_JITTER_SEQUENCE_LENGTH`] frames.
+pub fn taa_jitter_offset(frame_count: u32) -> Vec2 {
+    // Skip index 0 of the sequence, which is always at the pixel corner
+    let index = frame_count % TAA_JITTER_SEQUENCE_LENGTH + 1;
+    Vec2::new(halton(index, 2), halton(index, 3)) - 0.5
+}
+
+fn halton(mut index: u32, base: u32) -> f32 {
+    let mut fraction = 1.0;
+    let mut result = 0.0;
+    while index > 0 {
+        fraction /= base as f32;
+        result += fraction * (index % base) as f32;
+        index /= base;
+    }
+    result
+}
+
+fn prepare_taa_jitter(
+    frame_count: Res<FrameCount>,
+    mut query: Query<&mut TemporalJitter, With<TemporalAntiAliasSettings>>,
+) {
+    let offset = taa_jitter_offset(frame_count.0);
+
+    for mut jitter in &mut query {
+        jitter.offset = offset;
+    }
+}
+
+const TAA_HISTORY_TEXTURE_FORMAT: TextureFormat = ViewTarget::TEXTURE_FORMAT_HDR;
+
+/// The history textures a view reads from and writes to this frame.
+///
+/// The two textures swap roles every frame.
+#[derive(Component)]
+pub struct TAAHistoryTextures {
+    write: CachedTexture,
+    read: CachedTexture,
+}
+
+fn prepare_taa_history_textures(
+    mut commands: Commands,
+    mut texture_cache: ResMut<TextureCache>,
+    render_device: Res<RenderDevice>,
+    frame_count: Res<FrameCount>,
+    views: Query<(Entity, &ExtractedCamera), With<TemporalAntiAliasSettings>>,
+) {
+    for (entity, camera) in &views {
+        let Some(physical_target_size) = camera.physical_target_size else {
+            continue;
+        };
+
+        let mut texture_descriptor = TextureDescriptor {
+            label: None,
+            size: Extent3d {
+                depth_or_array_layers: 1,
+                width: physical_target_size.x,
+                height: physical_target_size.y,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TAA_HISTORY_TEXTURE_FORMAT,
+            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        };
+
+        texture_descriptor.label = Some("taa_history_1_texture");
+        let history_1_texture = texture_cache.get(&render_device, texture_descriptor.clone());
+
+        texture_descriptor.label = Some("taa_history_2_texture");
+        let history_2_texture = texture_cache.get(&render_device, texture_descriptor);
+
+        let textures = if frame_count.0 % 2 == 0 {
+            TAAHistoryTextures {
+                write: history_1_texture,
+                read: history_2_texture,
+            }
+        } else {
+            TAAHistoryTextures {
+                write: history_2_texture,
+                read: history_1_texture,
+            }
+        };
+
+        commands.entity(entity).insert(textures);
+    }
+}
+
+#[derive(Component)]
+pub struct TAAPipelineId(CachedRenderPipelineId);
+
+fn prepare_taa_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<TAAPipeline>>,
+    pipeline: Res<TAAPipeline>,
+    views: Query<(Entity, &ExtractedView, &TemporalAntiAliasSettings)>,
+) {
+    for (entity, view, taa_settings) in &views {
+        let mut pipeline_key = TAAPipelineKey {
+            hdr: view.hdr,
+            reset: taa_settings.reset,
+        };
+        let pipeline_id = pipelines.specialize(&pipeline_cache, &pipeline, pipeline_key.clone());
+
+        // Prepare the non-reset pipeline as well, it will be needed next frame
+        if pipeline_key.reset {
+            pipeline_key.reset = false;
+            pipelines.specialize(&pipeline_cache, &pipeline, pipeline_key);
+        }
+
+        commands.entity(entity).insert(TAAPipelineId(pipeline_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_render::render_graph::{Edge, EmptyNode};
+
+    #[test]
+    fn jitter_sequence_is_deterministic() {
+        let sequence: Vec<_> = (0..TAA_JITTER_SEQUENCE_LENGTH)
+            .map(taa_jitter_offset)
+            .collect();
+
+        // The first offsets of the recentered Halton(2, 3) sequence
+        assert_eq!(sequence[0], Vec2::new(0.0, 1.0 / 3.0 - 0.5));
+        assert_eq!(sequence[1], Vec2::new(-0.25, 2.0 / 3.0 - 0.5));
+        assert_eq!(sequence[2], Vec2::new(0.25, 1.0 / 9.0 - 0.5));
+
+        for (frame, offset) in sequence.iter().enumerate() {
+            assert!(offset.abs().max_element() <= 0.5);
+            // No two frames of one cycle share an offset
+            assert!(!sequence[..frame].contains(offset));
+            // The sequence repeats, including when the frame count wraps
+            let frame = frame as u32;
+            assert_eq!(
+                taa_jitter_offset(frame + TAA_JITTER_SEQUENCE_LENGTH),
+                *offset
+            );
+            assert_eq!(
+                taa_jitter_offset(frame.wrapping_sub(TAA_JITTER_SEQUENCE_LENGTH)),
+                *offset
+            );
+        }
+    }
+
+    #[test]
+    fn taa_node_runs_between_main_pass_and_post_processing() {
+        let mut world = World::new();
+        let mut graph = RenderGraph::default();
+        graph.set_input(vec![SlotInfo::new(
+            core_3d::graph::input::VIEW_ENTITY,
+            SlotType::Entity,
+        )]);
+        graph.add_node(core_3d::graph::node::MAIN_PASS, EmptyNode);
+        graph.add_node(core_3d::graph::node::BLOOM, EmptyNode);
+        graph.add_node(core_3d::graph::node::TONEMAPPING, EmptyNode);
+        graph.add_node_edge(core_3d::graph::node::MAIN_PASS, core_3d::graph::node::BLOOM);
+        graph.add_node_edge(
+            core_3d::graph::node::BLOOM,
+            core_3d::graph::node::TONEMAPPING,
+        );
+
+        add_taa_node(&mut graph, TAANode::new(&mut world));
+
+        let input_node_id = graph.input_node().id;
+        let mut node_inputs = graph.iter_node_inputs(core_3d::graph::node::TAA).unwrap();
+        let (edge, node) = node_inputs.next().unwrap();
+        assert!(matches!(edge, Edge::SlotEdge { .. }));
+        assert_eq!(node.id, input_node_id);
+        let (edge, node) = node_inputs.next().unwrap();
+        assert!(matches!(edge, Edge::NodeEdge { .. }));
+        assert_eq!(
+            Some(node.id),
+            graph.get_node_id(core_3d::graph::node::MAIN_PASS).ok()
+        );
+        assert!(node_inputs.next().is_none());
+
+        let outputs: Vec<_> = graph
+            .iter_node_outputs(core_3d::graph::node::TAA)
+            .unwrap()
+            .map(|(_, node)| node.id)
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![
+                graph.get_node_id(core_3d::graph::node::BLOOM).unwrap(),
+                graph
+                    .get_node_id(core_3d::graph::node::TONEMAPPING)
+                    .unwrap()
+            ]
+        );
+    }
+}
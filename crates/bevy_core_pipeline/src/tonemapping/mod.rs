@@ -178,15 +178,13 @@ pub struct TonemappingPipelineKey {
     tonemapping: Tonemapping,
 }
 
-impl SpecializedRenderPipeline for TonemappingPipeline {
-    type Key = TonemappingPipelineKey;
-
-    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+impl TonemappingPipelineKey {
+    fn shader_defs(&self) -> Vec<ShaderDefVal> {
         let mut shader_defs = Vec::new();
-        if let DebandDither::Enabled = key.deband_dither {
+        if let DebandDither::Enabled = self.deband_dither {
             shader_defs.push("DEBAND_DITHER".into());
         }
-        match key.tonemapping {
+        match self.tonemapping {
             Tonemapping::None => shader_defs.push("TONEMAP_METHOD_NONE".into()),
             Tonemapping::Reinhard => shader_defs.push("TONEMAP_METHOD_REINHARD".into()),
             Tonemapping::ReinhardLuminance => {
@@ -202,6 +200,15 @@ impl SpecializedRenderPipeline for TonemappingPipeline {
                 shader_defs.push("TONEMAP_METHOD_BLENDER_FILMIC".into());
             }
         }
+        shader_defs
+    }
+}
+
+impl SpecializedRenderPipeline for TonemappingPipeline {
+    type Key = TonemappingPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let shader_defs = key.shader_defs();
         RenderPipelineDescriptor {
             label: Some("tonemapping pipeline".into()),
             layout: vec![self.texture_bind_group.clone()],
@@ -405,5 +412,29 @@ pub fn lut_placeholder() -> Image {
         },
         sampler_descriptor: ImageSampler::Default,
         texture_view_descriptor: None,
+        ..default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switching_tonemapper_changes_pipeline_key() {
+        let key = |tonemapping| TonemappingPipelineKey {
+            deband_dither: DebandDither::Disabled,
+            tonemapping,
+        };
+
+        assert_ne!(key(Tonemapping::AgX), key(Tonemapping::TonyMcMapface));
+        assert_eq!(
+            key(Tonemapping::TonyMcMapface).shader_defs(),
+            vec!["TONEMAP_METHOD_TONY_MC_MAPFACE".into()]
+        );
+        assert_eq!(
+            key(Tonemapping::AgX).shader_defs(),
+            vec!["TONEMAP_METHOD_AGX".into()]
+        );
     }
 }
@@ -1,23 +1,101 @@
 use bevy_ecs::system::Resource;
-use bevy_log::warn;
-use bevy_utils::{Duration, Instant, StableHashMap, Uuid};
-use std::{borrow::Cow, collections::VecDeque};
+use bevy_utils::{Duration, Instant, StableHashMap};
+use std::{borrow::Cow, collections::VecDeque, fmt};
+
+/// Unique identifier for a [`Diagnostic`], made of components separated by `/`.
+///
+/// Paths group related diagnostics together, like `frame_time` or `system/my_game::move_player`.
+/// A path can't be empty, can't start or end with a `/`, and can't contain empty components.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DiagnosticPath {
+    path: Cow<'static, str>,
+}
+
+impl DiagnosticPath {
+    /// Create a new [`DiagnosticPath`] from a static string. Usable in const contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is empty, starts or ends with a `/`, or contains empty components.
+    pub const fn const_new(path: &'static str) -> DiagnosticPath {
+        Self::validate(path);
+        DiagnosticPath {
+            path: Cow::Borrowed(path),
+        }
+    }
+
+    /// Create a new [`DiagnosticPath`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is empty, starts or ends with a `/`, or contains empty components.
+    pub fn new(path: impl Into<Cow<'static, str>>) -> DiagnosticPath {
+        let path = path.into();
+        Self::validate(&path);
+        DiagnosticPath { path }
+    }
+
+    /// Create a new [`DiagnosticPath`] by joining the given components with `/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no components, or if any of them is empty or contains a `/`.
+    pub fn from_components<'a>(components: impl IntoIterator<Item = &'a str>) -> DiagnosticPath {
+        let mut path = String::new();
+        for (i, component) in components.into_iter().enumerate() {
+            if i > 0 {
+                path.push('/');
+            }
+            assert!(
+                !component.contains('/'),
+                "diagnostic path components can't contain `/`"
+            );
+            path.push_str(component);
+        }
+        Self::new(path)
+    }
 
-use crate::MAX_DIAGNOSTIC_NAME_WIDTH;
+    /// Returns the full path as a string.
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
 
-/// Unique identifier for a [Diagnostic]
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
-pub struct DiagnosticId(pub Uuid);
+    /// Returns an iterator over the components of this path.
+    pub fn components(&self) -> impl Iterator<Item = &str> + '_ {
+        self.path.split('/')
+    }
 
-impl DiagnosticId {
-    pub const fn from_u128(value: u128) -> Self {
-        DiagnosticId(Uuid::from_u128(value))
+    /// Returns `true` if this path is `other`, or is nested inside of it.
+    ///
+    /// For example `system/my_game::move_player` starts with `system`, but not with `sys`.
+    pub fn starts_with(&self, other: &DiagnosticPath) -> bool {
+        match self.path.strip_prefix(other.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with('/'),
+            None => false,
+        }
+    }
+
+    const fn validate(path: &str) {
+        let bytes = path.as_bytes();
+        assert!(!bytes.is_empty(), "diagnostic path can't be empty");
+        assert!(
+            bytes[0] != b'/' && bytes[bytes.len() - 1] != b'/',
+            "diagnostic path can't start or end with `/`"
+        );
+        let mut i = 1;
+        while i < bytes.len() {
+            assert!(
+                !(bytes[i] == b'/' && bytes[i - 1] == b'/'),
+                "diagnostic path can't contain empty components"
+            );
+            i += 1;
+        }
     }
 }
 
-impl Default for DiagnosticId {
-    fn default() -> Self {
-        DiagnosticId(Uuid::new_v4())
+impl fmt::Display for DiagnosticPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path.fmt(f)
     }
 }
 
@@ -32,8 +110,7 @@ pub struct DiagnosticMeasurement {
 /// Diagnostic examples: frames per second, CPU usage, network latency
 #[derive(Debug)]
 pub struct Diagnostic {
-    pub id: DiagnosticId,
-    pub name: Cow<'static, str>,
+    path: DiagnosticPath,
     pub suffix: Cow<'static, str>,
     history: VecDeque<DiagnosticMeasurement>,
     sum: f64,
@@ -46,8 +123,13 @@ pub struct Diagnostic {
 impl Diagnostic {
     /// Add a new value as a [`DiagnosticMeasurement`]. Its timestamp will be [`Instant::now`].
     pub fn add_measurement(&mut self, value: f64) {
-        let time = Instant::now();
+        self.add_measurement_at(Instant::now(), value);
+    }
 
+    /// Add a new value as a [`DiagnosticMeasurement`] taken at the given `time`.
+    ///
+    /// Measurements are expected to be added in chronological order.
+    pub fn add_measurement_at(&mut self, time: Instant, value: f64) {
         if let Some(previous) = self.measurement() {
             let delta = (time - previous.time).as_secs_f64();
             let alpha = (delta / self.ema_smoothing_factor).clamp(0.0, 1.0);
@@ -73,24 +155,10 @@ impl Diagnostic {
             .push_back(DiagnosticMeasurement { time, value });
     }
 
-    /// Create a new diagnostic with the given ID, name and maximum history.
-    pub fn new(
-        id: DiagnosticId,
-        name: impl Into<Cow<'static, str>>,
-        max_history_length: usize,
-    ) -> Diagnostic {
-        let name = name.into();
-        if name.chars().count() > MAX_DIAGNOSTIC_NAME_WIDTH {
-            // This could be a false positive due to a unicode width being shorter
-            warn!(
-                "Diagnostic {:?} has name longer than {} characters, and so might overflow in the LogDiagnosticsPlugin\
-                Consider using a shorter name.",
-                name, MAX_DIAGNOSTIC_NAME_WIDTH
-            );
-        }
+    /// Create a new diagnostic with the given path and maximum history.
+    pub fn new(path: DiagnosticPath, max_history_length: usize) -> Diagnostic {
         Diagnostic {
-            id,
-            name,
+            path,
             suffix: Cow::Borrowed(""),
             history: VecDeque::with_capacity(max_history_length),
             max_history_length,
@@ -101,6 +169,11 @@ impl Diagnostic {
         }
     }
 
+    /// Get the [`DiagnosticPath`] that identifies this diagnostic.
+    pub fn path(&self) -> &DiagnosticPath {
+        &self.path
+    }
+
     /// Add a suffix to use when logging the value, can be used to show a unit.
     #[must_use]
     pub fn with_suffix(mut self, suffix: impl Into<Cow<'static, str>>) -> Self {
@@ -183,6 +256,18 @@ impl Diagnostic {
         self.max_history_length
     }
 
+    /// Set the maximum number of elements for this diagnostic.
+    ///
+    /// If the history is longer than the new maximum, the oldest measurements are dropped.
+    pub fn set_max_history_length(&mut self, max_history_length: usize) {
+        self.max_history_length = max_history_length;
+        while self.history.len() > max_history_length.max(1) {
+            if let Some(removed_diagnostic) = self.history.pop_front() {
+                self.sum -= removed_diagnostic.value;
+            }
+        }
+    }
+
     pub fn values(&self) -> impl Iterator<Item = &f64> {
         self.history.iter().map(|x| &x.value)
     }
@@ -194,35 +279,38 @@ impl Diagnostic {
     /// Clear the history of this diagnostic.
     pub fn clear_history(&mut self) {
         self.history.clear();
+        self.sum = 0.0;
     }
 }
 
 /// A collection of [Diagnostic]s
 #[derive(Debug, Default, Resource)]
-pub struct Diagnostics {
+pub struct DiagnosticsStore {
     // This uses a [`StableHashMap`] to ensure that the iteration order is deterministic between
     // runs when all diagnostics are inserted in the same order.
-    diagnostics: StableHashMap<DiagnosticId, Diagnostic>,
+    diagnostics: StableHashMap<DiagnosticPath, Diagnostic>,
 }
 
-impl Diagnostics {
+impl DiagnosticsStore {
     /// Add a new [`Diagnostic`].
+    ///
+    /// If a diagnostic with the same path already exists, it is replaced.
     pub fn add(&mut self, diagnostic: Diagnostic) {
-        self.diagnostics.insert(diagnostic.id, diagnostic);
+        self.diagnostics.insert(diagnostic.path.clone(), diagnostic);
     }
 
-    pub fn get(&self, id: DiagnosticId) -> Option<&Diagnostic> {
-        self.diagnostics.get(&id)
+    pub fn get(&self, path: &DiagnosticPath) -> Option<&Diagnostic> {
+        self.diagnostics.get(path)
     }
 
-    pub fn get_mut(&mut self, id: DiagnosticId) -> Option<&mut Diagnostic> {
-        self.diagnostics.get_mut(&id)
+    pub fn get_mut(&mut self, path: &DiagnosticPath) -> Option<&mut Diagnostic> {
+        self.diagnostics.get_mut(path)
     }
 
     /// Get the latest [`DiagnosticMeasurement`] from an enabled [`Diagnostic`].
-    pub fn get_measurement(&self, id: DiagnosticId) -> Option<&DiagnosticMeasurement> {
+    pub fn get_measurement(&self, path: &DiagnosticPath) -> Option<&DiagnosticMeasurement> {
         self.diagnostics
-            .get(&id)
+            .get(path)
             .filter(|diagnostic| diagnostic.is_enabled)
             .and_then(|diagnostic| diagnostic.measurement())
     }
@@ -230,13 +318,13 @@ impl Diagnostics {
     /// Add a measurement to an enabled [`Diagnostic`]. The measurement is passed as a function so that
     /// it will be evaluated only if the [`Diagnostic`] is enabled. This can be useful if the value is
     /// costly to calculate.
-    pub fn add_measurement<F>(&mut self, id: DiagnosticId, value: F)
+    pub fn add_measurement<F>(&mut self, path: &DiagnosticPath, value: F)
     where
         F: FnOnce() -> f64,
     {
         if let Some(diagnostic) = self
             .diagnostics
-            .get_mut(&id)
+            .get_mut(path)
             .filter(|diagnostic| diagnostic.is_enabled)
         {
             diagnostic.add_measurement(value());
@@ -247,4 +335,127 @@ impl Diagnostics {
     pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
         self.diagnostics.values()
     }
+
+    /// Return an iterator over all [`Diagnostic`], allowing them to be modified.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Diagnostic> {
+        self.diagnostics.values_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostic_path() {
+        const FRAME_TIME: DiagnosticPath = DiagnosticPath::const_new("frame/time");
+        assert_eq!(FRAME_TIME, DiagnosticPath::new("frame/time"));
+        assert_eq!(
+            FRAME_TIME,
+            DiagnosticPath::from_components(["frame", "time"])
+        );
+        assert_eq!(
+            FRAME_TIME.components().collect::<Vec<_>>(),
+            ["frame", "time"]
+        );
+        assert_eq!(FRAME_TIME.to_string(), "frame/time");
+
+        assert!(FRAME_TIME.starts_with(&DiagnosticPath::new("frame")));
+        assert!(FRAME_TIME.starts_with(&FRAME_TIME));
+        assert!(!FRAME_TIME.starts_with(&DiagnosticPath::new("fra")));
+        assert!(!DiagnosticPath::new("frame").starts_with(&FRAME_TIME));
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_diagnostic_path() {
+        DiagnosticPath::new("");
+    }
+
+    #[test]
+    #[should_panic]
+    fn diagnostic_path_trailing_slash() {
+        DiagnosticPath::new("frame/");
+    }
+
+    #[test]
+    #[should_panic]
+    fn diagnostic_path_empty_component() {
+        DiagnosticPath::new("frame//time");
+    }
+
+    #[test]
+    fn smoothed() {
+        let start = Instant::now();
+        let mut diagnostic =
+            Diagnostic::new(DiagnosticPath::new("test"), 20).with_smoothing_factor(1.0);
+        assert_eq!(diagnostic.smoothed(), None);
+
+        // The first measurement is used as is
+        diagnostic.add_measurement_at(start, 10.0);
+        assert_eq!(diagnostic.smoothed(), Some(10.0));
+
+        // Half of the smoothing factor elapsed, the value moves halfway: 10 + 0.5 * (20 - 10)
+        diagnostic.add_measurement_at(start + Duration::from_millis(500), 20.0);
+        assert_eq!(diagnostic.smoothed(), Some(15.0));
+
+        // A quarter of the smoothing factor elapsed: 15 + 0.25 * (0 - 15)
+        diagnostic.add_measurement_at(start + Duration::from_millis(750), 0.0);
+        assert_eq!(diagnostic.smoothed(), Some(11.25));
+
+        // More than the smoothing factor elapsed, no smoothing is applied
+        diagnostic.add_measurement_at(start + Duration::from_millis(2750), 4.0);
+        assert_eq!(diagnostic.smoothed(), Some(4.0));
+        assert_eq!(diagnostic.value(), Some(4.0));
+        assert_eq!(diagnostic.average(), Some(8.5));
+    }
+
+    #[test]
+    fn smoothing_disabled() {
+        let start = Instant::now();
+        let mut diagnostic =
+            Diagnostic::new(DiagnosticPath::new("test"), 20).with_smoothing_factor(0.0);
+        for (i, value) in [3.0, 9.0, 1.0].into_iter().enumerate() {
+            diagnostic.add_measurement_at(start + Duration::from_millis(i as u64 * 16), value);
+            assert_eq!(diagnostic.smoothed(), Some(value));
+        }
+    }
+
+    #[test]
+    fn set_max_history_length() {
+        let mut diagnostics = DiagnosticsStore::default();
+        diagnostics.add(Diagnostic::new(DiagnosticPath::new("test"), 4));
+        let path = DiagnosticPath::new("test");
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            diagnostics.add_measurement(&path, || value);
+        }
+
+        for diagnostic in diagnostics.iter_mut() {
+            diagnostic.set_max_history_length(2);
+        }
+        let diagnostic = diagnostics.get(&path).unwrap();
+        assert_eq!(diagnostic.get_max_history_length(), 2);
+        assert_eq!(diagnostic.history_len(), 2);
+        assert_eq!(diagnostic.average(), Some(3.5));
+    }
+
+    #[test]
+    fn add_measurement() {
+        let path = DiagnosticPath::new("test/value");
+        let mut diagnostics = DiagnosticsStore::default();
+        diagnostics.add(Diagnostic::new(path.clone(), 2));
+
+        diagnostics.add_measurement(&path, || 1.0);
+        diagnostics.add_measurement(&path, || 2.0);
+        diagnostics.add_measurement(&path, || 3.0);
+        let diagnostic = diagnostics.get(&path).unwrap();
+        assert_eq!(diagnostic.value(), Some(3.0));
+        assert_eq!(diagnostic.history_len(), 2);
+        assert_eq!(diagnostic.average(), Some(2.5));
+
+        diagnostics.get_mut(&path).unwrap().is_enabled = false;
+        diagnostics.add_measurement(&path, || 4.0);
+        assert!(diagnostics.get_measurement(&path).is_none());
+        assert_eq!(diagnostics.get(&path).unwrap().value(), Some(3.0));
+    }
 }
@@ -1,7 +1,7 @@
 use bevy_app::prelude::*;
 use bevy_ecs::{entity::Entities, prelude::*};
 
-use crate::{Diagnostic, DiagnosticId, Diagnostics};
+use crate::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 
 /// Adds "entity count" diagnostic to an App
 #[derive(Default)]
@@ -15,14 +15,13 @@ impl Plugin for EntityCountDiagnosticsPlugin {
 }
 
 impl EntityCountDiagnosticsPlugin {
-    pub const ENTITY_COUNT: DiagnosticId =
-        DiagnosticId::from_u128(187513512115068938494459732780662867798);
+    pub const ENTITY_COUNT: DiagnosticPath = DiagnosticPath::const_new("entity_count");
 
-    pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
-        diagnostics.add(Diagnostic::new(Self::ENTITY_COUNT, "entity_count", 20));
+    pub fn setup_system(mut diagnostics: ResMut<DiagnosticsStore>) {
+        diagnostics.add(Diagnostic::new(Self::ENTITY_COUNT, 20));
     }
 
-    pub fn diagnostic_system(mut diagnostics: ResMut<Diagnostics>, entities: &Entities) {
-        diagnostics.add_measurement(Self::ENTITY_COUNT, || entities.len() as f64);
+    pub fn diagnostic_system(mut diagnostics: ResMut<DiagnosticsStore>, entities: &Entities) {
+        diagnostics.add_measurement(&Self::ENTITY_COUNT, || entities.len() as f64);
     }
 }
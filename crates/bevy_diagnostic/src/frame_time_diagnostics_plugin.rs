@@ -1,10 +1,14 @@
-use crate::{Diagnostic, DiagnosticId, Diagnostics};
+use crate::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 use bevy_app::prelude::*;
 use bevy_core::FrameCount;
 use bevy_ecs::prelude::*;
 use bevy_time::Time;
 
 /// Adds "frame time" diagnostic to an App, specifically "frame time", "fps" and "frame count"
+///
+/// Both the raw value of the latest frame ([`Diagnostic::value`]) and an exponential moving
+/// average ([`Diagnostic::smoothed`]) are available for "frame time" and "fps". The latter is
+/// better suited to FPS counters, as it doesn't jump around from one frame to the next.
 #[derive(Default)]
 pub struct FrameTimeDiagnosticsPlugin;
 
@@ -16,33 +20,30 @@ impl Plugin for FrameTimeDiagnosticsPlugin {
 }
 
 impl FrameTimeDiagnosticsPlugin {
-    pub const FPS: DiagnosticId = DiagnosticId::from_u128(288146834822086093791974408528866909483);
-    pub const FRAME_COUNT: DiagnosticId =
-        DiagnosticId::from_u128(54021991829115352065418785002088010277);
-    pub const FRAME_TIME: DiagnosticId =
-        DiagnosticId::from_u128(73441630925388532774622109383099159699);
-
-    pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
-        diagnostics.add(Diagnostic::new(Self::FRAME_TIME, "frame_time", 20).with_suffix("ms"));
-        diagnostics.add(Diagnostic::new(Self::FPS, "fps", 20));
-        diagnostics
-            .add(Diagnostic::new(Self::FRAME_COUNT, "frame_count", 1).with_smoothing_factor(0.0));
+    pub const FPS: DiagnosticPath = DiagnosticPath::const_new("fps");
+    pub const FRAME_COUNT: DiagnosticPath = DiagnosticPath::const_new("frame_count");
+    pub const FRAME_TIME: DiagnosticPath = DiagnosticPath::const_new("frame_time");
+
+    pub fn setup_system(mut diagnostics: ResMut<DiagnosticsStore>) {
+        diagnostics.add(Diagnostic::new(Self::FRAME_TIME, 20).with_suffix("ms"));
+        diagnostics.add(Diagnostic::new(Self::FPS, 20));
+        diagnostics.add(Diagnostic::new(Self::FRAME_COUNT, 1).with_smoothing_factor(0.0));
     }
 
     pub fn diagnostic_system(
-        mut diagnostics: ResMut<Diagnostics>,
+        mut diagnostics: ResMut<DiagnosticsStore>,
         time: Res<Time>,
         frame_count: Res<FrameCount>,
     ) {
-        diagnostics.add_measurement(Self::FRAME_COUNT, || frame_count.0 as f64);
+        diagnostics.add_measurement(&Self::FRAME_COUNT, || frame_count.0 as f64);
 
         let delta_seconds = time.raw_delta_seconds_f64();
         if delta_seconds == 0.0 {
             return;
         }
 
-        diagnostics.add_measurement(Self::FRAME_TIME, || delta_seconds * 1000.0);
+        diagnostics.add_measurement(&Self::FRAME_TIME, || delta_seconds * 1000.0);
 
-        diagnostics.add_measurement(Self::FPS, || 1.0 / delta_seconds);
+        diagnostics.add_measurement(&Self::FPS, || 1.0 / delta_seconds);
     }
 }
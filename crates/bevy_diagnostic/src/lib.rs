@@ -3,6 +3,8 @@ mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 mod system_information_diagnostics_plugin;
+#[cfg(feature = "system_timing")]
+mod system_time_diagnostics_plugin;
 
 use bevy_app::prelude::*;
 pub use diagnostic::*;
@@ -10,6 +12,8 @@ pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
 pub use system_information_diagnostics_plugin::SystemInformationDiagnosticsPlugin;
+#[cfg(feature = "system_timing")]
+pub use system_time_diagnostics_plugin::{SlowestSystems, SystemTimeDiagnosticsPlugin};
 
 /// Adds core diagnostics resources to an App.
 #[derive(Default)]
@@ -17,11 +21,10 @@ pub struct DiagnosticsPlugin;
 
 impl Plugin for DiagnosticsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Diagnostics>()
+        app.init_resource::<DiagnosticsStore>()
             .add_startup_system(system_information_diagnostics_plugin::internal::log_system_info);
     }
 }
 
-/// The width which diagnostic names will be printed as
-/// Plugin names should not be longer than this value
+/// The minimum width which diagnostic paths will be printed as by the [`LogDiagnosticsPlugin`]
 pub const MAX_DIAGNOSTIC_NAME_WIDTH: usize = 32;
@@ -1,4 +1,4 @@
-use super::{Diagnostic, DiagnosticId, Diagnostics};
+use super::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_log::{debug, info};
@@ -9,14 +9,35 @@ use bevy_utils::Duration;
 pub struct LogDiagnosticsPlugin {
     pub debug: bool,
     pub wait_duration: Duration,
-    pub filter: Option<Vec<DiagnosticId>>,
+    /// If set, only the diagnostics whose path starts with one of these paths are logged.
+    ///
+    /// For example, filtering on `system` logs every `system/...` diagnostic.
+    pub filter: Option<Vec<DiagnosticPath>>,
 }
 
 /// State used by the [`LogDiagnosticsPlugin`]
 #[derive(Resource)]
 struct LogDiagnosticsState {
     timer: Timer,
-    filter: Option<Vec<DiagnosticId>>,
+    filter: Option<Vec<DiagnosticPath>>,
+}
+
+impl LogDiagnosticsState {
+    /// Returns the enabled diagnostics that pass the filter.
+    fn filtered<'a>(
+        &'a self,
+        diagnostics: &'a DiagnosticsStore,
+    ) -> impl Iterator<Item = &'a Diagnostic> + 'a {
+        diagnostics.iter().filter(move |diagnostic| {
+            diagnostic.is_enabled
+                && match &self.filter {
+                    Some(filter) => filter
+                        .iter()
+                        .any(|path| diagnostic.path().starts_with(path)),
+                    None => true,
+                }
+        })
+    }
 }
 
 impl Default for LogDiagnosticsPlugin {
@@ -45,14 +66,14 @@ impl Plugin for LogDiagnosticsPlugin {
 }
 
 impl LogDiagnosticsPlugin {
-    pub fn filtered(filter: Vec<DiagnosticId>) -> Self {
+    pub fn filtered(filter: Vec<DiagnosticPath>) -> Self {
         LogDiagnosticsPlugin {
             filter: Some(filter),
             ..Default::default()
         }
     }
 
-    fn log_diagnostic(diagnostic: &Diagnostic) {
+    fn log_diagnostic(diagnostic: &Diagnostic, path_width: usize) {
         if let Some(value) = diagnostic.smoothed() {
             if diagnostic.get_max_history_length() > 1 {
                 if let Some(average) = diagnostic.average() {
@@ -62,20 +83,18 @@ impl LogDiagnosticsPlugin {
                         // so we reserve two columns for it; however,
                         // Do not reserve columns for the suffix in the average
                         // The ) hugging the value is more aesthetically pleasing
-                        "{name:<name_width$}: {value:>11.6}{suffix:2} (avg {average:>.6}{suffix:})",
-                        name = diagnostic.name,
+                        "{path:<path_width$}: {value:>11.6}{suffix:2} (avg {average:>.6}{suffix:})",
+                        path = diagnostic.path(),
                         suffix = diagnostic.suffix,
-                        name_width = crate::MAX_DIAGNOSTIC_NAME_WIDTH,
                     );
                     return;
                 }
             }
             info!(
                 target: "bevy diagnostic",
-                "{name:<name_width$}: {value:>.6}{suffix:}",
-                name = diagnostic.name,
+                "{path:<path_width$}: {value:>.6}{suffix:}",
+                path = diagnostic.path(),
                 suffix = diagnostic.suffix,
-                name_width = crate::MAX_DIAGNOSTIC_NAME_WIDTH,
             );
         }
     }
@@ -83,24 +102,18 @@ impl LogDiagnosticsPlugin {
     fn log_diagnostics_system(
         mut state: ResMut<LogDiagnosticsState>,
         time: Res<Time>,
-        diagnostics: Res<Diagnostics>,
+        diagnostics: Res<DiagnosticsStore>,
     ) {
         if state.timer.tick(time.raw_delta()).finished() {
-            if let Some(ref filter) = state.filter {
-                for diagnostic in filter.iter().flat_map(|id| {
-                    diagnostics
-                        .get(*id)
-                        .filter(|diagnostic| diagnostic.is_enabled)
-                }) {
-                    Self::log_diagnostic(diagnostic);
-                }
-            } else {
-                for diagnostic in diagnostics
-                    .iter()
-                    .filter(|diagnostic| diagnostic.is_enabled)
-                {
-                    Self::log_diagnostic(diagnostic);
-                }
+            // Align the values of every logged diagnostic
+            let path_width = state
+                .filtered(&diagnostics)
+                .map(|diagnostic| diagnostic.path().as_str().chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(crate::MAX_DIAGNOSTIC_NAME_WIDTH);
+            for diagnostic in state.filtered(&diagnostics) {
+                Self::log_diagnostic(diagnostic, path_width);
             }
         }
     }
@@ -108,24 +121,11 @@ impl LogDiagnosticsPlugin {
     fn log_diagnostics_debug_system(
         mut state: ResMut<LogDiagnosticsState>,
         time: Res<Time>,
-        diagnostics: Res<Diagnostics>,
+        diagnostics: Res<DiagnosticsStore>,
     ) {
         if state.timer.tick(time.raw_delta()).finished() {
-            if let Some(ref filter) = state.filter {
-                for diagnostic in filter.iter().flat_map(|id| {
-                    diagnostics
-                        .get(*id)
-                        .filter(|diagnostic| diagnostic.is_enabled)
-                }) {
-                    debug!("{:#?}\n", diagnostic);
-                }
-            } else {
-                for diagnostic in diagnostics
-                    .iter()
-                    .filter(|diagnostic| diagnostic.is_enabled)
-                {
-                    debug!("{:#?}\n", diagnostic);
-                }
+            for diagnostic in state.filtered(&diagnostics) {
+                debug!("{:#?}\n", diagnostic);
             }
         }
     }
@@ -1,7 +1,9 @@
-use crate::DiagnosticId;
+use crate::DiagnosticPath;
 use bevy_app::prelude::*;
 
 /// Adds a System Information Diagnostic, specifically `cpu_usage` (in %) and `mem_usage` (in %)
+/// of the whole host, as well as the `cpu_usage` (in %) and resident memory (in GiB) of the
+/// current process.
 ///
 /// Supported targets:
 /// * linux,
@@ -20,10 +22,16 @@ impl Plugin for SystemInformationDiagnosticsPlugin {
 }
 
 impl SystemInformationDiagnosticsPlugin {
-    pub const CPU_USAGE: DiagnosticId =
-        DiagnosticId::from_u128(78494871623549551581510633532637320956);
-    pub const MEM_USAGE: DiagnosticId =
-        DiagnosticId::from_u128(42846254859293759601295317811892519825);
+    /// Total CPU usage of the host, in %.
+    pub const CPU_USAGE: DiagnosticPath = DiagnosticPath::const_new("host/cpu_usage");
+    /// Memory usage of the host, in % of the total memory.
+    pub const MEM_USAGE: DiagnosticPath = DiagnosticPath::const_new("host/mem_usage");
+    /// CPU usage of the current process, in %.
+    ///
+    /// This can exceed 100% when the process keeps several cores busy.
+    pub const PROCESS_CPU_USAGE: DiagnosticPath = DiagnosticPath::const_new("process/cpu_usage");
+    /// Resident memory (RSS) of the current process, in GiB.
+    pub const PROCESS_MEM_USAGE: DiagnosticPath = DiagnosticPath::const_new("process/mem_usage");
 }
 
 // NOTE: sysinfo fails to compile when using bevy dynamic or on iOS and does nothing on wasm
@@ -39,43 +47,54 @@ impl SystemInformationDiagnosticsPlugin {
 pub mod internal {
     use bevy_ecs::{prelude::ResMut, system::Local};
     use bevy_log::info;
-    use sysinfo::{CpuExt, CpuRefreshKind, RefreshKind, System, SystemExt};
+    use sysinfo::{
+        CpuExt, CpuRefreshKind, Pid, ProcessExt, ProcessRefreshKind, RefreshKind, System, SystemExt,
+    };
 
-    use crate::{Diagnostic, Diagnostics};
+    use crate::{Diagnostic, DiagnosticsStore};
 
     const BYTES_TO_GIB: f64 = 1.0 / 1024.0 / 1024.0 / 1024.0;
 
-    pub(crate) fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
+    pub(crate) fn setup_system(mut diagnostics: ResMut<DiagnosticsStore>) {
+        diagnostics.add(
+            Diagnostic::new(super::SystemInformationDiagnosticsPlugin::CPU_USAGE, 20)
+                .with_suffix("%"),
+        );
+        diagnostics.add(
+            Diagnostic::new(super::SystemInformationDiagnosticsPlugin::MEM_USAGE, 20)
+                .with_suffix("%"),
+        );
         diagnostics.add(
             Diagnostic::new(
-                super::SystemInformationDiagnosticsPlugin::CPU_USAGE,
-                "cpu_usage",
+                super::SystemInformationDiagnosticsPlugin::PROCESS_CPU_USAGE,
                 20,
             )
             .with_suffix("%"),
         );
         diagnostics.add(
             Diagnostic::new(
-                super::SystemInformationDiagnosticsPlugin::MEM_USAGE,
-                "mem_usage",
+                super::SystemInformationDiagnosticsPlugin::PROCESS_MEM_USAGE,
                 20,
             )
-            .with_suffix("%"),
+            .with_suffix("GiB"),
         );
     }
 
     pub(crate) fn diagnostic_system(
-        mut diagnostics: ResMut<Diagnostics>,
-        mut sysinfo: Local<Option<System>>,
+        mut diagnostics: ResMut<DiagnosticsStore>,
+        mut sysinfo: Local<Option<(System, Option<Pid>)>>,
     ) {
         if sysinfo.is_none() {
-            *sysinfo = Some(System::new_with_specifics(
-                RefreshKind::new()
-                    .with_cpu(CpuRefreshKind::new().with_cpu_usage())
-                    .with_memory(),
+            *sysinfo = Some((
+                System::new_with_specifics(
+                    RefreshKind::new()
+                        .with_cpu(CpuRefreshKind::new().with_cpu_usage())
+                        .with_memory(),
+                ),
+                sysinfo::get_current_pid().ok(),
             ));
         }
-        let Some(sys) = sysinfo.as_mut() else {
+        let Some((sys, pid)) = sysinfo.as_mut() else {
             return;
         };
 
@@ -87,12 +106,33 @@ pub mod internal {
         let used_mem = sys.used_memory() as f64 / BYTES_TO_GIB;
         let current_used_mem = used_mem / total_mem * 100.0;
 
-        diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::CPU_USAGE, || {
-            current_cpu_usage as f64
-        });
-        diagnostics.add_measurement(super::SystemInformationDiagnosticsPlugin::MEM_USAGE, || {
-            current_used_mem
-        });
+        diagnostics.add_measurement(
+            &super::SystemInformationDiagnosticsPlugin::CPU_USAGE,
+            || current_cpu_usage as f64,
+        );
+        diagnostics.add_measurement(
+            &super::SystemInformationDiagnosticsPlugin::MEM_USAGE,
+            || current_used_mem,
+        );
+
+        let Some(pid) = *pid else {
+            return;
+        };
+        sys.refresh_process_specifics(pid, ProcessRefreshKind::new().with_cpu());
+        let Some(process) = sys.process(pid) else {
+            return;
+        };
+        let process_cpu_usage = process.cpu_usage();
+        let process_mem_usage = process.memory() as f64 * BYTES_TO_GIB;
+
+        diagnostics.add_measurement(
+            &super::SystemInformationDiagnosticsPlugin::PROCESS_CPU_USAGE,
+            || process_cpu_usage as f64,
+        );
+        diagnostics.add_measurement(
+            &super::SystemInformationDiagnosticsPlugin::PROCESS_MEM_USAGE,
+            || process_mem_usage,
+        );
     }
 
     #[derive(Debug)]
@@ -0,0 +1,158 @@
+use crate::{Diagnostic, DiagnosticPath, DiagnosticsStore};
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{BoxedScheduleLabel, ScheduleLabel, SystemDurations},
+};
+use bevy_utils::{Duration, HashMap};
+use std::borrow::Cow;
+
+/// Adds a diagnostic for the time each system takes to run, in milliseconds.
+///
+/// Each system gets its own diagnostic under `system/{system_name}`, see
+/// [`SystemTimeDiagnosticsPlugin::system_path`]. The slowest systems of each schedule are also
+/// available in the [`SlowestSystems`] resource.
+///
+/// Durations are recorded by the schedule executors, which is only done when the
+/// `system_timing` feature is enabled. Systems that ran during a frame are measured at the
+/// start of the next frame, once their schedule has finished.
+pub struct SystemTimeDiagnosticsPlugin {
+    /// The number of systems to keep in [`SlowestSystems`] for each schedule.
+    pub max_slowest_systems: usize,
+}
+
+impl Default for SystemTimeDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_slowest_systems: 5,
+        }
+    }
+}
+
+impl Plugin for SystemTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SystemDurations>()
+            .insert_resource(SlowestSystems {
+                max_systems: self.max_slowest_systems,
+                schedules: HashMap::new(),
+            })
+            .add_system(Self::diagnostic_system.in_base_set(CoreSet::First));
+    }
+}
+
+impl SystemTimeDiagnosticsPlugin {
+    /// The root of the paths of every system time diagnostic.
+    pub const SYSTEM: DiagnosticPath = DiagnosticPath::const_new("system");
+
+    /// Returns the path of the diagnostic measuring the system called `system_name`.
+    pub fn system_path(system_name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components([Self::SYSTEM.as_str(), system_name])
+    }
+
+    pub fn diagnostic_system(
+        mut diagnostics: ResMut<DiagnosticsStore>,
+        mut durations: ResMut<SystemDurations>,
+        mut slowest_systems: ResMut<SlowestSystems>,
+    ) {
+        // A system may be part of multiple schedules, its diagnostic measures its total time
+        let mut totals: HashMap<Cow<'static, str>, Duration> = HashMap::new();
+        for (label, systems) in durations.drain() {
+            let mut slowest: Vec<_> = systems.into_iter().collect();
+            for (name, duration) in &slowest {
+                *totals.entry(name.clone()).or_default() += *duration;
+            }
+            slowest.sort_by(|(_, a), (_, b)| b.cmp(a));
+            slowest.truncate(slowest_systems.max_systems);
+            slowest_systems.schedules.insert(label, slowest);
+        }
+
+        for (name, duration) in totals {
+            let path = Self::system_path(&name);
+            if diagnostics.get(&path).is_none() {
+                diagnostics.add(Diagnostic::new(path.clone(), 20).with_suffix("ms"));
+            }
+            diagnostics.add_measurement(&path, || duration.as_secs_f64() * 1000.0);
+        }
+    }
+}
+
+/// The slowest systems of each schedule during its latest run, as measured by the
+/// [`SystemTimeDiagnosticsPlugin`].
+#[derive(Resource)]
+pub struct SlowestSystems {
+    max_systems: usize,
+    schedules: HashMap<BoxedScheduleLabel, Vec<(Cow<'static, str>, Duration)>>,
+}
+
+impl SlowestSystems {
+    /// Returns the names of the slowest systems of the schedule with the given `label`, along with
+    /// the time they took to run, sorted from slowest to fastest.
+    pub fn get(&self, label: &dyn ScheduleLabel) -> Option<&[(Cow<'static, str>, Duration)]> {
+        self.schedules.get(label).map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the slowest systems of every measured schedule.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&dyn ScheduleLabel, &[(Cow<'static, str>, Duration)])> {
+        self.schedules
+            .iter()
+            .map(|(label, systems)| (&**label, systems.as_slice()))
+    }
+
+    /// The maximum number of systems kept for each schedule.
+    pub fn max_systems(&self) -> usize {
+        self.max_systems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticsPlugin;
+    use bevy_ecs::system::System;
+    use std::thread;
+
+    fn slow_system() {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    fn fast_system() {}
+
+    #[test]
+    fn records_system_time() {
+        let mut app = App::new();
+        app.add_plugin(DiagnosticsPlugin)
+            .add_plugin(SystemTimeDiagnosticsPlugin {
+                max_slowest_systems: 1,
+            })
+            .add_systems((slow_system, fast_system));
+
+        // The systems are measured once their schedule has run, at the start of the next frame
+        app.update();
+        app.update();
+
+        let slow_name = IntoSystem::into_system(slow_system).name();
+        let fast_name = IntoSystem::into_system(fast_system).name();
+        let diagnostics = app.world.resource::<DiagnosticsStore>();
+        let slow_time = diagnostics
+            .get(&SystemTimeDiagnosticsPlugin::system_path(&slow_name))
+            .and_then(Diagnostic::value)
+            .expect("the slow system should have been measured");
+        let fast_time = diagnostics
+            .get(&SystemTimeDiagnosticsPlugin::system_path(&fast_name))
+            .and_then(Diagnostic::value)
+            .expect("the fast system should have been measured");
+        assert!(slow_time >= 20.0);
+        assert!(fast_time < slow_time);
+
+        let slowest = app
+            .world
+            .resource::<SlowestSystems>()
+            .get(&CoreSchedule::Main)
+            .unwrap();
+        assert_eq!(slowest.len(), 1);
+        assert_eq!(slowest[0].0, slow_name);
+        assert!(slowest[0].1 >= Duration::from_millis(20));
+    }
+}
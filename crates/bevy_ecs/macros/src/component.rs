@@ -32,6 +32,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     };
 
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
+    let track_replacements = attrs.track_replacements;
 
     ast.generics
         .make_where_clause()
@@ -44,15 +45,18 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             type Storage = #storage;
+            const TRACK_REPLACEMENTS: bool = #track_replacements;
         }
     })
 }
 
 pub const COMPONENT: Symbol = Symbol("component");
 pub const STORAGE: Symbol = Symbol("storage");
+pub const TRACK_REPLACEMENTS: Symbol = Symbol("track_replacements");
 
 struct Attrs {
     storage: StorageTy,
+    track_replacements: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -70,11 +74,12 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
 
     let mut attrs = Attrs {
         storage: StorageTy::Table,
+        track_replacements: false,
     };
 
     for meta in meta_items {
         use syn::{
-            Meta::NameValue,
+            Meta::{NameValue, Path},
             NestedMeta::{Lit, Meta},
         };
         match meta {
@@ -92,6 +97,9 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                     }
                 };
             }
+            Meta(Path(p)) if p == TRACK_REPLACEMENTS => {
+                attrs.track_replacements = true;
+            }
             Meta(meta_item) => {
                 return Err(Error::new_spanned(
                     meta_item.path(),
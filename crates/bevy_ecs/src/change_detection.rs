@@ -6,7 +6,10 @@ use crate::{
     system::Resource,
 };
 use bevy_ptr::{Ptr, UnsafeCellDeref};
-use std::ops::{Deref, DerefMut};
+use std::{
+    mem,
+    ops::{Deref, DerefMut},
+};
 
 /// The (arbitrarily chosen) minimum number of world tick increments between `check_tick` scans.
 ///
@@ -125,8 +128,27 @@ pub trait DetectChangesMut: DetectChanges {
     ///
     /// This is useful to ensure change detection is only triggered when the underlying value
     /// changes, instead of every time [`DerefMut`] is used.
+    ///
+    /// Returns `true` if the value was overwritten, and change detection was triggered.
+    /// See [`replace_if_neq`](DetectChangesMut::replace_if_neq) to get the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, PartialEq, Eq)]
+    /// pub struct Score(u32);
+    ///
+    /// fn reset_score(mut score: ResMut<Score>) {
+    ///     // Set the score to zero, unless it is already zero.
+    ///     if score.set_if_neq(Score(0)) {
+    ///         println!("The score was reset");
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(reset_score);
+    /// ```
     #[inline]
-    fn set_if_neq(&mut self, value: Self::Inner)
+    fn set_if_neq(&mut self, value: Self::Inner) -> bool
     where
         Self::Inner: Sized + PartialEq,
     {
@@ -134,6 +156,32 @@ pub trait DetectChangesMut: DetectChanges {
         if *old != value {
             *old = value;
             self.set_changed();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Sets `self` to `value`, if and only if `*self != value`, returning the previous value.
+    ///
+    /// This is useful to ensure change detection is only triggered when the underlying value
+    /// changes, instead of every time [`DerefMut`] is used.
+    ///
+    /// Returns `None` if the values were equal, in which case change detection is not triggered.
+    /// See [`set_if_neq`](DetectChangesMut::set_if_neq) to only know whether the value changed.
+    #[inline]
+    #[must_use = "If you don't need to handle the previous value, use `set_if_neq` instead."]
+    fn replace_if_neq(&mut self, value: Self::Inner) -> Option<Self::Inner>
+    where
+        Self::Inner: Sized + PartialEq,
+    {
+        let old = self.bypass_change_detection();
+        if *old != value {
+            let previous = mem::replace(old, value);
+            self.set_changed();
+            Some(previous)
+        } else {
+            None
         }
     }
 }
@@ -743,7 +791,7 @@ mod tests {
     #[derive(Resource)]
     struct R;
 
-    #[derive(Resource, PartialEq)]
+    #[derive(Resource, Debug, PartialEq)]
     struct R2(u8);
 
     #[test]
@@ -838,6 +886,7 @@ mod tests {
         let mut component_ticks = ComponentTicks {
             added: Tick::new(1),
             changed: Tick::new(2),
+            replaced: Tick::new(0),
         };
         let ticks = TicksMut {
             added: &mut component_ticks.added,
@@ -863,6 +912,7 @@ mod tests {
         let mut component_ticks = ComponentTicks {
             added: Tick::new(1),
             changed: Tick::new(3),
+            replaced: Tick::new(0),
         };
         let mut res = R {};
 
@@ -883,6 +933,7 @@ mod tests {
         let mut component_ticks = ComponentTicks {
             added: Tick::new(1),
             changed: Tick::new(2),
+            replaced: Tick::new(0),
         };
         let ticks = TicksMut {
             added: &mut component_ticks.added,
@@ -913,6 +964,7 @@ mod tests {
         let mut component_ticks = ComponentTicks {
             added: Tick::new(1),
             changed: Tick::new(2),
+            replaced: Tick::new(0),
         };
         let ticks = TicksMut {
             added: &mut component_ticks.added,
@@ -964,4 +1016,40 @@ mod tests {
             "Resource must be changed after setting to a different value."
         );
     }
+
+    #[test]
+    fn set_if_neq_returns_changed() {
+        let mut world = World::new();
+        world.insert_resource(R2(0));
+        world.increment_change_tick();
+        world.clear_trackers();
+
+        let mut r = world.resource_mut::<R2>();
+        assert!(!r.set_if_neq(R2(0)));
+        assert!(!r.is_changed());
+        assert!(r.set_if_neq(R2(3)));
+        assert!(r.is_changed());
+    }
+
+    #[test]
+    fn replace_if_neq() {
+        let mut world = World::new();
+        world.insert_resource(R2(0));
+        world.increment_change_tick();
+        world.clear_trackers();
+
+        let mut r = world.resource_mut::<R2>();
+        assert_eq!(r.replace_if_neq(R2(0)), None);
+        assert!(
+            !r.is_changed(),
+            "Resource must not be changed after replacing with the same value."
+        );
+
+        assert_eq!(r.replace_if_neq(R2(3)), Some(R2(0)));
+        assert!(
+            r.is_changed(),
+            "Resource must be changed after replacing with a different value."
+        );
+        assert_eq!(*r, R2(3));
+    }
 }
@@ -88,6 +88,20 @@ use std::{
 /// [`Table`]: crate::storage::Table
 /// [`SparseSet`]: crate::storage::SparseSet
 ///
+/// # Tracking replacements
+///
+/// Components can also store when they were last replaced by inserting them again, for the
+/// [`Replaced`](crate::query::Replaced) filter. This costs one more tick per component, so it
+/// has to be enabled with the `#[component(track_replacements)]` attribute:
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// #
+/// #[derive(Component)]
+/// #[component(track_replacements)]
+/// struct ComponentA;
+/// ```
+///
 /// # Implementing the trait for foreign types
 ///
 /// As a consequence of the [orphan rule], it is not possible to separate into two different crates the implementation of `Component` from the definition of a type.
@@ -147,6 +161,10 @@ use std::{
 /// [`Exclusive`]: https://doc.rust-lang.org/nightly/std/sync/struct.Exclusive.html
 pub trait Component: Send + Sync + 'static {
     type Storage: ComponentStorage;
+
+    /// Whether the component stores when it was last replaced, see
+    /// [tracking replacements](Component#tracking-replacements).
+    const TRACK_REPLACEMENTS: bool = false;
 }
 
 pub struct TableStorage;
@@ -191,6 +209,9 @@ pub enum StorageType {
     SparseSet,
 }
 
+/// Stores metadata for a type of component or resource stored in a specific [`World`](crate::world::World).
+///
+/// All registered components can be iterated with [`Components::iter`].
 #[derive(Debug)]
 pub struct ComponentInfo {
     id: ComponentId,
@@ -198,21 +219,26 @@ pub struct ComponentInfo {
 }
 
 impl ComponentInfo {
+    /// Returns a value uniquely identifying the current component.
     #[inline]
     pub fn id(&self) -> ComponentId {
         self.id
     }
 
+    /// Returns the name of the current component.
     #[inline]
     pub fn name(&self) -> &str {
         &self.descriptor.name
     }
 
+    /// Returns the [`TypeId`] of the underlying component type.
+    /// Returns `None` if the component does not correspond to a Rust type.
     #[inline]
     pub fn type_id(&self) -> Option<TypeId> {
         self.descriptor.type_id
     }
 
+    /// Returns the layout used to store values of this component in memory.
     #[inline]
     pub fn layout(&self) -> Layout {
         self.descriptor.layout
@@ -229,16 +255,37 @@ impl ComponentInfo {
         self.descriptor.drop
     }
 
+    /// Returns a value indicating the storage strategy for the current component.
     #[inline]
     pub fn storage_type(&self) -> StorageType {
         self.descriptor.storage_type
     }
 
+    /// Returns `true` if the underlying component type can be freely shared between threads.
+    /// If this returns `false`, then extra care must be taken to ensure that components
+    /// are not accessed from the wrong thread.
     #[inline]
     pub fn is_send_and_sync(&self) -> bool {
         self.descriptor.is_send_and_sync
     }
 
+    /// Returns `true` if the component stores when it was last replaced, as set by
+    /// [`Component::TRACK_REPLACEMENTS`].
+    #[inline]
+    pub fn tracks_replacements(&self) -> bool {
+        self.descriptor.tracks_replacements
+    }
+
+    /// Returns `true` if this describes a resource, including non-send resources,
+    /// rather than a component.
+    ///
+    /// Components created with [`ComponentDescriptor::new_with_layout`] are never
+    /// considered resources, even if they are used to store one.
+    #[inline]
+    pub fn is_resource(&self) -> bool {
+        self.descriptor.is_resource
+    }
+
     /// Create a new [`ComponentInfo`].
     pub(crate) fn new(id: ComponentId, descriptor: ComponentDescriptor) -> Self {
         ComponentInfo { id, descriptor }
@@ -296,8 +343,10 @@ pub struct ComponentDescriptor {
     // SAFETY: This must remain private. It must only be set to "true" if this component is
     // actually Send + Sync
     is_send_and_sync: bool,
+    tracks_replacements: bool,
     type_id: Option<TypeId>,
     layout: Layout,
+    is_resource: bool,
     // SAFETY: this function must be safe to call with pointers pointing to items of the type
     // this descriptor describes.
     // None if the underlying type doesn't need to be dropped
@@ -311,8 +360,10 @@ impl std::fmt::Debug for ComponentDescriptor {
             .field("name", &self.name)
             .field("storage_type", &self.storage_type)
             .field("is_send_and_sync", &self.is_send_and_sync)
+            .field("tracks_replacements", &self.tracks_replacements)
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
+            .field("is_resource", &self.is_resource)
             .finish()
     }
 }
@@ -329,8 +380,10 @@ impl ComponentDescriptor {
             name: Cow::Borrowed(std::any::type_name::<T>()),
             storage_type: T::Storage::STORAGE_TYPE,
             is_send_and_sync: true,
+            tracks_replacements: T::TRACK_REPLACEMENTS,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
+            is_resource: false,
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
         }
     }
@@ -350,8 +403,10 @@ impl ComponentDescriptor {
             name: name.into(),
             storage_type,
             is_send_and_sync: true,
+            tracks_replacements: false,
             type_id: None,
             layout,
+            is_resource: false,
             drop,
         }
     }
@@ -366,8 +421,10 @@ impl ComponentDescriptor {
             // reasonable choice as `storage_type` for resources.
             storage_type: StorageType::Table,
             is_send_and_sync: true,
+            tracks_replacements: false,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
+            is_resource: true,
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
         }
     }
@@ -377,8 +434,10 @@ impl ComponentDescriptor {
             name: Cow::Borrowed(std::any::type_name::<T>()),
             storage_type,
             is_send_and_sync: false,
+            tracks_replacements: false,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
+            is_resource: true,
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
         }
     }
@@ -581,6 +640,8 @@ impl Components {
         ComponentId(*index)
     }
 
+    /// Iterates over the [`ComponentInfo`] of all the components and resources registered in
+    /// this [`Components`], in the order of their [`ComponentId`].
     pub fn iter(&self) -> impl Iterator<Item = &ComponentInfo> + '_ {
         self.components.iter()
     }
@@ -660,6 +721,8 @@ impl Tick {
 pub struct TickCells<'a> {
     pub added: &'a UnsafeCell<Tick>,
     pub changed: &'a UnsafeCell<Tick>,
+    /// Only present if the component tracks replacements.
+    pub replaced: Option<&'a UnsafeCell<Tick>>,
 }
 
 impl<'a> TickCells<'a> {
@@ -670,15 +733,21 @@ impl<'a> TickCells<'a> {
         ComponentTicks {
             added: self.added.read(),
             changed: self.changed.read(),
+            replaced: self.replaced.map_or_else(
+                || ComponentTicks::never_replaced(self.added.read()),
+                |replaced| replaced.read(),
+            ),
         }
     }
 }
 
-/// Records when a component was added and when it was last mutably dereferenced (or added).
+/// Records when a component was added, when it was last mutably dereferenced (or added), and when
+/// it was last replaced by inserting it again.
 #[derive(Copy, Clone, Debug)]
 pub struct ComponentTicks {
     pub(crate) added: Tick,
     pub(crate) changed: Tick,
+    pub(crate) replaced: Tick,
 }
 
 impl ComponentTicks {
@@ -694,13 +763,31 @@ impl ComponentTicks {
         self.changed.is_newer_than(last_run, this_run)
     }
 
+    #[inline]
+    /// Returns `true` if the component was replaced by inserting it again after the system last ran.
+    ///
+    /// Replacing a component also changes it, but adding it doesn't replace it. This is always
+    /// `false` for the components that don't [track replacements](Component#tracking-replacements).
+    pub fn is_replaced(&self, last_run: Tick, this_run: Tick) -> bool {
+        self.replaced.is_newer_than(last_run, this_run)
+    }
+
     pub(crate) fn new(change_tick: Tick) -> Self {
         Self {
             added: change_tick,
             changed: change_tick,
+            replaced: Self::never_replaced(change_tick),
         }
     }
 
+    /// Returns the "replaced" tick of a component added at `added` and never replaced since.
+    ///
+    /// It's as old as a clamped tick, so that the component isn't replaced until it's reinserted.
+    #[inline]
+    pub(crate) fn never_replaced(added: Tick) -> Tick {
+        added.relative_to(Tick::MAX)
+    }
+
     /// Manually sets the change tick.
     ///
     /// This is normally done automatically via the [`DerefMut`](std::ops::DerefMut) implementation
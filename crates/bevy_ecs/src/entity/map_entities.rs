@@ -1,5 +1,5 @@
-use crate::entity::Entity;
-use bevy_utils::{Entry, HashMap};
+use crate::entity::{Entity, EntityHash, EntityHashMap};
+use bevy_utils::hashbrown::hash_map::Entry;
 use std::fmt;
 
 /// The errors that might be returned while using [`MapEntities::map_entities`].
@@ -61,13 +61,13 @@ pub trait MapEntities {
 
 /// A mapping from one set of entities to another.
 ///
-/// The API generally follows [`HashMap`], but each [`Entity`] is returned by value, as they are [`Copy`].
+/// The API generally follows [`HashMap`](bevy_utils::HashMap), but each [`Entity`] is returned by value, as they are [`Copy`].
 ///
 /// This is typically used to coordinate data transfer between sets of entities, such as between a scene and the world or over the network.
 /// This is required as [`Entity`] identifiers are opaque; you cannot and do not want to reuse identifiers directly.
 #[derive(Default, Debug)]
 pub struct EntityMap {
-    map: HashMap<Entity, Entity>,
+    map: EntityHashMap<Entity>,
 }
 
 impl EntityMap {
@@ -86,7 +86,7 @@ impl EntityMap {
     }
 
     /// Gets the given entity's corresponding entry in the map for in-place manipulation.
-    pub fn entry(&mut self, entity: Entity) -> Entry<'_, Entity, Entity> {
+    pub fn entry(&mut self, entity: Entity) -> Entry<'_, Entity, Entity, EntityHash> {
         self.map.entry(entity)
     }
 
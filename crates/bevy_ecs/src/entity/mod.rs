@@ -39,12 +39,27 @@ mod map_entities;
 
 pub use map_entities::*;
 
+pub use bevy_utils::{EntityHash, EntityHasher};
+
+/// A [`HashMap`](bevy_utils::HashMap) keyed by [`Entity`] using the fast [`EntityHash`].
+pub type EntityHashMap<V> = bevy_utils::EntityHashMap<Entity, V>;
+
+/// A [`HashSet`](bevy_utils::HashSet) of [`Entity`] using the fast [`EntityHash`].
+pub type EntityHashSet = bevy_utils::EntityHashSet<Entity>;
+
 use crate::{
     archetype::{ArchetypeId, ArchetypeRow},
     storage::{SparseSetIndex, TableId, TableRow},
 };
 use serde::{Deserialize, Serialize};
-use std::{convert::TryFrom, fmt, mem, sync::atomic::Ordering};
+use std::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+    num::NonZeroU32,
+    sync::{atomic::Ordering, Arc},
+};
 
 #[cfg(target_has_atomic = "64")]
 use std::sync::atomic::AtomicI64 as AtomicIdCursor;
@@ -115,12 +130,60 @@ type IdCursor = isize;
 /// [`EntityCommands`]: crate::system::EntityCommands
 /// [`Query::get`]: crate::system::Query::get
 /// [`World`]: crate::world::World
-#[derive(Clone, Copy, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Copy, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(into = "EntityRepr", try_from = "EntityRepr")]
 pub struct Entity {
+    // Stored as `generation + 1`, so that zero is never a valid value and
+    // `Option<Entity>` can use it as a niche. The encoding preserves ordering.
+    generation: NonZeroU32,
+    index: u32,
+}
+
+/// Stored generation of a freshly allocated index, i.e. a public generation of 0.
+const FIRST_GENERATION: NonZeroU32 = match NonZeroU32::new(1) {
+    Some(generation) => generation,
+    None => unreachable!(),
+};
+
+/// Encodes a public generation into its stored form, returning `None` for `u32::MAX`,
+/// which cannot be represented.
+#[inline]
+const fn encode_generation(generation: u32) -> Option<NonZeroU32> {
+    NonZeroU32::new(generation.wrapping_add(1))
+}
+
+/// The serialized form of an [`Entity`], kept identical to the layout used before the
+/// generation was stored as a [`NonZeroU32`] so existing scenes still load.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Entity")]
+struct EntityRepr {
     generation: u32,
     index: u32,
 }
 
+impl From<Entity> for EntityRepr {
+    fn from(entity: Entity) -> Self {
+        EntityRepr {
+            generation: entity.generation(),
+            index: entity.index(),
+        }
+    }
+}
+
+impl TryFrom<EntityRepr> for Entity {
+    type Error = &'static str;
+
+    fn try_from(repr: EntityRepr) -> Result<Self, Self::Error> {
+        match encode_generation(repr.generation) {
+            Some(generation) => Ok(Entity {
+                generation,
+                index: repr.index,
+            }),
+            None => Err("entity generation must be less than u32::MAX"),
+        }
+    }
+}
+
 pub(crate) enum AllocAtWithoutReplacement {
     Exists(EntityLocation),
     DidNotExist,
@@ -130,7 +193,10 @@ pub(crate) enum AllocAtWithoutReplacement {
 impl Entity {
     #[cfg(test)]
     pub(crate) const fn new(index: u32, generation: u32) -> Entity {
-        Entity { index, generation }
+        match encode_generation(generation) {
+            Some(generation) => Entity { index, generation },
+            None => panic!("entity generation must be less than u32::MAX"),
+        }
     }
 
     /// An entity ID with a placeholder value. This may or may not correspond to an actual entity,
@@ -184,7 +250,7 @@ impl Entity {
     pub const fn from_raw(index: u32) -> Entity {
         Entity {
             index,
-            generation: 0,
+            generation: FIRST_GENERATION,
         }
     }
 
@@ -195,16 +261,35 @@ impl Entity {
     ///
     /// No particular structure is guaranteed for the returned bits.
     pub const fn to_bits(self) -> u64 {
-        (self.generation as u64) << 32 | self.index as u64
+        (self.generation() as u64) << 32 | self.index as u64
     }
 
     /// Reconstruct an `Entity` previously destructured with [`Entity::to_bits`].
     ///
     /// Only useful when applied to results from `to_bits` in the same instance of an application.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the bits do not correspond to a valid entity, i.e. if the
+    /// generation is `u32::MAX`. See [`Entity::try_from_bits`] for a non-panicking version.
     pub const fn from_bits(bits: u64) -> Self {
-        Self {
-            generation: (bits >> 32) as u32,
-            index: bits as u32,
+        match Self::try_from_bits(bits) {
+            Some(entity) => entity,
+            None => panic!("attempted to initialize an entity from invalid bits"),
+        }
+    }
+
+    /// Reconstruct an `Entity` previously destructured with [`Entity::to_bits`], returning
+    /// `None` if the bits do not correspond to a valid entity.
+    ///
+    /// Only useful when applied to results from `to_bits` in the same instance of an application.
+    pub const fn try_from_bits(bits: u64) -> Option<Self> {
+        match encode_generation((bits >> 32) as u32) {
+            Some(generation) => Some(Self {
+                generation,
+                index: bits as u32,
+            }),
+            None => None,
         }
     }
 
@@ -221,15 +306,25 @@ impl Entity {
     /// Returns the generation of this Entity's index. The generation is incremented each time an
     /// entity with a given index is despawned. This serves as a "count" of the number of times a
     /// given index has been reused (index, generation) pairs uniquely identify a given Entity.
+    ///
+    /// Generations range from `0` to `u32::MAX - 1`, after which they wrap back to `0`.
     #[inline]
     pub const fn generation(self) -> u32 {
-        self.generation
+        self.generation.get() - 1
+    }
+}
+
+// Hashing the bits as a single `u64` lets `EntityHasher` work on the whole entity at once.
+impl Hash for Entity {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
     }
 }
 
 impl fmt::Debug for Entity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}v{}", self.index, self.generation)
+        write!(f, "{}v{}", self.index, self.generation())
     }
 }
 
@@ -268,7 +363,7 @@ impl<'a> Iterator for ReserveEntitiesIterator<'a> {
             })
             .or_else(|| {
                 self.index_range.next().map(|index| Entity {
-                    generation: 0,
+                    generation: FIRST_GENERATION,
                     index,
                 })
             })
@@ -283,6 +378,61 @@ impl<'a> Iterator for ReserveEntitiesIterator<'a> {
 impl<'a> core::iter::ExactSizeIterator for ReserveEntitiesIterator<'a> {}
 impl<'a> core::iter::FusedIterator for ReserveEntitiesIterator<'a> {}
 
+/// A handle reserving [`Entity`] IDs of a [`World`] from any thread, without access to the world.
+///
+/// This is useful to allocate entities from tasks, for example to match the entities received
+/// over the network. Reserved entities become real, empty entities at the next
+/// [`World::flush`], after which components can be inserted on them. Since applying
+/// [`Commands`] flushes the world first, commands can also target reserved entities.
+///
+/// The reserver shares the atomic counter of the new entity IDs with the world, so reserving
+/// never fails or waits. Unlike [`Entities::reserve_entity`], it always reserves a new ID rather
+/// than reusing the ID of a despawned entity.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Remote(u64);
+///
+/// let mut world = World::new();
+/// let reserver = world.entity_reserver();
+///
+/// let entity = std::thread::spawn(move || reserver.reserve_entity())
+///     .join()
+///     .unwrap();
+///
+/// world.flush();
+/// world.entity_mut(entity).insert(Remote(42));
+/// ```
+///
+/// [`World`]: crate::world::World
+/// [`World::flush`]: crate::world::World::flush
+/// [`Commands`]: crate::system::Commands
+#[derive(Clone, Debug)]
+pub struct EntityReserver {
+    next_index: Arc<AtomicIdCursor>,
+}
+
+impl EntityReserver {
+    /// Reserves an entity.
+    pub fn reserve_entity(&self) -> Entity {
+        Entity {
+            generation: FIRST_GENERATION,
+            index: reserve_new_indices(&self.next_index, 1),
+        }
+    }
+}
+
+/// Reserves `count` consecutive indices that were never handed out, returning the first one.
+fn reserve_new_indices(next_index: &AtomicIdCursor, count: u32) -> u32 {
+    // Unwrap: these conversions can only fail on platforms that don't support 64-bit atomics
+    // and use AtomicIsize instead (see note on `IdCursor`).
+    let count = IdCursor::try_from(count).unwrap();
+    let start = next_index.fetch_add(count, Ordering::Relaxed);
+    u32::try_from(start + count).expect("too many entities");
+    start as u32
+}
+
 /// A [`World`]'s internal metadata store on all of its entities.
 ///
 /// Contains metadata on:
@@ -306,8 +456,8 @@ pub struct Entities {
     ///   `reserve_entities` or `reserve_entity()`. They are now waiting for `flush()` to make them
     ///   fully allocated.
     ///
-    /// - The count of new IDs that do not yet exist in `self.meta`, but which we have handed out
-    ///   and reserved. `flush()` will allocate room for them in `self.meta`.
+    /// - The count of IDs reserved once the freelist ran out, which are new IDs taken from
+    ///   `next_index` instead.
     ///
     /// The contents of `pending` look like this:
     ///
@@ -323,29 +473,47 @@ pub struct Entities {
     /// items from the freelist into the reserved list by sliding over the boundary.
     ///
     /// Once the freelist runs out, `free_cursor` starts going negative.
-    /// The more negative it is, the more IDs have been reserved starting exactly at
-    /// the end of `meta.len()`.
+    /// The more negative it is, the more IDs have been reserved from `next_index`.
     ///
     /// This formulation allows us to reserve any number of IDs first from the freelist
-    /// and then from the new IDs, using only a single atomic subtract.
+    /// and then from the new IDs, using only one atomic subtract and one atomic add.
     ///
     /// Once `flush()` is done, `free_cursor` will equal `pending.len()`.
     pending: Vec<u32>,
     free_cursor: AtomicIdCursor,
+    /// The first ID that was never handed out, shared with the [`EntityReserver`]s.
+    ///
+    /// Every new ID is taken from it, whether it is reserved or allocated directly. It is never
+    /// less than `meta.len()`: the IDs from `meta.len()` to `next_index` were reserved and wait for
+    /// `flush()` to allocate room for them in `self.meta`.
+    next_index: Arc<AtomicIdCursor>,
+    /// The reserved IDs of `self.meta` waiting for `flush()`, which `meta` grew past to allocate
+    /// an entity while an [`EntityReserver`] had reserved them.
+    reserved_in_meta: Vec<u32>,
     /// Stores the number of free entities for [`len`](Entities::len)
     len: u32,
 }
 
 impl Entities {
-    pub(crate) const fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Entities {
             meta: Vec::new(),
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
+            next_index: Arc::new(AtomicIdCursor::new(0)),
+            reserved_in_meta: Vec::new(),
             len: 0,
         }
     }
 
+    /// Returns an [`EntityReserver`], which reserves entities from any thread without access
+    /// to these [`Entities`].
+    pub fn reserver(&self) -> EntityReserver {
+        EntityReserver {
+            next_index: Arc::clone(&self.next_index),
+        }
+    }
+
     /// Reserve entity IDs concurrently.
     ///
     /// Storage for entity generation and location is lazily allocated by calling `flush`.
@@ -366,23 +534,14 @@ impl Entities {
             // We satisfied all requests from the freelist.
             (0, 0)
         } else {
-            // We need to allocate some new Entity IDs outside of the range of self.meta.
+            // We need to reserve some new Entity IDs.
             //
             // `range_start` covers some negative territory, e.g. `-3..6`.
             // Since the nonnegative values `0..6` are handled by the freelist, that
-            // means we need to handle the negative range here.
-            //
-            // In this example, we truncate the end to 0, leaving us with `-3..0`.
-            // Then we negate these values to indicate how far beyond the end of `meta.end()`
-            // to go, yielding `meta.len()+0 .. meta.len()+3`.
-            let base = self.meta.len() as IdCursor;
-
-            let new_id_end = u32::try_from(base - range_start).expect("too many entities");
-
-            // `new_id_end` is in range, so no need to check `start`.
-            let new_id_start = (base - range_end.min(0)) as u32;
-
-            (new_id_start, new_id_end)
+            // means we need to reserve 3 new IDs here.
+            let new_id_count = (range_end.min(0) - range_start) as u32;
+            let new_id_start = reserve_new_indices(&self.next_index, new_id_count);
+            (new_id_start, new_id_start + new_id_count)
         };
 
         ReserveEntitiesIterator {
@@ -407,12 +566,9 @@ impl Entities {
         } else {
             // Grab a new ID, outside the range of `meta.len()`. `flush()` must
             // eventually be called to make it valid.
-            //
-            // As `self.free_cursor` goes more and more negative, we return IDs farther
-            // and farther beyond `meta.len()`.
             Entity {
-                generation: 0,
-                index: u32::try_from(self.meta.len() as IdCursor - n).expect("too many entities"),
+                generation: FIRST_GENERATION,
+                index: reserve_new_indices(&self.next_index, 1),
             }
         }
     }
@@ -429,6 +585,11 @@ impl Entities {
     pub fn alloc(&mut self) -> Entity {
         self.verify_flushed();
         self.len += 1;
+        self.alloc_index()
+    }
+
+    /// Allocates an entity ID without counting it in [`len`](Entities::len).
+    fn alloc_index(&mut self) -> Entity {
         if let Some(index) = self.pending.pop() {
             let new_free_cursor = self.pending.len() as IdCursor;
             *self.free_cursor.get_mut() = new_free_cursor;
@@ -437,15 +598,48 @@ impl Entities {
                 index,
             }
         } else {
-            let index = u32::try_from(self.meta.len()).expect("too many entities");
-            self.meta.push(EntityMeta::EMPTY);
+            let index = reserve_new_indices(&self.next_index, 1);
+            // The IDs reserved by `EntityReserver`s in the meantime are kept for `flush()`
+            self.reserved_in_meta.extend(self.meta.len() as u32..index);
+            self.meta.resize(index as usize + 1, EntityMeta::EMPTY);
             Entity {
-                generation: 0,
+                generation: FIRST_GENERATION,
                 index,
             }
         }
     }
 
+    /// Marks the ID at `index` as allocated, returning `false` if it already was.
+    ///
+    /// The IDs beyond `meta.len()` that are skipped are added to the freelist, or kept for
+    /// `flush()` if they were reserved.
+    fn claim_index(&mut self, index: u32) -> bool {
+        if index as usize >= self.meta.len() {
+            let next_index = self
+                .next_index
+                .fetch_max(index as IdCursor + 1, Ordering::Relaxed);
+            // An ID that was already reserved is taken over, and isn't initialized by `flush()`
+            let reserved_end = next_index.min(index as IdCursor) as u32;
+            self.reserved_in_meta
+                .extend(self.meta.len() as u32..reserved_end);
+            self.pending.extend(reserved_end..index);
+            let new_free_cursor = self.pending.len() as IdCursor;
+            *self.free_cursor.get_mut() = new_free_cursor;
+            self.meta.resize(index as usize + 1, EntityMeta::EMPTY);
+        } else if let Some(position) = self.pending.iter().position(|item| *item == index) {
+            self.pending.swap_remove(position);
+            let new_free_cursor = self.pending.len() as IdCursor;
+            *self.free_cursor.get_mut() = new_free_cursor;
+        } else if let Some(position) = self.reserved_in_meta.iter().position(|item| *item == index)
+        {
+            self.reserved_in_meta.swap_remove(position);
+        } else {
+            return false;
+        }
+        self.len += 1;
+        true
+    }
+
     /// Allocate a specific entity ID, overwriting its generation.
     ///
     /// Returns the location of the entity currently using the given ID, if any. Location should be
@@ -453,19 +647,7 @@ impl Entities {
     pub fn alloc_at(&mut self, entity: Entity) -> Option<EntityLocation> {
         self.verify_flushed();
 
-        let loc = if entity.index as usize >= self.meta.len() {
-            self.pending.extend((self.meta.len() as u32)..entity.index);
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
-            self.meta
-                .resize(entity.index as usize + 1, EntityMeta::EMPTY);
-            self.len += 1;
-            None
-        } else if let Some(index) = self.pending.iter().position(|item| *item == entity.index) {
-            self.pending.swap_remove(index);
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
-            self.len += 1;
+        let loc = if self.claim_index(entity.index) {
             None
         } else {
             Some(mem::replace(
@@ -488,19 +670,7 @@ impl Entities {
     ) -> AllocAtWithoutReplacement {
         self.verify_flushed();
 
-        let result = if entity.index as usize >= self.meta.len() {
-            self.pending.extend((self.meta.len() as u32)..entity.index);
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
-            self.meta
-                .resize(entity.index as usize + 1, EntityMeta::EMPTY);
-            self.len += 1;
-            AllocAtWithoutReplacement::DidNotExist
-        } else if let Some(index) = self.pending.iter().position(|item| *item == entity.index) {
-            self.pending.swap_remove(index);
-            let new_free_cursor = self.pending.len() as IdCursor;
-            *self.free_cursor.get_mut() = new_free_cursor;
-            self.len += 1;
+        let result = if self.claim_index(entity.index) {
             AllocAtWithoutReplacement::DidNotExist
         } else {
             let current_meta = &self.meta[entity.index as usize];
@@ -527,7 +697,8 @@ impl Entities {
         if meta.generation != entity.generation {
             return None;
         }
-        meta.generation += 1;
+        meta.generation =
+            NonZeroU32::new(meta.generation.get().wrapping_add(1)).unwrap_or(FIRST_GENERATION);
 
         let loc = mem::replace(&mut meta.location, EntityMeta::EMPTY.location);
 
@@ -557,14 +728,42 @@ impl Entities {
     // not reallocated since the generation is incremented in `free`
     pub fn contains(&self, entity: Entity) -> bool {
         self.resolve_from_id(entity.index())
-            .map_or(false, |e| e.generation() == entity.generation)
+            .map_or(false, |e| e.generation == entity.generation)
     }
 
-    /// Clears all [`Entity`] from the World.
+    /// Frees all [`Entity`] from the World.
+    ///
+    /// Like with [`free`](Entities::free), the generation of every entity is incremented, so the
+    /// [`Entity`] IDs from before the clear don't match the entities allocated after it. This
+    /// includes the entities reserved by [`EntityReserver`]s that weren't flushed yet.
     pub fn clear(&mut self) {
+        self.verify_flushed();
+        let reserved_end = self.next_index.load(Ordering::Relaxed) as usize;
+        self.meta.resize(reserved_end, EntityMeta::EMPTY);
+        self.reserved_in_meta.clear();
+        for meta in &mut self.meta {
+            meta.generation =
+                NonZeroU32::new(meta.generation.get().wrapping_add(1)).unwrap_or(FIRST_GENERATION);
+            meta.location = EntityMeta::EMPTY.location;
+        }
+        // reversed, for the lowest indices to be allocated first
+        self.pending.clear();
+        self.pending.extend((0..self.meta.len() as u32).rev());
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        self.len = 0;
+    }
+
+    /// Frees all [`Entity`] from the World, and forgets their generations.
+    ///
+    /// Unlike [`clear`](Entities::clear), this doesn't need to go through every entity, but the
+    /// entities allocated after it may have the same [`Entity`] IDs as the freed ones, or as the
+    /// entities reserved by [`EntityReserver`]s before it.
+    pub fn clear_untracked(&mut self) {
         self.meta.clear();
         self.pending.clear();
         *self.free_cursor.get_mut() = 0;
+        self.next_index.store(0, Ordering::Relaxed);
+        self.reserved_in_meta.clear();
         self.len = 0;
     }
 
@@ -608,12 +807,9 @@ impl Entities {
             Some(Entity { generation, index })
         } else {
             // `id` is outside of the meta list - check whether it is reserved but not yet flushed.
-            let free_cursor = self.free_cursor.load(Ordering::Relaxed);
-            // If this entity was manually created, then free_cursor might be positive
-            // Returning None handles that case correctly
-            let num_pending = usize::try_from(-free_cursor).ok()?;
-            (idu < self.meta.len() + num_pending).then_some(Entity {
-                generation: 0,
+            let next_index = self.next_index.load(Ordering::Relaxed) as usize;
+            (idu < next_index).then_some(Entity {
+                generation: FIRST_GENERATION,
                 index,
             })
         }
@@ -623,8 +819,9 @@ impl Entities {
         *self.free_cursor.get_mut() != self.pending.len() as IdCursor
     }
 
-    /// Allocates space for entities previously reserved with `reserve_entity` or
-    /// `reserve_entities`, then initializes each one using the supplied function.
+    /// Allocates space for entities previously reserved with `reserve_entity`,
+    /// `reserve_entities` or an [`EntityReserver`], then initializes each one using the
+    /// supplied function.
     ///
     /// # Safety
     /// Flush _must_ set the entity location to the correct [`ArchetypeId`] for the given [`Entity`]
@@ -634,29 +831,32 @@ impl Entities {
     /// Note: freshly-allocated entities (ones which don't come from the pending list) are guaranteed
     /// to be initialized with the invalid archetype.
     pub unsafe fn flush(&mut self, mut init: impl FnMut(Entity, &mut EntityLocation)) {
-        let free_cursor = self.free_cursor.get_mut();
-        let current_free_cursor = *free_cursor;
-
-        let new_free_cursor = if current_free_cursor >= 0 {
-            current_free_cursor as usize
-        } else {
-            let old_meta_len = self.meta.len();
-            let new_meta_len = old_meta_len + -current_free_cursor as usize;
+        // The new IDs reserved here or by `EntityReserver`s
+        let old_meta_len = self.meta.len();
+        let new_meta_len = self.next_index.load(Ordering::Relaxed) as usize;
+        if new_meta_len > old_meta_len {
             self.meta.resize(new_meta_len, EntityMeta::EMPTY);
-            self.len += -current_free_cursor as u32;
-            for (index, meta) in self.meta.iter_mut().enumerate().skip(old_meta_len) {
-                init(
-                    Entity {
-                        index: index as u32,
-                        generation: meta.generation,
-                    },
-                    &mut meta.location,
-                );
-            }
+        }
+        let reserved_in_meta = std::mem::take(&mut self.reserved_in_meta);
+        let new_indices = reserved_in_meta
+            .iter()
+            .copied()
+            .chain(old_meta_len as u32..new_meta_len as u32);
+        for index in new_indices {
+            let meta = &mut self.meta[index as usize];
+            init(
+                Entity {
+                    index,
+                    generation: meta.generation,
+                },
+                &mut meta.location,
+            );
+            self.len += 1;
+        }
 
-            *free_cursor = 0;
-            0
-        };
+        let free_cursor = self.free_cursor.get_mut();
+        let new_free_cursor = (*free_cursor).max(0) as usize;
+        *free_cursor = new_free_cursor as IdCursor;
 
         self.len += (self.pending.len() - new_free_cursor) as u32;
         for index in self.pending.drain(new_free_cursor..) {
@@ -689,6 +889,12 @@ impl Entities {
     pub unsafe fn flush_and_reserve_invalid_assuming_no_entities(&mut self, count: usize) {
         let free_cursor = self.free_cursor.get_mut();
         *free_cursor = 0;
+        // the freed entities are overwritten
+        self.pending.clear();
+        self.reserved_in_meta.clear();
+        self.meta.clear();
+        self.next_index
+            .fetch_max(count as IdCursor, Ordering::Relaxed);
         self.meta.reserve(count);
         // the EntityMeta struct only contains integers, and it is valid to have all bytes set to u8::MAX
         self.meta.as_mut_ptr().write_bytes(u8::MAX, count);
@@ -730,8 +936,8 @@ impl Entities {
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 struct EntityMeta {
-    /// The current generation of the [`Entity`].
-    pub generation: u32,
+    /// The current generation of the [`Entity`], in the same encoding as [`Entity`] stores it.
+    pub generation: NonZeroU32,
     /// The current location of the [`Entity`]
     pub location: EntityLocation,
 }
@@ -739,7 +945,7 @@ struct EntityMeta {
 impl EntityMeta {
     /// meta for **pending entity**
     const EMPTY: EntityMeta = EntityMeta {
-        generation: 0,
+        generation: FIRST_GENERATION,
         location: EntityLocation::INVALID,
     };
 }
@@ -786,13 +992,11 @@ impl EntityLocation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy_utils::HashSet;
 
     #[test]
     fn entity_bits_roundtrip() {
-        let e = Entity {
-            generation: 0xDEADBEEF,
-            index: 0xBAADF00D,
-        };
+        let e = Entity::new(0xBAADF00D, 0xDEADBEEF);
         assert_eq!(Entity::from_bits(e.to_bits()), e);
     }
 
@@ -823,15 +1027,54 @@ mod tests {
         assert!(entities.get(e).is_none());
     }
 
+    #[test]
+    fn reserver_len() {
+        let mut entities = Entities::new();
+        let reserver = entities.reserver();
+
+        let e = reserver.reserve_entity();
+        assert_eq!(entities.len(), 0);
+        assert!(entities.contains(e));
+        // SAFETY: entity_location is left invalid
+        unsafe { entities.flush(|_, _| {}) };
+        assert_eq!(entities.len(), 1);
+        assert!(entities.contains(e));
+    }
+
+    #[test]
+    fn reserver_with_alloc() {
+        let mut entities = Entities::new();
+        let reserver = entities.reserver();
+
+        let reserved = reserver.reserve_entity();
+        let allocated = entities.alloc();
+        assert_ne!(allocated, reserved);
+        let reserved_after = reserver.reserve_entity();
+        let allocated_at = Entity::from_raw(reserved_after.index() + 2);
+        entities.alloc_at(allocated_at);
+
+        let mut flushed = Vec::new();
+        // SAFETY: entity_location is left invalid
+        unsafe { entities.flush(|entity, _| flushed.push(entity)) };
+        flushed.sort();
+        assert_eq!(flushed, [reserved, reserved_after]);
+        assert_eq!(entities.len(), 4);
+
+        // The ID skipped by `alloc_at` is free
+        let free = entities.alloc();
+        assert_eq!(free.index(), reserved_after.index() + 1);
+        assert!(entities.reserver().reserve_entity().index() > allocated_at.index());
+    }
+
     #[test]
     fn entity_const() {
         const C1: Entity = Entity::from_raw(42);
         assert_eq!(42, C1.index);
-        assert_eq!(0, C1.generation);
+        assert_eq!(0, C1.generation());
 
         const C2: Entity = Entity::from_bits(0x0000_00ff_0000_00cc);
         assert_eq!(0x0000_00cc, C2.index);
-        assert_eq!(0x0000_00ff, C2.generation);
+        assert_eq!(0x0000_00ff, C2.generation());
 
         const C3: u32 = Entity::from_raw(33).index();
         assert_eq!(33, C3);
@@ -839,4 +1082,135 @@ mod tests {
         const C4: u32 = Entity::from_bits(0x00dd_00ff_0000_0000).generation();
         assert_eq!(0x00dd_00ff, C4);
     }
+
+    #[test]
+    fn entity_niche_optimization() {
+        assert_eq!(std::mem::size_of::<Entity>(), 8);
+        assert_eq!(
+            std::mem::size_of::<Option<Entity>>(),
+            std::mem::size_of::<Entity>()
+        );
+    }
+
+    #[test]
+    fn entity_try_from_bits() {
+        for entity in [
+            Entity::from_raw(0),
+            Entity::new(42, 3),
+            Entity::new(u32::MAX, u32::MAX - 1),
+            Entity::PLACEHOLDER,
+        ] {
+            assert_eq!(Entity::try_from_bits(entity.to_bits()), Some(entity));
+        }
+
+        assert_eq!(Entity::try_from_bits(u64::MAX), None);
+        assert_eq!(Entity::try_from_bits(0xffff_ffff_0000_0001), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn entity_from_invalid_bits() {
+        Entity::from_bits(u64::MAX);
+    }
+
+    #[test]
+    fn entity_debug() {
+        assert_eq!(format!("{:?}", Entity::new(42, 3)), "42v3");
+        assert_eq!(format!("{:?}", Entity::from_raw(7)), "7v0");
+    }
+
+    #[test]
+    fn entity_generation_wraps() {
+        let mut entities = Entities::new();
+        let entity = entities.alloc();
+        entities.meta[entity.index as usize].generation = encode_generation(u32::MAX - 2).unwrap();
+        let entity = entities.resolve_from_id(entity.index).unwrap();
+        assert_eq!(entity.generation(), u32::MAX - 2);
+
+        entities.free(entity);
+        let entity = entities.alloc();
+        assert_eq!(entity.generation(), u32::MAX - 1);
+
+        entities.free(entity);
+        let entity = entities.alloc();
+        assert_eq!(entity.generation(), 0);
+    }
+
+    #[test]
+    fn entity_deserialize_keeps_layout() {
+        use serde::de::{
+            value::{Error, MapDeserializer},
+            IntoDeserializer,
+        };
+
+        let fields = |generation: u32| {
+            MapDeserializer::<_, Error>::new(
+                [("generation", generation), ("index", 42)]
+                    .into_iter()
+                    .map(|(key, value)| (key.into_deserializer(), value.into_deserializer())),
+            )
+        };
+
+        assert_eq!(Entity::deserialize(fields(3)), Ok(Entity::new(42, 3)));
+        assert!(Entity::deserialize(fields(u32::MAX)).is_err());
+    }
+
+    fn entity_hash(entity: Entity) -> u64 {
+        use std::hash::BuildHasher;
+
+        let mut hasher = EntityHash.build_hasher();
+        entity.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn entity_hash_keeps_index_in_low_bits() {
+        for (index, generation) in [(0, 0), (1, 0), (42, 7), (u32::MAX, u32::MAX - 1)] {
+            let hash = entity_hash(Entity::new(index, generation));
+            assert_eq!(hash as u32, index);
+        }
+    }
+
+    #[test]
+    fn entity_hash_distribution() {
+        const INDICES: u32 = 1024;
+        const GENERATIONS: u32 = 16;
+
+        let mut hashes = HashSet::new();
+        let mut high_bits = HashSet::new();
+        for generation in 0..GENERATIONS {
+            // hashbrown uses the top 7 bits to tell apart entries in the same group
+            let mut buckets = [0; 128];
+            for index in 0..INDICES {
+                let hash = entity_hash(Entity::new(index, generation));
+                assert!(hashes.insert(hash));
+                high_bits.insert(hash >> 32);
+                buckets[(hash >> 57) as usize] += 1;
+            }
+
+            // sequential indices spread evenly, no bucket holds more than twice its share
+            let expected = INDICES as usize / buckets.len();
+            assert!(buckets
+                .iter()
+                .all(|&count| count > 0 && count <= 2 * expected));
+        }
+
+        // different generations of the same index never share their high bits
+        assert_eq!(high_bits.len(), (INDICES * GENERATIONS) as usize);
+    }
+
+    #[test]
+    fn entity_hash_map() {
+        let mut map = EntityHashMap::default();
+        let mut set = EntityHashSet::default();
+        for index in 0..100 {
+            map.insert(Entity::new(index, index % 3), index);
+            set.insert(Entity::new(index, 0));
+        }
+
+        assert_eq!(map.get(&Entity::new(10, 1)), Some(&10));
+        assert_eq!(map.get(&Entity::new(10, 0)), None);
+        assert!(set.contains(&Entity::new(99, 0)));
+        assert!(!set.contains(&Entity::new(99, 1)));
+    }
 }
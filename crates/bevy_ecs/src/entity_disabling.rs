@@ -0,0 +1,184 @@
+//! Disabling entities, to hide them from queries without despawning them.
+//!
+//! Entities with a [`Disabled`] component are skipped by queries, which makes disabling a cheap
+//! alternative to despawning and respawning entities that come and go often, like the bullets or
+//! particles of a pool. Their components are kept, and they're visible again once enabled.
+//!
+//! Queries that mention `Disabled` still see the disabled entities, like the ones reading it
+//! with `&Disabled`, `Option<&Disabled>` or `Has<Disabled>`, or filtering it with `With<Disabled>` or
+//! `Without<Disabled>`. [`Allows<Disabled>`](crate::query::Allows) selects the entities whether
+//! they're disabled or not.
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId},
+    query::FilteredAccess,
+};
+
+/// Hides an entity from the queries that don't mention it, see the [module docs](self).
+///
+/// Insert and remove it with [`EntityCommands::disable`](crate::system::EntityCommands::disable)
+/// and [`EntityCommands::enable`](crate::system::EntityCommands::enable).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[component(storage = "SparseSet")]
+pub struct Disabled;
+
+/// The components that queries skip the entities of by default, like [`Disabled`].
+///
+/// A query skips the entities with one of these components, unless it mentions the component.
+/// Register more of them with [`World::register_disabling_component`](crate::world::World::register_disabling_component).
+#[derive(Debug, Clone, Default)]
+pub struct DefaultQueryFilters {
+    disabling_components: Vec<ComponentId>,
+}
+
+impl DefaultQueryFilters {
+    /// Returns the components queries skip the entities of by default.
+    pub fn disabling_components(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling_components.iter().copied()
+    }
+
+    pub(crate) fn register_disabling_component(&mut self, component_id: ComponentId) {
+        if !self.disabling_components.contains(&component_id) {
+            self.disabling_components.push(component_id);
+        }
+    }
+
+    /// Adds a `Without` filter to `access` for the disabling components it doesn't mention,
+    /// returning these components.
+    pub(crate) fn apply(&self, access: &mut FilteredAccess<ComponentId>) -> Vec<ComponentId> {
+        let mut filtered = Vec::new();
+        for &component_id in &self.disabling_components {
+            if !access.mentions(component_id) {
+                access.add_without(component_id);
+                filtered.push(component_id);
+            }
+        }
+        filtered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        query::{Allows, ReadOnlyWorldQuery},
+        system::{CommandQueue, SystemState},
+    };
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Bullet(u32);
+
+    #[derive(Component)]
+    struct Pooled;
+
+    fn bullets<F: ReadOnlyWorldQuery>(world: &mut World) -> Vec<u32> {
+        let mut bullets: Vec<_> = world
+            .query_filtered::<&Bullet, F>()
+            .iter(world)
+            .map(|bullet| bullet.0)
+            .collect();
+        bullets.sort_unstable();
+        bullets
+    }
+
+    #[test]
+    fn disabled_entities_are_skipped_by_queries() {
+        let mut world = World::new();
+        world.spawn(Bullet(0));
+        let disabled = world.spawn((Bullet(1), Disabled)).id();
+
+        assert_eq!(bullets::<()>(&mut world), [0]);
+        assert_eq!(world.query::<Entity>().iter(&world).count(), 1);
+        let mut for_each_count = 0;
+        world
+            .query::<&Bullet>()
+            .for_each(&world, |_| for_each_count += 1);
+        assert_eq!(for_each_count, 1);
+        assert!(world.query::<&Bullet>().get(&world, disabled).is_err());
+
+        world.entity_mut(disabled).remove::<Disabled>();
+        assert_eq!(bullets::<()>(&mut world), [0, 1]);
+    }
+
+    #[test]
+    fn queries_mentioning_disabled_see_disabled_entities() {
+        let mut world = World::new();
+        world.spawn(Bullet(0));
+        world.spawn((Bullet(1), Disabled));
+
+        assert_eq!(bullets::<With<Disabled>>(&mut world), [1]);
+        assert_eq!(bullets::<Without<Disabled>>(&mut world), [0]);
+        assert_eq!(bullets::<Allows<Disabled>>(&mut world), [0, 1]);
+        assert_eq!(
+            world.query::<(&Bullet, &Disabled)>().iter(&world).count(),
+            1
+        );
+        let mut optional = world.query::<(&Bullet, Option<&Disabled>)>();
+        let mut disabled: Vec<_> = optional
+            .iter(&world)
+            .map(|(bullet, disabled)| (bullet.0, disabled.is_some()))
+            .collect();
+        disabled.sort_unstable();
+        assert_eq!(disabled, [(0, false), (1, true)]);
+
+        let mut has_disabled: Vec<_> = world
+            .query::<(&Bullet, Has<Disabled>)>()
+            .iter(&world)
+            .map(|(bullet, disabled)| (bullet.0, disabled))
+            .collect();
+        has_disabled.sort_unstable();
+        assert_eq!(has_disabled, [(0, false), (1, true)]);
+    }
+
+    #[test]
+    fn registered_disabling_components_are_skipped_by_queries() {
+        let mut world = World::new();
+        world.register_disabling_component::<Pooled>();
+        world.spawn(Bullet(0));
+        world.spawn((Bullet(1), Pooled));
+        world.spawn((Bullet(2), Disabled));
+
+        assert_eq!(bullets::<()>(&mut world), [0]);
+        assert_eq!(bullets::<Allows<Pooled>>(&mut world), [0, 1]);
+        assert_eq!(
+            bullets::<(Allows<Pooled>, Allows<Disabled>)>(&mut world),
+            [0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn commands_disable_and_enable_entities() {
+        let mut world = World::new();
+        let bullet = world.spawn(Bullet(0)).id();
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world).entity(bullet).disable();
+        queue.apply(&mut world);
+        assert!(world.entity(bullet).contains::<Disabled>());
+        assert_eq!(bullets::<()>(&mut world), []);
+
+        Commands::new(&mut queue, &world).entity(bullet).enable();
+        queue.apply(&mut world);
+        assert!(!world.entity(bullet).contains::<Disabled>());
+        assert_eq!(bullets::<()>(&mut world), [0]);
+    }
+
+    #[test]
+    fn default_filtered_queries_are_disjoint_from_queries_of_disabled_entities() {
+        let mut world = World::new();
+        world.spawn(Bullet(0));
+        world.spawn((Bullet(1), Disabled));
+
+        // This would panic if both queries could access the same bullets
+        let mut state =
+            SystemState::<(Query<&mut Bullet>, Query<&mut Bullet, With<Disabled>>)>::new(
+                &mut world,
+            );
+        let (mut enabled, mut disabled) = state.get_mut(&mut world);
+        enabled.single_mut().0 += 10;
+        disabled.single_mut().0 += 20;
+        assert_eq!(bullets::<Allows<Disabled>>(&mut world), [10, 21]);
+    }
+}
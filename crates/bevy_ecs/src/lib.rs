@@ -9,6 +9,7 @@ pub mod bundle;
 pub mod change_detection;
 pub mod component;
 pub mod entity;
+pub mod entity_disabling;
 pub mod event;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
@@ -27,21 +28,22 @@ pub use bevy_ptr as ptr;
 pub mod prelude {
     #[doc(hidden)]
     #[cfg(feature = "bevy_reflect")]
-    pub use crate::reflect::{ReflectComponent, ReflectResource};
+    pub use crate::reflect::{ReflectBundle, ReflectComponent, ReflectResource};
     #[doc(hidden)]
     pub use crate::{
         bundle::Bundle,
         change_detection::{DetectChanges, DetectChangesMut, Mut, Ref},
         component::Component,
         entity::Entity,
+        entity_disabling::Disabled,
         event::{Event, EventReader, EventWriter, Events},
-        query::{Added, AnyOf, Changed, Or, QueryState, With, Without},
+        query::{Added, Allows, AnyOf, Changed, Has, Or, QueryState, Replaced, With, Without},
         removal_detection::RemovedComponents,
         schedule::{
             apply_state_transition, apply_system_buffers, common_conditions::*, Condition,
-            IntoSystemConfig, IntoSystemConfigs, IntoSystemSet, IntoSystemSetConfig,
-            IntoSystemSetConfigs, NextState, OnEnter, OnExit, OnTransition, OnUpdate, Schedule,
-            Schedules, State, States, SystemSet,
+            IntoPipeConditionSystem, IntoRunIfElseSystem, IntoSystemConfig, IntoSystemConfigs,
+            IntoSystemSet, IntoSystemSetConfig, IntoSystemSetConfigs, NextState, OnEnter, OnExit,
+            OnTransition, OnUpdate, Schedule, Schedules, State, States, SystemSet,
         },
         system::{
             adapter as system_adapter,
@@ -65,9 +67,10 @@ mod tests {
     use crate::{
         bundle::Bundle,
         change_detection::Ref,
-        component::{Component, ComponentId},
+        component::{Component, ComponentId, StorageType},
         entity::Entity,
-        query::{Added, Changed, FilteredAccess, ReadOnlyWorldQuery, With, Without},
+        entity_disabling::Disabled,
+        query::{Added, Changed, FilteredAccess, ReadOnlyWorldQuery, Replaced, With, Without},
         system::Resource,
         world::{Mut, World},
     };
@@ -1053,6 +1056,98 @@ mod tests {
         assert_eq!(get_filtered::<Changed<SparseStored>>(&mut world), vec![e4]);
     }
 
+    #[test]
+    fn replaced_trackers() {
+        #[derive(Component)]
+        #[component(track_replacements)]
+        struct TableTracked(u32);
+        #[derive(Component)]
+        #[component(storage = "SparseSet", track_replacements)]
+        struct SparseTracked(u32);
+
+        fn causes<T: Component>(world: &mut World, entity: Entity) -> (bool, bool, bool) {
+            world
+                .query::<(Added<T>, Changed<T>, Replaced<T>)>()
+                .get(world, entity)
+                .unwrap()
+        }
+
+        let mut world = World::default();
+        let table = world.spawn((TableTracked(0), SparseTracked(0), B(0))).id();
+        // Added and changed, but not replaced
+        assert_eq!(
+            causes::<TableTracked>(&mut world, table),
+            (true, true, false)
+        );
+        assert_eq!(
+            causes::<SparseTracked>(&mut world, table),
+            (true, true, false)
+        );
+        world.clear_trackers();
+
+        // Mutated
+        world.get_mut::<TableTracked>(table).unwrap().0 += 1;
+        world.get_mut::<SparseTracked>(table).unwrap().0 += 1;
+        assert_eq!(
+            causes::<TableTracked>(&mut world, table),
+            (false, true, false)
+        );
+        assert_eq!(
+            causes::<SparseTracked>(&mut world, table),
+            (false, true, false)
+        );
+        world.clear_trackers();
+
+        // Replaced, even when the insertion moves the entity to another archetype
+        world
+            .entity_mut(table)
+            .insert((TableTracked(2), SparseTracked(2), B(1), A(0)));
+        assert_eq!(
+            causes::<TableTracked>(&mut world, table),
+            (false, true, true)
+        );
+        assert_eq!(
+            causes::<SparseTracked>(&mut world, table),
+            (false, true, true)
+        );
+        assert_eq!(
+            world
+                .query_filtered::<Entity, Replaced<TableTracked>>()
+                .iter(&world)
+                .collect::<Vec<_>>(),
+            [table]
+        );
+        // Components that don't track replacements are only changed
+        let this_run = world.read_change_tick();
+        let ticks = world.entity(table).get_change_ticks::<B>().unwrap();
+        assert!(ticks.is_changed(world.last_change_tick(), this_run));
+        assert!(!ticks.is_replaced(world.last_change_tick(), this_run));
+        world.clear_trackers();
+
+        assert_eq!(
+            causes::<TableTracked>(&mut world, table),
+            (false, false, false)
+        );
+        assert!(world
+            .query_filtered::<Entity, Replaced<SparseTracked>>()
+            .iter(&world)
+            .next()
+            .is_none());
+        let ticks = world
+            .entity(table)
+            .get_change_ticks::<TableTracked>()
+            .unwrap();
+        let this_run = world.read_change_tick();
+        assert!(!ticks.is_replaced(world.last_change_tick(), this_run));
+    }
+
+    #[test]
+    #[should_panic(expected = "to track replacements")]
+    fn replaced_filter_on_untracked_component() {
+        let mut world = World::default();
+        world.query_filtered::<Entity, Replaced<A>>();
+    }
+
     #[test]
     fn empty_spawn() {
         let mut world = World::default();
@@ -1362,6 +1457,9 @@ mod tests {
         let b_id = world.components.get_id(TypeId::of::<B>()).unwrap();
         expected.add_write(a_id);
         expected.add_read(b_id);
+        // Queries skip disabled entities by default
+        let disabled_id = world.components.get_id(TypeId::of::<Disabled>()).unwrap();
+        expected.add_without(disabled_id);
         assert!(
             query.component_access.eq(&expected),
             "ComponentId access from query fetch and query filter should be combined"
@@ -1388,6 +1486,74 @@ mod tests {
         query.for_each(&world_b, |_| {});
     }
 
+    #[test]
+    fn archetype_statistics() {
+        #[derive(Component)]
+        struct Position([f32; 3]);
+        #[derive(Component)]
+        #[component(storage = "SparseSet")]
+        struct Marker(u64);
+
+        /// The number of entities and of bytes used by their components, for each archetype.
+        struct ArchetypeStatistics {
+            entities: usize,
+            bytes: usize,
+        }
+
+        fn archetype_statistics(world: &World) -> Vec<ArchetypeStatistics> {
+            world
+                .archetypes()
+                .iter()
+                .filter(|archetype| !archetype.is_empty())
+                .map(|archetype| {
+                    let component_size: usize = archetype
+                        .components()
+                        .map(|id| world.components().get_info(id).unwrap().layout().size())
+                        .sum();
+                    ArchetypeStatistics {
+                        entities: archetype.entities().len(),
+                        bytes: archetype.entities().len() * component_size,
+                    }
+                })
+                .collect()
+        }
+
+        let mut world = World::new();
+        world.insert_resource(A(0));
+        world.spawn_batch((0..3).map(|_| Position([0.0; 3])));
+        world.spawn_batch((0..2).map(|_| (Position([0.0; 3]), Marker(0))));
+
+        let statistics = archetype_statistics(&world);
+        assert_eq!(statistics.len(), 2);
+        assert_eq!(statistics[0].entities, 3);
+        assert_eq!(statistics[0].bytes, 3 * 12);
+        assert_eq!(statistics[1].entities, 2);
+        assert_eq!(statistics[1].bytes, 2 * (12 + 8));
+
+        let components = world.components();
+        let position = components
+            .iter()
+            .find(|info| info.type_id() == Some(TypeId::of::<Position>()))
+            .unwrap();
+        assert_eq!(position.layout().size(), 12);
+        assert_eq!(position.storage_type(), StorageType::Table);
+        assert!(!position.is_resource());
+        assert!(position.name().ends_with("Position"));
+
+        let marker = components
+            .iter()
+            .find(|info| info.type_id() == Some(TypeId::of::<Marker>()))
+            .unwrap();
+        assert_eq!(marker.storage_type(), StorageType::SparseSet);
+
+        let resources: Vec<_> = components
+            .iter()
+            .filter(|info| info.is_resource())
+            .collect();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].type_id(), Some(TypeId::of::<A>()));
+    }
+
     #[test]
     fn resource_scope() {
         let mut world = World::default();
@@ -1399,6 +1565,108 @@ mod tests {
         assert_eq!(world.resource::<A>().0, 1);
     }
 
+    #[test]
+    fn try_resource_scope_missing_resource() {
+        let mut world = World::default();
+        let result = world.try_resource_scope(|_: &mut World, _: Mut<A>| {
+            panic!("the scope should not run without the resource");
+        });
+        assert!(result.is_none());
+
+        world.insert_resource(A(1));
+        let result = world.try_resource_scope(|_: &mut World, value: Mut<A>| value.0);
+        assert_eq!(result, Some(1));
+        assert!(world.contains_resource::<A>());
+    }
+
+    #[test]
+    #[should_panic(expected = "resource does not exist: bevy_ecs::tests::A")]
+    fn resource_scope_missing_resource() {
+        let mut world = World::default();
+        world.resource_scope(|_: &mut World, _: Mut<A>| {});
+    }
+
+    #[test]
+    fn resource_scope_reinserts_on_panic() {
+        let mut world = World::default();
+        world.insert_resource(A(0));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.resource_scope(|_: &mut World, mut value: Mut<A>| {
+                value.0 = 1;
+                panic!("panicking in the scope");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(world.resource::<A>().0, 1);
+    }
+
+    #[test]
+    fn resource_scope_reinserting_the_resource_panics() {
+        let mut world = World::default();
+        world.insert_resource(A(0));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.resource_scope(|world: &mut World, mut value: Mut<A>| {
+                value.0 = 1;
+                world.insert_resource(A(2));
+            });
+        }));
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("Resource `bevy_ecs::tests::A` was inserted"));
+        // the original resource replaces the inserted one
+        assert_eq!(world.resource::<A>().0, 1);
+
+        // a panic of the scope isn't turned into an abort by the inserted resource
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.resource_scope(|world: &mut World, _: Mut<A>| {
+                world.insert_resource(A(3));
+                panic!("panicking in the scope");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(world.resource::<A>().0, 1);
+    }
+
+    #[test]
+    fn non_send_resource_scope() {
+        let mut world = World::default();
+        assert!(world
+            .try_non_send_resource_scope(|_: &mut World, _: Mut<NonSendA>| {})
+            .is_none());
+
+        world.insert_non_send_resource(NonSendA::default());
+        world.non_send_resource_scope(|world: &mut World, mut value: Mut<NonSendA>| {
+            value.0 += 1;
+            assert!(!world.contains_non_send::<NonSendA>());
+        });
+        assert_eq!(world.non_send_resource::<NonSendA>().0, 1);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.non_send_resource_scope(|_: &mut World, mut value: Mut<NonSendA>| {
+                value.0 += 1;
+                panic!("panicking in the scope");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(world.non_send_resource::<NonSendA>().0, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
+    )]
+    fn non_send_resource_scope_from_different_thread() {
+        let mut world = World::default();
+        world.insert_non_send_resource(NonSendA::default());
+
+        let thread = std::thread::spawn(move || {
+            world.non_send_resource_scope(|_: &mut World, _: Mut<NonSendA>| {});
+        });
+
+        if let Err(err) = thread.join() {
+            std::panic::resume_unwind(err);
+        }
+    }
+
     #[test]
     #[should_panic(
         expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
@@ -1418,6 +1686,91 @@ mod tests {
         }
     }
 
+    #[test]
+    #[should_panic(
+        expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
+    )]
+    fn non_send_resource_from_different_thread() {
+        let mut world = World::default();
+        world.insert_non_send_resource(NonSendA::default());
+
+        let thread = std::thread::spawn(move || {
+            world.non_send_resource::<NonSendA>();
+        });
+
+        if let Err(err) = thread.join() {
+            std::panic::resume_unwind(err);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
+    )]
+    fn non_send_resource_mut_from_different_thread() {
+        let mut world = World::default();
+        world.insert_non_send_resource(NonSendA::default());
+
+        let thread = std::thread::spawn(move || {
+            world.non_send_resource_mut::<NonSendA>();
+        });
+
+        if let Err(err) = thread.join() {
+            std::panic::resume_unwind(err);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
+    )]
+    fn get_non_send_resource_from_different_thread() {
+        let mut world = World::default();
+        world.insert_non_send_resource(NonSendA::default());
+
+        let thread = std::thread::spawn(move || {
+            world.get_non_send_resource::<NonSendA>();
+        });
+
+        if let Err(err) = thread.join() {
+            std::panic::resume_unwind(err);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
+    )]
+    fn insert_non_send_resource_from_different_thread() {
+        let mut world = World::default();
+        world.insert_non_send_resource(NonSendA::default());
+
+        let thread = std::thread::spawn(move || {
+            world.insert_non_send_resource(NonSendA::default());
+        });
+
+        if let Err(err) = thread.join() {
+            std::panic::resume_unwind(err);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
+    )]
+    fn remove_non_send_resource_from_different_thread() {
+        let mut world = World::default();
+        world.insert_non_send_resource(NonSendA::default());
+
+        let thread = std::thread::spawn(move || {
+            world.remove_non_send_resource::<NonSendA>();
+        });
+
+        if let Err(err) = thread.join() {
+            std::panic::resume_unwind(err);
+        }
+    }
+
     #[test]
     fn non_send_resource_drop_from_same_thread() {
         let mut world = World::default();
@@ -1493,6 +1846,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clear_entities_drops_components_once_and_sends_removals() {
+        let mut world = World::default();
+        let (table, table_drops) = DropCk::new_pair();
+        let (sparse, sparse_drops) = DropCk::new_pair();
+        let entity = world.spawn((table, DropCkSparse(sparse))).id();
+        let reserved = world.entities().reserve_entity();
+
+        world.clear_entities();
+        assert_eq!(table_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(sparse_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(world.removed::<DropCk>().collect::<Vec<_>>(), [entity]);
+        assert_eq!(
+            world.removed::<DropCkSparse>().collect::<Vec<_>>(),
+            [entity]
+        );
+        assert!(world.get_entity(entity).is_none());
+        assert!(world.get_entity(reserved).is_none());
+
+        drop(world);
+        assert_eq!(table_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(sparse_drops.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn entities_from_before_clear_entities_dont_alias_new_entities() {
+        let mut world = World::default();
+        let before = world.spawn(A(0)).id();
+
+        world.clear_entities();
+        let after = world.spawn(A(1)).id();
+        assert_eq!(after.index(), before.index());
+        assert_ne!(after, before);
+        assert!(world.get_entity(before).is_none());
+        assert!(!world.entities().contains(before));
+        assert_eq!(world.get::<A>(after), Some(&A(1)));
+    }
+
+    #[test]
+    fn clear_entities_untracked_drops_components_without_sending_removals() {
+        let mut world = World::default();
+        let (table, table_drops) = DropCk::new_pair();
+        let (sparse, sparse_drops) = DropCk::new_pair();
+        let before = world.spawn((table, DropCkSparse(sparse))).id();
+
+        world.clear_entities_untracked();
+        assert_eq!(table_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(sparse_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(world.removed::<DropCk>().count(), 0);
+        assert_eq!(world.removed::<DropCkSparse>().count(), 0);
+        assert_eq!(world.entities().len(), 0);
+
+        // the generations are reset
+        let after = world.spawn(A(0)).id();
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn clear_resources_drops_resources_once() {
+        #[derive(Resource)]
+        struct DropCkResource(DropCk);
+
+        let mut world = World::default();
+        let (resource, drops) = DropCk::new_pair();
+        world.insert_resource(DropCkResource(resource));
+        world.insert_non_send_resource(NonSendA::default());
+        world.spawn(A(0));
+
+        world.clear_resources();
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+        assert!(!world.contains_resource::<DropCkResource>());
+        assert!(!world.contains_non_send::<NonSendA>());
+        assert_eq!(
+            world.entities().len(),
+            1,
+            "world should still contain entities"
+        );
+
+        drop(world);
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn clear_all_from_different_thread_keeps_non_send_resources() {
+        let mut world = World::default();
+        world.insert_resource(A(0));
+        world.insert_non_send_resource(NonSendA::default());
+        world.spawn(A(1));
+
+        let world = std::thread::spawn(move || {
+            let result =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| world.clear_all()));
+            assert!(result.is_err());
+            world
+        })
+        .join()
+        .unwrap();
+        // nothing was cleared
+        assert!(world.contains_resource::<A>());
+        assert!(world.contains_non_send::<NonSendA>());
+        assert_eq!(world.entities().len(), 1);
+    }
+
     #[test]
     fn test_is_archetypal_size_hints() {
         let mut world = World::default();
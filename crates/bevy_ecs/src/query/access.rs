@@ -221,6 +221,9 @@ pub struct FilteredAccess<T: SparseSetIndex> {
     access: Access<T>,
     with: FixedBitSet,
     without: FixedBitSet,
+    /// Elements that don't filter nor are accessed, but opt out of the default query filters,
+    /// see [`Allows`](crate::query::Allows).
+    allowed: FixedBitSet,
 }
 impl<T: SparseSetIndex + fmt::Debug> fmt::Debug for FilteredAccess<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -228,6 +231,7 @@ impl<T: SparseSetIndex + fmt::Debug> fmt::Debug for FilteredAccess<T> {
             .field("access", &self.access)
             .field("with", &FormattedBitSet::<T>::new(&self.with))
             .field("without", &FormattedBitSet::<T>::new(&self.without))
+            .field("allowed", &FormattedBitSet::<T>::new(&self.allowed))
             .finish()
     }
 }
@@ -238,6 +242,7 @@ impl<T: SparseSetIndex> Default for FilteredAccess<T> {
             access: Access::default(),
             with: Default::default(),
             without: Default::default(),
+            allowed: Default::default(),
         }
     }
 }
@@ -287,6 +292,24 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.without.insert(index.sparse_set_index());
     }
 
+    /// Opts out of the default query filter of the element given by `index`, without accessing
+    /// or filtering it.
+    pub fn add_allowed(&mut self, index: T) {
+        self.allowed.grow(index.sparse_set_index() + 1);
+        self.allowed.insert(index.sparse_set_index());
+    }
+
+    /// Returns `true` if the element given by `index` is accessed, filtered or allowed.
+    ///
+    /// Having access to all elements doesn't mention any of them.
+    pub fn mentions(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        self.access.reads_and_writes.contains(index)
+            || self.with.contains(index)
+            || self.without.contains(index)
+            || self.allowed.contains(index)
+    }
+
     pub fn extend_intersect_filter(&mut self, other: &FilteredAccess<T>) {
         self.without.intersect_with(&other.without);
         self.with.intersect_with(&other.with);
@@ -317,6 +340,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.access.extend(&access.access);
         self.with.union_with(&access.with);
         self.without.union_with(&access.without);
+        self.allowed.union_with(&access.allowed);
     }
 
     /// Sets the underlying unfiltered access as having access to all indexed elements.
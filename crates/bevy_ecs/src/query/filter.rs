@@ -207,6 +207,95 @@ unsafe impl<T: Component> WorldQuery for Without<T> {
 // SAFETY: no component access or archetype component access
 unsafe impl<T: Component> ReadOnlyWorldQuery for Without<T> {}
 
+/// Filter that also selects the entities with a component `T` that queries skip by default,
+/// like [`Disabled`](crate::entity_disabling::Disabled).
+///
+/// Unlike [`With`], the entities without a component `T` are still selected.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::entity_disabling::Disabled;
+/// # use bevy_ecs::query::Allows;
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::system::Query;
+/// #
+/// # #[derive(Component)]
+/// # struct Bullet;
+/// #
+/// fn count_pooled_bullets(query: Query<&Bullet, Allows<Disabled>>) {
+///     println!("{} bullets are pooled, in use or not", query.iter().count());
+/// }
+/// # bevy_ecs::system::assert_is_system(count_pooled_bullets);
+/// ```
+pub struct Allows<T>(PhantomData<T>);
+
+// SAFETY: `Self::ReadOnly` is the same as `Self`
+unsafe impl<T: Component> WorldQuery for Allows<T> {
+    type Fetch<'w> = ();
+    type Item<'w> = ();
+    type ReadOnly = Self;
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    unsafe fn init_fetch(_world: &World, _state: &ComponentId, _last_run: Tick, _this_run: Tick) {}
+
+    unsafe fn clone_fetch<'w>(_fetch: &Self::Fetch<'w>) -> Self::Fetch<'w> {}
+
+    const IS_DENSE: bool = true;
+
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline]
+    unsafe fn set_table(_fetch: &mut (), _state: &Self::State, _table: &Table) {}
+
+    #[inline]
+    unsafe fn set_archetype(
+        _fetch: &mut (),
+        _state: &ComponentId,
+        _archetype: &Archetype,
+        _table: &Table,
+    ) {
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    #[inline]
+    fn update_component_access(&id: &ComponentId, access: &mut FilteredAccess<ComponentId>) {
+        access.add_allowed(id);
+    }
+
+    #[inline]
+    fn update_archetype_component_access(
+        _state: &ComponentId,
+        _archetype: &Archetype,
+        _access: &mut Access<ArchetypeComponentId>,
+    ) {
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.init_component::<T>()
+    }
+
+    fn matches_component_set(
+        _state: &ComponentId,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        true
+    }
+}
+
+// SAFETY: no component access or archetype component access
+unsafe impl<T: Component> ReadOnlyWorldQuery for Allows<T> {}
+
 /// A filter that tests if any of the given filters apply.
 ///
 /// This is useful for example if a system with multiple components in a query only wants to run
@@ -394,7 +483,8 @@ macro_rules! impl_tick_filter {
         $(#[$fetch_meta:meta])*
         $fetch_name: ident,
         $get_slice: expr,
-        $get_sparse_set: expr
+        $get_sparse_set: expr,
+        $init_state: expr
     ) => {
         $(#[$meta])*
         pub struct $name<T>(PhantomData<T>);
@@ -541,7 +631,7 @@ macro_rules! impl_tick_filter {
             }
 
             fn init_state(world: &mut World) -> ComponentId {
-                world.init_component::<T>()
+                $init_state(world)
             }
 
             fn matches_component_set(&id: &ComponentId, set_contains_id: &impl Fn(ComponentId) -> bool) -> bool {
@@ -584,7 +674,8 @@ impl_tick_filter!(
     Added,
     AddedFetch,
     Column::get_added_ticks_slice,
-    ComponentSparseSet::get_added_ticks
+    ComponentSparseSet::get_added_ticks,
+    World::init_component::<T>
 );
 
 impl_tick_filter!(
@@ -622,9 +713,63 @@ impl_tick_filter!(
     Changed,
     ChangedFetch,
     Column::get_changed_ticks_slice,
-    ComponentSparseSet::get_changed_ticks
+    ComponentSparseSet::get_changed_ticks,
+    World::init_component::<T>
 );
 
+impl_tick_filter!(
+    /// A filter on a component that only retains results replaced by inserting the component again
+    /// after the system last ran.
+    ///
+    /// Replacing a component with [`insert`](crate::system::EntityCommands::insert) also changes
+    /// it, but adding it for the first time doesn't replace it. This tells apart the causes of a
+    /// [`Changed`] component: it was either added, replaced, or mutated if it was neither.
+    /// Like the other filters, `Added<T>` and `Replaced<T>` can be queried as `bool`s for that:
+    /// `Query<(Added<T>, Replaced<T>), Changed<T>>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when initialized if `T` doesn't
+    /// [track replacements](crate::component::Component#tracking-replacements).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_ecs::component::Component;
+    /// # use bevy_ecs::query::Replaced;
+    /// # use bevy_ecs::system::IntoSystem;
+    /// # use bevy_ecs::system::Query;
+    /// #
+    /// # #[derive(Component, Debug)]
+    /// # struct Name {};
+    /// #[derive(Component)]
+    /// #[component(track_replacements)]
+    /// struct Mesh {};
+    ///
+    /// fn invalidate_replaced_meshes_system(query: Query<&Name, Replaced<Mesh>>) {
+    ///     for name in &query {
+    ///         println!("Mesh replaced: {:?}", name);
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(invalidate_replaced_meshes_system);
+    /// ```
+    Replaced,
+    ReplacedFetch,
+    Column::get_replaced_ticks_slice,
+    ComponentSparseSet::get_replaced_ticks,
+    init_replaced_state::<T>
+);
+
+fn init_replaced_state<T: Component>(world: &mut World) -> ComponentId {
+    assert!(
+        T::TRACK_REPLACEMENTS,
+        "Replaced<{0}> requires {0} to track replacements with #[component(track_replacements)]",
+        std::any::type_name::<T>()
+    );
+    world.init_component::<T>()
+}
+
 /// A marker trait to indicate that the filter works at an archetype level.
 ///
 /// This is needed to implement [`ExactSizeIterator`](std::iter::ExactSizeIterator) for
@@ -635,8 +780,8 @@ impl_tick_filter!(
 /// [Tuples](prim@tuple) and [`Or`] filters are automatically implemented with the trait only if its containing types
 /// also implement the same trait.
 ///
-/// [`Added`] and [`Changed`] works with entities, and therefore are not archetypal. As such
-/// they do not implement [`ArchetypeFilter`].
+/// [`Added`], [`Changed`] and [`Replaced`] works with entities, and therefore are not archetypal.
+/// As such they do not implement [`ArchetypeFilter`].
 pub trait ArchetypeFilter {}
 
 impl<T> ArchetypeFilter for With<T> {}
@@ -451,9 +451,11 @@ struct QueryIterationCursor<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> {
     archetype_entities: &'w [ArchetypeEntity],
     fetch: Q::Fetch<'w>,
     filter: F::Fetch<'w>,
-    // length of the table table or length of the archetype, depending on whether both `Q`'s and `F`'s fetches are dense
+    // whether to iterate tables, see `QueryState::is_dense`
+    is_dense: bool,
+    // length of the table table or length of the archetype, depending on `is_dense`
     current_len: usize,
-    // either table row or archetype index, depending on whether both `Q`'s and `F`'s fetches are dense
+    // either table row or archetype index, depending on `is_dense`
     current_row: usize,
     phantom: PhantomData<Q>,
 }
@@ -471,6 +473,7 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's,
             archetype_id_iter: self.archetype_id_iter.clone(),
             table_entities: self.table_entities,
             archetype_entities: self.archetype_entities,
+            is_dense: self.is_dense,
             // SAFETY: upheld by caller invariants
             fetch: Q::clone_fetch(&self.fetch),
             filter: F::clone_fetch(&self.filter),
@@ -482,8 +485,6 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's,
 }
 
 impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's, Q, F> {
-    const IS_DENSE: bool = Q::IS_DENSE && F::IS_DENSE;
-
     unsafe fn init_empty(
         world: &'w World,
         query_state: &'s QueryState<Q, F>,
@@ -512,6 +513,7 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's,
             archetype_entities: &[],
             table_id_iter: query_state.matched_table_ids.iter(),
             archetype_id_iter: query_state.matched_archetype_ids.iter(),
+            is_dense: query_state.is_dense,
             current_len: 0,
             current_row: 0,
             phantom: PhantomData,
@@ -523,7 +525,7 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's,
     unsafe fn peek_last(&mut self) -> Option<Q::Item<'w>> {
         if self.current_row > 0 {
             let index = self.current_row - 1;
-            if Self::IS_DENSE {
+            if self.is_dense {
                 let entity = self.table_entities.get_unchecked(index);
                 Some(Q::fetch(&mut self.fetch, *entity, TableRow::new(index)))
             } else {
@@ -544,7 +546,7 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's,
     /// Note that if `Q::IS_ARCHETYPAL && F::IS_ARCHETYPAL`, the return value
     /// will be **the exact count of remaining values**.
     fn max_remaining(&self, tables: &'w Tables, archetypes: &'w Archetypes) -> usize {
-        let remaining_matched: usize = if Self::IS_DENSE {
+        let remaining_matched: usize = if self.is_dense {
             let ids = self.table_id_iter.clone();
             ids.map(|id| tables[*id].entity_count()).sum()
         } else {
@@ -567,7 +569,7 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryIterationCursor<'w, 's,
         archetypes: &'w Archetypes,
         query_state: &'s QueryState<Q, F>,
     ) -> Option<Q::Item<'w>> {
-        if Self::IS_DENSE {
+        if self.is_dense {
             loop {
                 // we are on the beginning of the query, or finished processing a table, so skip to the next
                 if self.current_row == self.current_len {
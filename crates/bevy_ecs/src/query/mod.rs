@@ -61,7 +61,7 @@ impl<T> DebugCheckedUnwrap for Option<T> {
 #[cfg(test)]
 mod tests {
     use super::{ReadOnlyWorldQuery, WorldQuery};
-    use crate::prelude::{AnyOf, Changed, Entity, Or, QueryState, With, Without};
+    use crate::prelude::{AnyOf, Changed, Entity, Has, Or, QueryState, With, Without};
     use crate::query::{ArchetypeFilter, QueryCombinationIter};
     use crate::schedule::{IntoSystemConfigs, Schedule};
     use crate::system::{IntoSystem, Query, System, SystemState};
@@ -474,6 +474,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn has_query() {
+        #[derive(WorldQuery)]
+        struct Presence {
+            a: &'static A,
+            has_b: Has<B>,
+            has_sparse: Has<Sparse>,
+        }
+
+        let mut world = World::new();
+        world.spawn((A(1), B(1)));
+        world.spawn((A(2), Sparse(2)));
+        world.spawn(A(3));
+        world.spawn(B(4));
+
+        let mut values: Vec<_> = world
+            .query::<Presence>()
+            .iter(&world)
+            .map(|presence| (presence.a.0, presence.has_b, presence.has_sparse))
+            .collect();
+        values.sort_unstable();
+        assert_eq!(
+            values,
+            [(1, true, false), (2, false, true), (3, false, false)]
+        );
+
+        let mut values: Vec<_> = world
+            .query::<(Option<&A>, Has<B>)>()
+            .iter(&world)
+            .map(|(a, has_b)| (a.map(|a| a.0), has_b))
+            .collect();
+        values.sort_unstable();
+        assert_eq!(
+            values,
+            [
+                (None, true),
+                (Some(1), true),
+                (Some(2), false),
+                (Some(3), false)
+            ]
+        );
+
+        // The dense and archetype iterations of `for_each` agree
+        let mut count = 0;
+        world
+            .query::<(Has<B>, Has<Sparse>)>()
+            .for_each(&world, |(has_b, has_sparse)| {
+                count += usize::from(has_b) + usize::from(has_sparse);
+            });
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn has_query_does_not_conflict_with_mutable_access() {
+        let mut world = World::new();
+        world.spawn((A(1), B(1), Sparse(1)));
+        world.spawn(A(2));
+
+        let mut state = SystemState::<(
+            Query<(&A, Has<B>, Has<Sparse>)>,
+            Query<(&mut B, &mut Sparse)>,
+        )>::new(&mut world);
+        let (presence, mut mutable) = state.get_mut(&mut world);
+        for (mut b, mut sparse) in &mut mutable {
+            b.0 += 1;
+            sparse.0 += 1;
+        }
+        let mut values: Vec<_> = presence
+            .iter()
+            .map(|(a, has_b, has_sparse)| (a.0, has_b, has_sparse))
+            .collect();
+        values.sort_unstable();
+        assert_eq!(values, [(1, true, true), (2, false, false)]);
+    }
+
     #[test]
     #[should_panic = "&mut bevy_ecs::query::tests::A conflicts with a previous access in this query."]
     fn self_conflicting_worldquery() {
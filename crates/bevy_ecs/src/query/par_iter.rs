@@ -174,7 +174,7 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> QueryParIter<'w, 's, Q, F> {
             thread_count > 0,
             "Attempted to run parallel iteration over a query with an empty TaskPool"
         );
-        let max_size = if Q::IS_DENSE && F::IS_DENSE {
+        let max_size = if self.state.is_dense {
             let tables = &self.world.storages().tables;
             self.state
                 .matched_table_ids
@@ -1,6 +1,6 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId},
-    component::{ComponentId, Tick},
+    component::{ComponentId, StorageType, Tick},
     entity::Entity,
     prelude::FromWorld,
     query::{
@@ -34,6 +34,11 @@ pub struct QueryState<Q: WorldQuery, F: ReadOnlyWorldQuery = ()> {
     pub(crate) matched_table_ids: Vec<TableId>,
     // NOTE: we maintain both a ArchetypeId bitset and a vec because iterating the vec is faster
     pub(crate) matched_archetype_ids: Vec<ArchetypeId>,
+    // NOTE: the disabling components of the world that the query doesn't mention, see `DefaultQueryFilters`
+    pub(crate) default_filtered_components: Vec<ComponentId>,
+    // NOTE: `Q::IS_DENSE && F::IS_DENSE`, unless a matched table holds entities disabled by a sparse set component,
+    // which must then be skipped by iterating archetypes instead
+    pub(crate) is_dense: bool,
     pub(crate) fetch_state: Q::State,
     pub(crate) filter_state: F::State,
 }
@@ -112,11 +117,16 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
 
+        // Skip the disabled entities, unless the query mentions the components disabling them.
+        let default_filtered_components = world.default_query_filters.apply(&mut component_access);
+
         let mut state = Self {
             world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_table_ids: Vec::new(),
             matched_archetype_ids: Vec::new(),
+            default_filtered_components,
+            is_dense: Q::IS_DENSE && F::IS_DENSE,
             fetch_state,
             filter_state,
             component_access,
@@ -173,6 +183,22 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         if Q::matches_component_set(&self.fetch_state, &|id| archetype.contains(id))
             && F::matches_component_set(&self.filter_state, &|id| archetype.contains(id))
         {
+            let mut is_disabled = false;
+            for &component_id in &self.default_filtered_components {
+                match archetype.get_storage_type(component_id) {
+                    // The table of this archetype may also hold enabled entities
+                    Some(StorageType::SparseSet) => {
+                        is_disabled = true;
+                        self.is_dense = false;
+                    }
+                    Some(StorageType::Table) => is_disabled = true,
+                    None => {}
+                }
+            }
+            if is_disabled {
+                return;
+            }
+
             Q::update_archetype_component_access(
                 &self.fetch_state,
                 archetype,
@@ -867,7 +893,7 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         let mut filter = F::init_fetch(world, &self.filter_state, last_run, this_run);
 
         let tables = &world.storages().tables;
-        if Q::IS_DENSE && F::IS_DENSE {
+        if self.is_dense {
             for table_id in &self.matched_table_ids {
                 let table = tables.get(*table_id).debug_checked_unwrap();
                 Q::set_table(&mut fetch, &self.fetch_state, table);
@@ -939,7 +965,7 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         // NOTE: If you are changing query iteration code, remember to update the following places, where relevant:
         // QueryIter, QueryIterationCursor, QueryManyIter, QueryCombinationIter, QueryState::for_each_unchecked_manual, QueryState::par_for_each_unchecked_manual
         ComputeTaskPool::get().scope(|scope| {
-            if Q::IS_DENSE && F::IS_DENSE {
+            if self.is_dense {
                 let tables = &world.storages().tables;
                 for table_id in &self.matched_table_ids {
                     let table = &tables[*table_id];
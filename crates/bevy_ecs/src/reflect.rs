@@ -1,6 +1,7 @@
 //! Types that enable reflection support.
 
 use crate::{
+    bundle::Bundle,
     change_detection::Mut,
     component::Component,
     entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
@@ -11,8 +12,8 @@ use crate::{
     },
 };
 use bevy_reflect::{
-    impl_from_reflect_value, impl_reflect_value, FromType, Reflect, ReflectDeserialize,
-    ReflectSerialize,
+    impl_from_reflect_value, impl_reflect_value, FromType, Reflect, ReflectDeserialize, ReflectRef,
+    ReflectSerialize, TypeRegistry,
 };
 
 /// A struct used to operate on reflected [`Component`] of a type.
@@ -407,6 +408,157 @@ impl<C: Resource + Reflect + FromWorld> FromType<C> for ReflectResource {
     }
 }
 
+/// A struct used to operate on a reflected [`Bundle`] of a type.
+///
+/// A [`ReflectBundle`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`], once `T` derives [`Reflect`]
+/// with the `#[reflect(Bundle)]` attribute and is registered.
+#[derive(Clone)]
+pub struct ReflectBundle(ReflectBundleFns);
+
+/// The raw function pointers needed to make up a [`ReflectBundle`].
+///
+/// This is used when creating custom implementations of [`ReflectBundle`] with
+/// [`ReflectBundle::new()`].
+#[derive(Clone)]
+pub struct ReflectBundleFns {
+    /// Function pointer implementing [`ReflectBundle::from_world()`].
+    pub from_world: fn(&mut World) -> Box<dyn Reflect>,
+    /// Function pointer implementing [`ReflectBundle::insert()`].
+    pub insert: fn(&mut EntityMut, &dyn Reflect),
+    /// Function pointer implementing [`ReflectBundle::apply()`].
+    pub apply: fn(&mut EntityMut, &dyn Reflect, &TypeRegistry),
+    /// Function pointer implementing [`ReflectBundle::remove()`].
+    pub remove: fn(&mut EntityMut),
+}
+
+impl ReflectBundleFns {
+    /// Get the default set of [`ReflectBundleFns`] for a specific bundle type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Bundle + Reflect + FromWorld>() -> Self {
+        <ReflectBundle as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectBundle {
+    /// Constructs default reflected [`Bundle`] from world using [`from_world()`](FromWorld::from_world).
+    pub fn from_world(&self, world: &mut World) -> Box<dyn Reflect> {
+        (self.0.from_world)(world)
+    }
+
+    /// Insert a reflected [`Bundle`] into the entity like [`insert()`](crate::world::EntityMut::insert).
+    ///
+    /// The fields missing from `bundle`, like the ones of a partial [`DynamicStruct`](bevy_reflect::DynamicStruct),
+    /// are inserted with their [`from_world()`](FromWorld::from_world) value.
+    pub fn insert(&self, entity: &mut EntityMut, bundle: &dyn Reflect) {
+        (self.0.insert)(entity, bundle);
+    }
+
+    /// Uses reflection to set the value of the components of this [`Bundle`] type in the entity
+    /// to the fields of `bundle`.
+    ///
+    /// Each field is applied with the [`ReflectComponent`] or, for nested bundles, the [`ReflectBundle`]
+    /// registered for its type in `registry`. The fields missing from `bundle` are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity is missing a component of the bundle, if `bundle` isn't a struct or tuple struct
+    /// like the bundle, or if the type of one of its fields isn't registered with a [`ReflectComponent`]
+    /// or a [`ReflectBundle`].
+    pub fn apply(&self, entity: &mut EntityMut, bundle: &dyn Reflect, registry: &TypeRegistry) {
+        (self.0.apply)(entity, bundle, registry);
+    }
+
+    /// Removes the components of this [`Bundle`] type from the entity, like
+    /// [`remove()`](crate::world::EntityMut::remove). Does nothing if the entity doesn't have them all.
+    pub fn remove(&self, entity: &mut EntityMut) {
+        (self.0.remove)(entity);
+    }
+
+    /// Create a custom implementation of [`ReflectBundle`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(Bundle)]` attribute
+    /// to generate a [`ReflectBundle`] implementation automatically.
+    ///
+    /// See [`ReflectBundleFns`] for more information.
+    pub fn new(fns: ReflectBundleFns) -> Self {
+        Self(fns)
+    }
+}
+
+impl<B: Bundle + Reflect + FromWorld> FromType<B> for ReflectBundle {
+    fn from_type() -> Self {
+        ReflectBundle(ReflectBundleFns {
+            from_world: |world| Box::new(B::from_world(world)),
+            insert: |entity, reflected_bundle| {
+                let mut bundle = entity.world_scope(|world| B::from_world(world));
+                bundle.apply(reflected_bundle);
+                entity.insert(bundle);
+            },
+            apply: |entity, reflected_bundle, registry| {
+                // The default bundle gives the types of the fields, the reflected one their values
+                let bundle = entity.world_scope(|world| B::from_world(world));
+                match (bundle.reflect_ref(), reflected_bundle.reflect_ref()) {
+                    (ReflectRef::Struct(bundle), ReflectRef::Struct(reflected_bundle)) => {
+                        for (index, field) in bundle.iter_fields().enumerate() {
+                            let name = bundle.name_at(index).unwrap();
+                            if let Some(value) = reflected_bundle.field(name) {
+                                apply_bundle_field(entity, field, value, registry);
+                            }
+                        }
+                    }
+                    (
+                        ReflectRef::TupleStruct(bundle),
+                        ReflectRef::TupleStruct(reflected_bundle),
+                    ) => {
+                        for (index, field) in bundle.iter_fields().enumerate() {
+                            if let Some(value) = reflected_bundle.field(index) {
+                                apply_bundle_field(entity, field, value, registry);
+                            }
+                        }
+                    }
+                    _ => panic!(
+                        "expected a struct or tuple struct to apply to the bundle {}, found {}",
+                        std::any::type_name::<B>(),
+                        reflected_bundle.type_name(),
+                    ),
+                }
+            },
+            remove: |entity| {
+                entity.remove::<B>();
+            },
+        })
+    }
+}
+
+/// Applies `value` to the component or nested bundle of the type of `field`.
+fn apply_bundle_field(
+    entity: &mut EntityMut,
+    field: &dyn Reflect,
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) {
+    let type_id = field.as_any().type_id();
+    if let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(type_id) {
+        reflect_component.apply(entity, value);
+    } else if let Some(reflect_bundle) = registry.get_type_data::<ReflectBundle>(type_id) {
+        reflect_bundle.apply(entity, value, registry);
+    } else {
+        panic!(
+            "the bundle field of type {} isn't registered with a `ReflectComponent` or a `ReflectBundle`",
+            field.type_name(),
+        );
+    }
+}
+
 impl_reflect_value!(Entity(Hash, PartialEq, Serialize, Deserialize));
 impl_from_reflect_value!(Entity);
 
@@ -439,3 +591,120 @@ impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
         }
     }
 }
+
+/// A struct used to map the entities referenced by a reflected [`Resource`], like
+/// [`ReflectMapEntities`] does for components.
+///
+/// A [`ReflectMapEntitiesResource`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`] once `#[reflect(MapEntitiesResource)]` is added to a
+/// resource implementing [`MapEntities`].
+#[derive(Clone)]
+pub struct ReflectMapEntitiesResource {
+    map_entities: fn(&mut World, &EntityMap) -> Result<(), MapEntitiesError>,
+}
+
+impl ReflectMapEntitiesResource {
+    /// Maps the entities referenced by the resource of the world, if it's present.
+    pub fn map_entities(
+        &self,
+        world: &mut World,
+        entity_map: &EntityMap,
+    ) -> Result<(), MapEntitiesError> {
+        (self.map_entities)(world, entity_map)
+    }
+}
+
+impl<R: Resource + MapEntities> FromType<R> for ReflectMapEntitiesResource {
+    fn from_type() -> Self {
+        ReflectMapEntitiesResource {
+            map_entities: |world, entity_map| {
+                if let Some(mut resource) = world.get_resource_mut::<R>() {
+                    resource.map_entities(entity_map)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_ecs;
+    use bevy_reflect::DynamicStruct;
+    use std::any::TypeId;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Speed(f32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name(String);
+
+    #[derive(Bundle, Reflect, Default)]
+    #[reflect(Bundle)]
+    struct Stats {
+        health: Health,
+        speed: Speed,
+    }
+
+    #[derive(Bundle, Reflect, Default)]
+    #[reflect(Bundle)]
+    struct Player {
+        name: Name,
+        stats: Stats,
+    }
+
+    #[test]
+    fn reflected_bundles_are_inserted_applied_and_removed() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        registry.register::<Speed>();
+        registry.register::<Name>();
+        registry.register::<Stats>();
+        registry.register::<Player>();
+        let reflect_player = registry
+            .get_type_data::<ReflectBundle>(TypeId::of::<Player>())
+            .unwrap()
+            .clone();
+
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+
+        let mut stats = DynamicStruct::default();
+        stats.insert("health", Health(10));
+        let mut player = DynamicStruct::default();
+        player.insert("name", Name("Ferris".to_string()));
+        player.insert("stats", stats);
+        reflect_player.insert(&mut world.entity_mut(entity), &player);
+        let entity_ref = world.entity(entity);
+        assert_eq!(entity_ref.get::<Name>(), Some(&Name("Ferris".to_string())));
+        assert_eq!(entity_ref.get::<Health>(), Some(&Health(10)));
+        // The fields missing from the reflected bundle are inserted with their default value
+        assert_eq!(entity_ref.get::<Speed>(), Some(&Speed(0.0)));
+
+        let mut stats = DynamicStruct::default();
+        stats.insert("speed", Speed(2.5));
+        let mut player = DynamicStruct::default();
+        player.insert("stats", stats);
+        reflect_player.apply(&mut world.entity_mut(entity), &player, &registry);
+        let entity_ref = world.entity(entity);
+        assert_eq!(entity_ref.get::<Name>(), Some(&Name("Ferris".to_string())));
+        assert_eq!(entity_ref.get::<Health>(), Some(&Health(10)));
+        assert_eq!(entity_ref.get::<Speed>(), Some(&Speed(2.5)));
+
+        let reflect_health = registry
+            .get_type_data::<ReflectComponent>(TypeId::of::<Health>())
+            .unwrap();
+        assert!(reflect_health.contains(world.entity(entity)));
+        reflect_player.remove(&mut world.entity_mut(entity));
+        assert!(!reflect_health.contains(world.entity(entity)));
+        assert!(world.entity(entity).get::<Name>().is_none());
+        assert!(world.entity(entity).get::<Speed>().is_none());
+    }
+}
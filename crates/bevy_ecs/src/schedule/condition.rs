@@ -137,7 +137,7 @@ pub mod common_conditions {
         event::{Event, EventReader},
         prelude::{Component, Query, With},
         schedule::{State, States},
-        system::{In, IntoPipeSystem, Res, Resource},
+        system::{In, IntoPipeSystem, Local, Res, Resource},
     };
 
     /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
@@ -396,6 +396,71 @@ pub mod common_conditions {
         move |res: Res<T>| res.is_changed()
     }
 
+    /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
+    /// if the value of the resource of the given type is different from the one seen the
+    /// last time the condition returned `true`.
+    ///
+    /// Unlike [`resource_changed`], this condition compares values, and ignores writes that
+    /// leave the resource unchanged, like `*res = value` with an equal `value`. This is useful
+    /// when the systems writing the resource can't be trusted to use
+    /// [`set_if_neq`](crate::change_detection::DetectChangesMut::set_if_neq).
+    /// The first time this condition is checked, it will return `true`.
+    ///
+    /// The values are only compared when the resource was changed, but this condition keeps
+    /// a clone of the last value it has seen in a [`Local`], so each use of it stores a copy
+    /// of the resource: prefer [`resource_changed`] with `set_if_neq` for large resources.
+    ///
+    /// # Panics
+    ///
+    /// The condition will panic if the resource does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default, Clone, PartialEq)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::new();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// app.add_system(
+    ///     // `resource_changed_by_value` will only return true if the
+    ///     // value of the given resource changed
+    ///     my_system.run_if(resource_changed_by_value::<Counter>()),
+    /// );
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// // The first check always returns `true`, so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// // `my_system` changed the value, but it is set back to what the condition last saw,
+    /// // so `my_system` won't run
+    /// world.resource_mut::<Counter>().0 = 0;
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// // `Counter` got a new value so `my_system` will run
+    /// world.resource_mut::<Counter>().0 = 50;
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 51);
+    /// ```
+    pub fn resource_changed_by_value<T>() -> impl FnMut(Res<T>, Local<Option<T>>) -> bool + Clone
+    where
+        T: Resource + PartialEq + Clone,
+    {
+        move |res: Res<T>, mut last: Local<Option<T>>| {
+            if !res.is_changed() || last.as_ref() == Some(&*res) {
+                return false;
+            }
+            *last = Some(res.clone());
+            true
+        }
+    }
+
     /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
     /// if the resource of the given type has had its value changed since the condition
     /// was last checked.
@@ -971,13 +1036,156 @@ where
     }
 }
 
+/// Passes the output of a [`Condition`] into a system that takes a `bool` as input.
+pub type PipeCondition<A, B> = CombinatorSystem<PipeConditionMarker, A, B>;
+
+/// Runs the first system if the input is `true`, and the second system otherwise.
+pub type Branch<A, B> = CombinatorSystem<BranchMarker, A, B>;
+
+/// Runs a system if a [`Condition`] returns `true`, and a fallback system otherwise.
+pub type RunIfElse<C, A, B> = PipeCondition<C, Branch<A, B>>;
+
+#[doc(hidden)]
+pub struct PipeConditionMarker;
+
+impl<A, B> Combine<A, B> for PipeConditionMarker
+where
+    A: System<In = (), Out = bool>,
+    B: System<In = bool>,
+{
+    type In = ();
+    type Out = B::Out;
+
+    fn combine(
+        _input: Self::In,
+        a: impl FnOnce(<A as System>::In) -> <A as System>::Out,
+        b: impl FnOnce(<B as System>::In) -> <B as System>::Out,
+    ) -> Self::Out {
+        b(a(()))
+    }
+}
+
+#[doc(hidden)]
+pub struct BranchMarker;
+
+impl<Out, A, B> Combine<A, B> for BranchMarker
+where
+    A: System<In = (), Out = Out>,
+    B: System<In = (), Out = Out>,
+{
+    type In = bool;
+    type Out = Out;
+
+    fn combine(
+        input: Self::In,
+        a: impl FnOnce(<A as System>::In) -> <A as System>::Out,
+        b: impl FnOnce(<B as System>::In) -> <B as System>::Out,
+    ) -> Self::Out {
+        if input {
+            a(())
+        } else {
+            b(())
+        }
+    }
+}
+
+/// An extension trait for systems that take the output of a [`Condition`] as [`In<bool>`](crate::system::In).
+///
+/// This trait is blanket implemented for all systems that fulfill the type requirements.
+pub trait IntoPipeConditionSystem<Out, Marker>: IntoSystem<bool, Out, Marker> {
+    /// Evaluates `condition` every time this system runs and passes its result as the input.
+    ///
+    /// Unlike [`run_if`](crate::schedule::IntoSystemConfig::run_if), the system runs regardless
+    /// of the outcome. The access of the condition is combined with the access of the system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Resource, PartialEq)]
+    /// struct DebugMode(bool);
+    ///
+    /// fn draw(In(debug): In<bool>) {
+    ///     if debug {
+    ///         // draw debug overlays
+    ///     }
+    /// }
+    ///
+    /// # let mut schedule = Schedule::new();
+    /// # let mut world = World::new();
+    /// # world.insert_resource(DebugMode(true));
+    /// schedule.add_system(draw.pipe_condition(resource_equals(DebugMode(true))));
+    /// # schedule.run(&mut world);
+    /// ```
+    fn pipe_condition<M, C: Condition<M>>(
+        self,
+        condition: C,
+    ) -> PipeCondition<C::System, Self::System> {
+        let a = IntoSystem::into_system(condition);
+        let b = IntoSystem::into_system(self);
+        let name = format!("PipeCondition({}, {})", a.name(), b.name());
+        CombinatorSystem::new(a, b, Cow::Owned(name))
+    }
+}
+
+impl<Out, Marker, F> IntoPipeConditionSystem<Out, Marker> for F where
+    F: IntoSystem<bool, Out, Marker>
+{
+}
+
+/// An extension trait for running one of two systems depending on a [`Condition`].
+///
+/// This trait is blanket implemented for all systems that fulfill the type requirements.
+pub trait IntoRunIfElseSystem<Out, Marker>: IntoSystem<(), Out, Marker> {
+    /// Runs this system if `condition` returns `true`, and `fallback` otherwise.
+    ///
+    /// Exactly one of the two systems runs each time the combined system runs.
+    /// The access of all three systems is combined, so the scheduler treats them as one system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Resource, PartialEq)]
+    /// struct Paused(bool);
+    ///
+    /// fn simulate() {}
+    /// fn draw_pause_menu() {}
+    ///
+    /// # let mut schedule = Schedule::new();
+    /// # let mut world = World::new();
+    /// # world.insert_resource(Paused(false));
+    /// schedule.add_system(simulate.run_if_else(resource_equals(Paused(false)), draw_pause_menu));
+    /// # schedule.run(&mut world);
+    /// ```
+    fn run_if_else<MC, C, MF, F>(
+        self,
+        condition: C,
+        fallback: F,
+    ) -> RunIfElse<C::System, Self::System, F::System>
+    where
+        C: Condition<MC>,
+        F: IntoSystem<(), Out, MF>,
+    {
+        let system = IntoSystem::into_system(self);
+        let fallback = IntoSystem::into_system(fallback);
+        let name = format!("{} else {}", system.name(), fallback.name());
+        let branch = CombinatorSystem::new(system, fallback, Cow::Owned(name));
+        branch.pipe_condition(condition)
+    }
+}
+
+impl<Out, Marker, S> IntoRunIfElseSystem<Out, Marker> for S where S: IntoSystem<(), Out, Marker> {}
+
 #[cfg(test)]
 mod tests {
-    use super::Condition;
+    use super::{Condition, IntoPipeConditionSystem, IntoRunIfElseSystem};
     use crate as bevy_ecs;
     use crate::schedule::common_conditions::not;
     use crate::schedule::IntoSystemConfig;
-    use crate::system::Local;
+    use crate::system::{In, IntoSystem, Local, Res, System};
     use crate::{change_detection::ResMut, schedule::Schedule, world::World};
     use bevy_ecs_macros::Resource;
 
@@ -1020,6 +1228,70 @@ mod tests {
         assert_eq!(world.resource::<Counter>().0, 6);
     }
 
+    #[derive(Resource, Default, Clone, PartialEq)]
+    struct Score(u32);
+
+    #[test]
+    fn resource_changed_by_value() {
+        use crate::schedule::common_conditions::resource_changed_by_value;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Score>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(increment_counter.run_if(resource_changed_by_value::<Score>()));
+
+        // The first check always runs the system
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // Writing an equal value triggers change detection, but the system doesn't run
+        *world.resource_mut::<Score>() = Score(0);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        *world.resource_mut::<Score>() = Score(3);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+
+        // Equal to the last value the condition saw
+        *world.resource_mut::<Score>() = Score(3);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
+    #[test]
+    fn set_if_neq_does_not_trigger_resource_changed() {
+        use crate::change_detection::DetectChangesMut;
+        use crate::schedule::common_conditions::resource_changed;
+
+        fn reset_score(mut score: ResMut<Score>) {
+            score.set_if_neq(Score(0));
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Score>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(reset_score.before(increment_counter));
+        schedule.add_system(increment_counter.run_if(resource_changed::<Score>()));
+
+        // The resource was just added
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        world.resource_mut::<Score>().0 = 5;
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+        assert_eq!(world.resource::<Score>().0, 0);
+    }
+
     #[test]
     fn run_condition_combinators() {
         let mut world = World::new();
@@ -1074,4 +1346,72 @@ mod tests {
         schedule.run(&mut world);
         assert_eq!(world.resource::<Counter>().0, 0);
     }
+
+    #[derive(Resource, Default)]
+    struct Fallback(usize);
+
+    #[derive(Resource, Default)]
+    struct Piped(Vec<bool>);
+
+    #[test]
+    fn pipe_condition() {
+        let mut world = World::new();
+        world.init_resource::<Piped>();
+        let mut schedule = Schedule::new();
+
+        schedule.add_system(
+            (|In(value): In<bool>, mut piped: ResMut<Piped>| piped.0.push(value))
+                .pipe_condition(every_other_time),
+        );
+
+        for _ in 0..4 {
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.resource::<Piped>().0, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn run_if_else() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Fallback>();
+        let mut schedule = Schedule::new();
+
+        schedule.add_system(
+            increment_counter.run_if_else(every_other_time, |mut fallback: ResMut<Fallback>| {
+                fallback.0 += 1
+            }),
+        );
+
+        for i in 1..=4 {
+            schedule.run(&mut world);
+            // exactly one of the two systems runs each time
+            let counter = world.resource::<Counter>().0;
+            let fallback = world.resource::<Fallback>().0;
+            assert_eq!(counter + fallback, i);
+            assert_eq!(counter, (i + 1) / 2);
+        }
+    }
+
+    #[test]
+    fn run_if_else_combines_access() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Fallback>();
+
+        let mut system = IntoSystem::into_system(increment_counter.run_if_else(
+            |counter: Res<Counter>| counter.0 == 0,
+            |mut fallback: ResMut<Fallback>| fallback.0 += 1,
+        ));
+        system.initialize(&mut world);
+        let counter = world.components().resource_id::<Counter>().unwrap();
+        let fallback = world.components().resource_id::<Fallback>().unwrap();
+        assert!(system.component_access().has_write(counter));
+        assert!(system.component_access().has_write(fallback));
+
+        system.run((), &mut world);
+        system.run((), &mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        assert_eq!(world.resource::<Fallback>().0, 1);
+    }
 }
@@ -250,6 +250,18 @@ where
     fn after<M>(self, set: impl IntoSystemSet<M>) -> Config {
         self.into_config().after(set)
     }
+    /// Run before all systems in `set`, without inserting an automatic sync point in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_system_buffers`](crate::schedule::ScheduleBuildSettings::auto_insert_apply_system_buffers).
+    fn before_ignore_deferred<M>(self, set: impl IntoSystemSet<M>) -> Config {
+        self.into_config().before_ignore_deferred(set)
+    }
+    /// Run after all systems in `set`, without inserting an automatic sync point in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_system_buffers`](crate::schedule::ScheduleBuildSettings::auto_insert_apply_system_buffers).
+    fn after_ignore_deferred<M>(self, set: impl IntoSystemSet<M>) -> Config {
+        self.into_config().after_ignore_deferred(set)
+    }
     /// Run only if the [`Condition`] is `true`.
     ///
     /// The `Condition` will be evaluated at most once (per schedule run),
@@ -338,6 +350,22 @@ impl IntoSystemConfig<()> for SystemConfig {
         self
     }
 
+    fn before_ignore_deferred<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.graph_info.dependencies.push(Dependency::new(
+            DependencyKind::BeforeNoSync,
+            Box::new(set.into_system_set()),
+        ));
+        self
+    }
+
+    fn after_ignore_deferred<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.graph_info.dependencies.push(Dependency::new(
+            DependencyKind::AfterNoSync,
+            Box::new(set.into_system_set()),
+        ));
+        self
+    }
+
     fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
         self.conditions.push(new_condition(condition));
         self
@@ -357,8 +385,19 @@ impl IntoSystemConfig<()> for SystemConfig {
 /// A collection of [`SystemConfig`].
 pub struct SystemConfigs {
     pub(super) systems: Vec<SystemConfig>,
-    /// If `true`, adds `before -> after` ordering constraints between the successive elements.
-    pub(super) chained: bool,
+    /// Whether `before -> after` ordering constraints are added between the successive elements.
+    pub(super) chained: Chain,
+}
+
+/// Specifies the ordering constraints added between the successive elements of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Chain {
+    /// No ordering constraints.
+    Unchained,
+    /// Ordering constraints, which may imply automatic sync points.
+    Chained,
+    /// Ordering constraints, without automatic sync points.
+    ChainedIgnoreDeferred,
 }
 
 /// Types that can convert into a [`SystemConfigs`].
@@ -392,6 +431,20 @@ where
         self.into_configs().after(set)
     }
 
+    /// Run before all systems in `set`, without inserting an automatic sync point in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_system_buffers`](crate::schedule::ScheduleBuildSettings::auto_insert_apply_system_buffers).
+    fn before_ignore_deferred<M>(self, set: impl IntoSystemSet<M>) -> SystemConfigs {
+        self.into_configs().before_ignore_deferred(set)
+    }
+
+    /// Run after all systems in `set`, without inserting an automatic sync point in between.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_system_buffers`](crate::schedule::ScheduleBuildSettings::auto_insert_apply_system_buffers).
+    fn after_ignore_deferred<M>(self, set: impl IntoSystemSet<M>) -> SystemConfigs {
+        self.into_configs().after_ignore_deferred(set)
+    }
+
     /// Add a run condition to each contained system.
     ///
     /// Each system will receive its own clone of the [`Condition`] and will only run
@@ -444,6 +497,14 @@ where
     fn chain(self) -> SystemConfigs {
         self.into_configs().chain()
     }
+
+    /// Treat this collection as a sequence of systems, like [`chain`](IntoSystemConfigs::chain),
+    /// but without inserting automatic sync points between the successive elements.
+    ///
+    /// See [`ScheduleBuildSettings::auto_insert_apply_system_buffers`](crate::schedule::ScheduleBuildSettings::auto_insert_apply_system_buffers).
+    fn chain_ignore_deferred(self) -> SystemConfigs {
+        self.into_configs().chain_ignore_deferred()
+    }
 }
 
 impl IntoSystemConfigs<()> for SystemConfigs {
@@ -509,6 +570,30 @@ impl IntoSystemConfigs<()> for SystemConfigs {
         self
     }
 
+    fn before_ignore_deferred<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        let set = set.into_system_set();
+        for config in &mut self.systems {
+            config.graph_info.dependencies.push(Dependency::new(
+                DependencyKind::BeforeNoSync,
+                set.dyn_clone(),
+            ));
+        }
+
+        self
+    }
+
+    fn after_ignore_deferred<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        let set = set.into_system_set();
+        for config in &mut self.systems {
+            config.graph_info.dependencies.push(Dependency::new(
+                DependencyKind::AfterNoSync,
+                set.dyn_clone(),
+            ));
+        }
+
+        self
+    }
+
     fn distributive_run_if<M>(mut self, condition: impl Condition<M> + Clone) -> SystemConfigs {
         for config in &mut self.systems {
             config.conditions.push(new_condition(condition.clone()));
@@ -535,7 +620,12 @@ impl IntoSystemConfigs<()> for SystemConfigs {
     }
 
     fn chain(mut self) -> Self {
-        self.chained = true;
+        self.chained = Chain::Chained;
+        self
+    }
+
+    fn chain_ignore_deferred(mut self) -> Self {
+        self.chained = Chain::ChainedIgnoreDeferred;
         self
     }
 }
@@ -703,7 +793,7 @@ macro_rules! impl_system_collection {
                 let ($($sys,)*) = self;
                 SystemConfigs {
                     systems: vec![$($sys.into_config(),)*],
-                    chained: false,
+                    chained: Chain::Unchained,
                 }
             }
         }
@@ -6,6 +6,8 @@ pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
 
+#[cfg(feature = "system_timing")]
+use bevy_utils::Duration;
 use fixedbitset::FixedBitSet;
 
 use crate::{
@@ -59,6 +61,9 @@ pub struct SystemSchedule {
     pub(super) system_dependents: Vec<Vec<usize>>,
     pub(super) sets_with_conditions_of_systems: Vec<FixedBitSet>,
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Time spent running each system since the durations were last taken.
+    #[cfg(feature = "system_timing")]
+    pub(super) system_durations: Vec<Option<Duration>>,
 }
 
 impl SystemSchedule {
@@ -73,6 +78,8 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            #[cfg(feature = "system_timing")]
+            system_durations: Vec::new(),
         }
     }
 }
@@ -86,6 +93,12 @@ impl SystemSchedule {
 #[allow(unused_variables)]
 pub fn apply_system_buffers(world: &mut World) {}
 
+/// Adds `elapsed` to the time a system has spent running since its duration was last taken.
+#[cfg(feature = "system_timing")]
+pub(super) fn record_system_duration(duration: &mut Option<Duration>, elapsed: Duration) {
+    *duration = Some(duration.unwrap_or_default() + elapsed);
+}
+
 /// Returns `true` if the [`System`](crate::system::System) is an instance of [`apply_system_buffers`].
 pub(super) fn is_apply_system_buffers(system: &BoxedSystem) -> bool {
     use std::any::Any;
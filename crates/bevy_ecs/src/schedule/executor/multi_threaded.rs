@@ -5,6 +5,8 @@ use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Instrument};
+#[cfg(feature = "system_timing")]
+use bevy_utils::{Duration, Instant};
 use std::panic::AssertUnwindSafe;
 
 use async_channel::{Receiver, Sender};
@@ -27,6 +29,8 @@ use crate as bevy_ecs;
 struct SyncUnsafeSchedule<'a> {
     systems: &'a [SyncUnsafeCell<BoxedSystem>],
     conditions: Conditions<'a>,
+    #[cfg(feature = "system_timing")]
+    system_durations: &'a mut [Option<Duration>],
 }
 
 struct Conditions<'a> {
@@ -46,10 +50,20 @@ impl SyncUnsafeSchedule<'_> {
                 sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             },
+            #[cfg(feature = "system_timing")]
+            system_durations: &mut schedule.system_durations,
         }
     }
 }
 
+/// Sent by a system task to the [`MultiThreadedExecutor`] when the system completes.
+struct SystemResult {
+    system_index: usize,
+    /// How long the system took to run, if it was timed.
+    #[cfg(feature = "system_timing")]
+    duration: Option<Duration>,
+}
+
 /// Per-system data used by the [`MultiThreadedExecutor`].
 // Copied here because it can't be read from the system when it's running.
 struct SystemTaskMetadata {
@@ -66,9 +80,9 @@ struct SystemTaskMetadata {
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
 pub struct MultiThreadedExecutor {
     /// Sends system completion events.
-    sender: Sender<usize>,
+    sender: Sender<SystemResult>,
     /// Receives system completion events.
-    receiver: Receiver<usize>,
+    receiver: Receiver<SystemResult>,
     /// Metadata for scheduling and running system tasks.
     system_task_metadata: Vec<SystemTaskMetadata>,
     /// Union of the accesses of all currently running systems.
@@ -173,6 +187,8 @@ impl SystemExecutor for MultiThreadedExecutor {
         let SyncUnsafeSchedule {
             systems,
             mut conditions,
+            #[cfg(feature = "system_timing")]
+            system_durations,
         } = SyncUnsafeSchedule::new(schedule);
 
         ComputeTaskPool::init(TaskPool::default).scope_with_executor(
@@ -190,15 +206,19 @@ impl SystemExecutor for MultiThreadedExecutor {
 
                         if self.num_running_systems > 0 {
                             // wait for systems to complete
-                            let index =
+                            let result =
                                 self.receiver.recv().await.expect(
                                     "A system has panicked so the executor cannot continue.",
                                 );
 
-                            self.finish_system_and_signal_dependents(index);
+                            #[cfg(feature = "system_timing")]
+                            record_result_duration(system_durations, &result);
+                            self.finish_system_and_signal_dependents(result.system_index);
 
-                            while let Ok(index) = self.receiver.try_recv() {
-                                self.finish_system_and_signal_dependents(index);
+                            while let Ok(result) = self.receiver.try_recv() {
+                                #[cfg(feature = "system_timing")]
+                                record_result_duration(system_durations, &result);
+                                self.finish_system_and_signal_dependents(result.system_index);
                             }
 
                             self.rebuild_active_access();
@@ -443,6 +463,8 @@ impl MultiThreadedExecutor {
         let task = async move {
             #[cfg(feature = "trace")]
             let system_guard = system_span.enter();
+            #[cfg(feature = "system_timing")]
+            let start = Instant::now();
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY: access is compatible
                 unsafe { system.run_unsafe((), world) };
@@ -455,7 +477,11 @@ impl MultiThreadedExecutor {
                 sender.close();
             } else {
                 sender
-                    .try_send(system_index)
+                    .try_send(SystemResult {
+                        system_index,
+                        #[cfg(feature = "system_timing")]
+                        duration: Some(start.elapsed()),
+                    })
                     .unwrap_or_else(|error| unreachable!("{}", error));
             }
         };
@@ -511,7 +537,11 @@ impl MultiThreadedExecutor {
                     sender.close();
                 } else {
                     sender
-                        .try_send(system_index)
+                        .try_send(SystemResult {
+                            system_index,
+                            #[cfg(feature = "system_timing")]
+                            duration: None,
+                        })
                         .unwrap_or_else(|error| unreachable!("{}", error));
                 }
             };
@@ -523,6 +553,8 @@ impl MultiThreadedExecutor {
             let task = async move {
                 #[cfg(feature = "trace")]
                 let system_guard = system_span.enter();
+                #[cfg(feature = "system_timing")]
+                let start = Instant::now();
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     system.run((), world);
                 }));
@@ -534,7 +566,11 @@ impl MultiThreadedExecutor {
                     sender.close();
                 } else {
                     sender
-                        .try_send(system_index)
+                        .try_send(SystemResult {
+                            system_index,
+                            #[cfg(feature = "system_timing")]
+                            duration: Some(start.elapsed()),
+                        })
                         .unwrap_or_else(|error| unreachable!("{}", error));
                 }
             };
@@ -605,6 +641,13 @@ fn apply_system_buffers(
     }
 }
 
+#[cfg(feature = "system_timing")]
+fn record_result_duration(system_durations: &mut [Option<Duration>], result: &SystemResult) {
+    if let Some(duration) = result.duration {
+        super::record_system_duration(&mut system_durations[result.system_index], duration);
+    }
+}
+
 fn evaluate_and_fold_conditions(conditions: &mut [BoxedCondition], world: &World) -> bool {
     // not short-circuiting is intentional
     #[allow(clippy::unnecessary_fold)]
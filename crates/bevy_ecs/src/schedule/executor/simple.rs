@@ -1,5 +1,7 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+#[cfg(feature = "system_timing")]
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
 
 use crate::{
@@ -78,7 +80,14 @@ impl SystemExecutor for SimpleExecutor {
             let system = &mut schedule.systems[system_index];
             #[cfg(feature = "trace")]
             let system_span = info_span!("system", name = &*name).entered();
+            #[cfg(feature = "system_timing")]
+            let start = Instant::now();
             system.run((), world);
+            #[cfg(feature = "system_timing")]
+            super::record_system_duration(
+                &mut schedule.system_durations[system_index],
+                start.elapsed(),
+            );
             #[cfg(feature = "trace")]
             system_span.exit();
 
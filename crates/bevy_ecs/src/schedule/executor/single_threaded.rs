@@ -1,5 +1,7 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+#[cfg(feature = "system_timing")]
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
 
 use crate::{
@@ -95,7 +97,14 @@ impl SystemExecutor for SingleThreadedExecutor {
             } else {
                 #[cfg(feature = "trace")]
                 let system_span = info_span!("system", name = &*name).entered();
+                #[cfg(feature = "system_timing")]
+                let start = Instant::now();
                 system.run((), world);
+                #[cfg(feature = "system_timing")]
+                super::record_system_duration(
+                    &mut schedule.system_durations[system_index],
+                    start.elapsed(),
+                );
                 #[cfg(feature = "trace")]
                 system_span.exit();
                 self.unapplied_systems.insert(system_index);
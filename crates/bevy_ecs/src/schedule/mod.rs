@@ -665,6 +665,283 @@ mod tests {
         }
     }
 
+    mod ambiguity_report {
+        use super::*;
+        use crate::component::ComponentId;
+
+        #[derive(Resource)]
+        struct X;
+
+        #[derive(Resource)]
+        struct Y;
+
+        fn read_x(_x: Res<X>) {}
+        fn write_x(_x: ResMut<X>) {}
+        fn write_xy(_x: ResMut<X>, _y: ResMut<Y>) {}
+        fn write_y(_y: ResMut<Y>) {}
+
+        fn report(
+            schedule: &mut Schedule,
+            world: &mut World,
+        ) -> Vec<(String, String, Vec<ComponentId>)> {
+            world.insert_resource(X);
+            world.insert_resource(Y);
+            schedule.initialize(world).unwrap();
+            let mut ambiguities = schedule.ambiguities();
+            for (a, b, _) in &mut ambiguities {
+                if a > b {
+                    std::mem::swap(a, b);
+                }
+            }
+            ambiguities.sort();
+            ambiguities
+        }
+
+        fn name(system: &str) -> String {
+            format!("bevy_ecs::schedule::tests::ambiguity_report::{system}")
+        }
+
+        #[test]
+        fn report_contents() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule.add_systems((read_x, write_x, write_y));
+            assert!(schedule.ambiguities().is_empty());
+
+            let ambiguities = report(&mut schedule, &mut world);
+            let x = world.components().resource_id::<X>().unwrap();
+            assert_eq!(
+                ambiguities,
+                vec![(name("read_x"), name("write_x"), vec![x])]
+            );
+        }
+
+        #[test]
+        fn exclusive_systems_conflict_on_world() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule.add_systems((read_x, named_exclusive_system));
+
+            let ambiguities = report(&mut schedule, &mut world);
+            assert_eq!(
+                ambiguities,
+                vec![(
+                    name("read_x"),
+                    "bevy_ecs::schedule::tests::named_exclusive_system".to_string(),
+                    vec![]
+                )]
+            );
+        }
+
+        #[test]
+        fn ambiguous_with_sets() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule
+                .configure_set(TestSet::A.ambiguous_with(TestSet::B))
+                .add_systems((
+                    read_x.in_set(TestSet::A),
+                    write_x.in_set(TestSet::B),
+                    write_xy,
+                ));
+
+            let ambiguities = report(&mut schedule, &mut world);
+            let x = world.components().resource_id::<X>().unwrap();
+            assert_eq!(
+                ambiguities,
+                vec![
+                    (name("read_x"), name("write_xy"), vec![x]),
+                    (name("write_x"), name("write_xy"), vec![x]),
+                ]
+            );
+        }
+
+        #[test]
+        fn ignore_ambiguity_on() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule
+                .set_build_settings(ScheduleBuildSettings::new().ignore_ambiguity_on::<X>())
+                .add_systems((read_x, write_x, write_xy, write_y));
+
+            let ambiguities = report(&mut schedule, &mut world);
+            let y = world.components().resource_id::<Y>().unwrap();
+            assert_eq!(
+                ambiguities,
+                vec![(name("write_xy"), name("write_y"), vec![y])]
+            );
+        }
+
+        #[test]
+        fn ignored_ambiguities_are_not_errors() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule
+                .set_build_settings(ScheduleBuildSettings {
+                    ambiguity_detection: LogLevel::Error,
+                    ..ScheduleBuildSettings::new().ignore_ambiguity_on::<X>()
+                })
+                .add_systems((read_x, write_x));
+            world.insert_resource(X);
+            assert!(matches!(schedule.initialize(&mut world), Ok(())));
+        }
+    }
+
+    mod auto_insert_apply_system_buffers {
+        use super::*;
+        use crate::system::Commands;
+
+        #[derive(Resource)]
+        struct X;
+
+        fn insert_x(mut commands: Commands) {
+            commands.insert_resource(X);
+        }
+
+        fn spawn(mut commands: Commands) {
+            commands.spawn_empty();
+        }
+
+        fn read_x(x: Option<Res<X>>) {
+            assert!(x.is_some());
+        }
+
+        fn noop() {}
+
+        fn count_apply_system_buffers(mut schedule: Schedule, auto_insert: bool) -> usize {
+            let mut world = World::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                auto_insert_apply_system_buffers: auto_insert,
+                ..Default::default()
+            });
+
+            let graph = schedule.graph_mut();
+            graph.initialize(&mut world);
+            let built = graph.build_schedule(world.components()).unwrap();
+            built
+                .system_ids
+                .iter()
+                .filter(|&&id| {
+                    graph
+                        .system_at(id)
+                        .name()
+                        .ends_with("::apply_system_buffers")
+                })
+                .count()
+        }
+
+        #[test]
+        fn disabled_by_default() {
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, noop).chain());
+            assert_eq!(count_apply_system_buffers(schedule, false), 0);
+        }
+
+        #[test]
+        fn inserts_sync_point() {
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, noop).chain());
+            assert_eq!(count_apply_system_buffers(schedule, true), 1);
+
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, noop.after(insert_x)));
+            assert_eq!(count_apply_system_buffers(schedule, true), 1);
+        }
+
+        #[test]
+        fn no_sync_point_without_deferred() {
+            let mut schedule = Schedule::new();
+            schedule.add_systems((noop, named_system, noop).chain());
+            assert_eq!(count_apply_system_buffers(schedule, true), 0);
+        }
+
+        #[test]
+        fn no_sync_point_when_ignoring_deferred() {
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, noop).chain_ignore_deferred());
+            assert_eq!(count_apply_system_buffers(schedule, true), 0);
+
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, noop.after_ignore_deferred(insert_x)));
+            assert_eq!(count_apply_system_buffers(schedule, true), 0);
+
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x.before_ignore_deferred(noop), noop));
+            assert_eq!(count_apply_system_buffers(schedule, true), 0);
+        }
+
+        #[test]
+        fn no_sync_point_when_ignoring_deferred_through_sets() {
+            let mut schedule = Schedule::new();
+            schedule.add_systems((
+                insert_x.in_set(TestSet::A),
+                noop.after_ignore_deferred(TestSet::A),
+            ));
+            assert_eq!(count_apply_system_buffers(schedule, true), 0);
+
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x.in_set(TestSet::A), noop.after(TestSet::A)));
+            assert_eq!(count_apply_system_buffers(schedule, true), 1);
+        }
+
+        #[test]
+        fn deduplicates_sync_points() {
+            // deferred systems at the same depth share one sync point
+            let mut schedule = Schedule::new();
+            schedule.add_systems((
+                insert_x.in_set(TestSet::A),
+                spawn.in_set(TestSet::A),
+                noop.after(TestSet::A),
+                named_system.after(TestSet::A),
+            ));
+            assert_eq!(count_apply_system_buffers(schedule, true), 1);
+
+            // successive deferred systems need one sync point each
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, spawn, noop).chain());
+            assert_eq!(count_apply_system_buffers(schedule, true), 2);
+        }
+
+        #[test]
+        fn explicit_apply_system_buffers() {
+            let mut schedule = Schedule::new();
+            schedule.add_systems((insert_x, apply_system_buffers, noop).chain());
+            assert_eq!(count_apply_system_buffers(schedule, true), 1);
+        }
+
+        #[test]
+        fn applies_commands() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                auto_insert_apply_system_buffers: true,
+                ..Default::default()
+            });
+            schedule.add_systems((insert_x, read_x).chain());
+            schedule.run(&mut world);
+            assert!(world.contains_resource::<X>());
+
+            // rebuilding reuses the existing sync point
+            schedule.add_system(noop.after(read_x));
+            schedule.run(&mut world);
+        }
+
+        #[test]
+        fn ambiguity_with_sync_points() {
+            let mut world = World::new();
+            let mut schedule = Schedule::new();
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                auto_insert_apply_system_buffers: true,
+                ..Default::default()
+            });
+            world.init_resource::<SystemOrder>();
+            schedule.add_systems((insert_x, named_system).chain());
+            schedule.add_systems((spawn, read_x.after(insert_x)).chain());
+            assert!(matches!(schedule.initialize(&mut world), Ok(())));
+        }
+    }
+
     mod base_sets {
         use super::*;
 
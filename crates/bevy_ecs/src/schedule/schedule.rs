@@ -1,4 +1,7 @@
+#[cfg(feature = "system_timing")]
+use std::borrow::Cow;
 use std::{
+    any::TypeId,
     fmt::{Debug, Write},
     result::Result,
 };
@@ -6,6 +9,8 @@ use std::{
 use bevy_utils::default;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+#[cfg(feature = "system_timing")]
+use bevy_utils::Duration;
 use bevy_utils::{
     petgraph::{algo::TarjanScc, prelude::*},
     thiserror::Error,
@@ -15,11 +20,13 @@ use bevy_utils::{
 
 use fixedbitset::FixedBitSet;
 
+use super::{config::Chain, executor::is_apply_system_buffers};
+
 use crate::{
     self as bevy_ecs,
     component::{ComponentId, Components, Tick},
     schedule::*,
-    system::{BoxedSystem, Resource, System},
+    system::{BoxedSystem, IntoSystem, Resource, System},
     world::World,
 };
 
@@ -122,6 +129,49 @@ fn make_executor(kind: ExecutorKind) -> Box<dyn SystemExecutor> {
     }
 }
 
+/// Resource that collects the time each system spent running, grouped by schedule.
+///
+/// Whenever a schedule is run through [`World::run_schedule`], the durations recorded by
+/// [`Schedule::take_system_durations`] are added to this resource, if it exists in the world.
+/// Readers are expected to [`drain`](SystemDurations::drain) it regularly, for example once per frame.
+#[cfg(feature = "system_timing")]
+#[derive(Default, Resource)]
+pub struct SystemDurations {
+    schedules: HashMap<BoxedScheduleLabel, HashMap<Cow<'static, str>, Duration>>,
+}
+
+#[cfg(feature = "system_timing")]
+impl SystemDurations {
+    /// Adds the `durations` of the systems of the schedule with the given `label`.
+    ///
+    /// Durations of systems that were already recorded since the last drain are added together.
+    pub fn record(
+        &mut self,
+        label: &dyn ScheduleLabel,
+        durations: impl IntoIterator<Item = (Cow<'static, str>, Duration)>,
+    ) {
+        if !self.schedules.contains_key(label) {
+            self.schedules.insert(label.dyn_clone(), HashMap::new());
+        }
+        let systems = self.schedules.get_mut(label).unwrap();
+        for (name, duration) in durations {
+            *systems.entry(name).or_default() += duration;
+        }
+    }
+
+    /// Returns the recorded durations of the systems of the schedule with the given `label`.
+    pub fn get(&self, label: &dyn ScheduleLabel) -> Option<&HashMap<Cow<'static, str>, Duration>> {
+        self.schedules.get(label)
+    }
+
+    /// Removes and returns the recorded durations of every schedule.
+    pub fn drain(
+        &mut self,
+    ) -> impl Iterator<Item = (BoxedScheduleLabel, HashMap<Cow<'static, str>, Duration>)> + '_ {
+        self.schedules.drain()
+    }
+}
+
 /// A collection of systems, and the metadata and executor needed to run them
 /// in a certain order under certain conditions.
 ///
@@ -256,8 +306,7 @@ impl Schedule {
     pub fn initialize(&mut self, world: &mut World) -> Result<(), ScheduleBuildError> {
         if self.graph.changed {
             self.graph.initialize(world);
-            self.graph
-                .update_schedule(&mut self.executable, world.components())?;
+            self.graph.update_schedule(&mut self.executable, world)?;
             self.graph.changed = false;
             self.executor_initialized = false;
         }
@@ -301,6 +350,51 @@ impl Schedule {
         }
     }
 
+    /// Returns the name of each system that ran since the last call, along with the total time it
+    /// spent running, and resets the recorded durations.
+    ///
+    /// Systems that were skipped by their run conditions are not returned. If the schedule ran
+    /// multiple times in between, the durations of each run are added together.
+    #[cfg(feature = "system_timing")]
+    pub fn take_system_durations(
+        &mut self,
+    ) -> impl Iterator<Item = (Cow<'static, str>, Duration)> + '_ {
+        self.executable
+            .systems
+            .iter()
+            .zip(self.executable.system_durations.iter_mut())
+            .filter_map(|(system, duration)| Some((system.name(), duration.take()?)))
+    }
+
+    /// Returns the pairs of systems with conflicting data access and indeterminate execution order
+    /// found when the schedule was last initialized, along with the components and resources they
+    /// conflict on.
+    ///
+    /// If the `Vec<ComponentId>` is empty, the systems conflict on [`World`] access.
+    /// Ambiguities ignored through `ambiguous_with` or [`ScheduleBuildSettings::ignore_ambiguity_on`]
+    /// are not included. Returns an empty list until the schedule has been initialized.
+    pub fn ambiguities(&self) -> Vec<(String, String, Vec<ComponentId>)> {
+        let names = self
+            .executable
+            .system_ids
+            .iter()
+            .zip(self.executable.systems.iter())
+            .map(|(&id, system)| (id, system.name()))
+            .collect::<HashMap<_, _>>();
+
+        self.graph
+            .conflicting_systems
+            .iter()
+            .map(|(a, b, conflicts)| {
+                (
+                    names[a].to_string(),
+                    names[b].to_string(),
+                    conflicts.clone(),
+                )
+            })
+            .collect()
+    }
+
     /// Directly applies any accumulated system buffers (like [`Commands`](crate::prelude::Commands)) to the `world`.
     ///
     /// Like always, system buffers are applied in the "topological sort order" of the schedule graph.
@@ -423,6 +517,8 @@ pub struct ScheduleGraph {
     ambiguous_with: UnGraphMap<NodeId, ()>,
     ambiguous_with_flattened: UnGraphMap<NodeId, ()>,
     ambiguous_with_all: HashSet<NodeId>,
+    no_sync_edges: HashSet<(NodeId, NodeId)>,
+    auto_sync_node_ids: HashMap<u32, NodeId>,
     conflicting_systems: Vec<(NodeId, NodeId, Vec<ComponentId>)>,
     changed: bool,
     settings: ScheduleBuildSettings,
@@ -445,6 +541,8 @@ impl ScheduleGraph {
             ambiguous_with: UnGraphMap::new(),
             ambiguous_with_flattened: UnGraphMap::new(),
             ambiguous_with_all: HashSet::new(),
+            no_sync_edges: HashSet::new(),
+            auto_sync_node_ids: HashMap::new(),
             conflicting_systems: Vec::new(),
             changed: false,
             settings: default(),
@@ -559,12 +657,17 @@ impl ScheduleGraph {
     fn add_systems<M>(&mut self, systems: impl IntoSystemConfigs<M>) {
         let SystemConfigs { systems, chained } = systems.into_configs();
         let mut system_iter = systems.into_iter();
-        if chained {
-            let Some(prev) = system_iter.next() else { return };
+        if chained != Chain::Unchained {
+            let Some(prev) = system_iter.next() else {
+                return;
+            };
             let mut prev_id = self.add_system_inner(prev).unwrap();
             for next in system_iter {
                 let next_id = self.add_system_inner(next).unwrap();
                 self.dependency.graph.add_edge(prev_id, next_id, ());
+                if chained == Chain::ChainedIgnoreDeferred {
+                    self.no_sync_edges.insert((prev_id, next_id));
+                }
                 prev_id = next_id;
             }
         } else {
@@ -783,7 +886,15 @@ impl ScheduleGraph {
         {
             let (lhs, rhs) = match kind {
                 DependencyKind::Before => (id, set),
+                DependencyKind::BeforeNoSync => {
+                    self.no_sync_edges.insert((id, set));
+                    (id, set)
+                }
                 DependencyKind::After => (set, id),
+                DependencyKind::AfterNoSync => {
+                    self.no_sync_edges.insert((set, id));
+                    (set, id)
+                }
             };
             self.dependency.graph.add_edge(lhs, rhs, ());
 
@@ -1088,38 +1199,56 @@ impl ScheduleGraph {
         // flatten: combine `in_set` with `before` and `after` information
         // have to do it like this to preserve transitivity
         let mut dependency_flattened = self.dependency.graph.clone();
+        let mut no_sync_flattened = self.no_sync_edges.clone();
         let mut temp = Vec::new();
         for (&set, systems) in set_systems.iter() {
             if systems.is_empty() {
                 for a in dependency_flattened.neighbors_directed(set, Direction::Incoming) {
                     for b in dependency_flattened.neighbors_directed(set, Direction::Outgoing) {
-                        temp.push((a, b));
+                        let no_sync = no_sync_flattened.contains(&(a, set))
+                            && no_sync_flattened.contains(&(set, b));
+                        temp.push((a, b, no_sync));
                     }
                 }
             } else {
                 for a in dependency_flattened.neighbors_directed(set, Direction::Incoming) {
+                    let no_sync = no_sync_flattened.contains(&(a, set));
                     for &sys in systems {
-                        temp.push((a, sys));
+                        temp.push((a, sys, no_sync));
                     }
                 }
 
                 for b in dependency_flattened.neighbors_directed(set, Direction::Outgoing) {
+                    let no_sync = no_sync_flattened.contains(&(set, b));
                     for &sys in systems {
-                        temp.push((sys, b));
+                        temp.push((sys, b, no_sync));
                     }
                 }
             }
 
             dependency_flattened.remove_node(set);
-            for (a, b) in temp.drain(..) {
+            for (a, b, no_sync) in temp.drain(..) {
                 dependency_flattened.add_edge(a, b, ());
+                if no_sync {
+                    no_sync_flattened.insert((a, b));
+                }
             }
         }
 
         // topsort
-        self.dependency_flattened.topsort = self
+        let mut topsort = self
             .topsort_graph(&dependency_flattened, ReportCycles::Dependency)
             .map_err(|_| ScheduleBuildError::DependencyCycle)?;
+
+        if self.settings.auto_insert_apply_system_buffers {
+            topsort = self.auto_insert_apply_system_buffers(
+                &mut dependency_flattened,
+                &topsort,
+                &no_sync_flattened,
+            )?;
+        }
+
+        self.dependency_flattened.topsort = topsort;
         self.dependency_flattened.graph = dependency_flattened;
 
         let flat_results = check_graph(
@@ -1160,6 +1289,18 @@ impl ScheduleGraph {
         self.ambiguous_with_flattened = ambiguous_with_flattened;
 
         // check for conflicts
+        let ignored_ambiguities = self
+            .settings
+            .ignored_ambiguities
+            .iter()
+            .flat_map(|&type_id| {
+                [
+                    components.get_id(type_id),
+                    components.get_resource_id(type_id),
+                ]
+            })
+            .flatten()
+            .collect::<HashSet<_>>();
         let mut conflicting_systems = Vec::new();
         for &(a, b) in &flat_results.disconnected {
             if self.ambiguous_with_flattened.contains_edge(a, b)
@@ -1177,7 +1318,13 @@ impl ScheduleGraph {
                 let access_a = system_a.component_access();
                 let access_b = system_b.component_access();
                 if !access_a.is_compatible(access_b) {
-                    let conflicts = access_a.get_conflicts(access_b);
+                    let mut conflicts = access_a.get_conflicts(access_b);
+                    if !conflicts.is_empty() {
+                        conflicts.retain(|id| !ignored_ambiguities.contains(id));
+                        if conflicts.is_empty() {
+                            continue;
+                        }
+                    }
                     conflicting_systems.push((a, b, conflicts));
                 }
             }
@@ -1230,7 +1377,8 @@ impl ScheduleGraph {
 
         let sys_count = self.systems.len();
         let set_with_conditions_count = hg_set_ids.len();
-        let node_count = self.systems.len() + self.system_sets.len();
+        // automatically inserted sync points are not part of the hierarchy
+        let node_count = self.hierarchy.graph.node_count();
 
         // get the number of dependencies and the immediate dependents of each system
         // (needed by multi-threaded executor to run systems in the correct order)
@@ -1292,13 +1440,87 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            #[cfg(feature = "system_timing")]
+            system_durations: vec![None; sys_count],
         })
     }
 
+    /// Inserts [`apply_system_buffers`] systems between systems with deferred parameters and
+    /// the systems that depend on them, returning the new topological order.
+    ///
+    /// Each sync point is shared by every edge leaving a node at the same distance (number of
+    /// sync points that must run before it), so adjacent sync points are deduplicated.
+    fn auto_insert_apply_system_buffers(
+        &mut self,
+        dependency_flattened: &mut DiGraphMap<NodeId, ()>,
+        topsort: &[NodeId],
+        no_sync_edges: &HashSet<(NodeId, NodeId)>,
+    ) -> Result<Vec<NodeId>, ScheduleBuildError> {
+        let mut distances: HashMap<NodeId, u32> = HashMap::with_capacity(topsort.len());
+        for &node in topsort {
+            let add_sync_after = self.systems[node.index()].get().unwrap().has_deferred();
+            let distance = distances.get(&node).copied().unwrap_or(0);
+            for target in dependency_flattened.neighbors_directed(node, Direction::Outgoing) {
+                let needs_sync = add_sync_after
+                    && !is_apply_system_buffers(self.systems[target.index()].get().unwrap())
+                    && !no_sync_edges.contains(&(node, target));
+                let target_distance = distance + u32::from(needs_sync);
+                let entry = distances.entry(target).or_insert(0);
+                *entry = (*entry).max(target_distance);
+            }
+        }
+
+        let mut sync_edges = Vec::new();
+        for &node in topsort {
+            if !self.systems[node.index()].get().unwrap().has_deferred() {
+                continue;
+            }
+            let distance = distances.get(&node).copied().unwrap_or(0);
+            for target in dependency_flattened.neighbors_directed(node, Direction::Outgoing) {
+                if is_apply_system_buffers(self.systems[target.index()].get().unwrap())
+                    || no_sync_edges.contains(&(node, target))
+                {
+                    continue;
+                }
+                sync_edges.push((node, target, distance));
+            }
+        }
+
+        for (node, target, distance) in sync_edges {
+            let sync_point = self.get_or_add_auto_sync_node(distance);
+            dependency_flattened.add_edge(node, sync_point, ());
+            dependency_flattened.add_edge(sync_point, target, ());
+            // the sync point now carries the ordering, the direct edge would be redundant
+            dependency_flattened.remove_edge(node, target);
+        }
+
+        self.topsort_graph(dependency_flattened, ReportCycles::Dependency)
+            .map_err(|_| ScheduleBuildError::DependencyCycle)
+    }
+
+    /// Returns the automatically inserted sync point for the given distance, adding it if needed.
+    fn get_or_add_auto_sync_node(&mut self, distance: u32) -> NodeId {
+        if let Some(&id) = self.auto_sync_node_ids.get(&distance) {
+            return id;
+        }
+
+        let id = NodeId::System(self.systems.len());
+        self.systems
+            .push(SystemNode::new(Box::new(IntoSystem::into_system(
+                apply_system_buffers,
+            ))));
+        self.system_conditions.push(Some(Vec::new()));
+        // sync points are exclusive and only ordered relative to the systems they serve
+        self.ambiguous_with_all.insert(id);
+        self.uninit.push((id, 0));
+        self.auto_sync_node_ids.insert(distance, id);
+        id
+    }
+
     fn update_schedule(
         &mut self,
         schedule: &mut SystemSchedule,
-        components: &Components,
+        world: &mut World,
     ) -> Result<(), ScheduleBuildError> {
         if !self.uninit.is_empty() {
             return Err(ScheduleBuildError::Uninitialized);
@@ -1323,7 +1545,10 @@ impl ScheduleGraph {
             self.system_set_conditions[id.index()] = Some(conditions);
         }
 
-        *schedule = self.build_schedule(components)?;
+        *schedule = self.build_schedule(world.components())?;
+
+        // initialize any automatically inserted sync points
+        self.initialize(world);
 
         // move systems into new schedule
         for &id in &schedule.system_ids {
@@ -1665,6 +1890,23 @@ pub struct ScheduleBuildSettings {
     ///
     /// Defaults to `true`.
     pub report_sets: bool,
+    /// If set to true, an [`apply_system_buffers`] system is automatically inserted between
+    /// systems with deferred parameters (such as [`Commands`](crate::system::Commands)) and
+    /// the systems ordered after them, unless an explicit [`apply_system_buffers`] already
+    /// separates them. Systems ordered by the same depth share a single sync point.
+    ///
+    /// Use [`before_ignore_deferred`](IntoSystemConfig::before_ignore_deferred),
+    /// [`after_ignore_deferred`](IntoSystemConfig::after_ignore_deferred) or
+    /// [`chain_ignore_deferred`](IntoSystemConfigs::chain_ignore_deferred) to order
+    /// systems without implying a sync point.
+    ///
+    /// Defaults to `false`.
+    pub auto_insert_apply_system_buffers: bool,
+    /// The types of components and resources whose conflicting access is never reported as an
+    /// ambiguity, see [`ignore_ambiguity_on`](Self::ignore_ambiguity_on).
+    ///
+    /// Defaults to empty.
+    pub ignored_ambiguities: Vec<TypeId>,
 }
 
 impl Default for ScheduleBuildSettings {
@@ -1680,6 +1922,17 @@ impl ScheduleBuildSettings {
             hierarchy_detection: LogLevel::Warn,
             use_shortnames: true,
             report_sets: true,
+            auto_insert_apply_system_buffers: false,
+            ignored_ambiguities: Vec::new(),
         }
     }
+
+    /// Ignores ambiguities caused by conflicting access to the component or resource `T`.
+    ///
+    /// Systems that also conflict on other data are still reported, with `T` left out of
+    /// the conflicts.
+    pub fn ignore_ambiguity_on<T: 'static>(mut self) -> Self {
+        self.ignored_ambiguities.push(TypeId::of::<T>());
+        self
+    }
 }
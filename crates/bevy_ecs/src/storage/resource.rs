@@ -249,6 +249,18 @@ impl<const SEND: bool> Resources<SEND> {
         self.resources.clear();
     }
 
+    /// Validates that the resources can be accessed and dropped from the current thread.
+    ///
+    /// # Panics
+    /// If `SEND` is false, this will panic if one of the resources was inserted from a different thread.
+    pub(crate) fn validate_access(&self) {
+        for (_, data) in self.iter() {
+            if data.is_present() {
+                data.validate_access();
+            }
+        }
+    }
+
     /// Gets mutable access to a resource, if it exists.
     #[inline]
     pub(crate) fn get_mut(&mut self, component_id: ComponentId) -> Option<&mut ResourceData<SEND>> {
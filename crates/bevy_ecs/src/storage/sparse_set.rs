@@ -218,7 +218,7 @@ impl ComponentSparseSet {
         })
     }
 
-    /// Returns references to the entity's component value and its added and changed ticks.
+    /// Returns references to the entity's component value and its added, changed and replaced ticks.
     ///
     /// Returns `None` if `entity` does not have a component in the sparse set.
     #[inline]
@@ -233,6 +233,7 @@ impl ComponentSparseSet {
                 TickCells {
                     added: self.dense.get_added_ticks_unchecked(dense_index),
                     changed: self.dense.get_changed_ticks_unchecked(dense_index),
+                    replaced: self.dense.get_replaced_ticks_unchecked(dense_index),
                 },
             ))
         }
@@ -272,7 +273,23 @@ impl ComponentSparseSet {
         }
     }
 
-    /// Returns a reference to the "added" and "changed" ticks of the entity's component value.
+    /// Returns a reference to the "replaced" tick of the entity's component value.
+    ///
+    /// Returns `None` if `entity` does not have a component in the sparse set, or if the
+    /// component doesn't track replacements.
+    #[inline]
+    pub fn get_replaced_ticks(&self, entity: Entity) -> Option<&UnsafeCell<Tick>> {
+        let dense_index = *self.sparse.get(entity.index())? as usize;
+        #[cfg(debug_assertions)]
+        assert_eq!(entity, self.entities[dense_index]);
+        // SAFETY: if the sparse index points to something in the dense vec, it exists
+        unsafe {
+            self.dense
+                .get_replaced_ticks_unchecked(TableRow::new(dense_index))
+        }
+    }
+
+    /// Returns a reference to the "added", "changed" and "replaced" ticks of the entity's component value.
     ///
     /// Returns `None` if `entity` does not have a component in the sparse set.
     #[inline]
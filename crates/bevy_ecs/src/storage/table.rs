@@ -98,11 +98,12 @@ impl TableRow {
 ///
 /// Conceptually, a [`Column`] is very similar to a type-erased `Vec<T>`.
 /// It also stores the change detection ticks for its components, kept in two separate
-/// contiguous buffers internally. An element shares its data across these buffers by using the
-/// same index (i.e. the entity at row 3 has it's data at index 3 and its change detection ticks at
-/// index 3). A slice to these contiguous blocks of memory can be fetched
-/// via [`Column::get_data_slice`], [`Column::get_added_ticks_slice`], and
-/// [`Column::get_changed_ticks_slice`].
+/// contiguous buffers internally, or three for the components that
+/// [track replacements](crate::component::Component::TRACK_REPLACEMENTS). An element shares its
+/// data across these buffers by using the same index (i.e. the entity at row 3 has it's data at
+/// index 3 and its change detection ticks at index 3). A slice to these contiguous blocks of
+/// memory can be fetched via [`Column::get_data_slice`], [`Column::get_added_ticks_slice`],
+/// [`Column::get_changed_ticks_slice`], and [`Column::get_replaced_ticks_slice`].
 ///
 /// Like many other low-level storage types, [`Column`] has a limited and highly unsafe
 /// interface. It's highly advised to use higher level types and their safe abstractions
@@ -112,6 +113,8 @@ pub struct Column {
     data: BlobVec,
     added_ticks: Vec<UnsafeCell<Tick>>,
     changed_ticks: Vec<UnsafeCell<Tick>>,
+    /// Only present if the components track replacements.
+    replaced_ticks: Option<Vec<UnsafeCell<Tick>>>,
 }
 
 impl Column {
@@ -123,6 +126,9 @@ impl Column {
             data: unsafe { BlobVec::new(component_info.layout(), component_info.drop(), capacity) },
             added_ticks: Vec::with_capacity(capacity),
             changed_ticks: Vec::with_capacity(capacity),
+            replaced_ticks: component_info
+                .tracks_replacements()
+                .then(|| Vec::with_capacity(capacity)),
         }
     }
 
@@ -144,9 +150,14 @@ impl Column {
         self.data.initialize_unchecked(row.index(), data);
         *self.added_ticks.get_unchecked_mut(row.index()).get_mut() = tick;
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = tick;
+        if let Some(replaced_ticks) = &mut self.replaced_ticks {
+            *replaced_ticks.get_unchecked_mut(row.index()).get_mut() =
+                ComponentTicks::never_replaced(tick);
+        }
     }
 
-    /// Writes component data to the column at given row.
+    /// Writes component data to the column at given row, marking it as changed and replaced if
+    /// it tracks replacements.
     /// Assumes the slot is initialized, calls drop.
     ///
     /// # Safety
@@ -156,6 +167,9 @@ impl Column {
         debug_assert!(row.index() < self.len());
         self.data.replace_unchecked(row.index(), data);
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = change_tick;
+        if let Some(replaced_ticks) = &mut self.replaced_ticks {
+            *replaced_ticks.get_unchecked_mut(row.index()).get_mut() = change_tick;
+        }
     }
 
     /// Writes component data to the column at given row.
@@ -198,6 +212,9 @@ impl Column {
         self.data.swap_remove_and_drop_unchecked(row.index());
         self.added_ticks.swap_remove(row.index());
         self.changed_ticks.swap_remove(row.index());
+        if let Some(replaced_ticks) = &mut self.replaced_ticks {
+            replaced_ticks.swap_remove(row.index());
+        }
     }
 
     /// Removes an element from the [`Column`] and returns it and its change detection ticks.
@@ -221,7 +238,15 @@ impl Column {
             let data = unsafe { self.data.swap_remove_and_forget_unchecked(row.index()) };
             let added = self.added_ticks.swap_remove(row.index()).into_inner();
             let changed = self.changed_ticks.swap_remove(row.index()).into_inner();
-            (data, ComponentTicks { added, changed })
+            let replaced = Self::swap_remove_replaced_tick(&mut self.replaced_ticks, row, added);
+            (
+                data,
+                ComponentTicks {
+                    added,
+                    changed,
+                    replaced,
+                },
+            )
         })
     }
 
@@ -246,7 +271,29 @@ impl Column {
         let data = self.data.swap_remove_and_forget_unchecked(row.index());
         let added = self.added_ticks.swap_remove(row.index()).into_inner();
         let changed = self.changed_ticks.swap_remove(row.index()).into_inner();
-        (data, ComponentTicks { added, changed })
+        let replaced = Self::swap_remove_replaced_tick(&mut self.replaced_ticks, row, added);
+        (
+            data,
+            ComponentTicks {
+                added,
+                changed,
+                replaced,
+            },
+        )
+    }
+
+    /// Removes the "replaced" tick at `row`, or returns the one of a component added at `added`
+    /// that was never replaced if the components don't track replacements.
+    #[inline]
+    fn swap_remove_replaced_tick(
+        replaced_ticks: &mut Option<Vec<UnsafeCell<Tick>>>,
+        row: TableRow,
+        added: Tick,
+    ) -> Tick {
+        match replaced_ticks {
+            Some(replaced_ticks) => replaced_ticks.swap_remove(row.index()).into_inner(),
+            None => ComponentTicks::never_replaced(added),
+        }
     }
 
     /// Removes the element from `other` at `src_row` and inserts it
@@ -274,6 +321,12 @@ impl Column {
             other.added_ticks.swap_remove(src_row.index());
         *self.changed_ticks.get_unchecked_mut(dst_row.index()) =
             other.changed_ticks.swap_remove(src_row.index());
+        if let (Some(replaced_ticks), Some(other_replaced_ticks)) =
+            (&mut self.replaced_ticks, &mut other.replaced_ticks)
+        {
+            *replaced_ticks.get_unchecked_mut(dst_row.index()) =
+                other_replaced_ticks.swap_remove(src_row.index());
+        }
     }
 
     /// Pushes a new value onto the end of the [`Column`].
@@ -284,6 +337,9 @@ impl Column {
         self.data.push(ptr);
         self.added_ticks.push(UnsafeCell::new(ticks.added));
         self.changed_ticks.push(UnsafeCell::new(ticks.changed));
+        if let Some(replaced_ticks) = &mut self.replaced_ticks {
+            replaced_ticks.push(UnsafeCell::new(ticks.replaced));
+        }
     }
 
     #[inline]
@@ -291,6 +347,9 @@ impl Column {
         self.data.reserve_exact(additional);
         self.added_ticks.reserve_exact(additional);
         self.changed_ticks.reserve_exact(additional);
+        if let Some(replaced_ticks) = &mut self.replaced_ticks {
+            replaced_ticks.reserve_exact(additional);
+        }
     }
 
     /// Fetches the data pointer to the first element of the [`Column`].
@@ -341,6 +400,19 @@ impl Column {
         &self.changed_ticks
     }
 
+    /// Fetches the slice to the [`Column`]'s "replaced" change detection ticks, which is empty if
+    /// its components don't track replacements.
+    ///
+    /// Note: The values stored within are [`UnsafeCell`].
+    /// Users of this API must ensure that accesses to each individual element
+    /// adhere to the safety invariants of [`UnsafeCell`].
+    ///
+    /// [`UnsafeCell`]: std::cell::UnsafeCell
+    #[inline]
+    pub fn get_replaced_ticks_slice(&self) -> &[UnsafeCell<Tick>] {
+        self.replaced_ticks.as_deref().unwrap_or(&[])
+    }
+
     /// Fetches a reference to the data and change detection ticks at `row`.
     ///
     /// Returns `None` if `row` is out of bounds.
@@ -355,6 +427,7 @@ impl Column {
                     TickCells {
                         added: self.added_ticks.get_unchecked(row.index()),
                         changed: self.changed_ticks.get_unchecked(row.index()),
+                        replaced: self.get_replaced_ticks_unchecked(row),
                     },
                 )
             })
@@ -432,6 +505,20 @@ impl Column {
         self.changed_ticks.get(row.index())
     }
 
+    /// Fetches the "replaced" change detection ticks for the value at `row`.
+    ///
+    /// Returns `None` if `row` is out of bounds, or if the components don't track replacements.
+    ///
+    /// Note: The values stored within are [`UnsafeCell`].
+    /// Users of this API must ensure that accesses to each individual element
+    /// adhere to the safety invariants of [`UnsafeCell`].
+    ///
+    /// [`UnsafeCell`]: std::cell::UnsafeCell
+    #[inline]
+    pub fn get_replaced_ticks(&self, row: TableRow) -> Option<&UnsafeCell<Tick>> {
+        self.replaced_ticks.as_ref()?.get(row.index())
+    }
+
     /// Fetches the change detection ticks for the value at `row`.
     ///
     /// Returns `None` if `row` is out of bounds.
@@ -467,6 +554,20 @@ impl Column {
         self.changed_ticks.get_unchecked(row.index())
     }
 
+    /// Fetches the "replaced" change detection ticks for the value at `row`. Unlike [`Column::get_replaced_ticks`]
+    /// this function does not do any bounds checking.
+    ///
+    /// Returns `None` if the components don't track replacements.
+    ///
+    /// # Safety
+    /// `row` must be within the range `[0, self.len())`.
+    #[inline]
+    pub unsafe fn get_replaced_ticks_unchecked(&self, row: TableRow) -> Option<&UnsafeCell<Tick>> {
+        let replaced_ticks = self.replaced_ticks.as_ref()?;
+        debug_assert!(row.index() < replaced_ticks.len());
+        Some(replaced_ticks.get_unchecked(row.index()))
+    }
+
     /// Fetches the change detection ticks for the value at `row`. Unlike [`Column::get_ticks`]
     /// this function does not do any bounds checking.
     ///
@@ -476,9 +577,14 @@ impl Column {
     pub unsafe fn get_ticks_unchecked(&self, row: TableRow) -> ComponentTicks {
         debug_assert!(row.index() < self.added_ticks.len());
         debug_assert!(row.index() < self.changed_ticks.len());
+        let added = self.added_ticks.get_unchecked(row.index()).read();
         ComponentTicks {
-            added: self.added_ticks.get_unchecked(row.index()).read(),
+            added,
             changed: self.changed_ticks.get_unchecked(row.index()).read(),
+            replaced: self.get_replaced_ticks_unchecked(row).map_or_else(
+                || ComponentTicks::never_replaced(added),
+                |replaced| replaced.read(),
+            ),
         }
     }
 
@@ -489,6 +595,9 @@ impl Column {
         self.data.clear();
         self.added_ticks.clear();
         self.changed_ticks.clear();
+        if let Some(replaced_ticks) = &mut self.replaced_ticks {
+            replaced_ticks.clear();
+        }
     }
 
     #[inline]
@@ -499,6 +608,9 @@ impl Column {
         for component_ticks in &mut self.changed_ticks {
             component_ticks.get_mut().check_tick(change_tick);
         }
+        for component_ticks in self.replaced_ticks.iter_mut().flatten() {
+            component_ticks.get_mut().check_tick(change_tick);
+        }
     }
 }
 
@@ -736,6 +848,9 @@ impl Table {
             column.data.set_len(self.entities.len());
             column.added_ticks.push(UnsafeCell::new(Tick::new(0)));
             column.changed_ticks.push(UnsafeCell::new(Tick::new(0)));
+            if let Some(replaced_ticks) = &mut column.replaced_ticks {
+                replaced_ticks.push(UnsafeCell::new(Tick::new(0)));
+            }
         }
         TableRow::new(index)
     }
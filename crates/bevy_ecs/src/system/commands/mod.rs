@@ -4,7 +4,10 @@ mod parallel_scope;
 use crate::{
     self as bevy_ecs,
     bundle::Bundle,
+    change_detection::Mut,
+    component::Component,
     entity::{Entities, Entity},
+    entity_disabling::Disabled,
     world::{FromWorld, World},
 };
 use bevy_ecs_macros::SystemParam;
@@ -706,6 +709,47 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         self
     }
 
+    /// Gets the entry of the component `T` of the entity, to modify it if the entity has one and
+    /// insert it otherwise, like the entry of a [`HashMap`](std::collections::HashMap).
+    ///
+    /// This queues a single command, which checks for the component when it's applied, after the
+    /// commands queued before it.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component, Default)]
+    /// struct Level(u32);
+    ///
+    /// fn level_up_system(mut commands: Commands, players: Query<Entity, With<Level>>) {
+    ///     for player in &players {
+    ///         commands
+    ///             .entity(player)
+    ///             .entry::<Level>()
+    ///             // Only runs if the player still has a level when the command is applied
+    ///             .and_modify(|mut level| level.0 += 1)
+    ///             // Otherwise, the player starts again from the default level
+    ///             .or_default();
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(level_up_system);
+    /// ```
+    pub fn entry<T: Component>(&mut self) -> EntityEntryCommands<'w, 's, '_, T> {
+        EntityEntryCommands {
+            entity_commands: Some(EntityCommands {
+                entity: self.entity,
+                commands: self.commands,
+            }),
+            modify: None,
+        }
+    }
+
     /// Removes a [`Bundle`] of components from the entity.
     ///
     /// See [`EntityMut::remove`](crate::world::EntityMut::remove) for more
@@ -755,6 +799,39 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         self
     }
 
+    /// Disables the entity, inserting a [`Disabled`] component so that queries skip it.
+    ///
+    /// This is cheaper than despawning an entity to spawn it again later, see
+    /// [`entity_disabling`](crate::entity_disabling) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component)]
+    /// struct Bullet {
+    ///     range: f32,
+    /// }
+    ///
+    /// fn pool_spent_bullets(mut commands: Commands, bullets: Query<(Entity, &Bullet)>) {
+    ///     for (entity, bullet) in &bullets {
+    ///         if bullet.range <= 0.0 {
+    ///             commands.entity(entity).disable();
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(pool_spent_bullets);
+    /// ```
+    pub fn disable(&mut self) -> &mut Self {
+        self.insert(Disabled)
+    }
+
+    /// Enables the entity back, removing its [`Disabled`] component.
+    pub fn enable(&mut self) -> &mut Self {
+        self.remove::<Disabled>()
+    }
+
     /// Despawns the entity.
     ///
     /// See [`World::despawn`] for more details.
@@ -824,6 +901,76 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
     }
 }
 
+/// The entry of a component of an entity, to modify the component or insert it.
+///
+/// It's returned by [`EntityCommands::entry`], and queues an [`Entry`] command once one of its
+/// `or_*` methods is called, or when it's dropped.
+pub struct EntityEntryCommands<'w, 's, 'a, T: Component> {
+    // Taken once the command is queued
+    entity_commands: Option<EntityCommands<'w, 's, 'a>>,
+    #[allow(clippy::type_complexity)]
+    modify: Option<Box<dyn FnOnce(Mut<T>) + Send>>,
+}
+
+impl<'w, 's, 'a, T: Component> EntityEntryCommands<'w, 's, 'a, T> {
+    /// Modifies the component if the entity has it.
+    ///
+    /// Modifications run in the order they're added, and not at all if the component is missing.
+    pub fn and_modify(mut self, modify: impl FnOnce(Mut<T>) + Send + 'static) -> Self {
+        self.modify = Some(match self.modify.take() {
+            Some(previous) => Box::new(move |mut component: Mut<T>| {
+                previous(component.reborrow());
+                modify(component);
+            }),
+            None => Box::new(modify),
+        });
+        self
+    }
+
+    /// Inserts `value` if the entity doesn't have the component.
+    pub fn or_insert(self, value: T) -> EntityCommands<'w, 's, 'a> {
+        self.or_insert_with(move || value)
+    }
+
+    /// Inserts the component returned by `default` if the entity doesn't have one.
+    ///
+    /// `default` is only called when the command is applied and the component is missing.
+    pub fn or_insert_with(
+        mut self,
+        default: impl FnOnce() -> T + Send + 'static,
+    ) -> EntityCommands<'w, 's, 'a> {
+        self.queue(Some(Box::new(default)))
+            .expect("the entry should only be queued once")
+    }
+
+    /// Inserts the default value of the component if the entity doesn't have one.
+    pub fn or_default(self) -> EntityCommands<'w, 's, 'a>
+    where
+        T: Default,
+    {
+        self.or_insert_with(T::default)
+    }
+
+    fn queue(
+        &mut self,
+        default: Option<Box<dyn FnOnce() -> T + Send>>,
+    ) -> Option<EntityCommands<'w, 's, 'a>> {
+        let entity_commands = self.entity_commands.take()?;
+        entity_commands.commands.add(Entry {
+            entity: entity_commands.entity,
+            modify: self.modify.take(),
+            default,
+        });
+        Some(entity_commands)
+    }
+}
+
+impl<'w, 's, 'a, T: Component> Drop for EntityEntryCommands<'w, 's, 'a, T> {
+    fn drop(&mut self) {
+        self.queue(None);
+    }
+}
+
 impl<F> Command for F
 where
     F: FnOnce(&mut World) + Send + 'static,
@@ -939,6 +1086,30 @@ where
     }
 }
 
+/// [`Command`] to modify the component `T` of an entity, or insert it. See
+/// [`EntityCommands::entry`].
+#[allow(clippy::type_complexity)]
+pub struct Entry<T: Component> {
+    pub entity: Entity,
+    pub modify: Option<Box<dyn FnOnce(Mut<T>) + Send>>,
+    pub default: Option<Box<dyn FnOnce() -> T + Send>>,
+}
+
+impl<T: Component> Command for Entry<T> {
+    fn write(self, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(self.entity) else {
+            panic!("error[B0003]: Could not modify or insert a component (of type `{}`) for entity {:?} because it doesn't exist in this World.", std::any::type_name::<T>(), self.entity);
+        };
+        if let Some(component) = entity.get_mut::<T>() {
+            if let Some(modify) = self.modify {
+                modify(component);
+            }
+        } else if let Some(default) = self.default {
+            entity.insert(default());
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Remove<T> {
     pub entity: Entity,
@@ -1093,6 +1264,80 @@ mod tests {
         assert_eq!(results3, vec![(42u32, 0u64), (0u32, 42u64)]);
     }
 
+    #[test]
+    fn entry_modifies_present_components_and_inserts_missing_ones() {
+        #[derive(Component, Default, Debug, PartialEq)]
+        struct Counter(u32);
+
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+        let present = world.spawn((W(1u32), Counter(1))).id();
+        let absent = world.spawn_empty().id();
+
+        let mut commands = Commands::new(&mut command_queue, &world);
+        for entity in [present, absent] {
+            commands
+                .entity(entity)
+                .entry::<W<u32>>()
+                .and_modify(|mut w| w.0 += 1)
+                .and_modify(|mut w| w.0 *= 10)
+                .or_insert(W(5));
+            commands
+                .entity(entity)
+                .entry::<Counter>()
+                .and_modify(|mut counter| counter.0 += 1)
+                .or_default();
+            commands
+                .entity(entity)
+                .entry::<W<u64>>()
+                .or_insert_with(|| W(7));
+        }
+        command_queue.apply(&mut world);
+
+        assert_eq!(world.get::<W<u32>>(present).unwrap().0, 20);
+        assert_eq!(world.get::<W<u32>>(absent).unwrap().0, 5);
+        assert_eq!(world.get::<Counter>(present), Some(&Counter(2)));
+        assert_eq!(world.get::<Counter>(absent), Some(&Counter(0)));
+        assert_eq!(world.get::<W<u64>>(present).unwrap().0, 7);
+        assert_eq!(world.get::<W<u64>>(absent).unwrap().0, 7);
+
+        // Without an `or_*` call, the entry only modifies the component
+        Commands::new(&mut command_queue, &world)
+            .entity(absent)
+            .entry::<W<u32>>()
+            .and_modify(|mut w| w.0 += 1);
+        Commands::new(&mut command_queue, &world)
+            .entity(present)
+            .remove::<W<u64>>()
+            .entry::<W<u64>>()
+            .and_modify(|mut w| w.0 += 1);
+        command_queue.apply(&mut world);
+        assert_eq!(world.get::<W<u32>>(absent).unwrap().0, 6);
+        assert!(world.get::<W<u64>>(present).is_none());
+    }
+
+    #[test]
+    fn entry_is_ordered_with_inserts() {
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+        let entity = world.spawn_empty().id();
+
+        Commands::new(&mut command_queue, &world)
+            .entity(entity)
+            .insert(W(1u32))
+            .entry::<W<u32>>()
+            .and_modify(|mut w| w.0 += 1)
+            .or_insert(W(10))
+            .insert(W(2u64))
+            .entry::<W<u64>>()
+            .and_modify(|mut w| w.0 += 1)
+            .or_insert(W(10))
+            .insert(W(5u64));
+        command_queue.apply(&mut world);
+        assert_eq!(world.get::<W<u32>>(entity).unwrap().0, 2);
+        assert_eq!(world.get::<W<u64>>(entity).unwrap().0, 5);
+    }
+
     #[test]
     fn remove_components() {
         let mut world = World::default();
@@ -13,12 +13,26 @@ use super::{CommandQueue, Commands};
 
 #[derive(Default)]
 struct ParallelCommandQueue {
-    thread_local_storage: ThreadLocal<Cell<CommandQueue>>,
+    thread_local_storage: ThreadLocal<Cell<ThreadCommandQueues>>,
+    /// Scratch space used to sort ordered command queues, kept to avoid reallocating it.
+    apply_order: Vec<(usize, usize, CommandQueue)>,
+}
+
+/// The command queues of a single thread.
+#[derive(Default)]
+struct ThreadCommandQueues {
+    /// Commands recorded by [`ParallelCommands::command_scope`].
+    unordered: CommandQueue,
+    /// Commands recorded by [`ParallelCommands::command_scope_ordered`], along with their index.
+    ordered: Vec<(usize, CommandQueue)>,
+    /// Empty queues from previous applications, reused by ordered scopes.
+    pool: Vec<CommandQueue>,
 }
 
 /// An alternative to [`Commands`] that can be used in parallel contexts, such as those in [`Query::par_iter`](crate::system::Query::par_iter)
 ///
 /// Note: Because command application order will depend on how many threads are ran, non-commutative commands may result in non-deterministic results.
+/// Use [`command_scope_ordered`](Self::command_scope_ordered) when the order matters.
 ///
 /// Example:
 /// ```
@@ -55,24 +69,191 @@ impl SystemBuffer for ParallelCommandQueue {
         let _system_span =
             bevy_utils::tracing::info_span!("system_commands", name = _system_meta.name())
                 .entered();
-        for cq in &mut self.thread_local_storage {
-            cq.get_mut().apply(world);
+        for (thread, queues) in self.thread_local_storage.iter_mut().enumerate() {
+            let queues = queues.get_mut();
+            queues.unordered.apply(world);
+            self.apply_order.extend(
+                queues
+                    .ordered
+                    .drain(..)
+                    .map(|(index, queue)| (index, thread, queue)),
+            );
+        }
+
+        // stable, so queues with the same index and thread keep the order they were recorded in
+        self.apply_order
+            .sort_by_key(|&(index, thread, _)| (index, thread));
+        for (_, _, queue) in &mut self.apply_order {
+            queue.apply(world);
+        }
+
+        // return the now empty queues to the threads they came from
+        self.apply_order.sort_by_key(|&(_, thread, _)| thread);
+        let mut emptied = self.apply_order.drain(..).peekable();
+        for (thread, queues) in self.thread_local_storage.iter_mut().enumerate() {
+            let queues = queues.get_mut();
+            while let Some((_, _, queue)) = emptied.next_if(|&(_, t, _)| t == thread) {
+                queues.pool.push(queue);
+            }
         }
     }
 }
 
 impl<'w, 's> ParallelCommands<'w, 's> {
+    /// Temporarily provides access to the [`Commands`] of the current thread.
+    ///
+    /// Commands recorded here are applied before those of [`command_scope_ordered`](Self::command_scope_ordered),
+    /// in an order that depends on how the work was split between threads.
     pub fn command_scope<R>(&self, f: impl FnOnce(Commands) -> R) -> R {
+        self.command_scope_with_entity_reserver(|commands, _| f(commands))
+    }
+
+    /// Like [`command_scope`](Self::command_scope), but also provides the [`Entities`] of the world.
+    ///
+    /// Entities reserved through [`Entities::reserve_entity`] can be referenced right away,
+    /// for example to spawn an entity and store it in a component of another one in the same scope.
+    pub fn command_scope_with_entity_reserver<R>(
+        &self,
+        f: impl FnOnce(Commands, &Entities) -> R,
+    ) -> R {
         let store = &self.state.thread_local_storage;
         let command_queue_cell = store.get_or_default();
-        let mut command_queue = command_queue_cell.take();
+        let mut queues = command_queue_cell.take();
 
-        let r = f(Commands::new_from_entities(
-            &mut command_queue,
+        let r = f(
+            Commands::new_from_entities(&mut queues.unordered, self.entities),
             self.entities,
-        ));
+        );
+
+        command_queue_cell.set(queues);
+        r
+    }
+
+    /// Temporarily provides access to [`Commands`] that are applied in ascending order of `index`.
+    ///
+    /// Unlike [`command_scope`](Self::command_scope), the resulting order does not depend on
+    /// how the work was split between threads, as long as every `index` is used from a single
+    /// thread, e.g. by passing the index of the entity or item being processed.
+    /// Commands with the same index are applied in the order they were recorded.
+    ///
+    /// The queues backing these scopes are kept and reused when the system runs again.
+    pub fn command_scope_ordered<R>(&self, index: usize, f: impl FnOnce(Commands) -> R) -> R {
+        let store = &self.state.thread_local_storage;
+        let command_queue_cell = store.get_or_default();
+        let mut queues = command_queue_cell.take();
 
-        command_queue_cell.set(command_queue);
+        if !matches!(queues.ordered.last(), Some(&(last, _)) if last == index) {
+            let queue = queues.pool.pop().unwrap_or_default();
+            queues.ordered.push((index, queue));
+        }
+        let (_, command_queue) = queues.ordered.last_mut().unwrap();
+
+        let r = f(Commands::new_from_entities(command_queue, self.entities));
+
+        command_queue_cell.set(queues);
         r
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    use super::*;
+    use crate::{
+        component::Component,
+        entity::Entity,
+        query::BatchingStrategy,
+        schedule::Schedule,
+        system::{Query, ResMut, Resource},
+    };
+
+    #[derive(Component)]
+    struct Index(usize);
+
+    #[derive(Component)]
+    struct Parent(Entity);
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<usize>);
+
+    #[test]
+    fn ordered_commands_are_deterministic() {
+        ComputeTaskPool::init(TaskPool::default);
+
+        fn record_order(query: Query<&Index>, par_commands: ParallelCommands) {
+            query
+                .par_iter()
+                .batching_strategy(BatchingStrategy::fixed(8))
+                .for_each(|&Index(i)| {
+                    par_commands.command_scope_ordered(i, |mut commands| {
+                        commands.add(move |world: &mut World| {
+                            world.resource_mut::<Order>().0.push(i);
+                        });
+                    });
+                });
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        // spawn in reverse, so iteration order differs from index order
+        world.spawn_batch((0..256).rev().map(Index));
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(record_order);
+        for _ in 0..4 {
+            schedule.run(&mut world);
+            let order = std::mem::take(&mut world.resource_mut::<Order>().0);
+            assert_eq!(order, (0..256).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn ordered_queues_are_reused() {
+        let mut world = World::new();
+        let mut queue = ParallelCommandQueue::default();
+        let system_meta = SystemMeta::new::<()>();
+
+        for frame in 1..=3 {
+            let par_commands = ParallelCommands {
+                state: Deferred(&mut queue),
+                entities: world.entities(),
+            };
+            // consecutive scopes with the same index share a queue
+            for i in [0, 0, 1, 2, 3, 3] {
+                par_commands.command_scope_ordered(i, |mut commands| {
+                    commands.spawn_empty();
+                });
+            }
+            queue.apply(&system_meta, &mut world);
+
+            assert_eq!(world.entities().len(), 6 * frame);
+            let queues = queue.thread_local_storage.iter_mut().next().unwrap();
+            assert!(queues.get_mut().ordered.is_empty());
+            assert_eq!(queues.get_mut().pool.len(), 4);
+        }
+    }
+
+    #[test]
+    fn command_scope_with_entity_reserver() {
+        let mut world = World::new();
+        let mut queue = ParallelCommandQueue::default();
+
+        let (parent, child) = {
+            let par_commands = ParallelCommands {
+                state: Deferred(&mut queue),
+                entities: world.entities(),
+            };
+            par_commands.command_scope_with_entity_reserver(|mut commands, entities| {
+                let parent = entities.reserve_entity();
+                let child = commands.spawn(Parent(parent)).id();
+                commands.get_or_spawn(parent).insert(Index(0));
+                (parent, child)
+            })
+        };
+        queue.apply(&SystemMeta::new::<()>(), &mut world);
+
+        assert_eq!(world.get::<Parent>(child).unwrap().0, parent);
+        assert!(world.get::<Index>(parent).is_some());
+    }
+}
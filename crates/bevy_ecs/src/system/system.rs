@@ -38,6 +38,10 @@ pub trait System: Send + Sync + 'static {
     /// Returns true if the system must be run exclusively.
     fn is_exclusive(&self) -> bool;
 
+    /// Returns true if the system has deferred [`SystemParam`](crate::system::SystemParam)s,
+    /// whose changes only become visible once [`System::apply_buffers`] is called.
+    fn has_deferred(&self) -> bool;
+
     /// Runs the system with the given input in the world. Unlike [`System::run`], this function
     /// takes a shared reference to [`World`] and may therefore break Rust's aliasing rules, making
     /// it unsafe to call.
@@ -19,6 +19,7 @@ use bevy_utils::{all_tuples, synccell::SyncCell};
 use std::{
     borrow::Cow,
     fmt::Debug,
+    marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 
@@ -906,7 +907,8 @@ unsafe impl<T: SystemBuffer> SystemParam for Deferred<'_, T> {
     type State = SyncCell<T>;
     type Item<'w, 's> = Deferred<'s, T>;
 
-    fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        system_meta.set_has_deferred();
         SyncCell::new(T::from_world(world))
     }
 
@@ -981,6 +983,8 @@ impl<'a, T> From<NonSendMut<'a, T>> for NonSend<'a, T> {
             ticks: ComponentTicks {
                 added: nsm.ticks.added.to_owned(),
                 changed: nsm.ticks.changed.to_owned(),
+                // `NonSendMut` doesn't track replacements, which `NonSend` never reads
+                replaced: nsm.ticks.changed.to_owned(),
             },
             this_run: nsm.ticks.this_run,
             last_run: nsm.ticks.last_run,
@@ -1297,6 +1301,47 @@ unsafe impl SystemParam for SystemChangeTick {
     }
 }
 
+/// A [`SystemParam`] that forces the system to run on the main thread, without
+/// accessing any data.
+///
+/// Use it in systems that touch thread-bound state outside of the [`World`], such as
+/// windowing or platform APIs, where a [`NonSend`] resource would otherwise be borrowed
+/// only for its thread affinity.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::system::NonSendMarker;
+///
+/// fn main_thread_system(_marker: NonSendMarker) {
+///     // this code always runs on the main thread
+/// }
+/// # bevy_ecs::system::assert_is_system(main_thread_system);
+/// ```
+pub struct NonSendMarker(PhantomData<*mut ()>);
+
+// SAFETY: `NonSendMarker` doesn't access the world
+unsafe impl ReadOnlySystemParam for NonSendMarker {}
+
+// SAFETY: `NonSendMarker` doesn't access the world, and marks the system as `!Send`
+unsafe impl SystemParam for NonSendMarker {
+    type State = ();
+    type Item<'w, 's> = NonSendMarker;
+
+    fn init_state(_world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        system_meta.set_non_send();
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        _state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        _world: &'w World,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        NonSendMarker(PhantomData)
+    }
+}
+
 /// Name of the system that corresponds to this [`crate::system::SystemState`].
 ///
 /// This is not a reliable identifier, it is more so useful for debugging
@@ -1633,4 +1678,36 @@ mod tests {
     {
         _q: Query<'w, 's, Q, ()>,
     }
+
+    #[test]
+    fn non_send_marker() {
+        use crate::schedule::{ExecutorKind, Schedule};
+        use crate::system::{IntoSystem, System};
+        use bevy_tasks::{ComputeTaskPool, TaskPool};
+        use std::thread::ThreadId;
+
+        #[derive(Resource, Default)]
+        struct RanOn(Option<ThreadId>);
+
+        fn main_thread_system(_marker: NonSendMarker, mut ran_on: ResMut<RanOn>) {
+            ran_on.0 = Some(std::thread::current().id());
+        }
+
+        ComputeTaskPool::init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<RanOn>();
+
+        let mut system = IntoSystem::into_system(main_thread_system);
+        system.initialize(&mut world);
+        assert!(!system.is_send());
+
+        let mut schedule = Schedule::new();
+        schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+        schedule.add_system(main_thread_system);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<RanOn>().0,
+            Some(std::thread::current().id())
+        );
+    }
 }
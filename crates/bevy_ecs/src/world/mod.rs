@@ -12,8 +12,12 @@ use crate::{
     archetype::{ArchetypeComponentId, ArchetypeId, ArchetypeRow, Archetypes},
     bundle::{Bundle, BundleInserter, BundleSpawner, Bundles},
     change_detection::{MutUntyped, TicksMut},
-    component::{Component, ComponentDescriptor, ComponentId, ComponentInfo, Components, Tick},
-    entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    component::{
+        Component, ComponentDescriptor, ComponentId, ComponentInfo, ComponentTicks, Components,
+        Tick,
+    },
+    entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation, EntityReserver},
+    entity_disabling::{DefaultQueryFilters, Disabled},
     event::{Event, Events},
     query::{DebugCheckedUnwrap, QueryState, ReadOnlyWorldQuery, WorldQuery},
     removal_detection::RemovedComponentEvents,
@@ -26,6 +30,7 @@ use bevy_utils::tracing::warn;
 use std::{
     any::TypeId,
     fmt,
+    mem::ManuallyDrop,
     sync::atomic::{AtomicU32, Ordering},
 };
 mod identifier;
@@ -61,6 +66,7 @@ pub struct World {
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
     pub(crate) removed_components: RemovedComponentEvents,
+    pub(crate) default_query_filters: DefaultQueryFilters,
     /// Access cache used by [WorldCell]. Is only accessed in the `Drop` impl of `WorldCell`.
     pub(crate) archetype_component_access: ArchetypeComponentAccess,
     pub(crate) change_tick: AtomicU32,
@@ -70,7 +76,7 @@ pub struct World {
 
 impl Default for World {
     fn default() -> Self {
-        Self {
+        let mut world = Self {
             id: WorldId::new().expect("More `bevy` `World`s have been created than is supported"),
             entities: Entities::new(),
             components: Default::default(),
@@ -78,13 +84,16 @@ impl Default for World {
             storages: Default::default(),
             bundles: Default::default(),
             removed_components: Default::default(),
+            default_query_filters: Default::default(),
             archetype_component_access: Default::default(),
             // Default value is `1`, and `last_change_tick`s default to `0`, such that changes
             // are detected on first system runs and for direct world queries.
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
-        }
+        };
+        world.register_disabling_component::<Disabled>();
+        world
     }
 }
 
@@ -129,6 +138,14 @@ impl World {
         &self.entities
     }
 
+    /// Returns an [`EntityReserver`] to reserve entities of this world from other threads.
+    ///
+    /// Reserved entities become real at the next [`World::flush`].
+    /// See [`EntityReserver`] for more details.
+    pub fn entity_reserver(&self) -> EntityReserver {
+        self.entities.reserver()
+    }
+
     /// Retrieves this world's [Entities] collection mutably
     ///
     /// # Safety
@@ -169,6 +186,24 @@ impl World {
         &self.removed_components
     }
 
+    /// Retrieves this world's [`DefaultQueryFilters`], the components queries skip the entities of
+    /// by default
+    #[inline]
+    pub fn default_query_filters(&self) -> &DefaultQueryFilters {
+        &self.default_query_filters
+    }
+
+    /// Makes queries skip the entities with a component `C`, like the ones with [`Disabled`],
+    /// unless they mention `C`.
+    ///
+    /// Only the queries created afterward skip these entities, so this should be called before
+    /// creating queries, like when building an app.
+    pub fn register_disabling_component<C: Component>(&mut self) {
+        let component_id = self.init_component::<C>();
+        self.default_query_filters
+            .register_disabling_component(component_id);
+    }
+
     /// Retrieves a [`WorldCell`], which safely enables multiple mutable World accesses at the same
     /// time, provided those accesses do not conflict with each other.
     #[inline]
@@ -1284,55 +1319,128 @@ impl World {
     /// assert_eq!(world.get_resource::<A>().unwrap().0, 2);
     /// ```
     pub fn resource_scope<R: Resource, U>(&mut self, f: impl FnOnce(&mut World, Mut<R>) -> U) -> U {
+        self.try_resource_scope(f)
+            .unwrap_or_else(|| panic!("resource does not exist: {}", std::any::type_name::<R>()))
+    }
+
+    /// Temporarily removes the requested resource from this [`World`] if it exists, then re-adds it
+    /// before returning.
+    ///
+    /// Returns `None` if the resource does not exist, or the result of `f` otherwise.
+    /// See [`World::resource_scope`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct A(u32);
+    /// let mut world = World::new();
+    ///
+    /// assert_eq!(world.try_resource_scope(|_, a: Mut<A>| a.0), None);
+    /// world.insert_resource(A(1));
+    /// assert_eq!(world.try_resource_scope(|_, a: Mut<A>| a.0), Some(1));
+    /// ```
+    pub fn try_resource_scope<R: Resource, U>(
+        &mut self,
+        f: impl FnOnce(&mut World, Mut<R>) -> U,
+    ) -> Option<U> {
+        self.resource_scope_internal::<R, U, true>(f)
+    }
+
+    /// Temporarily removes the requested non-send resource from this [`World`], then re-adds it
+    /// before returning.
+    ///
+    /// This is the `!Send` equivalent of [`World::resource_scope`].
+    ///
+    /// # Panics
+    /// Panics if the resource does not exist, or if called from a different thread than the one
+    /// the resource was inserted from.
+    pub fn non_send_resource_scope<R: 'static, U>(
+        &mut self,
+        f: impl FnOnce(&mut World, Mut<R>) -> U,
+    ) -> U {
+        self.try_non_send_resource_scope(f).unwrap_or_else(|| {
+            panic!(
+                "non-send resource does not exist: {}",
+                std::any::type_name::<R>()
+            )
+        })
+    }
+
+    /// Temporarily removes the requested non-send resource from this [`World`] if it exists,
+    /// then re-adds it before returning.
+    ///
+    /// This is the `!Send` equivalent of [`World::try_resource_scope`].
+    ///
+    /// # Panics
+    /// Panics if the resource exists and this is called from a different thread than the one
+    /// it was inserted from.
+    pub fn try_non_send_resource_scope<R: 'static, U>(
+        &mut self,
+        f: impl FnOnce(&mut World, Mut<R>) -> U,
+    ) -> Option<U> {
+        self.resource_scope_internal::<R, U, false>(f)
+    }
+
+    fn resource_scope_internal<R: 'static, U, const SEND: bool>(
+        &mut self,
+        f: impl FnOnce(&mut World, Mut<R>) -> U,
+    ) -> Option<U> {
         let last_change_tick = self.last_change_tick();
         let change_tick = self.change_tick();
 
-        let component_id = self
-            .components
-            .get_resource_id(TypeId::of::<R>())
-            .unwrap_or_else(|| panic!("resource does not exist: {}", std::any::type_name::<R>()));
-        // If the resource isn't send and sync, validate that we are on the main thread, so that we can access it.
-        let (ptr, mut ticks) = self
-            .storages
-            .resources
-            .get_mut(component_id)
-            .and_then(|info| info.remove())
-            .unwrap_or_else(|| panic!("resource does not exist: {}", std::any::type_name::<R>()));
+        let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
+        // If the resource isn't send and sync, removing it validates that we are on the thread
+        // it was inserted from, so that we can access it.
+        let (ptr, ticks) = if SEND {
+            self.storages.resources.get_mut(component_id)?.remove()?
+        } else {
+            self.storages
+                .non_send_resources
+                .get_mut(component_id)?
+                .remove()?
+        };
         // Read the value onto the stack to avoid potential mut aliasing.
         // SAFETY: pointer is of type R
-        let mut value = unsafe { ptr.read::<R>() };
+        let value = unsafe { ptr.read::<R>() };
+
+        // The guard reinserts the resource when dropped, even if `f` panics.
+        let mut guard = ResourceScopeGuard::<R, SEND> {
+            world: self,
+            component_id,
+            value: ManuallyDrop::new(value),
+            ticks,
+        };
         let value_mut = Mut {
-            value: &mut value,
+            value: &mut *guard.value,
             ticks: TicksMut {
-                added: &mut ticks.added,
-                changed: &mut ticks.changed,
+                added: &mut guard.ticks.added,
+                changed: &mut guard.ticks.changed,
                 last_run: last_change_tick,
                 this_run: change_tick,
             },
         };
-        let result = f(self, value_mut);
-        assert!(!self.contains_resource::<R>(),
+        let result = f(&mut *guard.world, value_mut);
+        let storages = &guard.world.storages;
+        let is_present = if SEND {
+            storages
+                .resources
+                .get(component_id)
+                .map_or(false, ResourceData::is_present)
+        } else {
+            storages
+                .non_send_resources
+                .get(component_id)
+                .map_or(false, ResourceData::is_present)
+        };
+        // The guard then reinserts the original resource while unwinding.
+        assert!(!is_present,
             "Resource `{}` was inserted during a call to World::resource_scope.\n\
             This is not allowed as the original resource is reinserted to the world after the FnOnce param is invoked.",
             std::any::type_name::<R>());
 
-        OwningPtr::make(value, |ptr| {
-            // SAFETY: pointer is of type R
-            unsafe {
-                self.storages
-                    .resources
-                    .get_mut(component_id)
-                    .map(|info| info.insert_with_ticks(ptr, ticks))
-                    .unwrap_or_else(|| {
-                        panic!(
-                            "No resource of type {} exists in the World.",
-                            std::any::type_name::<R>()
-                        )
-                    });
-            }
-        });
-
-        result
+        drop(guard);
+        Some(result)
     }
 
     /// Sends an [`Event`].
@@ -1364,6 +1472,24 @@ impl World {
     /// **You should prefer to use the typed API [`World::insert_resource`] where possible and only
     /// use this in cases where the actual types are not known at compile time.**
     ///
+    /// The pointer to a value can be made with [`move_as_ptr!`](bevy_ptr::move_as_ptr):
+    ///
+    /// ```
+    /// # use bevy_ecs::{component::ComponentDescriptor, prelude::*, ptr::move_as_ptr};
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = World::new();
+    /// let component_id = world.init_component_with_descriptor(ComponentDescriptor::new_resource::<Score>());
+    /// let score = Score(42);
+    /// move_as_ptr!(score);
+    /// // SAFETY: `score` points to a `Score`, the resource of `component_id`.
+    /// unsafe { world.insert_resource_by_id(component_id, score) };
+    /// let score = world.get_resource_by_id(component_id).unwrap();
+    /// // SAFETY: the resource of `component_id` is a `Score`.
+    /// assert_eq!(unsafe { score.deref::<Score>() }.0, 42);
+    /// ```
+    ///
     /// # Safety
     /// The value referenced by `value` must be valid for the given [`ComponentId`] of this world.
     #[inline]
@@ -1453,7 +1579,12 @@ impl World {
     /// Empties queued entities and adds them to the empty [Archetype](crate::archetype::Archetype).
     /// This should be called before doing operations that might operate on queued entities,
     /// such as inserting a [Component].
-    pub(crate) fn flush(&mut self) {
+    ///
+    /// Queued entities are the ones reserved with [`Entities::reserve_entity`],
+    /// [`Entities::reserve_entities`] or an [`EntityReserver`]. Most operations taking `&mut self`
+    /// already flush the world, but inserting components on a reserved entity through
+    /// [`World::entity_mut`] requires flushing it first.
+    pub fn flush(&mut self) {
         let empty_archetype = self.archetypes.empty_mut();
         let table = &mut self.storages.tables[empty_archetype.table_id()];
         // PERF: consider pre-allocating space for flushed entities
@@ -1534,19 +1665,53 @@ impl World {
 
     /// Runs both [`clear_entities`](Self::clear_entities) and [`clear_resources`](Self::clear_resources),
     /// invalidating all [`Entity`] and resource fetches such as [`Res`](crate::system::Res), [`ResMut`](crate::system::ResMut)
+    ///
+    /// # Panics
+    ///
+    /// Panics before clearing anything if the world holds a non-send resource inserted from
+    /// another thread, see [`clear_resources`](Self::clear_resources).
     pub fn clear_all(&mut self) {
+        self.storages.non_send_resources.validate_access();
         self.clear_entities();
         self.clear_resources();
     }
 
     /// Despawns all entities in this [`World`].
+    ///
+    /// Like [`World::despawn`], the components of the entities are dropped, and their removal can
+    /// be read with [`RemovedComponents`](crate::removal_detection::RemovedComponents). The
+    /// generations of the entities are kept, so the [`Entity`] IDs from before the clear
+    /// never match the entities spawned after it. The registered components and the
+    /// archetypes are kept too.
     pub fn clear_entities(&mut self) {
+        self.flush();
+        for archetype in self.archetypes.iter() {
+            for component_id in archetype.components() {
+                for entity in archetype.entities() {
+                    self.removed_components.send(component_id, entity.entity());
+                }
+            }
+        }
+
         self.storages.tables.clear();
         self.storages.sparse_sets.clear_entities();
         self.archetypes.clear_entities();
         self.entities.clear();
     }
 
+    /// Despawns all entities in this [`World`], without sending the removals of their components
+    /// or keeping their generations like [`clear_entities`](Self::clear_entities) does.
+    ///
+    /// This doesn't depend on the number of entities and components, but the [`Entity`] IDs from
+    /// before the clear may match the entities spawned after it. It suits worlds that clear all
+    /// their entities every frame and don't keep their IDs around, like the render world.
+    pub fn clear_entities_untracked(&mut self) {
+        self.storages.tables.clear();
+        self.storages.sparse_sets.clear_entities();
+        self.archetypes.clear_entities();
+        self.entities.clear_untracked();
+    }
+
     /// Clears all resources in this [`World`].
     ///
     /// **Note:** Any resource fetch to this [World] will fail unless they are re-initialized,
@@ -1554,7 +1719,14 @@ impl World {
     ///
     /// This can easily cause systems expecting certain resources to immediately start panicking.
     /// Use with caution.
+    ///
+    /// # Panics
+    ///
+    /// Non-send resources can only be dropped from the thread they were inserted from, usually the
+    /// main thread. This panics before clearing any resource if called from another thread while
+    /// the world holds such a resource.
     pub fn clear_resources(&mut self) {
+        self.storages.non_send_resources.validate_access();
         self.storages.resources.clear();
         self.storages.non_send_resources.clear();
     }
@@ -1750,11 +1922,49 @@ impl World {
         #[cfg(feature = "trace")]
         let _span = bevy_utils::tracing::info_span!("schedule", name = ?extracted_label).entered();
         schedule.run(self);
+        #[cfg(feature = "system_timing")]
+        if let Some(mut durations) = self.get_resource_mut::<crate::schedule::SystemDurations>() {
+            durations.record(&*extracted_label, schedule.take_system_durations());
+        }
         self.resource_mut::<Schedules>()
             .insert(extracted_label, schedule);
     }
 }
 
+/// Reinserts a resource removed by [`World::resource_scope`] and its variants when dropped.
+struct ResourceScopeGuard<'w, R: 'static, const SEND: bool> {
+    world: &'w mut World,
+    component_id: ComponentId,
+    value: ManuallyDrop<R>,
+    ticks: ComponentTicks,
+}
+
+impl<'w, R: 'static, const SEND: bool> Drop for ResourceScopeGuard<'w, R, SEND> {
+    fn drop(&mut self) {
+        // SAFETY: the value is only taken here, and the guard is not used afterwards.
+        let value = unsafe { ManuallyDrop::take(&mut self.value) };
+        let (storages, ticks) = (&mut self.world.storages, self.ticks);
+        // Panicking here could abort while unwinding, so the value is dropped instead if it can't
+        // be reinserted.
+        OwningPtr::make(value, |ptr| {
+            // SAFETY: pointer is of type R
+            unsafe {
+                if SEND {
+                    match storages.resources.get_mut(self.component_id) {
+                        Some(info) => info.insert_with_ticks(ptr, ticks),
+                        None => ptr.drop_as::<R>(),
+                    }
+                } else {
+                    match storages.non_send_resources.get_mut(self.component_id) {
+                        Some(info) => info.insert_with_ticks(ptr, ticks),
+                        None => ptr.drop_as::<R>(),
+                    }
+                }
+            }
+        });
+    }
+}
+
 impl fmt::Debug for World {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("World")
@@ -1794,7 +2004,7 @@ mod tests {
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
         ptr::OwningPtr,
-        system::Resource,
+        system::{CommandQueue, Commands, Resource},
     };
     use bevy_ecs_macros::Component;
     use bevy_utils::{HashMap, HashSet};
@@ -2175,6 +2385,49 @@ mod tests {
         assert_eq!(entity_counters.len(), 0);
     }
 
+    #[test]
+    fn reserve_entities_from_other_thread() {
+        let mut world = World::new();
+        let reserver = world.entity_reserver();
+
+        let reserved = std::thread::spawn(move || {
+            (0..1000)
+                .map(|_| reserver.reserve_entity())
+                .collect::<Vec<_>>()
+        })
+        .join()
+        .unwrap();
+
+        // Reserved entities only exist once the world is flushed
+        assert!(world.get_entity(reserved[0]).is_none());
+        world.flush();
+        assert_eq!(world.entities().len(), 1000);
+        for entity in &reserved {
+            world.entity_mut(*entity).insert(Foo);
+        }
+        assert_eq!(world.query::<&Foo>().iter(&world).count(), 1000);
+
+        // Entities spawned afterwards don't reuse the reserved IDs
+        let spawned = world.spawn(Bar).id();
+        assert!(!reserved.contains(&spawned));
+    }
+
+    #[test]
+    fn apply_commands_to_reserved_entities() {
+        let mut world = World::new();
+        let reserver = world.entity_reserver();
+        let entity = reserver.reserve_entity();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(entity).insert(Foo);
+        assert!(world.get_entity(entity).is_none());
+
+        // Applying commands flushes the world before inserting the component
+        queue.apply(&mut world);
+        assert!(world.entity(entity).contains::<Foo>());
+    }
+
     #[test]
     fn spawn_empty_bundle() {
         let mut world = World::new();
@@ -830,6 +830,7 @@ unsafe fn get_component_and_ticks(
                 TickCells {
                     added: components.get_added_ticks_unchecked(location.table_row),
                     changed: components.get_changed_ticks_unchecked(location.table_row),
+                    replaced: components.get_replaced_ticks_unchecked(location.table_row),
                 },
             ))
         }
@@ -7,19 +7,30 @@ pub use loader::*;
 
 use bevy_app::prelude::*;
 use bevy_asset::{AddAsset, Handle};
-use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_ecs::{
+    prelude::{Component, FromWorld},
+    reflect::ReflectComponent,
+};
 use bevy_pbr::StandardMaterial;
 use bevy_reflect::{Reflect, TypeUuid};
-use bevy_render::mesh::Mesh;
+use bevy_render::{mesh::Mesh, render_asset::RenderAssetUsages};
 use bevy_scene::Scene;
 
 /// Adds support for glTF file loading to the app.
 #[derive(Default)]
-pub struct GltfPlugin;
+pub struct GltfPlugin {
+    /// The [`RenderAssetUsages`] of the meshes and images loaded from glTF files.
+    ///
+    /// Use [`RenderAssetUsages::RENDER_WORLD`] to free their data from the main world once they
+    /// are sent to the GPU, if no system needs to read them.
+    pub asset_usage: RenderAssetUsages,
+}
 
 impl Plugin for GltfPlugin {
     fn build(&self, app: &mut App) {
-        app.init_asset_loader::<GltfLoader>()
+        let mut loader = GltfLoader::from_world(&mut app.world);
+        loader.asset_usage = self.asset_usage;
+        app.add_asset_loader(loader)
             .register_type::<GltfExtras>()
             .add_asset::<Gltf>()
             .add_asset::<GltfNode>()
@@ -21,6 +21,7 @@ use bevy_render::{
     },
     prelude::SpatialBundle,
     primitives::Aabb,
+    render_asset::RenderAssetUsages,
     render_resource::{AddressMode, Face, FilterMode, PrimitiveTopology, SamplerDescriptor},
     renderer::RenderDevice,
     texture::{CompressedImageFormats, Image, ImageSampler, ImageType, TextureError},
@@ -32,6 +33,7 @@ use bevy_transform::components::Transform;
 
 use bevy_utils::{HashMap, HashSet};
 use gltf::{
+    json::Value,
     mesh::Mode,
     texture::{MagFilter, MinFilter, WrappingMode},
     Material, Node, Primitive,
@@ -64,11 +66,22 @@ pub enum GltfError {
     MissingAnimationSampler(usize),
     #[error("failed to generate tangents: {0}")]
     GenerateTangentsError(#[from] bevy_render::mesh::GenerateTangentsError),
+    #[error(
+        "{primitive} references joint {joint_index}, but {skin} only has {joint_count} joints"
+    )]
+    InvalidJointIndex {
+        primitive: String,
+        skin: String,
+        joint_index: u16,
+        joint_count: usize,
+    },
 }
 
 /// Loads glTF files with all of their data as their corresponding bevy representations.
 pub struct GltfLoader {
     supported_compressed_formats: CompressedImageFormats,
+    /// The [`RenderAssetUsages`] of the loaded meshes and images.
+    pub asset_usage: RenderAssetUsages,
 }
 
 impl AssetLoader for GltfLoader {
@@ -78,7 +91,13 @@ impl AssetLoader for GltfLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            Ok(load_gltf(bytes, load_context, self.supported_compressed_formats).await?)
+            Ok(load_gltf(
+                bytes,
+                load_context,
+                self.supported_compressed_formats,
+                self.asset_usage,
+            )
+            .await?)
         })
     }
 
@@ -96,6 +115,7 @@ impl FromWorld for GltfLoader {
         };
         Self {
             supported_compressed_formats,
+            asset_usage: RenderAssetUsages::default(),
         }
     }
 }
@@ -105,6 +125,7 @@ async fn load_gltf<'a, 'b>(
     bytes: &'a [u8],
     load_context: &'a mut LoadContext<'b>,
     supported_compressed_formats: CompressedImageFormats,
+    asset_usage: RenderAssetUsages,
 ) -> Result<(), GltfError> {
     let gltf = gltf::Gltf::from_slice(bytes)?;
     let buffer_data = load_buffers(&gltf, load_context, load_context.path()).await?;
@@ -152,12 +173,13 @@ async fn load_gltf<'a, 'b>(
         for animation in gltf.animations() {
             let mut animation_clip = bevy_animation::AnimationClip::default();
             for channel in animation.channels() {
-                match channel.sampler().interpolation() {
-                    gltf::animation::Interpolation::Linear => (),
-                    other => warn!(
-                        "Animation interpolation {:?} is not supported, will use linear",
-                        other
-                    ),
+                let interpolation = match channel.sampler().interpolation() {
+                    gltf::animation::Interpolation::Linear => bevy_animation::Interpolation::Linear,
+                    gltf::animation::Interpolation::Step => bevy_animation::Interpolation::Step,
+                    // The outputs hold the in-tangent, value and out-tangent of each keyframe
+                    gltf::animation::Interpolation::CubicSpline => {
+                        bevy_animation::Interpolation::CubicSpline
+                    }
                 };
                 let node = channel.target().node();
                 let reader = channel.reader(|buffer| Some(&buffer_data[buffer.index()]));
@@ -206,6 +228,7 @@ async fn load_gltf<'a, 'b>(
                         bevy_animation::VariableCurve {
                             keyframe_timestamps,
                             keyframes,
+                            interpolation,
                         },
                     );
                 } else {
@@ -229,8 +252,11 @@ async fn load_gltf<'a, 'b>(
 
     let mut meshes = vec![];
     let mut named_meshes = HashMap::default();
+    // The highest joint index of each mesh, along with the label of the primitive using it
+    let mut max_joint_indices: Vec<Option<(String, u16)>> = vec![];
     for mesh in gltf.meshes() {
         let mut primitives = vec![];
+        let mut max_joint_index: Option<(String, u16)> = None;
         for primitive in mesh.primitives() {
             let primitive_label = primitive_label(&mesh, &primitive);
             let reader = primitive.reader(|buffer| Some(&buffer_data[buffer.index()]));
@@ -267,7 +293,16 @@ async fn load_gltf<'a, 'b>(
             }
 
             if let Some(iter) = reader.read_joints(0) {
-                let vertex_attribute = VertexAttributeValues::Uint16x4(iter.into_u16().collect());
+                let joint_indices: Vec<[u16; 4]> = iter.into_u16().collect();
+                if let Some(primitive_max) = joint_indices.iter().flatten().max().copied() {
+                    if max_joint_index
+                        .as_ref()
+                        .map_or(true, |(_, max)| primitive_max > *max)
+                    {
+                        max_joint_index = Some((primitive_label.clone(), primitive_max));
+                    }
+                }
+                let vertex_attribute = VertexAttributeValues::Uint16x4(joint_indices);
                 mesh.insert_attribute(Mesh::ATTRIBUTE_JOINT_INDEX, vertex_attribute);
             }
 
@@ -318,6 +353,7 @@ async fn load_gltf<'a, 'b>(
                 }
             }
 
+            mesh.set_asset_usage(asset_usage);
             let mesh = load_context.set_labeled_asset(&primitive_label, LoadedAsset::new(mesh));
             primitives.push(super::GltfPrimitive {
                 mesh,
@@ -341,6 +377,7 @@ async fn load_gltf<'a, 'b>(
             named_meshes.insert(name.to_string(), handle.clone());
         }
         meshes.push(handle);
+        max_joint_indices.push(max_joint_index);
     }
 
     let mut nodes_intermediate = vec![];
@@ -404,6 +441,7 @@ async fn load_gltf<'a, 'b>(
                 &linear_textures,
                 load_context,
                 supported_compressed_formats,
+                asset_usage,
             )
             .await?;
             load_context.set_labeled_asset(&label, LoadedAsset::new(texture));
@@ -423,6 +461,7 @@ async fn load_gltf<'a, 'b>(
                             linear_textures,
                             load_context,
                             supported_compressed_formats,
+                            asset_usage,
                         )
                         .await
                     });
@@ -457,6 +496,20 @@ async fn load_gltf<'a, 'b>(
         })
         .collect();
 
+    // Joint indices out of the skin's range would read past the joint buffer on the GPU
+    for node in gltf.nodes() {
+        if let (Some(mesh), Some(skin)) = (node.mesh(), node.skin()) {
+            if let Some((primitive, joint_index)) = &max_joint_indices[mesh.index()] {
+                check_joint_index(
+                    primitive,
+                    *joint_index,
+                    &skin_label(&skin),
+                    skin.joints().count(),
+                )?;
+            }
+        }
+    }
+
     let mut scenes = vec![];
     let mut named_scenes = HashMap::default();
     let mut active_camera_found = false;
@@ -582,6 +635,7 @@ async fn load_texture<'a>(
     linear_textures: &HashSet<usize>,
     load_context: &LoadContext<'a>,
     supported_compressed_formats: CompressedImageFormats,
+    asset_usage: RenderAssetUsages,
 ) -> Result<(Image, String), GltfError> {
     let is_srgb = !linear_textures.contains(&gltf_texture.index());
     let mut texture = match gltf_texture.source().source() {
@@ -623,6 +677,7 @@ async fn load_texture<'a>(
         }
     };
     texture.sampler_descriptor = ImageSampler::Descriptor(texture_sampler(&gltf_texture));
+    texture.asset_usage = asset_usage;
 
     Ok((texture, texture_label(&gltf_texture)))
 }
@@ -665,6 +720,9 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
         load_context.get_handle(path)
     });
 
+    let clearcoat = ClearcoatExtension::parse(load_context, material).unwrap_or_default();
+    let anisotropy = AnisotropyExtension::parse(load_context, material).unwrap_or_default();
+
     let emissive = material.emissive_factor();
     let emissive_texture = material.emissive_texture().map(|info| {
         // TODO: handle occlusion_texture.tex_coord() (the *set* index for the right texcoords)
@@ -694,11 +752,84 @@ fn load_material(material: &Material, load_context: &mut LoadContext) -> Handle<
             emissive_texture,
             unlit: material.unlit(),
             alpha_mode: alpha_mode(material),
+            clearcoat: clearcoat.clearcoat_factor.unwrap_or_default() as f32,
+            clearcoat_perceptual_roughness: clearcoat.clearcoat_roughness_factor.unwrap_or_default()
+                as f32,
+            clearcoat_normal_texture: clearcoat.clearcoat_normal_texture,
+            anisotropy_strength: anisotropy.anisotropy_strength.unwrap_or_default() as f32,
+            anisotropy_rotation: anisotropy.anisotropy_rotation.unwrap_or_default() as f32,
+            anisotropy_texture: anisotropy.anisotropy_texture,
             ..Default::default()
         }),
     )
 }
 
+/// Parsed data from the `KHR_materials_clearcoat` extension.
+///
+/// See <https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Khronos/KHR_materials_clearcoat>
+#[derive(Default)]
+struct ClearcoatExtension {
+    clearcoat_factor: Option<f64>,
+    clearcoat_roughness_factor: Option<f64>,
+    clearcoat_normal_texture: Option<Handle<Image>>,
+}
+
+impl ClearcoatExtension {
+    fn parse(load_context: &LoadContext, material: &Material) -> Option<ClearcoatExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_clearcoat")?
+            .as_object()?;
+
+        // TODO: handle clearcoatTexture and clearcoatRoughnessTexture
+        Some(ClearcoatExtension {
+            clearcoat_factor: extension.get("clearcoatFactor").and_then(Value::as_f64),
+            clearcoat_roughness_factor: extension
+                .get("clearcoatRoughnessFactor")
+                .and_then(Value::as_f64),
+            clearcoat_normal_texture: extension
+                .get("clearcoatNormalTexture")
+                .and_then(|info| extension_texture_handle(load_context, info)),
+        })
+    }
+}
+
+/// Parsed data from the `KHR_materials_anisotropy` extension.
+///
+/// See <https://github.com/KhronosGroup/glTF/tree/main/extensions/2.0/Khronos/KHR_materials_anisotropy>
+#[derive(Default)]
+struct AnisotropyExtension {
+    anisotropy_strength: Option<f64>,
+    anisotropy_rotation: Option<f64>,
+    anisotropy_texture: Option<Handle<Image>>,
+}
+
+impl AnisotropyExtension {
+    fn parse(load_context: &LoadContext, material: &Material) -> Option<AnisotropyExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_anisotropy")?
+            .as_object()?;
+
+        Some(AnisotropyExtension {
+            anisotropy_strength: extension.get("anisotropyStrength").and_then(Value::as_f64),
+            anisotropy_rotation: extension.get("anisotropyRotation").and_then(Value::as_f64),
+            anisotropy_texture: extension
+                .get("anisotropyTexture")
+                .and_then(|info| extension_texture_handle(load_context, info)),
+        })
+    }
+}
+
+/// Returns the handle of the texture referenced by the texture `info` object of a material extension.
+fn extension_texture_handle(load_context: &LoadContext, info: &Value) -> Option<Handle<Image>> {
+    // TODO: handle texCoord (the *set* index for the right texcoords)
+    let index = info.get("index")?.as_u64()?;
+    let label = format!("Texture{index}");
+    let path = AssetPath::new_ref(load_context.path(), Some(&label));
+    Some(load_context.get_handle(path))
+}
+
 /// Loads a glTF node.
 fn load_node(
     gltf_node: &gltf::Node,
@@ -949,6 +1080,24 @@ fn skin_label(skin: &gltf::Skin) -> String {
     format!("Skin{}", skin.index())
 }
 
+/// Checks that a primitive's highest joint index refers to one of the skin's joints.
+fn check_joint_index(
+    primitive: &str,
+    joint_index: u16,
+    skin: &str,
+    joint_count: usize,
+) -> Result<(), GltfError> {
+    if joint_index as usize >= joint_count {
+        return Err(GltfError::InvalidJointIndex {
+            primitive: primitive.to_string(),
+            skin: skin.to_string(),
+            joint_index,
+            joint_count,
+        });
+    }
+    Ok(())
+}
+
 /// Extracts the texture sampler data from the glTF texture.
 fn texture_sampler<'a>(texture: &gltf::Texture) -> SamplerDescriptor<'a> {
     let gltf_sampler = texture.sampler();
@@ -1161,7 +1310,7 @@ impl<'a> DataUri<'a> {
 mod test {
     use std::path::PathBuf;
 
-    use super::resolve_node_hierarchy;
+    use super::{check_joint_index, resolve_node_hierarchy};
     use crate::GltfNode;
 
     impl GltfNode {
@@ -1174,6 +1323,17 @@ mod test {
             }
         }
     }
+    #[test]
+    fn invalid_joint_index() {
+        assert!(check_joint_index("Mesh0/Primitive0", 1, "Skin0", 2).is_ok());
+
+        let err = check_joint_index("Mesh0/Primitive0", 2, "Skin0", 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Mesh0/Primitive0 references joint 2, but Skin0 only has 2 joints"
+        );
+    }
+
     #[test]
     fn node_hierarchy_single_node() {
         let result = resolve_node_hierarchy(
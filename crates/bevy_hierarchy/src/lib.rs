@@ -22,12 +22,15 @@ pub use valid_parent_check_plugin::*;
 mod query_extension;
 pub use query_extension::*;
 
+mod state_scoped;
+pub use state_scoped::*;
+
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        child_builder::*, components::*, hierarchy::*, query_extension::*, HierarchyPlugin,
-        ValidParentCheckPlugin,
+        child_builder::*, components::*, hierarchy::*, query_extension::*, state_scoped::*,
+        HierarchyPlugin, ValidParentCheckPlugin,
     };
 }
 
@@ -0,0 +1,228 @@
+use bevy_app::{App, CoreSet};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{apply_state_transition, OnTransition, ScheduleLabel},
+};
+use bevy_utils::HashSet;
+
+use crate::despawn_with_children_recursive;
+
+/// Entities marked with this component will be despawned, along with their descendants,
+/// when the [`State<S>`] they are scoped to is exited.
+///
+/// Cleanup must first be enabled for `S` with
+/// [`enable_state_scoped_entities`](StateScopedApp::enable_state_scoped_entities).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_hierarchy::prelude::*;
+/// #[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     InGame,
+/// }
+///
+/// fn spawn_menu(mut commands: Commands) {
+///     // Despawned when leaving `GameState::MainMenu`.
+///     commands.spawn(StateScoped(GameState::MainMenu));
+/// }
+///
+/// App::new()
+///     .add_state::<GameState>()
+///     .enable_state_scoped_entities::<GameState>()
+///     .add_system(spawn_menu.in_schedule(OnEnter(GameState::MainMenu)));
+/// ```
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub struct StateScoped<S: States>(pub S);
+
+/// The entities scoped to the state currently being exited, captured before its
+/// [`OnExit`] schedule runs.
+#[derive(Resource)]
+struct ExitingStateScopedEntities<S: States> {
+    entities: Vec<Entity>,
+    /// The transitions whose [`OnTransition`] schedule despawns the entities.
+    transitions: HashSet<(S, S)>,
+}
+
+impl<S: States> Default for ExitingStateScopedEntities<S> {
+    fn default() -> Self {
+        Self {
+            entities: Vec::new(),
+            transitions: HashSet::default(),
+        }
+    }
+}
+
+/// Captures the entities scoped to the current state if a transition is queued, and makes
+/// sure the schedule of that transition despawns them.
+fn collect_state_scoped_entities<S: States>(world: &mut World) {
+    let entered = match &world.resource::<NextState<S>>().0 {
+        Some(entered) => entered.clone(),
+        None => return,
+    };
+    let exited = world.resource::<State<S>>().0.clone();
+    let entities = world
+        .query::<(Entity, &StateScoped<S>)>()
+        .iter(world)
+        .filter(|(_, scoped)| scoped.0 == exited)
+        .map(|(entity, _)| entity)
+        .collect();
+
+    let mut exiting = world.resource_mut::<ExitingStateScopedEntities<S>>();
+    exiting.entities = entities;
+    // `OnTransition` is the only schedule guaranteed to run between `OnExit` and `OnEnter`.
+    // Only the transitions that actually happen get the system, instead of every pair of states.
+    if exiting
+        .transitions
+        .insert((exited.clone(), entered.clone()))
+    {
+        add_system_to_schedule(
+            world,
+            OnTransition {
+                from: exited,
+                to: entered,
+            },
+            clear_state_scoped_entities::<S>,
+        );
+    }
+}
+
+fn add_system_to_schedule<M>(
+    world: &mut World,
+    label: impl ScheduleLabel,
+    system: impl IntoSystemConfig<M>,
+) {
+    let mut schedules = world.resource_mut::<Schedules>();
+    if let Some(schedule) = schedules.get_mut(&label) {
+        schedule.add_system(system);
+    } else {
+        let mut schedule = Schedule::new();
+        schedule.add_system(system);
+        schedules.insert(label, schedule);
+    }
+}
+
+/// Recursively despawns the entities captured by [`collect_state_scoped_entities`].
+fn clear_state_scoped_entities<S: States>(world: &mut World) {
+    let entities = std::mem::take(
+        &mut world
+            .resource_mut::<ExitingStateScopedEntities<S>>()
+            .entities,
+    );
+    for entity in entities {
+        // The entity may already be gone, e.g. as a descendant of another scoped entity.
+        if world.get_entity(entity).is_some() {
+            despawn_with_children_recursive(world, entity);
+        }
+    }
+}
+
+/// Adds [`StateScoped`] entity cleanup to an [`App`].
+pub trait StateScopedApp {
+    /// Enables despawning [`StateScoped<S>`] entities when their state is exited.
+    ///
+    /// The entities to despawn are captured right before the transition is applied by the
+    /// [`apply_state_transition::<S>`] system added by [`App::add_state`], and are despawned
+    /// recursively after the [`OnExit`] schedule and before the [`OnEnter`] schedule.
+    /// Entities scoped to the exited state that are spawned during [`OnExit`] are therefore
+    /// kept until that state is exited again.
+    ///
+    /// The state must have been added with [`App::add_state`].
+    fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
+}
+
+impl StateScopedApp for App {
+    fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self {
+        self.init_resource::<ExitingStateScopedEntities<S>>();
+        self.add_system(
+            collect_state_scoped_entities::<S>
+                .before(apply_state_transition::<S>)
+                .in_base_set(CoreSet::StateTransitions),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, IntoSystemAppConfig};
+    use bevy_ecs::prelude::*;
+    use bevy_utils::HashSet;
+
+    use super::{StateScoped, StateScopedApp};
+    use crate::BuildWorldChildren;
+
+    #[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+    enum AppState {
+        #[default]
+        Menu,
+        Game,
+    }
+
+    #[derive(Resource)]
+    struct SpawnedOnExit(Entity);
+
+    fn spawn_on_exit(mut commands: Commands) {
+        let entity = commands.spawn(StateScoped(AppState::Menu)).id();
+        commands.insert_resource(SpawnedOnExit(entity));
+    }
+
+    fn entities(world: &mut World) -> HashSet<Entity> {
+        world.query::<Entity>().iter(world).collect()
+    }
+
+    #[test]
+    fn state_scoped_entities() {
+        let mut app = App::new();
+        app.add_state::<AppState>()
+            .enable_state_scoped_entities::<AppState>()
+            .add_system(spawn_on_exit.in_schedule(OnExit(AppState::Menu)));
+        app.update();
+
+        let mut menu_child = None;
+        let menu = app
+            .world
+            .spawn(StateScoped(AppState::Menu))
+            .with_children(|parent| menu_child = Some(parent.spawn_empty().id()))
+            .id();
+        let menu_child = menu_child.unwrap();
+        let game = app.world.spawn(StateScoped(AppState::Game)).id();
+        let unscoped = app.world.spawn_empty().id();
+        assert_eq!(
+            entities(&mut app.world),
+            [menu, menu_child, game, unscoped].into_iter().collect()
+        );
+
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Game);
+        app.update();
+        // the entity spawned in `OnExit(AppState::Menu)` is kept until the menu is exited again
+        let spawned_on_exit = app.world.resource::<SpawnedOnExit>().0;
+        assert_eq!(
+            entities(&mut app.world),
+            [spawned_on_exit, game, unscoped].into_iter().collect()
+        );
+
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Menu);
+        app.update();
+        assert_eq!(
+            entities(&mut app.world),
+            [spawned_on_exit, unscoped].into_iter().collect()
+        );
+
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Game);
+        app.update();
+        let spawned_on_second_exit = app.world.resource::<SpawnedOnExit>().0;
+        assert_ne!(spawned_on_exit, spawned_on_second_exit);
+        assert_eq!(
+            entities(&mut app.world),
+            [spawned_on_second_exit, unscoped].into_iter().collect()
+        );
+    }
+}
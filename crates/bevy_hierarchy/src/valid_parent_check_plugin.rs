@@ -2,9 +2,9 @@ use std::marker::PhantomData;
 
 use bevy_app::{App, CoreSet, Plugin};
 use bevy_core::Name;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
 use bevy_log::warn;
-use bevy_utils::{get_short_name, HashSet};
+use bevy_utils::get_short_name;
 
 use crate::Parent;
 
@@ -59,7 +59,7 @@ pub fn check_hierarchy_component_has_valid_parent<T: Component>(
         (With<T>, Or<(Changed<Parent>, Added<T>)>),
     >,
     component_query: Query<(), With<T>>,
-    mut already_diagnosed: Local<HashSet<Entity>>,
+    mut already_diagnosed: Local<EntityHashSet>,
 ) {
     for (entity, parent, name) in &parent_query {
         let parent = parent.get();
@@ -1,19 +1,26 @@
 use bevy_ecs::system::Resource;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
 use bevy_utils::HashMap;
 use std::hash::Hash;
 
 /// Stores the position data of the input devices of type `T`.
 ///
 /// The values are stored as `f32`s, which range from [`Axis::MIN`] to [`Axis::MAX`], inclusive.
-#[derive(Debug, Resource)]
-pub struct Axis<T> {
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "T: serde::Deserialize<'de> + Eq + Hash"))
+)]
+pub struct Axis<T: FromReflect + Copy + Eq + Hash> {
     /// The position data of the input devices.
     axis_data: HashMap<T, f32>,
 }
 
 impl<T> Default for Axis<T>
 where
-    T: Copy + Eq + Hash,
+    T: FromReflect + Copy + Eq + Hash,
 {
     fn default() -> Self {
         Axis {
@@ -24,7 +31,7 @@ where
 
 impl<T> Axis<T>
 where
-    T: Copy + Eq + Hash,
+    T: FromReflect + Copy + Eq + Hash,
 {
     /// The smallest possible axis value.
     pub const MIN: f32 = -1.0;
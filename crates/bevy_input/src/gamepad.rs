@@ -2,10 +2,11 @@ use crate::{Axis, Input};
 use bevy_ecs::event::{EventReader, EventWriter};
 use bevy_ecs::{
     change_detection::DetectChangesMut,
-    system::{Res, ResMut, Resource},
+    system::{Local, Res, ResMut, Resource},
 };
 use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
-use bevy_utils::{tracing::info, HashMap};
+use bevy_time::Time;
+use bevy_utils::{tracing::info, Duration, HashMap};
 use thiserror::Error;
 
 /// Errors that occur when setting axis settings for gamepad input.
@@ -38,6 +39,9 @@ pub enum AxisSettingsError {
     /// The given parameter was not in range 0.0..=2.0.
     #[error("invalid threshold {0}, expected 0.0 <= threshold <= 2.0")]
     Threshold(f32),
+    /// The radius of a [`DeadZoneShape::Circle`] was not in range 0.0..=1.0.
+    #[error("invalid dead zone radius {0}, expected value [0.0..=1.0]")]
+    DeadZoneRadiusOutOfRange(f32),
 }
 
 /// Errors that occur when setting button settings for gamepad input.
@@ -285,6 +289,19 @@ pub enum GamepadAxisType {
     Other(u8),
 }
 
+impl GamepadAxisType {
+    /// Returns the other axis of the stick this axis belongs to, if any.
+    fn other_stick_axis(self) -> Option<GamepadAxisType> {
+        match self {
+            GamepadAxisType::LeftStickX => Some(GamepadAxisType::LeftStickY),
+            GamepadAxisType::LeftStickY => Some(GamepadAxisType::LeftStickX),
+            GamepadAxisType::RightStickX => Some(GamepadAxisType::RightStickY),
+            GamepadAxisType::RightStickY => Some(GamepadAxisType::RightStickX),
+            _ => None,
+        }
+    }
+}
+
 /// An axis of a [`Gamepad`].
 ///
 /// ## Usage
@@ -337,9 +354,10 @@ impl GamepadAxis {
 ///
 /// ## Note
 ///
-/// The [`GamepadSettings`] are used inside of `bevy_gilrs` to determine when raw gamepad events from `gilrs`,
-/// should register as a [`GamepadEvent`]. Events that don't meet the change thresholds defined in [`GamepadSettings`]
-/// will not register. To modify these settings, mutate the corresponding resource.
+/// The [`GamepadSettings`] are used inside of [`gamepad_event_system`] to filter the raw values of
+/// [`GamepadEvent`]s into [`GamepadButtonChangedEvent`]s and [`GamepadAxisChangedEvent`]s. Events that
+/// don't meet the change thresholds defined in [`GamepadSettings`] will not register. To modify these
+/// settings, mutate the corresponding resource.
 #[derive(Resource, Default, Debug, Reflect, FromReflect)]
 #[reflect(Debug, Default)]
 pub struct GamepadSettings {
@@ -422,12 +440,16 @@ impl GamepadSettings {
 /// to be considered pressed or released. A button is considered pressed if the `press_threshold`
 /// value is surpassed and released if the `release_threshold` value is undercut.
 ///
+/// To stop a button from flickering when its value hovers around a threshold, the button can be
+/// required to stay pressed or released for a `min_dwell_time` before it changes again.
+///
 /// Allowed values: `0.0 <= ``release_threshold`` <= ``press_threshold`` <= 1.0`
 #[derive(Debug, Clone, Reflect, FromReflect)]
 #[reflect(Debug, Default)]
 pub struct ButtonSettings {
     press_threshold: f32,
     release_threshold: f32,
+    min_dwell_time: Duration,
 }
 
 impl Default for ButtonSettings {
@@ -435,6 +457,7 @@ impl Default for ButtonSettings {
         ButtonSettings {
             press_threshold: 0.75,
             release_threshold: 0.65,
+            min_dwell_time: Duration::ZERO,
         }
     }
 }
@@ -479,6 +502,7 @@ impl ButtonSettings {
             Ok(ButtonSettings {
                 press_threshold,
                 release_threshold,
+                min_dwell_time: Duration::ZERO,
             })
         }
     }
@@ -570,6 +594,18 @@ impl ButtonSettings {
         self.try_set_release_threshold(value).ok();
         self.release_threshold
     }
+
+    /// Get the minimum time the button stays pressed or released before it can change again.
+    ///
+    /// This requires the [`Time`] resource, and is ignored without it.
+    pub fn min_dwell_time(&self) -> Duration {
+        self.min_dwell_time
+    }
+
+    /// Set the minimum time the button stays pressed or released before it can change again.
+    pub fn set_min_dwell_time(&mut self, value: Duration) {
+        self.min_dwell_time = value;
+    }
 }
 
 /// Settings for a [`GamepadAxis`].
@@ -582,6 +618,8 @@ impl ButtonSettings {
 /// to 0.0.
 /// Otherwise, values will not be rounded.
 ///
+/// The dead zone of the axes of a stick can instead be made circular with a [`DeadZoneShape`].
+///
 /// The valid range is `[-1.0, 1.0]`.
 #[derive(Debug, Clone, Reflect, FromReflect, PartialEq)]
 #[reflect(Debug, Default)]
@@ -596,6 +634,8 @@ pub struct AxisSettings {
     livezone_lowerbound: f32,
     /// `threshold` defines the minimum difference between old and new values to apply the changes.
     threshold: f32,
+    /// The shape of the dead zone, replacing `deadzone_lowerbound` and `deadzone_upperbound` when circular.
+    dead_zone_shape: DeadZoneShape,
 }
 
 impl Default for AxisSettings {
@@ -606,10 +646,28 @@ impl Default for AxisSettings {
             deadzone_lowerbound: -0.05,
             livezone_lowerbound: -0.95,
             threshold: 0.01,
+            dead_zone_shape: DeadZoneShape::Axial,
         }
     }
 }
 
+/// The shape of the dead zone of an axis, around the center of its stick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum DeadZoneShape {
+    /// Each axis is filtered on its own, rounding values between the `deadzone_lowerbound` and
+    /// `deadzone_upperbound` of its [`AxisSettings`] to 0.0.
+    ///
+    /// Near the center of a stick, this snaps diagonal movement to the closest axis.
+    #[default]
+    Axial,
+    /// The axis is filtered together with the other axis of its stick, rounding the value to 0.0
+    /// while the stick is less than `radius` away from its center.
+    ///
+    /// For axes that are not on a stick, values are rounded to 0.0 within `radius` of 0.0.
+    Circle { radius: f32 },
+}
+
 impl AxisSettings {
     /// Creates a new [`AxisSettings`] instance.
     ///
@@ -678,6 +736,7 @@ impl AxisSettings {
                 deadzone_upperbound,
                 livezone_upperbound,
                 threshold,
+                dead_zone_shape: DeadZoneShape::Axial,
             })
         }
     }
@@ -861,9 +920,59 @@ impl AxisSettings {
         self.threshold
     }
 
+    /// Get the shape of the dead zone.
+    pub fn dead_zone_shape(&self) -> DeadZoneShape {
+        self.dead_zone_shape
+    }
+
+    /// Try to set the shape of the dead zone.
+    ///
+    /// # Errors
+    ///
+    /// If the radius of a [`DeadZoneShape::Circle`] is not in range [0.0..=1.0], returns
+    /// `AxisSettingsError::DeadZoneRadiusOutOfRange`.
+    pub fn try_set_dead_zone_shape(
+        &mut self,
+        value: DeadZoneShape,
+    ) -> Result<(), AxisSettingsError> {
+        match value {
+            DeadZoneShape::Circle { radius } if !(0.0..=1.0).contains(&radius) => {
+                Err(AxisSettingsError::DeadZoneRadiusOutOfRange(radius))
+            }
+            _ => {
+                self.dead_zone_shape = value;
+                Ok(())
+            }
+        }
+    }
+
+    /// Try to set the shape of the dead zone. If the radius of a [`DeadZoneShape::Circle`] is not
+    /// in range [0.0..=1.0], the value will not be changed.
+    ///
+    /// Returns the new shape of the dead zone.
+    pub fn set_dead_zone_shape(&mut self, value: DeadZoneShape) -> DeadZoneShape {
+        self.try_set_dead_zone_shape(value).ok();
+        self.dead_zone_shape
+    }
+
     /// Clamps the `raw_value` according to the `AxisSettings`.
+    ///
+    /// A [`DeadZoneShape::Circle`] is applied as if the other axis of the stick was at 0.0, see
+    /// [`AxisSettings::clamp_stick`].
     pub fn clamp(&self, new_value: f32) -> f32 {
-        if self.deadzone_lowerbound <= new_value && new_value <= self.deadzone_upperbound {
+        self.clamp_stick(new_value, 0.0)
+    }
+
+    /// Clamps the `raw_value` of an axis of a stick according to the `AxisSettings`, given the raw
+    /// value of the other axis of the stick.
+    pub fn clamp_stick(&self, new_value: f32, other_axis_value: f32) -> f32 {
+        let in_dead_zone = match self.dead_zone_shape {
+            DeadZoneShape::Axial => {
+                self.deadzone_lowerbound <= new_value && new_value <= self.deadzone_upperbound
+            }
+            DeadZoneShape::Circle { radius } => new_value.hypot(other_axis_value) < radius,
+        };
+        if in_dead_zone {
             0.0
         } else if new_value >= self.livezone_upperbound {
             1.0
@@ -889,7 +998,21 @@ impl AxisSettings {
     /// Returns the clamped `new_value` if the change exceeds the settings threshold,
     /// and `None` otherwise.
     pub fn filter(&self, new_value: f32, old_value: Option<f32>) -> Option<f32> {
-        let new_value = self.clamp(new_value);
+        self.filter_stick(new_value, 0.0, old_value)
+    }
+
+    /// Filters the `new_value` of an axis of a stick based on the `old_value`, according to the
+    /// [`AxisSettings`], given the raw value of the other axis of the stick.
+    ///
+    /// Returns the clamped `new_value` if the change exceeds the settings threshold,
+    /// and `None` otherwise.
+    pub fn filter_stick(
+        &self,
+        new_value: f32,
+        other_axis_value: f32,
+        old_value: Option<f32>,
+    ) -> Option<f32> {
+        let new_value = self.clamp_stick(new_value, other_axis_value);
 
         if self.should_register_change(new_value, old_value) {
             return Some(new_value);
@@ -1128,27 +1251,66 @@ pub fn gamepad_axis_event_system(
 }
 
 /// Uses [`GamepadButtonChangedEvent`]s to update the relevant `Input` and `Axis` values.
+///
+/// Buttons that have not been pressed or released for the [`ButtonSettings::min_dwell_time`] only
+/// change once that time has passed.
+#[allow(clippy::too_many_arguments)]
 pub fn gamepad_button_event_system(
     mut button_events: EventReader<GamepadButtonChangedEvent>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
     mut button_input: ResMut<Input<GamepadButton>>,
     mut button_axis: ResMut<Axis<GamepadButton>>,
     settings: Res<GamepadSettings>,
+    time: Option<Res<Time>>,
+    // The latest value of each button, and when it was last pressed or released.
+    mut buttons: Local<HashMap<GamepadButton, (f32, Option<Duration>)>>,
 ) {
+    let now = time.map(|time| time.raw_elapsed());
+    let mut update_button =
+        |button: GamepadButton, value: f32, last_change: &mut Option<Duration>| {
+            let button_property = settings.get_button_settings(button);
+            let pressed = button_input.pressed(button);
+            let changes = if pressed {
+                button_property.is_released(value)
+            } else {
+                button_property.is_pressed(value)
+            };
+            let dwelled = match (now, *last_change) {
+                (Some(now), Some(last_change)) => {
+                    now.saturating_sub(last_change) >= button_property.min_dwell_time()
+                }
+                _ => true,
+            };
+            if changes && dwelled {
+                if pressed {
+                    button_input.release(button);
+                } else {
+                    button_input.press(button);
+                }
+                *last_change = now;
+            }
+        };
+
+    for connection_event in connection_events.iter() {
+        if connection_event.disconnected() {
+            buttons.retain(|button, _| button.gamepad != connection_event.gamepad);
+        }
+    }
+
     for button_event in button_events.iter() {
         let button = GamepadButton::new(button_event.gamepad, button_event.button_type);
         let value = button_event.value;
-        let button_property = settings.get_button_settings(button);
-
-        if button_property.is_released(value) {
-            // We don't have to check if the button was previously pressed
-            // because that check is performed within Input<T>::release()
-            button_input.release(button);
-        } else if button_property.is_pressed(value) {
-            button_input.press(button);
-        };
+        let (latest_value, last_change) = buttons.entry(button).or_default();
+        *latest_value = value;
+        update_button(button, value, last_change);
 
         button_axis.set(button, value);
     }
+
+    // Apply the changes held back until the buttons have dwelled for long enough.
+    for (&button, (value, last_change)) in buttons.iter_mut() {
+        update_button(button, *value, last_change);
+    }
 }
 
 /// A gamepad event.
@@ -1156,6 +1318,9 @@ pub fn gamepad_button_event_system(
 /// This event type is used over the [`GamepadConnectionEvent`],
 /// [`GamepadButtonChangedEvent`] and [`GamepadAxisChangedEvent`] when
 /// the in-frame relative ordering of events is important.
+///
+/// Button and axis events hold the raw values reported by the gamepad, which are filtered
+/// according to the [`GamepadSettings`] by the [`gamepad_event_system`].
 #[derive(Debug, Clone, PartialEq, Reflect, FromReflect)]
 #[reflect(Debug, PartialEq)]
 #[cfg_attr(
@@ -1188,21 +1353,90 @@ impl From<GamepadAxisChangedEvent> for GamepadEvent {
 }
 
 /// Splits the [`GamepadEvent`] event stream into it's component events.
+///
+/// The raw values of button and axis events are filtered according to the [`GamepadSettings`], and
+/// only sent on if they pass the change thresholds. Both axes of a stick with a
+/// [`DeadZoneShape::Circle`] are filtered together, so moving one axis may send an event for the
+/// other.
+#[allow(clippy::too_many_arguments)]
 pub fn gamepad_event_system(
     mut gamepad_events: EventReader<GamepadEvent>,
     mut connection_events: EventWriter<GamepadConnectionEvent>,
     mut button_events: EventWriter<GamepadButtonChangedEvent>,
     mut axis_events: EventWriter<GamepadAxisChangedEvent>,
     mut button_input: ResMut<Input<GamepadButton>>,
+    settings: Res<GamepadSettings>,
+    mut raw_axes: Local<HashMap<GamepadAxis, f32>>,
+    mut filtered_axes: Local<HashMap<GamepadAxis, f32>>,
+    mut filtered_buttons: Local<HashMap<GamepadButton, f32>>,
 ) {
     button_input.bypass_change_detection().clear();
     for gamepad_event in gamepad_events.iter() {
         match gamepad_event {
             GamepadEvent::Connection(connection_event) => {
+                if connection_event.disconnected() {
+                    let gamepad = connection_event.gamepad;
+                    raw_axes.retain(|axis, _| axis.gamepad != gamepad);
+                    filtered_axes.retain(|axis, _| axis.gamepad != gamepad);
+                    filtered_buttons.retain(|button, _| button.gamepad != gamepad);
+                }
                 connection_events.send(connection_event.clone());
             }
-            GamepadEvent::Button(button_event) => button_events.send(button_event.clone()),
-            GamepadEvent::Axis(axis_event) => axis_events.send(axis_event.clone()),
+            GamepadEvent::Button(button_event) => {
+                let button = GamepadButton::new(button_event.gamepad, button_event.button_type);
+                let old_value = filtered_buttons.get(&button).copied();
+                let button_settings = settings.get_button_axis_settings(button);
+
+                // Only send events that pass the user-defined change threshold
+                if let Some(filtered_value) = button_settings.filter(button_event.value, old_value)
+                {
+                    filtered_buttons.insert(button, filtered_value);
+                    button_events.send(GamepadButtonChangedEvent::new(
+                        button.gamepad,
+                        button.button_type,
+                        filtered_value,
+                    ));
+                }
+            }
+            GamepadEvent::Axis(axis_event) => {
+                let axis = GamepadAxis::new(axis_event.gamepad, axis_event.axis_type);
+                raw_axes.insert(axis, axis_event.value);
+
+                let other_stick_axis = |axis: GamepadAxis| {
+                    axis.axis_type
+                        .other_stick_axis()
+                        .map(|axis_type| GamepadAxis::new(axis.gamepad, axis_type))
+                };
+                // Moving this axis can move the other axis of a circular dead zone in or out of it.
+                let paired_axis = other_stick_axis(axis).filter(|other_axis| {
+                    raw_axes.contains_key(other_axis)
+                        && matches!(
+                            settings.get_axis_settings(*other_axis).dead_zone_shape(),
+                            DeadZoneShape::Circle { .. }
+                        )
+                });
+
+                for axis in std::iter::once(axis).chain(paired_axis) {
+                    let raw_value = raw_axes[&axis];
+                    let other_raw_value = other_stick_axis(axis)
+                        .and_then(|other_axis| raw_axes.get(&other_axis).copied())
+                        .unwrap_or(0.0);
+                    let old_value = filtered_axes.get(&axis).copied();
+                    let axis_settings = settings.get_axis_settings(axis);
+
+                    // Only send events that pass the user-defined change threshold
+                    if let Some(filtered_value) =
+                        axis_settings.filter_stick(raw_value, other_raw_value, old_value)
+                    {
+                        filtered_axes.insert(axis, filtered_value);
+                        axis_events.send(GamepadAxisChangedEvent::new(
+                            axis.gamepad,
+                            axis.axis_type,
+                            filtered_value,
+                        ));
+                    }
+                }
+            }
         }
     }
 }
@@ -1243,8 +1477,16 @@ const ALL_AXIS_TYPES: [GamepadAxisType; 6] = [
 #[cfg(test)]
 mod tests {
     use crate::gamepad::{AxisSettingsError, ButtonSettingsError};
+    use crate::{Axis, Input, InputPlugin};
+    use bevy_app::App;
+    use bevy_time::Time;
+    use bevy_utils::{Duration, Instant};
 
-    use super::{AxisSettings, ButtonAxisSettings, ButtonSettings};
+    use super::{
+        AxisSettings, ButtonAxisSettings, ButtonSettings, DeadZoneShape, Gamepad, GamepadAxis,
+        GamepadAxisChangedEvent, GamepadAxisType, GamepadButton, GamepadButtonChangedEvent,
+        GamepadButtonType, GamepadEvent, GamepadSettings,
+    };
 
     fn test_button_axis_settings_filter(
         settings: ButtonAxisSettings,
@@ -1496,6 +1738,7 @@ mod tests {
                 deadzone_upperbound: 0.05,
                 livezone_upperbound: 0.95,
                 threshold: 0.001,
+                dead_zone_shape: DeadZoneShape::Axial,
             })
         );
         assert_eq!(
@@ -1574,4 +1817,160 @@ mod tests {
             axis_settings.try_set_livezone_upperbound(0.1)
         );
     }
+
+    fn gamepad_app(axis_settings: AxisSettings) -> App {
+        let mut app = App::new();
+        app.add_plugin(InputPlugin);
+        app.world
+            .resource_mut::<GamepadSettings>()
+            .default_axis_settings = axis_settings;
+        app
+    }
+
+    fn update_axes(app: &mut App, raw_values: &[(GamepadAxisType, f32)]) {
+        for &(axis_type, value) in raw_values {
+            app.world
+                .send_event(GamepadEvent::from(GamepadAxisChangedEvent::new(
+                    Gamepad::new(0),
+                    axis_type,
+                    value,
+                )));
+        }
+        app.update();
+    }
+
+    fn axis_values(app: &App, axis_types: &[GamepadAxisType]) -> Vec<Option<f32>> {
+        let axes = app.world.resource::<Axis<GamepadAxis>>();
+        axis_types
+            .iter()
+            .map(|&axis_type| axes.get(GamepadAxis::new(Gamepad::new(0), axis_type)))
+            .collect()
+    }
+
+    const LEFT_STICK: [GamepadAxisType; 2] =
+        [GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY];
+
+    #[test]
+    fn test_axial_dead_zone_filters_each_axis() {
+        let mut app = gamepad_app(AxisSettings::new(-0.95, -0.2, 0.2, 0.95, 0.01).unwrap());
+
+        update_axes(
+            &mut app,
+            &[
+                (GamepadAxisType::LeftStickX, 0.15),
+                (GamepadAxisType::LeftStickY, 0.15),
+            ],
+        );
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(0.0), Some(0.0)]);
+
+        // diagonal movement snaps to the axis that left the dead zone
+        update_axes(&mut app, &[(GamepadAxisType::LeftStickY, 0.5)]);
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(0.0), Some(0.5)]);
+
+        update_axes(&mut app, &[(GamepadAxisType::LeftStickX, -0.97)]);
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(-1.0), Some(0.5)]);
+    }
+
+    #[test]
+    fn test_circular_dead_zone_filters_stick_axes_together() {
+        let mut axis_settings = AxisSettings::new(-0.95, -0.2, 0.2, 0.95, 0.01).unwrap();
+        axis_settings.set_dead_zone_shape(DeadZoneShape::Circle { radius: 0.2 });
+        let mut app = gamepad_app(axis_settings);
+
+        // both axes are inside of the axial dead zone, but the stick is outside of the circle
+        update_axes(
+            &mut app,
+            &[
+                (GamepadAxisType::LeftStickX, 0.15),
+                (GamepadAxisType::LeftStickY, 0.15),
+            ],
+        );
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(0.15), Some(0.15)]);
+
+        update_axes(&mut app, &[(GamepadAxisType::LeftStickX, 0.1)]);
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(0.0), Some(0.0)]);
+
+        // moving one axis out of the dead zone brings the other one along
+        update_axes(&mut app, &[(GamepadAxisType::LeftStickY, 0.5)]);
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(0.1), Some(0.5)]);
+        update_axes(&mut app, &[(GamepadAxisType::LeftStickY, 0.0)]);
+        assert_eq!(axis_values(&app, &LEFT_STICK), [Some(0.0), Some(0.0)]);
+
+        // the other stick is filtered separately
+        update_axes(&mut app, &[(GamepadAxisType::RightStickX, 0.15)]);
+        assert_eq!(
+            axis_values(&app, &[GamepadAxisType::RightStickX]),
+            [Some(0.0)]
+        );
+
+        // axes that are not on a stick are filtered on their own
+        update_axes(&mut app, &[(GamepadAxisType::LeftZ, 0.1)]);
+        assert_eq!(axis_values(&app, &[GamepadAxisType::LeftZ]), [Some(0.0)]);
+        update_axes(&mut app, &[(GamepadAxisType::LeftZ, -0.3)]);
+        assert_eq!(axis_values(&app, &[GamepadAxisType::LeftZ]), [Some(-0.3)]);
+    }
+
+    #[test]
+    fn test_try_out_of_range_dead_zone_shape() {
+        let mut axis_settings = AxisSettings::default();
+        assert_eq!(
+            Err(AxisSettingsError::DeadZoneRadiusOutOfRange(1.5)),
+            axis_settings.try_set_dead_zone_shape(DeadZoneShape::Circle { radius: 1.5 })
+        );
+        assert_eq!(
+            DeadZoneShape::Axial,
+            axis_settings.set_dead_zone_shape(DeadZoneShape::Circle { radius: -0.1 })
+        );
+        assert_eq!(
+            DeadZoneShape::Circle { radius: 0.1 },
+            axis_settings.set_dead_zone_shape(DeadZoneShape::Circle { radius: 0.1 })
+        );
+    }
+
+    #[test]
+    fn test_button_min_dwell_time() {
+        let mut app = App::new();
+        let start = Instant::now();
+        app.insert_resource(Time::new(start))
+            .add_plugin(InputPlugin);
+        app.world
+            .resource_mut::<GamepadSettings>()
+            .default_button_settings
+            .set_min_dwell_time(Duration::from_millis(50));
+        let button = GamepadButton::new(Gamepad::new(0), GamepadButtonType::RightTrigger2);
+
+        let update = |app: &mut App, millis: u64, raw_value: Option<f32>| {
+            if let Some(raw_value) = raw_value {
+                app.world
+                    .send_event(GamepadEvent::from(GamepadButtonChangedEvent::new(
+                        button.gamepad,
+                        button.button_type,
+                        raw_value,
+                    )));
+            }
+            app.world
+                .resource_mut::<Time>()
+                .update_with_instant(start + Duration::from_millis(millis));
+            app.update();
+            app.world.resource::<Input<GamepadButton>>().pressed(button)
+        };
+
+        assert!(update(&mut app, 0, Some(0.8)));
+        // flickering around the thresholds doesn't release the button
+        assert!(update(&mut app, 10, Some(0.6)));
+        assert!(update(&mut app, 20, Some(0.8)));
+        assert!(update(&mut app, 30, Some(0.5)));
+        assert!(update(&mut app, 40, None));
+        // the release is applied once the button has been pressed for long enough
+        assert!(!update(&mut app, 50, None));
+        assert!(!update(&mut app, 70, Some(0.9)));
+        assert!(update(&mut app, 100, None));
+        // without a dwell time, changes apply immediately
+        app.world
+            .resource_mut::<GamepadSettings>()
+            .default_button_settings
+            .set_min_dwell_time(Duration::ZERO);
+        assert!(!update(&mut app, 101, Some(0.2)));
+        assert!(update(&mut app, 102, Some(1.0)));
+    }
 }
@@ -5,6 +5,7 @@ pub mod gamepad;
 mod input;
 pub mod keyboard;
 pub mod mouse;
+pub mod recording;
 pub mod touch;
 
 pub use axis::*;
@@ -35,10 +36,10 @@ use touch::{touch_screen_input_system, ForceTouch, TouchInput, TouchPhase, Touch
 
 use gamepad::{
     gamepad_axis_event_system, gamepad_button_event_system, gamepad_connection_system,
-    gamepad_event_system, AxisSettings, ButtonAxisSettings, ButtonSettings, Gamepad, GamepadAxis,
-    GamepadAxisChangedEvent, GamepadAxisType, GamepadButton, GamepadButtonChangedEvent,
-    GamepadButtonType, GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadSettings,
-    Gamepads,
+    gamepad_event_system, AxisSettings, ButtonAxisSettings, ButtonSettings, DeadZoneShape, Gamepad,
+    GamepadAxis, GamepadAxisChangedEvent, GamepadAxisType, GamepadButton,
+    GamepadButtonChangedEvent, GamepadButtonType, GamepadConnection, GamepadConnectionEvent,
+    GamepadEvent, GamepadSettings, Gamepads,
 };
 
 #[cfg(feature = "serialize")]
@@ -123,6 +124,7 @@ impl Plugin for InputPlugin {
             .register_type::<GamepadSettings>()
             .register_type::<ButtonSettings>()
             .register_type::<AxisSettings>()
+            .register_type::<DeadZoneShape>()
             .register_type::<ButtonAxisSettings>();
     }
 }
@@ -0,0 +1,368 @@
+//! Recording of input sessions, and their playback in place of the real input devices.
+
+use bevy_app::prelude::*;
+use bevy_core::FrameCount;
+use bevy_ecs::{prelude::*, system::SystemParam};
+use bevy_reflect::{FromReflect, Reflect};
+
+use crate::{
+    gamepad::GamepadEvent,
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    touch::TouchInput,
+    InputSystem,
+};
+
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// Records the input events of an app, or plays back a recording in place of the real input
+/// devices.
+///
+/// Recorded events are keyed by the number of frames since the recording started, counted with
+/// the [`FrameCount`] resource, so a recording plays back identically regardless of how long each
+/// frame takes. This requires the [`FrameCountPlugin`](bevy_core::FrameCountPlugin) and the
+/// [`InputPlugin`](crate::InputPlugin).
+///
+/// The recorded events are the [`KeyboardInput`], [`MouseButtonInput`], [`MouseMotion`],
+/// [`MouseWheel`], [`TouchInput`] and [`GamepadEvent`] events sent by the input backends.
+pub enum InputRecordingPlugin {
+    /// Appends the input events of each frame to the [`InputRecording`] resource.
+    ///
+    /// With the `serialize` feature, the recording can be written out with any `serde` format.
+    Record,
+    /// Replaces the input events of each frame with the events of the recording, until the
+    /// [`InputPlayback`] is finished.
+    Playback(InputRecording),
+}
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RecordedInput>()
+            .register_type::<RecordedFrame>()
+            .register_type::<InputRecording>();
+
+        match self {
+            InputRecordingPlugin::Record => {
+                app.init_resource::<InputRecording>().add_system(
+                    record_input_system
+                        .before(InputSystem)
+                        .in_base_set(CoreSet::PreUpdate),
+                );
+            }
+            InputRecordingPlugin::Playback(recording) => {
+                app.insert_resource(InputPlayback::new(recording.clone()))
+                    .add_system(
+                        playback_input_system
+                            .before(InputSystem)
+                            .in_base_set(CoreSet::PreUpdate),
+                    );
+            }
+        }
+    }
+}
+
+/// An input event of an [`InputRecording`].
+#[derive(Debug, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum RecordedInput {
+    Keyboard(KeyboardInput),
+    MouseButton(MouseButtonInput),
+    MouseMotion(MouseMotion),
+    MouseWheel(MouseWheel),
+    Touch(TouchInput),
+    Gamepad(GamepadEvent),
+}
+
+/// The input events of one frame of an [`InputRecording`].
+#[derive(Debug, Clone, PartialEq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RecordedFrame {
+    /// The number of frames between the start of the recording and this frame.
+    pub frame: u32,
+    /// The events of this frame, in the order they were sent for each kind of event.
+    pub events: Vec<RecordedInput>,
+}
+
+/// A recorded input session.
+///
+/// Only the frames with at least one event are stored, in increasing [`RecordedFrame::frame`]
+/// order.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputRecording {
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// The progress of an [`InputRecording`] played back by the [`InputRecordingPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct InputPlayback {
+    recording: InputRecording,
+    /// The index of the next frame of the recording to play.
+    next_frame: usize,
+    /// The frame the playback started on.
+    start: Option<FrameCount>,
+}
+
+impl InputPlayback {
+    /// Creates a playback of `recording`, starting on the next frame.
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            next_frame: 0,
+            start: None,
+        }
+    }
+
+    /// Returns the recording being played.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Returns `true` if every frame of the recording has been played.
+    ///
+    /// Once finished, the events of the real input devices are no longer suppressed.
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.recording.frames.len()
+    }
+}
+
+/// The event streams of the input devices.
+#[derive(SystemParam)]
+struct InputEvents<'w> {
+    keyboard: ResMut<'w, Events<KeyboardInput>>,
+    mouse_button: ResMut<'w, Events<MouseButtonInput>>,
+    mouse_motion: ResMut<'w, Events<MouseMotion>>,
+    mouse_wheel: ResMut<'w, Events<MouseWheel>>,
+    touch: ResMut<'w, Events<TouchInput>>,
+    gamepad: ResMut<'w, Events<GamepadEvent>>,
+}
+
+impl InputEvents<'_> {
+    fn clear(&mut self) {
+        self.keyboard.clear();
+        self.mouse_button.clear();
+        self.mouse_motion.clear();
+        self.mouse_wheel.clear();
+        self.touch.clear();
+        self.gamepad.clear();
+    }
+
+    fn send(&mut self, event: RecordedInput) {
+        match event {
+            RecordedInput::Keyboard(event) => self.keyboard.send(event),
+            RecordedInput::MouseButton(event) => self.mouse_button.send(event),
+            RecordedInput::MouseMotion(event) => self.mouse_motion.send(event),
+            RecordedInput::MouseWheel(event) => self.mouse_wheel.send(event),
+            RecordedInput::Touch(event) => self.touch.send(event),
+            RecordedInput::Gamepad(event) => self.gamepad.send(event),
+        }
+    }
+}
+
+/// Readers of the event streams of the input devices.
+#[derive(SystemParam)]
+struct InputEventReaders<'w, 's> {
+    keyboard: EventReader<'w, 's, KeyboardInput>,
+    mouse_button: EventReader<'w, 's, MouseButtonInput>,
+    mouse_motion: EventReader<'w, 's, MouseMotion>,
+    mouse_wheel: EventReader<'w, 's, MouseWheel>,
+    touch: EventReader<'w, 's, TouchInput>,
+    gamepad: EventReader<'w, 's, GamepadEvent>,
+}
+
+impl InputEventReaders<'_, '_> {
+    fn read(&mut self) -> Vec<RecordedInput> {
+        let mut events = Vec::new();
+        events.extend(self.keyboard.iter().copied().map(RecordedInput::Keyboard));
+        events.extend(
+            self.mouse_button
+                .iter()
+                .copied()
+                .map(RecordedInput::MouseButton),
+        );
+        events.extend(
+            self.mouse_motion
+                .iter()
+                .copied()
+                .map(RecordedInput::MouseMotion),
+        );
+        events.extend(
+            self.mouse_wheel
+                .iter()
+                .copied()
+                .map(RecordedInput::MouseWheel),
+        );
+        events.extend(self.touch.iter().copied().map(RecordedInput::Touch));
+        events.extend(self.gamepad.iter().cloned().map(RecordedInput::Gamepad));
+        events
+    }
+}
+
+/// Appends the input events of this frame to the [`InputRecording`].
+fn record_input_system(
+    frame_count: Res<FrameCount>,
+    mut start: Local<Option<FrameCount>>,
+    mut recording: ResMut<InputRecording>,
+    mut readers: InputEventReaders,
+) {
+    let start = *start.get_or_insert(*frame_count);
+    let events = readers.read();
+    if !events.is_empty() {
+        recording.frames.push(RecordedFrame {
+            frame: frame_count.since(start),
+            events,
+        });
+    }
+}
+
+/// Replaces the input events of this frame with the events of the [`InputPlayback`].
+fn playback_input_system(
+    frame_count: Res<FrameCount>,
+    mut playback: ResMut<InputPlayback>,
+    mut events: InputEvents,
+) {
+    if playback.is_finished() {
+        return;
+    }
+    let playback = &mut *playback;
+    let start = *playback.start.get_or_insert(*frame_count);
+    let frame = frame_count.since(start);
+
+    events.clear();
+    while let Some(recorded) = playback
+        .recording
+        .frames
+        .get(playback.next_frame)
+        .filter(|recorded| recorded.frame <= frame)
+    {
+        for event in &recorded.events {
+            events.send(event.clone());
+        }
+        playback.next_frame += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_core::{FrameCount, FrameCountPlugin};
+    use bevy_utils::HashSet;
+
+    use super::{InputPlayback, InputRecording, InputRecordingPlugin, RecordedInput};
+    use crate::{
+        keyboard::{KeyCode, KeyboardInput},
+        mouse::{MouseButton, MouseButtonInput},
+        ButtonState, Input, InputPlugin,
+    };
+
+    fn key(key_code: KeyCode, state: ButtonState) -> RecordedInput {
+        RecordedInput::Keyboard(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state,
+        })
+    }
+
+    fn mouse(button: MouseButton, state: ButtonState) -> RecordedInput {
+        RecordedInput::MouseButton(MouseButtonInput { button, state })
+    }
+
+    fn app(recording_plugin: InputRecordingPlugin) -> App {
+        let mut app = App::new();
+        app.add_plugin(FrameCountPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(recording_plugin);
+        app
+    }
+
+    /// The pressed, just pressed and just released inputs.
+    type InputState<T> = [HashSet<T>; 3];
+
+    fn input_state<T: Copy + Eq + std::hash::Hash + Send + Sync>(
+        input: &Input<T>,
+    ) -> InputState<T> {
+        [
+            input.get_pressed().copied().collect(),
+            input.get_just_pressed().copied().collect(),
+            input.get_just_released().copied().collect(),
+        ]
+    }
+
+    /// Sends `events` as if they came from the input devices, then updates the app.
+    fn update(
+        app: &mut App,
+        events: &[RecordedInput],
+    ) -> (InputState<KeyCode>, InputState<MouseButton>) {
+        for event in events {
+            match *event {
+                RecordedInput::Keyboard(event) => app.world.send_event(event),
+                RecordedInput::MouseButton(event) => app.world.send_event(event),
+                _ => unreachable!(),
+            }
+        }
+        app.update();
+        (
+            input_state(app.world.resource::<Input<KeyCode>>()),
+            input_state(app.world.resource::<Input<MouseButton>>()),
+        )
+    }
+
+    #[test]
+    fn playback_reproduces_recorded_input() {
+        use ButtonState::{Pressed, Released};
+
+        let session = [
+            vec![key(KeyCode::A, Pressed)],
+            vec![],
+            vec![mouse(MouseButton::Left, Pressed), key(KeyCode::A, Released)],
+            vec![
+                mouse(MouseButton::Left, Released),
+                key(KeyCode::Space, Pressed),
+                key(KeyCode::Space, Released),
+            ],
+            vec![],
+            vec![key(KeyCode::B, Pressed)],
+        ];
+
+        let mut recorder = app(InputRecordingPlugin::Record);
+        // frames before the plugin sees any input aren't recorded
+        recorder.update();
+        let recorded_states: Vec<_> = session
+            .iter()
+            .map(|events| update(&mut recorder, events))
+            .collect();
+        let recording = recorder.world.resource::<InputRecording>().clone();
+        let recorded_frames: Vec<_> = recording.frames.iter().map(|frame| frame.frame).collect();
+        assert_eq!(recorded_frames, vec![1, 3, 4, 6]);
+
+        let mut player = app(InputRecordingPlugin::Playback(recording));
+        // the playback is synced to its own start, not to the frame count of the recording
+        player.world.insert_resource(FrameCount(100));
+        update(&mut player, &[]);
+        // events of the real devices are suppressed during the playback
+        let real_events = [key(KeyCode::Escape, Pressed)];
+        for recorded_state in recorded_states {
+            assert_eq!(update(&mut player, &real_events), recorded_state);
+        }
+        assert!(player.world.resource::<InputPlayback>().is_finished());
+
+        let (keys, _) = update(&mut player, &real_events);
+        assert!(keys[1].contains(&KeyCode::Escape));
+    }
+}
@@ -70,7 +70,7 @@ impl PluginGroup for DefaultPlugins {
 
         #[cfg(feature = "bevy_winit")]
         {
-            group = group.add(bevy_winit::WinitPlugin::default());
+            group = group.add(<bevy_winit::WinitPlugin>::default());
         }
 
         #[cfg(feature = "bevy_render")]
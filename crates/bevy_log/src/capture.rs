@@ -0,0 +1,141 @@
+use std::{
+    fmt::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{Receiver, SyncSender},
+        Arc, Mutex,
+    },
+};
+
+use bevy_ecs::{
+    event::EventWriter,
+    system::{ResMut, Resource},
+};
+use bevy_utils::tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::layer::{Context, Layer};
+
+/// A log record captured by the [`LogPlugin`](crate::LogPlugin), sent as an event once per frame.
+///
+/// Log records are only captured when [`LogPlugin::capture`](crate::LogPlugin::capture) is set.
+/// This can be used to display the logs of the engine in an in-game console.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEvent {
+    /// The formatted message of the record.
+    pub message: String,
+    /// The level of the record.
+    pub level: Level,
+    /// The target of the record, usually the module path it was emitted from.
+    pub target: String,
+    /// The other fields of the record, formatted with [`Debug`](std::fmt::Debug).
+    pub fields: Vec<(&'static str, String)>,
+}
+
+/// Settings for capturing log records as [`LogEvent`]s.
+#[derive(Debug, Clone)]
+pub struct LogCapture {
+    /// The maximum number of records buffered between two frames.
+    ///
+    /// Records logged while the buffer is full are dropped, and counted in [`DroppedLogEvents`].
+    pub capacity: usize,
+}
+
+impl Default for LogCapture {
+    fn default() -> Self {
+        Self { capacity: 1024 }
+    }
+}
+
+/// The number of log records that couldn't be captured as [`LogEvent`]s because the buffer was full.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DroppedLogEvents(Arc<AtomicUsize>);
+
+impl DroppedLogEvents {
+    /// Returns the number of records dropped since the app started.
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Receives the log records sent by the [`CaptureLayer`].
+#[derive(Resource)]
+pub(crate) struct CapturedLogs(Mutex<Receiver<LogEvent>>);
+
+impl CapturedLogs {
+    pub(crate) fn new(receiver: Receiver<LogEvent>) -> Self {
+        Self(Mutex::new(receiver))
+    }
+}
+
+/// Sends the captured log records as [`LogEvent`]s.
+pub(crate) fn send_captured_logs(
+    mut captured: ResMut<CapturedLogs>,
+    mut events: EventWriter<LogEvent>,
+) {
+    let receiver = captured.0.get_mut().unwrap_or_else(|err| err.into_inner());
+    events.send_batch(receiver.try_iter());
+}
+
+/// A [`Layer`] that forwards log records to the ECS without ever blocking.
+pub(crate) struct CaptureLayer {
+    sender: SyncSender<LogEvent>,
+    dropped: DroppedLogEvents,
+}
+
+impl CaptureLayer {
+    pub(crate) fn new(sender: SyncSender<LogEvent>, dropped: DroppedLogEvents) -> Self {
+        Self { sender, dropped }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        // Records from the `log` crate store their metadata in fields
+        let normalized_metadata = event.normalized_metadata();
+        let metadata = normalized_metadata
+            .as_ref()
+            .unwrap_or_else(|| event.metadata());
+
+        let mut visitor = CaptureVisitor::default();
+        event.record(&mut visitor);
+
+        let log_event = LogEvent {
+            message: visitor.message,
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            fields: visitor.fields,
+        };
+        if self.sender.try_send(log_event).is_err() {
+            self.dropped.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Default)]
+struct CaptureVisitor {
+    message: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for CaptureVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => {
+                let _ = write!(self.message, "{value:?}");
+            }
+            name if name.starts_with("log.") => {}
+            name => self.fields.push((name, format!("{value:?}"))),
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message.push_str(value),
+            name if name.starts_with("log.") => {}
+            name => self.fields.push((name, value.to_string())),
+        }
+    }
+}
@@ -16,6 +16,7 @@ use std::panic;
 
 #[cfg(target_os = "android")]
 mod android_tracing;
+mod capture;
 
 pub mod prelude {
     //! The Bevy Log Prelude.
@@ -30,11 +31,19 @@ pub use bevy_utils::tracing::{
     Level,
 };
 
-use bevy_app::{App, Plugin};
+pub use capture::{DroppedLogEvents, LogCapture, LogEvent};
+
+use bevy_app::{App, CoreSet, Plugin};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    schedule::IntoSystemConfig,
+    system::{Res, ResMut, Resource},
+};
+use capture::{CaptureLayer, CapturedLogs};
 use tracing_log::LogTracer;
 #[cfg(feature = "tracing-chrome")]
 use tracing_subscriber::fmt::{format::DefaultFields, FormattedFields};
-use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
+use tracing_subscriber::{prelude::*, registry::Registry, reload, EnvFilter};
 
 /// Adds logging to Apps. This plugin is part of the `DefaultPlugins`. Adding
 /// this plugin will setup a collector appropriate to your target platform:
@@ -55,6 +64,7 @@ use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
 ///         .add_plugins(DefaultPlugins.set(LogPlugin {
 ///             level: Level::DEBUG,
 ///             filter: "wgpu=error,bevy_render=info,bevy_ecs=trace".to_string(),
+///             capture: None,
 ///         }))
 ///         .run();
 /// }
@@ -67,6 +77,8 @@ use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
 /// If you define the `RUST_LOG` environment variable, the [`LogPlugin`] settings
 /// will be ignored.
 ///
+/// The filter can be changed while the app is running through the [`LogFilter`] resource.
+///
 /// If you want to setup your own tracing collector, you should disable this
 /// plugin from `DefaultPlugins`:
 /// ```no_run
@@ -91,6 +103,11 @@ pub struct LogPlugin {
     /// Filters out logs that are "less than" the given level.
     /// This can be further filtered using the `filter` setting.
     pub level: Level,
+
+    /// Captures log records and sends them as [`LogEvent`]s, if set.
+    ///
+    /// Only the records that pass the filter are captured.
+    pub capture: Option<LogCapture>,
 }
 
 impl Default for LogPlugin {
@@ -98,8 +115,41 @@ impl Default for LogPlugin {
         Self {
             filter: "wgpu=error".to_string(),
             level: Level::INFO,
+            capture: None,
+        }
+    }
+}
+
+/// The filter applied to logs, using the [`EnvFilter`] format.
+///
+/// It is initialized by the [`LogPlugin`] from the `RUST_LOG` environment variable, or from
+/// [`LogPlugin::level`] and [`LogPlugin::filter`]. Changing it updates the filter at the start of
+/// the next frame. An invalid filter is reported and ignored.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter(pub String);
+
+/// Reloads the [`EnvFilter`] of the global subscriber.
+#[derive(Resource)]
+struct LogFilterHandle {
+    handle: reload::Handle<EnvFilter, Registry>,
+    current: String,
+}
+
+fn update_log_filter(filter: Res<LogFilter>, mut handle: ResMut<LogFilterHandle>) {
+    if !filter.is_changed() || filter.0 == handle.current {
+        return;
+    }
+    match EnvFilter::try_new(&filter.0) {
+        Ok(env_filter) => {
+            if let Err(err) = handle.handle.reload(env_filter) {
+                warn!("Could not update the log filter: {err}");
+                return;
+            }
         }
+        Err(err) => warn!("Invalid log filter {:?}: {err}", filter.0),
     }
+    // Invalid filters are only reported once
+    handle.current = filter.0.clone();
 }
 
 impl Plugin for LogPlugin {
@@ -116,11 +166,27 @@ impl Plugin for LogPlugin {
 
         let finished_subscriber;
         let default_filter = { format!("{},{}", self.level, self.filter) };
-        let filter_layer = EnvFilter::try_from_default_env()
-            .or_else(|_| EnvFilter::try_new(&default_filter))
-            .unwrap();
+        let (filter_layer, filter) = match EnvFilter::try_from_default_env() {
+            Ok(filter_layer) => (
+                filter_layer,
+                std::env::var(EnvFilter::DEFAULT_ENV).unwrap_or_default(),
+            ),
+            Err(_) => (EnvFilter::try_new(&default_filter).unwrap(), default_filter),
+        };
+        let (filter_layer, filter_handle) = reload::Layer::new(filter_layer);
         let subscriber = Registry::default().with(filter_layer);
 
+        let capture_layer = self.capture.as_ref().map(|capture| {
+            let (sender, receiver) = std::sync::mpsc::sync_channel(capture.capacity);
+            let dropped = DroppedLogEvents::default();
+            app.add_event::<LogEvent>()
+                .insert_resource(CapturedLogs::new(receiver))
+                .insert_resource(dropped.clone())
+                .add_system(capture::send_captured_logs.in_base_set(CoreSet::Last));
+            CaptureLayer::new(sender, dropped)
+        });
+        let subscriber = subscriber.with(capture_layer);
+
         #[cfg(feature = "trace")]
         let subscriber = subscriber.with(tracing_error::ErrorLayer::default());
 
@@ -198,5 +264,76 @@ impl Plugin for LogPlugin {
             (_, true) => warn!("Could not set global tracing subscriber as it is already set. Consider disabling LogPlugin."),
             _ => (),
         }
+
+        // The filter can only be updated if this plugin set up the global subscriber
+        if !subscriber_already_set {
+            app.insert_resource(LogFilter(filter.clone()))
+                .insert_resource(LogFilterHandle {
+                    handle: filter_handle,
+                    current: filter,
+                })
+                .add_system(update_log_filter.in_base_set(CoreSet::First));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::event::Events;
+
+    fn log_system() {
+        info!(answer = 42, "hello from a system");
+    }
+
+    fn captured(app: &App) -> Vec<LogEvent> {
+        app.world
+            .resource::<Events<LogEvent>>()
+            .iter_current_update_events()
+            .filter(|event| event.target == module_path!())
+            .cloned()
+            .collect()
+    }
+
+    // The plugin sets up global state, so everything is tested in a single app
+    #[test]
+    fn capture_and_reload_filter() {
+        let mut app = App::new();
+        app.add_plugin(LogPlugin {
+            capture: Some(LogCapture { capacity: 16 }),
+            ..Default::default()
+        })
+        .add_system(log_system);
+        // Ignore `RUST_LOG`
+        app.insert_resource(LogFilter("info".to_string()));
+
+        app.update();
+        let events = captured(&app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].message, "hello from a system");
+        assert_eq!(events[0].level, Level::INFO);
+        assert_eq!(events[0].fields, vec![("answer", "42".to_string())]);
+
+        app.insert_resource(LogFilter("warn".to_string()));
+        app.update();
+        assert!(captured(&app).is_empty());
+
+        // Invalid filters are ignored
+        app.insert_resource(LogFilter("=^=".to_string()));
+        app.update();
+        assert!(captured(&app).is_empty());
+
+        app.insert_resource(LogFilter(format!("warn,{}=info", module_path!())));
+        app.update();
+        assert_eq!(captured(&app).len(), 1);
+        assert_eq!(app.world.resource::<DroppedLogEvents>().count(), 0);
+
+        // Records are dropped once the buffer is full
+        for _ in 0..20 {
+            info!("filling the buffer");
+        }
+        app.update();
+        assert_eq!(captured(&app).len(), 16);
+        assert!(app.world.resource::<DroppedLogEvents>().count() >= 5);
     }
 }
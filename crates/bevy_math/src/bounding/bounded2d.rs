@@ -0,0 +1,437 @@
+use super::{BoundingVolume, IntersectsVolume};
+use crate::Vec2;
+
+/// A 2D axis-aligned bounding box, or bounding rectangle.
+///
+/// The minimum/maximum invariant must be upheld by the user when directly assigning the fields,
+/// otherwise some methods produce invalid results. It is recommended to use one of the
+/// constructor methods instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb2d {
+    /// The minimum, conventionally bottom-left, point of the box.
+    pub min: Vec2,
+    /// The maximum, conventionally top-right, point of the box.
+    pub max: Vec2,
+}
+
+impl Aabb2d {
+    /// Constructs an AABB from its center and half-size.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the components of the half-size is negative.
+    #[inline]
+    pub fn new(center: Vec2, half_size: Vec2) -> Self {
+        assert!(half_size.cmpge(Vec2::ZERO).all());
+        Self {
+            min: center - half_size,
+            max: center + half_size,
+        }
+    }
+
+    /// Computes the smallest AABB containing all of the given points.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `points` is empty.
+    #[inline]
+    pub fn from_point_cloud(points: &[Vec2]) -> Self {
+        let (first, rest) = points
+            .split_first()
+            .expect("point cloud must contain at least one point for Aabb2d construction");
+        let (min, max) = rest.iter().fold((*first, *first), |(min, max), point| {
+            (min.min(*point), max.max(*point))
+        });
+        Self { min, max }
+    }
+
+    /// Finds the point on the AABB that is closest to the given `point`.
+    ///
+    /// If the point is outside the AABB, the returned point will be on its perimeter.
+    /// If the point is inside the AABB, the returned point will be `point` itself.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        // Clamp point coordinates to the AABB
+        point.clamp(self.min, self.max)
+    }
+}
+
+impl BoundingVolume for Aabb2d {
+    type Position = Vec2;
+    type HalfSize = Vec2;
+
+    #[inline]
+    fn center(&self) -> Self::Position {
+        (self.min + self.max) / 2.
+    }
+
+    #[inline]
+    fn half_size(&self) -> Self::HalfSize {
+        (self.max - self.min) / 2.
+    }
+
+    #[inline]
+    fn visible_area(&self) -> f32 {
+        let size = self.max - self.min;
+        size.x * size.y
+    }
+
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        other.min.cmpge(self.min).all() && other.max.cmple(self.max).all()
+    }
+
+    #[inline]
+    fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    #[inline]
+    fn grow(&self, amount: Self::HalfSize) -> Self {
+        let b = Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        };
+        debug_assert!(b.min.cmple(b.max).all());
+        b
+    }
+
+    #[inline]
+    fn shrink(&self, amount: Self::HalfSize) -> Self {
+        let b = Self {
+            min: self.min + amount,
+            max: self.max - amount,
+        };
+        debug_assert!(b.min.cmple(b.max).all());
+        b
+    }
+}
+
+impl IntersectsVolume<Self> for Aabb2d {
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && self.max.cmpge(other.min).all()
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for Aabb2d {
+    #[inline]
+    fn intersects(&self, circle: &BoundingCircle) -> bool {
+        let closest_point = self.closest_point(circle.center);
+        closest_point.distance_squared(circle.center) <= circle.radius * circle.radius
+    }
+}
+
+/// A bounding circle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingCircle {
+    /// The center of the bounding circle.
+    pub center: Vec2,
+    /// The radius of the bounding circle, which should not be negative.
+    pub radius: f32,
+}
+
+impl BoundingCircle {
+    /// Constructs a bounding circle from its center and radius.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the radius is negative.
+    #[inline]
+    pub fn new(center: Vec2, radius: f32) -> Self {
+        assert!(radius >= 0.);
+        Self { center, radius }
+    }
+
+    /// Computes a bounding circle containing all of the given points.
+    ///
+    /// The circle is centered on the center of the bounding box of the points, so it is not
+    /// necessarily the smallest one.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `points` is empty.
+    #[inline]
+    pub fn from_point_cloud(points: &[Vec2]) -> Self {
+        let center = Aabb2d::from_point_cloud(points).center();
+        let radius_squared = points
+            .iter()
+            .map(|point| point.distance_squared(center))
+            .fold(0., f32::max);
+        Self::new(center, radius_squared.sqrt())
+    }
+
+    /// Computes the smallest [`Aabb2d`] containing this circle.
+    #[inline]
+    pub fn aabb_2d(&self) -> Aabb2d {
+        Aabb2d::new(self.center, Vec2::splat(self.radius))
+    }
+
+    /// Finds the point on the bounding circle that is closest to the given `point`.
+    ///
+    /// If the point is outside the circle, the returned point will be on its perimeter.
+    /// If the point is inside the circle, the returned point will be `point` itself.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let offset = point - self.center;
+        if offset.length_squared() <= self.radius * self.radius {
+            point
+        } else {
+            self.center + offset.normalize() * self.radius
+        }
+    }
+}
+
+impl BoundingVolume for BoundingCircle {
+    type Position = Vec2;
+    type HalfSize = f32;
+
+    #[inline]
+    fn center(&self) -> Self::Position {
+        self.center
+    }
+
+    #[inline]
+    fn half_size(&self) -> Self::HalfSize {
+        self.radius
+    }
+
+    #[inline]
+    fn visible_area(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius
+    }
+
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        let diff = self.radius - other.radius;
+        diff >= 0. && self.center.distance_squared(other.center) <= diff * diff
+    }
+
+    #[inline]
+    fn merge(&self, other: &Self) -> Self {
+        let diff = other.center - self.center;
+        let length = diff.length();
+        if self.radius >= length + other.radius {
+            return *self;
+        }
+        if other.radius >= length + self.radius {
+            return *other;
+        }
+        let dir = diff / length;
+        Self::new(
+            (self.center + other.center) / 2. + dir * ((other.radius - self.radius) / 2.),
+            (length + self.radius + other.radius) / 2.,
+        )
+    }
+
+    #[inline]
+    fn grow(&self, amount: Self::HalfSize) -> Self {
+        debug_assert!(amount >= 0.);
+        Self::new(self.center, self.radius + amount)
+    }
+
+    #[inline]
+    fn shrink(&self, amount: Self::HalfSize) -> Self {
+        debug_assert!(amount >= 0.);
+        debug_assert!(self.radius >= amount);
+        Self::new(self.center, self.radius - amount)
+    }
+}
+
+impl IntersectsVolume<Self> for BoundingCircle {
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        let radius_sum = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= radius_sum * radius_sum
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for BoundingCircle {
+    #[inline]
+    fn intersects(&self, aabb: &Aabb2d) -> bool {
+        aabb.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod aabb2d_tests {
+    use super::*;
+
+    #[test]
+    fn center_and_half_size() {
+        let aabb = Aabb2d::new(Vec2::new(1., -2.), Vec2::new(2., 0.5));
+        assert_eq!(aabb.min, Vec2::new(-1., -2.5));
+        assert_eq!(aabb.max, Vec2::new(3., -1.5));
+        assert_eq!(aabb.center(), Vec2::new(1., -2.));
+        assert_eq!(aabb.half_size(), Vec2::new(2., 0.5));
+        assert_eq!(aabb.visible_area(), 4.);
+    }
+
+    #[test]
+    fn from_point_cloud() {
+        let aabb =
+            Aabb2d::from_point_cloud(&[Vec2::new(1., 3.), Vec2::new(-2., 0.), Vec2::new(0., -1.)]);
+        assert_eq!(aabb.min, Vec2::new(-2., -1.));
+        assert_eq!(aabb.max, Vec2::new(1., 3.));
+
+        let point = Aabb2d::from_point_cloud(&[Vec2::ONE]);
+        assert_eq!(point.half_size(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn contains() {
+        let a = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        assert!(a.contains(&a));
+        assert!(a.contains(&Aabb2d::new(Vec2::new(0.5, 0.), Vec2::new(0.5, 1.))));
+        assert!(!a.contains(&Aabb2d::new(Vec2::new(0.5, 0.), Vec2::new(0.6, 1.))));
+        // Degenerate boxes
+        assert!(a.contains(&Aabb2d::new(Vec2::X, Vec2::ZERO)));
+        assert!(!a.contains(&Aabb2d::new(Vec2::new(1. + 1e-5, 0.), Vec2::ZERO)));
+    }
+
+    #[test]
+    fn merge() {
+        let a = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let b = Aabb2d::new(Vec2::new(2., 1.), Vec2::splat(0.5));
+        let merged = a.merge(&b);
+        assert_eq!(merged.min, Vec2::new(-1., -1.));
+        assert_eq!(merged.max, Vec2::new(2.5, 1.5));
+        assert!(merged.contains(&a));
+        assert!(merged.contains(&b));
+    }
+
+    #[test]
+    fn grow_and_shrink() {
+        let a = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        let grown = a.grow(Vec2::new(1., 0.5));
+        assert_eq!(grown.half_size(), Vec2::new(2., 1.5));
+        assert_eq!(grown.shrink(Vec2::new(1., 0.5)), a);
+        assert_eq!(a.shrink(Vec2::ONE).half_size(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn closest_point() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        assert_eq!(aabb.closest_point(Vec2::new(3., 0.5)), Vec2::new(1., 0.5));
+        assert_eq!(aabb.closest_point(Vec2::new(-2., -2.)), Vec2::NEG_ONE);
+        assert_eq!(aabb.closest_point(Vec2::splat(0.5)), Vec2::splat(0.5));
+    }
+
+    #[test]
+    fn intersects_aabb() {
+        let a = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        assert!(a.intersects(&Aabb2d::new(Vec2::new(1.5, 1.5), Vec2::ONE)));
+        // Touching
+        assert!(a.intersects(&Aabb2d::new(Vec2::new(2., 0.), Vec2::ONE)));
+        assert!(!a.intersects(&Aabb2d::new(Vec2::new(2. + 1e-5, 0.), Vec2::ONE)));
+        // Degenerate boxes
+        let point = Aabb2d::new(Vec2::new(1., 0.), Vec2::ZERO);
+        assert!(a.intersects(&point));
+        assert!(point.intersects(&point));
+        assert!(!point.intersects(&Aabb2d::new(Vec2::new(1., 1e-5), Vec2::ZERO)));
+    }
+
+    #[test]
+    fn intersects_circle() {
+        let aabb = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        assert!(aabb.intersects(&BoundingCircle::new(Vec2::new(2., 0.), 1.)));
+        assert!(!aabb.intersects(&BoundingCircle::new(Vec2::new(2. + 1e-5, 0.), 1.)));
+        // The corner is further than the sides
+        assert!(!aabb.intersects(&BoundingCircle::new(Vec2::splat(1.75), 1.)));
+        assert!(aabb.intersects(&BoundingCircle::new(Vec2::splat(1.7), 1.)));
+        // Zero radius circle inside the box
+        assert!(aabb.intersects(&BoundingCircle::new(Vec2::splat(0.5), 0.)));
+        assert!(BoundingCircle::new(Vec2::splat(0.5), 0.).intersects(&aabb));
+    }
+}
+
+#[cfg(test)]
+mod bounding_circle_tests {
+    use super::*;
+
+    #[test]
+    fn from_point_cloud() {
+        let circle = BoundingCircle::from_point_cloud(&[
+            Vec2::new(-1., 0.),
+            Vec2::new(3., 0.),
+            Vec2::new(1., 1.),
+        ]);
+        assert_eq!(circle.center, Vec2::new(1., 0.5));
+        for point in [Vec2::new(-1., 0.), Vec2::new(3., 0.), Vec2::new(1., 1.)] {
+            assert!(circle.center.distance(point) <= circle.radius + 1e-5);
+        }
+
+        let point = BoundingCircle::from_point_cloud(&[Vec2::ONE]);
+        assert_eq!(point, BoundingCircle::new(Vec2::ONE, 0.));
+    }
+
+    #[test]
+    fn aabb_2d() {
+        let aabb = BoundingCircle::new(Vec2::new(1., 2.), 0.5).aabb_2d();
+        assert_eq!(aabb.min, Vec2::new(0.5, 1.5));
+        assert_eq!(aabb.max, Vec2::new(1.5, 2.5));
+    }
+
+    #[test]
+    fn contains() {
+        let a = BoundingCircle::new(Vec2::ZERO, 2.);
+        assert!(a.contains(&a));
+        assert!(a.contains(&BoundingCircle::new(Vec2::new(1., 0.), 1.)));
+        assert!(!a.contains(&BoundingCircle::new(Vec2::new(1. + 1e-5, 0.), 1.)));
+        assert!(!a.contains(&BoundingCircle::new(Vec2::ZERO, 3.)));
+        // Degenerate circles
+        assert!(a.contains(&BoundingCircle::new(Vec2::new(0., 2.), 0.)));
+        let point = BoundingCircle::new(Vec2::ZERO, 0.);
+        assert!(point.contains(&point));
+        assert!(!point.contains(&a));
+    }
+
+    #[test]
+    fn merge() {
+        let a = BoundingCircle::new(Vec2::ZERO, 1.);
+        let b = BoundingCircle::new(Vec2::new(4., 0.), 1.);
+        let merged = a.merge(&b);
+        assert!(merged.center.abs_diff_eq(Vec2::new(2., 0.), 1e-5));
+        assert!((merged.radius - 3.).abs() < 1e-5);
+        assert_eq!(a.merge(&BoundingCircle::new(Vec2::X, 3.)).radius, 3.);
+        assert_eq!(a.merge(&BoundingCircle::new(Vec2::ZERO, 0.)), a);
+        // Merging with itself or a concentric circle doesn't divide by zero.
+        assert_eq!(a.merge(&a), a);
+    }
+
+    #[test]
+    fn grow_and_shrink() {
+        let a = BoundingCircle::new(Vec2::ONE, 1.);
+        assert_eq!(a.grow(1.5), BoundingCircle::new(Vec2::ONE, 2.5));
+        assert_eq!(a.grow(1.5).shrink(1.5), a);
+        assert_eq!(a.shrink(1.).radius, 0.);
+        assert_eq!(a.half_size(), 1.);
+        assert!((a.visible_area() - std::f32::consts::PI).abs() < 1e-5);
+    }
+
+    #[test]
+    fn closest_point() {
+        let circle = BoundingCircle::new(Vec2::ZERO, 1.);
+        assert_eq!(circle.closest_point(Vec2::new(3., 0.)), Vec2::X);
+        assert_eq!(circle.closest_point(Vec2::splat(0.5)), Vec2::splat(0.5));
+    }
+
+    #[test]
+    fn intersects_circle() {
+        let a = BoundingCircle::new(Vec2::ZERO, 1.);
+        assert!(a.intersects(&BoundingCircle::new(Vec2::new(1.5, 0.), 1.)));
+        // Touching
+        assert!(a.intersects(&BoundingCircle::new(Vec2::new(0., 2.), 1.)));
+        assert!(!a.intersects(&BoundingCircle::new(Vec2::new(0., 2. + 1e-5), 1.)));
+        // Degenerate circles
+        let point = BoundingCircle::new(Vec2::new(0., 1.), 0.);
+        assert!(a.intersects(&point));
+        assert!(point.intersects(&point));
+        assert!(!point.intersects(&BoundingCircle::new(Vec2::new(1e-5, 1.), 0.)));
+    }
+}
@@ -0,0 +1,452 @@
+use super::{BoundingVolume, IntersectsVolume};
+use crate::Vec3;
+
+/// A 3D axis-aligned bounding box.
+///
+/// The minimum/maximum invariant must be upheld by the user when directly assigning the fields,
+/// otherwise some methods produce invalid results. It is recommended to use one of the
+/// constructor methods instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb3d {
+    /// The minimum point of the box.
+    pub min: Vec3,
+    /// The maximum point of the box.
+    pub max: Vec3,
+}
+
+impl Aabb3d {
+    /// Constructs an AABB from its center and half-size.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the components of the half-size is negative.
+    #[inline]
+    pub fn new(center: Vec3, half_size: Vec3) -> Self {
+        assert!(half_size.cmpge(Vec3::ZERO).all());
+        Self {
+            min: center - half_size,
+            max: center + half_size,
+        }
+    }
+
+    /// Computes the smallest AABB containing all of the given points.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `points` is empty.
+    #[inline]
+    pub fn from_point_cloud(points: &[Vec3]) -> Self {
+        let (first, rest) = points
+            .split_first()
+            .expect("point cloud must contain at least one point for Aabb3d construction");
+        let (min, max) = rest.iter().fold((*first, *first), |(min, max), point| {
+            (min.min(*point), max.max(*point))
+        });
+        Self { min, max }
+    }
+
+    /// Finds the point on the AABB that is closest to the given `point`.
+    ///
+    /// If the point is outside the AABB, the returned point will be on its surface.
+    /// If the point is inside the AABB, the returned point will be `point` itself.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        // Clamp point coordinates to the AABB
+        point.clamp(self.min, self.max)
+    }
+}
+
+impl BoundingVolume for Aabb3d {
+    type Position = Vec3;
+    type HalfSize = Vec3;
+
+    #[inline]
+    fn center(&self) -> Self::Position {
+        (self.min + self.max) / 2.
+    }
+
+    #[inline]
+    fn half_size(&self) -> Self::HalfSize {
+        (self.max - self.min) / 2.
+    }
+
+    #[inline]
+    fn visible_area(&self) -> f32 {
+        let size = self.max - self.min;
+        size.x * size.y + size.y * size.z + size.z * size.x
+    }
+
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        other.min.cmpge(self.min).all() && other.max.cmple(self.max).all()
+    }
+
+    #[inline]
+    fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    #[inline]
+    fn grow(&self, amount: Self::HalfSize) -> Self {
+        let b = Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        };
+        debug_assert!(b.min.cmple(b.max).all());
+        b
+    }
+
+    #[inline]
+    fn shrink(&self, amount: Self::HalfSize) -> Self {
+        let b = Self {
+            min: self.min + amount,
+            max: self.max - amount,
+        };
+        debug_assert!(b.min.cmple(b.max).all());
+        b
+    }
+}
+
+impl IntersectsVolume<Self> for Aabb3d {
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && self.max.cmpge(other.min).all()
+    }
+}
+
+impl IntersectsVolume<BoundingSphere> for Aabb3d {
+    #[inline]
+    fn intersects(&self, sphere: &BoundingSphere) -> bool {
+        let closest_point = self.closest_point(sphere.center);
+        closest_point.distance_squared(sphere.center) <= sphere.radius * sphere.radius
+    }
+}
+
+/// A bounding sphere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingSphere {
+    /// The center of the bounding sphere.
+    pub center: Vec3,
+    /// The radius of the bounding sphere, which should not be negative.
+    pub radius: f32,
+}
+
+impl BoundingSphere {
+    /// Constructs a bounding sphere from its center and radius.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the radius is negative.
+    #[inline]
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        assert!(radius >= 0.);
+        Self { center, radius }
+    }
+
+    /// Computes a bounding sphere containing all of the given points.
+    ///
+    /// The sphere is centered on the center of the bounding box of the points, so it is not
+    /// necessarily the smallest one.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `points` is empty.
+    #[inline]
+    pub fn from_point_cloud(points: &[Vec3]) -> Self {
+        let center = Aabb3d::from_point_cloud(points).center();
+        let radius_squared = points
+            .iter()
+            .map(|point| point.distance_squared(center))
+            .fold(0., f32::max);
+        Self::new(center, radius_squared.sqrt())
+    }
+
+    /// Computes the smallest [`Aabb3d`] containing this sphere.
+    #[inline]
+    pub fn aabb_3d(&self) -> Aabb3d {
+        Aabb3d::new(self.center, Vec3::splat(self.radius))
+    }
+
+    /// Finds the point on the bounding sphere that is closest to the given `point`.
+    ///
+    /// If the point is outside the sphere, the returned point will be on its surface.
+    /// If the point is inside the sphere, the returned point will be `point` itself.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let offset = point - self.center;
+        if offset.length_squared() <= self.radius * self.radius {
+            point
+        } else {
+            self.center + offset.normalize() * self.radius
+        }
+    }
+}
+
+impl BoundingVolume for BoundingSphere {
+    type Position = Vec3;
+    type HalfSize = f32;
+
+    #[inline]
+    fn center(&self) -> Self::Position {
+        self.center
+    }
+
+    #[inline]
+    fn half_size(&self) -> Self::HalfSize {
+        self.radius
+    }
+
+    #[inline]
+    fn visible_area(&self) -> f32 {
+        2. * std::f32::consts::PI * self.radius * self.radius
+    }
+
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        let diff = self.radius - other.radius;
+        diff >= 0. && self.center.distance_squared(other.center) <= diff * diff
+    }
+
+    #[inline]
+    fn merge(&self, other: &Self) -> Self {
+        let diff = other.center - self.center;
+        let length = diff.length();
+        if self.radius >= length + other.radius {
+            return *self;
+        }
+        if other.radius >= length + self.radius {
+            return *other;
+        }
+        let dir = diff / length;
+        Self::new(
+            (self.center + other.center) / 2. + dir * ((other.radius - self.radius) / 2.),
+            (length + self.radius + other.radius) / 2.,
+        )
+    }
+
+    #[inline]
+    fn grow(&self, amount: Self::HalfSize) -> Self {
+        debug_assert!(amount >= 0.);
+        Self::new(self.center, self.radius + amount)
+    }
+
+    #[inline]
+    fn shrink(&self, amount: Self::HalfSize) -> Self {
+        debug_assert!(amount >= 0.);
+        debug_assert!(self.radius >= amount);
+        Self::new(self.center, self.radius - amount)
+    }
+}
+
+impl IntersectsVolume<Self> for BoundingSphere {
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        let radius_sum = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= radius_sum * radius_sum
+    }
+}
+
+impl IntersectsVolume<Aabb3d> for BoundingSphere {
+    #[inline]
+    fn intersects(&self, aabb: &Aabb3d) -> bool {
+        aabb.intersects(self)
+    }
+}
+
+#[cfg(test)]
+mod aabb3d_tests {
+    use super::*;
+
+    #[test]
+    fn center_and_half_size() {
+        let aabb = Aabb3d::new(Vec3::new(1., -2., 3.), Vec3::new(2., 0.5, 1.));
+        assert_eq!(aabb.min, Vec3::new(-1., -2.5, 2.));
+        assert_eq!(aabb.max, Vec3::new(3., -1.5, 4.));
+        assert_eq!(aabb.center(), Vec3::new(1., -2., 3.));
+        assert_eq!(aabb.half_size(), Vec3::new(2., 0.5, 1.));
+        assert_eq!(aabb.visible_area(), 14.);
+        // Degenerate boxes have the area of their single face
+        assert_eq!(
+            Aabb3d::new(Vec3::ZERO, Vec3::new(1., 1., 0.)).visible_area(),
+            4.
+        );
+    }
+
+    #[test]
+    fn from_point_cloud() {
+        let aabb = Aabb3d::from_point_cloud(&[
+            Vec3::new(1., 3., 0.),
+            Vec3::new(-2., 0., 0.),
+            Vec3::new(0., -1., 0.),
+        ]);
+        assert_eq!(aabb.min, Vec3::new(-2., -1., 0.));
+        assert_eq!(aabb.max, Vec3::new(1., 3., 0.));
+
+        let point = Aabb3d::from_point_cloud(&[Vec3::ONE]);
+        assert_eq!(point.half_size(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn contains() {
+        let a = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        assert!(a.contains(&a));
+        assert!(a.contains(&Aabb3d::new(Vec3::new(0.5, 0., 0.), Vec3::new(0.5, 1., 0.))));
+        assert!(!a.contains(&Aabb3d::new(Vec3::new(0.5, 0., 0.), Vec3::new(0.6, 1., 0.))));
+        // Degenerate boxes
+        assert!(a.contains(&Aabb3d::new(Vec3::X, Vec3::ZERO)));
+        assert!(!a.contains(&Aabb3d::new(Vec3::new(1. + 1e-5, 0., 0.), Vec3::ZERO)));
+    }
+
+    #[test]
+    fn merge() {
+        let a = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        let b = Aabb3d::new(Vec3::new(2., 1., 0.), Vec3::splat(0.5));
+        let merged = a.merge(&b);
+        assert_eq!(merged.min, Vec3::new(-1., -1., -1.));
+        assert_eq!(merged.max, Vec3::new(2.5, 1.5, 1.));
+        assert!(merged.contains(&a));
+        assert!(merged.contains(&b));
+    }
+
+    #[test]
+    fn grow_and_shrink() {
+        let a = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        let grown = a.grow(Vec3::new(1., 0.5, 0.));
+        assert_eq!(grown.half_size(), Vec3::new(2., 1.5, 1.));
+        assert_eq!(grown.shrink(Vec3::new(1., 0.5, 0.)), a);
+        assert_eq!(a.shrink(Vec3::ONE).half_size(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn closest_point() {
+        let aabb = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        assert_eq!(
+            aabb.closest_point(Vec3::new(3., 0.5, 0.)),
+            Vec3::new(1., 0.5, 0.)
+        );
+        assert_eq!(aabb.closest_point(Vec3::splat(-2.)), Vec3::NEG_ONE);
+        assert_eq!(aabb.closest_point(Vec3::splat(0.5)), Vec3::splat(0.5));
+    }
+
+    #[test]
+    fn intersects_aabb() {
+        let a = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        assert!(a.intersects(&Aabb3d::new(Vec3::new(1.5, 1.5, 0.), Vec3::ONE)));
+        // Touching
+        assert!(a.intersects(&Aabb3d::new(Vec3::new(2., 0., 0.), Vec3::ONE)));
+        assert!(!a.intersects(&Aabb3d::new(Vec3::new(2. + 1e-5, 0., 0.), Vec3::ONE)));
+        // Degenerate boxes
+        let point = Aabb3d::new(Vec3::new(1., 0., 0.), Vec3::ZERO);
+        assert!(a.intersects(&point));
+        assert!(point.intersects(&point));
+        assert!(!point.intersects(&Aabb3d::new(Vec3::new(1., 0., 1e-5), Vec3::ZERO)));
+    }
+
+    #[test]
+    fn intersects_sphere() {
+        let aabb = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        assert!(aabb.intersects(&BoundingSphere::new(Vec3::new(2., 0., 0.), 1.)));
+        assert!(!aabb.intersects(&BoundingSphere::new(Vec3::new(2. + 1e-5, 0., 0.), 1.)));
+        // The corner is further than the sides
+        assert!(!aabb.intersects(&BoundingSphere::new(Vec3::splat(1.6), 1.)));
+        assert!(aabb.intersects(&BoundingSphere::new(Vec3::splat(1.55), 1.)));
+        // Zero radius sphere inside the box
+        assert!(aabb.intersects(&BoundingSphere::new(Vec3::splat(0.5), 0.)));
+        assert!(BoundingSphere::new(Vec3::splat(0.5), 0.).intersects(&aabb));
+    }
+}
+
+#[cfg(test)]
+mod bounding_sphere_tests {
+    use super::*;
+
+    #[test]
+    fn from_point_cloud() {
+        let sphere = BoundingSphere::from_point_cloud(&[
+            Vec3::new(-1., 0., 0.),
+            Vec3::new(3., 0., 0.),
+            Vec3::new(1., 1., 0.),
+        ]);
+        assert_eq!(sphere.center, Vec3::new(1., 0.5, 0.));
+        for point in [
+            Vec3::new(-1., 0., 0.),
+            Vec3::new(3., 0., 0.),
+            Vec3::new(1., 1., 0.),
+        ] {
+            assert!(sphere.center.distance(point) <= sphere.radius + 1e-5);
+        }
+
+        let point = BoundingSphere::from_point_cloud(&[Vec3::ONE]);
+        assert_eq!(point, BoundingSphere::new(Vec3::ONE, 0.));
+    }
+
+    #[test]
+    fn aabb_3d() {
+        let aabb = BoundingSphere::new(Vec3::new(1., 2., 3.), 0.5).aabb_3d();
+        assert_eq!(aabb.min, Vec3::new(0.5, 1.5, 2.5));
+        assert_eq!(aabb.max, Vec3::new(1.5, 2.5, 3.5));
+    }
+
+    #[test]
+    fn contains() {
+        let a = BoundingSphere::new(Vec3::ZERO, 2.);
+        assert!(a.contains(&a));
+        assert!(a.contains(&BoundingSphere::new(Vec3::new(1., 0., 0.), 1.)));
+        assert!(!a.contains(&BoundingSphere::new(Vec3::new(1. + 1e-5, 0., 0.), 1.)));
+        assert!(!a.contains(&BoundingSphere::new(Vec3::ZERO, 3.)));
+        // Degenerate spheres
+        assert!(a.contains(&BoundingSphere::new(Vec3::new(0., 2., 0.), 0.)));
+        let point = BoundingSphere::new(Vec3::ZERO, 0.);
+        assert!(point.contains(&point));
+        assert!(!point.contains(&a));
+    }
+
+    #[test]
+    fn merge() {
+        let a = BoundingSphere::new(Vec3::ZERO, 1.);
+        let b = BoundingSphere::new(Vec3::new(4., 0., 0.), 1.);
+        let merged = a.merge(&b);
+        assert!(merged.center.abs_diff_eq(Vec3::new(2., 0., 0.), 1e-5));
+        assert!((merged.radius - 3.).abs() < 1e-5);
+        assert_eq!(a.merge(&BoundingSphere::new(Vec3::X, 3.)).radius, 3.);
+        assert_eq!(a.merge(&BoundingSphere::new(Vec3::ZERO, 0.)), a);
+        // Merging with itself or a concentric sphere doesn't divide by zero.
+        assert_eq!(a.merge(&a), a);
+    }
+
+    #[test]
+    fn grow_and_shrink() {
+        let a = BoundingSphere::new(Vec3::ONE, 1.);
+        assert_eq!(a.grow(1.5), BoundingSphere::new(Vec3::ONE, 2.5));
+        assert_eq!(a.grow(1.5).shrink(1.5), a);
+        assert_eq!(a.shrink(1.).radius, 0.);
+        assert_eq!(a.half_size(), 1.);
+        assert!((a.visible_area() - 2. * std::f32::consts::PI).abs() < 1e-5);
+    }
+
+    #[test]
+    fn closest_point() {
+        let sphere = BoundingSphere::new(Vec3::ZERO, 1.);
+        assert_eq!(sphere.closest_point(Vec3::new(3., 0., 0.)), Vec3::X);
+        assert_eq!(sphere.closest_point(Vec3::splat(0.5)), Vec3::splat(0.5));
+    }
+
+    #[test]
+    fn intersects_sphere() {
+        let a = BoundingSphere::new(Vec3::ZERO, 1.);
+        assert!(a.intersects(&BoundingSphere::new(Vec3::new(1.5, 0., 0.), 1.)));
+        // Touching
+        assert!(a.intersects(&BoundingSphere::new(Vec3::new(0., 2., 0.), 1.)));
+        assert!(!a.intersects(&BoundingSphere::new(Vec3::new(0., 2. + 1e-5, 0.), 1.)));
+        // Degenerate spheres
+        let point = BoundingSphere::new(Vec3::new(0., 1., 0.), 0.);
+        assert!(a.intersects(&point));
+        assert!(point.intersects(&point));
+        assert!(!point.intersects(&BoundingSphere::new(Vec3::new(1e-5, 1., 0.), 0.)));
+    }
+}
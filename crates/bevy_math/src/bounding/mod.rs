@@ -0,0 +1,69 @@
+//! This module contains bounding volumes and intersection tests between them.
+//!
+//! Bounding volumes are simple shapes enclosing more complex ones, which are cheap to test for
+//! overlap. The 2D volumes are [`Aabb2d`] and [`BoundingCircle`], and the 3D ones are [`Aabb3d`]
+//! and [`BoundingSphere`].
+//!
+//! Volumes can be tested against each other with [`IntersectsVolume`], and against rays and
+//! moving volumes with casts like [`RayCast2d`] and [`BoundingSphereCast`], which return the
+//! distance at which they first hit a volume.
+
+mod bounded2d;
+mod bounded3d;
+mod raycast2d;
+mod raycast3d;
+
+pub use bounded2d::*;
+pub use bounded3d::*;
+pub use raycast2d::*;
+pub use raycast3d::*;
+
+/// A trait that generalizes different bounding volumes.
+///
+/// Bounding volumes are simplified shapes that are used to get simpler ways to check for
+/// overlapping elements or finding intersections.
+pub trait BoundingVolume: Sized {
+    /// The position type used for the volume. This should be `Vec2` for 2D and `Vec3` for 3D.
+    type Position: Clone + Copy + PartialEq;
+
+    /// The type used for the size of the volume, when it grows or shrinks. This should be `f32`
+    /// for circles and spheres, and the position type for axis-aligned bounding boxes.
+    type HalfSize;
+
+    /// Returns the center of the bounding volume.
+    fn center(&self) -> Self::Position;
+
+    /// Returns the half size of the bounding volume.
+    fn half_size(&self) -> Self::HalfSize;
+
+    /// Computes the visible surface area of the bounding volume.
+    ///
+    /// This method can be useful to make decisions about merging bounding volumes, using a
+    /// Surface Area Heuristic.
+    ///
+    /// For 2D shapes this would simply be the area of the shape. For 3D shapes this would
+    /// usually be half the area of the shape.
+    fn visible_area(&self) -> f32;
+
+    /// Checks if this bounding volume contains another one.
+    fn contains(&self, other: &Self) -> bool;
+
+    /// Computes the smallest bounding volume that contains both `self` and `other`.
+    fn merge(&self, other: &Self) -> Self;
+
+    /// Increases the size of the bounding volume in each direction by the given amount.
+    fn grow(&self, amount: Self::HalfSize) -> Self;
+
+    /// Decreases the size of the bounding volume in each direction by the given amount.
+    ///
+    /// The amount should not be larger than the half size of the volume.
+    fn shrink(&self, amount: Self::HalfSize) -> Self;
+}
+
+/// A trait that generalizes intersection tests against a volume.
+///
+/// Volumes that touch are considered to intersect.
+pub trait IntersectsVolume<Volume: BoundingVolume> {
+    /// Checks if a volume intersects this one.
+    fn intersects(&self, volume: &Volume) -> bool;
+}
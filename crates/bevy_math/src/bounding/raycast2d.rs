@@ -0,0 +1,611 @@
+use super::{Aabb2d, BoundingCircle, IntersectsVolume};
+use crate::{Ray2d, Vec2};
+
+/// A raycast intersection test for 2D bounding volumes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayCast2d {
+    /// The origin of the ray.
+    pub origin: Vec2,
+    /// The normalized direction of the ray.
+    direction: Vec2,
+    /// The maximum distance for the ray.
+    pub max: f32,
+    /// The multiplicative inverse direction of the ray.
+    direction_recip: Vec2,
+}
+
+impl RayCast2d {
+    /// Constructs a [`RayCast2d`] from an origin, a direction, and a max distance.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `direction` can't be normalized.
+    pub fn new(origin: Vec2, direction: Vec2, max: f32) -> Self {
+        let direction = direction
+            .try_normalize()
+            .expect("the direction of a ray cast must be a non-zero, finite vector");
+        Self {
+            origin,
+            direction,
+            max,
+            direction_recip: direction.recip(),
+        }
+    }
+
+    /// Constructs a [`RayCast2d`] from a [`Ray2d`] and a max distance.
+    pub fn from_ray(ray: Ray2d, max: f32) -> Self {
+        Self::new(ray.origin, *ray.direction, max)
+    }
+
+    /// Returns the normalized direction of the ray.
+    #[inline]
+    pub fn direction(&self) -> Vec2 {
+        self.direction
+    }
+
+    /// Returns the multiplicative inverse direction of the ray.
+    #[inline]
+    pub fn direction_recip(&self) -> Vec2 {
+        self.direction_recip
+    }
+
+    /// Gets the distance of an intersection with an [`Aabb2d`], if any.
+    ///
+    /// Returns `Some(0.)` if the origin of the ray is inside the AABB.
+    pub fn aabb_intersection_at(&self, aabb: &Aabb2d) -> Option<f32> {
+        let (min_x, max_x) = if self.direction.x.is_sign_positive() {
+            (aabb.min.x, aabb.max.x)
+        } else {
+            (aabb.max.x, aabb.min.x)
+        };
+        let (min_y, max_y) = if self.direction.y.is_sign_positive() {
+            (aabb.min.y, aabb.max.y)
+        } else {
+            (aabb.max.y, aabb.min.y)
+        };
+
+        // Calculate the minimum/maximum time for each axis based on how much the direction goes
+        // that way. These values can get arbitrarily large, or even become NaN, which is handled
+        // by the min/max operations below
+        let tmin_x = (min_x - self.origin.x) * self.direction_recip.x;
+        let tmin_y = (min_y - self.origin.y) * self.direction_recip.y;
+        let tmax_x = (max_x - self.origin.x) * self.direction_recip.x;
+        let tmax_y = (max_y - self.origin.y) * self.direction_recip.y;
+
+        // An axis that is not relevant to the ray direction will be NaN. When one of the
+        // arguments to min/max is NaN, the other argument is used.
+        // An axis for which the direction is the wrong way will return an arbitrarily large
+        // negative value.
+        let tmin = tmin_x.max(tmin_y).max(0.);
+        let tmax = tmax_y.min(tmax_x).min(self.max);
+
+        if tmin <= tmax {
+            Some(tmin)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the distance of an intersection with a [`BoundingCircle`], if any.
+    ///
+    /// Returns `Some(0.)` if the origin of the ray is inside the circle.
+    pub fn circle_intersection_at(&self, circle: &BoundingCircle) -> Option<f32> {
+        let offset = self.origin - circle.center;
+        let projected = offset.dot(self.direction);
+        let closest_point = offset - projected * self.direction;
+        let distance_squared = circle.radius * circle.radius - closest_point.length_squared();
+        if distance_squared < 0. || projected * projected.abs() > distance_squared {
+            // The ray misses the circle, or the circle is behind the origin of the ray
+            return None;
+        }
+        let distance = (-projected - distance_squared.sqrt()).max(0.);
+        (distance <= self.max).then_some(distance)
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for RayCast2d {
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        self.aabb_intersection_at(volume).is_some()
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for RayCast2d {
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        self.circle_intersection_at(volume).is_some()
+    }
+}
+
+/// An intersection test that casts an [`Aabb2d`] along a ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AabbCast2d {
+    /// The ray along which to cast the bounding volume.
+    pub ray: RayCast2d,
+    /// The AABB that is being cast, relative to the origin of the ray.
+    pub aabb: Aabb2d,
+}
+
+impl AabbCast2d {
+    /// Constructs an [`AabbCast2d`] from an [`Aabb2d`], an origin, a direction, and a max
+    /// distance.
+    ///
+    /// The AABB is relative to `origin`, so an AABB centered on zero is cast from `origin`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `direction` can't be normalized.
+    pub fn new(aabb: Aabb2d, origin: Vec2, direction: Vec2, max: f32) -> Self {
+        Self {
+            ray: RayCast2d::new(origin, direction, max),
+            aabb,
+        }
+    }
+
+    /// Gets the distance at which the cast AABB first touches the given [`Aabb2d`], if any.
+    ///
+    /// Returns `Some(0.)` if they already intersect at the origin of the ray.
+    pub fn aabb_collision_at(&self, mut aabb: Aabb2d) -> Option<f32> {
+        // Cast a ray against the Minkowski sum of both AABBs
+        aabb.min -= self.aabb.max;
+        aabb.max -= self.aabb.min;
+        self.ray.aabb_intersection_at(&aabb)
+    }
+}
+
+impl IntersectsVolume<Aabb2d> for AabbCast2d {
+    fn intersects(&self, volume: &Aabb2d) -> bool {
+        self.aabb_collision_at(*volume).is_some()
+    }
+}
+
+/// An intersection test that casts a [`BoundingCircle`] along a ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingCircleCast {
+    /// The ray along which to cast the bounding volume.
+    pub ray: RayCast2d,
+    /// The circle that is being cast, relative to the origin of the ray.
+    pub circle: BoundingCircle,
+}
+
+impl BoundingCircleCast {
+    /// Constructs a [`BoundingCircleCast`] from a [`BoundingCircle`], an origin, a direction,
+    /// and a max distance.
+    ///
+    /// The circle is relative to `origin`, so a circle centered on zero is cast from `origin`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `direction` can't be normalized.
+    pub fn new(circle: BoundingCircle, origin: Vec2, direction: Vec2, max: f32) -> Self {
+        Self {
+            ray: RayCast2d::new(origin, direction, max),
+            circle,
+        }
+    }
+
+    /// Gets the distance at which the cast circle first touches the given [`BoundingCircle`],
+    /// if any.
+    ///
+    /// Returns `Some(0.)` if they already intersect at the origin of the ray.
+    pub fn circle_collision_at(&self, mut circle: BoundingCircle) -> Option<f32> {
+        // Cast a ray against the Minkowski sum of both circles
+        circle.center -= self.circle.center;
+        circle.radius += self.circle.radius;
+        self.ray.circle_intersection_at(&circle)
+    }
+}
+
+impl IntersectsVolume<BoundingCircle> for BoundingCircleCast {
+    fn intersects(&self, volume: &BoundingCircle) -> bool {
+        self.circle_collision_at(*volume).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.001;
+
+    #[test]
+    fn ray_cast_circle() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of a centered circle
+                RayCast2d::new(Vec2::Y * -5., Vec2::Y, 90.),
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                4.,
+            ),
+            (
+                // Hit the center of a centered circle, but from the other side
+                RayCast2d::new(Vec2::Y * 5., -Vec2::Y, 90.),
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                4.,
+            ),
+            (
+                // Hit the center of an offset circle
+                RayCast2d::new(Vec2::ZERO, Vec2::Y, 90.),
+                BoundingCircle::new(Vec2::Y * 3., 2.),
+                1.,
+            ),
+            (
+                // Just barely hit the circle before the max distance
+                RayCast2d::new(Vec2::X, Vec2::Y, 1.),
+                BoundingCircle::new(Vec2::ONE, 0.01),
+                0.99,
+            ),
+            (
+                // Hit a circle off-center
+                RayCast2d::new(Vec2::X, Vec2::Y, 90.),
+                BoundingCircle::new(Vec2::Y * 5., 2.),
+                3.268,
+            ),
+            (
+                // Barely hit a circle on the side
+                RayCast2d::new(Vec2::X * 0.99999, Vec2::Y, 90.),
+                BoundingCircle::new(Vec2::Y * 5., 1.),
+                4.996,
+            ),
+            (
+                // Hit a point
+                RayCast2d::new(Vec2::ZERO, Vec2::X, 90.),
+                BoundingCircle::new(Vec2::X * 2., 0.),
+                2.,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.circle_intersection_at(volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast2d::new(test.origin, -test.direction, test.max);
+            assert!(!inverted_ray.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn ray_cast_circle_misses() {
+        let volume = BoundingCircle::new(Vec2::new(2., 0.), 1.);
+        for ray in &[
+            // The ray doesn't go in the right direction
+            RayCast2d::new(Vec2::ZERO, Vec2::Y, 90.),
+            // The ray doesn't go far enough
+            RayCast2d::new(Vec2::ZERO, Vec2::X, 0.9),
+            // The ray misses by a small margin
+            RayCast2d::new(Vec2::new(0., 1.001), Vec2::X, 90.),
+            // The ray starts after the circle
+            RayCast2d::new(Vec2::new(3.001, 0.), Vec2::X, 90.),
+        ] {
+            assert!(!ray.intersects(&volume), "Case:\n  Ray: {:?}", ray);
+        }
+        // A point next to the ray
+        let ray = RayCast2d::new(Vec2::ZERO, Vec2::X, 90.);
+        assert!(!ray.intersects(&BoundingCircle::new(Vec2::new(1., 0.001), 0.)));
+    }
+
+    #[test]
+    fn ray_cast_circle_inside() {
+        let volume = BoundingCircle::new(Vec2::splat(0.5), 1.);
+        for origin in &[Vec2::X, Vec2::Y, Vec2::ONE, Vec2::ZERO] {
+            for direction in &[Vec2::X, Vec2::Y, -Vec2::X, -Vec2::Y] {
+                for max in &[0., 1., 900.] {
+                    let test = RayCast2d::new(*origin, *direction, *max);
+                    let case = format!(
+                        "Case:\n - origin: {:?}\n - Direction: {:?}\n - Max: {}",
+                        origin, direction, max,
+                    );
+                    assert!(test.intersects(&volume), "{}", case);
+                    assert_eq!(test.circle_intersection_at(&volume), Some(0.), "{}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ray_cast_aabb() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of a centered aabb
+                RayCast2d::new(Vec2::Y * -5., Vec2::Y, 90.),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                4.,
+            ),
+            (
+                // Hit the center of a centered aabb, but from the other side
+                RayCast2d::new(Vec2::Y * 5., -Vec2::Y, 90.),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                4.,
+            ),
+            (
+                // Hit the center of an offset aabb
+                RayCast2d::new(Vec2::ZERO, Vec2::Y, 90.),
+                Aabb2d::new(Vec2::Y * 3., Vec2::splat(2.)),
+                1.,
+            ),
+            (
+                // Just barely hit the aabb before the max distance
+                RayCast2d::new(Vec2::X, Vec2::Y, 1.),
+                Aabb2d::new(Vec2::ONE, Vec2::splat(0.01)),
+                0.99,
+            ),
+            (
+                // Hit an aabb off-center
+                RayCast2d::new(Vec2::X, Vec2::Y, 90.),
+                Aabb2d::new(Vec2::Y * 5., Vec2::splat(2.)),
+                3.,
+            ),
+            (
+                // Hit an aabb diagonally
+                RayCast2d::new(Vec2::X * -0.001, Vec2::ONE, 90.),
+                Aabb2d::new(Vec2::Y * 2., Vec2::ONE),
+                1.414,
+            ),
+            (
+                // Hit a degenerate aabb with no width
+                RayCast2d::new(Vec2::ZERO, Vec2::X, 90.),
+                Aabb2d::new(Vec2::X * 2., Vec2::new(0., 1.)),
+                2.,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.aabb_intersection_at(volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast2d::new(test.origin, -test.direction, test.max);
+            assert!(!inverted_ray.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn ray_cast_aabb_misses() {
+        let volume = Aabb2d::new(Vec2::new(2., 0.), Vec2::ONE);
+        for ray in &[
+            // The ray doesn't go in the right direction
+            RayCast2d::new(Vec2::ZERO, Vec2::Y, 90.),
+            // The ray doesn't go far enough
+            RayCast2d::new(Vec2::ZERO, Vec2::X, 0.999),
+            // The ray misses by a small margin
+            RayCast2d::new(Vec2::new(0., 1.001), Vec2::X, 90.),
+            // The ray starts after the aabb
+            RayCast2d::new(Vec2::new(3.001, 0.), Vec2::X, 90.),
+            // The ray misses the corner by a small margin
+            RayCast2d::new(Vec2::new(0., 0.999), Vec2::new(1., 1.), 90.),
+        ] {
+            assert!(!ray.intersects(&volume), "Case:\n  Ray: {:?}", ray);
+        }
+        // A degenerate aabb next to the ray
+        let ray = RayCast2d::new(Vec2::ZERO, Vec2::X, 90.);
+        assert!(!ray.intersects(&Aabb2d::new(Vec2::new(1., 0.001), Vec2::ZERO)));
+    }
+
+    #[test]
+    fn ray_cast_aabb_inside() {
+        let volume = Aabb2d::new(Vec2::splat(0.5), Vec2::ONE);
+        for origin in &[Vec2::X, Vec2::Y, Vec2::ONE, Vec2::ZERO] {
+            for direction in &[Vec2::X, Vec2::Y, -Vec2::X, -Vec2::Y] {
+                for max in &[0., 1., 900.] {
+                    let test = RayCast2d::new(*origin, *direction, *max);
+                    let case = format!(
+                        "Case:\n - origin: {:?}\n - Direction: {:?}\n - Max: {}",
+                        origin, direction, max,
+                    );
+                    assert!(test.intersects(&volume), "{}", case);
+                    assert_eq!(test.aabb_intersection_at(&volume), Some(0.), "{}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ray_cast_from_ray() {
+        let ray = Ray2d::new(Vec2::new(0., -5.), Vec2::Y * 2.);
+        let test = RayCast2d::from_ray(ray, 90.);
+        assert_eq!(test.direction(), Vec2::Y);
+        let volume = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        assert_eq!(test.aabb_intersection_at(&volume), Some(4.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ray_cast_zero_direction() {
+        RayCast2d::new(Vec2::ZERO, Vec2::ZERO, 1.);
+    }
+
+    #[test]
+    fn aabb_cast() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of the aabb, that has a point in the center
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                    Vec2::Y * -5.,
+                    Vec2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                3.,
+            ),
+            (
+                // Hit the aabb, that has an offset in the cast
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::X * 3., Vec2::ONE),
+                    Vec2::new(-3., -5.),
+                    Vec2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                3.,
+            ),
+            (
+                // Hit the aabb with a degenerate cast
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::ZERO),
+                    Vec2::Y * -5.,
+                    Vec2::Y,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                4.,
+            ),
+            (
+                // Hit the side of the aabb
+                AabbCast2d::new(
+                    Aabb2d::new(Vec2::ZERO, Vec2::splat(0.5)),
+                    Vec2::new(-4., 1.4),
+                    Vec2::X,
+                    90.,
+                ),
+                Aabb2d::new(Vec2::ZERO, Vec2::ONE),
+                2.5,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.aabb_collision_at(*volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast2d::new(test.ray.origin, -test.ray.direction, test.ray.max);
+            let inverted = AabbCast2d {
+                ray: inverted_ray,
+                ..*test
+            };
+            assert!(!inverted.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn aabb_cast_misses() {
+        let volume = Aabb2d::new(Vec2::ZERO, Vec2::ONE);
+        // The cast aabb passes next to the volume by a small margin
+        let test = AabbCast2d::new(
+            Aabb2d::new(Vec2::ZERO, Vec2::splat(0.5)),
+            Vec2::new(-4., 1.501),
+            Vec2::X,
+            90.,
+        );
+        assert!(!test.intersects(&volume));
+        // The cast aabb stops short of the volume
+        let test = AabbCast2d::new(
+            Aabb2d::new(Vec2::ZERO, Vec2::splat(0.5)),
+            Vec2::new(-4., 0.),
+            Vec2::X,
+            2.499,
+        );
+        assert!(!test.intersects(&volume));
+    }
+
+    #[test]
+    fn circle_cast() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of the bounding circle
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 0.5),
+                    Vec2::Y * -5.,
+                    Vec2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                3.5,
+            ),
+            (
+                // Hit the bounding circle off-center, that has an offset in the cast
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::X * -1.5, 1.),
+                    Vec2::X * 1.5 + Vec2::Y * -5.,
+                    Vec2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                3.,
+            ),
+            (
+                // Hit the bounding circle with a degenerate cast
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 0.),
+                    Vec2::Y * -5.,
+                    Vec2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                4.,
+            ),
+            (
+                // Barely graze the bounding circle
+                BoundingCircleCast::new(
+                    BoundingCircle::new(Vec2::ZERO, 0.5),
+                    Vec2::new(1.499, -5.),
+                    Vec2::Y,
+                    90.,
+                ),
+                BoundingCircle::new(Vec2::ZERO, 1.),
+                4.945,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.circle_collision_at(*volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast2d::new(test.ray.origin, -test.ray.direction, test.ray.max);
+            let inverted = BoundingCircleCast {
+                ray: inverted_ray,
+                ..*test
+            };
+            assert!(!inverted.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn circle_cast_misses() {
+        let volume = BoundingCircle::new(Vec2::ZERO, 1.);
+        // The cast circle passes next to the volume by a small margin
+        let test = BoundingCircleCast::new(
+            BoundingCircle::new(Vec2::ZERO, 0.5),
+            Vec2::new(1.501, -5.),
+            Vec2::Y,
+            90.,
+        );
+        assert!(!test.intersects(&volume));
+        // The cast circle stops short of the volume
+        let test = BoundingCircleCast::new(
+            BoundingCircle::new(Vec2::ZERO, 0.5),
+            Vec2::new(0., -5.),
+            Vec2::Y,
+            3.499,
+        );
+        assert!(!test.intersects(&volume));
+    }
+}
@@ -0,0 +1,618 @@
+use super::{Aabb3d, BoundingSphere, IntersectsVolume};
+use crate::{Ray3d, Vec3};
+
+/// A raycast intersection test for 3D bounding volumes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayCast3d {
+    /// The origin of the ray.
+    pub origin: Vec3,
+    /// The normalized direction of the ray.
+    direction: Vec3,
+    /// The maximum distance for the ray.
+    pub max: f32,
+    /// The multiplicative inverse direction of the ray.
+    direction_recip: Vec3,
+}
+
+impl RayCast3d {
+    /// Constructs a [`RayCast3d`] from an origin, a direction, and a max distance.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `direction` can't be normalized.
+    pub fn new(origin: Vec3, direction: Vec3, max: f32) -> Self {
+        let direction = direction
+            .try_normalize()
+            .expect("the direction of a ray cast must be a non-zero, finite vector");
+        Self {
+            origin,
+            direction,
+            max,
+            direction_recip: direction.recip(),
+        }
+    }
+
+    /// Constructs a [`RayCast3d`] from a [`Ray3d`] and a max distance.
+    pub fn from_ray(ray: Ray3d, max: f32) -> Self {
+        Self::new(ray.origin, *ray.direction, max)
+    }
+
+    /// Returns the normalized direction of the ray.
+    #[inline]
+    pub fn direction(&self) -> Vec3 {
+        self.direction
+    }
+
+    /// Returns the multiplicative inverse direction of the ray.
+    #[inline]
+    pub fn direction_recip(&self) -> Vec3 {
+        self.direction_recip
+    }
+
+    /// Gets the distance of an intersection with an [`Aabb3d`], if any.
+    ///
+    /// Returns `Some(0.)` if the origin of the ray is inside the AABB.
+    pub fn aabb_intersection_at(&self, aabb: &Aabb3d) -> Option<f32> {
+        let (min_x, max_x) = if self.direction.x.is_sign_positive() {
+            (aabb.min.x, aabb.max.x)
+        } else {
+            (aabb.max.x, aabb.min.x)
+        };
+        let (min_y, max_y) = if self.direction.y.is_sign_positive() {
+            (aabb.min.y, aabb.max.y)
+        } else {
+            (aabb.max.y, aabb.min.y)
+        };
+        let (min_z, max_z) = if self.direction.z.is_sign_positive() {
+            (aabb.min.z, aabb.max.z)
+        } else {
+            (aabb.max.z, aabb.min.z)
+        };
+
+        // Calculate the minimum/maximum time for each axis based on how much the direction goes
+        // that way. These values can get arbitrarily large, or even become NaN, which is handled
+        // by the min/max operations below
+        let tmin_x = (min_x - self.origin.x) * self.direction_recip.x;
+        let tmin_y = (min_y - self.origin.y) * self.direction_recip.y;
+        let tmin_z = (min_z - self.origin.z) * self.direction_recip.z;
+        let tmax_x = (max_x - self.origin.x) * self.direction_recip.x;
+        let tmax_y = (max_y - self.origin.y) * self.direction_recip.y;
+        let tmax_z = (max_z - self.origin.z) * self.direction_recip.z;
+
+        // An axis that is not relevant to the ray direction will be NaN. When one of the
+        // arguments to min/max is NaN, the other argument is used.
+        // An axis for which the direction is the wrong way will return an arbitrarily large
+        // negative value.
+        let tmin = tmin_x.max(tmin_y).max(tmin_z).max(0.);
+        let tmax = tmax_z.min(tmax_y).min(tmax_x).min(self.max);
+
+        if tmin <= tmax {
+            Some(tmin)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the distance of an intersection with a [`BoundingSphere`], if any.
+    ///
+    /// Returns `Some(0.)` if the origin of the ray is inside the sphere.
+    pub fn sphere_intersection_at(&self, sphere: &BoundingSphere) -> Option<f32> {
+        let offset = self.origin - sphere.center;
+        let projected = offset.dot(self.direction);
+        let closest_point = offset - projected * self.direction;
+        let distance_squared = sphere.radius * sphere.radius - closest_point.length_squared();
+        if distance_squared < 0. || projected * projected.abs() > distance_squared {
+            // The ray misses the sphere, or the sphere is behind the origin of the ray
+            return None;
+        }
+        let distance = (-projected - distance_squared.sqrt()).max(0.);
+        (distance <= self.max).then_some(distance)
+    }
+}
+
+impl IntersectsVolume<Aabb3d> for RayCast3d {
+    fn intersects(&self, volume: &Aabb3d) -> bool {
+        self.aabb_intersection_at(volume).is_some()
+    }
+}
+
+impl IntersectsVolume<BoundingSphere> for RayCast3d {
+    fn intersects(&self, volume: &BoundingSphere) -> bool {
+        self.sphere_intersection_at(volume).is_some()
+    }
+}
+
+/// An intersection test that casts an [`Aabb3d`] along a ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AabbCast3d {
+    /// The ray along which to cast the bounding volume.
+    pub ray: RayCast3d,
+    /// The AABB that is being cast, relative to the origin of the ray.
+    pub aabb: Aabb3d,
+}
+
+impl AabbCast3d {
+    /// Constructs an [`AabbCast3d`] from an [`Aabb3d`], an origin, a direction, and a max
+    /// distance.
+    ///
+    /// The AABB is relative to `origin`, so an AABB centered on zero is cast from `origin`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `direction` can't be normalized.
+    pub fn new(aabb: Aabb3d, origin: Vec3, direction: Vec3, max: f32) -> Self {
+        Self {
+            ray: RayCast3d::new(origin, direction, max),
+            aabb,
+        }
+    }
+
+    /// Gets the distance at which the cast AABB first touches the given [`Aabb3d`], if any.
+    ///
+    /// Returns `Some(0.)` if they already intersect at the origin of the ray.
+    pub fn aabb_collision_at(&self, mut aabb: Aabb3d) -> Option<f32> {
+        // Cast a ray against the Minkowski sum of both AABBs
+        aabb.min -= self.aabb.max;
+        aabb.max -= self.aabb.min;
+        self.ray.aabb_intersection_at(&aabb)
+    }
+}
+
+impl IntersectsVolume<Aabb3d> for AabbCast3d {
+    fn intersects(&self, volume: &Aabb3d) -> bool {
+        self.aabb_collision_at(*volume).is_some()
+    }
+}
+
+/// An intersection test that casts a [`BoundingSphere`] along a ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingSphereCast {
+    /// The ray along which to cast the bounding volume.
+    pub ray: RayCast3d,
+    /// The sphere that is being cast, relative to the origin of the ray.
+    pub sphere: BoundingSphere,
+}
+
+impl BoundingSphereCast {
+    /// Constructs a [`BoundingSphereCast`] from a [`BoundingSphere`], an origin, a direction,
+    /// and a max distance.
+    ///
+    /// The sphere is relative to `origin`, so a sphere centered on zero is cast from `origin`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `direction` can't be normalized.
+    pub fn new(sphere: BoundingSphere, origin: Vec3, direction: Vec3, max: f32) -> Self {
+        Self {
+            ray: RayCast3d::new(origin, direction, max),
+            sphere,
+        }
+    }
+
+    /// Gets the distance at which the cast sphere first touches the given [`BoundingSphere`],
+    /// if any.
+    ///
+    /// Returns `Some(0.)` if they already intersect at the origin of the ray.
+    pub fn sphere_collision_at(&self, mut sphere: BoundingSphere) -> Option<f32> {
+        // Cast a ray against the Minkowski sum of both spheres
+        sphere.center -= self.sphere.center;
+        sphere.radius += self.sphere.radius;
+        self.ray.sphere_intersection_at(&sphere)
+    }
+}
+
+impl IntersectsVolume<BoundingSphere> for BoundingSphereCast {
+    fn intersects(&self, volume: &BoundingSphere) -> bool {
+        self.sphere_collision_at(*volume).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.001;
+
+    #[test]
+    fn ray_cast_sphere() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of a centered sphere
+                RayCast3d::new(Vec3::Y * -5., Vec3::Y, 90.),
+                BoundingSphere::new(Vec3::ZERO, 1.),
+                4.,
+            ),
+            (
+                // Hit the center of a centered sphere, but from the other side
+                RayCast3d::new(Vec3::Y * 5., -Vec3::Y, 90.),
+                BoundingSphere::new(Vec3::ZERO, 1.),
+                4.,
+            ),
+            (
+                // Hit the center of an offset sphere
+                RayCast3d::new(Vec3::ZERO, Vec3::Y, 90.),
+                BoundingSphere::new(Vec3::Y * 3., 2.),
+                1.,
+            ),
+            (
+                // Just barely hit the sphere before the max distance
+                RayCast3d::new(Vec3::X, Vec3::Y, 1.),
+                BoundingSphere::new(Vec3::new(1., 1., 0.), 0.01),
+                0.99,
+            ),
+            (
+                // Hit a sphere off-center
+                RayCast3d::new(Vec3::X, Vec3::Y, 90.),
+                BoundingSphere::new(Vec3::Y * 5., 2.),
+                3.268,
+            ),
+            (
+                // Barely hit a sphere on the side
+                RayCast3d::new(Vec3::X * 0.99999, Vec3::Y, 90.),
+                BoundingSphere::new(Vec3::Y * 5., 1.),
+                4.996,
+            ),
+            (
+                // Hit a point
+                RayCast3d::new(Vec3::ZERO, Vec3::X, 90.),
+                BoundingSphere::new(Vec3::X * 2., 0.),
+                2.,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.sphere_intersection_at(volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast3d::new(test.origin, -test.direction, test.max);
+            assert!(!inverted_ray.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn ray_cast_sphere_misses() {
+        let volume = BoundingSphere::new(Vec3::new(2., 0., 0.), 1.);
+        for ray in &[
+            // The ray doesn't go in the right direction
+            RayCast3d::new(Vec3::ZERO, Vec3::Y, 90.),
+            // The ray doesn't go far enough
+            RayCast3d::new(Vec3::ZERO, Vec3::X, 0.9),
+            // The ray misses by a small margin
+            RayCast3d::new(Vec3::new(0., 1.001, 0.), Vec3::X, 90.),
+            // The ray starts after the sphere
+            RayCast3d::new(Vec3::new(3.001, 0., 0.), Vec3::X, 90.),
+        ] {
+            assert!(!ray.intersects(&volume), "Case:\n  Ray: {:?}", ray);
+        }
+        // A point next to the ray
+        let ray = RayCast3d::new(Vec3::ZERO, Vec3::X, 90.);
+        assert!(!ray.intersects(&BoundingSphere::new(Vec3::new(1., 0.001, 0.), 0.)));
+    }
+
+    #[test]
+    fn ray_cast_sphere_inside() {
+        let volume = BoundingSphere::new(Vec3::splat(0.5), 1.);
+        for origin in &[Vec3::X, Vec3::Y, Vec3::ONE, Vec3::ZERO] {
+            for direction in &[Vec3::X, Vec3::Y, -Vec3::X, -Vec3::Y] {
+                for max in &[0., 1., 900.] {
+                    let test = RayCast3d::new(*origin, *direction, *max);
+                    let case = format!(
+                        "Case:\n - origin: {:?}\n - Direction: {:?}\n - Max: {}",
+                        origin, direction, max,
+                    );
+                    assert!(test.intersects(&volume), "{}", case);
+                    assert_eq!(test.sphere_intersection_at(&volume), Some(0.), "{}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ray_cast_aabb() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of a centered aabb
+                RayCast3d::new(Vec3::Y * -5., Vec3::Y, 90.),
+                Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                4.,
+            ),
+            (
+                // Hit the center of a centered aabb, but from the other side
+                RayCast3d::new(Vec3::Y * 5., -Vec3::Y, 90.),
+                Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                4.,
+            ),
+            (
+                // Hit the center of an offset aabb
+                RayCast3d::new(Vec3::ZERO, Vec3::Y, 90.),
+                Aabb3d::new(Vec3::Y * 3., Vec3::splat(2.)),
+                1.,
+            ),
+            (
+                // Just barely hit the aabb before the max distance
+                RayCast3d::new(Vec3::X, Vec3::Y, 1.),
+                Aabb3d::new(Vec3::new(1., 1., 0.), Vec3::splat(0.01)),
+                0.99,
+            ),
+            (
+                // Hit an aabb off-center
+                RayCast3d::new(Vec3::X, Vec3::Y, 90.),
+                Aabb3d::new(Vec3::Y * 5., Vec3::splat(2.)),
+                3.,
+            ),
+            (
+                // Hit an aabb diagonally
+                RayCast3d::new(Vec3::X * -0.001, Vec3::new(1., 1., 0.), 90.),
+                Aabb3d::new(Vec3::Y * 2., Vec3::ONE),
+                1.414,
+            ),
+            (
+                // Hit a degenerate aabb with no width
+                RayCast3d::new(Vec3::ZERO, Vec3::X, 90.),
+                Aabb3d::new(Vec3::X * 2., Vec3::new(0., 1., 0.)),
+                2.,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.aabb_intersection_at(volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast3d::new(test.origin, -test.direction, test.max);
+            assert!(!inverted_ray.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn ray_cast_aabb_misses() {
+        let volume = Aabb3d::new(Vec3::new(2., 0., 0.), Vec3::ONE);
+        for ray in &[
+            // The ray doesn't go in the right direction
+            RayCast3d::new(Vec3::ZERO, Vec3::Y, 90.),
+            // The ray doesn't go far enough
+            RayCast3d::new(Vec3::ZERO, Vec3::X, 0.999),
+            // The ray misses by a small margin
+            RayCast3d::new(Vec3::new(0., 1.001, 0.), Vec3::X, 90.),
+            // The ray starts after the aabb
+            RayCast3d::new(Vec3::new(3.001, 0., 0.), Vec3::X, 90.),
+            // The ray misses the corner by a small margin
+            RayCast3d::new(Vec3::new(0., 0.999, 0.), Vec3::new(1., 1., 0.), 90.),
+        ] {
+            assert!(!ray.intersects(&volume), "Case:\n  Ray: {:?}", ray);
+        }
+        // A degenerate aabb next to the ray
+        let ray = RayCast3d::new(Vec3::ZERO, Vec3::X, 90.);
+        assert!(!ray.intersects(&Aabb3d::new(Vec3::new(1., 0.001, 0.), Vec3::ZERO)));
+    }
+
+    #[test]
+    fn ray_cast_aabb_inside() {
+        let volume = Aabb3d::new(Vec3::splat(0.5), Vec3::ONE);
+        for origin in &[Vec3::X, Vec3::Y, Vec3::ONE, Vec3::ZERO] {
+            for direction in &[Vec3::X, Vec3::Y, -Vec3::X, -Vec3::Y] {
+                for max in &[0., 1., 900.] {
+                    let test = RayCast3d::new(*origin, *direction, *max);
+                    let case = format!(
+                        "Case:\n - origin: {:?}\n - Direction: {:?}\n - Max: {}",
+                        origin, direction, max,
+                    );
+                    assert!(test.intersects(&volume), "{}", case);
+                    assert_eq!(test.aabb_intersection_at(&volume), Some(0.), "{}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ray_cast_from_ray() {
+        let ray = Ray3d::new(Vec3::new(0., 0., -5.), Vec3::Z * 2.);
+        let test = RayCast3d::from_ray(ray, 90.);
+        assert_eq!(test.direction(), Vec3::Z);
+        let volume = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        assert_eq!(test.aabb_intersection_at(&volume), Some(4.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ray_cast_zero_direction() {
+        RayCast3d::new(Vec3::ZERO, Vec3::ZERO, 1.);
+    }
+
+    #[test]
+    fn aabb_cast() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of the aabb, that has a point in the center
+                AabbCast3d::new(
+                    Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                    Vec3::Y * -5.,
+                    Vec3::Y,
+                    90.,
+                ),
+                Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                3.,
+            ),
+            (
+                // Hit the aabb, that has an offset in the cast
+                AabbCast3d::new(
+                    Aabb3d::new(Vec3::X * 3., Vec3::ONE),
+                    Vec3::new(-3., -5., 0.),
+                    Vec3::Y,
+                    90.,
+                ),
+                Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                3.,
+            ),
+            (
+                // Hit the aabb with a degenerate cast
+                AabbCast3d::new(
+                    Aabb3d::new(Vec3::ZERO, Vec3::ZERO),
+                    Vec3::Y * -5.,
+                    Vec3::Y,
+                    90.,
+                ),
+                Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                4.,
+            ),
+            (
+                // Hit the side of the aabb
+                AabbCast3d::new(
+                    Aabb3d::new(Vec3::ZERO, Vec3::splat(0.5)),
+                    Vec3::new(-4., 1.4, 0.),
+                    Vec3::X,
+                    90.,
+                ),
+                Aabb3d::new(Vec3::ZERO, Vec3::ONE),
+                2.5,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.aabb_collision_at(*volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast3d::new(test.ray.origin, -test.ray.direction, test.ray.max);
+            let inverted = AabbCast3d {
+                ray: inverted_ray,
+                ..*test
+            };
+            assert!(!inverted.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn aabb_cast_misses() {
+        let volume = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+        // The cast aabb passes next to the volume by a small margin
+        let test = AabbCast3d::new(
+            Aabb3d::new(Vec3::ZERO, Vec3::splat(0.5)),
+            Vec3::new(-4., 1.501, 0.),
+            Vec3::X,
+            90.,
+        );
+        assert!(!test.intersects(&volume));
+        // The cast aabb stops short of the volume
+        let test = AabbCast3d::new(
+            Aabb3d::new(Vec3::ZERO, Vec3::splat(0.5)),
+            Vec3::new(-4., 0., 0.),
+            Vec3::X,
+            2.499,
+        );
+        assert!(!test.intersects(&volume));
+    }
+
+    #[test]
+    fn sphere_cast() {
+        for (test, volume, expected_distance) in &[
+            (
+                // Hit the center of the bounding sphere
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::ZERO, 0.5),
+                    Vec3::Y * -5.,
+                    Vec3::Y,
+                    90.,
+                ),
+                BoundingSphere::new(Vec3::ZERO, 1.),
+                3.5,
+            ),
+            (
+                // Hit the bounding sphere off-center, that has an offset in the cast
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::X * -1.5, 1.),
+                    Vec3::X * 1.5 + Vec3::Y * -5.,
+                    Vec3::Y,
+                    90.,
+                ),
+                BoundingSphere::new(Vec3::ZERO, 1.),
+                3.,
+            ),
+            (
+                // Hit the bounding sphere with a degenerate cast
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::ZERO, 0.),
+                    Vec3::Y * -5.,
+                    Vec3::Y,
+                    90.,
+                ),
+                BoundingSphere::new(Vec3::ZERO, 1.),
+                4.,
+            ),
+            (
+                // Barely graze the bounding sphere
+                BoundingSphereCast::new(
+                    BoundingSphere::new(Vec3::ZERO, 0.5),
+                    Vec3::new(1.499, -5., 0.),
+                    Vec3::Y,
+                    90.,
+                ),
+                BoundingSphere::new(Vec3::ZERO, 1.),
+                4.945,
+            ),
+        ] {
+            let case = format!(
+                "Case:\n  Test: {:?}\n  Volume: {:?}\n  Expected distance: {:?}",
+                test, volume, expected_distance
+            );
+            assert!(test.intersects(volume), "{}", case);
+            let actual_distance = test.sphere_collision_at(*volume).unwrap();
+            assert!(
+                (actual_distance - expected_distance).abs() < EPSILON,
+                "{}\n  Actual distance: {}",
+                case,
+                actual_distance
+            );
+
+            let inverted_ray = RayCast3d::new(test.ray.origin, -test.ray.direction, test.ray.max);
+            let inverted = BoundingSphereCast {
+                ray: inverted_ray,
+                ..*test
+            };
+            assert!(!inverted.intersects(volume), "{}", case);
+        }
+    }
+
+    #[test]
+    fn sphere_cast_misses() {
+        let volume = BoundingSphere::new(Vec3::ZERO, 1.);
+        // The cast sphere passes next to the volume by a small margin
+        let test = BoundingSphereCast::new(
+            BoundingSphere::new(Vec3::ZERO, 0.5),
+            Vec3::new(1.501, -5., 0.),
+            Vec3::Y,
+            90.,
+        );
+        assert!(!test.intersects(&volume));
+        // The cast sphere stops short of the volume
+        let test = BoundingSphereCast::new(
+            BoundingSphere::new(Vec3::ZERO, 0.5),
+            Vec3::new(0., -5., 0.),
+            Vec3::Y,
+            3.499,
+        );
+        assert!(!test.intersects(&volume));
+    }
+}
@@ -2,6 +2,8 @@
 
 use glam::{Vec2, Vec3, Vec3A};
 
+use thiserror::Error;
+
 use std::{
     fmt::Debug,
     iter::Sum,
@@ -53,24 +55,25 @@ impl Point for f32 {}
 ///     vec2(5.0, 3.0),
 ///     vec2(9.0, 8.0),
 /// ]];
-/// let bezier = Bezier::new(points).to_curve();
+/// let bezier = CubicBezier::new(points).to_curve().unwrap();
 /// let positions: Vec<_> = bezier.iter_positions(100).collect();
 /// ```
-pub struct Bezier<P: Point> {
+pub struct CubicBezier<P: Point> {
     control_points: Vec<[P; 4]>,
 }
 
-impl<P: Point> Bezier<P> {
-    /// Create a new Bezier curve from sets of control points.
+impl<P: Point> CubicBezier<P> {
+    /// Create a new cubic Bezier curve from sets of control points.
     pub fn new(control_points: impl Into<Vec<[P; 4]>>) -> Self {
         Self {
             control_points: control_points.into(),
         }
     }
 }
-impl<P: Point> CubicGenerator<P> for Bezier<P> {
+impl<P: Point> CubicGenerator<P> for CubicBezier<P> {
     #[inline]
-    fn to_curve(&self) -> CubicCurve<P> {
+    fn to_curve(&self) -> Result<CubicCurve<P>, InsufficientControlPointsError> {
+        InsufficientControlPointsError::check(4, self.control_points.len() * 4)?;
         let char_matrix = [
             [1., 0., 0., 0.],
             [-3., 3., 0., 0.],
@@ -84,7 +87,7 @@ impl<P: Point> CubicGenerator<P> for Bezier<P> {
             .map(|p| CubicCurve::coefficients(*p, 1.0, char_matrix))
             .collect();
 
-        CubicCurve { segments }
+        Ok(CubicCurve { segments })
     }
 }
 
@@ -120,14 +123,14 @@ impl<P: Point> CubicGenerator<P> for Bezier<P> {
 ///     vec2(0.0, 1.0),
 ///     vec2(0.0, 1.0),
 /// ];
-/// let hermite = Hermite::new(points, tangents).to_curve();
+/// let hermite = CubicHermite::new(points, tangents).to_curve().unwrap();
 /// let positions: Vec<_> = hermite.iter_positions(100).collect();
 /// ```
-pub struct Hermite<P: Point> {
+pub struct CubicHermite<P: Point> {
     control_points: Vec<(P, P)>,
 }
-impl<P: Point> Hermite<P> {
-    /// Create a new Hermite curve from sets of control points.
+impl<P: Point> CubicHermite<P> {
+    /// Create a new cubic Hermite curve from sets of control points.
     pub fn new(
         control_points: impl IntoIterator<Item = P>,
         tangents: impl IntoIterator<Item = P>,
@@ -140,9 +143,10 @@ impl<P: Point> Hermite<P> {
         }
     }
 }
-impl<P: Point> CubicGenerator<P> for Hermite<P> {
+impl<P: Point> CubicGenerator<P> for CubicHermite<P> {
     #[inline]
-    fn to_curve(&self) -> CubicCurve<P> {
+    fn to_curve(&self) -> Result<CubicCurve<P>, InsufficientControlPointsError> {
+        InsufficientControlPointsError::check(2, self.control_points.len())?;
         let char_matrix = [
             [1., 0., 0., 0.],
             [0., 1., 0., 0.],
@@ -159,7 +163,7 @@ impl<P: Point> CubicGenerator<P> for Hermite<P> {
             })
             .collect();
 
-        CubicCurve { segments }
+        Ok(CubicCurve { segments })
     }
 }
 
@@ -187,15 +191,15 @@ impl<P: Point> CubicGenerator<P> for Hermite<P> {
 ///     vec2(5.0, 3.0),
 ///     vec2(9.0, 8.0),
 /// ];
-/// let cardinal = CardinalSpline::new(0.3, points).to_curve();
+/// let cardinal = CubicCardinalSpline::new(0.3, points).to_curve().unwrap();
 /// let positions: Vec<_> = cardinal.iter_positions(100).collect();
 /// ```
-pub struct CardinalSpline<P: Point> {
+pub struct CubicCardinalSpline<P: Point> {
     tension: f32,
     control_points: Vec<P>,
 }
 
-impl<P: Point> CardinalSpline<P> {
+impl<P: Point> CubicCardinalSpline<P> {
     /// Build a new Cardinal spline.
     pub fn new(tension: f32, control_points: impl Into<Vec<P>>) -> Self {
         Self {
@@ -212,9 +216,10 @@ impl<P: Point> CardinalSpline<P> {
         }
     }
 }
-impl<P: Point> CubicGenerator<P> for CardinalSpline<P> {
+impl<P: Point> CubicGenerator<P> for CubicCardinalSpline<P> {
     #[inline]
-    fn to_curve(&self) -> CubicCurve<P> {
+    fn to_curve(&self) -> Result<CubicCurve<P>, InsufficientControlPointsError> {
+        InsufficientControlPointsError::check(4, self.control_points.len())?;
         let s = self.tension;
         let char_matrix = [
             [0., 1., 0., 0.],
@@ -229,7 +234,7 @@ impl<P: Point> CubicGenerator<P> for CardinalSpline<P> {
             .map(|p| CubicCurve::coefficients([p[0], p[1], p[2], p[3]], 1.0, char_matrix))
             .collect();
 
-        CubicCurve { segments }
+        Ok(CubicCurve { segments })
     }
 }
 
@@ -255,23 +260,24 @@ impl<P: Point> CubicGenerator<P> for CardinalSpline<P> {
 ///     vec2(5.0, 3.0),
 ///     vec2(9.0, 8.0),
 /// ];
-/// let b_spline = BSpline::new(points).to_curve();
+/// let b_spline = CubicBSpline::new(points).to_curve().unwrap();
 /// let positions: Vec<_> = b_spline.iter_positions(100).collect();
 /// ```
-pub struct BSpline<P: Point> {
+pub struct CubicBSpline<P: Point> {
     control_points: Vec<P>,
 }
-impl<P: Point> BSpline<P> {
-    /// Build a new Cardinal spline.
+impl<P: Point> CubicBSpline<P> {
+    /// Build a new B-spline.
     pub fn new(control_points: impl Into<Vec<P>>) -> Self {
         Self {
             control_points: control_points.into(),
         }
     }
 }
-impl<P: Point> CubicGenerator<P> for BSpline<P> {
+impl<P: Point> CubicGenerator<P> for CubicBSpline<P> {
     #[inline]
-    fn to_curve(&self) -> CubicCurve<P> {
+    fn to_curve(&self) -> Result<CubicCurve<P>, InsufficientControlPointsError> {
+        InsufficientControlPointsError::check(4, self.control_points.len())?;
         let char_matrix = [
             [1., 4., 1., 0.],
             [-3., 0., 3., 0.],
@@ -285,14 +291,39 @@ impl<P: Point> CubicGenerator<P> for BSpline<P> {
             .map(|p| CubicCurve::coefficients([p[0], p[1], p[2], p[3]], 1.0 / 6.0, char_matrix))
             .collect();
 
-        CubicCurve { segments }
+        Ok(CubicCurve { segments })
     }
 }
 
 /// Implement this on cubic splines that can generate a curve from their spline parameters.
 pub trait CubicGenerator<P: Point> {
     /// Build a [`CubicCurve`] by computing the interpolation coefficients for each curve segment.
-    fn to_curve(&self) -> CubicCurve<P>;
+    ///
+    /// Returns an error if the spline doesn't have enough control points to make a single segment.
+    fn to_curve(&self) -> Result<CubicCurve<P>, InsufficientControlPointsError>;
+}
+
+/// An error returned by [`CubicGenerator::to_curve`] when a spline doesn't have enough control
+/// points to build a curve segment.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error(
+    "at least {required} control points are needed to build a curve, but {provided} were given"
+)]
+pub struct InsufficientControlPointsError {
+    /// The number of control points needed for a single segment of the curve.
+    pub required: usize,
+    /// The number of control points of the spline.
+    pub provided: usize,
+}
+
+impl InsufficientControlPointsError {
+    fn check(required: usize, provided: usize) -> Result<(), Self> {
+        if provided < required {
+            Err(Self { required, provided })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// A segment of a cubic curve, used to hold precomputed coefficients for fast interpolation.
@@ -340,7 +371,9 @@ impl CubicSegment<Vec2> {
     /// example, the ubiquitous "ease-in-out" is defined as `(0.25, 0.1), (0.25, 1.0)`.
     pub fn new_bezier(p1: impl Into<Vec2>, p2: impl Into<Vec2>) -> Self {
         let (p0, p3) = (Vec2::ZERO, Vec2::ONE);
-        let bezier = Bezier::new([[p0, p1.into(), p2.into(), p3]]).to_curve();
+        let bezier = CubicBezier::new([[p0, p1.into(), p2.into(), p3]])
+            .to_curve()
+            .expect("a set of control points is enough for a Bezier curve");
         bezier.segments[0].clone()
     }
 
@@ -442,7 +475,7 @@ pub struct CubicCurve<P: Point> {
 impl<P: Point> CubicCurve<P> {
     /// Compute the position of a point on the cubic curve at the parametric value `t`.
     ///
-    /// Note that `t` varies from `0..=(n_points - 3)`.
+    /// Note that `t` varies from `0..=(number of segments)`.
     #[inline]
     pub fn position(&self, t: f32) -> P {
         let (segment, t) = self.segment(t);
@@ -452,7 +485,7 @@ impl<P: Point> CubicCurve<P> {
     /// Compute the first derivative with respect to t at `t`. This is the instantaneous velocity of
     /// a point on the cubic curve at `t`.
     ///
-    /// Note that `t` varies from `0..=(n_points - 3)`.
+    /// Note that `t` varies from `0..=(number of segments)`.
     #[inline]
     pub fn velocity(&self, t: f32) -> P {
         let (segment, t) = self.segment(t);
@@ -462,7 +495,7 @@ impl<P: Point> CubicCurve<P> {
     /// Compute the second derivative with respect to t at `t`. This is the instantaneous
     /// acceleration of a point on the cubic curve at `t`.
     ///
-    /// Note that `t` varies from `0..=(n_points - 3)`.
+    /// Note that `t` varies from `0..=(number of segments)`.
     #[inline]
     pub fn acceleration(&self, t: f32) -> P {
         let (segment, t) = self.segment(t);
@@ -472,8 +505,8 @@ impl<P: Point> CubicCurve<P> {
     /// A flexible iterator used to sample curves with arbitrary functions.
     ///
     /// This splits the curve into `subdivisions` of evenly spaced `t` values across the
-    /// length of the curve from start (t = 0) to end (t = 1), returning an iterator that evaluates
-    /// the curve with the supplied `sample_function` at each `t`.
+    /// length of the curve from start (t = 0) to end (t = number of segments), returning an
+    /// iterator that evaluates the curve with the supplied `sample_function` at each `t`.
     ///
     /// Given `subdivisions = 2`, this will split the curve into two lines, or three points, and
     /// return an iterator over those three points, one at the start, middle, and end.
@@ -483,7 +516,7 @@ impl<P: Point> CubicCurve<P> {
         subdivisions: usize,
         sample_function: fn(&Self, f32) -> P,
     ) -> impl Iterator<Item = P> + '_ {
-        (0..subdivisions).map(move |i| {
+        (0..=subdivisions).map(move |i| {
             let segments = self.segments.len() as f32;
             let t = i as f32 / subdivisions as f32 * segments;
             sample_function(self, t)
@@ -505,6 +538,12 @@ impl<P: Point> CubicCurve<P> {
         self.iter_samples(subdivisions, Self::acceleration)
     }
 
+    /// The [`CubicSegment`]s of the curve, each covering a unit range of `t`.
+    #[inline]
+    pub fn segments(&self) -> &[CubicSegment<P>] {
+        &self.segments
+    }
+
     /// Returns the [`CubicSegment`] and local `t` value given a spline's global `t` value.
     #[inline]
     fn segment(&self, t: f32) -> (&CubicSegment<P>, f32) {
@@ -534,9 +573,12 @@ impl<P: Point> CubicCurve<P> {
 
 #[cfg(test)]
 mod tests {
-    use glam::{vec2, Vec2};
+    use glam::{vec2, vec3a, Vec2, Vec3A};
 
-    use crate::cubic_splines::{Bezier, CubicGenerator, CubicSegment};
+    use crate::cubic_splines::{
+        CubicBSpline, CubicBezier, CubicCardinalSpline, CubicGenerator, CubicHermite, CubicSegment,
+        InsufficientControlPointsError,
+    };
 
     /// How close two floats can be and still be considered equal
     const FLOAT_EQ: f32 = 1e-5;
@@ -551,13 +593,148 @@ mod tests {
             vec2(5.0, 3.0),
             vec2(9.0, 8.0),
         ]];
-        let bezier = Bezier::new(points).to_curve();
+        let bezier = CubicBezier::new(points).to_curve().unwrap();
         for i in 0..=N_SAMPLES {
             let t = i as f32 / N_SAMPLES as f32; // Check along entire length
             assert!(bezier.position(t).distance(cubic_manual(t, points[0])) <= FLOAT_EQ);
         }
     }
 
+    /// The midpoint of a cubic Bezier is `(p0 + 3 p1 + 3 p2 + p3) / 8`, with a velocity of
+    /// `3 (p3 + p2 - p1 - p0) / 4`.
+    #[test]
+    fn bezier_midpoint() {
+        let points = [[
+            vec3a(0.0, 0.0, 0.0),
+            vec3a(0.0, 4.0, 2.0),
+            vec3a(8.0, 4.0, -2.0),
+            vec3a(8.0, 0.0, 0.0),
+        ]];
+        let bezier = CubicBezier::new(points).to_curve().unwrap();
+        assert!(bezier
+            .position(0.5)
+            .abs_diff_eq(vec3a(4.0, 3.0, 0.0), FLOAT_EQ));
+        assert!(bezier
+            .velocity(0.5)
+            .abs_diff_eq(vec3a(12.0, 0.0, -3.0), FLOAT_EQ));
+        // The acceleration at the start is `6 (p0 - 2 p1 + p2)`
+        assert!(bezier
+            .acceleration(0.0)
+            .abs_diff_eq(vec3a(48.0, -24.0, -36.0), FLOAT_EQ));
+    }
+
+    /// A Hermite curve passes through its control points with the given tangents as velocity.
+    #[test]
+    fn hermite_control_points() {
+        let points = [vec2(0.0, 0.0), vec2(2.0, 1.0), vec2(3.0, -1.0)];
+        let tangents = [vec2(1.0, 0.0), vec2(0.0, 2.0), vec2(-1.0, -1.0)];
+        let hermite = CubicHermite::new(points, tangents).to_curve().unwrap();
+        assert_eq!(hermite.segments().len(), 2);
+        for (t, (point, tangent)) in points.iter().zip(tangents.iter()).enumerate() {
+            let t = t as f32;
+            assert!(hermite.position(t).abs_diff_eq(*point, FLOAT_EQ));
+            assert!(hermite.velocity(t).abs_diff_eq(*tangent, FLOAT_EQ));
+        }
+    }
+
+    /// A Catmull-Rom spline passes through its inner control points, with a velocity of half the
+    /// difference between the neighbouring points.
+    #[test]
+    fn catmull_rom_control_points() {
+        let points = [
+            vec2(0.0, 0.0),
+            vec2(1.0, 2.0),
+            vec2(3.0, 2.0),
+            vec2(4.0, 0.0),
+            vec2(6.0, 1.0),
+        ];
+        let cardinal = CubicCardinalSpline::new_catmull_rom(points)
+            .to_curve()
+            .unwrap();
+        assert_eq!(cardinal.segments().len(), 2);
+        for i in 1..4 {
+            let t = (i - 1) as f32;
+            assert!(cardinal.position(t).abs_diff_eq(points[i], FLOAT_EQ));
+            let velocity = (points[i + 1] - points[i - 1]) * 0.5;
+            assert!(cardinal.velocity(t).abs_diff_eq(velocity, FLOAT_EQ));
+        }
+    }
+
+    /// A B-spline starts at `(p0 + 4 p1 + p2) / 6`, and is C2 continuous between segments.
+    #[test]
+    fn b_spline_continuity() {
+        let points = [
+            vec2(0.0, 0.0),
+            vec2(6.0, 6.0),
+            vec2(12.0, 0.0),
+            vec2(18.0, 6.0),
+            vec2(24.0, 0.0),
+        ];
+        let b_spline = CubicBSpline::new(points).to_curve().unwrap();
+        assert!(b_spline.position(0.0).abs_diff_eq(vec2(6.0, 4.0), FLOAT_EQ));
+        let [first, second] = b_spline.segments() else {
+            panic!("the B-spline should have two segments");
+        };
+        assert!(first
+            .position(1.0)
+            .abs_diff_eq(second.position(0.0), FLOAT_EQ));
+        assert!(first
+            .velocity(1.0)
+            .abs_diff_eq(second.velocity(0.0), FLOAT_EQ));
+        assert!(first
+            .acceleration(1.0)
+            .abs_diff_eq(second.acceleration(0.0), FLOAT_EQ));
+    }
+
+    #[test]
+    fn insufficient_control_points() {
+        let points = [vec2(0.0, 0.0), vec2(1.0, 1.0), vec2(2.0, 0.0)];
+        let error = |required, provided| {
+            Err::<(), _>(InsufficientControlPointsError { required, provided })
+        };
+        assert_eq!(
+            CubicBezier::<Vec2>::new([]).to_curve().map(|_| ()),
+            error(4, 0)
+        );
+        assert_eq!(
+            CubicHermite::new([points[0]], [points[1]])
+                .to_curve()
+                .map(|_| ()),
+            error(2, 1)
+        );
+        assert_eq!(
+            CubicCardinalSpline::new(0.3, points).to_curve().map(|_| ()),
+            error(4, 3)
+        );
+        assert_eq!(
+            CubicBSpline::new(points).to_curve().map(|_| ()),
+            error(4, 3)
+        );
+    }
+
+    /// The samples are evenly spaced along all of the segments, including both ends.
+    #[test]
+    fn iter_samples() {
+        let points = [vec2(0.0, 0.0), vec2(1.0, 0.0), vec2(2.0, 0.0)];
+        let tangents = [vec2(1.0, 0.0); 3];
+        let line = CubicHermite::new(points, tangents).to_curve().unwrap();
+        let positions: Vec<_> = line.iter_positions(4).collect();
+        assert_eq!(positions.len(), 5);
+        for (i, position) in positions.iter().enumerate() {
+            assert!(position.abs_diff_eq(vec2(i as f32 * 0.5, 0.0), FLOAT_EQ));
+        }
+        assert!(line
+            .iter_velocities(4)
+            .all(|velocity| velocity.abs_diff_eq(Vec2::X, FLOAT_EQ)));
+        assert!(line
+            .iter_accelerations(4)
+            .all(|acceleration| acceleration.abs_diff_eq(Vec2::ZERO, FLOAT_EQ)));
+        let _: Vec3A = CubicBSpline::new([Vec3A::ZERO; 4])
+            .to_curve()
+            .unwrap()
+            .position(0.5);
+    }
+
     /// Manual, hardcoded function for computing the position along a cubic bezier.
     fn cubic_manual(t: f32, points: [Vec2; 4]) -> Vec2 {
         let p = points;
@@ -0,0 +1,341 @@
+use crate::{Quat, Vec2, Vec3};
+use std::ops::{Deref, Mul, Neg};
+use thiserror::Error;
+
+/// An error indicating that a direction is invalid.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDirectionError {
+    /// The length of the direction vector is zero or very close to zero.
+    #[error("the length of the direction vector is zero or very close to zero")]
+    Zero,
+    /// The length of the direction vector is infinite.
+    #[error("the length of the direction vector is infinite")]
+    Infinite,
+    /// The direction vector contains `NaN`.
+    #[error("the direction vector contains NaN")]
+    NaN,
+}
+
+impl InvalidDirectionError {
+    /// Creates an [`InvalidDirectionError`] from the length of an invalid direction vector.
+    pub fn from_length(length: f32) -> Self {
+        if length.is_nan() {
+            InvalidDirectionError::NaN
+        } else if !length.is_finite() {
+            InvalidDirectionError::Infinite
+        } else {
+            InvalidDirectionError::Zero
+        }
+    }
+}
+
+/// A normalized vector pointing in a direction in 2D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dir2(Vec2);
+
+impl Dir2 {
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self(Vec2::X);
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self(Vec2::Y);
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self(Vec2::NEG_X);
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self(Vec2::NEG_Y);
+
+    /// Create a direction from a finite, nonzero [`Vec2`].
+    ///
+    /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
+    /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    pub fn new(value: Vec2) -> Result<Self, InvalidDirectionError> {
+        Self::new_and_length(value).map(|(dir, _)| dir)
+    }
+
+    /// Create a [`Dir2`] from a [`Vec2`] that is already normalized.
+    ///
+    /// # Warning
+    ///
+    /// `value` must be normalized, i.e its length must be `1.0`.
+    pub fn new_unchecked(value: Vec2) -> Self {
+        debug_assert!(value.is_normalized());
+
+        Self(value)
+    }
+
+    /// Create a direction from a finite, nonzero [`Vec2`], also returning its original length.
+    ///
+    /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
+    /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    pub fn new_and_length(value: Vec2) -> Result<(Self, f32), InvalidDirectionError> {
+        let length = value.length();
+        let direction = (length.is_finite() && length > 0.0).then_some(value / length);
+
+        direction
+            .map(|dir| (Self(dir), length))
+            .ok_or(InvalidDirectionError::from_length(length))
+    }
+
+    /// Create a direction from its `x` and `y` components.
+    ///
+    /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
+    /// of the vector formed by the components is zero (or very close to zero), infinite, or `NaN`.
+    pub fn from_xy(x: f32, y: f32) -> Result<Self, InvalidDirectionError> {
+        Self::new(Vec2::new(x, y))
+    }
+
+    /// Returns the inner [`Vec2`].
+    pub const fn as_vec2(&self) -> Vec2 {
+        self.0
+    }
+}
+
+impl TryFrom<Vec2> for Dir2 {
+    type Error = InvalidDirectionError;
+
+    fn try_from(value: Vec2) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Dir2> for Vec2 {
+    fn from(value: Dir2) -> Self {
+        value.as_vec2()
+    }
+}
+
+impl Deref for Dir2 {
+    type Target = Vec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Neg for Dir2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f32> for Dir2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+impl Mul<Dir2> for f32 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Dir2) -> Self::Output {
+        self * rhs.0
+    }
+}
+
+/// A normalized vector pointing in a direction in 3D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dir3(Vec3);
+
+impl Dir3 {
+    /// A unit vector pointing along the positive X axis.
+    pub const X: Self = Self(Vec3::X);
+    /// A unit vector pointing along the positive Y axis.
+    pub const Y: Self = Self(Vec3::Y);
+    /// A unit vector pointing along the positive Z axis.
+    pub const Z: Self = Self(Vec3::Z);
+    /// A unit vector pointing along the negative X axis.
+    pub const NEG_X: Self = Self(Vec3::NEG_X);
+    /// A unit vector pointing along the negative Y axis.
+    pub const NEG_Y: Self = Self(Vec3::NEG_Y);
+    /// A unit vector pointing along the negative Z axis.
+    pub const NEG_Z: Self = Self(Vec3::NEG_Z);
+
+    /// Create a direction from a finite, nonzero [`Vec3`].
+    ///
+    /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
+    /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    pub fn new(value: Vec3) -> Result<Self, InvalidDirectionError> {
+        Self::new_and_length(value).map(|(dir, _)| dir)
+    }
+
+    /// Create a [`Dir3`] from a [`Vec3`] that is already normalized.
+    ///
+    /// # Warning
+    ///
+    /// `value` must be normalized, i.e its length must be `1.0`.
+    pub fn new_unchecked(value: Vec3) -> Self {
+        debug_assert!(value.is_normalized());
+
+        Self(value)
+    }
+
+    /// Create a direction from a finite, nonzero [`Vec3`], also returning its original length.
+    ///
+    /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
+    /// of the given vector is zero (or very close to zero), infinite, or `NaN`.
+    pub fn new_and_length(value: Vec3) -> Result<(Self, f32), InvalidDirectionError> {
+        let length = value.length();
+        let direction = (length.is_finite() && length > 0.0).then_some(value / length);
+
+        direction
+            .map(|dir| (Self(dir), length))
+            .ok_or(InvalidDirectionError::from_length(length))
+    }
+
+    /// Create a direction from its `x`, `y`, and `z` components.
+    ///
+    /// Returns [`Err(InvalidDirectionError)`](InvalidDirectionError) if the length
+    /// of the vector formed by the components is zero (or very close to zero), infinite, or `NaN`.
+    pub fn from_xyz(x: f32, y: f32, z: f32) -> Result<Self, InvalidDirectionError> {
+        Self::new(Vec3::new(x, y, z))
+    }
+
+    /// Returns the inner [`Vec3`].
+    pub const fn as_vec3(&self) -> Vec3 {
+        self.0
+    }
+}
+
+impl TryFrom<Vec3> for Dir3 {
+    type Error = InvalidDirectionError;
+
+    fn try_from(value: Vec3) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Dir3> for Vec3 {
+    fn from(value: Dir3) -> Self {
+        value.as_vec3()
+    }
+}
+
+impl Deref for Dir3 {
+    type Target = Vec3;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Neg for Dir3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f32> for Dir3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+impl Mul<Dir3> for f32 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Dir3) -> Self::Output {
+        self * rhs.0
+    }
+}
+
+impl Mul<Dir3> for Quat {
+    type Output = Dir3;
+
+    /// Rotates the [`Dir3`] using a [`Quat`].
+    fn mul(self, direction: Dir3) -> Self::Output {
+        let rotated = self * direction.0;
+
+        // Make sure the result is normalized.
+        // This can fail for non-unit quaternions.
+        debug_assert!(rotated.is_normalized());
+
+        Dir3(rotated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir2_creation() {
+        assert_eq!(Dir2::new(Vec2::X * 12.5), Ok(Dir2::X));
+        assert_eq!(
+            Dir2::new(Vec2::new(0.0, 0.0)),
+            Err(InvalidDirectionError::Zero)
+        );
+        assert_eq!(
+            Dir2::new(Vec2::new(f32::INFINITY, 0.0)),
+            Err(InvalidDirectionError::Infinite)
+        );
+        assert_eq!(
+            Dir2::new(Vec2::new(f32::NEG_INFINITY, 0.0)),
+            Err(InvalidDirectionError::Infinite)
+        );
+        assert_eq!(
+            Dir2::new(Vec2::new(f32::NAN, 0.0)),
+            Err(InvalidDirectionError::NaN)
+        );
+        assert_eq!(Dir2::new_and_length(Vec2::X * 6.5), Ok((Dir2::X, 6.5)));
+        assert_eq!(Dir2::from_xy(0.0, -3.0), Ok(Dir2::NEG_Y));
+        assert_eq!(Dir2::try_from(Vec2::new(3.0, 4.0)).unwrap().x, 0.6);
+    }
+
+    #[test]
+    fn dir2_ops() {
+        assert_eq!(-Dir2::X, Dir2::NEG_X);
+        assert_eq!(Dir2::Y * 2.0, Vec2::new(0.0, 2.0));
+        assert_eq!(2.0 * Dir2::NEG_X, Vec2::new(-2.0, 0.0));
+        assert_eq!(Dir2::X.dot(*Dir2::Y), 0.0);
+        assert_eq!(Vec2::from(Dir2::NEG_Y), Vec2::NEG_Y);
+    }
+
+    #[test]
+    fn dir3_creation() {
+        assert_eq!(Dir3::new(Vec3::X * 12.5), Ok(Dir3::X));
+        assert_eq!(
+            Dir3::new(Vec3::new(0.0, 0.0, 0.0)),
+            Err(InvalidDirectionError::Zero)
+        );
+        assert_eq!(
+            Dir3::new(Vec3::new(f32::INFINITY, 0.0, 0.0)),
+            Err(InvalidDirectionError::Infinite)
+        );
+        assert_eq!(
+            Dir3::new(Vec3::new(f32::NEG_INFINITY, 0.0, 0.0)),
+            Err(InvalidDirectionError::Infinite)
+        );
+        assert_eq!(
+            Dir3::new(Vec3::new(f32::NAN, 0.0, 0.0)),
+            Err(InvalidDirectionError::NaN)
+        );
+        assert_eq!(Dir3::new_and_length(Vec3::X * 6.5), Ok((Dir3::X, 6.5)));
+        assert_eq!(Dir3::from_xyz(0.0, 0.0, -3.0), Ok(Dir3::NEG_Z));
+        assert_eq!(Dir3::try_from(Vec3::new(0.0, 3.0, 4.0)).unwrap().z, 0.8);
+    }
+
+    #[test]
+    fn dir3_ops() {
+        assert_eq!(-Dir3::Z, Dir3::NEG_Z);
+        assert_eq!(Dir3::Y * 2.0, Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(2.0 * Dir3::NEG_X, Vec3::new(-2.0, 0.0, 0.0));
+        assert_eq!(Dir3::X.cross(*Dir3::Y), Vec3::Z);
+        assert_eq!(Vec3::from(Dir3::NEG_Y), Vec3::NEG_Y);
+    }
+
+    #[test]
+    fn dir3_rotation() {
+        let rotated = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2) * Dir3::X;
+        assert!(rotated.abs_diff_eq(Vec3::Y, 1e-6));
+        assert!(rotated.is_normalized());
+    }
+}
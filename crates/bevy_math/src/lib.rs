@@ -6,20 +6,31 @@
 
 #![warn(missing_docs)]
 
+pub mod bounding;
 pub mod cubic_splines;
+mod direction;
+pub mod primitives;
 mod ray;
 mod rect;
+#[cfg(feature = "rand")]
+pub mod sampling;
+mod urect;
 
-pub use ray::Ray;
+pub use direction::{Dir2, Dir3, InvalidDirectionError};
+pub use ray::{Ray2d, Ray3d};
 pub use rect::Rect;
+pub use urect::URect;
 
 /// The `bevy_math` prelude.
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        cubic_splines::{BSpline, Bezier, CardinalSpline, CubicGenerator, CubicSegment, Hermite},
-        BVec2, BVec3, BVec4, EulerRot, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat, Ray, Rect,
-        UVec2, UVec3, UVec4, Vec2, Vec3, Vec4,
+        cubic_splines::{
+            CubicBSpline, CubicBezier, CubicCardinalSpline, CubicGenerator, CubicHermite,
+            CubicSegment,
+        },
+        BVec2, BVec3, BVec4, Dir2, Dir3, EulerRot, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat,
+        Ray2d, Ray3d, Rect, URect, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4,
     };
 }
 
@@ -0,0 +1,308 @@
+use super::Primitive2d;
+use crate::Vec2;
+use std::f32::consts::PI;
+
+/// A circle primitive, centered on the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circle {
+    /// The radius of the circle.
+    pub radius: f32,
+}
+impl Primitive2d for Circle {}
+
+impl Default for Circle {
+    /// Returns the default [`Circle`] with a radius of `0.5`.
+    fn default() -> Self {
+        Self { radius: 0.5 }
+    }
+}
+
+impl Circle {
+    /// Create a new [`Circle`] from a `radius`.
+    #[inline]
+    pub const fn new(radius: f32) -> Self {
+        Self { radius }
+    }
+
+    /// Get the diameter of the circle.
+    #[inline]
+    pub fn diameter(&self) -> f32 {
+        2.0 * self.radius
+    }
+
+    /// Get the area of the circle.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        PI * self.radius * self.radius
+    }
+
+    /// Get the perimeter or circumference of the circle.
+    #[inline]
+    pub fn perimeter(&self) -> f32 {
+        2.0 * PI * self.radius
+    }
+
+    /// Finds the point on the circle that is closest to the given `point`.
+    ///
+    /// If the point is outside the circle, the returned point will be on its perimeter.
+    /// Otherwise, it will be inside the circle and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let distance_squared = point.length_squared();
+        if distance_squared <= self.radius * self.radius {
+            // The point is inside the circle.
+            point
+        } else {
+            // The point is outside the circle.
+            // Find the closest point on the perimeter of the circle.
+            let dir_to_point = point / distance_squared.sqrt();
+            self.radius * dir_to_point
+        }
+    }
+
+    /// Checks if the given `point` is inside the circle, or on its perimeter.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.length_squared() <= self.radius * self.radius
+    }
+}
+
+/// A rectangle primitive, centered on the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rectangle {
+    /// Half of the width and height of the rectangle.
+    pub half_size: Vec2,
+}
+impl Primitive2d for Rectangle {}
+
+impl Default for Rectangle {
+    /// Returns the default [`Rectangle`] with a half-width and half-height of `0.5`.
+    fn default() -> Self {
+        Self {
+            half_size: Vec2::splat(0.5),
+        }
+    }
+}
+
+impl Rectangle {
+    /// Create a new [`Rectangle`] from a full width and height.
+    #[inline]
+    pub fn new(width: f32, height: f32) -> Self {
+        Self::from_size(Vec2::new(width, height))
+    }
+
+    /// Create a new [`Rectangle`] from a given full size.
+    #[inline]
+    pub fn from_size(size: Vec2) -> Self {
+        Self {
+            half_size: size / 2.0,
+        }
+    }
+
+    /// Get the size of the rectangle.
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        2.0 * self.half_size
+    }
+
+    /// Get the area of the rectangle.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        4.0 * self.half_size.x * self.half_size.y
+    }
+
+    /// Get the perimeter of the rectangle.
+    #[inline]
+    pub fn perimeter(&self) -> f32 {
+        4.0 * (self.half_size.x + self.half_size.y)
+    }
+
+    /// Finds the point on the rectangle that is closest to the given `point`.
+    ///
+    /// If the point is outside the rectangle, the returned point will be on its perimeter.
+    /// Otherwise, it will be inside the rectangle and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        // Clamp point coordinates to the rectangle
+        point.clamp(-self.half_size, self.half_size)
+    }
+
+    /// Checks if the given `point` is inside the rectangle, or on its perimeter.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.abs().cmple(self.half_size).all()
+    }
+}
+
+/// A 2D capsule primitive, also known as a stadium or pill shape.
+///
+/// It is a rectangle along the Y axis, with semicircles at its top and bottom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule2d {
+    /// The radius of the capsule.
+    pub radius: f32,
+    /// Half the height of the capsule, excluding the semicircles.
+    pub half_length: f32,
+}
+impl Primitive2d for Capsule2d {}
+
+impl Default for Capsule2d {
+    /// Returns the default [`Capsule2d`] with a radius of `0.5` and a half-length of `0.5`,
+    /// excluding the semicircles.
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            half_length: 0.5,
+        }
+    }
+}
+
+impl Capsule2d {
+    /// Create a new [`Capsule2d`] from a radius and length, excluding the semicircles.
+    #[inline]
+    pub fn new(radius: f32, length: f32) -> Self {
+        Self {
+            radius,
+            half_length: length / 2.0,
+        }
+    }
+
+    /// Get the area of the capsule.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        PI * self.radius * self.radius + 4.0 * self.radius * self.half_length
+    }
+
+    /// Get the perimeter of the capsule.
+    #[inline]
+    pub fn perimeter(&self) -> f32 {
+        2.0 * PI * self.radius + 4.0 * self.half_length
+    }
+
+    /// Finds the point on the capsule that is closest to the given `point`.
+    ///
+    /// If the point is outside the capsule, the returned point will be on its perimeter.
+    /// Otherwise, it will be inside the capsule and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        // The closest point on the line segment between the centers of the semicircles
+        let segment_point = Vec2::new(0.0, point.y.clamp(-self.half_length, self.half_length));
+        segment_point + Circle::new(self.radius).closest_point(point - segment_point)
+    }
+
+    /// Checks if the given `point` is inside the capsule, or on its perimeter.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let segment_point = Vec2::new(0.0, point.y.clamp(-self.half_length, self.half_length));
+        point.distance_squared(segment_point) <= self.radius * self.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_math() {
+        let circle = Circle::new(3.0);
+        assert_eq!(circle.diameter(), 6.0);
+        assert!((circle.area() - 28.274334).abs() < 1e-5);
+        assert!((circle.perimeter() - 18.849556).abs() < 1e-5);
+    }
+
+    #[test]
+    fn circle_closest_point() {
+        let circle = Circle::new(1.0);
+        assert_eq!(circle.closest_point(Vec2::X * 10.0), Vec2::X);
+        assert_eq!(
+            circle.closest_point(Vec2::NEG_ONE * 10.0),
+            Vec2::NEG_ONE.normalize()
+        );
+        assert_eq!(
+            circle.closest_point(Vec2::new(0.25, 0.1)),
+            Vec2::new(0.25, 0.1)
+        );
+        assert!(circle.contains_point(Vec2::Y));
+        assert!(!circle.contains_point(Vec2::new(0.0, 1.001)));
+
+        // A degenerate circle is a point
+        let point = Circle::new(0.0);
+        assert_eq!(point.closest_point(Vec2::new(2.0, 1.0)), Vec2::ZERO);
+        assert!(point.contains_point(Vec2::ZERO));
+    }
+
+    #[test]
+    fn rectangle_math() {
+        let rectangle = Rectangle::new(3.0, 7.0);
+        assert_eq!(rectangle.half_size, Vec2::new(1.5, 3.5));
+        assert_eq!(rectangle.size(), Vec2::new(3.0, 7.0));
+        assert_eq!(rectangle.area(), 21.0);
+        assert_eq!(rectangle.perimeter(), 20.0);
+    }
+
+    #[test]
+    fn rectangle_closest_point() {
+        let rectangle = Rectangle::new(2.0, 2.0);
+        assert_eq!(rectangle.closest_point(Vec2::X * 10.0), Vec2::X);
+        assert_eq!(rectangle.closest_point(Vec2::NEG_ONE * 10.0), Vec2::NEG_ONE);
+        assert_eq!(
+            rectangle.closest_point(Vec2::new(0.25, 0.1)),
+            Vec2::new(0.25, 0.1)
+        );
+        assert!(rectangle.contains_point(Vec2::ONE));
+        assert!(!rectangle.contains_point(Vec2::new(1.001, 0.0)));
+
+        // A degenerate rectangle is a line segment
+        let segment = Rectangle::new(2.0, 0.0);
+        assert_eq!(
+            segment.closest_point(Vec2::new(3.0, 5.0)),
+            Vec2::new(1.0, 0.0)
+        );
+        assert!(segment.contains_point(Vec2::new(0.5, 0.0)));
+        assert!(!segment.contains_point(Vec2::new(0.5, 0.001)));
+    }
+
+    #[test]
+    fn capsule_math() {
+        let capsule = Capsule2d::new(2.0, 6.0);
+        assert_eq!(capsule.half_length, 3.0);
+        assert!((capsule.area() - 36.566371).abs() < 1e-5);
+        assert!((capsule.perimeter() - 24.566371).abs() < 1e-5);
+    }
+
+    #[test]
+    fn capsule_closest_point() {
+        let capsule = Capsule2d::new(1.0, 2.0);
+        // Closest to the sides
+        assert_eq!(
+            capsule.closest_point(Vec2::new(5.0, 0.5)),
+            Vec2::new(1.0, 0.5)
+        );
+        assert_eq!(
+            capsule.closest_point(Vec2::new(-5.0, -1.0)),
+            Vec2::new(-1.0, -1.0)
+        );
+        // Closest to the semicircles
+        assert_eq!(capsule.closest_point(Vec2::Y * 5.0), Vec2::Y * 2.0);
+        assert!(capsule
+            .closest_point(Vec2::new(3.0, -5.0))
+            .abs_diff_eq(Vec2::new(0.6, -1.8), 1e-5));
+        // Inside
+        assert_eq!(
+            capsule.closest_point(Vec2::new(0.5, 1.5)),
+            Vec2::new(0.5, 1.5)
+        );
+        assert!(capsule.contains_point(Vec2::new(0.0, -2.0)));
+        assert!(!capsule.contains_point(Vec2::new(0.8, 1.8)));
+
+        // A degenerate capsule is a circle
+        let circle = Capsule2d::new(1.0, 0.0);
+        assert_eq!(
+            circle.closest_point(Vec2::new(0.0, -3.0)),
+            Circle::new(1.0).closest_point(Vec2::new(0.0, -3.0))
+        );
+    }
+}
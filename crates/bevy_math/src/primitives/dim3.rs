@@ -0,0 +1,467 @@
+use super::{Circle, Primitive3d};
+use crate::{Vec2, Vec3};
+use std::f32::consts::PI;
+
+/// A sphere primitive, centered on the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+impl Primitive3d for Sphere {}
+
+impl Default for Sphere {
+    /// Returns the default [`Sphere`] with a radius of `0.5`.
+    fn default() -> Self {
+        Self { radius: 0.5 }
+    }
+}
+
+impl Sphere {
+    /// Create a new [`Sphere`] from a `radius`.
+    #[inline]
+    pub const fn new(radius: f32) -> Self {
+        Self { radius }
+    }
+
+    /// Get the diameter of the sphere.
+    #[inline]
+    pub fn diameter(&self) -> f32 {
+        2.0 * self.radius
+    }
+
+    /// Get the surface area of the sphere.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        4.0 * PI * self.radius * self.radius
+    }
+
+    /// Get the volume of the sphere.
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        4.0 * PI * self.radius.powi(3) / 3.0
+    }
+
+    /// Finds the point on the sphere that is closest to the given `point`.
+    ///
+    /// If the point is outside the sphere, the returned point will be on its surface.
+    /// Otherwise, it will be inside the sphere and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let distance_squared = point.length_squared();
+        if distance_squared <= self.radius * self.radius {
+            // The point is inside the sphere.
+            point
+        } else {
+            // The point is outside the sphere.
+            // Find the closest point on the surface of the sphere.
+            let dir_to_point = point / distance_squared.sqrt();
+            self.radius * dir_to_point
+        }
+    }
+
+    /// Checks if the given `point` is inside the sphere, or on its surface.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.length_squared() <= self.radius * self.radius
+    }
+}
+
+/// A cuboid primitive, more commonly known as a box, centered on the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cuboid {
+    /// Half of the width, height and depth of the cuboid.
+    pub half_size: Vec3,
+}
+impl Primitive3d for Cuboid {}
+
+impl Default for Cuboid {
+    /// Returns the default [`Cuboid`] with a width, height, and depth of `1.0`.
+    fn default() -> Self {
+        Self {
+            half_size: Vec3::splat(0.5),
+        }
+    }
+}
+
+impl Cuboid {
+    /// Create a new [`Cuboid`] from a full x, y, and z length.
+    #[inline]
+    pub fn new(x_length: f32, y_length: f32, z_length: f32) -> Self {
+        Self::from_size(Vec3::new(x_length, y_length, z_length))
+    }
+
+    /// Create a new [`Cuboid`] from a given full size.
+    #[inline]
+    pub fn from_size(size: Vec3) -> Self {
+        Self {
+            half_size: size / 2.0,
+        }
+    }
+
+    /// Get the size of the cuboid.
+    #[inline]
+    pub fn size(&self) -> Vec3 {
+        2.0 * self.half_size
+    }
+
+    /// Get the surface area of the cuboid.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        let Vec3 { x, y, z } = self.half_size;
+        8.0 * (x * y + y * z + z * x)
+    }
+
+    /// Get the volume of the cuboid.
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        8.0 * self.half_size.x * self.half_size.y * self.half_size.z
+    }
+
+    /// Finds the point on the cuboid that is closest to the given `point`.
+    ///
+    /// If the point is outside the cuboid, the returned point will be on its surface.
+    /// Otherwise, it will be inside the cuboid and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        // Clamp point coordinates to the cuboid
+        point.clamp(-self.half_size, self.half_size)
+    }
+
+    /// Checks if the given `point` is inside the cuboid, or on its surface.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.abs().cmple(self.half_size).all()
+    }
+}
+
+/// A cylinder primitive, centered on the origin and standing along the Y axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cylinder {
+    /// The radius of the cylinder.
+    pub radius: f32,
+    /// The half height of the cylinder.
+    pub half_height: f32,
+}
+impl Primitive3d for Cylinder {}
+
+impl Default for Cylinder {
+    /// Returns the default [`Cylinder`] with a radius of `0.5` and a height of `1.0`.
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            half_height: 0.5,
+        }
+    }
+}
+
+impl Cylinder {
+    /// Create a new [`Cylinder`] from a radius and full height.
+    #[inline]
+    pub fn new(radius: f32, height: f32) -> Self {
+        Self {
+            radius,
+            half_height: height / 2.0,
+        }
+    }
+
+    /// Get the base of the cylinder as a [`Circle`].
+    #[inline]
+    pub fn base(&self) -> Circle {
+        Circle::new(self.radius)
+    }
+
+    /// Get the surface area of the side of the cylinder,
+    /// also known as the lateral area.
+    #[inline]
+    pub fn lateral_area(&self) -> f32 {
+        4.0 * PI * self.radius * self.half_height
+    }
+
+    /// Get the surface area of the cylinder.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        2.0 * self.base().area() + self.lateral_area()
+    }
+
+    /// Get the volume of the cylinder.
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        self.base().area() * 2.0 * self.half_height
+    }
+
+    /// Finds the point on the cylinder that is closest to the given `point`.
+    ///
+    /// If the point is outside the cylinder, the returned point will be on its surface.
+    /// Otherwise, it will be inside the cylinder and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        // The cylinder is the product of a disk in the XZ plane and a segment along the Y axis,
+        // so the closest point can be found independently on each of them.
+        let horizontal = self.base().closest_point(Vec2::new(point.x, point.z));
+        Vec3::new(
+            horizontal.x,
+            point.y.clamp(-self.half_height, self.half_height),
+            horizontal.y,
+        )
+    }
+
+    /// Checks if the given `point` is inside the cylinder, or on its surface.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.y.abs() <= self.half_height && self.base().contains_point(Vec2::new(point.x, point.z))
+    }
+}
+
+/// A torus primitive, often representing a ring or donut shape, centered on the origin.
+///
+/// The ring of the torus lies in the XZ plane, around the Y axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Torus {
+    /// The radius of the tube of the torus.
+    pub minor_radius: f32,
+    /// The distance from the center of the torus to the center of the tube.
+    pub major_radius: f32,
+}
+impl Primitive3d for Torus {}
+
+impl Default for Torus {
+    /// Returns the default [`Torus`] with a minor radius of `0.25` and a major radius of `0.75`.
+    fn default() -> Self {
+        Self {
+            minor_radius: 0.25,
+            major_radius: 0.75,
+        }
+    }
+}
+
+impl Torus {
+    /// Create a new [`Torus`] from an inner and outer radius.
+    ///
+    /// The inner radius is the radius of the hole, and the outer radius
+    /// is the radius of the entire object.
+    #[inline]
+    pub fn new(inner_radius: f32, outer_radius: f32) -> Self {
+        let minor_radius = (outer_radius - inner_radius) / 2.0;
+        let major_radius = outer_radius - minor_radius;
+
+        Self {
+            minor_radius,
+            major_radius,
+        }
+    }
+
+    /// Get the inner radius of the torus.
+    /// For a ring torus, this corresponds to the radius of the hole,
+    /// or `major_radius - minor_radius`.
+    #[inline]
+    pub fn inner_radius(&self) -> f32 {
+        self.major_radius - self.minor_radius
+    }
+
+    /// Get the outer radius of the torus.
+    /// This corresponds to the overall radius of the entire object,
+    /// or `major_radius + minor_radius`.
+    #[inline]
+    pub fn outer_radius(&self) -> f32 {
+        self.major_radius + self.minor_radius
+    }
+
+    /// Get the surface area of the torus.
+    #[inline]
+    pub fn area(&self) -> f32 {
+        4.0 * PI * PI * self.major_radius * self.minor_radius
+    }
+
+    /// Get the volume of the torus.
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        2.0 * PI * PI * self.major_radius * self.minor_radius * self.minor_radius
+    }
+
+    /// Finds the point closest to `point` on the circle at the center of the tube.
+    ///
+    /// For a point on the Y axis, all the points of the circle are equally close,
+    /// and the one on the X axis is returned.
+    #[inline]
+    fn closest_ring_point(&self, point: Vec3) -> Vec3 {
+        let direction = Vec3::new(point.x, 0.0, point.z)
+            .try_normalize()
+            .unwrap_or(Vec3::X);
+        direction * self.major_radius
+    }
+
+    /// Finds the point on the torus that is closest to the given `point`.
+    ///
+    /// If the point is outside the torus, the returned point will be on its surface.
+    /// Otherwise, it will be inside the torus and returned as is.
+    #[inline]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let ring_point = self.closest_ring_point(point);
+        ring_point + Sphere::new(self.minor_radius).closest_point(point - ring_point)
+    }
+
+    /// Checks if the given `point` is inside the torus, or on its surface.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.distance_squared(self.closest_ring_point(point))
+            <= self.minor_radius * self.minor_radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sphere_math() {
+        let sphere = Sphere::new(4.0);
+        assert_eq!(sphere.diameter(), 8.0);
+        assert!((sphere.area() - 201.06193).abs() < 1e-3);
+        assert!((sphere.volume() - 268.08257).abs() < 1e-3);
+    }
+
+    #[test]
+    fn sphere_closest_point() {
+        let sphere = Sphere::new(1.0);
+        assert_eq!(sphere.closest_point(Vec3::X * 10.0), Vec3::X);
+        assert!(sphere
+            .closest_point(Vec3::NEG_ONE * 10.0)
+            .abs_diff_eq(Vec3::NEG_ONE.normalize(), 1e-6));
+        assert_eq!(
+            sphere.closest_point(Vec3::new(0.25, 0.1, 0.3)),
+            Vec3::new(0.25, 0.1, 0.3)
+        );
+        assert!(sphere.contains_point(Vec3::Z));
+        assert!(!sphere.contains_point(Vec3::new(0.0, 0.0, 1.001)));
+
+        // A degenerate sphere is a point
+        let point = Sphere::new(0.0);
+        assert_eq!(point.closest_point(Vec3::ONE), Vec3::ZERO);
+        assert!(point.contains_point(Vec3::ZERO));
+    }
+
+    #[test]
+    fn cuboid_math() {
+        let cuboid = Cuboid::new(3.0, 7.0, 2.0);
+        assert_eq!(cuboid.half_size, Vec3::new(1.5, 3.5, 1.0));
+        assert_eq!(cuboid.size(), Vec3::new(3.0, 7.0, 2.0));
+        assert_eq!(cuboid.area(), 82.0);
+        assert_eq!(cuboid.volume(), 42.0);
+    }
+
+    #[test]
+    fn cuboid_closest_point() {
+        let cuboid = Cuboid::new(2.0, 2.0, 2.0);
+        assert_eq!(cuboid.closest_point(Vec3::X * 10.0), Vec3::X);
+        assert_eq!(cuboid.closest_point(Vec3::NEG_ONE * 10.0), Vec3::NEG_ONE);
+        assert_eq!(
+            cuboid.closest_point(Vec3::new(0.25, 0.1, 0.3)),
+            Vec3::new(0.25, 0.1, 0.3)
+        );
+        assert!(cuboid.contains_point(Vec3::ONE));
+        assert!(!cuboid.contains_point(Vec3::new(0.0, 0.0, -1.001)));
+
+        // A degenerate cuboid is a rectangle
+        let rectangle = Cuboid::new(2.0, 2.0, 0.0);
+        assert_eq!(
+            rectangle.closest_point(Vec3::new(0.5, 3.0, 5.0)),
+            Vec3::new(0.5, 1.0, 0.0)
+        );
+        assert!(rectangle.contains_point(Vec3::new(0.5, 0.5, 0.0)));
+        assert!(!rectangle.contains_point(Vec3::new(0.5, 0.5, 0.001)));
+    }
+
+    #[test]
+    fn cylinder_math() {
+        let cylinder = Cylinder::new(2.0, 9.0);
+        assert_eq!(cylinder.half_height, 4.5);
+        assert!((cylinder.lateral_area() - 113.097336).abs() < 1e-3);
+        assert!((cylinder.area() - 138.23007).abs() < 1e-3);
+        assert!((cylinder.volume() - 113.097336).abs() < 1e-3);
+    }
+
+    #[test]
+    fn cylinder_closest_point() {
+        let cylinder = Cylinder::new(1.0, 2.0);
+        // Closest to the side
+        assert_eq!(
+            cylinder.closest_point(Vec3::new(10.0, 0.5, 0.0)),
+            Vec3::new(1.0, 0.5, 0.0)
+        );
+        // Closest to a cap
+        assert_eq!(
+            cylinder.closest_point(Vec3::new(0.5, -5.0, 0.25)),
+            Vec3::new(0.5, -1.0, 0.25)
+        );
+        // Closest to an edge
+        assert!(cylinder
+            .closest_point(Vec3::new(3.0, 5.0, 4.0))
+            .abs_diff_eq(Vec3::new(0.6, 1.0, 0.8), 1e-6));
+        // Inside
+        assert_eq!(
+            cylinder.closest_point(Vec3::new(0.5, 0.5, 0.5)),
+            Vec3::new(0.5, 0.5, 0.5)
+        );
+        assert!(cylinder.contains_point(Vec3::new(0.0, 1.0, -1.0)));
+        assert!(!cylinder.contains_point(Vec3::new(0.8, 0.0, 0.8)));
+        assert!(!cylinder.contains_point(Vec3::new(0.0, 1.001, 0.0)));
+
+        // A degenerate cylinder is a disk
+        let disk = Cylinder::new(1.0, 0.0);
+        assert_eq!(
+            disk.closest_point(Vec3::new(0.5, 3.0, 0.0)),
+            Vec3::new(0.5, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn torus_math() {
+        let torus = Torus::new(0.5, 1.5);
+        assert_eq!(torus.minor_radius, 0.5);
+        assert_eq!(torus.major_radius, 1.0);
+        assert_eq!(torus.inner_radius(), 0.5);
+        assert_eq!(torus.outer_radius(), 1.5);
+        assert!((torus.area() - 19.739208).abs() < 1e-4);
+        assert!((torus.volume() - 4.934802).abs() < 1e-4);
+    }
+
+    #[test]
+    fn torus_closest_point() {
+        let torus = Torus::new(0.5, 1.5);
+        // Closest to the outside of the ring
+        assert_eq!(torus.closest_point(Vec3::X * 10.0), Vec3::X * 1.5);
+        assert!(torus
+            .closest_point(Vec3::new(0.0, 0.0, -10.0))
+            .abs_diff_eq(Vec3::NEG_Z * 1.5, 1e-6));
+        // Closest to the hole of the ring
+        assert_eq!(torus.closest_point(Vec3::X * 0.1), Vec3::X * 0.5);
+        // Closest to the top of the ring
+        assert!(torus
+            .closest_point(Vec3::new(0.0, 3.0, 1.0))
+            .abs_diff_eq(Vec3::new(0.0, 0.5, 1.0), 1e-6));
+        // Points on the axis are as close to every point of the hole
+        assert_eq!(
+            torus.closest_point(Vec3::ZERO).length(),
+            torus.inner_radius()
+        );
+        // Inside
+        assert_eq!(
+            torus.closest_point(Vec3::new(1.2, 0.1, 0.0)),
+            Vec3::new(1.2, 0.1, 0.0)
+        );
+        assert!(torus.contains_point(Vec3::new(0.0, 0.5, -1.0)));
+        assert!(!torus.contains_point(Vec3::ZERO));
+        assert!(!torus.contains_point(Vec3::new(0.0, 0.501, 1.0)));
+
+        // A degenerate torus is a circle
+        let circle = Torus::new(1.0, 1.0);
+        assert!(circle
+            .closest_point(Vec3::new(0.0, 3.0, 4.0))
+            .abs_diff_eq(Vec3::Z, 1e-6));
+    }
+}
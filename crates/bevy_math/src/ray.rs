@@ -1,20 +1,77 @@
-use crate::Vec3;
+use crate::{Dir2, Dir3, Vec2, Vec3};
+
+/// An infinite half-line starting at `origin` and going in `direction` in 2D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray2d {
+    /// The origin of the ray.
+    pub origin: Vec2,
+    /// The direction of the ray.
+    pub direction: Dir2,
+}
+
+impl Ray2d {
+    /// Create a new `Ray2d` from a given origin and direction
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given `direction` is zero (or very close to zero), or non-finite.
+    #[inline]
+    pub fn new(origin: Vec2, direction: Vec2) -> Self {
+        Self {
+            origin,
+            direction: Dir2::new(direction).expect("ray direction should be nonzero and finite"),
+        }
+    }
+
+    /// Get a point at a given distance along the ray
+    #[inline]
+    pub fn get_point(&self, distance: f32) -> Vec2 {
+        self.origin + *self.direction * distance
+    }
+
+    /// Get the distance to a line if the ray intersects it
+    #[inline]
+    pub fn intersect_line(&self, line_origin: Vec2, line_normal: Vec2) -> Option<f32> {
+        let denominator = line_normal.dot(*self.direction);
+        if denominator.abs() > f32::EPSILON {
+            let distance = (line_origin - self.origin).dot(line_normal) / denominator;
+            if distance > f32::EPSILON {
+                return Some(distance);
+            }
+        }
+        None
+    }
+}
 
 /// A ray is an infinite line starting at `origin`, going in `direction`.
-#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-pub struct Ray {
+pub struct Ray3d {
     /// The origin of the ray.
     pub origin: Vec3,
-    /// A normalized vector representing the direction of the ray.
-    pub direction: Vec3,
+    /// The direction of the ray.
+    pub direction: Dir3,
 }
 
-impl Ray {
+impl Ray3d {
+    /// Create a new `Ray3d` from a given origin and direction
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given `direction` is zero (or very close to zero), or non-finite.
+    #[inline]
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self {
+            origin,
+            direction: Dir3::new(direction).expect("ray direction should be nonzero and finite"),
+        }
+    }
+
     /// Returns the distance to the plane if the ray intersects it.
     #[inline]
     pub fn intersect_plane(&self, plane_origin: Vec3, plane_normal: Vec3) -> Option<f32> {
-        let denominator = plane_normal.dot(self.direction);
+        let denominator = plane_normal.dot(*self.direction);
         if denominator.abs() > f32::EPSILON {
             let distance = (plane_origin - self.origin).dot(plane_normal) / denominator;
             if distance > f32::EPSILON {
@@ -27,7 +84,7 @@ impl Ray {
     /// Retrieve a point at the given distance along the ray.
     #[inline]
     pub fn get_point(&self, distance: f32) -> Vec3 {
-        self.origin + self.direction * distance
+        self.origin + *self.direction * distance
     }
 }
 
@@ -35,12 +92,28 @@ impl Ray {
 mod tests {
     use super::*;
 
+    #[test]
+    fn intersect_line_2d() {
+        let ray = Ray2d::new(Vec2::ZERO, Vec2::Y);
+
+        // Orthogonal, and test that an inverse line_normal has the same result
+        assert_eq!(Some(1.), ray.intersect_line(Vec2::Y, Vec2::Y));
+        assert_eq!(Some(1.), ray.intersect_line(Vec2::Y, Vec2::NEG_Y));
+        assert_eq!(None, ray.intersect_line(Vec2::NEG_Y, Vec2::Y));
+
+        // Diagonal
+        assert_eq!(Some(1.), ray.intersect_line(Vec2::Y, Vec2::ONE));
+        assert_eq!(None, ray.intersect_line(Vec2::NEG_Y, Vec2::ONE));
+
+        // Parallel
+        assert_eq!(None, ray.intersect_line(Vec2::X, Vec2::X));
+
+        assert_eq!(ray.get_point(2.5), Vec2::new(0., 2.5));
+    }
+
     #[test]
     fn intersect_plane() {
-        let ray = Ray {
-            origin: Vec3::ZERO,
-            direction: Vec3::Z,
-        };
+        let ray = Ray3d::new(Vec3::ZERO, Vec3::Z);
 
         // Orthogonal, and test that an inverse plane_normal has the same result
         assert_eq!(Some(1.), ray.intersect_plane(Vec3::Z, Vec3::Z));
@@ -61,4 +134,17 @@ mod tests {
             ray.intersect_plane(Vec3::X, Vec3::X + Vec3::Z * f32::EPSILON)
         );
     }
+
+    #[test]
+    fn ray_direction_is_normalized() {
+        let ray = Ray3d::new(Vec3::ONE, Vec3::Z * 4.);
+        assert_eq!(ray.direction, Dir3::Z);
+        assert_eq!(ray.get_point(2.), Vec3::new(1., 1., 3.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ray_zero_direction() {
+        Ray3d::new(Vec3::ZERO, Vec3::ZERO);
+    }
 }
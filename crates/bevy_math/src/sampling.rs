@@ -0,0 +1,444 @@
+//! Uniform random sampling of points in and on the [primitive shapes](crate::primitives).
+//!
+//! This module is only available with the `rand` feature.
+
+use crate::{
+    primitives::{Capsule2d, Circle, Cuboid, Cylinder, Rectangle, Sphere, Torus},
+    Vec2, Vec3,
+};
+use rand::Rng;
+use std::f32::consts::TAU;
+
+/// Exposes methods to uniformly sample a variety of primitive shapes.
+pub trait ShapeSample {
+    /// The type of vector returned by the sample methods, [`Vec2`] for 2D shapes and [`Vec3`]
+    /// for 3D shapes.
+    type Output;
+
+    /// Uniformly sample a point from inside the area/volume of this shape, centered on 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{primitives::Sphere, sampling::ShapeSample};
+    /// let sphere = Sphere::new(1.0);
+    ///
+    /// // Returns a Vec3 inside the sphere with a length of at most 1
+    /// let point = sphere.sample_interior(&mut rand::thread_rng());
+    /// assert!(point.length() <= 1.0 + 1e-6);
+    /// ```
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Output;
+
+    /// Uniformly sample a point from the surface of this shape, centered on 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::{primitives::Sphere, sampling::ShapeSample};
+    /// let sphere = Sphere::new(1.0);
+    ///
+    /// // Returns a Vec3 on the surface of the sphere, with a length of 1
+    /// let point = sphere.sample_boundary(&mut rand::thread_rng());
+    /// assert!((point.length() - 1.0).abs() < 1e-5);
+    /// ```
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Output;
+}
+
+/// Returns a uniformly distributed value between `-1.0` and `1.0`.
+#[inline]
+fn signed_unit<R: Rng + ?Sized>(rng: &mut R) -> f32 {
+    rng.gen::<f32>() * 2.0 - 1.0
+}
+
+/// Returns `true` with a probability of `weight / total`, and `false` if `total` is zero.
+#[inline]
+fn choose<R: Rng + ?Sized>(rng: &mut R, weight: f32, total: f32) -> bool {
+    rng.gen::<f32>() * total < weight
+}
+
+/// Returns a uniformly distributed unit vector.
+#[inline]
+fn unit_circle<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+    let theta = rng.gen::<f32>() * TAU;
+    let (sin, cos) = theta.sin_cos();
+    Vec2::new(cos, sin)
+}
+
+/// Returns a uniformly distributed unit vector.
+#[inline]
+fn unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+    // Archimedes' hat-box theorem: the height is uniformly distributed on the sphere.
+    let z = signed_unit(rng);
+    let horizontal = unit_circle(rng) * (1.0 - z * z).max(0.0).sqrt();
+    Vec3::new(horizontal.x, horizontal.y, z)
+}
+
+impl ShapeSample for Circle {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // The square root makes the density uniform over the area instead of the radius.
+        let r = self.radius * rng.gen::<f32>().sqrt();
+        unit_circle(rng) * r
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        unit_circle(rng) * self.radius
+    }
+}
+
+impl ShapeSample for Rectangle {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        Vec2::new(signed_unit(rng), signed_unit(rng)) * self.half_size
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let Vec2 { x, y } = self.half_size;
+        let side = if rng.gen() { 1.0 } else { -1.0 };
+        // Pick the horizontal or vertical sides in proportion to their length.
+        if choose(rng, x, x + y) {
+            Vec2::new(signed_unit(rng) * x, side * y)
+        } else {
+            Vec2::new(side * x, signed_unit(rng) * y)
+        }
+    }
+}
+
+impl ShapeSample for Capsule2d {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let rectangle_area = 4.0 * self.radius * self.half_length;
+        if choose(
+            rng,
+            rectangle_area,
+            rectangle_area + Circle::new(self.radius).area(),
+        ) {
+            Rectangle {
+                half_size: Vec2::new(self.radius, self.half_length),
+            }
+            .sample_interior(rng)
+        } else {
+            // Split the circle in two semicircles at the ends of the capsule.
+            let point = Circle::new(self.radius).sample_interior(rng);
+            point + Vec2::Y * self.half_length.copysign(point.y)
+        }
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let sides_length = 4.0 * self.half_length;
+        if choose(
+            rng,
+            sides_length,
+            sides_length + Circle::new(self.radius).perimeter(),
+        ) {
+            let side = if rng.gen() { 1.0 } else { -1.0 };
+            Vec2::new(side * self.radius, signed_unit(rng) * self.half_length)
+        } else {
+            let point = Circle::new(self.radius).sample_boundary(rng);
+            point + Vec2::Y * self.half_length.copysign(point.y)
+        }
+    }
+}
+
+impl ShapeSample for Sphere {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // The cube root makes the density uniform over the volume instead of the radius.
+        let r = self.radius * rng.gen::<f32>().cbrt();
+        unit_sphere(rng) * r
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        unit_sphere(rng) * self.radius
+    }
+}
+
+impl ShapeSample for Cuboid {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        Vec3::new(signed_unit(rng), signed_unit(rng), signed_unit(rng)) * self.half_size
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let Vec3 { x, y, z } = self.half_size;
+        let side = if rng.gen() { 1.0 } else { -1.0 };
+        // Pick a pair of opposite faces in proportion to their area, then a point on them.
+        let (yz, xz, xy) = (y * z, x * z, x * y);
+        let mut point = Vec3::new(signed_unit(rng), signed_unit(rng), signed_unit(rng));
+        if choose(rng, yz, yz + xz + xy) {
+            point.x = side;
+        } else if choose(rng, xz, xz + xy) {
+            point.y = side;
+        } else {
+            point.z = side;
+        }
+        point * self.half_size
+    }
+}
+
+impl ShapeSample for Cylinder {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let Vec2 { x, y: z } = self.base().sample_interior(rng);
+        let y = signed_unit(rng) * self.half_height;
+        Vec3::new(x, y, z)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let caps_area = 2.0 * self.base().area();
+        if choose(rng, caps_area, caps_area + self.lateral_area()) {
+            let Vec2 { x, y: z } = self.base().sample_interior(rng);
+            let side = if rng.gen() { 1.0 } else { -1.0 };
+            Vec3::new(x, side * self.half_height, z)
+        } else {
+            let Vec2 { x, y: z } = self.base().sample_boundary(rng);
+            let y = signed_unit(rng) * self.half_height;
+            Vec3::new(x, y, z)
+        }
+    }
+}
+
+impl ShapeSample for Torus {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // A point of the cross-section of the tube, away from the axis by `offset.x`
+        let mut offset;
+        loop {
+            offset = Circle::new(self.minor_radius).sample_interior(rng);
+            // The density of a point is proportional to the circumference of the circle it
+            // sweeps around the axis, so points outward from the ring are kept more often.
+            if rng.gen::<f32>() * self.outer_radius() <= self.major_radius + offset.x {
+                break;
+            }
+        }
+        let direction = unit_circle(rng);
+        let distance = self.major_radius + offset.x;
+        Vec3::new(direction.x * distance, offset.y, direction.y * distance)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let mut offset;
+        loop {
+            offset = Circle::new(self.minor_radius).sample_boundary(rng);
+            if rng.gen::<f32>() * self.outer_radius() <= self.major_radius + offset.x {
+                break;
+            }
+        }
+        let direction = unit_circle(rng);
+        let distance = self.major_radius + offset.x;
+        Vec3::new(direction.x * distance, offset.y, direction.y * distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const SAMPLES: usize = 10_000;
+
+    /// Counts the samples in each of the 4 quadrants, checking they are spread evenly.
+    fn assert_quadrants_are_even(points: impl Iterator<Item = Vec2>) {
+        let mut quadrants = [0; 4];
+        for point in points {
+            let index = (point.x >= 0.0) as usize + 2 * (point.y >= 0.0) as usize;
+            quadrants[index] += 1;
+        }
+        for count in quadrants {
+            let fraction = count as f32 / SAMPLES as f32;
+            assert!((fraction - 0.25).abs() < 0.02, "{quadrants:?}");
+        }
+    }
+
+    #[test]
+    fn circle_interior_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let circle = Circle::new(2.0);
+        let points: Vec<_> = (0..SAMPLES)
+            .map(|_| circle.sample_interior(&mut rng))
+            .collect();
+        assert!(points.iter().all(|point| circle.contains_point(*point)));
+        // Half of the area is inside the circle with a radius divided by the square root of 2.
+        let inner = Circle::new(2.0 / 2f32.sqrt());
+        let inside = points.iter().filter(|point| inner.contains_point(**point));
+        assert!((inside.count() as f32 / SAMPLES as f32 - 0.5).abs() < 0.02);
+        assert_quadrants_are_even(points.into_iter());
+    }
+
+    #[test]
+    fn circle_boundary_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let circle = Circle::new(2.0);
+        let points: Vec<_> = (0..SAMPLES)
+            .map(|_| circle.sample_boundary(&mut rng))
+            .collect();
+        assert!(points
+            .iter()
+            .all(|point| (point.length() - 2.0).abs() < 1e-5));
+        assert_quadrants_are_even(points.into_iter());
+    }
+
+    #[test]
+    fn rectangle_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let rectangle = Rectangle::new(4.0, 1.0);
+        let interior: Vec<_> = (0..SAMPLES)
+            .map(|_| rectangle.sample_interior(&mut rng))
+            .collect();
+        assert!(interior
+            .iter()
+            .all(|point| rectangle.contains_point(*point)));
+        assert_quadrants_are_even(interior.into_iter());
+
+        let boundary: Vec<_> = (0..SAMPLES)
+            .map(|_| rectangle.sample_boundary(&mut rng))
+            .collect();
+        assert!(boundary.iter().all(|point| {
+            rectangle.contains_point(*point) && point.abs().cmpeq(rectangle.half_size).any()
+        }));
+        // The horizontal sides make up 80% of the perimeter.
+        let horizontal = boundary
+            .iter()
+            .filter(|point| point.y.abs() == 0.5 && point.x.abs() != 2.0);
+        assert!((horizontal.count() as f32 / SAMPLES as f32 - 0.8).abs() < 0.02);
+        assert_quadrants_are_even(boundary.into_iter());
+    }
+
+    #[test]
+    fn capsule_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let capsule = Capsule2d::new(1.0, 2.0);
+        let interior: Vec<_> = (0..SAMPLES)
+            .map(|_| capsule.sample_interior(&mut rng))
+            .collect();
+        assert!(interior.iter().all(|point| capsule.contains_point(*point)));
+        let in_rectangle = interior.iter().filter(|point| point.y.abs() <= 1.0);
+        let expected = 4.0 / capsule.area();
+        assert!((in_rectangle.count() as f32 / SAMPLES as f32 - expected).abs() < 0.02);
+        assert_quadrants_are_even(interior.into_iter());
+
+        let boundary: Vec<_> = (0..SAMPLES)
+            .map(|_| capsule.sample_boundary(&mut rng))
+            .collect();
+        assert!(boundary.iter().all(|point| {
+            let segment_point = Vec2::new(0.0, point.y.clamp(-1.0, 1.0));
+            (point.distance(segment_point) - 1.0).abs() < 1e-5
+        }));
+        assert_quadrants_are_even(boundary.into_iter());
+    }
+
+    #[test]
+    fn sphere_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let sphere = Sphere::new(1.0);
+        let interior: Vec<_> = (0..SAMPLES)
+            .map(|_| sphere.sample_interior(&mut rng))
+            .collect();
+        assert!(interior.iter().all(|point| point.length() <= 1.0 + 1e-6));
+        // An eighth of the volume is inside the sphere with half the radius.
+        let inside = interior.iter().filter(|point| point.length() <= 0.5);
+        assert!((inside.count() as f32 / SAMPLES as f32 - 0.125).abs() < 0.02);
+        assert_quadrants_are_even(interior.iter().map(|point| point.truncate()));
+        assert_quadrants_are_even(interior.iter().map(|point| Vec2::new(point.y, point.z)));
+
+        let boundary: Vec<_> = (0..SAMPLES)
+            .map(|_| sphere.sample_boundary(&mut rng))
+            .collect();
+        assert!(boundary
+            .iter()
+            .all(|point| (point.length() - 1.0).abs() < 1e-5));
+        // The cap above a height of 0.5 has a quarter of the area of the sphere.
+        let cap = boundary.iter().filter(|point| point.y >= 0.5);
+        assert!((cap.count() as f32 / SAMPLES as f32 - 0.25).abs() < 0.02);
+        assert_quadrants_are_even(boundary.iter().map(|point| point.truncate()));
+    }
+
+    #[test]
+    fn cuboid_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let cuboid = Cuboid::new(1.0, 2.0, 3.0);
+        let interior: Vec<_> = (0..SAMPLES)
+            .map(|_| cuboid.sample_interior(&mut rng))
+            .collect();
+        assert!(interior.iter().all(|point| cuboid.contains_point(*point)));
+        assert_quadrants_are_even(interior.iter().map(|point| point.truncate()));
+
+        let boundary: Vec<_> = (0..SAMPLES)
+            .map(|_| cuboid.sample_boundary(&mut rng))
+            .collect();
+        assert!(boundary.iter().all(|point| {
+            cuboid.contains_point(*point) && point.abs().cmpeq(cuboid.half_size).any()
+        }));
+        // The faces facing the x axis make up 12 of the 22 square units of the surface.
+        let x_faces = boundary.iter().filter(|point| point.x.abs() == 0.5);
+        assert!((x_faces.count() as f32 / SAMPLES as f32 - 12.0 / 22.0).abs() < 0.02);
+        assert_quadrants_are_even(boundary.iter().map(|point| Vec2::new(point.y, point.z)));
+    }
+
+    #[test]
+    fn cylinder_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let cylinder = Cylinder::new(1.0, 2.0);
+        let interior: Vec<_> = (0..SAMPLES)
+            .map(|_| cylinder.sample_interior(&mut rng))
+            .collect();
+        assert!(interior.iter().all(|point| cylinder.contains_point(*point)));
+        assert_quadrants_are_even(interior.iter().map(|point| point.truncate()));
+
+        let boundary: Vec<_> = (0..SAMPLES)
+            .map(|_| cylinder.sample_boundary(&mut rng))
+            .collect();
+        assert!(boundary.iter().all(|point| {
+            let horizontal = Vec2::new(point.x, point.z).length();
+            let on_caps = point.y.abs() == 1.0 && horizontal <= 1.0 + 1e-5;
+            let on_side = (horizontal - 1.0).abs() < 1e-5 && point.y.abs() <= 1.0;
+            on_caps || on_side
+        }));
+        // The caps make up a third of the surface.
+        let caps = boundary.iter().filter(|point| point.y.abs() == 1.0);
+        assert!((caps.count() as f32 / SAMPLES as f32 - 1.0 / 3.0).abs() < 0.02);
+        assert_quadrants_are_even(boundary.iter().map(|point| Vec2::new(point.x, point.z)));
+    }
+
+    #[test]
+    fn torus_sampling() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let torus = Torus::new(1.0, 3.0);
+        let interior: Vec<_> = (0..SAMPLES)
+            .map(|_| torus.sample_interior(&mut rng))
+            .collect();
+        assert!(interior.iter().all(|point| {
+            let ring_point = Vec3::new(point.x, 0.0, point.z).normalize() * torus.major_radius;
+            point.distance(ring_point) <= torus.minor_radius + 1e-5
+        }));
+        // The outer half of the tube is further from the axis, so it has more of the volume.
+        // With Pappus's centroid theorem, it has `(R + 4r / 3π) / 2R` of the volume.
+        let outer = interior
+            .iter()
+            .filter(|point| Vec2::new(point.x, point.z).length() >= torus.major_radius);
+        let expected = (2.0 + 4.0 / (3.0 * std::f32::consts::PI)) / 4.0;
+        assert!((outer.count() as f32 / SAMPLES as f32 - expected).abs() < 0.02);
+        assert_quadrants_are_even(interior.iter().map(|point| Vec2::new(point.x, point.z)));
+
+        let boundary: Vec<_> = (0..SAMPLES)
+            .map(|_| torus.sample_boundary(&mut rng))
+            .collect();
+        assert!(boundary.iter().all(|point| {
+            let ring_point = Vec3::new(point.x, 0.0, point.z).normalize() * torus.major_radius;
+            (point.distance(ring_point) - torus.minor_radius).abs() < 1e-5
+        }));
+        // The outer half of the surface has `(R + 2r / π) / 2R` of the area.
+        let outer = boundary
+            .iter()
+            .filter(|point| Vec2::new(point.x, point.z).length() >= torus.major_radius);
+        let expected = (2.0 + 2.0 / std::f32::consts::PI) / 4.0;
+        assert!((outer.count() as f32 / SAMPLES as f32 - expected).abs() < 0.02);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::UVec2;
+
+/// A rectangle defined by two opposite corners, with unsigned integer coordinates.
+///
+/// The rectangle is axis aligned, and defined by its minimum and maximum coordinates,
+/// stored in `URect::min` and `URect::max`, respectively. It contains the points with
+/// `min <= point < max`, so that it can describe a region of pixels. The minimum/maximum
+/// invariant must be upheld by the user when directly assigning the fields, otherwise some
+/// methods produce invalid results.
+#[repr(C)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct URect {
+    /// The minimum corner point of the rect.
+    pub min: UVec2,
+    /// The maximum corner point of the rect.
+    pub max: UVec2,
+}
+
+impl URect {
+    /// Create a new rectangle from two corner points.
+    ///
+    /// The two points do not need to be the minimum and/or maximum corners.
+    /// They only need to be two opposite corners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::URect;
+    /// let r = URect::new(0, 4, 10, 6); // w=10 h=2
+    /// let r = URect::new(2, 4, 5, 0); // w=3 h=4
+    /// ```
+    #[inline]
+    pub fn new(x0: u32, y0: u32, x1: u32, y1: u32) -> Self {
+        Self::from_corners(UVec2::new(x0, y0), UVec2::new(x1, y1))
+    }
+
+    /// Create a new rectangle from two corner points.
+    ///
+    /// The two points do not need to be the minimum and/or maximum corners.
+    /// They only need to be two opposite corners.
+    #[inline]
+    pub fn from_corners(p0: UVec2, p1: UVec2) -> Self {
+        URect {
+            min: p0.min(p1),
+            max: p0.max(p1),
+        }
+    }
+
+    /// Check if the rectangle is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::URect;
+    /// let r = URect::new(0, 0, 0, 1); // w=0 h=1
+    /// assert!(r.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpge(self.max).any()
+    }
+
+    /// Rectangle width (max.x - min.x).
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.max.x - self.min.x
+    }
+
+    /// Rectangle height (max.y - min.y).
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.max.y - self.min.y
+    }
+
+    /// Rectangle size.
+    #[inline]
+    pub fn size(&self) -> UVec2 {
+        self.max - self.min
+    }
+
+    /// Check if a point lies within this rectangle, excluding its maximum edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{URect, UVec2};
+    /// let r = URect::new(0, 0, 5, 1); // w=5 h=1
+    /// assert!(r.contains(UVec2::new(4, 0)));
+    /// assert!(!r.contains(UVec2::new(5, 0)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: UVec2) -> bool {
+        (point.cmpge(self.min) & point.cmplt(self.max)).all()
+    }
+
+    /// Build a new rectangle formed of the union of this rectangle and another rectangle.
+    ///
+    /// The union is the smallest rectangle enclosing both rectangles.
+    #[inline]
+    pub fn union(&self, other: URect) -> URect {
+        URect {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Build a new rectangle formed of the intersection of this rectangle and another rectangle.
+    ///
+    /// The intersection is the largest rectangle enclosed in both rectangles. If the intersection
+    /// is empty, this method returns an empty rectangle ([`URect::is_empty()`] returns `true`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{URect, UVec2};
+    /// let r1 = URect::new(0, 0, 5, 1); // w=5 h=1
+    /// let r2 = URect::new(1, 0, 3, 3); // w=2 h=3
+    /// let r = r1.intersect(r2);
+    /// assert_eq!(r, URect::new(1, 0, 3, 1));
+    /// ```
+    #[inline]
+    pub fn intersect(&self, other: URect) -> URect {
+        let mut r = URect {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        };
+        // Collapse min over max to enforce invariants and ensure e.g. width() or
+        // height() never underflow.
+        r.min = r.min.min(r.max);
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersect_disjoint_is_empty() {
+        let r = URect::new(0, 0, 4, 4).intersect(URect::new(6, 1, 8, 2));
+        assert!(r.is_empty());
+        assert_eq!(r.width(), 0);
+        assert_eq!(r.height(), 1);
+    }
+
+    #[test]
+    fn union_encloses_both() {
+        let r = URect::new(0, 2, 4, 4).union(URect::new(3, 0, 8, 1));
+        assert_eq!(r, URect::new(0, 0, 8, 4));
+        assert_eq!(r.size(), UVec2::new(8, 4));
+    }
+}
@@ -17,6 +17,7 @@ use bevy_render::{color::Color, extract_component::ExtractComponent, prelude::Ca
 /// - [`FogFalloff::Exponential`]
 /// - [`FogFalloff::ExponentialSquared`]
 /// - [`FogFalloff::Atmospheric`]
+/// - [`FogFalloff::Height`]
 ///
 /// ## Example
 ///
@@ -300,6 +301,46 @@ pub enum FogFalloff {
         /// For artistic control, use the [`FogFalloff::from_visibility_colors()`] convenience method.
         inscattering: Vec3,
     },
+
+    /// An exponential fog falloff whose density also decreases exponentially with height, for fog
+    /// that gathers in valleys and thins out towards the sky.
+    ///
+    /// The density is integrated along the view ray, so the fog looks consistent whether the camera
+    /// is inside the fog, above it, or looking at it from below.
+    ///
+    /// ## Formula
+    ///
+    /// The density at a given `height` in world units is:
+    ///
+    /// ```text
+    /// let local_density = density * (-(height - base_height) / falloff_height).exp();
+    /// ```
+    ///
+    /// Integrating it along the view ray gives the optical depth, which is used like the distance
+    /// times density of [`FogFalloff::Exponential`]:
+    ///
+    /// ```text
+    /// let fog_intensity = 1.0 - 1.0 / optical_depth.exp();
+    /// let result = input_color * (1.0 - fog_intensity) + fog_color * inscattering * fog_intensity;
+    /// ```
+    Height {
+        /// The density of the fog at `base_height`, similar to [`FogFalloff::Exponential`]'s `density`.
+        density: f32,
+
+        /// The height in world units at which the fog has the given `density`.
+        base_height: f32,
+
+        /// The height in world units over which the density of the fog decreases by a factor of `e`.
+        ///
+        /// Smaller values make a thin layer of ground fog, larger values a thick haze.
+        falloff_height: f32,
+
+        /// The color of the light scattered toward the camera by the fog, which tints the fog color.
+        ///
+        /// Use [`Color::WHITE`] for fog of the [`FogSettings`] `color`, or darker colors for
+        /// smoke that absorbs more light than it scatters.
+        inscattering: Color,
+    },
 }
 
 impl FogFalloff {
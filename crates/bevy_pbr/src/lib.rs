@@ -9,6 +9,7 @@ mod material;
 mod pbr_material;
 mod prepass;
 mod render;
+mod ssao;
 
 pub use alpha::*;
 pub use bundle::*;
@@ -19,6 +20,7 @@ pub use material::*;
 pub use pbr_material::*;
 pub use prepass::*;
 pub use render::*;
+pub use ssao::*;
 
 pub mod prelude {
     #[doc(hidden)]
@@ -37,9 +39,22 @@ pub mod prelude {
 }
 
 pub mod draw_3d_graph {
+    use bevy_render::render_graph::RenderLabel;
+
+    /// The labels of the nodes added to the `Core3d` sub graph by this crate.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderLabel)]
+    pub enum NodePbr {
+        ShadowPass,
+        ScreenSpaceAmbientOcclusion,
+    }
+
     pub mod node {
+        use super::NodePbr;
+
         /// Label for the shadow pass node.
-        pub const SHADOW_PASS: &str = "shadow_pass";
+        pub const SHADOW_PASS: NodePbr = NodePbr::ShadowPass;
+        /// Label for the screen space ambient occlusion node.
+        pub const SCREEN_SPACE_AMBIENT_OCCLUSION: NodePbr = NodePbr::ScreenSpaceAmbientOcclusion;
     }
 }
 
@@ -49,6 +64,7 @@ use bevy_ecs::prelude::*;
 use bevy_reflect::TypeUuid;
 use bevy_render::{
     camera::CameraUpdateSystem,
+    extract_component::ExtractComponentPlugin,
     extract_resource::ExtractResourcePlugin,
     prelude::Color,
     render_graph::RenderGraph,
@@ -80,6 +96,8 @@ pub const PBR_FUNCTIONS_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 16550102964439850292);
 pub const PBR_AMBIENT_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 2441520459096337034);
+pub const OIT_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 10390843132791819760);
 
 /// Sets up the entire PBR infrastructure of bevy.
 pub struct PbrPlugin {
@@ -141,6 +159,7 @@ impl Plugin for PbrPlugin {
             "render/pbr_ambient.wgsl",
             Shader::from_wgsl
         );
+        load_internal_asset!(app, OIT_SHADER_HANDLE, "render/oit.wgsl", Shader::from_wgsl);
         load_internal_asset!(app, PBR_SHADER_HANDLE, "render/pbr.wgsl", Shader::from_wgsl);
         load_internal_asset!(
             app,
@@ -162,6 +181,7 @@ impl Plugin for PbrPlugin {
             .register_type::<DirectionalLightShadowMap>()
             .register_type::<PointLight>()
             .register_type::<PointLightShadowMap>()
+            .register_type::<ShadowFilteringMethod>()
             .register_type::<SpotLight>()
             .add_plugin(MeshRenderPlugin)
             .add_plugin(MaterialPlugin::<StandardMaterial> {
@@ -174,6 +194,7 @@ impl Plugin for PbrPlugin {
             .init_resource::<DirectionalLightShadowMap>()
             .init_resource::<PointLightShadowMap>()
             .add_plugin(ExtractResourcePlugin::<AmbientLight>::default())
+            .add_plugin(ExtractComponentPlugin::<ShadowFilteringMethod>::default())
             .configure_sets(
                 (
                     SimulationLightSystems::AddClusters,
@@ -254,10 +275,14 @@ impl Plugin for PbrPlugin {
                 (
                     render::extract_clusters.in_set(RenderLightSystems::ExtractClusters),
                     render::extract_lights.in_set(RenderLightSystems::ExtractLights),
+                    render::extract_light_textures.in_set(RenderLightSystems::ExtractLights),
                 )
                     .in_schedule(ExtractSchedule),
             )
             .add_systems((
+                render::prepare_light_textures
+                    .before(render::prepare_lights)
+                    .in_set(RenderLightSystems::PrepareLights),
                 render::prepare_lights
                     .before(ViewSet::PrepareUniforms)
                     .in_set(RenderLightSystems::PrepareLights),
@@ -274,7 +299,8 @@ impl Plugin for PbrPlugin {
             ))
             .init_resource::<ShadowSamplers>()
             .init_resource::<LightMeta>()
-            .init_resource::<GlobalLightMeta>();
+            .init_resource::<GlobalLightMeta>()
+            .init_resource::<LightTextures>();
 
         let shadow_pass_node = ShadowPassNode::new(&mut render_app.world);
         let mut graph = render_app.world.resource_mut::<RenderGraph>();
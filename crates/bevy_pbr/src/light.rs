@@ -1,16 +1,19 @@
 use std::collections::HashSet;
 
+use bevy_asset::Handle;
 use bevy_ecs::prelude::*;
 use bevy_math::{Mat4, Rect, UVec2, UVec3, Vec2, Vec3, Vec3A, Vec3Swizzles, Vec4, Vec4Swizzles};
 use bevy_reflect::prelude::*;
 use bevy_render::{
     camera::Camera,
     color::Color,
+    extract_component::ExtractComponent,
     extract_resource::ExtractResource,
     prelude::Projection,
     primitives::{Aabb, CascadesFrusta, CubemapFrusta, Frustum, Plane, Sphere},
     render_resource::BufferBindingType,
     renderer::RenderDevice,
+    texture::Image,
     view::{ComputedVisibility, RenderLayers, VisibleEntities},
 };
 use bevy_transform::{components::GlobalTransform, prelude::Transform};
@@ -40,7 +43,7 @@ use crate::{
 /// | 4000 | 300 |    | 75-100 | 40.5  |
 ///
 /// Source: [Wikipedia](https://en.wikipedia.org/wiki/Lumen_(unit)#Lighting)
-#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component, Default)]
 pub struct PointLight {
     pub color: Color,
@@ -53,6 +56,15 @@ pub struct PointLight {
     /// shadow map's texel size so that it can be small close to the camera and gets larger further
     /// away.
     pub shadow_normal_bias: f32,
+    /// A cube map the light is filtered through, like a light cookie. The color of the texture in
+    /// the direction from the light to a fragment multiplies the light reaching that fragment.
+    ///
+    /// The image must have six array layers, one per cube face. All point light textures in a
+    /// scene must share the same size, format and mip level count; textures that don't match the
+    /// first one are ignored with a warning. The cube map is aligned with the world axes.
+    ///
+    /// On WebGL2 only one point light texture can be used at a time.
+    pub light_texture: Option<Handle<Image>>,
 }
 
 impl Default for PointLight {
@@ -66,6 +78,7 @@ impl Default for PointLight {
             shadows_enabled: false,
             shadow_depth_bias: Self::DEFAULT_SHADOW_DEPTH_BIAS,
             shadow_normal_bias: Self::DEFAULT_SHADOW_NORMAL_BIAS,
+            light_texture: None,
         }
     }
 }
@@ -91,7 +104,7 @@ impl Default for PointLightShadowMap {
 /// Behaves like a point light in a perfectly absorbent housing that
 /// shines light only in a given direction. The direction is taken from
 /// the transform, and can be specified with [`Transform::looking_at`](bevy_transform::components::Transform::looking_at).
-#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component, Default)]
 pub struct SpotLight {
     pub color: Color,
@@ -115,6 +128,16 @@ pub struct SpotLight {
     /// Light is attenuated from `inner_angle` to `outer_angle` to give a smooth falloff.
     /// `inner_angle` should be <= `outer_angle`
     pub inner_angle: f32,
+    /// A texture projected through the light's cone, like a light cookie. The color of the texture
+    /// multiplies the light reaching each fragment.
+    ///
+    /// The texture is projected like the light's shadow map: its corners lie on the square that
+    /// encloses the cone at `outer_angle`, and it is oriented from the light's direction only.
+    /// All spot light textures in a scene must share the same size, format and mip level count;
+    /// textures that don't match the first one are ignored with a warning.
+    ///
+    /// On WebGL2 only one spot light texture can be used at a time.
+    pub light_texture: Option<Handle<Image>>,
 }
 
 impl SpotLight {
@@ -136,6 +159,7 @@ impl Default for SpotLight {
             shadow_normal_bias: Self::DEFAULT_SHADOW_NORMAL_BIAS,
             inner_angle: 0.0,
             outer_angle: std::f32::consts::FRAC_PI_4,
+            light_texture: None,
         }
     }
 }
@@ -217,6 +241,33 @@ impl DirectionalLight {
     pub const DEFAULT_SHADOW_NORMAL_BIAS: f32 = 0.6;
 }
 
+/// Add this component to a 3D camera to choose how the shadow maps of [`DirectionalLight`]s and
+/// [`SpotLight`]s are filtered when rendering that camera's view.
+///
+/// Softer filters take more shadow map samples, so lowering the filter on some cameras can be used
+/// as a low-end quality setting. [`PointLight`] shadows always use [`Hardware2x2`] filtering.
+///
+/// [`Hardware2x2`]: ShadowFilteringMethod::Hardware2x2
+#[derive(
+    Component, ExtractComponent, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash,
+)]
+#[reflect(Component, Default)]
+#[extract_component_filter(With<Camera>)]
+pub enum ShadowFilteringMethod {
+    /// A single hardware 2x2 percentage-closer filtered sample, which gives hard, aliased
+    /// shadow edges. This is the cheapest method.
+    #[default]
+    Hardware2x2,
+    /// Nine hardware samples weighted to approximate a 5x5 Gaussian kernel, as described in
+    /// [Castaño, 2013](https://web.archive.org/web/20230210095515/http://the-witness.net/news/2013/09/shadow-mapping-summary-part-1).
+    /// Gives smooth shadow edges that are stable over time.
+    Gaussian,
+    /// Eight hardware samples on a disk that is randomly rotated per pixel and per frame, as
+    /// described in [Jimenez, 2014](https://www.iryoku.com/next-generation-post-processing-in-call-of-duty-advanced-warfare).
+    /// The noise is meant to be resolved by a temporal anti-aliasing pass.
+    Temporal,
+}
+
 /// Controls the resolution of [`DirectionalLight`] shadow maps.
 #[derive(Resource, Clone, Debug, Reflect)]
 #[reflect(Resource)]
@@ -254,6 +305,21 @@ pub struct CascadeShadowConfig {
     pub minimum_distance: f32,
 }
 
+impl CascadeShadowConfig {
+    /// The (positive) distance to the near boundary of the cascade at `cascade_index`.
+    ///
+    /// Each cascade after the first starts `overlap_proportion` of the previous cascade's far
+    /// bound before that bound. Fragments in the overlap blend linearly from the previous cascade's
+    /// shadow map to this one's, so that cascades don't visibly pop at their split.
+    pub fn near_bound(&self, cascade_index: usize) -> f32 {
+        if cascade_index == 0 {
+            self.minimum_distance
+        } else {
+            (1.0 - self.overlap_proportion) * self.bounds[cascade_index - 1]
+        }
+    }
+}
+
 impl Default for CascadeShadowConfig {
     fn default() -> Self {
         CascadeShadowConfigBuilder::default().into()
@@ -445,12 +511,7 @@ pub fn update_directional_light_cascades(
                 .enumerate()
                 .map(|(idx, far_bound)| {
                     // Negate bounds as -z is camera forward direction.
-                    let z_near = if idx > 0 {
-                        (1.0 - cascades_config.overlap_proportion)
-                            * -cascades_config.bounds[idx - 1]
-                    } else {
-                        -cascades_config.minimum_distance
-                    };
+                    let z_near = -cascades_config.near_bound(idx);
                     let z_far = -far_bound;
 
                     let corners = match projection {
@@ -2228,4 +2289,52 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_cascade_bounds_are_exponentially_spaced() {
+        let bounds = calculate_cascade_bounds(4, 5.0, 1000.0);
+        assert_eq!(bounds.len(), 4);
+        assert!((bounds[0] - 5.0).abs() < 1e-4);
+        assert!((bounds[3] - 1000.0).abs() < 1e-2);
+        let ratio = bounds[1] / bounds[0];
+        for pair in bounds.windows(2) {
+            assert!((pair[1] / pair[0] - ratio).abs() < 1e-4);
+        }
+
+        assert_eq!(calculate_cascade_bounds(1, 5.0, 1000.0), vec![1000.0]);
+    }
+
+    #[test]
+    fn test_cascades_overlap_by_the_overlap_proportion() {
+        let config: CascadeShadowConfig = CascadeShadowConfigBuilder {
+            num_cascades: 3,
+            minimum_distance: 0.5,
+            maximum_distance: 100.0,
+            first_cascade_far_bound: 10.0,
+            overlap_proportion: 0.25,
+        }
+        .into();
+
+        assert_eq!(config.near_bound(0), 0.5);
+        for cascade_index in 1..config.bounds.len() {
+            let previous_far_bound = config.bounds[cascade_index - 1];
+            // the blend band where both cascades are sampled spans the last quarter of the
+            // previous cascade
+            let near_bound = config.near_bound(cascade_index);
+            assert!((near_bound - 0.75 * previous_far_bound).abs() < 1e-4);
+            assert!(near_bound > config.near_bound(cascade_index - 1));
+        }
+
+        let no_overlap: CascadeShadowConfig = CascadeShadowConfigBuilder {
+            overlap_proportion: 0.0,
+            ..Default::default()
+        }
+        .into();
+        for cascade_index in 1..no_overlap.bounds.len() {
+            assert_eq!(
+                no_overlap.near_bound(cascade_index),
+                no_overlap.bounds[cascade_index - 1]
+            );
+        }
+    }
 }
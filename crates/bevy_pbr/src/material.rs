@@ -1,12 +1,14 @@
 use crate::{
     render, AlphaMode, DrawMesh, DrawPrepass, EnvironmentMapLight, MeshPipeline, MeshPipelineKey,
-    MeshUniform, PrepassPipelinePlugin, PrepassPlugin, RenderLightSystems, SetMeshBindGroup,
-    SetMeshViewBindGroup, Shadow,
+    MeshUniform, PrepassPipelinePlugin, PrepassPlugin, RenderLightSystems,
+    ScreenSpaceAmbientOcclusionSettings, SetMeshBindGroup, SetMeshViewBindGroup, Shadow,
+    ShadowFilteringMethod,
 };
 use bevy_app::{App, IntoSystemAppConfig, Plugin};
-use bevy_asset::{AddAsset, AssetEvent, AssetServer, Assets, Handle};
+use bevy_asset::{AddAsset, AssetEvent, AssetServer, Assets, Handle, HandleId};
 use bevy_core_pipeline::{
     core_3d::{AlphaMask3d, Opaque3d, Transparent3d},
+    oit::ViewOitBuffers,
     tonemapping::{DebandDither, Tonemapping},
 };
 use bevy_derive::{Deref, DerefMut};
@@ -19,7 +21,6 @@ use bevy_ecs::{
 };
 use bevy_reflect::TypeUuid;
 use bevy_render::{
-    extract_component::ExtractComponentPlugin,
     mesh::{Mesh, MeshVertexBufferLayout},
     prelude::Image,
     render_asset::{PrepareAssetSet, RenderAssets},
@@ -34,7 +35,7 @@ use bevy_render::{
     },
     renderer::RenderDevice,
     texture::FallbackImage,
-    view::{ExtractedView, Msaa, VisibleEntities},
+    view::{ComputedVisibility, ExtractedView, VisibleEntities},
     Extract, ExtractSchedule, RenderApp, RenderSet,
 };
 use bevy_utils::{tracing::error, HashMap, HashSet};
@@ -132,6 +133,18 @@ pub trait Material: AsBindGroup + Send + Sync + Clone + TypeUuid + Sized + 'stat
         0.0
     }
 
+    /// Returns whether this material's shaders read the depth and normal prepass textures of the
+    /// view, with the functions of `bevy_pbr::prepass_utils`. Defaults to `false`.
+    ///
+    /// The textures are only declared in the shaders of materials returning `true`. They are filled
+    /// by the prepass when the camera has a [`DepthPrepass`](bevy_core_pipeline::prepass::DepthPrepass)
+    /// or [`NormalPrepass`](bevy_core_pipeline::prepass::NormalPrepass) component, and are
+    /// replaced by 1x1 fallback textures otherwise, whose values shouldn't be relied on.
+    #[inline]
+    fn reads_prepass_textures(&self) -> bool {
+        false
+    }
+
     /// Returns this material's prepass vertex shader. If [`ShaderRef::Default`] is returned, the default prepass vertex shader
     /// will be used.
     fn prepass_vertex_shader() -> ShaderRef {
@@ -168,6 +181,10 @@ pub struct MaterialPlugin<M: Material> {
     /// When it is enabled, it will automatically add the [`PrepassPlugin`]
     /// required to make the prepass work on this Material.
     pub prepass_enabled: bool,
+    /// Controls if meshes with the Material cast shadows.
+    ///
+    /// [`NotShadowCaster`](crate::NotShadowCaster) disables the shadows of single entities instead.
+    pub shadows_enabled: bool,
     pub _marker: PhantomData<M>,
 }
 
@@ -175,6 +192,7 @@ impl<M: Material> Default for MaterialPlugin<M> {
     fn default() -> Self {
         Self {
             prepass_enabled: true,
+            shadows_enabled: true,
             _marker: Default::default(),
         }
     }
@@ -185,8 +203,7 @@ where
     M::Data: PartialEq + Eq + Hash + Clone,
 {
     fn build(&self, app: &mut App) {
-        app.add_asset::<M>()
-            .add_plugin(ExtractComponentPlugin::<Handle<M>>::extract_visible());
+        app.add_asset::<M>();
 
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
@@ -200,13 +217,19 @@ where
                 .init_resource::<RenderMaterials<M>>()
                 .init_resource::<SpecializedMeshPipelines<MaterialPipeline<M>>>()
                 .add_systems((
+                    extract_material_meshes::<M>.in_schedule(ExtractSchedule),
                     extract_materials::<M>.in_schedule(ExtractSchedule),
                     prepare_materials::<M>
                         .in_set(RenderSet::Prepare)
                         .after(PrepareAssetSet::PreAssetPrepare),
-                    render::queue_shadows::<M>.in_set(RenderLightSystems::QueueShadows),
                     queue_material_meshes::<M>.in_set(RenderSet::Queue),
                 ));
+
+            if self.shadows_enabled {
+                render_app.add_system(
+                    render::queue_shadows::<M>.in_set(RenderLightSystems::QueueShadows),
+                );
+            }
         }
 
         // PrepassPipelinePlugin is required for shadow mapping and the optional PrepassPlugin
@@ -337,6 +360,29 @@ type DrawMaterial<M> = (
     DrawMesh,
 );
 
+/// The id of the material of a mesh entity in the render world.
+///
+/// Unlike [`Handle<M>`], this does not depend on the material type, so that automatic batching
+/// can tell apart meshes drawn with different materials.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeshMaterialId(pub HandleId);
+
+/// Extracts the material handles of visible meshes, along with their [`MeshMaterialId`].
+pub fn extract_material_meshes<M: Material>(
+    mut commands: Commands,
+    mut previous_len: Local<usize>,
+    query: Extract<Query<(Entity, &ComputedVisibility, &Handle<M>)>>,
+) {
+    let mut values = Vec::with_capacity(*previous_len);
+    for (entity, visibility, handle) in &query {
+        if visibility.is_visible() {
+            values.push((entity, (handle.clone_weak(), MeshMaterialId(handle.id()))));
+        }
+    }
+    *previous_len = values.len();
+    commands.insert_or_spawn_batch(values);
+}
+
 /// Sets the bind group for a given [`Material`] at the configured `I` index.
 pub struct SetMaterialBindGroup<M: Material, const I: usize>(PhantomData<M>);
 impl<P: PhaseItem, M: Material, const I: usize> RenderCommand<P> for SetMaterialBindGroup<M, I> {
@@ -366,7 +412,6 @@ pub fn queue_material_meshes<M: Material>(
     material_pipeline: Res<MaterialPipeline<M>>,
     mut pipelines: ResMut<SpecializedMeshPipelines<MaterialPipeline<M>>>,
     pipeline_cache: Res<PipelineCache>,
-    msaa: Res<Msaa>,
     render_meshes: Res<RenderAssets<Mesh>>,
     render_materials: Res<RenderMaterials<M>>,
     material_meshes: Query<(&Handle<M>, &Handle<Mesh>, &MeshUniform)>,
@@ -377,6 +422,9 @@ pub fn queue_material_meshes<M: Material>(
         Option<&Tonemapping>,
         Option<&DebandDither>,
         Option<&EnvironmentMapLight>,
+        Option<&ShadowFilteringMethod>,
+        Option<&ScreenSpaceAmbientOcclusionSettings>,
+        Option<&ViewOitBuffers>,
         &mut RenderPhase<Opaque3d>,
         &mut RenderPhase<AlphaMask3d>,
         &mut RenderPhase<Transparent3d>,
@@ -390,6 +438,9 @@ pub fn queue_material_meshes<M: Material>(
         tonemapping,
         dither,
         environment_map,
+        shadow_filtering_method,
+        ssao_settings,
+        oit_buffers,
         mut opaque_phase,
         mut alpha_mask_phase,
         mut transparent_phase,
@@ -399,7 +450,7 @@ pub fn queue_material_meshes<M: Material>(
         let draw_alpha_mask_pbr = alpha_mask_draw_functions.read().id::<DrawMaterial<M>>();
         let draw_transparent_pbr = transparent_draw_functions.read().id::<DrawMaterial<M>>();
 
-        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
+        let mut view_key = MeshPipelineKey::from_msaa_samples(view.msaa.samples())
             | MeshPipelineKey::from_hdr(view.hdr);
 
         let environment_map_loaded = match environment_map {
@@ -410,6 +461,14 @@ pub fn queue_material_meshes<M: Material>(
             view_key |= MeshPipelineKey::ENVIRONMENT_MAP;
         }
 
+        if ssao_settings.is_some() {
+            view_key |= MeshPipelineKey::SCREEN_SPACE_AMBIENT_OCCLUSION;
+        }
+
+        view_key |= MeshPipelineKey::from_shadow_filtering_method(
+            shadow_filtering_method.copied().unwrap_or_default(),
+        );
+
         if !view.hdr {
             if let Some(tonemapping) = tonemapping {
                 view_key |= MeshPipelineKey::TONEMAP_IN_SHADER;
@@ -433,6 +492,13 @@ pub fn queue_material_meshes<M: Material>(
             }
         }
 
+        // Blended meshes store their fragments for the OIT resolve instead of being blended
+        let oit_enabled = oit_buffers.is_some()
+            && material_pipeline
+                .mesh_pipeline
+                .oit_fallback_buffers
+                .is_some();
+
         let rangefinder = view.rangefinder3d();
         for visible_entity in &visible_entities.entities {
             if let Ok((material_handle, mesh_handle, mesh_uniform)) =
@@ -448,6 +514,9 @@ pub fn queue_material_meshes<M: Material>(
                     match material.properties.alpha_mode {
                         AlphaMode::Blend => {
                             mesh_key |= MeshPipelineKey::BLEND_ALPHA;
+                            if oit_enabled {
+                                mesh_key |= MeshPipelineKey::OIT_ENABLED;
+                            }
                         }
                         AlphaMode::Premultiplied | AlphaMode::Add => {
                             // Premultiplied and Add share the same pipeline key
@@ -459,6 +528,9 @@ pub fn queue_material_meshes<M: Material>(
                         }
                         _ => (),
                     }
+                    if material.properties.reads_prepass_textures {
+                        mesh_key |= MeshPipelineKey::READS_PREPASS_TEXTURES;
+                    }
 
                     let pipeline_id = pipelines.specialize(
                         &pipeline_cache,
@@ -483,17 +555,23 @@ pub fn queue_material_meshes<M: Material>(
                         AlphaMode::Opaque => {
                             opaque_phase.add(Opaque3d {
                                 entity: *visible_entity,
+                                mesh: mesh_handle.id(),
                                 draw_function: draw_opaque_pbr,
                                 pipeline: pipeline_id,
                                 distance,
+                                instance_range: 0..1,
+                                dynamic_offset: None,
                             });
                         }
                         AlphaMode::Mask(_) => {
                             alpha_mask_phase.add(AlphaMask3d {
                                 entity: *visible_entity,
+                                mesh: mesh_handle.id(),
                                 draw_function: draw_alpha_mask_pbr,
                                 pipeline: pipeline_id,
                                 distance,
+                                instance_range: 0..1,
+                                dynamic_offset: None,
                             });
                         }
                         AlphaMode::Blend
@@ -505,6 +583,8 @@ pub fn queue_material_meshes<M: Material>(
                                 draw_function: draw_transparent_pbr,
                                 pipeline: pipeline_id,
                                 distance,
+                                instance_range: 0..1,
+                                dynamic_offset: None,
                             });
                         }
                     }
@@ -522,6 +602,8 @@ pub struct MaterialProperties {
     /// for meshes with equal depth, to avoid z-fighting.
     /// The bias is in depth-texture units so large values may be needed to overcome small depth differences.
     pub depth_bias: f32,
+    /// Whether the material reads the depth and normal prepass textures.
+    pub reads_prepass_textures: bool,
 }
 
 /// Data prepared for a [`Material`] instance.
@@ -675,6 +757,7 @@ fn prepare_material<M: Material>(
         properties: MaterialProperties {
             alpha_mode: material.alpha_mode(),
             depth_bias: material.depth_bias(),
+            reads_prepass_textures: material.reads_prepass_textures(),
         },
     })
 }
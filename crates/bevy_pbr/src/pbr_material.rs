@@ -3,10 +3,13 @@ use crate::{
     PBR_SHADER_HANDLE,
 };
 use bevy_asset::Handle;
-use bevy_math::Vec4;
+use bevy_math::{Vec2, Vec4};
 use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeUuid};
 use bevy_render::{
-    color::Color, mesh::MeshVertexBufferLayout, render_asset::RenderAssets, render_resource::*,
+    color::Color,
+    mesh::{Mesh, MeshVertexBufferLayout},
+    render_asset::RenderAssets,
+    render_resource::*,
     texture::Image,
 };
 
@@ -177,6 +180,67 @@ pub struct StandardMaterial {
     #[sampler(8)]
     pub occlusion_texture: Option<Handle<Image>>,
 
+    /// The strength of a thin, glossy layer on top of the base material, within `[0.0, 1.0]`.
+    ///
+    /// This models surfaces such as car paint or varnished wood, where a transparent coating
+    /// adds a second specular highlight over the base material.
+    ///
+    /// Defaults to `0.0`, which disables the clearcoat layer and its shading cost.
+    pub clearcoat: f32,
+
+    /// Linear perceptual roughness of the clearcoat layer, clamped to `[0.089, 1.0]` in the shader.
+    ///
+    /// Has no effect if [`clearcoat`] is `0.0`.
+    ///
+    /// Defaults to `0.0`, for a glossy coating.
+    ///
+    /// [`clearcoat`]: StandardMaterial::clearcoat
+    pub clearcoat_perceptual_roughness: f32,
+
+    /// A normal map for the clearcoat layer, used instead of the geometric normal of the mesh.
+    ///
+    /// The base layer keeps using [`normal_map_texture`], so a smooth coating can be put over
+    /// a bumpy surface. Like [`normal_map_texture`], this requires vertex UVs and tangents.
+    ///
+    /// Has no effect if [`clearcoat`] is `0.0`.
+    ///
+    /// [`clearcoat`]: StandardMaterial::clearcoat
+    /// [`normal_map_texture`]: StandardMaterial::normal_map_texture
+    #[texture(11)]
+    #[sampler(12)]
+    pub clearcoat_normal_texture: Option<Handle<Image>>,
+
+    /// How much the specular highlight is stretched along the direction of the surface,
+    /// within `[0.0, 1.0]`.
+    ///
+    /// This models surfaces such as brushed metal, where microscopic grooves stretch highlights
+    /// along a direction. The direction is the tangent of the mesh, rotated by
+    /// [`anisotropy_rotation`], so the mesh needs vertex tangents.
+    ///
+    /// Defaults to `0.0`, which disables anisotropy and its shading cost.
+    ///
+    /// [`anisotropy_rotation`]: StandardMaterial::anisotropy_rotation
+    pub anisotropy_strength: f32,
+
+    /// The counter-clockwise rotation of the anisotropy direction from the mesh tangent, in radians.
+    ///
+    /// Defaults to `0.0`.
+    pub anisotropy_rotation: f32,
+
+    /// Anisotropy direction and strength, stored as a single texture.
+    ///
+    /// The red and green channels contain the direction in tangent space, mapped from `[-1.0, 1.0]`
+    /// to `[0.0, 1.0]`, which is rotated by [`anisotropy_rotation`].
+    /// The blue channel contains the strength, which is multiplied by [`anisotropy_strength`].
+    ///
+    /// Has no effect if [`anisotropy_strength`] is `0.0`.
+    ///
+    /// [`anisotropy_rotation`]: StandardMaterial::anisotropy_rotation
+    /// [`anisotropy_strength`]: StandardMaterial::anisotropy_strength
+    #[texture(13)]
+    #[sampler(14)]
+    pub anisotropy_texture: Option<Handle<Image>>,
+
     /// Support two-sided lighting by automatically flipping the normals for "back" faces
     /// within the PBR lighting shader.
     ///
@@ -213,6 +277,11 @@ pub struct StandardMaterial {
     pub unlit: bool,
 
     /// Whether to enable fog for this material.
+    ///
+    /// Disable this for materials that shouldn't be covered by the [`FogSettings`](crate::FogSettings)
+    /// of the camera, such as a sky dome or distant emissive signs. This also applies to `unlit` materials.
+    ///
+    /// Defaults to `true`.
     pub fog_enabled: bool,
 
     /// How to apply the alpha channel of the `base_color_texture`.
@@ -252,6 +321,12 @@ impl Default for StandardMaterial {
             // <https://google.github.io/filament/Material%20Properties.pdf>
             reflectance: 0.5,
             occlusion_texture: None,
+            clearcoat: 0.0,
+            clearcoat_perceptual_roughness: 0.0,
+            clearcoat_normal_texture: None,
+            anisotropy_strength: 0.0,
+            anisotropy_rotation: 0.0,
+            anisotropy_texture: None,
             normal_map_texture: None,
             flip_normal_map_y: false,
             double_sided: false,
@@ -302,6 +377,7 @@ bitflags::bitflags! {
         const TWO_COMPONENT_NORMAL_MAP   = (1 << 6);
         const FLIP_NORMAL_MAP_Y          = (1 << 7);
         const FOG_ENABLED                = (1 << 8);
+        const ANISOTROPY_TEXTURE         = (1 << 9);
         const ALPHA_MODE_RESERVED_BITS   = (Self::ALPHA_MODE_MASK_BITS << Self::ALPHA_MODE_SHIFT_BITS); // ← Bitmask reserving bits for the `AlphaMode`
         const ALPHA_MODE_OPAQUE          = (0 << Self::ALPHA_MODE_SHIFT_BITS);                          // ← Values are just sequential values bitshifted into
         const ALPHA_MODE_MASK            = (1 << Self::ALPHA_MODE_SHIFT_BITS);                          //   the bitmask, and can range from 0 to 7.
@@ -341,6 +417,14 @@ pub struct StandardMaterialUniform {
     /// When the alpha mode mask flag is set, any base color alpha above this cutoff means fully opaque,
     /// and any below means fully transparent.
     pub alpha_cutoff: f32,
+    /// From [0.0, 1.0], the strength of the clearcoat layer
+    pub clearcoat: f32,
+    /// Linear perceptual roughness of the clearcoat layer, clamped to [0.089, 1.0] in the shader
+    pub clearcoat_perceptual_roughness: f32,
+    /// From [0.0, 1.0], how much the specular highlight is stretched along the anisotropy direction
+    pub anisotropy_strength: f32,
+    /// The cosine and sine of the anisotropy rotation
+    pub anisotropy_rotation: Vec2,
 }
 
 impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
@@ -367,6 +451,9 @@ impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
         if self.fog_enabled {
             flags |= StandardMaterialFlags::FOG_ENABLED;
         }
+        if self.anisotropy_texture.is_some() {
+            flags |= StandardMaterialFlags::ANISOTROPY_TEXTURE;
+        }
         let has_normal_map = self.normal_map_texture.is_some();
         if has_normal_map {
             if let Some(texture) = images.get(self.normal_map_texture.as_ref().unwrap()) {
@@ -407,6 +494,10 @@ impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
             reflectance: self.reflectance,
             flags: flags.bits(),
             alpha_cutoff,
+            clearcoat: self.clearcoat,
+            clearcoat_perceptual_roughness: self.clearcoat_perceptual_roughness,
+            anisotropy_strength: self.anisotropy_strength,
+            anisotropy_rotation: Vec2::from_angle(self.anisotropy_rotation),
         }
     }
 }
@@ -414,14 +505,21 @@ impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct StandardMaterialKey {
     normal_map: bool,
+    clearcoat: bool,
+    clearcoat_normal_map: bool,
+    anisotropy: bool,
     cull_mode: Option<Face>,
     depth_bias: i32,
 }
 
 impl From<&StandardMaterial> for StandardMaterialKey {
     fn from(material: &StandardMaterial) -> Self {
+        let clearcoat = material.clearcoat > 0.0;
         StandardMaterialKey {
             normal_map: material.normal_map_texture.is_some(),
+            clearcoat,
+            clearcoat_normal_map: clearcoat && material.clearcoat_normal_texture.is_some(),
+            anisotropy: material.anisotropy_strength > 0.0,
             cull_mode: material.cull_mode,
             depth_bias: material.depth_bias as i32,
         }
@@ -432,14 +530,24 @@ impl Material for StandardMaterial {
     fn specialize(
         _pipeline: &MaterialPipeline<Self>,
         descriptor: &mut RenderPipelineDescriptor,
-        _layout: &MeshVertexBufferLayout,
+        layout: &MeshVertexBufferLayout,
         key: MaterialPipelineKey<Self>,
     ) -> Result<(), SpecializedMeshPipelineError> {
-        if key.bind_group_data.normal_map {
-            if let Some(fragment) = descriptor.fragment.as_mut() {
-                fragment
-                    .shader_defs
-                    .push("STANDARDMATERIAL_NORMAL_MAP".into());
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            let shader_defs = &mut fragment.shader_defs;
+            if key.bind_group_data.normal_map {
+                shader_defs.push("STANDARDMATERIAL_NORMAL_MAP".into());
+            }
+            // The extra lobes are only compiled in for materials using them
+            if key.bind_group_data.clearcoat {
+                shader_defs.push("STANDARDMATERIAL_CLEARCOAT".into());
+            }
+            if key.bind_group_data.clearcoat_normal_map {
+                shader_defs.push("STANDARDMATERIAL_CLEARCOAT_NORMAL_MAP".into());
+            }
+            // Anisotropy is oriented by the mesh tangents, so it can't be applied without them
+            if key.bind_group_data.anisotropy && layout.contains(Mesh::ATTRIBUTE_TANGENT) {
+                shader_defs.push("STANDARDMATERIAL_ANISOTROPY".into());
             }
         }
         descriptor.primitive.cull_mode = key.bind_group_data.cull_mode;
@@ -470,3 +578,54 @@ impl Material for StandardMaterial {
         self.depth_bias
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{StandardMaterial, StandardMaterialFlags, StandardMaterialUniform};
+    use bevy_asset::Handle;
+    use bevy_math::Vec2;
+    use bevy_render::{
+        render_asset::RenderAssets,
+        render_resource::{AsBindGroupShaderType, ShaderType},
+        texture::Image,
+    };
+
+    #[test]
+    fn uniform_matches_the_shader_layout() {
+        // Must match the size of `StandardMaterial` in bevy_pbr/src/render/pbr_types.wgsl:
+        // two vec4s, nine scalars and a vec2 aligned to 8 bytes, padded to a multiple of 16.
+        assert_eq!(StandardMaterialUniform::min_size().get(), 80);
+    }
+
+    #[test]
+    fn clearcoat_and_anisotropy_are_packed() {
+        let material = StandardMaterial {
+            clearcoat: 0.75,
+            clearcoat_perceptual_roughness: 0.25,
+            anisotropy_strength: 0.5,
+            anisotropy_rotation: std::f32::consts::FRAC_PI_2,
+            anisotropy_texture: Some(Handle::default()),
+            ..Default::default()
+        };
+        let uniform: StandardMaterialUniform =
+            material.as_bind_group_shader_type(&RenderAssets::<Image>::default());
+
+        assert_eq!(uniform.clearcoat, 0.75);
+        assert_eq!(uniform.clearcoat_perceptual_roughness, 0.25);
+        assert_eq!(uniform.anisotropy_strength, 0.5);
+        assert!(uniform.anisotropy_rotation.abs_diff_eq(Vec2::Y, 1e-6));
+        assert_ne!(
+            uniform.flags & StandardMaterialFlags::ANISOTROPY_TEXTURE.bits(),
+            0
+        );
+
+        let uniform: StandardMaterialUniform =
+            StandardMaterial::default().as_bind_group_shader_type(&RenderAssets::default());
+        assert_eq!(uniform.clearcoat, 0.0);
+        assert_eq!(uniform.anisotropy_rotation, Vec2::X);
+        assert_eq!(
+            uniform.flags & StandardMaterialFlags::ANISOTROPY_TEXTURE.bits(),
+            0
+        );
+    }
+}
@@ -1,10 +1,11 @@
-use bevy_app::{IntoSystemAppConfig, Plugin};
+use bevy_app::{CoreSet, IntoSystemAppConfig, Plugin};
 use bevy_asset::{load_internal_asset, AssetServer, Handle, HandleUntyped};
 use bevy_core_pipeline::{
     prelude::Camera3d,
     prepass::{
-        AlphaMask3dPrepass, DepthPrepass, NormalPrepass, Opaque3dPrepass, ViewPrepassTextures,
-        DEPTH_PREPASS_FORMAT, NORMAL_PREPASS_FORMAT,
+        AlphaMask3dPrepass, DepthPrepass, MotionVectorPrepass, NormalPrepass, Opaque3dPrepass,
+        ViewPrepassTextures, DEPTH_PREPASS_FORMAT, MOTION_VECTOR_PREPASS_FORMAT,
+        NORMAL_PREPASS_FORMAT,
     },
 };
 use bevy_ecs::{
@@ -14,6 +15,7 @@ use bevy_ecs::{
         SystemParamItem,
     },
 };
+use bevy_math::{Affine3A, Mat4};
 use bevy_reflect::TypeUuid;
 use bevy_render::{
     camera::ExtractedCamera,
@@ -29,18 +31,21 @@ use bevy_render::{
         BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
         BindGroupLayoutEntry, BindingResource, BindingType, BlendState, BufferBindingType,
         ColorTargetState, ColorWrites, CompareFunction, DepthBiasState, DepthStencilState,
-        Extent3d, FragmentState, FrontFace, MultisampleState, PipelineCache, PolygonMode,
-        PrimitiveState, RenderPipelineDescriptor, Shader, ShaderDefVal, ShaderRef, ShaderStages,
-        ShaderType, SpecializedMeshPipeline, SpecializedMeshPipelineError,
+        DynamicUniformBuffer, Extent3d, FragmentState, FrontFace, MultisampleState, PipelineCache,
+        PolygonMode, PrimitiveState, RenderPipelineDescriptor, Shader, ShaderDefVal, ShaderRef,
+        ShaderStages, ShaderType, SpecializedMeshPipeline, SpecializedMeshPipelineError,
         SpecializedMeshPipelines, StencilFaceState, StencilState, TextureDescriptor,
         TextureDimension, TextureFormat, TextureSampleType, TextureUsages, TextureViewDimension,
         VertexState,
     },
-    renderer::RenderDevice,
+    renderer::{RenderDevice, RenderQueue},
     texture::{FallbackImagesDepth, FallbackImagesMsaa, TextureCache},
-    view::{ExtractedView, Msaa, ViewUniform, ViewUniformOffset, ViewUniforms, VisibleEntities},
+    view::{
+        ExtractedView, Msaa, ViewSet, ViewUniform, ViewUniformOffset, ViewUniforms, VisibleEntities,
+    },
     Extract, ExtractSchedule, RenderApp, RenderSet,
 };
+use bevy_transform::prelude::GlobalTransform;
 use bevy_utils::{tracing::error, HashMap};
 
 use crate::{
@@ -105,6 +110,7 @@ where
             .add_system(queue_prepass_view_bind_group::<M>.in_set(RenderSet::Queue))
             .init_resource::<PrepassPipeline<M>>()
             .init_resource::<PrepassViewBindGroup>()
+            .init_resource::<PreviousViewProjectionUniforms>()
             .init_resource::<SpecializedMeshPipelines<PrepassPipeline<M>>>();
     }
 }
@@ -125,9 +131,30 @@ where
     M::Data: PartialEq + Eq + Hash + Clone,
 {
     fn build(&self, app: &mut bevy_app::App) {
+        let no_prepass_plugin_loaded = app.world.get_resource::<AnyPrepassPluginLoaded>().is_none();
+
+        if no_prepass_plugin_loaded {
+            app.insert_resource(AnyPrepassPluginLoaded).add_systems(
+                (
+                    update_previous_view_projections,
+                    update_mesh_previous_global_transforms,
+                )
+                    .in_base_set(CoreSet::PreUpdate),
+            );
+        }
+
         let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
-            return;
-        };
+                return;
+            };
+
+        if no_prepass_plugin_loaded {
+            render_app.add_systems((
+                extract_camera_previous_view_projection.in_schedule(ExtractSchedule),
+                prepare_previous_view_projection_uniforms
+                    .in_set(RenderSet::Prepare)
+                    .after(ViewSet::PrepareUniforms),
+            ));
+        }
 
         render_app
             .add_systems((
@@ -146,11 +173,58 @@ where
     }
 }
 
+/// Marker resource for whether prepass is enabled globally for this material type
+#[derive(Resource, Debug)]
+struct AnyPrepassPluginLoaded;
+
+/// The previous frame's [`GlobalTransform`] of a mesh, used to compute motion vectors.
+///
+/// It is kept up to date automatically while any active camera has a [`MotionVectorPrepass`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct PreviousGlobalTransform(pub Affine3A);
+
+/// The previous frame's unjittered view projection of a camera, used to compute motion vectors.
+///
+/// It is kept up to date automatically for cameras with a [`MotionVectorPrepass`].
+#[derive(Component, ShaderType, Clone, Copy, Debug, PartialEq)]
+pub struct PreviousViewProjection {
+    pub view_proj: Mat4,
+}
+
+pub fn update_previous_view_projections(
+    mut commands: Commands,
+    query: Query<(Entity, &Camera, &GlobalTransform), (With<Camera3d>, With<MotionVectorPrepass>)>,
+) {
+    for (entity, camera, camera_transform) in &query {
+        commands.entity(entity).insert(PreviousViewProjection {
+            view_proj: camera.projection_matrix() * camera_transform.compute_matrix().inverse(),
+        });
+    }
+}
+
+pub fn update_mesh_previous_global_transforms(
+    mut commands: Commands,
+    views: Query<&Camera, (With<Camera3d>, With<MotionVectorPrepass>)>,
+    meshes: Query<(Entity, &GlobalTransform), With<Handle<Mesh>>>,
+) {
+    let should_run = views.iter().any(|camera| camera.is_active);
+
+    if should_run {
+        for (entity, transform) in &meshes {
+            commands
+                .entity(entity)
+                .insert(PreviousGlobalTransform(transform.affine()));
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct PrepassPipeline<M: Material> {
     pub view_layout: BindGroupLayout,
+    pub view_layout_motion_vectors: BindGroupLayout,
     pub mesh_layout: BindGroupLayout,
     pub skinned_mesh_layout: BindGroupLayout,
+    pub per_object_buffer_batch_size: Option<u32>,
     pub material_layout: BindGroupLayout,
     pub material_vertex_shader: Option<Handle<Shader>>,
     pub material_fragment_shader: Option<Handle<Shader>>,
@@ -163,40 +237,64 @@ impl<M: Material> FromWorld for PrepassPipeline<M> {
         let render_device = world.resource::<RenderDevice>();
         let asset_server = world.resource::<AssetServer>();
 
-        let view_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
-            entries: &[
-                // View
-                BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: ShaderStages::VERTEX_FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: true,
-                        min_binding_size: Some(ViewUniform::min_size()),
-                    },
-                    count: None,
+        let view_entries = [
+            // View
+            BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::VERTEX_FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: Some(ViewUniform::min_size()),
                 },
-                // Globals
-                BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: ShaderStages::VERTEX_FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(GlobalsUniform::min_size()),
-                    },
-                    count: None,
+                count: None,
+            },
+            // Globals
+            BindGroupLayoutEntry {
+                binding: 1,
+                visibility: ShaderStages::VERTEX_FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: Some(GlobalsUniform::min_size()),
                 },
-            ],
+                count: None,
+            },
+        ];
+
+        let view_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            entries: &view_entries,
             label: Some("prepass_view_layout"),
         });
 
+        let view_layout_motion_vectors =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                entries: &[
+                    view_entries[0],
+                    view_entries[1],
+                    // PreviousViewProjection
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: ShaderStages::VERTEX_FRAGMENT,
+                        ty: BindingType::Buffer {
+                            ty: BufferBindingType::Uniform,
+                            has_dynamic_offset: true,
+                            min_binding_size: Some(PreviousViewProjection::min_size()),
+                        },
+                        count: None,
+                    },
+                ],
+                label: Some("prepass_view_layout_motion_vectors"),
+            });
+
         let mesh_pipeline = world.resource::<MeshPipeline>();
 
         PrepassPipeline {
             view_layout,
+            view_layout_motion_vectors,
             mesh_layout: mesh_pipeline.mesh_layout.clone(),
             skinned_mesh_layout: mesh_pipeline.skinned_mesh_layout.clone(),
+            per_object_buffer_batch_size: mesh_pipeline.per_object_buffer_batch_size,
             material_vertex_shader: match M::prepass_vertex_shader() {
                 ShaderRef::Default => None,
                 ShaderRef::Handle(handle) => Some(handle),
@@ -225,7 +323,14 @@ where
         key: Self::Key,
         layout: &MeshVertexBufferLayout,
     ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
-        let mut bind_group_layout = vec![self.view_layout.clone()];
+        let mut bind_group_layout = vec![if key
+            .mesh_key
+            .contains(MeshPipelineKey::MOTION_VECTOR_PREPASS)
+        {
+            self.view_layout_motion_vectors.clone()
+        } else {
+            self.view_layout.clone()
+        }];
         let mut shader_defs = Vec::new();
         let mut vertex_attributes = Vec::new();
 
@@ -264,6 +369,12 @@ where
             "MAX_CASCADES_PER_LIGHT".to_string(),
             MAX_CASCADES_PER_LIGHT as i32,
         ));
+        if let Some(batch_size) = self.per_object_buffer_batch_size {
+            shader_defs.push(ShaderDefVal::UInt(
+                "PER_OBJECT_BUFFER_BATCH_SIZE".to_string(),
+                batch_size,
+            ));
+        }
         if key.mesh_key.contains(MeshPipelineKey::DEPTH_CLAMP_ORTHO) {
             shader_defs.push("DEPTH_CLAMP_ORTHO".into());
         }
@@ -283,6 +394,20 @@ where
             }
         }
 
+        if key
+            .mesh_key
+            .contains(MeshPipelineKey::MOTION_VECTOR_PREPASS)
+        {
+            shader_defs.push("MOTION_VECTOR_PREPASS".into());
+        }
+
+        if key
+            .mesh_key
+            .intersects(MeshPipelineKey::NORMAL_PREPASS | MeshPipelineKey::MOTION_VECTOR_PREPASS)
+        {
+            shader_defs.push("PREPASS_FRAGMENT".into());
+        }
+
         if layout.contains(Mesh::ATTRIBUTE_JOINT_INDEX)
             && layout.contains(Mesh::ATTRIBUTE_JOINT_WEIGHT)
         {
@@ -296,9 +421,11 @@ where
 
         let vertex_buffer_layout = layout.get_layout(&vertex_attributes)?;
 
-        // The fragment shader is only used when the normal prepass is enabled
+        // The fragment shader is only used when the normal or motion vector prepass is enabled
         // or the material uses alpha cutoff values and doesn't rely on the standard prepass shader
-        let fragment = if key.mesh_key.contains(MeshPipelineKey::NORMAL_PREPASS)
+        let fragment = if key
+            .mesh_key
+            .intersects(MeshPipelineKey::NORMAL_PREPASS | MeshPipelineKey::MOTION_VECTOR_PREPASS)
             || ((key.mesh_key.contains(MeshPipelineKey::ALPHA_MASK)
                 || blend_key == MeshPipelineKey::BLEND_PREMULTIPLIED_ALPHA
                 || blend_key == MeshPipelineKey::BLEND_ALPHA)
@@ -311,14 +438,27 @@ where
                 PREPASS_SHADER_HANDLE.typed::<Shader>()
             };
 
-            let mut targets = vec![];
-            // When the normal prepass is enabled we need a target to be able to write to it.
-            if key.mesh_key.contains(MeshPipelineKey::NORMAL_PREPASS) {
-                targets.push(Some(ColorTargetState {
-                    format: TextureFormat::Rgb10a2Unorm,
-                    blend: Some(BlendState::REPLACE),
-                    write_mask: ColorWrites::ALL,
-                }));
+            // When a prepass with a color output is enabled we need a target to be able to write to it.
+            // The targets are at fixed locations, so a missing output leaves a hole in the list.
+            let mut targets = vec![
+                key.mesh_key
+                    .contains(MeshPipelineKey::NORMAL_PREPASS)
+                    .then_some(ColorTargetState {
+                        format: NORMAL_PREPASS_FORMAT,
+                        blend: Some(BlendState::REPLACE),
+                        write_mask: ColorWrites::ALL,
+                    }),
+                key.mesh_key
+                    .contains(MeshPipelineKey::MOTION_VECTOR_PREPASS)
+                    .then_some(ColorTargetState {
+                        format: MOTION_VECTOR_PREPASS_FORMAT,
+                        blend: Some(BlendState::REPLACE),
+                        write_mask: ColorWrites::ALL,
+                    }),
+            ];
+            if targets.iter().all(Option::is_none) {
+                // No color outputs: clear the target list to match the prepass render pass
+                targets.clear();
             }
 
             Some(FragmentState {
@@ -467,18 +607,20 @@ pub fn extract_camera_prepass_phase(
                 &Camera,
                 Option<&DepthPrepass>,
                 Option<&NormalPrepass>,
+                Option<&MotionVectorPrepass>,
             ),
             With<Camera3d>,
         >,
     >,
 ) {
-    for (entity, camera, depth_prepass, normal_prepass) in cameras_3d.iter() {
+    for (entity, camera, depth_prepass, normal_prepass, motion_vector_prepass) in cameras_3d.iter()
+    {
         if !camera.is_active {
             continue;
         }
 
         let mut entity = commands.get_or_spawn(entity);
-        if depth_prepass.is_some() || normal_prepass.is_some() {
+        if depth_prepass.is_some() || normal_prepass.is_some() || motion_vector_prepass.is_some() {
             entity.insert((
                 RenderPhase::<Opaque3dPrepass>::default(),
                 RenderPhase::<AlphaMask3dPrepass>::default(),
@@ -490,21 +632,87 @@ pub fn extract_camera_prepass_phase(
         if normal_prepass.is_some() {
             entity.insert(NormalPrepass);
         }
+        if motion_vector_prepass.is_some() {
+            entity.insert(MotionVectorPrepass);
+        }
+    }
+}
+
+// Extract the previous view projection of cameras with a motion vector prepass
+pub fn extract_camera_previous_view_projection(
+    mut commands: Commands,
+    cameras_3d: Extract<
+        Query<(Entity, &Camera, Option<&PreviousViewProjection>), With<MotionVectorPrepass>>,
+    >,
+) {
+    for (entity, camera, previous_view_projection) in cameras_3d.iter() {
+        if !camera.is_active {
+            continue;
+        }
+
+        if let Some(previous_view_projection) = previous_view_projection {
+            commands
+                .get_or_spawn(entity)
+                .insert(*previous_view_projection);
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PreviousViewProjectionUniforms {
+    pub uniforms: DynamicUniformBuffer<PreviousViewProjection>,
+}
+
+#[derive(Component)]
+pub struct PreviousViewProjectionUniformOffset {
+    pub offset: u32,
+}
+
+pub fn prepare_previous_view_projection_uniforms(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut view_uniforms: ResMut<PreviousViewProjectionUniforms>,
+    views: Query<
+        (Entity, &ExtractedView, Option<&PreviousViewProjection>),
+        With<MotionVectorPrepass>,
+    >,
+) {
+    view_uniforms.uniforms.clear();
+
+    for (entity, camera, maybe_previous_view_proj) in &views {
+        // Without a previous frame, the current unjittered view projection means no camera motion
+        let view_projection = match maybe_previous_view_proj {
+            Some(previous_view) => *previous_view,
+            None => PreviousViewProjection {
+                view_proj: camera.projection * camera.transform.compute_matrix().inverse(),
+            },
+        };
+        commands
+            .entity(entity)
+            .insert(PreviousViewProjectionUniformOffset {
+                offset: view_uniforms.uniforms.push(view_projection),
+            });
     }
+
+    view_uniforms
+        .uniforms
+        .write_buffer(&render_device, &render_queue);
 }
 
 // Prepares the textures used by the prepass
 pub fn prepare_prepass_textures(
     mut commands: Commands,
     mut texture_cache: ResMut<TextureCache>,
-    msaa: Res<Msaa>,
     render_device: Res<RenderDevice>,
     views_3d: Query<
         (
             Entity,
             &ExtractedCamera,
+            &ExtractedView,
             Option<&DepthPrepass>,
             Option<&NormalPrepass>,
+            Option<&MotionVectorPrepass>,
         ),
         (
             With<RenderPhase<Opaque3dPrepass>>,
@@ -514,7 +722,8 @@ pub fn prepare_prepass_textures(
 ) {
     let mut depth_textures = HashMap::default();
     let mut normal_textures = HashMap::default();
-    for (entity, camera, depth_prepass, normal_prepass) in &views_3d {
+    let mut motion_vectors_textures = HashMap::default();
+    for (entity, camera, view, depth_prepass, normal_prepass, motion_vector_prepass) in &views_3d {
         let Some(physical_target_size) = camera.physical_target_size else {
             continue;
         };
@@ -527,13 +736,13 @@ pub fn prepare_prepass_textures(
 
         let cached_depth_texture = depth_prepass.is_some().then(|| {
             depth_textures
-                .entry(camera.target.clone())
+                .entry((camera.target.clone(), view.msaa))
                 .or_insert_with(|| {
                     let descriptor = TextureDescriptor {
                         label: Some("prepass_depth_texture"),
                         size,
                         mip_level_count: 1,
-                        sample_count: msaa.samples(),
+                        sample_count: view.msaa.samples(),
                         dimension: TextureDimension::D2,
                         format: DEPTH_PREPASS_FORMAT,
                         usage: TextureUsages::COPY_DST
@@ -548,7 +757,7 @@ pub fn prepare_prepass_textures(
 
         let cached_normals_texture = normal_prepass.is_some().then(|| {
             normal_textures
-                .entry(camera.target.clone())
+                .entry((camera.target.clone(), view.msaa))
                 .or_insert_with(|| {
                     texture_cache.get(
                         &render_device,
@@ -556,7 +765,7 @@ pub fn prepare_prepass_textures(
                             label: Some("prepass_normal_texture"),
                             size,
                             mip_level_count: 1,
-                            sample_count: msaa.samples(),
+                            sample_count: view.msaa.samples(),
                             dimension: TextureDimension::D2,
                             format: NORMAL_PREPASS_FORMAT,
                             usage: TextureUsages::RENDER_ATTACHMENT
@@ -568,9 +777,32 @@ pub fn prepare_prepass_textures(
                 .clone()
         });
 
+        let cached_motion_vectors_texture = motion_vector_prepass.is_some().then(|| {
+            motion_vectors_textures
+                .entry((camera.target.clone(), view.msaa))
+                .or_insert_with(|| {
+                    texture_cache.get(
+                        &render_device,
+                        TextureDescriptor {
+                            label: Some("prepass_motion_vectors_textures"),
+                            size,
+                            mip_level_count: 1,
+                            sample_count: view.msaa.samples(),
+                            dimension: TextureDimension::D2,
+                            format: MOTION_VECTOR_PREPASS_FORMAT,
+                            usage: TextureUsages::RENDER_ATTACHMENT
+                                | TextureUsages::TEXTURE_BINDING,
+                            view_formats: &[],
+                        },
+                    )
+                })
+                .clone()
+        });
+
         commands.entity(entity).insert(ViewPrepassTextures {
             depth: cached_depth_texture,
             normal: cached_normals_texture,
+            motion_vectors: cached_motion_vectors_texture,
             size,
         });
     }
@@ -579,6 +811,7 @@ pub fn prepare_prepass_textures(
 #[derive(Default, Resource)]
 pub struct PrepassViewBindGroup {
     bind_group: Option<BindGroup>,
+    motion_vectors: Option<BindGroup>,
 }
 
 pub fn queue_prepass_view_bind_group<M: Material>(
@@ -586,6 +819,7 @@ pub fn queue_prepass_view_bind_group<M: Material>(
     prepass_pipeline: Res<PrepassPipeline<M>>,
     view_uniforms: Res<ViewUniforms>,
     globals_buffer: Res<GlobalsBuffer>,
+    previous_view_proj_uniforms: Res<PreviousViewProjectionUniforms>,
     mut prepass_view_bind_group: ResMut<PrepassViewBindGroup>,
 ) {
     let Some(view_binding) = view_uniforms.uniforms.binding() else { return };
@@ -595,16 +829,41 @@ pub fn queue_prepass_view_bind_group<M: Material>(
             entries: &[
                 BindGroupEntry {
                     binding: 0,
-                    resource: view_binding,
+                    resource: view_binding.clone(),
                 },
                 BindGroupEntry {
                     binding: 1,
-                    resource: globals_binding,
+                    resource: globals_binding.clone(),
                 },
             ],
             label: Some("prepass_view_bind_group"),
             layout: &prepass_pipeline.view_layout,
         }));
+
+    prepass_view_bind_group.motion_vectors =
+        previous_view_proj_uniforms
+            .uniforms
+            .binding()
+            .map(|previous_view_proj_binding| {
+                render_device.create_bind_group(&BindGroupDescriptor {
+                    entries: &[
+                        BindGroupEntry {
+                            binding: 0,
+                            resource: view_binding,
+                        },
+                        BindGroupEntry {
+                            binding: 1,
+                            resource: globals_binding,
+                        },
+                        BindGroupEntry {
+                            binding: 2,
+                            resource: previous_view_proj_binding,
+                        },
+                    ],
+                    label: Some("prepass_view_motion_vectors_bind_group"),
+                    layout: &prepass_pipeline.view_layout_motion_vectors,
+                })
+            });
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -614,7 +873,6 @@ pub fn queue_prepass_material_meshes<M: Material>(
     prepass_pipeline: Res<PrepassPipeline<M>>,
     mut pipelines: ResMut<SpecializedMeshPipelines<PrepassPipeline<M>>>,
     pipeline_cache: Res<PipelineCache>,
-    msaa: Res<Msaa>,
     render_meshes: Res<RenderAssets<Mesh>>,
     render_materials: Res<RenderMaterials<M>>,
     material_meshes: Query<(&Handle<M>, &Handle<Mesh>, &MeshUniform)>,
@@ -625,6 +883,7 @@ pub fn queue_prepass_material_meshes<M: Material>(
         &mut RenderPhase<AlphaMask3dPrepass>,
         Option<&DepthPrepass>,
         Option<&NormalPrepass>,
+        Option<&MotionVectorPrepass>,
     )>,
 ) where
     M::Data: PartialEq + Eq + Hash + Clone,
@@ -644,15 +903,19 @@ pub fn queue_prepass_material_meshes<M: Material>(
         mut alpha_mask_phase,
         depth_prepass,
         normal_prepass,
+        motion_vector_prepass,
     ) in &mut views
     {
-        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
+        let mut view_key = MeshPipelineKey::from_msaa_samples(view.msaa.samples());
         if depth_prepass.is_some() {
             view_key |= MeshPipelineKey::DEPTH_PREPASS;
         }
         if normal_prepass.is_some() {
             view_key |= MeshPipelineKey::NORMAL_PREPASS;
         }
+        if motion_vector_prepass.is_some() {
+            view_key |= MeshPipelineKey::MOTION_VECTOR_PREPASS;
+        }
 
         let rangefinder = view.rangefinder3d();
 
@@ -706,6 +969,8 @@ pub fn queue_prepass_material_meshes<M: Material>(
                         draw_function: opaque_draw_prepass,
                         pipeline_id,
                         distance,
+                        instance_range: 0..1,
+                        dynamic_offset: None,
                     });
                 }
                 AlphaMode::Mask(_) => {
@@ -714,6 +979,8 @@ pub fn queue_prepass_material_meshes<M: Material>(
                         draw_function: alpha_mask_draw_prepass,
                         pipeline_id,
                         distance,
+                        instance_range: 0..1,
+                        dynamic_offset: None,
                     });
                 }
                 AlphaMode::Blend
@@ -728,23 +995,43 @@ pub fn queue_prepass_material_meshes<M: Material>(
 pub struct SetPrepassViewBindGroup<const I: usize>;
 impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetPrepassViewBindGroup<I> {
     type Param = SRes<PrepassViewBindGroup>;
-    type ViewWorldQuery = Read<ViewUniformOffset>;
+    type ViewWorldQuery = (
+        Read<ViewUniformOffset>,
+        Option<Read<PreviousViewProjectionUniformOffset>>,
+    );
     type ItemWorldQuery = ();
 
     #[inline]
     fn render<'w>(
         _item: &P,
-        view_uniform_offset: &'_ ViewUniformOffset,
+        (view_uniform_offset, previous_view_projection_uniform_offset): (
+            &'_ ViewUniformOffset,
+            Option<&'_ PreviousViewProjectionUniformOffset>,
+        ),
         _entity: (),
         prepass_view_bind_group: SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         let prepass_view_bind_group = prepass_view_bind_group.into_inner();
-        pass.set_bind_group(
-            I,
-            prepass_view_bind_group.bind_group.as_ref().unwrap(),
-            &[view_uniform_offset.offset],
-        );
+        match previous_view_projection_uniform_offset {
+            Some(previous_view_projection_uniform_offset) => {
+                pass.set_bind_group(
+                    I,
+                    prepass_view_bind_group.motion_vectors.as_ref().unwrap(),
+                    &[
+                        view_uniform_offset.offset,
+                        previous_view_projection_uniform_offset.offset,
+                    ],
+                );
+            }
+            None => {
+                pass.set_bind_group(
+                    I,
+                    prepass_view_bind_group.bind_group.as_ref().unwrap(),
+                    &[view_uniform_offset.offset],
+                );
+            }
+        }
         RenderCommandResult::Success
     }
 }
@@ -4,6 +4,7 @@
 // Most of these attributes are not used in the default prepass fragment shader, but they are still needed so we can
 // pass them to custom prepass shaders like pbr_prepass.wgsl.
 struct Vertex {
+    @builtin(instance_index) instance_index: u32,
     @location(0) position: vec3<f32>,
 
 #ifdef VERTEX_UVS
@@ -36,6 +37,11 @@ struct VertexOutput {
     @location(2) world_tangent: vec4<f32>,
 #endif // VERTEX_TANGENTS
 #endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    @location(3) world_position: vec4<f32>,
+    @location(4) previous_world_position: vec4<f32>,
+#endif // MOTION_VECTOR_PREPASS
 }
 
 @vertex
@@ -45,7 +51,7 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 #ifdef SKINNED
     var model = skin_model(vertex.joint_indices, vertex.joint_weights);
 #else // SKINNED
-    var model = mesh.model;
+    var model = get_model_matrix(vertex.instance_index);
 #endif // SKINNED
 
     out.clip_position = mesh_position_local_to_clip(model, vec4(vertex.position, 1.0));
@@ -61,24 +67,62 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 #ifdef SKINNED
     out.world_normal = skin_normals(model, vertex.normal);
 #else // SKINNED
-    out.world_normal = mesh_normal_local_to_world(vertex.normal);
+    out.world_normal = mesh_normal_local_to_world(vertex.normal, vertex.instance_index);
 #endif // SKINNED
 
 #ifdef VERTEX_TANGENTS
-    out.world_tangent = mesh_tangent_local_to_world(model, vertex.tangent);
+    out.world_tangent = mesh_tangent_local_to_world(model, vertex.tangent, vertex.instance_index);
 #endif // VERTEX_TANGENTS
 #endif // NORMAL_PREPASS
 
+#ifdef MOTION_VECTOR_PREPASS
+    out.world_position = mesh_position_local_to_world(model, vec4<f32>(vertex.position, 1.0));
+#ifdef SKINNED
+    // The previous joint matrices are not kept, so skinned meshes only get motion vectors
+    // from the movement of the camera.
+    out.previous_world_position = out.world_position;
+#else // SKINNED
+    out.previous_world_position = mesh_position_local_to_world(get_previous_model_matrix(vertex.instance_index), vec4<f32>(vertex.position, 1.0));
+#endif // SKINNED
+#endif // MOTION_VECTOR_PREPASS
+
     return out;
 }
 
-#ifdef NORMAL_PREPASS
+#ifdef PREPASS_FRAGMENT
 struct FragmentInput {
+#ifdef NORMAL_PREPASS
     @location(1) world_normal: vec3<f32>,
+#endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    @location(3) world_position: vec4<f32>,
+    @location(4) previous_world_position: vec4<f32>,
+#endif // MOTION_VECTOR_PREPASS
 }
 
-@fragment
-fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
-    return vec4(in.world_normal * 0.5 + vec3(0.5), 1.0);
+struct FragmentOutput {
+#ifdef NORMAL_PREPASS
+    @location(0) normal: vec4<f32>,
+#endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    @location(1) motion_vector: vec2<f32>,
+#endif // MOTION_VECTOR_PREPASS
 }
+
+@fragment
+fn fragment(in: FragmentInput) -> FragmentOutput {
+    var out: FragmentOutput;
+
+#ifdef NORMAL_PREPASS
+    out.normal = vec4(in.world_normal * 0.5 + vec3(0.5), 1.0);
 #endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    out.motion_vector = calculate_motion_vector(in.world_position, in.previous_world_position);
+#endif // MOTION_VECTOR_PREPASS
+
+    return out;
+}
+#endif // PREPASS_FRAGMENT
@@ -9,10 +9,35 @@ var<uniform> view: View;
 @group(0) @binding(1)
 var<uniform> globals: Globals;
 
+#ifdef MOTION_VECTOR_PREPASS
+@group(0) @binding(2)
+var<uniform> previous_view_proj: mat4x4<f32>;
+
+// Computes the screen space motion of a point between the previous frame and the current one.
+fn calculate_motion_vector(world_position: vec4<f32>, previous_world_position: vec4<f32>) -> vec2<f32> {
+    // The unjittered view projection is used so that camera jitter doesn't show up as motion.
+    let clip_position_t = view.unjittered_view_proj * world_position;
+    let clip_position = clip_position_t.xy / clip_position_t.w;
+    let previous_clip_position_t = previous_view_proj * previous_world_position;
+    let previous_clip_position = previous_clip_position_t.xy / previous_clip_position_t.w;
+    // The motion vectors are used as offsets to UV positions and are stored in the range -1,1
+    // to allow offsetting from one corner to the diagonally-opposite corner in either direction.
+    // A difference between diagonally-opposite corners of clip space is in the range -2,2,
+    // so it needs to be scaled by 0.5. The V direction goes down where clip space y goes up,
+    // so y is flipped.
+    return (clip_position - previous_clip_position) * vec2(0.5, -0.5);
+}
+#endif // MOTION_VECTOR_PREPASS
+
 // Material bindings will be in @group(1)
 
+#ifdef PER_OBJECT_BUFFER_BATCH_SIZE
 @group(2) @binding(0)
-var<uniform> mesh: Mesh;
+var<uniform> mesh: array<Mesh, #{PER_OBJECT_BUFFER_BATCH_SIZE}u>;
+#else
+@group(2) @binding(0)
+var<storage> mesh: array<Mesh>;
+#endif
 
 #ifdef SKINNED
 @group(2) @binding(1)
@@ -1,5 +1,12 @@
 #define_import_path bevy_pbr::prepass_utils
 
+// Reads the depth and normal prepass textures of the view, which requires the material to return
+// `true` from `Material::reads_prepass_textures`. Without the matching `DepthPrepass` or
+// `NormalPrepass` component on the camera, these read a 1x1 fallback texture instead.
+//
+// With MSAA, `sample_index` selects the sample to read, and is typically the `@builtin(sample_index)`
+// of the fragment.
+#ifdef PREPASS_TEXTURES
 #ifndef NORMAL_PREPASS
 fn prepass_normal(frag_coord: vec4<f32>, sample_index: u32) -> vec3<f32> {
 #ifdef MULTISAMPLED
@@ -21,3 +28,4 @@ fn prepass_depth(frag_coord: vec4<f32>, sample_index: u32) -> f32 {
     return depth_sample;
 }
 #endif // DEPTH_PREPASS
+#endif // PREPASS_TEXTURES
@@ -38,6 +38,62 @@ const GPU_FOG_MODE_LINEAR: u32 = 1;
 const GPU_FOG_MODE_EXPONENTIAL: u32 = 2;
 const GPU_FOG_MODE_EXPONENTIAL_SQUARED: u32 = 3;
 const GPU_FOG_MODE_ATMOSPHERIC: u32 = 4;
+const GPU_FOG_MODE_HEIGHT: u32 = 5;
+
+impl From<&FogSettings> for GpuFog {
+    fn from(fog: &FogSettings) -> Self {
+        match &fog.falloff {
+            FogFalloff::Linear { start, end } => GpuFog {
+                mode: GPU_FOG_MODE_LINEAR,
+                base_color: fog.color.into(),
+                directional_light_color: fog.directional_light_color.into(),
+                directional_light_exponent: fog.directional_light_exponent,
+                be: Vec3::new(*start, *end, 0.0),
+                ..Default::default()
+            },
+            FogFalloff::Exponential { density } => GpuFog {
+                mode: GPU_FOG_MODE_EXPONENTIAL,
+                base_color: fog.color.into(),
+                directional_light_color: fog.directional_light_color.into(),
+                directional_light_exponent: fog.directional_light_exponent,
+                be: Vec3::new(*density, 0.0, 0.0),
+                ..Default::default()
+            },
+            FogFalloff::ExponentialSquared { density } => GpuFog {
+                mode: GPU_FOG_MODE_EXPONENTIAL_SQUARED,
+                base_color: fog.color.into(),
+                directional_light_color: fog.directional_light_color.into(),
+                directional_light_exponent: fog.directional_light_exponent,
+                be: Vec3::new(*density, 0.0, 0.0),
+                ..Default::default()
+            },
+            FogFalloff::Atmospheric {
+                extinction,
+                inscattering,
+            } => GpuFog {
+                mode: GPU_FOG_MODE_ATMOSPHERIC,
+                base_color: fog.color.into(),
+                directional_light_color: fog.directional_light_color.into(),
+                directional_light_exponent: fog.directional_light_exponent,
+                be: *extinction,
+                bi: *inscattering,
+            },
+            FogFalloff::Height {
+                density,
+                base_height,
+                falloff_height,
+                inscattering,
+            } => GpuFog {
+                mode: GPU_FOG_MODE_HEIGHT,
+                base_color: fog.color.into(),
+                directional_light_color: fog.directional_light_color.into(),
+                directional_light_exponent: fog.directional_light_exponent,
+                be: Vec3::new(*density, *base_height, *falloff_height),
+                bi: Vec4::from_array(inscattering.as_linear_rgba_f32()).truncate(),
+            },
+        }
+    }
+}
 
 /// Metadata for fog
 #[derive(Default, Resource)]
@@ -56,51 +112,11 @@ pub fn prepare_fog(
     fog_meta.gpu_fogs.clear();
 
     for (entity, fog) in &views {
-        let gpu_fog = if let Some(fog) = fog {
-            match &fog.falloff {
-                FogFalloff::Linear { start, end } => GpuFog {
-                    mode: GPU_FOG_MODE_LINEAR,
-                    base_color: fog.color.into(),
-                    directional_light_color: fog.directional_light_color.into(),
-                    directional_light_exponent: fog.directional_light_exponent,
-                    be: Vec3::new(*start, *end, 0.0),
-                    ..Default::default()
-                },
-                FogFalloff::Exponential { density } => GpuFog {
-                    mode: GPU_FOG_MODE_EXPONENTIAL,
-                    base_color: fog.color.into(),
-                    directional_light_color: fog.directional_light_color.into(),
-                    directional_light_exponent: fog.directional_light_exponent,
-                    be: Vec3::new(*density, 0.0, 0.0),
-                    ..Default::default()
-                },
-                FogFalloff::ExponentialSquared { density } => GpuFog {
-                    mode: GPU_FOG_MODE_EXPONENTIAL_SQUARED,
-                    base_color: fog.color.into(),
-                    directional_light_color: fog.directional_light_color.into(),
-                    directional_light_exponent: fog.directional_light_exponent,
-                    be: Vec3::new(*density, 0.0, 0.0),
-                    ..Default::default()
-                },
-                FogFalloff::Atmospheric {
-                    extinction,
-                    inscattering,
-                } => GpuFog {
-                    mode: GPU_FOG_MODE_ATMOSPHERIC,
-                    base_color: fog.color.into(),
-                    directional_light_color: fog.directional_light_color.into(),
-                    directional_light_exponent: fog.directional_light_exponent,
-                    be: *extinction,
-                    bi: *inscattering,
-                },
-            }
-        } else {
+        let gpu_fog = fog.map(GpuFog::from).unwrap_or(GpuFog {
             // If no fog is added to a camera, by default it's off
-            GpuFog {
-                mode: GPU_FOG_MODE_OFF,
-                ..Default::default()
-            }
-        };
+            mode: GPU_FOG_MODE_OFF,
+            ..Default::default()
+        });
 
         // This is later read by `SetMeshViewBindGroup<I>`
         commands.entity(entity).insert(ViewFogUniformOffset {
@@ -147,3 +163,34 @@ impl Plugin for FogPlugin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{GpuFog, GPU_FOG_MODE_HEIGHT};
+    use crate::{FogFalloff, FogSettings};
+    use bevy_math::Vec3;
+    use bevy_render::{color::Color, render_resource::ShaderType};
+
+    #[test]
+    fn gpu_fog_matches_the_shader_layout() {
+        // Must match the size of `Fog` in `mesh_view_types.wgsl`
+        assert_eq!(GpuFog::min_size().get(), 64);
+    }
+
+    #[test]
+    fn height_fog_is_packed() {
+        let gpu_fog = GpuFog::from(&FogSettings {
+            falloff: FogFalloff::Height {
+                density: 0.25,
+                base_height: -1.0,
+                falloff_height: 4.0,
+                inscattering: Color::rgb_linear(0.5, 0.75, 1.0),
+            },
+            ..Default::default()
+        });
+
+        assert_eq!(gpu_fog.mode, GPU_FOG_MODE_HEIGHT);
+        assert_eq!(gpu_fog.be, Vec3::new(0.25, -1.0, 4.0));
+        assert!(gpu_fog.bi.abs_diff_eq(Vec3::new(0.5, 0.75, 1.0), 1e-6));
+    }
+}
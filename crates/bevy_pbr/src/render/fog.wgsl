@@ -67,3 +67,29 @@ fn atmospheric_fog(
         input_color.a
     );
 }
+
+fn height_fog(
+    input_color: vec4<f32>,
+    distance: f32,
+    scattering: vec3<f32>,
+    view_height: f32,
+    fragment_height: f32,
+) -> vec4<f32> {
+    var fog_color = scattering_adjusted_fog_color(scattering);
+    let density = fog.be.x;
+    let base_height = fog.be.y;
+    let falloff_height = max(fog.be.z, 0.0001);
+
+    // Analytic integral of `density * exp(-(height - base_height) / falloff_height)` along the view ray,
+    // see https://iquilezles.org/articles/fog/
+    let view_density = density * exp(-(view_height - base_height) / falloff_height);
+    let height_change = (fragment_height - view_height) / falloff_height;
+    var integral_factor = 1.0;
+    if abs(height_change) > 0.0001 {
+        integral_factor = (1.0 - exp(-height_change)) / height_change;
+    }
+    let optical_depth = distance * view_density * integral_factor;
+
+    fog_color.a *= 1.0 - 1.0 / exp(optical_depth);
+    return vec4<f32>(mix(input_color.rgb, fog_color.rgb * fog.bi, fog_color.a), input_color.a);
+}
@@ -6,11 +6,12 @@ use crate::{
     NotShadowCaster, PointLight, PointLightShadowMap, PrepassPipeline, RenderMaterials, SpotLight,
     VisiblePointLights,
 };
-use bevy_asset::Handle;
+use bevy_asset::{AssetEvent, Assets, Handle};
 use bevy_core_pipeline::core_3d::Transparent3d;
 use bevy_ecs::prelude::*;
 use bevy_math::{Mat4, UVec3, UVec4, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
 use bevy_render::{
+    batching::InstancedPhaseItem,
     camera::Camera,
     color::Color,
     mesh::Mesh,
@@ -22,7 +23,7 @@ use bevy_render::{
     render_resource::*,
     renderer::{RenderContext, RenderDevice, RenderQueue},
     texture::*,
-    view::{ComputedVisibility, ExtractedView, VisibleEntities},
+    view::{ComputedVisibility, ExtractedView, Msaa, VisibleEntities},
     Extract,
 };
 use bevy_transform::{components::GlobalTransform, prelude::Transform};
@@ -33,6 +34,7 @@ use bevy_utils::{
 use std::{
     hash::Hash,
     num::{NonZeroU32, NonZeroU64},
+    ops::Range,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -56,6 +58,7 @@ pub struct ExtractedPointLight {
     shadow_depth_bias: f32,
     shadow_normal_bias: f32,
     spot_light_angles: Option<(f32, f32)>,
+    light_texture: Option<Handle<Image>>,
 }
 
 #[derive(Component, Debug)]
@@ -166,11 +169,17 @@ bitflags::bitflags! {
     struct PointLightFlags: u32 {
         const SHADOWS_ENABLED            = (1 << 0);
         const SPOT_LIGHT_Y_NEGATIVE      = (1 << 1);
+        const LIGHT_TEXTURE              = (1 << 2);
         const NONE                       = 0;
         const UNINITIALIZED              = 0xFFFF;
     }
 }
 
+/// The upper 16 bits of a point light's flags hold the array layer of its light texture, as there
+/// is no room left in [`GpuPointLight`] within the uniform buffer fallback's size limit.
+// NOTE: This must match POINT_LIGHT_FLAGS_LIGHT_TEXTURE_LAYER_SHIFT in bevy_pbr/src/render/mesh_view_types.wgsl!
+const POINT_LIGHT_TEXTURE_LAYER_SHIFT: u32 = 16;
+
 #[derive(Copy, Clone, ShaderType, Default, Debug)]
 pub struct GpuDirectionalCascade {
     view_projection: Mat4,
@@ -262,6 +271,342 @@ impl FromWorld for ShadowSamplers {
     }
 }
 
+/// The light textures of the [`SpotLight`]s and [`PointLight`]s, extracted whenever the set of
+/// textures in use or one of their images changes.
+#[derive(Resource, Default)]
+pub struct ExtractedLightTextures {
+    spot_light_textures: Vec<(Handle<Image>, Image)>,
+    point_light_textures: Vec<(Handle<Image>, Image)>,
+}
+
+/// The light textures of the [`SpotLight`]s and [`PointLight`]s, each kind stored in one array
+/// texture. Lights whose texture has no layer here are rendered without it.
+#[derive(Resource)]
+pub struct LightTextures {
+    pub spot_light_texture_view: TextureView,
+    pub point_light_texture_view: TextureView,
+    pub sampler: Sampler,
+    spot_light_layers: HashMap<Handle<Image>, u32>,
+    point_light_layers: HashMap<Handle<Image>, u32>,
+}
+
+impl FromWorld for LightTextures {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let render_queue = world.resource::<RenderQueue>();
+
+        let (spot_light_texture_view, spot_light_layers) =
+            create_light_texture_array(render_device, render_queue, &[], false);
+        let (point_light_texture_view, point_light_layers) =
+            create_light_texture_array(render_device, render_queue, &[], true);
+
+        LightTextures {
+            spot_light_texture_view,
+            point_light_texture_view,
+            sampler: render_device.create_sampler(&SamplerDescriptor {
+                address_mode_u: AddressMode::ClampToEdge,
+                address_mode_v: AddressMode::ClampToEdge,
+                address_mode_w: AddressMode::ClampToEdge,
+                mag_filter: FilterMode::Linear,
+                min_filter: FilterMode::Linear,
+                mipmap_filter: FilterMode::Linear,
+                ..Default::default()
+            }),
+            spot_light_layers,
+            point_light_layers,
+        }
+    }
+}
+
+/// Creates the array texture holding `textures`, which must share the same descriptor, and the
+/// mapping from their handles to their layers. Without any texture, a single white one is stored.
+fn create_light_texture_array(
+    render_device: &RenderDevice,
+    render_queue: &RenderQueue,
+    textures: &[(Handle<Image>, Image)],
+    cube: bool,
+) -> (TextureView, HashMap<Handle<Image>, u32>) {
+    let layers_per_texture = if cube { 6 } else { 1 };
+    let (mut descriptor, data) = match textures.first() {
+        Some((_, first)) => {
+            let mut descriptor = first.texture_descriptor.clone();
+            descriptor.size.depth_or_array_layers = layers_per_texture * textures.len() as u32;
+            // Image data is stored layer by layer, each with all of its mip levels, so the data of
+            // the array texture is the data of its images one after the other.
+            let data = textures
+                .iter()
+                .flat_map(|(_, image)| image.data.iter().copied())
+                .collect::<Vec<_>>();
+            (descriptor, data)
+        }
+        None => (
+            TextureDescriptor {
+                label: None,
+                size: Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: layers_per_texture,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Rgba8UnormSrgb,
+                usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            vec![255; 4 * layers_per_texture as usize],
+        ),
+    };
+    descriptor.label = Some(if cube {
+        "point_light_textures"
+    } else {
+        "spot_light_textures"
+    });
+    let texture = render_device.create_texture_with_data(render_queue, &descriptor, &data);
+
+    #[cfg(not(feature = "webgl"))]
+    let dimension = if cube {
+        TextureViewDimension::CubeArray
+    } else {
+        TextureViewDimension::D2Array
+    };
+    #[cfg(feature = "webgl")]
+    let dimension = if cube {
+        TextureViewDimension::Cube
+    } else {
+        TextureViewDimension::D2
+    };
+    let texture_view = texture.create_view(&TextureViewDescriptor {
+        label: descriptor.label,
+        dimension: Some(dimension),
+        ..Default::default()
+    });
+
+    let layers = textures
+        .iter()
+        .enumerate()
+        .map(|(layer, (handle, _))| (handle.clone_weak(), layer as u32))
+        .collect();
+    (texture_view, layers)
+}
+
+/// Picks the light textures that can be stored together in one array texture: the loaded 2D
+/// images with `layers` array layers that match the first one's size, format and mip level count,
+/// up to `max_count` of them.
+fn select_light_textures(
+    handles: &[Handle<Image>],
+    images: &Assets<Image>,
+    layers: u32,
+    max_count: usize,
+) -> Vec<(Handle<Image>, Image)> {
+    let mut selected: Vec<(Handle<Image>, Image)> = Vec::new();
+    for handle in handles {
+        let Some(image) = images.get(handle) else {
+            continue;
+        };
+        let descriptor = &image.texture_descriptor;
+        if descriptor.dimension != TextureDimension::D2
+            || descriptor.size.depth_or_array_layers != layers
+        {
+            warn!(
+                "Light texture {:?} is ignored as it is not a 2D image with {} array layers.",
+                handle.id(),
+                layers
+            );
+            continue;
+        }
+        if descriptor.format.describe().sample_type
+            != (TextureSampleType::Float { filterable: true })
+        {
+            warn!(
+                "Light texture {:?} is ignored as its format {:?} cannot be filtered.",
+                handle.id(),
+                descriptor.format
+            );
+            continue;
+        }
+        if let Some((_, first)) = selected.first() {
+            let first_descriptor = &first.texture_descriptor;
+            if descriptor.size != first_descriptor.size
+                || descriptor.format != first_descriptor.format
+                || descriptor.mip_level_count != first_descriptor.mip_level_count
+                || image.data.len() != first.data.len()
+            {
+                warn!(
+                    "Light texture {:?} is ignored as its size, format or mip level count differs from the other light textures.",
+                    handle.id()
+                );
+                continue;
+            }
+        }
+        if selected.len() == max_count {
+            warn!(
+                "Light texture {:?} is ignored as at most {} light textures of this kind are supported.",
+                handle.id(),
+                max_count
+            );
+            continue;
+        }
+        selected.push((handle.clone_weak(), image.clone()));
+    }
+    selected
+}
+
+/// Returns the sorted, deduplicated light texture handles.
+fn light_texture_handles<'a>(
+    light_textures: impl Iterator<Item = &'a Option<Handle<Image>>>,
+) -> Vec<Handle<Image>> {
+    let mut handles = light_textures.flatten().cloned().collect::<Vec<_>>();
+    handles.sort();
+    handles.dedup();
+    handles
+}
+
+pub fn extract_light_textures(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    images: Extract<Res<Assets<Image>>>,
+    mut image_events: Extract<EventReader<AssetEvent<Image>>>,
+    spot_lights: Extract<Query<&SpotLight>>,
+    point_lights: Extract<Query<&PointLight>>,
+    mut previous_handles: Local<(Vec<Handle<Image>>, Vec<Handle<Image>>)>,
+) {
+    let spot_light_handles =
+        light_texture_handles(spot_lights.iter().map(|light| &light.light_texture));
+    let point_light_handles =
+        light_texture_handles(point_lights.iter().map(|light| &light.light_texture));
+
+    let mut images_changed = false;
+    for event in image_events.iter() {
+        if let AssetEvent::Created { handle } | AssetEvent::Modified { handle } = event {
+            images_changed |=
+                spot_light_handles.contains(handle) || point_light_handles.contains(handle);
+        }
+    }
+    if !images_changed
+        && previous_handles.0 == spot_light_handles
+        && previous_handles.1 == point_light_handles
+    {
+        return;
+    }
+
+    #[cfg(not(feature = "webgl"))]
+    let max_texture_array_layers = render_device.limits().max_texture_array_layers as usize;
+    #[cfg(not(feature = "webgl"))]
+    let max_texture_cubes = max_texture_array_layers / 6;
+    #[cfg(feature = "webgl")]
+    let max_texture_array_layers = 1;
+    #[cfg(feature = "webgl")]
+    let max_texture_cubes = 1;
+
+    commands.insert_resource(ExtractedLightTextures {
+        spot_light_textures: select_light_textures(
+            &spot_light_handles,
+            &images,
+            1,
+            max_texture_array_layers,
+        ),
+        point_light_textures: select_light_textures(
+            &point_light_handles,
+            &images,
+            6,
+            max_texture_cubes,
+        ),
+    });
+    *previous_handles = (spot_light_handles, point_light_handles);
+}
+
+pub fn prepare_light_textures(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    extracted_light_textures: Option<Res<ExtractedLightTextures>>,
+    mut light_textures: ResMut<LightTextures>,
+) {
+    let Some(extracted_light_textures) = extracted_light_textures else {
+        return;
+    };
+
+    let (spot_light_texture_view, spot_light_layers) = create_light_texture_array(
+        &render_device,
+        &render_queue,
+        &extracted_light_textures.spot_light_textures,
+        false,
+    );
+    let (point_light_texture_view, point_light_layers) = create_light_texture_array(
+        &render_device,
+        &render_queue,
+        &extracted_light_textures.point_light_textures,
+        true,
+    );
+    light_textures.spot_light_texture_view = spot_light_texture_view;
+    light_textures.spot_light_layers = spot_light_layers;
+    light_textures.point_light_texture_view = point_light_texture_view;
+    light_textures.point_light_layers = point_light_layers;
+
+    // The images are only needed until they have been uploaded
+    commands.remove_resource::<ExtractedLightTextures>();
+}
+
+pub fn get_light_texture_bind_group_layout_entries(
+    bindings: [u32; 3],
+) -> [BindGroupLayoutEntry; 3] {
+    [
+        BindGroupLayoutEntry {
+            binding: bindings[0],
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                multisampled: false,
+                sample_type: TextureSampleType::Float { filterable: true },
+                #[cfg(not(feature = "webgl"))]
+                view_dimension: TextureViewDimension::D2Array,
+                #[cfg(feature = "webgl")]
+                view_dimension: TextureViewDimension::D2,
+            },
+            count: None,
+        },
+        BindGroupLayoutEntry {
+            binding: bindings[1],
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                multisampled: false,
+                sample_type: TextureSampleType::Float { filterable: true },
+                #[cfg(not(feature = "webgl"))]
+                view_dimension: TextureViewDimension::CubeArray,
+                #[cfg(feature = "webgl")]
+                view_dimension: TextureViewDimension::Cube,
+            },
+            count: None,
+        },
+        BindGroupLayoutEntry {
+            binding: bindings[2],
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+            count: None,
+        },
+    ]
+}
+
+pub fn get_light_texture_bindings(
+    light_textures: &LightTextures,
+    bindings: [u32; 3],
+) -> [BindGroupEntry; 3] {
+    [
+        BindGroupEntry {
+            binding: bindings[0],
+            resource: BindingResource::TextureView(&light_textures.spot_light_texture_view),
+        },
+        BindGroupEntry {
+            binding: bindings[1],
+            resource: BindingResource::TextureView(&light_textures.point_light_texture_view),
+        },
+        BindGroupEntry {
+            binding: bindings[2],
+            resource: BindingResource::Sampler(&light_textures.sampler),
+        },
+    ]
+}
+
 #[derive(Component)]
 pub struct ExtractedClusterConfig {
     /// Special near value for cluster calculations
@@ -380,6 +725,7 @@ pub fn extract_lights(
                             * point_light_texel_size
                             * std::f32::consts::SQRT_2,
                         spot_light_angles: None,
+                        light_texture: point_light.light_texture.clone(),
                     },
                     render_cubemap_visible_entities,
                 ),
@@ -423,6 +769,7 @@ pub fn extract_lights(
                             * texel_size
                             * std::f32::consts::SQRT_2,
                         spot_light_angles: Some((spot_light.inner_angle, spot_light.outer_angle)),
+                        light_texture: spot_light.light_texture.clone(),
                     },
                     render_visible_entities,
                 ),
@@ -664,6 +1011,7 @@ pub fn prepare_lights(
     mut max_cascades_per_light_warning_emitted: Local<bool>,
     point_lights: Query<(Entity, &ExtractedPointLight)>,
     directional_lights: Query<(Entity, &ExtractedDirectionalLight)>,
+    light_textures: Res<LightTextures>,
 ) {
     light_meta.view_gpu_lights.clear();
 
@@ -785,6 +1133,18 @@ pub fn prepare_lights(
             flags |= PointLightFlags::SHADOWS_ENABLED;
         }
 
+        let light_texture_layers = match light.spot_light_angles {
+            Some(_) => &light_textures.spot_light_layers,
+            None => &light_textures.point_light_layers,
+        };
+        let light_texture_layer = light
+            .light_texture
+            .as_ref()
+            .and_then(|handle| light_texture_layers.get(handle));
+        if light_texture_layer.is_some() {
+            flags |= PointLightFlags::LIGHT_TEXTURE;
+        }
+
         let (light_custom_data, spot_light_tan_angle) = match light.spot_light_angles {
             Some((inner, outer)) => {
                 let light_direction = light.transform.forward();
@@ -826,7 +1186,8 @@ pub fn prepare_lights(
                 .xyz()
                 .extend(1.0 / (light.range * light.range)),
             position_radius: light.transform.translation().extend(light.radius),
-            flags: flags.bits,
+            flags: flags.bits
+                | light_texture_layer.map_or(0, |layer| layer << POINT_LIGHT_TEXTURE_LAYER_SHIFT),
             shadow_depth_bias: light.shadow_depth_bias,
             shadow_normal_bias: light.shadow_normal_bias,
             spot_light_tan_angle,
@@ -1017,6 +1378,7 @@ pub fn prepare_lights(
                             projection: cube_face_projection,
                             hdr: false,
                             color_grading: Default::default(),
+                            msaa: Msaa::Off,
                         },
                         RenderPhase::<Shadow>::default(),
                         LightEntity::Point {
@@ -1075,6 +1437,7 @@ pub fn prepare_lights(
                         view_projection: None,
                         hdr: false,
                         color_grading: Default::default(),
+                        msaa: Msaa::Off,
                     },
                     RenderPhase::<Shadow>::default(),
                     LightEntity::Spot { light_entity },
@@ -1141,6 +1504,7 @@ pub fn prepare_lights(
                             view_projection: Some(cascade.view_projection),
                             hdr: false,
                             color_grading: Default::default(),
+                            msaa: Msaa::Off,
                         },
                         RenderPhase::<Shadow>::default(),
                         LightEntity::Directional {
@@ -1636,6 +2000,8 @@ pub fn queue_shadows<M: Material>(
                             pipeline: pipeline_id,
                             entity,
                             distance: 0.0, // TODO: sort back-to-front
+                            instance_range: 0..1,
+                            dynamic_offset: None,
                         });
                     }
                 }
@@ -1649,6 +2015,8 @@ pub struct Shadow {
     pub entity: Entity,
     pub pipeline: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
+    pub instance_range: Range<u32>,
+    pub dynamic_offset: Option<u32>,
 }
 
 impl PhaseItem for Shadow {
@@ -1685,6 +2053,28 @@ impl CachedRenderPipelinePhaseItem for Shadow {
     }
 }
 
+impl InstancedPhaseItem for Shadow {
+    #[inline]
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    #[inline]
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    #[inline]
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    #[inline]
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
+
 pub struct ShadowPassNode {
     main_view_query: QueryState<&'static ViewLightEntities>,
     view_light_query: QueryState<(&'static ShadowView, &'static RenderPhase<Shadow>)>,
@@ -1750,3 +2140,106 @@ impl Node for ShadowPassNode {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{light_texture_handles, select_light_textures};
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetServer, Assets, FileAssetIo, Handle};
+    use bevy_render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::Image,
+    };
+
+    fn image(size: u32, layers: u32, format: TextureFormat) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: layers,
+            },
+            TextureDimension::D2,
+            &vec![255; format.describe().block_size as usize],
+            format,
+        )
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(FileAssetIo::new("", false)))
+            .add_asset::<Image>();
+        app
+    }
+
+    #[test]
+    fn light_texture_handles_are_deduplicated() {
+        let mut app = app();
+        let mut images = app.world.resource_mut::<Assets<Image>>();
+        let a = images.add(image(4, 1, TextureFormat::Rgba8UnormSrgb));
+        let b = images.add(image(4, 1, TextureFormat::Rgba8UnormSrgb));
+
+        let textures = [Some(b.clone()), None, Some(a.clone()), Some(b.clone())];
+        let mut expected = vec![a, b];
+        expected.sort();
+        assert_eq!(light_texture_handles(textures.iter()), expected);
+    }
+
+    #[test]
+    fn light_textures_must_match_the_first_one() {
+        let mut app = app();
+        let mut images = app.world.resource_mut::<Assets<Image>>();
+        let first = images.add(image(4, 1, TextureFormat::Rgba8UnormSrgb));
+        let same = images.add(image(4, 1, TextureFormat::Rgba8UnormSrgb));
+        let other_size = images.add(image(8, 1, TextureFormat::Rgba8UnormSrgb));
+        let other_format = images.add(image(4, 1, TextureFormat::R8Unorm));
+        let cube = images.add(image(4, 6, TextureFormat::Rgba8UnormSrgb));
+        let not_filterable = images.add(image(4, 1, TextureFormat::R32Float));
+        let not_loaded = Handle::<Image>::default();
+
+        let handles = [
+            not_loaded,
+            first.clone(),
+            other_size,
+            other_format,
+            cube,
+            not_filterable,
+            same.clone(),
+        ];
+        let images = app.world.resource::<Assets<Image>>();
+        let selected = select_light_textures(&handles, images, 1, usize::MAX);
+        let selected = selected
+            .into_iter()
+            .map(|(handle, _)| handle)
+            .collect::<Vec<_>>();
+        assert_eq!(selected, vec![first, same]);
+    }
+
+    #[test]
+    fn point_light_textures_are_cube_maps() {
+        let mut app = app();
+        let mut images = app.world.resource_mut::<Assets<Image>>();
+        let flat = images.add(image(4, 1, TextureFormat::Rgba8UnormSrgb));
+        let cube = images.add(image(4, 6, TextureFormat::Rgba8UnormSrgb));
+
+        let images = app.world.resource::<Assets<Image>>();
+        let selected = select_light_textures(&[flat, cube.clone()], images, 6, usize::MAX);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].0, cube);
+        assert_eq!(selected[0].1.data.len(), 4 * 4 * 4 * 6);
+    }
+
+    #[test]
+    fn light_textures_are_limited_in_count() {
+        let mut app = app();
+        let mut images = app.world.resource_mut::<Assets<Image>>();
+        let handles = (0..3)
+            .map(|_| images.add(image(4, 1, TextureFormat::Rgba8UnormSrgb)))
+            .collect::<Vec<_>>();
+
+        let images = app.world.resource::<Assets<Image>>();
+        let selected = select_light_textures(&handles, images, 1, 2);
+        assert_eq!(selected.len(), 2);
+        assert_eq!(selected[0].0, handles[0]);
+        assert_eq!(selected[1].0, handles[1]);
+    }
+}
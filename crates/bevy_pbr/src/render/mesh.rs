@@ -1,13 +1,18 @@
 use crate::{
-    environment_map, prepass, EnvironmentMapLight, FogMeta, GlobalLightMeta, GpuFog, GpuLights,
-    GpuPointLights, LightMeta, NotShadowCaster, NotShadowReceiver, ShadowSamplers,
-    ViewClusterBindings, ViewFogUniformOffset, ViewLightsUniformOffset, ViewShadowBindings,
-    CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT, MAX_CASCADES_PER_LIGHT, MAX_DIRECTIONAL_LIGHTS,
+    environment_map, get_light_texture_bind_group_layout_entries, get_light_texture_bindings,
+    prepass, EnvironmentMapLight, FogMeta, GlobalLightMeta, GpuFog, GpuLights, GpuPointLights,
+    LightMeta, LightTextures, MeshMaterialId, NotShadowCaster, NotShadowReceiver,
+    PreviousGlobalTransform, ScreenSpaceAmbientOcclusionTextures, Shadow, ShadowFilteringMethod,
+    ShadowSamplers, ViewClusterBindings, ViewFogUniformOffset, ViewLightsUniformOffset,
+    ViewShadowBindings, CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT, MAX_CASCADES_PER_LIGHT,
+    MAX_DIRECTIONAL_LIGHTS,
 };
 use bevy_app::{IntoSystemAppConfigs, Plugin};
-use bevy_asset::{load_internal_asset, Assets, Handle, HandleUntyped};
+use bevy_asset::{load_internal_asset, Assets, Handle, HandleId, HandleUntyped};
 use bevy_core_pipeline::{
-    prepass::ViewPrepassTextures,
+    core_3d::{AlphaMask3d, Opaque3d, Transparent3d},
+    oit::ViewOitBuffers,
+    prepass::{AlphaMask3dPrepass, Opaque3dPrepass, ViewPrepassTextures},
     tonemapping::{
         get_lut_bind_group_layout_entries, get_lut_bindings, Tonemapping, TonemappingLuts,
     },
@@ -20,22 +25,30 @@ use bevy_ecs::{
 use bevy_math::{Mat3A, Mat4, Vec2};
 use bevy_reflect::TypeUuid;
 use bevy_render::{
-    extract_component::{ComponentUniforms, DynamicUniformIndex, UniformComponentPlugin},
+    batching::{
+        batch_and_prepare_render_phase, clear_batched_instance_buffers,
+        write_batched_instance_buffer, GetBatchData, InstancedPhaseItem, NoAutomaticBatching,
+    },
+    extract_component::ExtractComponentPlugin,
     globals::{GlobalsBuffer, GlobalsUniform},
     mesh::{
         skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
         GpuBufferInfo, Mesh, MeshVertexBufferLayout,
     },
-    prelude::Msaa,
     render_asset::RenderAssets,
-    render_phase::{PhaseItem, RenderCommand, RenderCommandResult, TrackedRenderPass},
+    render_phase::{
+        sort_phase_system, PhaseItem, RenderCommand, RenderCommandResult, TrackedRenderPass,
+    },
     render_resource::*,
     renderer::{RenderDevice, RenderQueue},
+    settings::WgpuLimits,
     texture::{
-        BevyDefault, DefaultImageSampler, FallbackImageCubemap, FallbackImagesDepth,
+        BevyDefault, DefaultImageSampler, FallbackImage, FallbackImageCubemap, FallbackImagesDepth,
         FallbackImagesMsaa, GpuImage, Image, ImageSampler, TextureFormatPixelInfo,
     },
-    view::{ComputedVisibility, ViewTarget, ViewUniform, ViewUniformOffset, ViewUniforms},
+    view::{
+        ComputedVisibility, ExtractedView, ViewTarget, ViewUniform, ViewUniformOffset, ViewUniforms,
+    },
     Extract, ExtractSchedule, RenderApp, RenderSet,
 };
 use bevy_transform::components::GlobalTransform;
@@ -101,25 +114,64 @@ impl Plugin for MeshRenderPlugin {
         load_internal_asset!(app, MESH_SHADER_HANDLE, "mesh.wgsl", Shader::from_wgsl);
         load_internal_asset!(app, SKINNING_HANDLE, "skinning.wgsl", Shader::from_wgsl);
 
-        app.add_plugin(UniformComponentPlugin::<MeshUniform>::default());
+        app.add_plugin(ExtractComponentPlugin::<NoAutomaticBatching>::default());
 
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            let mesh_buffer =
+                GpuArrayBuffer::<MeshUniform>::new(render_app.world.resource::<RenderDevice>());
             render_app
+                .insert_resource(mesh_buffer)
                 .init_resource::<MeshPipeline>()
                 .init_resource::<SkinnedMeshUniform>()
                 .add_systems((extract_meshes, extract_skinned_meshes).in_schedule(ExtractSchedule))
                 .add_systems((
                     prepare_skinned_meshes.in_set(RenderSet::Prepare),
-                    queue_mesh_bind_group.in_set(RenderSet::Queue),
                     queue_mesh_view_bind_groups.in_set(RenderSet::Queue),
+                ))
+                .add_systems(
+                    (
+                        batch_and_prepare_render_phase::<Opaque3d, MeshPipeline>
+                            .after(sort_phase_system::<Opaque3d>),
+                        batch_and_prepare_render_phase::<AlphaMask3d, MeshPipeline>
+                            .after(sort_phase_system::<AlphaMask3d>),
+                        batch_and_prepare_render_phase::<Transparent3d, MeshPipeline>
+                            .after(sort_phase_system::<Transparent3d>),
+                        batch_and_prepare_render_phase::<Opaque3dPrepass, MeshPipeline>
+                            .after(sort_phase_system::<Opaque3dPrepass>),
+                        batch_and_prepare_render_phase::<AlphaMask3dPrepass, MeshPipeline>
+                            .after(sort_phase_system::<AlphaMask3dPrepass>),
+                        batch_and_prepare_render_phase::<Shadow, MeshPipeline>
+                            .after(sort_phase_system::<Shadow>),
+                    )
+                        .in_set(MeshBatchSet::Batch)
+                        .in_set(RenderSet::PhaseSort),
+                )
+                .add_systems((
+                    write_batched_instance_buffer::<MeshPipeline>
+                        .after(MeshBatchSet::Batch)
+                        .in_set(RenderSet::PhaseSort),
+                    queue_mesh_bind_group
+                        .after(write_batched_instance_buffer::<MeshPipeline>)
+                        .in_set(RenderSet::PhaseSort),
+                    clear_batched_instance_buffers::<MeshPipeline>.in_set(RenderSet::Cleanup),
                 ));
         }
     }
 }
 
+/// The system set batching the mesh phase items, in [`RenderSet::PhaseSort`].
+///
+/// Systems batching other phase items into the mesh buffer should run in this set, as the
+/// buffer is written to the GPU right after it.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum MeshBatchSet {
+    Batch,
+}
+
 #[derive(Component, ShaderType, Clone)]
 pub struct MeshUniform {
     pub transform: Mat4,
+    pub previous_transform: Mat4,
     pub inverse_transpose_model: Mat4,
     pub flags: u32,
 }
@@ -146,6 +198,7 @@ pub fn extract_meshes(
             Entity,
             &ComputedVisibility,
             &GlobalTransform,
+            Option<&PreviousGlobalTransform>,
             &Handle<Mesh>,
             Option<With<NotShadowReceiver>>,
             Option<With<NotShadowCaster>>,
@@ -156,8 +209,11 @@ pub fn extract_meshes(
     let mut not_caster_commands = Vec::with_capacity(*prev_not_caster_commands_len);
     let visible_meshes = meshes_query.iter().filter(|(_, vis, ..)| vis.is_visible());
 
-    for (entity, _, transform, handle, not_receiver, not_caster) in visible_meshes {
+    for (entity, _, transform, previous_transform, handle, not_receiver, not_caster) in
+        visible_meshes
+    {
         let transform = transform.compute_matrix();
+        let previous_transform = previous_transform.map(|t| t.0.into()).unwrap_or(transform);
         let mut flags = if not_receiver.is_some() {
             MeshFlags::empty()
         } else {
@@ -169,6 +225,7 @@ pub fn extract_meshes(
         let uniform = MeshUniform {
             flags: flags.bits,
             transform,
+            previous_transform,
             inverse_transpose_model: transform.inverse().transpose(),
         };
         if not_caster.is_some() {
@@ -270,6 +327,204 @@ pub struct MeshPipeline {
     // This dummy white texture is to be used in place of optional StandardMaterial textures
     pub dummy_white_gpu_image: GpuImage,
     pub clustered_forward_buffer_binding_type: BufferBindingType,
+    /// The number of [`MeshUniform`]s visible to shaders through a single binding, or `None` if
+    /// they are all stored in a storage buffer. See [`GpuArrayBuffer::batch_size`].
+    pub per_object_buffer_batch_size: Option<u32>,
+    /// The layers and fragment counters bound in place of the OIT buffers of views without
+    /// [`ViewOitBuffers`], or `None` if the device doesn't support the OIT bindings.
+    pub oit_fallback_buffers: Option<[Buffer; 2]>,
+}
+
+/// Returns `true` if the mesh view bind group can hold the read-write OIT storage buffers on a
+/// device with `limits`, in addition to the clustered forward buffers and the mesh uniforms.
+fn oit_bindings_supported(limits: &WgpuLimits) -> bool {
+    cfg!(not(feature = "webgl"))
+        && limits.max_storage_buffers_per_shader_stage >= CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT + 3
+}
+
+/// Returns the entries of the mesh view bind group layout, with multisampled prepass textures if
+/// `multisampled` is set, and the order independent transparency buffers if `oit` is set.
+fn mesh_view_layout_entries(
+    clustered_forward_buffer_binding_type: BufferBindingType,
+    multisampled: bool,
+    oit: bool,
+) -> Vec<BindGroupLayoutEntry> {
+    let mut entries = vec![
+        // View
+        BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: Some(ViewUniform::min_size()),
+            },
+            count: None,
+        },
+        // Lights
+        BindGroupLayoutEntry {
+            binding: 1,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: Some(GpuLights::min_size()),
+            },
+            count: None,
+        },
+        // Point Shadow Texture Cube Array
+        BindGroupLayoutEntry {
+            binding: 2,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                multisampled: false,
+                sample_type: TextureSampleType::Depth,
+                #[cfg(not(feature = "webgl"))]
+                view_dimension: TextureViewDimension::CubeArray,
+                #[cfg(feature = "webgl")]
+                view_dimension: TextureViewDimension::Cube,
+            },
+            count: None,
+        },
+        // Point Shadow Texture Array Sampler
+        BindGroupLayoutEntry {
+            binding: 3,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Sampler(SamplerBindingType::Comparison),
+            count: None,
+        },
+        // Directional Shadow Texture Array
+        BindGroupLayoutEntry {
+            binding: 4,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                multisampled: false,
+                sample_type: TextureSampleType::Depth,
+                #[cfg(not(feature = "webgl"))]
+                view_dimension: TextureViewDimension::D2Array,
+                #[cfg(feature = "webgl")]
+                view_dimension: TextureViewDimension::D2,
+            },
+            count: None,
+        },
+        // Directional Shadow Texture Array Sampler
+        BindGroupLayoutEntry {
+            binding: 5,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Sampler(SamplerBindingType::Comparison),
+            count: None,
+        },
+        // PointLights
+        BindGroupLayoutEntry {
+            binding: 6,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: clustered_forward_buffer_binding_type,
+                has_dynamic_offset: false,
+                min_binding_size: Some(GpuPointLights::min_size(
+                    clustered_forward_buffer_binding_type,
+                )),
+            },
+            count: None,
+        },
+        // ClusteredLightIndexLists
+        BindGroupLayoutEntry {
+            binding: 7,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: clustered_forward_buffer_binding_type,
+                has_dynamic_offset: false,
+                min_binding_size: Some(ViewClusterBindings::min_size_cluster_light_index_lists(
+                    clustered_forward_buffer_binding_type,
+                )),
+            },
+            count: None,
+        },
+        // ClusterOffsetsAndCounts
+        BindGroupLayoutEntry {
+            binding: 8,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: clustered_forward_buffer_binding_type,
+                has_dynamic_offset: false,
+                min_binding_size: Some(ViewClusterBindings::min_size_cluster_offsets_and_counts(
+                    clustered_forward_buffer_binding_type,
+                )),
+            },
+            count: None,
+        },
+        // Globals
+        BindGroupLayoutEntry {
+            binding: 9,
+            visibility: ShaderStages::VERTEX_FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: Some(GlobalsUniform::min_size()),
+            },
+            count: None,
+        },
+        // Fog
+        BindGroupLayoutEntry {
+            binding: 10,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: Some(GpuFog::min_size()),
+            },
+            count: None,
+        },
+    ];
+
+    // EnvironmentMapLight
+    let environment_map_entries = environment_map::get_bind_group_layout_entries([11, 12, 13]);
+    entries.extend_from_slice(&environment_map_entries);
+
+    // Tonemapping
+    let tonemapping_lut_entries = get_lut_bind_group_layout_entries([14, 15]);
+    entries.extend_from_slice(&tonemapping_lut_entries);
+
+    if cfg!(not(feature = "webgl")) || (cfg!(feature = "webgl") && !multisampled) {
+        entries.extend_from_slice(&prepass::get_bind_group_layout_entries(
+            [16, 17],
+            multisampled,
+        ));
+    }
+
+    // Light textures
+    entries.extend_from_slice(&get_light_texture_bind_group_layout_entries([18, 19, 20]));
+
+    // Screen space ambient occlusion, which is not available on WebGL2, where the sampled
+    // texture limit is already reached
+    if cfg!(not(feature = "webgl")) {
+        entries.push(BindGroupLayoutEntry {
+            binding: 21,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: false },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        });
+    }
+
+    // Order independent transparency layers and fragment counters
+    if oit {
+        entries.extend([22, 23].map(|binding| BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Storage { read_only: false },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }));
+    }
+
+    entries
 }
 
 impl FromWorld for MeshPipeline {
@@ -282,184 +537,41 @@ impl FromWorld for MeshPipeline {
         let (render_device, default_sampler, render_queue) = system_state.get_mut(world);
         let clustered_forward_buffer_binding_type = render_device
             .get_supported_read_only_binding_type(CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT);
-
-        /// Returns the appropriate bind group layout vec based on the parameters
-        fn layout_entries(
-            clustered_forward_buffer_binding_type: BufferBindingType,
-            multisampled: bool,
-        ) -> Vec<BindGroupLayoutEntry> {
-            let mut entries = vec![
-                // View
-                BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: true,
-                        min_binding_size: Some(ViewUniform::min_size()),
-                    },
-                    count: None,
-                },
-                // Lights
-                BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: true,
-                        min_binding_size: Some(GpuLights::min_size()),
-                    },
-                    count: None,
-                },
-                // Point Shadow Texture Cube Array
-                BindGroupLayoutEntry {
-                    binding: 2,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Texture {
-                        multisampled: false,
-                        sample_type: TextureSampleType::Depth,
-                        #[cfg(not(feature = "webgl"))]
-                        view_dimension: TextureViewDimension::CubeArray,
-                        #[cfg(feature = "webgl")]
-                        view_dimension: TextureViewDimension::Cube,
-                    },
-                    count: None,
-                },
-                // Point Shadow Texture Array Sampler
-                BindGroupLayoutEntry {
-                    binding: 3,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Sampler(SamplerBindingType::Comparison),
-                    count: None,
-                },
-                // Directional Shadow Texture Array
-                BindGroupLayoutEntry {
-                    binding: 4,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Texture {
-                        multisampled: false,
-                        sample_type: TextureSampleType::Depth,
-                        #[cfg(not(feature = "webgl"))]
-                        view_dimension: TextureViewDimension::D2Array,
-                        #[cfg(feature = "webgl")]
-                        view_dimension: TextureViewDimension::D2,
-                    },
-                    count: None,
-                },
-                // Directional Shadow Texture Array Sampler
-                BindGroupLayoutEntry {
-                    binding: 5,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Sampler(SamplerBindingType::Comparison),
-                    count: None,
-                },
-                // PointLights
-                BindGroupLayoutEntry {
-                    binding: 6,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: clustered_forward_buffer_binding_type,
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(GpuPointLights::min_size(
-                            clustered_forward_buffer_binding_type,
-                        )),
-                    },
-                    count: None,
-                },
-                // ClusteredLightIndexLists
-                BindGroupLayoutEntry {
-                    binding: 7,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: clustered_forward_buffer_binding_type,
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(
-                            ViewClusterBindings::min_size_cluster_light_index_lists(
-                                clustered_forward_buffer_binding_type,
-                            ),
-                        ),
-                    },
-                    count: None,
-                },
-                // ClusterOffsetsAndCounts
-                BindGroupLayoutEntry {
-                    binding: 8,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: clustered_forward_buffer_binding_type,
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(
-                            ViewClusterBindings::min_size_cluster_offsets_and_counts(
-                                clustered_forward_buffer_binding_type,
-                            ),
-                        ),
-                    },
-                    count: None,
-                },
-                // Globals
-                BindGroupLayoutEntry {
-                    binding: 9,
-                    visibility: ShaderStages::VERTEX_FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: Some(GlobalsUniform::min_size()),
-                    },
-                    count: None,
-                },
-                // Fog
-                BindGroupLayoutEntry {
-                    binding: 10,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Buffer {
-                        ty: BufferBindingType::Uniform,
-                        has_dynamic_offset: true,
-                        min_binding_size: Some(GpuFog::min_size()),
-                    },
-                    count: None,
-                },
-            ];
-
-            // EnvironmentMapLight
-            let environment_map_entries =
-                environment_map::get_bind_group_layout_entries([11, 12, 13]);
-            entries.extend_from_slice(&environment_map_entries);
-
-            // Tonemapping
-            let tonemapping_lut_entries = get_lut_bind_group_layout_entries([14, 15]);
-            entries.extend_from_slice(&tonemapping_lut_entries);
-
-            if cfg!(not(feature = "webgl")) || (cfg!(feature = "webgl") && !multisampled) {
-                entries.extend_from_slice(&prepass::get_bind_group_layout_entries(
-                    [16, 17],
-                    multisampled,
-                ));
-            }
-
-            entries
-        }
+        let oit_fallback_buffers = oit_bindings_supported(&render_device.limits()).then(|| {
+            ["oit_fallback_layers", "oit_fallback_layer_ids"].map(|label| {
+                render_device.create_buffer(&BufferDescriptor {
+                    label: Some(label),
+                    size: 4,
+                    usage: BufferUsages::STORAGE,
+                    mapped_at_creation: false,
+                })
+            })
+        });
 
         let view_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
             label: Some("mesh_view_layout"),
-            entries: &layout_entries(clustered_forward_buffer_binding_type, false),
+            entries: &mesh_view_layout_entries(
+                clustered_forward_buffer_binding_type,
+                false,
+                oit_fallback_buffers.is_some(),
+            ),
         });
 
         let view_layout_multisampled =
             render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
                 label: Some("mesh_view_layout_multisampled"),
-                entries: &layout_entries(clustered_forward_buffer_binding_type, true),
+                entries: &mesh_view_layout_entries(
+                    clustered_forward_buffer_binding_type,
+                    true,
+                    oit_fallback_buffers.is_some(),
+                ),
             });
 
-        let mesh_binding = BindGroupLayoutEntry {
-            binding: 0,
-            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
-            ty: BindingType::Buffer {
-                ty: BufferBindingType::Uniform,
-                has_dynamic_offset: true,
-                min_binding_size: Some(MeshUniform::min_size()),
-            },
-            count: None,
-        };
+        let mesh_binding = GpuArrayBuffer::<MeshUniform>::binding_layout(
+            0,
+            ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            &render_device,
+        );
 
         let mesh_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
             entries: &[mesh_binding],
@@ -536,6 +648,10 @@ impl FromWorld for MeshPipeline {
             skinned_mesh_layout,
             clustered_forward_buffer_binding_type,
             dummy_white_gpu_image,
+            per_object_buffer_batch_size: GpuArrayBuffer::<MeshUniform>::batch_size(
+                &render_device.limits(),
+            ),
+            oit_fallback_buffers,
         }
     }
 }
@@ -572,6 +688,10 @@ bitflags::bitflags! {
         const ALPHA_MASK                        = (1 << 5);
         const ENVIRONMENT_MAP                   = (1 << 6);
         const DEPTH_CLAMP_ORTHO                 = (1 << 7);
+        const SCREEN_SPACE_AMBIENT_OCCLUSION    = (1 << 8);
+        const MOTION_VECTOR_PREPASS             = (1 << 9);
+        const READS_PREPASS_TEXTURES            = (1 << 10);
+        const OIT_ENABLED                       = (1 << 11);
         const BLEND_RESERVED_BITS               = Self::BLEND_MASK_BITS << Self::BLEND_SHIFT_BITS; // ← Bitmask reserving bits for the blend state
         const BLEND_OPAQUE                      = (0 << Self::BLEND_SHIFT_BITS);                   // ← Values are just sequential within the mask, and can range from 0 to 3
         const BLEND_PREMULTIPLIED_ALPHA         = (1 << Self::BLEND_SHIFT_BITS);                   //
@@ -588,6 +708,10 @@ bitflags::bitflags! {
         const TONEMAP_METHOD_SOMEWHAT_BORING_DISPLAY_TRANSFORM = 5 << Self::TONEMAP_METHOD_SHIFT_BITS;
         const TONEMAP_METHOD_TONY_MC_MAPFACE    = 6 << Self::TONEMAP_METHOD_SHIFT_BITS;
         const TONEMAP_METHOD_BLENDER_FILMIC     = 7 << Self::TONEMAP_METHOD_SHIFT_BITS;
+        const SHADOW_FILTER_METHOD_RESERVED_BITS = Self::SHADOW_FILTER_METHOD_MASK_BITS << Self::SHADOW_FILTER_METHOD_SHIFT_BITS;
+        const SHADOW_FILTER_METHOD_HARDWARE_2X2  = 0 << Self::SHADOW_FILTER_METHOD_SHIFT_BITS;
+        const SHADOW_FILTER_METHOD_GAUSSIAN      = 1 << Self::SHADOW_FILTER_METHOD_SHIFT_BITS;
+        const SHADOW_FILTER_METHOD_TEMPORAL      = 2 << Self::SHADOW_FILTER_METHOD_SHIFT_BITS;
     }
 }
 
@@ -603,6 +727,9 @@ impl MeshPipelineKey {
     const TONEMAP_METHOD_MASK_BITS: u32 = 0b111;
     const TONEMAP_METHOD_SHIFT_BITS: u32 =
         Self::BLEND_SHIFT_BITS - Self::TONEMAP_METHOD_MASK_BITS.count_ones();
+    const SHADOW_FILTER_METHOD_MASK_BITS: u32 = 0b11;
+    const SHADOW_FILTER_METHOD_SHIFT_BITS: u32 =
+        Self::TONEMAP_METHOD_SHIFT_BITS - Self::SHADOW_FILTER_METHOD_MASK_BITS.count_ones();
 
     pub fn from_msaa_samples(msaa_samples: u32) -> Self {
         let msaa_bits =
@@ -618,6 +745,28 @@ impl MeshPipelineKey {
         }
     }
 
+    pub fn from_shadow_filtering_method(shadow_filtering_method: ShadowFilteringMethod) -> Self {
+        match shadow_filtering_method {
+            ShadowFilteringMethod::Hardware2x2 => {
+                MeshPipelineKey::SHADOW_FILTER_METHOD_HARDWARE_2X2
+            }
+            ShadowFilteringMethod::Gaussian => MeshPipelineKey::SHADOW_FILTER_METHOD_GAUSSIAN,
+            ShadowFilteringMethod::Temporal => MeshPipelineKey::SHADOW_FILTER_METHOD_TEMPORAL,
+        }
+    }
+
+    /// The shader def selecting the shadow map filter in `shadows.wgsl`.
+    pub fn shadow_filter_method_shader_def(&self) -> &'static str {
+        let method = self.intersection(MeshPipelineKey::SHADOW_FILTER_METHOD_RESERVED_BITS);
+        if method == MeshPipelineKey::SHADOW_FILTER_METHOD_GAUSSIAN {
+            "SHADOW_FILTER_METHOD_GAUSSIAN"
+        } else if method == MeshPipelineKey::SHADOW_FILTER_METHOD_TEMPORAL {
+            "SHADOW_FILTER_METHOD_TEMPORAL"
+        } else {
+            "SHADOW_FILTER_METHOD_HARDWARE_2X2"
+        }
+    }
+
     pub fn msaa_samples(&self) -> u32 {
         1 << ((self.bits >> Self::MSAA_SHIFT_BITS) & Self::MSAA_MASK_BITS)
     }
@@ -673,6 +822,13 @@ impl SpecializedMeshPipeline for MeshPipeline {
             MAX_CASCADES_PER_LIGHT as u32,
         ));
 
+        if let Some(batch_size) = self.per_object_buffer_batch_size {
+            shader_defs.push(ShaderDefVal::UInt(
+                "PER_OBJECT_BUFFER_BATCH_SIZE".to_string(),
+                batch_size,
+            ));
+        }
+
         if layout.contains(Mesh::ATTRIBUTE_UV_0) {
             shader_defs.push("VERTEX_UVS".into());
             vertex_attributes.push(Mesh::ATTRIBUTE_UV_0.at_shader_location(2));
@@ -696,6 +852,13 @@ impl SpecializedMeshPipeline for MeshPipeline {
             }
         };
 
+        // The prepass textures aren't bound on WebGL2 with MSAA, see `mesh_view_layout_entries`.
+        if key.contains(MeshPipelineKey::READS_PREPASS_TEXTURES)
+            && (cfg!(not(feature = "webgl")) || key.msaa_samples() == 1)
+        {
+            shader_defs.push("PREPASS_TEXTURES".into());
+        }
+
         if layout.contains(Mesh::ATTRIBUTE_JOINT_INDEX)
             && layout.contains(Mesh::ATTRIBUTE_JOINT_WEIGHT)
         {
@@ -782,6 +945,17 @@ impl SpecializedMeshPipeline for MeshPipeline {
             shader_defs.push("ENVIRONMENT_MAP".into());
         }
 
+        if key.contains(MeshPipelineKey::SCREEN_SPACE_AMBIENT_OCCLUSION) {
+            shader_defs.push("SCREEN_SPACE_AMBIENT_OCCLUSION".into());
+        }
+
+        // Only set for blended meshes of views with `ViewOitBuffers`, which never exist on WebGL2
+        if key.contains(MeshPipelineKey::OIT_ENABLED) {
+            shader_defs.push("OIT_ENABLED".into());
+        }
+
+        shader_defs.push(key.shadow_filter_method_shader_def().into());
+
         let format = if key.contains(MeshPipelineKey::HDR) {
             ViewTarget::TEXTURE_FORMAT_HDR
         } else {
@@ -842,6 +1016,32 @@ impl SpecializedMeshPipeline for MeshPipeline {
     }
 }
 
+impl GetBatchData for MeshPipeline {
+    type Query = (
+        &'static MeshUniform,
+        &'static Handle<Mesh>,
+        Option<&'static MeshMaterialId>,
+        Option<With<SkinnedMeshJoints>>,
+    );
+    type QueryFilter = ();
+    type CompareData = (HandleId, Option<MeshMaterialId>);
+    type BufferData = MeshUniform;
+
+    fn get_buffer_data((mesh_uniform, ..): &ROQueryItem<'_, Self::Query>) -> Self::BufferData {
+        (*mesh_uniform).clone()
+    }
+
+    fn get_compare_data(
+        (_, mesh_handle, material_id, skinned): &ROQueryItem<'_, Self::Query>,
+    ) -> Option<Self::CompareData> {
+        // Skinned meshes each bind their own joint matrices, so they can't share a draw call
+        if skinned.is_some() {
+            return None;
+        }
+        Some((mesh_handle.id(), material_id.copied()))
+    }
+}
+
 #[derive(Resource)]
 pub struct MeshBindGroup {
     pub normal: BindGroup,
@@ -852,10 +1052,10 @@ pub fn queue_mesh_bind_group(
     mut commands: Commands,
     mesh_pipeline: Res<MeshPipeline>,
     render_device: Res<RenderDevice>,
-    mesh_uniforms: Res<ComponentUniforms<MeshUniform>>,
+    mesh_uniforms: Res<GpuArrayBuffer<MeshUniform>>,
     skinned_mesh_uniform: Res<SkinnedMeshUniform>,
 ) {
-    if let Some(mesh_binding) = mesh_uniforms.uniforms().binding() {
+    if let Some(mesh_binding) = mesh_uniforms.binding() {
         let mut mesh_bind_group = MeshBindGroup {
             normal: render_device.create_bind_group(&BindGroupDescriptor {
                 entries: &[BindGroupEntry {
@@ -937,24 +1137,26 @@ pub fn queue_mesh_view_bind_groups(
     mut commands: Commands,
     render_device: Res<RenderDevice>,
     mesh_pipeline: Res<MeshPipeline>,
-    shadow_samplers: Res<ShadowSamplers>,
+    (shadow_samplers, light_textures): (Res<ShadowSamplers>, Res<LightTextures>),
     light_meta: Res<LightMeta>,
     global_light_meta: Res<GlobalLightMeta>,
     fog_meta: Res<FogMeta>,
     view_uniforms: Res<ViewUniforms>,
     views: Query<(
         Entity,
+        &ExtractedView,
         &ViewShadowBindings,
         &ViewClusterBindings,
         Option<&ViewPrepassTextures>,
         Option<&EnvironmentMapLight>,
         &Tonemapping,
+        Option<&ScreenSpaceAmbientOcclusionTextures>,
+        Option<&ViewOitBuffers>,
     )>,
     images: Res<RenderAssets<Image>>,
     mut fallback_images: FallbackImagesMsaa,
     mut fallback_depths: FallbackImagesDepth,
-    fallback_cubemap: Res<FallbackImageCubemap>,
-    msaa: Res<Msaa>,
+    (fallback_image, fallback_cubemap): (Res<FallbackImage>, Res<FallbackImageCubemap>),
     globals_buffer: Res<GlobalsBuffer>,
     tonemapping_luts: Res<TonemappingLuts>,
 ) {
@@ -973,14 +1175,17 @@ pub fn queue_mesh_view_bind_groups(
     ) {
         for (
             entity,
+            view,
             view_shadow_bindings,
             view_cluster_bindings,
             prepass_textures,
             environment_map,
             tonemapping,
+            ssao_textures,
+            oit_buffers,
         ) in &views
         {
-            let layout = if msaa.samples() > 1 {
+            let layout = if view.msaa.samples() > 1 {
                 &mesh_pipeline.view_layout_multisampled
             } else {
                 &mesh_pipeline.view_layout
@@ -1050,16 +1255,54 @@ pub fn queue_mesh_view_bind_groups(
             entries.extend_from_slice(&tonemapping_luts);
 
             // When using WebGL, we can't have a depth texture with multisampling
-            if cfg!(not(feature = "webgl")) || (cfg!(feature = "webgl") && msaa.samples() == 1) {
+            if cfg!(not(feature = "webgl")) || (cfg!(feature = "webgl") && view.msaa.samples() == 1)
+            {
                 entries.extend_from_slice(&prepass::get_bindings(
                     prepass_textures,
                     &mut fallback_images,
                     &mut fallback_depths,
-                    &msaa,
+                    &view.msaa,
                     [16, 17],
                 ));
             }
 
+            entries.extend_from_slice(&get_light_texture_bindings(&light_textures, [18, 19, 20]));
+
+            // The fallback is never sampled, see `MeshPipelineKey::SCREEN_SPACE_AMBIENT_OCCLUSION`
+            if cfg!(not(feature = "webgl")) {
+                let ssao_view = match ssao_textures {
+                    Some(ssao_textures) => {
+                        &ssao_textures
+                            .screen_space_ambient_occlusion_texture
+                            .default_view
+                    }
+                    None => &fallback_image.texture_view,
+                };
+                entries.push(BindGroupEntry {
+                    binding: 21,
+                    resource: BindingResource::TextureView(ssao_view),
+                });
+            }
+
+            // The fallbacks are never accessed, see `MeshPipelineKey::OIT_ENABLED`
+            if let Some([fallback_layers, fallback_layer_ids]) = &mesh_pipeline.oit_fallback_buffers
+            {
+                let (layers, layer_ids) = match oit_buffers {
+                    Some(oit_buffers) => (&oit_buffers.layers, &oit_buffers.layer_ids),
+                    None => (fallback_layers, fallback_layer_ids),
+                };
+                entries.extend([
+                    BindGroupEntry {
+                        binding: 22,
+                        resource: layers.as_entire_binding(),
+                    },
+                    BindGroupEntry {
+                        binding: 23,
+                        resource: layer_ids.as_entire_binding(),
+                    },
+                ]);
+            }
+
             let view_bind_group = render_device.create_bind_group(&BindGroupDescriptor {
                 entries: &entries,
                 label: Some("mesh_view_bind_group"),
@@ -1106,32 +1349,37 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetMeshViewBindGroup<I>
 }
 
 pub struct SetMeshBindGroup<const I: usize>;
-impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetMeshBindGroup<I> {
+impl<P: InstancedPhaseItem, const I: usize> RenderCommand<P> for SetMeshBindGroup<I> {
     type Param = SRes<MeshBindGroup>;
     type ViewWorldQuery = ();
-    type ItemWorldQuery = (
-        Read<DynamicUniformIndex<MeshUniform>>,
-        Option<Read<SkinnedMeshJoints>>,
-    );
+    type ItemWorldQuery = Option<Read<SkinnedMeshJoints>>;
     #[inline]
     fn render<'w>(
-        _item: &P,
+        item: &P,
         _view: (),
-        (mesh_index, skinned_mesh_joints): ROQueryItem<'_, Self::ItemWorldQuery>,
+        skinned_mesh_joints: ROQueryItem<'_, Self::ItemWorldQuery>,
         mesh_bind_group: SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
+        let mut dynamic_offsets: [u32; 2] = Default::default();
+        let mut offset_count = 0;
+        if let Some(dynamic_offset) = item.dynamic_offset() {
+            dynamic_offsets[offset_count] = dynamic_offset;
+            offset_count += 1;
+        }
         if let Some(joints) = skinned_mesh_joints {
+            dynamic_offsets[offset_count] = joints.index;
+            offset_count += 1;
             pass.set_bind_group(
                 I,
                 mesh_bind_group.into_inner().skinned.as_ref().unwrap(),
-                &[mesh_index.index(), joints.index],
+                &dynamic_offsets[..offset_count],
             );
         } else {
             pass.set_bind_group(
                 I,
                 &mesh_bind_group.into_inner().normal,
-                &[mesh_index.index()],
+                &dynamic_offsets[..offset_count],
             );
         }
         RenderCommandResult::Success
@@ -1139,13 +1387,13 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetMeshBindGroup<I> {
 }
 
 pub struct DrawMesh;
-impl<P: PhaseItem> RenderCommand<P> for DrawMesh {
+impl<P: InstancedPhaseItem> RenderCommand<P> for DrawMesh {
     type Param = SRes<RenderAssets<Mesh>>;
     type ViewWorldQuery = ();
     type ItemWorldQuery = Read<Handle<Mesh>>;
     #[inline]
     fn render<'w>(
-        _item: &P,
+        item: &P,
         _view: (),
         mesh_handle: ROQueryItem<'_, Self::ItemWorldQuery>,
         meshes: SystemParamItem<'w, '_, Self::Param>,
@@ -1160,10 +1408,10 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh {
                     count,
                 } => {
                     pass.set_index_buffer(buffer.slice(..), 0, *index_format);
-                    pass.draw_indexed(0..*count, 0, 0..1);
+                    pass.draw_indexed(0..*count, 0, item.instance_range().clone());
                 }
                 GpuBufferInfo::NonIndexed { vertex_count } => {
-                    pass.draw(0..*vertex_count, 0..1);
+                    pass.draw(0..*vertex_count, item.instance_range().clone());
                 }
             }
             RenderCommandResult::Success
@@ -1175,11 +1423,130 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh {
 
 #[cfg(test)]
 mod tests {
-    use super::MeshPipelineKey;
+    use super::{mesh_view_layout_entries, oit_bindings_supported, MeshPipelineKey};
+    use crate::ShadowFilteringMethod;
+    use bevy_render::{
+        render_resource::{
+            BindingType, BufferBindingType, TextureSampleType, TextureViewDimension,
+        },
+        settings::WgpuLimits,
+    };
+
+    #[test]
+    fn mesh_view_layout_binds_prepass_textures() {
+        for multisampled in [false, true] {
+            let entries = mesh_view_layout_entries(BufferBindingType::Uniform, multisampled, false);
+            let texture = |binding| {
+                let entry = entries
+                    .iter()
+                    .find(|entry| entry.binding == binding)
+                    .unwrap();
+                match entry.ty {
+                    BindingType::Texture {
+                        sample_type,
+                        view_dimension,
+                        multisampled,
+                    } => (sample_type, view_dimension, multisampled),
+                    _ => panic!("binding {binding} isn't a texture"),
+                }
+            };
+            assert_eq!(
+                texture(16),
+                (
+                    TextureSampleType::Depth,
+                    TextureViewDimension::D2,
+                    multisampled
+                )
+            );
+            assert_eq!(
+                texture(17),
+                (
+                    TextureSampleType::Float { filterable: true },
+                    TextureViewDimension::D2,
+                    multisampled
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn reads_prepass_textures_key_is_independent() {
+        let reserved = MeshPipelineKey::BLEND_RESERVED_BITS
+            | MeshPipelineKey::MSAA_RESERVED_BITS
+            | MeshPipelineKey::PRIMITIVE_TOPOLOGY_RESERVED_BITS
+            | MeshPipelineKey::TONEMAP_METHOD_RESERVED_BITS
+            | MeshPipelineKey::SHADOW_FILTER_METHOD_RESERVED_BITS;
+        assert!(!reserved.intersects(MeshPipelineKey::READS_PREPASS_TEXTURES));
+
+        let key = MeshPipelineKey::from_msaa_samples(4)
+            | MeshPipelineKey::BLEND_ALPHA
+            | MeshPipelineKey::READS_PREPASS_TEXTURES;
+        assert_eq!(key.msaa_samples(), 4);
+        assert!(key.contains(MeshPipelineKey::BLEND_ALPHA));
+    }
+
+    #[test]
+    fn mesh_view_layout_binds_oit_buffers() {
+        let oit_bindings = |oit| {
+            mesh_view_layout_entries(BufferBindingType::Uniform, false, oit)
+                .into_iter()
+                .filter(|entry| entry.binding >= 22)
+                .map(|entry| (entry.binding, entry.ty))
+                .collect::<Vec<_>>()
+        };
+        assert!(oit_bindings(false).is_empty());
+        let read_write = BindingType::Buffer {
+            ty: BufferBindingType::Storage { read_only: false },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        };
+        assert_eq!(oit_bindings(true), vec![(22, read_write), (23, read_write)]);
+
+        assert!(oit_bindings_supported(&WgpuLimits::default()));
+        assert!(!oit_bindings_supported(
+            &WgpuLimits::downlevel_webgl2_defaults()
+        ));
+
+        let reserved = MeshPipelineKey::BLEND_RESERVED_BITS
+            | MeshPipelineKey::MSAA_RESERVED_BITS
+            | MeshPipelineKey::PRIMITIVE_TOPOLOGY_RESERVED_BITS
+            | MeshPipelineKey::TONEMAP_METHOD_RESERVED_BITS
+            | MeshPipelineKey::SHADOW_FILTER_METHOD_RESERVED_BITS
+            | MeshPipelineKey::READS_PREPASS_TEXTURES;
+        assert!(!reserved.intersects(MeshPipelineKey::OIT_ENABLED));
+    }
+
     #[test]
     fn mesh_key_msaa_samples() {
         for i in [1, 2, 4, 8, 16, 32, 64, 128] {
             assert_eq!(MeshPipelineKey::from_msaa_samples(i).msaa_samples(), i);
         }
     }
+
+    #[test]
+    fn mesh_key_shadow_filter_method() {
+        let shader_def = |method| {
+            (MeshPipelineKey::from_msaa_samples(4)
+                | MeshPipelineKey::TONEMAP_METHOD_BLENDER_FILMIC
+                | MeshPipelineKey::from_shadow_filtering_method(method))
+            .shadow_filter_method_shader_def()
+        };
+        // cameras without the component use the default, which keeps the previous output
+        assert_eq!(
+            MeshPipelineKey::NONE.shadow_filter_method_shader_def(),
+            shader_def(ShadowFilteringMethod::default())
+        );
+        assert_eq!(
+            shader_def(ShadowFilteringMethod::Hardware2x2),
+            "SHADOW_FILTER_METHOD_HARDWARE_2X2"
+        );
+        assert_eq!(
+            shader_def(ShadowFilteringMethod::Gaussian),
+            "SHADOW_FILTER_METHOD_GAUSSIAN"
+        );
+        assert_eq!(
+            shader_def(ShadowFilteringMethod::Temporal),
+            "SHADOW_FILTER_METHOD_TEMPORAL"
+        );
+    }
 }
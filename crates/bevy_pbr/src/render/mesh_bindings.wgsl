@@ -2,8 +2,13 @@
 
 #import bevy_pbr::mesh_types
 
+#ifdef PER_OBJECT_BUFFER_BATCH_SIZE
 @group(2) @binding(0)
-var<uniform> mesh: Mesh;
+var<uniform> mesh: array<Mesh, #{PER_OBJECT_BUFFER_BATCH_SIZE}u>;
+#else
+@group(2) @binding(0)
+var<storage> mesh: array<Mesh>;
+#endif
 #ifdef SKINNED
 @group(2) @binding(1)
 var<uniform> joint_matrices: SkinnedMesh;
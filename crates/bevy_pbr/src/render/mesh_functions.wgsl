@@ -1,5 +1,15 @@
 #define_import_path bevy_pbr::mesh_functions
 
+// NOTE: The instance index of the vertex shader is the index of the entity's data in the mesh
+// array, as automatic batching draws each batched entity as one instance.
+fn get_model_matrix(instance_index: u32) -> mat4x4<f32> {
+    return mesh[instance_index].model;
+}
+
+fn get_previous_model_matrix(instance_index: u32) -> mat4x4<f32> {
+    return mesh[instance_index].previous_model;
+}
+
 fn mesh_position_local_to_world(model: mat4x4<f32>, vertex_position: vec4<f32>) -> vec4<f32> {
     return model * vertex_position;
 }
@@ -16,7 +26,7 @@ fn mesh_position_local_to_clip(model: mat4x4<f32>, vertex_position: vec4<f32>) -
     return mesh_position_world_to_clip(world_position);
 }
 
-fn mesh_normal_local_to_world(vertex_normal: vec3<f32>) -> vec3<f32> {
+fn mesh_normal_local_to_world(vertex_normal: vec3<f32>, instance_index: u32) -> vec3<f32> {
     // NOTE: The mikktspace method of normal mapping requires that the world normal is
     // re-normalized in the vertex shader to match the way mikktspace bakes vertex tangents
     // and normal maps so that the exact inverse process is applied when shading. Blender, Unity,
@@ -25,23 +35,23 @@ fn mesh_normal_local_to_world(vertex_normal: vec3<f32>) -> vec3<f32> {
     // http://www.mikktspace.com/
     return normalize(
         mat3x3<f32>(
-            mesh.inverse_transpose_model[0].xyz,
-            mesh.inverse_transpose_model[1].xyz,
-            mesh.inverse_transpose_model[2].xyz
+            mesh[instance_index].inverse_transpose_model[0].xyz,
+            mesh[instance_index].inverse_transpose_model[1].xyz,
+            mesh[instance_index].inverse_transpose_model[2].xyz
         ) * vertex_normal
     );
 }
 
 // Calculates the sign of the determinant of the 3x3 model matrix based on a
 // mesh flag
-fn sign_determinant_model_3x3() -> f32 {
+fn sign_determinant_model_3x3(instance_index: u32) -> f32 {
     // bool(u32) is false if 0u else true
     // f32(bool) is 1.0 if true else 0.0
     // * 2.0 - 1.0 remaps 0.0 or 1.0 to -1.0 or 1.0 respectively
-    return f32(bool(mesh.flags & MESH_FLAGS_SIGN_DETERMINANT_MODEL_3X3_BIT)) * 2.0 - 1.0;
+    return f32(bool(mesh[instance_index].flags & MESH_FLAGS_SIGN_DETERMINANT_MODEL_3X3_BIT)) * 2.0 - 1.0;
 }
 
-fn mesh_tangent_local_to_world(model: mat4x4<f32>, vertex_tangent: vec4<f32>) -> vec4<f32> {
+fn mesh_tangent_local_to_world(model: mat4x4<f32>, vertex_tangent: vec4<f32>, instance_index: u32) -> vec4<f32> {
     // NOTE: The mikktspace method of normal mapping requires that the world tangent is
     // re-normalized in the vertex shader to match the way mikktspace bakes vertex tangents
     // and normal maps so that the exact inverse process is applied when shading. Blender, Unity,
@@ -58,6 +68,6 @@ fn mesh_tangent_local_to_world(model: mat4x4<f32>, vertex_tangent: vec4<f32>) ->
         ),
         // NOTE: Multiplying by the sign of the determinant of the 3x3 model matrix accounts for
         // situations such as negative scaling.
-        vertex_tangent.w * sign_determinant_model_3x3()
+        vertex_tangent.w * sign_determinant_model_3x3(instance_index)
     );
 }
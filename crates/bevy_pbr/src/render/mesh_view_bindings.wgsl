@@ -58,6 +58,8 @@ var dt_lut_texture: texture_3d<f32>;
 @group(0) @binding(15)
 var dt_lut_sampler: sampler;
 
+// Only declared for materials that read them, see `Material::reads_prepass_textures`.
+#ifdef PREPASS_TEXTURES
 #ifdef MULTISAMPLED
 @group(0) @binding(16)
 var depth_prepass_texture: texture_depth_multisampled_2d;
@@ -69,3 +71,29 @@ var depth_prepass_texture: texture_depth_2d;
 @group(0) @binding(17)
 var normal_prepass_texture: texture_2d<f32>;
 #endif
+#endif // PREPASS_TEXTURES
+
+#ifdef NO_ARRAY_TEXTURES_SUPPORT
+@group(0) @binding(18)
+var spot_light_textures: texture_2d<f32>;
+@group(0) @binding(19)
+var point_light_textures: texture_cube<f32>;
+#else
+@group(0) @binding(18)
+var spot_light_textures: texture_2d_array<f32>;
+@group(0) @binding(19)
+var point_light_textures: texture_cube_array<f32>;
+#endif
+@group(0) @binding(20)
+var light_textures_sampler: sampler;
+
+@group(0) @binding(21)
+var screen_space_ambient_occlusion_texture: texture_2d<f32>;
+
+#ifdef OIT_ENABLED
+// See `ViewOitBuffers` for the layout of the buffers
+@group(0) @binding(22)
+var<storage, read_write> oit_layers: array<u32>;
+@group(0) @binding(23)
+var<storage, read_write> oit_layer_ids: array<atomic<u32>>;
+#endif // OIT_ENABLED
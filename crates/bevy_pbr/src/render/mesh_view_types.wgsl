@@ -18,6 +18,9 @@ struct PointLight {
 
 const POINT_LIGHT_FLAGS_SHADOWS_ENABLED_BIT: u32   = 1u;
 const POINT_LIGHT_FLAGS_SPOT_LIGHT_Y_NEGATIVE: u32 = 2u;
+const POINT_LIGHT_FLAGS_LIGHT_TEXTURE_BIT: u32     = 4u;
+// The upper 16 bits of the flags hold the array layer of the light's texture
+const POINT_LIGHT_FLAGS_LIGHT_TEXTURE_LAYER_SHIFT: u32 = 16u;
 
 struct DirectionalCascade {
     view_projection: mat4x4<f32>,
@@ -74,6 +77,9 @@ struct Fog {
     // For Atmospheric Fog:
     //     be = per-channel extinction density
     //     bi = per-channel inscattering density
+    // For Height Fog:
+    //     be.x = density, be.y = base height, be.z = falloff height
+    //     bi = inscattering color
     be: vec3<f32>,
     directional_light_exponent: f32,
     bi: vec3<f32>,
@@ -86,6 +92,7 @@ const FOG_MODE_LINEAR: u32                = 1u;
 const FOG_MODE_EXPONENTIAL: u32           = 2u;
 const FOG_MODE_EXPONENTIAL_SQUARED: u32   = 3u;
 const FOG_MODE_ATMOSPHERIC: u32           = 4u;
+const FOG_MODE_HEIGHT: u32                = 5u;
 
 #if AVAILABLE_STORAGE_BUFFER_BINDINGS >= 3
 struct PointLights {
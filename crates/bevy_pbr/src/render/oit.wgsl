@@ -0,0 +1,25 @@
+#define_import_path bevy_pbr::oit
+
+#ifdef OIT_ENABLED
+// Stores `color` as a fragment of the pixel at `position`, to be sorted and blended by the OIT
+// resolve pass. The fragment is dropped if the pixel already holds one fragment per layer.
+fn oit_draw(position: vec4<f32>, color: vec4<f32>) {
+    let size = vec2<u32>(view.viewport.zw);
+    let pixel = vec2<u32>(position.xy - view.viewport.xy);
+    let pixel_index = pixel.y * size.x + pixel.x;
+    let pixel_count = arrayLength(&oit_layer_ids);
+    let layer_count = arrayLength(&oit_layers) / (3u * pixel_count);
+    if pixel_index >= pixel_count {
+        return;
+    }
+
+    let layer = atomicAdd(&oit_layer_ids[pixel_index], 1u);
+    if layer >= layer_count {
+        return;
+    }
+    let index = 3u * (layer * pixel_count + pixel_index);
+    oit_layers[index] = pack2x16float(color.rg);
+    oit_layers[index + 1u] = pack2x16float(color.ba);
+    oit_layers[index + 2u] = bitcast<u32>(position.z);
+}
+#endif // OIT_ENABLED
@@ -9,6 +9,7 @@
 #import bevy_pbr::shadows
 #import bevy_pbr::fog
 #import bevy_pbr::pbr_functions
+#import bevy_pbr::oit
 
 struct FragmentInput {
     @builtin(front_facing) is_front: bool,
@@ -38,6 +39,10 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
         pbr_input.material.reflectance = material.reflectance;
         pbr_input.material.flags = material.flags;
         pbr_input.material.alpha_cutoff = material.alpha_cutoff;
+        pbr_input.material.clearcoat = material.clearcoat;
+        pbr_input.material.clearcoat_perceptual_roughness = material.clearcoat_perceptual_roughness;
+        pbr_input.material.anisotropy_strength = material.anisotropy_strength;
+        pbr_input.material.anisotropy_rotation = material.anisotropy_rotation;
 
         // TODO use .a for exposure compensation in HDR
         var emissive: vec4<f32> = material.emissive;
@@ -90,9 +95,41 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
 #endif
         );
         pbr_input.V = calculate_view(in.world_position, pbr_input.is_orthographic);
+
+#ifdef STANDARDMATERIAL_CLEARCOAT
+        pbr_input.clearcoat_N = clearcoat_normal(
+            pbr_input.world_normal,
+#ifdef VERTEX_TANGENTS
+#ifdef STANDARDMATERIAL_CLEARCOAT_NORMAL_MAP
+            in.world_tangent,
+#endif
+#endif
+#ifdef VERTEX_UVS
+            in.uv,
+#endif
+        );
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+        var anisotropy_strength: f32 = material.anisotropy_strength;
+        var anisotropy_direction = vec2<f32>(1.0, 0.0);
+#ifdef VERTEX_UVS
+        if ((material.flags & STANDARD_MATERIAL_FLAGS_ANISOTROPY_TEXTURE_BIT) != 0u) {
+            let anisotropy = textureSample(anisotropy_texture, anisotropy_sampler, in.uv).rgb;
+            anisotropy_direction = normalize(anisotropy.rg * 2.0 - 1.0);
+            anisotropy_strength = anisotropy_strength * anisotropy.b;
+        }
+#endif
+        pbr_input.material.anisotropy_strength = anisotropy_strength;
+        pbr_input.anisotropy_T = anisotropy_tangent(
+            pbr_input.N,
+            in.world_tangent,
+            material.anisotropy_rotation,
+            anisotropy_direction,
+        );
+#endif
         pbr_input.occlusion = occlusion;
 
-        pbr_input.flags = mesh.flags;
+        pbr_input.flags = mesh[in.instance_index].flags;
 
         output_color = pbr(pbr_input);
     } else {
@@ -119,5 +156,11 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
 #ifdef PREMULTIPLY_ALPHA
         output_color = premultiply_alpha(material.flags, output_color);
 #endif
+#ifdef OIT_ENABLED
+    // The fragment is blended by the OIT resolve pass instead
+    oit_draw(in.frag_coord, output_color);
+    discard;
+#else
     return output_color;
+#endif
 }
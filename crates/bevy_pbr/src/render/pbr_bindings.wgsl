@@ -24,3 +24,11 @@ var occlusion_sampler: sampler;
 var normal_map_texture: texture_2d<f32>;
 @group(1) @binding(10)
 var normal_map_sampler: sampler;
+@group(1) @binding(11)
+var clearcoat_normal_texture: texture_2d<f32>;
+@group(1) @binding(12)
+var clearcoat_normal_sampler: sampler;
+@group(1) @binding(13)
+var anisotropy_texture: texture_2d<f32>;
+@group(1) @binding(14)
+var anisotropy_sampler: sampler;
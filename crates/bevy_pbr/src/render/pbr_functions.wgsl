@@ -8,6 +8,10 @@
 #import bevy_pbr::environment_map
 #endif
 
+#ifdef SCREEN_SPACE_AMBIENT_OCCLUSION
+#import bevy_pbr::gtao_utils
+#endif
+
 fn alpha_discard(material: StandardMaterial, output_color: vec4<f32>) -> vec4<f32> {
     var color = output_color;
     let alpha_mode = material.flags & STANDARD_MATERIAL_FLAGS_ALPHA_MODE_RESERVED_BITS;
@@ -103,6 +107,58 @@ fn apply_normal_mapping(
     return normalize(N);
 }
 
+#ifdef STANDARDMATERIAL_CLEARCOAT
+// Returns the normal of the clearcoat layer, which is the geometric normal unless the layer has
+// its own normal map. Unlike the base layer, only 3-component normal maps are supported.
+fn clearcoat_normal(
+    world_normal: vec3<f32>,
+#ifdef VERTEX_TANGENTS
+#ifdef STANDARDMATERIAL_CLEARCOAT_NORMAL_MAP
+    world_tangent: vec4<f32>,
+#endif
+#endif
+#ifdef VERTEX_UVS
+    uv: vec2<f32>,
+#endif
+) -> vec3<f32> {
+    var N: vec3<f32> = world_normal;
+#ifdef VERTEX_TANGENTS
+#ifdef VERTEX_UVS
+#ifdef STANDARDMATERIAL_CLEARCOAT_NORMAL_MAP
+    // See `apply_normal_mapping` for why this follows the mikktspace conventions
+    let T: vec3<f32> = world_tangent.xyz;
+    let B: vec3<f32> = world_tangent.w * cross(N, T);
+    let Nt = textureSample(clearcoat_normal_texture, clearcoat_normal_sampler, uv).rgb * 2.0 - 1.0;
+    N = Nt.x * T + Nt.y * B + Nt.z * N;
+#endif
+#endif
+#endif
+    return normalize(N);
+}
+#endif
+
+#ifdef STANDARDMATERIAL_ANISOTROPY
+// Returns the direction the specular highlight is stretched along, in world space.
+// This is the mesh tangent rotated by the tangent space `direction`, then by `rotation`,
+// which holds the cosine and sine of the anisotropy rotation.
+fn anisotropy_tangent(
+    N: vec3<f32>,
+    world_tangent: vec4<f32>,
+    rotation: vec2<f32>,
+    direction: vec2<f32>,
+) -> vec3<f32> {
+    let rotated = vec2<f32>(
+        rotation.x * direction.x - rotation.y * direction.y,
+        rotation.y * direction.x + rotation.x * direction.y,
+    );
+
+    // Gram-Schmidt to keep the tangent frame orthogonal to the (normal mapped) normal
+    let T = normalize(world_tangent.xyz - N * dot(N, world_tangent.xyz));
+    let B = world_tangent.w * cross(N, T);
+    return normalize(rotated.x * T + rotated.y * B);
+}
+#endif
+
 // NOTE: Correctly calculates the view vector depending on whether
 // the projection is orthographic or perspective.
 fn calculate_view(
@@ -133,6 +189,11 @@ struct PbrInput {
     // Normalized view vector in world space, pointing from the fragment world position toward the
     // view world position
     V: vec3<f32>,
+    // Normalized normal of the clearcoat layer, only used with STANDARDMATERIAL_CLEARCOAT
+    clearcoat_N: vec3<f32>,
+    // Normalized direction in world space that the specular highlight is stretched along,
+    // only used with STANDARDMATERIAL_ANISOTROPY
+    anisotropy_T: vec3<f32>,
     is_orthographic: bool,
     flags: u32,
 };
@@ -152,6 +213,8 @@ fn pbr_input_new() -> PbrInput {
 
     pbr_input.N = vec3<f32>(0.0, 0.0, 1.0);
     pbr_input.V = vec3<f32>(1.0, 0.0, 0.0);
+    pbr_input.clearcoat_N = vec3<f32>(0.0, 0.0, 1.0);
+    pbr_input.anisotropy_T = vec3<f32>(1.0, 0.0, 0.0);
 
     pbr_input.flags = 0u;
 
@@ -172,7 +235,12 @@ fn pbr(
     let perceptual_roughness = in.material.perceptual_roughness;
     let roughness = perceptualRoughnessToRoughness(perceptual_roughness);
 
-    let occlusion = in.occlusion;
+    var occlusion = vec3<f32>(in.occlusion);
+#ifdef SCREEN_SPACE_AMBIENT_OCCLUSION
+    let ssao = textureLoad(screen_space_ambient_occlusion_texture, vec2<i32>(in.frag_coord.xy), 0).r;
+    let ssao_multibounce = gtao_multibounce(ssao, in.material.base_color.rgb);
+    occlusion = min(occlusion, ssao_multibounce);
+#endif
 
     output_color = alpha_discard(in.material, output_color);
 
@@ -191,6 +259,19 @@ fn pbr(
 
     let f_ab = F_AB(perceptual_roughness, NdotV);
 
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    var clearcoat: Clearcoat;
+    clearcoat.strength = in.material.clearcoat;
+    clearcoat.roughness = perceptualRoughnessToRoughness(in.material.clearcoat_perceptual_roughness);
+    clearcoat.N = in.clearcoat_N;
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+    var anisotropy: Anisotropy;
+    anisotropy.strength = in.material.anisotropy_strength;
+    anisotropy.T = in.anisotropy_T;
+    anisotropy.B = cross(in.N, in.anisotropy_T);
+#endif
+
     var direct_light: vec3<f32> = vec3<f32>(0.0);
 
     let view_z = dot(vec4<f32>(
@@ -210,8 +291,26 @@ fn pbr(
                 && (point_lights.data[light_id].flags & POINT_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
             shadow = fetch_point_shadow(light_id, in.world_position, in.world_normal);
         }
-        let light_contrib = point_light(in.world_position.xyz, light_id, roughness, NdotV, in.N, in.V, R, F0, f_ab, diffuse_color);
-        direct_light += light_contrib * shadow;
+        let light_contrib = point_light(
+            in.world_position.xyz,
+            light_id,
+            roughness,
+            NdotV,
+            in.N,
+            in.V,
+            R,
+            F0,
+            f_ab,
+            diffuse_color,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+            clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+            anisotropy,
+#endif
+        );
+        let light_texture = point_light_texture(light_id, in.world_position.xyz);
+        direct_light += light_contrib * light_texture * shadow;
     }
 
     // Spot lights (direct)
@@ -222,8 +321,26 @@ fn pbr(
                 && (point_lights.data[light_id].flags & POINT_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
             shadow = fetch_spot_shadow(light_id, in.world_position, in.world_normal);
         }
-        let light_contrib = spot_light(in.world_position.xyz, light_id, roughness, NdotV, in.N, in.V, R, F0, f_ab, diffuse_color);
-        direct_light += light_contrib * shadow;
+        let light_contrib = spot_light(
+            in.world_position.xyz,
+            light_id,
+            roughness,
+            NdotV,
+            in.N,
+            in.V,
+            R,
+            F0,
+            f_ab,
+            diffuse_color,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+            clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+            anisotropy,
+#endif
+        );
+        let light_texture = spot_light_texture(light_id, in.world_position.xyz);
+        direct_light += light_contrib * light_texture * shadow;
     }
 
     // Directional lights (direct)
@@ -234,7 +351,23 @@ fn pbr(
                 && (lights.directional_lights[i].flags & DIRECTIONAL_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
             shadow = fetch_directional_shadow(i, in.world_position, in.world_normal, view_z);
         }
-        var light_contrib = directional_light(i, roughness, NdotV, in.N, in.V, R, F0, f_ab, diffuse_color);
+        var light_contrib = directional_light(
+            i,
+            roughness,
+            NdotV,
+            in.N,
+            in.V,
+            R,
+            F0,
+            f_ab,
+            diffuse_color,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+            clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+            anisotropy,
+#endif
+        );
 #ifdef DIRECTIONAL_LIGHT_SHADOW_MAP_DEBUG_CASCADES
         light_contrib = cascade_debug_visualization(light_contrib, i, view_z);
 #endif
@@ -250,6 +383,28 @@ fn pbr(
     indirect_light += (environment_light.diffuse * occlusion) + environment_light.specular;
 #endif
 
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    // The clearcoat layer reflects the environment through its own normal and roughness,
+    // and attenuates the light reflected by the base layer
+    let clearcoat_NdotV = max(dot(clearcoat.N, in.V), 0.0001);
+    let clearcoat_fresnel = F_Schlick(0.04, 1.0, clearcoat_NdotV) * clearcoat.strength;
+    indirect_light *= 1.0 - clearcoat_fresnel;
+#ifdef ENVIRONMENT_MAP
+    let clearcoat_perceptual_roughness = in.material.clearcoat_perceptual_roughness;
+    let clearcoat_environment_light = environment_map_light(
+        clearcoat_perceptual_roughness,
+        clearcoat.roughness,
+        vec3<f32>(0.0),
+        clearcoat_NdotV,
+        F_AB(clearcoat_perceptual_roughness, clearcoat_NdotV),
+        clearcoat.N,
+        reflect(-in.V, clearcoat.N),
+        vec3<f32>(0.04),
+    );
+    indirect_light += clearcoat_environment_light.specular * clearcoat.strength;
+#endif
+#endif
+
     let emissive_light = emissive.rgb * output_color.a;
 
     // Total light
@@ -310,6 +465,8 @@ fn apply_fog(input_color: vec4<f32>, fragment_world_position: vec3<f32>, view_wo
         return exponential_squared_fog(input_color, distance, scattering);
     } else if fog.mode == FOG_MODE_ATMOSPHERIC {
         return atmospheric_fog(input_color, distance, scattering);
+    } else if fog.mode == FOG_MODE_HEIGHT {
+        return height_fog(input_color, distance, scattering, view_world_position.y, fragment_world_position.y);
     } else {
         return input_color;
     }
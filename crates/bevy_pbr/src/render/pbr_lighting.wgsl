@@ -124,6 +124,103 @@ fn specular(
     return Fr;
 }
 
+// The clearcoat layer of a material, see `StandardMaterial::clearcoat`
+struct Clearcoat {
+    strength: f32,
+    // Non-linear roughness of the layer
+    roughness: f32,
+    // Normal of the layer, which may differ from the normal of the base layer
+    N: vec3<f32>,
+};
+
+// The anisotropy of a material, see `StandardMaterial::anisotropy_strength`
+struct Anisotropy {
+    strength: f32,
+    // Direction the highlight is stretched along, in world space
+    T: vec3<f32>,
+    // Bitangent of the surface perpendicular to T, in world space
+    B: vec3<f32>,
+};
+
+// Anisotropic normal distribution function
+// https://google.github.io/filament/Filament.html#materialsystem/anisotropicmodel
+fn D_GGX_Anisotropic(at: f32, ab: f32, NoH: f32, ToH: f32, BoH: f32) -> f32 {
+    let a2 = at * ab;
+    let d = vec3<f32>(ab * ToH, at * BoH, a2 * NoH);
+    let d2 = dot(d, d);
+    let b2 = a2 / d2;
+    return a2 * b2 * b2 * (1.0 / PI);
+}
+
+// Anisotropic visibility function
+// https://google.github.io/filament/Filament.html#materialsystem/anisotropicmodel
+fn V_SmithGGXCorrelated_Anisotropic(
+    at: f32,
+    ab: f32,
+    ToV: f32,
+    BoV: f32,
+    ToL: f32,
+    BoL: f32,
+    NoV: f32,
+    NoL: f32,
+) -> f32 {
+    let lambdaV = NoL * length(vec3<f32>(at * ToV, ab * BoV, NoV));
+    let lambdaL = NoV * length(vec3<f32>(at * ToL, ab * BoL, NoL));
+    return saturate(0.5 / (lambdaV + lambdaL));
+}
+
+// Specular BRDF with the highlight stretched along the anisotropy direction
+fn specular_anisotropic(
+    f0: vec3<f32>,
+    roughness: f32,
+    anisotropy: Anisotropy,
+    L: vec3<f32>,
+    V: vec3<f32>,
+    H: vec3<f32>,
+    NoV: f32,
+    NoL: f32,
+    NoH: f32,
+    LoH: f32,
+    specularIntensity: f32,
+    f_ab: vec2<f32>,
+) -> vec3<f32> {
+    // Roughness along the tangent and bitangent, from the KHR_materials_anisotropy specification
+    let at = mix(roughness, 1.0, anisotropy.strength * anisotropy.strength);
+    let ab = clamp(roughness, 0.001, 1.0);
+
+    let D = D_GGX_Anisotropic(at, ab, NoH, dot(anisotropy.T, H), dot(anisotropy.B, H));
+    let V = V_SmithGGXCorrelated_Anisotropic(
+        at,
+        ab,
+        dot(anisotropy.T, V),
+        dot(anisotropy.B, V),
+        dot(anisotropy.T, L),
+        dot(anisotropy.B, L),
+        NoV,
+        NoL,
+    );
+    let F = fresnel(f0, LoH);
+
+    var Fr = (specularIntensity * D * V) * F;
+    Fr *= 1.0 + f0 * (1.0 / f_ab.x - 1.0);
+    return Fr;
+}
+
+// Visibility function of the clearcoat layer
+// https://google.github.io/filament/Filament.html#materialsystem/clearcoatmodel
+fn V_Kelemen(LoH: f32) -> f32 {
+    return 0.25 / max(LoH * LoH, 0.0001);
+}
+
+// Specular BRDF of the clearcoat layer, which has a fixed index of refraction of 1.5 (f0 = 0.04)
+// Returns the fresnel term of the layer in x, which attenuates the base layer, and the lobe in y
+fn specular_clearcoat(clearcoat: Clearcoat, NoH: f32, LoH: f32, specularIntensity: f32) -> vec2<f32> {
+    let D = D_GGX(clearcoat.roughness, NoH, vec3<f32>(0.0));
+    let V = V_Kelemen(LoH);
+    let F = F_Schlick(0.04, 1.0, LoH) * clearcoat.strength;
+    return vec2<f32>(F, specularIntensity * D * V * F);
+}
+
 // Diffuse BRDF
 // https://google.github.io/filament/Filament.html#materialsystem/diffusebrdf
 // fd(v,l) = σ/π * 1 / { |n⋅v||n⋅l| } ∫Ω D(m,α) G(v,l,m) (v⋅m) (l⋅m) dm
@@ -178,7 +275,13 @@ fn point_light(
     R: vec3<f32>,
     F0: vec3<f32>,
     f_ab: vec2<f32>,
-    diffuseColor: vec3<f32>
+    diffuseColor: vec3<f32>,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    clearcoat: Clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+    anisotropy: Anisotropy,
+#endif
 ) -> vec3<f32> {
     let light = &point_lights.data[light_id];
     let light_to_frag = (*light).position_radius.xyz - world_position.xyz;
@@ -201,7 +304,17 @@ fn point_light(
     var NoH: f32 = saturate(dot(N, H));
     var LoH: f32 = saturate(dot(L, H));
 
+#ifdef STANDARDMATERIAL_ANISOTROPY
+    let specular_light = specular_anisotropic(F0, roughness, anisotropy, L, V, H, NdotV, NoL, NoH, LoH, specularIntensity, f_ab);
+#else
     let specular_light = specular(F0, roughness, H, NdotV, NoL, NoH, LoH, specularIntensity, f_ab);
+#endif
+
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    let clearcoat_NoL = saturate(dot(clearcoat.N, L));
+    let clearcoat_NoH = saturate(dot(clearcoat.N, H));
+    let clearcoat_light = specular_clearcoat(clearcoat, clearcoat_NoH, LoH, specularIntensity);
+#endif
 
     // Diffuse.
     // Comes after specular since its NoL is used in the lighting equation.
@@ -226,7 +339,81 @@ fn point_light(
 
     // NOTE: (*light).color.rgb is premultiplied with (*light).intensity / 4 π (which would be the luminous intensity) on the CPU
 
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    // The light reaching the base layer is attenuated by the reflection of the clearcoat layer
+    let layered_light = (diffuse + specular_light) * (1.0 - clearcoat_light.x) * NoL + clearcoat_light.y * clearcoat_NoL;
+    return layered_light * (*light).color_inverse_square_range.rgb * rangeAttenuation;
+#else
     return ((diffuse + specular_light) * (*light).color_inverse_square_range.rgb) * (rangeAttenuation * NoL);
+#endif
+}
+
+fn spot_light_direction(light_id: u32) -> vec3<f32> {
+    let light = &point_lights.data[light_id];
+
+    // reconstruct spot dir from x/z and y-direction flag
+    var spot_dir = vec3<f32>((*light).light_custom_data.x, 0.0, (*light).light_custom_data.y);
+    spot_dir.y = sqrt(max(0.0, 1.0 - spot_dir.x * spot_dir.x - spot_dir.z * spot_dir.z));
+    if ((*light).flags & POINT_LIGHT_FLAGS_SPOT_LIGHT_Y_NEGATIVE) != 0u {
+        spot_dir.y = -spot_dir.y;
+    }
+    return spot_dir;
+}
+
+// The color of the point light's texture in the direction of the fragment, or white for lights
+// without a texture.
+// NOTE: The lights are iterated in non-uniform control flow, so the texture is sampled with an
+// explicit level to avoid implicit derivatives.
+fn point_light_texture(light_id: u32, world_position: vec3<f32>) -> vec3<f32> {
+    let light = &point_lights.data[light_id];
+    if ((*light).flags & POINT_LIGHT_FLAGS_LIGHT_TEXTURE_BIT) == 0u {
+        return vec3<f32>(1.0);
+    }
+
+    let frag_to_light_center = world_position - (*light).position_radius.xyz;
+#ifdef NO_ARRAY_TEXTURES_SUPPORT
+    return textureSampleLevel(point_light_textures, light_textures_sampler, frag_to_light_center, 0.0).rgb;
+#else
+    let layer = (*light).flags >> POINT_LIGHT_FLAGS_LIGHT_TEXTURE_LAYER_SHIFT;
+    return textureSampleLevel(point_light_textures, light_textures_sampler, frag_to_light_center, i32(layer), 0.0).rgb;
+#endif
+}
+
+// The color of the spot light's texture projected onto the fragment, or white for lights without
+// a texture. The texture is projected like the spot light's shadow map, see fetch_spot_shadow.
+fn spot_light_texture(light_id: u32, world_position: vec3<f32>) -> vec3<f32> {
+    let light = &point_lights.data[light_id];
+    if ((*light).flags & POINT_LIGHT_FLAGS_LIGHT_TEXTURE_BIT) == 0u {
+        return vec3<f32>(1.0);
+    }
+
+    // the construction of the light's basis needs to precisely mirror the code
+    // in render/light.rs:spot_light_view_matrix
+    let fwd = -spot_light_direction(light_id);
+    var sign = -1.0;
+    if (fwd.z >= 0.0) {
+        sign = 1.0;
+    }
+    let a = -1.0 / (fwd.z + sign);
+    let b = fwd.x * fwd.y * a;
+    let up_dir = vec3<f32>(1.0 + sign * fwd.x * fwd.x * a, sign * b, -sign * fwd.x);
+    let right_dir = vec3<f32>(-b, -sign - fwd.y * fwd.y * a, fwd.y);
+    let light_inv_rot = mat3x3<f32>(right_dir, up_dir, fwd);
+
+    let projected_position = (world_position - (*light).position_radius.xyz) * light_inv_rot;
+    // fragments behind the light are outside of its cone
+    if (projected_position.z >= 0.0) {
+        return vec3<f32>(0.0);
+    }
+    let xy_ndc = projected_position.xy / ((*light).spot_light_tan_angle * -projected_position.z);
+    let uv = xy_ndc * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5);
+
+#ifdef NO_ARRAY_TEXTURES_SUPPORT
+    return textureSampleLevel(spot_light_textures, light_textures_sampler, uv, 0.0).rgb;
+#else
+    let layer = (*light).flags >> POINT_LIGHT_FLAGS_LIGHT_TEXTURE_LAYER_SHIFT;
+    return textureSampleLevel(spot_light_textures, light_textures_sampler, uv, i32(layer), 0.0).rgb;
+#endif
 }
 
 fn spot_light(
@@ -239,19 +426,36 @@ fn spot_light(
     R: vec3<f32>,
     F0: vec3<f32>,
     f_ab: vec2<f32>,
-    diffuseColor: vec3<f32>
+    diffuseColor: vec3<f32>,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    clearcoat: Clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+    anisotropy: Anisotropy,
+#endif
 ) -> vec3<f32> {
     // reuse the point light calculations
-    let point_light = point_light(world_position, light_id, roughness, NdotV, N, V, R, F0, f_ab, diffuseColor);
+    let point_light = point_light(
+        world_position,
+        light_id,
+        roughness,
+        NdotV,
+        N,
+        V,
+        R,
+        F0,
+        f_ab,
+        diffuseColor,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+        clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+        anisotropy,
+#endif
+    );
 
     let light = &point_lights.data[light_id];
-
-    // reconstruct spot dir from x/z and y-direction flag
-    var spot_dir = vec3<f32>((*light).light_custom_data.x, 0.0, (*light).light_custom_data.y);
-    spot_dir.y = sqrt(max(0.0, 1.0 - spot_dir.x * spot_dir.x - spot_dir.z * spot_dir.z));
-    if ((*light).flags & POINT_LIGHT_FLAGS_SPOT_LIGHT_Y_NEGATIVE) != 0u {
-        spot_dir.y = -spot_dir.y;
-    }
+    let spot_dir = spot_light_direction(light_id);
     let light_to_frag = (*light).position_radius.xyz - world_position.xyz;
 
     // calculate attenuation based on filament formula https://google.github.io/filament/Filament.html#listing_glslpunctuallight
@@ -264,7 +468,23 @@ fn spot_light(
     return point_light * spot_attenuation;
 }
 
-fn directional_light(light_id: u32, roughness: f32, NdotV: f32, normal: vec3<f32>, view: vec3<f32>, R: vec3<f32>, F0: vec3<f32>, f_ab: vec2<f32>, diffuseColor: vec3<f32>) -> vec3<f32> {
+fn directional_light(
+    light_id: u32,
+    roughness: f32,
+    NdotV: f32,
+    normal: vec3<f32>,
+    view: vec3<f32>,
+    R: vec3<f32>,
+    F0: vec3<f32>,
+    f_ab: vec2<f32>,
+    diffuseColor: vec3<f32>,
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    clearcoat: Clearcoat,
+#endif
+#ifdef STANDARDMATERIAL_ANISOTROPY
+    anisotropy: Anisotropy,
+#endif
+) -> vec3<f32> {
     let light = &lights.directional_lights[light_id];
 
     let incident_light = (*light).direction_to_light.xyz;
@@ -276,7 +496,20 @@ fn directional_light(light_id: u32, roughness: f32, NdotV: f32, normal: vec3<f32
 
     let diffuse = diffuseColor * Fd_Burley(roughness, NdotV, NoL, LoH);
     let specularIntensity = 1.0;
+#ifdef STANDARDMATERIAL_ANISOTROPY
+    let specular_light = specular_anisotropic(F0, roughness, anisotropy, incident_light, view, half_vector, NdotV, NoL, NoH, LoH, specularIntensity, f_ab);
+#else
     let specular_light = specular(F0, roughness, half_vector, NdotV, NoL, NoH, LoH, specularIntensity, f_ab);
-
+#endif
+
+#ifdef STANDARDMATERIAL_CLEARCOAT
+    let clearcoat_NoL = saturate(dot(clearcoat.N, incident_light));
+    let clearcoat_NoH = saturate(dot(clearcoat.N, half_vector));
+    let clearcoat_light = specular_clearcoat(clearcoat, clearcoat_NoH, LoH, specularIntensity);
+    // The light reaching the base layer is attenuated by the reflection of the clearcoat layer
+    let layered_light = (specular_light + diffuse) * (1.0 - clearcoat_light.x) * NoL + clearcoat_light.y * clearcoat_NoL;
+    return layered_light * (*light).color.rgb;
+#else
     return (specular_light + diffuse) * (*light).color.rgb * NoL;
+#endif
 }
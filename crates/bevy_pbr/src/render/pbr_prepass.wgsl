@@ -16,6 +16,11 @@ struct FragmentInput {
     @location(2) world_tangent: vec4<f32>,
 #endif // VERTEX_TANGENTS
 #endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    @location(3) world_position: vec4<f32>,
+    @location(4) previous_world_position: vec4<f32>,
+#endif // MOTION_VECTOR_PREPASS
 };
 
 // Cutoff used for the premultiplied alpha modes BLEND and ADD.
@@ -63,12 +68,24 @@ fn prepass_alpha_discard(in: FragmentInput) {
 #endif // EMPTY_PREPASS_ALPHA_DISCARD not defined
 }
 
+#ifdef PREPASS_FRAGMENT
+struct FragmentOutput {
 #ifdef NORMAL_PREPASS
+    @location(0) normal: vec4<f32>,
+#endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    @location(1) motion_vector: vec2<f32>,
+#endif // MOTION_VECTOR_PREPASS
+}
 
 @fragment
-fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
+fn fragment(in: FragmentInput) -> FragmentOutput {
     prepass_alpha_discard(in);
 
+    var out: FragmentOutput;
+
+#ifdef NORMAL_PREPASS
     // NOTE: Unlit bit not set means == 0 is true, so the true case is if lit
     if (material.flags & STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
         let world_normal = prepare_world_normal(
@@ -90,18 +107,23 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
 #endif // VERTEX_UVS
         );
 
-        return vec4(normal * 0.5 + vec3(0.5), 1.0);
+        out.normal = vec4(normal * 0.5 + vec3(0.5), 1.0);
     } else {
-        return vec4(in.world_normal * 0.5 + vec3(0.5), 1.0);
+        out.normal = vec4(in.world_normal * 0.5 + vec3(0.5), 1.0);
     }
-}
+#endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    out.motion_vector = calculate_motion_vector(in.world_position, in.previous_world_position);
+#endif // MOTION_VECTOR_PREPASS
 
-#else // NORMAL_PREPASS
+    return out;
+}
+#else // PREPASS_FRAGMENT
 
 @fragment
 fn fragment(in: FragmentInput) {
     prepass_alpha_discard(in);
 }
 
-#endif // NORMAL_PREPASS
-
+#endif // PREPASS_FRAGMENT
@@ -41,6 +41,124 @@ fn fetch_point_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: v
 #endif
 }
 
+// A single lookup into the 2D shadow maps of directional and spot lights, using HW PCF and comparison.
+// NOTE: Due to non-uniform control flow in the callers, we must use the level variant of the
+// texture sampler to avoid use of implicit derivatives causing possible undefined behavior.
+fn sample_shadow_map_hardware(light_local: vec2<f32>, depth: f32, array_index: i32) -> f32 {
+#ifdef NO_ARRAY_TEXTURES_SUPPORT
+    return textureSampleCompareLevel(
+        directional_shadow_textures,
+        directional_shadow_textures_sampler,
+        light_local,
+        depth
+    );
+#else
+    return textureSampleCompareLevel(
+        directional_shadow_textures,
+        directional_shadow_textures_sampler,
+        light_local,
+        array_index,
+        depth
+    );
+#endif
+}
+
+// Nine hardware samples weighted to match a 5x5 tent filter, approximating a Gaussian.
+// https://web.archive.org/web/20230210095515/http://the-witness.net/news/2013/09/shadow-mapping-summary-part-1
+fn sample_shadow_map_castano_thirteen(light_local: vec2<f32>, depth: f32, array_index: i32) -> f32 {
+    let shadow_map_size = vec2<f32>(textureDimensions(directional_shadow_textures));
+    let inv_shadow_map_size = 1.0 / shadow_map_size;
+
+    let uv = light_local * shadow_map_size;
+    var base_uv = floor(uv + 0.5);
+    let s = (uv.x + 0.5 - base_uv.x);
+    let t = (uv.y + 0.5 - base_uv.y);
+    base_uv -= 0.5;
+    base_uv *= inv_shadow_map_size;
+
+    let uw0 = (4.0 - 3.0 * s);
+    let uw1 = 7.0;
+    let uw2 = (1.0 + 3.0 * s);
+
+    let u0 = (3.0 - 2.0 * s) / uw0 - 2.0;
+    let u1 = (3.0 + s) / uw1;
+    let u2 = s / uw2 + 2.0;
+
+    let vw0 = (4.0 - 3.0 * t);
+    let vw1 = 7.0;
+    let vw2 = (1.0 + 3.0 * t);
+
+    let v0 = (3.0 - 2.0 * t) / vw0 - 2.0;
+    let v1 = (3.0 + t) / vw1;
+    let v2 = t / vw2 + 2.0;
+
+    var sum = 0.0;
+    sum += uw0 * vw0 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u0, v0) * inv_shadow_map_size), depth, array_index);
+    sum += uw1 * vw0 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u1, v0) * inv_shadow_map_size), depth, array_index);
+    sum += uw2 * vw0 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u2, v0) * inv_shadow_map_size), depth, array_index);
+    sum += uw0 * vw1 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u0, v1) * inv_shadow_map_size), depth, array_index);
+    sum += uw1 * vw1 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u1, v1) * inv_shadow_map_size), depth, array_index);
+    sum += uw2 * vw1 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u2, v1) * inv_shadow_map_size), depth, array_index);
+    sum += uw0 * vw2 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u0, v2) * inv_shadow_map_size), depth, array_index);
+    sum += uw1 * vw2 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u1, v2) * inv_shadow_map_size), depth, array_index);
+    sum += uw2 * vw2 * sample_shadow_map_hardware(base_uv + (vec2<f32>(u2, v2) * inv_shadow_map_size), depth, array_index);
+
+    return sum * (1.0 / 144.0);
+}
+
+// https://blog.demofox.org/2022/01/01/interleaved-gradient-noise-a-different-kind-of-low-discrepancy-sequence
+fn interleaved_gradient_noise(pixel_coordinates: vec2<f32>, frame: u32) -> f32 {
+    let xy = pixel_coordinates + 5.588238 * f32(frame % 64u);
+    return fract(52.9829189 * fract(0.06711056 * xy.x + 0.00583715 * xy.y));
+}
+
+// Eight hardware samples on a disk with a radius of two texels, rotated per texel and per frame so
+// that a temporal anti-aliasing pass can resolve the noise into a soft edge.
+// https://www.iryoku.com/next-generation-post-processing-in-call-of-duty-advanced-warfare
+fn sample_shadow_map_jimenez_fourteen(light_local: vec2<f32>, depth: f32, array_index: i32) -> f32 {
+    let shadow_map_size = vec2<f32>(textureDimensions(directional_shadow_textures));
+
+    let random_angle = 2.0 * PI * interleaved_gradient_noise(light_local * shadow_map_size, globals.frame_count);
+    let m = vec2<f32>(sin(random_angle), cos(random_angle));
+    let rotation_matrix = mat2x2<f32>(
+        m.y, -m.x,
+        m.x, m.y
+    );
+    let uv_offset_scale = 2.0 / shadow_map_size;
+
+    // https://mynameismjp.wordpress.com/2013/09/10/shadow-maps/ (slides 120-135)
+    let sample_offset0 = (rotation_matrix * vec2<f32>(-0.7071, 0.7071)) * uv_offset_scale;
+    let sample_offset1 = (rotation_matrix * vec2<f32>(-0.0000, -0.8750)) * uv_offset_scale;
+    let sample_offset2 = (rotation_matrix * vec2<f32>(0.5303, 0.5303)) * uv_offset_scale;
+    let sample_offset3 = (rotation_matrix * vec2<f32>(-0.6250, -0.0000)) * uv_offset_scale;
+    let sample_offset4 = (rotation_matrix * vec2<f32>(0.3536, -0.3536)) * uv_offset_scale;
+    let sample_offset5 = (rotation_matrix * vec2<f32>(-0.0000, 0.3750)) * uv_offset_scale;
+    let sample_offset6 = (rotation_matrix * vec2<f32>(-0.1768, -0.1768)) * uv_offset_scale;
+    let sample_offset7 = (rotation_matrix * vec2<f32>(0.1250, 0.0000)) * uv_offset_scale;
+
+    var sum = 0.0;
+    sum += sample_shadow_map_hardware(light_local + sample_offset0, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset1, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset2, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset3, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset4, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset5, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset6, depth, array_index);
+    sum += sample_shadow_map_hardware(light_local + sample_offset7, depth, array_index);
+    return sum / 8.0;
+}
+
+// Samples the 2D shadow maps with the filter selected by the view's `ShadowFilteringMethod`.
+fn sample_shadow_map(light_local: vec2<f32>, depth: f32, array_index: i32) -> f32 {
+#ifdef SHADOW_FILTER_METHOD_GAUSSIAN
+    return sample_shadow_map_castano_thirteen(light_local, depth, array_index);
+#else ifdef SHADOW_FILTER_METHOD_TEMPORAL
+    return sample_shadow_map_jimenez_fourteen(light_local, depth, array_index);
+#else
+    return sample_shadow_map_hardware(light_local, depth, array_index);
+#endif
+}
+
 fn fetch_spot_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: vec3<f32>) -> f32 {
     let light = &point_lights.data[light_id];
 
@@ -89,13 +207,7 @@ fn fetch_spot_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: ve
     // 0.1 must match POINT_LIGHT_NEAR_Z
     let depth = 0.1 / -projected_position.z;
 
-    #ifdef NO_ARRAY_TEXTURES_SUPPORT
-        return textureSampleCompare(directional_shadow_textures, directional_shadow_textures_sampler,
-            shadow_uv, depth);
-    #else
-        return textureSampleCompareLevel(directional_shadow_textures, directional_shadow_textures_sampler,
-            shadow_uv, i32(light_id) + lights.spot_light_shadowmap_offset, depth);
-    #endif
+    return sample_shadow_map(shadow_uv, depth, i32(light_id) + lights.spot_light_shadowmap_offset);
 }
 
 fn get_cascade_index(light_id: u32, view_z: f32) -> u32 {
@@ -135,25 +247,7 @@ fn sample_cascade(light_id: u32, cascade_index: u32, frag_position: vec4<f32>, s
     let light_local = offset_position_ndc.xy * flip_correction + vec2<f32>(0.5, 0.5);
 
     let depth = offset_position_ndc.z;
-    // do the lookup, using HW PCF and comparison
-    // NOTE: Due to non-uniform control flow above, we must use the level variant of the texture
-    // sampler to avoid use of implicit derivatives causing possible undefined behavior.
-#ifdef NO_ARRAY_TEXTURES_SUPPORT
-    return textureSampleCompareLevel(
-        directional_shadow_textures,
-        directional_shadow_textures_sampler,
-        light_local,
-        depth
-    );
-#else
-    return textureSampleCompareLevel(
-        directional_shadow_textures,
-        directional_shadow_textures_sampler,
-        light_local,
-        i32((*light).depth_texture_base_index + cascade_index),
-        depth
-    );
-#endif
+    return sample_shadow_map(light_local, depth, i32((*light).depth_texture_base_index + cascade_index));
 }
 
 fn fetch_directional_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: vec3<f32>, view_z: f32) -> f32 {
@@ -0,0 +1,200 @@
+// Ground Truth-based Ambient Occlusion (GTAO)
+// Paper: https://www.activision.com/cdn/research/Practical_Real_Time_Strategies_for_Accurate_Indirect_Occlusion_NEW%20VERSION_COLOR.pdf
+// Presentation: https://blog.selfshadow.com/publications/s2016-shading-course/activision/s2016_pbs_activision_occlusion.pdf
+
+// Source code heavily based on XeGTAO v1.30 from Intel
+// https://github.com/GameTechDev/XeGTAO/blob/0d177ce06bfa642f64d8af4de1197ad1bcb862d4/Source/Rendering/Shaders/XeGTAO.hlsli
+
+#import bevy_pbr::utils
+#import bevy_render::view
+
+@group(0) @binding(0) var preprocessed_depth: texture_2d<f32>;
+@group(0) @binding(1) var normals: texture_2d<f32>;
+@group(0) @binding(2) var hilbert_index_lut: texture_2d<u32>;
+@group(0) @binding(3) var ambient_occlusion: texture_storage_2d<r16float, write>;
+@group(0) @binding(4) var depth_differences: texture_storage_2d<r32uint, write>;
+@group(1) @binding(0) var point_clamp_sampler: sampler;
+@group(1) @binding(1) var<uniform> view: View;
+
+const HALF_PI: f32 = 1.5707963267948966;
+
+// Matches MAX_LINEAR_DEPTH in preprocess_depth.wgsl
+const BACKGROUND_DEPTH: f32 = 65000.0;
+
+// Approximation of acos with a maximum error of ~0.0075 radians
+// https://seblagarde.wordpress.com/2014/12/01/inverse-trigonometric-functions-gpu-optimization-for-amd-gcn-architecture/
+fn fast_acos(in_x: f32) -> f32 {
+    let x = abs(in_x);
+    var res = -0.156583 * x + HALF_PI;
+    res *= sqrt(1.0 - x);
+    return select(PI - res, res, in_x >= 0.0);
+}
+
+fn load_noise(pixel_coordinates: vec2<i32>) -> vec2<f32> {
+    let index = textureLoad(hilbert_index_lut, pixel_coordinates % vec2<i32>(64), 0).r;
+
+    // R2 sequence - http://extremelearning.com.au/unreasonable-effectiveness-of-quasirandom-sequences
+    return fract(0.5 + f32(index) * vec2<f32>(0.75487766624669276005, 0.5698402909980532659114));
+}
+
+fn load_depth(pixel_coordinates: vec2<i32>) -> f32 {
+    let max_coordinates = vec2<i32>(textureDimensions(preprocessed_depth)) - 1;
+    return textureLoad(preprocessed_depth, clamp(pixel_coordinates, vec2<i32>(0), max_coordinates), 0).r;
+}
+
+// Calculate differences in depth between neighbor pixels (later used by the spatial denoiser pass to preserve object edges)
+fn calculate_neighboring_depth_differences(pixel_coordinates: vec2<i32>) -> f32 {
+    // Sample the pixel's depth and 4 depths around it
+    let depth_center = load_depth(pixel_coordinates);
+    let depth_left = load_depth(pixel_coordinates + vec2<i32>(-1, 0));
+    let depth_right = load_depth(pixel_coordinates + vec2<i32>(1, 0));
+    let depth_top = load_depth(pixel_coordinates + vec2<i32>(0, -1));
+    let depth_bottom = load_depth(pixel_coordinates + vec2<i32>(0, 1));
+
+    // Calculate the depth differences (large differences represent object edges)
+    var edge_info = vec4<f32>(depth_left, depth_right, depth_top, depth_bottom) - depth_center;
+    let slope_left_right = (edge_info.y - edge_info.x) * 0.5;
+    let slope_top_bottom = (edge_info.w - edge_info.z) * 0.5;
+    let edge_info_slope_adjusted = edge_info + vec4<f32>(slope_left_right, -slope_left_right, slope_top_bottom, -slope_top_bottom);
+    edge_info = min(abs(edge_info), abs(edge_info_slope_adjusted));
+    let bias = 0.25; // Using the bias and then saturating nudges the values a bit
+    let scale = depth_center * 0.011; // Weight the edges by their distance from the camera
+    edge_info = saturate((1.0 + bias) - edge_info / scale); // Apply the bias and scale, and invert edge_info so that small values become large, and vice versa
+
+    // Pack the edge info into the texture
+    let edge_info_packed = vec4<u32>(pack4x8unorm(edge_info), 0u, 0u, 0u);
+    textureStore(depth_differences, pixel_coordinates, edge_info_packed);
+
+    return depth_center;
+}
+
+fn load_normal_view_space(pixel_coordinates: vec2<i32>) -> vec3<f32> {
+    let world_normal = textureLoad(normals, pixel_coordinates, 0).xyz * 2.0 - 1.0;
+    let inverse_view = mat3x3<f32>(
+        view.inverse_view[0].xyz,
+        view.inverse_view[1].xyz,
+        view.inverse_view[2].xyz,
+    );
+    // The rotation part of the inverse view matrix is orthonormal, so multiplying
+    // by its transpose (vector on the left) applies the view rotation
+    return normalize(world_normal * inverse_view);
+}
+
+// Reconstructs a view space position from a linear (view space) depth and a UV within the viewport
+fn reconstruct_view_space_position(linear_depth: f32, viewport_uv: vec2<f32>) -> vec3<f32> {
+    let ndc_xy = vec2<f32>(viewport_uv.x * 2.0 - 1.0, 1.0 - 2.0 * viewport_uv.y);
+    let projection_scale = vec2<f32>(view.projection[0].x, view.projection[1].y);
+    if view.projection[3].w == 1.0 {
+        // Orthographic: x and y don't depend on the depth
+        return vec3<f32>((ndc_xy - view.projection[3].xy) / projection_scale, -linear_depth);
+    }
+    return vec3<f32>((ndc_xy + view.projection[2].xy) * linear_depth / projection_scale, -linear_depth);
+}
+
+// `pixel_position` is in render target pixels, which is also what the prepass textures cover
+fn load_and_reconstruct_view_space_position(pixel_position: vec2<f32>, sample_mip_level: f32) -> vec3<f32> {
+    let uv = pixel_position / vec2<f32>(textureDimensions(preprocessed_depth));
+    let depth = textureSampleLevel(preprocessed_depth, point_clamp_sampler, uv, sample_mip_level).r;
+    return reconstruct_view_space_position(depth, coords_to_viewport_uv(pixel_position, view.viewport));
+}
+
+@compute
+@workgroup_size(8, 8, 1)
+fn gtao(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let slice_count = f32(#SLICE_COUNT);
+    let samples_per_slice_side = f32(#SAMPLES_PER_SLICE_SIDE);
+    let effect_radius = 0.5 * 1.457;
+    let falloff_range = 0.615 * effect_radius;
+    let falloff_from = effect_radius * (1.0 - 0.615);
+    let falloff_mul = -1.0 / falloff_range;
+    let falloff_add = falloff_from / falloff_range + 1.0;
+
+    let pixel_coordinates = vec2<i32>(global_id.xy);
+    if any(pixel_coordinates >= vec2<i32>(textureDimensions(preprocessed_depth))) {
+        return;
+    }
+    let pixel_center = vec2<f32>(pixel_coordinates) + 0.5;
+
+    var pixel_depth = calculate_neighboring_depth_differences(pixel_coordinates);
+    if pixel_depth >= BACKGROUND_DEPTH {
+        // Nothing to occlude
+        textureStore(ambient_occlusion, pixel_coordinates, vec4<f32>(1.0, 0.0, 0.0, 0.0));
+        return;
+    }
+    pixel_depth *= 0.99999; // Avoid depth precision issues
+
+    let pixel_position = reconstruct_view_space_position(pixel_depth, coords_to_viewport_uv(pixel_center, view.viewport));
+    let pixel_normal = load_normal_view_space(pixel_coordinates);
+    let is_orthographic = view.projection[3].w == 1.0;
+    let view_vec = select(normalize(-pixel_position), vec3<f32>(0.0, 0.0, 1.0), is_orthographic);
+
+    let noise = load_noise(pixel_coordinates);
+
+    // The effect radius projected to pixels
+    let depth_scale = select(1.0 / pixel_depth, 1.0, is_orthographic);
+    let sample_scale = 0.5 * effect_radius * depth_scale * vec2<f32>(view.projection[0].x, view.projection[1].y) * view.viewport.zw;
+    // Samples closer than this would land on the center pixel
+    let min_s = 1.3 / length(sample_scale);
+
+    var visibility = 0.0;
+    for (var slice_t = 0.0; slice_t < slice_count; slice_t += 1.0) {
+        let slice = slice_t + noise.x;
+        let phi = (PI / slice_count) * slice;
+        let omega = vec2<f32>(cos(phi), sin(phi));
+
+        let direction = vec3<f32>(omega.xy, 0.0);
+        let orthographic_direction = direction - (dot(direction, view_vec) * view_vec);
+        let axis = cross(direction, view_vec);
+        let projected_normal = pixel_normal - axis * dot(pixel_normal, axis);
+        let projected_normal_length = length(projected_normal);
+
+        let sign_norm = sign(dot(orthographic_direction, projected_normal));
+        let cos_norm = saturate(dot(projected_normal, view_vec) / projected_normal_length);
+        let n = sign_norm * fast_acos(cos_norm);
+
+        let min_cos_horizon_1 = cos(n + HALF_PI);
+        let min_cos_horizon_2 = cos(n - HALF_PI);
+        var cos_horizon_1 = min_cos_horizon_1;
+        var cos_horizon_2 = min_cos_horizon_2;
+        // Pixel y goes down while view space y goes up
+        let sample_mul = vec2<f32>(omega.x, -omega.y) * sample_scale;
+        for (var sample_t = 0.0; sample_t < samples_per_slice_side; sample_t += 1.0) {
+            var sample_noise = (slice_t + sample_t * samples_per_slice_side) * 0.6180339887498948482;
+            sample_noise = fract(noise.y + sample_noise);
+
+            var s = (sample_t + sample_noise) / samples_per_slice_side;
+            s *= s; // https://github.com/GameTechDev/XeGTAO#sample-distribution
+            s += min_s;
+            let sample = round(s * sample_mul);
+
+            let sample_mip_level = clamp(log2(length(sample)) - 3.3, 0.0, 4.0); // https://github.com/GameTechDev/XeGTAO#memory-bandwidth-bottleneck
+            let sample_position_1 = load_and_reconstruct_view_space_position(pixel_center + sample, sample_mip_level);
+            let sample_position_2 = load_and_reconstruct_view_space_position(pixel_center - sample, sample_mip_level);
+
+            let sample_difference_1 = sample_position_1 - pixel_position;
+            let sample_difference_2 = sample_position_2 - pixel_position;
+            let sample_distance_1 = length(sample_difference_1);
+            let sample_distance_2 = length(sample_difference_2);
+            var sample_cos_horizon_1 = dot(sample_difference_1 / sample_distance_1, view_vec);
+            var sample_cos_horizon_2 = dot(sample_difference_2 / sample_distance_2, view_vec);
+
+            let weight_1 = saturate(sample_distance_1 * falloff_mul + falloff_add);
+            let weight_2 = saturate(sample_distance_2 * falloff_mul + falloff_add);
+            sample_cos_horizon_1 = mix(min_cos_horizon_1, sample_cos_horizon_1, weight_1);
+            sample_cos_horizon_2 = mix(min_cos_horizon_2, sample_cos_horizon_2, weight_2);
+
+            cos_horizon_1 = max(cos_horizon_1, sample_cos_horizon_1);
+            cos_horizon_2 = max(cos_horizon_2, sample_cos_horizon_2);
+        }
+
+        let horizon_1 = fast_acos(cos_horizon_1);
+        let horizon_2 = -fast_acos(cos_horizon_2);
+        let v1 = (cos_norm + 2.0 * horizon_1 * sin(n) - cos(2.0 * horizon_1 - n)) / 4.0;
+        let v2 = (cos_norm + 2.0 * horizon_2 * sin(n) - cos(2.0 * horizon_2 - n)) / 4.0;
+        visibility += projected_normal_length * (v1 + v2);
+    }
+    visibility /= slice_count;
+    visibility = clamp(visibility, 0.03, 1.0);
+
+    textureStore(ambient_occlusion, pixel_coordinates, vec4<f32>(visibility, 0.0, 0.0, 0.0));
+}
@@ -0,0 +1,1001 @@
+use bevy_app::{App, IntoSystemAppConfig, Plugin};
+use bevy_asset::{load_internal_asset, HandleUntyped};
+use bevy_core_pipeline::{
+    core_3d::{self, Camera3d},
+    prepass::{DepthPrepass, NormalPrepass, ViewPrepassTextures},
+};
+use bevy_ecs::{
+    prelude::{Bundle, Component, Entity},
+    query::{QueryState, With},
+    reflect::ReflectComponent,
+    schedule::IntoSystemConfig,
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+    world::{FromWorld, World},
+};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeUuid};
+use bevy_render::{
+    camera::{Camera, ExtractedCamera},
+    render_graph::{Node, NodeRunError, RenderGraph, RenderGraphContext, SlotInfo, SlotType},
+    render_resource::*,
+    renderer::{RenderAdapter, RenderContext, RenderDevice, RenderQueue},
+    texture::{CachedTexture, TextureCache},
+    view::{Msaa, ViewUniform, ViewUniformOffset, ViewUniforms},
+    Extract, ExtractSchedule, RenderApp, RenderSet,
+};
+use bevy_utils::tracing::{error, warn};
+use std::num::NonZeroU32;
+
+const PREPROCESS_DEPTH_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 102258915420479);
+const GTAO_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 253938746510568);
+const SPATIAL_DENOISE_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 466162052558226);
+const GTAO_UTILS_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 366465052568786);
+
+/// Number of mip levels of the preprocessed depth texture sampled by the GTAO pass.
+const PREPROCESSED_DEPTH_MIP_COUNT: u32 = 5;
+
+/// Plugin for screen space ambient occlusion.
+///
+/// Add it after [`PbrPlugin`](crate::PbrPlugin), then add a
+/// [`ScreenSpaceAmbientOcclusionBundle`] to the cameras that should use it.
+///
+/// The plugin requires compute shaders and storage textures, so it disables itself
+/// (with a warning) on platforms that lack them, such as WebGL2.
+pub struct ScreenSpaceAmbientOcclusionPlugin;
+
+impl Plugin for ScreenSpaceAmbientOcclusionPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            PREPROCESS_DEPTH_SHADER_HANDLE,
+            "preprocess_depth.wgsl",
+            Shader::from_wgsl
+        );
+        load_internal_asset!(app, GTAO_SHADER_HANDLE, "gtao.wgsl", Shader::from_wgsl);
+        load_internal_asset!(
+            app,
+            SPATIAL_DENOISE_SHADER_HANDLE,
+            "spatial_denoise.wgsl",
+            Shader::from_wgsl
+        );
+        load_internal_asset!(
+            app,
+            GTAO_UTILS_SHADER_HANDLE,
+            "gtao_utils.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.register_type::<ScreenSpaceAmbientOcclusionSettings>();
+
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Ok(render_app) => render_app,
+            Err(_) => return,
+        };
+
+        if !render_app
+            .world
+            .resource::<RenderAdapter>()
+            .get_texture_format_features(TextureFormat::R16Float)
+            .allowed_usages
+            .contains(TextureUsages::STORAGE_BINDING)
+        {
+            warn!("ScreenSpaceAmbientOcclusionPlugin not loaded. GPU lacks support: TextureFormat::R16Float does not support TextureUsages::STORAGE_BINDING.");
+            return;
+        }
+
+        if render_app
+            .world
+            .resource::<RenderDevice>()
+            .limits()
+            .max_storage_textures_per_shader_stage
+            < PREPROCESSED_DEPTH_MIP_COUNT
+        {
+            warn!("ScreenSpaceAmbientOcclusionPlugin not loaded. GPU lacks support: Limits::max_storage_textures_per_shader_stage is less than {PREPROCESSED_DEPTH_MIP_COUNT}.");
+            return;
+        }
+
+        render_app
+            .init_resource::<SsaoPipelines>()
+            .init_resource::<SpecializedComputePipelines<SsaoPipelines>>()
+            .add_system(extract_ssao_settings.in_schedule(ExtractSchedule))
+            .add_systems((
+                prepare_ssao_textures.in_set(RenderSet::Prepare),
+                prepare_ssao_pipelines.in_set(RenderSet::Prepare),
+                queue_ssao_bind_groups.in_set(RenderSet::Queue),
+            ));
+
+        let ssao_node = SsaoNode::new(&mut render_app.world);
+        let mut graph = render_app.world.resource_mut::<RenderGraph>();
+        let draw_3d_graph = graph.get_sub_graph_mut(core_3d::graph::NAME).unwrap();
+        add_ssao_node(draw_3d_graph, ssao_node);
+    }
+}
+
+/// Adds the SSAO node to the 3d render graph, running between the prepass and the main pass.
+fn add_ssao_node(draw_3d_graph: &mut RenderGraph, ssao_node: SsaoNode) {
+    draw_3d_graph.add_node(
+        crate::draw_3d_graph::node::SCREEN_SPACE_AMBIENT_OCCLUSION,
+        ssao_node,
+    );
+    draw_3d_graph.add_slot_edge(
+        draw_3d_graph.input_node().id,
+        core_3d::graph::input::VIEW_ENTITY,
+        crate::draw_3d_graph::node::SCREEN_SPACE_AMBIENT_OCCLUSION,
+        SsaoNode::IN_VIEW,
+    );
+    // PREPASS -> SCREEN_SPACE_AMBIENT_OCCLUSION -> MAIN_PASS
+    draw_3d_graph.add_node_edge(
+        core_3d::graph::node::PREPASS,
+        crate::draw_3d_graph::node::SCREEN_SPACE_AMBIENT_OCCLUSION,
+    );
+    draw_3d_graph.add_node_edge(
+        crate::draw_3d_graph::node::SCREEN_SPACE_AMBIENT_OCCLUSION,
+        core_3d::graph::node::MAIN_PASS,
+    );
+}
+
+/// Bundle to apply screen space ambient occlusion.
+#[derive(Bundle, Default)]
+pub struct ScreenSpaceAmbientOcclusionBundle {
+    pub settings: ScreenSpaceAmbientOcclusionSettings,
+    pub depth_prepass: DepthPrepass,
+    pub normal_prepass: NormalPrepass,
+}
+
+/// Component to apply screen space ambient occlusion to a 3d camera.
+///
+/// Screen space ambient occlusion (SSAO) approximates small-scale,
+/// local occlusion of _indirect_ diffuse light between objects, based on what's visible on-screen.
+/// SSAO does not apply to direct lighting, such as point or directional lights.
+///
+/// This darkens creases, e.g. on staircases, and gives nice contact shadows
+/// where objects meet, giving entities a more "grounded" feel.
+///
+/// # Usage Notes
+///
+/// Requires that you add [`ScreenSpaceAmbientOcclusionPlugin`] to your app,
+/// and add the [`DepthPrepass`] and [`NormalPrepass`] components to your camera.
+///
+/// SSAO is not supported on WebGL2, and is not currently supported with multisampling,
+/// so the [`Msaa`] of the camera must be set to [`Msaa::Off`].
+#[derive(Component, Reflect, FromReflect, PartialEq, Eq, Hash, Clone, Default, Debug)]
+#[reflect(Component, Default)]
+pub struct ScreenSpaceAmbientOcclusionSettings {
+    pub quality_level: ScreenSpaceAmbientOcclusionQualityLevel,
+}
+
+/// How many samples the GTAO pass takes per pixel, trading noise for performance.
+#[derive(Reflect, FromReflect, PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub enum ScreenSpaceAmbientOcclusionQualityLevel {
+    Low,
+    Medium,
+    #[default]
+    High,
+    Ultra,
+    Custom {
+        /// Higher slice count means less noise, but worse performance.
+        slice_count: u32,
+        /// Samples per slice side is also tweakable, but recommended to be left at 2 or 3.
+        samples_per_slice_side: u32,
+    },
+}
+
+impl ScreenSpaceAmbientOcclusionQualityLevel {
+    /// Returns the number of slices and the number of samples on each side of a slice.
+    fn sample_counts(&self) -> (u32, u32) {
+        match self {
+            Self::Low => (1, 2),    // 4 spp (1 * (2 * 2))
+            Self::Medium => (2, 2), // 8 spp (2 * (2 * 2))
+            Self::High => (3, 3),   // 18 spp (3 * (3 * 2))
+            Self::Ultra => (9, 3),  // 54 spp (9 * (3 * 2))
+            Self::Custom {
+                slice_count: slices,
+                samples_per_slice_side,
+            } => (*slices, *samples_per_slice_side),
+        }
+    }
+}
+
+struct SsaoNode {
+    view_query: QueryState<(
+        &'static ExtractedCamera,
+        &'static SsaoPipelineId,
+        &'static SsaoBindGroups,
+        &'static ViewUniformOffset,
+    )>,
+}
+
+impl SsaoNode {
+    pub const IN_VIEW: &'static str = "view";
+
+    pub fn new(world: &mut World) -> Self {
+        Self {
+            view_query: QueryState::new(world),
+        }
+    }
+}
+
+impl Node for SsaoNode {
+    fn input(&self) -> Vec<SlotInfo> {
+        vec![SlotInfo::new(Self::IN_VIEW, SlotType::Entity)]
+    }
+
+    fn update(&mut self, world: &mut World) {
+        self.view_query.update_archetypes(world);
+    }
+
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let pipelines = world.resource::<SsaoPipelines>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
+        let Ok((camera, pipeline_id, bind_groups, view_uniform_offset)) =
+            self.view_query.get_manual(world, view_entity)
+        else {
+            return Ok(());
+        };
+
+        let (
+            Some(camera_size),
+            Some(preprocess_depth_pipeline),
+            Some(gtao_pipeline),
+            Some(spatial_denoise_pipeline),
+        ) = (
+            camera.physical_target_size,
+            pipeline_cache.get_compute_pipeline(pipelines.preprocess_depth_pipeline),
+            pipeline_cache.get_compute_pipeline(pipeline_id.0),
+            pipeline_cache.get_compute_pipeline(pipelines.spatial_denoise_pipeline),
+        )
+        else {
+            return Ok(());
+        };
+
+        render_context.command_encoder().push_debug_group("ssao");
+
+        {
+            let mut preprocess_depth_pass =
+                render_context
+                    .command_encoder()
+                    .begin_compute_pass(&ComputePassDescriptor {
+                        label: Some("ssao_preprocess_depth_pass"),
+                    });
+            preprocess_depth_pass.set_pipeline(preprocess_depth_pipeline);
+            preprocess_depth_pass.set_bind_group(0, &bind_groups.preprocess_depth_bind_group, &[]);
+            preprocess_depth_pass.set_bind_group(
+                1,
+                &bind_groups.common_bind_group,
+                &[view_uniform_offset.offset],
+            );
+            // Each invocation writes a 2x2 block of the first mip
+            preprocess_depth_pass.dispatch_workgroups(
+                div_ceil(camera_size.x, 16),
+                div_ceil(camera_size.y, 16),
+                1,
+            );
+        }
+
+        {
+            let mut gtao_pass =
+                render_context
+                    .command_encoder()
+                    .begin_compute_pass(&ComputePassDescriptor {
+                        label: Some("ssao_gtao_pass"),
+                    });
+            gtao_pass.set_pipeline(gtao_pipeline);
+            gtao_pass.set_bind_group(0, &bind_groups.gtao_bind_group, &[]);
+            gtao_pass.set_bind_group(
+                1,
+                &bind_groups.common_bind_group,
+                &[view_uniform_offset.offset],
+            );
+            gtao_pass.dispatch_workgroups(
+                div_ceil(camera_size.x, 8),
+                div_ceil(camera_size.y, 8),
+                1,
+            );
+        }
+
+        {
+            let mut spatial_denoise_pass =
+                render_context
+                    .command_encoder()
+                    .begin_compute_pass(&ComputePassDescriptor {
+                        label: Some("ssao_spatial_denoise_pass"),
+                    });
+            spatial_denoise_pass.set_pipeline(spatial_denoise_pipeline);
+            spatial_denoise_pass.set_bind_group(0, &bind_groups.spatial_denoise_bind_group, &[]);
+            spatial_denoise_pass.set_bind_group(
+                1,
+                &bind_groups.common_bind_group,
+                &[view_uniform_offset.offset],
+            );
+            spatial_denoise_pass.dispatch_workgroups(
+                div_ceil(camera_size.x, 8),
+                div_ceil(camera_size.y, 8),
+                1,
+            );
+        }
+
+        render_context.command_encoder().pop_debug_group();
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct SsaoPipelines {
+    preprocess_depth_pipeline: CachedComputePipelineId,
+    spatial_denoise_pipeline: CachedComputePipelineId,
+
+    common_bind_group_layout: BindGroupLayout,
+    preprocess_depth_bind_group_layout: BindGroupLayout,
+    gtao_bind_group_layout: BindGroupLayout,
+    spatial_denoise_bind_group_layout: BindGroupLayout,
+
+    hilbert_index_lut: TextureView,
+    point_clamp_sampler: Sampler,
+}
+
+impl FromWorld for SsaoPipelines {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let render_queue = world.resource::<RenderQueue>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        let hilbert_index_lut = render_device
+            .create_texture_with_data(
+                render_queue,
+                &(TextureDescriptor {
+                    label: Some("ssao_hilbert_index_lut"),
+                    size: Extent3d {
+                        width: HILBERT_WIDTH as u32,
+                        height: HILBERT_WIDTH as u32,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: TextureDimension::D2,
+                    format: TextureFormat::R16Uint,
+                    usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+                    view_formats: &[],
+                }),
+                bytemuck::cast_slice(&generate_hilbert_index_lut()),
+            )
+            .create_view(&TextureViewDescriptor::default());
+
+        let point_clamp_sampler = render_device.create_sampler(&SamplerDescriptor {
+            min_filter: FilterMode::Nearest,
+            mag_filter: FilterMode::Nearest,
+            mipmap_filter: FilterMode::Nearest,
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            ..Default::default()
+        });
+
+        let common_bind_group_layout =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("ssao_common_bind_group_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Sampler(SamplerBindingType::NonFiltering),
+                        count: None,
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Buffer {
+                            ty: BufferBindingType::Uniform,
+                            has_dynamic_offset: true,
+                            min_binding_size: Some(ViewUniform::min_size()),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let mip_texture_entry = BindGroupLayoutEntry {
+            binding: 1,
+            visibility: ShaderStages::COMPUTE,
+            ty: BindingType::StorageTexture {
+                access: StorageTextureAccess::WriteOnly,
+                format: TextureFormat::R16Float,
+                view_dimension: TextureViewDimension::D2,
+            },
+            count: None,
+        };
+        let preprocess_depth_bind_group_layout =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("ssao_preprocess_depth_bind_group_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Depth,
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    mip_texture_entry,
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        ..mip_texture_entry
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 3,
+                        ..mip_texture_entry
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 4,
+                        ..mip_texture_entry
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 5,
+                        ..mip_texture_entry
+                    },
+                ],
+            });
+
+        let gtao_bind_group_layout =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("ssao_gtao_bind_group_layout"),
+                entries: &[
+                    // Preprocessed depth
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: false },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Normal prepass
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: false },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Hilbert index lookup table
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Uint,
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Noisy ambient occlusion output
+                    BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::StorageTexture {
+                            access: StorageTextureAccess::WriteOnly,
+                            format: TextureFormat::R16Float,
+                            view_dimension: TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                    // Depth differences output
+                    BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::StorageTexture {
+                            access: StorageTextureAccess::WriteOnly,
+                            format: TextureFormat::R32Uint,
+                            view_dimension: TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let spatial_denoise_bind_group_layout =
+            render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("ssao_spatial_denoise_bind_group_layout"),
+                entries: &[
+                    // Noisy ambient occlusion
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: false },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Depth differences
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Uint,
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    // Denoised ambient occlusion output
+                    BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: ShaderStages::COMPUTE,
+                        ty: BindingType::StorageTexture {
+                            access: StorageTextureAccess::WriteOnly,
+                            format: TextureFormat::R16Float,
+                            view_dimension: TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let preprocess_depth_pipeline =
+            pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
+                label: Some("ssao_preprocess_depth_pipeline".into()),
+                layout: vec![
+                    preprocess_depth_bind_group_layout.clone(),
+                    common_bind_group_layout.clone(),
+                ],
+                push_constant_ranges: vec![],
+                shader: PREPROCESS_DEPTH_SHADER_HANDLE.typed(),
+                shader_defs: Vec::new(),
+                entry_point: "preprocess_depth".into(),
+            });
+
+        let spatial_denoise_pipeline =
+            pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
+                label: Some("ssao_spatial_denoise_pipeline".into()),
+                layout: vec![
+                    spatial_denoise_bind_group_layout.clone(),
+                    common_bind_group_layout.clone(),
+                ],
+                push_constant_ranges: vec![],
+                shader: SPATIAL_DENOISE_SHADER_HANDLE.typed(),
+                shader_defs: Vec::new(),
+                entry_point: "spatial_denoise".into(),
+            });
+
+        Self {
+            preprocess_depth_pipeline,
+            spatial_denoise_pipeline,
+
+            common_bind_group_layout,
+            preprocess_depth_bind_group_layout,
+            gtao_bind_group_layout,
+            spatial_denoise_bind_group_layout,
+
+            hilbert_index_lut,
+            point_clamp_sampler,
+        }
+    }
+}
+
+impl SpecializedComputePipeline for SsaoPipelines {
+    type Key = ScreenSpaceAmbientOcclusionQualityLevel;
+
+    fn specialize(&self, key: Self::Key) -> ComputePipelineDescriptor {
+        let (slice_count, samples_per_slice_side) = key.sample_counts();
+
+        ComputePipelineDescriptor {
+            label: Some("ssao_gtao_pipeline".into()),
+            layout: vec![
+                self.gtao_bind_group_layout.clone(),
+                self.common_bind_group_layout.clone(),
+            ],
+            push_constant_ranges: vec![],
+            shader: GTAO_SHADER_HANDLE.typed(),
+            shader_defs: vec![
+                ShaderDefVal::UInt("SLICE_COUNT".into(), slice_count),
+                ShaderDefVal::UInt("SAMPLES_PER_SLICE_SIDE".into(), samples_per_slice_side),
+            ],
+            entry_point: "gtao".into(),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn extract_ssao_settings(
+    mut commands: Commands,
+    cameras: Extract<
+        Query<
+            (
+                Entity,
+                &Camera,
+                &ScreenSpaceAmbientOcclusionSettings,
+                Option<&DepthPrepass>,
+                Option<&NormalPrepass>,
+                Option<&Msaa>,
+            ),
+            With<Camera3d>,
+        >,
+    >,
+    default_msaa: Extract<Res<Msaa>>,
+    mut missing_prepass_error_emitted: Local<bool>,
+    mut msaa_error_emitted: Local<bool>,
+) {
+    for (entity, camera, ssao_settings, depth_prepass, normal_prepass, msaa) in &cameras {
+        if depth_prepass.is_none() || normal_prepass.is_none() {
+            if !*missing_prepass_error_emitted {
+                error!("SSAO is being used on a camera without both the DepthPrepass and NormalPrepass components, so it will not be applied. Use ScreenSpaceAmbientOcclusionBundle to add all of them.");
+                *missing_prepass_error_emitted = true;
+            }
+            continue;
+        }
+        let msaa = msaa.unwrap_or(&default_msaa);
+        if *msaa != Msaa::Off {
+            if !*msaa_error_emitted {
+                error!(
+                    "SSAO is being used on a camera which requires Msaa::Off, but its Msaa is set to {} samples",
+                    msaa.samples()
+                );
+                *msaa_error_emitted = true;
+            }
+            continue;
+        }
+
+        if camera.is_active {
+            commands.get_or_spawn(entity).insert(ssao_settings.clone());
+        }
+    }
+}
+
+/// The intermediate and output textures of the SSAO passes of a view.
+#[derive(Component)]
+pub struct ScreenSpaceAmbientOcclusionTextures {
+    preprocessed_depth_texture: CachedTexture,
+    ssao_noisy_texture: CachedTexture,
+    /// The denoised ambient occlusion of the view, sampled by the main pass.
+    pub screen_space_ambient_occlusion_texture: CachedTexture,
+    depth_differences_texture: CachedTexture,
+}
+
+fn prepare_ssao_textures(
+    mut commands: Commands,
+    mut texture_cache: ResMut<TextureCache>,
+    render_device: Res<RenderDevice>,
+    views: Query<(Entity, &ExtractedCamera), With<ScreenSpaceAmbientOcclusionSettings>>,
+) {
+    for (entity, camera) in &views {
+        // The prepass textures cover the whole render target
+        let Some(physical_target_size) = camera.physical_target_size else {
+            continue;
+        };
+        let size = Extent3d {
+            width: physical_target_size.x,
+            height: physical_target_size.y,
+            depth_or_array_layers: 1,
+        };
+
+        let mut create_texture = |label, mip_level_count, format| {
+            texture_cache.get(
+                &render_device,
+                TextureDescriptor {
+                    label: Some(label),
+                    size,
+                    mip_level_count,
+                    sample_count: 1,
+                    dimension: TextureDimension::D2,
+                    format,
+                    usage: TextureUsages::STORAGE_BINDING | TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                },
+            )
+        };
+
+        let preprocessed_depth_texture = create_texture(
+            "ssao_preprocessed_depth_texture",
+            PREPROCESSED_DEPTH_MIP_COUNT,
+            TextureFormat::R16Float,
+        );
+        let ssao_noisy_texture = create_texture("ssao_noisy_texture", 1, TextureFormat::R16Float);
+        let ssao_texture = create_texture("ssao_texture", 1, TextureFormat::R16Float);
+        let depth_differences_texture =
+            create_texture("ssao_depth_differences_texture", 1, TextureFormat::R32Uint);
+
+        commands
+            .entity(entity)
+            .insert(ScreenSpaceAmbientOcclusionTextures {
+                preprocessed_depth_texture,
+                ssao_noisy_texture,
+                screen_space_ambient_occlusion_texture: ssao_texture,
+                depth_differences_texture,
+            });
+    }
+}
+
+#[derive(Component)]
+struct SsaoPipelineId(CachedComputePipelineId);
+
+fn prepare_ssao_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedComputePipelines<SsaoPipelines>>,
+    pipeline: Res<SsaoPipelines>,
+    views: Query<(Entity, &ScreenSpaceAmbientOcclusionSettings)>,
+) {
+    for (entity, ssao_settings) in &views {
+        let pipeline_id =
+            pipelines.specialize(&pipeline_cache, &pipeline, ssao_settings.quality_level);
+
+        commands.entity(entity).insert(SsaoPipelineId(pipeline_id));
+    }
+}
+
+#[derive(Component)]
+struct SsaoBindGroups {
+    common_bind_group: BindGroup,
+    preprocess_depth_bind_group: BindGroup,
+    gtao_bind_group: BindGroup,
+    spatial_denoise_bind_group: BindGroup,
+}
+
+fn queue_ssao_bind_groups(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    pipelines: Res<SsaoPipelines>,
+    view_uniforms: Res<ViewUniforms>,
+    views: Query<(
+        Entity,
+        &ScreenSpaceAmbientOcclusionTextures,
+        &ViewPrepassTextures,
+    )>,
+) {
+    let Some(view_uniforms) = view_uniforms.uniforms.binding() else {
+        return;
+    };
+
+    for (entity, ssao_textures, prepass_textures) in &views {
+        let (Some(prepass_depth), Some(prepass_normal)) =
+            (&prepass_textures.depth, &prepass_textures.normal)
+        else {
+            continue;
+        };
+
+        let common_bind_group = render_device.create_bind_group(&BindGroupDescriptor {
+            label: Some("ssao_common_bind_group"),
+            layout: &pipelines.common_bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::Sampler(&pipelines.point_clamp_sampler),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: view_uniforms.clone(),
+                },
+            ],
+        });
+
+        let preprocessed_depth_mip_views: Vec<TextureView> = (0..PREPROCESSED_DEPTH_MIP_COUNT)
+            .map(|mip| {
+                ssao_textures
+                    .preprocessed_depth_texture
+                    .texture
+                    .create_view(&TextureViewDescriptor {
+                        label: Some("ssao_preprocessed_depth_texture_mip_view"),
+                        base_mip_level: mip,
+                        format: Some(TextureFormat::R16Float),
+                        dimension: Some(TextureViewDimension::D2),
+                        mip_level_count: NonZeroU32::new(1),
+                        ..Default::default()
+                    })
+            })
+            .collect();
+
+        let mut preprocess_depth_entries = vec![BindGroupEntry {
+            binding: 0,
+            resource: BindingResource::TextureView(&prepass_depth.default_view),
+        }];
+        preprocess_depth_entries.extend(preprocessed_depth_mip_views.iter().zip(1..).map(
+            |(view, binding)| BindGroupEntry {
+                binding,
+                resource: BindingResource::TextureView(view),
+            },
+        ));
+        let preprocess_depth_bind_group = render_device.create_bind_group(&BindGroupDescriptor {
+            label: Some("ssao_preprocess_depth_bind_group"),
+            layout: &pipelines.preprocess_depth_bind_group_layout,
+            entries: &preprocess_depth_entries,
+        });
+
+        let gtao_bind_group = render_device.create_bind_group(&BindGroupDescriptor {
+            label: Some("ssao_gtao_bind_group"),
+            layout: &pipelines.gtao_bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(
+                        &ssao_textures.preprocessed_depth_texture.default_view,
+                    ),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::TextureView(&prepass_normal.default_view),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::TextureView(&pipelines.hilbert_index_lut),
+                },
+                BindGroupEntry {
+                    binding: 3,
+                    resource: BindingResource::TextureView(
+                        &ssao_textures.ssao_noisy_texture.default_view,
+                    ),
+                },
+                BindGroupEntry {
+                    binding: 4,
+                    resource: BindingResource::TextureView(
+                        &ssao_textures.depth_differences_texture.default_view,
+                    ),
+                },
+            ],
+        });
+
+        let spatial_denoise_bind_group = render_device.create_bind_group(&BindGroupDescriptor {
+            label: Some("ssao_spatial_denoise_bind_group"),
+            layout: &pipelines.spatial_denoise_bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(
+                        &ssao_textures.ssao_noisy_texture.default_view,
+                    ),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::TextureView(
+                        &ssao_textures.depth_differences_texture.default_view,
+                    ),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::TextureView(
+                        &ssao_textures
+                            .screen_space_ambient_occlusion_texture
+                            .default_view,
+                    ),
+                },
+            ],
+        });
+
+        commands.entity(entity).insert(SsaoBindGroups {
+            common_bind_group,
+            preprocess_depth_bind_group,
+            gtao_bind_group,
+            spatial_denoise_bind_group,
+        });
+    }
+}
+
+fn div_ceil(numerator: u32, denominator: u32) -> u32 {
+    (numerator + denominator - 1) / denominator
+}
+
+const HILBERT_WIDTH: u16 = 64;
+
+/// Generates a lookup table of the position of each pixel of a 64x64 tile along a Hilbert curve.
+///
+/// The GTAO pass uses it to decorrelate the noise of neighboring pixels.
+fn generate_hilbert_index_lut() -> [[u16; HILBERT_WIDTH as usize]; HILBERT_WIDTH as usize] {
+    let mut t = [[0; HILBERT_WIDTH as usize]; HILBERT_WIDTH as usize];
+
+    for (y, row) in t.iter_mut().enumerate() {
+        for (x, index) in row.iter_mut().enumerate() {
+            *index = hilbert_index(x as u16, y as u16);
+        }
+    }
+
+    t
+}
+
+// https://www.shadertoy.com/view/3tB3z3
+fn hilbert_index(mut x: u16, mut y: u16) -> u16 {
+    let mut index = 0;
+
+    let mut level: u16 = HILBERT_WIDTH / 2;
+    while level > 0 {
+        let region_x = (x & level > 0) as u16;
+        let region_y = (y & level > 0) as u16;
+        index += level * level * ((3 * region_x) ^ region_y);
+
+        if region_y == 0 {
+            if region_x == 1 {
+                x = HILBERT_WIDTH - 1 - x;
+                y = HILBERT_WIDTH - 1 - y;
+            }
+
+            std::mem::swap(&mut x, &mut y);
+        }
+
+        level /= 2;
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_render::render_graph::{Edge, EmptyNode};
+
+    #[test]
+    fn hilbert_index_visits_every_pixel_with_adjacent_steps() {
+        let lut = generate_hilbert_index_lut();
+
+        let mut positions = vec![None; HILBERT_WIDTH as usize * HILBERT_WIDTH as usize];
+        for (y, row) in lut.iter().enumerate() {
+            for (x, index) in row.iter().enumerate() {
+                let position = &mut positions[*index as usize];
+                assert!(position.is_none(), "index {index} is used twice");
+                *position = Some((x as i32, y as i32));
+            }
+        }
+
+        let positions: Vec<_> = positions.into_iter().map(Option::unwrap).collect();
+        for pair in positions.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            assert_eq!((x1 - x0).abs() + (y1 - y0).abs(), 1);
+        }
+    }
+
+    #[test]
+    fn ssao_node_runs_between_prepass_and_main_pass() {
+        let mut world = World::new();
+        let mut graph = RenderGraph::default();
+        graph.set_input(vec![SlotInfo::new(
+            core_3d::graph::input::VIEW_ENTITY,
+            SlotType::Entity,
+        )]);
+        graph.add_node(core_3d::graph::node::PREPASS, EmptyNode);
+        graph.add_node(core_3d::graph::node::MAIN_PASS, EmptyNode);
+        graph.add_node_edge(
+            core_3d::graph::node::PREPASS,
+            core_3d::graph::node::MAIN_PASS,
+        );
+
+        add_ssao_node(&mut graph, SsaoNode::new(&mut world));
+
+        let ssao = crate::draw_3d_graph::node::SCREEN_SPACE_AMBIENT_OCCLUSION;
+        let input_node_id = graph.input_node().id;
+
+        let mut node_inputs = graph.iter_node_inputs(ssao).unwrap();
+        let (edge, node) = node_inputs.next().unwrap();
+        assert!(matches!(edge, Edge::SlotEdge { .. }));
+        assert_eq!(node.id, input_node_id);
+        let (edge, node) = node_inputs.next().unwrap();
+        assert!(matches!(edge, Edge::NodeEdge { .. }));
+        assert_eq!(
+            Some(node.id),
+            graph.get_node_id(core_3d::graph::node::PREPASS).ok()
+        );
+        assert!(node_inputs.next().is_none());
+
+        let outputs: Vec<_> = graph
+            .iter_node_outputs(ssao)
+            .unwrap()
+            .map(|(_, node)| node.id)
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![graph.get_node_id(core_3d::graph::node::MAIN_PASS).unwrap()]
+        );
+    }
+}
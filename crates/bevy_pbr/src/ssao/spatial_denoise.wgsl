@@ -0,0 +1,73 @@
+// 3x3 bilaterial filter (edge-preserving blur)
+// https://people.csail.mit.edu/sparis/bf_course/course_notes.pdf
+
+// Note: Does not use the Gaussian kernel part of a typical bilateral blur
+// From the paper: "use the information gathered on a neighborhood of 4 × 4 using a bilateral filter for
+// reconstruction, using _uniform_ convolution weights"
+
+// Note: The paper does a 4x4 (not quite centered) filter, offset by +/- 1 pixel every other frame
+// XeGTAO does a 3x3 filter, on two pixels at a time per compute thread, applied twice
+// We do a 3x3 filter, on 1 pixel per compute thread, applied once
+
+@group(0) @binding(0) var ambient_occlusion_noisy: texture_2d<f32>;
+@group(0) @binding(1) var depth_differences: texture_2d<u32>;
+@group(0) @binding(2) var ambient_occlusion: texture_storage_2d<r16float, write>;
+
+fn clamp_coordinates(pixel_coordinates: vec2<i32>) -> vec2<i32> {
+    let max_coordinates = vec2<i32>(textureDimensions(ambient_occlusion_noisy)) - 1;
+    return clamp(pixel_coordinates, vec2<i32>(0), max_coordinates);
+}
+
+// The similarity of a pixel's depth to its (left, right, top, bottom) neighbors, 1.0 meaning no edge
+fn load_edges(pixel_coordinates: vec2<i32>) -> vec4<f32> {
+    return unpack4x8unorm(textureLoad(depth_differences, clamp_coordinates(pixel_coordinates), 0).r);
+}
+
+fn load_visibility(pixel_coordinates: vec2<i32>) -> f32 {
+    return textureLoad(ambient_occlusion_noisy, clamp_coordinates(pixel_coordinates), 0).r;
+}
+
+@compute
+@workgroup_size(8, 8, 1)
+fn spatial_denoise(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    let pixel_coordinates = vec2<i32>(global_id.xy);
+
+    let left = pixel_coordinates + vec2<i32>(-1, 0);
+    let right = pixel_coordinates + vec2<i32>(1, 0);
+    let top = pixel_coordinates + vec2<i32>(0, -1);
+    let bottom = pixel_coordinates + vec2<i32>(0, 1);
+
+    let left_edges = load_edges(left);
+    let right_edges = load_edges(right);
+    let top_edges = load_edges(top);
+    let bottom_edges = load_edges(bottom);
+    var center_edges = load_edges(pixel_coordinates);
+    // Only blur across an edge if the pixels on both sides of it agree
+    center_edges *= vec4<f32>(left_edges.y, right_edges.x, top_edges.w, bottom_edges.z);
+
+    let center_weight = 1.2;
+    let left_weight = center_edges.x;
+    let right_weight = center_edges.y;
+    let top_weight = center_edges.z;
+    let bottom_weight = center_edges.w;
+    // Diagonal neighbors are reached through either of the two adjacent direct neighbors
+    let top_left_weight = 0.425 * (top_weight * top_edges.x + left_weight * left_edges.z);
+    let top_right_weight = 0.425 * (top_weight * top_edges.y + right_weight * right_edges.z);
+    let bottom_left_weight = 0.425 * (bottom_weight * bottom_edges.x + left_weight * left_edges.w);
+    let bottom_right_weight = 0.425 * (bottom_weight * bottom_edges.y + right_weight * right_edges.w);
+
+    var sum = center_weight * load_visibility(pixel_coordinates);
+    sum += left_weight * load_visibility(left);
+    sum += right_weight * load_visibility(right);
+    sum += top_weight * load_visibility(top);
+    sum += bottom_weight * load_visibility(bottom);
+    sum += top_left_weight * load_visibility(pixel_coordinates + vec2<i32>(-1, -1));
+    sum += top_right_weight * load_visibility(pixel_coordinates + vec2<i32>(1, -1));
+    sum += bottom_left_weight * load_visibility(pixel_coordinates + vec2<i32>(-1, 1));
+    sum += bottom_right_weight * load_visibility(pixel_coordinates + vec2<i32>(1, 1));
+
+    let weight_sum = center_weight + left_weight + right_weight + top_weight + bottom_weight + top_left_weight + top_right_weight + bottom_left_weight + bottom_right_weight;
+    let denoised_visibility = sum / weight_sum;
+
+    textureStore(ambient_occlusion, pixel_coordinates, vec4<f32>(denoised_visibility, 0.0, 0.0, 0.0));
+}
@@ -1,34 +1,32 @@
-use crate::MeshPipeline;
-use crate::{DrawMesh, MeshPipelineKey, MeshUniform, SetMeshBindGroup, SetMeshViewBindGroup};
-use bevy_app::Plugin;
-use bevy_asset::{load_internal_asset, Handle, HandleUntyped};
-use bevy_core_pipeline::core_3d::Opaque3d;
+use crate::{Material, MaterialPipeline, MaterialPipelineKey, MaterialPlugin};
+use bevy_app::{App, Plugin};
+use bevy_asset::{load_internal_asset, Assets, Handle, HandleUntyped};
 use bevy_ecs::{prelude::*, reflect::ReflectComponent};
-use bevy_reflect::std_traits::ReflectDefault;
-use bevy_reflect::{Reflect, TypeUuid};
-use bevy_render::extract_component::{ExtractComponent, ExtractComponentPlugin};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeUuid};
 use bevy_render::{
-    extract_resource::{ExtractResource, ExtractResourcePlugin},
+    color::Color,
+    extract_resource::ExtractResource,
     mesh::{Mesh, MeshVertexBufferLayout},
-    render_asset::RenderAssets,
-    render_phase::{AddRenderCommand, DrawFunctions, RenderPhase, SetItemPipeline},
     render_resource::{
-        PipelineCache, PolygonMode, RenderPipelineDescriptor, Shader, SpecializedMeshPipeline,
-        SpecializedMeshPipelineError, SpecializedMeshPipelines,
+        AsBindGroup, PolygonMode, RenderPipelineDescriptor, Shader, ShaderRef,
+        SpecializedMeshPipelineError,
     },
-    view::{ExtractedView, Msaa, VisibleEntities},
-    RenderApp, RenderSet,
 };
-use bevy_utils::tracing::error;
 
 pub const WIREFRAME_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 192598014480025766);
 
+/// Draws the wireframes of meshes with a [`Wireframe`] component, or of all meshes with
+/// [`WireframeConfig::global`].
+///
+/// Wireframes are drawn with a [`WireframeMaterial`], added alongside the material of each mesh.
+/// This requires the [`POLYGON_MODE_LINE`](bevy_render::render_resource::WgpuFeatures::POLYGON_MODE_LINE)
+/// device feature.
 #[derive(Debug, Default)]
 pub struct WireframePlugin;
 
 impl Plugin for WireframePlugin {
-    fn build(&self, app: &mut bevy_app::App) {
+    fn build(&self, app: &mut App) {
         load_internal_asset!(
             app,
             WIREFRAME_SHADER_HANDLE,
@@ -37,133 +35,230 @@ impl Plugin for WireframePlugin {
         );
 
         app.register_type::<Wireframe>()
+            .register_type::<NoWireframe>()
+            .register_type::<WireframeColor>()
             .register_type::<WireframeConfig>()
             .init_resource::<WireframeConfig>()
-            .add_plugin(ExtractResourcePlugin::<WireframeConfig>::default())
-            .add_plugin(ExtractComponentPlugin::<Wireframe>::default());
-
-        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
-            render_app
-                .add_render_command::<Opaque3d, DrawWireframes>()
-                .init_resource::<WireframePipeline>()
-                .init_resource::<SpecializedMeshPipelines<WireframePipeline>>()
-                .add_system(queue_wireframes.in_set(RenderSet::Queue));
-        }
+            .add_plugin(MaterialPlugin::<WireframeMaterial> {
+                prepass_enabled: false,
+                shadows_enabled: false,
+                ..Default::default()
+            })
+            .add_systems((update_global_wireframe_material, apply_wireframe_material).chain());
+
+        let global_material =
+            app.world
+                .resource_mut::<Assets<WireframeMaterial>>()
+                .add(WireframeMaterial {
+                    color: WireframeConfig::default().default_color,
+                });
+        app.insert_resource(GlobalWireframeMaterial(global_material));
     }
 }
 
-/// Controls whether an entity should rendered in wireframe-mode if the [`WireframePlugin`] is enabled
-#[derive(Component, Debug, Clone, Default, ExtractComponent, Reflect)]
+/// Draws the wireframe of a mesh entity if the [`WireframePlugin`] is enabled, regardless of
+/// [`WireframeConfig::global`] and [`NoWireframe`].
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
 #[reflect(Component, Default)]
 pub struct Wireframe;
 
-#[derive(Resource, Debug, Clone, Default, ExtractResource, Reflect)]
-#[reflect(Resource)]
+/// Excludes a mesh entity from the wireframes drawn with [`WireframeConfig::global`].
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component, Default)]
+pub struct NoWireframe;
+
+/// Overrides the [`WireframeConfig::default_color`] of the wireframe of a mesh entity.
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component, Default)]
+pub struct WireframeColor(pub Color);
+
+#[derive(Resource, Debug, Clone, ExtractResource, Reflect)]
+#[reflect(Resource, Default)]
 pub struct WireframeConfig {
     /// Whether to show wireframes for all meshes. If `false`, only meshes with a [Wireframe] component will be rendered.
     pub global: bool,
+    /// The color of the wireframes of meshes without a [`WireframeColor`].
+    pub default_color: Color,
 }
 
-#[derive(Resource, Clone)]
-pub struct WireframePipeline {
-    mesh_pipeline: MeshPipeline,
-    shader: Handle<Shader>,
-}
-impl FromWorld for WireframePipeline {
-    fn from_world(render_world: &mut World) -> Self {
-        WireframePipeline {
-            mesh_pipeline: render_world.resource::<MeshPipeline>().clone(),
-            shader: WIREFRAME_SHADER_HANDLE.typed(),
+impl Default for WireframeConfig {
+    fn default() -> Self {
+        Self {
+            global: false,
+            default_color: Color::WHITE,
         }
     }
 }
 
-impl SpecializedMeshPipeline for WireframePipeline {
-    type Key = MeshPipelineKey;
+/// The material drawing the wireframe of a mesh.
+///
+/// It is managed by the [`WireframePlugin`] from the [`Wireframe`], [`NoWireframe`] and
+/// [`WireframeColor`] components, and shouldn't be added manually.
+#[derive(AsBindGroup, TypeUuid, Debug, Clone, PartialEq)]
+#[uuid = "9e694f70-9963-4418-8bc1-3474c66b13b8"]
+pub struct WireframeMaterial {
+    #[uniform(0)]
+    pub color: Color,
+}
+
+impl Material for WireframeMaterial {
+    fn fragment_shader() -> ShaderRef {
+        WIREFRAME_SHADER_HANDLE.typed().into()
+    }
 
     fn specialize(
-        &self,
-        key: Self::Key,
-        layout: &MeshVertexBufferLayout,
-    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
-        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
-        descriptor.vertex.shader = self.shader.clone_weak();
-        descriptor.fragment.as_mut().unwrap().shader = self.shader.clone_weak();
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
         descriptor.primitive.polygon_mode = PolygonMode::Line;
         descriptor.depth_stencil.as_mut().unwrap().bias.slope_scale = 1.0;
-        Ok(descriptor)
+        Ok(())
+    }
+}
+
+/// The [`WireframeMaterial`] shared by the wireframes without a [`WireframeColor`].
+#[derive(Resource)]
+struct GlobalWireframeMaterial(Handle<WireframeMaterial>);
+
+/// Keeps the color of the [`GlobalWireframeMaterial`] in sync with the [`WireframeConfig`].
+fn update_global_wireframe_material(
+    config: Res<WireframeConfig>,
+    global_material: Res<GlobalWireframeMaterial>,
+    mut materials: ResMut<Assets<WireframeMaterial>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    if let Some(material) = materials.get_mut(&global_material.0) {
+        material.color = config.default_color;
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn queue_wireframes(
-    opaque_3d_draw_functions: Res<DrawFunctions<Opaque3d>>,
-    render_meshes: Res<RenderAssets<Mesh>>,
-    wireframe_config: Res<WireframeConfig>,
-    wireframe_pipeline: Res<WireframePipeline>,
-    mut pipelines: ResMut<SpecializedMeshPipelines<WireframePipeline>>,
-    pipeline_cache: Res<PipelineCache>,
-    msaa: Res<Msaa>,
-    mut material_meshes: ParamSet<(
-        Query<(Entity, &Handle<Mesh>, &MeshUniform)>,
-        Query<(Entity, &Handle<Mesh>, &MeshUniform), With<Wireframe>>,
-    )>,
-    mut views: Query<(&ExtractedView, &VisibleEntities, &mut RenderPhase<Opaque3d>)>,
+/// Returns `true` if the wireframe of a mesh entity should be drawn.
+fn draws_wireframe(config: &WireframeConfig, wireframe: bool, no_wireframe: bool) -> bool {
+    wireframe || (config.global && !no_wireframe)
+}
+
+/// Adds a [`WireframeMaterial`] to the mesh entities whose wireframe is drawn, and removes it
+/// from the others.
+#[allow(clippy::type_complexity)]
+fn apply_wireframe_material(
+    mut commands: Commands,
+    config: Res<WireframeConfig>,
+    global_material: Res<GlobalWireframeMaterial>,
+    mut materials: ResMut<Assets<WireframeMaterial>>,
+    meshes: Query<
+        (
+            Entity,
+            Option<&Wireframe>,
+            Option<&NoWireframe>,
+            Option<&WireframeColor>,
+            Option<&Handle<WireframeMaterial>>,
+        ),
+        With<Handle<Mesh>>,
+    >,
 ) {
-    let draw_custom = opaque_3d_draw_functions.read().id::<DrawWireframes>();
-    let msaa_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
-    for (view, visible_entities, mut opaque_phase) in &mut views {
-        let rangefinder = view.rangefinder3d();
-
-        let view_key = msaa_key | MeshPipelineKey::from_hdr(view.hdr);
-        let add_render_phase =
-            |(entity, mesh_handle, mesh_uniform): (Entity, &Handle<Mesh>, &MeshUniform)| {
-                if let Some(mesh) = render_meshes.get(mesh_handle) {
-                    let key = view_key
-                        | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology);
-                    let pipeline_id = pipelines.specialize(
-                        &pipeline_cache,
-                        &wireframe_pipeline,
-                        key,
-                        &mesh.layout,
-                    );
-                    let pipeline_id = match pipeline_id {
-                        Ok(id) => id,
-                        Err(err) => {
-                            error!("{}", err);
-                            return;
-                        }
-                    };
-                    opaque_phase.add(Opaque3d {
-                        entity,
-                        pipeline: pipeline_id,
-                        draw_function: draw_custom,
-                        distance: rangefinder.distance(&mesh_uniform.transform),
-                    });
+    for (entity, wireframe, no_wireframe, color, material) in &meshes {
+        if !draws_wireframe(&config, wireframe.is_some(), no_wireframe.is_some()) {
+            if material.is_some() {
+                commands
+                    .entity(entity)
+                    .remove::<Handle<WireframeMaterial>>();
+            }
+            continue;
+        }
+
+        // Colored wireframes own their material, and share it with nothing else
+        let own_material = material.filter(|material| **material != global_material.0);
+        match (color, own_material) {
+            (None, _) if material == Some(&global_material.0) => {}
+            (None, _) => {
+                commands
+                    .entity(entity)
+                    .insert(global_material.0.clone_weak());
+            }
+            (Some(WireframeColor(color)), Some(material)) => {
+                // Only touches the material when its color changes, so it isn't prepared again
+                // every frame
+                if materials.get(material).map(|material| material.color) != Some(*color) {
+                    if let Some(material) = materials.get_mut(material) {
+                        material.color = *color;
+                    }
                 }
-            };
-
-        if wireframe_config.global {
-            let query = material_meshes.p0();
-            visible_entities
-                .entities
-                .iter()
-                .filter_map(|visible_entity| query.get(*visible_entity).ok())
-                .for_each(add_render_phase);
-        } else {
-            let query = material_meshes.p1();
-            visible_entities
-                .entities
-                .iter()
-                .filter_map(|visible_entity| query.get(*visible_entity).ok())
-                .for_each(add_render_phase);
+            }
+            (Some(WireframeColor(color)), None) => {
+                let material = materials.add(WireframeMaterial { color: *color });
+                commands.entity(entity).insert(material);
+            }
         }
     }
 }
 
-type DrawWireframes = (
-    SetItemPipeline,
-    SetMeshViewBindGroup<0>,
-    SetMeshBindGroup<1>,
-    DrawMesh,
-);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::{AddAsset, AssetPlugin};
+
+    #[test]
+    fn wireframe_materials_follow_the_markers() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Shader>()
+            .add_asset::<Mesh>()
+            .add_plugin(WireframePlugin);
+
+        let mesh = Handle::<Mesh>::default();
+        let plain = app.world.spawn(mesh.clone()).id();
+        let wireframe = app.world.spawn((mesh.clone(), Wireframe)).id();
+        let excluded = app.world.spawn((mesh.clone(), NoWireframe)).id();
+        let both = app.world.spawn((mesh.clone(), Wireframe, NoWireframe)).id();
+        let colored = app
+            .world
+            .spawn((mesh, Wireframe, WireframeColor(Color::RED)))
+            .id();
+        let not_a_mesh = app.world.spawn(Wireframe).id();
+
+        let wireframe_color = |app: &App, entity| {
+            let handle = app.world.get::<Handle<WireframeMaterial>>(entity)?;
+            let materials = app.world.resource::<Assets<WireframeMaterial>>();
+            Some(materials.get(handle).unwrap().color)
+        };
+        let wireframe_colors = |app: &App| {
+            [plain, wireframe, excluded, both, colored, not_a_mesh]
+                .map(|entity| wireframe_color(app, entity))
+        };
+
+        app.update();
+        let white = Some(Color::WHITE);
+        let red = Some(Color::RED);
+        assert_eq!(
+            wireframe_colors(&app),
+            [None, white, None, white, red, None]
+        );
+
+        app.world.resource_mut::<WireframeConfig>().global = true;
+        app.update();
+        assert_eq!(
+            wireframe_colors(&app),
+            [white, white, None, white, red, None]
+        );
+
+        // the default color applies to every wireframe without a color of its own
+        app.world.resource_mut::<WireframeConfig>().default_color = Color::BLUE;
+        app.world.get_mut::<WireframeColor>(colored).unwrap().0 = Color::GREEN;
+        app.world.entity_mut(both).remove::<Wireframe>();
+        app.update();
+        let blue = Some(Color::BLUE);
+        assert_eq!(
+            wireframe_colors(&app),
+            [blue, blue, None, None, Some(Color::GREEN), None]
+        );
+
+        app.world.resource_mut::<WireframeConfig>().global = false;
+        app.world.entity_mut(colored).remove::<WireframeColor>();
+        app.update();
+        assert_eq!(wireframe_colors(&app), [None, blue, None, None, blue, None]);
+    }
+}
@@ -261,14 +261,55 @@ impl<'a, T> From<&'a mut T> for PtrMut<'a> {
     }
 }
 
+/// Moves a local variable into an [`OwningPtr`] of the same name, which shadows the variable.
+///
+/// The value isn't dropped at the end of the scope, since the [`OwningPtr`] is responsible
+/// for it: it should be consumed with [`OwningPtr::read`], [`OwningPtr::drop_as`], or by an API
+/// taking ownership of the pointee. Otherwise the value is leaked.
+///
+/// ```
+/// use bevy_ptr::move_as_ptr;
+///
+/// let name = String::from("Ferris");
+/// move_as_ptr!(name);
+/// // SAFETY: `name` points to a `String`.
+/// let name: String = unsafe { name.read() };
+/// assert_eq!(name, "Ferris");
+/// ```
+#[macro_export]
+macro_rules! move_as_ptr {
+    ($value: ident) => {
+        let mut $value = core::mem::ManuallyDrop::new($value);
+        // SAFETY: The value is shadowed by the pointer, so it can't be dropped or observed
+        // by anything else than the pointer.
+        let $value = unsafe { $crate::PtrMut::from(&mut *$value).promote() };
+    };
+}
+
 impl<'a> OwningPtr<'a> {
     /// Consumes a value and creates an [`OwningPtr`] to it while ensuring a double drop does not happen.
     #[inline]
     pub fn make<T, F: FnOnce(OwningPtr<'_>) -> R, R>(val: T, f: F) -> R {
-        let mut temp = ManuallyDrop::new(val);
-        // SAFETY: The value behind the pointer will not get dropped or observed later,
-        // so it's safe to promote it to an owning pointer.
-        f(unsafe { PtrMut::from(&mut *temp).promote() })
+        move_as_ptr!(val);
+        f(val)
+    }
+
+    /// Consumes an array of values and creates an [`OwningPtr`] to each of them, like [`OwningPtr::make`].
+    ///
+    /// This is useful to pass several values of the same type to an API taking an iterator of
+    /// [`OwningPtr`]s. The values whose pointer isn't consumed by `f` are leaked.
+    #[inline]
+    pub fn make_slice<T, const N: usize, F: FnOnce([OwningPtr<'_>; N]) -> R, R>(
+        values: [T; N],
+        f: F,
+    ) -> R {
+        let mut values = ManuallyDrop::new(values);
+        let values = values.as_mut_ptr();
+        f(core::array::from_fn(|index| {
+            // SAFETY: The values will not get dropped or observed later, each pointer
+            // points to a distinct element of the array, and the array outlives the pointers.
+            unsafe { OwningPtr::new(NonNull::new_unchecked(values.add(index)).cast()) }
+        }))
     }
 }
 impl<'a, A: IsAligned> OwningPtr<'a, A> {
@@ -296,6 +337,18 @@ impl<'a, A: IsAligned> OwningPtr<'a, A> {
         self.as_ptr().cast::<T>().debug_ensure_aligned().read()
     }
 
+    /// Consumes the [`OwningPtr`] to obtain ownership of the underlying data of type `T`,
+    /// without requiring the pointer to be aligned for `T`.
+    ///
+    /// Prefer [`OwningPtr::read`] for aligned pointers, which is more efficient.
+    ///
+    /// # Safety
+    /// - `T` must be the erased pointee type for this [`OwningPtr`].
+    #[inline]
+    pub unsafe fn read_unaligned<T>(self) -> T {
+        self.as_ptr().cast::<T>().read_unaligned()
+    }
+
     /// Consumes the [`OwningPtr`] to drop the underlying data of type `T`.
     ///
     /// # Safety
@@ -334,15 +387,6 @@ impl<'a, A: IsAligned> OwningPtr<'a, A> {
         unsafe { PtrMut::new(self.0) }
     }
 }
-impl<'a> OwningPtr<'a, Unaligned> {
-    /// Consumes the [`OwningPtr`] to obtain ownership of the underlying data of type `T`.
-    ///
-    /// # Safety
-    /// - `T` must be the erased pointee type for this [`OwningPtr`].
-    pub unsafe fn read_unaligned<T>(self) -> T {
-        self.as_ptr().cast::<T>().read_unaligned()
-    }
-}
 
 /// Conceptually equivalent to `&'a [T]` but with length information cut out for performance reasons
 pub struct ThinSlicePtr<'a, T> {
@@ -483,3 +527,69 @@ impl<T: Sized> DebugEnsureAligned for *mut T {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    struct DropCounter<'a>(&'a Cell<usize>, u32);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn moved_values_are_dropped_once() {
+        let drops = Cell::new(0);
+        let value = DropCounter(&drops, 1);
+        move_as_ptr!(value);
+        // SAFETY: `value` points to a `DropCounter`
+        let value = unsafe { value.read::<DropCounter>() };
+        assert_eq!(value.1, 1);
+        assert_eq!(drops.get(), 0);
+        drop(value);
+        assert_eq!(drops.get(), 1);
+
+        OwningPtr::make(DropCounter(&drops, 2), |ptr| {
+            // SAFETY: `ptr` points to a `DropCounter`
+            unsafe { ptr.drop_as::<DropCounter>() };
+        });
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn arrays_are_made_into_pointers_to_each_value() {
+        let drops = Cell::new(0);
+        let numbers = OwningPtr::make_slice(
+            [
+                DropCounter(&drops, 1),
+                DropCounter(&drops, 2),
+                DropCounter(&drops, 3),
+            ],
+            // SAFETY: the pointers point to `DropCounter`s
+            |ptrs| ptrs.map(|ptr| unsafe { ptr.read::<DropCounter>() }.1),
+        );
+        assert_eq!(numbers, [1, 2, 3]);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn unaligned_values_are_read() {
+        #[repr(C, packed)]
+        struct Packed(u8, u32);
+
+        let mut packed = Packed(1, 2);
+        // SAFETY: the field is a valid `u32`, and `packed` isn't used while the pointer is alive
+        let ptr = unsafe {
+            OwningPtr::<Unaligned>::new(
+                NonNull::new_unchecked(core::ptr::addr_of_mut!(packed.1)).cast(),
+            )
+        };
+        // SAFETY: `ptr` points to a `u32`
+        assert_eq!(unsafe { ptr.read_unaligned::<u32>() }, 2);
+        assert_eq!(packed.0, 1);
+    }
+}
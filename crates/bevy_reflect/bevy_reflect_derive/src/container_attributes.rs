@@ -8,12 +8,12 @@
 use crate::fq_std::{FQAny, FQOption};
 use crate::utility;
 use proc_macro2::{Ident, Span};
-use quote::quote_spanned;
+use quote::{quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Meta, NestedMeta, Path};
+use syn::{Lit, LitStr, Meta, NestedMeta, Path};
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
@@ -217,6 +217,24 @@ impl ReflectTraits {
         &self.idents
     }
 
+    /// Returns the bounds required by the implementations of the special traits.
+    ///
+    /// Traits registered with a custom function don't need the type to implement them,
+    /// so they don't add any bound.
+    pub fn special_trait_bounds(&self) -> Vec<proc_macro2::TokenStream> {
+        [
+            (&self.debug, quote!(::core::fmt::Debug)),
+            (&self.hash, quote!(::core::hash::Hash)),
+            (&self.partial_eq, quote!(::core::cmp::PartialEq)),
+        ]
+        .into_iter()
+        .filter_map(|(trait_impl, bound)| match trait_impl {
+            TraitImpl::Implemented(_) => Some(bound),
+            TraitImpl::NotImplemented | TraitImpl::Custom(..) => None,
+        })
+        .collect()
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
@@ -324,3 +342,112 @@ fn add_unique_ident(idents: &mut Vec<Ident>, ident: Ident) -> Result<(), syn::Er
     idents.push(ident);
     Ok(())
 }
+
+/// A custom path for a reflected type, given by the `#[type_path = "..."]` and
+/// `#[type_name = "..."]` container attributes.
+///
+/// Both parts are optional: the module path defaults to the module the type is defined in,
+/// and the name defaults to the identifier of the type.
+#[derive(Default)]
+pub(crate) struct TypePathOverride {
+    /// The module path the type is reflected under, such as `my_crate::my_module`.
+    module_path: Option<Path>,
+    /// The name the type is reflected with, _without_ generics.
+    name: Option<Ident>,
+}
+
+impl TypePathOverride {
+    /// Returns true if neither the module path nor the name are overridden.
+    pub fn is_empty(&self) -> bool {
+        self.module_path.is_none() && self.name.is_none()
+    }
+
+    /// The overridden module path, formatted as a string.
+    pub fn module_path(&self) -> Option<String> {
+        self.module_path.as_ref().map(|path| {
+            path.segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::")
+        })
+    }
+
+    /// The overridden name.
+    pub fn name(&self) -> Option<&Ident> {
+        self.name.as_ref()
+    }
+
+    /// Sets the module path from the value of a `#[type_path = "..."]` attribute.
+    ///
+    /// The path must be an absolute module path, without generics or a leading `::`.
+    pub fn set_module_path(&mut self, attribute_name: &str, lit: &Lit) -> Result<(), syn::Error> {
+        let lit = expect_str_lit(attribute_name, lit)?;
+        if self.module_path.is_some() {
+            return Err(syn::Error::new(
+                lit.span(),
+                format_args!("`#[{attribute_name}]` can only be used once"),
+            ));
+        }
+
+        let path = lit.parse_with(Path::parse_mod_style).map_err(|err| {
+            syn::Error::new(
+                lit.span(),
+                format_args!("expected a module path such as `my_crate::my_module`: {err}"),
+            )
+        })?;
+        if path.leading_colon.is_some() {
+            return Err(syn::Error::new(
+                lit.span(),
+                "the module path must not start with `::`",
+            ));
+        }
+        if let Some(segment) = path.segments.iter().find(|segment| {
+            segment.ident == "crate" || segment.ident == "self" || segment.ident == "super"
+        }) {
+            return Err(syn::Error::new(
+                lit.span(),
+                format_args!(
+                    "the module path must be absolute, but it contains `{}`",
+                    segment.ident
+                ),
+            ));
+        }
+
+        self.module_path = Some(path);
+        Ok(())
+    }
+
+    /// Sets the name from the value of a `#[type_name = "..."]` attribute.
+    ///
+    /// The name must be a valid identifier.
+    pub fn set_name(&mut self, attribute_name: &str, lit: &Lit) -> Result<(), syn::Error> {
+        let lit = expect_str_lit(attribute_name, lit)?;
+        if self.name.is_some() {
+            return Err(syn::Error::new(
+                lit.span(),
+                format_args!("`#[{attribute_name}]` can only be used once"),
+            ));
+        }
+
+        let name = lit.parse::<Ident>().map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                "expected a type name without a path or generics, such as `MyType`",
+            )
+        })?;
+
+        self.name = Some(name);
+        Ok(())
+    }
+}
+
+fn expect_str_lit<'a>(attribute_name: &str, lit: &'a Lit) -> Result<&'a LitStr, syn::Error> {
+    match lit {
+        Lit::Str(lit) => Ok(lit),
+        lit => Err(syn::Error::new(
+            lit.span(),
+            format_args!("expected a string literal, as in `#[{attribute_name} = \"...\"]`"),
+        )),
+    }
+}
@@ -1,14 +1,19 @@
-use crate::container_attributes::ReflectTraits;
+use crate::container_attributes::{ReflectTraits, TypePathOverride};
 use crate::field_attributes::{parse_field_attrs, ReflectFieldAttr};
-use crate::fq_std::{FQAny, FQDefault, FQSend, FQSync};
+use crate::fq_std::{FQAny, FQBox, FQClone, FQDefault, FQSend, FQSync};
 use crate::utility::{members_to_serialization_denylist, WhereClauseOptions};
 use bit_set::BitSet;
 use quote::quote;
 
-use crate::{utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME};
+use crate::{
+    utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME,
+    TYPE_PATH_ATTRIBUTE_NAME,
+};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Field, Fields, Generics, Ident, Meta, Path, Token, Variant};
+use syn::{
+    Data, DeriveInput, Field, Fields, GenericParam, Generics, Ident, Meta, Path, Token, Variant,
+};
 
 pub(crate) enum ReflectDerive<'a> {
     Struct(ReflectStruct<'a>),
@@ -38,6 +43,8 @@ pub(crate) struct ReflectMeta<'a> {
     type_name: &'a Ident,
     /// The generics defined on this type.
     generics: &'a Generics,
+    /// The custom path this type is reflected under, if any.
+    type_path: TypePathOverride,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
     /// The documentation for this type, if any
@@ -130,6 +137,7 @@ enum ReflectMode {
 impl<'a> ReflectDerive<'a> {
     pub fn from_input(input: &'a DeriveInput) -> Result<Self, syn::Error> {
         let mut traits = ReflectTraits::default();
+        let mut type_path = TypePathOverride::default();
         // Should indicate whether `#[reflect_value]` was used
         let mut reflect_mode = None;
 
@@ -172,6 +180,12 @@ impl<'a> ReflectDerive<'a> {
 
                     reflect_mode = Some(ReflectMode::Value);
                 }
+                Meta::NameValue(pair) if pair.path.is_ident(TYPE_PATH_ATTRIBUTE_NAME) => {
+                    type_path.set_module_path(TYPE_PATH_ATTRIBUTE_NAME, &pair.lit)?;
+                }
+                Meta::NameValue(pair) if pair.path.is_ident(TYPE_NAME_ATTRIBUTE_NAME) => {
+                    type_path.set_name(TYPE_NAME_ATTRIBUTE_NAME, &pair.lit)?;
+                }
                 #[cfg(feature = "documentation")]
                 Meta::NameValue(pair) if pair.path.is_ident("doc") => {
                     if let syn::Lit::Str(lit) = pair.lit {
@@ -182,7 +196,8 @@ impl<'a> ReflectDerive<'a> {
             }
         }
 
-        let meta = ReflectMeta::new(&input.ident, &input.generics, traits);
+        let meta =
+            ReflectMeta::new(&input.ident, &input.generics, traits).with_type_path(type_path);
 
         #[cfg(feature = "documentation")]
         let meta = meta.with_docs(doc);
@@ -284,12 +299,18 @@ impl<'a> ReflectMeta<'a> {
             traits,
             type_name,
             generics,
+            type_path: TypePathOverride::default(),
             bevy_reflect_path: utility::get_bevy_reflect_path(),
             #[cfg(feature = "documentation")]
             docs: Default::default(),
         }
     }
 
+    /// Sets the custom path this type is reflected under.
+    pub fn with_type_path(self, type_path: TypePathOverride) -> Self {
+        Self { type_path, ..self }
+    }
+
     /// Sets the documentation for this type.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: crate::documentation::Documentation) -> Self {
@@ -311,11 +332,95 @@ impl<'a> ReflectMeta<'a> {
         self.generics
     }
 
+    /// The name this type is reflected with, _without_ generics.
+    ///
+    /// This is the identifier of the type, unless it was overridden with `#[type_name = "..."]`.
+    pub fn reflected_name(&self) -> String {
+        self.type_path.name().unwrap_or(self.type_name).to_string()
+    }
+
+    /// Returns the call to `with_type_name` overriding the type name in the `TypeInfo`
+    /// of this type, if it was given a custom type path.
+    ///
+    /// Type parameters are appended to the custom path using their own type name,
+    /// the same way [`std::any::type_name`] formats them.
+    pub fn with_type_name(&self) -> Option<proc_macro2::TokenStream> {
+        if self.type_path.is_empty() {
+            return None;
+        }
+
+        let module_path = match self.type_path.module_path() {
+            Some(module_path) => quote!(#module_path),
+            None => quote!(::core::module_path!()),
+        };
+        let name = self.reflected_name();
+        let base_path = quote!(::core::concat!(#module_path, "::", #name));
+
+        let params: Vec<_> = self
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    Some(quote! {
+                        ::std::string::ToString::to_string(::core::any::type_name::<#ident>())
+                    })
+                }
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    Some(quote!(::std::string::ToString::to_string(&#ident)))
+                }
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+
+        let type_name = if params.is_empty() {
+            base_path
+        } else {
+            // The type info of generic types is only generated once per monomorphization,
+            // so leaking the formatted path here is fine
+            quote! {
+                #FQBox::leak(::std::format!("{}<{}>", #base_path, [#(#params),*].join(", ")).into_boxed_str())
+            }
+        };
+        Some(quote!(.with_type_name(#type_name)))
+    }
+
+    /// Returns the body of `Reflect::type_name` as a `TokenStream`.
+    pub fn type_name_impl(&self) -> proc_macro2::TokenStream {
+        let bevy_reflect_path = &self.bevy_reflect_path;
+        if self.type_path.is_empty() {
+            quote!(::core::any::type_name::<Self>())
+        } else {
+            quote!(<Self as #bevy_reflect_path::Typed>::type_info().type_name())
+        }
+    }
+
     /// The cached `bevy_reflect` path.
     pub fn bevy_reflect_path(&self) -> &Path {
         &self.bevy_reflect_path
     }
 
+    /// Returns the [`WhereClauseOptions`] used when this type is reflected as a value.
+    ///
+    /// Values are reflected as a whole, so generic values are bounded on `Self` rather than on
+    /// their fields: they need to be cloneable, and to implement the registered special traits.
+    pub fn value_where_clause_options(&self) -> WhereClauseOptions {
+        if self.generics.params.is_empty() {
+            return WhereClauseOptions::default();
+        }
+
+        let special_trait_bounds = self.traits.special_trait_bounds();
+        WhereClauseOptions {
+            active_types: Box::new([syn::parse_quote!(Self)]),
+            active_trait_bounds: quote! {
+                #FQAny + #FQSend + #FQSync + #FQClone #(+ #special_trait_bounds)*
+            },
+            ..Default::default()
+        }
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(
         &self,
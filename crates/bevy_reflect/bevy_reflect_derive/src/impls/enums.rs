@@ -47,6 +47,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
             }
         });
     let debug_fn = reflect_enum.meta().traits().get_debug_impl();
+    let type_name_impl = reflect_enum.meta().type_name_impl();
     let partial_eq_fn = reflect_enum
         .meta()
         .traits()
@@ -59,20 +60,21 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
             }
         });
 
-    let string_name = enum_name.to_string();
+    let string_name = reflect_enum.meta().reflected_name();
+    let with_type_name = reflect_enum.meta().with_type_name();
 
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_enum.meta().doc();
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants).with_docs(#doc)
+            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants).with_docs(#doc) #with_type_name
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants)
+            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants) #with_type_name
         }
     };
 
@@ -188,7 +190,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> TokenStream {
         impl #impl_generics #bevy_reflect_path::Reflect for #enum_name #ty_generics #where_reflect_clause {
             #[inline]
             fn type_name(&self) -> &str {
-                ::core::any::type_name::<Self>()
+                #type_name_impl
             }
 
             #[inline]
@@ -36,6 +36,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
         .traits()
         .get_hash_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().traits().get_debug_impl();
+    let type_name_impl = reflect_struct.meta().type_name_impl();
     let partial_eq_fn = reflect_struct.meta()
         .traits()
         .get_partial_eq_impl(bevy_reflect_path)
@@ -64,20 +65,21 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
         }
     };
 
-    let string_name = struct_name.to_string();
+    let string_name = reflect_struct.meta().reflected_name();
+    let with_type_name = reflect_struct.meta().with_type_name();
 
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_struct.meta().doc();
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields).with_docs(#doc)
+            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields).with_docs(#doc) #with_type_name
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields)
+            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields) #with_type_name
         }
     };
 
@@ -160,7 +162,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
         impl #impl_generics #bevy_reflect_path::Reflect for #struct_name #ty_generics #where_reflect_clause {
             #[inline]
             fn type_name(&self) -> &str {
-                ::core::any::type_name::<Self>()
+                #type_name_impl
             }
 
             #[inline]
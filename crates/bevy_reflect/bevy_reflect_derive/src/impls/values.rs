@@ -1,6 +1,6 @@
 use crate::fq_std::{FQAny, FQBox, FQClone, FQOption, FQResult};
 use crate::impls::impl_typed;
-use crate::utility::WhereClauseOptions;
+use crate::utility::extend_where_clause;
 use crate::ReflectMeta;
 use proc_macro::TokenStream;
 use quote::quote;
@@ -13,6 +13,8 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     let hash_fn = meta.traits().get_hash_impl(bevy_reflect_path);
     let partial_eq_fn = meta.traits().get_partial_eq_impl(bevy_reflect_path);
     let debug_fn = meta.traits().get_debug_impl();
+    let type_name_impl = meta.type_name_impl();
+    let with_type_name = meta.with_type_name();
 
     #[cfg(feature = "documentation")]
     let with_docs = {
@@ -22,19 +24,20 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
     #[cfg(not(feature = "documentation"))]
     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-    let where_clause_options = WhereClauseOptions::default();
+    let where_clause_options = meta.value_where_clause_options();
     let typed_impl = impl_typed(
         type_name,
         meta.generics(),
         &where_clause_options,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs #with_type_name;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
         bevy_reflect_path,
     );
 
     let (impl_generics, ty_generics, where_clause) = meta.generics().split_for_impl();
+    let where_reflect_clause = extend_where_clause(where_clause, &where_clause_options);
     let get_type_registration_impl = meta.get_type_registration(&where_clause_options);
 
     TokenStream::from(quote! {
@@ -42,10 +45,10 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> TokenStream {
 
         #typed_impl
 
-        impl #impl_generics #bevy_reflect_path::Reflect for #type_name #ty_generics #where_clause  {
+        impl #impl_generics #bevy_reflect_path::Reflect for #type_name #ty_generics #where_reflect_clause {
             #[inline]
             fn type_name(&self) -> &str {
-                ::core::any::type_name::<Self>()
+                #type_name_impl
             }
 
             #[inline]
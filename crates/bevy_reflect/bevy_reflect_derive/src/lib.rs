@@ -40,6 +40,8 @@ use type_uuid::TypeUuidDef;
 
 pub(crate) static REFLECT_ATTRIBUTE_NAME: &str = "reflect";
 pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
+pub(crate) static TYPE_PATH_ATTRIBUTE_NAME: &str = "type_path";
+pub(crate) static TYPE_NAME_ATTRIBUTE_NAME: &str = "type_name";
 
 /// The main derive macro used by `bevy_reflect` for deriving its `Reflect` trait.
 ///
@@ -99,6 +101,17 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// If planning to serialize this type using the reflection serializers,
 /// then the `Serialize` and `Deserialize` traits will need to be implemented and registered as well.
 ///
+/// ## `#[type_path = "my_crate::my_module"]` and `#[type_name = "MyType"]`
+///
+/// By default, a reflected type is named after the module it is defined in and its identifier,
+/// as returned by [`std::any::type_name`]. This name is the one that the type is registered and
+/// serialized with, so moving the type to another module would break existing serialized data.
+///
+/// These attributes override the module path and the name of the type respectively,
+/// in both `Reflect::type_name` and its `TypeInfo`. Either one may be used on its own.
+/// Type parameters of generic types are appended to the custom path as they are by [`std::any::type_name`],
+/// so `#[type_path = "my_crate"] struct Foo<T>` is reflected as `my_crate::Foo<u32>` for `Foo<u32>`.
+///
 /// # Field Attributes
 ///
 /// Along with the container attributes, this macro comes with some attributes that may be applied
@@ -123,7 +136,7 @@ pub(crate) static REFLECT_VALUE_ATTRIBUTE_NAME: &str = "reflect_value";
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
 /// [`reflect_trait`]: macro@reflect_trait
-#[proc_macro_derive(Reflect, attributes(reflect, reflect_value))]
+#[proc_macro_derive(Reflect, attributes(reflect, reflect_value, type_path, type_name))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
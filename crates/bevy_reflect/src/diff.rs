@@ -0,0 +1,758 @@
+use crate::{List, Map, Reflect, ReflectMut, ReflectRef, VariantType};
+use thiserror::Error;
+
+/// The difference between two reflected values, as returned by [`Reflect::diff`].
+///
+/// Applying the diff of `a` to `b` onto `a` with [`Diff::apply`] turns it into `b`.
+/// Only the parts of the values that changed are stored, which makes diffs suitable to implement
+/// undo/redo, or to send changes over the network. Diffs can be serialized with a
+/// [`DiffSerializer`] and deserialized with a [`DiffDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Diff, Modified, Reflect};
+/// #[derive(Reflect, Debug, Default, PartialEq)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let mut player = Player { name: "Bevy".to_string(), health: 100 };
+/// let hurt_player = Player { name: "Bevy".to_string(), health: 80 };
+///
+/// let diff = player.diff(&hurt_player);
+/// let Diff::Modified(Modified::Struct(fields)) = &diff else { unreachable!() };
+/// assert_eq!(1, fields.len());
+/// assert_eq!("health", fields[0].0);
+///
+/// diff.apply(&mut player).unwrap();
+/// assert_eq!(hurt_player, player);
+/// ```
+///
+/// [`DiffSerializer`]: crate::serde::DiffSerializer
+/// [`DiffDeserializer`]: crate::serde::DiffDeserializer
+#[derive(Debug)]
+pub enum Diff {
+    /// The values are equal.
+    NoChange,
+    /// The value is replaced by another one.
+    ///
+    /// This is used for value types, enums that changed variant, and values of different types.
+    Replaced(Box<dyn Reflect>),
+    /// Some parts of the value changed.
+    Modified(Modified),
+}
+
+/// The parts of a value that changed, stored in a [`Diff::Modified`].
+#[derive(Debug)]
+pub enum Modified {
+    /// The changed fields of a struct or of a struct variant, by name.
+    Struct(Vec<(String, Diff)>),
+    /// The changed fields of a tuple, a tuple struct or a tuple variant,
+    /// or the changed elements of an array, by index.
+    Tuple(Vec<(usize, Diff)>),
+    /// The changes turning a list into another one, to apply in order.
+    List(Vec<ListChange>),
+    /// The entries added to, removed from, or changed in a map.
+    Map(Vec<MapChange>),
+}
+
+/// A change made to a [`List`], as part of a [`Modified::List`].
+///
+/// Indices refer to the list as it is once the previous changes have been applied.
+#[derive(Debug)]
+pub enum ListChange {
+    /// Inserts an element at the given index.
+    Insert(usize, Box<dyn Reflect>),
+    /// Removes the element at the given index.
+    Remove(usize),
+    /// Changes the element at the given index.
+    Modify(usize, Diff),
+}
+
+/// A change made to a [`Map`], as part of a [`Modified::Map`].
+#[derive(Debug)]
+pub enum MapChange {
+    /// Inserts an entry with the given key and value.
+    Insert(Box<dyn Reflect>, Box<dyn Reflect>),
+    /// Removes the entry with the given key.
+    Remove(Box<dyn Reflect>),
+    /// Changes the value of the entry with the given key.
+    Modify(Box<dyn Reflect>, Diff),
+}
+
+/// An error returned by [`Diff::apply`] when a diff doesn't match the value it is applied to.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DiffApplyError {
+    #[error("expected a {expected}, found a value of type `{found}`")]
+    MismatchedKinds {
+        expected: &'static str,
+        found: String,
+    },
+    #[error("expected a value of type `{expected}`, found a value of type `{found}`")]
+    MismatchedTypes { expected: String, found: String },
+    #[error("no field named `{0}`")]
+    MissingField(String),
+    #[error("no field at index {0}")]
+    MissingIndex(usize),
+    #[error("index {index} is out of bounds for a list of length {len}")]
+    IndexOutOfBounds { index: usize, len: usize },
+    #[error("no entry with key `{0}`")]
+    MissingKey(String),
+}
+
+impl Diff {
+    /// Returns true if the diffed values are equal.
+    pub fn is_no_change(&self) -> bool {
+        matches!(self, Diff::NoChange)
+    }
+
+    /// Applies this diff to `value`.
+    ///
+    /// If `value` is equal to the first value this diff was computed from, it becomes equal
+    /// to the second one. Otherwise, the changes are applied as long as the value has the
+    /// fields, elements and entries they refer to, and an error is returned as soon as it doesn't.
+    pub fn apply(&self, value: &mut dyn Reflect) -> Result<(), DiffApplyError> {
+        match self {
+            Diff::NoChange => Ok(()),
+            Diff::Replaced(new_value) => replace(value, new_value.as_ref()),
+            Diff::Modified(modified) => modified.apply(value),
+        }
+    }
+}
+
+impl Modified {
+    fn apply(&self, value: &mut dyn Reflect) -> Result<(), DiffApplyError> {
+        match (self, value.reflect_mut()) {
+            (Modified::Struct(fields), ReflectMut::Struct(value)) => {
+                for (name, diff) in fields {
+                    let field = value
+                        .field_mut(name)
+                        .ok_or_else(|| DiffApplyError::MissingField(name.clone()))?;
+                    diff.apply(field)?;
+                }
+                return Ok(());
+            }
+            (Modified::Struct(fields), ReflectMut::Enum(value)) => {
+                for (name, diff) in fields {
+                    let field = value
+                        .field_mut(name)
+                        .ok_or_else(|| DiffApplyError::MissingField(name.clone()))?;
+                    diff.apply(field)?;
+                }
+                return Ok(());
+            }
+            (Modified::Tuple(fields), ReflectMut::Tuple(value)) => {
+                return apply_indexed(fields, value, |value, index| value.field_mut(index));
+            }
+            (Modified::Tuple(fields), ReflectMut::TupleStruct(value)) => {
+                return apply_indexed(fields, value, |value, index| value.field_mut(index));
+            }
+            (Modified::Tuple(fields), ReflectMut::Enum(value)) => {
+                return apply_indexed(fields, value, |value, index| value.field_at_mut(index));
+            }
+            (Modified::Tuple(elements), ReflectMut::Array(value)) => {
+                return apply_indexed(elements, value, |value, index| value.get_mut(index));
+            }
+            (Modified::List(changes), ReflectMut::List(value)) => {
+                return apply_list(changes, value);
+            }
+            (Modified::Map(changes), ReflectMut::Map(value)) => return apply_map(changes, value),
+            _ => {}
+        }
+
+        Err(DiffApplyError::MismatchedKinds {
+            expected: self.kind(),
+            found: value.type_name().to_string(),
+        })
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Modified::Struct(_) => "struct or struct variant",
+            Modified::Tuple(_) => "tuple, tuple struct, tuple variant or array",
+            Modified::List(_) => "list",
+            Modified::Map(_) => "map",
+        }
+    }
+}
+
+/// Computes the [`Diff`] turning `a` into `b`, by comparing their structure.
+///
+/// This is the default implementation of [`Reflect::diff`], which is used to diff the fields,
+/// elements and entries of `a` and `b`.
+///
+/// Lists are diffed by finding their longest common subsequence, which takes a time quadratic
+/// in their length. Value types are compared with [`Reflect::reflect_partial_eq`]: those that
+/// don't support it are always replaced.
+pub fn reflect_diff(a: &dyn Reflect, b: &dyn Reflect) -> Diff {
+    if a.type_name() != b.type_name() {
+        return Diff::Replaced(b.clone_value());
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+            if a.field_len() != b.field_len() {
+                return Diff::Replaced(b.clone_value());
+            }
+            let b_fields =
+                (0..b.field_len()).filter_map(|index| b.name_at(index).zip(b.field_at(index)));
+            diff_named(b.as_reflect(), |name| a.field(name), b_fields)
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            if a.field_len() != b.field_len() {
+                return Diff::Replaced(b.clone_value());
+            }
+            diff_indexed(a.iter_fields().zip(b.iter_fields()))
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            if a.field_len() != b.field_len() {
+                return Diff::Replaced(b.clone_value());
+            }
+            diff_indexed(a.iter_fields().zip(b.iter_fields()))
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            if a.len() != b.len() {
+                return Diff::Replaced(b.clone_value());
+            }
+            diff_indexed(a.iter().zip(b.iter()))
+        }
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => {
+            if a.variant_name() != b.variant_name() || a.field_len() != b.field_len() {
+                return Diff::Replaced(b.clone_value());
+            }
+            match b.variant_type() {
+                VariantType::Struct => {
+                    let b_fields = (0..b.field_len())
+                        .filter_map(|index| b.name_at(index).zip(b.field_at(index)));
+                    diff_named(b.as_reflect(), |name| a.field(name), b_fields)
+                }
+                VariantType::Tuple => diff_indexed(
+                    (0..a.field_len()).filter_map(|index| a.field_at(index).zip(b.field_at(index))),
+                ),
+                VariantType::Unit => Diff::NoChange,
+            }
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => diff_list(a, b),
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => diff_map(a, b),
+        (ReflectRef::Value(a), ReflectRef::Value(b)) => match a.reflect_partial_eq(b) {
+            Some(true) => Diff::NoChange,
+            _ => Diff::Replaced(b.clone_value()),
+        },
+        _ => Diff::Replaced(b.clone_value()),
+    }
+}
+
+fn diff_named<'a, 'b>(
+    b: &dyn Reflect,
+    a_field: impl Fn(&str) -> Option<&'a dyn Reflect>,
+    b_fields: impl Iterator<Item = (&'b str, &'b dyn Reflect)>,
+) -> Diff {
+    let mut changes = Vec::new();
+    for (name, b_field) in b_fields {
+        let Some(a_field) = a_field(name) else {
+            return Diff::Replaced(b.clone_value());
+        };
+        let diff = a_field.diff(b_field);
+        if !diff.is_no_change() {
+            changes.push((name.to_string(), diff));
+        }
+    }
+
+    if changes.is_empty() {
+        Diff::NoChange
+    } else {
+        Diff::Modified(Modified::Struct(changes))
+    }
+}
+
+fn diff_indexed<'a, 'b>(fields: impl Iterator<Item = (&'a dyn Reflect, &'b dyn Reflect)>) -> Diff {
+    let changes: Vec<_> = fields
+        .enumerate()
+        .filter_map(|(index, (a, b))| {
+            let diff = a.diff(b);
+            (!diff.is_no_change()).then_some((index, diff))
+        })
+        .collect();
+
+    if changes.is_empty() {
+        Diff::NoChange
+    } else {
+        Diff::Modified(Modified::Tuple(changes))
+    }
+}
+
+fn diff_list(a: &dyn List, b: &dyn List) -> Diff {
+    let a: Vec<_> = a.iter().collect();
+    let b: Vec<_> = b.iter().collect();
+    let index = |i: usize, j: usize| i * (b.len() + 1) + j;
+
+    let mut equal = vec![false; (a.len() + 1) * (b.len() + 1)];
+    // The length of the longest common subsequence of `a[i..]` and `b[j..]`
+    let mut common = vec![0_usize; (a.len() + 1) * (b.len() + 1)];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            equal[index(i, j)] = is_equal(a[i], b[j]);
+            common[index(i, j)] = if equal[index(i, j)] {
+                common[index(i + 1, j + 1)] + 1
+            } else {
+                common[index(i + 1, j)].max(common[index(i, j + 1)])
+            };
+        }
+    }
+
+    let mut changes = Vec::new();
+    let (mut i, mut j, mut position) = (0, 0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() {
+            if equal[index(i, j)] {
+                i += 1;
+                j += 1;
+                position += 1;
+                continue;
+            }
+            // Both elements are out of the common subsequence, so one turns into the other
+            if common[index(i, j)] == common[index(i + 1, j + 1)] {
+                changes.push(ListChange::Modify(position, a[i].diff(b[j])));
+                i += 1;
+                j += 1;
+                position += 1;
+                continue;
+            }
+        }
+
+        if j < b.len() && (i == a.len() || common[index(i, j + 1)] >= common[index(i + 1, j)]) {
+            changes.push(ListChange::Insert(position, b[j].clone_value()));
+            j += 1;
+            position += 1;
+        } else {
+            changes.push(ListChange::Remove(position));
+            i += 1;
+        }
+    }
+
+    if changes.is_empty() {
+        Diff::NoChange
+    } else {
+        Diff::Modified(Modified::List(changes))
+    }
+}
+
+fn diff_map(a: &dyn Map, b: &dyn Map) -> Diff {
+    let mut changes = Vec::new();
+    for (key, a_value) in a.iter() {
+        match b.get(key) {
+            Some(b_value) => {
+                let diff = a_value.diff(b_value);
+                if !diff.is_no_change() {
+                    changes.push(MapChange::Modify(key.clone_value(), diff));
+                }
+            }
+            None => changes.push(MapChange::Remove(key.clone_value())),
+        }
+    }
+    for (key, b_value) in b.iter() {
+        if a.get(key).is_none() {
+            changes.push(MapChange::Insert(key.clone_value(), b_value.clone_value()));
+        }
+    }
+
+    if changes.is_empty() {
+        Diff::NoChange
+    } else {
+        Diff::Modified(Modified::Map(changes))
+    }
+}
+
+/// Compares two values, falling back to diffing them if they don't support
+/// [`Reflect::reflect_partial_eq`].
+fn is_equal(a: &dyn Reflect, b: &dyn Reflect) -> bool {
+    match a.reflect_partial_eq(b) {
+        Some(equal) => equal,
+        None => a.diff(b).is_no_change(),
+    }
+}
+
+fn replace(value: &mut dyn Reflect, new_value: &dyn Reflect) -> Result<(), DiffApplyError> {
+    let (expected, found) = (kind(new_value.reflect_ref()), kind(value.reflect_ref()));
+    if expected != found {
+        return Err(DiffApplyError::MismatchedKinds {
+            expected,
+            found: value.type_name().to_string(),
+        });
+    }
+    // Applying a value to a value of another type panics
+    if let ReflectRef::Value(_) = new_value.reflect_ref() {
+        if value.as_any().type_id() != new_value.as_any().type_id() {
+            return Err(DiffApplyError::MismatchedTypes {
+                expected: new_value.type_name().to_string(),
+                found: value.type_name().to_string(),
+            });
+        }
+    }
+
+    value.apply(new_value);
+    Ok(())
+}
+
+fn apply_indexed<T: ?Sized>(
+    fields: &[(usize, Diff)],
+    value: &mut T,
+    field_mut: impl Fn(&mut T, usize) -> Option<&mut dyn Reflect>,
+) -> Result<(), DiffApplyError> {
+    for (index, diff) in fields {
+        let field = field_mut(value, *index).ok_or(DiffApplyError::MissingIndex(*index))?;
+        diff.apply(field)?;
+    }
+    Ok(())
+}
+
+fn apply_list(changes: &[ListChange], list: &mut dyn List) -> Result<(), DiffApplyError> {
+    for change in changes {
+        let len = list.len();
+        match change {
+            ListChange::Insert(index, element) => {
+                if *index > len {
+                    return Err(DiffApplyError::IndexOutOfBounds { index: *index, len });
+                }
+                list.insert(*index, element.clone_value());
+            }
+            ListChange::Remove(index) => {
+                if *index >= len {
+                    return Err(DiffApplyError::IndexOutOfBounds { index: *index, len });
+                }
+                list.remove(*index);
+            }
+            ListChange::Modify(index, diff) => {
+                let element = list
+                    .get_mut(*index)
+                    .ok_or(DiffApplyError::IndexOutOfBounds { index: *index, len })?;
+                diff.apply(element)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_map(changes: &[MapChange], map: &mut dyn Map) -> Result<(), DiffApplyError> {
+    for change in changes {
+        match change {
+            MapChange::Insert(key, value) => {
+                map.insert_boxed(key.clone_value(), value.clone_value());
+            }
+            MapChange::Remove(key) => {
+                map.remove(key.as_ref())
+                    .ok_or_else(|| DiffApplyError::MissingKey(format!("{key:?}")))?;
+            }
+            MapChange::Modify(key, diff) => {
+                let value = map
+                    .get_mut(key.as_ref())
+                    .ok_or_else(|| DiffApplyError::MissingKey(format!("{key:?}")))?;
+                diff.apply(value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn kind(value: ReflectRef) -> &'static str {
+    match value {
+        ReflectRef::Struct(_) => "struct",
+        ReflectRef::TupleStruct(_) => "tuple struct",
+        ReflectRef::Tuple(_) => "tuple",
+        ReflectRef::List(_) => "list",
+        ReflectRef::Array(_) => "array",
+        ReflectRef::Map(_) => "map",
+        ReflectRef::Enum(_) => "enum",
+        ReflectRef::Value(_) => "value",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{FromReflect, Reflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Debug, Default, Clone, PartialEq)]
+    struct Inner {
+        value: u32,
+        name: String,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, Default, Clone, PartialEq)]
+    struct Outer {
+        inner: Inner,
+        list: Vec<u32>,
+        map: HashMap<u32, String>,
+        tuple: (u8, Inner),
+        state: State,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, Default, Clone, PartialEq)]
+    enum State {
+        #[default]
+        Idle,
+        Moving {
+            speed: f32,
+        },
+        Named(String),
+    }
+
+    fn assert_round_trip<T: Reflect + Clone + PartialEq + std::fmt::Debug>(a: &T, b: &T) {
+        let diff = a.diff(b);
+        let mut value = a.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(b, &value, "applying {diff:?} to {a:?}");
+    }
+
+    /// A small deterministic generator, so that the property tests are reproducible.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, max: u32) -> u32 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 33) % max as u64) as u32
+        }
+
+        fn list(&mut self) -> Vec<u32> {
+            let len = self.next(8);
+            (0..len).map(|_| self.next(4)).collect()
+        }
+
+        fn map(&mut self) -> HashMap<u32, String> {
+            let len = self.next(5);
+            (0..len)
+                .map(|_| (self.next(6), self.next(3).to_string()))
+                .collect()
+        }
+
+        fn outer(&mut self) -> Outer {
+            let state = match self.next(3) {
+                0 => State::Idle,
+                1 => State::Moving {
+                    speed: self.next(2) as f32,
+                },
+                _ => State::Named(self.next(2).to_string()),
+            };
+            Outer {
+                inner: Inner {
+                    value: self.next(3),
+                    name: self.next(2).to_string(),
+                },
+                list: self.list(),
+                map: self.map(),
+                tuple: (self.next(2) as u8, Inner::default()),
+                state,
+            }
+        }
+    }
+
+    #[test]
+    fn should_not_diff_equal_values() {
+        let value = Outer {
+            list: vec![1, 2, 3],
+            ..Default::default()
+        };
+        assert!(value.diff(&value.clone()).is_no_change());
+    }
+
+    #[test]
+    fn should_diff_nested_structs() {
+        let a = Outer::default();
+        let mut b = a.clone();
+        b.inner.value = 5;
+        b.tuple.1.name = "changed".to_string();
+
+        let Diff::Modified(Modified::Struct(fields)) = a.diff(&b) else {
+            panic!("expected a modified struct");
+        };
+        assert_eq!(2, fields.len());
+
+        let (name, Diff::Modified(Modified::Struct(inner))) = &fields[0] else {
+            panic!("expected a modified `inner` field");
+        };
+        assert_eq!("inner", name);
+        assert_eq!(1, inner.len());
+        assert_eq!("value", inner[0].0);
+        let Diff::Replaced(value) = &inner[0].1 else {
+            panic!("expected a replaced value");
+        };
+        assert_eq!(Some(&5), value.downcast_ref::<u32>());
+
+        let (name, Diff::Modified(Modified::Tuple(tuple))) = &fields[1] else {
+            panic!("expected a modified `tuple` field");
+        };
+        assert_eq!("tuple", name);
+        assert_eq!(1, tuple.len());
+        assert_eq!(1, tuple[0].0);
+
+        assert_round_trip(&a, &b);
+    }
+
+    #[test]
+    fn should_diff_list_middle_insertion() {
+        let a = vec![1_u32, 2, 3, 4];
+        let b = vec![1_u32, 2, 7, 8, 3, 4];
+
+        let Diff::Modified(Modified::List(changes)) = a.diff(&b) else {
+            panic!("expected a modified list");
+        };
+        assert_eq!(2, changes.len());
+        let ListChange::Insert(2, value) = &changes[0] else {
+            panic!("expected an insertion at index 2, found {:?}", changes[0]);
+        };
+        assert_eq!(Some(&7), value.downcast_ref::<u32>());
+        assert!(matches!(changes[1], ListChange::Insert(3, _)));
+
+        assert_round_trip(&a, &b);
+        assert_round_trip(&b, &a);
+    }
+
+    #[test]
+    fn should_diff_list_changes() {
+        let a = vec![1_u32, 2, 3];
+        let b = vec![1_u32, 5, 3];
+
+        let Diff::Modified(Modified::List(changes)) = a.diff(&b) else {
+            panic!("expected a modified list");
+        };
+        assert_eq!(1, changes.len());
+        assert!(matches!(
+            changes[0],
+            ListChange::Modify(1, Diff::Replaced(_))
+        ));
+
+        let Diff::Modified(Modified::List(changes)) = b.diff(&vec![5_u32]) else {
+            panic!("expected a modified list");
+        };
+        assert_eq!(2, changes.len());
+        assert!(matches!(changes[0], ListChange::Remove(0)));
+        assert!(matches!(changes[1], ListChange::Remove(1)));
+    }
+
+    #[test]
+    fn should_diff_map_key_removal() {
+        let a: HashMap<u32, String> = [(1, "one".to_string()), (2, "two".to_string())]
+            .into_iter()
+            .collect();
+        let b: HashMap<u32, String> = [(2, "two".to_string())].into_iter().collect();
+
+        let Diff::Modified(Modified::Map(changes)) = a.diff(&b) else {
+            panic!("expected a modified map");
+        };
+        assert_eq!(1, changes.len());
+        let MapChange::Remove(key) = &changes[0] else {
+            panic!("expected a removal, found {:?}", changes[0]);
+        };
+        assert_eq!(Some(&1), key.downcast_ref::<u32>());
+
+        assert_round_trip(&a, &b);
+        assert_round_trip(&b, &a);
+    }
+
+    #[test]
+    fn should_diff_map_changes() {
+        let a: HashMap<u32, String> = [(1, "one".to_string())].into_iter().collect();
+        let b: HashMap<u32, String> = [(1, "uno".to_string()), (3, "tres".to_string())]
+            .into_iter()
+            .collect();
+
+        let Diff::Modified(Modified::Map(changes)) = a.diff(&b) else {
+            panic!("expected a modified map");
+        };
+        assert_eq!(2, changes.len());
+        assert!(matches!(
+            changes[0],
+            MapChange::Modify(_, Diff::Replaced(_))
+        ));
+        assert!(matches!(changes[1], MapChange::Insert(_, _)));
+
+        assert_round_trip(&a, &b);
+    }
+
+    #[test]
+    fn should_replace_changed_enum_variant() {
+        let a = State::Idle;
+        let b = State::Moving { speed: 1.0 };
+        let Diff::Replaced(value) = a.diff(&b) else {
+            panic!("expected a replaced enum");
+        };
+        assert!(value.reflect_partial_eq(&b).unwrap_or_default());
+        assert_round_trip(&a, &b);
+
+        let c = State::Moving { speed: 2.0 };
+        let Diff::Modified(Modified::Struct(fields)) = b.diff(&c) else {
+            panic!("expected a modified struct variant");
+        };
+        assert_eq!("speed", fields[0].0);
+        assert_round_trip(&b, &c);
+    }
+
+    #[test]
+    fn should_round_trip_random_lists() {
+        let mut rng = Lcg(1);
+        for _ in 0..500 {
+            assert_round_trip(&rng.list(), &rng.list());
+        }
+    }
+
+    #[test]
+    fn should_round_trip_random_maps() {
+        let mut rng = Lcg(2);
+        for _ in 0..500 {
+            assert_round_trip(&rng.map(), &rng.map());
+        }
+    }
+
+    #[test]
+    fn should_round_trip_random_structs() {
+        let mut rng = Lcg(3);
+        for _ in 0..500 {
+            assert_round_trip(&rng.outer(), &rng.outer());
+        }
+    }
+
+    #[test]
+    fn should_round_trip_lists_of_structs() {
+        let mut rng = Lcg(4);
+        for _ in 0..100 {
+            let a: Vec<Outer> = (0..rng.next(5)).map(|_| rng.outer()).collect();
+            let b: Vec<Outer> = (0..rng.next(5)).map(|_| rng.outer()).collect();
+            assert_round_trip(&a, &b);
+        }
+    }
+
+    #[test]
+    fn should_fail_to_apply_mismatched_diff() {
+        let diff = Inner::default().diff(&Inner {
+            value: 1,
+            ..Default::default()
+        });
+
+        let mut value = (1_u32,);
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(DiffApplyError::MismatchedKinds { .. })
+        ));
+
+        let diff = vec![1_u32].diff(&vec![1_u32, 2, 3]);
+        let mut value: Vec<u32> = Vec::new();
+        assert_eq!(
+            Err(DiffApplyError::IndexOutOfBounds { index: 1, len: 0 }),
+            diff.apply(&mut value)
+        );
+
+        let diff = 1_u32.diff(&2_u32);
+        let mut value = 1_u64;
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(DiffApplyError::MismatchedTypes { .. })
+        ));
+    }
+}
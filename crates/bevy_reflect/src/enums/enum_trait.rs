@@ -178,6 +178,13 @@ impl EnumInfo {
         Self { docs, ..self }
     }
 
+    /// Overrides the type name of this enum, which defaults to its [type name].
+    ///
+    /// [type name]: std::any::type_name
+    pub fn with_type_name(self, type_name: &'static str) -> Self {
+        Self { type_name, ..self }
+    }
+
     /// A slice containing the names of all variants in order.
     pub fn variant_names(&self) -> &[&'static str] {
         &self.variant_names
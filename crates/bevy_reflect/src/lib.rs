@@ -437,6 +437,7 @@
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod diff;
 mod fields;
 mod from_reflect;
 mod list;
@@ -483,6 +484,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -1635,6 +1637,148 @@ bevy_reflect::tests::should_reflect_debug::Test {
         assert_eq!("Foo".to_string(), format!("{foo:?}"));
     }
 
+    #[test]
+    fn generic_reflect_value() {
+        #[derive(Clone, Debug, Hash, PartialEq, Reflect, FromReflect)]
+        #[reflect_value(Debug, PartialEq, Hash)]
+        enum MyId<T> {
+            Id(T),
+            Invalid,
+        }
+
+        let id = MyId::Id(123_u32);
+        let id: &dyn Reflect = &id;
+
+        assert_eq!(
+            id.reflect_hash(),
+            MyId::Id(123_u32).as_reflect().reflect_hash()
+        );
+        assert!(id.reflect_hash().is_some());
+        assert_eq!(Some(true), id.reflect_partial_eq(id));
+        assert_eq!(Some(false), id.reflect_partial_eq(&MyId::<u32>::Invalid));
+        assert_eq!("Id(123)".to_string(), format!("{id:?}"));
+        assert_eq!(
+            Some(MyId::Id(123_u32)),
+            <MyId<u32> as FromReflect>::from_reflect(id)
+        );
+
+        let registration = <MyId<String> as GetTypeRegistration>::get_type_registration();
+        assert_eq!(registration.type_id(), TypeId::of::<MyId<String>>());
+    }
+
+    #[test]
+    fn generic_reflect_value_with_where_clause() {
+        #[derive(Clone, Reflect)]
+        #[reflect_value(PartialEq(eq_len))]
+        struct Bytes<T>(T)
+        where
+            T: AsRef<[u8]>;
+
+        fn eq_len<T: AsRef<[u8]> + 'static>(a: &Bytes<T>, b: &dyn Reflect) -> bool {
+            b.as_any()
+                .downcast_ref::<Bytes<T>>()
+                .map(|b| a.0.as_ref().len() == b.0.as_ref().len())
+                .unwrap_or_default()
+        }
+
+        // `Bytes` doesn't need to implement `PartialEq` since a custom function is used
+        let bytes = Bytes(vec![1_u8, 2, 3]);
+        let bytes: &dyn Reflect = &bytes;
+
+        assert_eq!(None, bytes.reflect_hash());
+        assert_eq!(
+            Some(true),
+            bytes.reflect_partial_eq(&Bytes(vec![4_u8, 5, 6]))
+        );
+    }
+
+    #[test]
+    fn custom_type_path() {
+        mod a {
+            pub mod b {
+                use crate as bevy_reflect;
+                use crate::Reflect;
+
+                #[derive(Reflect, Default)]
+                #[type_path = "my_crate::stable"]
+                #[type_name = "Renamed"]
+                pub struct Moved {
+                    pub value: u32,
+                }
+
+                #[derive(Reflect)]
+                #[type_path = "my_crate::stable"]
+                pub struct Generic<T, const N: usize>(pub [T; N]);
+
+                #[derive(Reflect, Clone)]
+                #[reflect_value]
+                #[type_name = "RenamedValue"]
+                pub struct Value;
+            }
+        }
+        use a::b::{Generic, Moved, Value};
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Moved>();
+        registry.register::<Generic<u8, 2>>();
+        registry.register::<Value>();
+
+        assert_eq!("my_crate::stable::Renamed", Moved::default().type_name());
+        assert_eq!("my_crate::stable::Renamed", Moved::type_info().type_name());
+        assert_eq!(
+            "my_crate::stable::Renamed",
+            Moved::default().clone_dynamic().name()
+        );
+        let TypeInfo::Struct(info) = Moved::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!("Renamed", info.name());
+
+        let registration = registry.get_with_name("my_crate::stable::Renamed").unwrap();
+        assert_eq!(TypeId::of::<Moved>(), registration.type_id());
+        assert_eq!("Renamed", registration.short_name());
+        assert!(registry
+            .get_with_name(std::any::type_name::<Moved>())
+            .is_none());
+
+        // Type parameters are formatted the same way as `std::any::type_name` does
+        assert_eq!(
+            "my_crate::stable::Generic<u8, 2>",
+            Generic([1_u8, 2]).type_name()
+        );
+        let registration = registry.get_with_short_name("Generic<u8, 2>").unwrap();
+        assert_eq!(TypeId::of::<Generic<u8, 2>>(), registration.type_id());
+
+        // The module path defaults to the module of the type
+        assert_eq!(
+            concat!(module_path!(), "::a::b::RenamedValue"),
+            Value.type_name()
+        );
+    }
+
+    #[test]
+    fn custom_type_path_serialization() {
+        #[derive(Reflect, FromReflect, Debug, PartialEq)]
+        #[type_path = "my_crate::stable"]
+        struct Moved {
+            value: u32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Moved>();
+        registry.register::<u32>();
+
+        let value = Moved { value: 123 };
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+        assert!(output.starts_with(r#"{"my_crate::stable::Moved":"#));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let dynamic = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(Some(value), Moved::from_reflect(dynamic.as_ref()));
+    }
+
     #[cfg(feature = "glam")]
     mod glam {
         use super::*;
@@ -1,7 +1,7 @@
 use crate::{
-    array_debug, enum_debug, list_debug, map_debug, serde::Serializable, struct_debug, tuple_debug,
-    tuple_struct_debug, Array, Enum, List, Map, Struct, Tuple, TupleStruct, TypeInfo, Typed,
-    ValueInfo,
+    array_debug, enum_debug, list_debug, map_debug, reflect_diff, serde::Serializable,
+    struct_debug, tuple_debug, tuple_struct_debug, Array, Diff, Enum, List, Map, Struct, Tuple,
+    TupleStruct, TypeInfo, Typed, ValueInfo,
 };
 use std::{
     any::{self, Any, TypeId},
@@ -190,6 +190,13 @@ pub trait Reflect: Any + Send + Sync {
         None
     }
 
+    /// Returns the [`Diff`] turning this value into `other`.
+    ///
+    /// The default implementation compares the structure of both values, see [`reflect_diff`].
+    fn diff(&self, other: &dyn Reflect) -> Diff {
+        reflect_diff(self.as_reflect(), other)
+    }
+
     /// Debug formatter for the value.
     ///
     /// Any value that is not an implementor of other `Reflect` subtraits
@@ -0,0 +1,616 @@
+use crate::serde::{ReflectSerializer, UntypedReflectDeserializer};
+use crate::{Diff, ListChange, MapChange, Modified, TypeRegistry};
+use serde::de::{DeserializeSeed, EnumAccess, Error as _, SeqAccess, VariantAccess, Visitor};
+use serde::ser::SerializeTupleVariant;
+use serde::{Deserializer, Serialize, Serializer};
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+
+const DIFF_VARIANTS: &[&str] = &["NoChange", "Replaced", "Modified"];
+const MODIFIED_VARIANTS: &[&str] = &["Struct", "Tuple", "List", "Map"];
+const CHANGE_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+
+/// A serializer for [`Diff`]s.
+///
+/// The reflected values stored in the diff are serialized with a [`ReflectSerializer`],
+/// so their types need to be registered in the registry.
+pub struct DiffSerializer<'a> {
+    pub diff: &'a Diff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffSerializer<'a> {
+    pub fn new(diff: &'a Diff, registry: &'a TypeRegistry) -> Self {
+        DiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for DiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.diff {
+            Diff::NoChange => serializer.serialize_unit_variant("Diff", 0, DIFF_VARIANTS[0]),
+            Diff::Replaced(value) => serializer.serialize_newtype_variant(
+                "Diff",
+                1,
+                DIFF_VARIANTS[1],
+                &ReflectSerializer::new(value.as_ref(), self.registry),
+            ),
+            Diff::Modified(modified) => serializer.serialize_newtype_variant(
+                "Diff",
+                2,
+                DIFF_VARIANTS[2],
+                &ModifiedSerializer {
+                    modified,
+                    registry: self.registry,
+                },
+            ),
+        }
+    }
+}
+
+struct ModifiedSerializer<'a> {
+    modified: &'a Modified,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ModifiedSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        match self.modified {
+            Modified::Struct(fields) => serializer.serialize_newtype_variant(
+                "Modified",
+                0,
+                MODIFIED_VARIANTS[0],
+                &SeqSerializer(|| {
+                    fields
+                        .iter()
+                        .map(move |(name, diff)| (name, DiffSerializer::new(diff, registry)))
+                }),
+            ),
+            Modified::Tuple(fields) => serializer.serialize_newtype_variant(
+                "Modified",
+                1,
+                MODIFIED_VARIANTS[1],
+                &SeqSerializer(|| {
+                    fields
+                        .iter()
+                        .map(move |(index, diff)| (index, DiffSerializer::new(diff, registry)))
+                }),
+            ),
+            Modified::List(changes) => serializer.serialize_newtype_variant(
+                "Modified",
+                2,
+                MODIFIED_VARIANTS[2],
+                &SeqSerializer(|| {
+                    changes
+                        .iter()
+                        .map(move |change| ListChangeSerializer { change, registry })
+                }),
+            ),
+            Modified::Map(changes) => serializer.serialize_newtype_variant(
+                "Modified",
+                3,
+                MODIFIED_VARIANTS[3],
+                &SeqSerializer(|| {
+                    changes
+                        .iter()
+                        .map(move |change| MapChangeSerializer { change, registry })
+                }),
+            ),
+        }
+    }
+}
+
+struct ListChangeSerializer<'a> {
+    change: &'a ListChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.change {
+            ListChange::Insert(index, value) => {
+                let mut state =
+                    serializer.serialize_tuple_variant("ListChange", 0, CHANGE_VARIANTS[0], 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            ListChange::Remove(index) => {
+                serializer.serialize_newtype_variant("ListChange", 1, CHANGE_VARIANTS[1], index)
+            }
+            ListChange::Modify(index, diff) => {
+                let mut state =
+                    serializer.serialize_tuple_variant("ListChange", 2, CHANGE_VARIANTS[2], 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&DiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+        }
+    }
+}
+
+struct MapChangeSerializer<'a> {
+    change: &'a MapChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.change {
+            MapChange::Insert(key, value) => {
+                let mut state =
+                    serializer.serialize_tuple_variant("MapChange", 0, CHANGE_VARIANTS[0], 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            MapChange::Remove(key) => serializer.serialize_newtype_variant(
+                "MapChange",
+                1,
+                CHANGE_VARIANTS[1],
+                &ReflectSerializer::new(key.as_ref(), self.registry),
+            ),
+            MapChange::Modify(key, diff) => {
+                let mut state =
+                    serializer.serialize_tuple_variant("MapChange", 2, CHANGE_VARIANTS[2], 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&DiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Serializes the items returned by an iterator as a sequence.
+struct SeqSerializer<F>(F);
+
+impl<F, I> Serialize for SeqSerializer<F>
+where
+    F: Fn() -> I,
+    I: IntoIterator,
+    I::Item: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq((self.0)())
+    }
+}
+
+/// A deserializer for [`Diff`]s serialized with a [`DiffSerializer`].
+///
+/// The reflected values stored in the diff are deserialized with an [`UntypedReflectDeserializer`],
+/// so their types need to be registered in the registry, and are deserialized as dynamic types
+/// where possible.
+#[derive(Clone, Copy)]
+pub struct DiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("Diff", DIFF_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for DiffDeserializer<'a> {
+    type Value = Diff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("enum Diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, variant) = data.variant_seed(VariantIndex(DIFF_VARIANTS))?;
+        match index {
+            0 => variant.unit_variant().map(|_| Diff::NoChange),
+            1 => variant
+                .newtype_variant_seed(UntypedReflectDeserializer::new(self.registry))
+                .map(Diff::Replaced),
+            _ => variant
+                .newtype_variant_seed(ModifiedDeserializer(self.registry))
+                .map(Diff::Modified),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ModifiedDeserializer<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for ModifiedDeserializer<'a> {
+    type Value = Modified;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("Modified", MODIFIED_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ModifiedDeserializer<'a> {
+    type Value = Modified;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("enum Modified")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let diff = DiffDeserializer::new(self.0);
+        let (index, variant) = data.variant_seed(VariantIndex(MODIFIED_VARIANTS))?;
+        match index {
+            0 => variant
+                .newtype_variant_seed(SeqDeserializer(PairDeserializer(PhantomData, diff)))
+                .map(Modified::Struct),
+            1 => variant
+                .newtype_variant_seed(SeqDeserializer(PairDeserializer(PhantomData, diff)))
+                .map(Modified::Tuple),
+            2 => variant
+                .newtype_variant_seed(SeqDeserializer(ListChangeDeserializer(self.0)))
+                .map(Modified::List),
+            _ => variant
+                .newtype_variant_seed(SeqDeserializer(MapChangeDeserializer(self.0)))
+                .map(Modified::Map),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListChangeDeserializer<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for ListChangeDeserializer<'a> {
+    type Value = ListChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("ListChange", CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListChangeDeserializer<'a> {
+    type Value = ListChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("enum ListChange")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, variant) = data.variant_seed(VariantIndex(CHANGE_VARIANTS))?;
+        match index {
+            0 => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(PhantomData, UntypedReflectDeserializer::new(self.0)),
+                )
+                .map(|(index, value)| ListChange::Insert(index, value)),
+            1 => variant.newtype_variant().map(ListChange::Remove),
+            _ => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(PhantomData, DiffDeserializer::new(self.0)),
+                )
+                .map(|(index, diff)| ListChange::Modify(index, diff)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapChangeDeserializer<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for MapChangeDeserializer<'a> {
+    type Value = MapChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("MapChange", CHANGE_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapChangeDeserializer<'a> {
+    type Value = MapChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("enum MapChange")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, variant) = data.variant_seed(VariantIndex(CHANGE_VARIANTS))?;
+        match index {
+            0 => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(
+                        UntypedReflectDeserializer::new(self.0),
+                        UntypedReflectDeserializer::new(self.0),
+                    ),
+                )
+                .map(|(key, value)| MapChange::Insert(key, value)),
+            1 => variant
+                .newtype_variant_seed(UntypedReflectDeserializer::new(self.0))
+                .map(MapChange::Remove),
+            _ => variant
+                .tuple_variant(
+                    2,
+                    PairDeserializer(
+                        UntypedReflectDeserializer::new(self.0),
+                        DiffDeserializer::new(self.0),
+                    ),
+                )
+                .map(|(key, diff)| MapChange::Modify(key, diff)),
+        }
+    }
+}
+
+/// Deserializes the index of an enum variant, given either by name or by index.
+struct VariantIndex(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantIndex {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIndex {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "one of {:?}", self.0)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match usize::try_from(value) {
+            Ok(index) if index < self.0.len() => Ok(index),
+            _ => Err(E::invalid_value(
+                serde::de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.0
+            .iter()
+            .position(|variant| *variant == value)
+            .ok_or_else(|| E::unknown_variant(value, self.0))
+    }
+}
+
+/// Deserializes a sequence, using a copy of the given seed for each element.
+struct SeqDeserializer<T>(T);
+
+impl<'de, T> DeserializeSeed<'de> for SeqDeserializer<T>
+where
+    T: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<T::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T> Visitor<'de> for SeqDeserializer<T>
+where
+    T: DeserializeSeed<'de> + Copy,
+{
+    type Value = Vec<T::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a tuple of two elements, using the given seeds.
+#[derive(Clone, Copy)]
+struct PairDeserializer<A, B>(A, B);
+
+impl<'de, A, B> DeserializeSeed<'de> for PairDeserializer<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A, B> Visitor<'de> for PairDeserializer<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a tuple of two elements")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| S::Error::invalid_length(0, &"a tuple of two elements"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| S::Error::invalid_length(1, &"a tuple of two elements"))?;
+        Ok((first, second))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{FromReflect, Reflect};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Debug, Default, Clone, PartialEq)]
+    struct Inventory {
+        owner: Owner,
+        items: Vec<Item>,
+        counts: HashMap<String, u32>,
+        slots: (u8, Option<Item>),
+    }
+
+    #[derive(Reflect, FromReflect, Debug, Default, Clone, PartialEq)]
+    struct Owner {
+        name: String,
+        level: u32,
+    }
+
+    #[derive(Reflect, FromReflect, Debug, Default, Clone, PartialEq)]
+    enum Item {
+        #[default]
+        Empty,
+        Sword {
+            damage: u32,
+        },
+        Potion(String),
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inventory>();
+        registry.register::<Owner>();
+        registry.register::<Item>();
+        registry.register::<Vec<Item>>();
+        registry.register::<Option<Item>>();
+        registry.register::<HashMap<String, u32>>();
+        registry.register::<(u8, Option<Item>)>();
+        registry.register::<String>();
+        registry.register::<u32>();
+        registry.register::<u8>();
+        registry
+    }
+
+    #[test]
+    fn should_round_trip_diff() {
+        let registry = get_registry();
+
+        let mut a = Inventory {
+            owner: Owner {
+                name: "Bevy".to_string(),
+                level: 1,
+            },
+            items: vec![Item::Sword { damage: 5 }, Item::Empty],
+            counts: [("arrows".to_string(), 20), ("coins".to_string(), 3)]
+                .into_iter()
+                .collect(),
+            slots: (1, None),
+        };
+        let b = Inventory {
+            owner: Owner {
+                name: "Bevy".to_string(),
+                level: 2,
+            },
+            items: vec![
+                Item::Sword { damage: 7 },
+                Item::Potion("health".to_string()),
+                Item::Empty,
+            ],
+            counts: [("arrows".to_string(), 19), ("gems".to_string(), 1)]
+                .into_iter()
+                .collect(),
+            slots: (1, Some(Item::Empty)),
+        };
+
+        let diff = a.diff(&b);
+        let serializer = DiffSerializer::new(&diff, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        deserialized.apply(&mut a).unwrap();
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    fn should_serialize_no_change() {
+        let registry = get_registry();
+        let diff = Diff::NoChange;
+        let serialized = ron::ser::to_string(&DiffSerializer::new(&diff, &registry)).unwrap();
+        assert_eq!("NoChange", serialized);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = DiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert!(deserialized.is_no_change());
+    }
+}
@@ -205,20 +205,26 @@ impl<'a> Serialize for StructSerializer<'a> {
             .registry
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
-        let mut state = serializer.serialize_struct(
-            struct_info.name(),
-            self.struct_value.field_len() - ignored_len,
-        )?;
-
+        // A dynamic struct may only have some of the fields of its type, in any order
+        let mut fields = Vec::with_capacity(self.struct_value.field_len());
         for (index, value) in self.struct_value.iter_fields().enumerate() {
+            let name = self.struct_value.name_at(index).unwrap_or_default();
+            let field_index = struct_info.index_of(name).ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no field named `{name}` in struct `{}`",
+                    struct_info.name()
+                ))
+            })?;
             if serialization_data
-                .map(|data| data.is_ignored_field(index))
+                .map(|data| data.is_ignored_field(field_index))
                 .unwrap_or(false)
             {
                 continue;
             }
-            let key = struct_info.field_at(index).unwrap().name();
+            fields.push((struct_info.field_at(field_index).unwrap().name(), value));
+        }
+        let mut state = serializer.serialize_struct(struct_info.name(), fields.len())?;
+        for (key, value) in fields {
             state.serialize_field(key, &TypedReflectSerializer::new(value, self.registry))?;
         }
         state.end()
@@ -463,8 +469,8 @@ impl<'a> Serialize for ArraySerializer<'a> {
 #[cfg(test)]
 mod tests {
     use crate as bevy_reflect;
-    use crate::serde::ReflectSerializer;
-    use crate::{FromReflect, Reflect, ReflectSerialize, TypeRegistry};
+    use crate::serde::{ReflectSerializer, TypedReflectSerializer};
+    use crate::{DynamicStruct, FromReflect, Reflect, ReflectSerialize, TypeRegistry};
     use bevy_utils::HashMap;
     use ron::extensions::Extensions;
     use ron::ser::PrettyConfig;
@@ -879,4 +885,30 @@ mod tests {
 
         assert_eq!(expected, bytes);
     }
+
+    #[test]
+    fn should_serialize_dynamic_struct_with_some_fields() {
+        #[derive(Reflect)]
+        struct Partial {
+            first: u32,
+            second: u32,
+            third: u32,
+        }
+
+        let mut registry = get_registry();
+        registry.register::<Partial>();
+
+        let mut value = DynamicStruct::default();
+        value.set_name(std::any::type_name::<Partial>().to_string());
+        value.insert("third", 3u32);
+        value.insert("first", 1u32);
+
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("(third:3,first:1)", output);
+
+        value.insert("fourth", 4u32);
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        assert!(ron::ser::to_string(&serializer).is_err());
+    }
 }
@@ -120,6 +120,13 @@ impl StructInfo {
         Self { docs, ..self }
     }
 
+    /// Overrides the type name of this struct, which defaults to its [type name].
+    ///
+    /// [type name]: std::any::type_name
+    pub fn with_type_name(self, type_name: &'static str) -> Self {
+        Self { type_name, ..self }
+    }
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
@@ -496,7 +503,7 @@ impl Typed for DynamicStruct {
 /// Returns [`None`] if the comparison couldn't even be performed.
 #[inline]
 pub fn struct_partial_eq<S: Struct>(a: &S, b: &dyn Reflect) -> Option<bool> {
-    let ReflectRef::Struct(struct_value) = b.reflect_ref()  else {
+    let ReflectRef::Struct(struct_value) = b.reflect_ref() else {
         return Some(false);
     };
 
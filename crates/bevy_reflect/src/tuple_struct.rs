@@ -87,6 +87,13 @@ impl TupleStructInfo {
         Self { docs, ..self }
     }
 
+    /// Overrides the type name of this tuple struct, which defaults to its [type name].
+    ///
+    /// [type name]: std::any::type_name
+    pub fn with_type_name(self, type_name: &'static str) -> Self {
+        Self { type_name, ..self }
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
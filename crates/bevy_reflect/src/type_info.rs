@@ -198,6 +198,13 @@ impl ValueInfo {
         Self { docs: doc, ..self }
     }
 
+    /// Overrides the type name of this value, which defaults to its [type name].
+    ///
+    /// [type name]: std::any::type_name
+    pub fn with_type_name(self, type_name: &'static str) -> Self {
+        Self { type_name, ..self }
+    }
+
     /// The [type name] of the value.
     ///
     /// [type name]: std::any::type_name
@@ -360,11 +360,11 @@ impl TypeRegistration {
 
     /// Creates type registration information for `T`.
     pub fn of<T: Reflect + Typed>() -> Self {
-        let type_name = std::any::type_name::<T>();
+        let type_info = T::type_info();
         Self {
             data: HashMap::default(),
-            short_name: bevy_utils::get_short_name(type_name),
-            type_info: T::type_info(),
+            short_name: bevy_utils::get_short_name(type_info.type_name()),
+            type_info,
         }
     }
 
@@ -0,0 +1,26 @@
+use bevy_reflect::{FromReflect, Reflect};
+use std::hash::Hash;
+
+#[derive(Clone, Debug, Hash, PartialEq, Reflect, FromReflect)]
+#[reflect_value(Debug, PartialEq, Hash)]
+enum MyId<T> {
+    Id(T),
+    Invalid,
+}
+
+// Check that existing where clauses are kept
+#[derive(Clone, PartialEq, Reflect)]
+#[reflect_value(PartialEq)]
+struct Wrapper<T>(T)
+where
+    T: Hash;
+
+fn main() {
+    let id: Box<dyn Reflect> = Box::new(MyId::Id(1_u32));
+    assert!(id.reflect_hash().is_some());
+    assert_eq!(Some(true), id.reflect_partial_eq(&MyId::Id(1_u32)));
+    let _ = MyId::<u32>::from_reflect(&MyId::<u32>::Invalid);
+
+    let wrapper: Box<dyn Reflect> = Box::new(Wrapper(String::new()));
+    assert!(wrapper.reflect_hash().is_none());
+}
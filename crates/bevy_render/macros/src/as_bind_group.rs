@@ -1,11 +1,11 @@
 use bevy_macro_utils::{get_lit_bool, get_lit_str, BevyManifest, Symbol};
 use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span};
-use quote::{quote, ToTokens};
+use proc_macro2::{Ident, Literal, Span};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    Data, DataStruct, Error, Fields, LitInt, LitStr, NestedMeta, Result, Token,
+    Data, DataStruct, Error, Fields, Lit, LitInt, LitStr, NestedMeta, Result, Token,
 };
 
 const UNIFORM_ATTRIBUTE_NAME: Symbol = Symbol("uniform");
@@ -44,6 +44,8 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
     let mut binding_impls = Vec::new();
     let mut bind_group_entries = Vec::new();
     let mut binding_layouts = Vec::new();
+    let mut binding_array_views = Vec::new();
+    let mut storage_checks = Vec::new();
     let mut attr_prepared_data_ident = None;
 
     // Read struct-level attributes
@@ -208,6 +210,13 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                     let field_name = field.ident.as_ref().unwrap();
                     let field_ty = &field.ty;
 
+                    let message = format!("The `{}` field of `{}` is bound as a storage buffer, which this device does not support (like on WebGL2). Use a `#[uniform]` binding instead.", field_name, ast.ident);
+                    storage_checks.push(quote! {
+                        if render_device.limits().max_storage_buffers_per_shader_stage == 0 {
+                            panic!("{}", #message);
+                        }
+                    });
+
                     let min_binding_size = if buffer {
                         quote! {None}
                     } else {
@@ -254,34 +263,87 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                         sample_type,
                         multisampled,
                         visibility,
+                        count,
                     } = get_texture_attrs(nested_meta_items)?;
 
                     let visibility =
                         visibility.hygenic_quote(&quote! { #render_path::render_resource });
 
-                    binding_impls.push(quote! {
-                        #render_path::render_resource::OwnedBindingResource::TextureView({
-                            let handle: Option<&#asset_path::Handle<#render_path::texture::Image>> = (&self.#field_name).into();
-                            if let Some(handle) = handle {
-                                images.get(handle).ok_or_else(|| #render_path::render_resource::AsBindGroupError::RetryNextUpdate)?.texture_view.clone()
-                            } else {
-                                fallback_image.texture_view.clone()
+                    if let Some(count) = count {
+                        let count = Literal::u32_unsuffixed(count);
+                        binding_impls.push(quote! {
+                            #render_path::render_resource::OwnedBindingResource::TextureViewArray({
+                                let mut views = Vec::with_capacity(#count);
+                                for handle in self.#field_name.iter().take(#count) {
+                                    let handle: Option<&#asset_path::Handle<#render_path::texture::Image>> = handle.into();
+                                    views.push(if let Some(handle) = handle {
+                                        images.get(handle).ok_or_else(|| #render_path::render_resource::AsBindGroupError::RetryNextUpdate)?.texture_view.clone()
+                                    } else {
+                                        fallback_image.texture_view.clone()
+                                    });
+                                }
+                                // The layout always has `count` textures, whatever the number of handles.
+                                views.resize(#count, fallback_image.texture_view.clone());
+                                views
+                            })
+                        });
+
+                        binding_layouts.push(quote! {
+                            #render_path::render_resource::BindGroupLayoutEntry {
+                                binding: #binding_index,
+                                visibility: #visibility,
+                                ty: #render_path::render_resource::BindingType::Texture {
+                                    multisampled: #multisampled,
+                                    sample_type: #render_path::render_resource::#sample_type,
+                                    view_dimension: #render_path::render_resource::#dimension,
+                                },
+                                count: ::core::num::NonZeroU32::new(#count),
                             }
-                        })
-                    });
+                        });
 
-                    binding_layouts.push(quote! {
-                        #render_path::render_resource::BindGroupLayoutEntry {
-                            binding: #binding_index,
-                            visibility: #visibility,
-                            ty: #render_path::render_resource::BindingType::Texture {
-                                multisampled: #multisampled,
-                                sample_type: #render_path::render_resource::#sample_type,
-                                view_dimension: #render_path::render_resource::#dimension,
-                            },
-                            count: None,
-                        }
-                    });
+                        // The entry of this binding was pushed last, and needs the views as a slice
+                        // that outlives the descriptor.
+                        let binding_vec_index = bind_group_entries.len() - 1;
+                        let views = format_ident!("texture_views_{}", binding_index);
+                        binding_array_views.push(quote! {
+                            let #views: Vec<_> = match &bindings[#binding_vec_index] {
+                                #render_path::render_resource::OwnedBindingResource::TextureViewArray(views) => {
+                                    views.iter().map(|view| &**view).collect()
+                                }
+                                _ => unreachable!(),
+                            };
+                        });
+                        *bind_group_entries.last_mut().unwrap() = quote! {
+                            #render_path::render_resource::BindGroupEntry {
+                                binding: #binding_index,
+                                resource: #render_path::render_resource::BindingResource::TextureViewArray(&#views),
+                            }
+                        };
+                    } else {
+                        binding_impls.push(quote! {
+                            #render_path::render_resource::OwnedBindingResource::TextureView({
+                                let handle: Option<&#asset_path::Handle<#render_path::texture::Image>> = (&self.#field_name).into();
+                                if let Some(handle) = handle {
+                                    images.get(handle).ok_or_else(|| #render_path::render_resource::AsBindGroupError::RetryNextUpdate)?.texture_view.clone()
+                                } else {
+                                    fallback_image.texture_view.clone()
+                                }
+                            })
+                        });
+
+                        binding_layouts.push(quote! {
+                            #render_path::render_resource::BindGroupLayoutEntry {
+                                binding: #binding_index,
+                                visibility: #visibility,
+                                ty: #render_path::render_resource::BindingType::Texture {
+                                    multisampled: #multisampled,
+                                    sample_type: #render_path::render_resource::#sample_type,
+                                    view_dimension: #render_path::render_resource::#dimension,
+                                },
+                                count: None,
+                            }
+                        });
+                    }
                 }
                 BindingType::Sampler => {
                     let SamplerAttrs {
@@ -429,6 +491,7 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                 fallback_image: &#render_path::texture::FallbackImage,
             ) -> Result<#render_path::render_resource::PreparedBindGroup<Self::Data>, #render_path::render_resource::AsBindGroupError> {
                 let bindings = vec![#(#binding_impls,)*];
+                #(#binding_array_views)*
 
                 let bind_group = {
                     let descriptor = #render_path::render_resource::BindGroupDescriptor {
@@ -447,6 +510,7 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
             }
 
             fn bind_group_layout(render_device: &#render_path::renderer::RenderDevice) -> #render_path::render_resource::BindGroupLayout {
+                #(#storage_checks)*
                 render_device.create_bind_group_layout(&#render_path::render_resource::BindGroupLayoutDescriptor {
                     entries: &[#(#binding_layouts,)*],
                     label: None,
@@ -689,6 +753,7 @@ struct TextureAttrs {
     sample_type: BindingTextureSampleType,
     multisampled: bool,
     visibility: ShaderStageVisibility,
+    count: Option<u32>,
 }
 
 impl Default for BindingTextureSampleType {
@@ -704,6 +769,7 @@ impl Default for TextureAttrs {
             sample_type: Default::default(),
             multisampled: true,
             visibility: Default::default(),
+            count: None,
         }
     }
 }
@@ -712,6 +778,7 @@ const DIMENSION: Symbol = Symbol("dimension");
 const SAMPLE_TYPE: Symbol = Symbol("sample_type");
 const FILTERABLE: Symbol = Symbol("filterable");
 const MULTISAMPLED: Symbol = Symbol("multisampled");
+const COUNT: Symbol = Symbol("count");
 
 // Values for `dimension` attribute.
 const DIM_1D: &str = "1d";
@@ -733,6 +800,7 @@ fn get_texture_attrs(metas: Vec<NestedMeta>) -> Result<TextureAttrs> {
     let mut multisampled = Default::default();
     let mut filterable = None;
     let mut filterable_ident = None;
+    let mut count = None;
 
     let mut visibility = ShaderStageVisibility::vertex_fragment();
 
@@ -761,6 +829,18 @@ fn get_texture_attrs(metas: Vec<NestedMeta>) -> Result<TextureAttrs> {
                 filterable = get_lit_bool(FILTERABLE, &m.lit)?.into();
                 filterable_ident = m.path.into();
             }
+            // Parse #[texture(0, count = ...)].
+            Meta(NameValue(m)) if m.path == COUNT => match m.lit {
+                Lit::Int(lit_int) if lit_int.base10_parse::<u32>()? > 0 => {
+                    count = Some(lit_int.base10_parse()?);
+                }
+                lit => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "The texture count must be an integer greater than 0.",
+                    ))
+                }
+            },
             // Parse #[texture(0, visibility(...))].
             Meta(List(m)) if m.path == VISIBILITY => {
                 visibility = get_visibility_flag_value(&m.nested)?;
@@ -768,7 +848,7 @@ fn get_texture_attrs(metas: Vec<NestedMeta>) -> Result<TextureAttrs> {
             Meta(NameValue(m)) => {
                 return Err(Error::new_spanned(
                     m.path,
-                    "Not a valid name. Available attributes: `dimension`, `sample_type`, `multisampled`, or `filterable`."
+                    "Not a valid name. Available attributes: `dimension`, `sample_type`, `multisampled`, `filterable`, or `count`."
                 ));
             }
             _ => {
@@ -802,6 +882,7 @@ fn get_texture_attrs(metas: Vec<NestedMeta>) -> Result<TextureAttrs> {
         sample_type,
         multisampled,
         visibility,
+        count,
     })
 }
 
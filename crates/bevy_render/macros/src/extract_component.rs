@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, Path};
+use syn::{parse_macro_input, parse_quote, DeriveInput, Error, Lit, Meta, NestedMeta, Path};
 
 pub fn derive_extract_component(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
@@ -17,24 +17,21 @@ pub fn derive_extract_component(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let filter = if let Some(attr) = ast
-        .attrs
-        .iter()
-        .find(|a| a.path.is_ident("extract_component_filter"))
-    {
-        let filter = match attr.parse_args::<syn::Type>() {
-            Ok(filter) => filter,
-            Err(e) => return e.to_compile_error().into(),
-        };
-
-        quote! {
-            #filter
+    let mut filter = quote! { () };
+    for attr in &ast.attrs {
+        if attr.path.is_ident("extract_component_filter") {
+            filter = match attr.parse_args::<syn::Type>() {
+                Ok(filter) => quote! { #filter },
+                Err(e) => return e.to_compile_error().into(),
+            };
+        } else if attr.path.is_ident("extract_component") {
+            // Parse `#[extract_component(filter = "...")]`.
+            filter = match get_filter(attr) {
+                Ok(filter) => quote! { #filter },
+                Err(e) => return e.to_compile_error().into(),
+            };
         }
-    } else {
-        quote! {
-            ()
-        }
-    };
+    }
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_render_path::extract_component::ExtractComponent for #struct_name #type_generics #where_clause {
@@ -49,3 +46,35 @@ pub fn derive_extract_component(input: TokenStream) -> TokenStream {
         }
     })
 }
+
+fn get_filter(attr: &syn::Attribute) -> syn::Result<syn::Type> {
+    let Meta::List(list) = attr.parse_meta()? else {
+        return Err(Error::new_spanned(
+            attr,
+            "Expected `#[extract_component(filter = \"...\")]`",
+        ));
+    };
+    let mut filter = None;
+    for meta in list.nested {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(m)) if m.path.is_ident("filter") => match m.lit {
+                Lit::Str(lit_str) => filter = Some(lit_str.parse()?),
+                lit => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "The filter must be a string, like `filter = \"With<Camera>\"`",
+                    ))
+                }
+            },
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "Not a valid attribute. Available attributes: `filter`",
+                ))
+            }
+        }
+    }
+    filter.ok_or_else(|| {
+        Error::new_spanned(attr, "Expected `#[extract_component(filter = \"...\")]`")
+    })
+}
@@ -1,9 +1,11 @@
 mod as_bind_group;
 mod extract_component;
 mod extract_resource;
+mod phase_item;
 
-use bevy_macro_utils::BevyManifest;
+use bevy_macro_utils::{derive_boxed_label, BevyManifest};
 use proc_macro::TokenStream;
+use quote::format_ident;
 use syn::{parse_macro_input, DeriveInput};
 
 pub(crate) fn bevy_render_path() -> syn::Path {
@@ -24,7 +26,8 @@ pub fn derive_extract_resource(input: TokenStream) -> TokenStream {
 /// Note that this only enables extraction of the component, it does not execute the extraction.
 /// See `ExtractComponentPlugin` to actually perform the extraction.
 ///
-/// If you only want to extract a component conditionally, you may use the `extract_component_filter` attribute.
+/// If you only want to extract a component conditionally, you may use the `extract_component_filter` attribute,
+/// or equivalently `#[extract_component(filter = "...")]`.
 ///
 /// # Example
 ///
@@ -38,13 +41,22 @@ pub fn derive_extract_resource(input: TokenStream) -> TokenStream {
 ///     pub should_foo: bool,
 /// }
 ///
+/// #[derive(Component, Clone, ExtractComponent)]
+/// #[extract_component(filter = "(With<Camera>, Without<Foo>)")]
+/// pub struct Baz {
+///     pub should_baz: bool,
+/// }
+///
 /// // Without a filter (unconditional).
 /// #[derive(Component, Clone, ExtractComponent)]
 /// pub struct Bar {
 ///     pub should_bar: bool,
 /// }
 /// ```
-#[proc_macro_derive(ExtractComponent, attributes(extract_component_filter))]
+#[proc_macro_derive(
+    ExtractComponent,
+    attributes(extract_component, extract_component_filter)
+)]
 pub fn derive_extract_component(input: TokenStream) -> TokenStream {
     extract_component::derive_extract_component(input)
 }
@@ -58,3 +70,70 @@ pub fn derive_as_bind_group(input: TokenStream) -> TokenStream {
 
     as_bind_group::derive_as_bind_group(input).unwrap_or_else(|err| err.to_compile_error().into())
 }
+
+/// Implements `PhaseItem` for a struct, from the fields marked with the following attributes:
+///
+/// * `#[entity]`: the `Entity` to draw.
+/// * `#[sort_key]`: the value the phase is sorted by. Its type becomes the `SortKey` and must
+///   implement `Ord` and `Clone`.
+/// * `#[draw_function]`: the `DrawFunctionId` used to draw the item.
+/// * `#[pipeline]` (optional): the `CachedRenderPipelineId` of the item. `CachedRenderPipelinePhaseItem`
+///   is also implemented when present, so `SetItemPipeline` can be used in the draw function.
+///
+/// # Example
+///
+/// ```no_compile
+/// use bevy_ecs::entity::Entity;
+/// use bevy_render::{render_phase::{DrawFunctionId, PhaseItem}, render_resource::CachedRenderPipelineId};
+/// use bevy_utils::FloatOrd;
+///
+/// #[derive(PhaseItem)]
+/// pub struct Outline3d {
+///     #[sort_key]
+///     pub distance: FloatOrd,
+///     #[entity]
+///     pub entity: Entity,
+///     #[pipeline]
+///     pub pipeline: CachedRenderPipelineId,
+///     #[draw_function]
+///     pub draw_function: DrawFunctionId,
+/// }
+/// ```
+#[proc_macro_derive(PhaseItem, attributes(entity, sort_key, draw_function, pipeline))]
+pub fn derive_phase_item(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    phase_item::derive_phase_item(input).unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// Derive macro generating an impl of the trait `RenderLabel`.
+///
+/// This does not work for unions.
+#[proc_macro_derive(RenderLabel)]
+pub fn derive_render_label(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let mut trait_path = bevy_render_path();
+    trait_path
+        .segments
+        .push(format_ident!("render_graph").into());
+    trait_path
+        .segments
+        .push(format_ident!("RenderLabel").into());
+    derive_boxed_label(input, &trait_path)
+}
+
+/// Derive macro generating an impl of the trait `RenderSubGraph`.
+///
+/// This does not work for unions.
+#[proc_macro_derive(RenderSubGraph)]
+pub fn derive_render_sub_graph(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let mut trait_path = bevy_render_path();
+    trait_path
+        .segments
+        .push(format_ident!("render_graph").into());
+    trait_path
+        .segments
+        .push(format_ident!("RenderSubGraph").into());
+    derive_boxed_label(input, &trait_path)
+}
@@ -0,0 +1,111 @@
+use bevy_macro_utils::{BevyManifest, Symbol};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Data, DataStruct, Error, Fields, Member, Result};
+
+const ENTITY_ATTRIBUTE_NAME: Symbol = Symbol("entity");
+const SORT_KEY_ATTRIBUTE_NAME: Symbol = Symbol("sort_key");
+const DRAW_FUNCTION_ATTRIBUTE_NAME: Symbol = Symbol("draw_function");
+const PIPELINE_ATTRIBUTE_NAME: Symbol = Symbol("pipeline");
+
+pub fn derive_phase_item(ast: syn::DeriveInput) -> Result<TokenStream> {
+    let render_path = crate::bevy_render_path();
+    let ecs_path = BevyManifest::default().get_path("bevy_ecs");
+
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) => &fields.unnamed,
+        _ => {
+            return Err(Error::new_spanned(
+                ast,
+                "PhaseItem can only be derived for structs with fields",
+            ))
+        }
+    };
+
+    let mut entity = None;
+    let mut sort_key = None;
+    let mut draw_function = None;
+    let mut pipeline = None;
+    for (index, field) in fields.iter().enumerate() {
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::Unnamed(index.into()), Member::Named);
+        for attr in &field.attrs {
+            let Some(attr_ident) = attr.path.get_ident() else { continue };
+            let target = if attr_ident == ENTITY_ATTRIBUTE_NAME {
+                &mut entity
+            } else if attr_ident == SORT_KEY_ATTRIBUTE_NAME {
+                &mut sort_key
+            } else if attr_ident == DRAW_FUNCTION_ATTRIBUTE_NAME {
+                &mut draw_function
+            } else if attr_ident == PIPELINE_ATTRIBUTE_NAME {
+                &mut pipeline
+            } else {
+                continue;
+            };
+            if target.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("only one field can be marked with #[{attr_ident}]"),
+                ));
+            }
+            *target = Some((member.clone(), &field.ty));
+        }
+    }
+
+    let missing = |name: Symbol| {
+        Error::new(
+            Span::call_site(),
+            format!("PhaseItem requires a field marked with #[{name}]"),
+        )
+    };
+    let (entity, _) = entity.ok_or_else(|| missing(ENTITY_ATTRIBUTE_NAME))?;
+    let (sort_key, sort_key_type) = sort_key.ok_or_else(|| missing(SORT_KEY_ATTRIBUTE_NAME))?;
+    let (draw_function, _) = draw_function.ok_or_else(|| missing(DRAW_FUNCTION_ATTRIBUTE_NAME))?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let cached_pipeline_impl = pipeline.map_or_else(TokenStream2::new, |(pipeline, _)| {
+        quote! {
+            impl #impl_generics #render_path::render_phase::CachedRenderPipelinePhaseItem for #struct_name #type_generics #where_clause {
+                #[inline]
+                fn cached_pipeline(&self) -> #render_path::render_resource::CachedRenderPipelineId {
+                    self.#pipeline
+                }
+            }
+        }
+    });
+
+    Ok(TokenStream::from(quote! {
+        impl #impl_generics #render_path::render_phase::PhaseItem for #struct_name #type_generics #where_clause {
+            type SortKey = #sort_key_type;
+
+            #[inline]
+            fn entity(&self) -> #ecs_path::entity::Entity {
+                self.#entity
+            }
+
+            #[inline]
+            fn sort_key(&self) -> Self::SortKey {
+                ::core::clone::Clone::clone(&self.#sort_key)
+            }
+
+            #[inline]
+            fn draw_function(&self) -> #render_path::render_phase::DrawFunctionId {
+                self.#draw_function
+            }
+        }
+
+        #cached_pipeline_impl
+    }))
+}
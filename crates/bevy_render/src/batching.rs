@@ -0,0 +1,423 @@
+//! Automatic batching of consecutive, compatible [`PhaseItem`](crate::render_phase::PhaseItem)s
+//! into single instanced draw calls.
+//!
+//! Each phase item gets its per-instance data written into a [`GpuArrayBuffer`], at the index
+//! that the shader will find in `@builtin(instance_index)`. Items that follow each other in a
+//! [`RenderPhase`], use the same pipeline and draw function, and report equal
+//! [`GetBatchData::CompareData`] are then merged: the first item draws all of their instances,
+//! and the others are removed from the phase.
+
+use crate::{
+    extract_component::ExtractComponent,
+    render_phase::{CachedRenderPipelinePhaseItem, DrawFunctionId, RenderPhase},
+    render_resource::{CachedRenderPipelineId, GpuArrayBuffer, GpuArrayBufferable},
+    renderer::{RenderDevice, RenderQueue},
+};
+use bevy_ecs::{
+    prelude::*,
+    query::{QueryItem, ROQueryItem, ReadOnlyWorldQuery},
+};
+use std::ops::Range;
+
+/// Add this component to mesh entities to disable automatic batching.
+///
+/// The entity is still drawn with its own per-instance data, but never as part of a batch.
+/// This is useful for entities whose draw function issues its own instanced draw.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct NoAutomaticBatching;
+
+impl ExtractComponent for NoAutomaticBatching {
+    type Query = ();
+    type Filter = With<NoAutomaticBatching>;
+    type Out = NoAutomaticBatching;
+
+    #[inline]
+    fn extract_component(_item: QueryItem<'_, Self::Query>) -> Option<Self::Out> {
+        Some(NoAutomaticBatching)
+    }
+}
+
+/// A [`CachedRenderPipelinePhaseItem`] that can be drawn as a range of instances.
+///
+/// The range and the dynamic offset are assigned by [`batch_and_prepare_render_phase`], so
+/// phase items should be queued with an `instance_range` of `0..1` and no dynamic offset.
+pub trait InstancedPhaseItem: CachedRenderPipelinePhaseItem {
+    /// The range of instances, in the per-instance data buffer, drawn by this item.
+    fn instance_range(&self) -> &Range<u32>;
+
+    /// The range of instances, in the per-instance data buffer, drawn by this item.
+    fn instance_range_mut(&mut self) -> &mut Range<u32>;
+
+    /// The dynamic offset to bind the per-instance data buffer at, when the uniform buffer
+    /// fallback of [`GpuArrayBuffer`] is in use.
+    fn dynamic_offset(&self) -> Option<u32>;
+
+    /// The dynamic offset to bind the per-instance data buffer at, when the uniform buffer
+    /// fallback of [`GpuArrayBuffer`] is in use.
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32>;
+}
+
+/// Gets the per-instance data of the entity of a phase item, and the data deciding whether it
+/// can be batched with its neighbors.
+///
+/// Implement this to let entities drawn with their own per-instance data participate in
+/// automatic batching.
+pub trait GetBatchData {
+    /// ECS [`WorldQuery`](bevy_ecs::query::WorldQuery) to fetch the data of the phase item's entity.
+    type Query: ReadOnlyWorldQuery;
+    /// Filters the entities handled by this batcher. Phase items whose entity does not match are
+    /// left untouched.
+    type QueryFilter: ReadOnlyWorldQuery;
+    /// Data that has to be equal for two consecutive items to be drawn in the same batch.
+    /// This should identify everything the draw function binds besides the per-instance data,
+    /// e.g. the mesh and the material.
+    type CompareData: PartialEq;
+    /// The per-instance data written into the [`GpuArrayBuffer`].
+    type BufferData: GpuArrayBufferable + Sync + Send + 'static;
+
+    /// Gets the per-instance data of the entity.
+    fn get_buffer_data(query_item: &ROQueryItem<'_, Self::Query>) -> Self::BufferData;
+
+    /// Gets the data used to compare the entity to its neighbors, or `None` if it must never
+    /// be batched.
+    fn get_compare_data(query_item: &ROQueryItem<'_, Self::Query>) -> Option<Self::CompareData>;
+}
+
+/// Everything that has to match for two consecutive items to be drawn in the same batch.
+struct BatchMeta<T: PartialEq> {
+    pipeline_id: CachedRenderPipelineId,
+    draw_function_id: DrawFunctionId,
+    dynamic_offset: Option<u32>,
+    compare_data: T,
+}
+
+impl<T: PartialEq> PartialEq for BatchMeta<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.pipeline_id == other.pipeline_id
+            && self.draw_function_id == other.draw_function_id
+            && self.dynamic_offset == other.dynamic_offset
+            && self.compare_data == other.compare_data
+    }
+}
+
+/// Writes the per-instance data of the [`InstancedPhaseItem`]s of all [`RenderPhase`]s of this
+/// type into the [`GpuArrayBuffer`], and merges consecutive compatible items into batches.
+///
+/// This must run after the phases are sorted, and before
+/// [`write_batched_instance_buffer`].
+pub fn batch_and_prepare_render_phase<I: InstancedPhaseItem, F: GetBatchData>(
+    gpu_array_buffer: ResMut<GpuArrayBuffer<F::BufferData>>,
+    mut views: Query<&mut RenderPhase<I>>,
+    query: Query<(Option<With<NoAutomaticBatching>>, F::Query), F::QueryFilter>,
+) {
+    let gpu_array_buffer = gpu_array_buffer.into_inner();
+
+    for mut phase in &mut views {
+        let items = &mut phase.items;
+        let mut merged = Vec::with_capacity(items.len());
+        let mut batch: Option<(usize, BatchMeta<F::CompareData>)> = None;
+
+        for item_index in 0..items.len() {
+            let item = &mut items[item_index];
+            let Ok((no_automatic_batching, query_item)) = query.get(item.entity()) else {
+                batch = None;
+                merged.push(false);
+                continue;
+            };
+
+            let buffer_index = gpu_array_buffer.push(F::get_buffer_data(&query_item));
+            *item.instance_range_mut() = buffer_index.index..buffer_index.index + 1;
+            *item.dynamic_offset_mut() = buffer_index.dynamic_offset;
+
+            let meta = if no_automatic_batching.is_some() {
+                None
+            } else {
+                F::get_compare_data(&query_item).map(|compare_data| BatchMeta {
+                    pipeline_id: item.cached_pipeline(),
+                    draw_function_id: item.draw_function(),
+                    dynamic_offset: buffer_index.dynamic_offset,
+                    compare_data,
+                })
+            };
+
+            match (&batch, meta) {
+                (Some((batch_index, batch_meta)), Some(meta)) if *batch_meta == meta => {
+                    items[*batch_index].instance_range_mut().end = buffer_index.index + 1;
+                    merged.push(true);
+                }
+                (_, meta) => {
+                    batch = meta.map(|meta| (item_index, meta));
+                    merged.push(false);
+                }
+            }
+        }
+
+        let mut merged = merged.into_iter();
+        items.retain(|_| !merged.next().unwrap());
+    }
+}
+
+/// Queues writing of the per-instance data to the GPU.
+pub fn write_batched_instance_buffer<F: GetBatchData>(
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    mut gpu_array_buffer: ResMut<GpuArrayBuffer<F::BufferData>>,
+) {
+    gpu_array_buffer.write_buffer(&render_device, &render_queue);
+}
+
+/// Clears the per-instance data, so that it can be written again by the next frame's batching.
+pub fn clear_batched_instance_buffers<F: GetBatchData>(
+    mut gpu_array_buffer: ResMut<GpuArrayBuffer<F::BufferData>>,
+) {
+    gpu_array_buffer.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        render_phase::{Draw, DrawFunctions, PhaseItem, TrackedRenderPass},
+        render_resource::{DynamicUniformBuffer, StorageBuffer},
+    };
+    use bevy_ecs::schedule::Schedule;
+
+    #[derive(Debug, PartialEq)]
+    struct TestPhaseItem {
+        entity: Entity,
+        draw_function: DrawFunctionId,
+        instance_range: Range<u32>,
+        dynamic_offset: Option<u32>,
+    }
+
+    impl PhaseItem for TestPhaseItem {
+        type SortKey = ();
+
+        fn entity(&self) -> Entity {
+            self.entity
+        }
+
+        fn sort_key(&self) -> Self::SortKey {}
+
+        fn draw_function(&self) -> DrawFunctionId {
+            self.draw_function
+        }
+    }
+
+    impl CachedRenderPipelinePhaseItem for TestPhaseItem {
+        fn cached_pipeline(&self) -> CachedRenderPipelineId {
+            CachedRenderPipelineId::INVALID
+        }
+    }
+
+    impl InstancedPhaseItem for TestPhaseItem {
+        fn instance_range(&self) -> &Range<u32> {
+            &self.instance_range
+        }
+
+        fn instance_range_mut(&mut self) -> &mut Range<u32> {
+            &mut self.instance_range
+        }
+
+        fn dynamic_offset(&self) -> Option<u32> {
+            self.dynamic_offset
+        }
+
+        fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+            &mut self.dynamic_offset
+        }
+    }
+
+    struct NoopDraw;
+
+    impl Draw<TestPhaseItem> for NoopDraw {
+        fn draw<'w>(
+            &mut self,
+            _world: &'w World,
+            _pass: &mut TrackedRenderPass<'w>,
+            _view: Entity,
+            _item: &TestPhaseItem,
+        ) {
+        }
+    }
+
+    #[derive(Component)]
+    struct TestInstance {
+        material: u32,
+        value: u32,
+    }
+
+    #[derive(Component)]
+    struct NotBatchable;
+
+    #[derive(Clone, Copy)]
+    enum Batching {
+        Automatic,
+        OptedOut,
+        NotBatchable,
+    }
+
+    struct TestBatch;
+
+    impl GetBatchData for TestBatch {
+        type Query = (&'static TestInstance, Option<With<NotBatchable>>);
+        type QueryFilter = ();
+        type CompareData = u32;
+        type BufferData = u32;
+
+        fn get_buffer_data((instance, _): &ROQueryItem<'_, Self::Query>) -> Self::BufferData {
+            instance.value
+        }
+
+        fn get_compare_data(
+            (instance, not_batchable): &ROQueryItem<'_, Self::Query>,
+        ) -> Option<Self::CompareData> {
+            not_batchable.is_none().then_some(instance.material)
+        }
+    }
+
+    /// Spawns one entity per `(material, batching)` and queues them in a phase, in that order.
+    fn run_batching(
+        buffer: GpuArrayBuffer<u32>,
+        instances: &[(u32, Batching)],
+    ) -> (Vec<TestPhaseItem>, GpuArrayBuffer<u32>, Vec<Entity>) {
+        let mut world = World::new();
+        let draw_functions = DrawFunctions::<TestPhaseItem>::default();
+        let draw_function = draw_functions.write().add(NoopDraw);
+
+        let mut phase = RenderPhase::<TestPhaseItem>::default();
+        let mut entities = Vec::new();
+        for (value, &(material, batching)) in instances.iter().enumerate() {
+            let mut entity = world.spawn(TestInstance {
+                material,
+                value: value as u32,
+            });
+            match batching {
+                Batching::Automatic => {}
+                Batching::OptedOut => {
+                    entity.insert(NoAutomaticBatching);
+                }
+                Batching::NotBatchable => {
+                    entity.insert(NotBatchable);
+                }
+            }
+            let entity = entity.id();
+            entities.push(entity);
+            phase.add(TestPhaseItem {
+                entity,
+                draw_function,
+                instance_range: 0..1,
+                dynamic_offset: None,
+            });
+        }
+        let view = world.spawn(phase).id();
+        world.insert_resource(buffer);
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(batch_and_prepare_render_phase::<TestPhaseItem, TestBatch>);
+        schedule.run(&mut world);
+
+        let items = std::mem::take(
+            &mut world
+                .get_mut::<RenderPhase<TestPhaseItem>>(view)
+                .unwrap()
+                .items,
+        );
+        let buffer = world.remove_resource::<GpuArrayBuffer<u32>>().unwrap();
+        (items, buffer, entities)
+    }
+
+    fn ranges(items: &[TestPhaseItem]) -> Vec<(Entity, Range<u32>)> {
+        items
+            .iter()
+            .map(|item| (item.entity, item.instance_range.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn consecutive_items_are_batched() {
+        let (items, buffer, entities) = run_batching(
+            GpuArrayBuffer::Storage(StorageBuffer::default()),
+            &[
+                (0, Batching::Automatic),
+                (0, Batching::Automatic),
+                (0, Batching::Automatic),
+            ],
+        );
+
+        assert_eq!(ranges(&items), vec![(entities[0], 0..3)]);
+        assert_eq!(items[0].dynamic_offset, None);
+        let GpuArrayBuffer::Storage(buffer) = buffer else {
+            unreachable!()
+        };
+        assert_eq!(buffer.get(), &vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn different_materials_break_batches() {
+        let (items, buffer, entities) = run_batching(
+            GpuArrayBuffer::Storage(StorageBuffer::default()),
+            &[
+                (0, Batching::Automatic),
+                (0, Batching::Automatic),
+                (1, Batching::Automatic),
+                (0, Batching::Automatic),
+                (0, Batching::Automatic),
+            ],
+        );
+
+        assert_eq!(
+            ranges(&items),
+            vec![
+                (entities[0], 0..2),
+                (entities[2], 2..3),
+                (entities[3], 3..5),
+            ]
+        );
+        // Every instance keeps its own data, in draw order
+        assert_eq!(buffer.len(), 5);
+    }
+
+    #[test]
+    fn opted_out_items_are_not_batched() {
+        let (items, _, entities) = run_batching(
+            GpuArrayBuffer::Storage(StorageBuffer::default()),
+            &[
+                (0, Batching::Automatic),
+                (0, Batching::OptedOut),
+                (0, Batching::Automatic),
+                (0, Batching::NotBatchable),
+                (0, Batching::Automatic),
+                (0, Batching::Automatic),
+            ],
+        );
+
+        assert_eq!(
+            ranges(&items),
+            vec![
+                (entities[0], 0..1),
+                (entities[1], 1..2),
+                (entities[2], 2..3),
+                (entities[3], 3..4),
+                (entities[4], 4..6),
+            ]
+        );
+    }
+
+    #[test]
+    fn uniform_fallback_draws_items_separately() {
+        let (items, buffer, entities) = run_batching(
+            GpuArrayBuffer::Uniform(DynamicUniformBuffer::default()),
+            &[(0, Batching::Automatic), (0, Batching::Automatic)],
+        );
+
+        assert_eq!(
+            ranges(&items),
+            vec![(entities[0], 0..1), (entities[1], 0..1)]
+        );
+        assert_eq!(items[0].dynamic_offset, Some(0));
+        assert!(items[1].dynamic_offset.unwrap() > 0);
+        assert_eq!(buffer.len(), 2);
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
-    camera::CameraProjection,
+    camera::{CameraProjection, ManualTextureViewHandle, ManualTextureViews},
     prelude::Image,
     render_asset::RenderAssets,
+    render_graph::{BoxedRenderSubGraph, RenderSubGraph},
     render_resource::TextureView,
-    view::{ColorGrading, ExtractedView, ExtractedWindows, VisibleEntities},
+    view::{ColorGrading, ExtractedView, ExtractedWindows, Msaa, VisibleEntities},
     Extract,
 };
 use bevy_asset::{AssetEvent, Assets, Handle};
@@ -18,7 +19,7 @@ use bevy_ecs::{
     system::{Commands, Query, Res, ResMut, Resource},
 };
 use bevy_log::warn;
-use bevy_math::{Mat4, Ray, UVec2, UVec4, Vec2, Vec3};
+use bevy_math::{Dir3, Mat4, Ray3d, UVec2, UVec4, Vec2, Vec3, Vec4};
 use bevy_reflect::prelude::*;
 use bevy_reflect::FromReflect;
 use bevy_transform::components::GlobalTransform;
@@ -27,7 +28,7 @@ use bevy_window::{
     NormalizedWindowRef, PrimaryWindow, Window, WindowCreated, WindowRef, WindowResized,
 };
 
-use std::{borrow::Cow, ops::Range};
+use std::ops::Range;
 use wgpu::{BlendState, Extent3d, LoadOp, TextureFormat};
 
 /// Render viewport configuration for the [`Camera`] component.
@@ -58,6 +59,45 @@ impl Default for Viewport {
     }
 }
 
+/// Configures a [`Camera`] to render only a region of the image it would otherwise render.
+///
+/// The camera renders into its viewport the `size` region at `offset` of an image of
+/// `full_size`, as if the viewport had that full size. This allows splitting the image of a
+/// camera in tiles rendered by several cameras, to render screenshots larger than a texture
+/// can be or to drive several projectors for instance. All the values are in physical pixels,
+/// with the origin at the top-left corner of the full image.
+#[derive(Reflect, FromReflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubCameraView {
+    /// The size of the full image the region is taken from.
+    pub full_size: UVec2,
+    /// The position of the top-left corner of the region in the full image.
+    pub offset: UVec2,
+    /// The size of the region.
+    pub size: UVec2,
+}
+
+impl SubCameraView {
+    /// Returns the matrix that crops the clip space of the full image to the region of this sub
+    /// view.
+    ///
+    /// Applied after a projection matrix, it stretches the region onto the whole viewport, which
+    /// amounts to an asymmetric frustum for perspective projections.
+    pub fn clip_crop_matrix(&self) -> Mat4 {
+        let full_size = self.full_size.as_vec2();
+        let offset = self.offset.as_vec2();
+        let size = self.size.as_vec2().max(Vec2::ONE);
+        let scale = full_size / size;
+        // The region is centered on the origin of clip space, whose y axis points up
+        let translation = (full_size - 2.0 * offset - size) / size * Vec2::new(1.0, -1.0);
+        Mat4::from_cols(
+            Vec4::new(scale.x, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, scale.y, 0.0, 0.0),
+            Vec4::Z,
+            translation.extend(0.0).extend(1.0),
+        )
+    }
+}
+
 /// Information about the current [`RenderTarget`].
 #[derive(Default, Debug, Clone)]
 pub struct RenderTargetInfo {
@@ -74,6 +114,7 @@ pub struct ComputedCameraValues {
     target_info: Option<RenderTargetInfo>,
     // position and size of the `Viewport`
     old_viewport_size: Option<UVec2>,
+    old_sub_camera_view: Option<SubCameraView>,
 }
 
 /// The defining component for camera entities, storing information about how and what to render
@@ -91,6 +132,8 @@ pub struct ComputedCameraValues {
 pub struct Camera {
     /// If set, this camera will render to the given [`Viewport`] rectangle within the configured [`RenderTarget`].
     pub viewport: Option<Viewport>,
+    /// If set, this camera will only render the given region of its full image, see [`SubCameraView`].
+    pub sub_camera_view: Option<SubCameraView>,
     /// Cameras with a higher order are rendered later, and thus on top of lower order cameras.
     pub order: isize,
     /// If this is set to `true`, this camera will be rendered to its specified [`RenderTarget`]. If `false`, this
@@ -125,6 +168,7 @@ impl Default for Camera {
             is_active: true,
             order: 0,
             viewport: None,
+            sub_camera_view: None,
             computed: Default::default(),
             target: Default::default(),
             output_mode: Default::default(),
@@ -142,6 +186,17 @@ impl Camera {
         Some((physical_size.as_dvec2() / scale).as_vec2())
     }
 
+    /// The scale factor of this camera's [`RenderTarget`], used to convert logical pixels into physical pixels.
+    ///
+    /// Returns `None` until the render target information has been computed by [`camera_system`].
+    #[inline]
+    pub fn target_scaling_factor(&self) -> Option<f64> {
+        self.computed
+            .target_info
+            .as_ref()
+            .map(|target_info| target_info.scale_factor)
+    }
+
     /// The rendered physical bounds (minimum, maximum) of the camera. If the `viewport` field is
     /// set to [`Some`], this will be the rect of that custom viewport. Otherwise it will default to
     /// the full physical rect of the current [`RenderTarget`].
@@ -248,7 +303,7 @@ impl Camera {
         &self,
         camera_transform: &GlobalTransform,
         viewport_position: Vec2,
-    ) -> Option<Ray> {
+    ) -> Option<Ray3d> {
         let target_size = self.logical_viewport_size()?;
         let ndc = viewport_position * 2. / target_size - Vec2::ONE;
 
@@ -258,10 +313,13 @@ impl Camera {
         // Using EPSILON because an ndc with Z = 0 returns NaNs.
         let world_far_plane = ndc_to_world.project_point3(ndc.extend(f32::EPSILON));
 
-        (!world_near_plane.is_nan() && !world_far_plane.is_nan()).then_some(Ray {
-            origin: world_near_plane,
-            direction: (world_far_plane - world_near_plane).normalize(),
-        })
+        // The fallible direction constructor ensures that world_near_plane and world_far_plane aren't NaN.
+        Dir3::new(world_far_plane - world_near_plane)
+            .ok()
+            .map(|direction| Ray3d {
+                origin: world_near_plane,
+                direction,
+            })
     }
 
     /// Returns a 2D world position computed from a position on this [`Camera`]'s viewport.
@@ -349,22 +407,28 @@ impl Default for CameraOutputMode {
     }
 }
 
-/// Configures the [`RenderGraph`](crate::render_graph::RenderGraph) name assigned to be run for a given [`Camera`] entity.
-#[derive(Component, Deref, DerefMut, Reflect, Default)]
+/// Configures the [`RenderGraph`](crate::render_graph::RenderGraph) sub graph assigned to be run for a given [`Camera`] entity.
+#[derive(Component, Deref, DerefMut, Reflect)]
 #[reflect(Component)]
-pub struct CameraRenderGraph(Cow<'static, str>);
+pub struct CameraRenderGraph(#[reflect(ignore)] BoxedRenderSubGraph);
 
 impl CameraRenderGraph {
-    /// Creates a new [`CameraRenderGraph`] from any string-like type.
+    /// Creates a new [`CameraRenderGraph`] from a [`RenderSubGraph`] label, which can be a string.
     #[inline]
-    pub fn new<T: Into<Cow<'static, str>>>(name: T) -> Self {
-        Self(name.into())
+    pub fn new(sub_graph: impl RenderSubGraph) -> Self {
+        Self(sub_graph.dyn_clone())
     }
 
     #[inline]
-    /// Sets the graph name.
-    pub fn set<T: Into<Cow<'static, str>>>(&mut self, name: T) {
-        self.0 = name.into();
+    /// Sets the sub graph label.
+    pub fn set(&mut self, sub_graph: impl RenderSubGraph) {
+        self.0 = sub_graph.dyn_clone();
+    }
+}
+
+impl Default for CameraRenderGraph {
+    fn default() -> Self {
+        Self::new("")
     }
 }
 
@@ -376,6 +440,9 @@ pub enum RenderTarget {
     Window(WindowRef),
     /// Image to which the camera's view is rendered.
     Image(Handle<Image>),
+    /// Texture view to which the camera's view is rendered.
+    /// Useful when the texture view needs to be created outside of Bevy, for example OpenXR.
+    TextureView(ManualTextureViewHandle),
 }
 
 /// Normalized version of the render target.
@@ -387,6 +454,9 @@ pub enum NormalizedRenderTarget {
     Window(NormalizedWindowRef),
     /// Image to which the camera's view is rendered.
     Image(Handle<Image>),
+    /// Texture view to which the camera's view is rendered.
+    /// Useful when the texture view needs to be created outside of Bevy, for example OpenXR.
+    TextureView(ManualTextureViewHandle),
 }
 
 impl Default for RenderTarget {
@@ -403,6 +473,7 @@ impl RenderTarget {
                 .normalize(primary_window)
                 .map(NormalizedRenderTarget::Window),
             RenderTarget::Image(handle) => Some(NormalizedRenderTarget::Image(handle.clone())),
+            RenderTarget::TextureView(id) => Some(NormalizedRenderTarget::TextureView(*id)),
         }
     }
 }
@@ -412,6 +483,7 @@ impl NormalizedRenderTarget {
         &self,
         windows: &'a ExtractedWindows,
         images: &'a RenderAssets<Image>,
+        manual_texture_views: &'a ManualTextureViews,
     ) -> Option<&'a TextureView> {
         match self {
             NormalizedRenderTarget::Window(window_ref) => windows
@@ -420,6 +492,9 @@ impl NormalizedRenderTarget {
             NormalizedRenderTarget::Image(image_handle) => {
                 images.get(image_handle).map(|image| &image.texture_view)
             }
+            NormalizedRenderTarget::TextureView(id) => {
+                manual_texture_views.get(id).map(|tex| &tex.texture_view)
+            }
         }
     }
 
@@ -428,6 +503,7 @@ impl NormalizedRenderTarget {
         &self,
         windows: &'a ExtractedWindows,
         images: &'a RenderAssets<Image>,
+        manual_texture_views: &'a ManualTextureViews,
     ) -> Option<TextureFormat> {
         match self {
             NormalizedRenderTarget::Window(window_ref) => windows
@@ -436,6 +512,9 @@ impl NormalizedRenderTarget {
             NormalizedRenderTarget::Image(image_handle) => {
                 images.get(image_handle).map(|image| image.texture_format)
             }
+            NormalizedRenderTarget::TextureView(id) => {
+                manual_texture_views.get(id).map(|tex| tex.format)
+            }
         }
     }
 
@@ -443,6 +522,7 @@ impl NormalizedRenderTarget {
         &self,
         resolutions: impl IntoIterator<Item = (Entity, &'a Window)>,
         images: &Assets<Image>,
+        manual_texture_views: &ManualTextureViews,
     ) -> Option<RenderTargetInfo> {
         match self {
             NormalizedRenderTarget::Window(window_ref) => resolutions
@@ -463,14 +543,22 @@ impl NormalizedRenderTarget {
                     scale_factor: 1.0,
                 })
             }
+            NormalizedRenderTarget::TextureView(id) => {
+                manual_texture_views.get(id).map(|tex| RenderTargetInfo {
+                    physical_size: tex.size,
+                    scale_factor: 1.0,
+                })
+            }
         }
     }
 
-    // Check if this render target is contained in the given changed windows or images.
+    // Check if this render target is contained in the given changed windows or images, or is a
+    // texture view while they changed.
     fn is_changed(
         &self,
         changed_window_ids: &HashSet<Entity>,
         changed_image_handles: &HashSet<&Handle<Image>>,
+        manual_texture_views_changed: bool,
     ) -> bool {
         match self {
             NormalizedRenderTarget::Window(window_ref) => {
@@ -479,6 +567,7 @@ impl NormalizedRenderTarget {
             NormalizedRenderTarget::Image(image_handle) => {
                 changed_image_handles.contains(&image_handle)
             }
+            NormalizedRenderTarget::TextureView(_) => manual_texture_views_changed,
         }
     }
 }
@@ -499,10 +588,14 @@ impl NormalizedRenderTarget {
 /// [`Res<Assets<Image>>`](Assets<Image>) -- For cameras that render to an image, this resource is used to
 /// inspect information about the render target. This system will not access any other image assets.
 ///
+/// [`Res<ManualTextureViews>`](ManualTextureViews) -- For cameras that render to a texture view, this
+/// resource is used to inspect information about the render target.
+///
 /// [`OrthographicProjection`]: crate::camera::OrthographicProjection
 /// [`PerspectiveProjection`]: crate::camera::PerspectiveProjection
 /// [`Projection`]: crate::camera::Projection
 /// [`CoreSet::PostUpdate`]: bevy_app::CoreSet::PostUpdate
+#[allow(clippy::too_many_arguments)]
 pub fn camera_system<T: CameraProjection + Component>(
     mut window_resized_events: EventReader<WindowResized>,
     mut window_created_events: EventReader<WindowCreated>,
@@ -510,6 +603,7 @@ pub fn camera_system<T: CameraProjection + Component>(
     primary_window: Query<Entity, With<PrimaryWindow>>,
     windows: Query<(Entity, &Window)>,
     images: Res<Assets<Image>>,
+    manual_texture_views: Res<ManualTextureViews>,
     mut cameras: Query<(&mut Camera, &mut T)>,
 ) {
     let primary_window = primary_window.iter().next();
@@ -536,14 +630,38 @@ pub fn camera_system<T: CameraProjection + Component>(
             .map(|viewport| viewport.physical_size);
 
         if let Some(normalized_target) = camera.target.normalize(primary_window) {
-            if normalized_target.is_changed(&changed_window_ids, &changed_image_handles)
-                || camera.is_added()
+            if normalized_target.is_changed(
+                &changed_window_ids,
+                &changed_image_handles,
+                manual_texture_views.is_changed(),
+            ) || camera.is_added()
                 || camera_projection.is_changed()
                 || camera.computed.old_viewport_size != viewport_size
+                || camera.computed.old_sub_camera_view != camera.sub_camera_view
             {
-                camera.computed.target_info =
-                    normalized_target.get_render_target_info(&windows, &images);
-                if let Some(size) = camera.logical_viewport_size() {
+                camera.computed.target_info = normalized_target.get_render_target_info(
+                    &windows,
+                    &images,
+                    &manual_texture_views,
+                );
+                if let (None, NormalizedRenderTarget::TextureView(id)) =
+                    (&camera.computed.target_info, &normalized_target)
+                {
+                    warn!(
+                        "Camera renders to the manual texture view {:?}, which doesn't exist. \
+                        The camera will be skipped until the texture view is added to ManualTextureViews.",
+                        id
+                    );
+                }
+                camera.computed.old_sub_camera_view = camera.sub_camera_view;
+                if let Some(sub_view) = camera.sub_camera_view {
+                    // The projection is sized for the full image, and cropped to the sub view
+                    if let Some(size) = camera.to_logical(sub_view.full_size) {
+                        camera_projection.update(size.x, size.y);
+                        camera.computed.projection_matrix =
+                            camera_projection.get_projection_matrix_for_sub(&sub_view);
+                    }
+                } else if let Some(size) = camera.logical_viewport_size() {
                     camera_projection.update(size.x, size.y);
                     camera.computed.projection_matrix = camera_projection.get_projection_matrix();
                 }
@@ -558,7 +676,7 @@ pub struct ExtractedCamera {
     pub physical_viewport_size: Option<UVec2>,
     pub physical_target_size: Option<UVec2>,
     pub viewport: Option<Viewport>,
-    pub render_graph: Cow<'static, str>,
+    pub render_graph: BoxedRenderSubGraph,
     pub order: isize,
     pub output_mode: CameraOutputMode,
     pub msaa_writeback: bool,
@@ -575,13 +693,24 @@ pub fn extract_cameras(
             &GlobalTransform,
             &VisibleEntities,
             Option<&ColorGrading>,
+            Option<&TemporalJitter>,
+            Option<&Msaa>,
         )>,
     >,
     primary_window: Extract<Query<Entity, With<PrimaryWindow>>>,
+    default_msaa: Extract<Res<Msaa>>,
 ) {
     let primary_window = primary_window.iter().next();
-    for (entity, camera, camera_render_graph, transform, visible_entities, color_grading) in
-        query.iter()
+    for (
+        entity,
+        camera,
+        camera_render_graph,
+        transform,
+        visible_entities,
+        color_grading,
+        temporal_jitter,
+        msaa,
+    ) in query.iter()
     {
         let color_grading = *color_grading.unwrap_or(&ColorGrading::default());
 
@@ -621,10 +750,48 @@ pub fn extract_cameras(
                         viewport_size.y,
                     ),
                     color_grading,
+                    msaa: *msaa.unwrap_or(&default_msaa),
                 },
                 visible_entities.clone(),
             ));
+
+            if let Some(temporal_jitter) = temporal_jitter {
+                commands
+                    .get_or_spawn(entity)
+                    .insert(temporal_jitter.clone());
+            }
+        }
+    }
+}
+
+/// A subpixel offset to jitter a perspective camera's frustum by.
+///
+/// Useful for temporal rendering techniques.
+///
+/// Do not use with [`OrthographicProjection`].
+///
+/// [`OrthographicProjection`]: crate::camera::OrthographicProjection
+#[derive(Component, Clone, Default, Reflect, FromReflect)]
+#[reflect(Default, Component)]
+pub struct TemporalJitter {
+    /// Offset is in range [-0.5, 0.5].
+    pub offset: Vec2,
+}
+
+impl TemporalJitter {
+    pub fn jitter_projection(&self, projection: &mut Mat4, view_size: Vec2) {
+        if projection.w_axis.w == 1.0 {
+            warn!(
+                "TemporalJitter not supported with OrthographicProjection. Use PerspectiveProjection instead."
+            );
+            return;
         }
+
+        // The offset is in pixels, convert it to clip space (y points up in clip space)
+        let jitter = (self.offset * Vec2::new(2.0, -2.0)) / view_size;
+
+        projection.z_axis.x += jitter.x;
+        projection.z_axis.y += jitter.y;
     }
 }
 
@@ -687,3 +854,194 @@ pub fn sort_cameras(
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camera::{OrthographicProjection, PerspectiveProjection};
+
+    fn test_camera() -> Camera {
+        Camera {
+            computed: ComputedCameraValues {
+                projection_matrix: PerspectiveProjection::default().get_projection_matrix(),
+                target_info: Some(RenderTargetInfo {
+                    physical_size: UVec2::new(100, 100),
+                    scale_factor: 1.0,
+                }),
+                old_viewport_size: None,
+                old_sub_camera_view: None,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn viewport_to_world() {
+        let camera = test_camera();
+        let camera_transform = GlobalTransform::from_xyz(1.0, 2.0, 3.0);
+
+        // The center of the viewport looks straight ahead
+        let ray = camera
+            .viewport_to_world(&camera_transform, Vec2::new(50.0, 50.0))
+            .unwrap();
+        assert!(ray.direction.abs_diff_eq(Vec3::NEG_Z, 1e-5));
+        assert!(ray.origin.abs_diff_eq(Vec3::new(1.0, 2.0, 2.9), 1e-5));
+
+        // Other positions are still normalized
+        let ray = camera
+            .viewport_to_world(&camera_transform, Vec2::new(0.0, 100.0))
+            .unwrap();
+        assert!(ray.direction.is_normalized());
+        assert!(ray.direction.x < 0.0);
+    }
+
+    #[test]
+    fn viewport_to_world_without_target() {
+        let camera = Camera::default();
+        assert!(camera
+            .viewport_to_world(&GlobalTransform::IDENTITY, Vec2::ZERO)
+            .is_none());
+    }
+
+    /// Checks that rendering the 2x2 tiles of a full image with sub views projects points to the
+    /// same pixels as rendering the full image.
+    fn assert_tiles_match_full_image(mut projection: impl CameraProjection) {
+        let full_size = UVec2::new(200, 100);
+        projection.update(full_size.x as f32, full_size.y as f32);
+        let full_matrix = projection.get_projection_matrix();
+        // Projects a point to pixel coordinates in a viewport of the given size
+        let to_pixel = |matrix: Mat4, point: Vec3, size: UVec2| {
+            let ndc = matrix.project_point3(point).truncate();
+            (ndc * Vec2::new(0.5, -0.5) + 0.5) * size.as_vec2()
+        };
+        let points = [
+            Vec3::new(0.0, 0.0, -2.0),
+            Vec3::new(-1.5, 0.4, -3.0),
+            Vec3::new(0.7, -0.3, -1.0),
+            Vec3::new(2.0, 1.0, -5.0),
+        ];
+
+        let tile_size = full_size / 2;
+        for offset in [UVec2::ZERO, UVec2::X, UVec2::Y, UVec2::ONE].map(|tile| tile * tile_size) {
+            let sub_view = SubCameraView {
+                full_size,
+                offset,
+                size: tile_size,
+            };
+            let tile_matrix = projection.get_projection_matrix_for_sub(&sub_view);
+            for point in points {
+                let full_pixel = to_pixel(full_matrix, point, full_size);
+                let tile_pixel = to_pixel(tile_matrix, point, tile_size) + offset.as_vec2();
+                assert!(
+                    full_pixel.abs_diff_eq(tile_pixel, 1e-3),
+                    "{point} projected to {tile_pixel} in tile at {offset}, instead of {full_pixel}"
+                );
+            }
+
+            // The corners of the tile are the corners of its region in the full image
+            let inverse = tile_matrix.inverse();
+            for corner in [Vec2::new(-1.0, 1.0), Vec2::new(1.0, -1.0)] {
+                let point = inverse.project_point3(corner.extend(0.5));
+                let full_pixel = to_pixel(full_matrix, point, full_size);
+                let expected =
+                    offset.as_vec2() + (corner * Vec2::new(0.5, -0.5) + 0.5) * tile_size.as_vec2();
+                assert!(full_pixel.abs_diff_eq(expected, 1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn perspective_sub_views_tile_the_full_image() {
+        assert_tiles_match_full_image(PerspectiveProjection::default());
+    }
+
+    #[test]
+    fn orthographic_sub_views_tile_the_full_image() {
+        assert_tiles_match_full_image(OrthographicProjection {
+            scale: 0.02,
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn cameras_without_their_manual_texture_view_are_skipped() {
+        use crate::MainWorld;
+        use bevy_app::App;
+        use bevy_asset::{AddAsset, AssetPlugin};
+        use bevy_ecs::{
+            system::{IntoSystem, System},
+            world::World,
+        };
+
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowCreated>()
+            .init_resource::<ManualTextureViews>()
+            .init_resource::<Msaa>()
+            .add_system(camera_system::<PerspectiveProjection>);
+        let camera = app
+            .world
+            .spawn((
+                Camera {
+                    target: RenderTarget::TextureView(ManualTextureViewHandle(3)),
+                    ..Default::default()
+                },
+                PerspectiveProjection::default(),
+                CameraRenderGraph::default(),
+                GlobalTransform::IDENTITY,
+                VisibleEntities::default(),
+            ))
+            .id();
+        app.update();
+        assert!(app
+            .world
+            .get::<Camera>(camera)
+            .unwrap()
+            .computed
+            .target_info
+            .is_none());
+
+        let mut render_world = World::new();
+        render_world.insert_resource(MainWorld(std::mem::take(&mut app.world)));
+        let mut system = IntoSystem::into_system(extract_cameras);
+        system.initialize(&mut render_world);
+        system.run((), &mut render_world);
+        system.apply_buffers(&mut render_world);
+        assert!(render_world.get::<ExtractedCamera>(camera).is_none());
+    }
+
+    #[test]
+    fn extracted_views_use_the_msaa_of_their_camera() {
+        use crate::MainWorld;
+        use bevy_ecs::{
+            system::{IntoSystem, System},
+            world::World,
+        };
+
+        let mut main_world = World::new();
+        main_world.insert_resource(Msaa::Sample8);
+        let camera_bundle = || {
+            (
+                test_camera(),
+                CameraRenderGraph::default(),
+                GlobalTransform::IDENTITY,
+                VisibleEntities::default(),
+            )
+        };
+        let with_component = main_world.spawn((camera_bundle(), Msaa::Off)).id();
+        let without_component = main_world.spawn(camera_bundle()).id();
+
+        let mut render_world = World::new();
+        render_world.insert_resource(MainWorld(main_world));
+        let mut system = IntoSystem::into_system(extract_cameras);
+        system.initialize(&mut render_world);
+        system.run((), &mut render_world);
+        system.apply_buffers(&mut render_world);
+
+        let msaa = |entity| render_world.get::<ExtractedView>(entity).unwrap().msaa;
+        assert_eq!(msaa(with_component), Msaa::Off);
+        assert_eq!(msaa(without_component), Msaa::Sample8);
+    }
+}
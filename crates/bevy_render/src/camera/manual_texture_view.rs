@@ -0,0 +1,242 @@
+use crate::{
+    extract_resource::ExtractResource, render_resource::TextureView, texture::BevyDefault,
+};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{prelude::Component, reflect::ReflectComponent, system::Resource};
+use bevy_math::UVec2;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+use bevy_utils::HashMap;
+use wgpu::TextureFormat;
+
+/// A unique id identifying a [`ManualTextureView`] in the [`ManualTextureViews`] resource.
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Component,
+    Reflect,
+    FromReflect,
+)]
+#[reflect(Component, Default)]
+pub struct ManualTextureViewHandle(pub u32);
+
+/// A [`TextureView`] provided manually, that a [`Camera`](crate::camera::Camera) can render to
+/// with [`RenderTarget::TextureView`](crate::camera::RenderTarget::TextureView).
+///
+/// This is useful to render to textures owned by something else than Bevy, like the swapchain
+/// of an XR runtime or the frames of a video encoder.
+#[derive(Debug, Clone)]
+pub struct ManualTextureView {
+    pub texture_view: TextureView,
+    /// The physical size of the texture.
+    pub size: UVec2,
+    pub format: TextureFormat,
+}
+
+impl ManualTextureView {
+    /// Creates a [`ManualTextureView`] with the default texture format of Bevy.
+    pub fn with_default_format(texture_view: TextureView, size: UVec2) -> Self {
+        Self {
+            texture_view,
+            size,
+            format: TextureFormat::bevy_default(),
+        }
+    }
+}
+
+/// The [`ManualTextureView`]s cameras can render to, extracted to the render world each frame
+/// they change.
+///
+/// A camera whose view is removed from this resource is skipped until a view is inserted again
+/// with the same handle.
+#[derive(Default, Clone, Resource, ExtractResource, Deref, DerefMut)]
+pub struct ManualTextureViews(HashMap<ManualTextureViewHandle, ManualTextureView>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        camera::{Camera, CameraRenderGraph, PerspectiveProjection, RenderTarget},
+        render_graph::{
+            NodeRunError, RenderGraph, RenderGraphApp, RenderGraphContext, SlotInfo, SlotType,
+            ViewNode, ViewNodeRunner,
+        },
+        renderer::{
+            RenderAdapter, RenderAdapterInfo, RenderContext, RenderDevice, RenderInstance,
+            RenderQueue,
+        },
+        settings::RenderCreation,
+        texture::ImagePlugin,
+        view::{ViewTarget, VisibleEntities},
+        RenderApp, RenderPlugin,
+    };
+    use bevy_app::App;
+    use bevy_asset::AssetPlugin;
+    use bevy_core::{FrameCountPlugin, TaskPoolPlugin};
+    use bevy_ecs::{query::QueryItem, world::World};
+    use bevy_time::TimePlugin;
+    use bevy_transform::components::GlobalTransform;
+    use bevy_window::WindowPlugin;
+    use futures_lite::future::block_on;
+    use std::{num::NonZeroU32, sync::Arc};
+
+    const CLEAR_GRAPH: &str = "clear_graph";
+
+    /// Clears the output texture of the view to red.
+    #[derive(Default)]
+    struct ClearNode;
+
+    impl ViewNode for ClearNode {
+        type ViewQuery = &'static ViewTarget;
+
+        fn run(
+            &self,
+            _graph: &mut RenderGraphContext,
+            render_context: &mut RenderContext,
+            target: QueryItem<Self::ViewQuery>,
+            _world: &World,
+        ) -> Result<(), NodeRunError> {
+            render_context
+                .command_encoder()
+                .begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("clear_pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: target.out_texture(),
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::RED),
+                            store: true,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                });
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn cameras_render_into_manual_texture_views() {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let Some(adapter) =
+            block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        else {
+            // There is no adapter to render with in this environment, not even a software one.
+            return;
+        };
+        let (device, queue) =
+            block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None)).unwrap();
+        let adapter_info = adapter.get_info();
+        let device = RenderDevice::from(device);
+        let queue = Arc::new(queue);
+
+        let mut app = App::new();
+        app.add_plugin(TaskPoolPlugin::default())
+            .add_plugin(FrameCountPlugin)
+            .add_plugin(TimePlugin)
+            .add_plugin(WindowPlugin {
+                primary_window: None,
+                ..Default::default()
+            })
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(RenderPlugin {
+                render_creation: RenderCreation::Manual(
+                    device.clone(),
+                    RenderQueue(queue.clone()),
+                    RenderAdapterInfo(adapter_info),
+                    RenderAdapter(Arc::new(adapter)),
+                    RenderInstance(Arc::new(instance)),
+                ),
+            })
+            .add_plugin(ImagePlugin::default());
+        let render_app = app.sub_app_mut(RenderApp);
+        let mut clear_graph = RenderGraph::default();
+        clear_graph.set_input(vec![SlotInfo::new(
+            ViewNodeRunner::<ClearNode>::IN_VIEW,
+            SlotType::Entity,
+        )]);
+        render_app
+            .world
+            .resource_mut::<RenderGraph>()
+            .add_sub_graph(CLEAR_GRAPH, clear_graph);
+        render_app.add_render_graph_node::<ViewNodeRunner<ClearNode>>(CLEAR_GRAPH, "clear");
+
+        // 64 pixels of 4 bytes fill the 256 bytes a row of a texture copy must be aligned to
+        let size = wgpu::Extent3d {
+            width: 64,
+            height: 4,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("manual_texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: TextureFormat::bevy_default(),
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let handle = ManualTextureViewHandle(1);
+        app.world.resource_mut::<ManualTextureViews>().insert(
+            handle,
+            ManualTextureView::with_default_format(
+                texture.create_view(&Default::default()),
+                UVec2::new(size.width, size.height),
+            ),
+        );
+        let camera = app
+            .world
+            .spawn((
+                Camera {
+                    target: RenderTarget::TextureView(handle),
+                    ..Default::default()
+                },
+                CameraRenderGraph::new(CLEAR_GRAPH),
+                PerspectiveProjection::default(),
+                GlobalTransform::default(),
+                VisibleEntities::default(),
+            ))
+            .id();
+        app.update();
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback_buffer"),
+            size: 256 * size.height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(256),
+                    rows_per_image: None,
+                },
+            },
+            size,
+        );
+        queue.submit([encoder.finish()]);
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| ());
+        device.poll(wgpu::Maintain::Wait);
+        assert_eq!(&slice.get_mapped_range()[..4], &[255, 0, 0, 255]);
+        buffer.unmap();
+
+        // Once its view is removed, the camera is skipped
+        app.world
+            .resource_mut::<ManualTextureViews>()
+            .remove(&handle);
+        app.update();
+        let camera = app.world.get::<Camera>(camera).unwrap();
+        assert!(camera.physical_target_size().is_none());
+    }
+}
@@ -1,13 +1,18 @@
 #[allow(clippy::module_inception)]
 mod camera;
 mod camera_driver_node;
+mod manual_texture_view;
 mod projection;
 
 pub use camera::*;
 pub use camera_driver_node::*;
+pub use manual_texture_view::*;
 pub use projection::*;
 
-use crate::{render_graph::RenderGraph, ExtractSchedule, RenderApp, RenderSet};
+use crate::{
+    extract_resource::ExtractResourcePlugin, render_graph::RenderGraph, ExtractSchedule, RenderApp,
+    RenderSet,
+};
 use bevy_app::{App, IntoSystemAppConfig, Plugin};
 use bevy_ecs::schedule::IntoSystemConfig;
 
@@ -19,9 +24,15 @@ impl Plugin for CameraPlugin {
         app.register_type::<Camera>()
             .register_type::<Viewport>()
             .register_type::<Option<Viewport>>()
+            .register_type::<SubCameraView>()
+            .register_type::<Option<SubCameraView>>()
             .register_type::<ScalingMode>()
             .register_type::<CameraRenderGraph>()
             .register_type::<RenderTarget>()
+            .register_type::<TemporalJitter>()
+            .register_type::<ManualTextureViewHandle>()
+            .init_resource::<ManualTextureViews>()
+            .add_plugin(ExtractResourcePlugin::<ManualTextureViews>::default())
             .add_plugin(CameraProjectionPlugin::<Projection>::default())
             .add_plugin(CameraProjectionPlugin::<OrthographicProjection>::default())
             .add_plugin(CameraProjectionPlugin::<PerspectiveProjection>::default());
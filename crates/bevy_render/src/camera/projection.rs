@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::camera::SubCameraView;
 use bevy_app::{App, CoreSchedule, CoreSet, IntoSystemAppConfig, Plugin, StartupSet};
 use bevy_ecs::{prelude::*, reflect::ReflectComponent};
 use bevy_math::{Mat4, Rect, Vec2};
@@ -58,6 +59,14 @@ impl<T: CameraProjection + Component + GetTypeRegistration> Plugin for CameraPro
 /// [`Camera`]: crate::camera::Camera
 pub trait CameraProjection {
     fn get_projection_matrix(&self) -> Mat4;
+    /// Returns the projection matrix rendering only the region of a [`SubCameraView`].
+    ///
+    /// The projection must have been [updated](CameraProjection::update) with the full size of the
+    /// sub view. By default, the [projection matrix](CameraProjection::get_projection_matrix) is
+    /// cropped with [`SubCameraView::clip_crop_matrix`].
+    fn get_projection_matrix_for_sub(&self, sub_view: &SubCameraView) -> Mat4 {
+        sub_view.clip_crop_matrix() * self.get_projection_matrix()
+    }
     fn update(&mut self, width: f32, height: f32);
     fn far(&self) -> f32;
 }
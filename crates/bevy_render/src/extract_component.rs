@@ -8,6 +8,7 @@ use bevy_app::{App, IntoSystemAppConfig, Plugin};
 use bevy_asset::{Asset, Handle};
 use bevy_ecs::{
     component::Component,
+    entity::{EntityHash, EntityHashMap},
     prelude::*,
     query::{QueryItem, ReadOnlyWorldQuery, WorldQuery},
     system::lifetimeless::Read,
@@ -155,6 +156,9 @@ fn prepare_uniform_components<C: Component>(
 /// for the specified [`ExtractComponent`].
 pub struct ExtractComponentPlugin<C, F = ()> {
     only_extract_visible: bool,
+    /// Adds the system of [`ExtractComponentPlugin::changed_only`], which can only be named
+    /// where `C::Out` is known to be [`Clone`].
+    add_changed_extraction: Option<fn(&mut App)>,
     marker: PhantomData<fn() -> (C, F)>,
 }
 
@@ -162,24 +166,59 @@ impl<C, F> Default for ExtractComponentPlugin<C, F> {
     fn default() -> Self {
         Self {
             only_extract_visible: false,
+            add_changed_extraction: None,
             marker: PhantomData,
         }
     }
 }
 
 impl<C, F> ExtractComponentPlugin<C, F> {
+    /// Only extracts the components of the entities whose [`ComputedVisibility`] is visible.
     pub fn extract_visible() -> Self {
         Self {
             only_extract_visible: true,
+            add_changed_extraction: None,
             marker: PhantomData,
         }
     }
 }
 
+impl<C: ExtractComponent, F> ExtractComponentPlugin<C, F>
+where
+    C::Out: Clone,
+{
+    /// Only calls [`ExtractComponent::extract_component`] for the components that were added or
+    /// changed since the last extraction, and inserts the value extracted before for the others.
+    ///
+    /// As the render world is cleared every frame, the previous values are kept by the
+    /// extraction system and cloned into the render world, which is cheaper than extracting
+    /// them again when extraction does more than cloning. Only the changes of `C` are detected,
+    /// not the ones of the other components of [`ExtractComponent::Query`].
+    ///
+    /// This can be combined with [`ExtractComponentPlugin::extract_visible`].
+    pub fn changed_only(mut self) -> Self {
+        self.add_changed_extraction = Some(if self.only_extract_visible {
+            |render_app| {
+                render_app
+                    .add_system(extract_changed_components::<C, true>.in_schedule(ExtractSchedule));
+            }
+        } else {
+            |render_app| {
+                render_app.add_system(
+                    extract_changed_components::<C, false>.in_schedule(ExtractSchedule),
+                );
+            }
+        });
+        self
+    }
+}
+
 impl<C: ExtractComponent> Plugin for ExtractComponentPlugin<C> {
     fn build(&self, app: &mut App) {
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
-            if self.only_extract_visible {
+            if let Some(add_changed_extraction) = self.add_changed_extraction {
+                add_changed_extraction(render_app);
+            } else if self.only_extract_visible {
                 render_app.add_system(extract_visible_components::<C>.in_schedule(ExtractSchedule));
             } else {
                 render_app.add_system(extract_components::<C>.in_schedule(ExtractSchedule));
@@ -232,3 +271,176 @@ fn extract_visible_components<C: ExtractComponent>(
     *previous_len = values.len();
     commands.insert_or_spawn_batch(values);
 }
+
+/// This system extracts the components of the corresponding [`ExtractComponent`] type that
+/// changed, and reuses the previously extracted values for the others.
+///
+/// With `ONLY_VISIBLE`, only the components of visible entities are extracted.
+fn extract_changed_components<C: ExtractComponent, const ONLY_VISIBLE: bool>(
+    mut commands: Commands,
+    mut previous_values: Local<EntityHashMap<C::Out>>,
+    query: Extract<Query<(Entity, Ref<C>, Option<&ComputedVisibility>, C::Query), C::Filter>>,
+) where
+    C::Out: Clone,
+{
+    let mut values = Vec::with_capacity(previous_values.len());
+    let mut current_values =
+        EntityHashMap::with_capacity_and_hasher(previous_values.len(), EntityHash);
+    for (entity, component, computed_visibility, query_item) in &query {
+        let is_visible = matches!(computed_visibility, Some(visibility) if visibility.is_visible());
+        if ONLY_VISIBLE && !is_visible {
+            continue;
+        }
+        let value = match previous_values.remove(&entity) {
+            Some(value) if !component.is_changed() => Some(value),
+            _ => C::extract_component(query_item),
+        };
+        if let Some(value) = value {
+            values.push((entity, value.clone()));
+            current_values.insert(entity, value);
+        }
+    }
+    // The values of the entities that are gone, or don't match anymore, are dropped.
+    *previous_values = current_values;
+    commands.insert_or_spawn_batch(values);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExtractComponent, ExtractComponentPlugin};
+    use crate::{apply_extract_commands, extract, ExtractSchedule, RenderApp, ScratchMainWorld};
+    use bevy_app::{App, CoreSchedule, SubApp};
+    use bevy_ecs::{prelude::*, query::QueryItem, system::lifetimeless::Read};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Counts how many times it was extracted.
+    #[derive(Component, Clone)]
+    struct Counted {
+        value: u32,
+        extractions: Arc<AtomicUsize>,
+    }
+
+    impl ExtractComponent for Counted {
+        type Query = Read<Counted>;
+        type Filter = ();
+        type Out = Counted;
+
+        fn extract_component(item: QueryItem<'_, Self::Query>) -> Option<Self::Out> {
+            item.extractions.fetch_add(1, Ordering::Relaxed);
+            Some(item.clone())
+        }
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Component, Clone, ExtractComponent)]
+    #[extract_component(filter = "With<Marker>")]
+    struct Filtered;
+
+    /// Creates an app whose render app only runs the extraction. The render world is cleared
+    /// before the extraction instead of after rendering, so that it can be checked.
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<ScratchMainWorld>();
+
+        let mut render_app = App::empty();
+        render_app
+            .add_simple_outer_schedule()
+            .add_schedule(CoreSchedule::Main, Schedule::new())
+            .edit_schedule(ExtractSchedule, |schedule| {
+                schedule.set_apply_final_buffers(false);
+            });
+        app.insert_sub_app(
+            RenderApp,
+            SubApp::new(render_app, |main_world, render_app| {
+                render_app.world.clear_entities();
+                extract(main_world, render_app);
+                apply_extract_commands(&mut render_app.world);
+            }),
+        );
+        app
+    }
+
+    fn spawn_counted(app: &mut App, value: u32, extractions: &Arc<AtomicUsize>) -> Entity {
+        app.world
+            .spawn(Counted {
+                value,
+                extractions: extractions.clone(),
+            })
+            .id()
+    }
+
+    fn extracted_values(app: &mut App) -> Vec<u32> {
+        let render_world = &mut app.sub_app_mut(RenderApp).world;
+        let mut values = render_world
+            .query::<&Counted>()
+            .iter(render_world)
+            .map(|counted| counted.value)
+            .collect::<Vec<_>>();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn components_are_extracted_every_frame() {
+        let mut app = app();
+        app.add_plugin(ExtractComponentPlugin::<Counted>::default());
+        let extractions = Arc::new(AtomicUsize::new(0));
+        spawn_counted(&mut app, 1, &extractions);
+        spawn_counted(&mut app, 2, &extractions);
+
+        app.update();
+        app.update();
+
+        assert_eq!(extractions.load(Ordering::Relaxed), 4);
+        assert_eq!(extracted_values(&mut app), vec![1, 2]);
+    }
+
+    #[test]
+    fn changed_only_extracts_changed_components() {
+        let mut app = app();
+        app.add_plugin(ExtractComponentPlugin::<Counted>::default().changed_only());
+        let extractions = Arc::new(AtomicUsize::new(0));
+        let first = spawn_counted(&mut app, 1, &extractions);
+        let second = spawn_counted(&mut app, 2, &extractions);
+
+        app.update();
+        assert_eq!(extractions.load(Ordering::Relaxed), 2);
+        assert_eq!(extracted_values(&mut app), vec![1, 2]);
+
+        // The unchanged components are still in the render world, without being extracted.
+        app.update();
+        assert_eq!(extractions.load(Ordering::Relaxed), 2);
+        assert_eq!(extracted_values(&mut app), vec![1, 2]);
+
+        app.world.get_mut::<Counted>(first).unwrap().value = 3;
+        app.update();
+        assert_eq!(extractions.load(Ordering::Relaxed), 3);
+        assert_eq!(extracted_values(&mut app), vec![2, 3]);
+
+        app.world.despawn(second);
+        app.update();
+        assert_eq!(extractions.load(Ordering::Relaxed), 3);
+        assert_eq!(extracted_values(&mut app), vec![3]);
+    }
+
+    #[test]
+    fn derived_filter_is_applied() {
+        let mut app = app();
+        app.add_plugin(ExtractComponentPlugin::<Filtered>::default());
+        app.world.spawn((Filtered, Marker));
+        app.world.spawn(Filtered);
+
+        app.update();
+
+        let render_world = &mut app.sub_app_mut(RenderApp).world;
+        assert_eq!(
+            render_world.query::<&Filtered>().iter(render_world).count(),
+            1
+        );
+    }
+}
@@ -10,6 +10,29 @@ use crate::{Extract, ExtractSchedule, RenderApp};
 ///
 /// Therefore the resource is transferred from the "main world" into the "render world"
 /// in the [`ExtractSchedule`](crate::ExtractSchedule) step.
+///
+/// [`ExtractResourcePlugin`] only extracts the resource again when it changed in the main world.
+/// A large resource that changes rarely can still be made cheap to extract by keeping its data
+/// behind an [`Arc`](std::sync::Arc), so that extracting it only clones the `Arc`:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_render::extract_resource::ExtractResource;
+/// # use std::sync::Arc;
+/// # struct Triangle;
+/// #[derive(Resource)]
+/// struct NavMesh(Arc<Vec<Triangle>>);
+///
+/// impl ExtractResource for NavMesh {
+///     type Source = NavMesh;
+///
+///     fn extract_resource(source: &Self::Source) -> Self {
+///         NavMesh(Arc::clone(&source.0))
+///     }
+/// }
+/// ```
+///
+/// Deriving [`ExtractResource`] on such a resource along with [`Clone`] does the same.
 pub trait ExtractResource: Resource {
     type Source: Resource;
 
@@ -61,3 +84,99 @@ pub fn extract_resource<R: ExtractResource>(
         commands.insert_resource(R::extract_resource(&main_resource));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ExtractResource, ExtractResourcePlugin};
+    use crate::{apply_extract_commands, extract, RenderApp, ScratchMainWorld};
+    use bevy_app::{App, CoreSchedule, SubApp};
+    use bevy_ecs::prelude::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Counts how many times it was cloned.
+    #[derive(Resource, ExtractResource)]
+    struct Counted {
+        value: u32,
+        clones: Arc<AtomicUsize>,
+    }
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            self.clones.fetch_add(1, Ordering::Relaxed);
+            Self {
+                value: self.value,
+                clones: self.clones.clone(),
+            }
+        }
+    }
+
+    #[derive(Resource, Clone, ExtractResource)]
+    struct Shared(Arc<Counted>);
+
+    /// Creates an app whose render app only runs the extraction.
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<ScratchMainWorld>();
+
+        let mut render_app = App::empty();
+        render_app
+            .add_simple_outer_schedule()
+            .add_schedule(CoreSchedule::Main, Schedule::new());
+        app.insert_sub_app(
+            RenderApp,
+            SubApp::new(render_app, |main_world, render_app| {
+                extract(main_world, render_app);
+                apply_extract_commands(&mut render_app.world);
+            }),
+        );
+        app
+    }
+
+    fn counted(value: u32, clones: &Arc<AtomicUsize>) -> Counted {
+        Counted {
+            value,
+            clones: clones.clone(),
+        }
+    }
+
+    #[test]
+    fn resources_are_only_extracted_when_changed() {
+        let mut app = app();
+        app.add_plugin(ExtractResourcePlugin::<Counted>::default());
+        let clones = Arc::new(AtomicUsize::new(0));
+        app.insert_resource(counted(1, &clones));
+
+        app.update();
+        app.update();
+        app.update();
+        assert_eq!(clones.load(Ordering::Relaxed), 1);
+
+        app.world.resource_mut::<Counted>().value = 2;
+        app.update();
+        app.update();
+        assert_eq!(clones.load(Ordering::Relaxed), 2);
+        let render_world = &app.sub_app(RenderApp).world;
+        assert_eq!(render_world.resource::<Counted>().value, 2);
+    }
+
+    #[test]
+    fn arc_resources_are_extracted_without_cloning_their_data() {
+        let mut app = app();
+        app.add_plugin(ExtractResourcePlugin::<Shared>::default());
+        let clones = Arc::new(AtomicUsize::new(0));
+        app.insert_resource(Shared(Arc::new(counted(1, &clones))));
+
+        app.update();
+        app.world.resource_mut::<Shared>().set_changed();
+        app.update();
+        assert_eq!(clones.load(Ordering::Relaxed), 0);
+        let render_world = &app.sub_app(RenderApp).world;
+        assert!(Arc::ptr_eq(
+            &render_world.resource::<Shared>().0,
+            &app.world.resource::<Shared>().0
+        ));
+    }
+}
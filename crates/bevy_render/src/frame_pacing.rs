@@ -0,0 +1,215 @@
+//! Limits the frame rate by pacing the presentation of frames in the render world.
+
+use std::sync::{Arc, Mutex};
+
+use bevy_app::{App, Plugin};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, DiagnosticsStore};
+use bevy_ecs::prelude::*;
+use bevy_utils::{Duration, Instant};
+
+use crate::{
+    extract_resource::{ExtractResource, ExtractResourcePlugin},
+    renderer::render_system,
+    RenderApp, RenderSet,
+};
+
+/// Limits the frame rate to the target set in the [`FramePace`] resource.
+///
+/// After the frame has been presented, the render world waits until [`FramePace::target_frame_time`]
+/// has passed since the previous frame was presented. It sleeps for most of the remaining time and
+/// spins for the last [`FramePace::spin_threshold`], since sleeping alone can overshoot by a
+/// scheduler tick. Changes to [`FramePace`] take effect on the next frame.
+///
+/// The frame time achieved this way is recorded in the [`FramePacePlugin::ACHIEVED_FRAME_TIME`]
+/// diagnostic.
+///
+/// # Vsync
+///
+/// With a vsync [`PresentMode`](bevy_window::PresentMode), presenting already blocks until the
+/// next vertical blank, so a target shorter than the refresh interval has no effect. A target that
+/// is not a multiple of the refresh interval will alternate between two frame times.
+/// Use [`PresentMode::AutoNoVsync`](bevy_window::PresentMode::AutoNoVsync) to pace frames only
+/// with this plugin.
+#[derive(Default)]
+pub struct FramePacePlugin;
+
+impl FramePacePlugin {
+    /// The time between two consecutive presented frames, in milliseconds.
+    pub const ACHIEVED_FRAME_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("frame_pace/achieved_frame_time");
+}
+
+impl Plugin for FramePacePlugin {
+    fn build(&self, app: &mut App) {
+        let stats = FramePaceStats::default();
+        app.init_resource::<FramePace>()
+            .insert_resource(stats.clone())
+            .add_plugin(ExtractResourcePlugin::<FramePace>::default())
+            .add_startup_system(setup_frame_pace_diagnostic)
+            .add_system(frame_pace_diagnostic_system);
+
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .insert_resource(stats)
+                .init_resource::<FramePacer>()
+                .add_system(
+                    frame_pace_system
+                        .after(render_system)
+                        .in_set(RenderSet::Render),
+                );
+        }
+    }
+}
+
+/// Configures the frame rate limit of the [`FramePacePlugin`].
+#[derive(Resource, ExtractResource, Clone, Debug, PartialEq, Eq)]
+pub struct FramePace {
+    /// The target time between two presented frames, or `None` to not limit the frame rate.
+    pub target_frame_time: Option<Duration>,
+    /// How long before the end of the frame to stop sleeping and spin instead.
+    pub spin_threshold: Duration,
+}
+
+impl FramePace {
+    /// Limits the frame rate to the given number of frames per second.
+    pub fn from_fps(fps: f64) -> Self {
+        Self {
+            target_frame_time: Some(Duration::from_secs_f64(1.0 / fps)),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for FramePace {
+    fn default() -> Self {
+        Self {
+            target_frame_time: None,
+            spin_threshold: Duration::from_millis(2),
+        }
+    }
+}
+
+/// The latest achieved frame time, shared between the render world and the main world.
+#[derive(Resource, Clone, Default)]
+struct FramePaceStats(Arc<Mutex<Option<Duration>>>);
+
+/// The render world's record of when the previous frame finished.
+#[derive(Resource, Default)]
+struct FramePacer {
+    last_frame: Option<Instant>,
+}
+
+/// Returns the instant the current frame should end at, or `None` if it is already late.
+fn frame_deadline(
+    last_frame: Instant,
+    target_frame_time: Duration,
+    now: Instant,
+) -> Option<Instant> {
+    let deadline = last_frame + target_frame_time;
+    (deadline > now).then_some(deadline)
+}
+
+/// Returns how long to sleep before spinning until `deadline`.
+fn sleep_duration(deadline: Instant, now: Instant, spin_threshold: Duration) -> Duration {
+    deadline
+        .saturating_duration_since(now)
+        .saturating_sub(spin_threshold)
+}
+
+fn frame_pace_system(
+    frame_pace: Res<FramePace>,
+    mut pacer: ResMut<FramePacer>,
+    stats: Res<FramePaceStats>,
+) {
+    if let (Some(target_frame_time), Some(last_frame)) =
+        (frame_pace.target_frame_time, pacer.last_frame)
+    {
+        let now = Instant::now();
+        if let Some(deadline) = frame_deadline(last_frame, target_frame_time, now) {
+            let sleep = sleep_duration(deadline, now, frame_pace.spin_threshold);
+            if !sleep.is_zero() {
+                std::thread::sleep(sleep);
+            }
+            while Instant::now() < deadline {
+                std::hint::spin_loop();
+            }
+        }
+    }
+
+    let frame_end = Instant::now();
+    if let Some(last_frame) = pacer.last_frame {
+        *stats.0.lock().unwrap() = Some(frame_end - last_frame);
+    }
+    pacer.last_frame = Some(frame_end);
+}
+
+fn setup_frame_pace_diagnostic(mut diagnostics: ResMut<DiagnosticsStore>) {
+    diagnostics.add(Diagnostic::new(FramePacePlugin::ACHIEVED_FRAME_TIME, 20).with_suffix("ms"));
+}
+
+fn frame_pace_diagnostic_system(
+    mut diagnostics: ResMut<DiagnosticsStore>,
+    stats: Res<FramePaceStats>,
+) {
+    if let Some(frame_time) = stats.0.lock().unwrap().take() {
+        diagnostics.add_measurement(&FramePacePlugin::ACHIEVED_FRAME_TIME, || {
+            frame_time.as_secs_f64() * 1000.0
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{frame_deadline, sleep_duration};
+    use bevy_utils::{Duration, Instant};
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn deadline_is_one_target_after_the_last_frame() {
+        let last_frame = Instant::now();
+        let target = 16 * MS;
+
+        assert_eq!(
+            frame_deadline(last_frame, target, last_frame + 5 * MS),
+            Some(last_frame + target)
+        );
+        // a frame that took longer than the target isn't delayed any further
+        assert_eq!(
+            frame_deadline(last_frame, target, last_frame + target),
+            None
+        );
+        assert_eq!(
+            frame_deadline(last_frame, target, last_frame + 20 * MS),
+            None
+        );
+
+        // a target changed at runtime applies from the previous frame
+        assert_eq!(
+            frame_deadline(last_frame, 33 * MS, last_frame + 20 * MS),
+            Some(last_frame + 33 * MS)
+        );
+        assert_eq!(
+            frame_deadline(last_frame, 8 * MS, last_frame + 10 * MS),
+            None
+        );
+    }
+
+    #[test]
+    fn sleep_stops_short_of_the_deadline() {
+        let now = Instant::now();
+        let spin_threshold = 2 * MS;
+
+        assert_eq!(sleep_duration(now + 10 * MS, now, spin_threshold), 8 * MS);
+        // within the spin threshold, only spin
+        assert_eq!(
+            sleep_duration(now + MS, now, spin_threshold),
+            Duration::ZERO
+        );
+        assert_eq!(
+            sleep_duration(now, now + MS, spin_threshold),
+            Duration::ZERO
+        );
+        assert_eq!(sleep_duration(now + 10 * MS, now, Duration::ZERO), 10 * MS);
+    }
+}
@@ -2,14 +2,20 @@
 compile_error!("bevy_render cannot compile for a 16-bit platform.");
 
 extern crate core;
+// Lets the derive macros of this crate, like `AsBindGroup`, be used in its own tests.
+#[cfg(test)]
+extern crate self as bevy_render;
 
+pub mod batching;
 pub mod camera;
 pub mod color;
 pub mod extract_component;
 mod extract_param;
 pub mod extract_resource;
+pub mod frame_pacing;
 pub mod globals;
 pub mod mesh;
+pub mod picking;
 pub mod pipelined_rendering;
 pub mod primitives;
 pub mod render_asset;
@@ -30,7 +36,7 @@ pub mod prelude {
     pub use crate::{
         camera::{Camera, OrthographicProjection, PerspectiveProjection, Projection},
         color::Color,
-        mesh::{shape, Mesh},
+        mesh::{shape, Mesh, Meshable},
         render_resource::Shader,
         spatial_bundle::SpatialBundle,
         texture::{Image, ImagePlugin},
@@ -48,19 +54,26 @@ use crate::{
     mesh::MeshPlugin,
     render_resource::{PipelineCache, Shader, ShaderLoader},
     renderer::{render_system, RenderInstance},
-    settings::WgpuSettings,
+    settings::RenderCreation,
     view::{ViewPlugin, WindowRenderPlugin},
 };
 use bevy_app::{App, AppLabel, CoreSchedule, Plugin, SubApp};
 use bevy_asset::{AddAsset, AssetServer};
 use bevy_ecs::{prelude::*, schedule::ScheduleLabel, system::SystemState};
 use bevy_utils::tracing::debug;
-use std::ops::{Deref, DerefMut};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
 
 /// Contains the default Bevy rendering backend based on wgpu.
+///
+/// By default the plugin creates its own `wgpu` instance and device, configured with
+/// [`WgpuSettings`](settings::WgpuSettings). Use [`RenderCreation::Manual`] to render with a
+/// device created elsewhere.
 #[derive(Default)]
 pub struct RenderPlugin {
-    pub wgpu_settings: WgpuSettings,
+    pub render_creation: RenderCreation,
 }
 
 /// The labels of the default App rendering sets.
@@ -173,8 +186,19 @@ impl DerefMut for MainWorld {
 }
 
 pub mod main_graph {
+    use crate::render_graph::RenderLabel;
+
+    /// The labels of the nodes of the main render graph.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderLabel)]
+    pub enum NodeMain {
+        /// Runs the [`CameraRenderGraph`](crate::camera::CameraRenderGraph) of each active camera.
+        CameraDriver,
+    }
+
     pub mod node {
-        pub const CAMERA_DRIVER: &str = "camera_driver";
+        use super::NodeMain;
+
+        pub const CAMERA_DRIVER: NodeMain = NodeMain::CameraDriver;
     }
 }
 
@@ -190,34 +214,53 @@ impl Plugin for RenderPlugin {
             .init_asset_loader::<ShaderLoader>()
             .init_debug_asset_loader::<ShaderLoader>();
 
-        let mut system_state: SystemState<Query<&RawHandleWrapper, With<PrimaryWindow>>> =
-            SystemState::new(&mut app.world);
-        let primary_window = system_state.get(&app.world);
-
-        if let Some(backends) = self.wgpu_settings.backends {
-            let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-                backends,
-                dx12_shader_compiler: self.wgpu_settings.dx12_shader_compiler.clone(),
-            });
-            let surface = primary_window.get_single().ok().map(|wrapper| unsafe {
-                // SAFETY: Plugins should be set up on the main thread.
-                let handle = wrapper.get_handle();
-                instance
-                    .create_surface(&handle)
-                    .expect("Failed to create wgpu surface")
-            });
-
-            let request_adapter_options = wgpu::RequestAdapterOptions {
-                power_preference: self.wgpu_settings.power_preference,
-                compatible_surface: surface.as_ref(),
-                ..Default::default()
-            };
-            let (device, queue, adapter_info, render_adapter) =
-                futures_lite::future::block_on(renderer::initialize_renderer(
-                    &instance,
-                    &self.wgpu_settings,
-                    &request_adapter_options,
-                ));
+        let render_resources = match &self.render_creation {
+            RenderCreation::Manual(device, queue, adapter_info, adapter, instance) => Some((
+                device.clone(),
+                queue.clone(),
+                adapter_info.clone(),
+                adapter.clone(),
+                instance.clone(),
+            )),
+            RenderCreation::Automatic(wgpu_settings) => wgpu_settings.backends.map(|backends| {
+                let mut system_state: SystemState<Query<&RawHandleWrapper, With<PrimaryWindow>>> =
+                    SystemState::new(&mut app.world);
+                let primary_window = system_state.get(&app.world);
+
+                let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+                    backends,
+                    dx12_shader_compiler: wgpu_settings.dx12_shader_compiler.clone(),
+                });
+                let surface = primary_window.get_single().ok().map(|wrapper| unsafe {
+                    // SAFETY: Plugins should be set up on the main thread.
+                    let handle = wrapper.get_handle();
+                    instance
+                        .create_surface(&handle)
+                        .expect("Failed to create wgpu surface")
+                });
+
+                let request_adapter_options = wgpu::RequestAdapterOptions {
+                    power_preference: wgpu_settings.power_preference,
+                    compatible_surface: surface.as_ref(),
+                    ..Default::default()
+                };
+                let (device, queue, adapter_info, render_adapter) =
+                    futures_lite::future::block_on(renderer::initialize_renderer(
+                        &instance,
+                        wgpu_settings,
+                        &request_adapter_options,
+                    ));
+                (
+                    device,
+                    queue,
+                    adapter_info,
+                    render_adapter,
+                    RenderInstance(Arc::new(instance)),
+                )
+            }),
+        };
+
+        if let Some((device, queue, adapter_info, render_adapter, instance)) = render_resources {
             debug!("Configured wgpu adapter Limits: {:#?}", device.limits());
             debug!("Configured wgpu adapter Features: {:#?}", device.features());
             app.insert_resource(device.clone())
@@ -251,12 +294,13 @@ impl Plugin for RenderPlugin {
             );
             render_schedule.add_system(render_system.in_set(RenderSet::Render));
 
-            render_schedule.add_system(World::clear_entities.in_set(RenderSet::Cleanup));
+            // The render world doesn't keep any entity from a frame to the next
+            render_schedule.add_system(World::clear_entities_untracked.in_set(RenderSet::Cleanup));
 
             render_app
                 .add_schedule(CoreSchedule::Main, render_schedule)
                 .init_resource::<render_graph::RenderGraph>()
-                .insert_resource(RenderInstance(instance))
+                .insert_resource(instance)
                 .insert_resource(device)
                 .insert_resource(queue)
                 .insert_resource(render_adapter)
@@ -348,3 +392,66 @@ fn apply_extract_commands(render_world: &mut World) {
             .apply_system_buffers(render_world);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        renderer::{RenderAdapter, RenderAdapterInfo, RenderDevice, RenderInstance, RenderQueue},
+        settings::RenderCreation,
+        texture::ImagePlugin,
+        RenderApp, RenderPlugin,
+    };
+    use bevy_app::App;
+    use bevy_asset::AssetPlugin;
+    use bevy_core::{FrameCountPlugin, TaskPoolPlugin};
+    use bevy_time::TimePlugin;
+    use bevy_window::WindowPlugin;
+    use futures_lite::future::block_on;
+    use std::sync::Arc;
+
+    #[test]
+    fn manual_render_creation_renders_a_frame() {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let Some(adapter) =
+            block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        else {
+            // There is no adapter to render with in this environment, not even a software one.
+            return;
+        };
+        let (device, queue) =
+            block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None)).unwrap();
+        let adapter_info = adapter.get_info();
+        let device = RenderDevice::from(device);
+
+        let mut app = App::new();
+        app.add_plugin(TaskPoolPlugin::default())
+            .add_plugin(FrameCountPlugin)
+            .add_plugin(TimePlugin)
+            .add_plugin(WindowPlugin {
+                primary_window: None,
+                ..Default::default()
+            })
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(RenderPlugin {
+                render_creation: RenderCreation::Manual(
+                    device.clone(),
+                    RenderQueue(Arc::new(queue)),
+                    RenderAdapterInfo(adapter_info.clone()),
+                    RenderAdapter(Arc::new(adapter)),
+                    RenderInstance(Arc::new(instance)),
+                ),
+            })
+            .add_plugin(ImagePlugin::default());
+        app.update();
+
+        let render_world = &app.sub_app(RenderApp).world;
+        assert_eq!(
+            render_world.resource::<RenderAdapterInfo>().name,
+            adapter_info.name
+        );
+        assert_eq!(
+            render_world.resource::<RenderDevice>().limits(),
+            device.limits()
+        );
+    }
+}
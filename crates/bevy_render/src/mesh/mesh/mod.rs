@@ -4,27 +4,31 @@ pub use wgpu::PrimitiveTopology;
 
 use crate::{
     primitives::Aabb,
-    render_asset::{PrepareAssetError, RenderAsset},
+    render_asset::{PrepareAssetError, RenderAsset, RenderAssetUsages},
     render_resource::{Buffer, VertexBufferLayout},
-    renderer::RenderDevice,
+    renderer::{RenderDevice, RenderQueue},
 };
 use bevy_core::cast_slice;
 use bevy_derive::EnumVariantMeta;
 use bevy_ecs::system::{lifetimeless::SRes, SystemParamItem};
 use bevy_math::*;
 use bevy_reflect::TypeUuid;
+use bevy_transform::components::Transform;
 use bevy_utils::{tracing::error, Hashed};
-use std::{collections::BTreeMap, hash::Hash, iter::FusedIterator};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    hash::Hash,
+    iter::FusedIterator,
+};
 use thiserror::Error;
 use wgpu::{
-    util::BufferInitDescriptor, BufferUsages, IndexFormat, VertexAttribute, VertexFormat,
-    VertexStepMode,
+    util::BufferInitDescriptor, BufferAddress, BufferUsages, IndexFormat, VertexAttribute,
+    VertexFormat, VertexStepMode,
 };
 
 pub const INDEX_BUFFER_ASSET_INDEX: u64 = 0;
 pub const VERTEX_ATTRIBUTE_BUFFER_ID: u64 = 10;
 
-// TODO: allow values to be unloaded after been submitting to the GPU to conserve memory
 #[derive(Debug, TypeUuid, Clone)]
 #[uuid = "8ecbac0f-f545-4473-ad43-e1f4243af51e"]
 pub struct Mesh {
@@ -35,6 +39,12 @@ pub struct Mesh {
     /// which allows easy stable VertexBuffers (i.e. same buffer order)
     attributes: BTreeMap<MeshVertexAttributeId, MeshAttributeData>,
     indices: Option<Indices>,
+    asset_usage: RenderAssetUsages,
+    /// The attributes modified since the mesh was last extracted, whose vertex buffer is written
+    /// again in place if its layout and size didn't change.
+    changed_attributes: BTreeSet<MeshVertexAttributeId>,
+    /// Whether the indices were modified since the mesh was last extracted.
+    indices_changed: bool,
 }
 
 /// Contains geometry in the form of a mesh.
@@ -91,6 +101,9 @@ impl Mesh {
             primitive_topology,
             attributes: Default::default(),
             indices: None,
+            asset_usage: RenderAssetUsages::default(),
+            changed_attributes: BTreeSet::new(),
+            indices_changed: false,
         }
     }
 
@@ -99,6 +112,28 @@ impl Mesh {
         self.primitive_topology
     }
 
+    /// Returns whether the mesh is used in the main world, the render world, or both.
+    pub fn asset_usage(&self) -> RenderAssetUsages {
+        self.asset_usage
+    }
+
+    /// Sets whether the mesh is used in the main world, the render world, or both.
+    ///
+    /// The attributes and indices of a mesh used only in the render world are removed once it is
+    /// extracted, after which [`Mesh::attribute`] and [`Mesh::indices`] return `None`.
+    pub fn set_asset_usage(&mut self, asset_usage: RenderAssetUsages) {
+        self.asset_usage = asset_usage;
+    }
+
+    /// Consumes the mesh and returns it with the given [`RenderAssetUsages`].
+    ///
+    /// See [`Mesh::set_asset_usage`].
+    #[must_use]
+    pub fn with_asset_usage(mut self, asset_usage: RenderAssetUsages) -> Self {
+        self.set_asset_usage(asset_usage);
+        self
+    }
+
     /// Sets the data for a vertex attribute (position, normal etc.). The name will
     /// often be one of the associated constants such as [`Mesh::ATTRIBUTE_POSITION`].
     ///
@@ -119,6 +154,7 @@ impl Mesh {
             );
         }
 
+        self.changed_attributes.insert(attribute.id);
         self.attributes
             .insert(attribute.id, MeshAttributeData { attribute, values });
     }
@@ -148,14 +184,20 @@ impl Mesh {
     }
 
     /// Retrieves the data currently set to the vertex attribute with the specified `name` mutably.
+    ///
+    /// The attribute is marked as changed, so that the vertex buffer of the mesh is written again
+    /// in place once extracted rather than created again.
     #[inline]
     pub fn attribute_mut(
         &mut self,
         id: impl Into<MeshVertexAttributeId>,
     ) -> Option<&mut VertexAttributeValues> {
-        self.attributes
-            .get_mut(&id.into())
-            .map(|data| &mut data.values)
+        let id = id.into();
+        let values = self.attributes.get_mut(&id).map(|data| &mut data.values);
+        if values.is_some() {
+            self.changed_attributes.insert(id);
+        }
+        values
     }
 
     /// Returns an iterator that yields references to the data of each vertex attribute.
@@ -169,6 +211,7 @@ impl Mesh {
     pub fn attributes_mut(
         &mut self,
     ) -> impl Iterator<Item = (MeshVertexAttributeId, &mut VertexAttributeValues)> {
+        self.changed_attributes.extend(self.attributes.keys());
         self.attributes
             .iter_mut()
             .map(|(id, data)| (*id, &mut data.values))
@@ -180,6 +223,7 @@ impl Mesh {
     #[inline]
     pub fn set_indices(&mut self, indices: Option<Indices>) {
         self.indices = indices;
+        self.indices_changed = true;
     }
 
     /// Retrieves the vertex `indices` of the mesh.
@@ -191,6 +235,7 @@ impl Mesh {
     /// Retrieves the vertex `indices` of the mesh mutably.
     #[inline]
     pub fn indices_mut(&mut self) -> Option<&mut Indices> {
+        self.indices_changed |= self.indices.is_some();
         self.indices.as_mut()
     }
 
@@ -296,6 +341,8 @@ impl Mesh {
             Some(indices) => indices,
             None => return,
         };
+        self.indices_changed = true;
+        self.changed_attributes.extend(self.attributes.keys());
 
         for attributes in self.attributes.values_mut() {
             let indices = indices.iter();
@@ -361,6 +408,147 @@ impl Mesh {
         self.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     }
 
+    /// Consumes the mesh and returns a mesh with no shared vertices.
+    ///
+    /// All vertex attributes are kept. See [`Mesh::duplicate_vertices`].
+    #[must_use]
+    pub fn with_duplicated_vertices(mut self) -> Self {
+        self.duplicate_vertices();
+        self
+    }
+
+    /// Consumes the mesh and returns a mesh with flat normals.
+    ///
+    /// All other vertex attributes are kept. See [`Mesh::compute_flat_normals`].
+    ///
+    /// # Panics
+    /// Panics under the same conditions as [`Mesh::compute_flat_normals`].
+    #[must_use]
+    pub fn with_computed_flat_normals(mut self) -> Self {
+        self.compute_flat_normals();
+        self
+    }
+
+    /// Merges the vertices and indices of `other` into this mesh.
+    ///
+    /// Both meshes must have the same set of vertex attributes with the same formats, and the
+    /// same [`PrimitiveTopology`]. Strip topologies can't be merged, as the strips would be joined.
+    /// The indices of `other` are offset by the vertex count of this mesh. If only one of the
+    /// meshes is indexed, sequential indices are generated for the other one.
+    pub fn merge(&mut self, other: &Mesh) -> Result<(), MergeMeshError> {
+        if self.primitive_topology != other.primitive_topology
+            || matches!(
+                self.primitive_topology,
+                PrimitiveTopology::LineStrip | PrimitiveTopology::TriangleStrip
+            )
+        {
+            return Err(MergeMeshError::IncompatiblePrimitiveTopology {
+                self_topology: self.primitive_topology,
+                other_topology: other.primitive_topology,
+            });
+        }
+
+        let formats_match = self.attributes.len() == other.attributes.len()
+            && self.attributes.iter().all(|(id, data)| {
+                other
+                    .attributes
+                    .get(id)
+                    .map(|other_data| VertexFormat::from(&other_data.values))
+                    == Some(VertexFormat::from(&data.values))
+            });
+        if !formats_match {
+            return Err(MergeMeshError::IncompatibleVertexAttributes);
+        }
+
+        let vertex_count = self.count_vertices();
+        let other_vertex_count = other.count_vertices();
+
+        let indices = match (self.indices.take(), other.indices()) {
+            (None, None) => None,
+            (indices, other_indices) => {
+                let use_u32 = matches!(indices, Some(Indices::U32(_)))
+                    || matches!(other_indices, Some(Indices::U32(_)))
+                    || vertex_count + other_vertex_count > u16::MAX as usize + 1;
+                let mut merged: Vec<usize> = match &indices {
+                    Some(indices) => indices.iter().collect(),
+                    None => (0..vertex_count).collect(),
+                };
+                match other_indices {
+                    Some(other_indices) => {
+                        merged.extend(other_indices.iter().map(|i| i + vertex_count));
+                    }
+                    None => merged.extend(vertex_count..vertex_count + other_vertex_count),
+                }
+                Some(if use_u32 {
+                    Indices::U32(merged.into_iter().map(|i| i as u32).collect())
+                } else {
+                    Indices::U16(merged.into_iter().map(|i| i as u16).collect())
+                })
+            }
+        };
+
+        for (id, data) in &mut self.attributes {
+            data.values.extend_from(&other.attributes[id].values);
+        }
+        self.changed_attributes.extend(self.attributes.keys());
+        self.set_indices(indices);
+
+        Ok(())
+    }
+
+    /// Consumes the mesh and returns it transformed by `transform`.
+    ///
+    /// See [`Mesh::transform_by`].
+    #[must_use]
+    pub fn transformed_by(mut self, transform: Transform) -> Self {
+        self.transform_by(transform);
+        self
+    }
+
+    /// Applies `transform` to the [`Mesh::ATTRIBUTE_POSITION`], [`Mesh::ATTRIBUTE_NORMAL`] and
+    /// [`Mesh::ATTRIBUTE_TANGENT`] of the mesh.
+    ///
+    /// Normals are transformed by the inverse transpose of the transform, so they stay
+    /// perpendicular to the surface under non-uniform scale. If the scale mirrors the mesh,
+    /// the handedness of the tangents is flipped, but note that the winding order of the
+    /// triangles is not changed.
+    pub fn transform_by(&mut self, transform: Transform) {
+        // Needed when transforming normals and tangents
+        let scale_recip = 1. / transform.scale;
+        let mirrored = transform.scale.x * transform.scale.y * transform.scale.z < 0.;
+
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            for position in positions.iter_mut() {
+                *position = transform.transform_point(Vec3::from_slice(position)).into();
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            for normal in normals.iter_mut() {
+                *normal = (transform.rotation * (Vec3::from_slice(normal) * scale_recip))
+                    .normalize_or_zero()
+                    .into();
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            for tangent in tangents.iter_mut() {
+                let handedness = if mirrored { -tangent[3] } else { tangent[3] };
+                *tangent = (transform.rotation
+                    * (Vec3::from_slice(&tangent[..3]) * transform.scale))
+                    .normalize_or_zero()
+                    .extend(handedness)
+                    .into();
+            }
+        }
+    }
+
     /// Generate tangents for the mesh using the `mikktspace` algorithm.
     ///
     /// Sets the [`Mesh::ATTRIBUTE_TANGENT`] attribute if successful.
@@ -376,21 +564,7 @@ impl Mesh {
         if let Some(VertexAttributeValues::Float32x3(values)) =
             self.attribute(Mesh::ATTRIBUTE_POSITION)
         {
-            let mut minimum = VEC3_MAX;
-            let mut maximum = VEC3_MIN;
-            for p in values {
-                minimum = minimum.min(Vec3::from_slice(p));
-                maximum = maximum.max(Vec3::from_slice(p));
-            }
-            if minimum.x != std::f32::MAX
-                && minimum.y != std::f32::MAX
-                && minimum.z != std::f32::MAX
-                && maximum.x != std::f32::MIN
-                && maximum.y != std::f32::MIN
-                && maximum.z != std::f32::MIN
-            {
-                return Some(Aabb::from_min_max(minimum, maximum));
-            }
+            return Aabb::from_points(values.iter().map(|p| Vec3::from_slice(p)));
         }
 
         None
@@ -522,9 +696,6 @@ struct MeshAttributeData {
     values: VertexAttributeValues,
 }
 
-const VEC3_MIN: Vec3 = Vec3::splat(std::f32::MIN);
-const VEC3_MAX: Vec3 = Vec3::splat(std::f32::MAX);
-
 fn face_normal(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> [f32; 3] {
     let (a, b, c) = (Vec3::from(a), Vec3::from(b), Vec3::from(c));
     (b - a).cross(c - a).normalize().into()
@@ -652,6 +823,101 @@ impl VertexAttributeValues {
         self.len() == 0
     }
 
+    /// Appends the values of `other`, which must have the same [`VertexFormat`].
+    fn extend_from(&mut self, other: &VertexAttributeValues) {
+        match (self, other) {
+            (VertexAttributeValues::Float32(values), VertexAttributeValues::Float32(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint32(values), VertexAttributeValues::Sint32(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint32(values), VertexAttributeValues::Uint32(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Float32x2(values), VertexAttributeValues::Float32x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint32x2(values), VertexAttributeValues::Sint32x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint32x2(values), VertexAttributeValues::Uint32x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Float32x3(values), VertexAttributeValues::Float32x3(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint32x3(values), VertexAttributeValues::Sint32x3(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint32x3(values), VertexAttributeValues::Uint32x3(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Float32x4(values), VertexAttributeValues::Float32x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint32x4(values), VertexAttributeValues::Sint32x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint32x4(values), VertexAttributeValues::Uint32x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint16x2(values), VertexAttributeValues::Sint16x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Snorm16x2(values), VertexAttributeValues::Snorm16x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint16x2(values), VertexAttributeValues::Uint16x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Unorm16x2(values), VertexAttributeValues::Unorm16x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint16x4(values), VertexAttributeValues::Sint16x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Snorm16x4(values), VertexAttributeValues::Snorm16x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint16x4(values), VertexAttributeValues::Uint16x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Unorm16x4(values), VertexAttributeValues::Unorm16x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint8x2(values), VertexAttributeValues::Sint8x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Snorm8x2(values), VertexAttributeValues::Snorm8x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint8x2(values), VertexAttributeValues::Uint8x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Unorm8x2(values), VertexAttributeValues::Unorm8x2(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Sint8x4(values), VertexAttributeValues::Sint8x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Snorm8x4(values), VertexAttributeValues::Snorm8x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Uint8x4(values), VertexAttributeValues::Uint8x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (VertexAttributeValues::Unorm8x4(values), VertexAttributeValues::Unorm8x4(other)) => {
+                values.extend_from_slice(other);
+            }
+            (values, other) => panic!(
+                "Cannot extend vertex attribute values of format {:?} with values of format {:?}",
+                VertexFormat::from(&*values),
+                VertexFormat::from(other)
+            ),
+        }
+    }
+
     /// Returns the values as float triples if possible.
     pub fn as_float3(&self) -> Option<&[[f32; 3]]> {
         match self {
@@ -828,42 +1094,75 @@ pub enum GpuBufferInfo {
     },
 }
 
+impl GpuBufferInfo {
+    /// Creates the index buffer of `mesh` if it has indices.
+    fn new(mesh: &Mesh, render_device: &RenderDevice) -> Self {
+        mesh.get_index_buffer_bytes().map_or(
+            GpuBufferInfo::NonIndexed {
+                vertex_count: mesh.count_vertices() as u32,
+            },
+            |data| GpuBufferInfo::Indexed {
+                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+                    usage: BufferUsages::INDEX | BufferUsages::COPY_DST,
+                    contents: data,
+                    label: Some("Mesh Index Buffer"),
+                }),
+                count: mesh.indices().unwrap().len() as u32,
+                index_format: mesh.indices().unwrap().into(),
+            },
+        )
+    }
+}
+
 impl RenderAsset for Mesh {
     type ExtractedAsset = Mesh;
     type PreparedAsset = GpuMesh;
-    type Param = SRes<RenderDevice>;
+    type Param = (SRes<RenderDevice>, SRes<RenderQueue>);
 
     /// Clones the mesh.
     fn extract_asset(&self) -> Self::ExtractedAsset {
         self.clone()
     }
 
+    fn asset_usage(&self) -> RenderAssetUsages {
+        self.asset_usage
+    }
+
+    /// Moves the attributes and indices out of the mesh.
+    fn take_asset(&mut self) -> Self::ExtractedAsset {
+        Mesh {
+            primitive_topology: self.primitive_topology,
+            attributes: std::mem::take(&mut self.attributes),
+            indices: self.indices.take(),
+            asset_usage: self.asset_usage,
+            changed_attributes: std::mem::take(&mut self.changed_attributes),
+            indices_changed: std::mem::take(&mut self.indices_changed),
+        }
+    }
+
+    /// The attributes of the mesh were moved out by [`RenderAsset::take_asset`].
+    fn is_unloaded(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    fn clear_dirty(&mut self) {
+        self.changed_attributes.clear();
+        self.indices_changed = false;
+    }
+
     /// Converts the extracted mesh a into [`GpuMesh`].
     fn prepare_asset(
         mesh: Self::ExtractedAsset,
-        render_device: &mut SystemParamItem<Self::Param>,
+        (render_device, _): &mut SystemParamItem<Self::Param>,
     ) -> Result<Self::PreparedAsset, PrepareAssetError<Self::ExtractedAsset>> {
         let vertex_buffer_data = mesh.get_vertex_buffer_data();
         let vertex_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
-            usage: BufferUsages::VERTEX,
+            usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
             label: Some("Mesh Vertex Buffer"),
             contents: &vertex_buffer_data,
         });
 
-        let buffer_info = mesh.get_index_buffer_bytes().map_or(
-            GpuBufferInfo::NonIndexed {
-                vertex_count: mesh.count_vertices() as u32,
-            },
-            |data| GpuBufferInfo::Indexed {
-                buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
-                    usage: BufferUsages::INDEX,
-                    contents: data,
-                    label: Some("Mesh Index Buffer"),
-                }),
-                count: mesh.indices().unwrap().len() as u32,
-                index_format: mesh.indices().unwrap().into(),
-            },
-        );
+        let buffer_info = GpuBufferInfo::new(&mesh, render_device);
 
         let mesh_vertex_buffer_layout = mesh.get_mesh_vertex_buffer_layout();
 
@@ -874,6 +1173,54 @@ impl RenderAsset for Mesh {
             layout: mesh_vertex_buffer_layout,
         })
     }
+
+    /// Writes the vertex and index buffers of the [`GpuMesh`] again if their attributes or indices
+    /// changed, as long as their layout and size stay the same. Otherwise the buffers are created
+    /// again.
+    fn update_asset(
+        mesh: Self::ExtractedAsset,
+        gpu_mesh: &mut Self::PreparedAsset,
+        param: &mut SystemParamItem<Self::Param>,
+    ) -> Result<(), PrepareAssetError<Self::ExtractedAsset>> {
+        let layout = mesh.get_mesh_vertex_buffer_layout();
+        let vertex_buffer_size =
+            (layout.layout().array_stride as usize * mesh.count_vertices()) as BufferAddress;
+        // The buffers are padded to `COPY_BUFFER_ALIGNMENT` when created, so data of the same size
+        // can always be written to them
+        if mesh.primitive_topology() != gpu_mesh.primitive_topology
+            || layout != gpu_mesh.layout
+            || vertex_buffer_size != gpu_mesh.vertex_buffer.size()
+        {
+            *gpu_mesh = Self::prepare_asset(mesh, param)?;
+            return Ok(());
+        }
+
+        let (render_device, render_queue) = param;
+        if !mesh.changed_attributes.is_empty() {
+            render_queue.write_buffer(&gpu_mesh.vertex_buffer, 0, &mesh.get_vertex_buffer_data());
+        }
+
+        let indexed = matches!(gpu_mesh.buffer_info, GpuBufferInfo::Indexed { .. });
+        if mesh.indices_changed || indexed != mesh.indices().is_some() {
+            match (&mut gpu_mesh.buffer_info, mesh.get_index_buffer_bytes()) {
+                (
+                    GpuBufferInfo::Indexed {
+                        buffer,
+                        count,
+                        index_format,
+                    },
+                    Some(data),
+                ) if buffer.size() == data.len() as BufferAddress => {
+                    render_queue.write_buffer(buffer, 0, data);
+                    let indices = mesh.indices().unwrap();
+                    *count = indices.len() as u32;
+                    *index_format = indices.into();
+                }
+                (buffer_info, _) => *buffer_info = GpuBufferInfo::new(&mesh, render_device),
+            }
+        }
+        Ok(())
+    }
 }
 
 struct MikktspaceGeometryHelper<'a> {
@@ -922,6 +1269,18 @@ impl bevy_mikktspace::Geometry for MikktspaceGeometryHelper<'_> {
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+/// Failed to merge two meshes with [`Mesh::merge`].
+pub enum MergeMeshError {
+    #[error("cannot merge a mesh with {other_topology:?} topology into a mesh with {self_topology:?} topology")]
+    IncompatiblePrimitiveTopology {
+        self_topology: PrimitiveTopology,
+        other_topology: PrimitiveTopology,
+    },
+    #[error("cannot merge meshes with different vertex attributes")]
+    IncompatibleVertexAttributes,
+}
+
 #[derive(thiserror::Error, Debug)]
 /// Failed to generate tangents for the mesh.
 pub enum GenerateTangentsError {
@@ -1004,9 +1363,183 @@ fn generate_tangents_for_mesh(mesh: &Mesh) -> Result<Vec<[f32; 4]>, GenerateTang
 
 #[cfg(test)]
 mod tests {
-    use super::Mesh;
+    use super::{Indices, MergeMeshError, Mesh, VertexAttributeValues};
+    use crate::render_asset::{RenderAsset, RenderAssetUsages};
+    use bevy_math::{Quat, Vec3};
+    use bevy_transform::components::Transform;
     use wgpu::PrimitiveTopology;
 
+    fn triangle(offset: f32) -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[offset, 0., 0.], [offset + 1., 0., 0.], [offset, 1., 0.]],
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0., 0., 1.]; 3]);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0., 0.], [1., 0.], [0., 1.]]);
+        mesh
+    }
+
+    fn quad() -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0., 0., 1.]; 4]);
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_UV_0,
+            vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+        );
+        mesh.set_indices(Some(Indices::U16(vec![0, 1, 2, 0, 2, 3])));
+        mesh
+    }
+
+    fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+            .unwrap()
+            .as_float3()
+            .unwrap()
+    }
+
+    #[test]
+    fn taking_a_mesh_unloads_its_data() {
+        let mut mesh = quad().with_asset_usage(RenderAssetUsages::RENDER_WORLD);
+        let extracted = mesh.take_asset();
+
+        assert_eq!(extracted.count_vertices(), 4);
+        assert_eq!(extracted.indices().map(Indices::len), Some(6));
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_POSITION).is_none());
+        assert!(mesh.indices().is_none());
+        assert_eq!(mesh.primitive_topology(), extracted.primitive_topology());
+        assert_eq!(mesh.asset_usage(), RenderAssetUsages::RENDER_WORLD);
+    }
+
+    #[test]
+    fn mutations_mark_what_changed() {
+        let mut mesh = quad();
+        assert!(mesh.indices_changed);
+        assert_eq!(mesh.changed_attributes.len(), 3);
+
+        mesh.clear_dirty();
+        mesh.attribute(Mesh::ATTRIBUTE_UV_0).unwrap();
+        mesh.indices().unwrap();
+        assert!(mesh.changed_attributes.is_empty());
+        assert!(!mesh.indices_changed);
+
+        mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        assert!(mesh.attribute_mut(Mesh::ATTRIBUTE_TANGENT).is_none());
+        assert_eq!(
+            mesh.changed_attributes.iter().collect::<Vec<_>>(),
+            [&Mesh::ATTRIBUTE_NORMAL.id]
+        );
+        assert!(!mesh.indices_changed);
+
+        mesh.indices_mut().unwrap();
+        assert!(mesh.indices_changed);
+    }
+
+    #[test]
+    fn merge_rebases_indices() {
+        let mut mesh = quad();
+        mesh.merge(&quad()).unwrap();
+
+        assert_eq!(mesh.count_vertices(), 8);
+        let Some(Indices::U16(indices)) = mesh.indices() else { panic!("expected u16 indices") };
+        assert_eq!(indices, &[0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7]);
+    }
+
+    #[test]
+    fn merge_generates_indices_for_unindexed_mesh() {
+        let mut mesh = quad();
+        mesh.merge(&triangle(2.)).unwrap();
+
+        assert_eq!(mesh.count_vertices(), 7);
+        assert_eq!(
+            mesh.indices().unwrap().iter().collect::<Vec<_>>(),
+            [0, 1, 2, 0, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(positions(&mesh)[4], [2., 0., 0.]);
+
+        let mut unindexed = triangle(0.);
+        unindexed.merge(&triangle(2.)).unwrap();
+        assert_eq!(unindexed.count_vertices(), 6);
+        assert!(unindexed.indices().is_none());
+    }
+
+    #[test]
+    fn merge_rejects_incompatible_meshes() {
+        let mut mesh = triangle(0.);
+        let mut other = triangle(1.);
+        other.remove_attribute(Mesh::ATTRIBUTE_UV_0);
+        assert!(matches!(
+            mesh.merge(&other),
+            Err(MergeMeshError::IncompatibleVertexAttributes)
+        ));
+
+        let mut lines = Mesh::new(PrimitiveTopology::LineList);
+        lines.insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0., 0., 0.], [1., 0., 0.]]);
+        assert!(matches!(
+            mesh.merge(&lines),
+            Err(MergeMeshError::IncompatiblePrimitiveTopology { .. })
+        ));
+
+        // The failed merges left the mesh untouched
+        assert_eq!(mesh.count_vertices(), 3);
+    }
+
+    #[test]
+    fn transform_keeps_normals_perpendicular_under_non_uniform_scale() {
+        // A sloped triangle, so a non-uniform scale changes the direction of the normal
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0., 0., 0.], [0., 0., 1.], [1., -1., 0.]],
+        );
+        mesh.compute_flat_normals();
+
+        let transform = Transform::from_rotation(Quat::from_rotation_y(0.5))
+            .with_scale(Vec3::new(3., 1., 0.5))
+            .with_translation(Vec3::new(1., 2., 3.));
+        let mesh = mesh.transformed_by(transform);
+
+        let [a, b, c] = [0, 1, 2].map(|i| Vec3::from_slice(&positions(&mesh)[i]));
+        let expected = (b - a).cross(c - a).normalize();
+        let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL) else { panic!("missing normals") };
+        for normal in normals {
+            assert!(Vec3::from_slice(normal).abs_diff_eq(expected, 1e-5));
+        }
+    }
+
+    #[test]
+    fn transform_flips_tangent_handedness_when_mirrored() {
+        let mut mesh = triangle(0.);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, vec![[1., 0., 0., 1.]; 3]);
+        let mesh = mesh.transformed_by(Transform::from_scale(Vec3::new(-2., 1., 1.)));
+
+        let Some(VertexAttributeValues::Float32x4(tangents)) =
+            mesh.attribute(Mesh::ATTRIBUTE_TANGENT) else { panic!("missing tangents") };
+        assert_eq!(tangents[0], [-1., 0., 0., -1.]);
+        assert_eq!(positions(&mesh)[1], [-2., 0., 0.]);
+    }
+
+    #[test]
+    fn flat_normals_keep_other_attributes() {
+        let mut mesh = quad();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, vec![[1., 0., 0., 1.]; 4]);
+        let mesh = mesh.with_duplicated_vertices().with_computed_flat_normals();
+
+        assert_eq!(mesh.count_vertices(), 6);
+        assert!(mesh.indices().is_none());
+        for attribute in [Mesh::ATTRIBUTE_UV_0, Mesh::ATTRIBUTE_COLOR] {
+            assert_eq!(mesh.attribute(attribute).unwrap().len(), 6);
+        }
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+            else { panic!("missing uvs") };
+        assert_eq!(uvs[5], [0., 1.]);
+    }
+
     #[test]
     #[should_panic]
     fn panic_invalid_format() {
@@ -1,9 +1,11 @@
 #[allow(clippy::module_inception)]
 mod mesh;
+mod primitives;
 /// Generation for some primitive shape meshes.
 pub mod shape;
 
 pub use mesh::*;
+pub use primitives::*;
 
 use crate::render_asset::RenderAssetPlugin;
 use bevy_app::{App, Plugin};
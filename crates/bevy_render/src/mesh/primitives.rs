@@ -0,0 +1,616 @@
+//! Mesh generation for the [primitive shapes](bevy_math::primitives) of `bevy_math`.
+
+use super::{
+    shape::{self, FromIcosphereError},
+    Indices, Mesh,
+};
+use bevy_math::primitives::{Capsule2d, Circle, Cuboid, Cylinder, Rectangle, Sphere, Torus};
+use std::f32::consts::PI;
+use wgpu::PrimitiveTopology;
+
+/// A trait for shapes that can be turned into a [`Mesh`].
+pub trait Meshable {
+    /// The output of [`Self::mesh`]. This can either be a [`Mesh`]
+    /// or a builder used for creating a [`Mesh`].
+    type Output;
+
+    /// Creates a [`Mesh`] for a shape.
+    fn mesh(&self) -> Self::Output;
+}
+
+/// A builder used for creating a [`Mesh`] with a [`Circle`] shape, in the `XY` plane.
+#[derive(Clone, Copy, Debug)]
+pub struct CircleMeshBuilder {
+    /// The [`Circle`] shape.
+    pub circle: Circle,
+    /// The number of vertices used for the circle mesh.
+    /// The default is `32`.
+    pub resolution: usize,
+}
+
+impl Default for CircleMeshBuilder {
+    fn default() -> Self {
+        Self {
+            circle: Circle::default(),
+            resolution: 32,
+        }
+    }
+}
+
+impl CircleMeshBuilder {
+    /// Creates a new [`CircleMeshBuilder`] from a given radius and vertex count.
+    #[inline]
+    pub const fn new(radius: f32, resolution: usize) -> Self {
+        Self {
+            circle: Circle { radius },
+            resolution,
+        }
+    }
+
+    /// Sets the number of vertices used for the circle mesh.
+    #[inline]
+    pub const fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Builds a [`Mesh`] based on the configuration in `self`.
+    pub fn build(&self) -> Mesh {
+        shape::Circle {
+            radius: self.circle.radius,
+            vertices: self.resolution,
+        }
+        .into()
+    }
+}
+
+impl Meshable for Circle {
+    type Output = CircleMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        CircleMeshBuilder {
+            circle: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<CircleMeshBuilder> for Mesh {
+    fn from(circle: CircleMeshBuilder) -> Self {
+        circle.build()
+    }
+}
+
+impl From<Circle> for Mesh {
+    fn from(circle: Circle) -> Self {
+        circle.mesh().build()
+    }
+}
+
+impl Meshable for Rectangle {
+    type Output = Mesh;
+
+    fn mesh(&self) -> Self::Output {
+        shape::Quad::new(self.size()).into()
+    }
+}
+
+impl From<Rectangle> for Mesh {
+    fn from(rectangle: Rectangle) -> Self {
+        rectangle.mesh()
+    }
+}
+
+/// A builder used for creating a [`Mesh`] with a [`Capsule2d`] shape, in the `XY` plane.
+#[derive(Clone, Copy, Debug)]
+pub struct Capsule2dMeshBuilder {
+    /// The [`Capsule2d`] shape.
+    pub capsule: Capsule2d,
+    /// The number of vertices used for each semicircle of the capsule.
+    /// The default is `16`, and values below `2` are treated as `2`.
+    pub resolution: usize,
+}
+
+impl Default for Capsule2dMeshBuilder {
+    fn default() -> Self {
+        Self {
+            capsule: Capsule2d::default(),
+            resolution: 16,
+        }
+    }
+}
+
+impl Capsule2dMeshBuilder {
+    /// Creates a new [`Capsule2dMeshBuilder`] from a given radius, length, and the number of
+    /// vertices used for each semicircle.
+    /// The length is the distance between the centers of the semicircles.
+    #[inline]
+    pub fn new(radius: f32, length: f32, resolution: usize) -> Self {
+        Self {
+            capsule: Capsule2d::new(radius, length),
+            resolution,
+        }
+    }
+
+    /// Sets the number of vertices used for each semicircle of the capsule.
+    #[inline]
+    pub const fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Builds a [`Mesh`] based on the configuration in `self`.
+    pub fn build(&self) -> Mesh {
+        let Capsule2d {
+            radius,
+            half_length,
+        } = self.capsule;
+        let resolution = self.resolution.max(2);
+        let vertex_count = 2 * resolution;
+        let step = PI / (resolution - 1) as f32;
+
+        // The top semicircle goes counterclockwise from the right side to the left one,
+        // and the bottom one continues from the left side back to the right one.
+        let positions: Vec<[f32; 3]> = (0..vertex_count)
+            .map(|i| {
+                let (center, angle) = if i < resolution {
+                    (half_length, i as f32 * step)
+                } else {
+                    (-half_length, PI + (i - resolution) as f32 * step)
+                };
+                let (sin, cos) = angle.sin_cos();
+                [radius * cos, center + radius * sin, 0.0]
+            })
+            .collect();
+        let normals = vec![[0.0, 0.0, 1.0]; vertex_count];
+        let uvs: Vec<[f32; 2]> = positions
+            .iter()
+            .map(|[x, y, _]| {
+                [
+                    0.5 + x / (2.0 * radius),
+                    0.5 - y / (2.0 * (radius + half_length)),
+                ]
+            })
+            .collect();
+
+        // The capsule is convex, so it can be triangulated as a fan from its first vertex.
+        let mut indices = Vec::with_capacity((vertex_count - 2) * 3);
+        for i in 1..(vertex_count as u32 - 1) {
+            indices.extend_from_slice(&[0, i, i + 1]);
+        }
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh
+    }
+}
+
+impl Meshable for Capsule2d {
+    type Output = Capsule2dMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        Capsule2dMeshBuilder {
+            capsule: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Capsule2dMeshBuilder> for Mesh {
+    fn from(capsule: Capsule2dMeshBuilder) -> Self {
+        capsule.build()
+    }
+}
+
+impl From<Capsule2d> for Mesh {
+    fn from(capsule: Capsule2d) -> Self {
+        capsule.mesh().build()
+    }
+}
+
+/// The type of the mesh generated for a [`Sphere`].
+#[derive(Clone, Copy, Debug)]
+pub enum SphereKind {
+    /// An icosphere, a spherical mesh that consists of equally sized triangles.
+    Ico {
+        /// The number of subdivisions applied.
+        /// The number of faces quadruples with each subdivision.
+        subdivisions: usize,
+    },
+    /// A UV sphere, a spherical mesh that consists of quadrilaterals
+    /// apart from triangles at the top and bottom.
+    Uv {
+        /// The number of longitudinal sectors, aka the horizontal resolution.
+        sectors: usize,
+        /// The number of latitudinal stacks, aka the vertical resolution.
+        stacks: usize,
+    },
+}
+
+impl Default for SphereKind {
+    fn default() -> Self {
+        Self::Ico { subdivisions: 5 }
+    }
+}
+
+/// A builder used for creating a [`Mesh`] with a [`Sphere`] shape.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SphereMeshBuilder {
+    /// The [`Sphere`] shape.
+    pub sphere: Sphere,
+    /// The type of sphere mesh that will be built.
+    pub kind: SphereKind,
+}
+
+impl SphereMeshBuilder {
+    /// Creates a new [`SphereMeshBuilder`] from a radius and [`SphereKind`].
+    #[inline]
+    pub const fn new(radius: f32, kind: SphereKind) -> Self {
+        Self {
+            sphere: Sphere { radius },
+            kind,
+        }
+    }
+
+    /// Sets the [`SphereKind`] that will be used for building the mesh.
+    #[inline]
+    pub const fn kind(mut self, kind: SphereKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Creates an icosphere mesh with the given number of subdivisions.
+    ///
+    /// The number of faces quadruples with each subdivision.
+    /// If there are `80` or more subdivisions, the vertex count will be too large,
+    /// and an [`FromIcosphereError`] is returned.
+    pub fn ico(&self, subdivisions: usize) -> Result<Mesh, FromIcosphereError> {
+        Mesh::try_from(shape::Icosphere {
+            radius: self.sphere.radius,
+            subdivisions,
+        })
+    }
+
+    /// Creates a UV sphere [`Mesh`] with the given number of
+    /// longitudinal sectors and latitudinal stacks, aka horizontal and vertical resolution.
+    pub fn uv(&self, sectors: usize, stacks: usize) -> Mesh {
+        shape::UVSphere {
+            radius: self.sphere.radius,
+            sectors,
+            stacks,
+        }
+        .into()
+    }
+
+    /// Builds a [`Mesh`] according to the configuration in `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sphere is a [`SphereKind::Ico`] with `80` or more subdivisions,
+    /// see [`SphereMeshBuilder::ico`].
+    pub fn build(&self) -> Mesh {
+        match self.kind {
+            SphereKind::Ico { subdivisions } => self.ico(subdivisions).unwrap(),
+            SphereKind::Uv { sectors, stacks } => self.uv(sectors, stacks),
+        }
+    }
+}
+
+impl Meshable for Sphere {
+    type Output = SphereMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        SphereMeshBuilder {
+            sphere: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<SphereMeshBuilder> for Mesh {
+    fn from(sphere: SphereMeshBuilder) -> Self {
+        sphere.build()
+    }
+}
+
+impl From<Sphere> for Mesh {
+    fn from(sphere: Sphere) -> Self {
+        sphere.mesh().build()
+    }
+}
+
+impl Meshable for Cuboid {
+    type Output = Mesh;
+
+    fn mesh(&self) -> Self::Output {
+        let size = self.size();
+        shape::Box::new(size.x, size.y, size.z).into()
+    }
+}
+
+impl From<Cuboid> for Mesh {
+    fn from(cuboid: Cuboid) -> Self {
+        cuboid.mesh()
+    }
+}
+
+/// A builder used for creating a [`Mesh`] with a [`Cylinder`] shape.
+#[derive(Clone, Copy, Debug)]
+pub struct CylinderMeshBuilder {
+    /// The [`Cylinder`] shape.
+    pub cylinder: Cylinder,
+    /// The number of vertices used for the top and bottom of the cylinder.
+    /// The default is `32`.
+    pub resolution: u32,
+    /// The number of segments along the height of the cylinder.
+    /// Must be greater than `0` for geometry to be generated.
+    /// The default is `1`.
+    pub segments: u32,
+}
+
+impl Default for CylinderMeshBuilder {
+    fn default() -> Self {
+        Self {
+            cylinder: Cylinder::default(),
+            resolution: 32,
+            segments: 1,
+        }
+    }
+}
+
+impl CylinderMeshBuilder {
+    /// Creates a new [`CylinderMeshBuilder`] from the given radius, a height,
+    /// and a resolution used for the top and bottom.
+    #[inline]
+    pub fn new(radius: f32, height: f32, resolution: u32) -> Self {
+        Self {
+            cylinder: Cylinder::new(radius, height),
+            resolution,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the number of vertices used for the top and bottom of the cylinder.
+    #[inline]
+    pub const fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Sets the number of segments along the height of the cylinder.
+    /// Must be greater than `0` for geometry to be generated.
+    #[inline]
+    pub const fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Builds a [`Mesh`] based on the configuration in `self`.
+    pub fn build(&self) -> Mesh {
+        shape::Cylinder {
+            radius: self.cylinder.radius,
+            height: 2.0 * self.cylinder.half_height,
+            resolution: self.resolution,
+            segments: self.segments,
+        }
+        .into()
+    }
+}
+
+impl Meshable for Cylinder {
+    type Output = CylinderMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        CylinderMeshBuilder {
+            cylinder: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<CylinderMeshBuilder> for Mesh {
+    fn from(cylinder: CylinderMeshBuilder) -> Self {
+        cylinder.build()
+    }
+}
+
+impl From<Cylinder> for Mesh {
+    fn from(cylinder: Cylinder) -> Self {
+        cylinder.mesh().build()
+    }
+}
+
+/// A builder used for creating a [`Mesh`] with a [`Torus`] shape.
+#[derive(Clone, Copy, Debug)]
+pub struct TorusMeshBuilder {
+    /// The [`Torus`] shape.
+    pub torus: Torus,
+    /// The number of vertices used for each circular segment
+    /// in the ring or tube of the torus.
+    /// The default is `24`.
+    pub minor_resolution: usize,
+    /// The number of segments used for the main ring of the torus.
+    /// A resolution of `4` would make the torus appear rectangular,
+    /// while a resolution of `32` resembles a circular ring.
+    /// The default is `32`.
+    pub major_resolution: usize,
+}
+
+impl Default for TorusMeshBuilder {
+    fn default() -> Self {
+        Self {
+            torus: Torus::default(),
+            minor_resolution: 24,
+            major_resolution: 32,
+        }
+    }
+}
+
+impl TorusMeshBuilder {
+    /// Creates a new [`TorusMeshBuilder`] from an inner and outer radius.
+    ///
+    /// The inner radius is the radius of the hole, and the outer radius
+    /// is the radius of the entire object.
+    #[inline]
+    pub fn new(inner_radius: f32, outer_radius: f32) -> Self {
+        Self {
+            torus: Torus::new(inner_radius, outer_radius),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the number of vertices used for each circular segment
+    /// in the ring or tube of the torus.
+    #[inline]
+    pub const fn minor_resolution(mut self, resolution: usize) -> Self {
+        self.minor_resolution = resolution;
+        self
+    }
+
+    /// Sets the number of segments used for the main ring of the torus.
+    #[inline]
+    pub const fn major_resolution(mut self, resolution: usize) -> Self {
+        self.major_resolution = resolution;
+        self
+    }
+
+    /// Builds a [`Mesh`] according to the configuration in `self`.
+    pub fn build(&self) -> Mesh {
+        shape::Torus {
+            radius: self.torus.major_radius,
+            ring_radius: self.torus.minor_radius,
+            subdivisions_segments: self.major_resolution,
+            subdivisions_sides: self.minor_resolution,
+        }
+        .into()
+    }
+}
+
+impl Meshable for Torus {
+    type Output = TorusMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        TorusMeshBuilder {
+            torus: *self,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<TorusMeshBuilder> for Mesh {
+    fn from(torus: TorusMeshBuilder) -> Self {
+        torus.build()
+    }
+}
+
+impl From<Torus> for Mesh {
+    fn from(torus: Torus) -> Self {
+        torus.mesh().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::VertexAttributeValues;
+    use bevy_math::{Vec2, Vec3};
+
+    fn positions(mesh: &Mesh) -> Vec<Vec3> {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("the mesh has no positions");
+        };
+        positions.iter().copied().map(Vec3::from).collect()
+    }
+
+    #[test]
+    fn circle_mesh() {
+        let mesh = Circle::new(2.0).mesh().resolution(8).build();
+        let positions = positions(&mesh);
+        assert_eq!(positions.len(), 8);
+        assert!(positions
+            .iter()
+            .all(|position| (position.length() - 2.0).abs() < 1e-5 && position.z == 0.0));
+    }
+
+    #[test]
+    fn capsule_2d_mesh() {
+        let capsule = Capsule2d::new(1.0, 2.0);
+        let mesh = capsule.mesh().resolution(4).build();
+        let positions = positions(&mesh);
+        assert_eq!(positions.len(), 8);
+        assert_eq!(mesh.indices().unwrap().len(), 6 * 3);
+        // All the vertices are on the boundary of the capsule
+        for position in &positions {
+            let segment_point = Vec3::new(0.0, position.y.clamp(-1.0, 1.0), 0.0);
+            assert!((position.distance(segment_point) - 1.0).abs() < 1e-5);
+        }
+        assert!(positions[0].abs_diff_eq(Vec3::new(1.0, 1.0, 0.0), 1e-5));
+        assert!(positions[3].abs_diff_eq(Vec3::new(-1.0, 1.0, 0.0), 1e-5));
+        assert!(positions[4].abs_diff_eq(Vec3::new(-1.0, -1.0, 0.0), 1e-5));
+        assert!(positions[7].abs_diff_eq(Vec3::new(1.0, -1.0, 0.0), 1e-5));
+
+        // The triangles face the positive Z axis.
+        let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i]]);
+            assert!((b - a).cross(c - a).z > 0.0);
+        }
+    }
+
+    #[test]
+    fn sphere_mesh_kinds() {
+        let sphere = Sphere::new(2.0);
+        for mesh in [
+            sphere.mesh().build(),
+            sphere
+                .mesh()
+                .kind(SphereKind::Ico { subdivisions: 2 })
+                .build(),
+            sphere
+                .mesh()
+                .kind(SphereKind::Uv {
+                    sectors: 8,
+                    stacks: 4,
+                })
+                .build(),
+        ] {
+            assert!(positions(&mesh)
+                .iter()
+                .all(|position| (position.length() - 2.0).abs() < 1e-5));
+        }
+        assert!(sphere.mesh().ico(80).is_err());
+    }
+
+    #[test]
+    fn meshes_fit_their_primitive() {
+        let rectangle = Rectangle::new(2.0, 4.0);
+        let cuboid = Cuboid::new(1.0, 2.0, 3.0);
+        let cylinder = Cylinder::new(1.0, 3.0);
+        let torus = Torus::new(1.0, 2.0);
+
+        let rectangle_positions = positions(&rectangle.mesh());
+        assert!(rectangle_positions
+            .iter()
+            .all(|position| position.truncate().abs() == rectangle.half_size));
+
+        let cuboid_positions = positions(&cuboid.mesh());
+        assert!(cuboid_positions
+            .iter()
+            .all(|position| position.abs() == cuboid.half_size));
+
+        for position in positions(&cylinder.mesh().build()) {
+            let horizontal = Vec2::new(position.x, position.z).length();
+            assert!(horizontal <= 1.0 + 1e-5 && position.y.abs() <= 1.5);
+        }
+
+        for position in positions(&torus.mesh().build()) {
+            let ring = Vec3::new(position.x, 0.0, position.z).normalize() * torus.major_radius;
+            assert!((position.distance(ring) - torus.minor_radius).abs() < 1e-5);
+        }
+    }
+}
@@ -273,7 +273,7 @@ mod uvsphere;
 
 pub use capsule::{Capsule, CapsuleUvProfile};
 pub use cylinder::Cylinder;
-pub use icosphere::Icosphere;
+pub use icosphere::{FromIcosphereError, Icosphere};
 pub use regular_polygon::{Circle, RegularPolygon};
 pub use torus::Torus;
 pub use uvsphere::UVSphere;
@@ -0,0 +1,241 @@
+//! Ray casts against meshes on the CPU, to find the entities under the cursor among other things.
+//!
+//! Cast rays with the [`MeshRayCast`] system parameter. It tests the [`Aabb`] of each visible mesh
+//! first, and only tests the triangles of the meshes whose bounds the ray hits.
+
+mod ray_cast;
+
+pub use ray_cast::{ray_mesh_intersection, Backfaces};
+
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{
+    prelude::Entity,
+    system::{Query, Res, SystemParam},
+};
+use bevy_math::{
+    bounding::{Aabb3d, RayCast3d},
+    Mat4, Ray3d, Vec3,
+};
+use bevy_transform::components::GlobalTransform;
+
+use crate::{mesh::Mesh, primitives::Aabb, view::ComputedVisibility};
+
+/// A hit of a ray cast against a mesh, see [`MeshRayCast`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayMeshHit {
+    /// The point hit, in world space.
+    pub point: Vec3,
+    /// The normal of the mesh at the point hit, in world space.
+    pub normal: Vec3,
+    /// The distance from the origin of the ray to the point hit.
+    pub distance: f32,
+    /// The index of the triangle hit, in the order of the indices of the mesh.
+    pub triangle_index: usize,
+}
+
+/// How a [`MeshRayCast`] selects and hits meshes.
+#[derive(Clone, Copy)]
+pub struct RayCastSettings<'a> {
+    /// Whether the back faces of the triangles are hit.
+    pub backfaces: Backfaces,
+    /// Only returns the nearest hit, which skips the meshes that can't be nearer than it.
+    pub nearest_only: bool,
+    /// Only the entities for which this returns `true` are hit.
+    pub filter: &'a dyn Fn(Entity) -> bool,
+}
+
+impl<'a> Default for RayCastSettings<'a> {
+    fn default() -> Self {
+        Self {
+            backfaces: Backfaces::Cull,
+            nearest_only: false,
+            filter: &|_| true,
+        }
+    }
+}
+
+impl<'a> RayCastSettings<'a> {
+    /// Sets whether the back faces of the triangles are hit.
+    pub fn with_backfaces(mut self, backfaces: Backfaces) -> Self {
+        self.backfaces = backfaces;
+        self
+    }
+
+    /// Only returns the nearest hit.
+    pub fn nearest_only(mut self) -> Self {
+        self.nearest_only = true;
+        self
+    }
+
+    /// Only hits the entities for which `filter` returns `true`.
+    pub fn with_filter(mut self, filter: &'a dyn Fn(Entity) -> bool) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+/// A [`SystemParam`] casting rays against the meshes of the world.
+///
+/// Only the meshes visible in the hierarchy, as per [`ComputedVisibility::is_visible_in_hierarchy`],
+/// and with an [`Aabb`] are hit. Skinned meshes are hit as if they were in their bind pose, the
+/// joints moving their vertices are ignored.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::Ray3d;
+/// # use bevy_render::picking::{MeshRayCast, RayCastSettings};
+/// #[derive(Resource)]
+/// struct Cursor(Ray3d);
+///
+/// fn print_hovered_mesh(cursor: Res<Cursor>, ray_cast: MeshRayCast) {
+///     let settings = RayCastSettings::default().nearest_only();
+///     if let Some((entity, hit)) = ray_cast.cast_ray(cursor.0, &settings).first() {
+///         println!("{entity:?} is hovered at {}", hit.point);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(print_hovered_mesh);
+/// ```
+#[derive(SystemParam)]
+pub struct MeshRayCast<'w, 's> {
+    meshes: Res<'w, Assets<Mesh>>,
+    #[allow(clippy::type_complexity)]
+    query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Handle<Mesh>,
+            &'static Aabb,
+            &'static GlobalTransform,
+            &'static ComputedVisibility,
+        ),
+    >,
+}
+
+impl<'w, 's> MeshRayCast<'w, 's> {
+    /// Casts `ray` against the meshes, returning the entities hit with their nearest hit, sorted
+    /// from the nearest to the farthest.
+    pub fn cast_ray(&self, ray: Ray3d, settings: &RayCastSettings) -> Vec<(Entity, RayMeshHit)> {
+        let mut candidates: Vec<_> = self
+            .query
+            .iter()
+            .filter(|(entity, .., visibility)| {
+                visibility.is_visible_in_hierarchy() && (settings.filter)(*entity)
+            })
+            .filter_map(|(entity, mesh, aabb, transform, _)| {
+                let mesh_transform = transform.compute_matrix();
+                let distance = ray_aabb_distance(ray, aabb, &mesh_transform)?;
+                Some((distance, entity, mesh, mesh_transform))
+            })
+            .collect();
+        candidates.sort_unstable_by(|(a, ..), (b, ..)| a.total_cmp(b));
+
+        let mut hits: Vec<(Entity, RayMeshHit)> = Vec::new();
+        let mut nearest_distance = f32::INFINITY;
+        for (aabb_distance, entity, mesh, mesh_transform) in candidates {
+            if settings.nearest_only && nearest_distance < aabb_distance {
+                // The meshes left are all behind the nearest hit
+                break;
+            }
+            let Some(mesh) = self.meshes.get(mesh) else {
+                continue;
+            };
+            let Some(hit) = ray_mesh_intersection(ray, mesh, &mesh_transform, settings.backfaces)
+            else {
+                continue;
+            };
+            if settings.nearest_only {
+                if hit.distance < nearest_distance {
+                    nearest_distance = hit.distance;
+                    hits = vec![(entity, hit)];
+                }
+            } else {
+                hits.push((entity, hit));
+            }
+        }
+        hits.sort_unstable_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance));
+        hits
+    }
+}
+
+/// Returns the distance from the origin of `ray` to the [`Aabb`] of a mesh, if the ray hits it.
+fn ray_aabb_distance(ray: Ray3d, aabb: &Aabb, mesh_transform: &Mat4) -> Option<f32> {
+    let world_to_mesh = mesh_transform.inverse();
+    let origin = world_to_mesh.transform_point3(ray.origin);
+    let direction = world_to_mesh.transform_vector3(*ray.direction);
+    let direction_length = direction.length();
+    if !direction_length.is_normal() {
+        return None;
+    }
+    let ray_cast = RayCast3d::new(origin, direction, f32::MAX);
+    let aabb = Aabb3d {
+        min: (aabb.center - aabb.half_extents).into(),
+        max: (aabb.center + aabb.half_extents).into(),
+    };
+    // The distances in the space of the mesh are scaled by the length of the direction
+    let distance = ray_cast.aabb_intersection_at(&aabb)?;
+    Some(distance / direction_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mesh::shape,
+        view::{Visibility, VisibilityPlugin},
+    };
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin};
+    use bevy_ecs::system::SystemState;
+    use bevy_transform::components::Transform;
+
+    #[test]
+    fn rays_hit_visible_meshes_from_the_nearest() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Mesh>()
+            .add_plugin(VisibilityPlugin);
+        let cube = app
+            .world
+            .resource_mut::<Assets<Mesh>>()
+            .add(shape::Cube::new(1.0).into());
+        let mut spawn_cube = |z: f32, visibility: Visibility| {
+            app.world
+                .spawn((
+                    cube.clone(),
+                    GlobalTransform::from(Transform::from_xyz(0.0, 0.0, z)),
+                    visibility,
+                    ComputedVisibility::default(),
+                ))
+                .id()
+        };
+        let near = spawn_cube(0.0, Visibility::Inherited);
+        let far = spawn_cube(-4.0, Visibility::Inherited);
+        spawn_cube(2.0, Visibility::Hidden);
+        // Computes the bounds and visibility of the cubes
+        app.update();
+
+        let mut state = SystemState::<MeshRayCast>::new(&mut app.world);
+        let ray_cast = state.get(&app.world);
+        let ray = Ray3d::new(Vec3::new(0.1, 0.2, 4.0), Vec3::NEG_Z);
+        let distances = |hits: Vec<(Entity, RayMeshHit)>| -> Vec<_> {
+            hits.into_iter()
+                .map(|(entity, hit)| (entity, hit.distance))
+                .collect()
+        };
+        assert_eq!(
+            distances(ray_cast.cast_ray(ray, &RayCastSettings::default())),
+            [(near, 3.5), (far, 7.5)]
+        );
+        let nearest_only = RayCastSettings::default().nearest_only();
+        assert_eq!(
+            distances(ray_cast.cast_ray(ray, &nearest_only)),
+            [(near, 3.5)]
+        );
+        let filter = |entity| entity != near;
+        let filtered = RayCastSettings::default().with_filter(&filter);
+        assert_eq!(distances(ray_cast.cast_ray(ray, &filtered)), [(far, 7.5)]);
+        let missing = Ray3d::new(Vec3::new(2.0, 0.0, 4.0), Vec3::NEG_Z);
+        assert!(ray_cast.cast_ray(missing, &nearest_only).is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+use bevy_math::{Mat4, Ray3d, Vec3};
+
+use super::RayMeshHit;
+use crate::{
+    mesh::{Indices, Mesh},
+    render_resource::PrimitiveTopology,
+};
+
+/// Whether a ray cast hits the back faces of triangles, the ones it enters from behind.
+///
+/// The front faces of a triangle are the ones its vertices are counter-clockwise from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backfaces {
+    /// Back faces are ignored, like they are culled when drawing most meshes.
+    #[default]
+    Cull,
+    /// Back faces are hit like front faces.
+    Include,
+}
+
+/// Casts `ray` against the triangles of `mesh`, drawn with `mesh_transform`, returning the nearest
+/// hit if any.
+///
+/// The mesh must have [`Mesh::ATTRIBUTE_POSITION`] and be a [`PrimitiveTopology::TriangleList`],
+/// it is never hit otherwise. The normal of the hit is interpolated from the
+/// [`Mesh::ATTRIBUTE_NORMAL`] of the mesh, if it has one.
+pub fn ray_mesh_intersection(
+    ray: Ray3d,
+    mesh: &Mesh,
+    mesh_transform: &Mat4,
+    backfaces: Backfaces,
+) -> Option<RayMeshHit> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+    let normals = mesh
+        .attribute(Mesh::ATTRIBUTE_NORMAL)
+        .and_then(|normals| normals.as_float3());
+    let indices = mesh.indices();
+    let vertex_index = |index: usize| match indices {
+        Some(Indices::U16(indices)) => indices.get(index).map(|&index| index as usize),
+        Some(Indices::U32(indices)) => indices.get(index).map(|&index| index as usize),
+        None => Some(index),
+    };
+    let triangle_count = indices.map_or(positions.len(), Indices::len) / 3;
+
+    // Cast in the space of the mesh, where the distances along the ray are proportional to the
+    // ones in world space.
+    let world_to_mesh = mesh_transform.inverse();
+    let origin = world_to_mesh.transform_point3(ray.origin);
+    let direction = world_to_mesh.transform_vector3(*ray.direction);
+
+    let mut nearest_distance = f32::INFINITY;
+    let mut nearest = None;
+    for triangle_index in 0..triangle_count {
+        let first = triangle_index * 3;
+        let (Some(a), Some(b), Some(c)) = (
+            vertex_index(first),
+            vertex_index(first + 1),
+            vertex_index(first + 2),
+        ) else {
+            continue;
+        };
+        let (Some(&position_a), Some(&position_b), Some(&position_c)) =
+            (positions.get(a), positions.get(b), positions.get(c))
+        else {
+            continue;
+        };
+        let triangle = [position_a, position_b, position_c].map(Vec3::from);
+        let Some((distance, u, v)) =
+            ray_triangle_intersection(origin, direction, triangle, backfaces)
+        else {
+            continue;
+        };
+        if distance < nearest_distance {
+            nearest_distance = distance;
+            nearest = Some((triangle_index, [a, b, c], u, v));
+        }
+    }
+
+    let (triangle_index, [a, b, c], u, v) = nearest?;
+    let point = origin + direction * nearest_distance;
+    let interpolated_normal = normals.and_then(|normals| {
+        let [normal_a, normal_b, normal_c] =
+            [normals.get(a)?, normals.get(b)?, normals.get(c)?].map(|&normal| Vec3::from(normal));
+        Some(normal_a * (1.0 - u - v) + normal_b * u + normal_c * v)
+    });
+    let normal = interpolated_normal.unwrap_or_else(|| {
+        let [a, b, c] = [a, b, c].map(|index| Vec3::from(positions[index]));
+        (b - a).cross(c - a)
+    });
+    let point = mesh_transform.transform_point3(point);
+    Some(RayMeshHit {
+        point,
+        // Normals are transformed by the inverse transpose, to stay orthogonal to the surface
+        normal: world_to_mesh
+            .transpose()
+            .transform_vector3(normal)
+            .normalize_or_zero(),
+        distance: point.distance(ray.origin),
+        triangle_index,
+    })
+}
+
+/// Returns the distance along `direction` to the intersection of the ray with the triangle,
+/// followed by the barycentric coordinates of the intersection relative to `b` and `c`.
+///
+/// This is the Möller–Trumbore intersection algorithm.
+fn ray_triangle_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    [a, b, c]: [Vec3; 3],
+    backfaces: Backfaces,
+) -> Option<(f32, f32, f32)> {
+    let edge_ab = b - a;
+    let edge_ac = c - a;
+    let p = direction.cross(edge_ac);
+    // Positive when the ray enters the front face of the triangle, zero when it is parallel to it
+    let determinant = edge_ab.dot(p);
+    let is_missed = match backfaces {
+        Backfaces::Cull => determinant < f32::EPSILON,
+        Backfaces::Include => determinant.abs() < f32::EPSILON,
+    };
+    if is_missed {
+        return None;
+    }
+
+    let determinant_recip = determinant.recip();
+    let offset = origin - a;
+    let u = offset.dot(p) * determinant_recip;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = offset.cross(edge_ab);
+    let v = direction.dot(q) * determinant_recip;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let distance = edge_ac.dot(q) * determinant_recip;
+    (distance >= 0.0).then_some((distance, u, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::shape;
+    use bevy_math::Quat;
+
+    fn assert_hit(hit: Option<RayMeshHit>, point: Vec3, normal: Vec3, distance: f32) {
+        let hit = hit.expect("the ray should hit the cube");
+        assert!(hit.point.abs_diff_eq(point, 1e-5), "{hit:?}");
+        assert!(hit.normal.abs_diff_eq(normal, 1e-5), "{hit:?}");
+        assert!((hit.distance - distance).abs() < 1e-5, "{hit:?}");
+    }
+
+    #[test]
+    fn rays_hit_the_faces_of_a_unit_cube() {
+        let cube = Mesh::from(shape::Cube::new(1.0));
+        let cast = |origin: Vec3, direction: Vec3, transform: Mat4| {
+            let ray = Ray3d::new(origin, direction);
+            ray_mesh_intersection(ray, &cube, &transform, Backfaces::Cull)
+        };
+
+        // Straight at the front face
+        let hit = cast(Vec3::new(0.1, 0.2, 2.0), Vec3::NEG_Z, Mat4::IDENTITY);
+        assert_hit(hit, Vec3::new(0.1, 0.2, 0.5), Vec3::Z, 1.5);
+
+        // At 45 degrees to the top face
+        let hit = cast(
+            Vec3::new(0.1, 1.5, 1.0),
+            Vec3::new(0.0, -1.0, -1.0),
+            Mat4::IDENTITY,
+        );
+        assert_hit(hit, Vec3::new(0.1, 0.5, 0.0), Vec3::Y, 2.0_f32.sqrt());
+
+        // At 45 degrees to the front face of a rotated cube
+        let rotation = Mat4::from_quat(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4));
+        let hit = cast(Vec3::new(0.1, 0.0, 2.0), Vec3::NEG_Z, rotation);
+        let z = 0.5_f32.sqrt() - 0.1;
+        assert_hit(
+            hit,
+            Vec3::new(0.1, 0.0, z),
+            Vec3::new(0.5_f32.sqrt(), 0.0, 0.5_f32.sqrt()),
+            2.0 - z,
+        );
+
+        // Away from the cube, and past it
+        assert!(cast(Vec3::new(0.1, 0.2, 2.0), Vec3::Z, Mat4::IDENTITY).is_none());
+        assert!(cast(Vec3::new(1.0, 0.2, 2.0), Vec3::NEG_Z, Mat4::IDENTITY).is_none());
+    }
+
+    #[test]
+    fn backfaces_are_only_hit_when_included() {
+        let cube = Mesh::from(shape::Cube::new(1.0));
+        let inside = Ray3d::new(Vec3::new(0.0, 0.1, 0.2), Vec3::X);
+        assert!(ray_mesh_intersection(inside, &cube, &Mat4::IDENTITY, Backfaces::Cull).is_none());
+        let hit = ray_mesh_intersection(inside, &cube, &Mat4::IDENTITY, Backfaces::Include);
+        assert_hit(hit, Vec3::new(0.5, 0.1, 0.2), Vec3::X, 0.5);
+
+        // Mirroring the cube keeps its front faces outside
+        let mirrored = Mat4::from_scale(Vec3::new(-2.0, 1.0, 1.0));
+        let outside = Ray3d::new(Vec3::new(2.0, 0.1, 0.2), Vec3::NEG_X);
+        let hit = ray_mesh_intersection(outside, &cube, &mirrored, Backfaces::Cull);
+        assert_hit(hit, Vec3::new(1.0, 0.1, 0.2), Vec3::X, 1.0);
+        assert!(ray_mesh_intersection(inside, &cube, &mirrored, Backfaces::Cull).is_none());
+    }
+}
@@ -24,6 +24,26 @@ impl Aabb {
         }
     }
 
+    /// Returns the smallest [`Aabb`] containing all the `points`, or `None` if there are none.
+    #[inline]
+    pub fn from_points(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (minimum, maximum) = points.fold((first, first), |(minimum, maximum), point| {
+            (minimum.min(point), maximum.max(point))
+        });
+        Some(Self::from_min_max(minimum, maximum))
+    }
+
+    /// Returns the smallest [`Aabb`] containing both this one and `other`.
+    #[inline]
+    pub fn merge(&self, other: &Aabb) -> Self {
+        Self::from_min_max(
+            self.min().min(other.min()).into(),
+            self.max().max(other.max()).into(),
+        )
+    }
+
     /// Calculate the relative radius of the AABB with respect to a plane
     #[inline]
     pub fn relative_radius(&self, p_normal: &Vec3A, axes: &[Vec3A]) -> f32 {
@@ -66,6 +86,11 @@ pub struct Sphere {
 }
 
 impl Sphere {
+    /// Returns whether the sphere intersects the oriented bounding box made of `aabb` transformed
+    /// by `local_to_world`.
+    ///
+    /// The test is conservative: it never returns `false` for an intersecting box, but can
+    /// return `true` for a box close to the sphere without intersecting it.
     #[inline]
     pub fn intersects_obb(&self, aabb: &Aabb, local_to_world: &Mat4) -> bool {
         let aabb_center_world = *local_to_world * aabb.center.extend(1.0);
@@ -145,6 +170,25 @@ impl Frustum {
 
     /// Returns a frustum derived from `view_projection`, but with a custom
     /// far plane.
+    ///
+    /// The far plane is at a distance `far` from `view_translation`, along `-view_backward`, which
+    /// is the direction the view looks towards. This is used for the views whose projection has no
+    /// far plane, like the infinite reverse-z perspective projections of cameras.
+    ///
+    /// ```
+    /// # use bevy_math::{Mat4, Vec3, Vec3A};
+    /// # use bevy_render::primitives::Frustum;
+    /// let projection = Mat4::perspective_infinite_reverse_rh(1.0, 1.0, 0.1);
+    /// let view = Mat4::look_at_rh(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+    /// let frustum = Frustum::from_view_projection_custom_far(
+    ///     &(projection * view),
+    ///     &Vec3::ZERO,
+    ///     &Vec3::Z,
+    ///     100.0,
+    /// );
+    /// assert!(frustum.contains_point(Vec3A::new(0.0, 0.0, -99.0)));
+    /// assert!(!frustum.contains_point(Vec3A::new(0.0, 0.0, -101.0)));
+    /// ```
     #[inline]
     pub fn from_view_projection_custom_far(
         view_projection: &Mat4,
@@ -175,6 +219,20 @@ impl Frustum {
         Self { planes }
     }
 
+    /// Returns whether `point` is inside the frustum, or on its boundary.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3A) -> bool {
+        let point = point.extend(1.0);
+        self.planes
+            .iter()
+            .all(|plane| plane.normal_d().dot(point) >= 0.0)
+    }
+
+    /// Returns whether `sphere` intersects the frustum.
+    ///
+    /// The far plane is ignored unless `intersect_far` is `true`. The test is conservative: it
+    /// never returns `false` for an intersecting sphere, but can return `true` for a sphere close
+    /// to a corner of the frustum without intersecting it.
     #[inline]
     pub fn intersects_sphere(&self, sphere: &Sphere, intersect_far: bool) -> bool {
         let sphere_center = sphere.center.extend(1.0);
@@ -187,6 +245,11 @@ impl Frustum {
         true
     }
 
+    /// Returns whether the oriented bounding box made of `aabb` transformed by `model_to_world`
+    /// intersects the frustum.
+    ///
+    /// The near and far planes are ignored unless `intersect_near` and `intersect_far` are `true`.
+    /// Like [`Frustum::intersects_sphere`], the test is conservative.
     #[inline]
     pub fn intersects_obb(
         &self,
@@ -217,6 +280,27 @@ impl Frustum {
         }
         true
     }
+
+    /// Returns whether the oriented bounding box made of `aabb` transformed by `model_to_world`
+    /// is entirely inside the frustum.
+    ///
+    /// Unlike the intersection tests, this test is exact. The entities of a hierarchy whose bounds
+    /// are entirely inside a frustum can skip testing their children.
+    #[inline]
+    pub fn contains_aabb(&self, aabb: &Aabb, model_to_world: &Mat4) -> bool {
+        let aabb_center_world = model_to_world.transform_point3a(aabb.center).extend(1.0);
+        let axes = [
+            Vec3A::from(model_to_world.x_axis),
+            Vec3A::from(model_to_world.y_axis),
+            Vec3A::from(model_to_world.z_axis),
+        ];
+
+        self.planes.iter().all(|plane| {
+            let p_normal = Vec3A::from(plane.normal_d());
+            let relative_radius = aabb.relative_radius(&p_normal, &axes);
+            plane.normal_d().dot(aabb_center_world) - relative_radius >= 0.0
+        })
+    }
 }
 
 #[derive(Component, Debug, Default, Reflect)]
@@ -245,6 +329,8 @@ pub struct CascadesFrusta {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy_math::{EulerRot, Quat};
+    use std::f32::consts::PI;
 
     // A big, offset frustum
     fn big_frustum() -> Frustum {
@@ -397,4 +483,221 @@ mod tests {
         };
         assert!(frustum.intersects_sphere(&sphere, true));
     }
+
+    #[test]
+    fn aabb_from_points_and_merge() {
+        assert!(Aabb::from_points([]).is_none());
+        let aabb = Aabb::from_points([
+            Vec3::new(1.0, -2.0, 0.5),
+            Vec3::new(-1.0, 3.0, 0.0),
+            Vec3::new(0.0, 0.0, 2.5),
+        ])
+        .unwrap();
+        assert_eq!(aabb.min(), Vec3A::new(-1.0, -2.0, 0.0));
+        assert_eq!(aabb.max(), Vec3A::new(1.0, 3.0, 2.5));
+
+        let other = Aabb::from_min_max(Vec3::new(0.0, 4.0, -1.0), Vec3::new(2.0, 5.0, 0.0));
+        let merged = aabb.merge(&other);
+        assert_eq!(merged.min(), Vec3A::new(-1.0, -2.0, -1.0));
+        assert_eq!(merged.max(), Vec3A::new(2.0, 5.0, 2.5));
+    }
+
+    /// A xorshift generator, for the random tests to be reproducible.
+    struct Rng(u32);
+
+    impl Rng {
+        fn range(&mut self, min: f32, max: f32) -> f32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            let unit = (self.0 >> 8) as f32 / (1 << 24) as f32;
+            min + unit * (max - min)
+        }
+
+        fn vec3(&mut self, min: f32, max: f32) -> Vec3 {
+            Vec3::new(
+                self.range(min, max),
+                self.range(min, max),
+                self.range(min, max),
+            )
+        }
+
+        fn rotation(&mut self) -> Quat {
+            let angles = self.vec3(-PI, PI);
+            Quat::from_euler(EulerRot::XYZ, angles.x, angles.y, angles.z)
+        }
+    }
+
+    /// A random perspective frustum, with its view projection.
+    fn random_frustum(rng: &mut Rng) -> (Mat4, Frustum) {
+        let projection = Mat4::perspective_rh(
+            rng.range(0.5, 2.0),
+            rng.range(0.5, 2.0),
+            rng.range(0.1, 1.0),
+            rng.range(5.0, 20.0),
+        );
+        let view = Mat4::from_rotation_translation(rng.rotation(), rng.vec3(-10.0, 10.0));
+        let view_projection = projection * view.inverse();
+        (
+            view_projection,
+            Frustum::from_view_projection(&view_projection),
+        )
+    }
+
+    /// A random point around the frustum of `view_projection`, with whether it's inside it.
+    /// The points too close to the boundary of the frustum to tell are skipped.
+    fn random_point(rng: &mut Rng, view_projection: &Mat4) -> Option<(Vec3, bool)> {
+        let ndc = Vec3::new(
+            rng.range(-1.5, 1.5),
+            rng.range(-1.5, 1.5),
+            rng.range(-0.5, 1.5),
+        );
+        let distances = [1.0 - ndc.x.abs(), 1.0 - ndc.y.abs(), ndc.z, 1.0 - ndc.z];
+        if distances.iter().any(|distance| distance.abs() < 1e-3) {
+            return None;
+        }
+        let point = view_projection.inverse().project_point3(ndc);
+        Some((point, distances.iter().all(|distance| *distance > 0.0)))
+    }
+
+    /// Points spread over the box of `aabb` transformed by `model_to_world`, including its corners.
+    fn obb_points(aabb: &Aabb, model_to_world: &Mat4) -> Vec<Vec3> {
+        let steps = [-1.0, -0.5, 0.0, 0.5, 1.0];
+        let mut points = Vec::new();
+        for x in steps {
+            for y in steps {
+                for z in steps {
+                    let local = aabb.center + Vec3A::new(x, y, z) * aabb.half_extents;
+                    points.push(model_to_world.transform_point3(local.into()));
+                }
+            }
+        }
+        points
+    }
+
+    fn obb_corners(aabb: &Aabb, model_to_world: &Mat4) -> Vec<Vec3> {
+        let signs = [-1.0, 1.0];
+        let mut corners = Vec::new();
+        for x in signs {
+            for y in signs {
+                for z in signs {
+                    let local = aabb.center + Vec3A::new(x, y, z) * aabb.half_extents;
+                    corners.push(model_to_world.transform_point3(local.into()));
+                }
+            }
+        }
+        corners
+    }
+
+    #[test]
+    fn contains_point_matches_clip_space() {
+        let mut rng = Rng(0x2545_f491);
+        let mut inside_count = 0;
+        for _ in 0..50 {
+            let (view_projection, frustum) = random_frustum(&mut rng);
+            for _ in 0..200 {
+                let Some((point, inside)) = random_point(&mut rng, &view_projection) else {
+                    continue;
+                };
+                assert_eq!(frustum.contains_point(point.into()), inside);
+                inside_count += inside as usize;
+            }
+        }
+        // both the inside and outside points are tested
+        assert!(inside_count > 1000 && inside_count < 9000);
+    }
+
+    #[test]
+    fn obb_tests_match_point_sampling() {
+        let mut rng = Rng(0x9e37_79b9);
+        let (mut intersecting, mut contained) = (0, 0);
+        for _ in 0..50 {
+            let (view_projection, frustum) = random_frustum(&mut rng);
+            for _ in 0..100 {
+                // around the frustum, for some boxes to intersect it
+                let ndc = Vec3::new(
+                    rng.range(-1.5, 1.5),
+                    rng.range(-1.5, 1.5),
+                    rng.range(0.0, 1.0),
+                );
+                let translation = view_projection.inverse().project_point3(ndc);
+                let model_to_world = Mat4::from_scale_rotation_translation(
+                    rng.vec3(0.2, 2.0),
+                    rng.rotation(),
+                    translation,
+                );
+                let aabb = Aabb {
+                    center: rng.vec3(-0.5, 0.5).into(),
+                    half_extents: rng.vec3(0.05, 1.0).into(),
+                };
+
+                let any_inside = obb_points(&aabb, &model_to_world)
+                    .into_iter()
+                    .any(|point| frustum.contains_point(point.into()));
+                let all_inside = obb_corners(&aabb, &model_to_world)
+                    .into_iter()
+                    .all(|point| frustum.contains_point(point.into()));
+                let intersects = frustum.intersects_obb(&aabb, &model_to_world, true, true);
+                if any_inside {
+                    assert!(intersects);
+                }
+                // the frustum is convex, so the box is inside it if all its corners are
+                assert_eq!(frustum.contains_aabb(&aabb, &model_to_world), all_inside);
+                intersecting += any_inside as usize;
+                contained += all_inside as usize;
+            }
+        }
+        assert!(intersecting > 100);
+        assert!(contained > 10);
+    }
+
+    #[test]
+    fn sphere_tests_match_point_sampling() {
+        let mut rng = Rng(0x1234_5678);
+        let mut intersecting = 0;
+        for _ in 0..50 {
+            let (view_projection, frustum) = random_frustum(&mut rng);
+            for _ in 0..100 {
+                let ndc = Vec3::new(
+                    rng.range(-1.5, 1.5),
+                    rng.range(-1.5, 1.5),
+                    rng.range(0.0, 1.0),
+                );
+                let sphere = Sphere {
+                    center: view_projection.inverse().project_point3(ndc).into(),
+                    radius: rng.range(0.1, 2.0),
+                };
+                let sphere_points: Vec<_> = (0..64)
+                    .map(|_| {
+                        let direction = rng.vec3(-1.0, 1.0).normalize_or_zero();
+                        sphere.center + Vec3A::from(direction) * sphere.radius * rng.range(0.0, 1.0)
+                    })
+                    .collect();
+
+                if sphere_points
+                    .iter()
+                    .any(|point| frustum.contains_point(*point))
+                {
+                    assert!(frustum.intersects_sphere(&sphere, true));
+                    intersecting += 1;
+                }
+
+                let model_to_world = Mat4::from_rotation_translation(
+                    rng.rotation(),
+                    rng.vec3(-2.0, 2.0) + Vec3::from(sphere.center),
+                );
+                let aabb = Aabb {
+                    center: Vec3A::ZERO,
+                    half_extents: rng.vec3(0.05, 1.0).into(),
+                };
+                let obb_intersects_sphere = obb_points(&aabb, &model_to_world)
+                    .into_iter()
+                    .any(|point| Vec3A::from(point).distance(sphere.center) < sphere.radius);
+                if obb_intersects_sphere {
+                    assert!(sphere.intersects_obb(&aabb, &model_to_world));
+                }
+            }
+        }
+        assert!(intersecting > 100);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Extract, ExtractSchedule, RenderApp, RenderSet};
+use crate::{Extract, ExtractSchedule, MainWorld, RenderApp, RenderSet};
 use bevy_app::{App, IntoSystemAppConfig, Plugin};
 use bevy_asset::{Asset, AssetEvent, Assets, Handle};
 use bevy_derive::{Deref, DerefMut};
@@ -13,6 +13,31 @@ pub enum PrepareAssetError<E: Send + Sync + 'static> {
     RetryNextUpdate(E),
 }
 
+bitflags::bitflags! {
+    /// Where a [`RenderAsset`] is used: by the systems of the main world, to render it, or both.
+    ///
+    /// The data of an asset used only in the [`RENDER_WORLD`](Self::RENDER_WORLD) is unloaded
+    /// from the main world once it has been extracted, which frees its CPU copy after it is
+    /// uploaded to the GPU. Its handle stays valid and it keeps being rendered, but its data
+    /// can't be read back (a [`Mesh`](crate::mesh::Mesh) has no attributes left for instance).
+    /// Modifying the asset afterwards doesn't extract it again, the asset keeps being rendered
+    /// as it was extracted.
+    ///
+    /// An asset used only in the [`MAIN_WORLD`](Self::MAIN_WORLD) isn't extracted at all.
+    #[repr(transparent)]
+    pub struct RenderAssetUsages: u8 {
+        const MAIN_WORLD = 1 << 0;
+        const RENDER_WORLD = 1 << 1;
+    }
+}
+
+impl Default for RenderAssetUsages {
+    /// Keeps the asset in the main world as well as rendering it.
+    fn default() -> Self {
+        RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD
+    }
+}
+
 /// Describes how an asset gets extracted and prepared for rendering.
 ///
 /// In the [`ExtractSchedule`](crate::ExtractSchedule) step the asset is transferred
@@ -32,12 +57,46 @@ pub trait RenderAsset: Asset {
     type Param: SystemParam;
     /// Converts the asset into a [`RenderAsset::ExtractedAsset`].
     fn extract_asset(&self) -> Self::ExtractedAsset;
+    /// Where the asset is used, both in the main and render worlds by default.
+    fn asset_usage(&self) -> RenderAssetUsages {
+        RenderAssetUsages::default()
+    }
+    /// Converts an asset used only in the render world into a [`RenderAsset::ExtractedAsset`],
+    /// unloading its data from the main world.
+    ///
+    /// The data should be moved rather than copied where possible. The default implementation
+    /// falls back to [`RenderAsset::extract_asset`] and keeps the asset whole.
+    fn take_asset(&mut self) -> Self::ExtractedAsset {
+        self.extract_asset()
+    }
+    /// Whether the data of the asset was unloaded from the main world by
+    /// [`RenderAsset::take_asset`]. Unloaded assets aren't extracted again. Returns `false` by
+    /// default.
+    fn is_unloaded(&self) -> bool {
+        false
+    }
+    /// Clears the changes tracked on the asset once it has been extracted, so that the next
+    /// extraction only carries the changes made after this one. Does nothing by default.
+    fn clear_dirty(&mut self) {}
     /// Prepares the `extracted asset` for the GPU by transforming it into
     /// a [`RenderAsset::PreparedAsset`]. Therefore ECS data may be accessed via the `param`.
     fn prepare_asset(
         extracted_asset: Self::ExtractedAsset,
         param: &mut SystemParamItem<Self::Param>,
     ) -> Result<Self::PreparedAsset, PrepareAssetError<Self::ExtractedAsset>>;
+    /// Updates the `prepared_asset` of an asset that was already prepared from its new
+    /// `extracted_asset`.
+    ///
+    /// By default the asset is prepared again with [`RenderAsset::prepare_asset`]. Implementing
+    /// this allows uploading only the parts of the asset that changed.
+    fn update_asset(
+        extracted_asset: Self::ExtractedAsset,
+        prepared_asset: &mut Self::PreparedAsset,
+        param: &mut SystemParamItem<Self::Param>,
+    ) -> Result<(), PrepareAssetError<Self::ExtractedAsset>> {
+        *prepared_asset = Self::prepare_asset(extracted_asset, param)?;
+        Ok(())
+    }
 }
 
 #[derive(Clone, Hash, Debug, Default, PartialEq, Eq, SystemSet)]
@@ -93,9 +152,26 @@ impl<A: RenderAsset> Plugin for RenderAssetPlugin<A> {
                 .init_resource::<RenderAssets<A>>()
                 .init_resource::<PrepareNextFrameAssets<A>>()
                 .add_systems((
-                    extract_render_asset::<A>.in_schedule(ExtractSchedule),
+                    extract_render_asset::<A>
+                        .in_set(ExtractRenderAssetSet)
+                        .in_schedule(ExtractSchedule),
                     prepare_assets::<A>.in_set(self.prepare_asset_set.clone()),
                 ));
+
+            if !render_app.world.contains_resource::<RenderAssetUnloaders>() {
+                render_app
+                    .init_resource::<RenderAssetUnloaders>()
+                    .add_system(
+                        apply_render_asset_unloaders
+                            .after(ExtractRenderAssetSet)
+                            .in_schedule(ExtractSchedule),
+                    );
+            }
+            render_app
+                .world
+                .resource_mut::<RenderAssetUnloaders>()
+                .0
+                .push(unload_render_assets::<A>);
         }
     }
 }
@@ -105,6 +181,8 @@ impl<A: RenderAsset> Plugin for RenderAssetPlugin<A> {
 pub struct ExtractedAssets<A: RenderAsset> {
     extracted: Vec<(Handle<A>, A::ExtractedAsset)>,
     removed: Vec<Handle<A>>,
+    /// The assets used only in the render world, extracted by [`unload_render_assets`].
+    unloaded: Vec<Handle<A>>,
 }
 
 impl<A: RenderAsset> Default for ExtractedAssets<A> {
@@ -112,6 +190,7 @@ impl<A: RenderAsset> Default for ExtractedAssets<A> {
         Self {
             extracted: Default::default(),
             removed: Default::default(),
+            unloaded: Default::default(),
         }
     }
 }
@@ -127,10 +206,21 @@ impl<A: RenderAsset> Default for RenderAssets<A> {
     }
 }
 
+/// The systems extracting the [`RenderAsset`]s, which only read the main world.
+#[derive(Clone, Hash, Debug, PartialEq, Eq, SystemSet)]
+struct ExtractRenderAssetSet;
+
+/// The [`unload_render_assets`] system of every [`RenderAsset`] type.
+#[derive(Resource, Default)]
+struct RenderAssetUnloaders(Vec<fn(&mut World)>);
+
 /// This system extracts all crated or modified assets of the corresponding [`RenderAsset`] type
 /// into the "render world".
+///
+/// The assets used only in the render world are extracted later by [`unload_render_assets`],
+/// which needs to access them mutably.
 fn extract_render_asset<A: RenderAsset>(
-    mut commands: Commands,
+    mut extracted_assets: ResMut<ExtractedAssets<A>>,
     mut events: Extract<EventReader<AssetEvent<A>>>,
     assets: Extract<Res<Assets<A>>>,
 ) {
@@ -148,16 +238,78 @@ fn extract_render_asset<A: RenderAsset>(
         }
     }
 
-    let mut extracted_assets = Vec::new();
+    let mut extracted = Vec::new();
+    let mut unloaded = Vec::new();
     for handle in changed_assets.drain() {
-        if let Some(asset) = assets.get(&handle) {
-            extracted_assets.push((handle, asset.extract_asset()));
+        let Some(asset) = assets.get(&handle) else {
+            continue;
+        };
+        let usage = asset.asset_usage();
+        if !usage.contains(RenderAssetUsages::RENDER_WORLD) {
+            removed.push(handle);
+        } else if usage.contains(RenderAssetUsages::MAIN_WORLD) {
+            extracted.push((handle, asset.extract_asset()));
+        } else {
+            unloaded.push(handle);
         }
     }
 
-    commands.insert_resource(ExtractedAssets {
-        extracted: extracted_assets,
+    *extracted_assets = ExtractedAssets {
+        extracted,
         removed,
+        unloaded,
+    };
+}
+
+/// Runs the [`unload_render_assets`] systems of all the [`RenderAsset`] types, in a single
+/// exclusive step after the extraction of the render assets.
+fn apply_render_asset_unloaders(world: &mut World) {
+    world.resource_scope(|world, unloaders: Mut<RenderAssetUnloaders>| {
+        for unload in &unloaders.0 {
+            unload(world);
+        }
+    });
+}
+
+/// Extracts the assets used only in the render world by unloading their data from the main
+/// world, and clears the changes tracked on the extracted assets.
+///
+/// Assets that were already unloaded are not extracted again, their prepared asset is kept.
+fn unload_render_assets<A: RenderAsset>(world: &mut World) {
+    let extracted_assets = world.resource::<ExtractedAssets<A>>();
+    if extracted_assets.extracted.is_empty() && extracted_assets.unloaded.is_empty() {
+        return;
+    }
+
+    world.resource_scope(|world, mut extracted_assets: Mut<ExtractedAssets<A>>| {
+        let mut main_world = world.resource_mut::<MainWorld>();
+        let mut assets = main_world.resource_mut::<Assets<A>>();
+        let extracted_assets = &mut *extracted_assets;
+        // Unloading the asset mustn't be seen as a modification, or it would be extracted
+        // again on the next frame
+        for (handle, _) in &extracted_assets.extracted {
+            if let Some(asset) = assets.get_mut_untracked(handle) {
+                asset.clear_dirty();
+            }
+        }
+        for handle in extracted_assets.unloaded.drain(..) {
+            let Some(asset) = assets.get_mut_untracked(&handle) else {
+                continue;
+            };
+            if asset.is_unloaded() {
+                bevy_log::warn!(
+                    "{:?} was modified after its data was unloaded from the main world, it won't \
+                    be extracted again. Include `RenderAssetUsages::MAIN_WORLD` in its usage to \
+                    modify it after it is extracted.",
+                    handle
+                );
+            } else {
+                extracted_assets
+                    .extracted
+                    .push((handle, asset.take_asset()));
+            }
+            asset.clear_dirty();
+        }
     });
 }
 
@@ -178,6 +330,8 @@ impl<A: RenderAsset> Default for PrepareNextFrameAssets<A> {
 
 /// This system prepares all assets of the corresponding [`RenderAsset`] type
 /// which where extracted this frame for the GPU.
+///
+/// The assets that were already prepared are updated with [`RenderAsset::update_asset`].
 pub fn prepare_assets<R: RenderAsset>(
     mut extracted_assets: ResMut<ExtractedAssets<R>>,
     mut render_assets: ResMut<RenderAssets<R>>,
@@ -187,14 +341,13 @@ pub fn prepare_assets<R: RenderAsset>(
     let mut param = param.into_inner();
     let queued_assets = std::mem::take(&mut prepare_next_frame.assets);
     for (handle, extracted_asset) in queued_assets {
-        match R::prepare_asset(extracted_asset, &mut param) {
-            Ok(prepared_asset) => {
-                render_assets.insert(handle, prepared_asset);
-            }
-            Err(PrepareAssetError::RetryNextUpdate(extracted_asset)) => {
-                prepare_next_frame.assets.push((handle, extracted_asset));
-            }
-        }
+        prepare_asset(
+            handle,
+            extracted_asset,
+            &mut render_assets,
+            &mut prepare_next_frame,
+            &mut param,
+        );
     }
 
     for removed in std::mem::take(&mut extracted_assets.removed) {
@@ -202,13 +355,154 @@ pub fn prepare_assets<R: RenderAsset>(
     }
 
     for (handle, extracted_asset) in std::mem::take(&mut extracted_assets.extracted) {
-        match R::prepare_asset(extracted_asset, &mut param) {
-            Ok(prepared_asset) => {
-                render_assets.insert(handle, prepared_asset);
-            }
-            Err(PrepareAssetError::RetryNextUpdate(extracted_asset)) => {
-                prepare_next_frame.assets.push((handle, extracted_asset));
-            }
+        prepare_asset(
+            handle,
+            extracted_asset,
+            &mut render_assets,
+            &mut prepare_next_frame,
+            &mut param,
+        );
+    }
+}
+
+/// Prepares an extracted asset, or updates it if it was already prepared.
+fn prepare_asset<R: RenderAsset>(
+    handle: Handle<R>,
+    extracted_asset: R::ExtractedAsset,
+    render_assets: &mut RenderAssets<R>,
+    prepare_next_frame: &mut PrepareNextFrameAssets<R>,
+    param: &mut SystemParamItem<R::Param>,
+) {
+    let result = match render_assets.get_mut(&handle) {
+        Some(prepared_asset) => R::update_asset(extracted_asset, prepared_asset, param),
+        None => R::prepare_asset(extracted_asset, param).map(|prepared_asset| {
+            render_assets.insert(handle.clone_weak(), prepared_asset);
+        }),
+    };
+    if let Err(PrepareAssetError::RetryNextUpdate(extracted_asset)) = result {
+        prepare_next_frame.assets.push((handle, extracted_asset));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::{AddAsset, AssetPlugin};
+    use bevy_ecs::system::{IntoSystem, System};
+    use bevy_reflect::TypeUuid;
+
+    #[derive(TypeUuid)]
+    #[uuid = "0c1a7e4c-4b5e-4d0b-9a2c-6a3f0d5e8b21"]
+    struct TestAsset {
+        data: Vec<u8>,
+        usage: RenderAssetUsages,
+    }
+
+    impl RenderAsset for TestAsset {
+        type ExtractedAsset = Vec<u8>;
+        type PreparedAsset = usize;
+        type Param = ();
+
+        fn extract_asset(&self) -> Self::ExtractedAsset {
+            self.data.clone()
         }
+
+        fn asset_usage(&self) -> RenderAssetUsages {
+            self.usage
+        }
+
+        fn take_asset(&mut self) -> Self::ExtractedAsset {
+            std::mem::take(&mut self.data)
+        }
+
+        fn is_unloaded(&self) -> bool {
+            self.data.is_empty()
+        }
+
+        fn prepare_asset(
+            data: Self::ExtractedAsset,
+            _param: &mut SystemParamItem<Self::Param>,
+        ) -> Result<Self::PreparedAsset, PrepareAssetError<Self::ExtractedAsset>> {
+            Ok(data.len())
+        }
+
+        fn update_asset(
+            data: Self::ExtractedAsset,
+            prepared: &mut Self::PreparedAsset,
+            _param: &mut SystemParamItem<Self::Param>,
+        ) -> Result<(), PrepareAssetError<Self::ExtractedAsset>> {
+            *prepared += data.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn render_world_assets_are_unloaded_from_the_main_world() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<TestAsset>();
+        let mut assets = app.world.resource_mut::<Assets<TestAsset>>();
+        let mut add = |usage| {
+            assets.add(TestAsset {
+                data: vec![1, 2, 3],
+                usage,
+            })
+        };
+        let both = add(RenderAssetUsages::default());
+        let render_world_only = add(RenderAssetUsages::RENDER_WORLD);
+        let main_world_only = add(RenderAssetUsages::MAIN_WORLD);
+        app.update();
+
+        let mut render_world = World::new();
+        render_world.insert_resource(MainWorld(std::mem::take(&mut app.world)));
+        render_world.init_resource::<ExtractedAssets<TestAsset>>();
+        render_world.init_resource::<RenderAssets<TestAsset>>();
+        render_world.init_resource::<PrepareNextFrameAssets<TestAsset>>();
+        render_world.insert_resource(RenderAssetUnloaders(vec![
+            unload_render_assets::<TestAsset>,
+        ]));
+        let mut extract = IntoSystem::into_system(extract_render_asset::<TestAsset>);
+        extract.initialize(&mut render_world);
+        let mut prepare = IntoSystem::into_system(prepare_assets::<TestAsset>);
+        prepare.initialize(&mut render_world);
+        let mut run_frame = |render_world: &mut World| {
+            extract.run((), render_world);
+            apply_render_asset_unloaders(render_world);
+            let extracted = render_world.resource::<ExtractedAssets<TestAsset>>();
+            let extracted_count = extracted.extracted.len();
+            prepare.run((), render_world);
+            extracted_count
+        };
+
+        assert_eq!(run_frame(&mut render_world), 2);
+        let render_assets = render_world.resource::<RenderAssets<TestAsset>>();
+        assert_eq!(render_assets.get(&both), Some(&3));
+        assert_eq!(render_assets.get(&render_world_only), Some(&3));
+        assert_eq!(render_assets.get(&main_world_only), None);
+
+        let main_world = render_world.resource::<MainWorld>();
+        let assets = main_world.resource::<Assets<TestAsset>>();
+        let data = |handle| &assets.get(handle).unwrap().data;
+        assert_eq!(data(&both), &[1, 2, 3]);
+        assert!(data(&render_world_only).is_empty());
+        assert_eq!(data(&main_world_only), &[1, 2, 3]);
+
+        // Unloading the asset doesn't extract it again, and its GPU representation is kept
+        assert_eq!(run_frame(&mut render_world), 0);
+        let render_assets = render_world.resource::<RenderAssets<TestAsset>>();
+        assert_eq!(render_assets.get(&render_world_only), Some(&3));
+
+        // Modified assets update their GPU representation, unless their data was unloaded
+        let mut main_world = render_world.resource_mut::<MainWorld>();
+        let mut assets = main_world.resource_mut::<Assets<TestAsset>>();
+        assets.get_mut(&both).unwrap().data.push(4);
+        assets.get_mut(&render_world_only).unwrap();
+        let mut send_events = IntoSystem::into_system(Assets::<TestAsset>::asset_event_system);
+        send_events.initialize(&mut main_world);
+        send_events.run((), &mut main_world);
+        assert_eq!(run_frame(&mut render_world), 1);
+        let render_assets = render_world.resource::<RenderAssets<TestAsset>>();
+        assert_eq!(render_assets.get(&both), Some(&7));
+        assert_eq!(render_assets.get(&render_world_only), Some(&3));
     }
 }
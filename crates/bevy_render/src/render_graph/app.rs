@@ -0,0 +1,329 @@
+use bevy_app::App;
+use bevy_ecs::world::FromWorld;
+use bevy_utils::{all_tuples, tracing::warn};
+
+use super::{BoxedRenderLabel, Node, RenderGraph, RenderLabel, RenderSubGraph, SlotType};
+
+/// The name of the input slot through which nodes receive the view entity, such as
+/// [`ViewNodeRunner::IN_VIEW`](super::ViewNodeRunner::IN_VIEW).
+const IN_VIEW: &str = "view";
+
+/// A sequence of [`RenderLabel`]s, passed to [`RenderGraphApp::add_render_graph_edges`].
+///
+/// This is implemented for tuples of up to 16 labels, which can be of different types, and for
+/// arrays of labels.
+pub trait IntoRenderNodeLabels {
+    /// Returns the labels, in order.
+    fn into_labels(self) -> Vec<BoxedRenderLabel>;
+}
+
+impl<T: RenderLabel, const N: usize> IntoRenderNodeLabels for [T; N] {
+    fn into_labels(self) -> Vec<BoxedRenderLabel> {
+        self.iter().map(RenderLabel::dyn_clone).collect()
+    }
+}
+
+macro_rules! impl_into_render_node_labels {
+    ($($label: ident),*) => {
+        impl<$($label: RenderLabel),*> IntoRenderNodeLabels for ($($label,)*) {
+            #[allow(non_snake_case)]
+            fn into_labels(self) -> Vec<BoxedRenderLabel> {
+                let ($($label,)*) = self;
+                vec![$($label.dyn_clone()),*]
+            }
+        }
+    };
+}
+
+all_tuples!(impl_into_render_node_labels, 1, 16, T);
+
+/// Adds common [`RenderGraph`] operations to [`App`].
+///
+/// These are meant to be called on the `RenderApp`, after the sub graph they refer to
+/// was added, for example by `Core3dPlugin`.
+pub trait RenderGraphApp {
+    /// Adds a [`Node`] created with [`FromWorld`] to the sub graph `sub_graph`.
+    ///
+    /// If the node has a `view` entity input slot and the sub graph has an input node
+    /// providing an entity, like the view entity of `Core3d` and `Core2d`, they are connected.
+    fn add_render_graph_node<T: Node + FromWorld>(
+        &mut self,
+        sub_graph: impl RenderSubGraph,
+        node_label: impl RenderLabel,
+    ) -> &mut Self;
+
+    /// Adds node edges between consecutive nodes of `edges`, so that each node runs after
+    /// the one before it.
+    ///
+    /// `(A, B, C)` is equivalent to adding the `A -> B` and `B -> C` edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the nodes is not in the sub graph, with an error listing the nodes
+    /// that are.
+    fn add_render_graph_edges(
+        &mut self,
+        sub_graph: impl RenderSubGraph,
+        edges: impl IntoRenderNodeLabels,
+    ) -> &mut Self;
+
+    /// Adds a node edge so that `input_node` runs after `output_node`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is not in the sub graph.
+    fn add_render_graph_edge(
+        &mut self,
+        sub_graph: impl RenderSubGraph,
+        output_node: impl RenderLabel,
+        input_node: impl RenderLabel,
+    ) -> &mut Self;
+}
+
+impl RenderGraphApp for App {
+    fn add_render_graph_node<T: Node + FromWorld>(
+        &mut self,
+        sub_graph: impl RenderSubGraph,
+        node_label: impl RenderLabel,
+    ) -> &mut Self {
+        let node = T::from_world(&mut self.world);
+        let mut render_graph = self.world.get_resource_mut::<RenderGraph>().expect(
+            "RenderGraph not found. Make sure you are using add_render_graph_node on the RenderApp",
+        );
+        let Some(graph) = render_graph.get_sub_graph_mut(sub_graph.dyn_clone()) else {
+            warn!(
+                "Tried adding a render graph node to {sub_graph:?} but the sub graph doesn't exist"
+            );
+            return self;
+        };
+
+        let node_id = graph.add_node(node_label, node);
+        let view_slot = graph
+            .get_node_state(node_id)
+            .unwrap()
+            .input_slots
+            .get_slot(IN_VIEW)
+            .map(|slot| slot.slot_type);
+        let graph_view_slot = graph.get_input_node().and_then(|input_node| {
+            let index = input_node
+                .output_slots
+                .iter()
+                .position(|slot| slot.slot_type == SlotType::Entity)?;
+            Some((input_node.id, index))
+        });
+        if let (Some(SlotType::Entity), Some((input_node_id, index))) = (view_slot, graph_view_slot)
+        {
+            graph.add_slot_edge(input_node_id, index, node_id, IN_VIEW);
+        }
+        self
+    }
+
+    #[track_caller]
+    fn add_render_graph_edges(
+        &mut self,
+        sub_graph: impl RenderSubGraph,
+        edges: impl IntoRenderNodeLabels,
+    ) -> &mut Self {
+        let mut render_graph = self.world.get_resource_mut::<RenderGraph>().expect(
+            "RenderGraph not found. Make sure you are using add_render_graph_edges on the RenderApp",
+        );
+        let Some(graph) = render_graph.get_sub_graph_mut(sub_graph.dyn_clone()) else {
+            warn!(
+                "Tried adding render graph edges to {sub_graph:?} but the sub graph doesn't exist"
+            );
+            return self;
+        };
+
+        // Check all the nodes first, so that no edge is added when one of them is missing.
+        let edges = edges.into_labels();
+        for label in &edges {
+            if let Err(err) = graph.get_node_id(label.clone()) {
+                panic!("{err}");
+            }
+        }
+        for window in edges.windows(2) {
+            graph.add_node_edge(window[0].clone(), window[1].clone());
+        }
+        self
+    }
+
+    #[track_caller]
+    fn add_render_graph_edge(
+        &mut self,
+        sub_graph: impl RenderSubGraph,
+        output_node: impl RenderLabel,
+        input_node: impl RenderLabel,
+    ) -> &mut Self {
+        self.add_render_graph_edges(sub_graph, (output_node, input_node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenderGraphApp;
+    use crate::{
+        render_graph::{
+            Edge, Node, NodeRunError, RenderGraph, RenderGraphContext, RenderLabel, SlotInfo,
+            SlotType, ViewNode, ViewNodeRunner,
+        },
+        renderer::RenderContext,
+    };
+    use bevy_app::App;
+    use bevy_ecs::{prelude::*, query::QueryItem};
+    use bevy_utils::HashSet;
+
+    const SUB_GRAPH: &str = "sub_graph";
+    const VIEW_ENTITY: &str = "view_entity";
+    const FIRST: &str = "first";
+    const CUSTOM: &str = "custom";
+    const LAST: &str = "last";
+
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+    struct TypedNode;
+
+    #[derive(Default)]
+    struct PassNode;
+
+    impl Node for PassNode {
+        fn run(
+            &self,
+            _graph: &mut RenderGraphContext,
+            _render_context: &mut RenderContext,
+            _world: &World,
+        ) -> Result<(), NodeRunError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct CustomViewNode;
+
+    impl ViewNode for CustomViewNode {
+        type ViewQuery = Entity;
+
+        fn run(
+            &self,
+            _graph: &mut RenderGraphContext,
+            _render_context: &mut RenderContext,
+            _view_query: QueryItem<Self::ViewQuery>,
+            _world: &World,
+        ) -> Result<(), NodeRunError> {
+            Ok(())
+        }
+    }
+
+    fn render_app() -> App {
+        let mut app = App::empty();
+        let mut sub_graph = RenderGraph::default();
+        sub_graph.set_input(vec![SlotInfo::new(VIEW_ENTITY, SlotType::Entity)]);
+        sub_graph.add_node(FIRST, PassNode);
+        sub_graph.add_node(LAST, PassNode);
+        sub_graph.add_node_edge(FIRST, LAST);
+        let mut graph = RenderGraph::default();
+        graph.add_sub_graph(SUB_GRAPH, sub_graph);
+        app.insert_resource(graph);
+        app
+    }
+
+    fn sub_graph(app: &App) -> &RenderGraph {
+        app.world
+            .resource::<RenderGraph>()
+            .get_sub_graph(SUB_GRAPH)
+            .unwrap()
+    }
+
+    #[test]
+    fn view_node_is_inserted_between_edges() {
+        let mut app = render_app();
+        app.add_render_graph_node::<ViewNodeRunner<CustomViewNode>>(SUB_GRAPH, CUSTOM)
+            .add_render_graph_edges(SUB_GRAPH, (FIRST, CUSTOM, LAST));
+
+        let graph = sub_graph(&app);
+        let first = graph.get_node_id(FIRST).unwrap();
+        let custom = graph.get_node_id(CUSTOM).unwrap();
+        let last = graph.get_node_id(LAST).unwrap();
+        assert!(graph.has_edge(&Edge::NodeEdge {
+            output_node: first,
+            input_node: custom,
+        }));
+        assert!(graph.has_edge(&Edge::NodeEdge {
+            output_node: custom,
+            input_node: last,
+        }));
+
+        // The custom node only runs once both `first` and the graph input have run.
+        let dependencies = graph
+            .iter_node_inputs(CUSTOM)
+            .unwrap()
+            .map(|(_, node)| node.id)
+            .collect::<HashSet<_>>();
+        assert_eq!(dependencies, HashSet::from([graph.input_node().id, first]));
+        assert!(graph
+            .get_node_state(custom)
+            .unwrap()
+            .validate_input_slots()
+            .is_ok());
+    }
+
+    #[test]
+    fn node_without_view_input_is_not_connected_to_the_graph_input() {
+        let mut app = render_app();
+        app.add_render_graph_node::<PassNode>(SUB_GRAPH, CUSTOM)
+            .add_render_graph_edge(SUB_GRAPH, FIRST, CUSTOM);
+
+        let graph = sub_graph(&app);
+        let first = graph.get_node_id(FIRST).unwrap();
+        let dependencies = graph
+            .iter_node_inputs(CUSTOM)
+            .unwrap()
+            .map(|(_, node)| node.id)
+            .collect::<Vec<_>>();
+        assert_eq!(dependencies, vec![first]);
+    }
+
+    #[test]
+    fn missing_sub_graph_is_ignored() {
+        let mut app = render_app();
+        app.add_render_graph_node::<PassNode>("missing", CUSTOM)
+            .add_render_graph_edges("missing", (FIRST, CUSTOM));
+
+        assert!(sub_graph(&app).get_node_id(CUSTOM).is_err());
+    }
+
+    #[test]
+    fn edges_mix_typed_and_string_labels() {
+        let mut app = render_app();
+        app.add_render_graph_node::<PassNode>(SUB_GRAPH, TypedNode)
+            .add_render_graph_edges(SUB_GRAPH, (FIRST, TypedNode, LAST));
+
+        let graph = sub_graph(&app);
+        let typed = graph.get_node_id(TypedNode).unwrap();
+        assert!(graph.has_edge(&Edge::NodeEdge {
+            output_node: graph.get_node_id(FIRST).unwrap(),
+            input_node: typed,
+        }));
+        assert!(graph.has_edge(&Edge::NodeEdge {
+            output_node: typed,
+            input_node: graph.get_node_id(LAST).unwrap(),
+        }));
+    }
+
+    #[test]
+    #[should_panic(expected = "node Label(TypedNode) does not exist in sub graph \"sub_graph\"")]
+    fn edges_to_missing_node_panic() {
+        render_app().add_render_graph_edges(SUB_GRAPH, (FIRST, TypedNode, LAST));
+    }
+
+    #[test]
+    fn edges_are_not_added_when_a_node_is_missing() {
+        let mut app = render_app();
+        app.add_render_graph_node::<PassNode>(SUB_GRAPH, CUSTOM);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            app.add_render_graph_edges(SUB_GRAPH, [CUSTOM, LAST, "missing"]);
+        }));
+
+        assert!(result.is_err());
+        let graph = sub_graph(&app);
+        assert!(graph.iter_node_outputs(CUSTOM).unwrap().next().is_none());
+    }
+}
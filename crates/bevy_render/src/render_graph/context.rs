@@ -1,5 +1,8 @@
 use crate::{
-    render_graph::{NodeState, RenderGraph, SlotInfos, SlotLabel, SlotType, SlotValue},
+    render_graph::{
+        BoxedRenderSubGraph, NodeState, RenderGraph, RenderSubGraph, SlotInfos, SlotLabel,
+        SlotType, SlotValue,
+    },
     render_resource::{Buffer, Sampler, TextureView},
 };
 use bevy_ecs::entity::Entity;
@@ -9,7 +12,7 @@ use thiserror::Error;
 /// A command that signals the graph runner to run the sub graph corresponding to the `name`
 /// with the specified `inputs` next.
 pub struct RunSubGraph {
-    pub name: Cow<'static, str>,
+    pub name: BoxedRenderSubGraph,
     pub inputs: Vec<SlotValue>,
 }
 
@@ -161,13 +164,13 @@ impl<'a> RenderGraphContext<'a> {
     /// Queues up a sub graph for execution after the node has finished running.
     pub fn run_sub_graph(
         &mut self,
-        name: impl Into<Cow<'static, str>>,
+        name: impl RenderSubGraph,
         inputs: Vec<SlotValue>,
     ) -> Result<(), RunSubGraphError> {
-        let name = name.into();
+        let name = name.dyn_clone();
         let sub_graph = self
             .graph
-            .get_sub_graph(&name)
+            .get_sub_graph(name.clone())
             .ok_or_else(|| RunSubGraphError::MissingSubGraph(name.clone()))?;
         if let Some(input_node) = sub_graph.get_input_node() {
             for (i, input_slot) in input_node.input_slots.iter().enumerate() {
@@ -207,19 +210,19 @@ impl<'a> RenderGraphContext<'a> {
 
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum RunSubGraphError {
-    #[error("attempted to run sub-graph `{0}`, but it does not exist")]
-    MissingSubGraph(Cow<'static, str>),
-    #[error("attempted to pass inputs to sub-graph `{0}`, which has no input slots")]
-    SubGraphHasNoInputs(Cow<'static, str>),
+    #[error("attempted to run sub-graph {0:?}, but it does not exist")]
+    MissingSubGraph(BoxedRenderSubGraph),
+    #[error("attempted to pass inputs to sub-graph {0:?}, which has no input slots")]
+    SubGraphHasNoInputs(BoxedRenderSubGraph),
     #[error("sub graph (name: `{graph_name:?}`) could not be run because slot `{slot_name}` at index {slot_index} has no value")]
     MissingInput {
         slot_index: usize,
         slot_name: Cow<'static, str>,
-        graph_name: Cow<'static, str>,
+        graph_name: BoxedRenderSubGraph,
     },
     #[error("attempted to use the wrong type for input slot")]
     MismatchedInputSlotType {
-        graph_name: Cow<'static, str>,
+        graph_name: BoxedRenderSubGraph,
         slot_index: usize,
         label: SlotLabel,
         expected: SlotType,
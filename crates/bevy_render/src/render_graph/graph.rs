@@ -1,16 +1,42 @@
 use crate::{
     render_graph::{
-        Edge, Node, NodeId, NodeLabel, NodeRunError, NodeState, RenderGraphContext,
-        RenderGraphError, SlotInfo, SlotLabel,
+        BoxedRenderLabel, Edge, Node, NodeId, NodeLabel, NodeRunError, NodeState,
+        RenderGraphContext, RenderGraphError, RenderLabel, SlotInfo, SlotLabel,
     },
     renderer::RenderContext,
 };
 use bevy_ecs::{prelude::World, system::Resource};
-use bevy_utils::HashMap;
+use bevy_utils::{define_boxed_label, HashMap};
 use std::{borrow::Cow, fmt::Debug};
 
 use super::EdgeExistence;
 
+pub use bevy_render_macros::RenderSubGraph;
+
+define_boxed_label!(RenderSubGraph);
+
+/// A shorthand for `Box<dyn RenderSubGraph>`.
+pub type BoxedRenderSubGraph = Box<dyn RenderSubGraph>;
+
+// Like node labels, sub graph labels can still be strings, which all compare as `Cow<'static, str>`.
+impl RenderSubGraph for Cow<'static, str> {
+    fn dyn_clone(&self) -> BoxedRenderSubGraph {
+        Box::new(self.clone())
+    }
+}
+
+impl RenderSubGraph for &'static str {
+    fn dyn_clone(&self) -> BoxedRenderSubGraph {
+        Box::new(Cow::Borrowed(*self))
+    }
+}
+
+impl RenderSubGraph for String {
+    fn dyn_clone(&self) -> BoxedRenderSubGraph {
+        Box::new(Cow::<'static, str>::Owned(self.clone()))
+    }
+}
+
 /// The render graph configures the modular, parallel and re-usable render logic.
 /// It is a retained and stateless (nodes themselves may have their own internal state) structure,
 /// which can not be modified while it is executed by the graph runner.
@@ -51,9 +77,11 @@ use super::EdgeExistence;
 #[derive(Resource, Default)]
 pub struct RenderGraph {
     nodes: HashMap<NodeId, NodeState>,
-    node_names: HashMap<Cow<'static, str>, NodeId>,
-    sub_graphs: HashMap<Cow<'static, str>, RenderGraph>,
+    node_labels: HashMap<BoxedRenderLabel, NodeId>,
+    sub_graphs: HashMap<BoxedRenderSubGraph, RenderGraph>,
     input_node: Option<NodeId>,
+    /// The label this graph was added with by [`RenderGraph::add_sub_graph`], used in errors.
+    label: Option<BoxedRenderSubGraph>,
 }
 
 impl RenderGraph {
@@ -104,29 +132,25 @@ impl RenderGraph {
         self.get_input_node().unwrap()
     }
 
-    /// Adds the `node` with the `name` to the graph.
-    /// If the name is already present replaces it instead.
-    pub fn add_node<T>(&mut self, name: impl Into<Cow<'static, str>>, node: T) -> NodeId
+    /// Adds the `node` with the `label` to the graph.
+    /// If the label is already present replaces it instead.
+    pub fn add_node<T>(&mut self, label: impl RenderLabel, node: T) -> NodeId
     where
         T: Node,
     {
         let id = NodeId::new();
-        let name = name.into();
+        let label = label.dyn_clone();
         let mut node_state = NodeState::new(id, node);
-        node_state.name = Some(name.clone());
+        node_state.label = Some(label.clone());
         self.nodes.insert(id, node_state);
-        self.node_names.insert(name, id);
+        self.node_labels.insert(label, id);
         id
     }
 
-    /// Removes the `node` with the `name` from the graph.
-    /// If the name is does not exist, nothing happens.
-    pub fn remove_node(
-        &mut self,
-        name: impl Into<Cow<'static, str>>,
-    ) -> Result<(), RenderGraphError> {
-        let name = name.into();
-        if let Some(id) = self.node_names.remove(&name) {
+    /// Removes the `node` with the `label` from the graph.
+    /// If the label does not exist, nothing happens.
+    pub fn remove_node(&mut self, label: impl RenderLabel) -> Result<(), RenderGraphError> {
+        if let Some(id) = self.node_labels.remove(&label.dyn_clone()) {
             if let Some(node_state) = self.nodes.remove(&id) {
                 // Remove all edges from other nodes to this one. Note that as we're removing this
                 // node, we don't need to remove its input edges
@@ -178,7 +202,7 @@ impl RenderGraph {
         let node_id = self.get_node_id(&label)?;
         self.nodes
             .get(&node_id)
-            .ok_or(RenderGraphError::InvalidNode(label))
+            .ok_or_else(|| self.invalid_node(label))
     }
 
     /// Retrieves the [`NodeState`] referenced by the `label` mutably.
@@ -188,9 +212,10 @@ impl RenderGraph {
     ) -> Result<&mut NodeState, RenderGraphError> {
         let label = label.into();
         let node_id = self.get_node_id(&label)?;
-        self.nodes
-            .get_mut(&node_id)
-            .ok_or(RenderGraphError::InvalidNode(label))
+        if !self.nodes.contains_key(&node_id) {
+            return Err(self.invalid_node(label));
+        }
+        Ok(self.nodes.get_mut(&node_id).unwrap())
     }
 
     /// Retrieves the [`NodeId`] referenced by the `label`.
@@ -198,11 +223,22 @@ impl RenderGraph {
         let label = label.into();
         match label {
             NodeLabel::Id(id) => Ok(id),
-            NodeLabel::Name(ref name) => self
-                .node_names
-                .get(name)
+            NodeLabel::Label(ref node_label) => self
+                .node_labels
+                .get(node_label)
                 .cloned()
-                .ok_or(RenderGraphError::InvalidNode(label)),
+                .ok_or_else(|| self.invalid_node(label)),
+        }
+    }
+
+    /// Creates the [`RenderGraphError::InvalidNode`] error for a `label` missing from this graph.
+    fn invalid_node(&self, label: NodeLabel) -> RenderGraphError {
+        let mut existing_nodes = self.node_labels.keys().cloned().collect::<Vec<_>>();
+        existing_nodes.sort_by_cached_key(|label| format!("{label:?}"));
+        RenderGraphError::InvalidNode {
+            label,
+            sub_graph: self.label.clone(),
+            existing_nodes,
         }
     }
 
@@ -285,6 +321,7 @@ impl RenderGraph {
     /// # See also
     ///
     /// - [`try_add_slot_edge`](Self::try_add_slot_edge) for a fallible version.
+    #[track_caller]
     pub fn add_slot_edge(
         &mut self,
         output_node: impl Into<NodeLabel>,
@@ -292,8 +329,9 @@ impl RenderGraph {
         input_node: impl Into<NodeLabel>,
         input_slot: impl Into<SlotLabel>,
     ) {
-        self.try_add_slot_edge(output_node, output_slot, input_node, input_slot)
-            .unwrap();
+        if let Err(err) = self.try_add_slot_edge(output_node, output_slot, input_node, input_slot) {
+            panic!("failed to add a slot edge: {err}");
+        }
     }
 
     /// Removes the [`Edge::SlotEdge`] from the graph. If any nodes or slots do not exist then
@@ -383,12 +421,15 @@ impl RenderGraph {
     /// # See also
     ///
     /// - [`try_add_node_edge`](Self::try_add_node_edge) for a fallible version.
+    #[track_caller]
     pub fn add_node_edge(
         &mut self,
         output_node: impl Into<NodeLabel>,
         input_node: impl Into<NodeLabel>,
     ) {
-        self.try_add_node_edge(output_node, input_node).unwrap();
+        if let Err(err) = self.try_add_node_edge(output_node, input_node) {
+            panic!("failed to add a node edge: {err}");
+        }
     }
 
     /// Removes the [`Edge::NodeEdge`] from the graph. If either node does not exist then nothing
@@ -517,17 +558,19 @@ impl RenderGraph {
     }
 
     /// Returns an iterator over the sub graphs.
-    pub fn iter_sub_graphs(&self) -> impl Iterator<Item = (&str, &RenderGraph)> {
+    pub fn iter_sub_graphs(&self) -> impl Iterator<Item = (&dyn RenderSubGraph, &RenderGraph)> {
         self.sub_graphs
             .iter()
-            .map(|(name, graph)| (name.as_ref(), graph))
+            .map(|(label, graph)| (label.as_ref(), graph))
     }
 
     /// Returns an iterator over the sub graphs, that allows modifying each value.
-    pub fn iter_sub_graphs_mut(&mut self) -> impl Iterator<Item = (&str, &mut RenderGraph)> {
+    pub fn iter_sub_graphs_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&dyn RenderSubGraph, &mut RenderGraph)> {
         self.sub_graphs
             .iter_mut()
-            .map(|(name, graph)| (name.as_ref(), graph))
+            .map(|(label, graph)| (label.as_ref(), graph))
     }
 
     /// Returns an iterator over a tuple of the input edges and the corresponding output nodes
@@ -562,26 +605,28 @@ impl RenderGraph {
             .map(move |(edge, input_node_id)| (edge, self.get_node_state(input_node_id).unwrap())))
     }
 
-    /// Adds the `sub_graph` with the `name` to the graph.
-    /// If the name is already present replaces it instead.
-    pub fn add_sub_graph(&mut self, name: impl Into<Cow<'static, str>>, sub_graph: RenderGraph) {
-        self.sub_graphs.insert(name.into(), sub_graph);
+    /// Adds the `sub_graph` with the `label` to the graph.
+    /// If the label is already present replaces it instead.
+    pub fn add_sub_graph(&mut self, label: impl RenderSubGraph, mut sub_graph: RenderGraph) {
+        let label = label.dyn_clone();
+        sub_graph.label = Some(label.clone());
+        self.sub_graphs.insert(label, sub_graph);
     }
 
-    /// Removes the `sub_graph` with the `name` from the graph.
-    /// If the name does not exist then nothing happens.
-    pub fn remove_sub_graph(&mut self, name: impl Into<Cow<'static, str>>) {
-        self.sub_graphs.remove(&name.into());
+    /// Removes the `sub_graph` with the `label` from the graph.
+    /// If the label does not exist then nothing happens.
+    pub fn remove_sub_graph(&mut self, label: impl RenderSubGraph) {
+        self.sub_graphs.remove(&label.dyn_clone());
     }
 
-    /// Retrieves the sub graph corresponding to the `name`.
-    pub fn get_sub_graph(&self, name: impl AsRef<str>) -> Option<&RenderGraph> {
-        self.sub_graphs.get(name.as_ref())
+    /// Retrieves the sub graph corresponding to the `label`.
+    pub fn get_sub_graph(&self, label: impl RenderSubGraph) -> Option<&RenderGraph> {
+        self.sub_graphs.get(&label.dyn_clone())
     }
 
-    /// Retrieves the sub graph corresponding to the `name` mutably.
-    pub fn get_sub_graph_mut(&mut self, name: impl AsRef<str>) -> Option<&mut RenderGraph> {
-        self.sub_graphs.get_mut(name.as_ref())
+    /// Retrieves the sub graph corresponding to the `label` mutably.
+    pub fn get_sub_graph_mut(&mut self, label: impl RenderSubGraph) -> Option<&mut RenderGraph> {
+        self.sub_graphs.get_mut(&label.dyn_clone())
     }
 }
 
@@ -630,13 +675,26 @@ impl Node for GraphInputNode {
 mod tests {
     use crate::{
         render_graph::{
-            Edge, Node, NodeId, NodeRunError, RenderGraph, RenderGraphContext, RenderGraphError,
-            SlotInfo, SlotType,
+            BoxedRenderLabel, Edge, Node, NodeId, NodeLabel, NodeRunError, RenderGraph,
+            RenderGraphContext, RenderGraphError, RenderLabel, RenderSubGraph, SlotInfo, SlotType,
         },
         renderer::RenderContext,
     };
     use bevy_ecs::world::World;
     use bevy_utils::HashSet;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+    enum TestLabel {
+        A,
+        B,
+    }
+
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderSubGraph)]
+    struct TestSubGraph;
 
     #[derive(Debug)]
     struct TestNode {
@@ -803,4 +861,72 @@ mod tests {
             "Adding to a duplicate edge should return an error"
         );
     }
+
+    fn label(label: impl RenderLabel) -> BoxedRenderLabel {
+        label.dyn_clone()
+    }
+
+    #[test]
+    fn test_labels_equality() {
+        fn hash(label: impl RenderLabel) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            label.dyn_clone().hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Strings of any kind name the same node.
+        assert_eq!(&*label("A"), &*label(String::from("A")));
+        assert_eq!(hash("A"), hash(String::from("A")));
+        assert_eq!(NodeLabel::from("A"), NodeLabel::from(String::from("A")));
+
+        // Typed labels are only equal to themselves, whether boxed or not.
+        assert_eq!(
+            NodeLabel::from(TestLabel::A),
+            NodeLabel::from(TestLabel::A.dyn_clone())
+        );
+        assert_ne!(NodeLabel::from(TestLabel::A), NodeLabel::from(TestLabel::B));
+        assert_ne!(NodeLabel::from(TestLabel::A), NodeLabel::from("A"));
+
+        let mut graph = RenderGraph::default();
+        let id = graph.add_node(TestLabel::A, TestNode::new(0, 0));
+        assert_eq!(graph.get_node_id(TestLabel::A.dyn_clone()), Ok(id));
+        assert!(graph.get_node_id("A").is_err());
+    }
+
+    #[test]
+    fn test_invalid_node_error() {
+        let mut sub_graph = RenderGraph::default();
+        sub_graph.add_node(TestLabel::B, TestNode::new(0, 0));
+        sub_graph.add_node("C", TestNode::new(0, 0));
+        let mut graph = RenderGraph::default();
+        graph.add_sub_graph(TestSubGraph, sub_graph);
+        let sub_graph = graph.get_sub_graph_mut(TestSubGraph).unwrap();
+
+        let err = sub_graph
+            .try_add_node_edge(TestLabel::A, TestLabel::B)
+            .unwrap_err();
+        let RenderGraphError::InvalidNode {
+            label: missing,
+            sub_graph: error_sub_graph,
+            existing_nodes,
+        } = &err
+        else {
+            panic!("expected an invalid node error, got {err:?}");
+        };
+        assert_eq!(missing, &NodeLabel::from(TestLabel::A));
+        assert_eq!(error_sub_graph, &Some(TestSubGraph.dyn_clone()));
+        assert_eq!(existing_nodes, &vec![label("C"), label(TestLabel::B)]);
+        assert_eq!(
+            err.to_string(),
+            "node Label(A) does not exist in sub graph TestSubGraph, existing nodes are [\"C\", B]"
+        );
+
+        let err = RenderGraph::default()
+            .try_add_node_edge("A", "B")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "node Label(\"A\") does not exist in the main render graph, existing nodes are []"
+        );
+    }
 }
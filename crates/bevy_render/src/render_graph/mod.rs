@@ -1,9 +1,11 @@
+mod app;
 mod context;
 mod edge;
 mod graph;
 mod node;
 mod node_slot;
 
+pub use app::*;
 pub use context::*;
 pub use edge::*;
 pub use graph::*;
@@ -14,8 +16,17 @@ use thiserror::Error;
 
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum RenderGraphError {
-    #[error("node does not exist")]
-    InvalidNode(NodeLabel),
+    #[error(
+        "node {label:?} does not exist in {}, existing nodes are {existing_nodes:?}",
+        describe_graph(.sub_graph)
+    )]
+    InvalidNode {
+        label: NodeLabel,
+        /// The sub graph the node was looked up in, or `None` for the main render graph.
+        sub_graph: Option<BoxedRenderSubGraph>,
+        /// The labels of the nodes of that graph, sorted by their [`Debug`] representation.
+        existing_nodes: Vec<BoxedRenderLabel>,
+    },
     #[error("output node slot does not exist")]
     InvalidOutputNodeSlot(SlotLabel),
     #[error("input node slot does not exist")]
@@ -44,3 +55,10 @@ pub enum RenderGraphError {
         occupied_by_node: NodeId,
     },
 }
+
+fn describe_graph(sub_graph: &Option<BoxedRenderSubGraph>) -> String {
+    match sub_graph {
+        Some(sub_graph) => format!("sub graph {sub_graph:?}"),
+        None => "the main render graph".to_string(),
+    }
+}
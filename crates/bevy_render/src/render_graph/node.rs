@@ -1,16 +1,48 @@
 use crate::{
     define_atomic_id,
     render_graph::{
-        Edge, InputSlotError, OutputSlotError, RenderGraphContext, RenderGraphError,
-        RunSubGraphError, SlotInfo, SlotInfos, SlotType, SlotValue,
+        BoxedRenderSubGraph, Edge, InputSlotError, OutputSlotError, RenderGraphContext,
+        RenderGraphError, RenderSubGraph, RunSubGraphError, SlotInfo, SlotInfos, SlotType,
+        SlotValue,
     },
     renderer::RenderContext,
 };
-use bevy_ecs::world::World;
+use bevy_ecs::{
+    query::{QueryItem, QueryState, ReadOnlyWorldQuery},
+    world::{FromWorld, World},
+};
+use bevy_utils::define_boxed_label;
 use downcast_rs::{impl_downcast, Downcast};
 use std::{borrow::Cow, fmt::Debug};
 use thiserror::Error;
 
+pub use bevy_render_macros::RenderLabel;
+
+define_boxed_label!(RenderLabel);
+
+/// A shorthand for `Box<dyn RenderLabel>`.
+pub type BoxedRenderLabel = Box<dyn RenderLabel>;
+
+// Strings can still be used as labels. They are all stored as `Cow<'static, str>`, so that a
+// `&'static str` and a `String` with the same contents name the same node.
+impl RenderLabel for Cow<'static, str> {
+    fn dyn_clone(&self) -> BoxedRenderLabel {
+        Box::new(self.clone())
+    }
+}
+
+impl RenderLabel for &'static str {
+    fn dyn_clone(&self) -> BoxedRenderLabel {
+        Box::new(Cow::Borrowed(*self))
+    }
+}
+
+impl RenderLabel for String {
+    fn dyn_clone(&self) -> BoxedRenderLabel {
+        Box::new(Cow::<'static, str>::Owned(self.clone()))
+    }
+}
+
 define_atomic_id!(NodeId);
 
 /// A render node that can be added to a [`RenderGraph`](super::RenderGraph).
@@ -182,7 +214,7 @@ impl Edges {
 /// The `input_slots` and `output_slots` are provided by the `node`.
 pub struct NodeState {
     pub id: NodeId,
-    pub name: Option<Cow<'static, str>>,
+    pub label: Option<BoxedRenderLabel>,
     /// The name of the type that implements [`Node`].
     pub type_name: &'static str,
     pub node: Box<dyn Node>,
@@ -193,7 +225,7 @@ pub struct NodeState {
 
 impl Debug for NodeState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{:?} ({:?})", self.id, self.name)
+        writeln!(f, "{:?} ({:?})", self.id, self.label)
     }
 }
 
@@ -206,7 +238,7 @@ impl NodeState {
     {
         NodeState {
             id,
-            name: None,
+            label: None,
             input_slots: node.input().into(),
             output_slots: node.output().into(),
             node: Box::new(node),
@@ -258,12 +290,12 @@ impl NodeState {
     }
 }
 
-/// A [`NodeLabel`] is used to reference a [`NodeState`] by either its name or [`NodeId`]
+/// A [`NodeLabel`] is used to reference a [`NodeState`] by either its [`RenderLabel`] or [`NodeId`]
 /// inside the [`RenderGraph`](super::RenderGraph).
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum NodeLabel {
     Id(NodeId),
-    Name(Cow<'static, str>),
+    Label(BoxedRenderLabel),
 }
 
 impl From<&NodeLabel> for NodeLabel {
@@ -272,15 +304,9 @@ impl From<&NodeLabel> for NodeLabel {
     }
 }
 
-impl From<String> for NodeLabel {
-    fn from(value: String) -> Self {
-        NodeLabel::Name(value.into())
-    }
-}
-
-impl From<&'static str> for NodeLabel {
-    fn from(value: &'static str) -> Self {
-        NodeLabel::Name(value.into())
+impl<T: RenderLabel> From<T> for NodeLabel {
+    fn from(value: T) -> Self {
+        NodeLabel::Label(value.dyn_clone())
     }
 }
 
@@ -306,17 +332,17 @@ impl Node for EmptyNode {
     }
 }
 
-/// A [`RenderGraph`](super::RenderGraph) [`Node`] that takes a view entity as input and runs the configured graph name once.
+/// A [`RenderGraph`](super::RenderGraph) [`Node`] that takes a view entity as input and runs the configured sub graph once.
 /// This makes it easier to insert sub-graph runs into a graph.
 pub struct RunGraphOnViewNode {
-    graph_name: Cow<'static, str>,
+    sub_graph: BoxedRenderSubGraph,
 }
 
 impl RunGraphOnViewNode {
     pub const IN_VIEW: &'static str = "view";
-    pub fn new<T: Into<Cow<'static, str>>>(graph_name: T) -> Self {
+    pub fn new(sub_graph: impl RenderSubGraph) -> Self {
         Self {
-            graph_name: graph_name.into(),
+            sub_graph: sub_graph.dyn_clone(),
         }
     }
 }
@@ -332,10 +358,87 @@ impl Node for RunGraphOnViewNode {
         _world: &World,
     ) -> Result<(), NodeRunError> {
         let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
-        graph.run_sub_graph(
-            self.graph_name.clone(),
-            vec![SlotValue::Entity(view_entity)],
-        )?;
+        graph.run_sub_graph(self.sub_graph.clone(), vec![SlotValue::Entity(view_entity)])?;
+        Ok(())
+    }
+}
+
+/// This trait should be used instead of the [`Node`] trait when making a render node that runs on a view.
+///
+/// It is intended to be used with [`ViewNodeRunner`], which queries the [`ViewNode::ViewQuery`]
+/// on the view entity passed to its `view` input slot, and runs the node only when the query
+/// matches.
+pub trait ViewNode {
+    /// The query that will be used on the view entity.
+    /// It is guaranteed to run on the view entity, so there's no need for a filter.
+    type ViewQuery: ReadOnlyWorldQuery;
+
+    /// Updates internal node state using the current render [`World`] prior to the run method.
+    fn update(&mut self, _world: &mut World) {}
+
+    /// Runs the graph node logic, issues draw calls, updates the output slots and
+    /// optionally queues up subgraphs for execution. The graph data, input and output values are
+    /// passed via the [`RenderGraphContext`].
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        view_query: QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError>;
+}
+
+/// This [`Node`] can be used to run any [`ViewNode`].
+/// It will take care of updating the view query in `update()` and running the query in `run()`.
+///
+/// This [`Node`] exists to help reduce boilerplate when making a render node that runs on a view.
+pub struct ViewNodeRunner<N: ViewNode> {
+    view_query: QueryState<N::ViewQuery>,
+    node: N,
+}
+
+impl<N: ViewNode> ViewNodeRunner<N> {
+    pub const IN_VIEW: &'static str = "view";
+
+    pub fn new(node: N, world: &mut World) -> Self {
+        Self {
+            view_query: world.query(),
+            node,
+        }
+    }
+}
+
+impl<N: ViewNode + FromWorld> FromWorld for ViewNodeRunner<N> {
+    fn from_world(world: &mut World) -> Self {
+        Self::new(N::from_world(world), world)
+    }
+}
+
+impl<N> Node for ViewNodeRunner<N>
+where
+    N: ViewNode + Send + Sync + 'static,
+{
+    fn input(&self) -> Vec<SlotInfo> {
+        vec![SlotInfo::new(Self::IN_VIEW, SlotType::Entity)]
+    }
+
+    fn update(&mut self, world: &mut World) {
+        self.view_query.update_archetypes(world);
+        self.node.update(world);
+    }
+
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
+        let Ok(view) = self.view_query.get_manual(world, view_entity) else {
+            return Ok(());
+        };
+
+        self.node.run(graph, render_context, view, world)?;
         Ok(())
     }
 }
@@ -24,21 +24,32 @@
 //!
 //! The [`Draw`] function trait can either be implemented directly or such a function can be
 //! created by composing multiple [`RenderCommand`]s.
+//!
+//! A custom phase needs a [`PhaseItem`], which can be derived, and a [`SortedRenderPhasePlugin`]
+//! to add it to the cameras that should render it. Its items are then drawn by a render graph
+//! node, usually a [`ViewNode`](crate::render_graph::ViewNode) added with
+//! [`RenderGraphApp`](crate::render_graph::RenderGraphApp).
 
 mod draw;
 mod draw_state;
 mod rangefinder;
 
+pub use bevy_render_macros::PhaseItem;
 pub use draw::*;
 pub use draw_state::*;
 pub use rangefinder::*;
 
-use crate::render_resource::{CachedRenderPipelineId, PipelineCache};
+use crate::{
+    camera::Camera,
+    render_resource::{CachedRenderPipelineId, PipelineCache},
+    Extract, ExtractSchedule, RenderApp, RenderSet,
+};
+use bevy_app::{App, IntoSystemAppConfig, Plugin};
 use bevy_ecs::{
     prelude::*,
     system::{lifetimeless::SRes, SystemParamItem},
 };
-use std::ops::Range;
+use std::{marker::PhantomData, ops::Range};
 
 /// A collection of all rendering instructions, that will be executed by the GPU, for a
 /// single render phase for a single view.
@@ -263,6 +274,49 @@ pub fn batch_phase_system<I: BatchedPhaseItem>(mut render_phases: Query<&mut Ren
     }
 }
 
+/// Adds a [`RenderPhase<I>`] to every active camera with the `M` marker component,
+/// and sorts it in the [`RenderSet::PhaseSort`].
+///
+/// This also initializes the [`DrawFunctions<I>`], to which the draw functions of the phase
+/// items must be added. The items themselves are queued by the user in the
+/// [`RenderSet::Queue`], and drawn by a render graph node.
+pub struct SortedRenderPhasePlugin<I: PhaseItem, M: Component> {
+    marker: PhantomData<fn() -> (I, M)>,
+}
+
+impl<I: PhaseItem, M: Component> Default for SortedRenderPhasePlugin<I, M> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I: PhaseItem, M: Component> Plugin for SortedRenderPhasePlugin<I, M> {
+    fn build(&self, app: &mut App) {
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+
+        render_app.init_resource::<DrawFunctions<I>>().add_systems((
+            extract_render_phase::<I, M>.in_schedule(ExtractSchedule),
+            sort_phase_system::<I>.in_set(RenderSet::PhaseSort),
+        ));
+    }
+}
+
+/// Inserts an empty [`RenderPhase<I>`] on every active camera with the `M` marker component.
+pub fn extract_render_phase<I: PhaseItem, M: Component>(
+    mut commands: Commands,
+    cameras: Extract<Query<(Entity, &Camera), With<M>>>,
+) {
+    for (entity, camera) in &cameras {
+        if camera.is_active {
+            commands
+                .get_or_spawn(entity)
+                .insert(RenderPhase::<I>::default());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,4 +434,100 @@ mod tests {
         ];
         assert_eq!(&*render_phase.items, items_batched);
     }
+
+    struct NoopDraw;
+
+    impl Draw<DerivedPhaseItem> for NoopDraw {
+        fn draw<'w>(
+            &mut self,
+            _world: &'w World,
+            _pass: &mut TrackedRenderPass<'w>,
+            _view: Entity,
+            _item: &DerivedPhaseItem,
+        ) {
+        }
+    }
+
+    #[derive(PhaseItem)]
+    struct DerivedPhaseItem {
+        #[sort_key]
+        order: u32,
+        #[entity]
+        entity: Entity,
+        #[draw_function]
+        draw_function: DrawFunctionId,
+        #[pipeline]
+        pipeline: CachedRenderPipelineId,
+    }
+
+    #[test]
+    fn derived_phase_item() {
+        let draw_functions = DrawFunctions::<DerivedPhaseItem>::default();
+        let draw_function = draw_functions.write().add(NoopDraw);
+        let item = |order| DerivedPhaseItem {
+            order,
+            entity: Entity::from_raw(order),
+            draw_function,
+            pipeline: CachedRenderPipelineId::INVALID,
+        };
+
+        let mut render_phase = RenderPhase::<DerivedPhaseItem>::default();
+        render_phase.add(item(2));
+        render_phase.add(item(0));
+        render_phase.add(item(1));
+        render_phase.sort();
+
+        let entities = render_phase
+            .items
+            .iter()
+            .map(PhaseItem::entity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entities,
+            [0, 1, 2].map(Entity::from_raw).to_vec(),
+            "items should be sorted by the #[sort_key] field"
+        );
+        assert_eq!(render_phase.items[0].draw_function(), draw_function);
+        assert_eq!(
+            render_phase.items[0].cached_pipeline(),
+            CachedRenderPipelineId::INVALID
+        );
+    }
+
+    #[test]
+    fn render_phase_is_extracted_to_active_marked_cameras() {
+        #[derive(Component)]
+        struct Marker;
+
+        let mut main_world = World::new();
+        let active = main_world.spawn((Camera::default(), Marker)).id();
+        let inactive = main_world
+            .spawn((
+                Camera {
+                    is_active: false,
+                    ..Default::default()
+                },
+                Marker,
+            ))
+            .id();
+        let unmarked = main_world.spawn(Camera::default()).id();
+
+        let mut render_world = World::new();
+        render_world.insert_resource(crate::MainWorld(main_world));
+        let mut schedule = Schedule::new();
+        schedule.add_system(extract_render_phase::<DerivedPhaseItem, Marker>);
+        schedule.run(&mut render_world);
+
+        assert!(render_world
+            .get::<RenderPhase<DerivedPhaseItem>>(active)
+            .is_some());
+        assert!(render_world
+            .get_entity(inactive)
+            .and_then(|entity| entity.get::<RenderPhase<DerivedPhaseItem>>())
+            .is_none());
+        assert!(render_world
+            .get_entity(unmarked)
+            .and_then(|entity| entity.get::<RenderPhase<DerivedPhaseItem>>())
+            .is_none());
+    }
 }
@@ -86,6 +86,8 @@ impl Deref for BindGroup {
 ///     values: Vec<f32>,
 ///     #[storage(4, read_only, buffer)]
 ///     buffer: Buffer,
+///     #[texture(5, count = 4)]
+///     textures: Vec<Handle<Image>>,
 /// }
 /// ```
 ///
@@ -100,6 +102,8 @@ impl Deref for BindGroup {
 /// var color_sampler: sampler;
 /// @group(1) @binding(3)
 /// var<storage> values: array<f32>;
+/// @group(1) @binding(5)
+/// var textures: binding_array<texture_2d<f32>, 4>;
 /// ```
 /// Note that the "group" index is determined by the usage context. It is not defined in [`AsBindGroup`]. For example, in Bevy material bind groups
 /// are generally bound to group 1.
@@ -117,6 +121,10 @@ impl Deref for BindGroup {
 ///     most fields should be a [`Handle<Image>`](bevy_asset::Handle) or [`Option<Handle<Image>>`]. If the value of an [`Option<Handle<Image>>`] is
 ///     [`None`], the [`FallbackImage`] resource will be used instead. This attribute can be used in conjunction with a `sampler` binding attribute
 ///    (with a different binding index) if a binding of the sampler for the [`Image`] is also required.
+///     * With `count = N`, the field is bound as an array of `N` textures instead, and has to be iterable over handles, like a
+///     [`Vec<Handle<Image>>`](bevy_asset::Handle). The first `N` handles are bound, and the rest of the array is filled with the [`FallbackImage`],
+///     so the layout is the same whatever the number of handles. Binding arrays require the [`TEXTURE_BINDING_ARRAY`](wgpu::Features::TEXTURE_BINDING_ARRAY)
+///     feature, and the shader declares them like `binding_array<texture_2d<f32>, N>`.
 ///
 /// | Arguments             | Values                                                                  | Default              |
 /// |-----------------------|-------------------------------------------------------------------------|----------------------|
@@ -125,6 +133,7 @@ impl Deref for BindGroup {
 /// | `filterable` = ...    | `true`, `false`                                                         | `true`               |
 /// | `multisampled` = ...  | `true`, `false`                                                         | `false`              |
 /// | `visibility(...)`     | `all`, `none`, or a list-combination of `vertex`, `fragment`, `compute` | `vertex`, `fragment` |
+/// | `count` = ...         | the length of a binding array of textures, greater than 0               | not an array         |
 ///
 /// * `sampler(BINDING_INDEX, arguments)`
 ///     * This field's [`Handle<Image>`](bevy_asset::Handle) will be used to look up the matching [`Sampler`](crate::render_resource::Sampler) GPU
@@ -140,7 +149,11 @@ impl Deref for BindGroup {
 ///
 /// * `storage(BINDING_INDEX, arguments)`
 ///     * The field will be converted to a shader-compatible type using the [`ShaderType`] trait, written to a [`Buffer`], and bound as a storage buffer.
+///     A [`Vec<T>`] of [`ShaderType`]s is bound as a runtime-sized array.
 ///     * It supports and optional `read_only` parameter. Defaults to false if not present.
+///     * With the `buffer` parameter, the field is a [`Buffer`] which is bound as it is.
+///     * [`AsBindGroup::bind_group_layout`] panics if the device does not support storage buffers, like on WebGL2. A `uniform` binding has to be
+///     used there instead.
 ///
 /// | Arguments              | Values                                                                  | Default              |
 /// |------------------------|-------------------------------------------------------------------------|----------------------|
@@ -301,14 +314,26 @@ pub struct PreparedBindGroup<T> {
 pub enum OwnedBindingResource {
     Buffer(Buffer),
     TextureView(TextureView),
+    /// The texture views of a binding array, such as the ones of a `texture(BINDING_INDEX, count = ...)`
+    /// field of an [`AsBindGroup`] derive.
+    TextureViewArray(Vec<TextureView>),
     Sampler(Sampler),
 }
 
 impl OwnedBindingResource {
+    /// # Panics
+    ///
+    /// Panics for a [`OwnedBindingResource::TextureViewArray`], which is bound with a
+    /// [`BindingResource::TextureViewArray`] of references to its views instead.
     pub fn get_binding(&self) -> BindingResource {
         match self {
             OwnedBindingResource::Buffer(buffer) => buffer.as_entire_binding(),
             OwnedBindingResource::TextureView(view) => BindingResource::TextureView(view),
+            OwnedBindingResource::TextureViewArray(_) => {
+                panic!(
+                    "A texture view array has to be bound with `BindingResource::TextureViewArray`"
+                )
+            }
             OwnedBindingResource::Sampler(sampler) => BindingResource::Sampler(sampler),
         }
     }
@@ -334,3 +359,162 @@ where
         self.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AsBindGroup, AsBindGroupError, OwnedBindingResource};
+    use crate::{
+        render_asset::RenderAssets,
+        render_resource::{WgpuFeatures, WgpuLimits},
+        renderer::{RenderDevice, RenderQueue},
+        texture::{DefaultImageSampler, FallbackImage, Image},
+    };
+    use bevy_asset::{Handle, HandleUntyped};
+    use bevy_ecs::world::{FromWorld, World};
+    use bevy_reflect::TypeUuid;
+    use futures_lite::future::block_on;
+    use std::sync::Arc;
+
+    #[derive(AsBindGroup)]
+    struct ArrayMaterial {
+        #[storage(0, read_only)]
+        values: Vec<f32>,
+        #[texture(1, count = 4)]
+        textures: Vec<Handle<Image>>,
+    }
+
+    #[derive(AsBindGroup)]
+    struct StorageMaterial {
+        #[storage(0, read_only)]
+        values: Vec<f32>,
+    }
+
+    /// Creates a device with `features` and `limits` on the default adapter, including software
+    /// ones, or returns `None` if the adapter is missing or doesn't support them.
+    fn render_world(features: WgpuFeatures, limits: WgpuLimits) -> Option<World> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))?;
+        if !adapter.features().contains(features) {
+            return None;
+        }
+        let (device, queue) = block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                features,
+                limits,
+            },
+            None,
+        ))
+        .ok()?;
+        let device = RenderDevice::from(device);
+
+        let mut world = World::new();
+        world.insert_resource(DefaultImageSampler(
+            device.create_sampler(&Default::default()),
+        ));
+        world.insert_resource(device);
+        world.insert_resource(RenderQueue(Arc::new(queue)));
+        let fallback_image = FallbackImage::from_world(&mut world);
+        world.insert_resource(fallback_image);
+        Some(world)
+    }
+
+    fn image_handle(id: u64) -> Handle<Image> {
+        HandleUntyped::weak_from_u64(Image::TYPE_UUID, id).typed()
+    }
+
+    #[test]
+    fn texture_array_layout_does_not_depend_on_the_number_of_handles() {
+        let Some(mut world) =
+            render_world(WgpuFeatures::TEXTURE_BINDING_ARRAY, WgpuLimits::default())
+        else {
+            return;
+        };
+        // A texture different from the fallback image.
+        let image = (*FallbackImage::from_world(&mut world)).clone();
+        let render_device = world.resource::<RenderDevice>();
+        let fallback_image = world.resource::<FallbackImage>();
+        let mut images = RenderAssets::<Image>::default();
+        for id in 0..6 {
+            images.insert(image_handle(id), image.clone());
+        }
+        let layout = ArrayMaterial::bind_group_layout(render_device);
+
+        for (value_count, handle_count) in [(1, 0), (3, 2), (2, 6)] {
+            let material = ArrayMaterial {
+                values: vec![1.0; value_count],
+                textures: (0..handle_count).map(image_handle).collect(),
+            };
+            let Ok(prepared) =
+                material.as_bind_group(&layout, render_device, &images, fallback_image)
+            else {
+                panic!("the images of the material are prepared");
+            };
+            let OwnedBindingResource::TextureViewArray(views) = &prepared.bindings[1] else {
+                panic!("the textures are bound as an array");
+            };
+            // The handles past the count are ignored, and the missing ones use the fallback image.
+            let view_ids = views.iter().map(|view| view.id()).collect::<Vec<_>>();
+            let bound_count = handle_count.min(4) as usize;
+            assert_eq!(view_ids.len(), 4);
+            assert!(view_ids[..bound_count]
+                .iter()
+                .all(|id| *id == image.texture_view.id()));
+            assert!(view_ids[bound_count..]
+                .iter()
+                .all(|id| *id == fallback_image.texture_view.id()));
+        }
+
+        // The material waits for all of its images.
+        let material = ArrayMaterial {
+            values: vec![1.0],
+            textures: vec![image_handle(0), image_handle(10)],
+        };
+        assert!(matches!(
+            material.as_bind_group(&layout, render_device, &images, fallback_image),
+            Err(AsBindGroupError::RetryNextUpdate)
+        ));
+    }
+
+    #[test]
+    fn storage_vec_layout_does_not_depend_on_the_vec_length() {
+        let Some(world) = render_world(WgpuFeatures::empty(), WgpuLimits::default()) else {
+            return;
+        };
+        let render_device = world.resource::<RenderDevice>();
+        let fallback_image = world.resource::<FallbackImage>();
+        let images = RenderAssets::<Image>::default();
+        let layout = StorageMaterial::bind_group_layout(render_device);
+
+        for value_count in [0, 1, 5] {
+            let material = StorageMaterial {
+                values: vec![1.0; value_count],
+            };
+            let Ok(prepared) =
+                material.as_bind_group(&layout, render_device, &images, fallback_image)
+            else {
+                panic!("the material doesn't have images to wait for");
+            };
+            let OwnedBindingResource::Buffer(buffer) = &prepared.bindings[0] else {
+                panic!("the values are bound as a buffer");
+            };
+            assert_eq!(buffer.size(), 4 * value_count.max(1) as u64);
+        }
+    }
+
+    #[test]
+    fn storage_bindings_require_storage_buffer_support() {
+        let Some(world) = render_world(
+            WgpuFeatures::empty(),
+            WgpuLimits::downlevel_webgl2_defaults(),
+        ) else {
+            return;
+        };
+        let render_device = world.resource::<RenderDevice>();
+
+        let result = std::panic::catch_unwind(|| StorageMaterial::bind_group_layout(render_device));
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message
+            .starts_with("The `values` field of `StorageMaterial` is bound as a storage buffer"));
+    }
+}
@@ -0,0 +1,148 @@
+use super::{DynamicUniformBuffer, StorageBuffer};
+use crate::renderer::{RenderDevice, RenderQueue};
+use bevy_ecs::system::Resource;
+use encase::{internal::WriteInto, ShaderSize, ShaderType};
+use std::marker::PhantomData;
+use wgpu::{
+    BindGroupLayoutEntry, BindingResource, BindingType, BufferBindingType, Limits, ShaderStages,
+};
+
+/// Trait for types able to go in a [`GpuArrayBuffer`].
+pub trait GpuArrayBufferable: ShaderType + ShaderSize + WriteInto + Clone {}
+impl<T: ShaderType + ShaderSize + WriteInto + Clone> GpuArrayBufferable for T {}
+
+/// Stores an array of elements to be transferred to the GPU and made accessible to shaders as a read-only array.
+///
+/// On platforms that support storage buffers, this is equivalent to [`StorageBuffer<Vec<T>>`] and shaders
+/// index into a runtime-sized `array<T>`.
+/// Otherwise, this falls back to a [`DynamicUniformBuffer<T>`] where every element is bound at its own
+/// dynamic offset, and shaders see an `array<T, 1u>` that always has to be indexed with `0`.
+/// Use [`GpuArrayBuffer::batch_size`] to know which of the two layouts is in use.
+///
+/// Other options for storing GPU-accessible data are:
+/// * [`StorageBuffer`](crate::render_resource::StorageBuffer)
+/// * [`DynamicStorageBuffer`](crate::render_resource::DynamicStorageBuffer)
+/// * [`UniformBuffer`](crate::render_resource::UniformBuffer)
+/// * [`DynamicUniformBuffer`](crate::render_resource::DynamicUniformBuffer)
+/// * [`BufferVec`](crate::render_resource::BufferVec)
+/// * [`Texture`](crate::render_resource::Texture)
+#[derive(Resource)]
+pub enum GpuArrayBuffer<T: GpuArrayBufferable> {
+    Uniform(DynamicUniformBuffer<T>),
+    Storage(StorageBuffer<Vec<T>>),
+}
+
+impl<T: GpuArrayBufferable> GpuArrayBuffer<T> {
+    pub fn new(device: &RenderDevice) -> Self {
+        if Self::batch_size(&device.limits()).is_some() {
+            GpuArrayBuffer::Uniform(DynamicUniformBuffer::default())
+        } else {
+            GpuArrayBuffer::Storage(StorageBuffer::default())
+        }
+    }
+
+    pub fn clear(&mut self) {
+        match self {
+            GpuArrayBuffer::Uniform(buffer) => buffer.clear(),
+            GpuArrayBuffer::Storage(buffer) => buffer.get_mut().clear(),
+        }
+    }
+
+    /// Pushes an element (residing on system RAM) and returns where shaders will find it.
+    pub fn push(&mut self, value: T) -> GpuArrayBufferIndex<T> {
+        match self {
+            GpuArrayBuffer::Uniform(buffer) => GpuArrayBufferIndex {
+                index: 0,
+                dynamic_offset: Some(buffer.push(value)),
+                element_type: PhantomData,
+            },
+            GpuArrayBuffer::Storage(buffer) => {
+                let values = buffer.get_mut();
+                let index = values.len() as u32;
+                values.push(value);
+                GpuArrayBufferIndex {
+                    index,
+                    dynamic_offset: None,
+                    element_type: PhantomData,
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            GpuArrayBuffer::Uniform(buffer) => buffer.len(),
+            GpuArrayBuffer::Storage(buffer) => buffer.get().len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Queues writing of the pushed elements from system RAM to VRAM.
+    ///
+    /// Nothing is written while the buffer is empty, as zero-sized bindings are not valid.
+    pub fn write_buffer(&mut self, device: &RenderDevice, queue: &RenderQueue) {
+        if self.is_empty() {
+            return;
+        }
+        match self {
+            GpuArrayBuffer::Uniform(buffer) => buffer.write_buffer(device, queue),
+            GpuArrayBuffer::Storage(buffer) => buffer.write_buffer(device, queue),
+        }
+    }
+
+    pub fn binding_layout(
+        binding: u32,
+        visibility: ShaderStages,
+        device: &RenderDevice,
+    ) -> BindGroupLayoutEntry {
+        BindGroupLayoutEntry {
+            binding,
+            visibility,
+            ty: if Self::batch_size(&device.limits()).is_some() {
+                BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: Some(T::min_size()),
+                }
+            } else {
+                BindingType::Buffer {
+                    ty: BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: Some(T::min_size()),
+                }
+            },
+            count: None,
+        }
+    }
+
+    pub fn binding(&self) -> Option<BindingResource> {
+        match self {
+            GpuArrayBuffer::Uniform(buffer) => buffer.binding(),
+            GpuArrayBuffer::Storage(buffer) => buffer.binding(),
+        }
+    }
+
+    /// Returns the number of elements visible to shaders through a single binding, or `None` if
+    /// the whole array is visible at once.
+    ///
+    /// This is meant to be passed to shaders as the `PER_OBJECT_BUFFER_BATCH_SIZE` shader def.
+    pub fn batch_size(limits: &Limits) -> Option<u32> {
+        if limits.max_storage_buffers_per_shader_stage == 0 {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
+/// The location of an element pushed into a [`GpuArrayBuffer`].
+pub struct GpuArrayBufferIndex<T: GpuArrayBufferable> {
+    /// The index to use in shaders to access the element.
+    pub index: u32,
+    /// The dynamic offset to bind the buffer at, if the uniform buffer fallback is in use.
+    pub dynamic_offset: Option<u32>,
+    pub element_type: PhantomData<T>,
+}
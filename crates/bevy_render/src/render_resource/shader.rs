@@ -5,7 +5,7 @@ use bevy_reflect::TypeUuid;
 use bevy_utils::{tracing::error, BoxedFuture, HashMap};
 use naga::{back::wgsl::WriterFlags, valid::Capabilities, valid::ModuleInfo, Module};
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Captures, Regex};
 use std::{borrow::Cow, marker::Copy, ops::Deref, path::PathBuf, str::FromStr};
 use thiserror::Error;
 use wgpu::{util::make_spirv, Features, ShaderModuleDescriptor, ShaderSource};
@@ -321,6 +321,26 @@ pub enum ProcessShaderError {
         shader_def_name: String,
         value: String,
     },
+    /// An import could not be processed, the error either being the import itself or coming
+    /// from the imported shader.
+    #[error("{error}\n    while importing {import:?} at line {line} of {}", describe_shader(.importing_shader))]
+    Import {
+        import: ShaderImport,
+        /// The [`import_path`](Shader::import_path) of the shader containing the `#import`,
+        /// which is the asset path for shaders loaded from files.
+        importing_shader: Option<ShaderImport>,
+        /// The line of the `#import`, starting at 1.
+        line: usize,
+        error: Box<ProcessShaderError>,
+    },
+}
+
+fn describe_shader(import_path: &Option<ShaderImport>) -> String {
+    match import_path {
+        Some(ShaderImport::AssetPath(path)) => format!("\"{path}\""),
+        Some(ShaderImport::Custom(path)) => path.clone(),
+        None => "the shader".to_string(),
+    }
 }
 
 pub struct ShaderImportProcessor {
@@ -338,8 +358,10 @@ pub enum ShaderImport {
 impl Default for ShaderImportProcessor {
     fn default() -> Self {
         Self {
-            import_asset_path_regex: Regex::new(r#"^\s*#\s*import\s+"(.+)""#).unwrap(),
-            import_custom_path_regex: Regex::new(r"^\s*#\s*import\s+(.+)").unwrap(),
+            import_asset_path_regex: Regex::new(r#"^\s*#\s*import\s+"(.+)"(?:\s+as\s+(\w+))?"#)
+                .unwrap(),
+            import_custom_path_regex: Regex::new(r"^\s*#\s*import\s+([^\s]+)(?:\s+as\s+(\w+))?")
+                .unwrap(),
             define_import_path_regex: Regex::new(r"^\s*#\s*define_import_path\s+(.+)").unwrap(),
         }
     }
@@ -391,6 +413,8 @@ pub struct ShaderProcessor {
     ifndef_regex: Regex,
     ifop_regex: Regex,
     else_ifdef_regex: Regex,
+    else_ifndef_regex: Regex,
+    else_ifop_regex: Regex,
     else_regex: Regex,
     endif_regex: Regex,
     define_regex: Regex,
@@ -405,6 +429,11 @@ impl Default for ShaderProcessor {
             ifndef_regex: Regex::new(r"^\s*#\s*ifndef\s*([\w|\d|_]+)").unwrap(),
             ifop_regex: Regex::new(r"^\s*#\s*if\s*([\w|\d|_]+)\s*([^\s]*)\s*([-\w|\d]+)").unwrap(),
             else_ifdef_regex: Regex::new(r"^\s*#\s*else\s+ifdef\s*([\w|\d|_]+)").unwrap(),
+            else_ifndef_regex: Regex::new(r"^\s*#\s*else\s+ifndef\s*([\w|\d|_]+)").unwrap(),
+            else_ifop_regex: Regex::new(
+                r"^\s*#\s*else\s+if\s+([\w|\d|_]+)\s*([^\s]*)\s*([-\w|\d]+)",
+            )
+            .unwrap(),
             else_regex: Regex::new(r"^\s*#\s*else").unwrap(),
             endif_regex: Regex::new(r"^\s*#\s*endif").unwrap(),
             define_regex: Regex::new(r"^\s*#\s*define\s+([\w|\d|_]+)\s*([-\w|\d]+)?").unwrap(),
@@ -449,6 +478,56 @@ impl Scope {
     }
 }
 
+/// Updates the current scope for an `#else ifdef`, `#else ifndef` or `#else if` directive
+/// whose condition is `condition`.
+fn else_if(scopes: &mut [Scope], condition: bool) {
+    // When should we accept the code in an
+    //
+    //  #else ifdef FOO
+    //      <stuff>
+    //  #endif
+    //
+    // block? Conditions:
+    //  1. The parent scope is accepting lines.
+    //  2. The current scope is _not_ accepting lines.
+    //  3. The condition holds, here FOO is defined.
+    //  4. We haven't already accepted another #ifdef (or #else ifdef) in the current scope.
+
+    // Condition 1
+    let mut parent_accepting = true;
+
+    if scopes.len() > 1 {
+        parent_accepting = scopes[scopes.len() - 2].is_accepting_lines();
+    }
+
+    if let Some(current) = scopes.last_mut() {
+        // Condition 2
+        let current_accepting = current.is_accepting_lines();
+
+        // Condition 3
+        if parent_accepting && !current_accepting && condition {
+            // Condition 4: Enforced by [`Scope`].
+            current.start_accepting_lines_if_appropriate();
+        } else {
+            current.stop_accepting_lines();
+        }
+    }
+}
+
+fn act_on<T: Eq + Ord>(a: T, b: T, op: &str) -> Result<bool, ProcessShaderError> {
+    match op {
+        "==" => Ok(a == b),
+        "!=" => Ok(a != b),
+        ">" => Ok(a > b),
+        ">=" => Ok(a >= b),
+        "<" => Ok(a < b),
+        "<=" => Ok(a <= b),
+        _ => Err(ProcessShaderError::UnknownShaderDefOperator {
+            operator: op.to_string(),
+        }),
+    }
+}
+
 impl ShaderProcessor {
     pub fn process(
         &self,
@@ -486,7 +565,9 @@ impl ShaderProcessor {
 
         let mut scopes = vec![Scope::new(true)];
         let mut final_string = String::new();
-        for line in shader_str.lines() {
+        // Matches the `Alias::` prefixes of the `#import path as Alias` lines seen so far.
+        let mut import_aliases = Vec::new();
+        for (line_index, line) in shader_str.lines().enumerate() {
             if let Some(cap) = self.ifdef_regex.captures(line) {
                 let def = cap.get(1).unwrap();
 
@@ -502,100 +583,19 @@ impl ShaderProcessor {
 
                 scopes.push(Scope::new(current_valid && !has_define));
             } else if let Some(cap) = self.ifop_regex.captures(line) {
-                let def = cap.get(1).unwrap();
-                let op = cap.get(2).unwrap();
-                let val = cap.get(3).unwrap();
-
-                fn act_on<T: Eq + Ord>(a: T, b: T, op: &str) -> Result<bool, ProcessShaderError> {
-                    match op {
-                        "==" => Ok(a == b),
-                        "!=" => Ok(a != b),
-                        ">" => Ok(a > b),
-                        ">=" => Ok(a >= b),
-                        "<" => Ok(a < b),
-                        "<=" => Ok(a <= b),
-                        _ => Err(ProcessShaderError::UnknownShaderDefOperator {
-                            operator: op.to_string(),
-                        }),
-                    }
-                }
-
-                let def = shader_defs_unique.get(def.as_str()).ok_or(
-                    ProcessShaderError::UnknownShaderDef {
-                        shader_def_name: def.as_str().to_string(),
-                    },
-                )?;
-                let new_scope = match def {
-                    ShaderDefVal::Bool(name, def) => {
-                        let val = val.as_str().parse().map_err(|_| {
-                            ProcessShaderError::InvalidShaderDefComparisonValue {
-                                shader_def_name: name.clone(),
-                                value: val.as_str().to_string(),
-                                expected: "bool".to_string(),
-                            }
-                        })?;
-                        act_on(*def, val, op.as_str())?
-                    }
-                    ShaderDefVal::Int(name, def) => {
-                        let val = val.as_str().parse().map_err(|_| {
-                            ProcessShaderError::InvalidShaderDefComparisonValue {
-                                shader_def_name: name.clone(),
-                                value: val.as_str().to_string(),
-                                expected: "int".to_string(),
-                            }
-                        })?;
-                        act_on(*def, val, op.as_str())?
-                    }
-                    ShaderDefVal::UInt(name, def) => {
-                        let val = val.as_str().parse().map_err(|_| {
-                            ProcessShaderError::InvalidShaderDefComparisonValue {
-                                shader_def_name: name.clone(),
-                                value: val.as_str().to_string(),
-                                expected: "uint".to_string(),
-                            }
-                        })?;
-                        act_on(*def, val, op.as_str())?
-                    }
-                };
-
+                let new_scope = self.evaluate_comparison(&cap, shader_defs_unique)?;
                 let current_valid = scopes.last().unwrap().is_accepting_lines();
 
                 scopes.push(Scope::new(current_valid && new_scope));
             } else if let Some(cap) = self.else_ifdef_regex.captures(line) {
-                // When should we accept the code in an
-                //
-                //  #else ifdef FOO
-                //      <stuff>
-                //  #endif
-                //
-                // block? Conditions:
-                //  1. The parent scope is accepting lines.
-                //  2. The current scope is _not_ accepting lines.
-                //  3. FOO is defined.
-                //  4. We haven't already accepted another #ifdef (or #else ifdef) in the current scope.
-
-                // Condition 1
-                let mut parent_accepting = true;
-
-                if scopes.len() > 1 {
-                    parent_accepting = scopes[scopes.len() - 2].is_accepting_lines();
-                }
-
-                if let Some(current) = scopes.last_mut() {
-                    // Condition 2
-                    let current_accepting = current.is_accepting_lines();
-
-                    // Condition 3
-                    let def = cap.get(1).unwrap();
-                    let has_define = shader_defs_unique.contains_key(def.as_str());
-
-                    if parent_accepting && !current_accepting && has_define {
-                        // Condition 4: Enforced by [`Scope`].
-                        current.start_accepting_lines_if_appropriate();
-                    } else {
-                        current.stop_accepting_lines();
-                    }
-                }
+                let has_define = shader_defs_unique.contains_key(cap.get(1).unwrap().as_str());
+                else_if(&mut scopes, has_define);
+            } else if let Some(cap) = self.else_ifndef_regex.captures(line) {
+                let has_define = shader_defs_unique.contains_key(cap.get(1).unwrap().as_str());
+                else_if(&mut scopes, !has_define);
+            } else if let Some(cap) = self.else_ifop_regex.captures(line) {
+                let condition = self.evaluate_comparison(&cap, shader_defs_unique)?;
+                else_if(&mut scopes, condition);
             } else if self.else_regex.is_match(line) {
                 let mut parent_accepting = true;
 
@@ -634,24 +634,22 @@ impl ShaderProcessor {
                     return Err(ProcessShaderError::TooManyEndIfs);
                 }
             } else if scopes.last().unwrap().is_accepting_lines() {
-                if let Some(cap) = SHADER_IMPORT_PROCESSOR
+                let import = if let Some(cap) = SHADER_IMPORT_PROCESSOR
                     .import_asset_path_regex
                     .captures(line)
                 {
                     let import = ShaderImport::AssetPath(cap.get(1).unwrap().as_str().to_string());
-                    self.apply_import(
-                        import_handles,
-                        shaders,
-                        &import,
-                        shader,
-                        shader_defs_unique,
-                        &mut final_string,
-                    )?;
+                    Some((import, cap.get(2)))
                 } else if let Some(cap) = SHADER_IMPORT_PROCESSOR
                     .import_custom_path_regex
                     .captures(line)
                 {
                     let import = ShaderImport::Custom(cap.get(1).unwrap().as_str().to_string());
+                    Some((import, cap.get(2)))
+                } else {
+                    None
+                };
+                if let Some((import, alias)) = import {
                     self.apply_import(
                         import_handles,
                         shaders,
@@ -659,7 +657,17 @@ impl ShaderProcessor {
                         shader,
                         shader_defs_unique,
                         &mut final_string,
-                    )?;
+                    )
+                    .map_err(|error| ProcessShaderError::Import {
+                        import,
+                        importing_shader: shader.import_path.clone(),
+                        line: line_index + 1,
+                        error: Box::new(error),
+                    })?;
+                    if let Some(alias) = alias {
+                        let alias = regex::escape(alias.as_str());
+                        import_aliases.push(Regex::new(&format!(r"\b{alias}::")).unwrap());
+                    }
                 } else if SHADER_IMPORT_PROCESSOR
                     .define_import_path_regex
                     .is_match(line)
@@ -687,6 +695,11 @@ impl ShaderProcessor {
                     }
                 } else {
                     let mut line_with_defs = line.to_string();
+                    // The imported items are added to the importing shader as they are, so
+                    // `Alias::item` refers to `item`.
+                    for alias in &import_aliases {
+                        line_with_defs = alias.replace_all(&line_with_defs, "").to_string();
+                    }
                     for capture in self.def_regex.captures_iter(line) {
                         let def = capture.get(1).unwrap();
                         if let Some(def) = shader_defs_unique.get(def.as_str()) {
@@ -726,6 +739,56 @@ impl ShaderProcessor {
         }
     }
 
+    /// Evaluates the `NAME op VALUE` condition captured from an `#if` or `#else if` directive.
+    fn evaluate_comparison(
+        &self,
+        cap: &Captures,
+        shader_defs_unique: &HashMap<String, ShaderDefVal>,
+    ) -> Result<bool, ProcessShaderError> {
+        let def = cap.get(1).unwrap();
+        let op = cap.get(2).unwrap();
+        let val = cap.get(3).unwrap();
+
+        let def =
+            shader_defs_unique
+                .get(def.as_str())
+                .ok_or(ProcessShaderError::UnknownShaderDef {
+                    shader_def_name: def.as_str().to_string(),
+                })?;
+        match def {
+            ShaderDefVal::Bool(name, def) => {
+                let val = val.as_str().parse().map_err(|_| {
+                    ProcessShaderError::InvalidShaderDefComparisonValue {
+                        shader_def_name: name.clone(),
+                        value: val.as_str().to_string(),
+                        expected: "bool".to_string(),
+                    }
+                })?;
+                act_on(*def, val, op.as_str())
+            }
+            ShaderDefVal::Int(name, def) => {
+                let val = val.as_str().parse().map_err(|_| {
+                    ProcessShaderError::InvalidShaderDefComparisonValue {
+                        shader_def_name: name.clone(),
+                        value: val.as_str().to_string(),
+                        expected: "int".to_string(),
+                    }
+                })?;
+                act_on(*def, val, op.as_str())
+            }
+            ShaderDefVal::UInt(name, def) => {
+                let val = val.as_str().parse().map_err(|_| {
+                    ProcessShaderError::InvalidShaderDefComparisonValue {
+                        shader_def_name: name.clone(),
+                        value: val.as_str().to_string(),
+                        expected: "uint".to_string(),
+                    }
+                })?;
+                act_on(*def, val, op.as_str())
+            }
+        }
+    }
+
     fn apply_import(
         &self,
         import_handles: &HashMap<ShaderImport, Handle<Shader>>,
@@ -2616,4 +2679,176 @@ true
             .unwrap();
         assert_eq!(result.get_wgsl_source().unwrap(), EXPECTED);
     }
+
+    #[test]
+    fn process_shader_def_else_if_comparison() {
+        #[rustfmt::skip]
+        const WGSL: &str = r"
+#if MAX_LIGHTS > 16
+// many lights
+#else if MAX_LIGHTS > 4
+// some lights
+#ifdef SHADOWS
+// shadows
+#else ifndef NO_FALLBACK
+// fallback
+#else
+// should not be here
+#endif
+#else if MAX_LIGHTS == 0
+// no lights
+#else
+// few lights
+#endif
+";
+
+        #[rustfmt::skip]
+        const EXPECTED_MANY: &str = r"
+// many lights
+";
+        #[rustfmt::skip]
+        const EXPECTED_SOME: &str = r"
+// some lights
+// fallback
+";
+        #[rustfmt::skip]
+        const EXPECTED_NONE: &str = r"
+// no lights
+";
+        #[rustfmt::skip]
+        const EXPECTED_FEW: &str = r"
+// few lights
+";
+        let processor = ShaderProcessor::default();
+        let process = |max_lights: u32| {
+            processor
+                .process(
+                    &Shader::from_wgsl(WGSL),
+                    &[ShaderDefVal::UInt("MAX_LIGHTS".to_string(), max_lights)],
+                    &HashMap::default(),
+                    &HashMap::default(),
+                )
+                .unwrap()
+        };
+        assert_eq!(process(32).get_wgsl_source().unwrap(), EXPECTED_MANY);
+        assert_eq!(process(8).get_wgsl_source().unwrap(), EXPECTED_SOME);
+        assert_eq!(process(0).get_wgsl_source().unwrap(), EXPECTED_NONE);
+        assert_eq!(process(2).get_wgsl_source().unwrap(), EXPECTED_FEW);
+    }
+
+    #[test]
+    fn process_shader_def_else_if_invalid_comparison() {
+        #[rustfmt::skip]
+        const WGSL: &str = r"
+#ifdef NOT_DEFINED
+#else if MAX_LIGHTS > -1
+#endif
+";
+        let result = ShaderProcessor::default().process(
+            &Shader::from_wgsl(WGSL),
+            &[ShaderDefVal::UInt("MAX_LIGHTS".to_string(), 8)],
+            &HashMap::default(),
+            &HashMap::default(),
+        );
+        assert_eq!(
+            result,
+            Err(ProcessShaderError::InvalidShaderDefComparisonValue {
+                shader_def_name: "MAX_LIGHTS".to_string(),
+                expected: "uint".to_string(),
+                value: "-1".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn process_import_as_alias() {
+        #[rustfmt::skip]
+        const LIGHTING: &str = r"
+#define_import_path bevy_pbr::lighting
+fn point_light() -> f32 { return 1.0; }
+";
+        #[rustfmt::skip]
+        const INPUT: &str = r"
+#import bevy_pbr::lighting as Lighting
+fn main() -> f32 {
+    return Lighting::point_light() + MyLighting::point_light();
+}
+";
+        #[rustfmt::skip]
+        const EXPECTED: &str = r"
+
+fn point_light() -> f32 { return 1.0; }
+fn main() -> f32 {
+    return point_light() + MyLighting::point_light();
+}
+";
+        let processor = ShaderProcessor::default();
+        let input = Shader::from_wgsl(INPUT);
+        assert_eq!(
+            input.imports().collect::<Vec<_>>(),
+            vec![&ShaderImport::Custom("bevy_pbr::lighting".to_string())]
+        );
+
+        let mut shaders = HashMap::default();
+        let mut import_handles = HashMap::default();
+        let lighting_handle = Handle::<Shader>::default();
+        shaders.insert(lighting_handle.clone_weak(), Shader::from_wgsl(LIGHTING));
+        import_handles.insert(
+            ShaderImport::Custom("bevy_pbr::lighting".to_string()),
+            lighting_handle.clone_weak(),
+        );
+        let result = processor
+            .process(&input, &[], &shaders, &import_handles)
+            .unwrap();
+        assert_eq!(result.get_wgsl_source().unwrap(), EXPECTED);
+    }
+
+    #[test]
+    fn process_import_error_points_to_importing_line() {
+        #[rustfmt::skip]
+        const FOO: &str = r"
+#define_import_path FOO
+#import BAR
+";
+        #[rustfmt::skip]
+        const INPUT: &str = r"
+fn main() { }
+#import FOO
+";
+        let processor = ShaderProcessor::default();
+        let mut shaders = HashMap::default();
+        let mut import_handles = HashMap::default();
+        let foo_handle = Handle::<Shader>::default();
+        shaders.insert(foo_handle.clone_weak(), Shader::from_wgsl(FOO));
+        import_handles.insert(
+            ShaderImport::Custom("FOO".to_string()),
+            foo_handle.clone_weak(),
+        );
+        let mut input = Shader::from_wgsl(INPUT);
+        input.import_path = Some(ShaderImport::AssetPath("shaders/main.wgsl".to_string()));
+
+        let error = processor
+            .process(&input, &[], &shaders, &import_handles)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ProcessShaderError::Import {
+                import: ShaderImport::Custom("FOO".to_string()),
+                importing_shader: Some(ShaderImport::AssetPath("shaders/main.wgsl".to_string())),
+                line: 3,
+                error: Box::new(ProcessShaderError::Import {
+                    import: ShaderImport::Custom("BAR".to_string()),
+                    importing_shader: Some(ShaderImport::Custom("FOO".to_string())),
+                    line: 3,
+                    error: Box::new(ProcessShaderError::UnresolvedImport(ShaderImport::Custom(
+                        "BAR".to_string()
+                    ))),
+                }),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Unresolved import: Custom(\"BAR\").\n    while importing Custom(\"BAR\") at line 3 of FOO\n    while importing Custom(\"FOO\") at line 3 of \"shaders/main.wgsl\""
+        );
+    }
 }
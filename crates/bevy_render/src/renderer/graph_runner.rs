@@ -3,15 +3,13 @@ use bevy_ecs::world::World;
 use bevy_utils::tracing::info_span;
 use bevy_utils::HashMap;
 use smallvec::{smallvec, SmallVec};
-#[cfg(feature = "trace")]
-use std::ops::Deref;
 use std::{borrow::Cow, collections::VecDeque};
 use thiserror::Error;
 
 use crate::{
     render_graph::{
-        Edge, NodeId, NodeRunError, NodeState, RenderGraph, RenderGraphContext, SlotLabel,
-        SlotType, SlotValue,
+        BoxedRenderLabel, BoxedRenderSubGraph, Edge, NodeId, NodeRunError, NodeState, RenderGraph,
+        RenderGraphContext, SlotLabel, SlotType, SlotValue,
     },
     renderer::{RenderContext, RenderDevice},
 };
@@ -32,7 +30,7 @@ pub enum RenderGraphRunnerError {
     MissingInput {
         slot_index: usize,
         slot_name: Cow<'static, str>,
-        graph_name: Option<Cow<'static, str>>,
+        graph_name: Option<BoxedRenderSubGraph>,
     },
     #[error("attempted to use the wrong type for input slot")]
     MismatchedInputSlotType {
@@ -45,7 +43,7 @@ pub enum RenderGraphRunnerError {
         "node (name: '{node_name:?}') has {slot_count} input slots, but was provided {value_count} values"
     )]
     MismatchedInputCount {
-        node_name: Option<Cow<'static, str>>,
+        node_name: Option<BoxedRenderLabel>,
         slot_count: usize,
         value_count: usize,
     },
@@ -70,7 +68,7 @@ impl RenderGraphRunner {
 
     fn run_graph(
         graph: &RenderGraph,
-        graph_name: Option<Cow<'static, str>>,
+        graph_name: Option<BoxedRenderSubGraph>,
         render_context: &mut RenderContext,
         world: &World,
         inputs: &[SlotValue],
@@ -78,7 +76,7 @@ impl RenderGraphRunner {
         let mut node_outputs: HashMap<NodeId, SmallVec<[SlotValue; 4]>> = HashMap::default();
         #[cfg(feature = "trace")]
         let span = if let Some(name) = &graph_name {
-            info_span!("run_graph", name = name.deref())
+            info_span!("run_graph", name = ?name)
         } else {
             info_span!("run_graph", name = "main_graph")
         };
@@ -165,7 +163,7 @@ impl RenderGraphRunner {
 
             if inputs.len() != node_state.input_slots.len() {
                 return Err(RenderGraphRunnerError::MismatchedInputCount {
-                    node_name: node_state.name.clone(),
+                    node_name: node_state.label.clone(),
                     slot_count: node_state.input_slots.len(),
                     value_count: inputs.len(),
                 });
@@ -184,7 +182,7 @@ impl RenderGraphRunner {
 
                 for run_sub_graph in context.finish() {
                     let sub_graph = graph
-                        .get_sub_graph(&run_sub_graph.name)
+                        .get_sub_graph(run_sub_graph.name.clone())
                         .expect("sub graph exists because it was validated when queued.");
                     Self::run_graph(
                         sub_graph,
@@ -99,8 +99,8 @@ pub struct RenderAdapter(pub Arc<Adapter>);
 
 /// The GPU instance is used to initialize the [`RenderQueue`] and [`RenderDevice`],
 /// as well as to create [`WindowSurfaces`](crate::view::window::WindowSurfaces).
-#[derive(Resource, Deref, DerefMut)]
-pub struct RenderInstance(pub Instance);
+#[derive(Resource, Clone, Deref, DerefMut)]
+pub struct RenderInstance(pub Arc<Instance>);
 
 /// The `AdapterInfo` of the adapter in use by the renderer.
 #[derive(Resource, Clone, Deref, DerefMut)]
@@ -160,102 +160,7 @@ pub async fn initialize_renderer(
     features |= options.features;
 
     // Enforce the limit constraints
-    if let Some(constrained_limits) = options.constrained_limits.as_ref() {
-        // NOTE: Respect the configured limits as an 'upper bound'. This means for 'max' limits, we
-        // take the minimum of the calculated limits according to the adapter/backend and the
-        // specified max_limits. For 'min' limits, take the maximum instead. This is intended to
-        // err on the side of being conservative. We can't claim 'higher' limits that are supported
-        // but we can constrain to 'lower' limits.
-        limits = wgpu::Limits {
-            max_texture_dimension_1d: limits
-                .max_texture_dimension_1d
-                .min(constrained_limits.max_texture_dimension_1d),
-            max_texture_dimension_2d: limits
-                .max_texture_dimension_2d
-                .min(constrained_limits.max_texture_dimension_2d),
-            max_texture_dimension_3d: limits
-                .max_texture_dimension_3d
-                .min(constrained_limits.max_texture_dimension_3d),
-            max_texture_array_layers: limits
-                .max_texture_array_layers
-                .min(constrained_limits.max_texture_array_layers),
-            max_bind_groups: limits
-                .max_bind_groups
-                .min(constrained_limits.max_bind_groups),
-            max_dynamic_uniform_buffers_per_pipeline_layout: limits
-                .max_dynamic_uniform_buffers_per_pipeline_layout
-                .min(constrained_limits.max_dynamic_uniform_buffers_per_pipeline_layout),
-            max_dynamic_storage_buffers_per_pipeline_layout: limits
-                .max_dynamic_storage_buffers_per_pipeline_layout
-                .min(constrained_limits.max_dynamic_storage_buffers_per_pipeline_layout),
-            max_sampled_textures_per_shader_stage: limits
-                .max_sampled_textures_per_shader_stage
-                .min(constrained_limits.max_sampled_textures_per_shader_stage),
-            max_samplers_per_shader_stage: limits
-                .max_samplers_per_shader_stage
-                .min(constrained_limits.max_samplers_per_shader_stage),
-            max_storage_buffers_per_shader_stage: limits
-                .max_storage_buffers_per_shader_stage
-                .min(constrained_limits.max_storage_buffers_per_shader_stage),
-            max_storage_textures_per_shader_stage: limits
-                .max_storage_textures_per_shader_stage
-                .min(constrained_limits.max_storage_textures_per_shader_stage),
-            max_uniform_buffers_per_shader_stage: limits
-                .max_uniform_buffers_per_shader_stage
-                .min(constrained_limits.max_uniform_buffers_per_shader_stage),
-            max_uniform_buffer_binding_size: limits
-                .max_uniform_buffer_binding_size
-                .min(constrained_limits.max_uniform_buffer_binding_size),
-            max_storage_buffer_binding_size: limits
-                .max_storage_buffer_binding_size
-                .min(constrained_limits.max_storage_buffer_binding_size),
-            max_vertex_buffers: limits
-                .max_vertex_buffers
-                .min(constrained_limits.max_vertex_buffers),
-            max_vertex_attributes: limits
-                .max_vertex_attributes
-                .min(constrained_limits.max_vertex_attributes),
-            max_vertex_buffer_array_stride: limits
-                .max_vertex_buffer_array_stride
-                .min(constrained_limits.max_vertex_buffer_array_stride),
-            max_push_constant_size: limits
-                .max_push_constant_size
-                .min(constrained_limits.max_push_constant_size),
-            min_uniform_buffer_offset_alignment: limits
-                .min_uniform_buffer_offset_alignment
-                .max(constrained_limits.min_uniform_buffer_offset_alignment),
-            min_storage_buffer_offset_alignment: limits
-                .min_storage_buffer_offset_alignment
-                .max(constrained_limits.min_storage_buffer_offset_alignment),
-            max_inter_stage_shader_components: limits
-                .max_inter_stage_shader_components
-                .min(constrained_limits.max_inter_stage_shader_components),
-            max_compute_workgroup_storage_size: limits
-                .max_compute_workgroup_storage_size
-                .min(constrained_limits.max_compute_workgroup_storage_size),
-            max_compute_invocations_per_workgroup: limits
-                .max_compute_invocations_per_workgroup
-                .min(constrained_limits.max_compute_invocations_per_workgroup),
-            max_compute_workgroup_size_x: limits
-                .max_compute_workgroup_size_x
-                .min(constrained_limits.max_compute_workgroup_size_x),
-            max_compute_workgroup_size_y: limits
-                .max_compute_workgroup_size_y
-                .min(constrained_limits.max_compute_workgroup_size_y),
-            max_compute_workgroup_size_z: limits
-                .max_compute_workgroup_size_z
-                .min(constrained_limits.max_compute_workgroup_size_z),
-            max_compute_workgroups_per_dimension: limits
-                .max_compute_workgroups_per_dimension
-                .min(constrained_limits.max_compute_workgroups_per_dimension),
-            max_buffer_size: limits
-                .max_buffer_size
-                .min(constrained_limits.max_buffer_size),
-            max_bindings_per_bind_group: limits
-                .max_bindings_per_bind_group
-                .min(constrained_limits.max_bindings_per_bind_group),
-        };
-    }
+    let limits = options.constrain_limits(limits);
 
     let (device, queue) = adapter
         .request_device(
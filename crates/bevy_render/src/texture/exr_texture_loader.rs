@@ -1,4 +1,7 @@
-use crate::texture::{Image, TextureFormatPixelInfo};
+use crate::{
+    render_asset::RenderAssetUsages,
+    texture::{Image, TextureFormatPixelInfo},
+};
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
 use bevy_utils::BoxedFuture;
@@ -7,7 +10,10 @@ use wgpu::{Extent3d, TextureDimension, TextureFormat};
 
 /// Loads EXR textures as Texture assets
 #[derive(Clone, Default)]
-pub struct ExrTextureLoader;
+pub struct ExrTextureLoader {
+    /// The [`RenderAssetUsages`] of the loaded images.
+    pub asset_usage: RenderAssetUsages,
+}
 
 impl AssetLoader for ExrTextureLoader {
     fn load<'a>(
@@ -34,7 +40,7 @@ impl AssetLoader for ExrTextureLoader {
             let mut buf = vec![0u8; total_bytes];
             decoder.read_image(buf.as_mut_slice())?;
 
-            let texture = Image::new(
+            let mut texture = Image::new(
                 Extent3d {
                     width,
                     height,
@@ -45,6 +51,8 @@ impl AssetLoader for ExrTextureLoader {
                 format,
             );
 
+            texture.asset_usage = self.asset_usage;
+
             load_context.set_default_asset(LoadedAsset::new(texture));
             Ok(())
         })
@@ -1,4 +1,7 @@
-use crate::texture::{Image, TextureFormatPixelInfo};
+use crate::{
+    render_asset::RenderAssetUsages,
+    texture::{Image, TextureFormatPixelInfo},
+};
 use anyhow::Result;
 use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
 use bevy_utils::BoxedFuture;
@@ -6,7 +9,10 @@ use wgpu::{Extent3d, TextureDimension, TextureFormat};
 
 /// Loads HDR textures as Texture assets
 #[derive(Clone, Default)]
-pub struct HdrTextureLoader;
+pub struct HdrTextureLoader {
+    /// The [`RenderAssetUsages`] of the loaded images.
+    pub asset_usage: RenderAssetUsages,
+}
 
 impl AssetLoader for HdrTextureLoader {
     fn load<'a>(
@@ -36,7 +42,7 @@ impl AssetLoader for HdrTextureLoader {
                 rgba_data.extend_from_slice(&alpha.to_ne_bytes());
             }
 
-            let texture = Image::new(
+            let mut texture = Image::new(
                 Extent3d {
                     width: info.width,
                     height: info.height,
@@ -47,6 +53,8 @@ impl AssetLoader for HdrTextureLoader {
                 format,
             );
 
+            texture.asset_usage = self.asset_usage;
+
             load_context.set_default_asset(LoadedAsset::new(texture));
             Ok(())
         })
@@ -6,7 +6,8 @@ use super::dds::*;
 use super::ktx2::*;
 
 use crate::{
-    render_asset::{PrepareAssetError, RenderAsset},
+    color::Color,
+    render_asset::{PrepareAssetError, RenderAsset, RenderAssetUsages},
     render_resource::{Sampler, Texture, TextureView},
     renderer::{RenderDevice, RenderQueue},
     texture::BevyDefault,
@@ -14,12 +15,15 @@ use crate::{
 use bevy_asset::HandleUntyped;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::system::{lifetimeless::SRes, Resource, SystemParamItem};
-use bevy_math::Vec2;
+use bevy_math::{URect, Vec2};
 use bevy_reflect::{FromReflect, Reflect, TypeUuid};
 
-use std::hash::Hash;
+use std::{hash::Hash, num::NonZeroU32, ops::Range};
 use thiserror::Error;
-use wgpu::{Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor};
+use wgpu::{
+    Extent3d, ImageCopyTexture, ImageDataLayout, Origin3d, TextureAspect, TextureDimension,
+    TextureFormat, TextureUsages, TextureViewDescriptor,
+};
 
 pub const TEXTURE_ASSET_INDEX: u64 = 0;
 pub const SAMPLER_ASSET_INDEX: u64 = 1;
@@ -113,6 +117,13 @@ pub struct Image {
     /// The [`ImageSampler`] to use during rendering.
     pub sampler_descriptor: ImageSampler,
     pub texture_view_descriptor: Option<wgpu::TextureViewDescriptor<'static>>,
+    /// Whether the image is used in the main world, the render world, or both.
+    ///
+    /// The `data` of an image used only in the render world are emptied once it is extracted.
+    pub asset_usage: RenderAssetUsages,
+    /// The regions of the image whose `data` changed since it was last extracted, see
+    /// [`Image::mark_dirty`].
+    pub dirty_rects: Vec<URect>,
 }
 
 /// Used in [`Image`], this determines what image sampler to use when rendering. The default setting,
@@ -194,6 +205,8 @@ impl Default for Image {
             },
             sampler_descriptor: ImageSampler::Default,
             texture_view_descriptor: None,
+            asset_usage: RenderAssetUsages::default(),
+            dirty_rects: Vec::new(),
         }
     }
 }
@@ -317,15 +330,151 @@ impl Image {
         });
     }
 
-    /// Convert a texture from a format to another. Only a few formats are
-    /// supported as input and output:
+    /// Resizes the image to the new size, keeping the existing pixels at their position and
+    /// filling the new pixels with 0.
+    ///
+    /// Unlike [`Image::resize`], the pixels of the rows and layers that are kept stay in place.
+    /// Returns an error for compressed formats, whose pixels cannot be moved independently.
+    pub fn resize_in_place(&mut self, new_size: Extent3d) -> Result<(), TextureAccessError> {
+        let format = self.texture_descriptor.format;
+        if format.describe().block_dimensions != (1, 1) {
+            return Err(TextureAccessError::UnsupportedTextureFormat(format));
+        }
+        if self.is_unloaded() {
+            return Err(TextureAccessError::Unloaded);
+        }
+
+        let old_size = self.texture_descriptor.size;
+        let pixel_size = format.pixel_size();
+        let old_row = old_size.width as usize * pixel_size;
+        let new_row = new_size.width as usize * pixel_size;
+        let copied_row = old_row.min(new_row);
+        let mut data = vec![0; new_size.volume() * pixel_size];
+        for layer in 0..old_size
+            .depth_or_array_layers
+            .min(new_size.depth_or_array_layers)
+        {
+            for y in 0..old_size.height.min(new_size.height) {
+                let old_start = (layer * old_size.height + y) as usize * old_row;
+                let new_start = (layer * new_size.height + y) as usize * new_row;
+                data[new_start..new_start + copied_row]
+                    .copy_from_slice(&self.data[old_start..old_start + copied_row]);
+            }
+        }
+
+        self.data = data;
+        self.texture_descriptor.size = new_size;
+        Ok(())
+    }
+
+    /// Returns the color of the pixel at `(x, y)` of the first layer of the image.
+    ///
+    /// The supported formats are:
+    /// - `TextureFormat::R8Unorm`, read as a gray color
+    /// - `TextureFormat::Rg8Unorm`, read with a blue channel of 0
+    /// - `TextureFormat::Rgba8Unorm` and `TextureFormat::Rgba8UnormSrgb`
+    /// - `TextureFormat::Bgra8Unorm` and `TextureFormat::Bgra8UnormSrgb`
+    /// - `TextureFormat::Rgba16Float`
+    /// - `TextureFormat::Rgba32Float`
+    ///
+    /// The pixels of the `Srgb` formats are returned as [`Color::Rgba`], the others as
+    /// [`Color::RgbaLinear`].
+    pub fn get_color_at(&self, x: u32, y: u32) -> Result<Color, TextureAccessError> {
+        let range = self.pixel_range(x, y)?;
+        Ok(decode_color(
+            self.texture_descriptor.format,
+            &self.data[range],
+        ))
+    }
+
+    /// Sets the color of the pixel at `(x, y)` of the first layer of the image.
+    ///
+    /// The color is converted to the color space of the format, and its channels are clamped
+    /// to `0.0..=1.0` for the normalized formats. `TextureFormat::R8Unorm` stores the luminance
+    /// of the color, and `TextureFormat::Rg8Unorm` drops its blue channel. See
+    /// [`Image::get_color_at`] for the supported formats.
+    pub fn set_color_at(&mut self, x: u32, y: u32, color: Color) -> Result<(), TextureAccessError> {
+        let range = self.pixel_range(x, y)?;
+        encode_color(self.texture_descriptor.format, color, &mut self.data[range]);
+        Ok(())
+    }
+
+    /// Whether the `data` of the image were unloaded from the main world after its extraction,
+    /// because its [`asset_usage`](Image::asset_usage) doesn't include
+    /// [`RenderAssetUsages::MAIN_WORLD`].
+    pub fn is_unloaded(&self) -> bool {
+        self.data.is_empty() && self.texture_descriptor.size.volume() > 0
+    }
+
+    /// Marks a region of the image as changed, so that only the changed regions are uploaded to
+    /// the GPU again instead of the whole image.
+    ///
+    /// The regions apply to every layer of the image. Only the pixels of the marked regions are
+    /// uploaded: the other changes to the image are ignored, unless its size, format or usage
+    /// changed, which creates the texture again.
+    pub fn mark_dirty(&mut self, rect: URect) {
+        self.dirty_rects.push(rect);
+    }
+
+    /// Returns the range of `data` holding the pixel at `(x, y)` of the first layer.
+    fn pixel_range(&self, x: u32, y: u32) -> Result<Range<usize>, TextureAccessError> {
+        let format = self.texture_descriptor.format;
+        if !supports_color_access(format) {
+            return Err(TextureAccessError::UnsupportedTextureFormat(format));
+        }
+        let size = self.texture_descriptor.size;
+        if x >= size.width || y >= size.height {
+            return Err(TextureAccessError::OutOfBounds { x, y });
+        }
+        if self.is_unloaded() {
+            return Err(TextureAccessError::Unloaded);
+        }
+
+        let pixel_size = format.pixel_size();
+        let start = (y as usize * size.width as usize + x as usize) * pixel_size;
+        Ok(start..start + pixel_size)
+    }
+
+    /// Convert a texture from a format to another.
+    ///
+    /// The formats supported by [`Image::get_color_at`] are converted pixel by pixel, between
+    /// each other. Otherwise, only a few formats are supported as input and output:
     /// - `TextureFormat::R8Unorm`
     /// - `TextureFormat::Rg8Unorm`
     /// - `TextureFormat::Rgba8UnormSrgb`
     ///
+    /// Returns `None` if the image [is unloaded](Image::is_unloaded).
+    ///
     /// To get [`Image`] as a [`image::DynamicImage`] see:
     /// [`Image::try_into_dynamic`].
     pub fn convert(&self, new_format: TextureFormat) -> Option<Self> {
+        if self.is_unloaded() {
+            return None;
+        }
+        let old_format = self.texture_descriptor.format;
+        if supports_color_access(old_format) && supports_color_access(new_format) {
+            let mut data = vec![0; self.texture_descriptor.size.volume() * new_format.pixel_size()];
+            for (old_pixel, new_pixel) in self
+                .data
+                .chunks_exact(old_format.pixel_size())
+                .zip(data.chunks_exact_mut(new_format.pixel_size()))
+            {
+                encode_color(new_format, decode_color(old_format, old_pixel), new_pixel);
+            }
+
+            let mut image = self.clone();
+            image.data = data;
+            image.texture_descriptor.format = new_format;
+            if let Some(view_format) = image
+                .texture_view_descriptor
+                .as_mut()
+                .and_then(|descriptor| descriptor.format.as_mut())
+            {
+                *view_format = new_format;
+            }
+            return Some(image);
+        }
+
         self.clone()
             .try_into_dynamic()
             .ok()
@@ -444,6 +593,160 @@ pub enum TextureError {
     FormatRequiresTranscodingError(TranscodeFormat),
 }
 
+/// An error that occurs when accessing the pixels of an [`Image`].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureAccessError {
+    #[error("pixel ({x}, {y}) is out of the bounds of the image")]
+    OutOfBounds { x: u32, y: u32 },
+    #[error("unsupported texture format for pixel access: {0:?}")]
+    UnsupportedTextureFormat(TextureFormat),
+    #[error("the image data were unloaded from the main world, see `Image::asset_usage`")]
+    Unloaded,
+}
+
+/// Whether the pixels of `format` can be read and written as a [`Color`].
+fn supports_color_access(format: TextureFormat) -> bool {
+    matches!(
+        format,
+        TextureFormat::R8Unorm
+            | TextureFormat::Rg8Unorm
+            | TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8Unorm
+            | TextureFormat::Bgra8UnormSrgb
+            | TextureFormat::Rgba16Float
+            | TextureFormat::Rgba32Float
+    )
+}
+
+fn decode_unorm8(value: u8) -> f32 {
+    value as f32 / u8::MAX as f32
+}
+
+fn encode_unorm8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8
+}
+
+/// Reads a pixel of a format for which [`supports_color_access`] is true.
+fn decode_color(format: TextureFormat, pixel: &[u8]) -> Color {
+    let unorm = |index: usize| decode_unorm8(pixel[index]);
+    let float16 =
+        |index: usize| f16_to_f32(u16::from_le_bytes([pixel[2 * index], pixel[2 * index + 1]]));
+    let float32 = |index: usize| {
+        f32::from_le_bytes([
+            pixel[4 * index],
+            pixel[4 * index + 1],
+            pixel[4 * index + 2],
+            pixel[4 * index + 3],
+        ])
+    };
+    match format {
+        TextureFormat::R8Unorm => Color::rgb_linear(unorm(0), unorm(0), unorm(0)),
+        TextureFormat::Rg8Unorm => Color::rgb_linear(unorm(0), unorm(1), 0.0),
+        TextureFormat::Rgba8Unorm => Color::rgba_linear(unorm(0), unorm(1), unorm(2), unorm(3)),
+        TextureFormat::Rgba8UnormSrgb => Color::rgba(unorm(0), unorm(1), unorm(2), unorm(3)),
+        TextureFormat::Bgra8Unorm => Color::rgba_linear(unorm(2), unorm(1), unorm(0), unorm(3)),
+        TextureFormat::Bgra8UnormSrgb => Color::rgba(unorm(2), unorm(1), unorm(0), unorm(3)),
+        TextureFormat::Rgba16Float => {
+            Color::rgba_linear(float16(0), float16(1), float16(2), float16(3))
+        }
+        TextureFormat::Rgba32Float => {
+            Color::rgba_linear(float32(0), float32(1), float32(2), float32(3))
+        }
+        _ => unreachable!("{format:?} does not support color access"),
+    }
+}
+
+/// Writes a pixel of a format for which [`supports_color_access`] is true.
+fn encode_color(format: TextureFormat, color: Color, pixel: &mut [u8]) {
+    let [r, g, b, a] = match format {
+        TextureFormat::Rgba8UnormSrgb | TextureFormat::Bgra8UnormSrgb => color.as_rgba_f32(),
+        _ => color.as_linear_rgba_f32(),
+    };
+    match format {
+        TextureFormat::R8Unorm => {
+            // Relative luminance of the linear color, which keeps gray colors unchanged.
+            pixel[0] = encode_unorm8(0.2126 * r + 0.7152 * g + 0.0722 * b);
+        }
+        TextureFormat::Rg8Unorm => {
+            pixel.copy_from_slice(&[encode_unorm8(r), encode_unorm8(g)]);
+        }
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {
+            pixel.copy_from_slice(&[r, g, b, a].map(encode_unorm8));
+        }
+        TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => {
+            pixel.copy_from_slice(&[b, g, r, a].map(encode_unorm8));
+        }
+        TextureFormat::Rgba16Float => {
+            for (bytes, value) in pixel.chunks_exact_mut(2).zip([r, g, b, a]) {
+                bytes.copy_from_slice(&f32_to_f16(value).to_le_bytes());
+            }
+        }
+        TextureFormat::Rgba32Float => {
+            for (bytes, value) in pixel.chunks_exact_mut(4).zip([r, g, b, a]) {
+                bytes.copy_from_slice(&value.to_le_bytes());
+            }
+        }
+        _ => unreachable!("{format:?} does not support color access"),
+    }
+}
+
+/// Converts an IEEE 754 half precision float to an `f32`.
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = u32::from((half >> 10) & 0x1f);
+    let mantissa = u32::from(half & 0x3ff);
+    match exponent {
+        // Zero and subnormals
+        0 => sign * mantissa as f32 * 2f32.powi(-24),
+        // Infinities and NaNs
+        0x1f => f32::from_bits((u32::from(half & 0x8000) << 16) | 0x7f80_0000 | (mantissa << 13)),
+        _ => f32::from_bits(
+            (u32::from(half & 0x8000) << 16) | ((exponent + 112) << 23) | (mantissa << 13),
+        ),
+    }
+}
+
+/// Converts an `f32` to the nearest IEEE 754 half precision float, rounding ties to even.
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // Infinities and NaNs, keeping NaNs quiet
+        let nan = if mantissa == 0 { 0 } else { 0x200 };
+        return sign | 0x7c00 | nan;
+    }
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        // Too large, rounds to infinity
+        return sign | 0x7c00;
+    }
+
+    // The number of low mantissa bits dropped, and their rounding
+    let (mantissa, shift, base) = if exponent <= 0 {
+        if exponent < -10 {
+            // Too small, rounds to zero
+            return sign;
+        }
+        // Subnormal, with the implicit leading bit made explicit
+        (mantissa | 0x80_0000, (14 - exponent) as u32, 0)
+    } else {
+        (mantissa, 13, (exponent as u32) << 10)
+    };
+    let half = base | (mantissa >> shift);
+    let round_bit = 1 << (shift - 1);
+    // Round up when above the halfway point, or on it with an odd result. A carry into the
+    // exponent is the correctly rounded value.
+    if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+        sign | (half + 1) as u16
+    } else {
+        sign | half as u16
+    }
+}
+
 /// The type of a raw image buffer.
 pub enum ImageType<'a> {
     /// The mime type of an image, for example `"image/png"`.
@@ -517,6 +820,27 @@ impl RenderAsset for Image {
         self.clone()
     }
 
+    fn asset_usage(&self) -> RenderAssetUsages {
+        self.asset_usage
+    }
+
+    /// Moves the data out of the image, keeping its descriptors.
+    fn take_asset(&mut self) -> Self::ExtractedAsset {
+        let data = std::mem::take(&mut self.data);
+        Image {
+            data,
+            ..self.clone()
+        }
+    }
+
+    fn is_unloaded(&self) -> bool {
+        Image::is_unloaded(self)
+    }
+
+    fn clear_dirty(&mut self) {
+        self.dirty_rects.clear();
+    }
+
     /// Converts the extracted image into a [`GpuImage`].
     fn prepare_asset(
         image: Self::ExtractedAsset,
@@ -553,6 +877,75 @@ impl RenderAsset for Image {
             mip_level_count: image.texture_descriptor.mip_level_count,
         })
     }
+
+    /// Uploads only the [`dirty_rects`](Image::dirty_rects) of the image to its texture, if any.
+    ///
+    /// The texture is created again if the image has no dirty regions, or if its descriptor
+    /// doesn't match the texture anymore.
+    fn update_asset(
+        image: Self::ExtractedAsset,
+        gpu_image: &mut Self::PreparedAsset,
+        param: &mut SystemParamItem<Self::Param>,
+    ) -> Result<(), PrepareAssetError<Self::ExtractedAsset>> {
+        let texture = &gpu_image.texture;
+        let descriptor = &image.texture_descriptor;
+        if image.dirty_rects.is_empty()
+            || texture.size() != descriptor.size
+            || texture.format() != descriptor.format
+            || texture.dimension() != descriptor.dimension
+            || texture.usage() != descriptor.usage
+            || !descriptor.usage.contains(TextureUsages::COPY_DST)
+            || texture.mip_level_count() != 1
+            || descriptor.mip_level_count != 1
+            || descriptor.format.describe().block_dimensions != (1, 1)
+            || image.data.len() != descriptor.size.volume() * descriptor.format.pixel_size()
+        {
+            *gpu_image = Self::prepare_asset(image, param)?;
+            return Ok(());
+        }
+
+        let (_, render_queue, _) = param;
+        let size = descriptor.size;
+        let pixel_size = descriptor.format.pixel_size();
+        let row_size = size.width as usize * pixel_size;
+        let layer_size = size.height as usize * row_size;
+        let bounds = URect::new(0, 0, size.width, size.height);
+        for rect in &image.dirty_rects {
+            let rect = rect.intersect(bounds);
+            if rect.is_empty() {
+                continue;
+            }
+            for layer in 0..size.depth_or_array_layers {
+                let offset = layer as usize * layer_size
+                    + rect.min.y as usize * row_size
+                    + rect.min.x as usize * pixel_size;
+                render_queue.write_texture(
+                    ImageCopyTexture {
+                        texture,
+                        mip_level: 0,
+                        origin: Origin3d {
+                            x: rect.min.x,
+                            y: rect.min.y,
+                            z: layer,
+                        },
+                        aspect: TextureAspect::All,
+                    },
+                    &image.data[offset..],
+                    ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: NonZeroU32::new(row_size as u32),
+                        rows_per_image: None,
+                    },
+                    Extent3d {
+                        width: rect.width(),
+                        height: rect.height(),
+                        depth_or_array_layers: 1,
+                    },
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 bitflags::bitflags! {
@@ -617,6 +1010,7 @@ impl CompressedImageFormats {
 mod test {
 
     use super::*;
+    use crate::color::SrgbColorSpace;
 
     #[test]
     fn image_size() {
@@ -641,4 +1035,394 @@ mod test {
         let image = Image::default();
         assert_eq!(Vec2::ONE, image.size());
     }
+
+    const ACCESSIBLE_FORMATS: [TextureFormat; 8] = [
+        TextureFormat::R8Unorm,
+        TextureFormat::Rg8Unorm,
+        TextureFormat::Rgba8Unorm,
+        TextureFormat::Rgba8UnormSrgb,
+        TextureFormat::Bgra8Unorm,
+        TextureFormat::Bgra8UnormSrgb,
+        TextureFormat::Rgba16Float,
+        TextureFormat::Rgba32Float,
+    ];
+
+    fn image_2d(width: u32, height: u32, format: TextureFormat) -> Image {
+        let size = Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        Image::new(
+            size,
+            TextureDimension::D2,
+            vec![0; size.volume() * format.pixel_size()],
+            format,
+        )
+    }
+
+    fn assert_color_eq(expected: Color, actual: Color, epsilon: f32, format: TextureFormat) {
+        let expected = expected.as_linear_rgba_f32();
+        let actual = actual.as_linear_rgba_f32();
+        for (expected, actual) in expected.into_iter().zip(actual) {
+            assert!(
+                (expected - actual).abs() <= epsilon,
+                "{format:?}: expected {expected}, got {actual}",
+            );
+        }
+    }
+
+    #[test]
+    fn get_color_at_reads_each_format() {
+        let cases: [(TextureFormat, &[u8], Color); 8] = [
+            (
+                TextureFormat::R8Unorm,
+                &[51],
+                Color::rgb_linear(0.2, 0.2, 0.2),
+            ),
+            (
+                TextureFormat::Rg8Unorm,
+                &[51, 255],
+                Color::rgb_linear(0.2, 1.0, 0.0),
+            ),
+            (
+                TextureFormat::Rgba8Unorm,
+                &[255, 51, 0, 102],
+                Color::rgba_linear(1.0, 0.2, 0.0, 0.4),
+            ),
+            (
+                TextureFormat::Rgba8UnormSrgb,
+                &[255, 51, 0, 102],
+                Color::rgba(1.0, 0.2, 0.0, 0.4),
+            ),
+            (
+                TextureFormat::Bgra8Unorm,
+                &[0, 51, 255, 102],
+                Color::rgba_linear(1.0, 0.2, 0.0, 0.4),
+            ),
+            (
+                TextureFormat::Bgra8UnormSrgb,
+                &[0, 51, 255, 102],
+                Color::rgba(1.0, 0.2, 0.0, 0.4),
+            ),
+            (
+                // 1.0, 0.5, -2.0 and 0.25 as half floats
+                TextureFormat::Rgba16Float,
+                &[0x00, 0x3c, 0x00, 0x38, 0x00, 0xc0, 0x00, 0x34],
+                Color::rgba_linear(1.0, 0.5, -2.0, 0.25),
+            ),
+            (
+                TextureFormat::Rgba32Float,
+                &[
+                    0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0xc0, 0x00,
+                    0x00, 0x80, 0x3e,
+                ],
+                Color::rgba_linear(1.0, 0.5, -2.0, 0.25),
+            ),
+        ];
+
+        for (format, pixel, expected) in cases {
+            let mut image = image_2d(2, 2, format);
+            let offset = 3 * format.pixel_size();
+            image.data[offset..offset + pixel.len()].copy_from_slice(pixel);
+
+            let color = image.get_color_at(1, 1).unwrap();
+            assert_eq!(
+                std::mem::discriminant(&color),
+                std::mem::discriminant(&expected),
+                "{format:?}",
+            );
+            assert_color_eq(expected, color, 1e-6, format);
+            assert_eq!(image.get_color_at(0, 1).unwrap().r(), 0.0, "{format:?}");
+        }
+    }
+
+    #[test]
+    fn set_color_at_writes_each_format() {
+        let color = Color::rgba_linear(1.0, 0.2, 0.0, 0.4);
+        let cases: [(TextureFormat, &[u8]); 8] = [
+            // Relative luminance of the color
+            (TextureFormat::R8Unorm, &[91]),
+            (TextureFormat::Rg8Unorm, &[255, 51]),
+            (TextureFormat::Rgba8Unorm, &[255, 51, 0, 102]),
+            (TextureFormat::Rgba8UnormSrgb, &[255, 124, 0, 102]),
+            (TextureFormat::Bgra8Unorm, &[0, 51, 255, 102]),
+            (TextureFormat::Bgra8UnormSrgb, &[0, 124, 255, 102]),
+            (
+                // 1.0, 0.2, 0.0 and 0.4 as half floats, rounded to the nearest
+                TextureFormat::Rgba16Float,
+                &[0x00, 0x3c, 0x66, 0x32, 0x00, 0x00, 0x66, 0x36],
+            ),
+            (
+                TextureFormat::Rgba32Float,
+                &[
+                    0x00, 0x00, 0x80, 0x3f, 0xcd, 0xcc, 0x4c, 0x3e, 0x00, 0x00, 0x00, 0x00, 0xcd,
+                    0xcc, 0xcc, 0x3e,
+                ],
+            ),
+        ];
+
+        for (format, expected) in cases {
+            let mut image = image_2d(2, 2, format);
+            image.set_color_at(0, 1, color).unwrap();
+
+            let offset = 2 * format.pixel_size();
+            assert_eq!(
+                &image.data[offset..offset + expected.len()],
+                expected,
+                "{format:?}"
+            );
+            assert!(
+                image.data[..offset].iter().all(|&byte| byte == 0),
+                "{format:?}"
+            );
+            assert!(
+                image.data[offset + expected.len()..]
+                    .iter()
+                    .all(|&byte| byte == 0),
+                "{format:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn set_color_at_clamps_normalized_formats() {
+        let mut image = image_2d(1, 1, TextureFormat::Rgba8Unorm);
+        image
+            .set_color_at(0, 0, Color::rgba_linear(2.0, -1.0, 0.5, 1.5))
+            .unwrap();
+        assert_eq!(image.data, [255, 0, 128, 255]);
+
+        let mut image = image_2d(1, 1, TextureFormat::Rgba16Float);
+        image
+            .set_color_at(0, 0, Color::rgba_linear(2.0, -1.0, 0.5, 1.5))
+            .unwrap();
+        assert_color_eq(
+            Color::rgba_linear(2.0, -1.0, 0.5, 1.5),
+            image.get_color_at(0, 0).unwrap(),
+            0.0,
+            TextureFormat::Rgba16Float,
+        );
+    }
+
+    #[test]
+    fn colors_round_trip_through_each_format() {
+        // The largest error of a linear color stored in 8 bit sRGB, at the top of the curve.
+        let srgb_epsilon = 1.0 - 254.5f32 / 255.0;
+        let srgb_epsilon = 1.0 - (1.0 - srgb_epsilon).nonlinear_to_linear_srgb() + 1e-6;
+        for format in ACCESSIBLE_FORMATS {
+            let epsilon = match format {
+                TextureFormat::Rgba8UnormSrgb | TextureFormat::Bgra8UnormSrgb => srgb_epsilon,
+                TextureFormat::Rgba16Float => 1.0 / 2048.0,
+                TextureFormat::Rgba32Float => 0.0,
+                _ => 0.5 / 255.0 + 1e-6,
+            };
+            let mut image = image_2d(3, 2, format);
+            for step in 0..=255u8 {
+                let value = step as f32 / 255.0;
+                let color = match format {
+                    // Gray, so that the luminance is the stored value
+                    TextureFormat::R8Unorm => Color::rgb_linear(value, value, value),
+                    TextureFormat::Rg8Unorm => Color::rgb_linear(value, 1.0 - value, 0.0),
+                    _ => Color::rgba_linear(value, 1.0 - value, value * value, 1.0 - value / 2.0),
+                };
+                image.set_color_at(2, 1, color).unwrap();
+                assert_color_eq(color, image.get_color_at(2, 1).unwrap(), epsilon, format);
+            }
+        }
+    }
+
+    #[test]
+    fn srgb_pixels_round_trip_exactly() {
+        for format in [TextureFormat::Rgba8UnormSrgb, TextureFormat::Bgra8UnormSrgb] {
+            let mut image = image_2d(1, 1, format);
+            for value in 0..=255u8 {
+                let pixel = [value, 255 - value, value / 2, value];
+                image.data.copy_from_slice(&pixel);
+                // Written back as a linear color, so that it goes through both conversions
+                let [r, g, b, a] = image.get_color_at(0, 0).unwrap().as_linear_rgba_f32();
+                image
+                    .set_color_at(0, 0, Color::rgba_linear(r, g, b, a))
+                    .unwrap();
+                assert_eq!(image.data, pixel, "{format:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn pixel_access_is_bounds_checked() {
+        let mut image = image_2d(3, 2, TextureFormat::Rgba8UnormSrgb);
+        assert_eq!(
+            image.get_color_at(3, 0),
+            Err(TextureAccessError::OutOfBounds { x: 3, y: 0 })
+        );
+        assert_eq!(
+            image.set_color_at(0, 2, Color::WHITE),
+            Err(TextureAccessError::OutOfBounds { x: 0, y: 2 })
+        );
+        assert!(image.data.iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn unloaded_images_return_an_error() {
+        let mut image = image_2d(2, 2, TextureFormat::Rgba8UnormSrgb);
+        image.asset_usage = RenderAssetUsages::RENDER_WORLD;
+        let extracted = image.take_asset();
+        assert_eq!(extracted.data.len(), 16);
+        assert!(image.is_unloaded());
+        assert!(!extracted.is_unloaded());
+
+        // The image keeps its metadata
+        assert_eq!(image.size(), Vec2::splat(2.0));
+        assert_eq!(image.get_color_at(0, 0), Err(TextureAccessError::Unloaded));
+        assert_eq!(
+            image.set_color_at(1, 1, Color::WHITE),
+            Err(TextureAccessError::Unloaded)
+        );
+        assert_eq!(
+            image.get_color_at(2, 0),
+            Err(TextureAccessError::OutOfBounds { x: 2, y: 0 })
+        );
+        assert!(image.convert(TextureFormat::R8Unorm).is_none());
+    }
+
+    #[test]
+    fn unsupported_formats_return_an_error() {
+        for format in [
+            TextureFormat::Bc1RgbaUnormSrgb,
+            TextureFormat::Etc2Rgb8Unorm,
+            TextureFormat::Rgba16Unorm,
+            TextureFormat::Depth32Float,
+        ] {
+            let mut image = Image {
+                data: vec![0; 64],
+                ..Default::default()
+            };
+            image.texture_descriptor.format = format;
+            image.texture_descriptor.size = Extent3d {
+                width: 4,
+                height: 4,
+                depth_or_array_layers: 1,
+            };
+
+            let error = TextureAccessError::UnsupportedTextureFormat(format);
+            assert_eq!(image.get_color_at(0, 0), Err(error));
+            assert_eq!(image.set_color_at(0, 0, Color::WHITE), Err(error));
+            if format.describe().block_dimensions != (1, 1) {
+                assert_eq!(image.resize_in_place(Extent3d::default()), Err(error));
+                assert!(image.convert(TextureFormat::Rgba8UnormSrgb).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn resize_in_place_keeps_pixels_in_place() {
+        let mut image = Image::new(
+            Extent3d {
+                width: 3,
+                height: 2,
+                depth_or_array_layers: 2,
+            },
+            TextureDimension::D2,
+            (1..=12).collect(),
+            TextureFormat::R8Unorm,
+        );
+
+        image
+            .resize_in_place(Extent3d {
+                width: 2,
+                height: 3,
+                depth_or_array_layers: 2,
+            })
+            .unwrap();
+        #[rustfmt::skip]
+        assert_eq!(image.data, [
+            1, 2,
+            4, 5,
+            0, 0,
+            7, 8,
+            10, 11,
+            0, 0,
+        ]);
+
+        image
+            .resize_in_place(Extent3d {
+                width: 3,
+                height: 1,
+                depth_or_array_layers: 3,
+            })
+            .unwrap();
+        assert_eq!(image.data, [1, 2, 0, 7, 8, 0, 0, 0, 0]);
+        assert_eq!(image.texture_descriptor.size.depth_or_array_layers, 3);
+    }
+
+    #[test]
+    fn convert_between_accessible_formats() {
+        let colors = [
+            Color::rgba(1.0, 0.5, 0.0, 1.0),
+            Color::rgba(0.2, 0.4, 0.6, 0.8),
+            Color::BLACK,
+            Color::WHITE,
+        ];
+        let mut image = image_2d(2, 2, TextureFormat::Rgba8UnormSrgb);
+        for (index, color) in colors.into_iter().enumerate() {
+            image
+                .set_color_at(index as u32 % 2, index as u32 / 2, color)
+                .unwrap();
+        }
+
+        for format in [
+            TextureFormat::Bgra8UnormSrgb,
+            TextureFormat::Rgba16Float,
+            TextureFormat::Rgba32Float,
+        ] {
+            let converted = image.convert(format).unwrap();
+            assert_eq!(converted.texture_descriptor.format, format);
+            assert_eq!(converted.data.len(), 4 * format.pixel_size());
+            assert_eq!(
+                converted
+                    .convert(TextureFormat::Rgba8UnormSrgb)
+                    .unwrap()
+                    .data,
+                image.data,
+                "{format:?}"
+            );
+        }
+
+        let gray = image.convert(TextureFormat::R8Unorm).unwrap();
+        assert_eq!(gray.data[2..], [0, 255]);
+    }
+
+    #[test]
+    fn half_floats_round_to_nearest_even() {
+        let cases = [
+            (0.0, 0x0000),
+            (-0.0, 0x8000),
+            (1.0, 0x3c00),
+            (-2.0, 0xc000),
+            (65504.0, 0x7bff),
+            // Halfway between 65504 and the next value rounds to infinity
+            (65520.0, 0x7c00),
+            (1e10, 0x7c00),
+            (f32::INFINITY, 0x7c00),
+            (f32::NEG_INFINITY, 0xfc00),
+            // Smallest normal and subnormal values
+            (6.103_515_6e-5, 0x0400),
+            (5.960_464_5e-8, 0x0001),
+            // Halfway below the smallest subnormal rounds to zero, above it to the subnormal
+            (2.980_232_2e-8, 0x0000),
+            (3.0e-8, 0x0001),
+            // Ties between 1.0 and the next value round to even
+            (1.0 + 1.0 / 2048.0, 0x3c00),
+            (1.0 + 3.0 / 2048.0, 0x3c02),
+        ];
+        for (value, half) in cases {
+            assert_eq!(f32_to_f16(value), half, "{value}");
+        }
+        assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+
+        for half in (0..=u16::MAX).filter(|half| half & 0x7c00 != 0x7c00) {
+            assert_eq!(f32_to_f16(f16_to_f32(half)), half, "{half:#x}");
+        }
+    }
 }
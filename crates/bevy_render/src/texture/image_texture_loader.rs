@@ -5,6 +5,7 @@ use bevy_utils::BoxedFuture;
 use thiserror::Error;
 
 use crate::{
+    render_asset::RenderAssetUsages,
     renderer::RenderDevice,
     texture::{Image, ImageType, TextureError},
 };
@@ -15,6 +16,8 @@ use super::CompressedImageFormats;
 #[derive(Clone)]
 pub struct ImageTextureLoader {
     supported_compressed_formats: CompressedImageFormats,
+    /// The [`RenderAssetUsages`] of the loaded images.
+    pub asset_usage: RenderAssetUsages,
 }
 
 const FILE_EXTENSIONS: &[&str] = &[
@@ -46,7 +49,7 @@ impl AssetLoader for ImageTextureLoader {
             // use the file extension for the image type
             let ext = load_context.path().extension().unwrap().to_str().unwrap();
 
-            let dyn_img = Image::from_buffer(
+            let mut dyn_img = Image::from_buffer(
                 bytes,
                 ImageType::Extension(ext),
                 self.supported_compressed_formats,
@@ -57,6 +60,8 @@ impl AssetLoader for ImageTextureLoader {
                 path: format!("{}", load_context.path().display()),
             })?;
 
+            dyn_img.asset_usage = self.asset_usage;
+
             load_context.set_default_asset(LoadedAsset::new(dyn_img));
             Ok(())
         })
@@ -76,6 +81,7 @@ impl FromWorld for ImageTextureLoader {
         };
         Self {
             supported_compressed_formats,
+            asset_usage: RenderAssetUsages::default(),
         }
     }
 }
@@ -31,7 +31,7 @@ pub use image_texture_loader::*;
 pub use texture_cache::*;
 
 use crate::{
-    render_asset::{PrepareAssetSet, RenderAssetPlugin},
+    render_asset::{PrepareAssetSet, RenderAssetPlugin, RenderAssetUsages},
     renderer::RenderDevice,
     RenderApp, RenderSet,
 };
@@ -44,6 +44,11 @@ use bevy_ecs::prelude::*;
 pub struct ImagePlugin {
     /// The default image sampler to use when [`ImageSampler`] is set to `Default`.
     pub default_sampler: wgpu::SamplerDescriptor<'static>,
+    /// The [`RenderAssetUsages`] of the images loaded from files.
+    ///
+    /// Use [`RenderAssetUsages::RENDER_WORLD`] to free their data from the main world once they
+    /// are sent to the GPU, if no system needs to read them.
+    pub default_asset_usage: RenderAssetUsages,
 }
 
 impl Default for ImagePlugin {
@@ -57,6 +62,7 @@ impl ImagePlugin {
     pub fn default_linear() -> ImagePlugin {
         ImagePlugin {
             default_sampler: ImageSampler::linear_descriptor(),
+            default_asset_usage: RenderAssetUsages::default(),
         }
     }
 
@@ -64,6 +70,7 @@ impl ImagePlugin {
     pub fn default_nearest() -> ImagePlugin {
         ImagePlugin {
             default_sampler: ImageSampler::nearest_descriptor(),
+            default_asset_usage: RenderAssetUsages::default(),
         }
     }
 }
@@ -80,17 +87,23 @@ impl Plugin for ImagePlugin {
             feature = "ktx2",
         ))]
         {
-            app.init_asset_loader::<ImageTextureLoader>();
+            let mut loader = ImageTextureLoader::from_world(&mut app.world);
+            loader.asset_usage = self.default_asset_usage;
+            app.add_asset_loader(loader);
         }
 
         #[cfg(feature = "exr")]
         {
-            app.init_asset_loader::<ExrTextureLoader>();
+            app.add_asset_loader(ExrTextureLoader {
+                asset_usage: self.default_asset_usage,
+            });
         }
 
         #[cfg(feature = "hdr")]
         {
-            app.init_asset_loader::<HdrTextureLoader>();
+            app.add_asset_loader(HdrTextureLoader {
+                asset_usage: self.default_asset_usage,
+            });
         }
 
         app.add_plugin(RenderAssetPlugin::<Image>::with_prepare_asset_set(
@@ -6,7 +6,7 @@ pub use visibility::*;
 pub use window::*;
 
 use crate::{
-    camera::ExtractedCamera,
+    camera::{ExtractedCamera, ManualTextureViews, TemporalJitter},
     extract_resource::{ExtractResource, ExtractResourcePlugin},
     prelude::{Image, Shader},
     render_asset::RenderAssets,
@@ -18,7 +18,7 @@ use crate::{
 };
 use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::*;
-use bevy_math::{Mat4, UVec4, Vec3, Vec4};
+use bevy_math::{Mat4, UVec4, Vec3, Vec4, Vec4Swizzles};
 use bevy_reflect::{Reflect, TypeUuid};
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashMap;
@@ -67,24 +67,41 @@ impl Plugin for ViewPlugin {
     }
 }
 
-/// Configuration resource for [Multi-Sample Anti-Aliasing](https://en.wikipedia.org/wiki/Multisample_anti-aliasing).
+/// Configuration component for [Multi-Sample Anti-Aliasing](https://en.wikipedia.org/wiki/Multisample_anti-aliasing).
 ///
-/// The number of samples to run for Multi-Sample Anti-Aliasing. Higher numbers result in
-/// smoother edges.
+/// The number of samples to run for Multi-Sample Anti-Aliasing on a camera. Higher numbers
+/// result in smoother edges.
 /// Defaults to 4 samples.
 ///
 /// Note that web currently only supports 1 or 4 samples.
 ///
+/// Cameras without this component use the [`Msaa`] resource instead. Configuring MSAA with the
+/// resource is deprecated, and will be removed in a future release.
+///
 /// # Example
 /// ```
-/// # use bevy_app::prelude::App;
-/// # use bevy_render::prelude::Msaa;
-/// App::new()
-///     .insert_resource(Msaa::default())
-///     .run();
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_render::{camera::Camera, prelude::Msaa};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Camera::default(), Msaa::Sample8));
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
 /// ```
-#[derive(Resource, Default, Clone, Copy, ExtractResource, Reflect, PartialEq, PartialOrd)]
-#[reflect(Resource)]
+#[derive(
+    Resource,
+    Component,
+    Default,
+    Clone,
+    Copy,
+    ExtractResource,
+    Reflect,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Hash,
+    Debug,
+)]
+#[reflect(Resource, Component)]
 pub enum Msaa {
     Off = 1,
     Sample2 = 2,
@@ -112,6 +129,11 @@ pub struct ExtractedView {
     // uvec4(origin.x, origin.y, width, height)
     pub viewport: UVec4,
     pub color_grading: ColorGrading,
+    /// The MSAA of the view, from the [`Msaa`] component of its camera or the [`Msaa`] resource.
+    ///
+    /// The textures of the view, including its depth and prepass textures, are created with this
+    /// sample count, so that pipelines specialized on it can render to all of them.
+    pub msaa: Msaa,
 }
 
 impl ExtractedView {
@@ -158,6 +180,7 @@ impl Default for ColorGrading {
 #[derive(Clone, ShaderType)]
 pub struct ViewUniform {
     view_proj: Mat4,
+    unjittered_view_proj: Mat4,
     inverse_view_proj: Mat4,
     view: Mat4,
     inverse_view: Mat4,
@@ -309,26 +332,40 @@ fn prepare_view_uniforms(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
     mut view_uniforms: ResMut<ViewUniforms>,
-    views: Query<(Entity, &ExtractedView)>,
+    views: Query<(Entity, &ExtractedView, Option<&TemporalJitter>)>,
 ) {
     view_uniforms.uniforms.clear();
-    for (entity, camera) in &views {
-        let projection = camera.projection;
+    for (entity, camera, temporal_jitter) in &views {
+        let viewport = camera.viewport.as_vec4();
+        let unjittered_projection = camera.projection;
+        let mut projection = unjittered_projection;
+
+        if let Some(temporal_jitter) = temporal_jitter {
+            temporal_jitter.jitter_projection(&mut projection, viewport.zw());
+        }
+
         let inverse_projection = projection.inverse();
         let view = camera.transform.compute_matrix();
         let inverse_view = view.inverse();
+
+        let view_proj = if temporal_jitter.is_some() {
+            projection * inverse_view
+        } else {
+            camera
+                .view_projection
+                .unwrap_or_else(|| projection * inverse_view)
+        };
         let view_uniforms = ViewUniformOffset {
             offset: view_uniforms.uniforms.push(ViewUniform {
-                view_proj: camera
-                    .view_projection
-                    .unwrap_or_else(|| projection * inverse_view),
+                view_proj,
+                unjittered_view_proj: unjittered_projection * inverse_view,
                 inverse_view_proj: view * inverse_projection,
                 view,
                 inverse_view,
                 projection,
                 inverse_projection,
                 world_position: camera.transform.translation(),
-                viewport: camera.viewport.as_vec4(),
+                viewport,
                 color_grading: camera.color_grading,
             }),
         };
@@ -356,17 +393,18 @@ fn prepare_view_targets(
     mut commands: Commands,
     windows: Res<ExtractedWindows>,
     images: Res<RenderAssets<Image>>,
-    msaa: Res<Msaa>,
+    manual_texture_views: Res<ManualTextureViews>,
     render_device: Res<RenderDevice>,
     mut texture_cache: ResMut<TextureCache>,
     cameras: Query<(Entity, &ExtractedCamera, &ExtractedView)>,
 ) {
     let mut textures = HashMap::default();
+    let mut sampled_textures = HashMap::default();
     for (entity, camera, view) in cameras.iter() {
         if let (Some(target_size), Some(target)) = (camera.physical_target_size, &camera.target) {
             if let (Some(out_texture_view), Some(out_texture_format)) = (
-                target.get_texture_view(&windows, &images),
-                target.get_texture_format(&windows, &images),
+                target.get_texture_view(&windows, &images, &manual_texture_views),
+                target.get_texture_format(&windows, &images, &manual_texture_views),
             ) {
                 let size = Extent3d {
                     width: target_size.x,
@@ -380,7 +418,7 @@ fn prepare_view_targets(
                     TextureFormat::bevy_default()
                 };
 
-                let main_textures = textures
+                let mut main_textures = textures
                     .entry((camera.target.clone(), view.hdr))
                     .or_insert_with(|| {
                         let descriptor = TextureDescriptor {
@@ -414,31 +452,41 @@ fn prepare_view_targets(
                                     },
                                 )
                                 .default_view,
-                            sampled: (msaa.samples() > 1).then(|| {
-                                texture_cache
-                                    .get(
-                                        &render_device,
-                                        TextureDescriptor {
-                                            label: Some("main_texture_sampled"),
-                                            size,
-                                            mip_level_count: 1,
-                                            sample_count: msaa.samples(),
-                                            dimension: TextureDimension::D2,
-                                            format: main_texture_format,
-                                            usage: TextureUsages::RENDER_ATTACHMENT,
-                                            view_formats: &[],
-                                        },
-                                    )
-                                    .default_view
-                            }),
+                            sampled: None,
                             main_texture: Arc::new(AtomicUsize::new(0)),
                         }
-                    });
+                    })
+                    .clone();
+
+                // Views of the same target with different sample counts share the resolved main
+                // textures, but not the multisampled one
+                main_textures.sampled = (view.msaa.samples() > 1).then(|| {
+                    sampled_textures
+                        .entry((camera.target.clone(), view.hdr, view.msaa))
+                        .or_insert_with(|| {
+                            texture_cache
+                                .get(
+                                    &render_device,
+                                    TextureDescriptor {
+                                        label: Some("main_texture_sampled"),
+                                        size,
+                                        mip_level_count: 1,
+                                        sample_count: view.msaa.samples(),
+                                        dimension: TextureDimension::D2,
+                                        format: main_texture_format,
+                                        usage: TextureUsages::RENDER_ATTACHMENT,
+                                        view_formats: &[],
+                                    },
+                                )
+                                .default_view
+                        })
+                        .clone()
+                });
 
                 commands.entity(entity).insert(ViewTarget {
-                    main_textures: main_textures.clone(),
-                    main_texture_format,
                     main_texture: main_textures.main_texture.clone(),
+                    main_textures,
+                    main_texture_format,
                     out_texture: out_texture_view.clone(),
                     out_texture_format,
                 });
@@ -453,3 +501,43 @@ pub enum ViewSet {
     /// Prepares view uniforms
     PrepareUniforms,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render_resource::encase::UniformBuffer;
+
+    #[test]
+    fn view_uniform_packs_color_grading() {
+        let color_grading = ColorGrading {
+            exposure: 1.5,
+            gamma: 2.2,
+            pre_saturation: 0.5,
+            post_saturation: 1.25,
+        };
+        let uniform = ViewUniform {
+            view_proj: Mat4::IDENTITY,
+            unjittered_view_proj: Mat4::IDENTITY,
+            inverse_view_proj: Mat4::IDENTITY,
+            view: Mat4::IDENTITY,
+            inverse_view: Mat4::IDENTITY,
+            projection: Mat4::IDENTITY,
+            inverse_projection: Mat4::IDENTITY,
+            world_position: Vec3::ZERO,
+            viewport: Vec4::ZERO,
+            color_grading,
+        };
+
+        let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&uniform).unwrap();
+        let bytes = buffer.into_inner();
+
+        // `color_grading` is the last member of `ViewUniform`: 7 matrices, a vec3 and a vec4 precede it
+        let offset = 7 * 64 + 16 + 16;
+        let packed: Vec<f32> = bytes[offset..offset + 16]
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(packed, [1.5, 2.2, 0.5, 1.25]);
+    }
+}
@@ -12,7 +12,7 @@ use bevy_window::{
 use std::ops::{Deref, DerefMut};
 use wgpu::TextureFormat;
 
-use super::Msaa;
+use super::{ExtractedView, Msaa};
 
 /// Token to ensure a system runs on the main thread.
 #[derive(Resource, Default)]
@@ -167,6 +167,7 @@ pub struct WindowSurfaces {
 ///   another alternative is to try to use [`ANGLE`](https://github.com/gfx-rs/wgpu#angle) and
 ///   [`Backends::GL`](crate::settings::Backends::GL) if your GPU/drivers support `OpenGL 4.3` / `OpenGL ES 3.0` or
 ///   later.
+#[allow(clippy::too_many_arguments)]
 pub fn prepare_windows(
     // By accessing a NonSend resource, we tell the scheduler to put this system on the main thread,
     // which is necessary for some OS s
@@ -177,6 +178,8 @@ pub fn prepare_windows(
     render_instance: Res<RenderInstance>,
     render_adapter: Res<RenderAdapter>,
     mut msaa: ResMut<Msaa>,
+    mut views: Query<&mut ExtractedView>,
+    mut warned_msaa: Local<HashSet<Msaa>>,
 ) {
     for window in windows.windows.values_mut() {
         let window_surfaces = window_surfaces.deref_mut();
@@ -234,31 +237,20 @@ pub fn prepare_windows(
         // This is an ugly hack to work around drivers that don't support MSAA.
         // This should be removed once https://github.com/bevyengine/bevy/issues/7194 lands and we're doing proper
         // feature detection for MSAA.
-        // When removed, we can also remove the `.after(prepare_windows)` of `prepare_core_3d_depth_textures` and `prepare_prepass_textures`
+        // When removed, we can also remove the `.after(prepare_windows)` of `prepare_core_3d_depth_textures`, `prepare_prepass_textures`
+        // and `prepare_oit_resolve_pipelines`
         let sample_flags = render_adapter
             .get_texture_format_features(surface_configuration.format)
             .flags;
 
-        if !sample_flags.sample_count_supported(msaa.samples()) {
-            let fallback = if sample_flags.sample_count_supported(Msaa::default().samples()) {
-                Msaa::default()
-            } else {
-                Msaa::Off
-            };
-
-            let fallback_str = if fallback == Msaa::Off {
-                "disabling MSAA".to_owned()
-            } else {
-                format!("MSAA {}x", fallback.samples())
-            };
-
-            bevy_log::warn!(
-                "MSAA {}x is not supported on this device. Falling back to {}.",
-                msaa.samples(),
-                fallback_str,
-            );
+        if let Some(fallback) = msaa_fallback(sample_flags, *msaa, &mut warned_msaa) {
             *msaa = fallback;
         }
+        for mut view in &mut views {
+            if let Some(fallback) = msaa_fallback(sample_flags, view.msaa, &mut warned_msaa) {
+                view.msaa = fallback;
+            }
+        }
 
         // A recurring issue is hitting `wgpu::SurfaceError::Timeout` on certain Linux
         // mesa driver implementations. This seems to be a quirk of some drivers.
@@ -313,3 +305,37 @@ pub fn prepare_windows(
         window.swap_chain_texture_format = Some(surface_data.format);
     }
 }
+
+/// Returns the sample count to use instead of `msaa` if the device doesn't support it, logging a
+/// warning the first time.
+fn msaa_fallback(
+    sample_flags: wgpu::TextureFormatFeatureFlags,
+    msaa: Msaa,
+    warned: &mut HashSet<Msaa>,
+) -> Option<Msaa> {
+    if sample_flags.sample_count_supported(msaa.samples()) {
+        return None;
+    }
+
+    let fallback = if sample_flags.sample_count_supported(Msaa::default().samples()) {
+        Msaa::default()
+    } else {
+        Msaa::Off
+    };
+
+    // The views are extracted every frame, so only warn once for each sample count
+    if warned.insert(msaa) {
+        let fallback_str = if fallback == Msaa::Off {
+            "disabling MSAA".to_owned()
+        } else {
+            format!("MSAA {}x", fallback.samples())
+        };
+
+        bevy_log::warn!(
+            "MSAA {}x is not supported on this device. Falling back to {}.",
+            msaa.samples(),
+            fallback_str,
+        );
+    }
+    Some(fallback)
+}
@@ -3,17 +3,19 @@ use anyhow::Result;
 use bevy_app::AppTypeRegistry;
 use bevy_ecs::{
     entity::EntityMap,
-    reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectMapEntitiesResource, ReflectResource},
     world::World,
 };
 use bevy_reflect::{Reflect, TypeRegistryArc, TypeUuid};
+use bevy_utils::tracing::warn;
 
 #[cfg(feature = "serialize")]
-use crate::serde::SceneSerializer;
+use crate::serde::{SceneSerializationConfig, SceneSerializer};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
 
-/// A collection of serializable dynamic entities, each with its own run-time defined set of components.
+/// A collection of serializable resources and dynamic entities, each with its own run-time defined
+/// set of components.
 /// To spawn a dynamic scene, you can use either:
 /// * [`SceneSpawner::spawn_dynamic`](crate::SceneSpawner::spawn_dynamic)
 /// * adding the [`DynamicSceneBundle`](crate::DynamicSceneBundle) to an entity
@@ -23,6 +25,9 @@ use serde::Serialize;
 #[derive(Default, TypeUuid)]
 #[uuid = "749479b1-fb8c-4ff8-a775-623aa76014f5"]
 pub struct DynamicScene {
+    /// The reflected resources of the scene, which must reflect the
+    /// [`Resource`](bevy_ecs::system::Resource) trait.
+    pub resources: Vec<Box<dyn Reflect>>,
     pub entities: Vec<DynamicEntity>,
 }
 
@@ -46,16 +51,24 @@ impl DynamicScene {
         let mut builder =
             DynamicSceneBuilder::from_world_with_type_registry(world, type_registry.clone());
 
-        builder.extract_entities(world.iter_entities().map(|entity| entity.id()));
+        builder
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources();
 
         builder.build()
     }
 
-    /// Write the dynamic entities and their corresponding components to the given world.
+    /// Write the resources, the dynamic entities and their corresponding components to the given
+    /// world.
+    ///
+    /// The entities referenced by the resources are mapped with `entity_map` if they reflect
+    /// [`MapEntitiesResource`](ReflectMapEntitiesResource). A resource the world holds as a
+    /// non-send resource is left untouched, with a warning.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
-    /// [`Component`](bevy_ecs::component::Component) trait.
+    /// [`Component`](bevy_ecs::component::Component) or
+    /// [`Resource`](bevy_ecs::system::Resource) trait.
     pub fn write_to_world_with(
         &self,
         world: &mut World,
@@ -102,6 +115,41 @@ impl DynamicScene {
             }
         }
 
+        for resource in &self.resources {
+            let registration = type_registry
+                .get_with_name(resource.type_name())
+                .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                    type_name: resource.type_name().to_string(),
+                })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_name: resource.type_name().to_string(),
+                }
+            })?;
+
+            let non_send_data = world
+                .components()
+                .get_resource_id(registration.type_id())
+                .and_then(|component_id| world.storages().non_send_resources.get(component_id));
+            if matches!(non_send_data, Some(data) if data.is_present()) {
+                warn!(
+                    "the world holds the resource `{}` as a non-send resource, it won't be written from the scene",
+                    resource.type_name()
+                );
+                continue;
+            }
+
+            // If the world already has the given resource, just apply the (possibly) new value,
+            // otherwise insert the resource.
+            reflect_resource.apply_or_insert(world, &**resource);
+
+            if let Some(map_entities_reflect) = registration.data::<ReflectMapEntitiesResource>() {
+                map_entities_reflect
+                    .map_entities(world, entity_map)
+                    .unwrap();
+            }
+        }
+
         Ok(())
     }
 
@@ -125,6 +173,21 @@ impl DynamicScene {
     pub fn serialize_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into rust object notation (ron), formatting it and rounding
+    /// or omitting the values of its components as configured.
+    #[cfg(feature = "serialize")]
+    pub fn serialize_ron_with(
+        &self,
+        registry: &TypeRegistryArc,
+        config: &SceneSerializationConfig,
+    ) -> Result<String, ron::Error> {
+        let serializer = SceneSerializer::with_config(self, registry, config);
+        match &config.pretty {
+            Some(pretty_config) => ron::ser::to_string_pretty(&serializer, pretty_config.clone()),
+            None => ron::ser::to_string(&serializer),
+        }
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -133,8 +196,67 @@ pub fn serialize_ron<S>(serialize: S) -> Result<String, ron::Error>
 where
     S: Serialize,
 {
-    let pretty_config = ron::ser::PrettyConfig::default()
+    ron::ser::to_string_pretty(&serialize, default_pretty_config())
+}
+
+#[cfg(feature = "serialize")]
+pub(crate) fn default_pretty_config() -> ron::ser::PrettyConfig {
+    ron::ser::PrettyConfig::default()
         .indentor("  ".to_string())
-        .new_line("\n".to_string());
-    ron::ser::to_string_pretty(&serialize, pretty_config)
+        .new_line("\n".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::AppTypeRegistry;
+    use bevy_ecs::{entity::EntityMap, reflect::ReflectResource, system::Resource, world::World};
+    use bevy_reflect::Reflect;
+
+    use crate::DynamicScene;
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Counter(u32);
+
+    fn create_world() -> World {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<Counter>();
+        world.insert_resource(atr);
+        world
+    }
+
+    #[test]
+    fn resources_are_written_to_the_world() {
+        let mut world = create_world();
+        world.insert_resource(Counter(2));
+        let scene = DynamicScene::from_world(&world, world.resource::<AppTypeRegistry>());
+
+        let mut dst_world = create_world();
+        scene
+            .write_to_world(&mut dst_world, &mut EntityMap::default())
+            .unwrap();
+        assert_eq!(&Counter(2), dst_world.resource::<Counter>());
+
+        dst_world.insert_resource(Counter(5));
+        scene
+            .write_to_world(&mut dst_world, &mut EntityMap::default())
+            .unwrap();
+        assert_eq!(&Counter(2), dst_world.resource::<Counter>());
+    }
+
+    #[test]
+    fn non_send_resources_are_not_written_to() {
+        let mut world = create_world();
+        world.insert_resource(Counter(2));
+        let scene = DynamicScene::from_world(&world, world.resource::<AppTypeRegistry>());
+
+        let mut dst_world = create_world();
+        dst_world.insert_non_send_resource(Counter(5));
+        scene
+            .write_to_world(&mut dst_world, &mut EntityMap::default())
+            .unwrap();
+        assert!(!dst_world.contains_resource::<Counter>());
+        assert_eq!(&Counter(5), dst_world.non_send_resource::<Counter>());
+    }
 }
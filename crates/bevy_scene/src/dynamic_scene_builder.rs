@@ -1,10 +1,17 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{DynamicEntity, DynamicScene, SceneFilter};
 use bevy_app::AppTypeRegistry;
-use bevy_ecs::{prelude::Entity, reflect::ReflectComponent, world::World};
-use bevy_utils::default;
-use std::collections::BTreeMap;
-
-/// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities.
+use bevy_ecs::{
+    component::ComponentId,
+    prelude::Entity,
+    reflect::{ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_reflect::Reflect;
+use bevy_utils::{default, tracing::warn};
+use std::{any::Any, collections::BTreeMap};
+
+/// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities
+/// and resources.
 ///
 /// # Entity Order
 ///
@@ -12,6 +19,12 @@ use std::collections::BTreeMap;
 /// This means that inserting `Entity(1v0)` then `Entity(0v0)` will always result in the entities
 /// being ordered as `[Entity(0v0), Entity(1v0)]`.
 ///
+/// # Resources
+///
+/// [`Self::extract_resources`] extracts the resources registered with
+/// [`ReflectResource`], those selected by the resource filter of the builder. Resources that
+/// aren't [`Send`] are never extracted.
+///
 /// # Example
 /// ```
 /// # use bevy_scene::DynamicSceneBuilder;
@@ -32,6 +45,8 @@ use std::collections::BTreeMap;
 /// ```
 pub struct DynamicSceneBuilder<'w> {
     extracted_scene: BTreeMap<u32, DynamicEntity>,
+    extracted_resources: BTreeMap<ComponentId, Box<dyn Reflect>>,
+    resource_filter: SceneFilter,
     type_registry: AppTypeRegistry,
     original_world: &'w World,
 }
@@ -42,6 +57,8 @@ impl<'w> DynamicSceneBuilder<'w> {
     pub fn from_world(world: &'w World) -> Self {
         Self {
             extracted_scene: default(),
+            extracted_resources: default(),
+            resource_filter: default(),
             type_registry: world.resource::<AppTypeRegistry>().clone(),
             original_world: world,
         }
@@ -52,6 +69,8 @@ impl<'w> DynamicSceneBuilder<'w> {
     pub fn from_world_with_type_registry(world: &'w World, type_registry: AppTypeRegistry) -> Self {
         Self {
             extracted_scene: default(),
+            extracted_resources: default(),
+            resource_filter: default(),
             type_registry,
             original_world: world,
         }
@@ -63,10 +82,29 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// [`Self::remove_empty_entities`] before building the scene.
     pub fn build(self) -> DynamicScene {
         DynamicScene {
+            resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
         }
     }
 
+    /// Replaces the filter selecting the resources extracted by [`Self::extract_resources`].
+    pub fn with_resource_filter(&mut self, filter: SceneFilter) -> &mut Self {
+        self.resource_filter = filter;
+        self
+    }
+
+    /// Allows the resource `T` to be extracted, see [`SceneFilter::allow`].
+    pub fn allow_resource<T: Any>(&mut self) -> &mut Self {
+        self.resource_filter.allow::<T>();
+        self
+    }
+
+    /// Denies the resource `T` from being extracted, see [`SceneFilter::deny`].
+    pub fn deny_resource<T: Any>(&mut self) -> &mut Self {
+        self.resource_filter.deny::<T>();
+        self
+    }
+
     /// Extract one entity from the builder's [`World`].
     ///
     /// Re-extracting an entity that was already extracted will have no effect.
@@ -144,13 +182,82 @@ impl<'w> DynamicSceneBuilder<'w> {
         drop(type_registry);
         self
     }
+
+    /// Extract the resources of the builder's [`World`] that are registered with
+    /// [`ReflectResource`] and allowed by the resource filter of the builder.
+    ///
+    /// Re-extracting a resource that was already extracted will have no effect. Resources that
+    /// aren't [`Send`] can't be stored in a scene, they're skipped with a warning.
+    ///
+    /// ```
+    /// # use bevy_scene::DynamicSceneBuilder;
+    /// # use bevy_app::AppTypeRegistry;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Resource, Default, Reflect)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// #[derive(Resource, Default, Reflect)]
+    /// #[reflect(Resource)]
+    /// struct Settings;
+    ///
+    /// # let mut world = World::default();
+    /// # world.init_resource::<AppTypeRegistry>();
+    /// # world.insert_resource(Score(1));
+    /// # world.insert_resource(Settings);
+    /// let mut builder = DynamicSceneBuilder::from_world(&world);
+    /// builder.deny_resource::<Settings>().extract_resources();
+    /// let scene = builder.build();
+    /// ```
+    pub fn extract_resources(&mut self) -> &mut Self {
+        let type_registry = self.type_registry.read();
+        let components = self.original_world.components();
+        let storages = self.original_world.storages();
+        let reflect_resource = |component_id| {
+            let type_id = components.get_info(component_id)?.type_id()?;
+            if !self.resource_filter.is_allowed_by_id(type_id) {
+                return None;
+            }
+            type_registry.get(type_id)?.data::<ReflectResource>()
+        };
+
+        for (component_id, _) in storages.resources.iter() {
+            if self.extracted_resources.contains_key(&component_id) {
+                continue;
+            }
+            let resource = reflect_resource(component_id)
+                .and_then(|reflect_resource| reflect_resource.reflect(self.original_world));
+            if let Some(resource) = resource {
+                self.extracted_resources
+                    .insert(component_id, resource.clone_value());
+            }
+        }
+
+        for (component_id, data) in storages.non_send_resources.iter() {
+            if data.is_present() && reflect_resource(component_id).is_some() {
+                warn!(
+                    "the resource `{}` isn't `Send`, it won't be extracted to the scene",
+                    components.get_info(component_id).unwrap().name()
+                );
+            }
+        }
+
+        drop(type_registry);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use bevy_app::AppTypeRegistry;
     use bevy_ecs::{
-        component::Component, prelude::Entity, query::With, reflect::ReflectComponent, world::World,
+        component::Component,
+        prelude::Entity,
+        query::With,
+        reflect::{ReflectComponent, ReflectResource},
+        system::Resource,
+        world::World,
     };
 
     use bevy_reflect::Reflect;
@@ -163,6 +270,26 @@ mod tests {
     #[derive(Component, Reflect, Default, Eq, PartialEq, Debug)]
     #[reflect(Component)]
     struct ComponentB;
+    #[derive(Resource, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct ResourceA;
+    #[derive(Resource, Reflect, Default, Eq, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct ResourceB;
+
+    fn create_world_with_resources() -> World {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ResourceA>();
+            register.register::<ResourceB>();
+        }
+        world.insert_resource(atr);
+        world.insert_resource(ResourceA);
+        world.insert_resource(ResourceB);
+        world
+    }
 
     #[test]
     fn extract_one_entity() {
@@ -303,4 +430,50 @@ mod tests {
         assert_eq!(scene.entities.len(), 1);
         assert_eq!(scene.entities[0].entity, entity_a.index());
     }
+
+    #[test]
+    fn extract_resources() {
+        let world = create_world_with_resources();
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_resources();
+        builder.extract_resources();
+        let scene = builder.build();
+
+        // The unregistered `AppTypeRegistry` isn't extracted
+        assert_eq!(scene.resources.len(), 2);
+        assert!(scene.resources[0].represents::<ResourceA>());
+        assert!(scene.resources[1].represents::<ResourceB>());
+    }
+
+    #[test]
+    fn extract_filtered_resources() {
+        let world = create_world_with_resources();
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.allow_resource::<ResourceB>().extract_resources();
+        let scene = builder.build();
+        assert_eq!(scene.resources.len(), 1);
+        assert!(scene.resources[0].represents::<ResourceB>());
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.deny_resource::<ResourceB>().extract_resources();
+        let scene = builder.build();
+        assert_eq!(scene.resources.len(), 1);
+        assert!(scene.resources[0].represents::<ResourceA>());
+    }
+
+    #[test]
+    fn skip_non_send_resources() {
+        let mut world = create_world_with_resources();
+        world.remove_resource::<ResourceB>();
+        world.insert_non_send_resource(ResourceB);
+
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_resources();
+        let scene = builder.build();
+
+        assert_eq!(scene.resources.len(), 1);
+        assert!(scene.resources[0].represents::<ResourceA>());
+    }
 }
@@ -2,6 +2,7 @@ mod bundle;
 mod dynamic_scene;
 mod dynamic_scene_builder;
 mod scene;
+mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
 
@@ -12,6 +13,7 @@ pub use bundle::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
 pub use scene::*;
+pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
 
@@ -36,6 +38,7 @@ impl Plugin for ScenePlugin {
             .add_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
             .init_resource::<SceneSpawner>()
+            .init_resource::<serde::SceneSerializationConfig>()
             .add_system(scene_spawner_system)
             // Systems `*_bundle_spawner` must run before `scene_spawner_system`
             .add_system(scene_spawner.in_base_set(CoreSet::PreUpdate));
@@ -0,0 +1,102 @@
+use bevy_utils::HashSet;
+use std::any::{Any, TypeId};
+
+/// A filter selecting the types extracted to a scene, like the resources extracted by
+/// [`DynamicSceneBuilder::extract_resources`](crate::DynamicSceneBuilder::extract_resources).
+///
+/// Allowing a type turns the filter into an allowlist, which only selects the allowed types.
+/// Denying a type turns it into a denylist, which selects every type but the denied ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SceneFilter {
+    /// Selects every type.
+    #[default]
+    Unset,
+    /// Only selects the types of the set.
+    Allowlist(HashSet<TypeId>),
+    /// Selects every type but the types of the set.
+    Denylist(HashSet<TypeId>),
+}
+
+impl SceneFilter {
+    /// Allows the type `T`, turning this filter into an allowlist if it isn't one.
+    ///
+    /// A denylist is replaced by an allowlist, since it allowed `T` already.
+    pub fn allow<T: Any>(&mut self) -> &mut Self {
+        self.allow_by_id(TypeId::of::<T>())
+    }
+
+    /// Allows the type of `type_id`, see [`Self::allow`].
+    pub fn allow_by_id(&mut self, type_id: TypeId) -> &mut Self {
+        match self {
+            Self::Allowlist(list) => {
+                list.insert(type_id);
+            }
+            Self::Unset | Self::Denylist(_) => {
+                *self = Self::Allowlist(HashSet::from_iter([type_id]));
+            }
+        }
+        self
+    }
+
+    /// Denies the type `T`, turning this filter into a denylist if it isn't one.
+    ///
+    /// The type is removed from an allowlist instead, which keeps only selecting the other
+    /// allowed types.
+    pub fn deny<T: Any>(&mut self) -> &mut Self {
+        self.deny_by_id(TypeId::of::<T>())
+    }
+
+    /// Denies the type of `type_id`, see [`Self::deny`].
+    pub fn deny_by_id(&mut self, type_id: TypeId) -> &mut Self {
+        match self {
+            Self::Allowlist(list) => {
+                list.remove(&type_id);
+            }
+            Self::Denylist(list) => {
+                list.insert(type_id);
+            }
+            Self::Unset => {
+                *self = Self::Denylist(HashSet::from_iter([type_id]));
+            }
+        }
+        self
+    }
+
+    /// Returns whether the filter selects the type `T`.
+    pub fn is_allowed<T: Any>(&self) -> bool {
+        self.is_allowed_by_id(TypeId::of::<T>())
+    }
+
+    /// Returns whether the filter selects the type of `type_id`.
+    pub fn is_allowed_by_id(&self, type_id: TypeId) -> bool {
+        match self {
+            Self::Unset => true,
+            Self::Allowlist(list) => list.contains(&type_id),
+            Self::Denylist(list) => !list.contains(&type_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_select_allowed_types() {
+        let mut filter = SceneFilter::default();
+        assert!(filter.is_allowed::<u8>());
+
+        filter.deny::<u8>();
+        assert!(!filter.is_allowed::<u8>());
+        assert!(filter.is_allowed::<u16>());
+
+        filter.allow::<u16>().allow::<u32>();
+        assert!(!filter.is_allowed::<u8>());
+        assert!(filter.is_allowed::<u16>());
+        assert!(filter.is_allowed::<u32>());
+
+        filter.deny::<u16>();
+        assert!(!filter.is_allowed::<u16>());
+        assert!(filter.is_allowed::<u32>());
+    }
+}
@@ -1,12 +1,18 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{dynamic_scene::default_pretty_config, DynamicEntity, DynamicScene};
 use anyhow::Result;
+use bevy_ecs::system::Resource;
 use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
 use bevy_reflect::{
     serde::{TypeRegistrationDeserializer, UntypedReflectDeserializer},
-    Reflect, TypeRegistry, TypeRegistryArc,
+    std_traits::ReflectDefault,
+    DynamicStruct, Reflect, ReflectRef, TypeRegistry, TypeRegistryArc,
 };
 use bevy_utils::HashSet;
-use serde::ser::SerializeMap;
+use ron::ser::PrettyConfig;
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStructVariant, SerializeTuple, SerializeTupleStruct,
+    SerializeTupleVariant,
+};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
     ser::SerializeStruct,
@@ -15,19 +21,95 @@ use serde::{
 use std::fmt::Formatter;
 
 pub const SCENE_STRUCT: &str = "Scene";
+pub const SCENE_RESOURCES: &str = "resources";
 pub const SCENE_ENTITIES: &str = "entities";
 
 pub const ENTITY_STRUCT: &str = "Entity";
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Configures how [`DynamicScene::serialize_ron_with`] writes a scene.
+///
+/// The default configuration writes the same indented RON as [`DynamicScene::serialize_ron`].
+/// Scenes written with any configuration are read back by the [`SceneDeserializer`].
+#[derive(Resource, Debug, Clone)]
+pub struct SceneSerializationConfig {
+    /// How to pretty-print the RON, or `None` to write compact RON on a single line.
+    pub pretty: Option<PrettyConfig>,
+    /// The number of decimal places the `f32` and `f64` values of components are rounded to,
+    /// or `None` to write them with their full precision.
+    pub float_precision: Option<u32>,
+    /// Whether to omit the fields of struct components that are equal to the fields of the
+    /// [`ReflectDefault`] value of their component.
+    ///
+    /// The omitted fields are set to their default value when the scene is spawned, or by
+    /// [`FromReflect`](bevy_reflect::FromReflect) for the types reflecting `Default`.
+    /// Only self-describing formats like RON support scenes with omitted fields.
+    pub omit_default_fields: bool,
+}
+
+impl Default for SceneSerializationConfig {
+    fn default() -> Self {
+        Self::pretty()
+    }
+}
+
+impl SceneSerializationConfig {
+    /// Writes indented RON, with one field per line.
+    pub fn pretty() -> Self {
+        Self {
+            pretty: Some(default_pretty_config()),
+            float_precision: None,
+            omit_default_fields: false,
+        }
+    }
+
+    /// Writes RON on a single line, without whitespace.
+    pub fn compact() -> Self {
+        Self {
+            pretty: None,
+            ..Self::pretty()
+        }
+    }
+
+    /// Rounds the `f32` and `f64` values of components to `decimals` decimal places.
+    pub fn with_float_precision(mut self, decimals: u32) -> Self {
+        self.float_precision = Some(decimals);
+        self
+    }
+
+    /// Omits the fields of struct components that are equal to their default value.
+    pub fn with_default_fields_omitted(mut self) -> Self {
+        self.omit_default_fields = true;
+        self
+    }
+}
+
 pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     pub registry: &'a TypeRegistryArc,
+    /// Rounds and omits the values of components, see [`SceneSerializationConfig`].
+    pub config: Option<&'a SceneSerializationConfig>,
 }
 
 impl<'a> SceneSerializer<'a> {
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistryArc) -> Self {
-        SceneSerializer { scene, registry }
+        SceneSerializer {
+            scene,
+            registry,
+            config: None,
+        }
+    }
+
+    pub fn with_config(
+        scene: &'a DynamicScene,
+        registry: &'a TypeRegistryArc,
+        config: &'a SceneSerializationConfig,
+    ) -> Self {
+        SceneSerializer {
+            scene,
+            registry,
+            config: Some(config),
+        }
     }
 }
 
@@ -36,12 +118,21 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 1)?;
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &ComponentsSerializer {
+                components: &self.scene.resources,
+                registry: self.registry,
+                config: self.config,
+            },
+        )?;
         state.serialize_field(
             SCENE_ENTITIES,
             &EntitiesSerializer {
                 entities: &self.scene.entities,
                 registry: self.registry,
+                config: self.config,
             },
         )?;
         state.end()
@@ -51,6 +142,7 @@ impl<'a> Serialize for SceneSerializer<'a> {
 pub struct EntitiesSerializer<'a> {
     pub entities: &'a [DynamicEntity],
     pub registry: &'a TypeRegistryArc,
+    pub config: Option<&'a SceneSerializationConfig>,
 }
 
 impl<'a> Serialize for EntitiesSerializer<'a> {
@@ -65,6 +157,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
                 &EntitySerializer {
                     entity,
                     registry: self.registry,
+                    config: self.config,
                 },
             )?;
         }
@@ -75,6 +168,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
 pub struct EntitySerializer<'a> {
     pub entity: &'a DynamicEntity,
     pub registry: &'a TypeRegistryArc,
+    pub config: Option<&'a SceneSerializationConfig>,
 }
 
 impl<'a> Serialize for EntitySerializer<'a> {
@@ -88,6 +182,7 @@ impl<'a> Serialize for EntitySerializer<'a> {
             &ComponentsSerializer {
                 components: &self.entity.components,
                 registry: self.registry,
+                config: self.config,
             },
         )?;
         state.end()
@@ -97,6 +192,7 @@ impl<'a> Serialize for EntitySerializer<'a> {
 pub struct ComponentsSerializer<'a> {
     pub components: &'a [Box<dyn Reflect>],
     pub registry: &'a TypeRegistryArc,
+    pub config: Option<&'a SceneSerializationConfig>,
 }
 
 impl<'a> Serialize for ComponentsSerializer<'a> {
@@ -104,20 +200,421 @@ impl<'a> Serialize for ComponentsSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let registry = self.registry.read();
+        let omit_default_fields = self
+            .config
+            .map_or(false, |config| config.omit_default_fields);
+        let float_precision = self.config.and_then(|config| config.float_precision);
         let mut state = serializer.serialize_map(Some(self.components.len()))?;
         for component in self.components {
-            state.serialize_entry(
-                component.type_name(),
-                &TypedReflectSerializer::new(&**component, &self.registry.read()),
-            )?;
+            let without_default_fields = if omit_default_fields {
+                without_default_fields(&**component, &registry)
+            } else {
+                None
+            };
+            let value = match &without_default_fields {
+                Some(without_default_fields) => without_default_fields as &dyn Reflect,
+                None => &**component,
+            };
+            let value = TypedReflectSerializer::new(value, &registry);
+            match float_precision {
+                Some(decimals) => state.serialize_entry(
+                    component.type_name(),
+                    &RoundedFloats {
+                        value: &value,
+                        decimals,
+                    },
+                )?,
+                None => state.serialize_entry(component.type_name(), &value)?,
+            }
         }
         state.end()
     }
 }
 
+/// Copies a struct component without the fields equal to the fields of its [`ReflectDefault`]
+/// value, or returns `None` if it isn't a struct reflecting `Default`.
+///
+/// Only the fields of the component itself are omitted: a struct nested in a field may have
+/// another default than the one of its field once deserialized.
+fn without_default_fields(
+    component: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Option<DynamicStruct> {
+    let component = match component.reflect_ref() {
+        ReflectRef::Struct(component) => component,
+        _ => return None,
+    };
+    let default = registry
+        .get_with_name(component.type_name())?
+        .data::<ReflectDefault>()?
+        .default();
+    let default = match default.reflect_ref() {
+        ReflectRef::Struct(default) => default,
+        _ => return None,
+    };
+
+    let mut output = DynamicStruct::default();
+    output.set_name(component.type_name().to_string());
+    for (index, field) in component.iter_fields().enumerate() {
+        let name = component.name_at(index)?;
+        let is_default = default
+            .field(name)
+            .and_then(|default_field| field.reflect_partial_eq(default_field))
+            .unwrap_or(false);
+        if !is_default {
+            output.insert_boxed(name, field.clone_value());
+        }
+    }
+    Some(output)
+}
+
+/// Rounds `value` to `decimals` decimal places, unless it's too large to be rounded.
+fn round_float(value: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    let rounded = (value * scale).round() / scale;
+    if rounded.is_finite() {
+        rounded
+    } else {
+        value
+    }
+}
+
+/// Serializes a value with its `f32` and `f64` leaves rounded to `decimals` decimal places.
+struct RoundedFloats<'a, T: ?Sized> {
+    value: &'a T,
+    decimals: u32,
+}
+
+impl<'a, T: Serialize + ?Sized> Serialize for RoundedFloats<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(RoundingSerializer {
+            serializer,
+            decimals: self.decimals,
+        })
+    }
+}
+
+/// A [`Serializer`] wrapper rounding the floats it serializes, and those of the values nested in
+/// the compound values it serializes.
+struct RoundingSerializer<S> {
+    serializer: S,
+    decimals: u32,
+}
+
+impl<S> RoundingSerializer<S> {
+    fn rounded<'a, T: ?Sized>(&self, value: &'a T) -> RoundedFloats<'a, T> {
+        RoundedFloats {
+            value,
+            decimals: self.decimals,
+        }
+    }
+}
+
+macro_rules! forward_to_serializer {
+    ($($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $type) -> Result<Self::Ok, Self::Error> {
+                self.serializer.$method(value)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = RoundingSerializer<S::SerializeSeq>;
+    type SerializeTuple = RoundingSerializer<S::SerializeTuple>;
+    type SerializeTupleStruct = RoundingSerializer<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = RoundingSerializer<S::SerializeTupleVariant>;
+    type SerializeMap = RoundingSerializer<S::SerializeMap>;
+    type SerializeStruct = RoundingSerializer<S::SerializeStruct>;
+    type SerializeStructVariant = RoundingSerializer<S::SerializeStructVariant>;
+
+    forward_to_serializer!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_f32(self, value: f32) -> Result<Self::Ok, Self::Error> {
+        let rounded = round_float(value as f64, self.decimals) as f32;
+        self.serializer.serialize_f32(rounded)
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Self::Ok, Self::Error> {
+        let rounded = round_float(value, self.decimals);
+        self.serializer.serialize_f64(rounded)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.serializer.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_some(&value)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.serializer.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serializer
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.rounded(value);
+        self.serializer
+            .serialize_newtype_variant(name, variant_index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let compound = self.serializer.serialize_seq(len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let compound = self.serializer.serialize_tuple(len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let compound = self.serializer.serialize_tuple_struct(name, len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let compound =
+            self.serializer
+                .serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let compound = self.serializer.serialize_map(len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let compound = self.serializer.serialize_struct(name, len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let compound =
+            self.serializer
+                .serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(RoundingSerializer {
+            serializer: compound,
+            decimals: self.decimals,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.serializer.is_human_readable()
+    }
+}
+
+impl<S: SerializeSeq> SerializeSeq for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
+impl<S: SerializeTuple> SerializeTuple for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
+impl<S: SerializeTupleStruct> SerializeTupleStruct for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
+impl<S: SerializeTupleVariant> SerializeTupleVariant for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
+impl<S: SerializeMap> SerializeMap for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), S::Error> {
+        let key = self.rounded(key);
+        self.serializer.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_value(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
+impl<S: SerializeStruct> SerializeStruct for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        self.serializer.skip_field(key)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
+impl<S: SerializeStructVariant> SerializeStructVariant for RoundingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        let value = self.rounded(value);
+        self.serializer.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        self.serializer.skip_field(key)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.serializer.end()
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
+    Resources,
     Entities,
 }
 
@@ -140,7 +637,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     {
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_ENTITIES],
+            &[SCENE_RESOURCES, SCENE_ENTITIES],
             SceneVisitor {
                 type_registry: self.type_registry,
             },
@@ -163,9 +660,18 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        let mut resources = None;
         let mut entities = None;
         while let Some(key) = map.next_key()? {
             match key {
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(ComponentDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
@@ -177,22 +683,35 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
             }
         }
 
+        // Scenes without resources may omit them, like the ones written before they were saved
+        let resources = resources.unwrap_or_default();
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene { entities })
+        Ok(DynamicScene {
+            resources,
+            entities,
+        })
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
+        let resources = seq
+            .next_element_seed(ComponentDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
         let entities = seq
             .next_element_seed(SceneEntitiesDeserializer {
                 type_registry: self.type_registry,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene { entities })
+        Ok(DynamicScene {
+            resources,
+            entities,
+        })
     }
 }
 
@@ -390,12 +909,13 @@ impl<'a, 'de> Visitor<'de> for ComponentVisitor<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::serde::{SceneDeserializer, SceneSerializer};
+    use crate::serde::{SceneDeserializer, SceneSerializationConfig, SceneSerializer};
     use crate::{DynamicScene, DynamicSceneBuilder};
     use bevy_app::AppTypeRegistry;
-    use bevy_ecs::entity::EntityMap;
-    use bevy_ecs::prelude::{Component, ReflectComponent, World};
-    use bevy_reflect::{FromReflect, Reflect, ReflectSerialize};
+    use bevy_ecs::entity::{Entity, EntityMap, MapEntities, MapEntitiesError};
+    use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
+    use bevy_ecs::reflect::ReflectMapEntitiesResource;
+    use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, ReflectSerialize};
     use bincode::Options;
     use serde::de::DeserializeSeed;
     use serde::Serialize;
@@ -429,6 +949,51 @@ mod tests {
         },
     }
 
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource, MapEntitiesResource)]
+    struct Target {
+        entity: Entity,
+    }
+
+    impl Default for Target {
+        fn default() -> Self {
+            Self {
+                entity: Entity::PLACEHOLDER,
+            }
+        }
+    }
+
+    impl MapEntities for Target {
+        fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+            self.entity = entity_map.get(self.entity)?;
+            Ok(())
+        }
+    }
+
+    #[derive(Component, Reflect, FromReflect, Debug, Clone, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Settings {
+        volume: f32,
+        speed: f64,
+        name: String,
+        offset: (f32, f32),
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                volume: 0.8,
+                speed: 1.5,
+                name: String::from("default"),
+                offset: (0.0, 0.0),
+            }
+        }
+    }
+
     fn create_world() -> World {
         let mut world = World::new();
         let registry = AppTypeRegistry::default();
@@ -443,6 +1008,10 @@ mod tests {
             registry.register_type_data::<String, ReflectSerialize>();
             registry.register::<[usize; 3]>();
             registry.register::<(f32, f32)>();
+            registry.register::<Settings>();
+            registry.register::<Score>();
+            registry.register::<Target>();
+            registry.register::<Entity>();
         }
         world.insert_resource(registry);
         world
@@ -461,6 +1030,7 @@ mod tests {
         let scene = builder.build();
 
         let expected = r#"(
+  resources: {},
   entities: {
     0: (
       components: {
@@ -554,10 +1124,10 @@ mod tests {
 
         assert_eq!(
             vec![
-                1, 0, 1, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58, 58, 115, 101, 114,
-                100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67, 111, 109, 112, 111,
-                110, 101, 110, 116, 1, 2, 3, 102, 102, 166, 63, 205, 204, 108, 64, 1, 12, 72, 101,
-                108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                0, 1, 0, 1, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58, 58, 115, 101,
+                114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67, 111, 109, 112,
+                111, 110, 101, 110, 116, 1, 2, 3, 102, 102, 166, 63, 205, 204, 108, 64, 1, 12, 72,
+                101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
             ],
             serialized_scene
         );
@@ -594,11 +1164,11 @@ mod tests {
 
         assert_eq!(
             vec![
-                145, 129, 0, 145, 129, 217, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58,
-                58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67,
-                111, 109, 112, 111, 110, 101, 110, 116, 147, 147, 1, 2, 3, 146, 202, 63, 166, 102,
-                102, 202, 64, 108, 204, 205, 129, 165, 84, 117, 112, 108, 101, 172, 72, 101, 108,
-                108, 111, 32, 87, 111, 114, 108, 100, 33
+                146, 128, 129, 0, 145, 129, 217, 37, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101,
+                58, 58, 115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121,
+                67, 111, 109, 112, 111, 110, 101, 110, 116, 147, 147, 1, 2, 3, 146, 202, 63, 166,
+                102, 102, 202, 64, 108, 204, 205, 129, 165, 84, 117, 112, 108, 101, 172, 72, 101,
+                108, 108, 111, 32, 87, 111, 114, 108, 100, 33
             ],
             buf
         );
@@ -635,12 +1205,12 @@ mod tests {
 
         assert_eq!(
             vec![
-                1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0,
-                0, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58, 58, 115, 101, 114, 100, 101,
-                58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67, 111, 109, 112, 111, 110, 101,
-                110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
-                102, 102, 166, 63, 205, 204, 108, 64, 1, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 72, 101,
-                108, 108, 111, 32, 87, 111, 114, 108, 100, 33
+                0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+                37, 0, 0, 0, 0, 0, 0, 0, 98, 101, 118, 121, 95, 115, 99, 101, 110, 101, 58, 58,
+                115, 101, 114, 100, 101, 58, 58, 116, 101, 115, 116, 115, 58, 58, 77, 121, 67, 111,
+                109, 112, 111, 110, 101, 110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+                3, 0, 0, 0, 0, 0, 0, 0, 102, 102, 166, 63, 205, 204, 108, 64, 1, 0, 0, 0, 12, 0, 0,
+                0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33
             ],
             serialized_scene
         );
@@ -658,8 +1228,257 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    mod a {
+        pub mod b {
+            use bevy_ecs::prelude::{Component, ReflectComponent};
+            use bevy_reflect::Reflect;
+
+            #[derive(Component, Reflect, Default)]
+            #[reflect(Component)]
+            #[type_path = "my_crate::stable"]
+            #[type_name = "Position"]
+            pub struct Moved {
+                pub x: i32,
+            }
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_custom_type_path() {
+        use a::b::Moved;
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Moved>();
+
+        let entity = world.spawn(Moved { x: 123 }).id();
+        let mut builder = DynamicSceneBuilder::from_world(&world);
+        builder.extract_entity(entity);
+        let scene = builder.build();
+
+        let expected = r#"(
+  resources: {},
+  entities: {
+    0: (
+      components: {
+        "my_crate::stable::Position": (
+          x: 123,
+        ),
+      },
+    ),
+  },
+)"#;
+        let output = scene
+            .serialize_ron(&world.resource::<AppTypeRegistry>().0)
+            .unwrap();
+        assert_eq!(expected, output);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        let deserialized_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut map = EntityMap::default();
+        let mut dst_world = World::new();
+        dst_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut map)
+            .unwrap();
+
+        let moved = dst_world.query::<&Moved>().single(&dst_world);
+        assert_eq!(123, moved.x);
+    }
+
+    fn deserialize_ron(world: &World, input: &str) -> DynamicScene {
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+        };
+        scene_deserializer.deserialize(&mut deserializer).unwrap()
+    }
+
+    fn spawn_settings(world: &World, scene: &DynamicScene) -> Settings {
+        let mut map = EntityMap::default();
+        let mut dst_world = World::new();
+        dst_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        scene.write_to_world(&mut dst_world, &mut map).unwrap();
+        dst_world.query::<&Settings>().single(&dst_world).clone()
+    }
+
+    #[test]
+    fn should_roundtrip_compact_ron() {
+        let mut world = create_world();
+        let settings = Settings {
+            volume: 0.25,
+            offset: (1.5, -2.0),
+            ..Default::default()
+        };
+        world.spawn(settings.clone());
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene = DynamicScene::from_world(&world, registry);
+        let output = scene
+            .serialize_ron_with(&registry.0, &SceneSerializationConfig::compact())
+            .unwrap();
+        assert!(!output.contains('\n'), "{output}");
+        assert!(!output.contains(' '), "{output}");
+
+        let deserialized_scene = deserialize_ron(&world, &output);
+        assert_scene_eq(&scene, &deserialized_scene);
+        assert_eq!(settings, spawn_settings(&world, &deserialized_scene));
+    }
+
+    #[test]
+    fn should_round_floats_to_the_configured_precision() {
+        let mut world = create_world();
+        let settings = Settings {
+            volume: 1.000_000_1,
+            speed: std::f64::consts::E,
+            name: String::from("rounded"),
+            offset: (0.123_456, -9.876_543),
+        };
+        world.spawn(settings.clone());
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene = DynamicScene::from_world(&world, registry);
+        let config = SceneSerializationConfig::pretty().with_float_precision(3);
+        let output = scene.serialize_ron_with(&registry.0, &config).unwrap();
+        assert!(!output.contains("1.0000001"), "{output}");
+        assert!(output.contains("2.718,"), "{output}");
+        assert!(output.contains("-9.877"), "{output}");
+
+        let received = spawn_settings(&world, &deserialize_ron(&world, &output));
+        let epsilon = 0.5e-3;
+        assert!((received.volume - settings.volume).abs() <= epsilon);
+        assert!((received.speed - settings.speed).abs() <= epsilon as f64);
+        assert!((received.offset.0 - settings.offset.0).abs() <= epsilon);
+        assert!((received.offset.1 - settings.offset.1).abs() <= epsilon);
+        assert_eq!(settings.name, received.name);
+    }
+
+    #[test]
+    fn should_omit_default_fields() {
+        let mut world = create_world();
+        let settings = Settings {
+            volume: 0.25,
+            ..Default::default()
+        };
+        world.spawn(settings.clone());
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene = DynamicScene::from_world(&world, registry);
+        let config = SceneSerializationConfig::pretty().with_default_fields_omitted();
+        let output = scene.serialize_ron_with(&registry.0, &config).unwrap();
+        let expected = r#"(
+  resources: {},
+  entities: {
+    0: (
+      components: {
+        "bevy_scene::serde::tests::Settings": (
+          volume: 0.25,
+        ),
+      },
+    ),
+  },
+)"#;
+        assert_eq!(expected, output);
+
+        // The omitted fields are filled with the defaults of `FromReflect` and of the spawned component
+        let deserialized_scene = deserialize_ron(&world, &output);
+        let component = &deserialized_scene.entities[0].components[0];
+        assert_eq!(Some(settings.clone()), Settings::from_reflect(&**component));
+        assert_eq!(settings, spawn_settings(&world, &deserialized_scene));
+
+        // Components without a default are written whole
+        let mut world = create_world();
+        world.spawn(Foo(0));
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene = DynamicScene::from_world(&world, registry);
+        let output = scene.serialize_ron_with(&registry.0, &config).unwrap();
+        assert!(
+            output.contains("\"bevy_scene::serde::tests::Foo\": (0)"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_resources_mapping_their_entities() {
+        let mut world = create_world();
+        let target = world.spawn(Foo(123)).id();
+        world.insert_resource(Score(7));
+        world.insert_resource(Target { entity: target });
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let scene = DynamicScene::from_world(&world, registry);
+        let output = scene.serialize_ron(&registry.0).unwrap();
+        let expected = r#"(
+  resources: {
+    "bevy_scene::serde::tests::Score": (7),
+    "bevy_scene::serde::tests::Target": (
+      entity: (
+        generation: 0,
+        index: 0,
+      ),
+    ),
+  },
+  entities: {
+    0: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+      },
+    ),
+  },
+)"#;
+        assert_eq!(expected, output);
+
+        let deserialized_scene = deserialize_ron(&world, &output);
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let mut map = EntityMap::default();
+        let mut dst_world = World::new();
+        dst_world.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        // Takes the index of the scene entity, so that it's mapped to another one
+        dst_world.spawn_empty();
+        dst_world.insert_resource(Score(1));
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut map)
+            .unwrap();
+
+        assert_eq!(7, dst_world.resource::<Score>().0);
+        let mapped = map.get(target).unwrap();
+        assert_ne!(target, mapped);
+        assert_eq!(mapped, dst_world.resource::<Target>().entity);
+        assert_eq!(123, dst_world.get::<Foo>(mapped).unwrap().0);
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
+        assert_eq!(
+            expected.resources.len(),
+            received.resources.len(),
+            "resource count did not match",
+        );
+
+        for expected in &expected.resources {
+            let received = received
+                .resources
+                .iter()
+                .find(|resource| resource.type_name() == expected.type_name())
+                .unwrap_or_else(|| {
+                    panic!("missing resource (expected: `{}`)", expected.type_name())
+                });
+
+            assert!(
+                expected
+                    .reflect_partial_eq(received.as_ref())
+                    .unwrap_or_default(),
+                "resources did not match: (expected: `{expected:?}`, received: `{received:?}`)",
+            );
+        }
+
         assert_eq!(
             expected.entities.len(),
             received.entities.len(),
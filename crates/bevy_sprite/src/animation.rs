@@ -0,0 +1,236 @@
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_reflect::{FromReflect, Reflect};
+use bevy_time::{Time, Timer, TimerMode};
+use bevy_utils::Duration;
+
+use crate::TextureAtlasSprite;
+
+/// Plays sprite sheet animations by advancing the [`TextureAtlasSprite::index`] of entities with
+/// [`AnimationIndices`] and an [`AnimationTimer`].
+#[derive(Default)]
+pub struct SpriteSheetAnimationPlugin;
+
+impl Plugin for SpriteSheetAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AnimationIndices>()
+            .register_type::<AnimationTimer>()
+            .register_type::<AnimationMode>()
+            .add_event::<AnimationFinished>()
+            .add_system(animate_sprite_sheets);
+    }
+}
+
+/// The range of [`TextureAtlasSprite::index`] values making up a sprite sheet animation,
+/// from `first` to `last` inclusive.
+#[derive(Component, Reflect, FromReflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub struct AnimationIndices {
+    pub first: usize,
+    pub last: usize,
+}
+
+impl AnimationIndices {
+    /// Returns the index following `index`, or `None` if an [`AnimationMode::Once`] animation is
+    /// over.
+    ///
+    /// An index outside of the range restarts the animation.
+    fn next_index(&self, index: usize, mode: AnimationMode, reversed: &mut bool) -> Option<usize> {
+        if index < self.first || index > self.last {
+            *reversed = false;
+            return Some(self.first);
+        }
+        match mode {
+            AnimationMode::Loop if index == self.last => Some(self.first),
+            AnimationMode::Once if index == self.last => None,
+            AnimationMode::Loop | AnimationMode::Once => Some(index + 1),
+            AnimationMode::PingPong if self.first == self.last => Some(index),
+            AnimationMode::PingPong => {
+                if (*reversed && index == self.first) || (!*reversed && index == self.last) {
+                    *reversed = !*reversed;
+                }
+                Some(if *reversed { index - 1 } else { index + 1 })
+            }
+        }
+    }
+}
+
+/// How a sprite sheet animation continues after its last frame.
+#[derive(Reflect, FromReflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnimationMode {
+    /// Starts over from the first frame.
+    #[default]
+    Loop,
+    /// Plays backwards to the first frame, then forwards again.
+    PingPong,
+    /// Stays on the last frame and sends an [`AnimationFinished`] event.
+    Once,
+}
+
+/// Times the frames of a sprite sheet animation over the entity's [`AnimationIndices`].
+#[derive(Component, Reflect, FromReflect, Debug, Clone, Default)]
+#[reflect(Component)]
+pub struct AnimationTimer {
+    /// A repeating timer finishing once per frame.
+    ///
+    /// It is paused when an [`AnimationMode::Once`] animation is over, and should be unpaused to
+    /// replay the animation.
+    pub timer: Timer,
+    pub mode: AnimationMode,
+    /// Whether a [`AnimationMode::PingPong`] animation is playing backwards.
+    reversed: bool,
+}
+
+impl AnimationTimer {
+    /// Creates an animation showing each frame for `frame_duration`.
+    pub fn new(frame_duration: Duration, mode: AnimationMode) -> Self {
+        Self {
+            timer: Timer::new(frame_duration, TimerMode::Repeating),
+            mode,
+            reversed: false,
+        }
+    }
+
+    /// Creates an animation showing each frame for `frame_duration` seconds.
+    pub fn from_seconds(frame_duration: f32, mode: AnimationMode) -> Self {
+        Self::new(Duration::from_secs_f32(frame_duration), mode)
+    }
+}
+
+/// An event sent when an [`AnimationMode::Once`] animation has shown its last frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimationFinished {
+    pub entity: Entity,
+}
+
+/// Advances the [`TextureAtlasSprite::index`] of each animated sprite by one frame each time its
+/// [`AnimationTimer`] finishes.
+pub fn animate_sprite_sheets(
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &AnimationIndices,
+        &mut AnimationTimer,
+        &mut TextureAtlasSprite,
+    )>,
+    mut finished: EventWriter<AnimationFinished>,
+) {
+    for (entity, indices, mut animation, mut sprite) in &mut query {
+        let animation = &mut *animation;
+        animation.timer.tick(time.delta());
+        for _ in 0..animation.timer.times_finished_this_tick() {
+            match indices.next_index(sprite.index, animation.mode, &mut animation.reversed) {
+                Some(index) => sprite.index = index,
+                None => {
+                    animation.timer.pause();
+                    finished.send(AnimationFinished { entity });
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+    use bevy_time::Time;
+    use bevy_utils::{Duration, Instant};
+
+    use super::{
+        AnimationFinished, AnimationIndices, AnimationMode, AnimationTimer,
+        SpriteSheetAnimationPlugin,
+    };
+    use crate::TextureAtlasSprite;
+
+    fn indices(
+        indices: AnimationIndices,
+        mode: AnimationMode,
+        start: usize,
+        steps: usize,
+    ) -> Vec<usize> {
+        let mut reversed = false;
+        let mut index = start;
+        (0..steps)
+            .map_while(|_| {
+                index = indices.next_index(index, mode, &mut reversed)?;
+                Some(index)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn index_progression() {
+        let walk = AnimationIndices { first: 1, last: 4 };
+        assert_eq!(
+            indices(walk, AnimationMode::Loop, 1, 7),
+            vec![2, 3, 4, 1, 2, 3, 4]
+        );
+        assert_eq!(indices(walk, AnimationMode::Once, 1, 7), vec![2, 3, 4]);
+        // turns around at both ends without repeating the last or first frame
+        assert_eq!(
+            indices(walk, AnimationMode::PingPong, 1, 9),
+            vec![2, 3, 4, 3, 2, 1, 2, 3, 4]
+        );
+        // an index outside of the range restarts the animation
+        assert_eq!(indices(walk, AnimationMode::Loop, 6, 2), vec![1, 2]);
+        assert_eq!(indices(walk, AnimationMode::PingPong, 0, 2), vec![1, 2]);
+
+        let two_frames = AnimationIndices { first: 0, last: 1 };
+        assert_eq!(
+            indices(two_frames, AnimationMode::PingPong, 0, 4),
+            vec![1, 0, 1, 0]
+        );
+        let still = AnimationIndices { first: 3, last: 3 };
+        assert_eq!(indices(still, AnimationMode::PingPong, 3, 2), vec![3, 3]);
+        assert_eq!(indices(still, AnimationMode::Loop, 3, 2), vec![3, 3]);
+        assert_eq!(indices(still, AnimationMode::Once, 3, 2), vec![]);
+    }
+
+    #[test]
+    fn once_animation_sends_finished_event() {
+        let mut app = App::new();
+        let start = Instant::now();
+        app.insert_resource(Time::new(start))
+            .add_plugin(SpriteSheetAnimationPlugin);
+        let entity = app
+            .world
+            .spawn((
+                AnimationIndices { first: 0, last: 2 },
+                AnimationTimer::from_seconds(0.1, AnimationMode::Once),
+                TextureAtlasSprite::new(0),
+            ))
+            .id();
+
+        let update = |app: &mut App, millis: u64| {
+            app.world
+                .resource_mut::<Time>()
+                .update_with_instant(start + Duration::from_millis(millis));
+            app.update();
+            let index = app.world.get::<TextureAtlasSprite>(entity).unwrap().index;
+            let finished: Vec<_> = app
+                .world
+                .resource_mut::<Events<AnimationFinished>>()
+                .drain()
+                .collect();
+            (index, finished)
+        };
+
+        assert_eq!(update(&mut app, 0), (0, vec![]));
+        assert_eq!(update(&mut app, 50), (0, vec![]));
+        // a long frame advances the animation by several frames
+        assert_eq!(update(&mut app, 250), (2, vec![]));
+        assert_eq!(
+            update(&mut app, 310),
+            (2, vec![AnimationFinished { entity }])
+        );
+        assert_eq!(update(&mut app, 1000), (2, vec![]));
+        assert!(app
+            .world
+            .get::<AnimationTimer>(entity)
+            .unwrap()
+            .timer
+            .paused());
+    }
+}
@@ -1,3 +1,4 @@
+mod animation;
 mod bundle;
 mod dynamic_texture_atlas_builder;
 mod mesh2d;
@@ -11,13 +12,15 @@ pub mod collide_aabb;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        animation::{AnimationIndices, AnimationMode, AnimationTimer, SpriteSheetAnimationPlugin},
         bundle::{SpriteBundle, SpriteSheetBundle},
-        sprite::Sprite,
+        sprite::{DrawOrder2d, Sprite},
         texture_atlas::{TextureAtlas, TextureAtlasSprite},
-        ColorMaterial, ColorMesh2dBundle, TextureAtlasBuilder,
+        AlphaMode2d, ColorMaterial, ColorMesh2dBundle, TextureAtlasBuilder,
     };
 }
 
+pub use animation::*;
 pub use bundle::*;
 pub use dynamic_texture_atlas_builder::*;
 pub use mesh2d::*;
@@ -32,6 +35,7 @@ use bevy_core_pipeline::core_2d::Transparent2d;
 use bevy_ecs::prelude::*;
 use bevy_reflect::TypeUuid;
 use bevy_render::{
+    extract_component::ExtractComponentPlugin,
     render_phase::AddRenderCommand,
     render_resource::{Shader, SpecializedRenderPipelines},
     ExtractSchedule, RenderApp, RenderSet,
@@ -57,7 +61,9 @@ impl Plugin for SpritePlugin {
             .register_asset_reflect::<TextureAtlas>()
             .register_type::<Sprite>()
             .register_type::<Anchor>()
+            .register_type::<DrawOrder2d>()
             .register_type::<Mesh2dHandle>()
+            .add_plugin(ExtractComponentPlugin::<DrawOrder2d>::extract_visible())
             .add_plugin(Mesh2dRenderPlugin)
             .add_plugin(ColorMaterialPlugin);
 
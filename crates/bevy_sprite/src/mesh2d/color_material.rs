@@ -6,7 +6,7 @@ use bevy_render::{
     color::Color, prelude::Shader, render_asset::RenderAssets, render_resource::*, texture::Image,
 };
 
-use crate::{Material2d, Material2dPlugin, MaterialMesh2dBundle};
+use crate::{AlphaMode2d, Material2d, Material2dPlugin, MaterialMesh2dBundle};
 
 pub const COLOR_MATERIAL_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 3253086872234592509);
@@ -45,6 +45,11 @@ impl Plugin for ColorMaterialPlugin {
 #[uniform(0, ColorMaterialUniform)]
 pub struct ColorMaterial {
     pub color: Color,
+    /// How the alpha channel of the color, multiplied by the texture, is used.
+    ///
+    /// Use [`AlphaMode2d::Mask`] for cutouts drawn without blending, or [`AlphaMode2d::Opaque`] to
+    /// ignore the alpha channel.
+    pub alpha_mode: AlphaMode2d,
     #[texture(1)]
     #[sampler(2)]
     pub texture: Option<Handle<Image>>,
@@ -54,6 +59,7 @@ impl Default for ColorMaterial {
     fn default() -> Self {
         ColorMaterial {
             color: Color::WHITE,
+            alpha_mode: AlphaMode2d::Blend,
             texture: None,
         }
     }
@@ -82,6 +88,8 @@ bitflags::bitflags! {
     #[repr(transparent)]
     pub struct ColorMaterialFlags: u32 {
         const TEXTURE           = (1 << 0);
+        const ALPHA_MODE_OPAQUE = (1 << 1);
+        const ALPHA_MODE_MASK   = (1 << 2);
         const NONE              = 0;
         const UNINITIALIZED     = 0xFFFF;
     }
@@ -92,6 +100,7 @@ bitflags::bitflags! {
 pub struct ColorMaterialUniform {
     pub color: Vec4,
     pub flags: u32,
+    pub alpha_cutoff: f32,
 }
 
 impl AsBindGroupShaderType<ColorMaterialUniform> for ColorMaterial {
@@ -101,9 +110,21 @@ impl AsBindGroupShaderType<ColorMaterialUniform> for ColorMaterial {
             flags |= ColorMaterialFlags::TEXTURE;
         }
 
+        // Only read by the shader in the mask mode
+        let mut alpha_cutoff = 0.5;
+        match self.alpha_mode {
+            AlphaMode2d::Opaque => flags |= ColorMaterialFlags::ALPHA_MODE_OPAQUE,
+            AlphaMode2d::Mask(cutoff) => {
+                alpha_cutoff = cutoff;
+                flags |= ColorMaterialFlags::ALPHA_MODE_MASK;
+            }
+            AlphaMode2d::Blend => {}
+        }
+
         ColorMaterialUniform {
             color: self.color.as_linear_rgba_f32().into(),
             flags: flags.bits(),
+            alpha_cutoff,
         }
     }
 }
@@ -112,7 +133,40 @@ impl Material2d for ColorMaterial {
     fn fragment_shader() -> ShaderRef {
         COLOR_MATERIAL_SHADER_HANDLE.typed().into()
     }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        self.alpha_mode
+    }
 }
 
 /// A component bundle for entities with a [`Mesh2dHandle`](crate::Mesh2dHandle) and a [`ColorMaterial`].
 pub type ColorMesh2dBundle = MaterialMesh2dBundle<ColorMaterial>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_modes_set_the_uniform_flags() {
+        let uniform = |alpha_mode| -> ColorMaterialUniform {
+            ColorMaterial {
+                alpha_mode,
+                ..Default::default()
+            }
+            .as_bind_group_shader_type(&RenderAssets::default())
+        };
+
+        assert_eq!(ColorMaterial::default().alpha_mode(), AlphaMode2d::Blend);
+        let opaque = uniform(AlphaMode2d::Opaque);
+        assert_eq!(opaque.flags, ColorMaterialFlags::ALPHA_MODE_OPAQUE.bits());
+
+        let mask = uniform(AlphaMode2d::Mask(0.25));
+        assert_eq!(mask.flags, ColorMaterialFlags::ALPHA_MODE_MASK.bits());
+        assert_eq!(mask.alpha_cutoff, 0.25);
+
+        assert_eq!(
+            uniform(AlphaMode2d::Blend).flags,
+            ColorMaterialFlags::NONE.bits()
+        );
+    }
+}
@@ -9,8 +9,11 @@ struct ColorMaterial {
     color: vec4<f32>,
     // 'flags' is a bit field indicating various options. u32 is 32 bits so we have up to 32 options.
     flags: u32,
+    alpha_cutoff: f32,
 };
 const COLOR_MATERIAL_FLAGS_TEXTURE_BIT: u32 = 1u;
+const COLOR_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE: u32 = 2u;
+const COLOR_MATERIAL_FLAGS_ALPHA_MODE_MASK: u32 = 4u;
 
 @group(1) @binding(0)
 var<uniform> material: ColorMaterial;
@@ -35,6 +38,17 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
     if ((material.flags & COLOR_MATERIAL_FLAGS_TEXTURE_BIT) != 0u) {
         output_color = output_color * textureSample(texture, texture_sampler, in.uv);
     }
+    if ((material.flags & COLOR_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE) != 0u) {
+        output_color.a = 1.0;
+    }
+#ifdef MAY_DISCARD
+    if ((material.flags & COLOR_MATERIAL_FLAGS_ALPHA_MODE_MASK) != 0u) {
+        if (output_color.a < material.alpha_cutoff) {
+            discard;
+        }
+        output_color.a = 1.0;
+    }
+#endif
 #ifdef TONEMAP_IN_SHADER
     output_color = tone_mapping(output_color);
 #endif
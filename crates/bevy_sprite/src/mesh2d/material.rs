@@ -1,7 +1,7 @@
 use bevy_app::{App, IntoSystemAppConfig, Plugin};
 use bevy_asset::{AddAsset, AssetEvent, AssetServer, Assets, Handle};
 use bevy_core_pipeline::{
-    core_2d::Transparent2d,
+    core_2d::{Opaque2d, Transparent2d},
     tonemapping::{DebandDither, Tonemapping},
 };
 use bevy_derive::{Deref, DerefMut};
@@ -14,7 +14,7 @@ use bevy_ecs::{
     },
 };
 use bevy_log::error;
-use bevy_reflect::TypeUuid;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeUuid};
 use bevy_render::{
     extract_component::ExtractComponentPlugin,
     mesh::{Mesh, MeshVertexBufferLayout},
@@ -31,7 +31,7 @@ use bevy_render::{
     },
     renderer::RenderDevice,
     texture::FallbackImage,
-    view::{ComputedVisibility, ExtractedView, Msaa, Visibility, VisibleEntities},
+    view::{ComputedVisibility, ExtractedView, Visibility, VisibleEntities},
     Extract, ExtractSchedule, RenderApp, RenderSet,
 };
 use bevy_transform::components::{GlobalTransform, Transform};
@@ -40,8 +40,8 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 
 use crate::{
-    DrawMesh2d, Mesh2dHandle, Mesh2dPipeline, Mesh2dPipelineKey, Mesh2dUniform, SetMesh2dBindGroup,
-    SetMesh2dViewBindGroup,
+    DrawMesh2d, DrawOrder2d, Mesh2dHandle, Mesh2dPipeline, Mesh2dPipelineKey, Mesh2dUniform,
+    SetMesh2dBindGroup, SetMesh2dViewBindGroup,
 };
 
 /// Materials are used alongside [`Material2dPlugin`] and [`MaterialMesh2dBundle`]
@@ -124,6 +124,19 @@ pub trait Material2d: AsBindGroup + Send + Sync + Clone + TypeUuid + Sized + 'st
         ShaderRef::Default
     }
 
+    /// Adds a bias to the z position of meshes with this material when sorting them, to draw them
+    /// over or under the meshes at the same z position.
+    #[inline]
+    fn depth_bias(&self) -> f32 {
+        0.0
+    }
+
+    /// Returns this material's [`AlphaMode2d`]. Defaults to [`AlphaMode2d::Blend`].
+    #[inline]
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+
     /// Customizes the default [`RenderPipelineDescriptor`].
     #[allow(unused_variables)]
     #[inline]
@@ -136,6 +149,29 @@ pub trait Material2d: AsBindGroup + Send + Sync + Clone + TypeUuid + Sized + 'st
     }
 }
 
+/// Sets how the alpha channel of the color of a [`Material2d`] is used.
+#[derive(Debug, Default, Reflect, FromReflect, Copy, Clone, PartialEq)]
+#[reflect(Default, Debug)]
+pub enum AlphaMode2d {
+    /// The alpha channel is ignored, and the color is drawn fully opaque.
+    ///
+    /// Opaque meshes are drawn in the [`Opaque2d`] phase, which is drawn before the
+    /// [`Transparent2d`] phase.
+    Opaque,
+    /// The color is drawn fully opaque if its alpha is greater than or equal to the threshold, and
+    /// discarded otherwise.
+    ///
+    /// This renders cutouts, like foliage, without the cost and sorting issues of blending. Like
+    /// opaque meshes, masked meshes are drawn in the [`Opaque2d`] phase.
+    Mask(f32),
+    /// The alpha channel defines the opacity of the color, which is blended with the color
+    /// behind it.
+    #[default]
+    Blend,
+}
+
+impl Eq for AlphaMode2d {}
+
 /// Adds the necessary ECS resources and render logic to enable rendering entities using the given [`Material2d`]
 /// asset type (which includes [`Material2d`] types).
 pub struct Material2dPlugin<M: Material2d>(PhantomData<M>);
@@ -156,6 +192,7 @@ where
 
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
+                .add_render_command::<Opaque2d, DrawMaterial2d<M>>()
                 .add_render_command::<Transparent2d, DrawMaterial2d<M>>()
                 .init_resource::<Material2dPipeline<M>>()
                 .init_resource::<ExtractedMaterials2d<M>>()
@@ -316,21 +353,38 @@ impl<P: PhaseItem, M: Material2d, const I: usize> RenderCommand<P>
     }
 }
 
+/// Returns the bits of the [`Mesh2dPipelineKey`] specializing the pipeline of a mesh drawn with
+/// the given [`AlphaMode2d`].
+fn alpha_mode_pipeline_key(alpha_mode: AlphaMode2d) -> Mesh2dPipelineKey {
+    match alpha_mode {
+        AlphaMode2d::Opaque => Mesh2dPipelineKey::BLEND_OPAQUE,
+        AlphaMode2d::Mask(_) => Mesh2dPipelineKey::BLEND_OPAQUE | Mesh2dPipelineKey::MAY_DISCARD,
+        AlphaMode2d::Blend => Mesh2dPipelineKey::NONE,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 pub fn queue_material2d_meshes<M: Material2d>(
+    opaque_draw_functions: Res<DrawFunctions<Opaque2d>>,
     transparent_draw_functions: Res<DrawFunctions<Transparent2d>>,
     material2d_pipeline: Res<Material2dPipeline<M>>,
     mut pipelines: ResMut<SpecializedMeshPipelines<Material2dPipeline<M>>>,
     pipeline_cache: Res<PipelineCache>,
-    msaa: Res<Msaa>,
     render_meshes: Res<RenderAssets<Mesh>>,
     render_materials: Res<RenderMaterials2d<M>>,
-    material2d_meshes: Query<(&Handle<M>, &Mesh2dHandle, &Mesh2dUniform)>,
+    material2d_meshes: Query<(
+        &Handle<M>,
+        &Mesh2dHandle,
+        &Mesh2dUniform,
+        Option<&DrawOrder2d>,
+    )>,
     mut views: Query<(
         &ExtractedView,
         &VisibleEntities,
         Option<&Tonemapping>,
         Option<&DebandDither>,
+        &mut RenderPhase<Opaque2d>,
         &mut RenderPhase<Transparent2d>,
     )>,
 ) where
@@ -340,10 +394,13 @@ pub fn queue_material2d_meshes<M: Material2d>(
         return;
     }
 
-    for (view, visible_entities, tonemapping, dither, mut transparent_phase) in &mut views {
+    for (view, visible_entities, tonemapping, dither, mut opaque_phase, mut transparent_phase) in
+        &mut views
+    {
+        let draw_opaque_2d = opaque_draw_functions.read().id::<DrawMaterial2d<M>>();
         let draw_transparent_pbr = transparent_draw_functions.read().id::<DrawMaterial2d<M>>();
 
-        let mut view_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
+        let mut view_key = Mesh2dPipelineKey::from_msaa_samples(view.msaa.samples())
             | Mesh2dPipelineKey::from_hdr(view.hdr);
 
         if !view.hdr {
@@ -370,13 +427,14 @@ pub fn queue_material2d_meshes<M: Material2d>(
         }
 
         for visible_entity in &visible_entities.entities {
-            if let Ok((material2d_handle, mesh2d_handle, mesh2d_uniform)) =
+            if let Ok((material2d_handle, mesh2d_handle, mesh2d_uniform, draw_order)) =
                 material2d_meshes.get(*visible_entity)
             {
                 if let Some(material2d) = render_materials.get(material2d_handle) {
                     if let Some(mesh) = render_meshes.get(&mesh2d_handle.0) {
                         let mesh_key = view_key
-                            | Mesh2dPipelineKey::from_primitive_topology(mesh.primitive_topology);
+                            | Mesh2dPipelineKey::from_primitive_topology(mesh.primitive_topology)
+                            | alpha_mode_pipeline_key(material2d.alpha_mode);
 
                         let pipeline_id = pipelines.specialize(
                             &pipeline_cache,
@@ -396,7 +454,22 @@ pub fn queue_material2d_meshes<M: Material2d>(
                             }
                         };
 
-                        let mesh_z = mesh2d_uniform.transform.w_axis.z;
+                        let mesh_z = mesh2d_uniform.transform.w_axis.z + material2d.depth_bias;
+                        let draw_order = draw_order.map(|draw_order| FloatOrd(draw_order.0));
+                        if material2d.alpha_mode != AlphaMode2d::Blend {
+                            opaque_phase.add(Opaque2d {
+                                entity: *visible_entity,
+                                draw_function: draw_opaque_2d,
+                                pipeline: pipeline_id,
+                                // NOTE: Without a depth buffer, opaque items are also drawn
+                                // back-to-front, see `Opaque2d`
+                                sort_key: FloatOrd(mesh_z),
+                                draw_order,
+                                // This material is not batched
+                                batch_range: None,
+                            });
+                            continue;
+                        }
                         transparent_phase.add(Transparent2d {
                             entity: *visible_entity,
                             draw_function: draw_transparent_pbr,
@@ -406,6 +479,7 @@ pub fn queue_material2d_meshes<M: Material2d>(
                             // -z in front of the camera, the largest distance is -far with values increasing toward the
                             // camera. As such we can just use mesh_z as the distance
                             sort_key: FloatOrd(mesh_z),
+                            draw_order,
                             // This material is not batched
                             batch_range: None,
                         });
@@ -421,6 +495,8 @@ pub struct PreparedMaterial2d<T: Material2d> {
     pub bindings: Vec<OwnedBindingResource>,
     pub bind_group: BindGroup,
     pub key: T::Data,
+    pub depth_bias: f32,
+    pub alpha_mode: AlphaMode2d,
 }
 
 #[derive(Resource)]
@@ -563,6 +639,8 @@ fn prepare_material2d<M: Material2d>(
         bindings: prepared.bindings,
         bind_group: prepared.bind_group,
         key: prepared.data,
+        depth_bias: material.depth_bias(),
+        alpha_mode: material.alpha_mode(),
     })
 }
 
@@ -591,3 +669,25 @@ impl<M: Material2d> Default for MaterialMesh2dBundle<M> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_modes_specialize_the_blending() {
+        let opaque = alpha_mode_pipeline_key(AlphaMode2d::Opaque);
+        assert!(opaque.contains(Mesh2dPipelineKey::BLEND_OPAQUE));
+        assert!(!opaque.contains(Mesh2dPipelineKey::MAY_DISCARD));
+
+        let mask = alpha_mode_pipeline_key(AlphaMode2d::Mask(0.5));
+        assert!(mask.contains(Mesh2dPipelineKey::BLEND_OPAQUE | Mesh2dPipelineKey::MAY_DISCARD));
+        // The cutoff is read from the material, so it doesn't create new pipelines
+        assert_eq!(mask, alpha_mode_pipeline_key(AlphaMode2d::Mask(0.1)));
+
+        assert_eq!(
+            alpha_mode_pipeline_key(AlphaMode2d::Blend),
+            Mesh2dPipelineKey::NONE
+        );
+    }
+}
@@ -289,6 +289,8 @@ bitflags::bitflags! {
         const HDR                               = (1 << 0);
         const TONEMAP_IN_SHADER                 = (1 << 1);
         const DEBAND_DITHER                     = (1 << 2);
+        const BLEND_OPAQUE                      = (1 << 3);
+        const MAY_DISCARD                       = (1 << 4); // Guards shader codepaths that may discard fragments
         const MSAA_RESERVED_BITS                = Self::MSAA_MASK_BITS << Self::MSAA_SHIFT_BITS;
         const PRIMITIVE_TOPOLOGY_RESERVED_BITS  = Self::PRIMITIVE_TOPOLOGY_MASK_BITS << Self::PRIMITIVE_TOPOLOGY_SHIFT_BITS;
         const TONEMAP_METHOD_RESERVED_BITS      = Self::TONEMAP_METHOD_MASK_BITS << Self::TONEMAP_METHOD_SHIFT_BITS;
@@ -417,8 +419,21 @@ impl SpecializedMeshPipeline for Mesh2dPipeline {
             }
         }
 
+        if key.contains(Mesh2dPipelineKey::MAY_DISCARD) {
+            shader_defs.push("MAY_DISCARD".into());
+        }
+
         let vertex_buffer_layout = layout.get_layout(&vertex_attributes)?;
 
+        let (blend, label) = if key.contains(Mesh2dPipelineKey::BLEND_OPAQUE) {
+            (None, "opaque_mesh2d_pipeline")
+        } else {
+            (
+                Some(BlendState::ALPHA_BLENDING),
+                "transparent_mesh2d_pipeline",
+            )
+        };
+
         let format = match key.contains(Mesh2dPipelineKey::HDR) {
             true => ViewTarget::TEXTURE_FORMAT_HDR,
             false => TextureFormat::bevy_default(),
@@ -437,7 +452,7 @@ impl SpecializedMeshPipeline for Mesh2dPipeline {
                 entry_point: "fragment".into(),
                 targets: vec![Some(ColorTargetState {
                     format,
-                    blend: Some(BlendState::ALPHA_BLENDING),
+                    blend,
                     write_mask: ColorWrites::ALL,
                 })],
             }),
@@ -458,7 +473,7 @@ impl SpecializedMeshPipeline for Mesh2dPipeline {
                 mask: !0,
                 alpha_to_coverage_enabled: false,
             },
-            label: Some("transparent_mesh2d_pipeline".into()),
+            label: Some(label.into()),
         })
     }
 }
@@ -0,0 +1,268 @@
+use crate::{Material2d, Material2dKey, Material2dPlugin, Mesh2dHandle};
+use bevy_app::{App, Plugin};
+use bevy_asset::{load_internal_asset, Assets, Handle, HandleUntyped};
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect, TypeUuid};
+use bevy_render::{
+    color::Color,
+    extract_resource::ExtractResource,
+    mesh::MeshVertexBufferLayout,
+    render_resource::{
+        AsBindGroup, PolygonMode, RenderPipelineDescriptor, Shader, ShaderRef,
+        SpecializedMeshPipelineError,
+    },
+};
+
+pub const WIREFRAME_2D_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 6920362697190520314);
+
+/// Draws the wireframes of 2d meshes with a [`Wireframe2d`] component, or of all 2d meshes with
+/// [`Wireframe2dConfig::global`].
+///
+/// Wireframes are drawn with a [`Wireframe2dMaterial`], added alongside the material of each mesh.
+/// This requires the [`POLYGON_MODE_LINE`](bevy_render::render_resource::WgpuFeatures::POLYGON_MODE_LINE)
+/// device feature.
+#[derive(Debug, Default)]
+pub struct Wireframe2dPlugin;
+
+impl Plugin for Wireframe2dPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            WIREFRAME_2D_SHADER_HANDLE,
+            "wireframe2d.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.register_type::<Wireframe2d>()
+            .register_type::<NoWireframe2d>()
+            .register_type::<Wireframe2dColor>()
+            .register_type::<Wireframe2dConfig>()
+            .init_resource::<Wireframe2dConfig>()
+            .add_plugin(Material2dPlugin::<Wireframe2dMaterial>::default())
+            .add_systems((update_global_wireframe_material, apply_wireframe_material).chain());
+
+        let global_material =
+            app.world
+                .resource_mut::<Assets<Wireframe2dMaterial>>()
+                .add(Wireframe2dMaterial {
+                    color: Wireframe2dConfig::default().default_color,
+                });
+        app.insert_resource(GlobalWireframe2dMaterial(global_material));
+    }
+}
+
+/// Draws the wireframe of a 2d mesh entity if the [`Wireframe2dPlugin`] is enabled, regardless of
+/// [`Wireframe2dConfig::global`] and [`NoWireframe2d`].
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component, Default)]
+pub struct Wireframe2d;
+
+/// Excludes a 2d mesh entity from the wireframes drawn with [`Wireframe2dConfig::global`].
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component, Default)]
+pub struct NoWireframe2d;
+
+/// Overrides the [`Wireframe2dConfig::default_color`] of the wireframe of a 2d mesh entity.
+#[derive(Component, Debug, Clone, Default, Reflect, FromReflect)]
+#[reflect(Component, Default)]
+pub struct Wireframe2dColor(pub Color);
+
+#[derive(Resource, Debug, Clone, ExtractResource, Reflect)]
+#[reflect(Resource, Default)]
+pub struct Wireframe2dConfig {
+    /// Whether to show wireframes for all 2d meshes. If `false`, only meshes with a [`Wireframe2d`]
+    /// component are drawn.
+    pub global: bool,
+    /// The color of the wireframes of meshes without a [`Wireframe2dColor`].
+    pub default_color: Color,
+}
+
+impl Default for Wireframe2dConfig {
+    fn default() -> Self {
+        Self {
+            global: false,
+            default_color: Color::WHITE,
+        }
+    }
+}
+
+/// The material drawing the wireframe of a 2d mesh.
+///
+/// It is managed by the [`Wireframe2dPlugin`] from the [`Wireframe2d`], [`NoWireframe2d`] and
+/// [`Wireframe2dColor`] components, and shouldn't be added manually.
+#[derive(AsBindGroup, TypeUuid, Debug, Clone, PartialEq)]
+#[uuid = "3d8a2694-5a8e-4a4d-a5ea-89a6eec93b4c"]
+pub struct Wireframe2dMaterial {
+    #[uniform(0)]
+    pub color: Color,
+}
+
+impl Material2d for Wireframe2dMaterial {
+    fn fragment_shader() -> ShaderRef {
+        WIREFRAME_2D_SHADER_HANDLE.typed().into()
+    }
+
+    /// Draws the wireframe over its mesh.
+    fn depth_bias(&self) -> f32 {
+        1.0
+    }
+
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        _key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        descriptor.primitive.polygon_mode = PolygonMode::Line;
+        Ok(())
+    }
+}
+
+/// The [`Wireframe2dMaterial`] shared by the wireframes without a [`Wireframe2dColor`].
+#[derive(Resource)]
+struct GlobalWireframe2dMaterial(Handle<Wireframe2dMaterial>);
+
+/// Keeps the color of the [`GlobalWireframe2dMaterial`] in sync with the [`Wireframe2dConfig`].
+fn update_global_wireframe_material(
+    config: Res<Wireframe2dConfig>,
+    global_material: Res<GlobalWireframe2dMaterial>,
+    mut materials: ResMut<Assets<Wireframe2dMaterial>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    if let Some(material) = materials.get_mut(&global_material.0) {
+        material.color = config.default_color;
+    }
+}
+
+/// Returns `true` if the wireframe of a 2d mesh entity should be drawn.
+fn draws_wireframe(config: &Wireframe2dConfig, wireframe: bool, no_wireframe: bool) -> bool {
+    wireframe || (config.global && !no_wireframe)
+}
+
+/// Adds a [`Wireframe2dMaterial`] to the 2d mesh entities whose wireframe is drawn, and removes it
+/// from the others.
+#[allow(clippy::type_complexity)]
+fn apply_wireframe_material(
+    mut commands: Commands,
+    config: Res<Wireframe2dConfig>,
+    global_material: Res<GlobalWireframe2dMaterial>,
+    mut materials: ResMut<Assets<Wireframe2dMaterial>>,
+    meshes: Query<
+        (
+            Entity,
+            Option<&Wireframe2d>,
+            Option<&NoWireframe2d>,
+            Option<&Wireframe2dColor>,
+            Option<&Handle<Wireframe2dMaterial>>,
+        ),
+        With<Mesh2dHandle>,
+    >,
+) {
+    for (entity, wireframe, no_wireframe, color, material) in &meshes {
+        if !draws_wireframe(&config, wireframe.is_some(), no_wireframe.is_some()) {
+            if material.is_some() {
+                commands
+                    .entity(entity)
+                    .remove::<Handle<Wireframe2dMaterial>>();
+            }
+            continue;
+        }
+
+        // Colored wireframes own their material, and share it with nothing else
+        let own_material = material.filter(|material| **material != global_material.0);
+        match (color, own_material) {
+            (None, _) if material == Some(&global_material.0) => {}
+            (None, _) => {
+                commands
+                    .entity(entity)
+                    .insert(global_material.0.clone_weak());
+            }
+            (Some(Wireframe2dColor(color)), Some(material)) => {
+                // Only touches the material when its color changes, so it isn't prepared again
+                // every frame
+                if materials.get(material).map(|material| material.color) != Some(*color) {
+                    if let Some(material) = materials.get_mut(material) {
+                        material.color = *color;
+                    }
+                }
+            }
+            (Some(Wireframe2dColor(color)), None) => {
+                let material = materials.add(Wireframe2dMaterial { color: *color });
+                commands.entity(entity).insert(material);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::{AddAsset, AssetPlugin};
+    use bevy_render::mesh::Mesh;
+
+    #[test]
+    fn wireframe_materials_follow_the_markers() {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default())
+            .add_asset::<Shader>()
+            .add_asset::<Mesh>()
+            .add_plugin(Wireframe2dPlugin);
+
+        let mesh = Mesh2dHandle(Handle::<Mesh>::default());
+        let plain = app.world.spawn(mesh.clone()).id();
+        let wireframe = app.world.spawn((mesh.clone(), Wireframe2d)).id();
+        let excluded = app.world.spawn((mesh.clone(), NoWireframe2d)).id();
+        let both = app
+            .world
+            .spawn((mesh.clone(), Wireframe2d, NoWireframe2d))
+            .id();
+        let colored = app
+            .world
+            .spawn((mesh, Wireframe2d, Wireframe2dColor(Color::RED)))
+            .id();
+        let not_a_mesh = app.world.spawn(Wireframe2d).id();
+
+        let wireframe_color = |app: &App, entity| {
+            let handle = app.world.get::<Handle<Wireframe2dMaterial>>(entity)?;
+            let materials = app.world.resource::<Assets<Wireframe2dMaterial>>();
+            Some(materials.get(handle).unwrap().color)
+        };
+        let wireframe_colors = |app: &App| {
+            [plain, wireframe, excluded, both, colored, not_a_mesh]
+                .map(|entity| wireframe_color(app, entity))
+        };
+
+        app.update();
+        let white = Some(Color::WHITE);
+        let red = Some(Color::RED);
+        assert_eq!(
+            wireframe_colors(&app),
+            [None, white, None, white, red, None]
+        );
+
+        app.world.resource_mut::<Wireframe2dConfig>().global = true;
+        app.update();
+        assert_eq!(
+            wireframe_colors(&app),
+            [white, white, None, white, red, None]
+        );
+
+        // the default color applies to every wireframe without a color of its own
+        app.world.resource_mut::<Wireframe2dConfig>().default_color = Color::BLUE;
+        app.world.get_mut::<Wireframe2dColor>(colored).unwrap().0 = Color::GREEN;
+        app.world.entity_mut(both).remove::<Wireframe2d>();
+        app.update();
+        let blue = Some(Color::BLUE);
+        assert_eq!(
+            wireframe_colors(&app),
+            [blue, blue, None, None, Some(Color::GREEN), None]
+        );
+
+        app.world.resource_mut::<Wireframe2dConfig>().global = false;
+        app.world.entity_mut(colored).remove::<Wireframe2dColor>();
+        app.update();
+        assert_eq!(wireframe_colors(&app), [None, blue, None, None, blue, None]);
+    }
+}
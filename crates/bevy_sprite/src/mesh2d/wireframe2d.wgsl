@@ -0,0 +1,11 @@
+struct Wireframe2dMaterial {
+    color: vec4<f32>,
+};
+
+@group(1) @binding(0)
+var<uniform> material: Wireframe2dMaterial;
+
+@fragment
+fn fragment() -> @location(0) vec4<f32> {
+    return material.color;
+}
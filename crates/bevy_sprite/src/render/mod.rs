@@ -1,8 +1,6 @@
-use std::cmp::Ordering;
-
 use crate::{
     texture_atlas::{TextureAtlas, TextureAtlasSprite},
-    Sprite, SPRITE_SHADER_HANDLE,
+    DrawOrder2d, Sprite, SPRITE_SHADER_HANDLE,
 };
 use bevy_asset::{AssetEvent, Assets, Handle, HandleId};
 use bevy_core_pipeline::{
@@ -28,7 +26,7 @@ use bevy_render::{
         BevyDefault, DefaultImageSampler, GpuImage, Image, ImageSampler, TextureFormatPixelInfo,
     },
     view::{
-        ComputedVisibility, ExtractedView, Msaa, ViewTarget, ViewUniform, ViewUniformOffset,
+        ComputedVisibility, ExtractedView, ViewTarget, ViewUniform, ViewUniformOffset,
         ViewUniforms, VisibleEntities,
     },
     Extract,
@@ -36,6 +34,7 @@ use bevy_render::{
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::FloatOrd;
 use bevy_utils::HashMap;
+use bevy_utils::Parallel;
 use bytemuck::{Pod, Zeroable};
 use fixedbitset::FixedBitSet;
 
@@ -315,6 +314,16 @@ pub struct ExtractedSprite {
     pub flip_x: bool,
     pub flip_y: bool,
     pub anchor: Vec2,
+    /// The [`DrawOrder2d`] of the sprite, if any
+    pub draw_order: Option<f32>,
+}
+
+impl ExtractedSprite {
+    /// The keys this sprite is sorted by in the [`Transparent2d`] phase.
+    fn sort_keys(&self) -> (FloatOrd, FloatOrd) {
+        let z = self.transform.translation().z;
+        (FloatOrd(self.draw_order.unwrap_or(z)), FloatOrd(z))
+    }
 }
 
 #[derive(Resource, Default)]
@@ -350,8 +359,26 @@ pub fn extract_sprite_events(
     }
 }
 
+/// Sorts sprites by draw order and z for correct transparency and then by handle to improve batching.
+///
+/// The sprites are extracted in parallel, so they are finally sorted by entity for their order
+/// not to change from one frame to the next.
+///
+/// NOTE: This can be done independent of views by reasonably assuming that all 2D views look along
+/// the negative-z axis in world space
+fn sort_extracted_sprites(extracted_sprites: &mut [ExtractedSprite]) {
+    extracted_sprites.sort_unstable_by(|a, b| {
+        a.sort_keys()
+            .cmp(&b.sort_keys())
+            .then_with(|| a.image_handle_id.cmp(&b.image_handle_id))
+            .then_with(|| a.entity.cmp(&b.entity))
+    });
+}
+
+#[allow(clippy::type_complexity)]
 pub fn extract_sprites(
     mut extracted_sprites: ResMut<ExtractedSprites>,
+    mut thread_sprites: Local<Parallel<Vec<ExtractedSprite>>>,
     texture_atlases: Extract<Res<Assets<TextureAtlas>>>,
     sprite_query: Extract<
         Query<(
@@ -360,6 +387,7 @@ pub fn extract_sprites(
             &Sprite,
             &GlobalTransform,
             &Handle<Image>,
+            Option<&DrawOrder2d>,
         )>,
     >,
     atlas_query: Extract<
@@ -369,35 +397,44 @@ pub fn extract_sprites(
             &TextureAtlasSprite,
             &GlobalTransform,
             &Handle<TextureAtlas>,
+            Option<&DrawOrder2d>,
         )>,
     >,
 ) {
     extracted_sprites.sprites.clear();
-    for (entity, visibility, sprite, transform, handle) in sprite_query.iter() {
-        if !visibility.is_visible() {
-            continue;
-        }
-        // PERF: we don't check in this function that the `Image` asset is ready, since it should be in most cases and hashing the handle is expensive
-        extracted_sprites.sprites.push(ExtractedSprite {
-            entity,
-            color: sprite.color,
-            transform: *transform,
-            rect: sprite.rect,
-            // Pass the custom size
-            custom_size: sprite.custom_size,
-            flip_x: sprite.flip_x,
-            flip_y: sprite.flip_y,
-            image_handle_id: handle.id(),
-            anchor: sprite.anchor.as_vec(),
-        });
-    }
-    for (entity, visibility, atlas_sprite, transform, texture_atlas_handle) in atlas_query.iter() {
-        if !visibility.is_visible() {
-            continue;
-        }
-        if let Some(texture_atlas) = texture_atlases.get(texture_atlas_handle) {
+    // the sprites are sorted when queued, so the order they're extracted in doesn't matter
+    sprite_query.par_iter().for_each(
+        |(entity, visibility, sprite, transform, handle, draw_order)| {
+            if !visibility.is_visible() {
+                return;
+            }
+            // PERF: we don't check in this function that the `Image` asset is ready, since it should be in most cases and hashing the handle is expensive
+            let sprite = ExtractedSprite {
+                entity,
+                color: sprite.color,
+                transform: *transform,
+                rect: sprite.rect,
+                // Pass the custom size
+                custom_size: sprite.custom_size,
+                flip_x: sprite.flip_x,
+                flip_y: sprite.flip_y,
+                image_handle_id: handle.id(),
+                anchor: sprite.anchor.as_vec(),
+                draw_order: draw_order.map(|draw_order| draw_order.0),
+            };
+            thread_sprites.scope(|sprites| sprites.push(sprite));
+        },
+    );
+    atlas_query.par_iter().for_each(
+        |(entity, visibility, atlas_sprite, transform, texture_atlas_handle, draw_order)| {
+            if !visibility.is_visible() {
+                return;
+            }
+            let Some(texture_atlas) = texture_atlases.get(texture_atlas_handle) else {
+                return;
+            };
             let rect = Some(texture_atlas.textures[atlas_sprite.index]);
-            extracted_sprites.sprites.push(ExtractedSprite {
+            let sprite = ExtractedSprite {
                 entity,
                 color: atlas_sprite.color,
                 transform: *transform,
@@ -409,9 +446,12 @@ pub fn extract_sprites(
                 flip_y: atlas_sprite.flip_y,
                 image_handle_id: texture_atlas.texture.id(),
                 anchor: atlas_sprite.anchor.as_vec(),
-            });
-        }
-    }
+                draw_order: draw_order.map(|draw_order| draw_order.0),
+            };
+            thread_sprites.scope(|sprites| sprites.push(sprite));
+        },
+    );
+    thread_sprites.drain_into(&mut extracted_sprites.sprites);
 }
 
 #[repr(C)]
@@ -487,7 +527,6 @@ pub fn queue_sprites(
     pipeline_cache: Res<PipelineCache>,
     mut image_bind_groups: ResMut<ImageBindGroups>,
     gpu_images: Res<RenderAssets<Image>>,
-    msaa: Res<Msaa>,
     mut extracted_sprites: ResMut<ExtractedSprites>,
     mut views: Query<(
         &mut RenderPhase<Transparent2d>,
@@ -508,8 +547,6 @@ pub fn queue_sprites(
         };
     }
 
-    let msaa_key = SpritePipelineKey::from_msaa_samples(msaa.samples());
-
     if let Some(view_binding) = view_uniforms.uniforms.binding() {
         let sprite_meta = &mut sprite_meta;
 
@@ -535,23 +572,12 @@ pub fn queue_sprites(
         // FIXME: VisibleEntities is ignored
 
         let extracted_sprites = &mut extracted_sprites.sprites;
-        // Sort sprites by z for correct transparency and then by handle to improve batching
-        // NOTE: This can be done independent of views by reasonably assuming that all 2D views look along the negative-z axis in world space
-        extracted_sprites.sort_unstable_by(|a, b| {
-            match a
-                .transform
-                .translation()
-                .z
-                .partial_cmp(&b.transform.translation().z)
-            {
-                Some(Ordering::Equal) | None => a.image_handle_id.cmp(&b.image_handle_id),
-                Some(other) => other,
-            }
-        });
+        sort_extracted_sprites(extracted_sprites);
         let image_bind_groups = &mut *image_bind_groups;
 
         for (mut transparent_phase, visible_entities, view, tonemapping, dither) in &mut views {
-            let mut view_key = SpritePipelineKey::from_hdr(view.hdr) | msaa_key;
+            let mut view_key = SpritePipelineKey::from_hdr(view.hdr)
+                | SpritePipelineKey::from_msaa_samples(view.msaa.samples());
 
             if !view.hdr {
                 if let Some(tonemapping) = tonemapping {
@@ -688,8 +714,9 @@ pub fn queue_sprites(
                         .into()
                 });
 
-                // These items will be sorted by depth with other phase items
+                // These items will be sorted by draw order and depth with other phase items
                 let sort_key = FloatOrd(extracted_sprite.transform.translation().z);
+                let draw_order = extracted_sprite.draw_order.map(FloatOrd);
 
                 // Store the vertex data and add the item to the render phase
                 if current_batch.colored {
@@ -710,6 +737,7 @@ pub fn queue_sprites(
                         pipeline: colored_pipeline,
                         entity: current_batch_entity,
                         sort_key,
+                        draw_order,
                         batch_range: Some(item_start..item_end),
                     });
                 } else {
@@ -728,6 +756,7 @@ pub fn queue_sprites(
                         pipeline,
                         entity: current_batch_entity,
                         sort_key,
+                        draw_order,
                         batch_range: Some(item_start..item_end),
                     });
                 }
@@ -820,3 +849,49 @@ impl<P: BatchedPhaseItem> RenderCommand<P> for DrawSpriteBatch {
         RenderCommandResult::Success
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec3;
+
+    fn extracted_sprite(index: u32, z: f32, draw_order: Option<f32>) -> ExtractedSprite {
+        ExtractedSprite {
+            entity: Entity::from_raw(index),
+            transform: GlobalTransform::from_translation(Vec3::new(0.0, 0.0, z)),
+            color: Color::WHITE,
+            rect: None,
+            custom_size: None,
+            image_handle_id: Handle::<Image>::default().id(),
+            flip_x: false,
+            flip_y: false,
+            anchor: Vec2::ZERO,
+            draw_order,
+        }
+    }
+
+    #[test]
+    fn overlapping_sprites_are_sorted_by_draw_order_then_z() {
+        let mut sprites = vec![
+            extracted_sprite(0, 100.0, Some(0.0)),
+            extracted_sprite(1, -100.0, Some(1.0)),
+            extracted_sprite(2, 0.5, None),
+            extracted_sprite(3, -1.0, Some(1.0)),
+            extracted_sprite(4, 0.0, None),
+        ];
+        sort_extracted_sprites(&mut sprites);
+        let entities: Vec<_> = sprites.iter().map(|sprite| sprite.entity.index()).collect();
+        assert_eq!(entities, [4, 0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn sprites_with_equal_keys_are_sorted_by_entity() {
+        let mut sprites: Vec<_> = [3, 0, 4, 1, 2]
+            .into_iter()
+            .map(|index| extracted_sprite(index, 0.0, None))
+            .collect();
+        sort_extracted_sprites(&mut sprites);
+        let entities: Vec<_> = sprites.iter().map(|sprite| sprite.entity.index()).collect();
+        assert_eq!(entities, [0, 1, 2, 3, 4]);
+    }
+}
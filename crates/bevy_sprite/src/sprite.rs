@@ -1,7 +1,7 @@
-use bevy_ecs::component::Component;
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
 use bevy_math::{Rect, Vec2};
-use bevy_reflect::Reflect;
-use bevy_render::color::Color;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{color::Color, extract_component::ExtractComponent};
 
 #[derive(Component, Debug, Default, Clone, Reflect)]
 #[repr(C)]
@@ -58,3 +58,15 @@ impl Anchor {
         }
     }
 }
+
+/// Orders the drawing of a sprite, 2d mesh or 2d text independently of its z position.
+///
+/// Entities are drawn in increasing draw order, so that the ones with the greatest draw order are
+/// drawn over the others, and entities with the same draw order are drawn in increasing z
+/// position. Entities without a `DrawOrder2d` use their z position as their draw order.
+///
+/// Unlike a z position, the draw order is not part of the transform, so it's not propagated to the
+/// children of an entity.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect, ExtractComponent)]
+#[reflect(Component, Default)]
+pub struct DrawOrder2d(pub f32);
@@ -1,7 +1,7 @@
 use crate::Anchor;
 use bevy_asset::Handle;
 use bevy_ecs::component::Component;
-use bevy_math::{Rect, Vec2};
+use bevy_math::{Rect, UVec2, Vec2};
 use bevy_reflect::{FromReflect, Reflect, TypeUuid};
 use bevy_render::{color::Color, texture::Image};
 use bevy_utils::HashMap;
@@ -23,6 +23,63 @@ pub struct TextureAtlas {
     pub(crate) texture_handles: Option<HashMap<Handle<Image>, usize>>,
 }
 
+/// The layout of the textures packed in an atlas by a [`TextureAtlasBuilder`](crate::TextureAtlasBuilder),
+/// without the atlas texture.
+///
+/// Turned into a [`TextureAtlas`] with [`TextureAtlas::from_layout`].
+#[derive(Debug, Clone, Default)]
+pub struct TextureAtlasLayout {
+    /// The size of the atlas texture in pixels
+    pub size: Vec2,
+    /// The specific areas of the atlas where each texture can be found, in the order they were added
+    pub textures: Vec<Rect>,
+    /// Mapping from texture handle to index
+    pub(crate) texture_handles: HashMap<Handle<Image>, usize>,
+}
+
+impl TextureAtlasLayout {
+    /// Generate a [`TextureAtlasLayout`] by splitting a sprite sheet into a grid of `columns` by
+    /// `rows` cells of `tile_size` pixels each.
+    ///
+    /// Cells are separated by a gutter of `padding` pixels, and the first cell starts `offset`
+    /// pixels from the top left corner of the sheet. The textures are indexed left to right,
+    /// top to bottom, and the layout's size is the extent of the grid including the offset.
+    pub fn from_grid(
+        tile_size: UVec2,
+        columns: u32,
+        rows: u32,
+        padding: Option<UVec2>,
+        offset: Option<UVec2>,
+    ) -> Self {
+        let padding = padding.unwrap_or_default();
+        let offset = offset.unwrap_or_default();
+
+        let mut textures = Vec::with_capacity((columns * rows) as usize);
+        for y in 0..rows {
+            for x in 0..columns {
+                let min = offset + (tile_size + padding) * UVec2::new(x, y);
+                textures.push(Rect::from_corners(
+                    min.as_vec2(),
+                    (min + tile_size).as_vec2(),
+                ));
+            }
+        }
+
+        let grid_size = UVec2::new(columns, rows);
+        let gutters = UVec2::new(columns.saturating_sub(1), rows.saturating_sub(1)) * padding;
+        Self {
+            size: (offset + tile_size * grid_size + gutters).as_vec2(),
+            textures,
+            texture_handles: HashMap::default(),
+        }
+    }
+
+    /// Returns the index of the texture corresponding to the given image handle in the layout
+    pub fn get_texture_index(&self, texture: &Handle<Image>) -> Option<usize> {
+        self.texture_handles.get(texture).cloned()
+    }
+}
+
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct TextureAtlasSprite {
     /// The tint color used to draw the sprite, defaulting to [`Color::WHITE`]
@@ -76,6 +133,17 @@ impl TextureAtlas {
         }
     }
 
+    /// Create a [`TextureAtlas`] from the `layout` of the textures packed in `texture`, as
+    /// returned by [`TextureAtlasBuilder::finish`](crate::TextureAtlasBuilder::finish).
+    pub fn from_layout(texture: Handle<Image>, layout: TextureAtlasLayout) -> Self {
+        Self {
+            texture,
+            size: layout.size,
+            textures: layout.textures,
+            texture_handles: Some(layout.texture_handles),
+        }
+    }
+
     /// Generate a [`TextureAtlas`] by splitting a texture into a grid where each
     /// `tile_size` by `tile_size` grid-cell is one of the textures in the
     /// atlas. Grid cells are separated by some `padding`, and the grid starts
@@ -153,3 +221,49 @@ impl TextureAtlas {
             .and_then(|texture_handles| texture_handles.get(texture).cloned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Rect, UVec2, Vec2};
+
+    use super::TextureAtlasLayout;
+
+    #[test]
+    fn grid_without_padding() {
+        let layout = TextureAtlasLayout::from_grid(UVec2::new(16, 8), 3, 2, None, None);
+        assert_eq!(layout.size, Vec2::new(48.0, 16.0));
+        assert_eq!(layout.textures.len(), 6);
+        assert_eq!(layout.textures[0], Rect::new(0.0, 0.0, 16.0, 8.0));
+        assert_eq!(layout.textures[2], Rect::new(32.0, 0.0, 48.0, 8.0));
+        assert_eq!(layout.textures[3], Rect::new(0.0, 8.0, 16.0, 16.0));
+    }
+
+    #[test]
+    fn grid_with_padding_and_offset() {
+        let layout = TextureAtlasLayout::from_grid(
+            UVec2::new(16, 8),
+            3,
+            2,
+            Some(UVec2::new(2, 4)),
+            Some(UVec2::new(1, 3)),
+        );
+        // no gutter after the last column and row
+        assert_eq!(layout.size, Vec2::new(53.0, 23.0));
+        let textures = [
+            Rect::new(1.0, 3.0, 17.0, 11.0),
+            Rect::new(19.0, 3.0, 35.0, 11.0),
+            Rect::new(37.0, 3.0, 53.0, 11.0),
+            Rect::new(1.0, 15.0, 17.0, 23.0),
+            Rect::new(19.0, 15.0, 35.0, 23.0),
+            Rect::new(37.0, 15.0, 53.0, 23.0),
+        ];
+        assert_eq!(layout.textures, textures);
+    }
+
+    #[test]
+    fn empty_grid() {
+        let layout = TextureAtlasLayout::from_grid(UVec2::new(16, 8), 0, 0, Some(UVec2::ONE), None);
+        assert!(layout.textures.is_empty());
+        assert_eq!(layout.size, Vec2::ZERO);
+    }
+}
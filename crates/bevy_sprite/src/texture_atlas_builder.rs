@@ -1,18 +1,18 @@
 use bevy_asset::{Assets, Handle};
 use bevy_log::{debug, error, warn};
-use bevy_math::{Rect, Vec2};
+use bevy_math::{Rect, UVec2, Vec2};
 use bevy_render::{
     render_resource::{Extent3d, TextureDimension, TextureFormat},
     texture::{Image, TextureFormatPixelInfo},
 };
 use bevy_utils::HashMap;
 use rectangle_pack::{
-    contains_smallest_box, pack_rects, volume_heuristic, GroupedRectsToPlace, PackedLocation,
-    RectToInsert, TargetBin,
+    contains_smallest_box, pack_rects, volume_heuristic, GroupedRectsToPlace, RectToInsert,
+    TargetBin,
 };
 use thiserror::Error;
 
-use crate::texture_atlas::TextureAtlas;
+use crate::texture_atlas::TextureAtlasLayout;
 
 #[derive(Debug, Error)]
 pub enum TextureAtlasBuilderError {
@@ -27,9 +27,10 @@ pub enum TextureAtlasBuilderError {
 /// A builder which is used to create a texture atlas from many individual
 /// sprites.
 pub struct TextureAtlasBuilder {
-    /// The grouped rects which must be placed with a key value pair of a
-    /// texture handle to an index.
-    rects_to_place: GroupedRectsToPlace<Handle<Image>>,
+    /// The handles and sizes of the textures to place, in the order of their index.
+    textures_to_place: Vec<(Handle<Image>, UVec2)>,
+    /// Mapping from the added texture handles to their index, to add each texture once.
+    texture_indices: HashMap<Handle<Image>, usize>,
     /// The initial atlas size in pixels.
     initial_size: Vec2,
     /// The absolute maximum size of the texture atlas in pixels.
@@ -38,16 +39,20 @@ pub struct TextureAtlasBuilder {
     format: TextureFormat,
     /// Enable automatic format conversion for textures if they are not in the atlas format.
     auto_format_conversion: bool,
+    /// The empty space left to the right and below each texture, in pixels.
+    padding: UVec2,
 }
 
 impl Default for TextureAtlasBuilder {
     fn default() -> Self {
         Self {
-            rects_to_place: GroupedRectsToPlace::new(),
+            textures_to_place: Vec::new(),
+            texture_indices: HashMap::default(),
             initial_size: Vec2::new(256., 256.),
             max_size: Vec2::new(2048., 2048.),
             format: TextureFormat::Rgba8UnormSrgb,
             auto_format_conversion: true,
+            padding: UVec2::ZERO,
         }
     }
 }
@@ -79,28 +84,37 @@ impl TextureAtlasBuilder {
         self
     }
 
-    /// Adds a texture to be copied to the texture atlas.
-    pub fn add_texture(&mut self, texture_handle: Handle<Image>, texture: &Image) {
-        self.rects_to_place.push_rect(
-            texture_handle,
-            None,
-            RectToInsert::new(
-                texture.texture_descriptor.size.width,
-                texture.texture_descriptor.size.height,
-                1,
-            ),
-        );
+    /// Sets the empty space left to the right and below each texture in the atlas, in pixels.
+    ///
+    /// The padding is not part of the texture rects of the atlas, so sampling a texture with
+    /// linear filtering doesn't bleed into its neighbors. Defaults to no padding.
+    pub fn padding(mut self, padding: UVec2) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Adds a texture to be copied to the texture atlas, and returns its index in the atlas.
+    ///
+    /// A texture handle that was already added is not added again, and returns the same index.
+    pub fn add_texture(&mut self, texture_handle: Handle<Image>, texture: &Image) -> usize {
+        if let Some(&index) = self.texture_indices.get(&texture_handle) {
+            return index;
+        }
+
+        let index = self.textures_to_place.len();
+        let size = texture.texture_descriptor.size;
+        self.texture_indices
+            .insert(texture_handle.clone_weak(), index);
+        self.textures_to_place
+            .push((texture_handle, UVec2::new(size.width, size.height)));
+        index
     }
 
-    fn copy_texture_to_atlas(
-        atlas_texture: &mut Image,
-        texture: &Image,
-        packed_location: &PackedLocation,
-    ) {
-        let rect_width = packed_location.width() as usize;
-        let rect_height = packed_location.height() as usize;
-        let rect_x = packed_location.x() as usize;
-        let rect_y = packed_location.y() as usize;
+    fn copy_texture_to_atlas(atlas_texture: &mut Image, texture: &Image, position: UVec2) {
+        let rect_width = texture.texture_descriptor.size.width as usize;
+        let rect_height = texture.texture_descriptor.size.height as usize;
+        let rect_x = position.x as usize;
+        let rect_y = position.y as usize;
         let atlas_width = atlas_texture.texture_descriptor.size.width as usize;
         let format_size = atlas_texture.texture_descriptor.format.pixel_size();
 
@@ -114,20 +128,15 @@ impl TextureAtlasBuilder {
         }
     }
 
-    fn copy_converted_texture(
-        &self,
-        atlas_texture: &mut Image,
-        texture: &Image,
-        packed_location: &PackedLocation,
-    ) {
+    fn copy_converted_texture(&self, atlas_texture: &mut Image, texture: &Image, position: UVec2) {
         if self.format == texture.texture_descriptor.format {
-            Self::copy_texture_to_atlas(atlas_texture, texture, packed_location);
+            Self::copy_texture_to_atlas(atlas_texture, texture, position);
         } else if let Some(converted_texture) = texture.convert(self.format) {
             debug!(
                 "Converting texture from '{:?}' to '{:?}'",
                 texture.texture_descriptor.format, self.format
             );
-            Self::copy_texture_to_atlas(atlas_texture, &converted_texture, packed_location);
+            Self::copy_texture_to_atlas(atlas_texture, &converted_texture, position);
         } else {
             error!(
                 "Error converting texture from '{:?}' to '{:?}', ignoring",
@@ -136,26 +145,42 @@ impl TextureAtlasBuilder {
         }
     }
 
-    /// Consumes the builder and returns a result with a new texture atlas.
+    /// Consumes the builder and returns a result with the layout of the textures in the atlas,
+    /// and the atlas texture.
     ///
     /// Internally it copies all rectangles from the textures and copies them
-    /// into a new texture which the texture atlas will use. It is not useful to
-    /// hold a strong handle to the texture afterwards else it will exist twice
-    /// in memory.
+    /// into the new atlas texture, converting them to the atlas format if needed. The atlas
+    /// texture is not added to `textures`: once it is, create the atlas with
+    /// [`TextureAtlas::from_layout`](crate::TextureAtlas::from_layout). It is not useful to
+    /// hold a strong handle to the textures afterwards else they will exist twice in memory.
     ///
     /// # Errors
     ///
     /// If there is not enough space in the atlas texture, an error will
     /// be returned. It is then recommended to make a larger sprite sheet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the added textures is not in `textures`.
     pub fn finish(
         self,
-        textures: &mut Assets<Image>,
-    ) -> Result<TextureAtlas, TextureAtlasBuilderError> {
+        textures: &Assets<Image>,
+    ) -> TextureAtlasBuilderResult<(TextureAtlasLayout, Image)> {
         let initial_width = self.initial_size.x as u32;
         let initial_height = self.initial_size.y as u32;
         let max_width = self.max_size.x as u32;
         let max_height = self.max_size.y as u32;
 
+        let mut rects_to_place = GroupedRectsToPlace::<usize>::new();
+        for (index, (_, size)) in self.textures_to_place.iter().enumerate() {
+            let padded_size = *size + self.padding;
+            rects_to_place.push_rect(
+                index,
+                None,
+                RectToInsert::new(padded_size.x, padded_size.y, 1),
+            );
+        }
+
         let mut current_width = initial_width;
         let mut current_height = initial_height;
         let mut rect_placements = None;
@@ -171,7 +196,7 @@ impl TextureAtlasBuilder {
             let mut target_bins = std::collections::BTreeMap::new();
             target_bins.insert(0, TargetBin::new(current_width, current_height, 1));
             rect_placements = match pack_rects(
-                &self.rects_to_place,
+                &rects_to_place,
                 &mut target_bins,
                 &volume_heuristic,
                 &contains_smallest_box,
@@ -206,18 +231,15 @@ impl TextureAtlasBuilder {
 
         let rect_placements = rect_placements.ok_or(TextureAtlasBuilderError::NotEnoughSpace)?;
 
-        let mut texture_rects = Vec::with_capacity(rect_placements.packed_locations().len());
-        let mut texture_handles = HashMap::default();
-        for (texture_handle, (_, packed_location)) in rect_placements.packed_locations().iter() {
+        let mut texture_rects = vec![Rect::default(); self.textures_to_place.len()];
+        for (&index, (_, packed_location)) in rect_placements.packed_locations().iter() {
+            let (texture_handle, size) = &self.textures_to_place[index];
             let texture = textures.get(texture_handle).unwrap();
-            let min = Vec2::new(packed_location.x() as f32, packed_location.y() as f32);
-            let max = min
-                + Vec2::new(
-                    packed_location.width() as f32,
-                    packed_location.height() as f32,
-                );
-            texture_handles.insert(texture_handle.clone_weak(), texture_rects.len());
-            texture_rects.push(Rect { min, max });
+            let position = UVec2::new(packed_location.x(), packed_location.y());
+            texture_rects[index] = Rect {
+                min: position.as_vec2(),
+                max: (position + *size).as_vec2(),
+            };
             if texture.texture_descriptor.format != self.format && !self.auto_format_conversion {
                 warn!(
                     "Loading a texture of format '{:?}' in an atlas with format '{:?}'",
@@ -225,16 +247,180 @@ impl TextureAtlasBuilder {
                 );
                 return Err(TextureAtlasBuilderError::WrongFormat);
             }
-            self.copy_converted_texture(&mut atlas_texture, texture, packed_location);
+            self.copy_converted_texture(&mut atlas_texture, texture, position);
         }
-        Ok(TextureAtlas {
-            size: Vec2::new(
-                atlas_texture.texture_descriptor.size.width as f32,
-                atlas_texture.texture_descriptor.size.height as f32,
-            ),
-            texture: textures.add(atlas_texture),
-            textures: texture_rects,
-            texture_handles: Some(texture_handles),
+        Ok((
+            TextureAtlasLayout {
+                size: Vec2::new(
+                    atlas_texture.texture_descriptor.size.width as f32,
+                    atlas_texture.texture_descriptor.size.height as f32,
+                ),
+                textures: texture_rects,
+                texture_handles: self.texture_indices,
+            },
+            atlas_texture,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TextureAtlas;
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin};
+
+    fn image(width: u32, height: u32, pixel: &[u8], format: TextureFormat) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            pixel,
+            format,
+        )
+    }
+
+    fn images() -> App {
+        let mut app = App::new();
+        app.add_plugin(AssetPlugin::default()).add_asset::<Image>();
+        app
+    }
+
+    /// Whether the area of the `atlas` texture covered by `rect` is filled with `pixel`.
+    fn is_filled(atlas: &Image, rect: Rect, pixel: &[u8]) -> bool {
+        let width = atlas.texture_descriptor.size.width as usize;
+        let pixel_size = atlas.texture_descriptor.format.pixel_size();
+        (rect.min.y as usize..rect.max.y as usize).all(|y| {
+            (rect.min.x as usize..rect.max.x as usize).all(|x| {
+                let start = (y * width + x) * pixel_size;
+                atlas.data[start..start + pixel_size] == *pixel
+            })
         })
     }
+
+    #[test]
+    fn padding_separates_textures() {
+        let mut app = images();
+        let mut textures = app.world.resource_mut::<Assets<Image>>();
+        let padding = UVec2::new(2, 3);
+        let mut builder = TextureAtlasBuilder::default()
+            .initial_size(Vec2::splat(16.))
+            .padding(padding);
+        let pixels: [&[u8]; 4] = [
+            &[255, 0, 0, 255],
+            &[0, 255, 0, 255],
+            &[0, 0, 255, 255],
+            &[255, 255, 255, 255],
+        ];
+        for (size, pixel) in [4, 5, 3, 6].into_iter().zip(pixels) {
+            let texture = image(size, size, pixel, TextureFormat::Rgba8UnormSrgb);
+            let handle = textures.add(texture.clone());
+            builder.add_texture(handle, &texture);
+        }
+
+        let (layout, atlas) = builder.finish(&textures).unwrap();
+        assert_eq!(layout.textures.len(), 4);
+        for (index, (rect, pixel)) in layout.textures.iter().zip(pixels).enumerate() {
+            assert_eq!(rect.size(), Vec2::splat([4., 5., 3., 6.][index]));
+            assert!(is_filled(&atlas, *rect, pixel));
+
+            // The padding to the right and below the texture is left empty.
+            let padding = padding.as_vec2();
+            let right = Rect::from_corners(
+                Vec2::new(rect.max.x, rect.min.y),
+                Vec2::new(rect.max.x + padding.x, rect.max.y + padding.y),
+            );
+            let below = Rect::from_corners(
+                Vec2::new(rect.min.x, rect.max.y),
+                Vec2::new(rect.max.x, rect.max.y + padding.y),
+            );
+            let size = layout.size;
+            let right = right.intersect(Rect::from_corners(Vec2::ZERO, size));
+            let below = below.intersect(Rect::from_corners(Vec2::ZERO, size));
+            assert!(is_filled(&atlas, right, &[0; 4]));
+            assert!(is_filled(&atlas, below, &[0; 4]));
+
+            for other in &layout.textures[index + 1..] {
+                let padded = Rect {
+                    min: rect.min,
+                    max: rect.max + padding,
+                };
+                assert!(padded.intersect(*other).is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn duplicated_textures_are_added_once() {
+        let mut app = images();
+        let mut textures = app.world.resource_mut::<Assets<Image>>();
+        let mut builder = TextureAtlasBuilder::default();
+        let red = image(4, 4, &[255, 0, 0, 255], TextureFormat::Rgba8UnormSrgb);
+        let blue = image(8, 2, &[0, 0, 255, 255], TextureFormat::Rgba8UnormSrgb);
+        let red_handle = textures.add(red.clone());
+        let blue_handle = textures.add(blue.clone());
+
+        assert_eq!(builder.add_texture(red_handle.clone(), &red), 0);
+        assert_eq!(builder.add_texture(blue_handle.clone(), &blue), 1);
+        assert_eq!(builder.add_texture(red_handle.clone_weak(), &red), 0);
+        assert_eq!(builder.add_texture(blue_handle.clone(), &blue), 1);
+
+        let (layout, atlas) = builder.finish(&textures).unwrap();
+        assert_eq!(layout.textures.len(), 2);
+        assert_eq!(layout.get_texture_index(&red_handle), Some(0));
+        assert_eq!(layout.get_texture_index(&blue_handle), Some(1));
+        assert_eq!(layout.textures[0].size(), Vec2::new(4., 4.));
+        assert_eq!(layout.textures[1].size(), Vec2::new(8., 2.));
+        assert!(is_filled(&atlas, layout.textures[0], &[255, 0, 0, 255]));
+        assert!(is_filled(&atlas, layout.textures[1], &[0, 0, 255, 255]));
+
+        let atlas_handle = textures.add(atlas);
+        let texture_atlas = TextureAtlas::from_layout(atlas_handle, layout);
+        assert_eq!(texture_atlas.get_texture_index(&blue_handle), Some(1));
+    }
+
+    #[test]
+    fn textures_are_converted_to_the_atlas_format() {
+        let mut app = images();
+        let mut textures = app.world.resource_mut::<Assets<Image>>();
+        let mut builder = TextureAtlasBuilder::default();
+        let bgra = image(2, 2, &[0, 0, 255, 255], TextureFormat::Bgra8UnormSrgb);
+        let float = image(
+            3,
+            1,
+            &[1.0f32, 1.0, 1.0, 1.0].map(f32::to_le_bytes).concat(),
+            TextureFormat::Rgba32Float,
+        );
+        let bgra_index = builder.add_texture(textures.add(bgra.clone()), &bgra);
+        let float_index = builder.add_texture(textures.add(float.clone()), &float);
+
+        let (layout, atlas) = builder.finish(&textures).unwrap();
+        assert_eq!(
+            atlas.texture_descriptor.format,
+            TextureFormat::Rgba8UnormSrgb
+        );
+        assert!(is_filled(
+            &atlas,
+            layout.textures[bgra_index],
+            &[255, 0, 0, 255]
+        ));
+        assert!(is_filled(&atlas, layout.textures[float_index], &[255; 4]));
+    }
+
+    #[test]
+    fn format_conversion_can_be_disabled() {
+        let mut app = images();
+        let mut textures = app.world.resource_mut::<Assets<Image>>();
+        let mut builder = TextureAtlasBuilder::default().auto_format_conversion(false);
+        let bgra = image(2, 2, &[0, 0, 255, 255], TextureFormat::Bgra8UnormSrgb);
+        builder.add_texture(textures.add(bgra.clone()), &bgra);
+
+        assert!(matches!(
+            builder.finish(&textures),
+            Err(TextureAtlasBuilderError::WrongFormat)
+        ));
+    }
 }
@@ -0,0 +1,146 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread,
+    time::Duration,
+};
+
+use async_task::Runnable;
+
+use crate::{task, Task};
+
+/// How long a blocking thread waits for new work before exiting.
+const KEEP_ALIVE: Duration = Duration::from_secs(1);
+
+/// A set of threads running blocking functions, spawning new threads when all of them are busy.
+///
+/// Threads exit after staying idle for a while, so the set shrinks back once the load goes down.
+pub(crate) struct BlockingPool {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    state: Mutex<State>,
+    work_available: Condvar,
+    thread_name: String,
+    stack_size: Option<usize>,
+    max_threads: usize,
+}
+
+#[derive(Default)]
+struct State {
+    queue: VecDeque<Runnable>,
+    /// The number of running threads.
+    threads: usize,
+    /// The number of threads waiting for work.
+    idle: usize,
+    /// Incremented for each spawned thread, used to name them.
+    spawned: usize,
+    shutdown: bool,
+}
+
+impl BlockingPool {
+    pub(crate) fn new(thread_name: String, stack_size: Option<usize>, max_threads: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                state: Mutex::default(),
+                work_available: Condvar::new(),
+                thread_name,
+                stack_size,
+                max_threads: max_threads.max(1),
+            }),
+        }
+    }
+
+    pub(crate) fn spawn<T>(&self, f: impl FnOnce() -> T + Send + 'static) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        let (runnable, task) =
+            async_task::spawn(task::catch_panic(async move { f() }), move |runnable| {
+                inner.schedule(runnable);
+            });
+        runnable.schedule();
+        Task::from_caught(task)
+    }
+
+    pub(crate) fn thread_num(&self) -> usize {
+        self.inner.lock().threads
+    }
+
+    /// Lets the threads exit once they have run the remaining work.
+    pub(crate) fn shutdown(&self) {
+        self.inner.lock().shutdown = true;
+        self.inner.work_available.notify_all();
+    }
+}
+
+impl fmt::Debug for BlockingPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockingPool")
+            .field("thread_name", &self.inner.thread_name)
+            .field("max_threads", &self.inner.max_threads)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // Functions run outside of the lock, so it can't be poisoned by a panic
+        self.state.lock().unwrap()
+    }
+
+    fn schedule(self: &Arc<Self>, runnable: Runnable) {
+        let mut state = self.lock();
+        state.queue.push_back(runnable);
+
+        // Grow the pool if there is more work than idle threads
+        if state.queue.len() > state.idle && state.threads < self.max_threads {
+            state.threads += 1;
+            state.idle += 1;
+            let index = state.spawned;
+            state.spawned += 1;
+            drop(state);
+
+            let mut thread_builder =
+                thread::Builder::new().name(format!("{} ({index})", self.thread_name));
+            if let Some(stack_size) = self.stack_size {
+                thread_builder = thread_builder.stack_size(stack_size);
+            }
+            let inner = Arc::clone(self);
+            thread_builder
+                .spawn(move || inner.run())
+                .expect("Failed to spawn thread.");
+        } else {
+            self.work_available.notify_one();
+        }
+    }
+
+    fn run(&self) {
+        // Threads are counted as idle when they get spawned
+        let mut state = self.lock();
+        loop {
+            state.idle -= 1;
+            while let Some(runnable) = state.queue.pop_front() {
+                drop(state);
+                // Panics are caught by the task itself
+                runnable.run();
+                state = self.lock();
+            }
+            if state.shutdown {
+                break;
+            }
+
+            state.idle += 1;
+            let (new_state, timeout) = self.work_available.wait_timeout(state, KEEP_ALIVE).unwrap();
+            state = new_state;
+            if timeout.timed_out() && state.queue.is_empty() {
+                state.idle -= 1;
+                break;
+            }
+        }
+        state.threads -= 1;
+    }
+}
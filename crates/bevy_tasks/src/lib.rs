@@ -5,7 +5,10 @@ mod slice;
 pub use slice::{ParallelSlice, ParallelSliceMut};
 
 mod task;
-pub use task::Task;
+pub use task::{CatchUnwind, Panicked, Task};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod blocking;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod task_pool;
@@ -20,7 +23,7 @@ pub use single_threaded_task_pool::{Scope, TaskPool, TaskPoolBuilder, ThreadExec
 mod usages;
 #[cfg(not(target_arch = "wasm32"))]
 pub use usages::tick_global_task_pools_on_main_thread;
-pub use usages::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool};
+pub use usages::{AsyncComputeTaskPool, ComputeTaskPool, GlobalTaskPoolTickSettings, IoTaskPool};
 
 #[cfg(not(target_arch = "wasm32"))]
 mod thread_executor;
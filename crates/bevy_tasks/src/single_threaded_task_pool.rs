@@ -44,6 +44,16 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    pub fn max_blocking_threads(self, _max_blocking_threads: usize) -> Self {
+        self
+    }
+
+    /// No op on the single threaded task pool
+    pub fn borrow_idle_threads(self, _lender: &TaskPool, _max_threads: usize) -> Self {
+        self
+    }
+
     /// Creates a new [`TaskPool`]
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal()
@@ -151,6 +161,14 @@ impl TaskPool {
         FakeTask
     }
 
+    /// Runs a blocking function on the JS event loop, as there are no other threads to run it on.
+    pub fn spawn_blocking<T>(&self, f: impl FnOnce() -> T + 'static) -> FakeTask
+    where
+        T: 'static,
+    {
+        self.spawn(async move { f() })
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskSpool::spawn`].
     pub fn spawn_local<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask
     where
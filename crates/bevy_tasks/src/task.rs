@@ -1,9 +1,21 @@
 use std::{
+    any::Any,
+    fmt,
     future::Future,
+    panic::{self, AssertUnwindSafe},
     pin::Pin,
     task::{Context, Poll},
+    thread,
 };
 
+use futures_lite::FutureExt;
+
+/// Wraps a future so that it returns its panic instead of unwinding through the executor polling
+/// it.
+pub(crate) fn catch_panic<F: Future>(future: F) -> impl Future<Output = thread::Result<F::Output>> {
+    AssertUnwindSafe(future).catch_unwind()
+}
+
 /// Wraps `async_executor::Task`, a spawned future.
 ///
 /// Tasks are also futures themselves and yield the output of the spawned future.
@@ -11,22 +23,45 @@ use std::{
 /// When a task is dropped, its gets canceled and won't be polled again. To cancel a task a bit
 /// more gracefully and wait until it stops running, use the [`cancel()`][Task::cancel()] method.
 ///
-/// Tasks that panic get immediately canceled. Awaiting a canceled task also causes a panic.
+/// Tasks spawned by the task pools that panic get immediately canceled, without affecting the
+/// pool they were running on. The panic is resumed on the thread awaiting the task, unless it is
+/// caught with [`catch_unwind()`][Task::catch_unwind()].
 /// Wraps `async_executor::Task`
 #[derive(Debug)]
 #[must_use = "Tasks are canceled when dropped, use `.detach()` to run them in the background."]
-pub struct Task<T>(async_executor::Task<T>);
+pub struct Task<T>(TaskInner<T>);
+
+#[derive(Debug)]
+enum TaskInner<T> {
+    /// Spawned from a future wrapped with [`catch_panic`], which returns its panic.
+    Caught(async_executor::Task<thread::Result<T>>),
+    /// Spawned from a future as is, whose panic is handled by the executor.
+    Uncaught(async_executor::Task<T>),
+}
 
 impl<T> Task<T> {
     /// Creates a new task from a given `async_executor::Task`
+    ///
+    /// The panics of its future are handled by the executor: an `async_executor::Executor`
+    /// resumes them on the thread awaiting the task. Awaiting the task after it panicked always
+    /// causes a panic.
     pub fn new(task: async_executor::Task<T>) -> Self {
-        Self(task)
+        Self(TaskInner::Uncaught(task))
+    }
+
+    /// Creates a new task from a given `async_executor::Task`, spawned from a future wrapped with
+    /// [`catch_panic`].
+    pub(crate) fn from_caught(task: async_executor::Task<thread::Result<T>>) -> Self {
+        Self(TaskInner::Caught(task))
     }
 
     /// Detaches the task to let it keep running in the background. See
     /// `async_executor::Task::detach`
     pub fn detach(self) {
-        self.0.detach();
+        match self.0 {
+            TaskInner::Caught(task) => task.detach(),
+            TaskInner::Uncaught(task) => task.detach(),
+        }
     }
 
     /// Cancels the task and waits for it to stop running.
@@ -39,7 +74,13 @@ impl<T> Task<T> {
     ///
     /// See `async_executor::Task::cancel`
     pub async fn cancel(self) -> Option<T> {
-        self.0.cancel().await
+        match self.0 {
+            TaskInner::Caught(task) => task
+                .cancel()
+                .await
+                .map(|result| result.unwrap_or_else(|payload| panic::resume_unwind(payload))),
+            TaskInner::Uncaught(task) => task.cancel().await,
+        }
     }
 
     /// Returns `true` if the current task is finished.
@@ -48,7 +89,26 @@ impl<T> Task<T> {
     /// Unlike poll, it doesn't resolve the final value, it just checks if the task has finished.
     /// Note that in a multithreaded environment, this task can be finished immediately after calling this function.
     pub fn is_finished(&self) -> bool {
-        self.0.is_finished()
+        match &self.0 {
+            TaskInner::Caught(task) => task.is_finished(),
+            TaskInner::Uncaught(task) => task.is_finished(),
+        }
+    }
+
+    /// Returns a future that yields [`Err(Panicked)`](Panicked) if the task panicked, instead of
+    /// resuming the panic on the thread awaiting it.
+    ///
+    /// ```
+    /// use bevy_tasks::TaskPool;
+    /// use futures_lite::future;
+    ///
+    /// let pool = TaskPool::new();
+    /// let task = pool.spawn(async { panic!("oh no") });
+    /// let panicked = future::block_on(task.catch_unwind()).unwrap_err();
+    /// assert_eq!(panicked.message(), Some("oh no"));
+    /// ```
+    pub fn catch_unwind(self) -> CatchUnwind<T> {
+        CatchUnwind(self)
     }
 }
 
@@ -56,6 +116,105 @@ impl<T> Future for Task<T> {
     type Output = T;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        Pin::new(&mut self.0).poll(cx)
+        match &mut self.0 {
+            TaskInner::Caught(task) => Pin::new(task)
+                .poll(cx)
+                .map(|result| result.unwrap_or_else(|payload| panic::resume_unwind(payload))),
+            TaskInner::Uncaught(task) => Pin::new(task).poll(cx),
+        }
+    }
+}
+
+/// A future yielding the output of a [`Task`], or [`Panicked`] if the task panicked.
+///
+/// Created by [`Task::catch_unwind`].
+#[derive(Debug)]
+#[must_use = "Tasks are canceled when dropped, use `.detach()` to run them in the background."]
+pub struct CatchUnwind<T>(Task<T>);
+
+impl<T> CatchUnwind<T> {
+    /// Returns the wrapped task, which resumes panics when awaited.
+    pub fn into_inner(self) -> Task<T> {
+        self.0
+    }
+}
+
+impl<T> Future for CatchUnwind<T> {
+    type Output = Result<T, Panicked>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.0 .0 {
+            TaskInner::Caught(task) => Pin::new(task)
+                .poll(cx)
+                .map(|result| result.map_err(Panicked)),
+            // This catches the panic of awaiting the failed task, which is the panic of its future
+            // when the executor propagates it.
+            TaskInner::Uncaught(task) => {
+                match panic::catch_unwind(AssertUnwindSafe(|| Pin::new(task).poll(cx))) {
+                    Ok(poll) => poll.map(Ok),
+                    Err(payload) => Poll::Ready(Err(Panicked(payload))),
+                }
+            }
+        }
+    }
+}
+
+/// The error returned by [`CatchUnwind`] when a [`Task`] panicked.
+pub struct Panicked(Box<dyn Any + Send + 'static>);
+
+impl Panicked {
+    /// Returns the message the task panicked with, if it was a string.
+    pub fn message(&self) -> Option<&str> {
+        self.0
+            .downcast_ref::<&'static str>()
+            .copied()
+            .or_else(|| self.0.downcast_ref::<String>().map(String::as_str))
+    }
+
+    /// Returns the payload the task panicked with.
+    pub fn into_payload(self) -> Box<dyn Any + Send + 'static> {
+        self.0
+    }
+
+    /// Resumes the panic on the current thread.
+    pub fn resume(self) -> ! {
+        panic::resume_unwind(self.into_payload())
+    }
+}
+
+impl fmt::Debug for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Panicked")
+            .field("message", &self.message())
+            .finish()
+    }
+}
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "task panicked: {message}"),
+            None => write!(f, "task panicked"),
+        }
+    }
+}
+
+impl std::error::Error for Panicked {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future;
+
+    #[test]
+    fn tasks_of_external_executors() {
+        let executor = async_executor::Executor::new();
+        let task = Task::new(executor.spawn(async { 1 }));
+        assert_eq!(future::block_on(executor.run(task)), 1);
+
+        // the executor catches the panic and resumes it on the thread awaiting the task
+        let task = Task::new(executor.spawn(async { panic!("oh no") }));
+        let panicked = future::block_on(executor.run(task.catch_unwind())).unwrap_err();
+        assert_eq!(panicked.message(), Some("oh no"));
     }
 }
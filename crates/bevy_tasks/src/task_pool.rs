@@ -12,10 +12,15 @@ use concurrent_queue::ConcurrentQueue;
 use futures_lite::{future, FutureExt};
 
 use crate::{
+    blocking::BlockingPool,
+    task,
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
     Task,
 };
 
+/// The default maximum number of threads used by [`TaskPool::spawn_blocking`]
+const DEFAULT_MAX_BLOCKING_THREADS: usize = 500;
+
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
 
 impl Drop for CallOnDrop {
@@ -38,6 +43,11 @@ pub struct TaskPoolBuilder {
     /// Allows customizing the name of the threads - helpful for debugging. If set, threads will
     /// be named <thread_name> (<thread_index>), i.e. "MyThreadPool (2)"
     thread_name: Option<String>,
+    /// If set, we'll use at most `max_blocking_threads` threads to run blocking functions.
+    /// Otherwise use [`DEFAULT_MAX_BLOCKING_THREADS`]
+    max_blocking_threads: Option<usize>,
+    /// If set, scopes will borrow idle threads of another pool to run their tasks
+    thread_lender: Option<ThreadLender>,
 
     on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
@@ -69,6 +79,28 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Override the maximum number of threads created to run the functions spawned with
+    /// [`TaskPool::spawn_blocking`]. If unset, we default to 500 threads
+    pub fn max_blocking_threads(mut self, max_blocking_threads: usize) -> Self {
+        self.max_blocking_threads = Some(max_blocking_threads);
+        self
+    }
+
+    /// Lets the scopes of the pool borrow up to `max_threads` threads of the `lender` pool to run
+    /// their tasks, while these threads are idle.
+    ///
+    /// This is useful to let a pool running latency sensitive work, like per-frame scopes, use
+    /// the threads of a pool running background work when there is no background work to run.
+    /// Threads are only borrowed for the duration of a scope, and background tasks waiting for
+    /// a borrowed thread have to wait until the scope completes.
+    pub fn borrow_idle_threads(mut self, lender: &TaskPool, max_threads: usize) -> Self {
+        self.thread_lender = Some(ThreadLender {
+            executor: Arc::clone(&lender.executor),
+            max_threads,
+        });
+        self
+    }
+
     /// Sets a callback that is invoked once for every created thread as it starts.
     ///
     /// This is called on the thread itself and has access to all thread-local storage.
@@ -93,6 +125,13 @@ impl TaskPoolBuilder {
     }
 }
 
+/// A pool whose idle threads can be borrowed by the scopes of another pool.
+#[derive(Clone, Debug)]
+struct ThreadLender {
+    executor: Arc<async_executor::Executor<'static>>,
+    max_threads: usize,
+}
+
 /// A thread pool for executing tasks. Tasks are futures that are being automatically driven by
 /// the pool on threads owned by the pool.
 #[derive(Debug)]
@@ -107,6 +146,12 @@ pub struct TaskPool {
     /// Inner state of the pool
     threads: Vec<JoinHandle<()>>,
     shutdown_tx: async_channel::Sender<()>,
+
+    /// The threads running the functions spawned with [`TaskPool::spawn_blocking`]
+    blocking: BlockingPool,
+
+    /// The pool whose idle threads help running the tasks of scopes
+    thread_lender: Option<ThreadLender>,
 }
 
 impl TaskPool {
@@ -182,10 +227,25 @@ impl TaskPool {
             })
             .collect();
 
+        let blocking_thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
+            format!("{thread_name} Blocking")
+        } else {
+            "TaskPool Blocking".to_string()
+        };
+        let blocking = BlockingPool::new(
+            blocking_thread_name,
+            builder.stack_size,
+            builder
+                .max_blocking_threads
+                .unwrap_or(DEFAULT_MAX_BLOCKING_THREADS),
+        );
+
         Self {
             executor,
             threads,
             shutdown_tx,
+            blocking,
+            thread_lender: builder.thread_lender,
         }
     }
 
@@ -194,6 +254,12 @@ impl TaskPool {
         self.threads.len()
     }
 
+    /// Return the number of threads currently running the functions spawned with
+    /// [`TaskPool::spawn_blocking`]
+    pub fn blocking_thread_num(&self) -> usize {
+        self.blocking.thread_num()
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -369,6 +435,9 @@ impl TaskPool {
         if spawned.is_empty() {
             Vec::new()
         } else {
+            // Dropped once the scope completes, which stops the borrowed threads
+            let _borrowed_threads = self.borrow_idle_threads(spawned.len());
+
             future::block_on(async move {
                 let get_results = async {
                     let mut results = Vec::with_capacity(spawned.len());
@@ -414,6 +483,25 @@ impl TaskPool {
         }
     }
 
+    /// Spawns tasks on the lender pool running the tasks of this pool, for up to `task_count`
+    /// threads. The tasks stop once the returned sender is dropped.
+    fn borrow_idle_threads(&self, task_count: usize) -> Option<async_channel::Sender<()>> {
+        let lender = self.thread_lender.as_ref()?;
+        let (done_tx, done_rx) = async_channel::bounded(1);
+        for _ in 0..lender.max_threads.min(task_count) {
+            let executor = Arc::clone(&self.executor);
+            let done_rx = done_rx.clone();
+            lender
+                .executor
+                .spawn(task::catch_panic(async move {
+                    // Errors once the sender is dropped
+                    let _ = executor.run(done_rx.recv()).await;
+                }))
+                .detach();
+        }
+        Some(done_tx)
+    }
+
     #[inline]
     async fn execute_global_external_scope<'scope, 'ticker, T>(
         executor: &'scope async_executor::Executor<'scope>,
@@ -509,7 +597,31 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        Task::from_caught(self.executor.spawn(task::catch_panic(future)))
+    }
+
+    /// Spawns a blocking function onto a dedicated set of threads. The returned Task is a future
+    /// yielding the output of the function.
+    ///
+    /// This should be used for work that blocks the thread for a long time, like synchronous IO
+    /// or heavy computations that can't yield, so that it doesn't prevent the tasks spawned
+    /// with [`TaskPool::spawn`] from making progress. New threads are spawned when all of them
+    /// are busy, up to [`TaskPoolBuilder::max_blocking_threads`], and exit after being idle for
+    /// a while.
+    ///
+    /// ```
+    /// use bevy_tasks::TaskPool;
+    /// use futures_lite::future;
+    ///
+    /// let pool = TaskPool::new();
+    /// let task = pool.spawn_blocking(|| (0..1000u64).sum::<u64>());
+    /// assert_eq!(future::block_on(task), 499500);
+    /// ```
+    pub fn spawn_blocking<T>(&self, f: impl FnOnce() -> T + Send + 'static) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        self.blocking.spawn(f)
     }
 
     /// Spawns a static future on the thread-local async executor for the current thread. The task
@@ -521,7 +633,9 @@ impl TaskPool {
     where
         T: 'static,
     {
-        Task::new(TaskPool::LOCAL_EXECUTOR.with(|executor| executor.spawn(future)))
+        Task::from_caught(
+            TaskPool::LOCAL_EXECUTOR.with(|executor| executor.spawn(task::catch_panic(future))),
+        )
     }
 
     /// Runs a function with the local executor. Typically used to tick
@@ -552,6 +666,7 @@ impl Default for TaskPool {
 impl Drop for TaskPool {
     fn drop(&mut self) {
         self.shutdown_tx.close();
+        self.blocking.shutdown();
 
         let panicking = thread::panicking();
         for join_handle in self.threads.drain(..) {
@@ -882,4 +997,144 @@ mod tests {
         assert!(!thread_check_failed.load(Ordering::Acquire));
         assert_eq!(count.load(Ordering::Acquire), 200);
     }
+
+    #[test]
+    fn test_panicking_task_does_not_poison_pool() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+
+        let task = pool.spawn(async { panic!("task panicked on purpose") });
+        let panicked = future::block_on(task.catch_unwind()).unwrap_err();
+        assert_eq!(panicked.message(), Some("task panicked on purpose"));
+
+        // Without `catch_unwind`, the panic is resumed on the thread awaiting the task
+        let task = pool.spawn(async { panic!("task panicked on purpose") });
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| future::block_on(task)));
+        assert!(result.is_err());
+
+        // The pool thread is still running tasks
+        let task = pool.spawn(async { 42 });
+        assert_eq!(future::block_on(task), 42);
+
+        let task = pool.spawn_blocking(|| panic!("function panicked on purpose"));
+        assert!(future::block_on(task.catch_unwind()).is_err());
+        assert_eq!(future::block_on(pool.spawn_blocking(|| 42)), 42);
+    }
+
+    #[test]
+    fn test_blocking_pool_grows() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let barrier = Arc::new(Barrier::new(4));
+        let (sender, receiver) = async_channel::unbounded();
+
+        // The functions can only finish once each of them runs on its own thread,
+        // and the async task has been able to run alongside them
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let barrier = barrier.clone();
+                let receiver = receiver.clone();
+                pool.spawn_blocking(move || {
+                    barrier.wait();
+                    future::block_on(receiver.recv()).unwrap()
+                })
+            })
+            .collect();
+        pool.spawn(async move {
+            for i in 0..4 {
+                sender.send(i).await.unwrap();
+            }
+        })
+        .detach();
+
+        let mut outputs: Vec<i32> = tasks.into_iter().map(future::block_on).collect();
+        outputs.sort();
+        assert_eq!(outputs, vec![0, 1, 2, 3]);
+        assert!(pool.blocking_thread_num() <= 4);
+    }
+
+    #[test]
+    fn test_max_blocking_threads() {
+        let pool = TaskPoolBuilder::new().max_blocking_threads(2).build();
+        let tasks: Vec<_> = (0..10)
+            .map(|i| {
+                pool.spawn_blocking(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(1));
+                    i
+                })
+            })
+            .collect();
+        assert!(pool.blocking_thread_num() <= 2);
+
+        let outputs: Vec<i32> = tasks.into_iter().map(future::block_on).collect();
+        assert_eq!(outputs, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_scope_borrows_idle_threads() {
+        let lender = TaskPoolBuilder::new().num_threads(3).build();
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .borrow_idle_threads(&lender, 3)
+            .build();
+
+        // The scope thread and the single pool thread can't run 4 tasks at the same time on
+        // their own, so this only completes if threads of the lender pool run some of them
+        let barrier = Barrier::new(4);
+        let outputs = pool.scope(|scope| {
+            for i in 0..4 {
+                let barrier = &barrier;
+                scope.spawn(async move {
+                    barrier.wait();
+                    i
+                });
+            }
+        });
+        assert_eq!(outputs, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_scope_doesnt_wait_for_busy_lent_threads() {
+        let lender = TaskPoolBuilder::new().num_threads(2).build();
+        let pool = TaskPoolBuilder::new()
+            .num_threads(2)
+            .borrow_idle_threads(&lender, 2)
+            .build();
+
+        // Keep every thread of the lender pool blocked until the scope completes
+        let started = Arc::new(Barrier::new(3));
+        let (release_senders, background_tasks): (Vec<_>, Vec<_>) = (0..2)
+            .map(|_| {
+                let started = started.clone();
+                let (release, released) = std::sync::mpsc::channel::<()>();
+                let task = lender.spawn(async move {
+                    started.wait();
+                    // only times out if the scope waited for this thread, to fail instead of hanging
+                    released
+                        .recv_timeout(std::time::Duration::from_secs(10))
+                        .is_ok()
+                });
+                (release, task)
+            })
+            .unzip();
+        started.wait();
+
+        // The scope doesn't wait for the busy threads it tried to borrow
+        let count = AtomicI32::new(0);
+        let outputs = pool.scope(|scope| {
+            for _ in 0..100 {
+                scope.spawn(async {
+                    count.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+        });
+        assert_eq!(outputs.len(), 100);
+        assert_eq!(count.load(Ordering::Relaxed), 100);
+        assert!(background_tasks.iter().all(|task| !task.is_finished()));
+
+        for release in release_senders {
+            release.send(()).unwrap();
+        }
+        for task in background_tasks {
+            assert!(future::block_on(task));
+        }
+    }
 }
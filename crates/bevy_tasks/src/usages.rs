@@ -12,7 +12,7 @@
 
 use super::TaskPool;
 use once_cell::sync::OnceCell;
-use std::ops::Deref;
+use std::{ops::Deref, time::Duration};
 
 static COMPUTE_TASK_POOL: OnceCell<ComputeTaskPool> = OnceCell::new();
 static ASYNC_COMPUTE_TASK_POOL: OnceCell<AsyncComputeTaskPool> = OnceCell::new();
@@ -110,14 +110,36 @@ impl Deref for IoTaskPool {
     }
 }
 
+/// Limits the work done by [`tick_global_task_pools_on_main_thread`] each time it is called.
+#[derive(Clone, Debug)]
+pub struct GlobalTaskPoolTickSettings {
+    /// The maximum number of local tasks to run per executor.
+    pub max_ticks: usize,
+    /// The maximum time spent running local tasks, if any.
+    ///
+    /// It is checked after each round of ticks of the executors, so a slow task can exceed it.
+    pub max_duration: Option<Duration>,
+}
+
+impl Default for GlobalTaskPoolTickSettings {
+    fn default() -> Self {
+        Self {
+            max_ticks: 100,
+            max_duration: Some(Duration::from_millis(2)),
+        }
+    }
+}
+
 /// A function used by `bevy_core` to tick the global tasks pools on the main thread.
-/// This will run a maximum of 100 local tasks per executor per call to this function.
+/// This will run a maximum of [`GlobalTaskPoolTickSettings::max_ticks`] local tasks per executor
+/// per call to this function, and stop early once there are no local tasks left or once
+/// [`GlobalTaskPoolTickSettings::max_duration`] has elapsed.
 ///
 /// # Warning
 ///
 /// This function *must* be called on the main thread, or the task pools will not be updated appropriately.
 #[cfg(not(target_arch = "wasm32"))]
-pub fn tick_global_task_pools_on_main_thread() {
+pub fn tick_global_task_pools_on_main_thread(settings: &GlobalTaskPoolTickSettings) {
     COMPUTE_TASK_POOL
         .get()
         .unwrap()
@@ -130,12 +152,102 @@ pub fn tick_global_task_pools_on_main_thread() {
                         .get()
                         .unwrap()
                         .with_local_executor(|io_local_executor| {
-                            for _ in 0..100 {
-                                compute_local_executor.try_tick();
-                                async_local_executor.try_tick();
-                                io_local_executor.try_tick();
-                            }
+                            tick_local_executors(
+                                &[
+                                    &|| compute_local_executor.try_tick(),
+                                    &|| async_local_executor.try_tick(),
+                                    &|| io_local_executor.try_tick(),
+                                ],
+                                settings,
+                            );
                         });
                 });
         });
 }
+
+/// Ticks each executor in turn with their `try_tick` function, returning the number of rounds of
+/// ticks.
+#[cfg(not(target_arch = "wasm32"))]
+fn tick_local_executors(
+    try_ticks: &[&dyn Fn() -> bool],
+    settings: &GlobalTaskPoolTickSettings,
+) -> usize {
+    let start = std::time::Instant::now();
+    for round in 0..settings.max_ticks {
+        let mut ticked = false;
+        for try_tick in try_ticks {
+            ticked |= try_tick();
+        }
+        if !ticked {
+            return round;
+        }
+        if let Some(max_duration) = settings.max_duration {
+            if start.elapsed() >= max_duration {
+                return round + 1;
+            }
+        }
+    }
+    settings.max_ticks
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use async_executor::LocalExecutor;
+    use std::{cell::Cell, rc::Rc, thread};
+
+    fn spawn_slow_tasks(executor: &LocalExecutor, count: usize) -> Rc<Cell<usize>> {
+        let finished = Rc::new(Cell::new(0));
+        for _ in 0..count {
+            let finished = finished.clone();
+            executor
+                .spawn(async move {
+                    thread::sleep(Duration::from_millis(5));
+                    finished.set(finished.get() + 1);
+                })
+                .detach();
+        }
+        finished
+    }
+
+    #[test]
+    fn tick_budget() {
+        let executor = LocalExecutor::new();
+        let finished = spawn_slow_tasks(&executor, 10);
+
+        let settings = GlobalTaskPoolTickSettings {
+            max_ticks: 100,
+            max_duration: Some(Duration::from_millis(1)),
+        };
+        assert_eq!(
+            tick_local_executors(&[&|| executor.try_tick()], &settings),
+            1
+        );
+        assert_eq!(finished.get(), 1);
+
+        let settings = GlobalTaskPoolTickSettings {
+            max_ticks: 3,
+            max_duration: None,
+        };
+        assert_eq!(
+            tick_local_executors(&[&|| executor.try_tick()], &settings),
+            3
+        );
+        assert_eq!(finished.get(), 4);
+
+        // Stops early once there are no tasks left
+        let settings = GlobalTaskPoolTickSettings {
+            max_ticks: 100,
+            max_duration: None,
+        };
+        assert_eq!(
+            tick_local_executors(&[&|| executor.try_tick()], &settings),
+            6
+        );
+        assert_eq!(finished.get(), 10);
+        assert_eq!(
+            tick_local_executors(&[&|| executor.try_tick()], &settings),
+            0
+        );
+    }
+}
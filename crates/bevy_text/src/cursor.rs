@@ -0,0 +1,505 @@
+use std::ops::Range;
+
+use bevy_math::{Rect, Vec2};
+
+use crate::{TextLayoutInfo, YAxisOrientation};
+
+/// A line of laid out text, ended by line wrapping or a line break.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLine {
+    /// The bytes from the start of this line to the start of the next one.
+    ///
+    /// Offsets index into the values of all the text's sections, concatenated.
+    pub byte_range: Range<usize>,
+    /// The horizontal extent of the line's clusters over the full height of the line.
+    ///
+    /// Empty lines have a width of zero.
+    pub rect: Rect,
+    /// The grapheme clusters on this line, from left to right.
+    pub clusters: Vec<TextCluster>,
+}
+
+/// A laid out grapheme cluster.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextCluster {
+    /// The bytes making up this cluster.
+    pub byte_range: Range<usize>,
+    /// The horizontal advance of the cluster over the full height of its line.
+    pub rect: Rect,
+}
+
+impl TextLayoutInfo {
+    /// Returns the caret for the given byte offset, as a zero-width rect spanning the height of its
+    /// line.
+    ///
+    /// Byte offsets index into the values of all the text's sections, concatenated. An offset
+    /// inside a grapheme cluster places the caret before that cluster. If the text has no laid
+    /// out glyphs, an empty rect at the origin is returned.
+    pub fn caret_position(&self, byte_index: usize) -> Rect {
+        let Some(line) = self.line_at(byte_index) else {
+            return Rect::default();
+        };
+        let x = match line
+            .clusters
+            .iter()
+            .position(|cluster| cluster.byte_range.end > byte_index)
+        {
+            Some(index) if index == 0 || line.clusters[index].byte_range.start <= byte_index => {
+                line.clusters[index].rect.min.x
+            }
+            // The offset is on a character without a glyph, e.g. a tab.
+            Some(index) => line.clusters[index - 1].rect.max.x,
+            None => line
+                .clusters
+                .last()
+                .map_or(line.rect.min.x, |cluster| cluster.rect.max.x),
+        };
+        Rect::new(x, line.rect.min.y, x, line.rect.max.y)
+    }
+
+    /// Returns the byte offset of the caret position closest to `point`.
+    ///
+    /// A point past the end of a wrapped line places the caret before the last cluster of the line,
+    /// since the caret after it is at the start of the next line.
+    pub fn hit_test(&self, point: Vec2) -> usize {
+        let Some((index, line)) = self.lines.iter().enumerate().min_by(|(_, a), (_, b)| {
+            vertical_distance(a.rect, point.y).total_cmp(&vertical_distance(b.rect, point.y))
+        }) else {
+            return 0;
+        };
+        if let Some(cluster) = line
+            .clusters
+            .iter()
+            .find(|cluster| point.x < cluster.rect.center().x)
+        {
+            return cluster.byte_range.start;
+        }
+        match line.clusters.last() {
+            Some(cluster)
+                if cluster.byte_range.end == line.byte_range.end
+                    && index + 1 < self.lines.len() =>
+            {
+                cluster.byte_range.start
+            }
+            Some(cluster) => cluster.byte_range.end,
+            None => line.byte_range.start,
+        }
+    }
+
+    /// Returns the rects covering the clusters in `byte_range`, one for each line the range
+    /// spans.
+    ///
+    /// Lines with no selected clusters, such as empty lines, are skipped.
+    pub fn selection_rects(&self, byte_range: Range<usize>) -> Vec<Rect> {
+        self.lines
+            .iter()
+            .filter_map(|line| {
+                let mut selected = line.clusters.iter().filter(|cluster| {
+                    cluster.byte_range.start < byte_range.end
+                        && cluster.byte_range.end > byte_range.start
+                });
+                let first = selected.next()?;
+                let last = selected.last().unwrap_or(first);
+                Some(Rect::new(
+                    first.rect.min.x,
+                    line.rect.min.y,
+                    last.rect.max.x,
+                    line.rect.max.y,
+                ))
+            })
+            .collect()
+    }
+
+    fn line_at(&self, byte_index: usize) -> Option<&TextLine> {
+        self.lines
+            .iter()
+            .rev()
+            .find(|line| line.byte_range.start <= byte_index)
+            .or_else(|| self.lines.first())
+    }
+}
+
+fn vertical_distance(rect: Rect, y: f32) -> f32 {
+    (rect.min.y - y).max(y - rect.max.y).max(0.0)
+}
+
+/// A glyph as positioned by the glyph brush, before it is rasterized.
+pub(crate) struct LayoutGlyph {
+    pub section_index: usize,
+    /// The byte offset of the glyph's character in its section.
+    pub byte_index: usize,
+    /// The glyph's origin on the baseline.
+    pub position: Vec2,
+    pub advance: f32,
+    pub ascent: f32,
+    pub descent: f32,
+}
+
+/// Groups the glyphs laid out for `sections` into lines of grapheme clusters.
+///
+/// The glyph brush emits no glyphs for line breaks, so the lines they end are found in the text,
+/// with `line_height` giving the height of a line break in the given section.
+pub(crate) fn compute_lines(
+    sections: &[&str],
+    glyphs: &[LayoutGlyph],
+    line_height: impl Fn(usize) -> f32,
+) -> Vec<TextLine> {
+    let text = sections.concat();
+    let section_starts: Vec<usize> = sections
+        .iter()
+        .scan(0, |start, section| {
+            let section_start = *start;
+            *start += section.len();
+            Some(section_start)
+        })
+        .collect();
+    let section_at = |byte: usize| {
+        section_starts
+            .partition_point(|&start| start <= byte)
+            .saturating_sub(1)
+    };
+
+    // The lines with glyphs, and their baselines.
+    let mut glyph_lines: Vec<(f32, TextLine)> = Vec::new();
+    for glyph in glyphs {
+        let start = section_starts[glyph.section_index] + glyph.byte_index;
+        let Some(c) = text.get(start..).and_then(|rest| rest.chars().next()) else {
+            continue;
+        };
+        let end = start + c.len_utf8();
+        let top = glyph.position.y - glyph.ascent;
+        let bottom = glyph.position.y - glyph.descent;
+        let cluster = TextCluster {
+            byte_range: start..end,
+            rect: Rect::new(
+                glyph.position.x,
+                top,
+                glyph.position.x + glyph.advance,
+                bottom,
+            ),
+        };
+
+        match glyph_lines.last_mut() {
+            Some((baseline, line)) if (*baseline - glyph.position.y).abs() < 0.5 => {
+                line.rect = line.rect.union(cluster.rect);
+                let joins_previous =
+                    extends_grapheme(c) || text[..start].ends_with(ZERO_WIDTH_JOINER);
+                match line.clusters.last_mut() {
+                    Some(previous) if joins_previous => {
+                        previous.byte_range.end = end;
+                        previous.rect = previous.rect.union(cluster.rect);
+                    }
+                    _ => line.clusters.push(cluster),
+                }
+            }
+            _ => glyph_lines.push((
+                glyph.position.y,
+                TextLine {
+                    byte_range: start..end,
+                    rect: cluster.rect,
+                    clusters: vec![cluster],
+                },
+            )),
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut previous: Option<(f32, Rect)> = None;
+    let mut searched_to = 0;
+    for (baseline, mut line) in glyph_lines {
+        for cluster in &mut line.clusters {
+            cluster.rect.min.y = line.rect.min.y;
+            cluster.rect.max.y = line.rect.max.y;
+        }
+        let first_byte = line.clusters[0].byte_range.start;
+        let breaks = line_breaks(&text, searched_to..first_byte);
+        match previous {
+            // The first break ends the previous line, and each following one ends an empty line.
+            Some((previous_baseline, previous_rect)) => {
+                let spacing = (baseline - previous_baseline) / breaks.len().max(1) as f32;
+                for (i, pair) in breaks.windows(2).enumerate() {
+                    lines.push(empty_line(
+                        pair[0] + 1,
+                        previous_rect.min.y + (i + 1) as f32 * spacing,
+                        previous_rect.height(),
+                    ));
+                }
+            }
+            // Each break before the first glyph ends an empty line.
+            None => {
+                let mut top = line.rect.min.y;
+                for (i, &line_break) in breaks.iter().enumerate().rev() {
+                    top -= line_height(section_at(line_break));
+                    let start = if i == 0 { 0 } else { breaks[i - 1] + 1 };
+                    lines.push(empty_line(start, top, line.rect.height()));
+                }
+                lines.reverse();
+            }
+        }
+        line.byte_range.start = match (breaks.last(), &previous) {
+            (Some(&line_break), _) => line_break + 1,
+            (None, None) => 0,
+            (None, Some(_)) => first_byte,
+        };
+        searched_to = line.clusters.last().unwrap().byte_range.end;
+        previous = Some((baseline, line.rect));
+        lines.push(line);
+    }
+
+    // Each break after the last glyph starts an empty line.
+    if let Some((_, last_rect)) = previous {
+        let mut top = last_rect.min.y;
+        for line_break in line_breaks(&text, searched_to..text.len()) {
+            top += line_height(section_at(line_break));
+            lines.push(empty_line(line_break + 1, top, last_rect.height()));
+        }
+    }
+
+    for i in 0..lines.len() {
+        lines[i].byte_range.end = lines
+            .get(i + 1)
+            .map_or(text.len(), |next| next.byte_range.start);
+    }
+    lines
+}
+
+/// Moves lines from the glyph brush's coordinates to those of the text's
+/// [`PositionedGlyph`](crate::PositionedGlyph)s.
+pub(crate) fn lines_to_text_space(
+    lines: &mut [TextLine],
+    min: Vec2,
+    max_y: f32,
+    y_axis_orientation: &YAxisOrientation,
+) {
+    let to_text_space = |rect: Rect| match y_axis_orientation {
+        YAxisOrientation::TopToBottom => Rect::from_corners(rect.min - min, rect.max - min),
+        YAxisOrientation::BottomToTop => Rect::new(
+            rect.min.x - min.x,
+            max_y - rect.min.y,
+            rect.max.x - min.x,
+            max_y - rect.max.y,
+        ),
+    };
+    for line in lines {
+        line.rect = to_text_space(line.rect);
+        for cluster in &mut line.clusters {
+            cluster.rect = to_text_space(cluster.rect);
+        }
+    }
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Whether `c` belongs to the grapheme cluster of the character before it.
+///
+/// This covers combining marks, variation selectors, emoji modifiers and joiners, which keeps
+/// accented letters and most emoji sequences together.
+fn extends_grapheme(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | ZERO_WIDTH_JOINER
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+fn line_breaks(text: &str, range: Range<usize>) -> Vec<usize> {
+    text[range.clone()]
+        .match_indices('\n')
+        .map(|(index, _)| range.start + index)
+        .collect()
+}
+
+fn empty_line(start: usize, top: f32, height: f32) -> TextLine {
+    TextLine {
+        byte_range: start..start,
+        rect: Rect::new(0.0, top, 0.0, top + height),
+        clusters: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Rect, Vec2};
+
+    use super::{compute_lines, extends_grapheme, lines_to_text_space, LayoutGlyph};
+    use crate::{TextLayoutInfo, YAxisOrientation};
+
+    const ADVANCE: f32 = 10.0;
+    const ASCENT: f32 = 16.0;
+    const DESCENT: f32 = -4.0;
+    const LINE_HEIGHT: f32 = ASCENT - DESCENT;
+
+    /// Lays out `sections` in a monospace font, wrapping after `wrap` characters.
+    fn monospace(sections: &[&str], wrap: usize) -> TextLayoutInfo {
+        let mut glyphs = Vec::new();
+        let (mut line, mut column) = (0, 0);
+        for (section_index, section) in sections.iter().enumerate() {
+            for (byte_index, c) in section.char_indices() {
+                if c == '\n' {
+                    (line, column) = (line + 1, 0);
+                    continue;
+                }
+                if column == wrap && !extends_grapheme(c) {
+                    (line, column) = (line + 1, 0);
+                }
+                glyphs.push(LayoutGlyph {
+                    section_index,
+                    byte_index,
+                    position: Vec2::new(
+                        column as f32 * ADVANCE,
+                        line as f32 * LINE_HEIGHT + ASCENT,
+                    ),
+                    advance: if extends_grapheme(c) { 0.0 } else { ADVANCE },
+                    ascent: ASCENT,
+                    descent: DESCENT,
+                });
+                if !extends_grapheme(c) {
+                    column += 1;
+                }
+            }
+        }
+        TextLayoutInfo {
+            lines: compute_lines(sections, &glyphs, |_| LINE_HEIGHT),
+            ..Default::default()
+        }
+    }
+
+    fn caret(x: f32, line: usize) -> Rect {
+        let top = line as f32 * LINE_HEIGHT;
+        Rect::new(x, top, x, top + LINE_HEIGHT)
+    }
+
+    #[test]
+    fn caret_at_wrapped_line_boundaries() {
+        let layout = monospace(&["hello world"], 6);
+        let byte_ranges: Vec<_> = layout
+            .lines
+            .iter()
+            .map(|line| line.byte_range.clone())
+            .collect();
+        assert_eq!(byte_ranges, vec![0..6, 6..11]);
+
+        assert_eq!(layout.caret_position(0), caret(0.0, 0));
+        assert_eq!(layout.caret_position(5), caret(50.0, 0));
+        // the caret after the space at the end of a wrapped line is at the start of the next line
+        assert_eq!(layout.caret_position(6), caret(0.0, 1));
+        assert_eq!(layout.caret_position(8), caret(20.0, 1));
+        assert_eq!(layout.caret_position(11), caret(50.0, 1));
+        assert_eq!(layout.caret_position(100), caret(50.0, 1));
+
+        assert_eq!(layout.hit_test(Vec2::new(-10.0, -10.0)), 0);
+        assert_eq!(layout.hit_test(Vec2::new(12.0, 5.0)), 1);
+        assert_eq!(layout.hit_test(Vec2::new(16.0, 5.0)), 2);
+        assert_eq!(layout.hit_test(Vec2::new(200.0, 10.0)), 5);
+        assert_eq!(layout.hit_test(Vec2::new(24.0, 30.0)), 8);
+        assert_eq!(layout.hit_test(Vec2::new(200.0, 100.0)), 11);
+    }
+
+    #[test]
+    fn caret_on_empty_lines() {
+        let layout = monospace(&["ab\n\ncd\n"], 80);
+        let byte_ranges: Vec<_> = layout
+            .lines
+            .iter()
+            .map(|line| line.byte_range.clone())
+            .collect();
+        assert_eq!(byte_ranges, vec![0..3, 3..4, 4..7, 7..7]);
+
+        assert_eq!(layout.caret_position(2), caret(20.0, 0));
+        assert_eq!(layout.caret_position(3), caret(0.0, 1));
+        assert_eq!(layout.caret_position(4), caret(0.0, 2));
+        assert_eq!(layout.caret_position(6), caret(20.0, 2));
+        assert_eq!(layout.caret_position(7), caret(0.0, 3));
+
+        assert_eq!(layout.hit_test(Vec2::new(200.0, 10.0)), 2);
+        assert_eq!(layout.hit_test(Vec2::new(200.0, 30.0)), 3);
+        assert_eq!(layout.hit_test(Vec2::new(200.0, 70.0)), 7);
+
+        let layout = monospace(&["\nab"], 80);
+        let byte_ranges: Vec<_> = layout
+            .lines
+            .iter()
+            .map(|line| line.byte_range.clone())
+            .collect();
+        assert_eq!(byte_ranges, vec![0..1, 1..3]);
+        assert_eq!(layout.caret_position(0), caret(0.0, 0));
+        assert_eq!(layout.caret_position(1), caret(0.0, 1));
+    }
+
+    #[test]
+    fn caret_across_sections_and_grapheme_clusters() {
+        // "e" with a combining acute accent, followed by a woman technologist emoji sequence
+        let layout = monospace(&["ae\u{301}", "\u{1F469}\u{200D}\u{1F4BB}b"], 80);
+        let clusters: Vec<_> = layout.lines[0]
+            .clusters
+            .iter()
+            .map(|cluster| cluster.byte_range.clone())
+            .collect();
+        assert_eq!(clusters, vec![0..1, 1..4, 4..15, 15..16]);
+
+        assert_eq!(layout.caret_position(1), caret(10.0, 0));
+        // offsets inside a cluster place the caret before it
+        assert_eq!(layout.caret_position(2), caret(10.0, 0));
+        assert_eq!(layout.caret_position(4), caret(20.0, 0));
+        assert_eq!(layout.caret_position(8), caret(20.0, 0));
+        assert_eq!(layout.caret_position(15), caret(40.0, 0));
+        assert_eq!(layout.caret_position(16), caret(50.0, 0));
+
+        assert_eq!(layout.hit_test(Vec2::new(19.0, 5.0)), 4);
+        assert_eq!(layout.hit_test(Vec2::new(31.0, 5.0)), 15);
+    }
+
+    #[test]
+    fn selection_rects_per_line() {
+        let layout = monospace(&["hello world"], 6);
+        assert_eq!(
+            layout.selection_rects(3..8),
+            vec![
+                Rect::new(30.0, 0.0, 60.0, LINE_HEIGHT),
+                Rect::new(0.0, LINE_HEIGHT, 20.0, 2.0 * LINE_HEIGHT)
+            ]
+        );
+        assert_eq!(
+            layout.selection_rects(7..100),
+            vec![Rect::new(10.0, LINE_HEIGHT, 50.0, 2.0 * LINE_HEIGHT)]
+        );
+        assert!(layout.selection_rects(4..4).is_empty());
+
+        let layout = monospace(&["ab\n\ncd"], 80);
+        assert_eq!(
+            layout.selection_rects(1..5),
+            vec![
+                Rect::new(10.0, 0.0, 20.0, LINE_HEIGHT),
+                Rect::new(0.0, 2.0 * LINE_HEIGHT, 10.0, 3.0 * LINE_HEIGHT)
+            ]
+        );
+    }
+
+    #[test]
+    fn bottom_to_top_text_space() {
+        let mut layout = monospace(&["ab\ncd"], 80);
+        lines_to_text_space(
+            &mut layout.lines,
+            Vec2::new(-5.0, 0.0),
+            2.0 * LINE_HEIGHT,
+            &YAxisOrientation::BottomToTop,
+        );
+        assert_eq!(
+            layout.caret_position(1),
+            Rect::new(15.0, LINE_HEIGHT, 15.0, 2.0 * LINE_HEIGHT)
+        );
+        assert_eq!(
+            layout.caret_position(4),
+            Rect::new(15.0, 0.0, 15.0, LINE_HEIGHT)
+        );
+        assert_eq!(layout.hit_test(Vec2::new(100.0, 5.0)), 5);
+    }
+}
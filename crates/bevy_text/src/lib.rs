@@ -1,3 +1,4 @@
+mod cursor;
 mod error;
 mod font;
 mod font_atlas;
@@ -8,6 +9,7 @@ mod pipeline;
 mod text;
 mod text2d;
 
+pub use cursor::*;
 pub use error::*;
 pub use font::*;
 pub use font_atlas::*;
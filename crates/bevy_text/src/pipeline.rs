@@ -10,8 +10,11 @@ use bevy_utils::HashMap;
 use glyph_brush_layout::{FontId, SectionText};
 
 use crate::{
-    error::TextError, glyph_brush::GlyphBrush, scale_value, BreakLineOn, Font, FontAtlasSet,
-    FontAtlasWarning, PositionedGlyph, TextAlignment, TextSection, TextSettings, YAxisOrientation,
+    cursor::{compute_lines, lines_to_text_space, LayoutGlyph, TextLine},
+    error::TextError,
+    glyph_brush::GlyphBrush,
+    scale_value, BreakLineOn, Font, FontAtlasSet, FontAtlasWarning, PositionedGlyph, TextAlignment,
+    TextSection, TextSettings, YAxisOrientation,
 };
 
 #[derive(Default, Resource)]
@@ -27,6 +30,11 @@ pub struct TextPipeline {
 pub struct TextLayoutInfo {
     pub glyphs: Vec<PositionedGlyph>,
     pub size: Vec2,
+    /// The laid out lines, used to place a text cursor.
+    ///
+    /// See [`TextLayoutInfo::caret_position`], [`TextLayoutInfo::hit_test`] and
+    /// [`TextLayoutInfo::selection_rects`].
+    pub lines: Vec<TextLine>,
 }
 
 impl TextPipeline {
@@ -100,6 +108,33 @@ impl TextPipeline {
 
         let size = Vec2::new(max_x - min_x, max_y - min_y);
 
+        let layout_glyphs: Vec<_> = section_glyphs
+            .iter()
+            .map(|sg| {
+                let scaled_font = scaled_fonts[sg.section_index];
+                LayoutGlyph {
+                    section_index: sg.section_index,
+                    byte_index: sg.byte_index,
+                    position: Vec2::new(sg.glyph.position.x, sg.glyph.position.y),
+                    advance: scaled_font.h_advance(sg.glyph.id),
+                    ascent: scaled_font.ascent(),
+                    descent: scaled_font.descent(),
+                }
+            })
+            .collect();
+        let section_texts: Vec<_> = sections.iter().map(|section| section.text).collect();
+        let mut lines = compute_lines(&section_texts, &layout_glyphs, |section_index| {
+            let scaled_font = scaled_fonts[section_index];
+            scaled_font.height() + scaled_font.line_gap()
+        });
+        // `process_glyphs` positions glyphs relative to the floored bounds.
+        lines_to_text_space(
+            &mut lines,
+            Vec2::new(min_x.floor(), min_y.floor()),
+            max_y.floor(),
+            &y_axis_orientation,
+        );
+
         let glyphs = self.brush.process_glyphs(
             section_glyphs,
             &sections,
@@ -112,6 +147,10 @@ impl TextPipeline {
             y_axis_orientation,
         )?;
 
-        Ok(TextLayoutInfo { glyphs, size })
+        Ok(TextLayoutInfo {
+            glyphs,
+            size,
+            lines,
+        })
     }
 }
@@ -17,7 +17,7 @@ use bevy_render::{
     view::{ComputedVisibility, Visibility},
     Extract,
 };
-use bevy_sprite::{Anchor, ExtractedSprite, ExtractedSprites, TextureAtlas};
+use bevy_sprite::{Anchor, DrawOrder2d, ExtractedSprite, ExtractedSprites, TextureAtlas};
 use bevy_transform::prelude::{GlobalTransform, Transform};
 use bevy_utils::HashSet;
 use bevy_window::{PrimaryWindow, Window, WindowScaleFactorChanged};
@@ -86,6 +86,7 @@ pub fn extract_text2d_sprite(
             &TextLayoutInfo,
             &Anchor,
             &GlobalTransform,
+            Option<&DrawOrder2d>,
         )>,
     >,
 ) {
@@ -96,8 +97,15 @@ pub fn extract_text2d_sprite(
         .unwrap_or(1.0);
     let scaling = GlobalTransform::from_scale(Vec3::splat(scale_factor.recip()));
 
-    for (entity, computed_visibility, text, text_layout_info, anchor, global_transform) in
-        text2d_query.iter()
+    for (
+        entity,
+        computed_visibility,
+        text,
+        text_layout_info,
+        anchor,
+        global_transform,
+        draw_order,
+    ) in text2d_query.iter()
     {
         if !computed_visibility.is_visible() {
             continue;
@@ -133,6 +141,7 @@ pub fn extract_text2d_sprite(
                 flip_x: false,
                 flip_y: false,
                 anchor: Anchor::Center.as_vec(),
+                draw_order: draw_order.map(|draw_order| draw_order.0),
             });
         }
     }
@@ -97,10 +97,11 @@ impl GlobalTransform {
     /// Returns the transformation as a [`Transform`].
     ///
     /// The transform is expected to be non-degenerate and without shearing, or the output
-    /// will be invalid.
+    /// will be invalid. The first shear, which [`Transform`] can't represent, is logged as a
+    /// warning in debug builds.
     #[inline]
     pub fn compute_transform(&self) -> Transform {
-        let (scale, rotation, translation) = self.0.to_scale_rotation_translation();
+        let (scale, rotation, translation) = decompose(&self.0);
         Transform {
             translation,
             rotation,
@@ -139,12 +140,14 @@ impl GlobalTransform {
     /// }
     /// ```
     ///
-    /// The transform is expected to be non-degenerate and without shearing, or the output
-    /// will be invalid.
+    /// The relative transform is expected to be non-degenerate and without shearing, or the
+    /// output will be invalid. This is the case when the parent has a uniform scale, but a
+    /// parent with a non-uniform scale shears the children rotated relatively to it. The first
+    /// such shear is logged as a warning in debug builds.
     #[inline]
     pub fn reparented_to(&self, parent: &GlobalTransform) -> Transform {
         let relative_affine = parent.affine().inverse() * self.affine();
-        let (scale, rotation, translation) = relative_affine.to_scale_rotation_translation();
+        let (scale, rotation, translation) = decompose(&relative_affine);
         Transform {
             translation,
             rotation,
@@ -155,10 +158,24 @@ impl GlobalTransform {
     /// Extracts `scale`, `rotation` and `translation` from `self`.
     ///
     /// The transform is expected to be non-degenerate and without shearing, or the output
-    /// will be invalid.
+    /// will be invalid. The first shear is logged as a warning in debug builds.
     #[inline]
     pub fn to_scale_rotation_translation(&self) -> (Vec3, Quat, Vec3) {
-        self.0.to_scale_rotation_translation()
+        decompose(&self.0)
+    }
+
+    /// Returns the rotation and translation of `self` as a [`Transform`] with a scale of one,
+    /// dropping its scale.
+    ///
+    /// This is the rigid part of the transformation, for example to place an entity at the
+    /// position and orientation of another without inheriting its size.
+    ///
+    /// The transform is expected to be non-degenerate and without shearing, or the output
+    /// will be invalid. The first shear is logged as a warning in debug builds.
+    #[inline]
+    pub fn to_isometry(&self) -> Transform {
+        let (_, rotation, translation) = decompose(&self.0);
+        Transform::from_rotation(rotation).with_translation(translation)
     }
 
     impl_local_axis!(right, left, X);
@@ -185,7 +202,7 @@ impl GlobalTransform {
 
     /// Transforms the given `point`, applying shear, scale, rotation and translation.
     ///
-    /// This moves `point` into the local space of this [`GlobalTransform`].
+    /// This moves `point` from the local space of this [`GlobalTransform`] to the global space.
     #[inline]
     pub fn transform_point(&self, point: Vec3) -> Vec3 {
         self.0.transform_point3(point)
@@ -199,6 +216,42 @@ impl GlobalTransform {
     }
 }
 
+/// Decomposes `affine` into its scale, rotation and translation, warning in debug builds the
+/// first time it has a shear they can't represent.
+#[inline]
+fn decompose(affine: &Affine3A) -> (Vec3, Quat, Vec3) {
+    let (scale, rotation, translation) = affine.to_scale_rotation_translation();
+    #[cfg(debug_assertions)]
+    {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        // A sheared transform is usually decomposed every frame, so only the first one is logged
+        static WARNED: AtomicBool = AtomicBool::new(false);
+        if !WARNED.load(Ordering::Relaxed)
+            && is_sheared(affine.matrix3, scale, rotation)
+            && !WARNED.swap(true, Ordering::Relaxed)
+        {
+            bevy_utils::tracing::warn!(
+                "The affine transformation {:?} has a shear or is degenerate, which is lost when \
+                decomposing it into the scale {} and rotation {:?}. Further shears won't be logged.",
+                affine.matrix3,
+                scale,
+                rotation,
+            );
+        }
+    }
+    (scale, rotation, translation)
+}
+
+/// Returns whether `matrix3` differs from the `rotation` and `scale` it was decomposed into,
+/// because of a shear.
+#[cfg(any(debug_assertions, test))]
+fn is_sheared(matrix3: bevy_math::Mat3A, scale: Vec3, rotation: Quat) -> bool {
+    let recomposed = bevy_math::Mat3A::from_quat(rotation) * bevy_math::Mat3A::from_diagonal(scale);
+    let tolerance = 1e-4 * scale.abs().max_element();
+    !recomposed.abs_diff_eq(matrix3, tolerance)
+}
+
 impl Default for GlobalTransform {
     fn default() -> Self {
         Self::IDENTITY
@@ -305,4 +358,92 @@ mod test {
             t1_prime.compute_transform(),
         );
     }
+
+    /// A xorshift generator, for the random tests to be reproducible.
+    struct Rng(u32);
+
+    impl Rng {
+        fn range(&mut self, min: f32, max: f32) -> f32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            let unit = (self.0 >> 8) as f32 / (1 << 24) as f32;
+            min + unit * (max - min)
+        }
+
+        fn vec3(&mut self, min: f32, max: f32) -> Vec3 {
+            Vec3::new(
+                self.range(min, max),
+                self.range(min, max),
+                self.range(min, max),
+            )
+        }
+
+        fn transform(&mut self, scale: Vec3) -> Transform {
+            let angles = self.vec3(-3.0, 3.0);
+            Transform {
+                translation: self.vec3(-100.0, 100.0),
+                rotation: Quat::from_euler(XYZ, angles.x, angles.y, angles.z),
+                scale,
+            }
+        }
+    }
+
+    #[test]
+    fn reparented_to_random_transforms() {
+        let mut rng = Rng(0x2545_f491);
+        for _ in 0..1000 {
+            // a uniformly scaled parent doesn't shear its children
+            let parent_scale = Vec3::splat(rng.range(0.1, 10.0));
+            let parent = GlobalTransform::from(rng.transform(parent_scale));
+            let child_scale = rng.vec3(0.1, 10.0);
+            let child = GlobalTransform::from(rng.transform(child_scale));
+
+            let reparented = child.reparented_to(&parent);
+            let relative = parent.affine().inverse() * child.affine();
+            assert!(!is_sheared(
+                relative.matrix3,
+                reparented.scale,
+                reparented.rotation
+            ));
+            let recomposed = parent.mul_transform(reparented);
+            assert!(
+                recomposed.affine().abs_diff_eq(child.affine(), 1e-2),
+                "child:{:#?} recomposed:{:#?}",
+                child.compute_transform(),
+                recomposed.compute_transform(),
+            );
+        }
+    }
+
+    #[test]
+    fn shears_are_detected() {
+        let transform = GlobalTransform::from(Transform {
+            translation: Vec3::ONE,
+            rotation: Quat::from_euler(XYZ, 0.3, 1.2, -0.7),
+            scale: Vec3::new(0.5, 2.0, 8.0),
+        });
+        let (scale, rotation, _) = transform.to_scale_rotation_translation();
+        assert!(!is_sheared(transform.affine().matrix3, scale, rotation));
+
+        // rotating a child of a non-uniformly scaled parent shears it
+        let parent = GlobalTransform::from_scale(Vec3::new(1.0, 3.0, 1.0));
+        let sheared = parent * Transform::from_rotation(Quat::from_rotation_z(0.5));
+        let (scale, rotation, _) = sheared.to_scale_rotation_translation();
+        assert!(is_sheared(sheared.affine().matrix3, scale, rotation));
+    }
+
+    #[test]
+    fn to_isometry_drops_scale() {
+        let rotation = Quat::from_euler(XYZ, 0.3, 1.2, -0.7);
+        let transform = GlobalTransform::from(Transform {
+            translation: Vec3::new(1.0, -2.0, 3.0),
+            rotation,
+            scale: Vec3::new(0.5, 2.0, 8.0),
+        });
+        let isometry = transform.to_isometry();
+        assert_eq!(isometry.translation, Vec3::new(1.0, -2.0, 3.0));
+        assert!(isometry.rotation.abs_diff_eq(rotation, 1e-5));
+        assert_eq!(isometry.scale, Vec3::ONE);
+    }
 }
@@ -1,6 +1,6 @@
 use super::GlobalTransform;
 use bevy_ecs::{component::Component, reflect::ReflectComponent};
-use bevy_math::{Affine3A, Mat3, Mat4, Quat, Vec3};
+use bevy_math::{Affine3A, Dir3, Mat3, Mat4, Quat, Vec3};
 use bevy_reflect::prelude::*;
 use bevy_reflect::Reflect;
 use std::ops::Mul;
@@ -120,18 +120,22 @@ impl Transform {
 
     /// Returns this [`Transform`] with a new rotation so that [`Transform::forward`]
     /// points towards the `target` position and [`Transform::up`] points towards `up`.
+    ///
+    /// See [`Transform::look_at`] for how degenerate inputs are handled.
     #[inline]
     #[must_use]
-    pub fn looking_at(mut self, target: Vec3, up: Vec3) -> Self {
+    pub fn looking_at(mut self, target: Vec3, up: impl TryInto<Dir3>) -> Self {
         self.look_at(target, up);
         self
     }
 
     /// Returns this [`Transform`] with a new rotation so that [`Transform::forward`]
     /// points in the given `direction` and [`Transform::up`] points towards `up`.
+    ///
+    /// See [`Transform::look_to`] for how degenerate inputs are handled.
     #[inline]
     #[must_use]
-    pub fn looking_to(mut self, direction: Vec3, up: Vec3) -> Self {
+    pub fn looking_to(mut self, direction: impl TryInto<Dir3>, up: impl TryInto<Dir3>) -> Self {
         self.look_to(direction, up);
         self
     }
@@ -325,19 +329,33 @@ impl Transform {
 
     /// Rotates this [`Transform`] so that [`Transform::forward`] points towards the `target` position,
     /// and [`Transform::up`] points towards `up`.
+    ///
+    /// In some cases it's not possible to construct a rotation. Another axis will be picked in those cases:
+    /// * if `target` is the same as the transform translation, `Dir3::NEG_Z` is used as the direction
+    /// * if `up` fails converting to [`Dir3`] (e.g if it is `Vec3::ZERO`), `Dir3::Y` is used instead
+    /// * if the resulting forward direction is parallel with `up`, an orthogonal vector is used as the "right" direction
     #[inline]
-    pub fn look_at(&mut self, target: Vec3, up: Vec3) {
+    pub fn look_at(&mut self, target: Vec3, up: impl TryInto<Dir3>) {
         self.look_to(target - self.translation, up);
     }
 
     /// Rotates this [`Transform`] so that [`Transform::forward`] points in the given `direction`
     /// and [`Transform::up`] points towards `up`.
-    #[inline]
-    pub fn look_to(&mut self, direction: Vec3, up: Vec3) {
-        let forward = -direction.normalize();
-        let right = up.cross(forward).normalize();
-        let up = forward.cross(right);
-        self.rotation = Quat::from_mat3(&Mat3::from_cols(right, up, forward));
+    ///
+    /// In some cases it's not possible to construct a rotation. Another axis will be picked in those cases:
+    /// * if `direction` fails converting to [`Dir3`] (e.g if it is `Vec3::ZERO`), `Dir3::NEG_Z` is used instead
+    /// * if `up` fails converting to [`Dir3`], `Dir3::Y` is used instead
+    /// * if `direction` is parallel with `up`, an orthogonal vector is used as the "right" direction
+    #[inline]
+    pub fn look_to(&mut self, direction: impl TryInto<Dir3>, up: impl TryInto<Dir3>) {
+        let back = -direction.try_into().unwrap_or(Dir3::NEG_Z);
+        let up = up.try_into().unwrap_or(Dir3::Y);
+        let right = up
+            .cross(*back)
+            .try_normalize()
+            .unwrap_or_else(|| up.any_orthonormal_vector());
+        let up = back.cross(right);
+        self.rotation = Quat::from_mat3(&Mat3::from_cols(right, up, *back));
     }
 
     /// Multiplies `self` with `transform` component by component, returning the
@@ -403,3 +421,40 @@ impl Mul<Vec3> for Transform {
         self.transform_point(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn look_to() {
+        let transform = Transform::IDENTITY.looking_to(Vec3::X * 3.0, Vec3::Y);
+        assert!(transform.forward().abs_diff_eq(Vec3::X, 1e-6));
+        assert!(transform.up().abs_diff_eq(Vec3::Y, 1e-6));
+
+        let transform = Transform::IDENTITY.looking_to(Dir3::NEG_Y, Dir3::X);
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_Y, 1e-6));
+        assert!(transform.up().abs_diff_eq(Vec3::X, 1e-6));
+    }
+
+    #[test]
+    fn look_to_degenerate() {
+        // A zero direction falls back to looking down the negative Z axis
+        let transform = Transform::IDENTITY.looking_to(Vec3::ZERO, Vec3::Y);
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_Z, 1e-6));
+
+        // A zero up vector falls back to the Y axis
+        let transform = Transform::IDENTITY.looking_to(Vec3::X, Vec3::ZERO);
+        assert!(transform.up().abs_diff_eq(Vec3::Y, 1e-6));
+
+        // A direction parallel to up still produces a valid rotation
+        let transform = Transform::IDENTITY.looking_to(Vec3::Y, Vec3::Y);
+        assert!(transform.rotation.is_normalized());
+        assert!(transform.forward().abs_diff_eq(Vec3::Y, 1e-6));
+
+        // Looking at the translation itself
+        let transform =
+            Transform::from_xyz(1.0, 2.0, 3.0).looking_at(Vec3::new(1.0, 2.0, 3.0), Vec3::Y);
+        assert!(!transform.rotation.is_nan());
+    }
+}
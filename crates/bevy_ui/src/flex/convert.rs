@@ -1,126 +1,251 @@
-use taffy::style::LengthPercentageAuto;
+use taffy::{
+    style::{Dimension, LengthPercentage, LengthPercentageAuto},
+    style_helpers,
+};
 
 use crate::{
-    AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, JustifyContent,
-    PositionType, Size, Style, UiRect, Val,
+    AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
+    GridPlacement, GridTrack, GridTrackRepetition, JustifyContent, MaxTrackSizingFunction,
+    MinTrackSizingFunction, PositionType, RepeatedGridTrack, Size, Style, UiRect, Val,
 };
 
+use super::LayoutContext;
+
 impl Val {
-    fn scaled(self, scale_factor: f64) -> Self {
+    fn into_length_percentage_auto(self, context: &LayoutContext) -> LengthPercentageAuto {
         match self {
-            Val::Auto => Val::Auto,
-            Val::Percent(value) => Val::Percent(value),
-            Val::Px(value) => Val::Px((scale_factor * value as f64) as f32),
+            Val::Auto => LengthPercentageAuto::Auto,
+            Val::Percent(value) => LengthPercentageAuto::Percent(value / 100.),
+            Val::Px(value) => {
+                LengthPercentageAuto::Points((context.scale_factor * value as f64) as f32)
+            }
+            Val::Vw(value) => LengthPercentageAuto::Points(context.physical_size.x * value / 100.),
+            Val::Vh(value) => LengthPercentageAuto::Points(context.physical_size.y * value / 100.),
+            Val::VMin(value) => LengthPercentageAuto::Points(context.min_size * value / 100.),
+            Val::VMax(value) => LengthPercentageAuto::Points(context.max_size * value / 100.),
         }
     }
 
-    fn to_inset(self) -> LengthPercentageAuto {
-        match self {
-            Val::Auto => taffy::style::LengthPercentageAuto::Auto,
-            Val::Percent(value) => taffy::style::LengthPercentageAuto::Percent(value / 100.0),
-            Val::Px(value) => taffy::style::LengthPercentageAuto::Points(value),
+    fn into_length_percentage(self, context: &LayoutContext) -> LengthPercentage {
+        match self.into_length_percentage_auto(context) {
+            LengthPercentageAuto::Auto => LengthPercentage::Points(0.0),
+            LengthPercentageAuto::Percent(value) => LengthPercentage::Percent(value),
+            LengthPercentageAuto::Points(value) => LengthPercentage::Points(value),
+        }
+    }
+
+    fn into_dimension(self, context: &LayoutContext) -> Dimension {
+        match self.into_length_percentage_auto(context) {
+            LengthPercentageAuto::Auto => Dimension::Auto,
+            LengthPercentageAuto::Percent(value) => Dimension::Percent(value),
+            LengthPercentageAuto::Points(value) => Dimension::Points(value),
         }
     }
 }
 
 impl UiRect {
-    fn scaled(self, scale_factor: f64) -> Self {
-        Self {
-            left: self.left.scaled(scale_factor),
-            right: self.right.scaled(scale_factor),
-            top: self.top.scaled(scale_factor),
-            bottom: self.bottom.scaled(scale_factor),
+    fn map_to_taffy_rect<T>(self, map_fn: impl Fn(Val) -> T) -> taffy::geometry::Rect<T> {
+        taffy::geometry::Rect {
+            left: map_fn(self.left),
+            right: map_fn(self.right),
+            top: map_fn(self.top),
+            bottom: map_fn(self.bottom),
         }
     }
 }
 
 impl Size {
-    fn scaled(self, scale_factor: f64) -> Self {
-        Self {
-            width: self.width.scaled(scale_factor),
-            height: self.height.scaled(scale_factor),
+    fn map_to_taffy_size<T>(self, map_fn: impl Fn(Val) -> T) -> taffy::geometry::Size<T> {
+        taffy::geometry::Size {
+            width: map_fn(self.width),
+            height: map_fn(self.height),
         }
     }
 }
 
-impl<T: From<Val>> From<UiRect> for taffy::prelude::Rect<T> {
-    fn from(value: UiRect) -> Self {
-        Self {
-            left: value.left.into(),
-            right: value.right.into(),
-            top: value.top.into(),
-            bottom: value.bottom.into(),
+pub fn from_style(context: &LayoutContext, style: &Style) -> taffy::style::Style {
+    taffy::style::Style {
+        display: style.display.into(),
+        position: style.position_type.into(),
+        flex_direction: style.flex_direction.into(),
+        flex_wrap: style.flex_wrap.into(),
+        align_items: Some(style.align_items.into()),
+        align_self: style.align_self.into(),
+        align_content: Some(style.align_content.into()),
+        justify_content: Some(style.justify_content.into()),
+        inset: taffy::prelude::Rect {
+            left: style.left.into_length_percentage_auto(context),
+            right: style.right.into_length_percentage_auto(context),
+            top: style.top.into_length_percentage_auto(context),
+            bottom: style.bottom.into_length_percentage_auto(context),
+        },
+        margin: style
+            .margin
+            .map_to_taffy_rect(|m| m.into_length_percentage_auto(context)),
+        padding: style
+            .padding
+            .map_to_taffy_rect(|m| m.into_length_percentage(context)),
+        border: style
+            .border
+            .map_to_taffy_rect(|m| m.into_length_percentage(context)),
+        flex_grow: style.flex_grow,
+        flex_shrink: style.flex_shrink,
+        flex_basis: style.flex_basis.into_dimension(context),
+        size: style.size.map_to_taffy_size(|s| s.into_dimension(context)),
+        min_size: style
+            .min_size
+            .map_to_taffy_size(|s| s.into_dimension(context)),
+        max_size: style
+            .max_size
+            .map_to_taffy_size(|s| s.into_dimension(context)),
+        aspect_ratio: style.aspect_ratio,
+        gap: style
+            .gap
+            .map_to_taffy_size(|s| s.into_length_percentage(context)),
+        justify_self: None,
+        justify_items: None,
+        grid_auto_flow: style.grid_auto_flow.into(),
+        grid_template_rows: style
+            .grid_template_rows
+            .iter()
+            .map(|track| track.into_repeated_taffy_track(context))
+            .collect(),
+        grid_template_columns: style
+            .grid_template_columns
+            .iter()
+            .map(|track| track.into_repeated_taffy_track(context))
+            .collect(),
+        grid_auto_rows: style
+            .grid_auto_rows
+            .iter()
+            .map(|track| track.into_taffy_track(context))
+            .collect(),
+        grid_auto_columns: style
+            .grid_auto_columns
+            .iter()
+            .map(|track| track.into_taffy_track(context))
+            .collect(),
+        grid_row: style.grid_row.into(),
+        grid_column: style.grid_column.into(),
+    }
+}
+
+impl MinTrackSizingFunction {
+    fn into_taffy(self, context: &LayoutContext) -> taffy::style::MinTrackSizingFunction {
+        match self {
+            MinTrackSizingFunction::Px(value) => taffy::style::MinTrackSizingFunction::Fixed(
+                Val::Px(value).into_length_percentage(context),
+            ),
+            MinTrackSizingFunction::Percent(value) => taffy::style::MinTrackSizingFunction::Fixed(
+                Val::Percent(value).into_length_percentage(context),
+            ),
+            MinTrackSizingFunction::MinContent => taffy::style::MinTrackSizingFunction::MinContent,
+            MinTrackSizingFunction::MaxContent => taffy::style::MinTrackSizingFunction::MaxContent,
+            MinTrackSizingFunction::Auto => taffy::style::MinTrackSizingFunction::Auto,
         }
     }
 }
 
-impl<T: From<Val>> From<Size> for taffy::prelude::Size<T> {
-    fn from(value: Size) -> Self {
-        Self {
-            width: value.width.into(),
-            height: value.height.into(),
+impl MaxTrackSizingFunction {
+    fn into_taffy(self, context: &LayoutContext) -> taffy::style::MaxTrackSizingFunction {
+        match self {
+            MaxTrackSizingFunction::Px(value) => taffy::style::MaxTrackSizingFunction::Fixed(
+                Val::Px(value).into_length_percentage(context),
+            ),
+            MaxTrackSizingFunction::Percent(value) => taffy::style::MaxTrackSizingFunction::Fixed(
+                Val::Percent(value).into_length_percentage(context),
+            ),
+            MaxTrackSizingFunction::MinContent => taffy::style::MaxTrackSizingFunction::MinContent,
+            MaxTrackSizingFunction::MaxContent => taffy::style::MaxTrackSizingFunction::MaxContent,
+            MaxTrackSizingFunction::FitContentPx(value) => {
+                taffy::style::MaxTrackSizingFunction::FitContent(
+                    Val::Px(value).into_length_percentage(context),
+                )
+            }
+            MaxTrackSizingFunction::FitContentPercent(value) => {
+                taffy::style::MaxTrackSizingFunction::FitContent(
+                    Val::Percent(value).into_length_percentage(context),
+                )
+            }
+            MaxTrackSizingFunction::Auto => taffy::style::MaxTrackSizingFunction::Auto,
+            MaxTrackSizingFunction::Fraction(fraction) => {
+                taffy::style::MaxTrackSizingFunction::Fraction(fraction)
+            }
         }
     }
 }
 
-impl From<Val> for taffy::style::Dimension {
-    fn from(value: Val) -> Self {
-        match value {
-            Val::Auto => taffy::style::Dimension::Auto,
-            Val::Percent(value) => taffy::style::Dimension::Percent(value / 100.0),
-            Val::Px(value) => taffy::style::Dimension::Points(value),
+impl GridTrack {
+    fn into_taffy_track(
+        self,
+        context: &LayoutContext,
+    ) -> taffy::style::NonRepeatedTrackSizingFunction {
+        taffy::geometry::MinMax {
+            min: self.min_sizing_function.into_taffy(context),
+            max: self.max_sizing_function.into_taffy(context),
         }
     }
 }
 
-impl From<Val> for taffy::style::LengthPercentage {
-    fn from(value: Val) -> Self {
-        match value {
-            Val::Auto => taffy::style::LengthPercentage::Points(0.0),
-            Val::Percent(value) => taffy::style::LengthPercentage::Percent(value / 100.0),
-            Val::Px(value) => taffy::style::LengthPercentage::Points(value),
+impl RepeatedGridTrack {
+    fn into_repeated_taffy_track(
+        &self,
+        context: &LayoutContext,
+    ) -> taffy::style::TrackSizingFunction {
+        match self.repetition {
+            GridTrackRepetition::Count(1) if self.tracks.len() == 1 => {
+                taffy::style::TrackSizingFunction::Single(self.tracks[0].into_taffy_track(context))
+            }
+            repetition => taffy::style::TrackSizingFunction::Repeat(
+                repetition.into(),
+                self.tracks
+                    .iter()
+                    .map(|track| track.into_taffy_track(context))
+                    .collect(),
+            ),
         }
     }
 }
 
-impl From<Val> for taffy::style::LengthPercentageAuto {
-    fn from(value: Val) -> Self {
+impl From<GridTrackRepetition> for taffy::style::GridTrackRepetition {
+    fn from(value: GridTrackRepetition) -> Self {
         match value {
-            Val::Auto => taffy::style::LengthPercentageAuto::Auto,
-            Val::Percent(value) => taffy::style::LengthPercentageAuto::Percent(value / 100.0),
-            Val::Px(value) => taffy::style::LengthPercentageAuto::Points(value),
+            GridTrackRepetition::Count(count) => taffy::style::GridTrackRepetition::Count(count),
+            GridTrackRepetition::AutoFill => taffy::style::GridTrackRepetition::AutoFill,
+            GridTrackRepetition::AutoFit => taffy::style::GridTrackRepetition::AutoFit,
         }
     }
 }
 
-pub fn from_style(scale_factor: f64, style: &Style) -> taffy::style::Style {
-    taffy::style::Style {
-        display: style.display.into(),
-        position: style.position_type.into(),
-        flex_direction: style.flex_direction.into(),
-        flex_wrap: style.flex_wrap.into(),
-        align_items: Some(style.align_items.into()),
-        align_self: style.align_self.into(),
-        align_content: Some(style.align_content.into()),
-        justify_content: Some(style.justify_content.into()),
-        inset: taffy::prelude::Rect {
-            left: style.left.scaled(scale_factor).to_inset(),
-            right: style.right.scaled(scale_factor).to_inset(),
-            top: style.top.scaled(scale_factor).to_inset(),
-            bottom: style.bottom.scaled(scale_factor).to_inset(),
-        },
-        margin: style.margin.scaled(scale_factor).into(),
-        padding: style.padding.scaled(scale_factor).into(),
-        border: style.border.scaled(scale_factor).into(),
-        flex_grow: style.flex_grow,
-        flex_shrink: style.flex_shrink,
-        flex_basis: style.flex_basis.scaled(scale_factor).into(),
-        size: style.size.scaled(scale_factor).into(),
-        min_size: style.min_size.scaled(scale_factor).into(),
-        max_size: style.max_size.scaled(scale_factor).into(),
-        aspect_ratio: style.aspect_ratio,
-        gap: style.gap.scaled(scale_factor).into(),
-        justify_self: None,
+impl From<GridPlacement> for taffy::geometry::Line<taffy::style::GridPlacement> {
+    fn from(value: GridPlacement) -> Self {
+        let span = value.get_span().unwrap_or(1);
+        match (value.get_start(), value.get_end()) {
+            (Some(start), Some(end)) => taffy::geometry::Line {
+                start: style_helpers::line(start),
+                end: style_helpers::line(end),
+            },
+            (Some(start), None) => taffy::geometry::Line {
+                start: style_helpers::line(start),
+                end: style_helpers::span(span),
+            },
+            (None, Some(end)) => taffy::geometry::Line {
+                start: style_helpers::span(span),
+                end: style_helpers::line(end),
+            },
+            (None, None) => style_helpers::span(span),
+        }
+    }
+}
+
+impl From<GridAutoFlow> for taffy::style::GridAutoFlow {
+    fn from(value: GridAutoFlow) -> Self {
+        match value {
+            GridAutoFlow::Row => taffy::style::GridAutoFlow::Row,
+            GridAutoFlow::Column => taffy::style::GridAutoFlow::Column,
+            GridAutoFlow::RowDense => taffy::style::GridAutoFlow::RowDense,
+            GridAutoFlow::ColumnDense => taffy::style::GridAutoFlow::ColumnDense,
+        }
     }
 }
 
@@ -173,6 +298,7 @@ impl From<Display> for taffy::style::Display {
     fn from(value: Display) -> Self {
         match value {
             Display::Flex => taffy::style::Display::Flex,
+            Display::Grid => taffy::style::Display::Grid,
             Display::None => taffy::style::Display::None,
         }
     }
@@ -282,8 +408,28 @@ mod tests {
                 width: Val::Px(0.),
                 height: Val::Percent(0.),
             },
+            grid_auto_flow: GridAutoFlow::ColumnDense,
+            grid_template_rows: vec![
+                GridTrack::px(10.0).into(),
+                GridTrack::percent(50.0).into(),
+                GridTrack::fr(1.0).into(),
+            ],
+            grid_template_columns: vec![RepeatedGridTrack::px(5, 10.0)],
+            grid_auto_rows: vec![
+                GridTrack::fit_content_px(10.0),
+                GridTrack::fit_content_percent(25.0),
+                GridTrack::flex(2.0),
+            ],
+            grid_auto_columns: vec![
+                GridTrack::auto(),
+                GridTrack::min_content(),
+                GridTrack::max_content(),
+            ],
+            grid_column: GridPlacement::start(4),
+            grid_row: GridPlacement::span(3),
         };
-        let taffy_style = from_style(1.0, &bevy_style);
+        let context = LayoutContext::new(1.0, 1.0, bevy_math::Vec2::new(800., 600.));
+        let taffy_style = from_style(&context, &bevy_style);
         assert_eq!(taffy_style.display, taffy::style::Display::Flex);
         assert_eq!(taffy_style.position, taffy::style::Position::Absolute);
         assert!(matches!(
@@ -407,5 +553,87 @@ mod tests {
             taffy_style.gap.height,
             taffy::style::LengthPercentage::Percent(0.)
         );
+        assert_eq!(
+            taffy_style.grid_auto_flow,
+            taffy::style::GridAutoFlow::ColumnDense
+        );
+        assert_eq!(
+            taffy_style.grid_template_rows,
+            vec![
+                style_helpers::points(10.0),
+                style_helpers::percent(0.5),
+                style_helpers::minmax(
+                    taffy::style::MinTrackSizingFunction::Auto,
+                    taffy::style::MaxTrackSizingFunction::Fraction(1.0)
+                ),
+            ]
+        );
+        assert_eq!(
+            taffy_style.grid_template_columns,
+            vec![style_helpers::repeat(5, vec![style_helpers::points(10.0)])]
+        );
+        assert_eq!(
+            taffy_style.grid_auto_rows,
+            vec![
+                style_helpers::fit_content(taffy::style::LengthPercentage::Points(10.0)),
+                style_helpers::fit_content(taffy::style::LengthPercentage::Percent(0.25)),
+                style_helpers::minmax(
+                    style_helpers::points(0.0),
+                    taffy::style::MaxTrackSizingFunction::Fraction(2.0)
+                ),
+            ]
+        );
+        assert_eq!(
+            taffy_style.grid_auto_columns,
+            vec![
+                style_helpers::auto(),
+                style_helpers::min_content(),
+                style_helpers::max_content()
+            ]
+        );
+        assert_eq!(
+            taffy_style.grid_column,
+            taffy::geometry::Line {
+                start: style_helpers::line(4),
+                end: style_helpers::span(1)
+            }
+        );
+        assert_eq!(taffy_style.grid_row, style_helpers::span(3));
+    }
+
+    #[test]
+    fn test_convert_viewport_units() {
+        // A 400x200 logical viewport with a scale factor of 2
+        let context = LayoutContext::new(2.0, 1.0, bevy_math::Vec2::new(400., 200.));
+        let bevy_style = crate::Style {
+            left: Val::Vw(10.),
+            top: Val::Vh(10.),
+            size: Size::new(Val::VMin(50.), Val::VMax(50.)),
+            padding: UiRect::all(Val::Vw(1.)),
+            flex_basis: Val::Px(3.),
+            ..Default::default()
+        };
+        let taffy_style = from_style(&context, &bevy_style);
+        assert_eq!(
+            taffy_style.inset.left,
+            taffy::style::LengthPercentageAuto::Points(80.)
+        );
+        assert_eq!(
+            taffy_style.inset.top,
+            taffy::style::LengthPercentageAuto::Points(40.)
+        );
+        assert_eq!(
+            taffy_style.size.width,
+            taffy::style::Dimension::Points(200.)
+        );
+        assert_eq!(
+            taffy_style.size.height,
+            taffy::style::Dimension::Points(400.)
+        );
+        assert_eq!(
+            taffy_style.padding.left,
+            taffy::style::LengthPercentage::Points(8.)
+        );
+        assert_eq!(taffy_style.flex_basis, taffy::style::Dimension::Points(6.));
     }
 }
@@ -1,20 +1,23 @@
 mod convert;
 
-use crate::{CalculatedSize, Node, Style, UiScale};
+use crate::{
+    camera_config::DefaultUiCamera, ContentSize, Node, ScrollPosition, Style, TargetCamera, UiScale,
+};
 use bevy_ecs::{
-    change_detection::DetectChanges,
+    change_detection::{DetectChanges, Ref},
     entity::Entity,
     event::EventReader,
-    query::{Changed, ReadOnlyWorldQuery, With, Without},
+    query::{Changed, With, Without},
     removal_detection::RemovedComponents,
-    system::{Query, Res, ResMut, Resource},
+    system::{Local, Query, Res, ResMut, Resource},
 };
 use bevy_hierarchy::{Children, Parent};
 use bevy_log::warn;
 use bevy_math::Vec2;
+use bevy_render::camera::Camera;
 use bevy_transform::components::Transform;
 use bevy_utils::HashMap;
-use bevy_window::{PrimaryWindow, Window, WindowResolution, WindowScaleFactorChanged};
+use bevy_window::{PrimaryWindow, Window, WindowScaleFactorChanged};
 use std::fmt;
 use taffy::{
     prelude::{AvailableSpace, Size},
@@ -22,10 +25,40 @@ use taffy::{
     Taffy,
 };
 
+/// The values used to convert the [`Style`] of UI nodes into physical pixel values for the layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutContext {
+    /// The factor used to convert [`Val::Px`](crate::Val::Px) values into physical pixels,
+    /// including the [`UiScale`].
+    pub scale_factor: f64,
+    /// The physical size of the viewport the UI is laid out in.
+    pub physical_size: Vec2,
+    /// The smaller dimension of [`LayoutContext::physical_size`].
+    pub min_size: f32,
+    /// The larger dimension of [`LayoutContext::physical_size`].
+    pub max_size: f32,
+}
+
+impl LayoutContext {
+    /// Creates a new [`LayoutContext`] from the scale factor of the render target,
+    /// the [`UiScale`] and the logical size of the viewport.
+    ///
+    /// Viewport-relative units are not affected by the [`UiScale`].
+    pub fn new(target_scale_factor: f64, ui_scale: f64, logical_viewport_size: Vec2) -> Self {
+        let physical_size = logical_viewport_size * target_scale_factor as f32;
+        Self {
+            scale_factor: target_scale_factor * ui_scale,
+            physical_size,
+            min_size: physical_size.min_element(),
+            max_size: physical_size.max_element(),
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct FlexSurface {
     entity_to_taffy: HashMap<Entity, taffy::node::Node>,
-    window_nodes: HashMap<Entity, taffy::node::Node>,
+    camera_roots: HashMap<Entity, taffy::node::Node>,
     taffy: Taffy,
 }
 
@@ -43,7 +76,7 @@ impl fmt::Debug for FlexSurface {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("FlexSurface")
             .field("entity_to_taffy", &self.entity_to_taffy)
-            .field("window_nodes", &self.window_nodes)
+            .field("camera_roots", &self.camera_roots)
             .finish()
     }
 }
@@ -52,26 +85,24 @@ impl Default for FlexSurface {
     fn default() -> Self {
         Self {
             entity_to_taffy: Default::default(),
-            window_nodes: Default::default(),
+            camera_roots: Default::default(),
             taffy: Taffy::new(),
         }
     }
 }
 
 impl FlexSurface {
-    pub fn upsert_node(&mut self, entity: Entity, style: &Style, scale_factor: f64) {
+    pub fn upsert_node(&mut self, entity: Entity, style: &Style, context: &LayoutContext) {
         let mut added = false;
         let taffy = &mut self.taffy;
         let taffy_node = self.entity_to_taffy.entry(entity).or_insert_with(|| {
             added = true;
-            taffy
-                .new_leaf(convert::from_style(scale_factor, style))
-                .unwrap()
+            taffy.new_leaf(convert::from_style(context, style)).unwrap()
         });
 
         if !added {
             self.taffy
-                .set_style(*taffy_node, convert::from_style(scale_factor, style))
+                .set_style(*taffy_node, convert::from_style(context, style))
                 .unwrap();
         }
     }
@@ -80,49 +111,57 @@ impl FlexSurface {
         &mut self,
         entity: Entity,
         style: &Style,
-        calculated_size: CalculatedSize,
-        scale_factor: f64,
+        content_size: &ContentSize,
+        context: &LayoutContext,
     ) {
         let taffy = &mut self.taffy;
-        let taffy_style = convert::from_style(scale_factor, style);
-        let measure = taffy::node::MeasureFunc::Boxed(Box::new(
-            move |constraints: Size<Option<f32>>, _available: Size<AvailableSpace>| {
-                let mut size = Size {
-                    width: (scale_factor * calculated_size.size.x as f64) as f32,
-                    height: (scale_factor * calculated_size.size.y as f64) as f32,
-                };
-                match (constraints.width, constraints.height) {
-                    (None, None) => {}
-                    (Some(width), None) => {
-                        if calculated_size.preserve_aspect_ratio {
-                            size.height = width * size.height / size.width;
-                        }
-                        size.width = width;
-                    }
-                    (None, Some(height)) => {
-                        if calculated_size.preserve_aspect_ratio {
-                            size.width = height * size.width / size.height;
-                        }
-                        size.height = height;
+        let taffy_style = convert::from_style(context, style);
+        let measure = content_size.measure.clone().map(|measure| {
+            let scale_factor = context.scale_factor as f32;
+            let to_logical = move |available: AvailableSpace| match available {
+                AvailableSpace::Definite(value) => AvailableSpace::Definite(value / scale_factor),
+                other => other,
+            };
+            taffy::node::MeasureFunc::Boxed(Box::new(
+                move |constraints: Size<Option<f32>>, available: Size<AvailableSpace>| {
+                    // measures work in logical pixels, while taffy lays out in physical pixels
+                    let size = measure.measure(
+                        constraints.width.map(|width| width / scale_factor),
+                        constraints.height.map(|height| height / scale_factor),
+                        to_logical(available.width),
+                        to_logical(available.height),
+                    ) * scale_factor;
+                    Size {
+                        width: size.x,
+                        height: size.y,
                     }
-                    (Some(width), Some(height)) => {
-                        size.width = width;
-                        size.height = height;
-                    }
-                }
-                size
-            },
-        ));
+                },
+            ))
+        });
         if let Some(taffy_node) = self.entity_to_taffy.get(&entity) {
             self.taffy.set_style(*taffy_node, taffy_style).unwrap();
-            self.taffy.set_measure(*taffy_node, Some(measure)).unwrap();
+            self.taffy.set_measure(*taffy_node, measure).unwrap();
         } else {
-            let taffy_node = taffy.new_leaf_with_measure(taffy_style, measure).unwrap();
+            let taffy_node = match measure {
+                Some(measure) => taffy.new_leaf_with_measure(taffy_style, measure).unwrap(),
+                None => taffy.new_leaf(taffy_style).unwrap(),
+            };
             self.entity_to_taffy.insert(entity, taffy_node);
         }
     }
 
+    /// Removes the measure of `entity`, after its [`ContentSize`] was removed.
+    pub fn remove_measure(&mut self, entity: Entity) {
+        if let Some(taffy_node) = self.entity_to_taffy.get(&entity) {
+            self.taffy.set_measure(*taffy_node, None).unwrap();
+        }
+    }
+
     pub fn update_children(&mut self, entity: Entity, children: &Children) {
+        let Some(taffy_node) = self.entity_to_taffy.get(&entity) else {
+            // the node is part of a UI tree that isn't rendered to any camera
+            return;
+        };
         let mut taffy_children = Vec::with_capacity(children.len());
         for child in children {
             if let Some(taffy_node) = self.entity_to_taffy.get(child) {
@@ -135,7 +174,6 @@ without UI components as a child of an entity with UI components, results may be
             }
         }
 
-        let taffy_node = self.entity_to_taffy.get(&entity).unwrap();
         self.taffy
             .set_children(*taffy_node, &taffy_children)
             .unwrap();
@@ -148,11 +186,12 @@ without UI components as a child of an entity with UI components, results may be
         }
     }
 
-    pub fn update_window(&mut self, window: Entity, window_resolution: &WindowResolution) {
+    /// Updates the implicit root node of the UI rendered to `camera`, sizing it to the camera's physical viewport.
+    pub fn update_camera_root(&mut self, camera: Entity, physical_size: Vec2) {
         let taffy = &mut self.taffy;
         let node = self
-            .window_nodes
-            .entry(window)
+            .camera_roots
+            .entry(camera)
             .or_insert_with(|| taffy.new_leaf(taffy::style::Style::default()).unwrap());
 
         taffy
@@ -160,12 +199,8 @@ without UI components as a child of an entity with UI components, results may be
                 *node,
                 taffy::style::Style {
                     size: taffy::geometry::Size {
-                        width: taffy::style::Dimension::Points(
-                            window_resolution.physical_width() as f32
-                        ),
-                        height: taffy::style::Dimension::Points(
-                            window_resolution.physical_height() as f32,
-                        ),
+                        width: taffy::style::Dimension::Points(physical_size.x),
+                        height: taffy::style::Dimension::Points(physical_size.y),
                     },
                     ..Default::default()
                 },
@@ -173,22 +208,31 @@ without UI components as a child of an entity with UI components, results may be
             .unwrap();
     }
 
-    pub fn set_window_children(
-        &mut self,
-        parent_window: Entity,
-        children: impl Iterator<Item = Entity>,
-    ) {
-        let taffy_node = self.window_nodes.get(&parent_window).unwrap();
+    /// Sets the UI root nodes rendered to `camera`.
+    pub fn set_camera_children(&mut self, camera: Entity, children: impl Iterator<Item = Entity>) {
+        let taffy_node = self.camera_roots.get(&camera).unwrap();
         let child_nodes = children
             .map(|e| *self.entity_to_taffy.get(&e).unwrap())
             .collect::<Vec<taffy::node::Node>>();
         self.taffy.set_children(*taffy_node, &child_nodes).unwrap();
     }
 
-    pub fn compute_window_layouts(&mut self) {
-        for window_node in self.window_nodes.values() {
+    /// Removes the implicit root nodes of all cameras for which `keep` returns `false`.
+    pub fn retain_camera_roots(&mut self, mut keep: impl FnMut(Entity) -> bool) {
+        let taffy = &mut self.taffy;
+        self.camera_roots.retain(|camera, node| {
+            let retain = keep(*camera);
+            if !retain {
+                taffy.remove(*node).unwrap();
+            }
+            retain
+        });
+    }
+
+    pub fn compute_camera_layouts(&mut self) {
+        for camera_root in self.camera_roots.values() {
             self.taffy
-                .compute_layout(*window_node, Size::MAX_CONTENT)
+                .compute_layout(*camera_root, Size::MAX_CONTENT)
                 .unwrap();
         }
     }
@@ -223,73 +267,135 @@ pub enum FlexError {
     TaffyError(taffy::error::TaffyError),
 }
 
+/// The layout information of the UI rendered to a single camera.
+struct CameraLayoutInfo {
+    context: LayoutContext,
+    /// The scale factor of the camera's render target, excluding the [`UiScale`]
+    target_scale_factor: f64,
+    /// The root nodes rendered to this camera and whether their [`TargetCamera`] changed
+    root_nodes: Vec<(Entity, bool)>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn flex_node_system(
     primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
-    windows: Query<(Entity, &Window)>,
+    cameras: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
     ui_scale: Res<UiScale>,
     mut scale_factor_events: EventReader<WindowScaleFactorChanged>,
+    mut last_layout_contexts: Local<HashMap<Entity, LayoutContext>>,
     mut flex_surface: ResMut<FlexSurface>,
-    root_node_query: Query<Entity, (With<Node>, Without<Parent>)>,
-    node_query: Query<(Entity, &Style, Option<&CalculatedSize>), (With<Node>, Changed<Style>)>,
-    full_node_query: Query<(Entity, &Style, Option<&CalculatedSize>), With<Node>>,
-    changed_size_query: Query<
-        (Entity, &Style, &CalculatedSize),
-        (With<Node>, Changed<CalculatedSize>),
-    >,
+    root_node_query: Query<(Entity, Option<Ref<TargetCamera>>), (With<Node>, Without<Parent>)>,
+    style_query: Query<(Ref<Style>, Option<Ref<ContentSize>>), With<Node>>,
+    full_children_query: Query<&Children, With<Node>>,
     children_query: Query<(Entity, &Children), (With<Node>, Changed<Children>)>,
     mut removed_children: RemovedComponents<Children>,
-    mut node_transform_query: Query<(Entity, &mut Node, &mut Transform, Option<&Parent>)>,
+    mut node_transform_query: Query<(&mut Node, &mut Transform, Option<&mut ScrollPosition>)>,
     mut removed_nodes: RemovedComponents<Node>,
+    mut removed_content_sizes: RemovedComponents<ContentSize>,
 ) {
-    // assume one window for time being...
     // TODO: Support window-independent scaling: https://github.com/bevyengine/bevy/issues/5621
-    let (primary_window_entity, logical_to_physical_factor) =
-        if let Ok((entity, primary_window)) = primary_window.get_single() {
-            (entity, primary_window.resolution.scale_factor())
-        } else {
-            return;
+    let primary_window = primary_window.get_single().ok();
+    let default_camera = default_ui_camera.get();
+
+    // Group the root nodes by the camera they are rendered to.
+    // Without any camera the UI is laid out against the primary window.
+    let mut camera_layout_info: HashMap<Entity, CameraLayoutInfo> = HashMap::default();
+    for (entity, target_camera) in &root_node_query {
+        let Some(camera_entity) = target_camera
+            .as_ref()
+            .map(|target_camera| target_camera.entity())
+            .or(default_camera)
+            .or(primary_window.map(|(window_entity, _)| window_entity))
+        else {
+            continue;
         };
+        let retargeted = target_camera.map_or(false, |target_camera| target_camera.is_changed());
 
-    // update window root nodes
-    for (entity, window) in windows.iter() {
-        flex_surface.update_window(entity, &window.resolution);
-    }
+        if let Some(info) = camera_layout_info.get_mut(&camera_entity) {
+            info.root_nodes.push((entity, retargeted));
+            continue;
+        }
 
-    let scale_factor = logical_to_physical_factor * ui_scale.scale;
+        let (target_scale_factor, logical_viewport_size) = if let Ok(camera) =
+            cameras.get(camera_entity)
+        {
+            let (Some(target_scale_factor), Some(logical_viewport_size)) = (
+                camera.target_scaling_factor(),
+                camera.logical_viewport_size(),
+            ) else {
+                continue;
+            };
+            (target_scale_factor, logical_viewport_size)
+        } else if let Some((_, window)) =
+            primary_window.filter(|(window_entity, _)| *window_entity == camera_entity)
+        {
+            (
+                window.resolution.scale_factor(),
+                Vec2::new(window.width(), window.height()),
+            )
+        } else {
+            warn!(
+                    "UI root node {entity:?} targets the entity {camera_entity:?}, which is not a camera. \
+The UI will not be laid out."
+                );
+            continue;
+        };
 
-    fn update_changed<F: ReadOnlyWorldQuery>(
-        flex_surface: &mut FlexSurface,
-        scaling_factor: f64,
-        query: Query<(Entity, &Style, Option<&CalculatedSize>), F>,
-    ) {
-        // update changed nodes
-        for (entity, style, calculated_size) in &query {
-            // TODO: remove node from old hierarchy if its root has changed
-            if let Some(calculated_size) = calculated_size {
-                flex_surface.upsert_leaf(entity, style, *calculated_size, scaling_factor);
-            } else {
-                flex_surface.upsert_node(entity, style, scaling_factor);
-            }
-        }
+        camera_layout_info.insert(
+            camera_entity,
+            CameraLayoutInfo {
+                context: LayoutContext::new(
+                    target_scale_factor,
+                    ui_scale.scale,
+                    logical_viewport_size,
+                ),
+                target_scale_factor,
+                root_nodes: vec![(entity, retargeted)],
+            },
+        );
     }
 
-    if !scale_factor_events.is_empty() || ui_scale.is_changed() {
-        scale_factor_events.clear();
-        update_changed(&mut flex_surface, scale_factor, full_node_query);
-    } else {
-        update_changed(&mut flex_surface, scale_factor, node_query);
+    let scale_factor_changed = !scale_factor_events.is_empty() || ui_scale.is_changed();
+    scale_factor_events.clear();
+
+    // nodes without a content size are only sized by their style
+    for entity in removed_content_sizes.iter() {
+        flex_surface.remove_measure(entity);
     }
 
-    for (entity, style, calculated_size) in &changed_size_query {
-        flex_surface.upsert_leaf(entity, style, *calculated_size, scale_factor);
+    // update the styles of changed nodes, or of every node if the camera's layout context changed
+    for (camera_entity, info) in &camera_layout_info {
+        let context_changed = last_layout_contexts.get(camera_entity) != Some(&info.context);
+        flex_surface.update_camera_root(*camera_entity, info.context.physical_size);
+        for (root_node, retargeted) in &info.root_nodes {
+            update_node_styles_recursive(
+                &mut flex_surface,
+                *root_node,
+                &info.context,
+                scale_factor_changed || context_changed || *retargeted,
+                &style_query,
+                &full_children_query,
+            );
+        }
     }
+    *last_layout_contexts = camera_layout_info
+        .iter()
+        .map(|(camera_entity, info)| (*camera_entity, info.context.clone()))
+        .collect();
 
     // clean up removed nodes
     flex_surface.remove_entities(removed_nodes.iter());
 
-    // update window children (for now assuming all Nodes live in the primary window)
-    flex_surface.set_window_children(primary_window_entity, root_node_query.iter());
+    // update camera children
+    flex_surface
+        .retain_camera_roots(|camera_entity| camera_layout_info.contains_key(&camera_entity));
+    for (camera_entity, info) in &camera_layout_info {
+        flex_surface.set_camera_children(
+            *camera_entity,
+            info.root_nodes.iter().map(|(root_node, _)| *root_node),
+        );
+    }
 
     // update and remove children
     for entity in removed_children.iter() {
@@ -300,35 +406,562 @@ pub fn flex_node_system(
     }
 
     // compute layouts
-    flex_surface.compute_window_layouts();
+    flex_surface.compute_camera_layouts();
+
+    // PERF: try doing this incrementally
+    for info in camera_layout_info.values() {
+        let physical_to_logical_factor = 1. / info.target_scale_factor;
+        for (root_node, _) in &info.root_nodes {
+            update_node_geometry_recursive(
+                *root_node,
+                None,
+                Vec2::ZERO,
+                &flex_surface,
+                &mut node_transform_query,
+                &full_children_query,
+                physical_to_logical_factor,
+            );
+        }
+    }
+}
+
+/// Converts the [`Style`] of `entity` and its descendants into taffy styles.
+///
+/// Only nodes with a changed [`Style`] or [`ContentSize`] are updated, unless `full_update` is set.
+fn update_node_styles_recursive(
+    flex_surface: &mut FlexSurface,
+    entity: Entity,
+    context: &LayoutContext,
+    full_update: bool,
+    style_query: &Query<(Ref<Style>, Option<Ref<ContentSize>>), With<Node>>,
+    children_query: &Query<&Children, With<Node>>,
+) {
+    let Ok((style, content_size)) = style_query.get(entity) else {
+        return;
+    };
+
+    // TODO: remove node from old hierarchy if its root has changed
+    if let Some(content_size) = content_size {
+        if full_update || style.is_changed() || content_size.is_changed() {
+            flex_surface.upsert_leaf(entity, &style, &content_size, context);
+        }
+    } else if full_update || style.is_changed() {
+        flex_surface.upsert_node(entity, &style, context);
+    }
+
+    if let Ok(children) = children_query.get(entity) {
+        for child in children {
+            update_node_styles_recursive(
+                flex_surface,
+                *child,
+                context,
+                full_update,
+                style_query,
+                children_query,
+            );
+        }
+    }
+}
 
-    let physical_to_logical_factor = 1. / logical_to_physical_factor;
+/// Copies the computed layout of `entity` and its descendants into their [`Node`] and [`Transform`].
+///
+/// The children of nodes with a [`ScrollPosition`] are offset by the clamped scroll position.
+fn update_node_geometry_recursive(
+    entity: Entity,
+    parent_layout: Option<&taffy::layout::Layout>,
+    parent_scroll_offset: Vec2,
+    flex_surface: &FlexSurface,
+    node_transform_query: &mut Query<(&mut Node, &mut Transform, Option<&mut ScrollPosition>)>,
+    children_query: &Query<&Children, With<Node>>,
+    physical_to_logical_factor: f64,
+) {
+    let Ok((mut node, mut transform, scroll_position)) = node_transform_query.get_mut(entity)
+    else {
+        return;
+    };
+    let Ok(layout) = flex_surface.get_layout(entity) else {
+        return;
+    };
 
     let to_logical = |v| (physical_to_logical_factor * v as f64) as f32;
 
-    // PERF: try doing this incrementally
-    for (entity, mut node, mut transform, parent) in &mut node_transform_query {
-        let layout = flex_surface.get_layout(entity).unwrap();
-        let new_size = Vec2::new(
-            to_logical(layout.size.width),
-            to_logical(layout.size.height),
-        );
-        // only trigger change detection when the new value is different
-        if node.calculated_size != new_size {
-            node.calculated_size = new_size;
+    let new_size = Vec2::new(
+        to_logical(layout.size.width),
+        to_logical(layout.size.height),
+    );
+    // only trigger change detection when the new value is different
+    if node.calculated_size != new_size {
+        node.calculated_size = new_size;
+    }
+    let mut new_position = transform.translation;
+    new_position.x = to_logical(layout.location.x + layout.size.width / 2.0);
+    new_position.y = to_logical(layout.location.y + layout.size.height / 2.0);
+    if let Some(parent_layout) = parent_layout {
+        new_position.x -= to_logical(parent_layout.size.width / 2.0);
+        new_position.y -= to_logical(parent_layout.size.height / 2.0);
+    }
+    new_position.x -= parent_scroll_offset.x;
+    new_position.y -= parent_scroll_offset.y;
+    // only trigger change detection when the new value is different
+    if transform.translation != new_position {
+        transform.translation = new_position;
+    }
+
+    let children = children_query.get(entity).ok();
+    let content_size = children
+        .into_iter()
+        .flatten()
+        .filter_map(|child| flex_surface.get_layout(*child).ok())
+        .fold(Vec2::ZERO, |content_size, child_layout| {
+            content_size.max(Vec2::new(
+                to_logical(child_layout.location.x + child_layout.size.width),
+                to_logical(child_layout.location.y + child_layout.size.height),
+            ))
+        });
+    if node.content_size != content_size {
+        node.content_size = content_size;
+    }
+
+    let scroll_offset = match scroll_position {
+        Some(mut scroll_position) => {
+            let max_offset = (content_size - new_size).max(Vec2::ZERO);
+            let clamped_offset = scroll_position.offset.clamp(Vec2::ZERO, max_offset);
+            if scroll_position.offset != clamped_offset {
+                scroll_position.offset = clamped_offset;
+            }
+            clamped_offset
         }
-        let mut new_position = transform.translation;
-        new_position.x = to_logical(layout.location.x + layout.size.width / 2.0);
-        new_position.y = to_logical(layout.location.y + layout.size.height / 2.0);
-        if let Some(parent) = parent {
-            if let Ok(parent_layout) = flex_surface.get_layout(**parent) {
-                new_position.x -= to_logical(parent_layout.size.width / 2.0);
-                new_position.y -= to_logical(parent_layout.size.height / 2.0);
+        None => Vec2::ZERO,
+    };
+
+    if let Some(children) = children {
+        for child in children {
+            update_node_geometry_recursive(
+                *child,
+                Some(layout),
+                scroll_offset,
+                flex_surface,
+                node_transform_query,
+                children_query,
+                physical_to_logical_factor,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        flex_node_system, node_bundles::NodeBundle, update::resolve_outlines_system, AlignSelf,
+        AvailableSpace, ContentSize, Display, FixedMeasure, FlexSurface, GridPlacement,
+        LayoutContext, Measure, Node, Outline, Overflow, RepeatedGridTrack, ScrollPosition, Size,
+        Style, UiRect, UiScale, Val,
+    };
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetServer, FileAssetIo};
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::{Rect, UVec2, Vec2};
+    use bevy_render::{
+        camera::{camera_system, Camera, ManualTextureViews, OrthographicProjection, Viewport},
+        color::Color,
+        texture::Image,
+    };
+    use bevy_transform::components::Transform;
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowResized, WindowScaleFactorChanged,
+    };
+
+    fn ui_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<UiScale>();
+        world.init_resource::<FlexSurface>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.spawn((Window::default(), PrimaryWindow));
+        world
+    }
+
+    fn node(size: Size) -> NodeBundle {
+        NodeBundle {
+            style: Style {
+                size,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn outline_does_not_affect_layout() {
+        let mut world = ui_world();
+        let plain = world.spawn(node(Size::all(Val::Px(60.)))).id();
+        let outlined = world
+            .spawn((
+                node(Size::all(Val::Px(60.))),
+                Outline::new(Val::Px(10.), Val::Px(5.), Color::RED),
+            ))
+            .id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems((flex_node_system, resolve_outlines_system).chain());
+        schedule.run(&mut world);
+
+        let plain_node = world.get::<Node>(plain).unwrap().clone();
+        let outlined_node = world.get::<Node>(outlined).unwrap().clone();
+        assert_eq!(plain_node.size(), outlined_node.size());
+        assert_eq!(outlined_node.size(), Vec2::splat(60.));
+        assert_eq!(outlined_node.outline_width(), 10.);
+        assert_eq!(outlined_node.outline_offset(), 5.);
+
+        let flex_surface = world.resource::<FlexSurface>();
+        let plain_layout = *flex_surface.get_layout(plain).unwrap();
+        let outlined_layout = *flex_surface.get_layout(outlined).unwrap();
+        assert_eq!(plain_layout.size, outlined_layout.size);
+
+        // the nodes are laid out directly next to each other, the outline takes up no space
+        let outlined_translation = world.get::<Transform>(outlined).unwrap().translation;
+        let plain_translation = world.get::<Transform>(plain).unwrap().translation;
+        assert_eq!((outlined_translation.x - plain_translation.x).abs(), 60.);
+    }
+
+    #[test]
+    fn percentage_outline_resolves_against_node_width() {
+        let mut world = ui_world();
+        let outlined = world
+            .spawn((
+                node(Size::new(Val::Px(200.), Val::Px(50.))),
+                Outline::new(Val::Percent(5.), Val::Percent(1.), Color::RED),
+            ))
+            .id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems((flex_node_system, resolve_outlines_system).chain());
+        schedule.run(&mut world);
+
+        let node = world.get::<Node>(outlined).unwrap();
+        assert_eq!(node.outline_width(), 10.);
+        assert_eq!(node.outline_offset(), 2.);
+    }
+
+    /// A measure for content that keeps a constant area, like wrapping text
+    struct AreaMeasure {
+        area: f32,
+    }
+
+    impl Measure for AreaMeasure {
+        fn measure(
+            &self,
+            width: Option<f32>,
+            height: Option<f32>,
+            _: AvailableSpace,
+            _: AvailableSpace,
+        ) -> Vec2 {
+            match (width, height) {
+                (Some(width), Some(height)) => Vec2::new(width, height),
+                (Some(width), None) => Vec2::new(width, self.area / width),
+                (None, Some(height)) => Vec2::new(self.area / height, height),
+                (None, None) => Vec2::new(100., self.area / 100.),
             }
         }
-        // only trigger change detection when the new value is different
-        if transform.translation != new_position {
-            transform.translation = new_position;
+    }
+
+    fn measured_node(style: Style, measure: impl Measure) -> (NodeBundle, ContentSize) {
+        let mut content_size = ContentSize::default();
+        content_size.set(measure);
+        (
+            NodeBundle {
+                style: Style {
+                    align_self: AlignSelf::FlexStart,
+                    ..style
+                },
+                ..Default::default()
+            },
+            content_size,
+        )
+    }
+
+    #[test]
+    fn custom_measure_respects_min_and_max_size() {
+        let mut world = ui_world();
+        let unconstrained = world
+            .spawn(measured_node(Style::default(), AreaMeasure { area: 2000. }))
+            .id();
+        let max_width = world
+            .spawn(measured_node(
+                Style {
+                    max_size: Size::width(Val::Px(40.)),
+                    ..Default::default()
+                },
+                AreaMeasure { area: 2000. },
+            ))
+            .id();
+        let min_height = world
+            .spawn(measured_node(
+                Style {
+                    min_size: Size::height(Val::Px(80.)),
+                    ..Default::default()
+                },
+                FixedMeasure {
+                    size: Vec2::new(30., 10.),
+                },
+            ))
+            .id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(flex_node_system);
+        schedule.run(&mut world);
+
+        assert_eq!(
+            world.get::<Node>(unconstrained).unwrap().size(),
+            Vec2::new(100., 20.)
+        );
+        assert_eq!(
+            world.get::<Node>(max_width).unwrap().size(),
+            Vec2::new(40., 50.)
+        );
+        assert_eq!(
+            world.get::<Node>(min_height).unwrap().size(),
+            Vec2::new(30., 80.)
+        );
+    }
+
+    #[test]
+    fn replacing_the_measure_triggers_relayout() {
+        let mut world = ui_world();
+        let entity = world
+            .spawn(measured_node(
+                Style::default(),
+                FixedMeasure {
+                    size: Vec2::new(30., 10.),
+                },
+            ))
+            .id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(flex_node_system);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Node>(entity).unwrap().size(),
+            Vec2::new(30., 10.)
+        );
+
+        world
+            .get_mut::<ContentSize>(entity)
+            .unwrap()
+            .set(FixedMeasure {
+                size: Vec2::new(60., 20.),
+            });
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Node>(entity).unwrap().size(),
+            Vec2::new(60., 20.)
+        );
+
+        world.entity_mut(entity).remove::<ContentSize>();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Node>(entity).unwrap().size(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn scroll_position_is_clamped_to_the_overflowing_content() {
+        let mut world = ui_world();
+        let content = world
+            .spawn(node(Size::new(Val::Px(100.), Val::Px(300.))))
+            .id();
+        let container = world
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        size: Size::all(Val::Px(100.)),
+                        overflow: Overflow::Scroll,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ScrollPosition::new(Vec2::new(10., 500.)),
+            ))
+            .push_children(&[content])
+            .id();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(flex_node_system);
+        schedule.run(&mut world);
+
+        assert_eq!(
+            world.get::<Node>(container).unwrap().content_size(),
+            Vec2::new(100., 300.)
+        );
+        assert_eq!(
+            world.get::<ScrollPosition>(container).unwrap().offset,
+            Vec2::new(0., 200.)
+        );
+        // the content is scrolled to its bottom edge
+        assert_eq!(
+            world.get::<Transform>(content).unwrap().translation.y,
+            -100.
+        );
+
+        world.get_mut::<ScrollPosition>(container).unwrap().offset = Vec2::new(-5., -50.);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<ScrollPosition>(container).unwrap().offset,
+            Vec2::ZERO
+        );
+        assert_eq!(world.get::<Transform>(content).unwrap().translation.y, 100.);
+    }
+
+    #[test]
+    fn grid_places_children_in_cells() {
+        let mut world = ui_world();
+        let spanning = world
+            .spawn(NodeBundle {
+                style: Style {
+                    grid_column: GridPlacement::span(2),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .id();
+        let cells: Vec<Entity> = (0..4)
+            .map(|_| world.spawn(NodeBundle::default()).id())
+            .collect();
+        world
+            .spawn(NodeBundle {
+                style: Style {
+                    display: Display::Grid,
+                    size: Size::new(Val::Px(300.), Val::Px(200.)),
+                    grid_template_columns: vec![RepeatedGridTrack::px(3, 100.)],
+                    grid_template_rows: vec![RepeatedGridTrack::fr(2, 1.)],
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .push_children(&[spanning])
+            .push_children(&cells);
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(flex_node_system);
+        schedule.run(&mut world);
+
+        // returns the rect of the node relative to the top-left corner of the grid
+        let rect = |entity: Entity| {
+            let size = world.get::<Node>(entity).unwrap().size();
+            let center = world
+                .get::<Transform>(entity)
+                .unwrap()
+                .translation
+                .truncate()
+                + Vec2::new(150., 100.);
+            Rect::from_center_size(center, size)
+        };
+        assert_eq!(
+            rect(spanning),
+            Rect::new(0., 0., 200., 100.),
+            "the first cell spans two columns"
+        );
+        assert_eq!(rect(cells[0]), Rect::new(200., 0., 300., 100.));
+        assert_eq!(rect(cells[1]), Rect::new(0., 100., 100., 200.));
+        assert_eq!(rect(cells[2]), Rect::new(100., 100., 200., 200.));
+        assert_eq!(rect(cells[3]), Rect::new(200., 100., 300., 200.));
+    }
+
+    #[test]
+    fn identical_trees_on_different_cameras_use_their_own_viewport() {
+        let camera_a = Entity::from_raw(0);
+        let camera_b = Entity::from_raw(1);
+        let root_a = Entity::from_raw(2);
+        let root_b = Entity::from_raw(3);
+        let style = Style {
+            size: Size::all(Val::Percent(50.)),
+            padding: UiRect::all(Val::Vw(10.)),
+            ..Default::default()
+        };
+
+        let context_a = LayoutContext::new(1., 1., Vec2::new(800., 600.));
+        // a camera rendering to a 400x200 logical viewport on a high dpi display
+        let context_b = LayoutContext::new(2., 1., Vec2::new(400., 200.));
+
+        let mut flex_surface = FlexSurface::default();
+        for (camera, root, context) in [
+            (camera_a, root_a, &context_a),
+            (camera_b, root_b, &context_b),
+        ] {
+            flex_surface.upsert_node(root, &style, context);
+            flex_surface.update_camera_root(camera, context.physical_size);
+            flex_surface.set_camera_children(camera, std::iter::once(root));
         }
+        flex_surface.compute_camera_layouts();
+
+        let layout_a = *flex_surface.get_layout(root_a).unwrap();
+        let layout_b = *flex_surface.get_layout(root_b).unwrap();
+        assert_eq!(layout_a.size.width, 400.);
+        assert_eq!(layout_a.size.height, 300.);
+        assert_eq!(layout_b.size.width, 400.);
+        assert_eq!(layout_b.size.height, 200.);
+        assert_ne!(layout_a.size, layout_b.size);
+    }
+
+    #[test]
+    fn viewport_units_resolve_against_camera_viewport() {
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(FileAssetIo::new("", false)))
+            .add_asset::<Image>()
+            .init_resource::<ManualTextureViews>()
+            .init_resource::<UiScale>()
+            .init_resource::<FlexSurface>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_systems((camera_system::<OrthographicProjection>, flex_node_system).chain());
+
+        // a camera rendering to the bottom right quarter of a 1280x720 window
+        let window = app.world.spawn((Window::default(), PrimaryWindow)).id();
+        app.world.send_event(WindowCreated { window });
+        app.world.spawn((
+            Camera {
+                viewport: Some(Viewport {
+                    physical_position: UVec2::new(640, 360),
+                    physical_size: UVec2::new(640, 360),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            OrthographicProjection::default(),
+        ));
+        let node = app
+            .world
+            .spawn(NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Vw(50.), Val::Vh(50.)),
+                    margin: UiRect::left(Val::Vw(10.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world.get::<Node>(node).unwrap().size(),
+            Vec2::new(320., 180.)
+        );
+        // the node's position is relative to the viewport
+        let translation = app.world.get::<Transform>(node).unwrap().translation;
+        assert_eq!(translation.truncate(), Vec2::new(64. + 160., 90.));
+    }
+
+    #[test]
+    fn removed_cameras_release_their_root() {
+        let camera = Entity::from_raw(0);
+        let mut flex_surface = FlexSurface::default();
+        flex_surface.update_camera_root(camera, Vec2::new(100., 100.));
+        flex_surface.retain_camera_roots(|_| false);
+        assert!(flex_surface.camera_roots.is_empty());
     }
 }
@@ -1,8 +1,9 @@
-use crate::{camera_config::UiCameraConfig, CalculatedClip, Node, UiStack};
+use crate::{camera_config::UiCameraConfig, CalculatedClip, Node, UiConfig, UiStack};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     change_detection::DetectChangesMut,
     entity::Entity,
+    event::EventWriter,
     prelude::{Component, With},
     query::WorldQuery,
     reflect::ReflectComponent,
@@ -21,6 +22,7 @@ use smallvec::SmallVec;
 /// Describes what type of input interaction has occurred for a UI node.
 ///
 /// This is commonly queried with a `Changed<Interaction>` filter.
+/// To react to a completed click instead of a press, read the [`Clicked`] events.
 ///
 /// Updated in [`ui_focus_system`].
 ///
@@ -35,8 +37,10 @@ use smallvec::SmallVec;
 #[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Reflect, Serialize, Deserialize)]
 #[reflect(Component, Serialize, Deserialize, PartialEq)]
 pub enum Interaction {
-    /// The node has been clicked
-    Clicked,
+    /// The node has been pressed
+    ///
+    /// The node stays pressed until the press is released, even if the cursor leaves the node.
+    Pressed,
     /// The node has been hovered over
     Hovered,
     /// Nothing has happened
@@ -53,6 +57,16 @@ impl Default for Interaction {
     }
 }
 
+/// An event sent by [`ui_focus_system`] when a node with an [`Interaction`] has been clicked.
+///
+/// A click is a press that is released on the same node, without the cursor moving further than
+/// [`UiConfig::drag_threshold`](crate::UiConfig::drag_threshold) in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clicked {
+    /// The clicked node
+    pub entity: Entity,
+}
+
 /// A component storing the position of the mouse relative to the node, (0., 0.) being the top-left corner and (1., 1.) being the bottom-right
 /// If the mouse is not over the node, the value will go beyond the range of (0., 0.) to (1., 1.)
 /// A None value means that the cursor position is unknown.
@@ -106,10 +120,18 @@ impl Default for FocusPolicy {
     }
 }
 
-/// Contains entities whose Interaction should be set to None
+/// Contains entities whose Interaction should be set to None, and the state of the current press
 #[derive(Default)]
 pub struct State {
     entities_to_reset: SmallVec<[Entity; 1]>,
+    /// The nodes that were pressed by the current press
+    pressed_entities: SmallVec<[Entity; 1]>,
+    /// The position of the cursor when the current press started
+    press_start_position: Option<Vec2>,
+    /// Whether the cursor moved further than the drag threshold during the current press
+    dragged: bool,
+    /// The id of the touch that is used as the cursor, so multi-touch input uses the first touch
+    touch_id: Option<u64>,
 }
 
 /// Main query for [`ui_focus_system`]
@@ -126,9 +148,43 @@ pub struct NodeQuery {
     computed_visibility: Option<&'static ComputedVisibility>,
 }
 
+/// Returns the cursor position in UI coordinates of the first window rendered to by a camera showing UI.
+pub(crate) fn window_cursor_position(
+    camera: &Query<(&Camera, Option<&UiCameraConfig>)>,
+    windows: &Query<&Window>,
+    primary_window: Option<Entity>,
+) -> Option<Vec2> {
+    let is_ui_disabled =
+        |camera_ui| matches!(camera_ui, Some(&UiCameraConfig { show_ui: false, .. }));
+
+    camera
+        .iter()
+        .filter(|(_, camera_ui)| !is_ui_disabled(*camera_ui))
+        .filter_map(|(camera, _)| {
+            if let Some(NormalizedRenderTarget::Window(window_id)) =
+                camera.target.normalize(primary_window)
+            {
+                Some(window_id)
+            } else {
+                None
+            }
+        })
+        .find_map(|window_ref| {
+            windows.get(window_ref.entity()).ok().and_then(|window| {
+                window.cursor_position().map(|mut cursor_pos| {
+                    cursor_pos.y = window.height() - cursor_pos.y;
+                    cursor_pos
+                })
+            })
+        })
+}
+
 /// The system that sets Interaction for all UI elements based on the mouse cursor activity
 ///
 /// Entities with a hidden [`ComputedVisibility`] are always treated as released.
+///
+/// Sends a [`Clicked`] event when a press is released on the node it started on.
+/// With multiple touches, only the first touch is used until it is released.
 #[allow(clippy::too_many_arguments)]
 pub fn ui_focus_system(
     mut state: Local<State>,
@@ -137,6 +193,8 @@ pub fn ui_focus_system(
     mouse_button_input: Res<Input<MouseButton>>,
     touches_input: Res<Touches>,
     ui_stack: Res<UiStack>,
+    ui_config: Res<UiConfig>,
+    mut clicked_events: EventWriter<Clicked>,
     mut node_query: Query<NodeQuery>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
 ) {
@@ -149,45 +207,47 @@ pub fn ui_focus_system(
         }
     }
 
+    // track the first touch until it is released, ignoring any other touch in the meantime
+    if state.touch_id.is_none() {
+        state.touch_id = touches_input
+            .iter_just_pressed()
+            .map(|touch| touch.id())
+            .min();
+    }
+    let touch = state.touch_id.and_then(|id| {
+        touches_input
+            .get_pressed(id)
+            .or_else(|| touches_input.get_released(id))
+    });
+    let touch_pressed = state
+        .touch_id
+        .map_or(false, |id| touches_input.just_pressed(id));
+    let touch_released = state
+        .touch_id
+        .map_or(false, |id| touches_input.just_released(id));
+    let touch_cancelled = state
+        .touch_id
+        .map_or(false, |id| touches_input.just_cancelled(id));
+    if touch_released || touch_cancelled {
+        state.touch_id = None;
+    }
+
     let mouse_released =
-        mouse_button_input.just_released(MouseButton::Left) || touches_input.any_just_released();
+        mouse_button_input.just_released(MouseButton::Left) || touch_released || touch_cancelled;
     if mouse_released {
         for node in node_query.iter_mut() {
             if let Some(mut interaction) = node.interaction {
-                if *interaction == Interaction::Clicked {
+                if *interaction == Interaction::Pressed {
                     *interaction = Interaction::None;
                 }
             }
         }
     }
 
-    let mouse_clicked =
-        mouse_button_input.just_pressed(MouseButton::Left) || touches_input.any_just_pressed();
+    let mouse_clicked = mouse_button_input.just_pressed(MouseButton::Left) || touch_pressed;
 
-    let is_ui_disabled =
-        |camera_ui| matches!(camera_ui, Some(&UiCameraConfig { show_ui: false, .. }));
-
-    let cursor_position = camera
-        .iter()
-        .filter(|(_, camera_ui)| !is_ui_disabled(*camera_ui))
-        .filter_map(|(camera, _)| {
-            if let Some(NormalizedRenderTarget::Window(window_id)) =
-                camera.target.normalize(primary_window)
-            {
-                Some(window_id)
-            } else {
-                None
-            }
-        })
-        .find_map(|window_ref| {
-            windows.get(window_ref.entity()).ok().and_then(|window| {
-                window.cursor_position().map(|mut cursor_pos| {
-                    cursor_pos.y = window.height() - cursor_pos.y;
-                    cursor_pos
-                })
-            })
-        })
-        .or_else(|| touches_input.first_pressed_position());
+    let cursor_position = window_cursor_position(&camera, &windows, primary_window)
+        .or_else(|| touch.map(|touch| touch.position()));
 
     // prepare an iterator that contains all the nodes that have the cursor in their rect,
     // from the top node to the bottom one. this will also reset the interaction to `None`
@@ -261,15 +321,21 @@ pub fn ui_focus_system(
         .collect::<Vec<Entity>>()
         .into_iter();
 
-    // set Clicked or Hovered on top nodes. as soon as a node with a `Block` focus policy is detected,
+    // set Pressed or Hovered on top nodes. as soon as a node with a `Block` focus policy is detected,
     // the iteration will stop on it because it "captures" the interaction.
+    let mut hovered_entities: SmallVec<[Entity; 1]> = SmallVec::new();
+    if mouse_clicked {
+        state.pressed_entities.clear();
+    }
     let mut iter = node_query.iter_many_mut(moused_over_nodes.by_ref());
     while let Some(node) = iter.fetch_next() {
         if let Some(mut interaction) = node.interaction {
+            hovered_entities.push(node.entity);
             if mouse_clicked {
                 // only consider nodes with Interaction "clickable"
-                if *interaction != Interaction::Clicked {
-                    *interaction = Interaction::Clicked;
+                if *interaction != Interaction::Pressed {
+                    *interaction = Interaction::Pressed;
+                    state.pressed_entities.push(node.entity);
                     // if the mouse was simultaneously released, reset this Interaction in the next
                     // frame
                     if mouse_released {
@@ -285,18 +351,283 @@ pub fn ui_focus_system(
             FocusPolicy::Block => {
                 break;
             }
-            FocusPolicy::Pass => { /* allow the next node to be hovered/clicked */ }
+            FocusPolicy::Pass => { /* allow the next node to be hovered/pressed */ }
+        }
+    }
+
+    if mouse_clicked {
+        state.press_start_position = cursor_position;
+        state.dragged = false;
+    }
+    if let (Some(start), Some(cursor_position)) = (state.press_start_position, cursor_position) {
+        if start.distance(cursor_position) > ui_config.drag_threshold {
+            state.dragged = true;
         }
     }
+    if mouse_released {
+        if !state.dragged && !touch_cancelled {
+            for entity in state.pressed_entities.iter() {
+                if hovered_entities.contains(entity) {
+                    clicked_events.send(Clicked { entity: *entity });
+                }
+            }
+        }
+        state.pressed_entities.clear();
+        state.press_start_position = None;
+    }
+
     // reset `Interaction` for the remaining lower nodes to `None`. those are the nodes that remain in
     // `moused_over_nodes` after the previous loop is exited.
     let mut iter = node_query.iter_many_mut(moused_over_nodes);
     while let Some(node) = iter.fetch_next() {
         if let Some(mut interaction) = node.interaction {
-            // don't reset clicked nodes because they're handled separately
-            if *interaction != Interaction::Clicked {
+            // don't reset pressed nodes because they're handled separately
+            if *interaction != Interaction::Pressed {
                 interaction.set_if_neq(Interaction::None);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ui_focus_system, Clicked, Interaction, Node, UiConfig, UiStack};
+    use bevy_ecs::{
+        entity::Entity,
+        event::Events,
+        query::With,
+        schedule::{IntoSystemConfigs, Schedule},
+        world::World,
+    };
+    use bevy_input::{
+        mouse::MouseButton,
+        touch::{touch_screen_input_system, TouchInput, TouchPhase, Touches},
+        Input,
+    };
+    use bevy_math::Vec2;
+    use bevy_render::camera::Camera;
+    use bevy_transform::components::GlobalTransform;
+    use bevy_window::{PrimaryWindow, Window};
+
+    fn focus_world() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<Input<MouseButton>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<UiStack>();
+        world.init_resource::<UiConfig>();
+        world.init_resource::<Events<Clicked>>();
+        world.init_resource::<Events<TouchInput>>();
+        world.spawn((Window::default(), PrimaryWindow));
+        world.spawn(Camera::default());
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems((touch_screen_input_system, ui_focus_system).chain());
+        (world, schedule)
+    }
+
+    /// Spawns an interactable node of size 100x100 centered on `center`
+    fn spawn_button(world: &mut World, center: Vec2) -> Entity {
+        let entity = world
+            .spawn((
+                Node {
+                    calculated_size: Vec2::splat(100.),
+                    ..Default::default()
+                },
+                GlobalTransform::from_translation(center.extend(0.)),
+                Interaction::default(),
+            ))
+            .id();
+        world.resource_mut::<UiStack>().uinodes.push(entity);
+        entity
+    }
+
+    /// Moves the cursor to `position` in UI coordinates, with the origin at the top-left corner
+    fn move_cursor(world: &mut World, position: Vec2) {
+        let mut window = world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(world);
+        let height = window.height();
+        window.set_cursor_position(Some(Vec2::new(position.x, height - position.y)));
+    }
+
+    fn run_frame(world: &mut World, schedule: &mut Schedule) {
+        schedule.run(world);
+        world.resource_mut::<Input<MouseButton>>().clear();
+    }
+
+    fn press(world: &mut World, schedule: &mut Schedule) {
+        world
+            .resource_mut::<Input<MouseButton>>()
+            .press(MouseButton::Left);
+        run_frame(world, schedule);
+    }
+
+    fn release(world: &mut World, schedule: &mut Schedule) {
+        world
+            .resource_mut::<Input<MouseButton>>()
+            .release(MouseButton::Left);
+        run_frame(world, schedule);
+    }
+
+    fn touch(
+        world: &mut World,
+        schedule: &mut Schedule,
+        id: u64,
+        phase: TouchPhase,
+        position: Vec2,
+    ) {
+        world.send_event(TouchInput {
+            phase,
+            position,
+            force: None,
+            id,
+        });
+        run_frame(world, schedule);
+    }
+
+    fn clicks(world: &mut World) -> Vec<Entity> {
+        world
+            .resource_mut::<Events<Clicked>>()
+            .drain()
+            .map(|clicked| clicked.entity)
+            .collect()
+    }
+
+    #[test]
+    fn release_on_pressed_node_sends_click() {
+        let (mut world, mut schedule) = focus_world();
+        let button = spawn_button(&mut world, Vec2::new(100., 100.));
+        move_cursor(&mut world, Vec2::new(100., 100.));
+
+        press(&mut world, &mut schedule);
+        assert_eq!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Pressed)
+        );
+        assert!(clicks(&mut world).is_empty());
+
+        // moving within the drag threshold still counts as a click
+        move_cursor(&mut world, Vec2::new(102., 100.));
+        release(&mut world, &mut schedule);
+        assert_eq!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Hovered)
+        );
+        assert_eq!(clicks(&mut world), vec![button]);
+    }
+
+    #[test]
+    fn dragging_past_the_threshold_cancels_the_click() {
+        let (mut world, mut schedule) = focus_world();
+        let button = spawn_button(&mut world, Vec2::new(100., 100.));
+        move_cursor(&mut world, Vec2::new(100., 100.));
+
+        press(&mut world, &mut schedule);
+        move_cursor(&mut world, Vec2::new(120., 100.));
+        run_frame(&mut world, &mut schedule);
+        // moving back onto the press position doesn't resume the click
+        move_cursor(&mut world, Vec2::new(100., 100.));
+        release(&mut world, &mut schedule);
+        assert_eq!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Hovered)
+        );
+        assert!(clicks(&mut world).is_empty());
+    }
+
+    #[test]
+    fn release_on_another_node_does_not_click() {
+        let (mut world, mut schedule) = focus_world();
+        world.resource_mut::<UiConfig>().drag_threshold = f32::MAX;
+        let pressed = spawn_button(&mut world, Vec2::new(100., 100.));
+        let released = spawn_button(&mut world, Vec2::new(300., 100.));
+        move_cursor(&mut world, Vec2::new(100., 100.));
+
+        press(&mut world, &mut schedule);
+        move_cursor(&mut world, Vec2::new(300., 100.));
+        run_frame(&mut world, &mut schedule);
+        // the node stays pressed after the cursor left it
+        assert_eq!(
+            world.get::<Interaction>(pressed),
+            Some(&Interaction::Pressed)
+        );
+        release(&mut world, &mut schedule);
+        assert_eq!(world.get::<Interaction>(pressed), Some(&Interaction::None));
+        assert_eq!(
+            world.get::<Interaction>(released),
+            Some(&Interaction::Hovered)
+        );
+        assert!(clicks(&mut world).is_empty());
+    }
+
+    #[test]
+    fn multi_touch_uses_the_first_touch() {
+        let (mut world, mut schedule) = focus_world();
+        let first = spawn_button(&mut world, Vec2::new(100., 100.));
+        let second = spawn_button(&mut world, Vec2::new(300., 100.));
+
+        touch(
+            &mut world,
+            &mut schedule,
+            1,
+            TouchPhase::Started,
+            Vec2::new(100., 100.),
+        );
+        touch(
+            &mut world,
+            &mut schedule,
+            2,
+            TouchPhase::Started,
+            Vec2::new(300., 100.),
+        );
+        assert_eq!(world.get::<Interaction>(first), Some(&Interaction::Pressed));
+        assert_eq!(world.get::<Interaction>(second), Some(&Interaction::None));
+
+        // releasing the second touch doesn't release the first one
+        touch(
+            &mut world,
+            &mut schedule,
+            2,
+            TouchPhase::Ended,
+            Vec2::new(300., 100.),
+        );
+        assert_eq!(world.get::<Interaction>(first), Some(&Interaction::Pressed));
+        assert!(clicks(&mut world).is_empty());
+
+        touch(
+            &mut world,
+            &mut schedule,
+            1,
+            TouchPhase::Ended,
+            Vec2::new(100., 100.),
+        );
+        assert_eq!(world.get::<Interaction>(first), Some(&Interaction::Hovered));
+        assert_eq!(clicks(&mut world), vec![first]);
+    }
+
+    #[test]
+    fn cancelled_touch_does_not_click() {
+        let (mut world, mut schedule) = focus_world();
+        let button = spawn_button(&mut world, Vec2::new(100., 100.));
+
+        touch(
+            &mut world,
+            &mut schedule,
+            1,
+            TouchPhase::Started,
+            Vec2::new(100., 100.),
+        );
+        touch(
+            &mut world,
+            &mut schedule,
+            1,
+            TouchPhase::Cancelled,
+            Vec2::new(100., 100.),
+        );
+        assert_ne!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Pressed)
+        );
+        assert!(clicks(&mut world).is_empty());
+    }
+}
@@ -5,6 +5,8 @@
 mod flex;
 mod focus;
 mod geometry;
+mod measurement;
+mod navigation;
 mod render;
 mod stack;
 mod ui_node;
@@ -22,6 +24,8 @@ use bevy_render::extract_component::ExtractComponentPlugin;
 pub use flex::*;
 pub use focus::*;
 pub use geometry::*;
+pub use measurement::*;
+pub use navigation::*;
 pub use render::*;
 pub use ui_node::*;
 
@@ -29,7 +33,8 @@ pub use ui_node::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        camera_config::*, geometry::*, node_bundles::*, ui_node::*, widget::*, Interaction, UiScale,
+        camera_config::*, geometry::*, measurement::*, node_bundles::*, ui_node::*, widget::*,
+        Interaction, UiScale,
     };
 }
 
@@ -39,7 +44,10 @@ use bevy_input::InputSystem;
 use bevy_transform::TransformSystem;
 use stack::ui_stack_system;
 pub use stack::UiStack;
-use update::update_clipping_system;
+use update::{
+    resolve_box_shadows_system, resolve_outlines_system, update_clipping_system,
+    update_scroll_position_system, update_target_camera_system,
+};
 
 use crate::prelude::UiCameraConfig;
 
@@ -74,40 +82,89 @@ impl Default for UiScale {
     }
 }
 
+/// Configures how the UI reacts to input.
+#[derive(Debug, Resource)]
+pub struct UiConfig {
+    /// The distance in logical pixels the cursor may move during a press
+    /// for its release to still count as a [`Clicked`] event.
+    pub drag_threshold: f32,
+    /// The distance in logical pixels scrolled per line of a [`MouseWheel`](bevy_input::mouse::MouseWheel) event.
+    pub scroll_line_height: f32,
+    /// Whether moving the [`Focus`] past the last node in a direction wraps around to the
+    /// farthest node on the opposite side.
+    pub navigation_wrap_around: bool,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            drag_threshold: 4.0,
+            scroll_line_height: 20.0,
+            navigation_wrap_around: false,
+        }
+    }
+}
+
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(ExtractComponentPlugin::<UiCameraConfig>::default())
             .init_resource::<FlexSurface>()
             .init_resource::<UiScale>()
+            .init_resource::<UiConfig>()
             .init_resource::<UiStack>()
+            .add_event::<Clicked>()
             .register_type::<AlignContent>()
             .register_type::<AlignItems>()
             .register_type::<AlignSelf>()
-            .register_type::<CalculatedSize>()
             .register_type::<Direction>()
             .register_type::<Display>()
             .register_type::<FlexDirection>()
             .register_type::<FlexWrap>()
             .register_type::<FocusPolicy>()
+            .register_type::<GridAutoFlow>()
+            .register_type::<GridPlacement>()
+            .register_type::<GridTrack>()
+            .register_type::<GridTrackRepetition>()
             .register_type::<Interaction>()
             .register_type::<JustifyContent>()
+            .register_type::<MaxTrackSizingFunction>()
+            .register_type::<MinTrackSizingFunction>()
             .register_type::<Node>()
+            .register_type::<Outline>()
             // NOTE: used by Style::aspect_ratio
             .register_type::<Option<f32>>()
             .register_type::<Overflow>()
             .register_type::<PositionType>()
+            .register_type::<RepeatedGridTrack>()
+            // NOTE: used by the grid fields of Style
+            .register_type::<Vec<GridTrack>>()
+            .register_type::<Vec<RepeatedGridTrack>>()
+            .register_type::<ScrollPosition>()
             .register_type::<Size>()
             .register_type::<UiRect>()
             .register_type::<Style>()
+            .register_type::<TargetCamera>()
             .register_type::<BackgroundColor>()
+            .register_type::<BorderRadius>()
+            .register_type::<BoxShadow>()
+            .register_type::<CalculatedBoxShadow>()
+            // NOTE: used by the fields of BoxShadow and CalculatedBoxShadow
+            .register_type::<Vec<ShadowStyle>>()
+            .register_type::<Vec<ResolvedShadow>>()
             .register_type::<UiImage>()
+            .register_type::<UiTextureAtlasImage>()
             .register_type::<Val>()
             .register_type::<widget::Button>()
             .register_type::<widget::Label>()
+            .register_type::<widget::UiImageSize>()
             .configure_set(UiSystem::Focus.in_base_set(CoreSet::PreUpdate))
             .configure_set(UiSystem::Flex.in_base_set(CoreSet::PostUpdate))
             .configure_set(UiSystem::Stack.in_base_set(CoreSet::PostUpdate))
-            .add_system(ui_focus_system.in_set(UiSystem::Focus).after(InputSystem));
+            .add_systems(
+                (ui_focus_system, update_scroll_position_system)
+                    .in_set(UiSystem::Focus)
+                    .after(InputSystem),
+            );
         // add these systems to front because these must run before transform update systems
         #[cfg(feature = "bevy_text")]
         app.add_system(
@@ -127,7 +184,7 @@ impl Plugin for UiPlugin {
         #[cfg(feature = "bevy_text")]
         app.add_plugin(accessibility::AccessibilityPlugin);
         app.add_system({
-            let system = widget::update_image_calculated_size_system
+            let system = widget::update_image_content_size_system
                 .in_base_set(CoreSet::PostUpdate)
                 .before(UiSystem::Flex);
             // Potential conflicts: `Assets<Image>`
@@ -146,6 +203,15 @@ impl Plugin for UiPlugin {
                 .in_set(UiSystem::Flex)
                 .before(TransformSystem::TransformPropagate),
             ui_stack_system.in_set(UiSystem::Stack),
+            update_target_camera_system
+                .after(UiSystem::Flex)
+                .in_base_set(CoreSet::PostUpdate),
+            resolve_outlines_system
+                .after(UiSystem::Flex)
+                .in_base_set(CoreSet::PostUpdate),
+            resolve_box_shadows_system
+                .after(UiSystem::Flex)
+                .in_base_set(CoreSet::PostUpdate),
             update_clipping_system
                 .after(TransformSystem::TransformPropagate)
                 .in_base_set(CoreSet::PostUpdate),
@@ -0,0 +1,146 @@
+use bevy_ecs::prelude::Component;
+use bevy_math::Vec2;
+use std::fmt::Formatter;
+use std::sync::Arc;
+pub use taffy::style::AvailableSpace;
+
+/// A `Measure` is used to compute the size of a UI node whose size depends on its content,
+/// such as text, images or user-defined widgets.
+///
+/// All values are in logical pixels.
+pub trait Measure: Send + Sync + 'static {
+    /// Calculate the size of the node given the constraints.
+    ///
+    /// `width` and `height` are set if the layout has already determined that dimension of the node,
+    /// in which case the measure should usually return it unchanged.
+    /// `available_width` and `available_height` describe the space the node is laid out in.
+    fn measure(
+        &self,
+        width: Option<f32>,
+        height: Option<f32>,
+        available_width: AvailableSpace,
+        available_height: AvailableSpace,
+    ) -> Vec2;
+}
+
+/// A `FixedMeasure` is a `Measure` that ignores all constraints and
+/// always returns the same size.
+#[derive(Default, Clone)]
+pub struct FixedMeasure {
+    /// The size of the content in logical pixels
+    pub size: Vec2,
+}
+
+impl Measure for FixedMeasure {
+    fn measure(
+        &self,
+        width: Option<f32>,
+        height: Option<f32>,
+        _: AvailableSpace,
+        _: AvailableSpace,
+    ) -> Vec2 {
+        Vec2::new(width.unwrap_or(self.size.x), height.unwrap_or(self.size.y))
+    }
+}
+
+/// An `AspectRatioMeasure` is a `Measure` that preserves the aspect ratio of its content
+/// when only one dimension of the node is known.
+#[derive(Default, Clone)]
+pub struct AspectRatioMeasure {
+    /// The size of the content in logical pixels
+    pub size: Vec2,
+}
+
+impl Measure for AspectRatioMeasure {
+    fn measure(
+        &self,
+        width: Option<f32>,
+        height: Option<f32>,
+        _: AvailableSpace,
+        _: AvailableSpace,
+    ) -> Vec2 {
+        match (width, height) {
+            (None, None) => self.size,
+            (Some(width), None) => Vec2::new(width, width * self.size.y / self.size.x),
+            (None, Some(height)) => Vec2::new(height * self.size.x / self.size.y, height),
+            (Some(width), Some(height)) => Vec2::new(width, height),
+        }
+    }
+}
+
+/// A node with a `ContentSize` component is a node whose size depends on its content,
+/// as computed by its [`Measure`].
+///
+/// Replacing the measure with [`ContentSize::set`] schedules a new layout of the node.
+#[derive(Component, Clone, Default)]
+pub struct ContentSize {
+    /// The `Measure` used to compute the intrinsic size
+    pub(crate) measure: Option<Arc<dyn Measure>>,
+}
+
+impl ContentSize {
+    /// Set the `Measure` used to compute the intrinsic size of the node
+    pub fn set(&mut self, measure: impl Measure) {
+        self.measure = Some(Arc::new(measure));
+    }
+
+    /// Remove the `Measure`, the node will then be sized by its [`Style`](crate::Style) alone
+    pub fn clear(&mut self) {
+        self.measure = None;
+    }
+
+    /// Returns `true` if a `Measure` is set
+    pub fn has_measure(&self) -> bool {
+        self.measure.is_some()
+    }
+}
+
+impl std::fmt::Debug for ContentSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentSize")
+            .field("has_measure", &self.has_measure())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AspectRatioMeasure, AvailableSpace, FixedMeasure, Measure};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn fixed_measure_takes_known_dimensions() {
+        let measure = FixedMeasure {
+            size: Vec2::new(100., 50.),
+        };
+        let space = AvailableSpace::MaxContent;
+        assert_eq!(
+            measure.measure(None, None, space, space),
+            Vec2::new(100., 50.)
+        );
+        assert_eq!(
+            measure.measure(Some(30.), None, space, space),
+            Vec2::new(30., 50.)
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_measure_preserves_aspect_ratio() {
+        let measure = AspectRatioMeasure {
+            size: Vec2::new(100., 50.),
+        };
+        let space = AvailableSpace::MaxContent;
+        assert_eq!(
+            measure.measure(Some(200.), None, space, space),
+            Vec2::new(200., 100.)
+        );
+        assert_eq!(
+            measure.measure(None, Some(10.), space, space),
+            Vec2::new(20., 10.)
+        );
+        assert_eq!(
+            measure.measure(Some(1.), Some(2.), space, space),
+            Vec2::new(1., 2.)
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use crate::{ui_focus_system, Clicked, FocusPolicy, Interaction, Node, UiConfig, UiSystem};
+use bevy_app::{App, Plugin};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    event::{EventReader, EventWriter},
+    prelude::{Component, With},
+    schedule::{IntoSystemConfig, IntoSystemConfigs},
+    system::{Local, Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::Parent;
+use bevy_input::{
+    gamepad::{GamepadButton, GamepadButtonType},
+    keyboard::KeyCode,
+    Input, InputSystem,
+};
+use bevy_math::Vec2;
+use bevy_render::view::ComputedVisibility;
+use bevy_transform::components::GlobalTransform;
+use smallvec::SmallVec;
+
+/// Adds keyboard and gamepad navigation between UI nodes that have an [`Interaction`].
+///
+/// Directional input moves the [`Focus`] to the nearest node in that direction,
+/// and activating the focused node presses it as if it had been clicked.
+#[derive(Default)]
+pub struct UiNavigationPlugin;
+
+impl Plugin for UiNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Focus>()
+            .init_resource::<NavigationBindings>()
+            .add_event::<NavigationEvent>()
+            .add_event::<NavigationActivate>()
+            .add_systems(
+                (
+                    navigation_input_system.after(InputSystem),
+                    ui_navigation_system.after(ui_focus_system),
+                )
+                    .chain()
+                    .in_set(UiSystem::Focus),
+            );
+    }
+}
+
+/// The UI node that currently has the navigation focus, if any.
+///
+/// Updated by [`ui_navigation_system`], but can also be set directly.
+#[derive(Resource, Deref, DerefMut, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Focus(pub Option<Entity>);
+
+/// A direction in which the [`Focus`] can be moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavigationDirection {
+    /// Towards the top of the screen
+    Up,
+    /// Towards the bottom of the screen
+    Down,
+    /// Towards the left of the screen
+    Left,
+    /// Towards the right of the screen
+    Right,
+}
+
+impl NavigationDirection {
+    /// The direction as a unit vector in UI coordinates, where y points down.
+    pub fn as_vec2(self) -> Vec2 {
+        match self {
+            NavigationDirection::Up => Vec2::NEG_Y,
+            NavigationDirection::Down => Vec2::Y,
+            NavigationDirection::Left => Vec2::NEG_X,
+            NavigationDirection::Right => Vec2::X,
+        }
+    }
+}
+
+/// An event requesting the [`Focus`] to move in a [`NavigationDirection`].
+///
+/// Sent by [`navigation_input_system`] according to the [`NavigationBindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavigationEvent {
+    /// The direction to move the focus in
+    pub direction: NavigationDirection,
+}
+
+/// An event requesting the focused node to be activated.
+///
+/// Sent by [`navigation_input_system`] according to the [`NavigationBindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavigationActivate;
+
+/// Overrides the spatial navigation from a node with explicit neighbors.
+///
+/// Directions set to `None` fall back to choosing the nearest node in that direction.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NavNeighbors {
+    /// The node focused when navigating up
+    pub up: Option<Entity>,
+    /// The node focused when navigating down
+    pub down: Option<Entity>,
+    /// The node focused when navigating left
+    pub left: Option<Entity>,
+    /// The node focused when navigating right
+    pub right: Option<Entity>,
+}
+
+impl NavNeighbors {
+    /// Returns the explicit neighbor in the given `direction`, if any.
+    pub fn get(&self, direction: NavigationDirection) -> Option<Entity> {
+        match direction {
+            NavigationDirection::Up => self.up,
+            NavigationDirection::Down => self.down,
+            NavigationDirection::Left => self.left,
+            NavigationDirection::Right => self.right,
+        }
+    }
+}
+
+/// The inputs that [`navigation_input_system`] turns into navigation events.
+#[derive(Resource, Clone, Debug)]
+pub struct NavigationBindings {
+    /// Keys sending a [`NavigationEvent`] in the associated direction
+    pub keys: Vec<(KeyCode, NavigationDirection)>,
+    /// Gamepad buttons sending a [`NavigationEvent`] in the associated direction
+    pub gamepad_buttons: Vec<(GamepadButtonType, NavigationDirection)>,
+    /// Keys sending a [`NavigationActivate`] event
+    pub activate_keys: Vec<KeyCode>,
+    /// Gamepad buttons sending a [`NavigationActivate`] event
+    pub activate_gamepad_buttons: Vec<GamepadButtonType>,
+}
+
+impl Default for NavigationBindings {
+    fn default() -> Self {
+        Self {
+            keys: vec![
+                (KeyCode::Up, NavigationDirection::Up),
+                (KeyCode::Down, NavigationDirection::Down),
+                (KeyCode::Left, NavigationDirection::Left),
+                (KeyCode::Right, NavigationDirection::Right),
+            ],
+            gamepad_buttons: vec![
+                (GamepadButtonType::DPadUp, NavigationDirection::Up),
+                (GamepadButtonType::DPadDown, NavigationDirection::Down),
+                (GamepadButtonType::DPadLeft, NavigationDirection::Left),
+                (GamepadButtonType::DPadRight, NavigationDirection::Right),
+            ],
+            activate_keys: vec![KeyCode::Return, KeyCode::Space],
+            activate_gamepad_buttons: vec![GamepadButtonType::South],
+        }
+    }
+}
+
+/// The system that sends navigation events for the just pressed [`NavigationBindings`].
+pub fn navigation_input_system(
+    bindings: Res<NavigationBindings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_button_input: Res<Input<GamepadButton>>,
+    mut navigation_events: EventWriter<NavigationEvent>,
+    mut activate_events: EventWriter<NavigationActivate>,
+) {
+    let gamepad_just_pressed = |button_type: GamepadButtonType| {
+        gamepad_button_input
+            .get_just_pressed()
+            .any(|button| button.button_type == button_type)
+    };
+
+    for &(key, direction) in &bindings.keys {
+        if keyboard_input.just_pressed(key) {
+            navigation_events.send(NavigationEvent { direction });
+        }
+    }
+    for &(button_type, direction) in &bindings.gamepad_buttons {
+        if gamepad_just_pressed(button_type) {
+            navigation_events.send(NavigationEvent { direction });
+        }
+    }
+
+    let activated = bindings
+        .activate_keys
+        .iter()
+        .any(|&key| keyboard_input.just_pressed(key))
+        || bindings
+            .activate_gamepad_buttons
+            .iter()
+            .any(|&button_type| gamepad_just_pressed(button_type));
+    if activated {
+        activate_events.send(NavigationActivate);
+    }
+}
+
+/// Nodes pressed by the last activation, released again on the next frame
+#[derive(Default)]
+pub struct NavigationState {
+    pressed_entities: SmallVec<[Entity; 1]>,
+}
+
+/// The system that moves the [`Focus`] between UI nodes with an [`Interaction`] and
+/// presses the focused node when it is activated.
+///
+/// Moving in a direction follows the focused node's [`NavNeighbors`] if it sets one for that
+/// direction, and otherwise focuses the nearest visible node whose center lies in that direction,
+/// preferring nodes aligned with the focused one. When there is none, the focus stays put, or with
+/// [`UiConfig::navigation_wrap_around`] wraps to the farthest node on the opposite side.
+/// Without a valid focus, any direction focuses the top-left-most node.
+///
+/// Activating the focused node sets its [`Interaction`] to [`Interaction::Pressed`] for one frame
+/// and sends a [`Clicked`] event for it. Like a mouse press, the press passes through to the
+/// nearest ancestors with an [`Interaction`] while their [`FocusPolicy`] is [`FocusPolicy::Pass`].
+#[allow(clippy::too_many_arguments)]
+pub fn ui_navigation_system(
+    mut state: Local<NavigationState>,
+    mut focus: ResMut<Focus>,
+    ui_config: Res<UiConfig>,
+    mut navigation_events: EventReader<NavigationEvent>,
+    mut activate_events: EventReader<NavigationActivate>,
+    mut clicked_events: EventWriter<Clicked>,
+    nodes: Query<(Entity, &Node, &GlobalTransform, Option<&ComputedVisibility>), With<Interaction>>,
+    neighbors: Query<&NavNeighbors>,
+    mut interactions: Query<(&mut Interaction, Option<&FocusPolicy>)>,
+    parents: Query<&Parent>,
+) {
+    for entity in state.pressed_entities.drain(..) {
+        if let Ok((mut interaction, _)) = interactions.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    let center = |entity: Entity| -> Option<Vec2> {
+        let (_, node, transform, visibility) = nodes.get(entity).ok()?;
+        if visibility.map_or(false, |visibility| !visibility.is_visible()) {
+            return None;
+        }
+        Some(node.logical_rect(transform).center())
+    };
+    let candidates = || {
+        nodes
+            .iter()
+            .filter_map(|(entity, ..)| Some((entity, center(entity)?)))
+    };
+
+    for event in navigation_events.iter() {
+        let Some((current, current_center)) =
+            focus.0.and_then(|entity| Some((entity, center(entity)?)))
+        else {
+            focus.0 = candidates()
+                .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+                .map(|(entity, _)| entity);
+            continue;
+        };
+
+        if let Some(neighbor) = neighbors
+            .get(current)
+            .ok()
+            .and_then(|neighbors| neighbors.get(event.direction))
+        {
+            if center(neighbor).is_some() {
+                focus.0 = Some(neighbor);
+                continue;
+            }
+        }
+
+        // Candidates are scored by their distance along the direction, with their distance away
+        // from the line through the focused node counting double.
+        let direction = event.direction.as_vec2();
+        let scored: Vec<(Entity, f32, f32)> = candidates()
+            .filter(|&(entity, _)| entity != current)
+            .map(|(entity, center)| {
+                let offset = center - current_center;
+                let along = offset.dot(direction);
+                let across = (offset - along * direction).length();
+                (entity, along, along + 2. * across)
+            })
+            .collect();
+        let best = |ahead: bool| {
+            scored
+                .iter()
+                .filter(|(_, along, _)| if ahead { *along > 0. } else { *along < 0. })
+                .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+        };
+
+        let mut next = best(true);
+        if next.is_none() && ui_config.navigation_wrap_around {
+            next = best(false);
+        }
+        if let Some(&(entity, ..)) = next {
+            focus.0 = Some(entity);
+        }
+    }
+
+    if activate_events.iter().count() == 0 {
+        return;
+    }
+    let Some(focused) = focus.0.filter(|&entity| center(entity).is_some()) else {
+        return;
+    };
+
+    clicked_events.send(Clicked { entity: focused });
+    let mut entity = Some(focused);
+    while let Some(current) = entity {
+        let mut focus_policy = FocusPolicy::Pass;
+        if let Ok((mut interaction, policy)) = interactions.get_mut(current) {
+            interaction.set_if_neq(Interaction::Pressed);
+            state.pressed_entities.push(current);
+            focus_policy = policy.copied().unwrap_or(FocusPolicy::Block);
+        }
+        if focus_policy == FocusPolicy::Block {
+            break;
+        }
+        entity = parents.get(current).ok().map(|parent| parent.get());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ui_navigation_system, Clicked, Focus, FocusPolicy, Interaction, NavNeighbors,
+        NavigationActivate, NavigationDirection, NavigationEvent, Node, UiConfig,
+    };
+    use bevy_ecs::{entity::Entity, event::Events, schedule::Schedule, world::World};
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::Vec2;
+    use bevy_transform::components::GlobalTransform;
+
+    fn navigation_world() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<Focus>();
+        world.init_resource::<UiConfig>();
+        world.init_resource::<Events<NavigationEvent>>();
+        world.init_resource::<Events<NavigationActivate>>();
+        world.init_resource::<Events<Clicked>>();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(ui_navigation_system);
+        (world, schedule)
+    }
+
+    fn button(center: Vec2) -> (Node, GlobalTransform, Interaction) {
+        (
+            Node {
+                calculated_size: Vec2::splat(100.),
+                ..Default::default()
+            },
+            GlobalTransform::from_translation(center.extend(0.)),
+            Interaction::default(),
+        )
+    }
+
+    /// Spawns a 3x3 grid of 100x100 buttons, indexed as `grid[row][column]`.
+    fn spawn_grid(world: &mut World) -> [[Entity; 3]; 3] {
+        [0, 1, 2].map(|row| {
+            [0, 1, 2].map(|column| {
+                let center = Vec2::new(column as f32, row as f32) * 120. + 50.;
+                world.spawn(button(center)).id()
+            })
+        })
+    }
+
+    /// Sends each direction in turn, returning the focused node after each one.
+    fn navigate(
+        world: &mut World,
+        schedule: &mut Schedule,
+        directions: &[NavigationDirection],
+    ) -> Vec<Option<Entity>> {
+        directions
+            .iter()
+            .map(|&direction| {
+                world.send_event(NavigationEvent { direction });
+                schedule.run(world);
+                world.resource::<Focus>().0
+            })
+            .collect()
+    }
+
+    use NavigationDirection::{Down, Left, Right, Up};
+
+    #[test]
+    fn spatial_navigation_follows_the_grid() {
+        let (mut world, mut schedule) = navigation_world();
+        let grid = spawn_grid(&mut world);
+
+        let path = navigate(
+            &mut world,
+            &mut schedule,
+            &[
+                Right, Right, Right, Right, Down, Left, Down, Down, Left, Left, Up,
+            ],
+        );
+        let expected = [
+            grid[0][0], grid[0][1], grid[0][2], grid[0][2], grid[1][2], grid[1][1], grid[2][1],
+            grid[2][1], grid[2][0], grid[2][0], grid[1][0],
+        ];
+        assert_eq!(path, expected.map(Some));
+    }
+
+    #[test]
+    fn navigation_wraps_around() {
+        let (mut world, mut schedule) = navigation_world();
+        world.resource_mut::<UiConfig>().navigation_wrap_around = true;
+        let grid = spawn_grid(&mut world);
+        world.resource_mut::<Focus>().0 = Some(grid[0][2]);
+
+        let path = navigate(&mut world, &mut schedule, &[Right, Up, Left, Down, Down]);
+        let expected = [grid[0][0], grid[2][0], grid[2][2], grid[0][2], grid[1][2]];
+        assert_eq!(path, expected.map(Some));
+    }
+
+    #[test]
+    fn nav_neighbors_override_spatial_navigation() {
+        let (mut world, mut schedule) = navigation_world();
+        let grid = spawn_grid(&mut world);
+        world.entity_mut(grid[0][0]).insert(NavNeighbors {
+            right: Some(grid[2][2]),
+            ..Default::default()
+        });
+        world.resource_mut::<Focus>().0 = Some(grid[0][0]);
+
+        let path = navigate(&mut world, &mut schedule, &[Down, Up, Right, Left]);
+        let expected = [grid[1][0], grid[0][0], grid[2][2], grid[2][1]];
+        assert_eq!(path, expected.map(Some));
+    }
+
+    #[test]
+    fn activate_presses_the_focused_node_for_one_frame() {
+        let (mut world, mut schedule) = navigation_world();
+        let mut button_entity = None;
+        let panel = world
+            .spawn((button(Vec2::splat(100.)), FocusPolicy::Block))
+            .with_children(|parent| {
+                button_entity = Some(
+                    parent
+                        .spawn((button(Vec2::splat(100.)), FocusPolicy::Pass))
+                        .id(),
+                );
+            })
+            .id();
+        let button_entity = button_entity.unwrap();
+        world.resource_mut::<Focus>().0 = Some(button_entity);
+
+        world.send_event(NavigationActivate);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Interaction>(button_entity),
+            Some(&Interaction::Pressed)
+        );
+        // the press passes through the button to its panel
+        assert_eq!(world.get::<Interaction>(panel), Some(&Interaction::Pressed));
+        let clicked: Vec<_> = world
+            .resource_mut::<Events<Clicked>>()
+            .drain()
+            .map(|clicked| clicked.entity)
+            .collect();
+        assert_eq!(clicked, vec![button_entity]);
+
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Interaction>(button_entity),
+            Some(&Interaction::None)
+        );
+        assert_eq!(world.get::<Interaction>(panel), Some(&Interaction::None));
+    }
+}
@@ -1,8 +1,8 @@
 //! This module contains basic node bundles used to build UIs
 
 use crate::{
-    widget::Button, BackgroundColor, CalculatedSize, FocusPolicy, Interaction, Node, Style,
-    UiImage, ZIndex,
+    widget::{Button, UiImageSize},
+    BackgroundColor, ContentSize, FocusPolicy, Interaction, Node, Style, UiImage, ZIndex,
 };
 use bevy_ecs::bundle::Bundle;
 use bevy_render::{
@@ -68,8 +68,12 @@ pub struct ImageBundle {
     pub node: Node,
     /// Describes the style including flexbox settings
     pub style: Style,
-    /// The calculated size based on the given image
-    pub calculated_size: CalculatedSize,
+    /// The size of the node's content, measured from the given image
+    pub content_size: ContentSize,
+    /// The size of the image in pixels
+    ///
+    /// This field is set automatically
+    pub image_size: UiImageSize,
     /// The background color, which serves as a "fill" for this node
     ///
     /// Combines with `UiImage` to tint the provided image.
@@ -106,8 +110,8 @@ pub struct TextBundle {
     pub style: Style,
     /// Contains the text of the node
     pub text: Text,
-    /// The calculated size based on the given image
-    pub calculated_size: CalculatedSize,
+    /// The size of the node's content, measured from the given text
+    pub content_size: ContentSize,
     /// Whether this node should block interaction with lower nodes
     pub focus_policy: FocusPolicy,
     /// The transform of the node
@@ -135,7 +139,7 @@ impl Default for TextBundle {
     fn default() -> Self {
         Self {
             text: Default::default(),
-            calculated_size: Default::default(),
+            content_size: Default::default(),
             // Transparent background
             background_color: BackgroundColor(Color::NONE),
             node: Default::default(),
@@ -8,11 +8,15 @@ use bevy_window::{PrimaryWindow, Window};
 pub use pipeline::*;
 pub use render_pass::*;
 
-use crate::{prelude::UiCameraConfig, BackgroundColor, CalculatedClip, Node, UiImage, UiStack};
+use crate::{
+    camera_config::DefaultUiCamera, prelude::UiCameraConfig, BackgroundColor, BorderRadius,
+    CalculatedBoxShadow, CalculatedClip, Node, Outline, ResolvedShadow, TargetCamera, UiImage,
+    UiStack, UiTextureAtlasImage,
+};
 use bevy_app::prelude::*;
 use bevy_asset::{load_internal_asset, AssetEvent, Assets, Handle, HandleUntyped};
 use bevy_ecs::prelude::*;
-use bevy_math::{Mat4, Rect, UVec4, Vec2, Vec3, Vec4Swizzles};
+use bevy_math::{Mat4, Rect, UVec4, Vec2, Vec3, Vec4, Vec4Swizzles};
 use bevy_reflect::TypeUuid;
 use bevy_render::texture::DEFAULT_IMAGE_HANDLE;
 use bevy_render::{
@@ -24,12 +28,10 @@ use bevy_render::{
     render_resource::*,
     renderer::{RenderDevice, RenderQueue},
     texture::Image,
-    view::{ComputedVisibility, ExtractedView, ViewUniforms},
+    view::{ComputedVisibility, ExtractedView, Msaa, ViewUniforms},
     Extract, RenderApp, RenderSet,
 };
-use bevy_sprite::SpriteAssetEvents;
-#[cfg(feature = "bevy_text")]
-use bevy_sprite::TextureAtlas;
+use bevy_sprite::{SpriteAssetEvents, TextureAtlas};
 #[cfg(feature = "bevy_text")]
 use bevy_text::{PositionedGlyph, Text, TextLayoutInfo};
 use bevy_transform::components::GlobalTransform;
@@ -43,12 +45,26 @@ pub mod node {
 }
 
 pub mod draw_ui_graph {
-    pub const NAME: &str = "draw_ui";
+    use bevy_render::render_graph::{RenderLabel, RenderSubGraph};
+
+    /// The label of the sub graph drawing the UI of a camera.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderSubGraph)]
+    pub struct DrawUi;
+
+    /// The labels of the UI render graph nodes.
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, RenderLabel)]
+    pub enum NodeUi {
+        UiPass,
+    }
+
+    pub const NAME: DrawUi = DrawUi;
     pub mod input {
         pub const VIEW_ENTITY: &str = "view_entity";
     }
     pub mod node {
-        pub const UI_PASS: &str = "ui_pass";
+        use super::NodeUi;
+
+        pub const UI_PASS: NodeUi = NodeUi::UiPass;
     }
 }
 
@@ -81,6 +97,8 @@ pub fn build_ui_render(app: &mut App) {
                 extract_default_ui_camera_view::<Camera2d>,
                 extract_default_ui_camera_view::<Camera3d>,
                 extract_uinodes.in_set(RenderUiSystem::ExtractNode),
+                extract_uinode_outlines.after(RenderUiSystem::ExtractNode),
+                extract_uinode_box_shadows.after(RenderUiSystem::ExtractNode),
                 #[cfg(feature = "bevy_text")]
                 extract_text_uinodes.after(RenderUiSystem::ExtractNode),
             )
@@ -169,14 +187,31 @@ fn get_ui_graph(render_app: &mut App) -> RenderGraph {
 
 pub struct ExtractedUiNode {
     pub stack_index: usize,
+    /// The camera this node is rendered to
+    pub camera_entity: Entity,
     pub transform: Mat4,
     pub color: Color,
     pub rect: Rect,
     pub image: Handle<Image>,
     pub atlas_size: Option<Vec2>,
+    /// The region of the texture that is stretched over the node, in normalized texture coordinates.
+    ///
+    /// The whole texture is used if this is `None`.
+    pub uv_rect: Option<Rect>,
     pub clip: Option<Rect>,
+    /// The corner radii of the clip, in the order `[top_left, top_right, bottom_right, bottom_left]`
+    pub clip_border_radius: [f32; 4],
     pub flip_x: bool,
     pub flip_y: bool,
+    /// The corner radii of the node, in the order `[top_left, top_right, bottom_right, bottom_left]`
+    pub border_radius: [f32; 4],
+    /// If greater than zero, only a band of this width along the edge of the (rounded) rect is drawn
+    pub border_width: f32,
+    /// If greater than zero, the (rounded) rect is shrunk by this distance on each side
+    /// and faded out over this distance on both sides of its edge
+    pub blur_radius: f32,
+    /// Shadows are drawn before anything else with the same `stack_index`
+    pub is_shadow: bool,
 }
 
 #[derive(Resource, Default)]
@@ -194,44 +229,356 @@ pub fn extract_uinodes(
             &GlobalTransform,
             &BackgroundColor,
             Option<&UiImage>,
+            Option<&UiTextureAtlasImage>,
             &ComputedVisibility,
             Option<&CalculatedClip>,
+            Option<&BorderRadius>,
+            Option<&TargetCamera>,
         )>,
     >,
+    texture_atlases: Extract<Res<Assets<TextureAtlas>>>,
+    default_ui_camera: Extract<DefaultUiCamera>,
 ) {
     extracted_uinodes.uinodes.clear();
+    let default_camera_entity = default_ui_camera.get();
     for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
-        if let Ok((uinode, transform, color, maybe_image, visibility, clip)) =
-            uinode_query.get(*entity)
+        if let Ok((
+            uinode,
+            transform,
+            color,
+            maybe_image,
+            atlas_image,
+            visibility,
+            clip,
+            border_radius,
+            target_camera,
+        )) = uinode_query.get(*entity)
         {
             // Skip invisible and completely transparent nodes
             if !visibility.is_visible() || color.0.a() == 0.0 {
                 continue;
             }
 
-            let (image, flip_x, flip_y) = if let Some(image) = maybe_image {
+            let Some(camera_entity) = target_camera
+                .map(TargetCamera::entity)
+                .or(default_camera_entity)
+            else {
+                continue;
+            };
+
+            let (image, flip_x, flip_y, color, uv_rect) = if let Some(image) = maybe_image {
                 // Skip loading images
                 if !images.contains(&image.texture) {
                     continue;
                 }
-                (image.texture.clone_weak(), image.flip_x, image.flip_y)
+                let uv_rect = match atlas_image {
+                    Some(atlas_image) => {
+                        // Skip loading atlases
+                        let Some(uv_rect) = texture_atlases
+                            .get(&atlas_image.atlas)
+                            .and_then(|atlas| atlas_uv_rect(atlas, atlas_image.index))
+                        else {
+                            continue;
+                        };
+                        Some(uv_rect)
+                    }
+                    None => None,
+                };
+                (
+                    image.texture.clone_weak(),
+                    image.flip_x,
+                    image.flip_y,
+                    color.0 * Vec4::from_array(image.color.as_rgba_f32()),
+                    uv_rect,
+                )
             } else {
-                (DEFAULT_IMAGE_HANDLE.typed().clone_weak(), false, false)
+                (
+                    DEFAULT_IMAGE_HANDLE.typed().clone_weak(),
+                    false,
+                    false,
+                    color.0,
+                    None,
+                )
             };
 
             extracted_uinodes.uinodes.push(ExtractedUiNode {
                 stack_index,
+                camera_entity,
                 transform: transform.compute_matrix(),
-                color: color.0,
+                color,
                 rect: Rect {
                     min: Vec2::ZERO,
                     max: uinode.calculated_size,
                 },
                 image,
                 atlas_size: None,
+                uv_rect,
                 clip: clip.map(|clip| clip.clip),
+                clip_border_radius: clip.map(|clip| clip.border_radius).unwrap_or_default(),
                 flip_x,
                 flip_y,
+                border_radius: border_radius
+                    .map(|border_radius| border_radius.resolve(uinode.size()))
+                    .unwrap_or_default(),
+                border_width: 0.,
+                blur_radius: 0.,
+                is_shadow: false,
+            });
+        }
+    }
+}
+
+/// Returns the frame `index` of `atlas` in normalized texture coordinates,
+/// or `None` if the atlas doesn't contain the frame.
+fn atlas_uv_rect(atlas: &TextureAtlas, index: usize) -> Option<Rect> {
+    let frame = atlas.textures.get(index)?;
+    Some(Rect {
+        min: frame.min / atlas.size,
+        max: frame.max / atlas.size,
+    })
+}
+
+/// Returns the top, bottom, left and right edges of a node's outline,
+/// relative to the center of a node of size `node_size`.
+///
+/// The outline surrounds the node at a distance of `offset` and is `width` thick.
+pub(crate) fn outline_edges(node_size: Vec2, width: f32, offset: f32) -> [Rect; 4] {
+    let inner = 0.5 * node_size + offset;
+    let outer = inner + width;
+    [
+        // top
+        Rect {
+            min: -outer,
+            max: Vec2::new(outer.x, -inner.y),
+        },
+        // bottom
+        Rect {
+            min: Vec2::new(-outer.x, inner.y),
+            max: outer,
+        },
+        // left
+        Rect {
+            min: Vec2::new(-outer.x, -inner.y),
+            max: Vec2::new(-inner.x, inner.y),
+        },
+        // right
+        Rect {
+            min: Vec2::new(inner.x, -inner.y),
+            max: Vec2::new(outer.x, inner.y),
+        },
+    ]
+}
+
+pub fn extract_uinode_outlines(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    ui_stack: Extract<Res<UiStack>>,
+    outline_query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            &Outline,
+            &ComputedVisibility,
+            Option<&CalculatedClip>,
+            Option<&BorderRadius>,
+            Option<&TargetCamera>,
+        )>,
+    >,
+    default_ui_camera: Extract<DefaultUiCamera>,
+) {
+    let image = DEFAULT_IMAGE_HANDLE.typed();
+    let default_camera_entity = default_ui_camera.get();
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        if let Ok((
+            node,
+            global_transform,
+            outline,
+            visibility,
+            clip,
+            border_radius,
+            target_camera,
+        )) = outline_query.get(*entity)
+        {
+            // Skip invisible outlines
+            if !visibility.is_visible() || outline.color.a() == 0. || node.outline_width() <= 0. {
+                continue;
+            }
+
+            let Some(camera_entity) = target_camera
+                .map(TargetCamera::entity)
+                .or(default_camera_entity)
+            else {
+                continue;
+            };
+
+            // Each edge is positioned relative to the node's transform,
+            // so the outline follows any rotation or scaling of the node.
+            let transform = global_transform.compute_matrix();
+            let clip_border_radius = clip.map(|clip| clip.border_radius).unwrap_or_default();
+
+            let border_radius = border_radius
+                .map(|border_radius| border_radius.resolve(node.size()))
+                .unwrap_or_default();
+            if border_radius.iter().any(|radius| *radius > 0.) {
+                // Rounded outlines are drawn as a single band following the rounded corners
+                let distance = node.outline_offset() + node.outline_width();
+                let size = node.size() + 2. * distance;
+                extracted_uinodes.uinodes.push(ExtractedUiNode {
+                    stack_index,
+                    camera_entity,
+                    transform,
+                    color: outline.color,
+                    rect: Rect {
+                        min: Vec2::ZERO,
+                        max: size,
+                    },
+                    image: image.clone_weak(),
+                    atlas_size: None,
+                    uv_rect: None,
+                    clip: clip.map(|clip| clip.clip),
+                    clip_border_radius,
+                    flip_x: false,
+                    flip_y: false,
+                    border_radius: border_radius.map(|radius| {
+                        if radius > 0. {
+                            radius + distance
+                        } else {
+                            0.
+                        }
+                    }),
+                    border_width: node.outline_width(),
+                    blur_radius: 0.,
+                    is_shadow: false,
+                });
+                continue;
+            }
+
+            for edge in outline_edges(node.size(), node.outline_width(), node.outline_offset()) {
+                let size = edge.size();
+                if size.x <= 0. || size.y <= 0. {
+                    continue;
+                }
+                extracted_uinodes.uinodes.push(ExtractedUiNode {
+                    stack_index,
+                    camera_entity,
+                    transform: transform * Mat4::from_translation(edge.center().extend(0.)),
+                    color: outline.color,
+                    rect: Rect {
+                        min: Vec2::ZERO,
+                        max: size,
+                    },
+                    image: image.clone_weak(),
+                    atlas_size: None,
+                    uv_rect: None,
+                    clip: clip.map(|clip| clip.clip),
+                    clip_border_radius,
+                    flip_x: false,
+                    flip_y: false,
+                    border_radius: [0.; 4],
+                    border_width: 0.,
+                    blur_radius: 0.,
+                    is_shadow: false,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the quad drawn for a `shadow` of a node of size `node_size`, relative to the center of the node,
+/// and the corner radii of the shadow.
+///
+/// The shadow is a copy of the node grown by the spread radius, following its `border_radius`.
+/// The quad is grown further by the blur radius, to leave room for the blur around the shadow.
+pub(crate) fn box_shadow_geometry(
+    node_size: Vec2,
+    border_radius: [f32; 4],
+    shadow: &ResolvedShadow,
+) -> (Rect, [f32; 4]) {
+    let shadow_size = (node_size + 2. * shadow.spread_radius).max(Vec2::ZERO);
+    let max_radius = 0.5 * shadow_size.min_element();
+    let radius = border_radius.map(|radius| {
+        if radius > 0. {
+            (radius + shadow.spread_radius).clamp(0., max_radius)
+        } else {
+            0.
+        }
+    });
+    let quad = Rect::from_center_size(shadow.offset, shadow_size + 2. * shadow.blur_radius);
+    (quad, radius)
+}
+
+pub fn extract_uinode_box_shadows(
+    mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    ui_stack: Extract<Res<UiStack>>,
+    shadow_query: Extract<
+        Query<(
+            &Node,
+            &GlobalTransform,
+            &CalculatedBoxShadow,
+            &ComputedVisibility,
+            Option<&CalculatedClip>,
+            Option<&BorderRadius>,
+            Option<&TargetCamera>,
+        )>,
+    >,
+    default_ui_camera: Extract<DefaultUiCamera>,
+) {
+    let image = DEFAULT_IMAGE_HANDLE.typed();
+    let default_camera_entity = default_ui_camera.get();
+    for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
+        let Ok((
+            node,
+            global_transform,
+            box_shadow,
+            visibility,
+            clip,
+            border_radius,
+            target_camera,
+        )) = shadow_query.get(*entity)
+        else {
+            continue;
+        };
+        if !visibility.is_visible() {
+            continue;
+        }
+
+        let Some(camera_entity) = target_camera
+            .map(TargetCamera::entity)
+            .or(default_camera_entity)
+        else {
+            continue;
+        };
+
+        let transform = global_transform.compute_matrix();
+        let border_radius = border_radius
+            .map(|border_radius| border_radius.resolve(node.size()))
+            .unwrap_or_default();
+        // The first shadow is drawn on top of the others
+        for shadow in box_shadow.shadows.iter().rev() {
+            let (quad, radius) = box_shadow_geometry(node.size(), border_radius, shadow);
+            // Skip invisible shadows
+            if shadow.color.a() == 0. || quad.is_empty() {
+                continue;
+            }
+            extracted_uinodes.uinodes.push(ExtractedUiNode {
+                stack_index,
+                camera_entity,
+                transform: transform * Mat4::from_translation(quad.center().extend(0.)),
+                color: shadow.color,
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: quad.size(),
+                },
+                image: image.clone_weak(),
+                atlas_size: None,
+                uv_rect: None,
+                clip: clip.map(|clip| clip.clip),
+                clip_border_radius: clip.map(|clip| clip.border_radius).unwrap_or_default(),
+                flip_x: false,
+                flip_y: false,
+                border_radius: radius,
+                border_width: 0.,
+                blur_radius: shadow.blur_radius,
+                is_shadow: true,
             });
         }
     }
@@ -285,6 +632,8 @@ pub fn extract_default_ui_camera_view<T: Component>(
                         physical_size.y,
                     ),
                     color_grading: Default::default(),
+                    // The UI is drawn directly to the resolved main texture
+                    msaa: Msaa::Off,
                 })
                 .id();
             commands.get_or_spawn(entity).insert((
@@ -309,9 +658,13 @@ pub fn extract_text_uinodes(
             &TextLayoutInfo,
             &ComputedVisibility,
             Option<&CalculatedClip>,
+            Option<&TargetCamera>,
         )>,
     >,
+    default_ui_camera: Extract<DefaultUiCamera>,
 ) {
+    let default_camera_entity = default_ui_camera.get();
+
     // TODO: Support window-independent UI scale: https://github.com/bevyengine/bevy/issues/5621
     let scale_factor = windows
         .get_single()
@@ -321,14 +674,28 @@ pub fn extract_text_uinodes(
     let scaling = Mat4::from_scale(Vec3::splat(scale_factor.recip()));
 
     for (stack_index, entity) in ui_stack.uinodes.iter().enumerate() {
-        if let Ok((uinode, global_transform, text, text_layout_info, visibility, clip)) =
-            uinode_query.get(*entity)
+        if let Ok((
+            uinode,
+            global_transform,
+            text,
+            text_layout_info,
+            visibility,
+            clip,
+            target_camera,
+        )) = uinode_query.get(*entity)
         {
             // Skip if not visible or if size is set to zero (e.g. when a parent is set to `Display::None`)
             if !visibility.is_visible() || uinode.size().x == 0. || uinode.size().y == 0. {
                 continue;
             }
 
+            let Some(camera_entity) = target_camera
+                .map(TargetCamera::entity)
+                .or(default_camera_entity)
+            else {
+                continue;
+            };
+
             let transform = global_transform.compute_matrix()
                 * Mat4::from_translation(-0.5 * uinode.size().extend(0.))
                 * scaling;
@@ -350,14 +717,21 @@ pub fn extract_text_uinodes(
 
                 extracted_uinodes.uinodes.push(ExtractedUiNode {
                     stack_index,
+                    camera_entity,
                     transform: transform * Mat4::from_translation(position.extend(0.)),
                     color,
                     rect: atlas.textures[atlas_info.glyph_index],
                     image: atlas.texture.clone_weak(),
                     atlas_size: Some(atlas.size),
+                    uv_rect: None,
                     clip: clip.map(|clip| clip.clip),
+                    clip_border_radius: clip.map(|clip| clip.border_radius).unwrap_or_default(),
                     flip_x: false,
                     flip_y: false,
+                    border_radius: [0.; 4],
+                    border_width: 0.,
+                    blur_radius: 0.,
+                    is_shadow: false,
                 });
             }
         }
@@ -370,6 +744,20 @@ struct UiVertex {
     pub position: [f32; 3],
     pub uv: [f32; 2],
     pub color: [f32; 4],
+    /// Position relative to the center of the node
+    pub point: [f32; 2],
+    /// Size of the (unclipped) node
+    pub size: [f32; 2],
+    /// Corner radii of the node: `[top_left, top_right, bottom_right, bottom_left]`
+    pub radius: [f32; 4],
+    /// Width of the band drawn along the edge of the node, zero to fill the whole node
+    pub border_width: f32,
+    /// Distance over which the edge of a box shadow is blurred, zero for anything but blurred shadows
+    pub blur_radius: f32,
+    /// The clipping rect as `[min_x, min_y, max_x, max_y]`
+    pub clip: [f32; 4],
+    /// Corner radii of the clipping rect: `[top_left, top_right, bottom_right, bottom_left]`
+    pub clip_radius: [f32; 4],
 }
 
 #[derive(Resource)]
@@ -401,6 +789,47 @@ pub struct UiBatch {
     pub range: Range<u32>,
     pub image: Handle<Image>,
     pub z: f32,
+    /// The camera this batch is rendered to
+    pub camera: Entity,
+}
+
+/// Computes the texture coordinates of the corners of a node,
+/// after its quad has been clipped by `positions_diff`.
+fn node_uvs(extracted_uinode: &ExtractedUiNode, positions_diff: [Vec2; 4]) -> [Vec2; 4] {
+    let uinode_rect = extracted_uinode.rect;
+    let atlas_extent = extracted_uinode.atlas_size.unwrap_or(uinode_rect.max);
+    let mut uvs = [
+        Vec2::new(
+            uinode_rect.min.x + positions_diff[0].x,
+            uinode_rect.min.y + positions_diff[0].y,
+        ),
+        Vec2::new(
+            uinode_rect.max.x + positions_diff[1].x,
+            uinode_rect.min.y + positions_diff[1].y,
+        ),
+        Vec2::new(
+            uinode_rect.max.x + positions_diff[2].x,
+            uinode_rect.max.y + positions_diff[2].y,
+        ),
+        Vec2::new(
+            uinode_rect.min.x + positions_diff[3].x,
+            uinode_rect.max.y + positions_diff[3].y,
+        ),
+    ]
+    .map(|pos| pos / atlas_extent);
+
+    if extracted_uinode.flip_x {
+        uvs = [uvs[1], uvs[0], uvs[3], uvs[2]];
+    }
+    if extracted_uinode.flip_y {
+        uvs = [uvs[3], uvs[2], uvs[1], uvs[0]];
+    }
+
+    // stretch the region of the texture over the node
+    if let Some(uv_rect) = extracted_uinode.uv_rect {
+        uvs = uvs.map(|uv| uv_rect.min + uv * uv_rect.size());
+    }
+    uvs
 }
 
 pub fn prepare_uinodes(
@@ -412,26 +841,31 @@ pub fn prepare_uinodes(
 ) {
     ui_meta.vertices.clear();
 
-    // sort by ui stack index, starting from the deepest node
+    // sort by ui stack index, starting from the deepest node, drawing the shadows of each node first
     extracted_uinodes
         .uinodes
-        .sort_by_key(|node| node.stack_index);
+        .sort_by_key(|node| (node.stack_index, !node.is_shadow));
 
     let mut start = 0;
     let mut end = 0;
     let mut current_batch_handle = Default::default();
+    let mut current_batch_camera = Entity::PLACEHOLDER;
     let mut last_z = 0.0;
     for extracted_uinode in &extracted_uinodes.uinodes {
-        if current_batch_handle != extracted_uinode.image {
+        if current_batch_handle != extracted_uinode.image
+            || current_batch_camera != extracted_uinode.camera_entity
+        {
             if start != end {
                 commands.spawn(UiBatch {
                     range: start..end,
                     image: current_batch_handle,
                     z: last_z,
+                    camera: current_batch_camera,
                 });
                 start = end;
             }
             current_batch_handle = extracted_uinode.image.clone_weak();
+            current_batch_camera = extracted_uinode.camera_entity;
         }
 
         let uinode_rect = extracted_uinode.rect;
@@ -490,40 +924,26 @@ pub fn prepare_uinodes(
             }
         }
 
-        let atlas_extent = extracted_uinode.atlas_size.unwrap_or(uinode_rect.max);
-        let mut uvs = [
-            Vec2::new(
-                uinode_rect.min.x + positions_diff[0].x,
-                uinode_rect.min.y + positions_diff[0].y,
-            ),
-            Vec2::new(
-                uinode_rect.max.x + positions_diff[1].x,
-                uinode_rect.min.y + positions_diff[1].y,
-            ),
-            Vec2::new(
-                uinode_rect.max.x + positions_diff[2].x,
-                uinode_rect.max.y + positions_diff[2].y,
-            ),
-            Vec2::new(
-                uinode_rect.min.x + positions_diff[3].x,
-                uinode_rect.max.y + positions_diff[3].y,
-            ),
-        ]
-        .map(|pos| pos / atlas_extent);
-
-        if extracted_uinode.flip_x {
-            uvs = [uvs[1], uvs[0], uvs[3], uvs[2]];
-        }
-        if extracted_uinode.flip_y {
-            uvs = [uvs[3], uvs[2], uvs[1], uvs[0]];
-        }
+        let uvs = node_uvs(extracted_uinode, positions_diff);
 
         let color = extracted_uinode.color.as_linear_rgba_f32();
+        let clip = extracted_uinode
+            .clip
+            .map(|clip| [clip.min.x, clip.min.y, clip.max.x, clip.max.y])
+            .unwrap_or_default();
         for i in QUAD_INDICES {
             ui_meta.vertices.push(UiVertex {
                 position: positions_clipped[i].into(),
                 uv: uvs[i].into(),
                 color,
+                point: ((QUAD_VERTEX_POSITIONS[i] * rect_size).truncate() + positions_diff[i])
+                    .into(),
+                size: uinode_rect.size().into(),
+                radius: extracted_uinode.border_radius,
+                border_width: extracted_uinode.border_width,
+                blur_radius: extracted_uinode.blur_radius,
+                clip,
+                clip_radius: extracted_uinode.clip_border_radius,
             });
         }
 
@@ -537,6 +957,7 @@ pub fn prepare_uinodes(
             range: start..end,
             image: current_batch_handle,
             z: last_z,
+            camera: current_batch_camera,
         });
     }
 
@@ -560,7 +981,7 @@ pub fn queue_uinodes(
     mut image_bind_groups: ResMut<UiImageBindGroups>,
     gpu_images: Res<RenderAssets<Image>>,
     ui_batches: Query<(Entity, &UiBatch)>,
-    mut views: Query<(&ExtractedView, &mut RenderPhase<TransparentUi>)>,
+    mut views: Query<(Entity, &ExtractedView, &mut RenderPhase<TransparentUi>)>,
     events: Res<SpriteAssetEvents>,
 ) {
     // If an image has changed, the GpuImage has (probably) changed
@@ -583,13 +1004,17 @@ pub fn queue_uinodes(
             layout: &ui_pipeline.view_layout,
         }));
         let draw_ui_function = draw_functions.read().id::<DrawUi>();
-        for (view, mut transparent_phase) in &mut views {
+        for (view_entity, view, mut transparent_phase) in &mut views {
             let pipeline = pipelines.specialize(
                 &pipeline_cache,
                 &ui_pipeline,
                 UiPipelineKey { hdr: view.hdr },
             );
-            for (entity, batch) in &ui_batches {
+            // only draw the batches of the UI rendered to this camera
+            for (entity, batch) in ui_batches
+                .iter()
+                .filter(|(_, batch)| batch.camera == view_entity)
+            {
                 image_bind_groups
                     .values
                     .entry(batch.image.clone_weak())
@@ -620,3 +1045,149 @@ pub fn queue_uinodes(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{atlas_uv_rect, box_shadow_geometry, node_uvs, outline_edges, ExtractedUiNode};
+    use crate::ResolvedShadow;
+    use bevy_asset::Handle;
+    use bevy_ecs::entity::Entity;
+    use bevy_math::{Mat4, Rect, Vec2};
+    use bevy_render::color::Color;
+    use bevy_sprite::TextureAtlas;
+
+    fn image_node(uv_rect: Option<Rect>, flip_x: bool) -> ExtractedUiNode {
+        ExtractedUiNode {
+            stack_index: 0,
+            camera_entity: Entity::PLACEHOLDER,
+            transform: Mat4::IDENTITY,
+            color: Color::WHITE,
+            rect: Rect::new(0., 0., 100., 50.),
+            image: Handle::default(),
+            atlas_size: None,
+            uv_rect,
+            clip: None,
+            clip_border_radius: [0.; 4],
+            flip_x,
+            flip_y: false,
+            border_radius: [0.; 4],
+            border_width: 0.,
+            blur_radius: 0.,
+            is_shadow: false,
+        }
+    }
+
+    #[test]
+    fn outline_edges_surround_node() {
+        let [top, bottom, left, right] = outline_edges(Vec2::new(100., 50.), 5., 2.);
+
+        assert_eq!(
+            top,
+            Rect {
+                min: Vec2::new(-57., -32.),
+                max: Vec2::new(57., -27.),
+            }
+        );
+        assert_eq!(
+            bottom,
+            Rect {
+                min: Vec2::new(-57., 27.),
+                max: Vec2::new(57., 32.),
+            }
+        );
+        assert_eq!(
+            left,
+            Rect {
+                min: Vec2::new(-57., -27.),
+                max: Vec2::new(-52., 27.),
+            }
+        );
+        assert_eq!(
+            right,
+            Rect {
+                min: Vec2::new(52., -27.),
+                max: Vec2::new(57., 27.),
+            }
+        );
+    }
+
+    #[test]
+    fn outline_edges_do_not_overlap_node() {
+        let node_rect = Rect::from_center_size(Vec2::ZERO, Vec2::new(40., 40.));
+        for edge in outline_edges(node_rect.size(), 3., 0.) {
+            assert!(edge.intersect(node_rect).is_empty());
+        }
+    }
+
+    #[test]
+    fn box_shadow_quad_covers_spread_and_blur() {
+        let shadow = ResolvedShadow {
+            color: Color::BLACK,
+            offset: Vec2::new(10., -5.),
+            spread_radius: 4.,
+            blur_radius: 6.,
+        };
+        let (quad, radius) = box_shadow_geometry(Vec2::new(100., 50.), [8., 0., 8., 0.], &shadow);
+
+        assert_eq!(quad, Rect::new(-50., -40., 70., 30.));
+        // rounded corners grow with the spread, square corners stay square
+        assert_eq!(radius, [12., 0., 12., 0.]);
+    }
+
+    #[test]
+    fn box_shadow_negative_spread_shrinks_corners() {
+        let shadow = ResolvedShadow {
+            color: Color::BLACK,
+            offset: Vec2::ZERO,
+            spread_radius: -10.,
+            blur_radius: 0.,
+        };
+        let (quad, radius) = box_shadow_geometry(Vec2::new(40., 30.), [5., 20., 15., 0.], &shadow);
+
+        assert_eq!(quad, Rect::new(-10., -5., 10., 5.));
+        // radii are clamped to zero and to half the shortest side of the shadow
+        assert_eq!(radius, [0., 5., 5., 0.]);
+    }
+
+    #[test]
+    fn atlas_frame_is_normalized_by_the_atlas_size() {
+        let mut atlas = TextureAtlas::new_empty(Handle::default(), Vec2::new(200., 100.));
+        atlas.add_texture(Rect::new(0., 0., 50., 50.));
+        atlas.add_texture(Rect::new(50., 25., 150., 75.));
+
+        assert_eq!(
+            atlas_uv_rect(&atlas, 1),
+            Some(Rect::new(0.25, 0.25, 0.75, 0.75))
+        );
+        assert_eq!(atlas_uv_rect(&atlas, 2), None);
+    }
+
+    #[test]
+    fn uvs_are_mapped_into_the_uv_rect() {
+        let uv_rect = Rect::new(0.25, 0.5, 0.75, 1.);
+
+        assert_eq!(
+            node_uvs(&image_node(None, false), [Vec2::ZERO; 4]),
+            [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y]
+        );
+        assert_eq!(
+            node_uvs(&image_node(Some(uv_rect), false), [Vec2::ZERO; 4]),
+            [
+                Vec2::new(0.25, 0.5),
+                Vec2::new(0.75, 0.5),
+                Vec2::new(0.75, 1.),
+                Vec2::new(0.25, 1.),
+            ]
+        );
+        // flipping stays within the frame
+        assert_eq!(
+            node_uvs(&image_node(Some(uv_rect), true), [Vec2::ZERO; 4]),
+            [
+                Vec2::new(0.75, 0.5),
+                Vec2::new(0.25, 0.5),
+                Vec2::new(0.25, 1.),
+                Vec2::new(0.75, 1.),
+            ]
+        );
+    }
+}
@@ -77,6 +77,20 @@ impl SpecializedRenderPipeline for UiPipeline {
                 VertexFormat::Float32x2,
                 // color
                 VertexFormat::Float32x4,
+                // point
+                VertexFormat::Float32x2,
+                // size
+                VertexFormat::Float32x2,
+                // border radius
+                VertexFormat::Float32x4,
+                // border width
+                VertexFormat::Float32,
+                // blur radius
+                VertexFormat::Float32,
+                // clip rect
+                VertexFormat::Float32x4,
+                // clip border radius
+                VertexFormat::Float32x4,
             ],
         );
         let shader_defs = Vec::new();
@@ -6,6 +6,16 @@ var<uniform> view: View;
 struct VertexOutput {
     @location(0) uv: vec2<f32>,
     @location(1) color: vec4<f32>,
+    // Position relative to the center of the node
+    @location(2) point: vec2<f32>,
+    @location(3) @interpolate(flat) size: vec2<f32>,
+    @location(4) @interpolate(flat) radius: vec4<f32>,
+    @location(5) @interpolate(flat) border_width: f32,
+    @location(6) @interpolate(flat) blur_radius: f32,
+    // Position in the same space as the clip rect
+    @location(7) clip_point: vec2<f32>,
+    @location(8) @interpolate(flat) clip: vec4<f32>,
+    @location(9) @interpolate(flat) clip_radius: vec4<f32>,
     @builtin(position) position: vec4<f32>,
 };
 
@@ -14,11 +24,26 @@ fn vertex(
     @location(0) vertex_position: vec3<f32>,
     @location(1) vertex_uv: vec2<f32>,
     @location(2) vertex_color: vec4<f32>,
+    @location(3) vertex_point: vec2<f32>,
+    @location(4) vertex_size: vec2<f32>,
+    @location(5) vertex_radius: vec4<f32>,
+    @location(6) vertex_border_width: f32,
+    @location(7) vertex_blur_radius: f32,
+    @location(8) vertex_clip: vec4<f32>,
+    @location(9) vertex_clip_radius: vec4<f32>,
 ) -> VertexOutput {
     var out: VertexOutput;
     out.uv = vertex_uv;
     out.position = view.view_proj * vec4<f32>(vertex_position, 1.0);
     out.color = vertex_color;
+    out.point = vertex_point;
+    out.size = vertex_size;
+    out.radius = vertex_radius;
+    out.border_width = vertex_border_width;
+    out.blur_radius = vertex_blur_radius;
+    out.clip_point = vertex_position.xy;
+    out.clip = vertex_clip;
+    out.clip_radius = vertex_clip_radius;
     return out;
 }
 
@@ -27,9 +52,59 @@ var sprite_texture: texture_2d<f32>;
 @group(1) @binding(1)
 var sprite_sampler: sampler;
 
+// The signed distance from `point` to the edge of a rounded rect of size `size` centered on the origin.
+// Negative inside the rect, positive outside.
+// `corner_radii` are in the order top left, top right, bottom right, bottom left.
+fn sd_rounded_box(point: vec2<f32>, size: vec2<f32>, corner_radii: vec4<f32>) -> f32 {
+    // The y-axis points down, so if 0.0 < y then select the bottom left (w) and bottom right (z) radii.
+    // Else select the top left (x) and top right (y) radii.
+    let rs = select(corner_radii.xy, corner_radii.wz, 0.0 < point.y);
+    // Both pairs are now in left to right order.
+    let radius = select(rs.x, rs.y, 0.0 < point.x);
+    // Vector from the corner closest to the point, to the point.
+    let corner_to_point = abs(point) - 0.5 * size;
+    // Vector from the center of the radius circle to the point.
+    let q = corner_to_point + radius;
+    // Zeros a component if the point is not within the quadrant of the radius circle that is part of the curved corner.
+    let l = length(max(q, vec2<f32>(0.0)));
+    let m = min(max(q.x, q.y), 0.0);
+    return l + m - radius;
+}
+
+fn antialias(distance: f32) -> f32 {
+    return clamp(0.5 - distance, 0.0, 1.0);
+}
+
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     var color = textureSample(sprite_texture, sprite_sampler, in.uv);
     color = in.color * color;
+
+    if 0.0 < in.blur_radius {
+        // A blurred box shadow: the shadow's shape is the quad shrunk by the blur radius.
+        // Approximate the blur by fading the shadow out across its rounded edge,
+        // from full opacity at `blur_radius` inside the edge to zero at `blur_radius` outside of it.
+        let shadow_size = in.size - 2.0 * in.blur_radius;
+        let distance = sd_rounded_box(in.point, shadow_size, in.radius);
+        color.a = color.a * (1.0 - smoothstep(-in.blur_radius, in.blur_radius, distance));
+    } else if 0.0 < max(max(in.radius.x, in.radius.y), max(in.radius.z, in.radius.w)) || 0.0 < in.border_width {
+        let external_distance = sd_rounded_box(in.point, in.size, in.radius);
+        var alpha = antialias(external_distance);
+        if 0.0 < in.border_width {
+            let inner_size = in.size - 2.0 * in.border_width;
+            let inner_radius = max(in.radius - in.border_width, vec4<f32>(0.0));
+            let internal_distance = sd_rounded_box(in.point, inner_size, inner_radius);
+            alpha = alpha * antialias(-internal_distance);
+        }
+        color.a = color.a * alpha;
+    }
+
+    if 0.0 < max(max(in.clip_radius.x, in.clip_radius.y), max(in.clip_radius.z, in.clip_radius.w)) {
+        let clip_center = 0.5 * (in.clip.xy + in.clip.zw);
+        let clip_size = in.clip.zw - in.clip.xy;
+        let clip_distance = sd_rounded_box(in.clip_point - clip_center, clip_size, in.clip_radius);
+        color.a = color.a * antialias(clip_distance);
+    }
+
     return color;
 }
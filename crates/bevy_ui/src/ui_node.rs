@@ -1,14 +1,21 @@
 use crate::{Size, UiRect};
-use bevy_asset::Handle;
-use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::Component,
+    reflect::ReflectComponent,
+    world::{FromWorld, World},
+};
 use bevy_math::{Rect, Vec2};
 use bevy_reflect::prelude::*;
 use bevy_render::{
     color::Color,
     texture::{Image, DEFAULT_IMAGE_HANDLE},
 };
+use bevy_sprite::TextureAtlas;
 use bevy_transform::prelude::GlobalTransform;
 use serde::{Deserialize, Serialize};
+use std::num::{NonZeroI16, NonZeroU16};
 use std::ops::{Div, DivAssign, Mul, MulAssign};
 use thiserror::Error;
 
@@ -19,6 +26,15 @@ pub struct Node {
     /// The size of the node as width and height in logical pixels
     /// automatically calculated by [`super::flex::flex_node_system`]
     pub(crate) calculated_size: Vec2,
+    /// The width of this node's outline in logical pixels
+    /// automatically calculated by [`super::update::resolve_outlines_system`]
+    pub(crate) outline_width: f32,
+    /// The amount of space between the outline and the edge of this node in logical pixels
+    /// automatically calculated by [`super::update::resolve_outlines_system`]
+    pub(crate) outline_offset: f32,
+    /// The size of the content of this node in logical pixels, which may overflow the node
+    /// automatically calculated by [`super::flex::flex_node_system`]
+    pub(crate) content_size: Vec2,
 }
 
 impl Node {
@@ -28,6 +44,31 @@ impl Node {
         self.calculated_size
     }
 
+    /// The size of this node's children as width and height in logical pixels,
+    /// measured from the top-left corner of the node.
+    ///
+    /// If it is larger than [`Node::size`], the content overflows and may be scrolled with a [`ScrollPosition`].
+    #[inline]
+    pub fn content_size(&self) -> Vec2 {
+        self.content_size
+    }
+
+    /// The resolved width of this node's [`Outline`] in logical pixels.
+    ///
+    /// Is zero if the node has no [`Outline`].
+    #[inline]
+    pub fn outline_width(&self) -> f32 {
+        self.outline_width
+    }
+
+    /// The resolved offset of this node's [`Outline`] in logical pixels.
+    ///
+    /// Is zero if the node has no [`Outline`].
+    #[inline]
+    pub fn outline_offset(&self) -> f32 {
+        self.outline_offset
+    }
+
     /// Returns the logical pixel coordinates of the UI node, based on its `GlobalTransform`.
     #[inline]
     pub fn logical_rect(&self, transform: &GlobalTransform) -> Rect {
@@ -48,6 +89,9 @@ impl Node {
 impl Node {
     pub const DEFAULT: Self = Self {
         calculated_size: Vec2::ZERO,
+        outline_width: 0.,
+        outline_offset: 0.,
+        content_size: Vec2::ZERO,
     };
 }
 
@@ -58,7 +102,7 @@ impl Default for Node {
 }
 
 /// An enum that describes possible types of value in flexbox layout options
-#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect)]
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
 #[reflect(PartialEq, Serialize, Deserialize)]
 pub enum Val {
     /// Automatically determine this value
@@ -67,6 +111,14 @@ pub enum Val {
     Px(f32),
     /// Set this value in percent
     Percent(f32),
+    /// Set this value in percent of the viewport width
+    Vw(f32),
+    /// Set this value in percent of the viewport height
+    Vh(f32),
+    /// Set this value in percent of the viewport's smaller dimension.
+    VMin(f32),
+    /// Set this value in percent of the viewport's larger dimension.
+    VMax(f32),
 }
 
 impl Val {
@@ -87,6 +139,10 @@ impl Mul<f32> for Val {
             Val::Auto => Val::Auto,
             Val::Px(value) => Val::Px(value * rhs),
             Val::Percent(value) => Val::Percent(value * rhs),
+            Val::Vw(value) => Val::Vw(value * rhs),
+            Val::Vh(value) => Val::Vh(value * rhs),
+            Val::VMin(value) => Val::VMin(value * rhs),
+            Val::VMax(value) => Val::VMax(value * rhs),
         }
     }
 }
@@ -95,7 +151,12 @@ impl MulAssign<f32> for Val {
     fn mul_assign(&mut self, rhs: f32) {
         match self {
             Val::Auto => {}
-            Val::Px(value) | Val::Percent(value) => *value *= rhs,
+            Val::Px(value)
+            | Val::Percent(value)
+            | Val::Vw(value)
+            | Val::Vh(value)
+            | Val::VMin(value)
+            | Val::VMax(value) => *value *= rhs,
         }
     }
 }
@@ -108,6 +169,10 @@ impl Div<f32> for Val {
             Val::Auto => Val::Auto,
             Val::Px(value) => Val::Px(value / rhs),
             Val::Percent(value) => Val::Percent(value / rhs),
+            Val::Vw(value) => Val::Vw(value / rhs),
+            Val::Vh(value) => Val::Vh(value / rhs),
+            Val::VMin(value) => Val::VMin(value / rhs),
+            Val::VMax(value) => Val::VMax(value / rhs),
         }
     }
 }
@@ -116,7 +181,12 @@ impl DivAssign<f32> for Val {
     fn div_assign(&mut self, rhs: f32) {
         match self {
             Val::Auto => {}
-            Val::Px(value) | Val::Percent(value) => *value /= rhs,
+            Val::Px(value)
+            | Val::Percent(value)
+            | Val::Vw(value)
+            | Val::Vh(value)
+            | Val::VMin(value)
+            | Val::VMax(value) => *value /= rhs,
         }
     }
 }
@@ -138,6 +208,10 @@ impl Val {
             (Val::Auto, Val::Auto) => Ok(*self),
             (Val::Px(value), Val::Px(rhs_value)) => Ok(Val::Px(value + rhs_value)),
             (Val::Percent(value), Val::Percent(rhs_value)) => Ok(Val::Percent(value + rhs_value)),
+            (Val::Vw(value), Val::Vw(rhs_value)) => Ok(Val::Vw(value + rhs_value)),
+            (Val::Vh(value), Val::Vh(rhs_value)) => Ok(Val::Vh(value + rhs_value)),
+            (Val::VMin(value), Val::VMin(rhs_value)) => Ok(Val::VMin(value + rhs_value)),
+            (Val::VMax(value), Val::VMax(rhs_value)) => Ok(Val::VMax(value + rhs_value)),
             _ => Err(ValArithmeticError::NonIdenticalVariants),
         }
     }
@@ -156,6 +230,10 @@ impl Val {
             (Val::Auto, Val::Auto) => Ok(*self),
             (Val::Px(value), Val::Px(rhs_value)) => Ok(Val::Px(value - rhs_value)),
             (Val::Percent(value), Val::Percent(rhs_value)) => Ok(Val::Percent(value - rhs_value)),
+            (Val::Vw(value), Val::Vw(rhs_value)) => Ok(Val::Vw(value - rhs_value)),
+            (Val::Vh(value), Val::Vh(rhs_value)) => Ok(Val::Vh(value - rhs_value)),
+            (Val::VMin(value), Val::VMin(rhs_value)) => Ok(Val::VMin(value - rhs_value)),
+            (Val::VMax(value), Val::VMax(rhs_value)) => Ok(Val::VMax(value - rhs_value)),
             _ => Err(ValArithmeticError::NonIdenticalVariants),
         }
     }
@@ -179,6 +257,27 @@ impl Val {
         }
     }
 
+    /// Resolves a [`Val`] into a concrete value in logical pixels.
+    ///
+    /// [`Val::Percent`] is resolved relative to `parent_size` and the viewport units
+    /// ([`Val::Vw`], [`Val::Vh`], [`Val::VMin`] and [`Val::VMax`]) relative to `viewport_size`.
+    /// Returns a [`ValArithmeticError::NonEvaluateable`] for [`Val::Auto`].
+    pub fn resolve(
+        &self,
+        parent_size: f32,
+        viewport_size: Vec2,
+    ) -> Result<f32, ValArithmeticError> {
+        match self {
+            Val::Percent(value) => Ok(parent_size * value / 100.0),
+            Val::Px(value) => Ok(*value),
+            Val::Vw(value) => Ok(viewport_size.x * value / 100.0),
+            Val::Vh(value) => Ok(viewport_size.y * value / 100.0),
+            Val::VMin(value) => Ok(viewport_size.min_element() * value / 100.0),
+            Val::VMax(value) => Ok(viewport_size.max_element() * value / 100.0),
+            Val::Auto => Err(ValArithmeticError::NonEvaluateable),
+        }
+    }
+
     /// Similar to [`Val::try_add`], but performs [`Val::evaluate`] on both values before adding.
     /// Returns an [`f32`] value in pixels.
     pub fn try_add_with_size(&self, rhs: Val, size: f32) -> Result<f32, ValArithmeticError> {
@@ -325,10 +424,33 @@ pub struct Style {
     pub aspect_ratio: Option<f32>,
     /// How to handle overflow
     pub overflow: Overflow,
-    /// The size of the gutters between the rows and columns of the flexbox layout
+    /// The size of the gutters between the rows and columns of the flexbox or grid layout
     ///
     /// A value of `Size::AUTO` is treated as zero.
     pub gap: Size,
+    /// Controls whether automatically placed grid items are placed row-wise or column-wise,
+    /// and whether the sparse or dense packing algorithm is used.
+    ///
+    /// Only affects nodes with [`Display::Grid`].
+    pub grid_auto_flow: GridAutoFlow,
+    /// Defines the number of rows of the grid and their sizes.
+    ///
+    /// Only affects nodes with [`Display::Grid`].
+    pub grid_template_rows: Vec<RepeatedGridTrack>,
+    /// Defines the number of columns of the grid and their sizes.
+    ///
+    /// Only affects nodes with [`Display::Grid`].
+    pub grid_template_columns: Vec<RepeatedGridTrack>,
+    /// Defines the size of implicitly created rows, which are added when items are placed
+    /// outside of the rows defined by [`Style::grid_template_rows`].
+    pub grid_auto_rows: Vec<GridTrack>,
+    /// Defines the size of implicitly created columns, which are added when items are placed
+    /// outside of the columns defined by [`Style::grid_template_columns`].
+    pub grid_auto_columns: Vec<GridTrack>,
+    /// The row(s) this node is placed in, if its parent uses [`Display::Grid`].
+    pub grid_row: GridPlacement,
+    /// The column(s) this node is placed in, if its parent uses [`Display::Grid`].
+    pub grid_column: GridPlacement,
 }
 
 impl Style {
@@ -358,6 +480,13 @@ impl Style {
         aspect_ratio: None,
         overflow: Overflow::DEFAULT,
         gap: Size::AUTO,
+        grid_auto_flow: GridAutoFlow::DEFAULT,
+        grid_template_rows: Vec::new(),
+        grid_template_columns: Vec::new(),
+        grid_auto_rows: Vec::new(),
+        grid_auto_columns: Vec::new(),
+        grid_row: GridPlacement::DEFAULT,
+        grid_column: GridPlacement::DEFAULT,
     };
 }
 
@@ -497,7 +626,7 @@ impl Default for Direction {
     }
 }
 
-/// Whether to use a Flexbox layout model.
+/// Whether to use a Flexbox or CSS Grid layout model.
 ///
 /// Part of the [`Style`] component.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
@@ -505,6 +634,11 @@ impl Default for Direction {
 pub enum Display {
     /// Use Flexbox layout model to determine the position of this [`Node`].
     Flex,
+    /// Use CSS Grid layout model to determine the position of this [`Node`]'s children.
+    ///
+    /// The grid is defined by [`Style::grid_template_rows`] and [`Style::grid_template_columns`],
+    /// the children are placed with [`Style::grid_row`] and [`Style::grid_column`].
+    Grid,
     /// Use no layout, don't render this node and its children.
     ///
     /// If you want to hide a node and its children,
@@ -586,10 +720,17 @@ pub enum Overflow {
     Visible,
     /// Hide overflowing items.
     Hidden,
+    /// Hide overflowing items and allow scrolling them into view with a [`ScrollPosition`].
+    Scroll,
 }
 
 impl Overflow {
     pub const DEFAULT: Self = Self::Visible;
+
+    /// Hide overflowing items and allow scrolling them into view with a [`ScrollPosition`].
+    pub const fn scroll() -> Self {
+        Self::Scroll
+    }
 }
 
 impl Default for Overflow {
@@ -598,6 +739,29 @@ impl Default for Overflow {
     }
 }
 
+/// The amount a node's content is scrolled, in logical pixels.
+///
+/// The children of the node are translated by `-offset` when their layout is applied.
+/// The offset is clamped between zero and the amount the [`Node::content_size`] overflows the node.
+///
+/// The offset of nodes with [`Overflow::Scroll`] is updated from mouse wheel events
+/// by [`update_scroll_position_system`](crate::update::update_scroll_position_system).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ScrollPosition {
+    /// How far the content is scrolled to the right and down
+    pub offset: Vec2,
+}
+
+impl ScrollPosition {
+    pub const DEFAULT: Self = Self { offset: Vec2::ZERO };
+
+    /// Creates a new [`ScrollPosition`] with the given offset
+    pub const fn new(offset: Vec2) -> Self {
+        Self { offset }
+    }
+}
+
 /// The strategy used to position this node
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(PartialEq, Serialize, Deserialize)]
@@ -642,29 +806,466 @@ impl Default for FlexWrap {
     }
 }
 
-/// The calculated size of the node
-#[derive(Component, Copy, Clone, Debug, Reflect)]
-#[reflect(Component)]
-pub struct CalculatedSize {
-    /// The size of the node in logical pixels
-    pub size: Vec2,
-    /// Whether to attempt to preserve the aspect ratio when determining the layout for this item
-    pub preserve_aspect_ratio: bool,
+/// Controls whether automatically placed grid items are placed row-wise or column-wise,
+/// and whether the sparse or dense packing algorithm is used.
+///
+/// Part of the [`Style`] component.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum GridAutoFlow {
+    /// Items are placed by filling each row in turn, adding new rows as necessary.
+    Row,
+    /// Items are placed by filling each column in turn, adding new columns as necessary.
+    Column,
+    /// Like [`GridAutoFlow::Row`], but fills in holes earlier in the grid with smaller items.
+    RowDense,
+    /// Like [`GridAutoFlow::Column`], but fills in holes earlier in the grid with smaller items.
+    ColumnDense,
+}
+
+impl GridAutoFlow {
+    pub const DEFAULT: Self = Self::Row;
+}
+
+impl Default for GridAutoFlow {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The minimum size of a grid track.
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum MinTrackSizingFunction {
+    /// Track minimum size should be a fixed pixel value
+    Px(f32),
+    /// Track minimum size should be a percentage value of the grid's size
+    Percent(f32),
+    /// Track minimum size should be content sized under a min-content constraint
+    MinContent,
+    /// Track minimum size should be content sized under a max-content constraint
+    MaxContent,
+    /// Track minimum size should be automatically sized
+    Auto,
+}
+
+/// The maximum size of a grid track.
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum MaxTrackSizingFunction {
+    /// Track maximum size should be a fixed pixel value
+    Px(f32),
+    /// Track maximum size should be a percentage value of the grid's size
+    Percent(f32),
+    /// Track maximum size should be content sized under a min-content constraint
+    MinContent,
+    /// Track maximum size should be content sized under a max-content constraint
+    MaxContent,
+    /// Track maximum size should be sized according to the fit-content formula with a fixed pixel limit
+    FitContentPx(f32),
+    /// Track maximum size should be sized according to the fit-content formula with a percentage limit
+    FitContentPercent(f32),
+    /// Track maximum size should be automatically sized
+    Auto,
+    /// The dimension as a fraction of the free space in the grid after content and fixed tracks are sized.
+    ///
+    /// Corresponds to the CSS `fr` unit.
+    Fraction(f32),
+}
+
+/// The size of a single row or column of a grid, as a minimum and a maximum sizing function.
+///
+/// See [`RepeatedGridTrack`] to define several tracks at once.
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub struct GridTrack {
+    pub(crate) min_sizing_function: MinTrackSizingFunction,
+    pub(crate) max_sizing_function: MaxTrackSizingFunction,
+}
+
+impl GridTrack {
+    pub const DEFAULT: Self = Self {
+        min_sizing_function: MinTrackSizingFunction::Auto,
+        max_sizing_function: MaxTrackSizingFunction::Auto,
+    };
+
+    /// Create a grid track with a fixed pixel size
+    pub const fn px(value: f32) -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::Px(value),
+            max_sizing_function: MaxTrackSizingFunction::Px(value),
+        }
+    }
+
+    /// Create a grid track with a percentage size of the grid
+    pub const fn percent(value: f32) -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::Percent(value),
+            max_sizing_function: MaxTrackSizingFunction::Percent(value),
+        }
+    }
+
+    /// Create a grid track that takes up a fraction of the free space in the grid,
+    /// like the CSS `fr` unit.
+    ///
+    /// The track can't shrink below the size of its content, as with `minmax(auto, <value>fr)`.
+    pub const fn fr(value: f32) -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::Auto,
+            max_sizing_function: MaxTrackSizingFunction::Fraction(value),
+        }
+    }
+
+    /// Create a grid track that takes up a fraction of the free space in the grid,
+    /// even if that makes it smaller than its content, as with `minmax(0px, <value>fr)`.
+    pub const fn flex(value: f32) -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::Px(0.0),
+            max_sizing_function: MaxTrackSizingFunction::Fraction(value),
+        }
+    }
+
+    /// Create a grid track that is sized automatically
+    pub const fn auto() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Create a grid track that is sized to its content under a min-content constraint
+    pub const fn min_content() -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::MinContent,
+            max_sizing_function: MaxTrackSizingFunction::MinContent,
+        }
+    }
+
+    /// Create a grid track that is sized to its content under a max-content constraint
+    pub const fn max_content() -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::MaxContent,
+            max_sizing_function: MaxTrackSizingFunction::MaxContent,
+        }
+    }
+
+    /// Create a grid track that is sized to its content, but no larger than a fixed pixel limit,
+    /// like the CSS `fit-content()` function.
+    pub const fn fit_content_px(limit: f32) -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::Auto,
+            max_sizing_function: MaxTrackSizingFunction::FitContentPx(limit),
+        }
+    }
+
+    /// Create a grid track that is sized to its content, but no larger than a percentage of the grid,
+    /// like the CSS `fit-content()` function.
+    pub const fn fit_content_percent(limit: f32) -> Self {
+        Self {
+            min_sizing_function: MinTrackSizingFunction::Auto,
+            max_sizing_function: MaxTrackSizingFunction::FitContentPercent(limit),
+        }
+    }
+
+    /// Create a grid track whose size is bounded by the given sizing functions,
+    /// like the CSS `minmax()` function.
+    pub const fn minmax(min: MinTrackSizingFunction, max: MaxTrackSizingFunction) -> Self {
+        Self {
+            min_sizing_function: min,
+            max_sizing_function: max,
+        }
+    }
+
+    /// The minimum size of the track
+    pub const fn min_sizing_function(&self) -> MinTrackSizingFunction {
+        self.min_sizing_function
+    }
+
+    /// The maximum size of the track
+    pub const fn max_sizing_function(&self) -> MaxTrackSizingFunction {
+        self.max_sizing_function
+    }
+}
+
+impl Default for GridTrack {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How many times the tracks of a [`RepeatedGridTrack`] are repeated.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub enum GridTrackRepetition {
+    /// Repeat the tracks a fixed number of times
+    Count(u16),
+    /// Repeat the tracks as many times as they fit into the grid, keeping empty tracks
+    AutoFill,
+    /// Repeat the tracks as many times as they fit into the grid, collapsing empty tracks
+    AutoFit,
 }
 
-impl CalculatedSize {
-    const DEFAULT: Self = Self {
-        size: Vec2::ZERO,
-        preserve_aspect_ratio: false,
+impl From<u16> for GridTrackRepetition {
+    fn from(count: u16) -> Self {
+        Self::Count(count)
+    }
+}
+
+impl From<i32> for GridTrackRepetition {
+    fn from(count: i32) -> Self {
+        Self::Count(count as u16)
+    }
+}
+
+impl From<usize> for GridTrackRepetition {
+    fn from(count: usize) -> Self {
+        Self::Count(count as u16)
+    }
+}
+
+/// A sequence of [`GridTrack`]s, repeated as often as its [`GridTrackRepetition`] specifies,
+/// like the CSS `repeat()` function.
+///
+/// Used by [`Style::grid_template_rows`] and [`Style::grid_template_columns`].
+///
+/// ```
+/// # use bevy_ui::{GridTrack, RepeatedGridTrack, Style, Display};
+/// // three columns of 40 pixels, followed by a column that takes up the remaining space
+/// let style = Style {
+///     display: Display::Grid,
+///     grid_template_columns: vec![RepeatedGridTrack::px(3, 40.), GridTrack::fr(1.).into()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Reflect, FromReflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub struct RepeatedGridTrack {
+    pub(crate) repetition: GridTrackRepetition,
+    pub(crate) tracks: Vec<GridTrack>,
+}
+
+impl RepeatedGridTrack {
+    fn single(repetition: impl Into<GridTrackRepetition>, track: GridTrack) -> Self {
+        Self {
+            repetition: repetition.into(),
+            tracks: vec![track],
+        }
+    }
+
+    /// Repeat a grid track with a fixed pixel size
+    pub fn px(repetition: impl Into<GridTrackRepetition>, value: f32) -> Self {
+        Self::single(repetition, GridTrack::px(value))
+    }
+
+    /// Repeat a grid track with a percentage size of the grid
+    pub fn percent(repetition: impl Into<GridTrackRepetition>, value: f32) -> Self {
+        Self::single(repetition, GridTrack::percent(value))
+    }
+
+    /// Repeat a grid track that takes up a fraction of the free space, see [`GridTrack::fr`]
+    pub fn fr(repetition: impl Into<GridTrackRepetition>, value: f32) -> Self {
+        Self::single(repetition, GridTrack::fr(value))
+    }
+
+    /// Repeat a grid track that takes up a fraction of the free space, see [`GridTrack::flex`]
+    pub fn flex(repetition: impl Into<GridTrackRepetition>, value: f32) -> Self {
+        Self::single(repetition, GridTrack::flex(value))
+    }
+
+    /// Repeat an automatically sized grid track
+    pub fn auto(repetition: impl Into<GridTrackRepetition>) -> Self {
+        Self::single(repetition, GridTrack::auto())
+    }
+
+    /// Repeat a grid track that is sized to its content under a min-content constraint
+    pub fn min_content(repetition: impl Into<GridTrackRepetition>) -> Self {
+        Self::single(repetition, GridTrack::min_content())
+    }
+
+    /// Repeat a grid track that is sized to its content under a max-content constraint
+    pub fn max_content(repetition: impl Into<GridTrackRepetition>) -> Self {
+        Self::single(repetition, GridTrack::max_content())
+    }
+
+    /// Repeat a grid track limited to a fixed pixel size, see [`GridTrack::fit_content_px`]
+    pub fn fit_content_px(repetition: impl Into<GridTrackRepetition>, limit: f32) -> Self {
+        Self::single(repetition, GridTrack::fit_content_px(limit))
+    }
+
+    /// Repeat a grid track limited to a percentage of the grid, see [`GridTrack::fit_content_percent`]
+    pub fn fit_content_percent(repetition: impl Into<GridTrackRepetition>, limit: f32) -> Self {
+        Self::single(repetition, GridTrack::fit_content_percent(limit))
+    }
+
+    /// Repeat a grid track bounded by the given sizing functions, see [`GridTrack::minmax`]
+    pub fn minmax(
+        repetition: impl Into<GridTrackRepetition>,
+        min: MinTrackSizingFunction,
+        max: MaxTrackSizingFunction,
+    ) -> Self {
+        Self::single(repetition, GridTrack::minmax(min, max))
+    }
+
+    /// Repeat a sequence of grid tracks
+    pub fn repeat_many(
+        repetition: impl Into<GridTrackRepetition>,
+        tracks: impl Into<Vec<GridTrack>>,
+    ) -> Self {
+        Self {
+            repetition: repetition.into(),
+            tracks: tracks.into(),
+        }
+    }
+
+    /// How often the tracks are repeated
+    pub fn repetition(&self) -> GridTrackRepetition {
+        self.repetition
+    }
+
+    /// The repeated tracks
+    pub fn tracks(&self) -> &[GridTrack] {
+        &self.tracks
+    }
+}
+
+impl From<GridTrack> for RepeatedGridTrack {
+    fn from(track: GridTrack) -> Self {
+        Self::single(1u16, track)
+    }
+}
+
+/// Represents the position of a grid item in a single axis, defined by its start line, end line and/or span.
+///
+/// Lines are numbered starting at 1, negative numbers count backwards from the end of the explicit grid.
+/// An item without a start or end line is placed automatically.
+///
+/// Part of the [`Style`] component.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+pub struct GridPlacement {
+    /// The grid line at which the item should start
+    pub(crate) start: Option<NonZeroI16>,
+    /// How many grid tracks the item should span
+    pub(crate) span: Option<NonZeroU16>,
+    /// The grid line at which the item should end
+    pub(crate) end: Option<NonZeroI16>,
+}
+
+impl GridPlacement {
+    pub const DEFAULT: Self = Self {
+        start: None,
+        span: NonZeroU16::new(1),
+        end: None,
     };
+
+    /// Place the grid item automatically in a single track
+    pub const fn auto() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Place the grid item automatically, spanning `span` tracks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `span` is `0`
+    pub fn span(span: u16) -> Self {
+        Self {
+            start: None,
+            span: try_into_grid_span(span),
+            end: None,
+        }
+    }
+
+    /// Place the grid item starting at the line `start`, spanning a single track
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is `0`
+    pub fn start(start: i16) -> Self {
+        Self {
+            start: try_into_grid_index(start),
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Place the grid item ending at the line `end`, spanning a single track
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` is `0`
+    pub fn end(end: i16) -> Self {
+        Self {
+            end: try_into_grid_index(end),
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Place the grid item starting at the line `start`, spanning `span` tracks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` or `span` is `0`
+    pub fn start_span(start: i16, span: u16) -> Self {
+        Self {
+            start: try_into_grid_index(start),
+            span: try_into_grid_span(span),
+            end: None,
+        }
+    }
+
+    /// Place the grid item between the lines `start` and `end`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` or `end` is `0`
+    pub fn start_end(start: i16, end: i16) -> Self {
+        Self {
+            start: try_into_grid_index(start),
+            span: None,
+            end: try_into_grid_index(end),
+        }
+    }
+
+    /// Place the grid item ending at the line `end`, spanning `span` tracks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` or `span` is `0`
+    pub fn end_span(end: i16, span: u16) -> Self {
+        Self {
+            start: None,
+            span: try_into_grid_span(span),
+            end: try_into_grid_index(end),
+        }
+    }
+
+    /// The grid line at which the item starts, if set
+    pub fn get_start(self) -> Option<i16> {
+        self.start.map(NonZeroI16::get)
+    }
+
+    /// The grid line at which the item ends, if set
+    pub fn get_end(self) -> Option<i16> {
+        self.end.map(NonZeroI16::get)
+    }
+
+    /// The number of tracks the item spans, if set
+    pub fn get_span(self) -> Option<u16> {
+        self.span.map(NonZeroU16::get)
+    }
 }
 
-impl Default for CalculatedSize {
+impl Default for GridPlacement {
     fn default() -> Self {
         Self::DEFAULT
     }
 }
 
+fn try_into_grid_index(index: i16) -> Option<NonZeroI16> {
+    Some(NonZeroI16::new(index).expect("Grid line indices must not be 0"))
+}
+
+fn try_into_grid_span(span: u16) -> Option<NonZeroU16> {
+    Some(NonZeroU16::new(span).expect("Grid spans must not be 0"))
+}
+
 /// The background color of the node
 ///
 /// This serves as the "fill" color.
@@ -689,7 +1290,352 @@ impl From<Color> for BackgroundColor {
     }
 }
 
+/// The [`Outline`] component adds an outline outside the edge of a UI node.
+///
+/// Outlines do not take up space in the layout and are drawn on top of the node's background.
+/// To add an [`Outline`] to a ui node you can spawn a `(NodeBundle, Outline)` tuple bundle:
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use bevy_render::prelude::Color;
+/// fn setup_ui(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle {
+///             style: Style {
+///                 size: Size::all(Val::Px(100.)),
+///                 ..Default::default()
+///             },
+///             background_color: Color::BLUE.into(),
+///             ..Default::default()
+///         },
+///         Outline::new(Val::Px(10.), Val::Px(0.), Color::RED),
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct Outline {
+    /// The width of the outline.
+    ///
+    /// Percentage `Val` values are resolved based on the width of the outlined [`Node`].
+    pub width: Val,
+    /// The amount of space between the outline and the edge of the node.
+    ///
+    /// Percentage `Val` values are resolved based on the width of the outlined [`Node`].
+    pub offset: Val,
+    /// The color of the outline.
+    ///
+    /// If you are frequently toggling outlines for a UI node on and off it is recommended to set `Color::NONE` to hide the outline.
+    /// This avoids the table moves that would occur from the repeated insertion and removal of the `Outline` component.
+    pub color: Color,
+}
+
+impl Outline {
+    /// Create a new outline
+    pub const fn new(width: Val, offset: Val, color: Color) -> Self {
+        Self {
+            width,
+            offset,
+            color,
+        }
+    }
+}
+
+/// The [`BoxShadow`] component adds drop shadows behind a UI node.
+///
+/// Each [`ShadowStyle`] is drawn as a copy of the node's (rounded) rect, offset, grown by its spread radius
+/// and blurred over its blur radius. The shadows are drawn before the node's background, the first one on top,
+/// and like outlines they don't take up space in the layout nor receive interactions.
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use bevy_render::prelude::Color;
+/// fn setup_ui(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle {
+///             style: Style {
+///                 size: Size::all(Val::Px(100.)),
+///                 ..Default::default()
+///             },
+///             background_color: Color::WHITE.into(),
+///             ..Default::default()
+///         },
+///         BoxShadow::new(Color::BLACK, Val::Px(5.), Val::Px(5.), Val::Px(0.), Val::Px(10.)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct BoxShadow(pub Vec<ShadowStyle>);
+
+impl BoxShadow {
+    /// Create a box shadow made of a single shadow
+    pub fn new(
+        color: Color,
+        x_offset: Val,
+        y_offset: Val,
+        spread_radius: Val,
+        blur_radius: Val,
+    ) -> Self {
+        Self(vec![ShadowStyle {
+            color,
+            x_offset,
+            y_offset,
+            spread_radius,
+            blur_radius,
+        }])
+    }
+}
+
+impl From<ShadowStyle> for BoxShadow {
+    fn from(shadow: ShadowStyle) -> Self {
+        Self(vec![shadow])
+    }
+}
+
+/// A single shadow of a [`BoxShadow`].
+///
+/// Percentage `Val` values are resolved based on the width of the shadowed [`Node`],
+/// except for `y_offset` which is resolved based on its height.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect, FromReflect)]
+#[reflect(PartialEq)]
+pub struct ShadowStyle {
+    /// The color of the shadow.
+    pub color: Color,
+    /// Horizontal offset of the shadow, positive values move it to the right.
+    pub x_offset: Val,
+    /// Vertical offset of the shadow, positive values move it down.
+    pub y_offset: Val,
+    /// How much the shadow grows on each side of the node before being blurred, negative values shrink it.
+    pub spread_radius: Val,
+    /// The distance over which the edge of the shadow fades out, on both sides of its edge.
+    pub blur_radius: Val,
+}
+
+impl Default for ShadowStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+            x_offset: Val::Percent(20.),
+            y_offset: Val::Percent(20.),
+            spread_radius: Val::Px(0.),
+            blur_radius: Val::Percent(10.),
+        }
+    }
+}
+
+/// A [`ShadowStyle`] resolved into logical pixels.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(PartialEq)]
+pub struct ResolvedShadow {
+    pub color: Color,
+    /// The offset of the shadow from the center of the node
+    pub offset: Vec2,
+    /// Can be negative, but never shrinks the shadow below a size of zero
+    pub spread_radius: f32,
+    /// Never negative
+    pub blur_radius: f32,
+}
+
+impl ResolvedShadow {
+    /// Resolve `shadow` for a node of size `node_size`.
+    pub fn new(shadow: &ShadowStyle, node_size: Vec2) -> Self {
+        let spread_radius = shadow.spread_radius.evaluate(node_size.x).unwrap_or(0.);
+        Self {
+            color: shadow.color,
+            offset: Vec2::new(
+                shadow.x_offset.evaluate(node_size.x).unwrap_or(0.),
+                shadow.y_offset.evaluate(node_size.y).unwrap_or(0.),
+            ),
+            spread_radius: spread_radius.max(-0.5 * node_size.min_element()),
+            blur_radius: shadow
+                .blur_radius
+                .evaluate(node_size.x)
+                .unwrap_or(0.)
+                .max(0.),
+        }
+    }
+}
+
+/// The shadows of a node's [`BoxShadow`] in logical pixels,
+/// automatically calculated by [`super::update::resolve_box_shadows_system`]
+#[derive(Component, Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct CalculatedBoxShadow {
+    pub shadows: Vec<ResolvedShadow>,
+}
+
+/// Used to add rounded corners to a UI node. You can set a UI node to have uniformly
+/// rounded corners or specify different radii for each corner.
+///
+/// The rounded corners are applied to the node's background, its image (if any),
+/// its [`Outline`], its [`BoxShadow`] and, if [`Overflow::Hidden`] or [`Overflow::Scroll`] is set, to the clipping region of its children.
+///
+/// # Resolution
+///
+/// Percentage values are resolved based on the length of the shortest side of the node.
+/// The resolved radius of each corner is clamped to half the length of the shortest side,
+/// so a node with a radius of `Val::Percent(50.)` is rendered as a circle or capsule.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use bevy_render::prelude::Color;
+/// fn setup_ui(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle {
+///             style: Style {
+///                 size: Size::all(Val::Px(100.)),
+///                 ..Default::default()
+///             },
+///             background_color: Color::BLUE.into(),
+///             ..Default::default()
+///         },
+///         BorderRadius::all(Val::Px(10.)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct BorderRadius {
+    pub top_left: Val,
+    pub top_right: Val,
+    pub bottom_left: Val,
+    pub bottom_right: Val,
+}
+
+impl Default for BorderRadius {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl BorderRadius {
+    pub const DEFAULT: Self = Self::ZERO;
+
+    /// Zero curvature. All the corners will be right-angled.
+    pub const ZERO: Self = Self::all(Val::Px(0.));
+
+    /// Maximum curvature. The UI Node will take a capsule shape or circular if width and height are equal.
+    pub const MAX: Self = Self::all(Val::Px(f32::MAX));
+
+    /// Set all four corners to the same curvature.
+    #[inline]
+    pub const fn all(radius: Val) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_left: radius,
+            bottom_right: radius,
+        }
+    }
+
+    #[inline]
+    pub const fn new(top_left: Val, top_right: Val, bottom_right: Val, bottom_left: Val) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Sets the radii to logical pixel values.
+    #[inline]
+    pub const fn px(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Self {
+        Self {
+            top_left: Val::Px(top_left),
+            top_right: Val::Px(top_right),
+            bottom_right: Val::Px(bottom_right),
+            bottom_left: Val::Px(bottom_left),
+        }
+    }
+
+    /// Sets the radii to percentage values.
+    #[inline]
+    pub const fn percent(
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        Self {
+            top_left: Val::Percent(top_left),
+            top_right: Val::Percent(top_right),
+            bottom_right: Val::Percent(bottom_right),
+            bottom_left: Val::Percent(bottom_left),
+        }
+    }
+
+    /// Sets the radius for the top left and top right corners.
+    #[inline]
+    pub const fn top(radius: Val) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Sets the radius for the bottom left and bottom right corners.
+    #[inline]
+    pub const fn bottom(radius: Val) -> Self {
+        Self {
+            bottom_left: radius,
+            bottom_right: radius,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Sets the radius for the top left and bottom left corners.
+    #[inline]
+    pub const fn left(radius: Val) -> Self {
+        Self {
+            top_left: radius,
+            bottom_left: radius,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Sets the radius for the top right and bottom right corners.
+    #[inline]
+    pub const fn right(radius: Val) -> Self {
+        Self {
+            top_right: radius,
+            bottom_right: radius,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Resolve a single corner radius into logical pixels for a node of size `node_size`.
+    ///
+    /// Returns zero for non-numeric values. The result is clamped between zero and half the
+    /// length of the node's shortest side.
+    pub fn resolve_single_corner(radius: Val, node_size: Vec2) -> f32 {
+        let min_side = node_size.min_element();
+        radius
+            .evaluate(min_side)
+            .unwrap_or(0.)
+            .clamp(0., 0.5 * min_side.max(0.))
+    }
+
+    /// Resolve the radii of all four corners into logical pixels for a node of size `node_size`.
+    ///
+    /// The radii are returned in the order `[top_left, top_right, bottom_right, bottom_left]`,
+    /// which is the order expected by the UI shader.
+    pub fn resolve(&self, node_size: Vec2) -> [f32; 4] {
+        [
+            Self::resolve_single_corner(self.top_left, node_size),
+            Self::resolve_single_corner(self.top_right, node_size),
+            Self::resolve_single_corner(self.bottom_right, node_size),
+            Self::resolve_single_corner(self.bottom_left, node_size),
+        ]
+    }
+}
+
 /// The 2D texture displayed for this UI node
+///
+/// Add a [`UiTextureAtlasImage`] to display a single frame of a [`TextureAtlas`] instead of the whole texture.
 #[derive(Component, Clone, Debug, Reflect)]
 #[reflect(Component, Default)]
 pub struct UiImage {
@@ -699,6 +1645,8 @@ pub struct UiImage {
     pub flip_x: bool,
     /// Whether the image should be flipped along its y-axis
     pub flip_y: bool,
+    /// The tint color of the image, multiplied with the node's [`BackgroundColor`]
+    pub color: Color,
 }
 
 impl Default for UiImage {
@@ -707,6 +1655,7 @@ impl Default for UiImage {
             texture: DEFAULT_IMAGE_HANDLE.typed(),
             flip_x: false,
             flip_y: false,
+            color: Color::WHITE,
         }
     }
 }
@@ -718,6 +1667,54 @@ impl UiImage {
             ..Default::default()
         }
     }
+
+    /// Flip the image along its x-axis
+    #[must_use]
+    pub const fn with_flip_x(mut self) -> Self {
+        self.flip_x = true;
+        self
+    }
+
+    /// Flip the image along its y-axis
+    #[must_use]
+    pub const fn with_flip_y(mut self) -> Self {
+        self.flip_y = true;
+        self
+    }
+
+    /// Set the tint color of the image
+    #[must_use]
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Displays the frame `index` of a [`TextureAtlas`] in an image node, instead of the whole [`UiImage`] texture.
+///
+/// The [`UiImage`] texture should be the texture of the atlas.
+/// The size of the node's content is the size of the frame.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiTextureAtlasImage {
+    /// The atlas defining the frames of the texture
+    pub atlas: Handle<TextureAtlas>,
+    /// The index of the displayed frame in the atlas
+    pub index: usize,
+}
+
+impl UiTextureAtlasImage {
+    pub fn new(atlas: Handle<TextureAtlas>, index: usize) -> Self {
+        Self { atlas, index }
+    }
+
+    /// Returns the frame of the atlas in texture pixels, if the atlas is loaded and has the frame
+    pub fn frame(&self, atlases: &Assets<TextureAtlas>) -> Option<Rect> {
+        atlases
+            .get(&self.atlas)
+            .and_then(|atlas| atlas.textures.get(self.index))
+            .copied()
+    }
 }
 
 impl From<Handle<Image>> for UiImage {
@@ -726,12 +1723,43 @@ impl From<Handle<Image>> for UiImage {
     }
 }
 
+/// Indicates that this root [`Node`] entity should be rendered to a specific camera.
+///
+/// The UI will be laid out using the camera's viewport size and scale factor,
+/// and rendered to the camera's [`RenderTarget`](bevy_render::camera::RenderTarget).
+///
+/// Setting this component on a non-root node has no effect: it is overridden by the
+/// [`TargetCamera`] of the root node, which is propagated to all of its descendants.
+///
+/// Root nodes without a [`TargetCamera`] are rendered to the [`DefaultUiCamera`](crate::camera_config::DefaultUiCamera).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct TargetCamera(pub Entity);
+
+impl TargetCamera {
+    /// The camera entity the UI is rendered to.
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+impl FromWorld for TargetCamera {
+    fn from_world(_world: &mut World) -> Self {
+        TargetCamera(Entity::PLACEHOLDER)
+    }
+}
+
 /// The calculated clip of the node
 #[derive(Component, Default, Copy, Clone, Debug, Reflect)]
 #[reflect(Component)]
 pub struct CalculatedClip {
     /// The rect of the clip
     pub clip: Rect,
+    /// The resolved corner radii of the clip in logical pixels,
+    /// in the order `[top_left, top_right, bottom_right, bottom_left]`.
+    ///
+    /// Set when the clip was produced by a node with both [`Overflow::Hidden`] and a [`BorderRadius`].
+    pub border_radius: [f32; 4],
 }
 
 /// Indicates that this [`Node`] entity's front-to-back ordering is not controlled solely
@@ -764,7 +1792,8 @@ impl Default for ZIndex {
 
 #[cfg(test)]
 mod tests {
-    use crate::ValArithmeticError;
+    use crate::{BorderRadius, ValArithmeticError};
+    use bevy_math::Vec2;
 
     use super::Val;
 
@@ -853,6 +1882,49 @@ mod tests {
         assert_eq!(evaluate_auto, Err(ValArithmeticError::NonEvaluateable));
     }
 
+    #[test]
+    fn viewport_val_try_add() {
+        assert_eq!(Val::Vw(20.).try_add(Val::Vw(22.)), Ok(Val::Vw(42.)));
+        assert_eq!(Val::Vh(20.).try_add(Val::Vh(22.)), Ok(Val::Vh(42.)));
+        assert_eq!(Val::VMin(20.).try_add(Val::VMin(22.)), Ok(Val::VMin(42.)));
+        assert_eq!(Val::VMax(20.).try_sub(Val::VMax(22.)), Ok(Val::VMax(-2.)));
+        assert_eq!(
+            Val::Vw(20.).try_add(Val::Vh(22.)),
+            Err(ValArithmeticError::NonIdenticalVariants)
+        );
+    }
+
+    #[test]
+    fn val_resolve() {
+        let parent_size = 200.;
+        let viewport_size = Vec2::new(1000., 500.);
+
+        assert_eq!(Val::Px(10.).resolve(parent_size, viewport_size), Ok(10.));
+        assert_eq!(
+            Val::Percent(10.).resolve(parent_size, viewport_size),
+            Ok(20.)
+        );
+        assert_eq!(Val::Vw(10.).resolve(parent_size, viewport_size), Ok(100.));
+        assert_eq!(Val::Vh(10.).resolve(parent_size, viewport_size), Ok(50.));
+        assert_eq!(Val::VMin(10.).resolve(parent_size, viewport_size), Ok(50.));
+        assert_eq!(Val::VMax(10.).resolve(parent_size, viewport_size), Ok(100.));
+        assert_eq!(
+            Val::Auto.resolve(parent_size, viewport_size),
+            Err(ValArithmeticError::NonEvaluateable)
+        );
+    }
+
+    #[test]
+    fn val_resolve_in_viewport_sub_rect() {
+        // a camera viewport covering the bottom right quarter of a 1600x900 window
+        let viewport_size = Vec2::new(800., 450.);
+
+        assert_eq!(Val::Vw(50.).resolve(0., viewport_size), Ok(400.));
+        assert_eq!(Val::Vh(50.).resolve(0., viewport_size), Ok(225.));
+        assert_eq!(Val::VMin(100.).resolve(0., viewport_size), Ok(450.));
+        assert_eq!(Val::VMax(100.).resolve(0., viewport_size), Ok(800.));
+    }
+
     #[test]
     fn val_try_add_with_size() {
         let size = 250.;
@@ -908,6 +1980,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn border_radius_resolve_px() {
+        let radius = BorderRadius::px(1., 2., 3., 4.);
+
+        assert_eq!(radius.resolve(Vec2::new(100., 50.)), [1., 2., 3., 4.]);
+    }
+
+    #[test]
+    fn border_radius_resolve_percent() {
+        let radius = BorderRadius::percent(10., 20., 30., 40.);
+
+        // percentages are resolved against the shortest side
+        assert_eq!(radius.resolve(Vec2::new(200., 100.)), [10., 20., 30., 40.]);
+    }
+
+    #[test]
+    fn border_radius_resolve_clamps_to_half_shortest_side() {
+        let node_size = Vec2::new(80., 40.);
+
+        assert_eq!(BorderRadius::MAX.resolve(node_size), [20.; 4]);
+        assert_eq!(
+            BorderRadius::percent(100., 50., 25., 0.).resolve(node_size),
+            [20., 20., 10., 0.]
+        );
+        assert_eq!(
+            BorderRadius::new(Val::Px(-5.), Val::Auto, Val::Px(30.), Val::Px(19.5))
+                .resolve(node_size),
+            [0., 0., 20., 19.5]
+        );
+    }
+
+    #[test]
+    fn border_radius_resolve_zero_sized_node() {
+        assert_eq!(BorderRadius::all(Val::Px(10.)).resolve(Vec2::ZERO), [0.; 4]);
+    }
+
     #[test]
     fn default_val_equals_const_default_val() {
         assert_eq!(Val::default(), Val::DEFAULT);
@@ -1,22 +1,38 @@
 //! This module contains systems that update the UI when something changes
 
-use crate::{CalculatedClip, Overflow, Style};
+use crate::{
+    camera_config::UiCameraConfig, focus::window_cursor_position, BorderRadius, BoxShadow,
+    CalculatedBoxShadow, CalculatedClip, Outline, Overflow, ResolvedShadow, ScrollPosition, Style,
+    TargetCamera, UiConfig, UiStack,
+};
 
 use super::Node;
 use bevy_ecs::{
+    change_detection::DetectChangesMut,
     entity::Entity,
-    query::{With, Without},
-    system::{Commands, Query},
+    event::EventReader,
+    query::{Changed, Or, With, Without},
+    removal_detection::RemovedComponents,
+    system::{Commands, Query, Res},
 };
 use bevy_hierarchy::{Children, Parent};
-use bevy_math::Rect;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_math::{Rect, Vec2};
+use bevy_render::camera::Camera;
 use bevy_transform::components::GlobalTransform;
+use bevy_window::{PrimaryWindow, Window};
 
 /// Updates clipping for all nodes
 pub fn update_clipping_system(
     mut commands: Commands,
     root_node_query: Query<Entity, (With<Node>, Without<Parent>)>,
-    mut node_query: Query<(&Node, &GlobalTransform, &Style, Option<&mut CalculatedClip>)>,
+    mut node_query: Query<(
+        &Node,
+        &GlobalTransform,
+        &Style,
+        Option<&BorderRadius>,
+        Option<&mut CalculatedClip>,
+    )>,
     children_query: Query<&Children>,
 ) {
     for root_node in &root_node_query {
@@ -33,11 +49,18 @@ pub fn update_clipping_system(
 fn update_clipping(
     commands: &mut Commands,
     children_query: &Query<&Children>,
-    node_query: &mut Query<(&Node, &GlobalTransform, &Style, Option<&mut CalculatedClip>)>,
+    node_query: &mut Query<(
+        &Node,
+        &GlobalTransform,
+        &Style,
+        Option<&BorderRadius>,
+        Option<&mut CalculatedClip>,
+    )>,
     entity: Entity,
-    clip: Option<Rect>,
+    clip: Option<CalculatedClip>,
 ) {
-    let (node, global_transform, style, calculated_clip) = node_query.get_mut(entity).unwrap();
+    let (node, global_transform, style, border_radius, calculated_clip) =
+        node_query.get_mut(entity).unwrap();
     // Update this node's CalculatedClip component
     match (clip, calculated_clip) {
         (None, None) => {}
@@ -45,11 +68,11 @@ fn update_clipping(
             commands.entity(entity).remove::<CalculatedClip>();
         }
         (Some(clip), None) => {
-            commands.entity(entity).insert(CalculatedClip { clip });
+            commands.entity(entity).insert(clip);
         }
         (Some(clip), Some(mut old_clip)) => {
-            if old_clip.clip != clip {
-                *old_clip = CalculatedClip { clip };
+            if old_clip.clip != clip.clip || old_clip.border_radius != clip.border_radius {
+                *old_clip = clip;
             }
         }
     }
@@ -57,10 +80,33 @@ fn update_clipping(
     // Calculate new clip for its children
     let children_clip = match style.overflow {
         Overflow::Visible => clip,
-        Overflow::Hidden => {
+        Overflow::Hidden | Overflow::Scroll => {
             let node_center = global_transform.translation().truncate();
             let node_rect = Rect::from_center_size(node_center, node.calculated_size);
-            Some(clip.map_or(node_rect, |c| c.intersect(node_rect)))
+            let node_radius = border_radius
+                .map(|border_radius| border_radius.resolve(node.calculated_size))
+                .unwrap_or_default();
+            Some(match clip {
+                None => CalculatedClip {
+                    clip: node_rect,
+                    border_radius: node_radius,
+                },
+                Some(parent_clip) => {
+                    let clip = parent_clip.clip.intersect(node_rect);
+                    // Only keep rounded corners if the clip is entirely defined by a single rounded rect
+                    let border_radius = if clip == node_rect {
+                        node_radius
+                    } else if clip == parent_clip.clip {
+                        parent_clip.border_radius
+                    } else {
+                        [0.; 4]
+                    };
+                    CalculatedClip {
+                        clip,
+                        border_radius,
+                    }
+                }
+            })
         }
     };
 
@@ -70,3 +116,345 @@ fn update_clipping(
         }
     }
 }
+
+/// Resolves the [`Outline`] of each [`Node`] into logical pixels and stores them on the [`Node`].
+///
+/// Outlines are drawn outside the node's bounds and never affect the layout.
+pub fn resolve_outlines_system(
+    mut outlines_query: Query<(&Outline, &mut Node), Or<(Changed<Outline>, Changed<Node>)>>,
+    mut node_query: Query<&mut Node, Without<Outline>>,
+    mut removed_outlines: RemovedComponents<Outline>,
+) {
+    for entity in removed_outlines.iter() {
+        if let Ok(mut node) = node_query.get_mut(entity) {
+            let node = node.bypass_change_detection();
+            node.outline_width = 0.;
+            node.outline_offset = 0.;
+        }
+    }
+
+    for (outline, mut node) in outlines_query.iter_mut() {
+        let node = node.bypass_change_detection();
+        node.outline_width = outline.width.evaluate(node.size().x).unwrap_or(0.).max(0.);
+        node.outline_offset = outline.offset.evaluate(node.size().x).unwrap_or(0.).max(0.);
+    }
+}
+
+/// Resolves the shadows of each [`BoxShadow`] into logical pixels and stores them in a [`CalculatedBoxShadow`].
+///
+/// Like outlines, shadows are drawn outside the node's bounds and never affect the layout.
+pub fn resolve_box_shadows_system(
+    mut commands: Commands,
+    mut shadows_query: Query<
+        (Entity, &BoxShadow, &Node, Option<&mut CalculatedBoxShadow>),
+        Or<(Changed<BoxShadow>, Changed<Node>)>,
+    >,
+    mut removed_shadows: RemovedComponents<BoxShadow>,
+) {
+    for entity in removed_shadows.iter() {
+        // the shadow may have been added back since it was removed
+        if shadows_query.contains(entity) {
+            continue;
+        }
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.remove::<CalculatedBoxShadow>();
+        }
+    }
+
+    for (entity, box_shadow, node, calculated_shadow) in shadows_query.iter_mut() {
+        let shadows = box_shadow
+            .0
+            .iter()
+            .map(|shadow| ResolvedShadow::new(shadow, node.size()))
+            .collect();
+        match calculated_shadow {
+            Some(mut calculated_shadow) => calculated_shadow.shadows = shadows,
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(CalculatedBoxShadow { shadows });
+            }
+        }
+    }
+}
+
+/// Propagates the [`TargetCamera`] of each root [`Node`] to all of its descendants.
+///
+/// This allows the render extraction to find the camera of every node without traversing the hierarchy.
+pub fn update_target_camera_system(
+    mut commands: Commands,
+    root_node_query: Query<(Entity, Option<&TargetCamera>), (With<Node>, Without<Parent>)>,
+    node_query: Query<Option<&TargetCamera>, With<Node>>,
+    children_query: Query<&Children, With<Node>>,
+) {
+    for (root_node, target_camera) in &root_node_query {
+        update_children_target_camera(
+            root_node,
+            target_camera,
+            &node_query,
+            &children_query,
+            &mut commands,
+        );
+    }
+}
+
+fn update_children_target_camera(
+    entity: Entity,
+    camera_to_set: Option<&TargetCamera>,
+    node_query: &Query<Option<&TargetCamera>, With<Node>>,
+    children_query: &Query<&Children, With<Node>>,
+    commands: &mut Commands,
+) {
+    let Ok(children) = children_query.get(entity) else {
+        return;
+    };
+
+    for &child in children {
+        let Ok(child_target_camera) = node_query.get(child) else {
+            continue;
+        };
+        // only update the component if it differs from the root's target camera
+        if child_target_camera != camera_to_set {
+            match camera_to_set {
+                Some(camera) => {
+                    commands.entity(child).insert(*camera);
+                }
+                None => {
+                    commands.entity(child).remove::<TargetCamera>();
+                }
+            }
+        }
+
+        update_children_target_camera(child, camera_to_set, node_query, children_query, commands);
+    }
+}
+
+/// Updates the [`ScrollPosition`] of nodes with [`Overflow::Scroll`] from [`MouseWheel`] events.
+///
+/// The events are applied to the top-most scrollable node under the cursor.
+/// Whatever that node can't scroll, because its scroll position reached an end,
+/// is passed on to its closest scrollable ancestor.
+#[allow(clippy::too_many_arguments)]
+pub fn update_scroll_position_system(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    ui_config: Res<UiConfig>,
+    ui_stack: Res<UiStack>,
+    camera: Query<(&Camera, Option<&UiCameraConfig>)>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut node_query: Query<(
+        &Node,
+        &Style,
+        &GlobalTransform,
+        Option<&CalculatedClip>,
+        Option<&mut ScrollPosition>,
+    )>,
+    parent_query: Query<&Parent>,
+) {
+    let delta: Vec2 = mouse_wheel_events
+        .iter()
+        .map(|event| {
+            let delta = Vec2::new(event.x, event.y);
+            match event.unit {
+                MouseScrollUnit::Line => delta * ui_config.scroll_line_height,
+                MouseScrollUnit::Pixel => delta,
+            }
+        })
+        .sum();
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    let primary_window = primary_window.iter().next();
+    let Some(cursor_position) = window_cursor_position(&camera, &windows, primary_window) else {
+        return;
+    };
+
+    let Some(mut entity) = ui_stack.uinodes.iter().rev().copied().find(|entity| {
+        node_query
+            .get(*entity)
+            .map_or(false, |(node, style, transform, clip, scroll_position)| {
+                let mut rect = node.logical_rect(transform);
+                if let Some(clip) = clip {
+                    rect = rect.intersect(clip.clip);
+                }
+                style.overflow == Overflow::Scroll
+                    && scroll_position.is_some()
+                    && rect.contains(cursor_position)
+            })
+    }) else {
+        return;
+    };
+
+    // scrolling the wheel up moves the content down
+    let mut remaining = -delta;
+    loop {
+        if let Ok((node, style, _, _, Some(mut scroll_position))) = node_query.get_mut(entity) {
+            if style.overflow == Overflow::Scroll {
+                let max_offset = (node.content_size() - node.size()).max(Vec2::ZERO);
+                let target = scroll_position.offset.clamp(Vec2::ZERO, max_offset) + remaining;
+                let new_offset = target.clamp(Vec2::ZERO, max_offset);
+                remaining = target - new_offset;
+                if scroll_position.offset != new_offset {
+                    scroll_position.offset = new_offset;
+                }
+            }
+        }
+
+        if remaining == Vec2::ZERO {
+            break;
+        }
+        let Ok(parent) = parent_query.get(entity) else {
+            break;
+        };
+        entity = parent.get();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_box_shadows_system, update_scroll_position_system};
+    use crate::{
+        BoxShadow, CalculatedBoxShadow, Node, Overflow, ResolvedShadow, ScrollPosition,
+        ShadowStyle, Style, UiConfig, UiStack, Val,
+    };
+    use bevy_ecs::{entity::Entity, event::Events, schedule::Schedule, world::World};
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+    use bevy_math::Vec2;
+    use bevy_render::{camera::Camera, color::Color};
+    use bevy_transform::components::GlobalTransform;
+    use bevy_window::{PrimaryWindow, Window};
+
+    fn scroll_world() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<UiConfig>();
+        world.init_resource::<UiStack>();
+        world.init_resource::<Events<MouseWheel>>();
+        world.spawn(Camera::default());
+        let mut window = Window::default();
+        // the cursor is at (50, 50) in UI coordinates
+        window.set_cursor_position(Some(Vec2::new(50., window.height() - 50.)));
+        world.spawn((window, PrimaryWindow));
+
+        let mut schedule = Schedule::new();
+        schedule.add_system(update_scroll_position_system);
+        (world, schedule)
+    }
+
+    /// Spawns a scroll container centered on the cursor
+    fn spawn_scroll_container(world: &mut World, size: Vec2, content_size: Vec2) -> Entity {
+        let entity = world
+            .spawn((
+                Node {
+                    calculated_size: size,
+                    content_size,
+                    ..Default::default()
+                },
+                Style {
+                    overflow: Overflow::Scroll,
+                    ..Default::default()
+                },
+                GlobalTransform::from_xyz(50., 50., 0.),
+                ScrollPosition::default(),
+            ))
+            .id();
+        world.resource_mut::<UiStack>().uinodes.push(entity);
+        entity
+    }
+
+    fn scroll(world: &mut World, schedule: &mut Schedule, unit: MouseScrollUnit, y: f32) {
+        world.send_event(MouseWheel { unit, x: 0., y });
+        schedule.run(world);
+    }
+
+    fn offset(world: &World, entity: Entity) -> Vec2 {
+        world.get::<ScrollPosition>(entity).unwrap().offset
+    }
+
+    #[test]
+    fn scroll_is_clamped_at_both_ends() {
+        let (mut world, mut schedule) = scroll_world();
+        let container =
+            spawn_scroll_container(&mut world, Vec2::splat(100.), Vec2::new(100., 150.));
+
+        scroll(&mut world, &mut schedule, MouseScrollUnit::Pixel, 20.);
+        assert_eq!(offset(&world, container), Vec2::ZERO);
+
+        scroll(&mut world, &mut schedule, MouseScrollUnit::Line, -1.);
+        assert_eq!(offset(&world, container), Vec2::new(0., 20.));
+
+        scroll(&mut world, &mut schedule, MouseScrollUnit::Line, -10.);
+        assert_eq!(offset(&world, container), Vec2::new(0., 50.));
+    }
+
+    #[test]
+    fn nested_containers_scroll_innermost_first() {
+        let (mut world, mut schedule) = scroll_world();
+        let outer = spawn_scroll_container(&mut world, Vec2::splat(100.), Vec2::new(100., 500.));
+        let inner = spawn_scroll_container(&mut world, Vec2::splat(100.), Vec2::new(100., 200.));
+        world.entity_mut(outer).push_children(&[inner]);
+
+        // the inner container consumes the scroll until it reaches its end
+        scroll(&mut world, &mut schedule, MouseScrollUnit::Pixel, -60.);
+        assert_eq!(offset(&world, inner), Vec2::new(0., 60.));
+        assert_eq!(offset(&world, outer), Vec2::ZERO);
+
+        // the rest is passed on to the outer container
+        scroll(&mut world, &mut schedule, MouseScrollUnit::Pixel, -60.);
+        assert_eq!(offset(&world, inner), Vec2::new(0., 100.));
+        assert_eq!(offset(&world, outer), Vec2::new(0., 20.));
+
+        scroll(&mut world, &mut schedule, MouseScrollUnit::Pixel, 110.);
+        assert_eq!(offset(&world, inner), Vec2::ZERO);
+        assert_eq!(offset(&world, outer), Vec2::new(0., 10.));
+    }
+
+    #[test]
+    fn box_shadows_are_resolved_and_removed_with_the_component() {
+        let mut world = World::new();
+        let mut schedule = Schedule::new();
+        schedule.add_system(resolve_box_shadows_system);
+        let shadow = ShadowStyle {
+            color: Color::BLACK,
+            x_offset: Val::Percent(10.),
+            y_offset: Val::Percent(10.),
+            spread_radius: Val::Px(-80.),
+            blur_radius: Val::Px(-5.),
+        };
+        let entity = world
+            .spawn((
+                Node {
+                    calculated_size: Vec2::new(200., 100.),
+                    ..Default::default()
+                },
+                BoxShadow(vec![shadow, ShadowStyle::default()]),
+            ))
+            .id();
+
+        schedule.run(&mut world);
+        let shadows = &world.get::<CalculatedBoxShadow>(entity).unwrap().shadows;
+        assert_eq!(
+            shadows[0],
+            ResolvedShadow {
+                color: Color::BLACK,
+                offset: Vec2::new(20., 10.),
+                // the shadow can't shrink below a size of zero, nor have a negative blur
+                spread_radius: -50.,
+                blur_radius: 0.,
+            }
+        );
+        assert_eq!(shadows[1].offset, Vec2::new(40., 20.));
+        assert_eq!(shadows[1].blur_radius, 20.);
+
+        // the shadows follow the size of the node
+        world.get_mut::<Node>(entity).unwrap().calculated_size = Vec2::new(100., 100.);
+        schedule.run(&mut world);
+        let shadows = &world.get::<CalculatedBoxShadow>(entity).unwrap().shadows;
+        assert_eq!(shadows[1].offset, Vec2::new(20., 20.));
+
+        world.entity_mut(entity).remove::<BoxShadow>();
+        schedule.run(&mut world);
+        assert!(world.get::<CalculatedBoxShadow>(entity).is_none());
+    }
+}
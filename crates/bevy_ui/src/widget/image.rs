@@ -1,30 +1,141 @@
-use crate::{CalculatedSize, UiImage};
+use crate::{measurement::AspectRatioMeasure, ContentSize, UiImage, UiTextureAtlasImage};
 use bevy_asset::Assets;
 #[cfg(feature = "bevy_text")]
 use bevy_ecs::query::Without;
-use bevy_ecs::system::{Query, Res};
+use bevy_ecs::{
+    prelude::Component,
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
 use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::texture::Image;
+use bevy_sprite::TextureAtlas;
 #[cfg(feature = "bevy_text")]
 use bevy_text::Text;
 
-/// Updates calculated size of the node based on the image provided
-pub fn update_image_calculated_size_system(
+/// The size of the image in pixels, or the size of the displayed frame for nodes with a [`UiTextureAtlasImage`]
+///
+/// This field is set automatically by `update_image_content_size_system`
+#[derive(Component, Debug, Copy, Clone, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct UiImageSize {
+    size: Vec2,
+}
+
+impl UiImageSize {
+    /// The size of the image's texture or atlas frame
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+}
+
+/// Updates content size of the node based on the image provided
+///
+/// Nodes with a [`UiTextureAtlasImage`] are sized by the displayed frame of the atlas.
+pub fn update_image_content_size_system(
     textures: Res<Assets<Image>>,
-    #[cfg(feature = "bevy_text")] mut query: Query<(&mut CalculatedSize, &UiImage), Without<Text>>,
-    #[cfg(not(feature = "bevy_text"))] mut query: Query<(&mut CalculatedSize, &UiImage)>,
+    atlases: Res<Assets<TextureAtlas>>,
+    #[cfg(feature = "bevy_text")] mut query: Query<
+        (
+            &mut ContentSize,
+            &UiImage,
+            &mut UiImageSize,
+            Option<&UiTextureAtlasImage>,
+        ),
+        Without<Text>,
+    >,
+    #[cfg(not(feature = "bevy_text"))] mut query: Query<(
+        &mut ContentSize,
+        &UiImage,
+        &mut UiImageSize,
+        Option<&UiTextureAtlasImage>,
+    )>,
 ) {
-    for (mut calculated_size, image) in &mut query {
-        if let Some(texture) = textures.get(&image.texture) {
-            let size = Vec2::new(
-                texture.texture_descriptor.size.width as f32,
-                texture.texture_descriptor.size.height as f32,
-            );
+    for (mut content_size, image, mut image_size, atlas_image) in &mut query {
+        let size = match atlas_image {
+            Some(atlas_image) => atlas_image.frame(&atlases).map(|frame| frame.size()),
+            None => textures.get(&image.texture).map(|texture| {
+                Vec2::new(
+                    texture.texture_descriptor.size.width as f32,
+                    texture.texture_descriptor.size.height as f32,
+                )
+            }),
+        };
+        if let Some(size) = size {
             // Update only if size has changed to avoid needless layout calculations
-            if size != calculated_size.size {
-                calculated_size.size = size;
-                calculated_size.preserve_aspect_ratio = true;
+            if size != image_size.size || !content_size.has_measure() {
+                image_size.size = size;
+                content_size.set(AspectRatioMeasure { size });
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{update_image_content_size_system, UiImageSize};
+    use crate::{ContentSize, UiImage, UiTextureAtlasImage};
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetServer, Assets, FileAssetIo, Handle};
+    use bevy_ecs::entity::Entity;
+    use bevy_math::{Rect, Vec2};
+    use bevy_render::texture::Image;
+    use bevy_sprite::TextureAtlas;
+    use std::sync::Arc;
+
+    fn measure(app: &App, entity: Entity) -> Arc<dyn crate::Measure> {
+        app.world
+            .get::<ContentSize>(entity)
+            .unwrap()
+            .measure
+            .clone()
+            .unwrap()
+    }
+
+    #[test]
+    fn atlas_image_is_sized_by_its_frame() {
+        let mut app = App::new();
+        app.insert_resource(AssetServer::new(FileAssetIo::new("", false)))
+            .add_asset::<Image>()
+            .add_asset::<TextureAtlas>()
+            .add_system(update_image_content_size_system);
+
+        let mut atlas = TextureAtlas::new_empty(Handle::default(), Vec2::new(64., 64.));
+        atlas.add_texture(Rect::new(0., 0., 16., 32.));
+        atlas.add_texture(Rect::new(16., 0., 32., 32.));
+        atlas.add_texture(Rect::new(32., 0., 64., 32.));
+        let atlas = app.world.resource_mut::<Assets<TextureAtlas>>().add(atlas);
+
+        let entity = app
+            .world
+            .spawn((
+                ContentSize::default(),
+                UiImage::default(),
+                UiImageSize::default(),
+                UiTextureAtlasImage::new(atlas, 0),
+            ))
+            .id();
+
+        app.update();
+        let image_size = |app: &App| app.world.get::<UiImageSize>(entity).unwrap().size();
+        assert_eq!(image_size(&app), Vec2::new(16., 32.));
+        let first_measure = measure(&app, entity);
+
+        // a frame of the same size keeps its measure
+        app.world
+            .get_mut::<UiTextureAtlasImage>(entity)
+            .unwrap()
+            .index = 1;
+        app.update();
+        assert!(Arc::ptr_eq(&first_measure, &measure(&app, entity)));
+
+        app.world
+            .get_mut::<UiTextureAtlasImage>(entity)
+            .unwrap()
+            .index = 2;
+        app.update();
+        assert_eq!(image_size(&app), Vec2::new(32., 32.));
+        assert!(!Arc::ptr_eq(&first_measure, &measure(&app, entity)));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{CalculatedSize, Node, Style, UiScale, Val};
+use crate::{ContentSize, FixedMeasure, Node, Style, UiScale, Val};
 use bevy_asset::Assets;
 use bevy_ecs::{
     entity::Entity,
@@ -54,12 +54,7 @@ pub fn text_system(
     mut text_queries: ParamSet<(
         Query<Entity, Or<(Changed<Text>, Changed<Node>, Changed<Style>)>>,
         Query<Entity, (With<Text>, With<Style>)>,
-        Query<(
-            &Text,
-            &Style,
-            &mut CalculatedSize,
-            Option<&mut TextLayoutInfo>,
-        )>,
+        Query<(&Text, &Style, &mut ContentSize, Option<&mut TextLayoutInfo>)>,
     )>,
 ) {
     // TODO: Support window-independent scaling: https://github.com/bevyengine/bevy/issues/5621
@@ -94,7 +89,7 @@ pub fn text_system(
     let mut new_queue = Vec::new();
     let mut query = text_queries.p2();
     for entity in queued_text_ids.drain(..) {
-        if let Ok((text, style, mut calculated_size, text_layout_info)) = query.get_mut(entity) {
+        if let Ok((text, style, mut content_size, text_layout_info)) = query.get_mut(entity) {
             let node_size = Vec2::new(
                 text_constraint(
                     style.min_size.width,
@@ -133,10 +128,12 @@ pub fn text_system(
                     panic!("Fatal error when processing text: {e}.");
                 }
                 Ok(info) => {
-                    calculated_size.size = Vec2::new(
-                        scale_value(info.size.x, inv_scale_factor),
-                        scale_value(info.size.y, inv_scale_factor),
-                    );
+                    content_size.set(FixedMeasure {
+                        size: Vec2::new(
+                            scale_value(info.size.x, inv_scale_factor),
+                            scale_value(info.size.y, inv_scale_factor),
+                        ),
+                    });
                     match text_layout_info {
                         Some(mut t) => *t = info,
                         None => {
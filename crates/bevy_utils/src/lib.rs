@@ -19,6 +19,7 @@ pub mod syncunsafecell;
 
 mod default;
 mod float_ord;
+mod parallel_queue;
 
 pub use ahash::AHasher;
 pub use bevy_utils_proc_macros::*;
@@ -26,6 +27,7 @@ pub use default::default;
 pub use float_ord::*;
 pub use hashbrown;
 pub use instant::{Duration, Instant};
+pub use parallel_queue::*;
 pub use petgraph;
 pub use thiserror;
 pub use tracing;
@@ -239,6 +241,59 @@ impl<K: Hash + Eq + PartialEq + Clone, V> PreHashMapExt<K, V> for PreHashMap<K,
     }
 }
 
+/// A [`BuildHasher`] that results in an [`EntityHasher`].
+#[derive(Default, Clone)]
+pub struct EntityHash;
+
+impl BuildHasher for EntityHash {
+    type Hasher = EntityHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        EntityHasher::default()
+    }
+}
+
+/// A very fast hash that is only designed to work on generational indices
+/// like `Entity`. It will panic if attempting to hash a type containing
+/// non-u64 fields.
+///
+/// The low 32 bits of the hash are the index itself, so entities spawned together stay close
+/// together in the table. The high bits mix the index and the generation with a multiplication,
+/// which [`hashbrown`] relies on to quickly tell apart entries that share a slot.
+#[derive(Debug, Default)]
+pub struct EntityHasher {
+    hash: u64,
+}
+
+impl Hasher for EntityHasher {
+    fn write(&mut self, _bytes: &[u8]) {
+        panic!("can only hash u64 using EntityHasher");
+    }
+
+    #[inline]
+    fn write_u64(&mut self, bits: u64) {
+        // The upper half of this constant is the 32 bit fixed-point inverse of the golden ratio,
+        // which spreads sequential indices evenly over the high bits. Its lower half is 1, so the
+        // low 32 bits of the product are the index unchanged. The multiplication is invertible,
+        // so no two inputs share a hash.
+        const UPPER_PHI: u64 = 0x9e37_79b9_0000_0001;
+        self.hash = bits.wrapping_mul(UPPER_PHI);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// A [`HashMap`] pre-configured to use [`EntityHash`] hashing.
+/// Iteration order only depends on the order of insertions and deletions.
+pub type EntityHashMap<K, V> = hashbrown::HashMap<K, V, EntityHash>;
+
+/// A [`HashSet`] pre-configured to use [`EntityHash`] hashing.
+/// Iteration order only depends on the order of insertions and deletions.
+pub type EntityHashSet<T> = hashbrown::HashSet<T, EntityHash>;
+
 /// A type which calls a function when dropped.
 /// This can be used to ensure that cleanup code is run even in case of a panic.
 ///
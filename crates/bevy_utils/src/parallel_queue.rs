@@ -0,0 +1,138 @@
+use std::{cell::RefCell, ops::DerefMut};
+use thread_local::ThreadLocal;
+
+/// A set of thread-local values, one for each thread accessing it.
+///
+/// This lets parallel tasks accumulate data, like the entities passing a test in a parallel
+/// query, without contending for a lock. Each thread accesses its own value with
+/// [`Parallel::scope`], and the values of all the threads are then read with [`Parallel::iter_mut`]
+/// or [`Parallel::drain`] from a single thread.
+///
+/// ```
+/// use bevy_utils::Parallel;
+///
+/// let mut even_numbers = Parallel::<Vec<u32>>::default();
+/// std::thread::scope(|scope| {
+///     for start in [0, 10, 20] {
+///         let even_numbers = &even_numbers;
+///         scope.spawn(move || {
+///             for number in (start..start + 10).filter(|number| number % 2 == 0) {
+///                 even_numbers.scope(|local| local.push(number));
+///             }
+///         });
+///     }
+/// });
+/// let mut even_numbers: Vec<u32> = even_numbers.drain().collect();
+/// even_numbers.sort();
+/// assert_eq!(even_numbers, [0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28]);
+/// ```
+#[derive(Default)]
+pub struct Parallel<T: Send> {
+    locals: ThreadLocal<RefCell<T>>,
+}
+
+impl<T: Send> Parallel<T> {
+    /// Returns an iterator over the values of all the threads that accessed this [`Parallel`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.locals.iter_mut().map(RefCell::get_mut)
+    }
+
+    /// Removes the values of all the threads.
+    pub fn clear(&mut self) {
+        self.locals.clear();
+    }
+}
+
+impl<T: Default + Send> Parallel<T> {
+    /// Runs `f` with the value of the current thread, which is initialized to its default value
+    /// the first time this thread accesses it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within `f`, since the value of the thread is already borrowed.
+    pub fn scope<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut local = self.locals.get_or_default().borrow_mut();
+        f(local.deref_mut())
+    }
+}
+
+impl<T, I> Parallel<I>
+where
+    I: IntoIterator<Item = T> + Default + Send,
+{
+    /// Removes the items of the values of all the threads, returning an iterator over them.
+    ///
+    /// The value of each thread is taken when the iterator reaches it, and replaced by its
+    /// default value. If the iterator is dropped before the end, the rest of the items of the
+    /// value it was reading are dropped and the values it didn't reach are kept.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.locals.iter_mut().flat_map(|local| local.take())
+    }
+}
+
+impl<T: Send> Parallel<Vec<T>> {
+    /// Moves the items of the vectors of all the threads to the end of `out`,
+    /// keeping the vectors' allocations for the next uses.
+    pub fn drain_into(&mut self, out: &mut Vec<T>) {
+        let size = self.iter_mut().map(|local| local.len()).sum();
+        out.reserve(size);
+        for local in self.iter_mut() {
+            out.append(local);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_local_to_each_thread() {
+        let mut counts = Parallel::<usize>::default();
+        // keeps the threads alive together, since the values of exited threads can be reused
+        let barrier = std::sync::Barrier::new(4);
+        std::thread::scope(|scope| {
+            for increments in 1..=4 {
+                let (counts, barrier) = (&counts, &barrier);
+                scope.spawn(move || {
+                    for _ in 0..increments {
+                        counts.scope(|count| *count += 1);
+                    }
+                    barrier.wait();
+                    // other threads can't have touched the value of this thread
+                    assert_eq!(counts.scope(|count| *count), increments);
+                    barrier.wait();
+                });
+            }
+        });
+        let mut counts: Vec<usize> = counts.iter_mut().map(|count| *count).collect();
+        counts.sort_unstable();
+        assert_eq!(counts, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn drained_values_are_reset() {
+        let mut numbers = Parallel::<Vec<u32>>::default();
+        std::thread::scope(|scope| {
+            for number in 0..4 {
+                let numbers = &numbers;
+                scope.spawn(move || numbers.scope(|local| local.push(number)));
+            }
+        });
+        numbers.scope(|local| local.push(4));
+
+        let mut drained: Vec<u32> = numbers.drain().collect();
+        drained.sort_unstable();
+        assert_eq!(drained, [0, 1, 2, 3, 4]);
+        assert!(numbers.iter_mut().all(|local| local.is_empty()));
+
+        numbers.scope(|local| local.push(5));
+        let mut out = vec![6];
+        numbers.drain_into(&mut out);
+        assert_eq!(out, [6, 5]);
+        assert!(numbers.iter_mut().all(|local| local.is_empty()));
+
+        numbers.clear();
+        assert_eq!(numbers.iter_mut().count(), 0);
+    }
+}
@@ -290,3 +290,31 @@ pub struct WindowMoved {
     /// Where the window moved to in physical pixels.
     pub position: IVec2,
 }
+
+/// An event that is sent when a monitor is connected, after its [`Monitor`](crate::Monitor)
+/// entity has been spawned.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MonitorConnected {
+    /// The entity of the connected monitor.
+    pub monitor: Entity,
+}
+
+/// An event that is sent when a monitor is disconnected, after its [`Monitor`](crate::Monitor)
+/// entity has been despawned.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, FromReflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MonitorDisconnected {
+    /// The entity of the disconnected monitor.
+    pub monitor: Entity,
+}
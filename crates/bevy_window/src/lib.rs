@@ -1,6 +1,7 @@
 #[warn(missing_docs)]
 mod cursor;
 mod event;
+mod monitor;
 mod raw_handle;
 mod system;
 mod window;
@@ -9,14 +10,15 @@ pub use crate::raw_handle::*;
 
 pub use cursor::*;
 pub use event::*;
+pub use monitor::*;
 pub use system::*;
 pub use window::*;
 
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        CursorEntered, CursorIcon, CursorLeft, CursorMoved, FileDragAndDrop, Ime, MonitorSelection,
-        ReceivedCharacter, Window, WindowMoved, WindowPlugin, WindowPosition,
+        CursorEntered, CursorIcon, CursorLeft, CursorMoved, FileDragAndDrop, Ime, Monitor,
+        MonitorSelection, ReceivedCharacter, Window, WindowMoved, WindowPlugin, WindowPosition,
         WindowResizeConstraints,
     };
 }
@@ -83,7 +85,9 @@ impl Plugin for WindowPlugin {
             .add_event::<WindowScaleFactorChanged>()
             .add_event::<WindowBackendScaleFactorChanged>()
             .add_event::<FileDragAndDrop>()
-            .add_event::<WindowMoved>();
+            .add_event::<WindowMoved>()
+            .add_event::<MonitorConnected>()
+            .add_event::<MonitorDisconnected>();
 
         if let Some(primary_window) = &self.primary_window {
             app.world
@@ -120,7 +124,9 @@ impl Plugin for WindowPlugin {
             .register_type::<WindowScaleFactorChanged>()
             .register_type::<WindowBackendScaleFactorChanged>()
             .register_type::<FileDragAndDrop>()
-            .register_type::<WindowMoved>();
+            .register_type::<WindowMoved>()
+            .register_type::<MonitorConnected>()
+            .register_type::<MonitorDisconnected>();
 
         // Register window descriptor and related types
         app.register_type::<Window>()
@@ -131,6 +137,7 @@ impl Plugin for WindowPlugin {
             .register_type::<PresentMode>()
             .register_type::<InternalWindowState>()
             .register_type::<MonitorSelection>()
+            .register_type::<Monitor>()
             .register_type::<WindowResizeConstraints>();
 
         // Register `PathBuf` as it's used by `FileDragAndDrop`
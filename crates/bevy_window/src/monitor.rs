@@ -0,0 +1,27 @@
+use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_math::{IVec2, UVec2};
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+
+/// A monitor connected to the system.
+///
+/// The windowing backend spawns an entity with this component for each available monitor,
+/// keeps it up to date, and despawns it when the monitor is disconnected, sending
+/// [`MonitorConnected`](crate::MonitorConnected) and
+/// [`MonitorDisconnected`](crate::MonitorDisconnected) events.
+///
+/// A monitor entity can be selected with [`MonitorSelection::Entity`](crate::MonitorSelection::Entity),
+/// for example to make a window fullscreen on it.
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect, FromReflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct Monitor {
+    /// The human-readable name of the monitor, if available.
+    pub name: Option<String>,
+    /// The size of the monitor in physical pixels.
+    pub physical_size: UVec2,
+    /// The position of the top-left corner of the monitor on the virtual desktop, in physical pixels.
+    pub position: IVec2,
+    /// The refresh rate of the monitor's current video mode in millihertz, if available.
+    pub refresh_rate_millihertz: Option<u32>,
+    /// The scale factor the windowing system uses for windows on this monitor.
+    pub scale_factor: f64,
+}
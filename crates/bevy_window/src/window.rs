@@ -228,6 +228,14 @@ impl Window {
         self.internal.minimize_request = Some(minimized);
     }
 
+    /// Requests the window to be redrawn.
+    ///
+    /// This runs one more update of the app, even if the update mode of the backend would
+    /// otherwise wait for an event, like sending a [`RequestRedraw`](crate::RequestRedraw) event.
+    pub fn request_redraw(&mut self) {
+        self.internal.redraw_request = true;
+    }
+
     /// The window's client area width in logical pixels.
     #[inline]
     pub fn width(&self) -> f32 {
@@ -659,6 +667,8 @@ pub struct InternalWindowState {
     maximize_request: Option<bool>,
     /// Unscaled cursor position.
     physical_cursor_position: Option<DVec2>,
+    /// If this is true then next frame we will ask to redraw the window.
+    redraw_request: bool,
 }
 
 impl InternalWindowState {
@@ -671,6 +681,12 @@ impl InternalWindowState {
     pub fn take_minimize_request(&mut self) -> Option<bool> {
         self.minimize_request.take()
     }
+
+    /// Consumes the current redraw request, returning true if it existed. This should only be
+    /// called by window backends.
+    pub fn take_redraw_request(&mut self) -> bool {
+        std::mem::take(&mut self.redraw_request)
+    }
 }
 
 /// Defines which monitor to use.
@@ -690,6 +706,8 @@ pub enum MonitorSelection {
     Primary,
     /// Uses monitor with the specified index.
     Index(usize),
+    /// Uses the monitor of the specified [`Monitor`](crate::Monitor) entity.
+    Entity(Entity),
 }
 
 /// Presentation mode for a window.
@@ -791,13 +809,13 @@ pub enum WindowMode {
     /// Creates a window that uses the given size.
     #[default]
     Windowed,
-    /// Creates a borderless window that uses the full size of the screen.
-    BorderlessFullscreen,
-    /// Creates a fullscreen window that will render at desktop resolution. The app will use the closest supported size
-    /// from the given size and scale it to fit the screen.
-    SizedFullscreen,
-    /// Creates a fullscreen window that uses the maximum supported size.
-    Fullscreen,
+    /// Creates a borderless window that uses the full size of the selected monitor.
+    BorderlessFullscreen(MonitorSelection),
+    /// Creates a fullscreen window on the selected monitor that will render at desktop resolution.
+    /// The app will use the closest supported size from the given size and scale it to fit the screen.
+    SizedFullscreen(MonitorSelection),
+    /// Creates a fullscreen window on the selected monitor that uses the maximum supported size.
+    Fullscreen(MonitorSelection),
 }
 
 /// A window level groups windows with respect to their z-position.
@@ -4,13 +4,15 @@ mod system;
 #[cfg(target_arch = "wasm32")]
 mod web_resize;
 mod winit_config;
+mod winit_monitors;
 mod winit_windows;
 
 use bevy_a11y::AccessibilityRequested;
 use bevy_ecs::system::{SystemParam, SystemState};
-use system::{changed_window, create_window, despawn_window, CachedWindow};
+use system::{changed_window, create_window, despawn_window, reapply_cursor_grab, CachedWindow};
 
 pub use winit_config::*;
+pub use winit_monitors::*;
 pub use winit_windows::*;
 
 use bevy_app::{App, AppExit, CoreSet, Plugin};
@@ -32,13 +34,16 @@ use bevy_window::{
     WindowCloseRequested, WindowCreated, WindowFocused, WindowMoved, WindowResized,
     WindowScaleFactorChanged,
 };
+use std::{marker::PhantomData, sync::Mutex};
 
 #[cfg(target_os = "android")]
 pub use winit::platform::android::activity::AndroidApp;
 
 use winit::{
-    event::{self, DeviceEvent, Event, StartCause, WindowEvent},
-    event_loop::{ControlFlow, EventLoop, EventLoopBuilder, EventLoopWindowTarget},
+    event::{self, DeviceEvent, StartCause, WindowEvent},
+    event_loop::{
+        ControlFlow, EventLoop, EventLoopBuilder, EventLoopClosed, EventLoopWindowTarget,
+    },
 };
 
 use crate::accessibility::{AccessKitAdapters, AccessibilityPlugin, WinitActionHandlers};
@@ -49,12 +54,70 @@ use crate::web_resize::{CanvasParentResizeEventChannel, CanvasParentResizePlugin
 #[cfg(target_os = "android")]
 pub static ANDROID_APP: once_cell::sync::OnceCell<AndroidApp> = once_cell::sync::OnceCell::new();
 
-#[derive(Default)]
-pub struct WinitPlugin;
+/// A [`Plugin`] that uses `winit` to create windows and run the app.
+///
+/// The event loop can be woken up from any thread by sending it a user event of type `T` through
+/// the [`EventLoopProxy`] resource. These events are then sent as ECS events of type `T`, and
+/// update the app even in the reactive [`UpdateMode`]s.
+///
+/// To use your own user event type, replace this plugin in the `DefaultPlugins`:
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_winit::WinitPlugin;
+/// struct FileDialogClosed(Option<std::path::PathBuf>);
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(
+///             DefaultPlugins
+///                 .build()
+///                 .disable::<WinitPlugin>()
+///                 .add(WinitPlugin::<FileDialogClosed>::default()),
+///         )
+///         .run();
+/// }
+/// ```
+pub struct WinitPlugin<T: Event = WakeUp> {
+    marker: PhantomData<T>,
+}
+
+impl<T: Event> Default for WinitPlugin<T> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+/// The default user event of the [`WinitPlugin`], used to wake up the event loop.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WakeUp;
+
+/// Sends user events of type `T` to the event loop from any thread, waking it up.
+///
+/// This resource is inserted by the [`WinitPlugin`]. The events are sent as ECS events of type
+/// `T` once they are received by the event loop.
+#[derive(Resource)]
+pub struct EventLoopProxy<T: Event>(Mutex<winit::event_loop::EventLoopProxy<T>>);
+
+impl<T: Event> EventLoopProxy<T> {
+    /// Sends an event to the event loop, waking it up.
+    ///
+    /// Returns an error containing the event if the event loop doesn't exist anymore.
+    pub fn send_event(&self, event: T) -> Result<(), EventLoopClosed<T>> {
+        self.0.lock().unwrap().send_event(event)
+    }
+}
 
-impl Plugin for WinitPlugin {
+impl<T: Event> Clone for EventLoopProxy<T> {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.0.lock().unwrap().clone()))
+    }
+}
+
+impl<T: Event> Plugin for WinitPlugin<T> {
     fn build(&self, app: &mut App) {
-        let mut event_loop_builder = EventLoopBuilder::<()>::with_user_event();
+        let mut event_loop_builder = EventLoopBuilder::<T>::with_user_event();
 
         #[cfg(target_os = "android")]
         {
@@ -68,11 +131,19 @@ impl Plugin for WinitPlugin {
         }
 
         let event_loop = event_loop_builder.build();
-        app.insert_non_send_resource(event_loop);
+
+        // Spawn the monitor entities before any window is created, so windows can select them.
+        let mut winit_monitors = WinitMonitors::default();
+        winit_monitors.sync(&*event_loop, &mut app.world);
+
+        app.insert_resource(EventLoopProxy(Mutex::new(event_loop.create_proxy())))
+            .insert_non_send_resource(event_loop)
+            .insert_non_send_resource(winit_monitors);
 
         app.init_non_send_resource::<WinitWindows>()
             .init_resource::<WinitSettings>()
-            .set_runner(winit_runner)
+            .add_event::<T>()
+            .set_runner(winit_runner::<T>)
             // exit_on_all_closed only uses the query to determine if the query is empty,
             // and so doesn't care about ordering relative to changed_window
             .add_systems(
@@ -80,6 +151,7 @@ impl Plugin for WinitPlugin {
                     changed_window.ambiguous_with(exit_on_all_closed),
                     // Update the state of the window before attempting to despawn to ensure consistent event ordering
                     despawn_window.after(changed_window),
+                    reapply_cursor_grab.after(changed_window),
                 )
                     .in_base_set(CoreSet::Last),
             );
@@ -92,10 +164,11 @@ impl Plugin for WinitPlugin {
         #[cfg(not(target_arch = "wasm32"))]
         let mut create_window_system_state: SystemState<(
             Commands,
-            NonSendMut<EventLoop<()>>,
+            NonSendMut<EventLoop<T>>,
             Query<(Entity, &mut Window)>,
             EventWriter<WindowCreated>,
             NonSendMut<WinitWindows>,
+            NonSend<WinitMonitors>,
             NonSendMut<AccessKitAdapters>,
             ResMut<WinitActionHandlers>,
             ResMut<AccessibilityRequested>,
@@ -104,10 +177,11 @@ impl Plugin for WinitPlugin {
         #[cfg(target_arch = "wasm32")]
         let mut create_window_system_state: SystemState<(
             Commands,
-            NonSendMut<EventLoop<()>>,
+            NonSendMut<EventLoop<T>>,
             Query<(Entity, &mut Window)>,
             EventWriter<WindowCreated>,
             NonSendMut<WinitWindows>,
+            NonSend<WinitMonitors>,
             NonSendMut<AccessKitAdapters>,
             ResMut<WinitActionHandlers>,
             ResMut<AccessibilityRequested>,
@@ -125,6 +199,7 @@ impl Plugin for WinitPlugin {
                 mut new_windows,
                 event_writer,
                 winit_windows,
+                winit_monitors,
                 adapters,
                 handlers,
                 accessibility_requested,
@@ -137,6 +212,7 @@ impl Plugin for WinitPlugin {
                 mut new_windows,
                 event_writer,
                 winit_windows,
+                winit_monitors,
                 adapters,
                 handlers,
                 accessibility_requested,
@@ -152,6 +228,7 @@ impl Plugin for WinitPlugin {
                 new_windows.iter_mut(),
                 event_writer,
                 winit_windows,
+                winit_monitors,
                 adapters,
                 handlers,
                 accessibility_requested,
@@ -164,9 +241,9 @@ impl Plugin for WinitPlugin {
     }
 }
 
-fn run<F>(event_loop: EventLoop<()>, event_handler: F) -> !
+fn run<T, F>(event_loop: EventLoop<T>, event_handler: F) -> !
 where
-    F: 'static + FnMut(Event<'_, ()>, &EventLoopWindowTarget<()>, &mut ControlFlow),
+    F: 'static + FnMut(event::Event<'_, T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
 {
     event_loop.run(event_handler)
 }
@@ -183,9 +260,9 @@ where
     target_os = "netbsd",
     target_os = "openbsd"
 ))]
-fn run_return<F>(event_loop: &mut EventLoop<()>, event_handler: F)
+fn run_return<T, F>(event_loop: &mut EventLoop<T>, event_handler: F)
 where
-    F: FnMut(Event<'_, ()>, &EventLoopWindowTarget<()>, &mut ControlFlow),
+    F: FnMut(event::Event<'_, T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
 {
     use winit::platform::run_return::EventLoopExtRunReturn;
     event_loop.run_return(event_handler);
@@ -200,9 +277,9 @@ where
     target_os = "netbsd",
     target_os = "openbsd"
 )))]
-fn run_return<F>(_event_loop: &mut EventLoop<()>, _event_handler: F)
+fn run_return<T, F>(_event_loop: &mut EventLoop<T>, _event_handler: F)
 where
-    F: FnMut(Event<'_, ()>, &EventLoopWindowTarget<()>, &mut ControlFlow),
+    F: FnMut(event::Event<'_, T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
 {
     panic!("Run return is not supported on this platform!")
 }
@@ -249,20 +326,99 @@ struct CursorEvents<'w> {
 struct WinitPersistentState {
     /// Tracks whether or not the application is active or suspended.
     active: bool,
-    /// Tracks whether or not an event has occurred this frame that would trigger an update in low
-    /// power mode. Should be reset at the end of every frame.
-    low_power_event: bool,
+    /// Tracks whether or not a window event has occurred this frame. Should be reset at the start
+    /// of every frame.
+    window_event: bool,
+    /// Tracks whether or not a device event has occurred this frame. Should be reset at the start
+    /// of every frame.
+    device_event: bool,
+    /// Tracks whether or not a user event has occurred this frame. Should be reset at the start
+    /// of every frame.
+    user_event: bool,
     /// Tracks whether the event loop was started this frame because of a redraw request.
     redraw_request_sent: bool,
     /// Tracks if the event loop was started this frame because of a `WaitUntil` timeout.
     timeout_reached: bool,
     last_update: Instant,
 }
+
+impl WinitPersistentState {
+    /// Resets the state at the start of an iteration of the event loop.
+    fn start_frame(&mut self, start: StartCause, now: Instant, update_mode: &UpdateMode) {
+        // Check if either the `WaitUntil` timeout was triggered by winit, or that same
+        // amount of time has elapsed since the last app update. This manual check is needed
+        // because we don't know if the criteria for an app update were met until the end of
+        // the frame.
+        let auto_timeout_reached = matches!(start, StartCause::ResumeTimeReached { .. });
+        let manual_timeout_reached = match update_mode {
+            UpdateMode::Continuous => false,
+            UpdateMode::Reactive { max_wait, .. } => {
+                now.duration_since(self.last_update) >= *max_wait
+            }
+        };
+        // The event states and timeout must be reset at the start of every frame.
+        self.window_event = false;
+        self.device_event = false;
+        self.user_event = false;
+        self.timeout_reached = auto_timeout_reached || manual_timeout_reached;
+    }
+
+    /// Returns true if the app should be updated at the end of this iteration of the event loop.
+    fn should_update(&self, update_mode: &UpdateMode) -> bool {
+        if !self.active {
+            return false;
+        }
+        match *update_mode {
+            UpdateMode::Continuous => true,
+            UpdateMode::Reactive {
+                react_to_device_events,
+                react_to_user_events,
+                react_to_window_events,
+                ..
+            } => {
+                (react_to_window_events && self.window_event)
+                    || (react_to_device_events && self.device_event)
+                    || (react_to_user_events && self.user_event)
+                    || self.redraw_request_sent
+                    || self.timeout_reached
+            }
+        }
+    }
+
+    /// Returns how the event loop should wait for events once the app has been updated.
+    ///
+    /// A redraw request makes the event loop run again immediately, which runs exactly one more
+    /// update.
+    fn end_frame(
+        &mut self,
+        now: Instant,
+        update_mode: &UpdateMode,
+        redraw_requested: bool,
+    ) -> ControlFlow {
+        self.redraw_request_sent = redraw_requested;
+        if redraw_requested {
+            return ControlFlow::Poll;
+        }
+        match update_mode {
+            UpdateMode::Continuous => ControlFlow::Poll,
+            UpdateMode::Reactive { max_wait, .. } => {
+                if let Some(instant) = now.checked_add(*max_wait) {
+                    ControlFlow::WaitUntil(instant)
+                } else {
+                    ControlFlow::Wait
+                }
+            }
+        }
+    }
+}
+
 impl Default for WinitPersistentState {
     fn default() -> Self {
         Self {
             active: false,
-            low_power_event: false,
+            window_event: false,
+            device_event: false,
+            user_event: false,
             redraw_request_sent: false,
             timeout_reached: false,
             last_update: Instant::now(),
@@ -270,11 +426,21 @@ impl Default for WinitPersistentState {
     }
 }
 
-pub fn winit_runner(mut app: App) {
+/// Sends a user event received by the event loop as an ECS event.
+fn handle_user_event<T: Event>(
+    world: &mut World,
+    winit_state: &mut WinitPersistentState,
+    event: T,
+) {
+    world.send_event(event);
+    winit_state.user_event = true;
+}
+
+pub fn winit_runner<T: Event>(mut app: App) {
     // We remove this so that we have ownership over it.
     let mut event_loop = app
         .world
-        .remove_non_send_resource::<EventLoop<()>>()
+        .remove_non_send_resource::<EventLoop<T>>()
         .unwrap();
 
     let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
@@ -296,6 +462,7 @@ pub fn winit_runner(mut app: App) {
         Query<(Entity, &mut Window), Added<Window>>,
         EventWriter<WindowCreated>,
         NonSendMut<WinitWindows>,
+        NonSend<WinitMonitors>,
         NonSendMut<AccessKitAdapters>,
         ResMut<WinitActionHandlers>,
         ResMut<AccessibilityRequested>,
@@ -307,14 +474,15 @@ pub fn winit_runner(mut app: App) {
         Query<(Entity, &mut Window), Added<Window>>,
         EventWriter<WindowCreated>,
         NonSendMut<WinitWindows>,
+        NonSend<WinitMonitors>,
         NonSendMut<AccessKitAdapters>,
         ResMut<WinitActionHandlers>,
         ResMut<AccessibilityRequested>,
         ResMut<CanvasParentResizeEventChannel>,
     )> = SystemState::from_world(&mut app.world);
 
-    let event_handler = move |event: Event<()>,
-                              event_loop: &EventLoopWindowTarget<()>,
+    let event_handler = move |event: event::Event<T>,
+                              event_loop: &EventLoopWindowTarget<T>,
                               control_flow: &mut ControlFlow| {
         #[cfg(feature = "trace")]
         let _span = bevy_utils::tracing::info_span!("winit event_handler").entered();
@@ -331,23 +499,11 @@ pub fn winit_runner(mut app: App) {
                 let (winit_config, window_focused_query) = focused_window_state.get(&app.world);
 
                 let app_focused = window_focused_query.iter().any(|window| window.focused);
-
-                // Check if either the `WaitUntil` timeout was triggered by winit, or that same
-                // amount of time has elapsed since the last app update. This manual check is needed
-                // because we don't know if the criteria for an app update were met until the end of
-                // the frame.
-                let auto_timeout_reached = matches!(start, StartCause::ResumeTimeReached { .. });
-                let now = Instant::now();
-                let manual_timeout_reached = match winit_config.update_mode(app_focused) {
-                    UpdateMode::Continuous => false,
-                    UpdateMode::Reactive { max_wait }
-                    | UpdateMode::ReactiveLowPower { max_wait } => {
-                        now.duration_since(winit_state.last_update) >= *max_wait
-                    }
-                };
-                // The low_power_event state and timeout must be reset at the start of every frame.
-                winit_state.low_power_event = false;
-                winit_state.timeout_reached = auto_timeout_reached || manual_timeout_reached;
+                winit_state.start_frame(
+                    start,
+                    Instant::now(),
+                    winit_config.update_mode(app_focused),
+                );
             }
             event::Event::WindowEvent {
                 event,
@@ -395,7 +551,7 @@ pub fn winit_runner(mut app: App) {
                         return;
                     };
 
-                winit_state.low_power_event = true;
+                winit_state.window_event = true;
 
                 match event {
                     WindowEvent::Resized(size) => {
@@ -606,6 +762,13 @@ pub fn winit_runner(mut app: App) {
                 mouse_motion.send(MouseMotion {
                     delta: Vec2::new(x as f32, y as f32),
                 });
+                winit_state.device_event = true;
+            }
+            event::Event::DeviceEvent { .. } => {
+                winit_state.device_event = true;
+            }
+            event::Event::UserEvent(event) => {
+                handle_user_event(&mut app.world, &mut winit_state, event);
             }
             event::Event::Suspended => {
                 winit_state.active = false;
@@ -623,60 +786,43 @@ pub fn winit_runner(mut app: App) {
             event::Event::MainEventsCleared => {
                 let (winit_config, window_focused_query) = focused_window_state.get(&app.world);
 
-                let update = if winit_state.active {
-                    // True if _any_ windows are currently being focused
-                    let app_focused = window_focused_query.iter().any(|window| window.focused);
-                    match winit_config.update_mode(app_focused) {
-                        UpdateMode::Continuous | UpdateMode::Reactive { .. } => true,
-                        UpdateMode::ReactiveLowPower { .. } => {
-                            winit_state.low_power_event
-                                || winit_state.redraw_request_sent
-                                || winit_state.timeout_reached
-                        }
-                    }
-                } else {
-                    false
-                };
+                // True if _any_ windows are currently being focused
+                let app_focused = window_focused_query.iter().any(|window| window.focused);
+                let update = winit_state.should_update(winit_config.update_mode(app_focused));
 
                 if update {
                     winit_state.last_update = Instant::now();
+                    // winit has no events for monitors being plugged in or out, so poll them.
+                    app.world.non_send_resource_scope(
+                        |world, mut winit_monitors: Mut<WinitMonitors>| {
+                            winit_monitors.sync(event_loop, world);
+                        },
+                    );
                     app.update();
                 }
             }
-            Event::RedrawEventsCleared => {
-                {
-                    // Fetch from world
-                    let (winit_config, window_focused_query) = focused_window_state.get(&app.world);
-
-                    // True if _any_ windows are currently being focused
-                    let app_focused = window_focused_query.iter().any(|window| window.focused);
-
-                    let now = Instant::now();
-                    use UpdateMode::*;
-                    *control_flow = match winit_config.update_mode(app_focused) {
-                        Continuous => ControlFlow::Poll,
-                        Reactive { max_wait } | ReactiveLowPower { max_wait } => {
-                            if let Some(instant) = now.checked_add(*max_wait) {
-                                ControlFlow::WaitUntil(instant)
-                            } else {
-                                ControlFlow::Wait
-                            }
-                        }
-                    };
-                }
-
+            event::Event::RedrawEventsCleared => {
                 // This block needs to run after `app.update()` in `MainEventsCleared`. Otherwise,
                 // we won't be able to see redraw requests until the next event, defeating the
                 // purpose of a redraw request!
                 let mut redraw = false;
                 if let Some(app_redraw_events) = app.world.get_resource::<Events<RequestRedraw>>() {
                     if redraw_event_reader.iter(app_redraw_events).last().is_some() {
-                        *control_flow = ControlFlow::Poll;
                         redraw = true;
                     }
                 }
 
-                winit_state.redraw_request_sent = redraw;
+                // Fetch from world
+                let (winit_config, window_focused_query) = focused_window_state.get(&app.world);
+
+                // True if _any_ windows are currently being focused
+                let app_focused = window_focused_query.iter().any(|window| window.focused);
+
+                *control_flow = winit_state.end_frame(
+                    Instant::now(),
+                    winit_config.update_mode(app_focused),
+                    redraw,
+                );
             }
 
             _ => (),
@@ -689,6 +835,7 @@ pub fn winit_runner(mut app: App) {
                 mut new_windows,
                 created_window_writer,
                 winit_windows,
+                winit_monitors,
                 adapters,
                 handlers,
                 accessibility_requested,
@@ -700,6 +847,7 @@ pub fn winit_runner(mut app: App) {
                 mut new_windows,
                 created_window_writer,
                 winit_windows,
+                winit_monitors,
                 adapters,
                 handlers,
                 accessibility_requested,
@@ -713,6 +861,7 @@ pub fn winit_runner(mut app: App) {
                 new_windows.iter_mut(),
                 created_window_writer,
                 winit_windows,
+                winit_monitors,
                 adapters,
                 handlers,
                 accessibility_requested,
@@ -731,3 +880,126 @@ pub fn winit_runner(mut app: App) {
         run(event_loop, event_handler);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_utils::Duration;
+
+    const WAIT: Duration = Duration::from_secs(5);
+
+    fn active_state() -> WinitPersistentState {
+        WinitPersistentState {
+            active: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn user_events_update_the_app() {
+        let mut app = App::new();
+        app.add_event::<WakeUp>();
+        let mut winit_state = active_state();
+        let low_power = UpdateMode::reactive_low_power(WAIT);
+        winit_state.start_frame(StartCause::Poll, winit_state.last_update, &low_power);
+        assert!(!winit_state.should_update(&low_power));
+
+        handle_user_event(&mut app.world, &mut winit_state, WakeUp);
+        assert!(winit_state.should_update(&low_power));
+        assert!(winit_state.should_update(&UpdateMode::reactive(WAIT)));
+
+        let events = app.world.resource::<Events<WakeUp>>();
+        assert_eq!(events.iter_current_update_events().count(), 1);
+
+        // Suspended apps are never updated
+        winit_state.active = false;
+        assert!(!winit_state.should_update(&low_power));
+    }
+
+    #[test]
+    fn device_events_only_update_in_reactive_mode() {
+        let mut winit_state = active_state();
+        let reactive = UpdateMode::reactive(WAIT);
+        let low_power = UpdateMode::reactive_low_power(WAIT);
+        winit_state.start_frame(StartCause::Poll, winit_state.last_update, &reactive);
+        assert!(!winit_state.should_update(&reactive));
+
+        winit_state.device_event = true;
+        assert!(winit_state.should_update(&reactive));
+        assert!(!winit_state.should_update(&low_power));
+
+        winit_state.window_event = true;
+        assert!(winit_state.should_update(&low_power));
+
+        // Events are reset at the start of each frame
+        winit_state.start_frame(StartCause::Poll, winit_state.last_update, &reactive);
+        assert!(!winit_state.should_update(&reactive));
+        assert!(winit_state.should_update(&UpdateMode::Continuous));
+    }
+
+    #[test]
+    fn redraw_requests_run_exactly_one_update() {
+        let mut winit_state = active_state();
+        let low_power = UpdateMode::reactive_low_power(WAIT);
+        let now = winit_state.last_update;
+
+        // The event loop runs again immediately after a redraw request
+        assert_eq!(
+            winit_state.end_frame(now, &low_power, true),
+            ControlFlow::Poll
+        );
+        winit_state.start_frame(StartCause::Poll, now, &low_power);
+        assert!(winit_state.should_update(&low_power));
+
+        // Then waits for the next event once the redraw has been done
+        assert_eq!(
+            winit_state.end_frame(now, &low_power, false),
+            ControlFlow::WaitUntil(now + WAIT)
+        );
+        winit_state.start_frame(StartCause::Poll, now, &low_power);
+        assert!(!winit_state.should_update(&low_power));
+    }
+
+    #[test]
+    fn updates_once_the_wait_time_elapsed() {
+        let mut winit_state = active_state();
+        let low_power = UpdateMode::reactive_low_power(WAIT);
+        let last_update = winit_state.last_update;
+
+        let start = StartCause::WaitCancelled {
+            start: last_update,
+            requested_resume: Some(last_update + WAIT),
+        };
+        winit_state.start_frame(start, last_update + WAIT / 2, &low_power);
+        assert!(!winit_state.should_update(&low_power));
+
+        // The wait time elapsed since the last update, even if winit didn't report it
+        winit_state.start_frame(start, last_update + WAIT, &low_power);
+        assert!(winit_state.should_update(&low_power));
+
+        // Winit reports that the wait time elapsed
+        let start = StartCause::ResumeTimeReached {
+            start: last_update,
+            requested_resume: last_update + WAIT,
+        };
+        winit_state.start_frame(start, last_update + WAIT / 2, &low_power);
+        assert!(winit_state.should_update(&low_power));
+
+        // Continuous mode never waits
+        winit_state.start_frame(start, last_update, &UpdateMode::Continuous);
+        assert_eq!(
+            winit_state.end_frame(last_update, &UpdateMode::Continuous, false),
+            ControlFlow::Poll
+        );
+    }
+
+    #[test]
+    fn waits_indefinitely_for_long_durations() {
+        let mut winit_state = active_state();
+        let now = winit_state.last_update;
+        assert_eq!(
+            winit_state.end_frame(now, &UpdateMode::reactive(Duration::MAX), false),
+            ControlFlow::Wait
+        );
+    }
+}
@@ -1,17 +1,21 @@
 use bevy_a11y::AccessibilityRequested;
 use bevy_ecs::{
     entity::Entity,
+    event::EventReader,
     event::EventWriter,
     prelude::{Changed, Component, Resource},
     removal_detection::RemovedComponents,
-    system::{Commands, NonSendMut, Query, ResMut},
+    system::{Commands, NonSend, NonSendMut, Query, ResMut},
     world::Mut,
 };
 use bevy_utils::{
     tracing::{error, info, warn},
     HashMap,
 };
-use bevy_window::{RawHandleWrapper, Window, WindowClosed, WindowCreated};
+use bevy_window::{
+    CursorGrabMode, RawHandleWrapper, RequestRedraw, Window, WindowClosed, WindowCreated,
+    WindowFocused,
+};
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
 use winit::{
@@ -24,7 +28,9 @@ use crate::web_resize::{CanvasParentResizeEventChannel, WINIT_CANVAS_SELECTOR};
 use crate::{
     accessibility::{AccessKitAdapters, WinitActionHandlers},
     converters::{self, convert_window_level},
-    get_best_videomode, get_fitting_videomode, WinitWindows,
+    get_best_videomode, get_fitting_videomode,
+    winit_monitors::{select_monitor, WinitMonitors},
+    WinitWindows,
 };
 
 /// System responsible for creating new windows whenever a `Window` component is added
@@ -32,12 +38,13 @@ use crate::{
 ///
 /// This will default any necessary components if they are not already added.
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn create_window<'a>(
+pub(crate) fn create_window<'a, T>(
     mut commands: Commands,
-    event_loop: &EventLoopWindowTarget<()>,
+    event_loop: &EventLoopWindowTarget<T>,
     created_windows: impl Iterator<Item = (Entity, Mut<'a, Window>)>,
     mut event_writer: EventWriter<WindowCreated>,
     mut winit_windows: NonSendMut<WinitWindows>,
+    winit_monitors: NonSend<WinitMonitors>,
     mut adapters: NonSendMut<AccessKitAdapters>,
     mut handlers: ResMut<WinitActionHandlers>,
     mut accessibility_requested: ResMut<AccessibilityRequested>,
@@ -58,6 +65,7 @@ pub(crate) fn create_window<'a>(
             event_loop,
             entity,
             &window,
+            &winit_monitors,
             &mut adapters,
             &mut handlers,
             &mut accessibility_requested,
@@ -128,6 +136,8 @@ pub struct CachedWindow {
 pub(crate) fn changed_window(
     mut changed_windows: Query<(Entity, &mut Window, &mut CachedWindow), Changed<Window>>,
     winit_windows: NonSendMut<WinitWindows>,
+    winit_monitors: NonSend<WinitMonitors>,
+    mut redraw_events: EventWriter<RequestRedraw>,
 ) {
     for (entity, mut window, mut cache) in &mut changed_windows {
         if let Some(winit_window) = winit_windows.get_window(entity) {
@@ -136,21 +146,32 @@ pub(crate) fn changed_window(
             }
 
             if window.mode != cache.window.mode {
+                let select_fullscreen_monitor = |selection| {
+                    select_monitor(
+                        selection,
+                        &winit_monitors,
+                        winit_window.available_monitors(),
+                        winit_window.primary_monitor(),
+                        winit_window.current_monitor(),
+                    )
+                };
                 let new_mode = match window.mode {
-                    bevy_window::WindowMode::BorderlessFullscreen => {
-                        Some(winit::window::Fullscreen::Borderless(None))
+                    bevy_window::WindowMode::BorderlessFullscreen(ref selection) => Some(
+                        winit::window::Fullscreen::Borderless(select_fullscreen_monitor(selection)),
+                    ),
+                    bevy_window::WindowMode::Fullscreen(ref selection) => {
+                        select_fullscreen_monitor(selection).map(|monitor| {
+                            winit::window::Fullscreen::Exclusive(get_best_videomode(&monitor))
+                        })
                     }
-                    bevy_window::WindowMode::Fullscreen => {
-                        Some(winit::window::Fullscreen::Exclusive(get_best_videomode(
-                            &winit_window.current_monitor().unwrap(),
-                        )))
-                    }
-                    bevy_window::WindowMode::SizedFullscreen => {
-                        Some(winit::window::Fullscreen::Exclusive(get_fitting_videomode(
-                            &winit_window.current_monitor().unwrap(),
-                            window.width() as u32,
-                            window.height() as u32,
-                        )))
+                    bevy_window::WindowMode::SizedFullscreen(ref selection) => {
+                        select_fullscreen_monitor(selection).map(|monitor| {
+                            winit::window::Fullscreen::Exclusive(get_fitting_videomode(
+                                &monitor,
+                                window.width() as u32,
+                                window.height() as u32,
+                            ))
+                        })
                     }
                     bevy_window::WindowMode::Windowed => None,
                 };
@@ -238,6 +259,7 @@ pub(crate) fn changed_window(
                 if let Some(position) = crate::winit_window_position(
                     &window.position,
                     &window.resolution,
+                    &winit_monitors,
                     winit_window.available_monitors(),
                     winit_window.primary_monitor(),
                     winit_window.current_monitor(),
@@ -261,6 +283,12 @@ pub(crate) fn changed_window(
                 winit_window.set_minimized(minimized);
             }
 
+            if window.internal.take_redraw_request() {
+                winit_window.request_redraw();
+                // Makes sure the app is updated in the next frame, like for a global redraw request
+                redraw_events.send(RequestRedraw);
+            }
+
             if window.focused != cache.window.focused && window.focused {
                 winit_window.focus_window();
             }
@@ -300,3 +328,81 @@ pub(crate) fn changed_window(
         }
     }
 }
+
+/// Re-applies the cursor grab mode of the windows that regained focus.
+///
+/// Some platforms release the cursor grab when the window loses focus (e.g. after alt-tabbing),
+/// without grabbing it again once the window is focused.
+pub(crate) fn reapply_cursor_grab(
+    mut focused_events: EventReader<WindowFocused>,
+    windows: Query<&Window>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    for event in focused_events.iter() {
+        let Ok(window) = windows.get(event.window) else {
+            continue;
+        };
+        let Some(grab_mode) = cursor_grab_to_reapply(event, window) else {
+            continue;
+        };
+        if let Some(winit_window) = winit_windows.get_window(event.window) {
+            crate::winit_windows::attempt_grab(winit_window, grab_mode);
+            // The cursor visibility is usually restored along with the grab
+            winit_window.set_cursor_visible(window.cursor.visible);
+        }
+    }
+}
+
+/// Returns the grab mode to apply to the cursor of `window` after receiving the focus `event`.
+fn cursor_grab_to_reapply(event: &WindowFocused, window: &Window) -> Option<CursorGrabMode> {
+    match window.cursor.grab_mode {
+        CursorGrabMode::None => None,
+        grab_mode => event.focused.then_some(grab_mode),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_window::Cursor;
+
+    fn window_with_grab(grab_mode: CursorGrabMode) -> Window {
+        Window {
+            cursor: Cursor {
+                grab_mode,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reapply_grab_on_focus() {
+        let entity = Entity::from_raw(0);
+        let focused = WindowFocused {
+            window: entity,
+            focused: true,
+        };
+        let unfocused = WindowFocused {
+            window: entity,
+            focused: false,
+        };
+
+        let locked = window_with_grab(CursorGrabMode::Locked);
+        assert_eq!(
+            cursor_grab_to_reapply(&focused, &locked),
+            Some(CursorGrabMode::Locked)
+        );
+        assert_eq!(cursor_grab_to_reapply(&unfocused, &locked), None);
+
+        let confined = window_with_grab(CursorGrabMode::Confined);
+        assert_eq!(
+            cursor_grab_to_reapply(&focused, &confined),
+            Some(CursorGrabMode::Confined)
+        );
+
+        // Cursors that weren't grabbed are left alone
+        let free = window_with_grab(CursorGrabMode::None);
+        assert_eq!(cursor_grab_to_reapply(&focused, &free), None);
+    }
+}
@@ -41,12 +41,8 @@ impl WinitSettings {
     /// Configure winit with common settings for a desktop application.
     pub fn desktop_app() -> Self {
         WinitSettings {
-            focused_mode: UpdateMode::Reactive {
-                max_wait: Duration::from_secs(5),
-            },
-            unfocused_mode: UpdateMode::ReactiveLowPower {
-                max_wait: Duration::from_secs(60),
-            },
+            focused_mode: UpdateMode::reactive(Duration::from_secs(5)),
+            unfocused_mode: UpdateMode::reactive_low_power(Duration::from_secs(60)),
             ..Default::default()
         }
     }
@@ -70,44 +66,58 @@ impl Default for WinitSettings {
 }
 
 /// Configure how the winit event loop should update.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UpdateMode {
     /// The event loop will update continuously, running as fast as possible.
     Continuous,
-    /// The event loop will only update if there is a winit event, a redraw is requested, or the
-    /// maximum wait time has elapsed.
+    /// The event loop will only update if there is an event it reacts to, a redraw is requested,
+    /// or the maximum wait time has elapsed.
     ///
-    /// ## Note
+    /// Redraws can be requested with the [`RequestRedraw`](bevy_window::RequestRedraw) event, or
+    /// for a single window with [`Window::request_redraw`](bevy_window::Window::request_redraw).
+    /// Each request runs exactly one more update.
     ///
-    /// Once the app has executed all bevy systems and reaches the end of the event loop, there is
-    /// no way to force the app to wake and update again, unless a `winit` event (such as user
-    /// input, or the window being resized) is received or the time limit is reached.
+    /// Use [`UpdateMode::reactive`] or [`UpdateMode::reactive_low_power`] to create this mode.
     Reactive {
         /// The maximum time to wait before the event loop runs again.
         ///
         /// Note that Bevy will wait indefinitely if the duration is too high (such as [`Duration::MAX`]).
         max_wait: Duration,
+        /// Reacts to device events, that aren't tied to a window (e.g. the raw mouse motion used
+        /// by cameras while the cursor is locked).
+        react_to_device_events: bool,
+        /// Reacts to user events, sent with the [`EventLoopProxy`](crate::EventLoopProxy).
+        react_to_user_events: bool,
+        /// Reacts to window events, from direct interaction with the window (e.g. mouseover,
+        /// key presses or resizes).
+        react_to_window_events: bool,
     },
-    /// The event loop will only update if there is a winit event from direct interaction with the
-    /// window (e.g. mouseover), a redraw is requested, or the maximum wait time has elapsed.
-    ///
-    /// ## Note
-    ///
-    /// Once the app has executed all bevy systems and reaches the end of the event loop, there is
-    /// no way to force the app to wake and update again, unless a `winit` event (such as user
-    /// input, or the window being resized) is received or the time limit is reached.
-    ///
-    /// ## Differences from [`UpdateMode::Reactive`]
+}
+
+impl UpdateMode {
+    /// Reacts to window, device and user events, and updates at least every `max_wait`.
+    pub fn reactive(max_wait: Duration) -> Self {
+        Self::Reactive {
+            max_wait,
+            react_to_device_events: true,
+            react_to_user_events: true,
+            react_to_window_events: true,
+        }
+    }
+
+    /// Reacts to window and user events, and updates at least every `max_wait`.
     ///
-    /// Unlike [`UpdateMode::Reactive`], this mode will ignore winit events that aren't directly
+    /// Unlike [`UpdateMode::reactive`], this mode will ignore device events that aren't directly
     /// caused by interaction with the window. For example, you might want to use this mode when the
     /// window is not focused, to only re-draw your bevy app when the cursor is over the window, but
     /// not when the mouse moves somewhere else on the screen. This helps to significantly reduce
     /// power consumption by only updated the app when absolutely necessary.
-    ReactiveLowPower {
-        /// The maximum time to wait before the event loop runs again.
-        ///
-        /// Note that Bevy will wait indefinitely if the duration is too high (such as [`Duration::MAX`]).
-        max_wait: Duration,
-    },
+    pub fn reactive_low_power(max_wait: Duration) -> Self {
+        Self::Reactive {
+            max_wait,
+            react_to_device_events: false,
+            react_to_user_events: true,
+            react_to_window_events: true,
+        }
+    }
 }
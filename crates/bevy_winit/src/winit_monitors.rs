@@ -0,0 +1,213 @@
+use bevy_ecs::{change_detection::DetectChangesMut, entity::Entity, world::World};
+use bevy_math::{IVec2, UVec2};
+use bevy_utils::tracing::warn;
+use bevy_window::{Monitor, MonitorConnected, MonitorDisconnected, MonitorSelection};
+
+use winit::{event_loop::EventLoopWindowTarget, monitor::MonitorHandle};
+
+/// A source of the monitors currently connected to the system.
+pub trait MonitorProvider {
+    /// The backend handle identifying a monitor across calls.
+    type Handle: Clone + PartialEq;
+
+    /// Returns the handle and current description of every available monitor.
+    fn available_monitors(&self) -> Vec<(Self::Handle, Monitor)>;
+}
+
+impl<T> MonitorProvider for EventLoopWindowTarget<T> {
+    type Handle = MonitorHandle;
+
+    fn available_monitors(&self) -> Vec<(MonitorHandle, Monitor)> {
+        EventLoopWindowTarget::available_monitors(self)
+            .map(|handle| {
+                let size = handle.size();
+                let position = handle.position();
+                let monitor = Monitor {
+                    name: handle.name(),
+                    physical_size: UVec2::new(size.width, size.height),
+                    position: IVec2::new(position.x, position.y),
+                    refresh_rate_millihertz: handle.refresh_rate_millihertz(),
+                    scale_factor: handle.scale_factor(),
+                };
+                (handle, monitor)
+            })
+            .collect()
+    }
+}
+
+/// The [`Monitor`] entities spawned for each monitor handle.
+#[derive(Debug)]
+pub struct WinitMonitors<H = MonitorHandle> {
+    monitors: Vec<(H, Entity)>,
+}
+
+impl<H> Default for WinitMonitors<H> {
+    fn default() -> Self {
+        Self {
+            monitors: Vec::new(),
+        }
+    }
+}
+
+impl<H: Clone + PartialEq> WinitMonitors<H> {
+    /// Get the handle of the monitor of a [`Monitor`] entity.
+    pub fn get_monitor(&self, entity: Entity) -> Option<&H> {
+        self.monitors
+            .iter()
+            .find(|(_, monitor_entity)| *monitor_entity == entity)
+            .map(|(handle, _)| handle)
+    }
+
+    /// Get the [`Monitor`] entity of a monitor handle.
+    pub fn get_monitor_entity(&self, handle: &H) -> Option<Entity> {
+        self.monitors
+            .iter()
+            .find(|(monitor_handle, _)| monitor_handle == handle)
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Spawns, updates and despawns [`Monitor`] entities to match the monitors of `provider`,
+    /// sending a [`MonitorConnected`] or [`MonitorDisconnected`] event for each change.
+    pub fn sync<P: MonitorProvider<Handle = H>>(&mut self, provider: &P, world: &mut World) {
+        let available = provider.available_monitors();
+
+        self.monitors.retain(|(handle, entity)| {
+            if available.iter().any(|(available, _)| available == handle) {
+                return true;
+            }
+            world.despawn(*entity);
+            world.send_event(MonitorDisconnected { monitor: *entity });
+            false
+        });
+
+        for (handle, monitor) in available {
+            if let Some(entity) = self.get_monitor_entity(&handle) {
+                if let Some(mut existing) = world.get_mut::<Monitor>(entity) {
+                    existing.set_if_neq(monitor);
+                }
+            } else {
+                let entity = world.spawn(monitor).id();
+                self.monitors.push((handle, entity));
+                world.send_event(MonitorConnected { monitor: entity });
+            }
+        }
+    }
+}
+
+/// Resolves a [`MonitorSelection`] to a monitor handle.
+///
+/// `current_monitor` is used for [`MonitorSelection::Current`], and should be `None` if the window
+/// has not been created yet.
+pub(crate) fn select_monitor(
+    selection: &MonitorSelection,
+    monitors: &WinitMonitors,
+    mut available_monitors: impl Iterator<Item = MonitorHandle>,
+    primary_monitor: Option<MonitorHandle>,
+    current_monitor: Option<MonitorHandle>,
+) -> Option<MonitorHandle> {
+    match selection {
+        MonitorSelection::Current => {
+            if current_monitor.is_none() {
+                warn!("Can't select current monitor on window creation or cannot find current monitor!");
+            }
+            current_monitor
+        }
+        MonitorSelection::Primary => primary_monitor,
+        MonitorSelection::Index(n) => available_monitors.nth(*n),
+        MonitorSelection::Entity(entity) => monitors.get_monitor(*entity).cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use bevy_ecs::{entity::Entity, event::Events, world::World};
+    use bevy_math::UVec2;
+    use bevy_window::{Monitor, MonitorConnected, MonitorDisconnected};
+
+    use super::{MonitorProvider, WinitMonitors};
+
+    #[derive(Default)]
+    struct MockMonitors(RefCell<Vec<(u32, Monitor)>>);
+
+    impl MockMonitors {
+        fn set(&self, monitors: &[(u32, u32)]) {
+            *self.0.borrow_mut() = monitors
+                .iter()
+                .map(|&(handle, width)| {
+                    let monitor = Monitor {
+                        name: Some(format!("Monitor {handle}")),
+                        physical_size: UVec2::new(width, 1080),
+                        ..Default::default()
+                    };
+                    (handle, monitor)
+                })
+                .collect();
+        }
+    }
+
+    impl MonitorProvider for MockMonitors {
+        type Handle = u32;
+
+        fn available_monitors(&self) -> Vec<(u32, Monitor)> {
+            self.0.borrow().clone()
+        }
+    }
+
+    fn monitor_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<MonitorConnected>>();
+        world.init_resource::<Events<MonitorDisconnected>>();
+        world
+    }
+
+    fn connected(world: &mut World) -> Vec<Entity> {
+        world
+            .resource_mut::<Events<MonitorConnected>>()
+            .drain()
+            .map(|event| event.monitor)
+            .collect()
+    }
+
+    fn disconnected(world: &mut World) -> Vec<Entity> {
+        world
+            .resource_mut::<Events<MonitorDisconnected>>()
+            .drain()
+            .map(|event| event.monitor)
+            .collect()
+    }
+
+    #[test]
+    fn sync_spawns_updates_and_despawns_monitors() {
+        let mut world = monitor_world();
+        let provider = MockMonitors::default();
+        let mut monitors = WinitMonitors::default();
+
+        provider.set(&[(1, 1920), (2, 2560)]);
+        monitors.sync(&provider, &mut world);
+        let first = monitors.get_monitor_entity(&1).unwrap();
+        let second = monitors.get_monitor_entity(&2).unwrap();
+        assert_eq!(connected(&mut world), vec![first, second]);
+        assert!(disconnected(&mut world).is_empty());
+        assert_eq!(world.get::<Monitor>(second).unwrap().physical_size.x, 2560);
+        assert_eq!(monitors.get_monitor(first), Some(&1));
+
+        // an unchanged set of monitors sends no events
+        monitors.sync(&provider, &mut world);
+        assert!(connected(&mut world).is_empty());
+        assert!(disconnected(&mut world).is_empty());
+
+        // unplugging the first monitor, changing the mode of the second and plugging in a third
+        provider.set(&[(2, 3840), (3, 1280)]);
+        monitors.sync(&provider, &mut world);
+        let third = monitors.get_monitor_entity(&3).unwrap();
+        assert_eq!(connected(&mut world), vec![third]);
+        assert_eq!(disconnected(&mut world), vec![first]);
+        assert!(world.get_entity(first).is_none());
+        assert_eq!(monitors.get_monitor_entity(&1), None);
+        assert_eq!(monitors.get_monitor_entity(&2), Some(second));
+        assert_eq!(world.get::<Monitor>(second).unwrap().physical_size.x, 3840);
+        assert_eq!(world.query::<&Monitor>().iter(&world).count(), 2);
+    }
+}
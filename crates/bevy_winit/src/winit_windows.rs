@@ -18,6 +18,7 @@ use winit::{
 use crate::{
     accessibility::{AccessKitAdapters, WinitActionHandler, WinitActionHandlers},
     converters::convert_window_level,
+    winit_monitors::{select_monitor, WinitMonitors},
 };
 
 #[derive(Debug, Default)]
@@ -32,11 +33,12 @@ pub struct WinitWindows {
 }
 
 impl WinitWindows {
-    pub fn create_window(
+    pub fn create_window<T>(
         &mut self,
-        event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
+        event_loop: &winit::event_loop::EventLoopWindowTarget<T>,
         entity: Entity,
         window: &Window,
+        monitors: &WinitMonitors,
         adapters: &mut AccessKitAdapters,
         handlers: &mut WinitActionHandlers,
         accessibility_requested: &mut AccessibilityRequested,
@@ -47,26 +49,42 @@ impl WinitWindows {
         // AccessKit adapter is initialized.
         winit_window_builder = winit_window_builder.with_visible(false);
 
+        // The window has no current monitor yet, so `MonitorSelection::Current` falls back to the
+        // primary monitor.
+        let select_fullscreen_monitor = |selection| {
+            select_monitor(
+                selection,
+                monitors,
+                event_loop.available_monitors(),
+                event_loop.primary_monitor(),
+                event_loop.primary_monitor(),
+            )
+        };
+
         winit_window_builder = match window.mode {
-            WindowMode::BorderlessFullscreen => winit_window_builder.with_fullscreen(Some(
-                winit::window::Fullscreen::Borderless(event_loop.primary_monitor()),
-            )),
-            WindowMode::Fullscreen => {
-                winit_window_builder.with_fullscreen(Some(winit::window::Fullscreen::Exclusive(
-                    get_best_videomode(&event_loop.primary_monitor().unwrap()),
-                )))
+            WindowMode::BorderlessFullscreen(ref selection) => winit_window_builder
+                .with_fullscreen(Some(winit::window::Fullscreen::Borderless(
+                    select_fullscreen_monitor(selection),
+                ))),
+            WindowMode::Fullscreen(ref selection) => {
+                winit_window_builder.with_fullscreen(select_fullscreen_monitor(selection).map(
+                    |monitor| winit::window::Fullscreen::Exclusive(get_best_videomode(&monitor)),
+                ))
             }
-            WindowMode::SizedFullscreen => winit_window_builder.with_fullscreen(Some(
-                winit::window::Fullscreen::Exclusive(get_fitting_videomode(
-                    &event_loop.primary_monitor().unwrap(),
-                    window.width() as u32,
-                    window.height() as u32,
-                )),
-            )),
+            WindowMode::SizedFullscreen(ref selection) => winit_window_builder.with_fullscreen(
+                select_fullscreen_monitor(selection).map(|monitor| {
+                    winit::window::Fullscreen::Exclusive(get_fitting_videomode(
+                        &monitor,
+                        window.width() as u32,
+                        window.height() as u32,
+                    ))
+                }),
+            ),
             WindowMode::Windowed => {
                 if let Some(position) = winit_window_position(
                     &window.position,
                     &window.resolution,
+                    monitors,
                     event_loop.available_monitors(),
                     event_loop.primary_monitor(),
                     None,
@@ -305,7 +323,8 @@ pub(crate) fn attempt_grab(winit_window: &winit::window::Window, grab_mode: Curs
 pub fn winit_window_position(
     position: &WindowPosition,
     resolution: &WindowResolution,
-    mut available_monitors: impl Iterator<Item = MonitorHandle>,
+    monitors: &WinitMonitors,
+    available_monitors: impl Iterator<Item = MonitorHandle>,
     primary_monitor: Option<MonitorHandle>,
     current_monitor: Option<MonitorHandle>,
 ) -> Option<PhysicalPosition<i32>> {
@@ -315,17 +334,13 @@ pub fn winit_window_position(
             None
         }
         WindowPosition::Centered(monitor_selection) => {
-            use bevy_window::MonitorSelection::*;
-            let maybe_monitor = match monitor_selection {
-                Current => {
-                    if current_monitor.is_none() {
-                        warn!("Can't select current monitor on window creation or cannot find current monitor!");
-                    }
-                    current_monitor
-                }
-                Primary => primary_monitor,
-                Index(n) => available_monitors.nth(*n),
-            };
+            let maybe_monitor = select_monitor(
+                monitor_selection,
+                monitors,
+                available_monitors,
+                primary_monitor,
+                current_monitor,
+            );
 
             if let Some(monitor) = maybe_monitor {
                 let screen_size = monitor.size();
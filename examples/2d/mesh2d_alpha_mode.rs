@@ -0,0 +1,67 @@
+//! Shows the different alpha modes of 2d materials: the Bevy logo is drawn opaque, masked and
+//! blended, over a background of colored bars.
+
+use bevy::{
+    prelude::*,
+    sprite::{AlphaMode2d, MaterialMesh2dBundle},
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Opaque bars behind the logos, to see where they are drawn through
+    let bar = meshes.add(Mesh::from(shape::Quad::new(Vec2::new(800.0, 40.0))));
+    for (i, color) in [Color::RED, Color::GREEN, Color::BLUE]
+        .into_iter()
+        .enumerate()
+    {
+        commands.spawn(MaterialMesh2dBundle {
+            mesh: bar.clone().into(),
+            material: materials.add(ColorMaterial {
+                color,
+                alpha_mode: AlphaMode2d::Opaque,
+                ..default()
+            }),
+            transform: Transform::from_xyz(0.0, 60.0 * (i as f32 - 1.0), -1.0),
+            ..default()
+        });
+    }
+
+    let logo = asset_server.load("branding/icon.png");
+    let quad = meshes.add(Mesh::from(shape::Quad::new(Vec2::splat(200.0))));
+    for (i, alpha_mode) in [
+        // The transparent pixels are drawn with their color, hiding the bars
+        AlphaMode2d::Opaque,
+        // The pixels of the logo under the threshold are discarded, without blending
+        AlphaMode2d::Mask(0.5),
+        // The pixels are blended with the bars, with their alpha as opacity
+        AlphaMode2d::Blend,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        commands.spawn(MaterialMesh2dBundle {
+            mesh: quad.clone().into(),
+            material: materials.add(ColorMaterial {
+                // Make the logo semi-transparent, to show the difference between masking and blending
+                color: Color::rgba(1.0, 1.0, 1.0, 0.7),
+                alpha_mode,
+                texture: Some(logo.clone()),
+            }),
+            transform: Transform::from_xyz(250.0 * (i as f32 - 1.0), 0.0, 0.0),
+            ..default()
+        });
+    }
+}
@@ -316,7 +316,6 @@ pub fn queue_colored_mesh2d(
     colored_mesh2d_pipeline: Res<ColoredMesh2dPipeline>,
     mut pipelines: ResMut<SpecializedRenderPipelines<ColoredMesh2dPipeline>>,
     pipeline_cache: Res<PipelineCache>,
-    msaa: Res<Msaa>,
     render_meshes: Res<RenderAssets<Mesh>>,
     colored_mesh2d: Query<(&Mesh2dHandle, &Mesh2dUniform), With<ColoredMesh2d>>,
     mut views: Query<(
@@ -332,7 +331,7 @@ pub fn queue_colored_mesh2d(
     for (visible_entities, mut transparent_phase, view) in &mut views {
         let draw_colored_mesh2d = transparent_draw_functions.read().id::<DrawColoredMesh2d>();
 
-        let mesh_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
+        let mesh_key = Mesh2dPipelineKey::from_msaa_samples(view.msaa.samples())
             | Mesh2dPipelineKey::from_hdr(view.hdr);
 
         // Queue all entities visible to that view
@@ -356,6 +355,7 @@ pub fn queue_colored_mesh2d(
                     // The 2d render items are sorted according to their z value before rendering,
                     // in order to get correct transparency
                     sort_key: FloatOrd(mesh_z),
+                    draw_order: None,
                     // This material is not batched
                     batch_range: None,
                 });
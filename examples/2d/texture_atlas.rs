@@ -70,8 +70,11 @@ fn setup(
         texture_atlas_builder.add_texture(handle, texture);
     }
 
-    let texture_atlas = texture_atlas_builder.finish(&mut textures).unwrap();
-    let texture_atlas_texture = texture_atlas.texture.clone();
+    let (texture_atlas_layout, texture_atlas_image) =
+        texture_atlas_builder.finish(&textures).unwrap();
+    let texture_atlas_texture = textures.add(texture_atlas_image);
+    let texture_atlas =
+        TextureAtlas::from_layout(texture_atlas_texture.clone(), texture_atlas_layout);
     let vendor_handle = asset_server.get_handle("textures/rpg/chars/vendor/generic-rpg-vendor.png");
     let vendor_index = texture_atlas.get_texture_index(&vendor_handle).unwrap();
     let atlas_handle = texture_atlases.add(texture_atlas);
@@ -3,16 +3,18 @@
 //!
 //! ## Controls
 //!
-//! | Key Binding        | Action                              |
-//! |:-------------------|:------------------------------------|
-//! | `1` / `2` / `3`    | Fog Falloff Mode                    |
-//! | `A` / `S`          | Move Start Distance (Linear Fog)    |
-//! |                    | Change Density (Exponential Fogs)   |
-//! | `Z` / `X`          | Move End Distance (Linear Fog)      |
-//! | `-` / `=`          | Adjust Fog Red Channel              |
-//! | `[` / `]`          | Adjust Fog Green Channel            |
-//! | `;` / `'`          | Adjust Fog Blue Channel             |
-//! | `.` / `?`          | Adjust Fog Alpha Channel            |
+//! | Key Binding           | Action                              |
+//! |:----------------------|:------------------------------------|
+//! | `1` / `2` / `3` / `4` | Fog Falloff Mode                    |
+//! | `A` / `S`             | Move Start Distance (Linear Fog)    |
+//! |                       | Change Density (Exponential Fogs)   |
+//! |                       | Change Density (Height Fog)         |
+//! | `Z` / `X`             | Move End Distance (Linear Fog)      |
+//! |                       | Change Falloff Height (Height Fog)  |
+//! | `-` / `=`             | Adjust Fog Red Channel              |
+//! | `[` / `]`             | Adjust Fog Green Channel            |
+//! | `;` / `'`             | Adjust Fog Blue Channel             |
+//! | `.` / `?`             | Adjust Fog Alpha Channel            |
 
 use bevy::{
     pbr::{NotShadowCaster, NotShadowReceiver},
@@ -178,7 +180,7 @@ fn update_system(
     // Fog Falloff Mode Switching
     text.sections[0]
         .value
-        .push_str("\n\n1 / 2 / 3 - Fog Falloff Mode");
+        .push_str("\n\n1 / 2 / 3 / 4 - Fog Falloff Mode");
 
     if keycode.pressed(KeyCode::Key1) {
         if let FogFalloff::Linear { .. } = fog.falloff {
@@ -211,6 +213,19 @@ fn update_system(
         };
     }
 
+    if keycode.pressed(KeyCode::Key4) {
+        if let FogFalloff::Height { .. } = fog.falloff {
+            // No change
+        } else {
+            fog.falloff = FogFalloff::Height {
+                density: 0.3,
+                base_height: 0.0,
+                falloff_height: 1.5,
+                inscattering: Color::WHITE,
+            };
+        };
+    }
+
     // Linear Fog Controls
     if let FogFalloff::Linear {
         ref mut start,
@@ -265,6 +280,31 @@ fn update_system(
         }
     }
 
+    // Height Fog Controls
+    if let FogFalloff::Height {
+        ref mut density,
+        ref mut falloff_height,
+        ..
+    } = &mut fog.falloff
+    {
+        text.sections[0]
+            .value
+            .push_str("\nA / S - Change Density\nZ / X - Change Falloff Height");
+
+        if keycode.pressed(KeyCode::A) {
+            *density -= delta * 0.5 * *density;
+        }
+        if keycode.pressed(KeyCode::S) {
+            *density += delta * 0.5 * *density;
+        }
+        if keycode.pressed(KeyCode::Z) {
+            *falloff_height = (*falloff_height - delta).max(0.1);
+        }
+        if keycode.pressed(KeyCode::X) {
+            *falloff_height += delta;
+        }
+    }
+
     // RGBA Controls
     text.sections[0]
         .value
@@ -15,7 +15,6 @@ use bevy::{
 fn main() {
     App::new()
         // Disable MSAA by default
-        .insert_resource(Msaa::Off)
         .add_plugins(DefaultPlugins)
         .add_systems((setup.on_startup(), toggle_fxaa))
         .run();
@@ -101,10 +100,10 @@ fn setup(
                 .looking_at(Vec3::new(0.0, 0.3, 0.0), Vec3::Y),
             ..default()
         })
-        .insert(Fxaa::default());
+        .insert((Fxaa::default(), Msaa::Off));
 }
 
-fn toggle_fxaa(keys: Res<Input<KeyCode>>, mut query: Query<&mut Fxaa>, mut msaa: ResMut<Msaa>) {
+fn toggle_fxaa(keys: Res<Input<KeyCode>>, mut query: Query<(&mut Fxaa, &mut Msaa)>) {
     let set_no_aa = keys.just_pressed(KeyCode::Key1);
     let set_msaa = keys.just_pressed(KeyCode::Key2);
     let set_fxaa = keys.just_pressed(KeyCode::Key3);
@@ -115,7 +114,7 @@ fn toggle_fxaa(keys: Res<Input<KeyCode>>, mut query: Query<&mut Fxaa>, mut msaa:
     let fxaa_extreme = keys.just_pressed(KeyCode::Key0);
     let set_fxaa = set_fxaa | fxaa_low | fxaa_med | fxaa_high | fxaa_ultra | fxaa_extreme;
 
-    for mut fxaa in &mut query {
+    for (mut fxaa, mut msaa) in &mut query {
         if set_msaa {
             fxaa.enabled = false;
             *msaa = Msaa::Sample4;
@@ -0,0 +1,217 @@
+//! Projects textures through spot and point lights, like light cookies.
+//!
+//! The spot light shines through a window frame while the point light is filtered through a
+//! colored cube map.
+
+use std::f32::consts::*;
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems((setup.on_startup(), circle_point_light, sway_spot_light))
+        .run();
+}
+
+#[derive(Component)]
+struct Swaying;
+
+#[derive(Component)]
+struct Circling;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let white = materials.add(StandardMaterial {
+        base_color: Color::WHITE,
+        perceptual_roughness: 1.0,
+        ..default()
+    });
+
+    // floor and walls
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Plane::from_size(20.0).into()),
+        material: white.clone(),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Box::new(20.0, 8.0, 0.2).into()),
+        material: white.clone(),
+        transform: Transform::from_xyz(0.0, 4.0, -4.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Box::new(0.2, 8.0, 20.0).into()),
+        material: white.clone(),
+        transform: Transform::from_xyz(-6.0, 4.0, 0.0),
+        ..default()
+    });
+
+    // a few shapes to catch the light
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(
+            shape::UVSphere {
+                radius: 0.5,
+                ..default()
+            }
+            .into(),
+        ),
+        material: white.clone(),
+        transform: Transform::from_xyz(2.5, 0.5, 1.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Cube { size: 1.0 }.into()),
+        material: white,
+        transform: Transform::from_xyz(-3.5, 0.5, 0.5),
+        ..default()
+    });
+
+    // a spot light shining through a window frame onto the back wall
+    commands.spawn((
+        SpotLightBundle {
+            transform: Transform::from_xyz(2.0, 5.0, 6.0)
+                .looking_at(Vec3::new(2.0, 3.0, -4.0), Vec3::Y),
+            spot_light: SpotLight {
+                intensity: 8000.0,
+                range: 30.0,
+                outer_angle: PI / 8.0,
+                inner_angle: PI / 8.0 * 0.9,
+                shadows_enabled: true,
+                light_texture: Some(images.add(window_frame(256))),
+                ..default()
+            },
+            ..default()
+        },
+        Swaying,
+    ));
+
+    // a point light filtered through a cube map with differently colored stripes on each face
+    commands
+        .spawn((
+            PointLightBundle {
+                transform: Transform::from_xyz(-3.0, 3.0, 1.0),
+                point_light: PointLight {
+                    intensity: 3000.0,
+                    shadows_enabled: true,
+                    light_texture: Some(images.add(striped_cube_map(128))),
+                    ..default()
+                },
+                ..default()
+            },
+            Circling,
+        ))
+        .with_children(|builder| {
+            builder.spawn(PbrBundle {
+                mesh: meshes.add(
+                    shape::UVSphere {
+                        radius: 0.05,
+                        ..default()
+                    }
+                    .into(),
+                ),
+                material: materials.add(StandardMaterial {
+                    base_color: Color::WHITE,
+                    emissive: Color::WHITE,
+                    ..default()
+                }),
+                ..default()
+            });
+        });
+
+    commands.insert_resource(AmbientLight {
+        color: Color::WHITE,
+        brightness: 0.02,
+    });
+
+    // camera
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(6.0, 5.0, 10.0)
+            .looking_at(Vec3::new(0.0, 2.0, 0.0), Vec3::Y),
+        ..default()
+    });
+}
+
+/// A white texture with a dark frame dividing it into four panes.
+fn window_frame(size: u32) -> Image {
+    let frame_width = size / 16;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let on_frame = |coordinate: u32| {
+                coordinate < frame_width
+                    || coordinate >= size - frame_width
+                    || coordinate.abs_diff(size / 2) < frame_width / 2
+            };
+            let value = if on_frame(x) || on_frame(y) { 0 } else { 255 };
+            data.extend_from_slice(&[value, value, value, 255]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+/// A cube map with stripes of a different color on each of its six faces.
+fn striped_cube_map(size: u32) -> Image {
+    let colors = [
+        Color::RED,
+        Color::GREEN,
+        Color::BLUE,
+        Color::YELLOW,
+        Color::CYAN,
+        Color::FUCHSIA,
+    ];
+    let mut data = Vec::with_capacity((size * size * 4 * 6) as usize);
+    for color in colors {
+        let color = color.as_rgba_f32().map(|channel| (channel * 255.0) as u8);
+        for _y in 0..size {
+            for x in 0..size {
+                if (x * 8 / size) % 2 == 0 {
+                    data.extend_from_slice(&color);
+                } else {
+                    data.extend_from_slice(&[0, 0, 0, 255]);
+                }
+            }
+        }
+    }
+    // The six faces are stored as the six array layers of one image
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn sway_spot_light(time: Res<Time>, mut query: Query<&mut Transform, With<Swaying>>) {
+    for mut transform in &mut query {
+        let target = Vec3::new(2.0 + time.elapsed_seconds().sin() * 2.0, 3.0, -4.0);
+        transform.look_at(target, Vec3::Y);
+    }
+}
+
+fn circle_point_light(time: Res<Time>, mut query: Query<&mut Transform, With<Circling>>) {
+    for mut transform in &mut query {
+        let angle = time.elapsed_seconds() * 0.5;
+        transform.translation = Vec3::new(-3.0 + angle.cos(), 3.0, 1.0 + angle.sin());
+    }
+}
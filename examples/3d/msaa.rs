@@ -1,4 +1,4 @@
-//! This example shows how to configure Multi-Sample Anti-Aliasing. Setting the sample count higher
+//! This example shows how to configure Multi-Sample Anti-Aliasing on a camera. Setting the sample count higher
 //! will result in smoother edges, but it will also increase the cost to render those edges. The
 //! range should generally be somewhere between 1 (no multi sampling, but cheap) to 8 (crisp but
 //! expensive).
@@ -8,7 +8,6 @@ use bevy::prelude::*;
 
 fn main() {
     App::new()
-        .insert_resource(Msaa::default())
         .add_plugins(DefaultPlugins)
         .add_systems((setup.on_startup(), cycle_msaa))
         .run();
@@ -35,13 +34,17 @@ fn setup(
         ..default()
     });
     // camera
-    commands.spawn(Camera3dBundle {
-        transform: Transform::from_xyz(-3.0, 3.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
-        ..default()
-    });
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-3.0, 3.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        Msaa::Sample4,
+    ));
 }
 
-fn cycle_msaa(input: Res<Input<KeyCode>>, mut msaa: ResMut<Msaa>) {
+fn cycle_msaa(input: Res<Input<KeyCode>>, mut cameras: Query<&mut Msaa>) {
+    let mut msaa = cameras.single_mut();
     if input.just_pressed(KeyCode::Key1) {
         info!("Not using MSAA");
         *msaa = Msaa::Off;
@@ -61,6 +61,43 @@ fn setup(
         ..default()
     });
 
+    // a row of clearcoated spheres, then a row of brushed metal spheres
+    let mut sphere_with_tangents = Mesh::try_from(shape::Icosphere {
+        radius: 0.45,
+        subdivisions: 32,
+    })
+    .unwrap();
+    // anisotropy is oriented along the mesh tangents
+    sphere_with_tangents.generate_tangents().unwrap();
+    let sphere_with_tangents = meshes.add(sphere_with_tangents);
+    for x in -4..=5 {
+        let material = if x <= 0 {
+            // vary the roughness of the coating over a rough base
+            StandardMaterial {
+                base_color: Color::hex("#9b1b1b").unwrap(),
+                perceptual_roughness: 0.8,
+                clearcoat: 1.0,
+                clearcoat_perceptual_roughness: (x + 4) as f32 / 8.0,
+                ..default()
+            }
+        } else {
+            // vary how much the highlight is stretched
+            StandardMaterial {
+                base_color: Color::hex("#c0c0c8").unwrap(),
+                metallic: 1.0,
+                perceptual_roughness: 0.3,
+                anisotropy_strength: (x - 1) as f32 / 4.0,
+                ..default()
+            }
+        };
+        commands.spawn(PbrBundle {
+            mesh: sphere_with_tangents.clone(),
+            material: materials.add(material),
+            transform: Transform::from_xyz(x as f32, -3.0, 0.0),
+            ..default()
+        });
+    }
+
     // light
     commands.spawn(PointLightBundle {
         transform: Transform::from_xyz(50.0, 50.0, 50.0),
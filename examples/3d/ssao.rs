@@ -0,0 +1,171 @@
+//! A scene showcasing screen space ambient occlusion.
+
+use bevy::{
+    pbr::{
+        ScreenSpaceAmbientOcclusionBundle, ScreenSpaceAmbientOcclusionPlugin,
+        ScreenSpaceAmbientOcclusionQualityLevel, ScreenSpaceAmbientOcclusionSettings,
+    },
+    prelude::*,
+};
+use std::f32::consts::PI;
+
+fn main() {
+    App::new()
+        .insert_resource(AmbientLight {
+            brightness: 1.0,
+            ..default()
+        })
+        // SSAO does not support multisampling
+        .add_plugins(DefaultPlugins)
+        .add_plugin(ScreenSpaceAmbientOcclusionPlugin)
+        .add_systems((setup.on_startup(), update))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-2.0, 2.0, -2.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        ScreenSpaceAmbientOcclusionBundle::default(),
+        // SSAO doesn't support multisampling
+        Msaa::Off,
+    ));
+
+    let material = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.5, 0.5, 0.5),
+        perceptual_roughness: 1.0,
+        reflectance: 0.0,
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material: material.clone(),
+        transform: Transform::from_xyz(0.0, 0.0, 1.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material: material.clone(),
+        transform: Transform::from_xyz(0.0, -1.0, 0.0),
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material,
+        transform: Transform::from_xyz(1.0, 0.0, 0.0),
+        ..default()
+    });
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::UVSphere {
+                radius: 0.4,
+                sectors: 72,
+                stacks: 36,
+            })),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.4, 0.4, 0.4),
+                perceptual_roughness: 1.0,
+                reflectance: 0.0,
+                ..default()
+            }),
+            ..default()
+        },
+        SphereMarker,
+    ));
+
+    commands.spawn(DirectionalLightBundle {
+        directional_light: DirectionalLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_rotation(Quat::from_euler(
+            EulerRot::ZYX,
+            0.0,
+            PI * -0.15,
+            PI * -0.15,
+        )),
+        ..default()
+    });
+
+    commands.spawn(
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+                font_size: 26.0,
+                color: Color::BLACK,
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+    );
+}
+
+fn update(
+    camera: Query<(Entity, Option<&ScreenSpaceAmbientOcclusionSettings>), With<Camera>>,
+    mut text: Query<&mut Text>,
+    mut sphere: Query<&mut Transform, With<SphereMarker>>,
+    mut commands: Commands,
+    keycode: Res<Input<KeyCode>>,
+    time: Res<Time>,
+) {
+    let mut sphere = sphere.single_mut();
+    sphere.translation.y = (time.elapsed_seconds() / 1.7).sin() * 0.7;
+
+    let (camera_entity, ssao_settings) = camera.single();
+
+    let mut commands = commands.entity(camera_entity);
+    if keycode.just_pressed(KeyCode::Key1) {
+        commands.remove::<ScreenSpaceAmbientOcclusionSettings>();
+    }
+    let mut set_quality_level = |quality_level| {
+        commands.insert(ScreenSpaceAmbientOcclusionSettings { quality_level });
+    };
+    if keycode.just_pressed(KeyCode::Key2) {
+        set_quality_level(ScreenSpaceAmbientOcclusionQualityLevel::Low);
+    }
+    if keycode.just_pressed(KeyCode::Key3) {
+        set_quality_level(ScreenSpaceAmbientOcclusionQualityLevel::Medium);
+    }
+    if keycode.just_pressed(KeyCode::Key4) {
+        set_quality_level(ScreenSpaceAmbientOcclusionQualityLevel::High);
+    }
+    if keycode.just_pressed(KeyCode::Key5) {
+        set_quality_level(ScreenSpaceAmbientOcclusionQualityLevel::Ultra);
+    }
+
+    let mut text = text.single_mut();
+    let text = &mut text.sections[0].value;
+    text.clear();
+
+    let quality_level = ssao_settings.map(|settings| settings.quality_level);
+    let (o, l, m, h, u) = match quality_level {
+        None => ("*", "", "", "", ""),
+        Some(ScreenSpaceAmbientOcclusionQualityLevel::Low) => ("", "*", "", "", ""),
+        Some(ScreenSpaceAmbientOcclusionQualityLevel::Medium) => ("", "", "*", "", ""),
+        Some(ScreenSpaceAmbientOcclusionQualityLevel::High) => ("", "", "", "*", ""),
+        Some(ScreenSpaceAmbientOcclusionQualityLevel::Ultra) => ("", "", "", "", "*"),
+        Some(ScreenSpaceAmbientOcclusionQualityLevel::Custom { .. }) => ("", "", "", "", ""),
+    };
+
+    text.push_str("SSAO Quality:\n");
+    text.push_str(&format!("(1) {o}Off{o}\n"));
+    text.push_str(&format!("(2) {l}Low{l}\n"));
+    text.push_str(&format!("(3) {m}Medium{m}\n"));
+    text.push_str(&format!("(4) {h}High{h}\n"));
+    text.push_str(&format!("(5) {u}Ultra{u}"));
+}
+
+#[derive(Component)]
+struct SphereMarker;
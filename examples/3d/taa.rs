@@ -0,0 +1,131 @@
+//! This example compares MSAA (Multi-Sample Anti-Aliasing) and TAA (Temporal Anti-Aliasing).
+
+use std::f32::consts::PI;
+
+use bevy::{
+    core_pipeline::taa::{
+        TemporalAntiAliasBundle, TemporalAntiAliasPlugin, TemporalAntiAliasSettings,
+    },
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(TemporalAntiAliasPlugin)
+        .add_systems((setup.on_startup(), rotate, toggle_taa))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    println!("Toggle with:");
+    println!("1 - NO AA");
+    println!("2 - MSAA 4");
+    println!("3 - TAA (default)");
+    println!("R - Reset the TAA history");
+
+    // plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Plane::from_size(5.0).into()),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..default()
+    });
+
+    // thin, shiny rotating bars have lots of edge and specular aliasing
+    let material = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.8, 0.7, 0.6),
+        perceptual_roughness: 0.1,
+        metallic: 1.0,
+        ..default()
+    });
+    for i in 0..5 {
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::Box::new(0.05, 0.05, 1.5))),
+                material: material.clone(),
+                transform: Transform::from_xyz(i as f32 * 0.4 - 0.8, 0.5, -i as f32 * 0.3),
+                ..default()
+            },
+            Rotates,
+        ));
+    }
+
+    // light
+    commands.spawn(DirectionalLightBundle {
+        directional_light: DirectionalLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_rotation(Quat::from_euler(
+            EulerRot::ZYX,
+            0.0,
+            PI * -0.15,
+            PI * -0.15,
+        )),
+        ..default()
+    });
+
+    // camera
+    commands.spawn((
+        Camera3dBundle {
+            camera: Camera {
+                hdr: true, // Works with and without hdr
+                ..default()
+            },
+            transform: Transform::from_xyz(0.7, 1.5, 3.0)
+                .looking_at(Vec3::new(0.0, 0.3, 0.0), Vec3::Y),
+            ..default()
+        },
+        TemporalAntiAliasBundle::default(),
+        // TAA requires MSAA to be disabled
+        Msaa::Off,
+    ));
+}
+
+#[derive(Component)]
+struct Rotates;
+
+fn rotate(time: Res<Time>, mut query: Query<&mut Transform, With<Rotates>>) {
+    for mut transform in &mut query {
+        transform.rotate_y(time.delta_seconds() * 0.5);
+    }
+}
+
+fn toggle_taa(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    mut camera: Query<(Entity, Option<&mut TemporalAntiAliasSettings>, &mut Msaa), With<Camera>>,
+) {
+    let (camera_entity, taa_settings, mut msaa) = camera.single_mut();
+
+    if keys.just_pressed(KeyCode::Key1) {
+        *msaa = Msaa::Off;
+        commands
+            .entity(camera_entity)
+            .remove::<TemporalAntiAliasSettings>();
+        info!("NO AA");
+    }
+    if keys.just_pressed(KeyCode::Key2) {
+        *msaa = Msaa::Sample4;
+        commands
+            .entity(camera_entity)
+            .remove::<TemporalAntiAliasSettings>();
+        info!("MSAA 4x");
+    }
+    if keys.just_pressed(KeyCode::Key3) {
+        *msaa = Msaa::Off;
+        commands
+            .entity(camera_entity)
+            .insert(TemporalAntiAliasSettings::default());
+        info!("TAA");
+    }
+    if keys.just_pressed(KeyCode::R) {
+        if let Some(mut taa_settings) = taa_settings {
+            taa_settings.reset = true;
+        }
+    }
+}
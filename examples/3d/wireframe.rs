@@ -1,19 +1,22 @@
 //! Showcases wireframe rendering.
 
 use bevy::{
-    pbr::wireframe::{Wireframe, WireframeConfig, WireframePlugin},
+    pbr::wireframe::{NoWireframe, Wireframe, WireframeColor, WireframeConfig, WireframePlugin},
     prelude::*,
     render::{render_resource::WgpuFeatures, settings::WgpuSettings, RenderPlugin},
 };
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins.set(RenderPlugin {
-            wgpu_settings: WgpuSettings {
-                features: WgpuFeatures::POLYGON_MODE_LINE,
-                ..default()
-            },
-        }))
+        .add_plugins(
+            DefaultPlugins.set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    features: WgpuFeatures::POLYGON_MODE_LINE,
+                    ..default()
+                }
+                .into(),
+            }),
+        )
         .add_plugin(WireframePlugin)
         .add_startup_system(setup)
         .run();
@@ -28,12 +31,18 @@ fn setup(
 ) {
     // To draw the wireframe on all entities, set this to 'true'
     wireframe_config.global = false;
+    // The color of the wireframes without a `WireframeColor`
+    wireframe_config.default_color = Color::WHITE;
     // plane
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(shape::Plane::from_size(5.0).into()),
-        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
-        ..default()
-    });
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(shape::Plane::from_size(5.0).into()),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..default()
+        },
+        // This excludes the plane from the wireframes drawn when `global` is set
+        NoWireframe,
+    ));
     // cube
     commands.spawn((
         PbrBundle {
@@ -44,6 +53,8 @@ fn setup(
         },
         // This enables wireframe drawing on this entity
         Wireframe,
+        // This overrides the default wireframe color
+        WireframeColor(Color::YELLOW),
     ));
     // light
     commands.spawn(PointLightBundle {
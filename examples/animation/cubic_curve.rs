@@ -0,0 +1,116 @@
+//! Demonstrates how to build a cubic curve and move along it.
+
+use bevy::{
+    math::{cubic_splines::CubicCurve, vec3},
+    prelude::*,
+};
+
+#[derive(Component)]
+struct Curve(CubicCurve<Vec3>);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(animate_cube)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Define your control points
+    // These points will define the curve
+    // You can learn more about bezier curves here
+    // https://en.wikipedia.org/wiki/B%C3%A9zier_curve
+    let points = [[
+        vec3(-6., 2., 0.),
+        vec3(12., 8., 0.),
+        vec3(-12., 8., 0.),
+        vec3(6., 2., 0.),
+    ]];
+
+    // Make a CubicCurve
+    let bezier = CubicBezier::new(points)
+        .to_curve()
+        .expect("a set of four control points makes a Bezier curve");
+
+    // Mark the path of the curve with small spheres
+    let marker_mesh = meshes.add(Mesh::from(shape::UVSphere {
+        radius: 0.1,
+        ..default()
+    }));
+    let marker_material = materials.add(Color::WHITE.into());
+    for position in bezier.iter_positions(50) {
+        commands.spawn(PbrBundle {
+            mesh: marker_mesh.clone(),
+            material: marker_material.clone(),
+            transform: Transform::from_translation(position),
+            ..default()
+        });
+    }
+
+    // The control points are shown in red
+    let control_point_material = materials.add(Color::RED.into());
+    for point in points[0] {
+        commands.spawn(PbrBundle {
+            mesh: marker_mesh.clone(),
+            material: control_point_material.clone(),
+            transform: Transform::from_translation(point).with_scale(Vec3::splat(2.0)),
+            ..default()
+        });
+    }
+
+    // Spawning a cube to experiment on
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(shape::Cube::default().into()),
+            material: materials.add(Color::ORANGE.into()),
+            transform: Transform::from_translation(points[0][0]),
+            ..default()
+        },
+        Curve(bezier),
+    ));
+
+    // Some light to see something
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 9000.,
+            range: 100.,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(8., 16., 8.),
+        ..default()
+    });
+
+    // ground plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Plane::from_size(50.).into()),
+        material: materials.add(Color::SILVER.into()),
+        ..default()
+    });
+
+    // The camera
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0., 6., 12.).looking_at(Vec3::new(0., 3., 0.), Vec3::Y),
+        ..default()
+    });
+}
+
+fn animate_cube(time: Res<Time>, mut query: Query<(&mut Transform, &Curve)>) {
+    // Go back and forth along the curve
+    let t = (time.elapsed_seconds().sin() + 1.) / 2.;
+
+    for (mut transform, cubic_curve) in &mut query {
+        // Move the cube along the curve
+        transform.translation = cubic_curve.0.position(t);
+        // Face the direction the cube is moving in
+        let velocity = cubic_curve.0.velocity(t);
+        if velocity != Vec3::ZERO {
+            transform.look_to(velocity, Vec3::Y);
+        }
+    }
+}
@@ -4,12 +4,15 @@ use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
+        // Spatial audio uses the distance to attenuate the sound volume. In 2D with the default
+        // camera, 1 pixel is 1 unit of distance, so we use a scale so that 100 pixels is 1 unit
+        // of distance for audio.
+        .insert_resource(SpatialScale::new_2d(1.0 / AUDIO_SCALE))
         .add_systems((setup.on_startup(), update_positions))
         .run();
 }
 
-/// Spatial audio uses the distance to attenuate the sound volume. In 2D with the default camera, 1 pixel is 1 unit of distance,
-/// so we use a scale so that 100 pixels is 1 unit of distance for audio.
+/// The number of pixels in 1 unit of distance for audio.
 const AUDIO_SCALE: f32 = 100.0;
 
 fn setup(
@@ -18,20 +21,12 @@ fn setup(
     mut materials: ResMut<Assets<ColorMaterial>>,
     asset_server: Res<AssetServer>,
     audio: Res<Audio>,
-    audio_sinks: Res<Assets<SpatialAudioSink>>,
 ) {
     // Space between the two ears
     let gap = 400.0;
 
-    let music = asset_server.load("sounds/Windless Slopes.ogg");
-    let handle = audio_sinks.get_handle(audio.play_spatial_with_settings(
-        music,
-        PlaybackSettings::LOOP,
-        Transform::IDENTITY,
-        gap / AUDIO_SCALE,
-        Vec3::ZERO,
-    ));
-    commands.insert_resource(AudioController(handle));
+    // listener, hearing the sounds from between the two ears
+    commands.spawn((SpatialBundle::default(), SpatialListener::new(gap)));
 
     // left ear
     commands.spawn(SpriteBundle {
@@ -55,16 +50,23 @@ fn setup(
         ..default()
     });
 
-    // sound emitter
-    commands.spawn((
-        MaterialMesh2dBundle {
-            mesh: meshes.add(shape::Circle::new(15.0).into()).into(),
-            material: materials.add(ColorMaterial::from(Color::BLUE)),
-            transform: Transform::from_translation(Vec3::new(0.0, 50.0, 0.0)),
-            ..default()
-        },
-        Emitter,
-    ));
+    // sound emitter, playing the music from its position as it moves
+    let emitter = commands
+        .spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(shape::Circle::new(15.0).into()).into(),
+                material: materials.add(ColorMaterial::from(Color::BLUE)),
+                transform: Transform::from_translation(Vec3::new(0.0, 50.0, 0.0)),
+                ..default()
+            },
+            Emitter,
+        ))
+        .id();
+    audio.play_on(
+        emitter,
+        asset_server.load("sounds/Windless Slopes.ogg"),
+        PlaybackSettings::LOOP.with_spatial(true),
+    );
 
     // camera
     commands.spawn(Camera2dBundle::default());
@@ -73,18 +75,7 @@ fn setup(
 #[derive(Component)]
 struct Emitter;
 
-#[derive(Resource)]
-struct AudioController(Handle<SpatialAudioSink>);
-
-fn update_positions(
-    audio_sinks: Res<Assets<SpatialAudioSink>>,
-    music_controller: Res<AudioController>,
-    time: Res<Time>,
-    mut emitter: Query<&mut Transform, With<Emitter>>,
-) {
-    if let Some(sink) = audio_sinks.get(&music_controller.0) {
-        let mut emitter_transform = emitter.single_mut();
-        emitter_transform.translation.x = time.elapsed_seconds().sin() * 500.0;
-        sink.set_emitter_position(emitter_transform.translation / AUDIO_SCALE);
-    }
+fn update_positions(time: Res<Time>, mut emitter: Query<&mut Transform, With<Emitter>>) {
+    let mut emitter_transform = emitter.single_mut();
+    emitter_transform.translation.x = time.elapsed_seconds().sin() * 500.0;
 }
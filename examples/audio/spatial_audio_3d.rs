@@ -12,22 +12,14 @@ fn setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     audio: Res<Audio>,
-    audio_sinks: Res<Assets<SpatialAudioSink>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
     // Space between the two ears
     let gap = 4.0;
 
-    let music = asset_server.load("sounds/Windless Slopes.ogg");
-    let handle = audio_sinks.get_handle(audio.play_spatial_with_settings(
-        music,
-        PlaybackSettings::LOOP,
-        Transform::IDENTITY,
-        gap,
-        Vec3::ZERO,
-    ));
-    commands.insert_resource(AudioController(handle));
+    // listener, hearing the sounds from between the two ears
+    commands.spawn((SpatialBundle::default(), SpatialListener::new(gap)));
 
     // left ear
     commands.spawn(PbrBundle {
@@ -45,19 +37,26 @@ fn setup(
         ..default()
     });
 
-    // sound emitter
-    commands.spawn((
-        PbrBundle {
-            mesh: meshes.add(Mesh::from(shape::UVSphere {
-                radius: 0.2,
+    // sound emitter, playing the music from its position as it moves
+    let emitter = commands
+        .spawn((
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::UVSphere {
+                    radius: 0.2,
+                    ..default()
+                })),
+                material: materials.add(Color::BLUE.into()),
+                transform: Transform::from_xyz(0.0, 0.0, 0.0),
                 ..default()
-            })),
-            material: materials.add(Color::BLUE.into()),
-            transform: Transform::from_xyz(0.0, 0.0, 0.0),
-            ..default()
-        },
-        Emitter,
-    ));
+            },
+            Emitter,
+        ))
+        .id();
+    audio.play_on(
+        emitter,
+        asset_server.load("sounds/Windless Slopes.ogg"),
+        PlaybackSettings::LOOP.with_spatial(true),
+    );
 
     // light
     commands.spawn(PointLightBundle {
@@ -79,19 +78,8 @@ fn setup(
 #[derive(Component)]
 struct Emitter;
 
-#[derive(Resource)]
-struct AudioController(Handle<SpatialAudioSink>);
-
-fn update_positions(
-    audio_sinks: Res<Assets<SpatialAudioSink>>,
-    music_controller: Res<AudioController>,
-    time: Res<Time>,
-    mut emitter: Query<&mut Transform, With<Emitter>>,
-) {
-    if let Some(sink) = audio_sinks.get(&music_controller.0) {
-        let mut emitter_transform = emitter.single_mut();
-        emitter_transform.translation.x = time.elapsed_seconds().sin() * 3.0;
-        emitter_transform.translation.z = time.elapsed_seconds().cos() * 3.0;
-        sink.set_emitter_position(emitter_transform.translation);
-    }
+fn update_positions(time: Res<Time>, mut emitter: Query<&mut Transform, With<Emitter>>) {
+    let mut emitter_transform = emitter.single_mut();
+    emitter_transform.translation.x = time.elapsed_seconds().sin() * 3.0;
+    emitter_transform.translation.z = time.elapsed_seconds().cos() * 3.0;
 }
@@ -1,7 +1,7 @@
 //! This example illustrates how to create a custom diagnostic.
 
 use bevy::{
-    diagnostic::{Diagnostic, DiagnosticId, Diagnostics, LogDiagnosticsPlugin},
+    diagnostic::{Diagnostic, DiagnosticPath, DiagnosticsStore, LogDiagnosticsPlugin},
     prelude::*,
 };
 
@@ -15,22 +15,17 @@ fn main() {
         .run();
 }
 
-// All diagnostics should have a unique DiagnosticId.
-// For each new diagnostic, generate a new random number.
-pub const SYSTEM_ITERATION_COUNT: DiagnosticId =
-    DiagnosticId::from_u128(337040787172757619024841343456040760896);
+// All diagnostics should have a unique DiagnosticPath.
+pub const SYSTEM_ITERATION_COUNT: DiagnosticPath =
+    DiagnosticPath::const_new("system_iteration_count");
 
-fn setup_diagnostic_system(mut diagnostics: ResMut<Diagnostics>) {
+fn setup_diagnostic_system(mut diagnostics: ResMut<DiagnosticsStore>) {
     // Diagnostics must be initialized before measurements can be added.
     // In general it's a good idea to set them up in a "startup system".
-    diagnostics.add(Diagnostic::new(
-        SYSTEM_ITERATION_COUNT,
-        "system_iteration_count",
-        10,
-    ));
+    diagnostics.add(Diagnostic::new(SYSTEM_ITERATION_COUNT, 10));
 }
 
-fn my_system(mut diagnostics: ResMut<Diagnostics>) {
+fn my_system(mut diagnostics: ResMut<DiagnosticsStore>) {
     // Add a measurement of 10.0 for our diagnostic each time this system runs.
-    diagnostics.add_measurement(SYSTEM_ITERATION_COUNT, || 10.0);
+    diagnostics.add_measurement(&SYSTEM_ITERATION_COUNT, || 10.0);
 }
@@ -19,5 +19,7 @@ fn main() {
         // .add_plugin(bevy::asset::diagnostic::AssetCountDiagnosticsPlugin::<Texture>::default())
         // Uncomment this to add system info diagnostics:
         // .add_plugin(bevy::diagnostic::SystemInformationDiagnosticsPlugin::default())
+        // Uncomment this to add per-system time diagnostics (requires the `system_timing` feature):
+        // .add_plugin(bevy::diagnostic::SystemTimeDiagnosticsPlugin::default())
         .run();
 }
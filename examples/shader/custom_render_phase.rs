@@ -0,0 +1,313 @@
+//! Renders outlines in a custom render phase, drawn by a custom render graph node
+//! that runs between the main opaque pass and tonemapping.
+//!
+//! The outlines are drawn with the "inverted hull" technique: the mesh is inflated along
+//! its normals and only its back faces are drawn, leaving a rim around the original mesh.
+
+use bevy::{
+    core_pipeline::core_3d::{self, Camera3d},
+    ecs::query::QueryItem,
+    pbr::{
+        DrawMesh, MeshBatchSet, MeshPipeline, MeshPipelineKey, MeshUniform, SetMeshBindGroup,
+        SetMeshViewBindGroup,
+    },
+    prelude::*,
+    render::{
+        batching::{batch_and_prepare_render_phase, InstancedPhaseItem},
+        extract_component::{ExtractComponent, ExtractComponentPlugin},
+        mesh::MeshVertexBufferLayout,
+        render_asset::RenderAssets,
+        render_graph::{
+            NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
+        },
+        render_phase::{
+            sort_phase_system, AddRenderCommand, DrawFunctionId, DrawFunctions, PhaseItem,
+            RenderPhase, SetItemPipeline, SortedRenderPhasePlugin,
+        },
+        render_resource::*,
+        renderer::RenderContext,
+        view::{ExtractedView, ViewDepthTexture, ViewTarget, VisibleEntities},
+        RenderApp, RenderSet,
+    },
+    utils::FloatOrd,
+};
+use std::ops::Range;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(OutlinePlugin)
+        .add_systems((setup.on_startup(), rotate))
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(shape::Plane::from_size(5.0).into()),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+        ..default()
+    });
+
+    // outlined cube
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            transform: Transform::from_xyz(-0.8, 0.5, 0.0),
+            ..default()
+        },
+        Outlined,
+        Rotates,
+    ));
+
+    // outlined sphere
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(
+                Mesh::try_from(shape::Icosphere {
+                    radius: 0.5,
+                    subdivisions: 4,
+                })
+                .unwrap(),
+            ),
+            material: materials.add(Color::rgb(0.6, 0.7, 0.8).into()),
+            transform: Transform::from_xyz(0.8, 0.5, 0.0),
+            ..default()
+        },
+        Outlined,
+    ));
+
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    // camera
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 2.5, 5.0).looking_at(Vec3::new(0.0, 0.5, 0.0), Vec3::Y),
+        ..default()
+    });
+}
+
+#[derive(Component)]
+struct Rotates;
+
+fn rotate(time: Res<Time>, mut query: Query<&mut Transform, With<Rotates>>) {
+    for mut transform in &mut query {
+        transform.rotate_y(time.delta_seconds() * 0.5);
+    }
+}
+
+/// Marks the meshes drawn in the [`Outline3d`] phase.
+#[derive(Component, Clone, ExtractComponent)]
+struct Outlined;
+
+struct OutlinePlugin;
+
+impl Plugin for OutlinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(ExtractComponentPlugin::<Outlined>::default())
+            // Adds the `RenderPhase<Outline3d>` to every 3d camera, and sorts it.
+            .add_plugin(SortedRenderPhasePlugin::<Outline3d, Camera3d>::default());
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+
+        render_app
+            .add_render_command::<Outline3d, DrawOutline>()
+            .init_resource::<OutlinePipeline>()
+            .init_resource::<SpecializedMeshPipelines<OutlinePipeline>>()
+            .add_systems((
+                queue_outlines.in_set(RenderSet::Queue),
+                // The outlined meshes use the mesh bind group, so they have to be given
+                // their `MeshUniform` like the other mesh phases.
+                batch_and_prepare_render_phase::<Outline3d, MeshPipeline>
+                    .after(sort_phase_system::<Outline3d>)
+                    .in_set(MeshBatchSet::Batch)
+                    .in_set(RenderSet::PhaseSort),
+            ))
+            // Runs the outline pass after the opaque meshes are drawn, and before tonemapping.
+            .add_render_graph_node::<ViewNodeRunner<OutlineNode>>(core_3d::graph::NAME, OutlinePass)
+            .add_render_graph_edges(
+                core_3d::graph::NAME,
+                (
+                    core_3d::graph::node::MAIN_PASS,
+                    OutlinePass,
+                    core_3d::graph::node::TONEMAPPING,
+                ),
+            );
+    }
+}
+
+/// The phase item of the outline phase, drawn front-to-back.
+#[derive(PhaseItem)]
+struct Outline3d {
+    #[sort_key]
+    distance: FloatOrd,
+    #[entity]
+    entity: Entity,
+    #[pipeline]
+    pipeline: CachedRenderPipelineId,
+    #[draw_function]
+    draw_function: DrawFunctionId,
+    instance_range: Range<u32>,
+    dynamic_offset: Option<u32>,
+}
+
+impl InstancedPhaseItem for Outline3d {
+    fn instance_range(&self) -> &Range<u32> {
+        &self.instance_range
+    }
+
+    fn instance_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.instance_range
+    }
+
+    fn dynamic_offset(&self) -> Option<u32> {
+        self.dynamic_offset
+    }
+
+    fn dynamic_offset_mut(&mut self) -> &mut Option<u32> {
+        &mut self.dynamic_offset
+    }
+}
+
+type DrawOutline = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMeshBindGroup<1>,
+    DrawMesh,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn queue_outlines(
+    draw_functions: Res<DrawFunctions<Outline3d>>,
+    outline_pipeline: Res<OutlinePipeline>,
+    mut pipelines: ResMut<SpecializedMeshPipelines<OutlinePipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    meshes: Res<RenderAssets<Mesh>>,
+    outlined_meshes: Query<(&MeshUniform, &Handle<Mesh>), With<Outlined>>,
+    mut views: Query<(
+        &ExtractedView,
+        &VisibleEntities,
+        &mut RenderPhase<Outline3d>,
+    )>,
+) {
+    let draw_outline = draw_functions.read().id::<DrawOutline>();
+    for (view, visible_entities, mut outline_phase) in &mut views {
+        let view_key = MeshPipelineKey::from_msaa_samples(view.msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
+        let rangefinder = view.rangefinder3d();
+        for &entity in &visible_entities.entities {
+            let Ok((mesh_uniform, mesh_handle)) = outlined_meshes.get(entity) else { continue };
+            let Some(mesh) = meshes.get(mesh_handle) else { continue };
+            let key = view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology);
+            let pipeline = pipelines
+                .specialize(&pipeline_cache, &outline_pipeline, key, &mesh.layout)
+                .unwrap();
+            outline_phase.add(Outline3d {
+                distance: FloatOrd(-rangefinder.distance(&mesh_uniform.transform)),
+                entity,
+                pipeline,
+                draw_function: draw_outline,
+                instance_range: 0..1,
+                dynamic_offset: None,
+            });
+        }
+    }
+}
+
+#[derive(Resource)]
+struct OutlinePipeline {
+    shader: Handle<Shader>,
+    mesh_pipeline: MeshPipeline,
+}
+
+impl FromWorld for OutlinePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        OutlinePipeline {
+            shader: asset_server.load("shaders/outline.wgsl"),
+            mesh_pipeline: world.resource::<MeshPipeline>().clone(),
+        }
+    }
+}
+
+impl SpecializedMeshPipeline for OutlinePipeline {
+    type Key = MeshPipelineKey;
+
+    fn specialize(
+        &self,
+        key: Self::Key,
+        layout: &MeshVertexBufferLayout,
+    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
+        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
+        descriptor.label = Some("outline_pipeline".into());
+        descriptor.vertex.shader = self.shader.clone();
+        descriptor.fragment.as_mut().unwrap().shader = self.shader.clone();
+        // Only the back faces of the inflated mesh are visible around the original mesh
+        descriptor.primitive.cull_mode = Some(Face::Front);
+        Ok(descriptor)
+    }
+}
+
+/// The label of the [`OutlineNode`] in the 3d render graph.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+struct OutlinePass;
+
+/// Draws the [`Outline3d`] phase of a view.
+#[derive(Default)]
+struct OutlineNode;
+
+impl ViewNode for OutlineNode {
+    type ViewQuery = (
+        Entity,
+        &'static RenderPhase<Outline3d>,
+        &'static ViewTarget,
+        &'static ViewDepthTexture,
+    );
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (view_entity, outline_phase, target, depth): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        if outline_phase.items.is_empty() {
+            return Ok(());
+        }
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("outline_pass"),
+            // The outlines are drawn on top of the output of the main pass
+            color_attachments: &[Some(target.get_color_attachment(Operations {
+                load: LoadOp::Load,
+                store: true,
+            }))],
+            depth_stencil_attachment: Some(RenderPassDepthStencilAttachment {
+                view: &depth.view,
+                depth_ops: Some(Operations {
+                    load: LoadOp::Load,
+                    store: true,
+                }),
+                stencil_ops: None,
+            }),
+        });
+
+        outline_phase.render(&mut render_pass, world, view_entity);
+
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@ use bevy::{
     pbr::{MeshPipeline, MeshPipelineKey, MeshUniform, SetMeshBindGroup, SetMeshViewBindGroup},
     prelude::*,
     render::{
+        batching::NoAutomaticBatching,
         extract_component::{ExtractComponent, ExtractComponentPlugin},
         mesh::{GpuBufferInfo, MeshVertexBufferLayout},
         render_asset::RenderAssets,
@@ -54,6 +55,9 @@ fn setup(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
         // We must disable the built-in frustum culling by adding the `NoFrustumCulling` marker
         // component to avoid incorrect culling.
         NoFrustumCulling,
+        // This entity issues its own instanced draw call, so it must not be drawn as part of an
+        // automatic batch.
+        NoAutomaticBatching,
     ));
 
     // camera
@@ -104,7 +108,6 @@ struct InstanceData {
 fn queue_custom(
     transparent_3d_draw_functions: Res<DrawFunctions<Transparent3d>>,
     custom_pipeline: Res<CustomPipeline>,
-    msaa: Res<Msaa>,
     mut pipelines: ResMut<SpecializedMeshPipelines<CustomPipeline>>,
     pipeline_cache: Res<PipelineCache>,
     meshes: Res<RenderAssets<Mesh>>,
@@ -113,10 +116,9 @@ fn queue_custom(
 ) {
     let draw_custom = transparent_3d_draw_functions.read().id::<DrawCustom>();
 
-    let msaa_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
-
     for (view, mut transparent_phase) in &mut views {
-        let view_key = msaa_key | MeshPipelineKey::from_hdr(view.hdr);
+        let view_key = MeshPipelineKey::from_msaa_samples(view.msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
         let rangefinder = view.rangefinder3d();
         for (entity, mesh_uniform, mesh_handle) in &material_meshes {
             if let Some(mesh) = meshes.get(mesh_handle) {
@@ -130,6 +132,8 @@ fn queue_custom(
                     pipeline,
                     draw_function: draw_custom,
                     distance: rangefinder.distance(&mesh_uniform.transform),
+                    instance_range: 0..1,
+                    dynamic_offset: None,
                 });
             }
         }
@@ -212,6 +212,12 @@ impl Material for PrepassOutputMaterial {
     fn alpha_mode(&self) -> AlphaMode {
         AlphaMode::Blend
     }
+
+    // The shader samples the prepass textures with the functions of `bevy_pbr::prepass_utils`,
+    // which are only available to materials asking for them
+    fn reads_prepass_textures(&self) -> bool {
+        true
+    }
 }
 
 /// Every time you press space, it will cycle between transparent, depth and normals view
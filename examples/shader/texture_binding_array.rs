@@ -5,13 +5,10 @@ use bevy::{
     prelude::*,
     reflect::TypeUuid,
     render::{
-        render_asset::RenderAssets,
-        render_resource::{AsBindGroupError, PreparedBindGroup, *},
+        render_resource::{AsBindGroup, ShaderRef, WgpuFeatures},
         renderer::RenderDevice,
-        texture::FallbackImage,
     },
 };
-use std::num::NonZeroU32;
 
 fn main() {
     let mut app = App::new();
@@ -37,7 +34,6 @@ fn main() {
         .run();
 }
 
-const MAX_TEXTURE_COUNT: usize = 16;
 const TILE_ID: [usize; 16] = [
     19, 23, 4, 33, 12, 69, 30, 48, 10, 65, 40, 47, 57, 41, 44, 46,
 ];
@@ -65,99 +61,28 @@ fn setup(
     // a cube with multiple textures
     commands.spawn(MaterialMeshBundle {
         mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
-        material: materials.add(BindlessMaterial { textures }),
+        material: materials.add(BindlessMaterial {
+            textures,
+            sampler: None,
+        }),
         ..Default::default()
     });
 }
 
-#[derive(Debug, Clone, TypeUuid)]
+#[derive(AsBindGroup, Debug, Clone, TypeUuid)]
 #[uuid = "8dd2b424-45a2-4a53-ac29-7ce356b2d5fe"]
 struct BindlessMaterial {
+    // @group(1) @binding(0) var textures: binding_array<texture_2d<f32>>;
+    // The array always has 16 textures, the ones past the handles being the fallback image.
+    #[texture(0, count = 16)]
     textures: Vec<Handle<Image>>,
-}
-
-impl AsBindGroup for BindlessMaterial {
-    type Data = ();
-
-    fn as_bind_group(
-        &self,
-        layout: &BindGroupLayout,
-        render_device: &RenderDevice,
-        image_assets: &RenderAssets<Image>,
-        fallback_image: &FallbackImage,
-    ) -> Result<PreparedBindGroup<Self::Data>, AsBindGroupError> {
-        // retrieve the render resources from handles
-        let mut images = vec![];
-        for handle in self.textures.iter().take(MAX_TEXTURE_COUNT) {
-            match image_assets.get(handle) {
-                Some(image) => images.push(image),
-                None => return Err(AsBindGroupError::RetryNextUpdate),
-            }
-        }
-
-        let textures = vec![&fallback_image.texture_view; MAX_TEXTURE_COUNT];
-
-        // convert bevy's resource types to WGPU's references
-        let mut textures: Vec<_> = textures.into_iter().map(|texture| &**texture).collect();
-
-        // fill in up to the first `MAX_TEXTURE_COUNT` textures and samplers to the arrays
-        for (id, image) in images.into_iter().enumerate() {
-            textures[id] = &*image.texture_view;
-        }
-
-        let bind_group = render_device.create_bind_group(&BindGroupDescriptor {
-            label: "bindless_material_bind_group".into(),
-            layout,
-            entries: &[
-                BindGroupEntry {
-                    binding: 0,
-                    resource: BindingResource::TextureViewArray(&textures[..]),
-                },
-                BindGroupEntry {
-                    binding: 1,
-                    resource: BindingResource::Sampler(&fallback_image.sampler),
-                },
-            ],
-        });
-
-        Ok(PreparedBindGroup {
-            bindings: vec![],
-            bind_group,
-            data: (),
-        })
-    }
-
-    fn bind_group_layout(render_device: &RenderDevice) -> BindGroupLayout
-    where
-        Self: Sized,
-    {
-        render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
-            label: "bindless_material_layout".into(),
-            entries: &[
-                // @group(1) @binding(0) var textures: binding_array<texture_2d<f32>>;
-                BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Texture {
-                        sample_type: TextureSampleType::Float { filterable: true },
-                        view_dimension: TextureViewDimension::D2,
-                        multisampled: false,
-                    },
-                    count: NonZeroU32::new(MAX_TEXTURE_COUNT as u32),
-                },
-                // @group(1) @binding(1) var nearest_sampler: sampler;
-                BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: ShaderStages::FRAGMENT,
-                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
-                    count: None,
-                    // Note: as textures, multiple samplers can also be bound onto one binding slot.
-                    // One may need to pay attention to the limit of sampler binding amount on some platforms.
-                    // count: NonZeroU32::new(MAX_TEXTURE_COUNT as u32),
-                },
-            ],
-        })
-    }
+    // @group(1) @binding(1) var nearest_sampler: sampler;
+    // Without a handle, this is the sampler of the fallback image, which is the default
+    // sampler of the `ImagePlugin`.
+    // Note: as textures, multiple samplers can also be bound onto one binding slot.
+    // One may need to pay attention to the limit of sampler binding amount on some platforms.
+    #[sampler(1)]
+    sampler: Option<Handle<Image>>,
 }
 
 impl Material for BindlessMaterial {
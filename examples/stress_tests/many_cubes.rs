@@ -9,6 +9,10 @@
 //!
 //! To start the demo using the spherical layout run
 //! `cargo run --example many_cubes --release sphere`
+//!
+//! Identical meshes sharing a material are drawn in instanced batches. To measure the cost of
+//! breaking those batches, give the cubes many different materials by adding `many_materials`
+//! `cargo run --example many_cubes --release cube many_materials`
 
 use std::f64::consts::PI;
 
@@ -48,6 +52,21 @@ fn setup(
         base_color: Color::PINK,
         ..default()
     });
+    let material_variants = if std::env::args().any(|arg| arg == "many_materials") {
+        // NOTE: Cubes cycle through the materials so that, once sorted by mesh and distance,
+        // consecutive draws rarely share a material and batches are broken up.
+        (0..64)
+            .map(|i| {
+                materials.add(StandardMaterial {
+                    base_color: Color::hsl(i as f32 * 360.0 / 64.0, 0.8, 0.6),
+                    ..default()
+                })
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![material.clone()]
+    };
+    let material_for = |i: usize| material_variants[i % material_variants.len()].clone();
 
     match std::env::args().nth(1).as_deref() {
         Some("sphere") => {
@@ -63,7 +82,7 @@ fn setup(
                 let unit_sphere_p = spherical_polar_to_cartesian(spherical_polar_theta_phi);
                 commands.spawn(PbrBundle {
                     mesh: mesh.clone_weak(),
-                    material: material.clone_weak(),
+                    material: material_for(i),
                     transform: Transform::from_translation((radius * unit_sphere_p).as_vec3()),
                     ..default()
                 });
@@ -84,13 +103,13 @@ fn setup(
                     // cube
                     commands.spawn(PbrBundle {
                         mesh: mesh.clone_weak(),
-                        material: material.clone_weak(),
+                        material: material_for(x * HEIGHT + y),
                         transform: Transform::from_xyz((x as f32) * 2.5, (y as f32) * 2.5, 0.0),
                         ..default()
                     });
                     commands.spawn(PbrBundle {
                         mesh: mesh.clone_weak(),
-                        material: material.clone_weak(),
+                        material: material_for(x * HEIGHT + y),
                         transform: Transform::from_xyz(
                             (x as f32) * 2.5,
                             HEIGHT as f32 * 2.5,
@@ -100,13 +119,13 @@ fn setup(
                     });
                     commands.spawn(PbrBundle {
                         mesh: mesh.clone_weak(),
-                        material: material.clone_weak(),
+                        material: material_for(x * HEIGHT + y),
                         transform: Transform::from_xyz((x as f32) * 2.5, 0.0, (y as f32) * 2.5),
                         ..default()
                     });
                     commands.spawn(PbrBundle {
                         mesh: mesh.clone_weak(),
-                        material: material.clone_weak(),
+                        material: material_for(x * HEIGHT + y),
                         transform: Transform::from_xyz(0.0, (x as f32) * 2.5, (y as f32) * 2.5),
                         ..default()
                     });
@@ -120,7 +139,7 @@ fn setup(
         }
     }
 
-    // add one cube, the only one with strong handles
+    // add one cube, the only one with a strong mesh handle
     // also serves as a reference point during rotation
     commands.spawn(PbrBundle {
         mesh,
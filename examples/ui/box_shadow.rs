@@ -0,0 +1,67 @@
+//! Demonstrates how to add drop shadows to UI nodes with the [`BoxShadow`] component.
+//! Shows a grid of rounded nodes, with the blur radius of their shadow increasing from top to bottom
+//! and its spread radius increasing from left to right.
+
+use bevy::prelude::*;
+
+const BLUR_RADII: [f32; 4] = [0., 5., 10., 20.];
+const SPREAD_RADII: [f32; 4] = [-10., 0., 5., 15.];
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(0.8, 0.8, 0.85)))
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::all(Val::Percent(100.)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for blur_radius in BLUR_RADII {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            size: Size::width(Val::Percent(100.)),
+                            justify_content: JustifyContent::SpaceEvenly,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for spread_radius in SPREAD_RADII {
+                            parent.spawn((
+                                NodeBundle {
+                                    style: Style {
+                                        size: Size::new(Val::Px(120.), Val::Px(80.)),
+                                        ..default()
+                                    },
+                                    background_color: Color::rgb(0.95, 0.95, 1.).into(),
+                                    ..default()
+                                },
+                                BorderRadius::all(Val::Px(12.)),
+                                BoxShadow::new(
+                                    Color::rgba(0., 0., 0., 0.6),
+                                    Val::Px(6.),
+                                    Val::Px(6.),
+                                    Val::Px(spread_radius),
+                                    Val::Px(blur_radius),
+                                ),
+                            ));
+                        }
+                    });
+            }
+        });
+}
@@ -19,9 +19,7 @@ fn main() {
         // You can also customize update behavior with the fields of [`WinitConfig`]
         .insert_resource(WinitSettings {
             focused_mode: bevy::winit::UpdateMode::Continuous,
-            unfocused_mode: bevy::winit::UpdateMode::ReactiveLowPower {
-                max_wait: Duration::from_millis(10),
-            },
+            unfocused_mode: bevy::winit::UpdateMode::reactive_low_power(Duration::from_millis(10)),
             ..default()
         })
         .insert_resource(ExampleMode::Game)